@@ -6,8 +6,9 @@ use std::{
 
 use clap::Parser;
 use monty::{
-    LimitedTracker, MontyObject, MontyRepl, MontyRun, NameLookupResult, NoLimitTracker, PrintWriter,
-    ReplContinuationMode, ReplProgress, ResourceLimits, ResourceTracker, RunProgress, detect_repl_continuation_mode,
+    LimitedTracker, ModuleLookupResult, MontyObject, MontyRepl, MontyRun, NameLookupResult, NoLimitTracker,
+    PrintWriter, ReplContinuationMode, ReplProgress, ResourceLimits, ResourceTracker, RunProgress,
+    detect_repl_continuation_mode,
     fs::{MountMode, MountTable, OverlayState},
 };
 use rustyline::{DefaultEditor, error::ReadlineError};
@@ -72,6 +73,10 @@ struct Cli {
     #[arg(long)]
     max_duration: Option<f64>,
 
+    /// Maximum fuel: bytecode instructions plus the proportional cost of expensive builtins.
+    #[arg(long)]
+    max_fuel: Option<usize>,
+
     /// Maximum heap memory (e.g. `1024`, `512KB`, `10MB`, `1GB`).
     #[arg(long, value_parser = parse_memory_size)]
     max_memory: Option<usize>,
@@ -83,6 +88,10 @@ struct Cli {
     /// Maximum call-stack depth (defaults to 1000 when any limit is set).
     #[arg(long)]
     max_recursion_depth: Option<usize>,
+
+    /// Maximum output written by the program (e.g. `1024`, `512KB`, `10MB`).
+    #[arg(long, value_parser = parse_memory_size)]
+    max_output: Option<usize>,
 }
 
 impl Cli {
@@ -94,9 +103,11 @@ impl Cli {
     fn resource_limits(&self) -> Result<Option<ResourceLimits>, String> {
         if self.max_allocations.is_none()
             && self.max_duration.is_none()
+            && self.max_fuel.is_none()
             && self.max_memory.is_none()
             && self.gc_interval.is_none()
             && self.max_recursion_depth.is_none()
+            && self.max_output.is_none()
         {
             return Ok(None);
         }
@@ -110,6 +121,9 @@ impl Cli {
                 Duration::try_from_secs_f64(secs).map_err(|err| format!("invalid --max-duration: {err}"))?,
             );
         }
+        if let Some(fuel) = self.max_fuel {
+            limits = limits.max_fuel(fuel);
+        }
         if let Some(bytes) = self.max_memory {
             limits = limits.max_memory(bytes);
         }
@@ -119,6 +133,9 @@ impl Cli {
         if let Some(depth) = self.max_recursion_depth {
             limits = limits.max_recursion_depth(Some(depth));
         }
+        if let Some(bytes) = self.max_output {
+            limits = limits.max_output_bytes(bytes);
+        }
         Ok(Some(limits))
     }
 }
@@ -491,6 +508,18 @@ fn execute_repl_with_mounts<T: ResourceTracker>(
                 Ok(p) => progress = p,
                 Err(err) => return Err((err.repl, format!("{}", err.error))),
             },
+            ReplProgress::ModuleLookup(lookup) => {
+                match lookup.resume(ModuleLookupResult::NotFound, PrintWriter::Stdout) {
+                    Ok(p) => progress = p,
+                    Err(err) => return Err((err.repl, format!("{}", err.error))),
+                }
+            }
+            ReplProgress::HandleCall(call) => {
+                return Err((call.into_repl(), "host handles not supported in CLI".to_owned()));
+            }
+            ReplProgress::CallbackResult(result) => {
+                return Err((result.into_repl(), "callbacks not supported in CLI".to_owned()));
+            }
             ReplProgress::ResolveFutures(state) => {
                 return Err((state.into_repl(), "async futures not supported in CLI".to_owned()));
             }
@@ -539,6 +568,17 @@ fn run_until_complete(
                     .resume(result, PrintWriter::Stdout)
                     .map_err(|err| format!("{err}"))?;
             }
+            RunProgress::ModuleLookup(lookup) => {
+                progress = lookup
+                    .resume(ModuleLookupResult::NotFound, PrintWriter::Stdout)
+                    .map_err(|err| format!("{err}"))?;
+            }
+            RunProgress::HandleCall(call) => {
+                return Err(format!("host handles not supported in CLI: {}", call.type_name));
+            }
+            RunProgress::CallbackResult(result) => {
+                return Err(format!("callbacks not supported in CLI: {}", result.callback_id));
+            }
             RunProgress::OsCall(call) => {
                 let result = handle_os_call(&call.function_call, mount_table);
                 progress = call
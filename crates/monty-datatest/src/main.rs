@@ -20,9 +20,9 @@ use std::{
 use ahash::AHashMap;
 use chrono::{Datelike, Timelike};
 use monty::{
-    ExcType, ExtFunctionResult, FileMode, LimitedTracker, MontyDate, MontyDateTime, MontyException, MontyFileHandle,
-    MontyObject, MontyRun, NameLookupResult, OsFunctionCall, PrintWriter, ResourceLimits, RunProgress, dir_stat,
-    file_stat,
+    ExcType, ExtFunctionResult, FileMode, LimitedTracker, ModuleLookupResult, MontyDate, MontyDateTime, MontyException,
+    MontyFileHandle, MontyObject, MontyRun, NameLookupResult, OsFunctionCall, PrintWriter, ResourceLimits, RunProgress,
+    dir_stat, file_stat,
     fs::{MountMode, MountTable, OverlayState},
 };
 use pyo3::{prelude::*, types::PyDict};
@@ -1750,6 +1750,19 @@ fn run_mount_fs_iter_loop(
                 };
                 progress = lookup.resume(result, PrintWriter::Stdout)?;
             }
+            RunProgress::ModuleLookup(lookup) => {
+                progress = lookup.resume(ModuleLookupResult::NotFound, PrintWriter::Stdout)?;
+            }
+            // Test cases never pass host handles in
+            RunProgress::HandleCall(call) => {
+                let exc = MontyException::new(ExcType::NotImplementedError, Some("host handle".to_owned()));
+                progress = call.resume(exc, PrintWriter::Stdout)?;
+            }
+            // Test cases never call callbacks
+            RunProgress::CallbackResult(result) => {
+                let exc = MontyException::new(ExcType::NotImplementedError, Some("callback".to_owned()));
+                progress = result.resume(exc, PrintWriter::Stdout)?;
+            }
             RunProgress::OsCall(call) => {
                 // Dispatch through the mount table first.
                 let result = mount_table.handle_os_call(&call.function_call);
@@ -1871,6 +1884,20 @@ fn run_iter_loop(exec: MontyRun, limits: ResourceLimits) -> Result<MontyObject,
                 };
                 progress = lookup.resume(result, PrintWriter::Stdout)?;
             }
+            // Unknown modules → ModuleNotFoundError
+            RunProgress::ModuleLookup(lookup) => {
+                progress = lookup.resume(ModuleLookupResult::NotFound, PrintWriter::Stdout)?;
+            }
+            // Test cases never pass host handles in
+            RunProgress::HandleCall(call) => {
+                let exc = MontyException::new(ExcType::NotImplementedError, Some("host handle".to_owned()));
+                progress = call.resume(exc, PrintWriter::Stdout)?;
+            }
+            // Test cases never call callbacks
+            RunProgress::CallbackResult(result) => {
+                let exc = MontyException::new(ExcType::NotImplementedError, Some("callback".to_owned()));
+                progress = result.resume(exc, PrintWriter::Stdout)?;
+            }
             RunProgress::OsCall(call) => {
                 let result = dispatch_os_call(&call.function_call);
                 progress = call.resume(result, PrintWriter::Stdout)?;
@@ -103,6 +103,20 @@ len(result)
   t.true(error.message.includes('MemoryError'))
 })
 
+test('fuel limit', (t) => {
+  const m = new Monty('while True:\n    pass')
+  const limits: ResourceLimits = { maxFuel: 1000 }
+  const error = t.throws(() => m.run({ limits }), { instanceOf: MontyRuntimeError })
+  t.true(error.message.includes('fuel limit exceeded'))
+})
+
+test('output limit', (t) => {
+  const m = new Monty("while True:\n    print('x' * 100)")
+  const limits: ResourceLimits = { maxOutputBytes: 1000 }
+  const error = t.throws(() => m.run({ limits, printCallback: () => {} }), { instanceOf: MontyRuntimeError })
+  t.true(error.message.includes('output limit exceeded'))
+})
+
 testAboveU32('memory limit accepts values above u32 max', (t) => {
   const m = new Monty('1 + 1')
   const limits: ResourceLimits = { maxMemory: 2 ** 33 }
@@ -14,6 +14,7 @@
 //! - `MontyObject::Float` ↔ `number` (including `NaN`, `Infinity`, `-Infinity`)
 //! - `MontyObject::String` ↔ `string`
 //! - `MontyObject::Bytes` ↔ `Buffer` (Node.js)
+//! - `MontyObject::ByteArray` → `Buffer` (output only)
 //! - `MontyObject::List` ↔ `Array`
 //! - `MontyObject::Dict` ↔ `Map` (preserves key types and insertion order)
 //! - `MontyObject::Set` ↔ `Set`
@@ -23,7 +24,8 @@
 //! - `MontyObject::Ellipsis` → `{ __monty_type__: 'Ellipsis' }`
 //! - `MontyObject::Tuple` → `Array` with `__tuple__: true`
 //! - `MontyObject::Exception` → `{ __monty_type__: 'Exception', excType, message }`
-//! - `MontyObject::Type` → `{ __monty_type__: 'Type', value }`
+//! - `MontyObject::Complex` ↔ `{ __monty_type__: 'Complex', real, imag }`
+//! - `MontyObject::Type`, `DataclassType`, `NamedTupleType` → `{ __monty_type__: 'Type', value }`
 //! - `MontyObject::BuiltinFunction` → `{ __monty_type__: 'BuiltinFunction', value }`
 //! - `MontyObject::Dataclass` → `{ __monty_type__: 'Dataclass', name, fields, ... }`
 //! - `MontyObject::Repr` → plain `string`
@@ -31,7 +33,7 @@
 
 use std::{collections::HashMap, ptr};
 
-use monty::{DictPairs, ExcType, MontyDate, MontyDateTime, MontyObject, MontyTimeDelta, MontyTimeZone};
+use monty::{DictPairs, ExcType, MontyDate, MontyDateTime, MontyException, MontyObject, MontyTimeDelta, MontyTimeZone};
 use napi::{bindgen_prelude::*, sys::Status};
 use num_bigint::BigInt as NumBigInt;
 
@@ -77,19 +79,27 @@ pub fn monty_to_js<'e>(obj: &MontyObject, env: &'e Env) -> Result<JsMontyObject<
         MontyObject::BigInt(bi) => create_js_bigint(bi, env)?,
         MontyObject::Float(f) => env.create_double(*f)?.into_unknown(env)?,
         MontyObject::String(s) => env.create_string(s)?.into_unknown(env)?,
-        MontyObject::Bytes(bytes) => create_js_buffer(bytes, env)?,
+        // bytearray has no distinct JS counterpart, so it also becomes a Buffer
+        MontyObject::Bytes(bytes) | MontyObject::ByteArray(bytes) => create_js_buffer(bytes, env)?,
         MontyObject::List(items) => create_js_array(items, env)?.into_unknown(env)?,
         MontyObject::Tuple(items) => create_js_tuple(items, env)?,
         // NamedTuple is converted to a tuple (loses named access in JS)
         MontyObject::NamedTuple { values, .. } => create_js_tuple(values, env)?,
         MontyObject::Dict(pairs) => create_js_map(pairs, env)?,
         MontyObject::Set(items) | MontyObject::FrozenSet(items) => create_js_set(items, env)?,
-        MontyObject::Exception { exc_type, arg } => create_js_exception(*exc_type, arg.as_deref(), env)?,
+        MontyObject::Exception { exc_type, args } => {
+            let exc = MontyException::with_args(*exc_type, args.clone());
+            create_js_exception(exc.exc_type(), exc.message(), env)?
+        }
         MontyObject::Date(date) => create_js_date(date, env)?,
         MontyObject::DateTime(datetime) => create_js_datetime(datetime, env)?,
         MontyObject::TimeDelta(delta) => create_js_timedelta(delta, env)?,
+        MontyObject::Complex { real, imag } => create_js_complex(*real, *imag, env)?,
         MontyObject::TimeZone(timezone) => create_js_timezone(timezone, env)?,
         MontyObject::Type(t) => create_js_type_marker(&t.to_string(), env)?,
+        MontyObject::DataclassType { name, .. } | MontyObject::NamedTupleType { type_name: name, .. } => {
+            create_js_type_marker(name, env)?
+        }
         MontyObject::BuiltinFunction(f) => create_js_builtin_function_marker(&f.to_string(), env)?,
         MontyObject::Dataclass {
             name,
@@ -101,7 +111,11 @@ pub fn monty_to_js<'e>(obj: &MontyObject, env: &'e Env) -> Result<JsMontyObject<
         MontyObject::Path(p) => env.create_string(p)?.into_unknown(env)?,
         // A Monty file object has no faithful JS representation (it is not a
         // real OS file): expose its repr string.
-        MontyObject::FileHandle { .. } => env.create_string(obj.py_repr())?.into_unknown(env)?,
+        // Host handles are never created by the JS bindings, and callbacks only reach hosts
+        // that opt in to them, which the JS bindings don't; expose the repr as for file objects.
+        MontyObject::FileHandle { .. } | MontyObject::HostHandle { .. } | MontyObject::Callback { .. } => {
+            env.create_string(obj.py_repr())?.into_unknown(env)?
+        }
         MontyObject::Repr(s) | MontyObject::Cycle(_, s) => env.create_string(s)?.into_unknown(env)?,
         // Function objects are internal to the name lookup protocol and should not normally
         // appear as final output values. If they do, represent as a string with the function name.
@@ -284,6 +298,15 @@ fn create_js_timedelta<'e>(delta: &MontyTimeDelta, env: &'e Env) -> Result<Unkno
     obj.into_unknown(env)
 }
 
+/// Creates a JS object representing a Python `complex`.
+fn create_js_complex<'e>(real: f64, imag: f64, env: &'e Env) -> Result<Unknown<'e>> {
+    let mut obj = Object::new(env)?;
+    obj.set_named_property("__monty_type__", "Complex")?;
+    obj.set_named_property("real", real)?;
+    obj.set_named_property("imag", imag)?;
+    obj.into_unknown(env)
+}
+
 /// Creates a JS object representing a Python `datetime.timezone`.
 fn create_js_timezone<'e>(timezone: &MontyTimeZone, env: &'e Env) -> Result<Unknown<'e>> {
     let mut obj = Object::new(env)?;
@@ -729,8 +752,12 @@ fn js_marked_object_to_monty(obj: &Object, monty_type: &str, env: Env) -> Result
             let exc_type: ExcType = exc_type_str
                 .parse()
                 .map_err(|_| Error::from_reason(format!("Unknown exception type: {exc_type_str}")))?;
-            let arg = if message.is_empty() { None } else { Some(message) };
-            Ok(MontyObject::Exception { exc_type, arg })
+            let args = if message.is_empty() {
+                vec![]
+            } else {
+                vec![MontyObject::String(message)]
+            };
+            Ok(MontyObject::Exception { exc_type, args })
         }
         "Date" => Ok(MontyObject::Date(MontyDate {
             year: obj.get_named_property::<i32>("year")?,
@@ -753,6 +780,10 @@ fn js_marked_object_to_monty(obj: &Object, monty_type: &str, env: Env) -> Result
             seconds: obj.get_named_property::<i32>("seconds")?,
             microseconds: obj.get_named_property::<i32>("microseconds")?,
         })),
+        "Complex" => Ok(MontyObject::Complex {
+            real: obj.get_named_property::<f64>("real")?,
+            imag: obj.get_named_property::<f64>("imag")?,
+        }),
         "TimeZone" => Ok(MontyObject::TimeZone(MontyTimeZone {
             offset_seconds: obj.get_named_property::<i32>("offsetSeconds")?,
             name: obj.get_named_property::<Option<String>>("name")?,
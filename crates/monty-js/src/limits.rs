@@ -5,7 +5,7 @@
 
 use std::time::Duration;
 
-use monty::{ResourceLimits, DEFAULT_MAX_RECURSION_DEPTH};
+use monty::{DEFAULT_MAX_RECURSION_DEPTH, ResourceLimits};
 use napi::{Error, Result, Status};
 use napi_derive::napi;
 
@@ -21,12 +21,18 @@ pub struct JsResourceLimits {
     pub max_allocations: Option<f64>,
     /// Maximum execution time in seconds.
     pub max_duration_secs: Option<f64>,
+    /// Maximum fuel: bytecode instructions plus the proportional cost of expensive builtins.
+    pub max_fuel: Option<f64>,
     /// Maximum heap memory in bytes.
     pub max_memory: Option<f64>,
     /// Run garbage collection every N allocations.
     pub gc_interval: Option<f64>,
     /// Maximum function call stack depth (default: 1000).
     pub max_recursion_depth: Option<f64>,
+    /// Maximum backtracking steps for a single regex match (default: 1,000,000).
+    pub regex_backtrack_limit: Option<f64>,
+    /// Maximum bytes of output from `print()`, `sys.stdout`/`sys.stderr` writes, warnings and log records.
+    pub max_output_bytes: Option<f64>,
 }
 
 /// Extracts a Rust resource-limit configuration from a JS resource-limit object.
@@ -54,12 +60,21 @@ pub fn extract_limits(js_limits: JsResourceLimits) -> Result<ResourceLimits> {
             Duration::try_from_secs_f64(secs).map_err(|err| Error::new(Status::InvalidArg, err.to_string()))?,
         );
     }
+    if let Some(max) = js_limits.max_fuel {
+        limits = limits.max_fuel(js_number_to_usize(max, "maxFuel")?);
+    }
     if let Some(max) = js_limits.max_memory {
         limits = limits.max_memory(js_number_to_usize(max, "maxMemory")?);
     }
     if let Some(interval) = js_limits.gc_interval {
         limits = limits.gc_interval(js_number_to_usize(interval, "gcInterval")?);
     }
+    if let Some(limit) = js_limits.regex_backtrack_limit {
+        limits = limits.regex_backtrack_limit(js_number_to_usize(limit, "regexBacktrackLimit")?);
+    }
+    if let Some(max) = js_limits.max_output_bytes {
+        limits = limits.max_output_bytes(js_number_to_usize(max, "maxOutputBytes")?);
+    }
 
     Ok(limits)
 }
@@ -51,21 +51,21 @@ use std::{
 };
 
 use monty::{
-    fs::MountTable, ExcType, ExtFunctionResult, FunctionCall, LimitedTracker, MontyException, MontyObject,
-    MontyRepl as CoreMontyRepl, MontyRun, NameLookup, NameLookupResult, NoLimitTracker, OsCall, OsFunctionCall,
-    PrintWriter, PrintWriterCallback, ReplFunctionCall, ReplNameLookup, ReplOsCall, ReplProgress, ReplStartError,
-    ResourceTracker, RunProgress,
+    ExcType, ExtFunctionResult, FunctionCall, LimitedTracker, ModuleLookupResult, MontyException, MontyLogRecord,
+    MontyObject, MontyRepl as CoreMontyRepl, MontyRun, MontyWarning, NameLookup, NameLookupResult, NoLimitTracker,
+    OsCall, OsFunctionCall, PrintStream, PrintWriter, PrintWriterCallback, ReplFunctionCall, ReplNameLookup,
+    ReplOsCall, ReplProgress, ReplStartError, ResourceTracker, RunProgress, fs::MountTable,
 };
-use monty_type_checking::{type_check, SourceFile};
+use monty_type_checking::{SourceFile, type_check};
 use napi::{bindgen_prelude::*, sys::Status};
 use napi_derive::napi;
 use serde::de::DeserializeOwned;
 
 use crate::{
-    convert::{js_external_result_to_monty, js_to_monty, monty_to_js, JsMontyObject},
-    exceptions::{exc_js_to_monty, JsMontyException, MontyTypingError},
+    convert::{JsMontyObject, js_external_result_to_monty, js_to_monty, monty_to_js},
+    exceptions::{JsMontyException, MontyTypingError, exc_js_to_monty},
     limits::JsResourceLimits,
-    mount::{extract_mounts, OsHandler},
+    mount::{OsHandler, extract_mounts},
 };
 
 // =============================================================================
@@ -319,6 +319,36 @@ impl Monty {
                                 }
                             };
                         }
+                        RunProgress::ModuleLookup(lookup) => {
+                            // No host modules in the JS bindings — let it raise ModuleNotFoundError
+                            progress = match lookup.resume(ModuleLookupResult::NotFound, print_output.reborrow()) {
+                                Ok(p) => p,
+                                Err(exc) => {
+                                    put_back(mount_table);
+                                    return Ok(Either::B(JsMontyException::new(exc)));
+                                }
+                            };
+                        }
+                        RunProgress::HandleCall(call) => {
+                            // The JS bindings never pass host handles in
+                            progress = match call.resume(handle_call_unsupported(), print_output.reborrow()) {
+                                Ok(p) => p,
+                                Err(exc) => {
+                                    put_back(mount_table);
+                                    return Ok(Either::B(JsMontyException::new(exc)));
+                                }
+                            };
+                        }
+                        RunProgress::CallbackResult(result) => {
+                            // The JS bindings never call callbacks
+                            progress = match result.resume(callback_result_unsupported(), print_output.reborrow()) {
+                                Ok(p) => p,
+                                Err(exc) => {
+                                    put_back(mount_table);
+                                    return Ok(Either::B(JsMontyException::new(exc)));
+                                }
+                            };
+                        }
                         RunProgress::ResolveFutures(_) => {
                             put_back(mount_table);
                             return Err(Error::from_reason(
@@ -857,6 +887,51 @@ impl MontyRepl {
                                 }
                             };
                         }
+                        ReplProgress::ModuleLookup(lookup) => {
+                            // No host modules — let it raise ModuleNotFoundError
+                            let print_writer = match &mut print_cb {
+                                Some(cb) => PrintWriter::Callback(cb),
+                                None => PrintWriter::Stdout,
+                            };
+                            progress = match lookup.resume(ModuleLookupResult::NotFound, print_writer) {
+                                Ok(p) => p,
+                                Err(e) => {
+                                    put_shared_repl(&self.repl, EitherRepl::from_core(e.repl))?;
+                                    put_back(mount_table);
+                                    return Ok(Either::B(JsMontyException::new(e.error)));
+                                }
+                            };
+                        }
+                        ReplProgress::HandleCall(call) => {
+                            // The JS bindings never pass host handles in
+                            let print_writer = match &mut print_cb {
+                                Some(cb) => PrintWriter::Callback(cb),
+                                None => PrintWriter::Stdout,
+                            };
+                            progress = match call.resume(handle_call_unsupported(), print_writer) {
+                                Ok(p) => p,
+                                Err(e) => {
+                                    put_shared_repl(&self.repl, EitherRepl::from_core(e.repl))?;
+                                    put_back(mount_table);
+                                    return Ok(Either::B(JsMontyException::new(e.error)));
+                                }
+                            };
+                        }
+                        ReplProgress::CallbackResult(result) => {
+                            // The JS bindings never call callbacks
+                            let print_writer = match &mut print_cb {
+                                Some(cb) => PrintWriter::Callback(cb),
+                                None => PrintWriter::Stdout,
+                            };
+                            progress = match result.resume(callback_result_unsupported(), print_writer) {
+                                Ok(p) => p,
+                                Err(e) => {
+                                    put_shared_repl(&self.repl, EitherRepl::from_core(e.repl))?;
+                                    put_back(mount_table);
+                                    return Ok(Either::B(JsMontyException::new(e.error)));
+                                }
+                            };
+                        }
                         ReplProgress::ResolveFutures(state) => {
                             put_shared_repl(&self.repl, EitherRepl::from_core(state.into_repl()))?;
                             put_back(mount_table);
@@ -1740,6 +1815,31 @@ impl PrintWriterCallback for CallbackStringPrint<'_> {
             .map_err(exc_js_to_monty)?;
         Ok(())
     }
+
+    fn write(&mut self, stream: PrintStream, output: Cow<'_, str>) -> result::Result<(), MontyException> {
+        let stream = match stream {
+            PrintStream::Stdout => "stdout",
+            PrintStream::Stderr => "stderr",
+        };
+        self.0
+            .call((stream, output.into_owned()).into())
+            .map_err(exc_js_to_monty)?;
+        Ok(())
+    }
+
+    fn warn(&mut self, warning: &MontyWarning) -> result::Result<(), MontyException> {
+        self.0
+            .call(("stderr", format!("{warning}\n")).into())
+            .map_err(exc_js_to_monty)?;
+        Ok(())
+    }
+
+    fn log(&mut self, record: &MontyLogRecord) -> result::Result<(), MontyException> {
+        self.0
+            .call(("stderr", format!("{record}\n")).into())
+            .map_err(exc_js_to_monty)?;
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -1811,6 +1911,48 @@ where
                     repl_state: None,
                 }));
             }
+            RunProgress::ModuleLookup(lookup) => {
+                // No host modules in the JS bindings — let it raise ModuleNotFoundError
+                let print_writer = match &mut print_cb {
+                    Some(cb) => PrintWriter::Callback(cb),
+                    None => PrintWriter::Stdout,
+                };
+                progress = match lookup.resume(ModuleLookupResult::NotFound, print_writer) {
+                    Ok(p) => p,
+                    Err(exc) => {
+                        put_back_mount_state(mount_state);
+                        return Ok(Either4::D(JsMontyException::new(exc)));
+                    }
+                };
+            }
+            RunProgress::HandleCall(call) => {
+                // The JS bindings never pass host handles in
+                let print_writer = match &mut print_cb {
+                    Some(cb) => PrintWriter::Callback(cb),
+                    None => PrintWriter::Stdout,
+                };
+                progress = match call.resume(handle_call_unsupported(), print_writer) {
+                    Ok(p) => p,
+                    Err(exc) => {
+                        put_back_mount_state(mount_state);
+                        return Ok(Either4::D(JsMontyException::new(exc)));
+                    }
+                };
+            }
+            RunProgress::CallbackResult(result) => {
+                // The JS bindings never call callbacks
+                let print_writer = match &mut print_cb {
+                    Some(cb) => PrintWriter::Callback(cb),
+                    None => PrintWriter::Stdout,
+                };
+                progress = match result.resume(callback_result_unsupported(), print_writer) {
+                    Ok(p) => p,
+                    Err(exc) => {
+                        put_back_mount_state(mount_state);
+                        return Ok(Either4::D(JsMontyException::new(exc)));
+                    }
+                };
+            }
             RunProgress::ResolveFutures(_) => {
                 put_back_mount_state(mount_state);
                 return Ok(Either4::D(JsMontyException::new(MontyException::new(
@@ -1917,6 +2059,51 @@ where
                     repl_state: Some(repl_state),
                 }));
             }
+            ReplProgress::ModuleLookup(lookup) => {
+                // No host modules in the JS bindings — let it raise ModuleNotFoundError
+                let print_writer = match &mut print_cb {
+                    Some(cb) => PrintWriter::Callback(cb),
+                    None => PrintWriter::Stdout,
+                };
+                progress = match lookup.resume(ModuleLookupResult::NotFound, print_writer) {
+                    Ok(p) => p,
+                    Err(exc) => {
+                        let exc = restore_repl_start_error(&repl_state, *exc)?;
+                        put_back_mount_state(mount_state);
+                        return Ok(Either4::D(exc));
+                    }
+                };
+            }
+            ReplProgress::HandleCall(call) => {
+                // The JS bindings never pass host handles in
+                let print_writer = match &mut print_cb {
+                    Some(cb) => PrintWriter::Callback(cb),
+                    None => PrintWriter::Stdout,
+                };
+                progress = match call.resume(handle_call_unsupported(), print_writer) {
+                    Ok(p) => p,
+                    Err(exc) => {
+                        let exc = restore_repl_start_error(&repl_state, *exc)?;
+                        put_back_mount_state(mount_state);
+                        return Ok(Either4::D(exc));
+                    }
+                };
+            }
+            ReplProgress::CallbackResult(result) => {
+                // The JS bindings never call callbacks
+                let print_writer = match &mut print_cb {
+                    Some(cb) => PrintWriter::Callback(cb),
+                    None => PrintWriter::Stdout,
+                };
+                progress = match result.resume(callback_result_unsupported(), print_writer) {
+                    Ok(p) => p,
+                    Err(exc) => {
+                        let exc = restore_repl_start_error(&repl_state, *exc)?;
+                        put_back_mount_state(mount_state);
+                        return Ok(Either4::D(exc));
+                    }
+                };
+            }
             ReplProgress::ResolveFutures(state) => {
                 put_shared_repl(&repl_state, EitherRepl::from_core(state.into_repl()))?;
                 put_back_mount_state(mount_state);
@@ -1964,6 +2151,26 @@ where
     }
 }
 
+/// The error raised in the sandbox for an operation on a host handle.
+///
+/// The JS bindings never pass host handles in, so this is only a safeguard.
+fn handle_call_unsupported() -> MontyException {
+    MontyException::new(
+        ExcType::NotImplementedError,
+        Some("Host handles are not supported in the JS bindings".to_owned()),
+    )
+}
+
+/// The error raised in the sandbox for an external call whose callback finished.
+///
+/// The JS bindings never call callbacks, so this is only a safeguard.
+fn callback_result_unsupported() -> MontyException {
+    MontyException::new(
+        ExcType::NotImplementedError,
+        Some("Callbacks are not supported in the JS bindings".to_owned()),
+    )
+}
+
 /// Puts mount state back into shared slots, if present.
 fn put_back_mount_state(mount_state: Option<MountState>) {
     if let Some((handler, table)) = mount_state {
@@ -643,7 +643,7 @@ export interface RunMontyAsyncOptions {
   externalFunctions?: Record<string, (...args: unknown[]) => unknown>
   /** Resource limits. */
   limits?: ResourceLimits
-  /** Callback invoked on each print() call. The first argument is the stream name (always "stdout"), the second is the printed text. */
+  /** Callback invoked on each print() call, shown warning and log record. The first argument is the stream name ("stdout" for print output, "stderr" for warnings and log records), the second is the text. */
   printCallback?: (stream: string, text: string) => void
   /** Filesystem mount(s) for the sandbox. */
   mount?: MountDir | MountDir[]
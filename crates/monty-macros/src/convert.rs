@@ -0,0 +1,576 @@
+//! Codegen for `#[derive(IntoMonty)]` and `#[derive(FromMonty)]`.
+//!
+//! Unlike `FromArgs` / `ToArgs`, these derives are for embedders: the output uses
+//! `::monty::...` paths, so it compiles in crates that depend on `monty` but not
+//! inside `monty` itself. `FromMonty` impls lean on the hidden helpers in
+//! `monty::__derive` to keep the generated code small.
+//!
+//! Shapes map as follows:
+//! - named fields → `MontyObject::Dataclass` named after the type, or a `Dict`
+//!   with `#[monty(dict)]`
+//! - one unnamed field → the field itself
+//! - several unnamed fields → a `Tuple`
+//! - unit struct → `None`
+//! - enum → per variant, the tag string (unit), a tuple starting with the tag
+//!   (unnamed fields) or a dataclass named after the variant (named fields)
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, LitInt, LitStr, Type, ext::IdentExt, spanned::Spanned};
+
+pub(crate) fn expand_into(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let shape = Shape::parse(input)?;
+    Ok(shape.render_into())
+}
+
+pub(crate) fn expand_from(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let shape = Shape::parse(input)?;
+    Ok(shape.render_from())
+}
+
+/// A parsed, validated type deriving `IntoMonty` or `FromMonty`.
+struct Shape {
+    ident: Ident,
+    /// Python name of the type: the dataclass or type alias name in stubs.
+    py_name: String,
+    kind: Kind,
+}
+
+enum Kind {
+    /// Named fields, as a dataclass or, with `dict`, a dict.
+    Struct {
+        fields: Vec<NamedField>,
+        dict: bool,
+        frozen: bool,
+    },
+    /// One unnamed field, converted as the field itself.
+    Newtype(Type),
+    /// Several unnamed fields, as a tuple.
+    Tuple(Vec<Type>),
+    /// No fields, as `None`.
+    Unit,
+    Enum(Vec<Variant>),
+}
+
+struct NamedField {
+    ident: Ident,
+    /// Attribute name in Python, `#[monty(rename = "...")]` or the field name.
+    py_name: String,
+    ty: Type,
+}
+
+struct Variant {
+    ident: Ident,
+    /// Tag in Python, `#[monty(rename = "...")]` or the variant name.
+    py_name: String,
+    fields: VariantFields,
+}
+
+enum VariantFields {
+    Unit,
+    Tuple(Vec<Type>),
+    Named(Vec<NamedField>),
+}
+
+/// Options from `#[monty(...)]` attributes.
+#[derive(Default)]
+struct Attrs {
+    rename: Option<String>,
+    dict: bool,
+    frozen: bool,
+}
+
+/// Parses the `#[monty(...)]` attributes of a type (`allow_flags`), field or variant.
+fn parse_attrs(attrs: &[syn::Attribute], allow_flags: bool) -> syn::Result<Attrs> {
+    let mut out = Attrs::default();
+    for attr in attrs {
+        if !attr.path().is_ident("monty") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let value: LitStr = meta.value()?.parse()?;
+                out.rename = Some(value.value());
+                Ok(())
+            } else if allow_flags && meta.path.is_ident("dict") {
+                out.dict = true;
+                Ok(())
+            } else if allow_flags && meta.path.is_ident("frozen") {
+                out.frozen = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown monty attribute"))
+            }
+        })?;
+    }
+    Ok(out)
+}
+
+fn parse_named_fields(fields: &syn::FieldsNamed) -> syn::Result<Vec<NamedField>> {
+    fields
+        .named
+        .iter()
+        .map(|field| {
+            let attrs = parse_attrs(&field.attrs, false)?;
+            let ident = field.ident.clone().expect("named field");
+            Ok(NamedField {
+                py_name: attrs.rename.unwrap_or_else(|| ident.unraw().to_string()),
+                ident,
+                ty: field.ty.clone(),
+            })
+        })
+        .collect()
+}
+
+fn unnamed_types(fields: &syn::FieldsUnnamed) -> syn::Result<Vec<Type>> {
+    for field in &fields.unnamed {
+        parse_attrs(&field.attrs, false)?;
+    }
+    Ok(fields.unnamed.iter().map(|field| field.ty.clone()).collect())
+}
+
+impl Shape {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                input.generics.span(),
+                "IntoMonty and FromMonty can't be derived for generic types",
+            ));
+        }
+        let attrs = parse_attrs(&input.attrs, true)?;
+        let is_named_struct = matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Named(_)));
+        if (attrs.dict || attrs.frozen) && !is_named_struct {
+            return Err(syn::Error::new(
+                input.span(),
+                "`dict` and `frozen` only apply to structs with named fields",
+            ));
+        }
+        if attrs.dict && attrs.frozen {
+            return Err(syn::Error::new(
+                input.span(),
+                "`dict` and `frozen` are mutually exclusive",
+            ));
+        }
+
+        let kind = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(named) => Kind::Struct {
+                    fields: parse_named_fields(named)?,
+                    dict: attrs.dict,
+                    frozen: attrs.frozen,
+                },
+                Fields::Unnamed(unnamed) => {
+                    let mut types = unnamed_types(unnamed)?;
+                    match types.len() {
+                        0 => Kind::Unit,
+                        1 => Kind::Newtype(types.remove(0)),
+                        _ => Kind::Tuple(types),
+                    }
+                }
+                Fields::Unit => Kind::Unit,
+            },
+            Data::Enum(data) => {
+                if data.variants.is_empty() {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "enums without variants have no values to convert",
+                    ));
+                }
+                let variants = data
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let attrs = parse_attrs(&variant.attrs, false)?;
+                        let fields = match &variant.fields {
+                            Fields::Named(named) => VariantFields::Named(parse_named_fields(named)?),
+                            Fields::Unnamed(unnamed) => VariantFields::Tuple(unnamed_types(unnamed)?),
+                            Fields::Unit => VariantFields::Unit,
+                        };
+                        Ok(Variant {
+                            ident: variant.ident.clone(),
+                            py_name: attrs.rename.unwrap_or_else(|| variant.ident.unraw().to_string()),
+                            fields,
+                        })
+                    })
+                    .collect::<syn::Result<_>>()?;
+                Kind::Enum(variants)
+            }
+            Data::Union(_) => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "IntoMonty and FromMonty can't be derived for unions",
+                ));
+            }
+        };
+        Ok(Self {
+            ident: input.ident.clone(),
+            py_name: attrs.rename.unwrap_or_else(|| input.ident.unraw().to_string()),
+            kind,
+        })
+    }
+
+    fn render_into(&self) -> TokenStream {
+        let ident = &self.ident;
+        let trait_path = quote!(::monty::IntoMonty);
+        let (body, type_hint) = match &self.kind {
+            Kind::Struct { fields, dict, frozen } => {
+                let values = fields.iter().map(|field| {
+                    let ident = &field.ident;
+                    quote!(::monty::IntoMonty::into_monty(self.#ident))
+                });
+                let body = if *dict {
+                    dict_object(fields, values)
+                } else {
+                    dataclass_object(&self.py_name, fields, values, *frozen)
+                };
+                (body, self.struct_hint(fields, *dict, *frozen, &trait_path))
+            }
+            Kind::Newtype(ty) => (
+                quote!(::monty::IntoMonty::into_monty(self.0)),
+                quote!(<#ty as ::monty::IntoMonty>::type_hint(stubs)),
+            ),
+            Kind::Tuple(types) => {
+                let indexes = (0..types.len()).map(syn::Index::from);
+                (
+                    quote!(::monty::MontyObject::Tuple(
+                        ::std::vec![#(::monty::IntoMonty::into_monty(self.#indexes)),*]
+                    )),
+                    tuple_hint(None, types, &trait_path),
+                )
+            }
+            Kind::Unit => (
+                quote!(::monty::MontyObject::None),
+                quote!(::std::string::String::from("None")),
+            ),
+            Kind::Enum(variants) => {
+                let arms = variants.iter().map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let tag = &variant.py_name;
+                    match &variant.fields {
+                        VariantFields::Unit => quote! {
+                            Self::#variant_ident => ::monty::MontyObject::String(::std::string::String::from(#tag))
+                        },
+                        VariantFields::Tuple(types) => {
+                            let vars: Vec<Ident> =
+                                (0..types.len()).map(|i| quote::format_ident!("__field{}", i)).collect();
+                            quote! {
+                                Self::#variant_ident(#(#vars),*) => ::monty::MontyObject::Tuple(::std::vec![
+                                    ::monty::MontyObject::String(::std::string::String::from(#tag)),
+                                    #(::monty::IntoMonty::into_monty(#vars)),*
+                                ])
+                            }
+                        }
+                        VariantFields::Named(fields) => {
+                            let idents: Vec<&Ident> = fields.iter().map(|field| &field.ident).collect();
+                            let values = idents
+                                .iter()
+                                .map(|ident| quote!(::monty::IntoMonty::into_monty(#ident)));
+                            let object = dataclass_object(tag, fields, values, false);
+                            quote!(Self::#variant_ident { #(#idents),* } => #object)
+                        }
+                    }
+                });
+                (quote!(match self { #(#arms,)* }), self.enum_hint(variants, &trait_path))
+            }
+        };
+        quote! {
+            #[automatically_derived]
+            impl ::monty::IntoMonty for #ident {
+                fn into_monty(self) -> ::monty::MontyObject {
+                    #body
+                }
+
+                fn type_hint(stubs: &mut ::monty::TypeStubs) -> ::std::string::String {
+                    #type_hint
+                }
+            }
+        }
+    }
+
+    fn render_from(&self) -> TokenStream {
+        let ident = &self.ident;
+        let py_name = &self.py_name;
+        let trait_path = quote!(::monty::FromMonty);
+        let mut optional = quote!();
+        let (body, type_hint) = match &self.kind {
+            Kind::Struct { fields, dict, frozen } => {
+                let inits = fields.iter().map(|field| {
+                    let ident = &field.ident;
+                    let name = &field.py_name;
+                    quote!(#ident: __fields.take(#name)?)
+                });
+                let body = quote! {
+                    let mut __fields = ::monty::__derive::Fields::new(object, #py_name)?;
+                    ::std::result::Result::Ok(Self { #(#inits),* })
+                };
+                (body, self.struct_hint(fields, *dict, *frozen, &trait_path))
+            }
+            Kind::Newtype(ty) => {
+                optional = quote!(const OPTIONAL: bool = <#ty as ::monty::FromMonty>::OPTIONAL;);
+                (
+                    quote!(<#ty as ::monty::FromMonty>::from_monty(object).map(Self)),
+                    quote!(<#ty as ::monty::FromMonty>::type_hint(stubs)),
+                )
+            }
+            Kind::Tuple(types) => {
+                let len = LitInt::new(&types.len().to_string(), ident.span());
+                let vars: Vec<Ident> = (0..types.len()).map(|i| quote::format_ident!("__item{}", i)).collect();
+                let converted = convert_items(&vars, types, 0);
+                let body = quote! {
+                    let [#(#vars),*] = ::monty::__derive::tuple::<#len>(object)?;
+                    ::std::result::Result::Ok(Self(#(#converted),*))
+                };
+                (body, tuple_hint(None, types, &trait_path))
+            }
+            Kind::Unit => (
+                quote! {
+                    match object {
+                        ::monty::MontyObject::None => ::std::result::Result::Ok(Self),
+                        _ => ::std::result::Result::Err(::monty::FromMontyError::wrong_type("None", &object)),
+                    }
+                },
+                quote!(::std::string::String::from("None")),
+            ),
+            Kind::Enum(variants) => {
+                let arms = variants.iter().map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let tag = &variant.py_name;
+                    match &variant.fields {
+                        VariantFields::Unit => quote! {
+                            ::monty::__derive::Tagged::Unit(__tag) if __tag == #tag => ::std::result::Result::Ok(Self::#variant_ident)
+                        },
+                        VariantFields::Tuple(types) => {
+                            let len = LitInt::new(&(types.len() + 1).to_string(), variant_ident.span());
+                            let vars: Vec<Ident> = (0..types.len()).map(|i| quote::format_ident!("__item{}", i)).collect();
+                            let converted = convert_items(&vars, types, 1);
+                            quote! {
+                                ::monty::__derive::Tagged::Tuple(__tag, __items) if __tag == #tag => {
+                                    let [_, #(#vars),*] = ::monty::__derive::tuple_items::<#len>(__items)?;
+                                    ::std::result::Result::Ok(Self::#variant_ident(#(#converted),*))
+                                }
+                            }
+                        }
+                        VariantFields::Named(fields) => {
+                            let inits = fields.iter().map(|field| {
+                                let ident = &field.ident;
+                                let name = &field.py_name;
+                                quote!(#ident: __fields.take(#name)?)
+                            });
+                            quote! {
+                                ::monty::__derive::Tagged::Dataclass(__tag, __object) if __tag == #tag => {
+                                    let mut __fields = ::monty::__derive::Fields::new(__object, #tag)?;
+                                    ::std::result::Result::Ok(Self::#variant_ident { #(#inits),* })
+                                }
+                            }
+                        }
+                    }
+                });
+                let unknown = format!("must be a variant of {py_name}, not '{{}}'");
+                let body = quote! {
+                    match ::monty::__derive::tagged(object) {
+                        #(#arms,)*
+                        ::monty::__derive::Tagged::Unit(__tag)
+                        | ::monty::__derive::Tagged::Tuple(__tag, _)
+                        | ::monty::__derive::Tagged::Dataclass(__tag, _) => ::std::result::Result::Err(
+                            ::monty::FromMontyError::new(::std::format!(#unknown, __tag)),
+                        ),
+                        ::monty::__derive::Tagged::Other(object) => ::std::result::Result::Err(
+                            ::monty::FromMontyError::wrong_type(#py_name, &object),
+                        ),
+                    }
+                };
+                (body, self.enum_hint(variants, &trait_path))
+            }
+        };
+        quote! {
+            #[automatically_derived]
+            impl ::monty::FromMonty for #ident {
+                #optional
+
+                fn from_monty(object: ::monty::MontyObject) -> ::std::result::Result<Self, ::monty::FromMontyError> {
+                    #body
+                }
+
+                fn type_hint(stubs: &mut ::monty::TypeStubs) -> ::std::string::String {
+                    #type_hint
+                }
+            }
+        }
+    }
+
+    /// Renders the `type_hint` body of a struct with named fields, defining its
+    /// dataclass or `TypedDict`.
+    fn struct_hint(&self, fields: &[NamedField], dict: bool, frozen: bool, trait_path: &TokenStream) -> TokenStream {
+        let define = if dict {
+            class_definition(&self.py_name, fields, ClassStyle::TypedDict, trait_path)
+        } else {
+            class_definition(&self.py_name, fields, ClassStyle::Dataclass { frozen }, trait_path)
+        };
+        let py_name = &self.py_name;
+        quote! {
+            #define
+            ::std::string::String::from(#py_name)
+        }
+    }
+
+    /// Renders the `type_hint` body of an enum, defining a type alias for the union
+    /// of its variants and a dataclass for each variant with named fields.
+    fn enum_hint(&self, variants: &[Variant], trait_path: &TokenStream) -> TokenStream {
+        let py_name = &self.py_name;
+        let tags: Vec<String> = variants
+            .iter()
+            .filter(|variant| matches!(variant.fields, VariantFields::Unit))
+            .map(|variant| format!("{:?}", variant.py_name))
+            .collect();
+        let literal = (!tags.is_empty()).then(|| {
+            let literal = format!("Literal[{}]", tags.join(", "));
+            quote! {
+                {
+                    stubs.import("typing", "Literal");
+                    ::std::string::String::from(#literal)
+                },
+            }
+        });
+        let members = variants.iter().filter_map(|variant| match &variant.fields {
+            VariantFields::Unit => None,
+            VariantFields::Tuple(types) => Some(tuple_hint(Some(&variant.py_name), types, trait_path)),
+            VariantFields::Named(fields) => {
+                let define = class_definition(
+                    &variant.py_name,
+                    fields,
+                    ClassStyle::Dataclass { frozen: false },
+                    trait_path,
+                );
+                let name = &variant.py_name;
+                Some(quote! {
+                    {
+                        #define
+                        ::std::string::String::from(#name)
+                    }
+                })
+            }
+        });
+        let prefix = format!("{py_name}: TypeAlias = ");
+        quote! {
+            stubs.define(#py_name, |stubs| {
+                stubs.import("typing", "TypeAlias");
+                let __members: ::std::vec::Vec<::std::string::String> = ::std::vec![#literal #(#members),*];
+                ::std::format!("{}{}", #prefix, __members.join(" | "))
+            });
+            ::std::string::String::from(#py_name)
+        }
+    }
+}
+
+/// How a struct with named fields is declared in stubs.
+#[derive(Clone, Copy)]
+enum ClassStyle {
+    Dataclass { frozen: bool },
+    TypedDict,
+}
+
+/// Renders a statement adding the class `py_name` with `fields` to `stubs`.
+fn class_definition(py_name: &str, fields: &[NamedField], style: ClassStyle, trait_path: &TokenStream) -> TokenStream {
+    let (import, header) = match style {
+        ClassStyle::Dataclass { frozen } => {
+            let decorator = if frozen {
+                "@dataclass(frozen=True)"
+            } else {
+                "@dataclass"
+            };
+            (
+                quote!(stubs.import("dataclasses", "dataclass");),
+                format!("{decorator}\nclass {py_name}:"),
+            )
+        }
+        ClassStyle::TypedDict => (
+            quote!(stubs.import("typing", "TypedDict");),
+            format!("class {py_name}(TypedDict):"),
+        ),
+    };
+    let lines = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let line = format!("\n    {}: {{}}", field.py_name);
+        quote! {
+            __definition.push_str(&::std::format!(#line, <#ty as #trait_path>::type_hint(stubs)));
+        }
+    });
+    let empty = fields.is_empty().then(|| quote!(__definition.push_str("\n    pass");));
+    quote! {
+        stubs.define(#py_name, |stubs| {
+            #import
+            let mut __definition = ::std::string::String::from(#header);
+            #(#lines)*
+            #empty
+            __definition
+        });
+    }
+}
+
+/// Renders an expression for the `tuple[...]` annotation of `types`, after a
+/// `Literal` of the tag for an enum variant.
+fn tuple_hint(tag: Option<&str>, types: &[Type], trait_path: &TokenStream) -> TokenStream {
+    let (import, tag) = match tag {
+        Some(tag) => {
+            let literal = format!("Literal[{tag:?}]");
+            (
+                quote!(stubs.import("typing", "Literal");),
+                quote!(::std::string::String::from(#literal),),
+            )
+        }
+        None => (quote!(), quote!()),
+    };
+    quote! {
+        {
+            #import
+            let __items: ::std::vec::Vec<::std::string::String> =
+                ::std::vec![#tag #(<#types as #trait_path>::type_hint(stubs)),*];
+            ::std::format!("tuple[{}]", __items.join(", "))
+        }
+    }
+}
+
+/// Renders the conversions of tuple items `vars`, the first at index `offset` of the tuple.
+fn convert_items(vars: &[Ident], types: &[Type], offset: usize) -> Vec<TokenStream> {
+    vars.iter()
+        .zip(types)
+        .enumerate()
+        .map(|(index, (var, ty))| {
+            let index = index + offset;
+            quote!(<#ty as ::monty::FromMonty>::from_monty(#var).map_err(|err| err.in_item(#index))?)
+        })
+        .collect()
+}
+
+/// Renders a `MontyObject::Dataclass` expression with the field `values`.
+fn dataclass_object(
+    py_name: &str,
+    fields: &[NamedField],
+    values: impl Iterator<Item = TokenStream>,
+    frozen: bool,
+) -> TokenStream {
+    let names: Vec<&String> = fields.iter().map(|field| &field.py_name).collect();
+    quote! {
+        ::monty::MontyObject::Dataclass {
+            name: ::std::string::String::from(#py_name),
+            type_id: 0,
+            field_names: ::std::vec![#(::std::string::String::from(#names)),*],
+            attrs: ::monty::DictPairs::from(::std::vec![#((
+                ::monty::MontyObject::String(::std::string::String::from(#names)),
+                #values,
+            )),*]),
+            frozen: #frozen,
+        }
+    }
+}
+
+/// Renders a `MontyObject::Dict` expression with `str` keys and the field `values`.
+fn dict_object(fields: &[NamedField], values: impl Iterator<Item = TokenStream>) -> TokenStream {
+    let names = fields.iter().map(|field| &field.py_name);
+    quote! {
+        ::monty::MontyObject::Dict(::monty::DictPairs::from(::std::vec![#((
+            ::monty::MontyObject::String(::std::string::String::from(#names)),
+            #values,
+        )),*]))
+    }
+}
@@ -3,13 +3,18 @@
 //! - `#[derive(FromArgs)]` — `ArgValues` → typed struct (positional/kwarg
 //!   dispatch, defaults, type coercion via `FromValue`, refcount cleanup).
 //! - `#[derive(ToArgs)]` — typed struct → `(Vec<MontyObject>, kwargs)`.
+//! - `#[derive(IntoMonty)]` / `#[derive(FromMonty)]` — typed struct or enum ↔
+//!   `MontyObject`, for embedders' external functions.
 //!
-//! Generated code emits `crate::...` paths and only compiles inside `monty`.
+//! `FromArgs` / `ToArgs` emit `crate::...` paths and only compile inside
+//! `monty`; `IntoMonty` / `FromMonty` emit `::monty::...` paths and only
+//! compile outside it.
 //! See the crate `README.md` for usage and the docstrings on `StructAttrs` /
 //! `FieldKind` in `from_args.rs` for the full attribute surface.
 
 use proc_macro::TokenStream;
 
+mod convert;
 mod from_args;
 mod to_args;
 
@@ -45,3 +50,31 @@ pub fn derive_to_args(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+/// Derive `monty::IntoMonty` — converts a struct or enum into a `MontyObject`
+/// and describes it in `.pyi` stubs. Named fields become a dataclass named
+/// after the type (`#[monty(dict)]` for a dict, `#[monty(frozen)]` for a
+/// frozen dataclass), a single unnamed field is transparent, several become a
+/// tuple, and enum variants become their tag string, a tuple starting with the
+/// tag, or a dataclass named after the variant. `#[monty(rename = "...")]`
+/// renames the type, a field or a variant. Field types must implement
+/// `IntoMonty`.
+#[proc_macro_derive(IntoMonty, attributes(monty))]
+pub fn derive_into_monty(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    convert::expand_into(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `monty::FromMonty` — the inverse of `IntoMonty`, with the same
+/// attributes. Structs with named fields also accept a dict with str keys or
+/// a namedtuple; missing `Option` fields become `None`. Field types must
+/// implement `FromMonty`.
+#[proc_macro_derive(FromMonty, attributes(monty))]
+pub fn derive_from_monty(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    convert::expand_from(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -82,7 +82,7 @@ async def run_monty_async(
     inputs: dict[str, Any] | None = None,
     external_functions: dict[str, Callable[..., Any]] | None = None,
     limits: ResourceLimits | None = None,
-    print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+    print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
     os: AbstractOS | None = None,
 ) -> Any:
     return await monty_runner.run_async(
@@ -101,7 +101,7 @@ async def run_repl_async(
     *,
     inputs: dict[str, Any] | None = None,
     external_functions: dict[str, Callable[..., Any]] | None = None,
-    print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+    print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
     os: AbstractOS | None = None,
 ) -> Any:
     return await repl.feed_run_async(
@@ -126,6 +126,12 @@ class ResourceLimits(TypedDict, total=False):
     max_duration_secs: float
     """Maximum execution time in seconds."""
 
+    max_fuel: int
+    """Maximum fuel: bytecode instructions plus the proportional cost of expensive builtins.
+
+    Unlike `max_duration_secs`, the same code always consumes the same fuel.
+    """
+
     max_memory: int
     """Maximum heap memory in bytes."""
 
@@ -135,6 +141,12 @@ class ResourceLimits(TypedDict, total=False):
     max_recursion_depth: int
     """Maximum function call stack depth (default: 1000)."""
 
+    regex_backtrack_limit: int
+    """Maximum backtracking steps for a single regex match (default: 1,000,000)."""
+
+    max_output_bytes: int
+    """Maximum bytes of output from `print()`, `sys.stdout`/`sys.stderr` writes, warnings and log records."""
+
 
 class ExternalReturnValue(TypedDict):
     """Represents the return value of an external function call."""
@@ -167,7 +179,10 @@ ExcType = Literal[
     'NameError',
     'UnboundLocalError',
     'ValueError',
+    'UnicodeError',
     'UnicodeDecodeError',
+    'UnicodeEncodeError',
+    'UnicodeTranslateError',
     'json.JSONDecodeError',
     'ImportError',
     'ModuleNotFoundError',
@@ -184,6 +199,18 @@ ExcType = Literal[
     'SyntaxError',
     'TimeoutError',
     'TypeError',
+    'Warning',
+    'UserWarning',
+    'DeprecationWarning',
+    'PendingDeprecationWarning',
+    'SyntaxWarning',
+    'RuntimeWarning',
+    'FutureWarning',
+    'ImportWarning',
+    'UnicodeWarning',
+    'BytesWarning',
+    'ResourceWarning',
+    'EncodingWarning',
     're.PatternError',
 ]
 """String names of Python exception types that Monty understands.
@@ -153,7 +153,7 @@ class Monty:
         inputs: dict[str, Any] | None = None,
         limits: ResourceLimits | None = None,
         external_functions: dict[str, Callable[..., Any]] | None = None,
-        print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+        print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
         mount: MountDir | list[MountDir] | None = None,
         os: Callable[[OsFunction, tuple[Any, ...], dict[str, Any]], Any] | None = None,
     ) -> Any:
@@ -186,7 +186,7 @@ class Monty:
         *,
         inputs: dict[str, Any] | None = None,
         limits: ResourceLimits | None = None,
-        print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+        print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
         mount: MountDir | list[MountDir] | None = None,
         os: Callable[[OsFunction, tuple[Any, ...], dict[str, Any]], Any] | None = None,
     ) -> FunctionSnapshot | NameLookupSnapshot | FutureSnapshot | MontyComplete:
@@ -231,7 +231,7 @@ class Monty:
         inputs: dict[str, Any] | None = None,
         limits: ResourceLimits | None = None,
         external_functions: dict[str, Callable[..., Any]] | None = None,
-        print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+        print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
         os: AbstractOS | None = None,
     ) -> Coroutine[Any, Any, Any]:
         """
@@ -378,7 +378,7 @@ class MontyRepl:
         *,
         inputs: dict[str, Any] | None = None,
         external_functions: dict[str, Callable[..., Any]] | None = None,
-        print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+        print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
         mount: MountDir | list[MountDir] | None = None,
         os: Callable[[OsFunction, tuple[Any, ...], dict[str, Any]], Any] | None = None,
         skip_type_check: bool = False,
@@ -410,7 +410,7 @@ class MontyRepl:
         *,
         inputs: dict[str, Any] | None = None,
         external_functions: dict[str, Callable[..., Any]] | None = None,
-        print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+        print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
         os: AbstractOS | None = None,
         skip_type_check: bool = False,
     ) -> Coroutine[Any, Any, Any]:
@@ -444,7 +444,7 @@ class MontyRepl:
         code: str,
         *,
         inputs: dict[str, Any] | None = None,
-        print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+        print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
         mount: MountDir | list[MountDir] | None = None,
         os: Callable[[OsFunction, tuple[Any, ...], dict[str, Any]], Any] | None = None,
         skip_type_check: bool = False,
@@ -937,7 +937,7 @@ class Frame:
 def load_snapshot(
     data: bytes,
     *,
-    print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+    print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
     dataclass_registry: list[type] | None = None,
 ) -> FunctionSnapshot | NameLookupSnapshot | FutureSnapshot:
     """Load a non-REPL snapshot from serialized bytes.
@@ -961,7 +961,7 @@ def load_snapshot(
 def load_repl_snapshot(
     data: bytes,
     *,
-    print_callback: Callable[[Literal['stdout'], str], None] | CollectStreams | CollectString | None = None,
+    print_callback: Callable[[Literal['stdout', 'stderr'], str], None] | CollectStreams | CollectString | None = None,
     dataclass_registry: list[type] | None = None,
 ) -> tuple[FunctionSnapshot | NameLookupSnapshot | FutureSnapshot, MontyRepl]:
     """Load a REPL snapshot from serialized bytes.
@@ -11,8 +11,8 @@
 use std::mem::drop;
 
 use monty::{
-    ExcType, ExtFunctionResult, MontyException, MontyObject, MontyRepl, NameLookupResult, OsFunctionCall, ReplProgress,
-    ReplStartError, ResourceTracker, RunProgress,
+    ExcType, ExtFunctionResult, ModuleLookupResult, MontyException, MontyObject, MontyRepl, NameLookupResult,
+    OsFunctionCall, ReplProgress, ReplStartError, ResourceTracker, RunProgress,
 };
 use pyo3::{
     exceptions::PyRuntimeError,
@@ -33,6 +33,7 @@ use crate::{
         CallResult, ExternalFunctionRegistry, dispatch_method_call_or_coroutine, py_err_to_ext_result,
         py_obj_to_ext_result,
     },
+    monty_cls::{callback_result_unsupported, handle_call_unsupported},
     print_target::PrintTarget,
     repl::{EitherRepl, FromCoreRepl, PyMontyRepl},
 };
@@ -176,6 +177,21 @@ pub(crate) async fn dispatch_loop_run<T: ResourceTracker + Send + 'static>(
                 progress = spawn_resume!(lookup, result, target)
                     .map_err(|e| Python::attach(|py| MontyError::new_err(py, e)))?;
             }
+            RunProgress::ModuleLookup(lookup) => {
+                let target = print_target.clone_handle_detached();
+                progress = spawn_resume!(lookup, ModuleLookupResult::NotFound, target)
+                    .map_err(|e| Python::attach(|py| MontyError::new_err(py, e)))?;
+            }
+            RunProgress::HandleCall(call) => {
+                let target = print_target.clone_handle_detached();
+                progress = spawn_resume!(call, handle_call_unsupported(), target)
+                    .map_err(|e| Python::attach(|py| MontyError::new_err(py, e)))?;
+            }
+            RunProgress::CallbackResult(result) => {
+                let target = print_target.clone_handle_detached();
+                progress = spawn_resume!(result, callback_result_unsupported(), target)
+                    .map_err(|e| Python::attach(|py| MontyError::new_err(py, e)))?;
+            }
             RunProgress::ResolveFutures(state) => {
                 let results = wait_for_futures(&mut join_set, state.pending_call_ids()).await?;
                 let target = print_target.clone_handle_detached();
@@ -278,6 +294,33 @@ where
                     .await?;
                 progress_guard.store(next_progress);
             }
+            ReplProgress::ModuleLookup(lookup) => {
+                let target = print_target.clone_handle_detached();
+                let next_progress =
+                    await_repl_transition(&repl_owner, cleanup_notifier.clone(), target, move |target| {
+                        target.with_writer(|writer| lookup.resume(ModuleLookupResult::NotFound, writer))
+                    })
+                    .await?;
+                progress_guard.store(next_progress);
+            }
+            ReplProgress::HandleCall(call) => {
+                let target = print_target.clone_handle_detached();
+                let next_progress =
+                    await_repl_transition(&repl_owner, cleanup_notifier.clone(), target, move |target| {
+                        target.with_writer(|writer| call.resume(handle_call_unsupported(), writer))
+                    })
+                    .await?;
+                progress_guard.store(next_progress);
+            }
+            ReplProgress::CallbackResult(result) => {
+                let target = print_target.clone_handle_detached();
+                let next_progress =
+                    await_repl_transition(&repl_owner, cleanup_notifier.clone(), target, move |target| {
+                        target.with_writer(|writer| result.resume(callback_result_unsupported(), writer))
+                    })
+                    .await?;
+                progress_guard.store(next_progress);
+            }
             ReplProgress::ResolveFutures(state) => {
                 let pending_call_ids = state.pending_call_ids().to_vec();
                 progress_guard.store(ReplProgress::ResolveFutures(state));
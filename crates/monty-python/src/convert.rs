@@ -15,13 +15,16 @@ use pyo3::{
     prelude::*,
     sync::PyOnceLock,
     types::{
-        PyBool, PyBytes, PyDate, PyDateAccess, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyFloat, PyFrozenSet, PyInt,
-        PyList, PyModule, PySet, PyString, PyTimeAccess, PyTuple, PyType, PyTzInfo, PyTzInfoAccess,
+        PyBool, PyByteArray, PyBytes, PyComplex, PyDate, PyDateAccess, PyDateTime, PyDelta, PyDeltaAccess, PyDict,
+        PyFloat, PyFrozenSet, PyInt, PyList, PyModule, PySet, PyString, PyTimeAccess, PyTuple, PyType, PyTzInfo,
+        PyTzInfoAccess,
     },
 };
 
 use crate::{
-    dataclass::{DcRegistry, dataclass_to_monty, dataclass_to_py, is_dataclass},
+    dataclass::{
+        DcRegistry, dataclass_to_monty, dataclass_to_py, dataclass_type_to_monty, dataclass_type_to_py, is_dataclass,
+    },
     exceptions::{exc_monty_to_py, exc_py_to_monty, exc_to_monty_object},
 };
 
@@ -70,10 +73,17 @@ pub fn py_to_monty(obj: &Bound<'_, PyAny>, dc_registry: &DcRegistry, mut depth:
         }
     } else if let Ok(float) = obj.cast::<PyFloat>() {
         Ok(MontyObject::Float(float.extract()?))
+    } else if let Ok(complex) = obj.cast::<PyComplex>() {
+        Ok(MontyObject::Complex {
+            real: complex.real(),
+            imag: complex.imag(),
+        })
     } else if let Ok(string) = obj.cast::<PyString>() {
         Ok(MontyObject::String(string.extract()?))
     } else if let Ok(bytes) = obj.cast::<PyBytes>() {
         Ok(MontyObject::Bytes(bytes.extract()?))
+    } else if let Ok(bytearray) = obj.cast::<PyByteArray>() {
+        Ok(MontyObject::ByteArray(bytearray.to_vec()))
     } else if let Ok(list) = obj.cast::<PyList>() {
         let items: PyResult<Vec<MontyObject>> =
             list.iter().map(|item| py_to_monty(&item, dc_registry, depth)).collect();
@@ -84,18 +94,7 @@ pub fn py_to_monty(obj: &Bound<'_, PyAny>, dc_registry: &DcRegistry, mut depth:
         if let Ok(fields) = obj.getattr("_fields")
             && let Ok(fields_tuple) = fields.cast::<PyTuple>()
         {
-            let py_type = obj.get_type();
-            // Get the simple class name (e.g., "stat_result")
-            let simple_name = py_type.name()?.to_string();
-            // Get the module (e.g., "os" or "__main__")
-            let module: String = py_type.getattr("__module__")?.extract()?;
-            // Construct full type name: "os.stat_result"
-            // Skip module prefix if it's a Python built-in module
-            let type_name = if module.starts_with('_') || module == "builtins" {
-                simple_name
-            } else {
-                format!("{module}.{simple_name}")
-            };
+            let type_name = namedtuple_type_name(&obj.get_type())?;
             // Extract field names as strings
             let field_names: PyResult<Vec<String>> = fields_tuple.iter().map(|f| f.extract::<String>()).collect();
             // Extract values
@@ -168,8 +167,25 @@ pub fn py_to_monty(obj: &Bound<'_, PyAny>, dc_registry: &DcRegistry, mut depth:
     } else if let Ok(ty) = obj.cast::<PyType>() {
         // A class is callable, so it would otherwise fall into the generic callable
         // branch below. Classes Monty models are preserved as type objects (so they
-        // round-trip and `isinstance` works in the sandbox); any other host class has
-        // no Monty `Type`, so it falls back to the callable representation.
+        // round-trip and `isinstance` works in the sandbox), as are dataclass and
+        // namedtuple classes; any other host class has no Monty `Type`, so it falls
+        // back to the callable representation.
+        if ty.hasattr(intern!(ty.py(), "__dataclass_fields__"))? {
+            dc_registry.insert(ty)?;
+            return dataclass_type_to_monty(ty);
+        }
+        if ty.is_subclass_of::<PyTuple>()?
+            && let Ok(fields) = ty.getattr(intern!(ty.py(), "_fields"))
+            && let Ok(fields_tuple) = fields.cast::<PyTuple>()
+        {
+            return Ok(MontyObject::NamedTupleType {
+                type_name: namedtuple_type_name(ty)?,
+                field_names: fields_tuple
+                    .iter()
+                    .map(|f| f.extract::<String>())
+                    .collect::<PyResult<_>>()?,
+            });
+        }
         match py_type_object_to_monty(ty)? {
             Some(t) => Ok(MontyObject::Type(t)),
             None => Ok(callable_to_monty_function(obj)),
@@ -221,8 +237,11 @@ fn round_trip_type_table(py: Python<'_>) -> PyResult<&'static Vec<(Py<PyAny>, Ty
             Type::Bool,
             Type::Int,
             Type::Float,
+            Type::Complex,
             Type::Str,
             Type::Bytes,
+            Type::ByteArray,
+            Type::MemoryView,
             Type::List,
             Type::Tuple,
             Type::Dict,
@@ -231,6 +250,7 @@ fn round_trip_type_table(py: Python<'_>) -> PyResult<&'static Vec<(Py<PyAny>, Ty
             Type::Range,
             Type::Slice,
             Type::Type,
+            Type::Object,
             Type::Property,
             Type::Date,
             Type::DateTime,
@@ -243,6 +263,18 @@ fn round_trip_type_table(py: Python<'_>) -> PyResult<&'static Vec<(Py<PyAny>, Ty
             Type::BufferedWriter,
             Type::BufferedRandom,
             Type::SpecialForm,
+            Type::GenericAlias,
+            Type::NewType,
+            Type::TypedDict,
+            Type::DataclassField,
+            Type::MissingType,
+            Type::Suppress,
+            Type::NullContext,
+            Type::Closing,
+            Type::RedirectStdout,
+            Type::ExitStack,
+            Type::StringIO,
+            Type::BytesIO,
         ]
         .into_iter()
         .map(|t| Ok((type_object_to_py(py, t)?, t)))
@@ -293,8 +325,10 @@ pub(crate) fn monty_to_py_inner(
         MontyObject::Int(i) => Ok(i.into_pyobject(py)?.clone().into_any().unbind()),
         MontyObject::BigInt(bi) => Ok(bi.into_pyobject(py)?.clone().into_any().unbind()),
         MontyObject::Float(f) => Ok(f.into_pyobject(py)?.clone().into_any().unbind()),
+        MontyObject::Complex { real, imag } => Ok(PyComplex::from_doubles(py, *real, *imag).into_any().unbind()),
         MontyObject::String(s) => Ok(PyString::new(py, s).into_any().unbind()),
         MontyObject::Bytes(b) => Ok(PyBytes::new(py, b).into_any().unbind()),
+        MontyObject::ByteArray(b) => Ok(PyByteArray::new(py, b).into_any().unbind()),
         MontyObject::List(items) => {
             let py_items: PyResult<Vec<Py<PyAny>>> = items
                 .iter()
@@ -315,25 +349,7 @@ pub(crate) fn monty_to_py_inner(
             field_names,
             values,
         } => {
-            // Extract module and simple name from full type_name
-            // e.g., "os.stat_result" -> module="os", simple_name="stat_result"
-            let (module, simple_name) = if let Some(idx) = type_name.rfind('.') {
-                (&type_name[..idx], &type_name[idx + 1..])
-            } else {
-                ("", type_name.as_str())
-            };
-
-            // Create a namedtuple type with the module set for round-trip support
-            // collections.namedtuple(typename, field_names, module=module)
-            let namedtuple_fn = get_namedtuple(py)?;
-            let py_field_names = PyList::new(py, field_names)?;
-            let nt_type = if module.is_empty() {
-                namedtuple_fn.call1((simple_name, py_field_names))?
-            } else {
-                let kwargs = PyDict::new(py);
-                kwargs.set_item("module", module)?;
-                namedtuple_fn.call((simple_name, py_field_names), Some(&kwargs))?
-            };
+            let nt_type = namedtuple_type_to_py(py, type_name, field_names)?;
 
             // Convert values and instantiate using _make() which accepts an iterable
             // note `_make` might start with an underscore, but it's a public documented method
@@ -370,8 +386,8 @@ pub(crate) fn monty_to_py_inner(
             Ok(PyFrozenSet::new(py, &py_items?)?.into_any().unbind())
         }
         // Return the exception instance as a value (not raised)
-        MontyObject::Exception { exc_type, arg } => {
-            let exc = exc_monty_to_py(py, MontyException::new(*exc_type, arg.clone()));
+        MontyObject::Exception { exc_type, args } => {
+            let exc = exc_monty_to_py(py, MontyException::with_args(*exc_type, args.clone()));
             Ok(exc.into_value(py).into_any())
         }
         MontyObject::Date(date) => PyDate::new(py, date.year, date.month, date.day)
@@ -384,6 +400,10 @@ pub(crate) fn monty_to_py_inner(
         MontyObject::TimeZone(timezone) => monty_timezone_to_py(py, timezone),
         // Return the host Python type object the sandbox type maps to.
         MontyObject::Type(t) => type_object_to_py(py, *t),
+        MontyObject::DataclassType { name, type_id, .. } => dataclass_type_to_py(py, name, *type_id, dc_registry),
+        MontyObject::NamedTupleType { type_name, field_names } => {
+            Ok(namedtuple_type_to_py(py, type_name, field_names)?.unbind())
+        }
         MontyObject::BuiltinFunction(f) => import_builtins(py)?.getattr(py, f.to_string()),
         // Dataclass - use registry to reconstruct original type if available
         MontyObject::Dataclass {
@@ -404,6 +424,11 @@ pub(crate) fn monty_to_py_inner(
         // callers can inspect `path`, `mode`, `position`, and `id` directly
         // instead of parsing the repr string.
         MontyObject::FileHandle(handle) => Ok(Py::new(py, PyMontyFileHandle::from_inner(handle.clone()))?.into_any()),
+        // Host handles are never created by the Python bindings, and callbacks only reach
+        // hosts that opt in to them, which the Python bindings don't - convert to their repr
+        MontyObject::HostHandle { .. } | MontyObject::Callback { .. } => {
+            Ok(PyString::new(py, &obj.py_repr()).into_any().unbind())
+        }
         // Output-only types - convert to string representation
         MontyObject::Repr(s) => Ok(PyString::new(py, s).into_any().unbind()),
         MontyObject::Cycle(_, placeholder) => Ok(PyString::new(py, placeholder).into_any().unbind()),
@@ -413,6 +438,44 @@ pub(crate) fn monty_to_py_inner(
     }
 }
 
+/// Builds the full type name of a host namedtuple class, e.g. "os.stat_result".
+fn namedtuple_type_name(py_type: &Bound<'_, PyType>) -> PyResult<String> {
+    // Get the simple class name (e.g., "stat_result")
+    let simple_name = py_type.name()?.to_string();
+    // Get the module (e.g., "os" or "__main__")
+    let module: String = py_type.getattr("__module__")?.extract()?;
+    // Construct full type name: "os.stat_result"
+    // Skip module prefix if it's a Python built-in module
+    if module.starts_with('_') || module == "builtins" {
+        Ok(simple_name)
+    } else {
+        Ok(format!("{module}.{simple_name}"))
+    }
+}
+
+/// Creates a `collections.namedtuple` class for a Monty named tuple type name and fields.
+fn namedtuple_type_to_py<'py>(py: Python<'py>, type_name: &str, field_names: &[String]) -> PyResult<Bound<'py, PyAny>> {
+    // Extract module and simple name from full type_name
+    // e.g., "os.stat_result" -> module="os", simple_name="stat_result"
+    let (module, simple_name) = if let Some(idx) = type_name.rfind('.') {
+        (&type_name[..idx], &type_name[idx + 1..])
+    } else {
+        ("", type_name)
+    };
+
+    // Create a namedtuple type with the module set for round-trip support
+    // collections.namedtuple(typename, field_names, module=module)
+    let namedtuple_fn = get_namedtuple(py)?;
+    let py_field_names = PyList::new(py, field_names)?;
+    if module.is_empty() {
+        namedtuple_fn.call1((simple_name, py_field_names))
+    } else {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("module", module)?;
+        namedtuple_fn.call((simple_name, py_field_names), Some(&kwargs))
+    }
+}
+
 pub fn import_builtins(py: Python<'_>) -> PyResult<&Py<PyModule>> {
     static BUILTINS: PyOnceLock<Py<PyModule>> = PyOnceLock::new();
 
@@ -451,6 +514,18 @@ fn type_object_to_py(py: Python<'_>, t: Type) -> PyResult<Py<PyAny>> {
         Type::BufferedWriter => cached!("io", "BufferedWriter"),
         Type::BufferedRandom => cached!("io", "BufferedRandom"),
         Type::SpecialForm => cached!("typing", "_SpecialForm"),
+        Type::GenericAlias => cached!("types", "GenericAlias"),
+        Type::NewType => cached!("typing", "NewType"),
+        Type::TypedDict => cached!("typing", "_TypedDictMeta"),
+        Type::DataclassField => cached!("dataclasses", "Field"),
+        Type::MissingType => cached!("dataclasses", "_MISSING_TYPE"),
+        Type::Suppress => cached!("contextlib", "suppress"),
+        Type::NullContext => cached!("contextlib", "nullcontext"),
+        Type::Closing => cached!("contextlib", "closing"),
+        Type::RedirectStdout => cached!("contextlib", "redirect_stdout"),
+        Type::ExitStack => cached!("contextlib", "ExitStack"),
+        Type::StringIO => cached!("io", "StringIO"),
+        Type::BytesIO => cached!("io", "BytesIO"),
         // `NoneType` and `ellipsis` aren't `builtins` attributes; take them from
         // the singletons (`type(None)` / `type(...)`).
         Type::NoneType => Ok(py.None().bind(py).get_type().into_any().unbind()),
@@ -3,6 +3,7 @@
 //! This module handles:
 //! - Converting Python dataclass instances to `MontyObject::Dataclass`
 //! - Converting `MontyObject::Dataclass` back to Python via `PyUnknownDataclass`
+//! - Converting dataclass classes to and from `MontyObject::DataclassType`
 //! - `PyUnknownDataclass`: A Python class that mimics dataclass behavior
 
 use std::{
@@ -90,11 +91,54 @@ pub fn dataclass_to_monty(value: &Bound<'_, PyAny>, dc_registry: &DcRegistry, de
     })
 }
 
+/// Converts a Python dataclass class to `MontyObject::DataclassType`.
+///
+/// Uses the same `type_id` and public field names as `dataclass_to_monty`, so
+/// `isinstance(instance, cls)` inside the sandbox matches instances of the class.
+pub fn dataclass_type_to_monty(dc_type: &Bound<'_, PyType>) -> PyResult<MontyObject> {
+    let py = dc_type.py();
+    let name: String = dc_type.getattr(intern!(py, "__name__"))?.extract()?;
+    let type_id = dc_type.as_ptr() as u64;
+
+    let fields_dict = dc_type
+        .getattr(intern!(py, "__dataclass_fields__"))?
+        .cast_into::<PyDict>()?;
+    let field_type_marker = get_field_marker(py)?;
+
+    let mut field_names = Vec::new();
+    for (field_name_obj, field) in fields_dict.iter() {
+        if field.getattr(intern!(py, "_field_type"))?.is(field_type_marker) {
+            let field_name = field_name_obj.cast::<PyString>()?.to_str()?;
+            if !field_name.starts_with('_') {
+                field_names.push(field_name.to_owned());
+            }
+        }
+    }
+
+    Ok(MontyObject::DataclassType {
+        name,
+        type_id,
+        field_names,
+    })
+}
+
+/// Converts a `MontyObject::DataclassType` back to the original class.
+///
+/// Classes missing from the dc_registry have no host equivalent and are returned
+/// as their name, like external functions.
+pub fn dataclass_type_to_py(py: Python<'_>, name: &str, type_id: u64, dc_registry: &DcRegistry) -> PyResult<Py<PyAny>> {
+    match dc_registry.get(py, type_id)? {
+        Some(original_type) => Ok(original_type),
+        None => Ok(PyString::new(py, name).into_any().unbind()),
+    }
+}
+
 /// Converts a `MontyObject::Dataclass` to a Python object.
 ///
 /// If the `type_id` is found in the dc_registry, creates an instance of the original
-/// Python dataclass type (so `isinstance(result, OriginalClass)` works).
-/// Otherwise, falls back to creating a `PyUnknownDataclass`.
+/// Python dataclass type (so `isinstance(result, OriginalClass)` works). Instances of
+/// classes declared inside the sandbox have a `type_id` of 0 and are looked up by
+/// class name instead. Otherwise, falls back to creating a `PyUnknownDataclass`.
 ///
 /// `depth` is the caller's current recursion depth; it is forwarded to
 /// `monty_to_py_inner` so nested dataclass fields continue to respect the
@@ -110,8 +154,14 @@ pub fn dataclass_to_py(
     dc_registry: &DcRegistry,
     depth: u8,
 ) -> PyResult<Py<PyAny>> {
-    // Try to use the original type from the dc_registry (keyed by type_id)
-    if let Some(original_type_py) = dc_registry.get(py, type_id)? {
+    // Try to use the original type from the dc_registry (keyed by type_id, or by
+    // name for sandbox-created instances)
+    let original_type_py = if type_id == 0 {
+        dc_registry.get_by_name(py, name)?
+    } else {
+        dc_registry.get(py, type_id)?
+    };
+    if let Some(original_type_py) = original_type_py {
         let original_type = original_type_py.bind(py).cast::<PyType>()?;
         // Build kwargs dict from field names and values
         let kwargs = PyDict::new(py);
@@ -143,8 +193,8 @@ pub fn dataclass_to_py(
     }
 }
 
-/// Maps Python dataclass type identity (pointer address as `u64`) to the original
-/// Python type object (`Py<PyAny>`).
+/// Maps Python dataclass type identity (pointer address as `u64`) and class name to
+/// the original Python type object (`Py<PyAny>`).
 ///
 /// This registry enables round-trip reconstruction of dataclass types: when a
 /// dataclass passes through Monty, its type is stored here so that on output,
@@ -198,16 +248,30 @@ impl DcRegistry {
     /// type object, matching what `dataclass_to_monty` stores as `type_id` in
     /// `MontyObject::Dataclass`. This allows `dataclass_to_py` to look up the original
     /// Python class when reconstructing output values.
+    ///
+    /// The type is also registered under its `__name__`, so instances of a same-named
+    /// class declared inside the sandbox convert back to it. Pointer keys are ints, so
+    /// the two kinds of key can't collide.
     pub fn insert<T>(&self, obj: &Bound<'_, T>) -> PyResult<()> {
         let py = obj.py();
         let type_id = obj.as_ptr() as u64;
-        self.registry.bind(py).set_item(type_id, obj.as_any())
+        let registry = self.registry.bind(py);
+        registry.set_item(type_id, obj.as_any())?;
+        if let Ok(name) = obj.as_any().getattr(intern!(py, "__name__")) {
+            registry.set_item(name, obj.as_any())?;
+        }
+        Ok(())
     }
 
     /// Looks up an original Python type by its pointer identity.
     pub fn get(&self, py: Python<'_>, type_id: u64) -> PyResult<Option<Py<PyAny>>> {
         Ok(self.registry.bind(py).get_item(type_id)?.map(Bound::unbind))
     }
+
+    /// Looks up an original Python type by its class name.
+    pub fn get_by_name(&self, py: Python<'_>, name: &str) -> PyResult<Option<Py<PyAny>>> {
+        Ok(self.registry.bind(py).get_item(name)?.map(Bound::unbind))
+    }
 }
 
 /// Python class that mimics dataclass behavior for `MontyObject::Dataclass`.
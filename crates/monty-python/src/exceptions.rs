@@ -454,6 +454,12 @@ pub fn exc_monty_to_py(py: Python<'_>, exc: MontyException) -> PyErr {
         ExcType::TypeError => exceptions::PyTypeError::new_err(msg),
         ExcType::ValueError => exceptions::PyValueError::new_err(msg),
         ExcType::UnicodeDecodeError => exceptions::PyUnicodeDecodeError::new_err(msg),
+        // `UnicodeEncodeError` / `UnicodeTranslateError` can't be built from a
+        // message alone (their constructors take the codec details), so fall back
+        // to their `UnicodeError` parent.
+        ExcType::UnicodeError | ExcType::UnicodeEncodeError | ExcType::UnicodeTranslateError => {
+            exceptions::PyUnicodeError::new_err(msg)
+        }
         ExcType::JsonDecodeError => {
             if let Ok(json_decode_error) = get_json_decode_error(py)
                 && let Ok(exc_instance) = json_decode_error.call1((PyString::new(py, &msg),))
@@ -463,6 +469,18 @@ pub fn exc_monty_to_py(py: Python<'_>, exc: MontyException) -> PyErr {
                 exceptions::PyValueError::new_err(msg)
             }
         }
+        ExcType::Warning => exceptions::PyWarning::new_err(msg),
+        ExcType::UserWarning => exceptions::PyUserWarning::new_err(msg),
+        ExcType::DeprecationWarning => exceptions::PyDeprecationWarning::new_err(msg),
+        ExcType::PendingDeprecationWarning => exceptions::PyPendingDeprecationWarning::new_err(msg),
+        ExcType::SyntaxWarning => exceptions::PySyntaxWarning::new_err(msg),
+        ExcType::RuntimeWarning => exceptions::PyRuntimeWarning::new_err(msg),
+        ExcType::FutureWarning => exceptions::PyFutureWarning::new_err(msg),
+        ExcType::ImportWarning => exceptions::PyImportWarning::new_err(msg),
+        ExcType::UnicodeWarning => exceptions::PyUnicodeWarning::new_err(msg),
+        ExcType::BytesWarning => exceptions::PyBytesWarning::new_err(msg),
+        ExcType::ResourceWarning => exceptions::PyResourceWarning::new_err(msg),
+        ExcType::EncodingWarning => exceptions::PyEncodingWarning::new_err(msg),
         ExcType::ImportError => exceptions::PyImportError::new_err(msg),
         ExcType::ModuleNotFoundError => exceptions::PyModuleNotFoundError::new_err(msg),
         ExcType::OSError => exceptions::PyOSError::new_err(msg),
@@ -507,9 +525,14 @@ pub fn exc_py_to_monty(py: Python<'_>, py_err: &PyErr) -> MontyException {
 /// Converts a Python exception to Monty's `MontyObject::Exception`.
 pub fn exc_to_monty_object(exc: &Bound<'_, exceptions::PyBaseException>) -> ::monty::MontyObject {
     let exc_type = py_err_to_exc_type(exc);
-    let arg = exc.str().ok().map(|s| s.to_string_lossy().into_owned());
-
-    ::monty::MontyObject::Exception { exc_type, arg }
+    let args = exc
+        .str()
+        .ok()
+        .map(|s| ::monty::MontyObject::String(s.to_string_lossy().into_owned()))
+        .into_iter()
+        .collect();
+
+    ::monty::MontyObject::Exception { exc_type, args }
 }
 
 /// Maps a Python exception type to Monty's `ExcType` enum.
@@ -522,12 +545,20 @@ fn py_err_to_exc_type(exc: &Bound<'_, exceptions::PyBaseException>) -> ExcType {
         // put the most commonly used exceptions first
         if exceptions::PyTypeError::type_check(exc) {
             ExcType::TypeError
-        // ValueError hierarchy (check UnicodeDecodeError first as it's a subclass)
+        // ValueError hierarchy (check the UnicodeError subclasses first)
         } else if exceptions::PyValueError::type_check(exc) {
             if is_json_decode_error(exc) {
                 ExcType::JsonDecodeError
-            } else if exceptions::PyUnicodeDecodeError::type_check(exc) {
-                ExcType::UnicodeDecodeError
+            } else if exceptions::PyUnicodeError::type_check(exc) {
+                if exceptions::PyUnicodeDecodeError::type_check(exc) {
+                    ExcType::UnicodeDecodeError
+                } else if exceptions::PyUnicodeEncodeError::type_check(exc) {
+                    ExcType::UnicodeEncodeError
+                } else if exceptions::PyUnicodeTranslateError::type_check(exc) {
+                    ExcType::UnicodeTranslateError
+                } else {
+                    ExcType::UnicodeError
+                }
             } else if is_unsupported_operation(exc) {
                 // `io.UnsupportedOperation` inherits from both `OSError` and `ValueError`
                 ExcType::UnsupportedOperation
@@ -594,6 +625,33 @@ fn py_err_to_exc_type(exc: &Bound<'_, exceptions::PyBaseException>) -> ExcType {
             } else {
                 ExcType::OSError
             }
+        // Warning hierarchy
+        } else if exceptions::PyWarning::type_check(exc) {
+            if exceptions::PyUserWarning::type_check(exc) {
+                ExcType::UserWarning
+            } else if exceptions::PyDeprecationWarning::type_check(exc) {
+                ExcType::DeprecationWarning
+            } else if exceptions::PyPendingDeprecationWarning::type_check(exc) {
+                ExcType::PendingDeprecationWarning
+            } else if exceptions::PySyntaxWarning::type_check(exc) {
+                ExcType::SyntaxWarning
+            } else if exceptions::PyRuntimeWarning::type_check(exc) {
+                ExcType::RuntimeWarning
+            } else if exceptions::PyFutureWarning::type_check(exc) {
+                ExcType::FutureWarning
+            } else if exceptions::PyImportWarning::type_check(exc) {
+                ExcType::ImportWarning
+            } else if exceptions::PyUnicodeWarning::type_check(exc) {
+                ExcType::UnicodeWarning
+            } else if exceptions::PyBytesWarning::type_check(exc) {
+                ExcType::BytesWarning
+            } else if exceptions::PyResourceWarning::type_check(exc) {
+                ExcType::ResourceWarning
+            } else if exceptions::PyEncodingWarning::type_check(exc) {
+                ExcType::EncodingWarning
+            } else {
+                ExcType::Warning
+            }
         // other standalone exception types
         } else if exceptions::PyTimeoutError::type_check(exc) {
             ExcType::TimeoutError
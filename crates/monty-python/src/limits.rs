@@ -24,9 +24,12 @@ pub(crate) type CancellationFlag = Arc<AtomicBool>;
 /// The dict should have the following optional keys:
 /// - `max_allocations`: Maximum number of heap allocations allowed (int)
 /// - `max_duration_secs`: Maximum execution time in seconds (float)
+/// - `max_fuel`: Maximum fuel, a deterministic count of instructions and builtin work (int)
 /// - `max_memory`: Maximum heap memory in bytes (int)
 /// - `gc_interval`: Run garbage collection every N allocations (int)
 /// - `max_recursion_depth`: Maximum function call stack depth (int, default: 1000)
+/// - `regex_backtrack_limit`: Maximum backtracking steps for a single regex match (int)
+/// - `max_output_bytes`: Maximum bytes of print/stream/warning/log output (int)
 ///
 /// If a key is missing or set to `None`, that limit is not applied
 /// (except `max_recursion_depth` which defaults to 1000).
@@ -36,8 +39,11 @@ pub(crate) type CancellationFlag = Arc<AtomicBool>;
 pub fn extract_limits(dict: &Bound<'_, PyDict>) -> PyResult<monty::ResourceLimits> {
     let max_allocations = extract_optional_usize(dict, "max_allocations")?;
     let max_duration_secs = extract_optional_f64(dict, "max_duration_secs")?;
+    let max_fuel = extract_optional_usize(dict, "max_fuel")?;
     let max_memory = extract_optional_usize(dict, "max_memory")?;
     let gc_interval = extract_optional_usize(dict, "gc_interval")?;
+    let regex_backtrack_limit = extract_optional_usize(dict, "regex_backtrack_limit")?;
+    let max_output_bytes = extract_optional_usize(dict, "max_output_bytes")?;
     let max_recursion_depth =
         extract_optional_usize(dict, "max_recursion_depth")?.or(Some(DEFAULT_MAX_RECURSION_DEPTH));
 
@@ -50,12 +56,21 @@ pub fn extract_limits(dict: &Bound<'_, PyDict>) -> PyResult<monty::ResourceLimit
         limits = limits
             .max_duration(Duration::try_from_secs_f64(secs).map_err(|err| PyValueError::new_err(err.to_string()))?);
     }
+    if let Some(max) = max_fuel {
+        limits = limits.max_fuel(max);
+    }
     if let Some(max) = max_memory {
         limits = limits.max_memory(max);
     }
     if let Some(interval) = gc_interval {
         limits = limits.gc_interval(interval);
     }
+    if let Some(limit) = regex_backtrack_limit {
+        limits = limits.regex_backtrack_limit(limit);
+    }
+    if let Some(max) = max_output_bytes {
+        limits = limits.max_output_bytes(max);
+    }
 
     Ok(limits)
 }
@@ -218,6 +233,10 @@ impl<T: ResourceTracker> ResourceTracker for PySignalTracker<T> {
         self.check_python_signals()
     }
 
+    fn consume_fuel(&self, amount: usize) -> Result<(), ResourceError> {
+        self.inner.consume_fuel(amount)
+    }
+
     fn check_recursion_depth(&self, current_depth: usize) -> Result<(), ResourceError> {
         self.inner.check_recursion_depth(current_depth)
     }
@@ -234,6 +253,18 @@ impl<T: ResourceTracker> ResourceTracker for PySignalTracker<T> {
         self.inner.gc_interval()
     }
 
+    fn regex_backtrack_limit(&self) -> Option<usize> {
+        self.inner.regex_backtrack_limit()
+    }
+
+    fn on_output(&self, bytes: usize) -> Result<(), ResourceError> {
+        self.inner.on_output(bytes)
+    }
+
+    fn check_interrupt(&self) -> Result<(), ResourceError> {
+        self.inner.check_interrupt()
+    }
+
     #[cfg(feature = "test-hooks")]
     fn lower_recursion_limit(&self, new_limit: usize) -> Result<(), Option<usize>> {
         self.inner.lower_recursion_limit(new_limit)
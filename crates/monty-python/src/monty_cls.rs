@@ -7,8 +7,9 @@ use std::{
 // Use `::monty` to refer to the external crate (not the pymodule)
 use ::monty::{
     ExcType, ExtFunctionResult, FunctionCall, JsonMontyArray, JsonMontyObject, JsonMontyPairs, LimitedTracker,
-    MontyException, MontyObject, MontyRun, NameLookupResult, NoLimitTracker, OsCall, ReplFunctionCall, ReplNameLookup,
-    ReplOsCall, ReplProgress, ReplResolveFutures, ReplStartError, ResolveFutures, ResourceTracker, RunProgress,
+    ModuleLookupResult, MontyException, MontyObject, MontyRun, NameLookupResult, NoLimitTracker, OsCall,
+    ReplFunctionCall, ReplNameLookup, ReplOsCall, ReplProgress, ReplResolveFutures, ReplStartError, ResolveFutures,
+    ResourceTracker, RunProgress,
 };
 use monty::{NameLookup, fs::MountTable};
 use pyo3::{
@@ -622,6 +623,37 @@ impl PyMonty {
                         }
                     };
                 }
+                RunProgress::ModuleLookup(lookup) => {
+                    let result = ModuleLookupResult::NotFound;
+                    progress = match py.detach(|| print_target.with_writer(|writer| lookup.resume(result, writer))) {
+                        Ok(p) => p,
+                        Err(e) => {
+                            put_back(mount_table);
+                            return Err(to_err(py, e));
+                        }
+                    };
+                }
+                RunProgress::HandleCall(call) => {
+                    let result = handle_call_unsupported();
+                    progress = match py.detach(|| print_target.with_writer(|writer| call.resume(result, writer))) {
+                        Ok(p) => p,
+                        Err(e) => {
+                            put_back(mount_table);
+                            return Err(to_err(py, e));
+                        }
+                    };
+                }
+                RunProgress::CallbackResult(result) => {
+                    let ext_result = callback_result_unsupported();
+                    progress = match py.detach(|| print_target.with_writer(|writer| result.resume(ext_result, writer)))
+                    {
+                        Ok(p) => p,
+                        Err(e) => {
+                            put_back(mount_table);
+                            return Err(to_err(py, e));
+                        }
+                    };
+                }
                 RunProgress::ResolveFutures(_) => {
                     put_back(mount_table);
                     return Err(PyRuntimeError::new_err("async futures not supported with `Monty.run`"));
@@ -760,6 +792,27 @@ where
         RunProgress::NameLookup(lookup) => {
             PyNameLookupSnapshot::new_py_any(py, lookup, script_name, print_callback, dc_registry)
         }
+        RunProgress::ModuleLookup(lookup) => {
+            // No host modules in the Python bindings — let it raise ModuleNotFoundError
+            let progress = print_callback
+                .with_writer(|w| lookup.resume(ModuleLookupResult::NotFound, w))
+                .map_err(|e| MontyError::new_err(py, e))?;
+            run_progress_to_py(py, progress, script_name, print_callback, dc_registry)
+        }
+        RunProgress::HandleCall(call) => {
+            // No host handles in the Python bindings — let it raise NotImplementedError
+            let progress = print_callback
+                .with_writer(|w| call.resume(handle_call_unsupported(), w))
+                .map_err(|e| MontyError::new_err(py, e))?;
+            run_progress_to_py(py, progress, script_name, print_callback, dc_registry)
+        }
+        RunProgress::CallbackResult(result) => {
+            // No callbacks in the Python bindings — let it raise NotImplementedError
+            let progress = print_callback
+                .with_writer(|w| result.resume(callback_result_unsupported(), w))
+                .map_err(|e| MontyError::new_err(py, e))?;
+            run_progress_to_py(py, progress, script_name, print_callback, dc_registry)
+        }
     }
 }
 
@@ -804,6 +857,27 @@ where
                 variable_name,
             )
         }
+        ReplProgress::ModuleLookup(lookup) => {
+            // No host modules in the Python bindings — let it raise ModuleNotFoundError
+            let progress = print_callback
+                .with_writer(|w| lookup.resume(ModuleLookupResult::NotFound, w))
+                .map_err(|e| restore_repl_from_repl_start_error(py, &repl_owner, *e))?;
+            repl_progress_to_py(py, progress, script_name, print_callback, dc_registry, repl_owner)
+        }
+        ReplProgress::HandleCall(call) => {
+            // No host handles in the Python bindings — let it raise NotImplementedError
+            let progress = print_callback
+                .with_writer(|w| call.resume(handle_call_unsupported(), w))
+                .map_err(|e| restore_repl_from_repl_start_error(py, &repl_owner, *e))?;
+            repl_progress_to_py(py, progress, script_name, print_callback, dc_registry, repl_owner)
+        }
+        ReplProgress::CallbackResult(result) => {
+            // No callbacks in the Python bindings — let it raise NotImplementedError
+            let progress = print_callback
+                .with_writer(|w| result.resume(callback_result_unsupported(), w))
+                .map_err(|e| restore_repl_from_repl_start_error(py, &repl_owner, *e))?;
+            repl_progress_to_py(py, progress, script_name, print_callback, dc_registry, repl_owner)
+        }
         ReplProgress::ResolveFutures(state) => {
             PyFutureSnapshot::repl_resolve_futures(py, state, script_name, print_callback, dc_registry, repl_owner)
         }
@@ -2202,3 +2276,23 @@ pub(crate) fn call_os_callback_parts(
         Err(err) => Ok(exc_py_to_monty(py, &err).into()),
     }
 }
+
+/// The error raised in the sandbox for an operation on a host handle.
+///
+/// The Python bindings never pass host handles in, so this is only a safeguard.
+pub(crate) fn handle_call_unsupported() -> MontyException {
+    MontyException::new(
+        ExcType::NotImplementedError,
+        Some("Host handles are not supported in the Python bindings".to_owned()),
+    )
+}
+
+/// The error raised in the sandbox for an external call whose callback finished.
+///
+/// The Python bindings never call callbacks, so this is only a safeguard.
+pub(crate) fn callback_result_unsupported() -> MontyException {
+    MontyException::new(
+        ExcType::NotImplementedError,
+        Some("Callbacks are not supported in the Python bindings".to_owned()),
+    )
+}
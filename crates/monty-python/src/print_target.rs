@@ -20,7 +20,7 @@ use std::{
     sync::{Arc, Mutex, MutexGuard, PoisonError},
 };
 
-use monty::{MontyException, PrintStream, PrintWriter, PrintWriterCallback};
+use monty::{MontyException, MontyLogRecord, MontyWarning, PrintStream, PrintWriter, PrintWriterCallback};
 use pyo3::{
     PyRef,
     exceptions::PyTypeError,
@@ -328,4 +328,32 @@ impl PrintWriterCallback for CallbackStringPrint<'_> {
         })
         .map_err(|e| Python::attach(|py| exc_py_to_monty(py, &e)))
     }
+
+    fn write(&mut self, stream: PrintStream, output: Cow<'_, str>) -> Result<(), MontyException> {
+        let stream = match stream {
+            PrintStream::Stdout => "stdout",
+            PrintStream::Stderr => "stderr",
+        };
+        Python::attach(|py| {
+            self.0.bind(py).call1((stream, output.as_ref()))?;
+            Ok::<_, PyErr>(())
+        })
+        .map_err(|e| Python::attach(|py| exc_py_to_monty(py, &e)))
+    }
+
+    fn warn(&mut self, warning: &MontyWarning) -> Result<(), MontyException> {
+        Python::attach(|py| {
+            self.0.bind(py).call1(("stderr", format!("{warning}\n")))?;
+            Ok::<_, PyErr>(())
+        })
+        .map_err(|e| Python::attach(|py| exc_py_to_monty(py, &e)))
+    }
+
+    fn log(&mut self, record: &MontyLogRecord) -> Result<(), MontyException> {
+        Python::attach(|py| {
+            self.0.bind(py).call1(("stderr", format!("{record}\n")))?;
+            Ok::<_, PyErr>(())
+        })
+        .map_err(|e| Python::attach(|py| exc_py_to_monty(py, &e)))
+    }
 }
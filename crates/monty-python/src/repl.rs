@@ -5,8 +5,8 @@ use std::{
 
 // Use `::monty` to refer to the external crate (not the pymodule)
 use ::monty::{
-    ExtFunctionResult, LimitedTracker, MontyObject, MontyRepl as CoreMontyRepl, NameLookupResult, NoLimitTracker,
-    ReplProgress, ReplStartError, ResourceTracker,
+    ExtFunctionResult, LimitedTracker, ModuleLookupResult, MontyObject, MontyRepl as CoreMontyRepl, NameLookupResult,
+    NoLimitTracker, ReplProgress, ReplStartError, ResourceTracker,
 };
 use monty::fs::MountTable;
 use pyo3::{
@@ -26,7 +26,7 @@ use crate::{
     exceptions::{MontyError, exc_py_to_monty},
     external::{ExternalFunctionRegistry, dispatch_method_call},
     limits::{CancellationFlag, FutureCancellationGuard, PySignalTracker, extract_limits},
-    monty_cls::{EitherProgress, call_os_callback_parts},
+    monty_cls::{EitherProgress, call_os_callback_parts, callback_result_unsupported, handle_call_unsupported},
     mount::OsHandler,
     print_target::PrintTarget,
 };
@@ -934,6 +934,27 @@ impl PyMontyRepl {
                         Err(e) => restore_err!(e),
                     };
                 }
+                ReplProgress::ModuleLookup(lookup) => {
+                    let result = ModuleLookupResult::NotFound;
+                    progress = match py.detach(|| print_target.with_writer(|w| lookup.resume(result, w))) {
+                        Ok(p) => p,
+                        Err(e) => restore_err!(e),
+                    };
+                }
+                ReplProgress::HandleCall(call) => {
+                    let result = handle_call_unsupported();
+                    progress = match py.detach(|| print_target.with_writer(|w| call.resume(result, w))) {
+                        Ok(p) => p,
+                        Err(e) => restore_err!(e),
+                    };
+                }
+                ReplProgress::CallbackResult(callback) => {
+                    let result = callback_result_unsupported();
+                    progress = match py.detach(|| print_target.with_writer(|w| callback.resume(result, w))) {
+                        Ok(p) => p,
+                        Err(e) => restore_err!(e),
+                    };
+                }
                 ReplProgress::OsCall(call) => {
                     // `handle_repl_os_call` can fail during Python⇄Monty conversion of
                     // args/results. The OS call still owns the REPL handle — extract
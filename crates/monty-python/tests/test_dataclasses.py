@@ -167,20 +167,15 @@ def test_dataclass_empty():
     assert repr(result) == snapshot('test_dataclass_empty.<locals>.Empty()')
 
 
-@pytest.mark.xfail(reason='We should extend the dataclass registry to cover all types, then test it is enforced')
-def test_dataclass_type_raises():
-    """Dataclass type (not instance) should raise TypeError."""
+def test_dataclass_type_input():
+    """A dataclass type (not instance) is passed in as a class and returned unchanged."""
 
     @dataclass
     class MyClass:
         value: int
 
     m = pydantic_monty.Monty('x', inputs=['x'])
-    m.register_dataclass(MyClass)
-    with pytest.raises(TypeError) as exc_info:
-        m.run(inputs={'x': MyClass})
-
-    assert str(exc_info.value) == snapshot('Cannot convert builtins.type to Monty value')
+    assert m.run(inputs={'x': MyClass}) is MyClass
 
 
 # === Field access ===
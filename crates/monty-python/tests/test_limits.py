@@ -97,6 +97,26 @@ len(result)
     assert isinstance(exc_info.value.exception(), MemoryError)
 
 
+def test_fuel_limit():
+    m = pydantic_monty.Monty('while True:\n    pass')
+    limits = pydantic_monty.ResourceLimits(max_fuel=1000)
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        m.run(limits=limits)
+    assert isinstance(exc_info.value.exception(), TimeoutError)
+    assert str(exc_info.value.exception()) == snapshot('fuel limit exceeded: 1001 > 1000')
+
+
+def test_output_limit():
+    m = pydantic_monty.Monty("while True:\n    print('x' * 100)")
+    output: list[str] = []
+    limits = pydantic_monty.ResourceLimits(max_output_bytes=1000)
+    with pytest.raises(pydantic_monty.MontyRuntimeError) as exc_info:
+        m.run(limits=limits, print_callback=lambda _stream, text: output.append(text))
+    assert isinstance(exc_info.value.exception(), MemoryError)
+    assert str(exc_info.value.exception()) == snapshot('output limit exceeded: 1009 bytes > 1000 bytes')
+    assert len(''.join(output)) == 909
+
+
 def test_limits_with_inputs():
     m = pydantic_monty.Monty('x * 2', inputs=['x'])
     limits = pydantic_monty.ResourceLimits(max_duration_secs=5.0)
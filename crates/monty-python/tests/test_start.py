@@ -269,7 +269,7 @@ def test_complete_output_json_dataclass():
 
 def test_complete_output_json_namedtuple():
     # `sys.version_info` is the most convenient namedtuple to get out of
-    # Monty (no class definitions in the parser), and exercises both the
+    # Monty (only dataclass definitions are parsed), and exercises both the
     # fields-as-object body and the `name` sibling key.
     m = pydantic_monty.Monty('import sys; sys.version_info')
     result = m.start()
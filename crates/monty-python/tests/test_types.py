@@ -1,7 +1,9 @@
+import collections
 import datetime
 import pathlib
 import re
 import zoneinfo
+from dataclasses import dataclass
 
 import pytest
 from inline_snapshot import snapshot
@@ -47,6 +49,13 @@ def test_float():
     assert m.run(inputs={'x': 0.0}) == snapshot(0.0)
 
 
+def test_complex():
+    m = pydantic_monty.Monty('x * 2j', inputs=['x'])
+    result = m.run(inputs={'x': 1 + 2j})
+    assert result == snapshot(-4 + 2j)
+    assert type(result) is complex
+
+
 def test_string():
     m = pydantic_monty.Monty('x', inputs=['x'])
     assert m.run(inputs={'x': 'hello'}) == snapshot('hello')
@@ -210,6 +219,45 @@ def test_type_object_input_isinstance():
     assert m.run(inputs={'t': int}) == snapshot((True, False))
 
 
+def test_dataclass_type_input_isinstance():
+    """A host dataclass class passed in matches its instances inside the sandbox,
+    and `type()` of an instance compares equal to it."""
+
+    @dataclass
+    class Point:
+        x: int
+        y: int
+
+    @dataclass
+    class Other:
+        x: int
+
+    code = """
+(
+    isinstance(p, Point),
+    isinstance(o, Point),
+    isinstance(p, (int, Point)),
+    type(p) == Point,
+    repr(type(p)),
+    issubclass(type(o), Other),
+)
+"""
+    m = pydantic_monty.Monty(code, inputs=['p', 'o', 'Point', 'Other'])
+    result = m.run(inputs={'p': Point(1, 2), 'o': Other(3), 'Point': Point, 'Other': Other})
+    assert result == snapshot((True, False, True, True, "<class 'Point'>", True))
+
+
+def test_namedtuple_type_input_isinstance():
+    """A host namedtuple class passed in matches namedtuples with the same name and fields."""
+    Pair = collections.namedtuple('Pair', ['a', 'b'])
+    Triple = collections.namedtuple('Triple', ['a', 'b', 'c'])
+
+    code = '(isinstance(p, Pair), isinstance(p, Triple), isinstance(p, (Triple, Pair)), type(p) == Pair)'
+    m = pydantic_monty.Monty(code, inputs=['p', 'Pair', 'Triple'])
+    result = m.run(inputs={'p': Pair(1, 2), 'Pair': Pair, 'Triple': Triple})
+    assert result == snapshot((True, False, True, True))
+
+
 def test_unmodeled_class_input_becomes_callable():
     """A host class Monty has no `Type` for still degrades to a callable
     function at the boundary (unchanged behavior)."""
@@ -2,4 +2,4 @@ mod db;
 mod pool;
 mod type_check;
 
-pub use crate::type_check::{SourceFile, TypeCheckingDiagnostics, type_check};
+pub use crate::type_check::{SourceFile, TypeCheckingDiagnostics, type_check, type_check_with_modules};
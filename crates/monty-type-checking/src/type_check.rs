@@ -48,6 +48,28 @@ impl<'a> SourceFile<'a> {
 pub fn type_check(
     python_source: &SourceFile<'_>,
     stubs_file: Option<&SourceFile<'_>>,
+) -> Result<Option<TypeCheckingDiagnostics>, String> {
+    type_check_with_modules(python_source, stubs_file, &[])
+}
+
+/// Type check some python source code that imports host-defined modules.
+///
+/// Like [`type_check`], but also writes a stub file for each host module so imports
+/// of it resolve. Each path is relative to the source root and follows the usual
+/// layout: `mytools.pyi` for a module, `mytools/__init__.pyi` and
+/// `mytools/config.pyi` for a package with a submodule.
+///
+/// # Arguments
+/// * `python_source` - The python source code to type check.
+/// * `stubs_file` - Optional stubs file to use for type checking.
+/// * `module_stubs` - Stub files for the modules the code can import.
+///
+/// # Returns
+/// Same as [`type_check`].
+pub fn type_check_with_modules(
+    python_source: &SourceFile<'_>,
+    stubs_file: Option<&SourceFile<'_>>,
+    module_stubs: &[SourceFile<'_>],
 ) -> Result<Option<TypeCheckingDiagnostics>, String> {
     // Check out a pre-configured db from the global pool. The `Drop` impl on
     // `PooledMemoryDb` scrubs every file (and now also every parent directory) we
@@ -60,6 +82,10 @@ pub fn type_check(
     let main_path = src_root.join(python_source.path);
     let main_source = python_source.source_code;
 
+    for module_stub in module_stubs {
+        pooled_db.write_root_file(&src_root.join(module_stub.path), module_stub.source_code)?;
+    }
+
     let (main_file, code_offset): (File, u32) = if let Some(stubs_file) = stubs_file {
         let stubs_path = src_root.join(stubs_file.path);
         pooled_db.write_root_file(&stubs_path, stubs_file.source_code)?;
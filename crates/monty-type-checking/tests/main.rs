@@ -1,7 +1,8 @@
 use std::thread;
 
 use insta::assert_snapshot;
-use monty_type_checking::{SourceFile, type_check};
+use monty::{FromMonty, FunctionRegistry, IntoMonty, MontyException};
+use monty_type_checking::{SourceFile, type_check, type_check_with_modules};
 use ruff_db::diagnostic::DiagnosticFormat;
 
 #[test]
@@ -116,6 +117,27 @@ fn stdlib_datetime_resolves() {
     assert!(result.is_none(), "Expected no type errors, got: {result:#?}");
 }
 
+#[test]
+fn host_module_stubs_resolve() {
+    let module_stubs = [
+        SourceFile::new("def search(query: str) -> list[str]: ...\n", "mytools/__init__.pyi"),
+        SourceFile::new("DEBUG: bool\n", "mytools/config.pyi"),
+    ];
+    let code =
+        "import mytools.config\nfrom mytools import search\nresults = search('monty')\nprint(mytools.config.DEBUG)\n";
+    let result = type_check_with_modules(&SourceFile::new(code, "main.py"), None, &module_stubs).unwrap();
+    assert!(result.is_none(), "Expected no type errors, got: {result:#?}");
+
+    let code = "from mytools import search\nsearch(1)\n";
+    let result = type_check_with_modules(&SourceFile::new(code, "main.py"), None, &module_stubs)
+        .unwrap()
+        .expect("expected type errors");
+    assert_snapshot!(
+        result.format(DiagnosticFormat::Concise).to_string(),
+        @"main.py:2:8: error[invalid-argument-type] Argument to function `search` is incorrect: Expected `str`, found `Literal[1]`"
+    );
+}
+
 /// Test that good_types.py type-checks without errors.
 ///
 /// This file uses `assert_type` from typing to verify that inferred types match expected types.
@@ -319,3 +341,39 @@ fn deeply_nested_parentheses_do_not_stack_overflow() {
     "
     );
 }
+
+#[derive(IntoMonty, FromMonty)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+fn shift(Point { x, y }: Point, dx: f64) -> Result<Point, MontyException> {
+    Ok(Point { x: x + dx, y })
+}
+
+#[test]
+fn function_registry_stubs() {
+    let registry = FunctionRegistry::new().with_function("shift", &["point", "dx"], shift);
+    let stubs = registry.type_stubs();
+
+    let good = "p = shift(Point(1.0, 2.0), dx=0.5)\ny: float = p.y\n";
+    let result = type_check(
+        &SourceFile::new(good, "main.py"),
+        Some(&SourceFile::new(&stubs, "type_stubs.pyi")),
+    )
+    .unwrap();
+    assert!(result.is_none(), "registry stubs should type check: {result:#?}");
+
+    let bad = "shift(Point(1.0, 2.0), dx='far')\n";
+    let result = type_check(
+        &SourceFile::new(bad, "main.py"),
+        Some(&SourceFile::new(&stubs, "type_stubs.pyi")),
+    )
+    .unwrap();
+    let error_diagnostics = result.expect("expected type errors").to_string();
+    assert!(
+        error_diagnostics.contains("invalid-argument-type"),
+        "{error_diagnostics}"
+    );
+}
@@ -15,7 +15,7 @@ use crate::{
     exception_private::{ExcType, RunError, RunResult, SimpleException},
     heap::ContainsHeap,
     resource::ResourceTracker,
-    types::PyTrait,
+    types::host_handle::checked_truth,
     value::Value,
 };
 
@@ -46,7 +46,7 @@ pub(crate) trait FromValue: Sized {
     ///
     /// Takes `&mut VM` so impls can both inspect the heap (for type coercion)
     /// and call `drop_with_heap` on the input; this also lets `LaxBool` route
-    /// through `PyTrait::py_bool`.
+    /// through [`checked_truth`].
     fn from_value(value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Self>;
 
     /// Drop the *extracted* value (i.e. `Self`) so refcounts stay balanced
@@ -183,9 +183,9 @@ pub(crate) struct LaxBool(bool);
 
 impl FromValue for LaxBool {
     fn from_value(value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Self> {
-        let result = value.py_bool(vm);
+        let result = checked_truth(&value, vm);
         value.drop_with_heap(vm);
-        Ok(Self(result))
+        Ok(Self(result?))
     }
 }
 
@@ -196,18 +196,22 @@ impl ArgValues {
 
     /// Converts the arguments into a Vec of MontyObjects.
     ///
-    /// This is used when passing arguments to external functions.
+    /// This is used when passing arguments to external functions, so functions
+    /// defined in the sandbox become callbacks.
     pub fn into_py_objects(
         self,
         vm: &mut VM<'_, impl ResourceTracker>,
     ) -> (Vec<MontyObject>, Vec<(MontyObject, MontyObject)>) {
         match self {
             Self::Empty => (vec![], vec![]),
-            Self::One(a) => (vec![MontyObject::new(a, vm)], vec![]),
-            Self::Two(a1, a2) => (vec![MontyObject::new(a1, vm), MontyObject::new(a2, vm)], vec![]),
+            Self::One(a) => (vec![MontyObject::new_host_arg(a, vm)], vec![]),
+            Self::Two(a1, a2) => (
+                vec![MontyObject::new_host_arg(a1, vm), MontyObject::new_host_arg(a2, vm)],
+                vec![],
+            ),
             Self::Kwargs(kwargs) => (vec![], kwargs.into_py_objects(vm)),
             Self::ArgsKargs { args, kwargs } => (
-                args.into_iter().map(|v| MontyObject::new(v, vm)).collect(),
+                args.into_iter().map(|v| MontyObject::new_host_arg(v, vm)).collect(),
                 kwargs.into_py_objects(vm),
             ),
         }
@@ -360,13 +364,13 @@ impl KwargsValues {
                 .into_iter()
                 .map(|(k, v)| {
                     let key = MontyObject::String(vm.interns.get_str(k).to_owned());
-                    let value = MontyObject::new(v, vm);
+                    let value = MontyObject::new_host_arg(v, vm);
                     (key, value)
                 })
                 .collect(),
             Self::Dict(dict) => dict
                 .into_iter()
-                .map(|(k, v)| (MontyObject::new(k, vm), MontyObject::new(v, vm)))
+                .map(|(k, v)| (MontyObject::new_host_arg(k, vm), MontyObject::new_host_arg(v, vm)))
                 .collect(),
         }
     }
@@ -16,7 +16,8 @@ use crate::{
 
 /// Implementation of the abs() builtin function.
 ///
-/// Returns the absolute value of a number. Works with integers, floats, and LongInts.
+/// Returns the absolute value of a number. Works with integers, floats, LongInts and
+/// complex numbers (whose absolute value is their magnitude).
 /// For `i64::MIN`, which overflows on negation, promotes to LongInt.
 pub fn builtin_abs(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let value = args.get_one_arg("abs", vm.heap)?;
@@ -37,6 +38,7 @@ pub fn builtin_abs(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> Ru
         Value::Bool(b) => Ok(Value::Int(i64::from(*b))),
         Value::Ref(id) => match vm.heap.get(*id) {
             HeapData::LongInt(li) => Ok(li.abs().into_value(vm.heap)?),
+            HeapData::Complex(c) => Ok(Value::Float(c.abs()?)),
             HeapData::TimeDelta(td) => {
                 let total = timedelta::total_microseconds(td);
                 let abs_total = total.checked_abs().unwrap_or(total);
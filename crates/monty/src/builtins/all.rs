@@ -6,7 +6,7 @@ use crate::{
     defer_drop, defer_drop_mut,
     exception_private::RunResult,
     resource::ResourceTracker,
-    types::{MontyIter, PyTrait},
+    types::{MontyIter, host_handle::checked_truth},
     value::Value,
 };
 
@@ -21,7 +21,7 @@ pub fn builtin_all(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> Ru
 
     while let Some(item) = iter.for_next(vm)? {
         defer_drop!(item, vm);
-        if !item.py_bool(vm) {
+        if !checked_truth(item, vm)? {
             return Ok(Value::Bool(false));
         }
     }
@@ -0,0 +1,35 @@
+//! Implementation of the ascii() builtin function.
+
+use std::fmt::Write;
+
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::RunResult,
+    resource::ResourceTracker,
+    types::{PyTrait, str::allocate_string},
+    value::Value,
+};
+
+/// Implementation of the ascii() builtin function.
+///
+/// Like `repr()`, but escapes every non-ASCII character with `\x`, `\u` or `\U`.
+pub fn builtin_ascii(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let value = args.get_one_arg("ascii", vm.heap)?;
+    defer_drop!(value, vm);
+    let repr = value.py_repr(vm)?;
+    if repr.is_ascii() {
+        return Ok(allocate_string(repr.into_owned(), vm.heap)?);
+    }
+    let mut s = String::with_capacity(repr.len());
+    for c in repr.chars() {
+        match u32::from(c) {
+            0..=0x7f => s.push(c),
+            code @ 0x80..=0xff => write!(s, "\\x{code:02x}")?,
+            code @ 0x100..=0xffff => write!(s, "\\u{code:04x}")?,
+            code => write!(s, "\\U{code:08x}")?,
+        }
+    }
+    Ok(allocate_string(s, vm.heap)?)
+}
@@ -0,0 +1,15 @@
+//! Implementation of the callable() builtin function.
+
+use crate::{
+    args::ArgValues, bytecode::VM, defer_drop, exception_private::RunResult, resource::ResourceTracker, value::Value,
+};
+
+/// Implementation of the callable() builtin function.
+///
+/// Returns True if the object can be called: functions, lambdas, closures,
+/// builtins, external functions, classes and type constructors.
+pub fn builtin_callable(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let value = args.get_one_arg("callable", vm.heap)?;
+    defer_drop!(value, vm);
+    Ok(Value::Bool(value.is_callable(vm.heap)))
+}
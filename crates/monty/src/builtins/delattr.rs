@@ -0,0 +1,38 @@
+//! Implementation of the delattr() builtin function.
+
+use crate::{
+    ExcType,
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{RunResult, SimpleException},
+    resource::ResourceTracker,
+    types::PyTrait,
+    value::Value,
+};
+
+/// Implementation of the delattr() builtin function.
+///
+/// Deletes the named attribute from the given object. This is the counterpart
+/// to setattr(). Returns None on success.
+///
+/// Examples:
+/// ```python
+/// delattr(obj, 'x')  # Delete obj.x
+/// ```
+pub fn builtin_delattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (object, name) = args.get_two_args("delattr", vm.heap)?;
+    defer_drop!(object, vm);
+    defer_drop!(name, vm);
+
+    let Some(name) = name.as_either_str(vm.heap) else {
+        return Err(SimpleException::new_msg(
+            ExcType::TypeError,
+            format!("attribute name must be string, not '{}'", name.py_type(vm)),
+        )
+        .into());
+    };
+
+    object.py_del_attr(&name, vm)?;
+    Ok(Value::None)
+}
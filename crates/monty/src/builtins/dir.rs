@@ -0,0 +1,467 @@
+//! Implementation of the dir() builtin function.
+
+use super::Builtins;
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::RunResult,
+    heap::HeapData,
+    resource::ResourceTracker,
+    types::{Dict, List, PyTrait, Type, str::allocate_string},
+    value::Value,
+};
+
+/// Implementation of the dir() builtin function.
+///
+/// Without an argument, returns the sorted names bound in the current scope.
+/// With an argument, returns the sorted attribute names of the object: the
+/// methods and attributes of its type, plus instance attributes for dataclasses,
+/// named tuples, classes and modules. Dunder names are not listed.
+pub fn builtin_dir(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let mut names = match args.get_zero_one_arg("dir", vm.heap)? {
+        None => {
+            let bindings = vm.local_bindings();
+            let names = bindings
+                .iter()
+                .map(|(name, _)| vm.interns.get_str(*name).to_owned())
+                .collect();
+            for (_, value) in bindings {
+                value.drop_with_heap(vm);
+            }
+            names
+        }
+        Some(object) => {
+            defer_drop!(object, vm);
+            attr_names(object, vm)
+        }
+    };
+    names.sort_unstable();
+    names.dedup();
+
+    let mut items = Vec::with_capacity(names.len());
+    for name in names {
+        items.push(allocate_string(name, vm.heap)?);
+    }
+    Ok(Value::Ref(vm.heap.allocate(HeapData::List(List::new(items)))?))
+}
+
+/// Collects the (unsorted, possibly duplicated) attribute names of `object`.
+fn attr_names(object: &Value, vm: &VM<'_, impl ResourceTracker>) -> Vec<String> {
+    let interns = vm.interns;
+    let ty = match object {
+        Value::Builtin(Builtins::Type(t)) => *t,
+        Value::Builtin(Builtins::ExcType(exc)) => Type::Exception(*exc),
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Module(module) => return dict_key_names(module.attrs(), vm),
+            HeapData::Dataclass(dc) => {
+                let mut names = dict_key_names(dc.attrs(), vm);
+                if let Some(class_id) = dc.class_id()
+                    && let HeapData::DataclassType(class) = vm.heap.get(class_id)
+                {
+                    names.extend(class.attr_names().map(|name| interns.get_str(name).to_owned()));
+                }
+                return names;
+            }
+            HeapData::DataclassType(class) => {
+                return class
+                    .attr_names()
+                    .map(|name| interns.get_str(name).to_owned())
+                    .collect();
+            }
+            HeapData::NamedTuple(nt) => {
+                return nt
+                    .field_names()
+                    .iter()
+                    .map(|name| name.as_str(interns).to_owned())
+                    .collect();
+            }
+            _ => object.py_type(vm),
+        },
+        _ => object.py_type(vm),
+    };
+    type_attr_names(ty).iter().map(|&name| name.to_owned()).collect()
+}
+
+/// Returns the string keys of a namespace dict such as a module's or an instance's attributes.
+fn dict_key_names(dict: &Dict, vm: &VM<'_, impl ResourceTracker>) -> Vec<String> {
+    dict.iter()
+        .filter_map(|(key, _)| key.as_either_str(vm.heap))
+        .map(|name| name.into_string(vm.interns))
+        .collect()
+}
+
+/// Public methods and attributes Monty implements for each builtin type.
+fn type_attr_names(ty: Type) -> &'static [&'static str] {
+    match ty {
+        Type::Bool | Type::Int => &[
+            "as_integer_ratio",
+            "bit_count",
+            "bit_length",
+            "conjugate",
+            "denominator",
+            "from_bytes",
+            "imag",
+            "is_integer",
+            "numerator",
+            "real",
+            "to_bytes",
+        ],
+        Type::Float => &[
+            "as_integer_ratio",
+            "conjugate",
+            "fromhex",
+            "hex",
+            "imag",
+            "is_integer",
+            "real",
+        ],
+        Type::Complex => &["conjugate", "imag", "real"],
+        Type::Slice => &["start", "step", "stop"],
+        Type::Str => &[
+            "capitalize",
+            "casefold",
+            "center",
+            "count",
+            "encode",
+            "endswith",
+            "expandtabs",
+            "find",
+            "index",
+            "isalnum",
+            "isalpha",
+            "isascii",
+            "isdecimal",
+            "isdigit",
+            "isidentifier",
+            "islower",
+            "isnumeric",
+            "isprintable",
+            "isspace",
+            "istitle",
+            "isupper",
+            "join",
+            "ljust",
+            "lower",
+            "lstrip",
+            "maketrans",
+            "partition",
+            "removeprefix",
+            "removesuffix",
+            "replace",
+            "rfind",
+            "rindex",
+            "rjust",
+            "rpartition",
+            "rsplit",
+            "rstrip",
+            "split",
+            "splitlines",
+            "startswith",
+            "strip",
+            "swapcase",
+            "title",
+            "translate",
+            "upper",
+            "zfill",
+        ],
+        Type::Bytes => &[
+            "capitalize",
+            "center",
+            "count",
+            "decode",
+            "endswith",
+            "find",
+            "fromhex",
+            "hex",
+            "index",
+            "isalnum",
+            "isalpha",
+            "isascii",
+            "isdigit",
+            "islower",
+            "isspace",
+            "istitle",
+            "isupper",
+            "join",
+            "ljust",
+            "lower",
+            "lstrip",
+            "maketrans",
+            "partition",
+            "removeprefix",
+            "removesuffix",
+            "replace",
+            "rfind",
+            "rindex",
+            "rjust",
+            "rpartition",
+            "rsplit",
+            "rstrip",
+            "split",
+            "splitlines",
+            "startswith",
+            "strip",
+            "swapcase",
+            "title",
+            "translate",
+            "upper",
+            "zfill",
+        ],
+        Type::ByteArray => &[
+            "append",
+            "capitalize",
+            "center",
+            "clear",
+            "copy",
+            "count",
+            "decode",
+            "endswith",
+            "extend",
+            "find",
+            "fromhex",
+            "hex",
+            "index",
+            "insert",
+            "isalnum",
+            "isalpha",
+            "isascii",
+            "isdigit",
+            "islower",
+            "isspace",
+            "istitle",
+            "isupper",
+            "join",
+            "ljust",
+            "lower",
+            "lstrip",
+            "maketrans",
+            "partition",
+            "pop",
+            "remove",
+            "removeprefix",
+            "removesuffix",
+            "replace",
+            "reverse",
+            "rfind",
+            "rindex",
+            "rjust",
+            "rpartition",
+            "rsplit",
+            "rstrip",
+            "split",
+            "splitlines",
+            "startswith",
+            "strip",
+            "swapcase",
+            "title",
+            "translate",
+            "upper",
+            "zfill",
+        ],
+        Type::MemoryView => &[
+            "format",
+            "hex",
+            "itemsize",
+            "nbytes",
+            "ndim",
+            "obj",
+            "readonly",
+            "release",
+            "tobytes",
+            "tolist",
+            "toreadonly",
+        ],
+        Type::List => &[
+            "append", "clear", "copy", "count", "extend", "index", "insert", "pop", "remove", "reverse", "sort",
+        ],
+        Type::Tuple => &["count", "index"],
+        Type::Dict => &[
+            "clear",
+            "copy",
+            "fromkeys",
+            "get",
+            "items",
+            "keys",
+            "pop",
+            "popitem",
+            "setdefault",
+            "update",
+            "values",
+        ],
+        Type::DictKeys | Type::DictItems => &["isdisjoint"],
+        Type::Set => &[
+            "add",
+            "clear",
+            "copy",
+            "difference",
+            "discard",
+            "intersection",
+            "isdisjoint",
+            "issubset",
+            "issuperset",
+            "pop",
+            "remove",
+            "symmetric_difference",
+            "union",
+            "update",
+        ],
+        Type::FrozenSet => &[
+            "copy",
+            "difference",
+            "intersection",
+            "isdisjoint",
+            "issubset",
+            "issuperset",
+            "symmetric_difference",
+            "union",
+        ],
+        Type::Date => &[
+            "day",
+            "fromisoformat",
+            "isoformat",
+            "isoweekday",
+            "month",
+            "replace",
+            "strftime",
+            "today",
+            "weekday",
+            "year",
+        ],
+        Type::DateTime => &[
+            "date",
+            "day",
+            "fromisoformat",
+            "hour",
+            "isoformat",
+            "isoweekday",
+            "microsecond",
+            "minute",
+            "month",
+            "now",
+            "replace",
+            "second",
+            "strftime",
+            "strptime",
+            "timestamp",
+            "tzinfo",
+            "weekday",
+            "year",
+        ],
+        Type::TimeDelta => &["days", "microseconds", "seconds", "total_seconds"],
+        Type::Path => &[
+            "absolute",
+            "as_posix",
+            "exists",
+            "is_absolute",
+            "is_dir",
+            "is_file",
+            "is_symlink",
+            "iterdir",
+            "joinpath",
+            "mkdir",
+            "name",
+            "open",
+            "parent",
+            "parts",
+            "read_bytes",
+            "read_text",
+            "rename",
+            "resolve",
+            "rmdir",
+            "stat",
+            "stem",
+            "suffix",
+            "suffixes",
+            "unlink",
+            "with_name",
+            "with_stem",
+            "with_suffix",
+            "write_bytes",
+            "write_text",
+        ],
+        Type::TextIOWrapper | Type::BufferedReader | Type::BufferedWriter | Type::BufferedRandom => &[
+            "close",
+            "closed",
+            "encoding",
+            "errors",
+            "flush",
+            "mode",
+            "name",
+            "read",
+            "readable",
+            "readline",
+            "readlines",
+            "seek",
+            "seekable",
+            "tell",
+            "writable",
+            "write",
+        ],
+        Type::StringIO | Type::BytesIO => &[
+            "close",
+            "closed",
+            "flush",
+            "getvalue",
+            "read",
+            "readable",
+            "readline",
+            "readlines",
+            "seek",
+            "seekable",
+            "tell",
+            "truncate",
+            "writable",
+            "write",
+            "writelines",
+        ],
+        Type::RePattern => &[
+            "findall",
+            "finditer",
+            "flags",
+            "fullmatch",
+            "match",
+            "pattern",
+            "search",
+            "split",
+            "sub",
+        ],
+        Type::ReMatch => &["end", "group", "groupdict", "groups", "span", "start", "string"],
+        Type::ExitStack => &["callback", "close", "enter_context", "pop_all", "push"],
+        Type::Logger => &[
+            "critical",
+            "debug",
+            "error",
+            "exception",
+            "fatal",
+            "getChild",
+            "getEffectiveLevel",
+            "info",
+            "isEnabledFor",
+            "level",
+            "log",
+            "name",
+            "setLevel",
+            "warn",
+            "warning",
+        ],
+        Type::LoggerAdapter => &[
+            "critical",
+            "debug",
+            "error",
+            "exception",
+            "extra",
+            "getEffectiveLevel",
+            "info",
+            "isEnabledFor",
+            "log",
+            "logger",
+            "name",
+            "setLevel",
+            "warn",
+            "warning",
+        ],
+        Type::Exception(_) => &["args"],
+        _ => &[],
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
     exception_private::RunResult,
     heap::{HeapData, HeapGuard},
     resource::ResourceTracker,
-    types::{List, MontyIter, PyTrait},
+    types::{List, MontyIter, host_handle::checked_truth},
     value::Value,
 };
 
@@ -46,14 +46,14 @@ pub fn builtin_filter(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) ->
         let (item, vm) = item_guard.as_parts_mut();
         let should_include = if let Value::None = function {
             // No predicate - use truthiness of element
-            item.py_bool(vm)
+            checked_truth(item, vm)?
         } else {
             // Clone for predicate call - the clone is consumed by evaluate_function
             let item_for_predicate = item.clone_with_heap(vm);
             let result = vm.evaluate_function("filter()", function, ArgValues::One(item_for_predicate))?;
-            let is_truthy = result.py_bool(vm);
+            let is_truthy = checked_truth(&result, vm);
             result.drop_with_heap(vm);
-            is_truthy
+            is_truthy?
         };
 
         if should_include {
@@ -8,8 +8,10 @@ use crate::{
     exception_private::{RunError, RunResult, SimpleException},
     heap::DropWithHeap,
     resource::ResourceTracker,
-    types::PyTrait,
-    value::Value,
+    types::{
+        HostHandle, PyTrait,
+        host_handle::{HandleEffect, HandleOp, HandleRequest},
+    },
 };
 
 /// Implementation of the getattr() builtin function.
@@ -22,13 +24,16 @@ use crate::{
 /// at compilation time, one must manually mangle a private attribute's (attributes with
 /// two leading underscores) name in order to retrieve it with getattr()."
 ///
+/// Attributes of a host handle are asked from the host, which can raise
+/// `AttributeError` to get the default.
+///
 /// Examples:
 /// ```python
 /// getattr(obj, 'x')             # Get obj.x
 /// getattr(obj, 'y', None)       # Get obj.y or None if not found
 /// getattr(module, 'function')   # Get module.function
 /// ```
-pub fn builtin_getattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+pub fn builtin_getattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
     let positional = args.into_pos_only("getattr", vm.heap)?;
     defer_drop!(positional, vm);
 
@@ -46,8 +51,16 @@ pub fn builtin_getattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -
         );
     };
 
+    if let Some(handle) = HostHandle::of(object, vm) {
+        let mut request = HandleRequest::new(handle, HandleOp::GetAttr(attr));
+        if let Some(d) = default {
+            request = request.with_effect(HandleEffect::AttrDefault(d.clone_with_heap(vm)));
+        }
+        return Ok(CallResult::HandleCall(request));
+    }
+
     match object.py_getattr(&attr, vm) {
-        Ok(CallResult::Value(value)) => Ok(value),
+        Ok(CallResult::Value(value)) => Ok(CallResult::Value(value)),
         Ok(other) => {
             other.drop_with_heap(vm);
             // getattr() only retrieves attribute values — OS calls, external calls,
@@ -60,7 +73,7 @@ pub fn builtin_getattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -
             if let Some(d) = default
                 && e.exc.exc_type() == ExcType::AttributeError =>
         {
-            Ok(d.clone_with_heap(vm))
+            Ok(CallResult::Value(d.clone_with_heap(vm)))
         }
         Err(e) => Err(e),
     }
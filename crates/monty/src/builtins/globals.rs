@@ -0,0 +1,13 @@
+//! Implementation of the globals() builtin function.
+
+use crate::{args::ArgValues, bytecode::VM, exception_private::RunResult, resource::ResourceTracker, value::Value};
+
+/// Implementation of the globals() builtin function.
+///
+/// Returns a new dict of the module's bound global names. The dict is a
+/// snapshot: assigning to it does not create or rebind globals.
+pub fn builtin_globals(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    args.check_zero_args("globals", vm.heap)?;
+    let bindings = vm.global_bindings();
+    Ok(Value::Ref(vm.bindings_dict(bindings)?))
+}
@@ -8,7 +8,10 @@ use crate::{
     exception_private::{RunError, RunResult, SimpleException},
     heap::DropWithHeap,
     resource::ResourceTracker,
-    types::PyTrait,
+    types::{
+        HostHandle, PyTrait,
+        host_handle::{HandleEffect, HandleOp, HandleRequest},
+    },
     value::Value,
 };
 
@@ -22,13 +25,16 @@ use crate::{
 /// Note: This is implemented by calling getattr(object, name) and returning
 /// True if it succeeds, False if it raises an exception.
 ///
+/// Attributes of a host handle are asked from the host, which raises
+/// `AttributeError` for a missing one.
+///
 /// Examples:
 /// ```python
 /// hasattr(obj, 'x')             # Check if obj.x exists
 /// hasattr(slice(1, 10), 'start') # True - slice has start attribute
 /// hasattr(42, 'nonexistent')    # False - int has no such attribute
 /// ```
-pub fn builtin_hasattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+pub fn builtin_hasattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
     let positional = args.into_pos_only("hasattr", vm.heap)?;
     defer_drop!(positional, vm);
 
@@ -45,6 +51,11 @@ pub fn builtin_hasattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -
         .into());
     };
 
+    if let Some(handle) = HostHandle::of(object, vm) {
+        let request = HandleRequest::new(handle, HandleOp::GetAttr(name)).with_effect(HandleEffect::HasAttr);
+        return Ok(CallResult::HandleCall(request));
+    }
+
     // important: we must own the returned value if py_get_attr succeeds to drop it
     let has_attr = match object.py_getattr(&name, vm) {
         Ok(CallResult::Value(value)) => {
@@ -65,5 +76,5 @@ pub fn builtin_hasattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -
         Err(e) => return Err(e),
     };
 
-    Ok(Value::Bool(has_attr))
+    Ok(CallResult::Value(Value::Bool(has_attr)))
 }
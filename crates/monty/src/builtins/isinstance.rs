@@ -5,10 +5,10 @@ use crate::{
     args::ArgValues,
     bytecode::VM,
     defer_drop,
-    exception_private::{ExcType, RunResult},
-    heap::{HeapRead, HeapReadOutput},
+    exception_private::{ExcType, RunError, RunResult},
+    heap::{HeapData, HeapId, HeapRead, HeapReadOutput},
     resource::ResourceTracker,
-    types::{PyTrait, Tuple, Type},
+    types::{HostType, PyTrait, Tuple, Type, dataclass::class_of},
     value::Value,
 };
 
@@ -20,8 +20,32 @@ pub fn builtin_isinstance(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues
     defer_drop!(obj, vm);
     defer_drop!(classinfo, vm);
     let obj_type = obj.py_type(vm);
+    let obj_class = ObjClass::of(obj, vm);
 
-    isinstance_check(obj_type, classinfo, vm).map(Value::Bool)
+    isinstance_check(obj_type, &obj_class, classinfo, ExcType::isinstance_arg2_error, vm).map(Value::Bool)
+}
+
+/// The class of the object being checked, beyond its builtin `Type`.
+pub(super) enum ObjClass {
+    /// Nothing more specific than the object's `Type`.
+    Builtin,
+    /// A class declared in the sandbox (`HeapData::DataclassType`).
+    Declared(HeapId),
+    /// A dataclass or named tuple class defined by the host.
+    Host(HostType),
+}
+
+impl ObjClass {
+    /// Returns the class of an instance.
+    fn of(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> Self {
+        if let Some(class_id) = class_of(value, vm) {
+            Self::Declared(class_id)
+        } else if let Some(host_type) = HostType::of(value, vm) {
+            Self::Host(host_type)
+        } else {
+            Self::Builtin
+        }
+    }
 }
 
 /// Checks if `obj_type` matches a single classinfo entry.
@@ -30,24 +54,37 @@ pub fn builtin_isinstance(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues
 /// - Single types: `isinstance(x, int)`
 /// - Exception types: `isinstance(err, ValueError)`
 /// - Exception hierarchy: `isinstance(err, LookupError)` for KeyError/IndexError
+/// - Sandbox-declared classes and host dataclass / named tuple classes, matched against `obj_class`
 /// - Tuples (possibly nested) of the above
-fn isinstance_check(obj_type: Type, classinfo: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<bool> {
+///
+/// Any other classinfo raises the error built by `arg2_error`, which lets
+/// `issubclass()` share this check with its own message.
+pub(super) fn isinstance_check(
+    obj_type: Type,
+    obj_class: &ObjClass,
+    classinfo: &Value,
+    arg2_error: fn() -> RunError,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<bool> {
     match classinfo {
         Value::Builtin(Builtins::Type(t)) => Ok(obj_type.is_instance_of(*t)),
         Value::Builtin(Builtins::ExcType(handler_type)) => {
             Ok(matches!(obj_type, Type::Exception(exc_type) if exc_type.is_subclass_of(*handler_type)))
         }
+        Value::Ref(id) if let Some(is_match) = class_matches(obj_class, *id, vm) => Ok(is_match),
         Value::Ref(id) if let HeapReadOutput::Tuple(tuple) = vm.heap.read(*id) => {
-            isinstance_check_tuple(obj_type, &tuple, vm)
+            isinstance_check_tuple(obj_type, obj_class, &tuple, arg2_error, vm)
         }
-        _ => Err(ExcType::isinstance_arg2_error()),
+        _ => Err(arg2_error()),
     }
 }
 
 /// Recursively walks a tuple of classinfo entries.
 fn isinstance_check_tuple<'h>(
     obj_type: Type,
+    obj_class: &ObjClass,
     tuple: &HeapRead<'h, Tuple>,
+    arg2_error: fn() -> RunError,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<bool> {
     let len = tuple.get(vm.heap).as_slice().len();
@@ -65,13 +102,27 @@ fn isinstance_check_tuple<'h>(
                     return Ok(true);
                 }
             }
+            Value::Ref(class_id) if let Some(is_match) = class_matches(obj_class, *class_id, vm) => {
+                if is_match {
+                    return Ok(true);
+                }
+            }
             Value::Ref(nested_id) if let HeapReadOutput::Tuple(tuple) = vm.heap.read(*nested_id) => {
-                if isinstance_check_tuple(obj_type, &tuple, vm)? {
+                if isinstance_check_tuple(obj_type, obj_class, &tuple, arg2_error, vm)? {
                     return Ok(true);
                 }
             }
-            _ => return Err(ExcType::isinstance_arg2_error()),
+            _ => return Err(arg2_error()),
         }
     }
     Ok(false)
 }
+
+/// Checks `obj_class` against the class at `class_id`, or returns `None` if that isn't a class.
+fn class_matches(obj_class: &ObjClass, class_id: HeapId, vm: &VM<'_, impl ResourceTracker>) -> Option<bool> {
+    match vm.heap.get(class_id) {
+        HeapData::DataclassType(_) => Some(matches!(obj_class, ObjClass::Declared(id) if *id == class_id)),
+        HeapData::HostType(class) => Some(matches!(obj_class, ObjClass::Host(host) if host.is_same_class(class))),
+        _ => None,
+    }
+}
@@ -0,0 +1,42 @@
+//! Implementation of the issubclass() builtin function.
+
+use super::{
+    Builtins,
+    isinstance::{ObjClass, isinstance_check},
+};
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::HeapData,
+    resource::ResourceTracker,
+    types::Type,
+    value::Value,
+};
+
+/// Implementation of the issubclass() builtin function.
+///
+/// Checks if a class is a subclass of a class or a tuple of classes, using the
+/// same subtype rules as `isinstance()` (e.g. `issubclass(bool, int)` is True).
+pub fn builtin_issubclass(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (cls, classinfo) = args.get_two_args("issubclass", vm.heap)?;
+    defer_drop!(cls, vm);
+    defer_drop!(classinfo, vm);
+
+    let (cls_type, cls_class) = match cls {
+        Value::Builtin(Builtins::Type(t)) => (*t, ObjClass::Builtin),
+        Value::Builtin(Builtins::ExcType(exc)) => (Type::Exception(*exc), ObjClass::Builtin),
+        Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::DataclassType(_)) => {
+            (Type::Dataclass, ObjClass::Declared(*id))
+        }
+        Value::Ref(id) if let HeapData::HostType(class) = vm.heap.get(*id) => {
+            (class.instance_type(), ObjClass::Host(class.clone()))
+        }
+        _ => {
+            return Err(SimpleException::new_msg(ExcType::TypeError, "issubclass() arg 1 must be a class").into());
+        }
+    };
+
+    isinstance_check(cls_type, &cls_class, classinfo, ExcType::issubclass_arg2_error, vm).map(Value::Bool)
+}
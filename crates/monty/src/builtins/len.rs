@@ -2,24 +2,31 @@
 
 use crate::{
     args::ArgValues,
-    bytecode::VM,
+    bytecode::{CallResult, VM},
     defer_drop,
     exception_private::{ExcType, RunResult, SimpleException},
     resource::ResourceTracker,
-    types::PyTrait,
+    types::{
+        HostHandle, PyTrait,
+        host_handle::{HandleOp, HandleRequest},
+    },
     value::Value,
 };
 
 /// Implementation of the len() builtin function.
 ///
 /// Returns the length of an object (number of items in a container).
-pub fn builtin_len(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+/// The length of a host handle is asked from the host.
+pub fn builtin_len(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
     let value = args.get_one_arg("len", vm.heap)?;
     defer_drop!(value, vm);
+    if let Some(handle) = HostHandle::of(value, vm) {
+        return Ok(CallResult::HandleCall(HandleRequest::new(handle, HandleOp::Len)));
+    }
     if let Some(len) = value.py_len(vm) {
-        Ok(Value::Int(
+        Ok(CallResult::Value(Value::Int(
             i64::try_from(len).map_err(|_| ExcType::overflow_c_ssize_t())?,
-        ))
+        )))
     } else {
         let type_name = value.py_type(vm);
         Err(SimpleException::new_msg(ExcType::TypeError, format!("object of type '{type_name}' has no len()")).into())
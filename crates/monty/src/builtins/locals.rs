@@ -0,0 +1,14 @@
+//! Implementation of the locals() builtin function.
+
+use crate::{args::ArgValues, bytecode::VM, exception_private::RunResult, resource::ResourceTracker, value::Value};
+
+/// Implementation of the locals() builtin function.
+///
+/// Returns a new dict of the current function's bound local names, including
+/// captured variables. At module level this is the same as `globals()`.
+/// The dict is a snapshot: assigning to it does not rebind any variable.
+pub fn builtin_locals(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    args.check_zero_args("locals", vm.heap)?;
+    let bindings = vm.local_bindings();
+    Ok(Value::Ref(vm.bindings_dict(bindings)?))
+}
@@ -6,18 +6,25 @@
 mod abs;
 mod all;
 mod any;
+mod ascii;
 mod bin;
+mod callable;
 mod chr;
+mod delattr;
+mod dir;
 mod divmod;
 mod enumerate;
 mod filter;
 mod getattr;
+mod globals;
 mod hasattr;
 mod hash;
 mod hex;
 mod id;
 mod isinstance;
+mod issubclass;
 mod len;
+mod locals;
 mod map;
 mod min_max; // min and max share implementation
 mod next;
@@ -37,6 +44,7 @@ mod sum;
 #[cfg(feature = "test-hooks")]
 mod test_cm;
 mod type_;
+mod vars;
 mod zip;
 
 use std::{fmt, fmt::Write, str::FromStr};
@@ -47,8 +55,12 @@ use crate::{
     args::ArgValues,
     bytecode::{CallResult, VM},
     exception_private::{ExcType, RunResult},
+    heap::DropWithHeap,
     resource::ResourceTracker,
-    types::Type,
+    types::{
+        HostHandle, Type,
+        host_handle::{HandleEffect, HandleOp, HandleRequest},
+    },
 };
 
 /// Enumerates every interpreter-native Python builtins
@@ -72,11 +84,29 @@ impl Builtins {
     /// `open()` is the exception: it must touch the host filesystem at call
     /// time to perform the open-time effect, so it returns a
     /// [`CallResult::OsCall`] for [`crate::os::OsFunction::Open`] (see
-    /// [`crate::builtins::open`]).
+    /// [`crate::builtins::open`]). `bool(h)` and `iter(h)` on a host handle ask the
+    /// host for the answer with a [`CallResult::HandleCall`], and builtins that consume
+    /// an iterable, like `list(h)`, ask for `iter(h)` and then run on its result.
     pub fn call(self, vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
+        if self.consumes_iterable()
+            && let Some(handle) = HostHandle::sole_arg(&args, vm)
+        {
+            args.drop_with_heap(vm);
+            let request = HandleRequest::new(handle, HandleOp::Iter).with_effect(HandleEffect::IterInto(self));
+            return Ok(CallResult::HandleCall(request));
+        }
         match self {
             Self::Function(b) => b.call(vm, args),
             Self::ExcType(exc) => exc.call(vm, args).map(CallResult::Value),
+            Self::Type(t @ (Type::Bool | Type::Iterator)) if let Some(handle) = HostHandle::sole_arg(&args, vm) => {
+                args.drop_with_heap(vm);
+                let op = if t == Type::Bool {
+                    HandleOp::Bool
+                } else {
+                    HandleOp::Iter
+                };
+                Ok(CallResult::HandleCall(HandleRequest::new(handle, op)))
+            }
             Self::Type(t) => t.call(vm, args).map(CallResult::Value),
         }
     }
@@ -90,6 +120,23 @@ impl Builtins {
         }
     }
 
+    /// Whether this builtin iterates its only argument to completion, so a host handle
+    /// passed to it can be replaced by the host's answer to `iter(h)`.
+    fn consumes_iterable(self) -> bool {
+        matches!(
+            self,
+            Self::Function(
+                BuiltinsFunctions::All
+                    | BuiltinsFunctions::Any
+                    | BuiltinsFunctions::Enumerate
+                    | BuiltinsFunctions::Max
+                    | BuiltinsFunctions::Min
+                    | BuiltinsFunctions::Sorted
+                    | BuiltinsFunctions::Sum
+            ) | Self::Type(Type::List | Type::Tuple | Type::Set | Type::FrozenSet)
+        )
+    }
+
     /// Returns the type of this builtin.
     pub fn py_type(self) -> Type {
         match self {
@@ -149,20 +196,20 @@ pub enum BuiltinsFunctions {
     All,
     // Anext,
     Any,
-    // Ascii,
+    Ascii,
     Bin,
     // bool - handled by Type enum
     // Breakpoint,
     // bytearray - handled by Type enum
     // bytes - handled by Type enum
-    // Callable,
+    Callable,
     Chr,
     // Classmethod,
     // Compile,
     // complex - handled by Type enum
-    // Delattr,
+    Delattr,
     // dict - handled by Type enum
-    // Dir,
+    Dir,
     Divmod,
     Enumerate,
     // Eval,
@@ -172,7 +219,7 @@ pub enum BuiltinsFunctions {
     // Format,
     // frozenset - handled by Type enum
     Getattr,
-    // Globals,
+    Globals,
     Hasattr,
     Hash,
     // Help,
@@ -181,11 +228,11 @@ pub enum BuiltinsFunctions {
     // Input,
     // int - handled by Type enum
     Isinstance,
-    // Issubclass,
+    Issubclass,
     // Iter - handled by Type enum
     Len,
     // list - handled by Type enum
-    // Locals,
+    Locals,
     Map,
     Max,
     // memoryview - handled by Type enum
@@ -212,7 +259,7 @@ pub enum BuiltinsFunctions {
     // Super,
     // tuple - handled by Type enum
     Type,
-    // Vars,
+    Vars,
     Zip,
     // __import__ - not planned
     /// Test-only synthetic context manager constructor. Only present
@@ -231,24 +278,33 @@ impl BuiltinsFunctions {
     /// Almost every builtin completes synchronously and produces a
     /// [`CallResult::Value`]. `open()` is the exception: it performs the
     /// open-time file effect via a host filesystem round-trip, so it returns a
-    /// [`CallResult::OsCall`] directly.
+    /// [`CallResult::OsCall`] directly. `print(file=f)` does the same when `f`
+    /// is an open file, and `len()`, `getattr()`, `hasattr()` and `setattr()` return a
+    /// [`CallResult::HandleCall`] for a host handle.
     pub(crate) fn call(self, vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
         let r = match self {
             Self::Abs => abs::builtin_abs(vm, args),
             Self::All => all::builtin_all(vm, args),
             Self::Any => any::builtin_any(vm, args),
+            Self::Ascii => ascii::builtin_ascii(vm, args),
             Self::Bin => bin::builtin_bin(vm, args),
+            Self::Callable => callable::builtin_callable(vm, args),
             Self::Chr => chr::builtin_chr(vm, args),
+            Self::Delattr => delattr::builtin_delattr(vm, args),
+            Self::Dir => dir::builtin_dir(vm, args),
             Self::Divmod => divmod::builtin_divmod(vm, args),
             Self::Enumerate => enumerate::builtin_enumerate(vm, args),
             Self::Filter => filter::builtin_filter(vm, args),
-            Self::Getattr => getattr::builtin_getattr(vm, args),
-            Self::Hasattr => hasattr::builtin_hasattr(vm, args),
+            Self::Getattr => return getattr::builtin_getattr(vm, args),
+            Self::Globals => globals::builtin_globals(vm, args),
+            Self::Hasattr => return hasattr::builtin_hasattr(vm, args),
             Self::Hash => hash::builtin_hash(vm, args),
             Self::Hex => hex::builtin_hex(vm, args),
             Self::Id => id::builtin_id(vm, args),
             Self::Isinstance => isinstance::builtin_isinstance(vm, args),
-            Self::Len => len::builtin_len(vm, args),
+            Self::Issubclass => issubclass::builtin_issubclass(vm, args),
+            Self::Len => return len::builtin_len(vm, args),
+            Self::Locals => locals::builtin_locals(vm, args),
             Self::Map => map::builtin_map(vm, args),
             Self::Max => min_max::builtin_max(vm, args),
             Self::Min => min_max::builtin_min(vm, args),
@@ -258,14 +314,16 @@ impl BuiltinsFunctions {
             Self::Open => return open::builtin_open(vm, args),
             Self::Ord => ord::builtin_ord(vm, args),
             Self::Pow => pow::builtin_pow(vm, args),
-            Self::Print => print::builtin_print(vm, args),
+            // `print(file=f)` on an open file yields the file's OS call.
+            Self::Print => return print::builtin_print(vm, args),
             Self::Repr => repr::builtin_repr(vm, args),
             Self::Reversed => reversed::builtin_reversed(vm, args),
             Self::Round => round::builtin_round(vm, args),
-            Self::Setattr => setattr::builtin_setattr(vm, args),
+            Self::Setattr => return setattr::builtin_setattr(vm, args),
             Self::Sorted => sorted::builtin_sorted(vm, args),
             Self::Sum => sum::builtin_sum(vm, args),
             Self::Type => type_::builtin_type(vm, args),
+            Self::Vars => vars::builtin_vars(vm, args),
             Self::Zip => zip::builtin_zip(vm, args),
             #[cfg(feature = "test-hooks")]
             Self::TestCm => test_cm::builtin_test_cm(vm, args),
@@ -7,18 +7,26 @@
 //! generic resume path converts into the heap [`OpenFile`](crate::types::OpenFile)
 //! wrapper. `read()`/`write()` then delegate to full-file OS calls, so all
 //! filesystem access remains behind `OsFunction`.
+//!
+//! A text-mode `encoding` / `errors` is validated here and attached to the
+//! returned file through [`PendingFileEffect::OpenText`]; the file then reads
+//! and writes bytes and runs them through [`crate::codecs`].
 
 use std::str;
 
 use crate::{
     args::{ArgValues, FromArgs},
     bytecode::{CallResult, VM},
+    codecs::Codec,
     defer_drop,
     exception_private::{ExcType, RunError, RunResult, SimpleException},
     heap::{HeapData, HeapGuard},
     os::{MontyPath, OpenCallArgs, OsFunctionCall},
     resource::ResourceTracker,
-    types::{PyTrait, file::FileMode},
+    types::{
+        PyTrait,
+        file::{FileMode, PendingFileEffect},
+    },
     value::Value,
 };
 
@@ -57,8 +65,8 @@ pub(crate) fn builtin_open(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValue
     // Reject non-default values for ignored kwargs so caller code can't
     // silently rely on (e.g.) `buffering=0` semantics Monty doesn't model.
     validate_ignored_open_kwarg("buffering", buffering, vm)?;
-    validate_ignored_open_kwarg("encoding", encoding, vm)?;
-    validate_ignored_open_kwarg("errors", errors, vm)?;
+    let encoding = optional_str_open_arg("encoding", encoding, vm)?;
+    let errors = optional_str_open_arg("errors", errors, vm)?;
     validate_ignored_open_kwarg("newline", newline, vm)?;
     validate_ignored_open_kwarg("closefd", closefd, vm)?;
     validate_ignored_open_kwarg("opener", opener, vm)?;
@@ -70,6 +78,25 @@ pub(crate) fn builtin_open(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValue
         .parse::<FileMode>()
         .map_err(|e| RunError::from(SimpleException::new_msg(ExcType::ValueError, e)))?;
 
+    if file_mode.is_binary() {
+        if encoding.is_some() {
+            return Err(
+                SimpleException::new_msg(ExcType::ValueError, "binary mode doesn't take an encoding argument").into(),
+            );
+        }
+        if errors.is_some() {
+            return Err(
+                SimpleException::new_msg(ExcType::ValueError, "binary mode doesn't take an errors argument").into(),
+            );
+        }
+    }
+    if let Some(encoding) = &encoding {
+        Codec::lookup(encoding)?;
+    }
+    if encoding.is_some() || errors.is_some() {
+        vm.pending_file_effect = Some(PendingFileEffect::OpenText { encoding, errors });
+    }
+
     Ok(CallResult::OsCall(OsFunctionCall::Open(OpenCallArgs {
         path: MontyPath::new(path),
         mode: file_mode,
@@ -82,9 +109,10 @@ pub(crate) fn builtin_open(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValue
 /// `mode` is taken as `String` so wrong-type errors flow through the macro's
 /// `bad_arg_named` path and match CPython's `open() argument 'mode' must be
 /// str, not …` wording verbatim. The other kwargs stay as raw `Value`
-/// because they have monty-specific validation (`validate_ignored_open_kwarg`)
-/// that the macro doesn't model — Monty rejects any *non-default* value to
-/// avoid silently dropping semantics it doesn't honour (e.g. `buffering=0`,
+/// because they have monty-specific validation (`validate_ignored_open_kwarg`,
+/// `optional_str_open_arg`) that the macro doesn't model — Monty rejects any
+/// *non-default* value of the remaining kwargs to avoid silently dropping
+/// semantics it doesn't honour (e.g. `buffering=0`,
 /// `opener=my_opener`). `file` is also raw because `open()`'s file-path
 /// error wording (`expected str, bytes or os.PathLike object, not …`) doesn't
 /// follow the `_PyArg_BadArgument` shape that `bad_arg_named` emits.
@@ -163,48 +191,39 @@ fn decode_utf8_path(bytes: &[u8]) -> RunResult<Option<&str>> {
     }
 }
 
+/// Extracts the `encoding` / `errors` argument of `open()`, which must be
+/// `str` or `None`.
+fn optional_str_open_arg(name: &str, value: &Value, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Option<String>> {
+    match value {
+        Value::None => Ok(None),
+        Value::InternString(id) => Ok(Some(vm.interns.get_str(*id).to_owned())),
+        Value::Ref(id) if let HeapData::Str(s) = vm.heap.get(*id) => Ok(Some(s.as_str().to_owned())),
+        _ => Err(ExcType::type_error(format!(
+            "open() argument '{name}' must be str or None, not {}",
+            value.py_type(vm).cpython_arg_name()
+        ))),
+    }
+}
+
 /// Validates `open()` kwargs that Monty does not actually honor.
 ///
-/// Monty only models the `file` and `mode` arguments. Any other argument set
-/// to a non-default value would silently be ignored if accepted, hiding bugs
-/// in user code that passes (for example) `buffering=0` expecting an
-/// unbuffered file or `opener=my_opener` expecting a custom open hook. To
-/// avoid that, the only accepted values are the CPython defaults plus, for
-/// `encoding`, the literal `"utf-8"` — which is what Monty already uses.
+/// Monty models the `file`, `mode`, `encoding` and `errors` arguments. Any
+/// other argument set to a non-default value would silently be ignored if
+/// accepted, hiding bugs in user code that passes (for example) `buffering=0`
+/// expecting an unbuffered file or `opener=my_opener` expecting a custom open
+/// hook. To avoid that, the only accepted values are the CPython defaults.
 ///
 /// Non-default values raise `TypeError` ("'<name>' argument is not yet
-/// supported"). A wrong *type* (e.g. `encoding=123`) is reported as a
+/// supported"). A wrong *type* (e.g. `newline=123`) is reported as a
 /// dedicated type error so it remains diagnosable.
 fn validate_ignored_open_kwarg(name: &str, value: &Value, vm: &VM<'_, impl ResourceTracker>) -> Result<(), RunError> {
     let is_default = match name {
         // CPython default is -1 (sentinel for "interpreter picks the
         // buffer size"). Monty has no buffering layer to tune.
         "buffering" => matches!(value, Value::Int(-1)),
-        // None is the CPython default; "utf-8" is the encoding Monty
-        // already uses, so accept it as a documented no-op.
-        "encoding" => {
-            if matches!(value, Value::None) {
-                true
-            } else if value.is_str(vm.heap) {
-                let s = match value {
-                    Value::InternString(id) => vm.interns.get_str(*id),
-                    Value::Ref(id) => match vm.heap.get(*id) {
-                        HeapData::Str(s) => s.as_str(),
-                        _ => "",
-                    },
-                    _ => "",
-                };
-                s.eq_ignore_ascii_case("utf-8") || s.eq_ignore_ascii_case("utf8")
-            } else {
-                return Err(ExcType::type_error(format!(
-                    "open() argument '{name}' must be str or None, not {}",
-                    value.py_type(vm).cpython_arg_name()
-                )));
-            }
-        }
-        // `errors` and `newline` accept str or None in CPython; only the
-        // default (None) is honored by Monty.
-        "errors" | "newline" => {
+        // `newline` accepts str or None in CPython; only the default (None)
+        // is honored by Monty.
+        "newline" => {
             if matches!(value, Value::None) {
                 true
             } else if value.is_str(vm.heap) {
@@ -12,7 +12,7 @@ use crate::{
     exception_private::{ExcType, RunResult, SimpleException},
     heap::{Heap, HeapData},
     resource::{ResourceTracker, check_pow_size},
-    types::{LongInt, PyTrait},
+    types::{LongInt, PyTrait, Type, complex::complex_operands},
     value::Value,
 };
 
@@ -50,6 +50,9 @@ pub fn builtin_pow(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> Ru
                     };
                     Ok(Value::Int(mod_pow(*b, e, m_nz)))
                 }
+                _ if [base, exp, m].into_iter().any(|v| v.py_type(vm) == Type::Complex) => {
+                    Err(SimpleException::new_msg(ExcType::ValueError, "complex modulo").into())
+                }
                 _ => Err(SimpleException::new_msg(
                     ExcType::TypeError,
                     "pow() 3rd argument not allowed unless all arguments are integers",
@@ -193,6 +196,9 @@ fn two_arg_pow(base: &Value, exp: &Value, vm: &mut VM<'_, impl ResourceTracker>)
                 Ok(Value::Float(b.powf(*e as f64)))
             }
         }
+        (Value::Ref(_), _) | (_, Value::Ref(_)) if let Some((b, e)) = complex_operands(base, exp, vm.heap) => {
+            Ok(b.pow(e)?.into_value(vm.heap)?)
+        }
         _ => Err(ExcType::binary_type_error(
             "** or pow()",
             base.py_type(vm),
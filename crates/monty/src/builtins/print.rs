@@ -1,14 +1,18 @@
 //! Implementation of the print() builtin function.
 
+use std::borrow::Cow;
+
 use crate::{
     args::{ArgValues, FromArgs},
-    bytecode::VM,
-    defer_drop,
+    bytecode::{CallResult, VM},
+    defer_drop, defer_drop_mut,
     exception_private::{ExcType, RunResult, SimpleException},
-    heap::HeapData,
+    heap::{DropWithHeap, HeapData},
+    intern::StaticStrings,
+    io::PrintStream,
     resource::ResourceTracker,
-    types::PyTrait,
-    value::Value,
+    types::{PyTrait, file::PendingFileEffect, str::allocate_string},
+    value::{Marker, Value},
 };
 
 /// Implementation of the print() builtin function.
@@ -16,13 +20,15 @@ use crate::{
 /// Supports the following keyword arguments:
 /// - `sep`: separator between values (default: " ")
 /// - `end`: string appended after the last value (default: "\n")
+/// - `file`: where to write — `None` or `sys.stdout` for the host's stdout,
+///   `sys.stderr` for its stderr, or any object with a `write()` method
+///   such as an `io.StringIO` or an open file
 /// - `flush`: whether to flush the stream (accepted but ignored — Monty
 ///   doesn't buffer stdout)
 ///
-/// The `file` keyword is recognised so it can produce a *specific* error
-/// (`"print() 'file' argument is not supported"`) rather than the generic
-/// "unexpected keyword" produced by leaving it off the struct.
-pub fn builtin_print(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+/// Writing to an open file goes through the host, so this returns the file's
+/// pending OS call rather than a value in that case.
+pub fn builtin_print(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
     let PrintArgs {
         objects,
         sep,
@@ -35,41 +41,97 @@ pub fn builtin_print(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) ->
     defer_drop!(end, vm);
     defer_drop!(file, vm);
 
-    if !matches!(file, Value::None) {
-        return Err(SimpleException::new_msg(ExcType::TypeError, "print() 'file' argument is not supported").into());
-    }
-
     let sep_str = extract_string_kwarg(sep, "sep", vm)?;
     let end_str = extract_string_kwarg(end, "end", vm)?;
 
+    // Output for the host's stdout is streamed to the print writer fragment by
+    // fragment. Output for `sys.stderr`, or for an object's `write()` (the `file`
+    // argument or a `contextlib.redirect_stdout` target), is collected and
+    // written in one call.
+    let to_stderr = matches!(file, Value::Marker(Marker(StaticStrings::Stderr)));
+    let target = match file {
+        Value::None | Value::Marker(Marker(StaticStrings::Stdout)) => {
+            vm.stdout_redirects.last().map(|target| target.clone_with_heap(vm))
+        }
+        _ if to_stderr => None,
+        target => Some(target.clone_with_heap(vm)),
+    };
+    defer_drop_mut!(target, vm);
+    let mut buffered = (to_stderr || target.is_some()).then(String::new);
+
     let mut first = true;
     for value in objects.as_slice() {
         if first {
             first = false;
         } else if let Some(sep) = &sep_str {
-            vm.print_writer.stdout_write(sep.as_str().into())?;
+            write_str(vm, &mut buffered, sep.as_str().into())?;
         } else {
-            vm.print_writer.stdout_push(' ')?;
+            write_char(vm, &mut buffered, ' ')?;
         }
         let s = value.py_str(vm)?;
-        vm.print_writer.stdout_write(s)?;
+        write_str(vm, &mut buffered, s)?;
     }
 
     if let Some(end) = end_str {
-        vm.print_writer.stdout_write(end.into())?;
+        write_str(vm, &mut buffered, end.into())?;
     } else {
-        vm.print_writer.stdout_push('\n')?;
+        write_char(vm, &mut buffered, '\n')?;
+    }
+
+    let Some(text) = buffered else {
+        return Ok(CallResult::Value(Value::None));
+    };
+    let Some(target) = target.take() else {
+        vm.print_writer
+            .tracked_write(PrintStream::Stderr, text.into(), vm.heap.tracker())?;
+        return Ok(CallResult::Value(Value::None));
+    };
+    let text = match allocate_string(text, vm.heap) {
+        Ok(text) => text,
+        Err(err) => {
+            target.drop_with_heap(vm);
+            return Err(err.into());
+        }
+    };
+    match vm.call_attr(target, StaticStrings::Write.into(), ArgValues::One(text))? {
+        CallResult::OsCall(call) => {
+            // A write to an open file: the host performs it, and the resumed
+            // call must return `None` rather than `write()`'s count.
+            if let Some(PendingFileEffect::WritePosition { returns_none, .. }) = &mut vm.pending_file_effect {
+                *returns_none = true;
+            }
+            Ok(CallResult::OsCall(call))
+        }
+        result => {
+            vm.evaluate_call_result("print()", result)?.drop_with_heap(vm);
+            Ok(CallResult::Value(Value::None))
+        }
+    }
+}
+
+/// Writes a fragment to the buffer if there is one, otherwise to the print writer.
+fn write_str(vm: &mut VM<'_, impl ResourceTracker>, buffered: &mut Option<String>, s: Cow<'_, str>) -> RunResult<()> {
+    match buffered {
+        Some(buf) => buf.push_str(&s),
+        None => vm.print_writer.tracked_stdout_write(s, vm.heap.tracker())?,
     }
+    Ok(())
+}
 
-    Ok(Value::None)
+/// Writes a single character to the buffer if there is one, otherwise to the print writer.
+fn write_char(vm: &mut VM<'_, impl ResourceTracker>, buffered: &mut Option<String>, c: char) -> RunResult<()> {
+    match buffered {
+        Some(buf) => buf.push(c),
+        None => vm.print_writer.tracked_stdout_push(c, vm.heap.tracker())?,
+    }
+    Ok(())
 }
 
 /// Argument shape for `print(*objects, sep=' ', end='\n', file=sys.stdout, flush=False)`.
 ///
 /// Every kwarg is held as a raw `Value` so the caller can do the
 /// "must be None or str" coercion inline, and so `flush` can be accepted
-/// without forcing a type check. Explicit `file` rejection lives in
-/// `builtin_print`.
+/// without forcing a type check. `file` is resolved in `builtin_print`.
 #[derive(FromArgs)]
 #[from_args(name = "print")]
 struct PrintArgs {
@@ -3,11 +3,14 @@
 use crate::{
     ExcType,
     args::ArgValues,
-    bytecode::VM,
+    bytecode::{CallResult, VM},
     defer_drop,
     exception_private::{RunResult, SimpleException},
     resource::ResourceTracker,
-    types::PyTrait,
+    types::{
+        HostHandle, PyTrait,
+        host_handle::{HandleEffect, HandleOp, HandleRequest},
+    },
     value::Value,
 };
 
@@ -16,12 +19,14 @@ use crate::{
 /// Sets the named attribute on the given object to the specified value
 /// This is the counterpart to getattr(). Returns None on success
 ///
+/// Setting an attribute of a host handle is left to the host.
+///
 /// Examples:
 /// ```python
 /// setattr(obj, 'x', 42)      # Set obj.x = 42
 /// setattr(obj, 'name', 'foo') # Set obj.name = 'foo'
 /// ```
-pub fn builtin_setattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+pub fn builtin_setattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
     let positional = args.into_pos_only("setattr", vm.heap)?;
     defer_drop!(positional, vm);
 
@@ -38,8 +43,14 @@ pub fn builtin_setattr(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -
         .into());
     };
 
+    if let Some(handle) = HostHandle::of(object, vm) {
+        let op = HandleOp::SetAttr(name, value.clone_with_heap(vm));
+        let request = HandleRequest::new(handle, op).with_effect(HandleEffect::ReturnNone);
+        return Ok(CallResult::HandleCall(request));
+    }
+
     // note: py_set_attr takes ownership of the inc-ref'd value and drops it on error
     object.py_set_attr(&name, value.clone_with_heap(vm), vm)?;
 
-    Ok(Value::None)
+    Ok(CallResult::Value(Value::None))
 }
@@ -2,15 +2,29 @@
 
 use super::Builtins;
 use crate::{
-    args::ArgValues, bytecode::VM, defer_drop, exception_private::RunResult, resource::ResourceTracker, types::PyTrait,
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::RunResult,
+    heap::HeapData,
+    resource::ResourceTracker,
+    types::{HostType, PyTrait, dataclass::class_of},
     value::Value,
 };
 
 /// Implementation of the type() builtin function.
 ///
-/// Returns the type of an object.
+/// Returns the type of an object; instances of sandbox-declared classes return their class,
+/// and host dataclass instances, named tuples and host handles a [`HostType`] describing theirs.
 pub fn builtin_type(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let value = args.get_one_arg("type", vm.heap)?;
     defer_drop!(value, vm);
+    if let Some(class_id) = class_of(value, vm) {
+        vm.heap.inc_ref(class_id);
+        return Ok(Value::Ref(class_id));
+    }
+    if let Some(host_type) = HostType::of(value, vm) {
+        return Ok(Value::Ref(vm.heap.allocate(HeapData::HostType(host_type))?));
+    }
     Ok(Value::Builtin(Builtins::Type(value.py_type(vm))))
 }
@@ -0,0 +1,46 @@
+//! Implementation of the vars() builtin function.
+
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::HeapData,
+    resource::ResourceTracker,
+    types::Dict,
+    value::Value,
+};
+
+/// Implementation of the vars() builtin function.
+///
+/// Without an argument, behaves like `locals()`. With an argument, returns a new
+/// dict of the object's attributes; only modules and dataclass instances have
+/// attribute dicts. The returned dict is a copy, so mutating it does not change
+/// the object.
+pub fn builtin_vars(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let Some(object) = args.get_zero_one_arg("vars", vm.heap)? else {
+        let bindings = vm.local_bindings();
+        return Ok(Value::Ref(vm.bindings_dict(bindings)?));
+    };
+    defer_drop!(object, vm);
+
+    let attrs = match object {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Dataclass(dc) => Some(dc.attrs()),
+            HeapData::Module(module) => Some(module.attrs()),
+            _ => None,
+        },
+        _ => None,
+    };
+    let Some(attrs) = attrs else {
+        return Err(
+            SimpleException::new_msg(ExcType::TypeError, "vars() argument must have __dict__ attribute").into(),
+        );
+    };
+    let pairs = attrs
+        .iter()
+        .map(|(key, value)| (key.clone_with_heap(vm), value.clone_with_heap(vm)))
+        .collect();
+    let dict = Dict::from_pairs(pairs, vm)?;
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Dict(dict))?))
+}
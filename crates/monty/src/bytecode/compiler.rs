@@ -23,14 +23,18 @@ use crate::{
     exception_private::ExcType,
     exception_public::{MontyException, SourceMap, StackFrame},
     expressions::{
-        AssignTarget, Callable, CmpOperator, Comprehension, DictItem, Expr, ExprLoc, Identifier, Literal, NameScope,
-        Node, Operator, PreparedFunctionDef, PreparedNode, SequenceItem, UnpackTarget,
+        AssignTarget, Callable, CmpOperator, Comprehension, DictItem, Expr, ExprLoc, Identifier, ImportName, Literal,
+        NameScope, Node, Operator, PreparedClassDef, PreparedFunctionDef, PreparedNode, SequenceItem, UnpackTarget,
     },
     fstring::{ConversionFlag, FStringPart, FormatSpec},
     function::Function,
     intern::{Interns, StringId},
     modules::StandardLib,
+    namespace::NamespaceId,
+    object::MontyObject,
     parse::{CodeRange, ExceptHandler, Try},
+    signature::Signature,
+    types::Type,
     value::{EitherStr, Value},
 };
 
@@ -295,6 +299,11 @@ pub struct Compiler<'a> {
     /// `RaiseUnboundLocal(name_id)`. The same comprehension's slots are
     /// removed at `exit_comprehension`, so sibling comps start fresh.
     bound_comp_slots: AHashSet<u16>,
+    /// Name of the supplied source module whose body is being compiled.
+    ///
+    /// A top-level `return` in the body ends it early, returning the module
+    /// object like reaching the end of the body does. `None` for any other code.
+    source_module: Option<StringId>,
 }
 
 /// Information about a loop for break/continue handling.
@@ -405,6 +414,7 @@ impl<'a> Compiler<'a> {
             frame_locals,
             slot_offsets: Vec::new(),
             bound_comp_slots: AHashSet::new(),
+            source_module: None,
         }
     }
 
@@ -413,12 +423,17 @@ impl<'a> Compiler<'a> {
     /// Returns the compiled module Code and all compiled Functions, or a compile
     /// error if limits were exceeded. The module implicitly returns the value
     /// of the last expression, or None if empty.
+    ///
+    /// `global_names` pairs every global slot with its name so
+    /// `globals()` can name slots the module body itself never references
+    /// (e.g. input names).
     pub fn compile_module(
         nodes: &[PreparedNode],
         interns: &Interns,
         namespace_size: usize,
+        global_names: &[(NamespaceId, StringId)],
     ) -> Result<CompileResult, CompileError> {
-        Self::compile_module_with_functions(nodes, interns, namespace_size, Vec::new())
+        Self::compile_module_with_functions(nodes, interns, namespace_size, global_names, Vec::new())
     }
 
     /// Compiles module-level code while preserving an existing function table prefix.
@@ -430,6 +445,7 @@ impl<'a> Compiler<'a> {
         nodes: &[PreparedNode],
         interns: &Interns,
         namespace_size: usize,
+        global_names: &[(NamespaceId, StringId)],
         existing_functions: Vec<Function>,
     ) -> Result<CompileResult, CompileError> {
         let num_locals = check_namespace_size_u16(namespace_size, "module")?;
@@ -437,6 +453,9 @@ impl<'a> Compiler<'a> {
         // `self.globals`), so comp-var offsets are emitted as plain operand-
         // stack indices.
         let mut compiler = Compiler::new(interns, existing_functions, true, 0);
+        for &(slot, name) in global_names {
+            compiler.code.register_local_name(slot.as_u16(), name);
+        }
         compiler.compile_block(nodes)?;
 
         // Module returns None if no explicit return
@@ -449,6 +468,59 @@ impl<'a> Compiler<'a> {
         })
     }
 
+    /// Compiles the body of a module supplied as a source file.
+    ///
+    /// The body runs in its own `<module>` frame whose return value is the module
+    /// object, so the import that pushed the frame receives the module like a call
+    /// result. A submodule first imports its `parent` package, then `InitModule`
+    /// creates the module object — or skips the body if importing the parent
+    /// already imported this module.
+    ///
+    /// Unlike [`compile_module`](Self::compile_module), the last expression is
+    /// not returned.
+    #[expect(clippy::too_many_arguments)]
+    pub fn compile_source_module(
+        nodes: &[PreparedNode],
+        interns: &Interns,
+        namespace_size: usize,
+        global_names: &[(NamespaceId, StringId)],
+        existing_functions: Vec<Function>,
+        module_index: u16,
+        module_name: StringId,
+        parent: Option<StringId>,
+        filename: StringId,
+    ) -> Result<CompileResult, CompileError> {
+        let num_locals = check_namespace_size_u16(namespace_size, "module")?;
+        let mut compiler = Compiler::new(interns, existing_functions, true, 0);
+        compiler.source_module = Some(module_name);
+        for &(slot, name) in global_names {
+            compiler.code.register_local_name(slot.as_u16(), name);
+        }
+        // Errors importing the parent package point at the start of the file
+        let file_start = CodeRange {
+            filename,
+            start_byte: 0,
+            end_byte: 0,
+        };
+        compiler.code.set_location(file_start, None);
+        if let Some(parent) = parent {
+            compiler.compile_load_module(parent)?;
+            compiler.code.emit(Opcode::Pop)?;
+        }
+        compiler.code.emit_u16(Opcode::InitModule, module_index)?;
+        let skip_body = compiler.code.emit_jump(Opcode::JumpIfFalse)?;
+        compiler.compile_block(nodes)?;
+        compiler.code.patch_jump(skip_body)?;
+
+        compiler.compile_load_module(module_name)?;
+        compiler.code.emit(Opcode::ReturnValue)?;
+
+        Ok(CompileResult {
+            code: compiler.code.build(num_locals),
+            functions: compiler.functions,
+        })
+    }
+
     /// Compiles a function body to bytecode, returning the Code and any nested functions.
     ///
     /// Used internally when compiling function definitions. The function body is
@@ -461,12 +533,18 @@ impl<'a> Compiler<'a> {
         body: &[PreparedNode],
         interns: &Interns,
         functions: Vec<Function>,
+        signature: &Signature,
         num_locals: u16,
     ) -> Result<(Code, Vec<Function>), CompileError> {
         // Function frames have `locals_count = num_locals` at runtime, so
         // comp-var load/store opcodes use `num_locals + offset` to skip past
         // the locals region into the operand-stack region.
         let mut compiler = Compiler::new(interns, functions, false, num_locals);
+        // Name the parameter slots up front so `locals()` sees parameters the
+        // body never reads.
+        for (slot, name) in (0..num_locals).zip(signature.param_names()) {
+            compiler.code.register_local_name(slot, name);
+        }
         compiler.compile_block(body)?;
 
         // Implicit return None if no explicit return
@@ -643,7 +721,7 @@ impl<'a> Compiler<'a> {
             } => self.compile_with(context, target.as_ref(), body)?,
             Node::Import { names } => {
                 for import_name in names {
-                    self.compile_import(import_name.module_name, &import_name.binding)?;
+                    self.compile_import(import_name)?;
                 }
             }
             Node::ImportFrom {
@@ -659,27 +737,67 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
-    /// Compiles a function definition.
+    /// Compiles a function definition and stores the function object to its name.
+    fn compile_function_def(&mut self, func_def: &PreparedFunctionDef) -> Result<(), CompileError> {
+        self.compile_make_function(func_def, "function")?;
+        self.compile_store(&func_def.name)
+    }
+
+    /// Compiles a lambda expression.
+    ///
+    /// Unlike `compile_function_def`, the function object is NOT stored to a name
+    /// slot: it stays on the stack as an expression result. The lambda's
+    /// `PreparedFunctionDef` already has `<lambda>` as its name.
+    fn compile_lambda(&mut self, func_def: &PreparedFunctionDef) -> Result<(), CompileError> {
+        self.compile_make_function(func_def, "lambda")
+    }
+
+    /// Compiles a function and emits the code creating it, leaving the function object on the stack.
     ///
     /// This involves:
     /// 1. Recursively compiling the function body to bytecode
     /// 2. Creating a Function struct with the compiled Code
     /// 3. Adding the Function to the compiler's functions vector
     /// 4. Emitting bytecode to evaluate defaults and create the function at runtime
-    fn compile_function_def(&mut self, func_def: &PreparedFunctionDef) -> Result<(), CompileError> {
+    ///
+    /// `kind` names the definition in namespace-size errors.
+    fn compile_make_function(
+        &mut self,
+        func_def: &PreparedFunctionDef,
+        kind: &'static str,
+    ) -> Result<(), CompileError> {
         let func_pos = func_def.name.position;
 
         // Bound the bytecode-operand counts before compiling — the `u8` casts
-        // below depend on these fitting in 255.
-        let defaults_count = check_call_args_u8(func_def.default_exprs.len(), "default parameter values", func_pos)?;
+        // below depend on these fitting in 255. The operand also counts the
+        // `__annotate__` function, which the VM subtracts again via
+        // `Function::has_annotate`, so it takes one slot from the defaults' limit.
+        let has_annotate = usize::from(func_def.annotate.is_some());
+        let default_count = func_def.default_exprs.len();
+        if default_count + has_annotate > MAX_CALL_ARGS {
+            return Err(CompileError::new(
+                format!(
+                    "more than {} default parameter values ({default_count})",
+                    MAX_CALL_ARGS - has_annotate
+                ),
+                func_pos,
+            ));
+        }
+        let defaults_count =
+            u8::try_from(default_count + has_annotate).expect("defaults count was checked against MAX_CALL_ARGS");
         let cell_count = check_call_args_u8(func_def.free_var_enclosing_slots.len(), "closure variables", func_pos)?;
 
         // 1. Compile the function body recursively
         // Take ownership of functions for the recursive compile, then restore
         let functions = mem::take(&mut self.functions);
-        let namespace_size = check_namespace_size_u16(func_def.namespace_size, "function")?;
-        let (body_code, mut functions) =
-            Self::compile_function_body(&func_def.body, self.interns, functions, namespace_size)?;
+        let namespace_size = check_namespace_size_u16(func_def.namespace_size, kind)?;
+        let (body_code, mut functions) = Self::compile_function_body(
+            &func_def.body,
+            self.interns,
+            functions,
+            &func_def.signature,
+            namespace_size,
+        )?;
 
         // 2. Create the compiled Function and add to the vector
         let func_id = functions.len();
@@ -691,6 +809,7 @@ impl<'a> Compiler<'a> {
             func_def.cell_var_count,
             func_def.cell_param_indices.clone(),
             func_def.default_exprs.len(),
+            func_def.annotate.is_some(),
             func_def.is_async,
             body_code,
         );
@@ -703,6 +822,11 @@ impl<'a> Compiler<'a> {
         for default_expr in &func_def.default_exprs {
             self.compile_expr(default_expr)?;
         }
+        // The `__annotate__` function is created now but only called when
+        // `__annotations__` is read; `Function::has_annotate` tells the VM to pop it.
+        if let Some(annotate) = &func_def.annotate {
+            self.compile_expr(annotate)?;
+        }
         let func_id_u16 = check_function_count_u16(func_id, func_pos)?;
 
         // 4. Emit MakeFunction or MakeClosure (if has free vars)
@@ -724,109 +848,66 @@ impl<'a> Compiler<'a> {
                 .emit_u16_u8_u8(Opcode::MakeClosure, func_id_u16, defaults_count, cell_count)?;
         }
 
-        // 5. Store the function object to its name slot
-        self.compile_store(&func_def.name)?;
-
         Ok(())
     }
 
-    /// Compiles a lambda expression.
-    ///
-    /// This is similar to `compile_function_def` but:
-    /// - Does NOT store the function to a name slot (it stays on the stack as an expression result)
-    ///
-    /// The lambda's `PreparedFunctionDef` already has `<lambda>` as its name.
-    fn compile_lambda(&mut self, func_def: &PreparedFunctionDef) -> Result<(), CompileError> {
-        let func_pos = func_def.name.position;
-
-        // Bound the bytecode-operand counts before compiling — the `u8` casts
-        // below depend on these fitting in 255.
-        let defaults_count = check_call_args_u8(func_def.default_exprs.len(), "default parameter values", func_pos)?;
-        let cell_count = check_call_args_u8(func_def.free_var_enclosing_slots.len(), "closure variables", func_pos)?;
-
-        // 1. Compile the function body recursively
-        let functions = mem::take(&mut self.functions);
-        let namespace_size = check_namespace_size_u16(func_def.namespace_size, "lambda")?;
-        let (body_code, mut functions) =
-            Self::compile_function_body(&func_def.body, self.interns, functions, namespace_size)?;
-
-        // 2. Create the compiled Function and add to the vector
-        let func_id = functions.len();
-        let function = Function::new(
-            func_def.name,
-            func_def.signature.clone(),
-            func_def.namespace_size,
-            func_def.free_var_enclosing_slots.clone(),
-            func_def.cell_var_count,
-            func_def.cell_param_indices.clone(),
-            func_def.default_exprs.len(),
-            func_def.is_async,
-            body_code,
-        );
-        functions.push(function);
-
-        // Restore functions to self
-        self.functions = functions;
-
-        // 3. Compile and push default values (evaluated at definition time)
-        for default_expr in &func_def.default_exprs {
-            self.compile_expr(default_expr)?;
-        }
-        let func_id_u16 = check_function_count_u16(func_id, func_pos)?;
-
-        // 4. Emit MakeFunction or MakeClosure (if has free vars)
-        if func_def.free_var_enclosing_slots.is_empty() {
-            // MakeFunction: func_id (u16) + defaults_count (u8)
-            self.code
-                .emit_u16_u8(Opcode::MakeFunction, func_id_u16, defaults_count)?;
-        } else {
-            // Push captured cells from enclosing scope. `slot` is a
-            // `NamespaceId` from the enclosing scope, bounded by
-            // `check_namespace_size_u16`; the conversion is an invariant.
-            for &slot in &func_def.free_var_enclosing_slots {
-                self.code.emit_load_local(slot.as_u16())?;
+    /// Compiles a class definition, leaving the class object on the stack.
+    ///
+    /// Pushes `name, annotation, default` for each field (`Value::Undefined` when the
+    /// field has no default) and `name, function` for each method, then emits
+    /// `BuildClass`.
+    fn compile_class(&mut self, class_def: &PreparedClassDef, position: CodeRange) -> Result<(), CompileError> {
+        let field_count = check_call_args_u8(class_def.fields.len(), "class fields", position)?;
+        let method_count = check_call_args_u8(class_def.methods.len(), "class methods", position)?;
+        let name_idx = check_name_index_u16(class_def.name_id, position)?;
+
+        for field in &class_def.fields {
+            self.compile_literal(&Literal::Str(field.name))?;
+            self.compile_expr(&field.annotation)?;
+            if let Some(default) = &field.default {
+                self.compile_expr(default)?;
+            } else {
+                let idx = self.code.add_const(Value::Undefined)?;
+                self.code.emit_u16(Opcode::LoadConst, idx)?;
             }
-            // MakeClosure: func_id (u16) + defaults_count (u8) + cell_count (u8)
-            self.code
-                .emit_u16_u8_u8(Opcode::MakeClosure, func_id_u16, defaults_count, cell_count)?;
+        }
+        for method in &class_def.methods {
+            self.compile_literal(&Literal::Str(method.name.name_id))?;
+            self.compile_make_function(method, "method")?;
         }
 
-        // NOTE: Unlike compile_function_def, we do NOT call compile_store here.
-        // The function object stays on the stack as an expression result.
-
-        Ok(())
+        self.code.set_location(position, None);
+        self.code
+            .emit_u16_u8_u8(Opcode::BuildClass, name_idx, field_count, method_count)
     }
 
     /// Compiles an import statement.
     ///
-    /// Emits `LoadModule` to create the module, then stores it to the binding name.
-    /// If the module is unknown, emits `RaiseImportError` to defer the error to runtime.
-    /// This allows imports inside `if TYPE_CHECKING:` blocks to compile successfully.
-    fn compile_import(&mut self, module_name: StringId, binding: &Identifier) -> Result<(), CompileError> {
-        let position = binding.position;
-        self.code.set_location(position, None);
+    /// Emits `LoadModule` for a standard library module, or `LoadHostModule` for
+    /// any other, then stores the module to the binding name. Unknown modules only
+    /// fail when the import runs, so imports inside `if TYPE_CHECKING:` blocks
+    /// compile successfully.
+    ///
+    /// For `import a.b`, loads `a.b` to check it exists, then binds the top-level
+    /// package `a`.
+    fn compile_import(&mut self, import_name: &ImportName) -> Result<(), CompileError> {
+        let binding = &import_name.binding;
+        self.code.set_location(binding.position, None);
 
-        // Look up the module by name
-        if let Some(builtin_module) = StandardLib::from_string_id(module_name) {
-            // Known module - emit LoadModule
-            self.code.emit_u8(Opcode::LoadModule, builtin_module as u8)?;
-            // Store to the binding (respects Local/Global/Cell scope)
-            self.compile_store(binding)?;
-        } else {
-            // Unknown module - defer error to runtime with RaiseImportError
-            // This allows TYPE_CHECKING imports to compile without error
-            let name_const = self.code.add_const(Value::InternString(module_name))?;
-            self.code.emit_u16(Opcode::RaiseImportError, name_const)?;
+        self.compile_load_module(import_name.module_name)?;
+        if import_name.bind_top_level {
+            self.code.emit(Opcode::Pop)?;
+            self.compile_load_module(binding.name_id)?;
         }
-        Ok(())
+        // Store to the binding (respects Local/Global/Cell scope)
+        self.compile_store(binding)
     }
 
     /// Compiles a `from module import name, ...` statement.
     ///
-    /// Creates the module once, then loads each attribute and stores to the binding.
-    /// Invalid attribute names will raise `AttributeError` at runtime.
-    /// If the module is unknown, emits `RaiseImportError` to defer the error to runtime.
-    /// This allows imports inside `if TYPE_CHECKING:` blocks to compile successfully.
+    /// Loads the module once, then loads each attribute and stores to the binding.
+    /// Invalid attribute names will raise `ImportError` at runtime, as will an
+    /// unknown module (see [`compile_import`](Self::compile_import)).
     fn compile_import_from(
         &mut self,
         module_name: StringId,
@@ -834,33 +915,35 @@ impl<'a> Compiler<'a> {
         position: CodeRange,
     ) -> Result<(), CompileError> {
         self.code.set_location(position, None);
+        self.compile_load_module(module_name)?;
 
-        // Look up the module
-        if let Some(builtin_module) = StandardLib::from_string_id(module_name) {
-            // Known module - emit LoadModule
-            self.code.emit_u8(Opcode::LoadModule, builtin_module as u8)?;
-
-            // For each name to import
-            for (i, (import_name, binding)) in names.iter().enumerate() {
-                // Dup the module if this isn't the last import (last one consumes the module)
-                if i < names.len() - 1 {
-                    self.code.emit(Opcode::Dup)?;
-                }
+        // For each name to import
+        for (i, (import_name, binding)) in names.iter().enumerate() {
+            // Dup the module if this isn't the last import (last one consumes the module)
+            if i < names.len() - 1 {
+                self.code.emit(Opcode::Dup)?;
+            }
 
-                // Load the attribute from the module (raises ImportError if not found)
-                let name_idx = check_name_index_u16(*import_name, position)?;
-                self.code.emit_u16(Opcode::LoadAttrImport, name_idx)?;
+            // Load the attribute from the module (raises ImportError if not found)
+            let name_idx = check_name_index_u16(*import_name, position)?;
+            self.code.emit_u16(Opcode::LoadAttrImport, name_idx)?;
 
-                // Store to the binding
-                self.compile_store(binding)?;
-            }
+            // Store to the binding
+            self.compile_store(binding)?;
+        }
+        Ok(())
+    }
+
+    /// Emits the load of a module onto the stack: `LoadModule` for a standard
+    /// library module, otherwise `LoadHostModule` to look it up among the
+    /// supplied source files and host-defined modules at runtime.
+    fn compile_load_module(&mut self, module_name: StringId) -> Result<(), CompileError> {
+        if let Some(builtin_module) = StandardLib::from_string_id(module_name) {
+            self.code.emit_u8(Opcode::LoadModule, builtin_module as u8)
         } else {
-            // Unknown module - defer error to runtime with RaiseImportError
-            // This allows TYPE_CHECKING imports to compile without error
             let name_const = self.code.add_const(Value::InternString(module_name))?;
-            self.code.emit_u16(Opcode::RaiseImportError, name_const)?;
+            self.code.emit_u16(Opcode::LoadHostModule, name_const)
         }
-        Ok(())
     }
 
     // ========================================================================
@@ -1125,6 +1208,15 @@ impl<'a> Compiler<'a> {
                 unreachable!("Expr::LambdaRaw should not exist after prepare phase")
             }
 
+            Expr::Class(class_def) => {
+                self.compile_class(class_def, expr_loc.position)?;
+            }
+
+            Expr::ClassRaw(_) => {
+                // ClassRaw should be converted to Class during prepare phase
+                unreachable!("Expr::ClassRaw should not exist after prepare phase")
+            }
+
             Expr::Await(value) => {
                 // Await expressions: compile the inner expression, then emit Await
                 // Await handles ExternalFuture, Coroutine, and GatherFuture
@@ -1185,6 +1277,17 @@ impl<'a> Compiler<'a> {
                     self.code.emit_u16(Opcode::LoadConst, idx)
                 }
             }
+            // Complex values are heap-allocated, so build `complex(0.0, imag)` at runtime
+            Literal::Imaginary(imag) => {
+                let real_idx = self.code.add_const(Value::Float(0.0))?;
+                self.code.emit_u16(Opcode::LoadConst, real_idx)?;
+                let imag_idx = self.code.add_const(Value::Float(*imag))?;
+                self.code.emit_u16(Opcode::LoadConst, imag_idx)?;
+                let type_id = Type::Complex
+                    .callable_to_u8()
+                    .expect("complex is a callable builtin type");
+                self.code.emit_call_builtin_type(type_id, 2)
+            }
             // For Float, Str, Bytes, Ellipsis - use LoadConst with Value::from
             _ => {
                 let idx = self.code.add_const(Value::from(*literal))?;
@@ -1214,8 +1317,8 @@ impl<'a> Compiler<'a> {
                 }
             }
             NameScope::Global => {
-                // Register the name for NameError/NameLookup messages
-                self.code.register_local_name(slot, ident.name_id);
+                // Not registered here: global slots are a different namespace from this
+                // code's local slots. The VM names globals from the module code instead.
                 self.code.emit_u16(Opcode::LoadGlobal, slot)
             }
             NameScope::Cell => {
@@ -1290,6 +1393,8 @@ impl<'a> Compiler<'a> {
             }
             NameScope::Global => self.code.emit_u16(Opcode::StoreGlobal, slot),
             NameScope::Cell => {
+                // Register the name so `locals()` sees cells the function never reads itself
+                self.code.register_local_name(slot, target.name_id);
                 // Emit local slot index — the VM reads the cell HeapId from the stack
                 self.code.emit_u16(Opcode::StoreCell, slot)
             }
@@ -3173,7 +3278,14 @@ impl<'a> Compiler<'a> {
     ///
     /// `expr` is the expression after `return` (`None` for a bare `return`).
     fn compile_return(&mut self, expr: Option<&ExprLoc>) -> Result<(), CompileError> {
-        if let Some(expr) = expr {
+        if let Some(module_name) = self.source_module {
+            // Only the module object is returned to the import
+            if let Some(expr) = expr {
+                self.compile_expr(expr)?;
+                self.code.emit(Opcode::Pop)?;
+            }
+            self.compile_load_module(module_name)?;
+        } else if let Some(expr) = expr {
             self.compile_expr(expr)?;
         } else {
             self.code.emit(Opcode::LoadNone)?;
@@ -3783,7 +3895,14 @@ impl CompileError {
         if self.exc_type == ExcType::ModuleNotFoundError {
             frame.hide_caret = true;
         }
-        MontyException::new_full(self.exc_type, Some(self.message.into_owned()), vec![frame])
+        let message = self.message.into_owned();
+        MontyException::new_full(
+            self.exc_type,
+            Some(message.clone()),
+            vec![MontyObject::String(message)],
+            vec![],
+            vec![frame],
+        )
     }
 }
 
@@ -356,9 +356,11 @@ pub enum Opcode {
     ForIter,
 
     // === Function Definition ===
-    /// Create function object. Operand: u16 func_id.
+    /// Create function object. Operands: u16 func_id, u8 count of defaults plus the
+    /// `__annotate__` function when the function has one.
     MakeFunction,
-    /// Create closure. Operands: u16 func_id, u8 cell_count.
+    /// Create closure. Operands: u16 func_id, u8 defaults count (as for `MakeFunction`),
+    /// u8 cell_count.
     MakeClosure,
 
     // === Exception Handling ===
@@ -520,6 +522,32 @@ pub enum Opcode {
     /// available — we synthesise the equivalent by peeking the receiver.
     /// Appended at the end to preserve the serialized byte values of all older opcodes.
     MethodDictMerge,
+    /// Build a class object. Operands: u16 name_id, u8 field_count, u8 method_count.
+    ///
+    /// Stack: [..., (name, annotation, default) * field_count, (name, function) * method_count]
+    ///     -> [..., class]
+    ///
+    /// Names are interned strings. A field without a default has `Value::Undefined`
+    /// in the default position.
+    /// Appended at the end to preserve the serialized byte values of all older opcodes.
+    BuildClass,
+    /// Load a module that isn't part of the standard library. Operand: u16 constant
+    /// index for the module name.
+    ///
+    /// Looks the module up among the host-defined modules; if the host hasn't
+    /// declared it, yields `FrameExit::ModuleLookup` so the host can supply it or
+    /// let the VM raise `ModuleNotFoundError`. Replaces `RaiseImportError` for
+    /// non-stdlib imports; that opcode stays so older serialized code still runs.
+    /// Appended at the end to preserve the serialized byte values of all older opcodes.
+    LoadHostModule,
+    /// Start running the body of a module supplied as a source file. Operand: u16
+    /// index of the module in the interns' source-module table.
+    ///
+    /// Creates and caches the module object and pushes `True` if the module hasn't
+    /// been imported yet, otherwise pushes `False` so the body is skipped. The body
+    /// can already have run when importing the module's parent package imported it.
+    /// Appended at the end to preserve the serialized byte values of all older opcodes.
+    InitModule,
 }
 
 impl TryFrom<u8> for Opcode {
@@ -633,6 +661,8 @@ impl Opcode {
             // MakeClosure: pops `cell_count` cells AND `defaults_count` defaults,
             // pushes the closure → 1 - defaults - cells.
             (MakeClosure, Operand::U16U8U8(_, defaults, cells)) => 1 - i16::from(defaults) - i16::from(cells),
+            // BuildClass: pops three values per field and two per method, pushes the class.
+            (BuildClass, Operand::U16U8U8(_, fields, methods)) => 1 - 3 * i16::from(fields) - 2 * i16::from(methods),
 
             // === Variable-effect: variable-length kw operands ===
             // pops callable + pos_args + kw_args, pushes result → -(pos_count + kw_count).
@@ -726,6 +756,10 @@ impl Opcode {
             (DictMerge | MethodDictMerge, Operand::U16(_)) => -1,
             // `RaiseImportError` takes a u16 const_id naming the missing module.
             (RaiseImportError, Operand::U16(_)) => 0,
+            // `LoadHostModule` takes a u16 const_id naming the module and pushes it.
+            (LoadHostModule, Operand::U16(_)) => 1,
+            // `InitModule` takes a u16 source-module index and pushes whether to run the body.
+            (InitModule, Operand::U16(_)) => 1,
             // `RaiseUnboundLocal(name_id)` always raises — fall-through is dead
             // code, but the tracker absorbs the bytes with effect 0 before the
             // following region starts.
@@ -798,7 +832,7 @@ mod tests {
     #[test]
     fn test_opcode_roundtrip() {
         // Verify that all opcodes from 0 to the last opcode can be converted to u8 and back.
-        for byte in 0..=Opcode::MethodDictMerge as u8 {
+        for byte in 0..=Opcode::InitModule as u8 {
             let opcode = Opcode::try_from(byte).unwrap();
             assert_eq!(opcode as u8, byte, "opcode {opcode:?} has wrong discriminant");
         }
@@ -825,12 +859,18 @@ mod tests {
         // Method-call duplicate-kwarg qualifier; sister to `DictMerge` but appended at the
         // tail so older opcode bytes keep their discriminants.
         assert_eq!(Opcode::MethodDictMerge as u8, 118);
+        // Class construction for `@dataclass` class statements.
+        assert_eq!(Opcode::BuildClass as u8, 119);
+        // Imports of host-defined modules.
+        assert_eq!(Opcode::LoadHostModule as u8, 120);
+        // Module bodies of source files supplied by the host.
+        assert_eq!(Opcode::InitModule as u8, 121);
     }
 
     #[test]
     fn test_invalid_opcode() {
         // Byte just after the last valid opcode should fail
-        let result = Opcode::try_from(Opcode::MethodDictMerge as u8 + 1);
+        let result = Opcode::try_from(Opcode::InitModule as u8 + 1);
         assert!(result.is_err());
         // 255 should also fail
         let result = Opcode::try_from(255u8);
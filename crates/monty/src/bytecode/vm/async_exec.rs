@@ -540,6 +540,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                         function_id: sf.function_id,
                         call_position: sf.call_position,
                         should_return: false,
+                        callback: sf.callback,
                     }
                 })
                 .collect();
@@ -799,7 +800,16 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
     /// host resolutions can find the heap entry; the `Value::Ref` pushed onto
     /// the stack is the user's reference, which travels with the value until
     /// it's awaited or dropped.
+    ///
+    /// Host handle operations whose result the VM has to check, like `len(h)`,
+    /// can't be resolved with a future and raise `TypeError`. The callbacks passed
+    /// to the call are released.
     pub fn add_pending_call(&mut self, call_id: CallId) -> RunResult<()> {
+        self.release_callbacks();
+        if let Some(effect) = self.pending_handle_effect.take() {
+            effect.drop_with_heap(self);
+            return Err(ExcType::type_error("this host handle operation can't return a future"));
+        }
         let future_id = self
             .heap
             .allocate(HeapData::ExternalFuture(ExternalFuture::new_pending(call_id)))?;
@@ -819,7 +829,8 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
     /// 1. Resolve or fail each future from the provided results
     /// 2. Attempt to resume the current task (or fail it if any future resolution caused it to fail)
     /// 3. Load a ready task if needed (current task still blocked)
-    /// 4. If no task is ready, return `ResolveFutures` with remaining pending call IDs
+    /// 4. If no task is ready, stop on a pending host interrupt, otherwise return
+    ///    `ResolveFutures` with remaining pending call IDs
     pub fn resume_with_resolved_futures(&mut self, results: Vec<(u32, ExtFunctionResult)>) -> RunResult<FrameExit> {
         for (call_id, ext_result) in results {
             match ext_result {
@@ -836,6 +847,11 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 ExtFunctionResult::NotFound(function_name) => {
                     self.fail_future(call_id, ExtFunctionResult::not_found_exc(&function_name))?;
                 }
+                // A resolved future's call is no longer paused, so there's no frame to run a callback on.
+                ExtFunctionResult::Callback { .. } => {
+                    let msg = format!("cannot call a callback to resolve the future for call {call_id}");
+                    self.fail_future(call_id, SimpleException::new(ExcType::TypeError, Some(msg)).into())?;
+                }
             }
         }
 
@@ -879,6 +895,13 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
             return self.run();
         }
 
+        // Every task is still waiting on the host, so no code runs that could pick up
+        // an interrupt: stop here instead of suspending again. There is no running
+        // task to raise into, so even a catchable interrupt ends execution.
+        if let Err(err) = self.heap.check_interrupt() {
+            return Err(RunError::UncatchableExc(err.into_exception(None)));
+        }
+
         let pending_call_ids = self.get_pending_call_ids();
 
         assert!(
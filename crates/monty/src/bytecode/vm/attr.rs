@@ -5,9 +5,10 @@ use crate::{
     bytecode::vm::CallResult,
     defer_drop,
     exception_private::{ExcType, RunError},
+    heap::HeapData,
     intern::StringId,
     resource::ResourceTracker,
-    value::EitherStr,
+    value::{EitherStr, Value},
 };
 
 impl<T: ResourceTracker> VM<'_, T> {
@@ -27,7 +28,9 @@ impl<T: ResourceTracker> VM<'_, T> {
     /// Loads an attribute from a module for `from ... import` and pushes it onto the stack.
     ///
     /// Returns an ImportError (not AttributeError) if the attribute doesn't exist,
-    /// matching CPython's behavior for `from module import name`.
+    /// matching CPython's behavior for `from module import name`. For a package
+    /// supplied as source files, a missing attribute naming one of its submodules
+    /// imports the submodule, like `from pkg import submodule` in CPython.
     pub(super) fn load_attr_import(&mut self, name_id: StringId) -> Result<CallResult, RunError> {
         let this = self;
 
@@ -38,6 +41,9 @@ impl<T: ResourceTracker> VM<'_, T> {
         match obj.py_getattr(&attr, this) {
             Ok(result) => Ok(result),
             Err(RunError::Exc(exc)) if exc.exc.exc_type() == ExcType::AttributeError => {
+                if let Some(submodule) = this.source_submodule(obj, name_id) {
+                    return this.import_source_module(submodule);
+                }
                 // Only compute module_name when we need it for the error message
                 let module_name = obj.module_name(this);
                 let name_str = this.interns.get_str(name_id);
@@ -47,6 +53,18 @@ impl<T: ResourceTracker> VM<'_, T> {
         }
     }
 
+    /// Returns the index of the supplied source module `<obj>.<name>`, if `obj` is a
+    /// module supplied as a source file.
+    fn source_submodule(&self, obj: &Value, name_id: StringId) -> Option<u16> {
+        let Value::Ref(id) = obj else { return None };
+        let HeapData::Module(module) = self.heap.get(*id) else {
+            return None;
+        };
+        let package = &self.interns.source_module(module.source()?).name;
+        let name = format!("{package}.{}", self.interns.get_str(name_id));
+        self.interns.find_source_module(&name)
+    }
+
     /// Stores a value as an attribute on an object.
     ///
     /// Returns an AttributeError if the attribute cannot be set.
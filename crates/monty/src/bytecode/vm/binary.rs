@@ -6,7 +6,7 @@ use crate::{
     exception_private::{ExcType, RunError},
     heap::{HeapData, HeapGuard, HeapReadOutput},
     resource::ResourceTracker,
-    types::{PyTrait, Set, dict_view::collect_iterable_to_set, set::SetBinaryOp},
+    types::{PyTrait, Set, dict_view::collect_iterable_to_set, set::SetBinaryOp, typing_forms::union_operands},
     value::{BitwiseOp, Value},
 };
 
@@ -263,7 +263,7 @@ impl<T: ResourceTracker> VM<'_, T> {
         Ok(())
     }
 
-    /// Binary `|` with CPython-style dict-view handling before numeric fallback.
+    /// Binary `|` with CPython-style dict-view and type-union handling before numeric fallback.
     pub(super) fn binary_or(&mut self) -> Result<(), RunError> {
         let this = self;
 
@@ -282,6 +282,12 @@ impl<T: ResourceTracker> VM<'_, T> {
             return Ok(());
         }
 
+        // `int | None` style type unions
+        if let Some(result) = union_operands(lhs, rhs, this)? {
+            this.push(result);
+            return Ok(());
+        }
+
         let result = lhs.py_bitwise(rhs, BitwiseOp::Or, this)?;
         this.push(result);
         Ok(())
@@ -17,10 +17,20 @@ use crate::{
     heap::{ContainsHeap, DropWithHeap, HeapData, HeapGuard, HeapId},
     heap_data::CellValue,
     intern::{FunctionId, StaticStrings, StringId},
+    io::PrintStream,
+    modules::sys,
     os::OsFunctionCall,
     resource::ResourceTracker,
-    types::{Dict, PyTrait, Type, bytes::call_bytes_method, str::call_str_method},
-    value::{EitherStr, Value},
+    types::{
+        Dict, PyTrait, Type,
+        bytes::call_bytes_method,
+        dataclass_type::instantiate,
+        float::call_float_method,
+        host_handle::{HandleOp, HandleRequest},
+        int::{IntReceiver, call_int_method},
+        str::call_str_method,
+    },
+    value::{EitherStr, Marker, Value},
 };
 
 /// Result of executing a call or attribute method.
@@ -75,6 +85,11 @@ pub(crate) enum CallResult {
     /// `OpenFile` itself (in `pending_read`), so this variant only needs to
     /// carry the typed call plus the file id used to look up the buffer slot.
     OsCallStoreBuffer { call: OsFunctionCall, file_id: HeapId },
+    /// Operation on a host handle - VM should yield `FrameExit::HandleCall` to host.
+    ///
+    /// The host performs the operation on the object behind the handle and resumes
+    /// the VM with the result.
+    HandleCall(HandleRequest),
 }
 
 impl DropWithHeap for CallResult {
@@ -85,6 +100,7 @@ impl DropWithHeap for CallResult {
                 args.drop_with_heap(heap);
             }
             Self::OsCall(call) => call.drop_with_heap(heap),
+            Self::HandleCall(request) => request.drop_with_heap(heap),
             Self::FramePushed => {}
             Self::OsCallStoreBuffer { call, file_id } => {
                 call.drop_with_heap(heap);
@@ -138,11 +154,12 @@ impl<T: ResourceTracker> VM<'_, T> {
     ///
     /// Calls a builtin type constructor directly without stack manipulation for the callable.
     /// This is an optimization for type constructors like `list()`, `int()`, `str()`.
-    pub(super) fn exec_call_builtin_type(&mut self, type_id: u8, arg_count: usize) -> Result<Value, RunError> {
+    pub(super) fn exec_call_builtin_type(&mut self, type_id: u8, arg_count: usize) -> Result<CallResult, RunError> {
         // Convert u8 to Type via callable_from_u8
         if let Some(t) = Type::callable_from_u8(type_id) {
             let args = self.pop_n_args(arg_count);
-            t.call(self, args)
+            // Through `Builtins` so `bool(h)` and `iter(h)` on a host handle can yield
+            Builtins::Type(t).call(self, args)
         } else {
             Err(RunError::internal("CallBuiltinType: invalid type_id"))
         }
@@ -306,6 +323,7 @@ impl<T: ResourceTracker> VM<'_, T> {
     ///
     /// For interned strings (`Value::InternString`), uses the unified `call_str_method`.
     /// For interned bytes (`Value::InternBytes`), uses the unified `call_bytes_method`.
+    /// For the `sys.stdout` / `sys.stderr` markers, uses `sys::call_stream_method`.
     ///
     /// **Dunder dispatch**: before reaching the type-specific dispatcher, this
     /// method intercepts known dunder names (`__enter__`, `__exit__`, …) and
@@ -316,7 +334,7 @@ impl<T: ResourceTracker> VM<'_, T> {
     /// override only need a single trait impl, not parallel `StaticStrings::Foo`
     /// arms in their `py_call_attr` body. New dunder methods plug into the
     /// dispatch table here without touching individual types.
-    fn call_attr(&mut self, obj: Value, name_id: StringId, args: ArgValues) -> Result<CallResult, RunError> {
+    pub(crate) fn call_attr(&mut self, obj: Value, name_id: StringId, args: ArgValues) -> Result<CallResult, RunError> {
         let this = self;
         let attr = EitherStr::Interned(name_id);
 
@@ -352,6 +370,19 @@ impl<T: ResourceTracker> VM<'_, T> {
                 // Handle classmethods on type objects like dict.fromkeys()
                 t.call_class_method(name_id, args, this).map(Into::into)
             }
+            Value::Int(i) => {
+                call_int_method(IntReceiver::Small(i), Type::Int, &attr, args, this).map(CallResult::Value)
+            }
+            Value::Bool(b) => {
+                call_int_method(IntReceiver::Small(i64::from(b)), Type::Bool, &attr, args, this).map(CallResult::Value)
+            }
+            Value::Float(f) => call_float_method(f, &attr, args, this).map(CallResult::Value),
+            Value::Marker(Marker(StaticStrings::Stdout)) => {
+                sys::call_stream_method(this, PrintStream::Stdout, name_id, args)
+            }
+            Value::Marker(Marker(StaticStrings::Stderr)) => {
+                sys::call_stream_method(this, PrintStream::Stderr, name_id, args)
+            }
             _ => {
                 // Non-heap values without method support
                 let type_name = obj.py_type(this);
@@ -374,7 +405,32 @@ impl<T: ResourceTracker> VM<'_, T> {
         callable: &Value,
         args: ArgValues,
     ) -> Result<Value, RunError> {
-        match self.call_function(callable, args)? {
+        let result = self.call_function(callable, args)?;
+        self.evaluate_call_result(ctx, result)
+    }
+
+    /// Calls `obj.<name_id>(*args)` in a position that doesn't yet support suspending.
+    ///
+    /// The method counterpart of [`evaluate_function`](Self::evaluate_function), used by
+    /// built-ins that invoke protocol methods such as `write()` or `close()` on
+    /// arbitrary objects. Takes ownership of `obj`.
+    pub(crate) fn evaluate_method(
+        &mut self,
+        ctx: &'static str,
+        obj: Value,
+        name_id: StringId,
+        args: ArgValues,
+    ) -> Result<Value, RunError> {
+        let result = self.call_attr(obj, name_id, args)?;
+        self.evaluate_call_result(ctx, result)
+    }
+
+    /// Runs a `CallResult` to completion, returning the value it produces.
+    ///
+    /// A pushed frame is run until it returns; external and OS calls can't be
+    /// resolved synchronously and raise `NotImplementedError`.
+    pub(crate) fn evaluate_call_result(&mut self, ctx: &'static str, result: CallResult) -> Result<Value, RunError> {
+        match result {
             CallResult::Value(v) => return Ok(v),
             CallResult::FramePushed => {
                 // A new frame was pushed for a defined function call - we need to run it
@@ -452,6 +508,22 @@ impl<T: ResourceTracker> VM<'_, T> {
                 let name = name.clone();
                 return Ok(CallResult::External(EitherStr::Heap(name), args));
             }
+            HeapData::NewType(new_type) => {
+                // `NewType` is the identity function at runtime
+                let name = new_type.name().to_owned();
+                return Ok(CallResult::Value(args.get_one_arg(&name, self.heap)?));
+            }
+            HeapData::TypedDict(_) => {
+                // A `TypedDict` instance is just a plain dict
+                return Type::Dict.call(self, args).map(CallResult::Value);
+            }
+            HeapData::DataclassType(_) => {
+                return instantiate(heap_id, args, self).map(CallResult::Value);
+            }
+            HeapData::HostHandle(handle) => {
+                let request = HandleRequest::new(handle.clone(), HandleOp::Call(args));
+                return Ok(CallResult::HandleCall(request));
+            }
             _ => {
                 args.drop_with_heap(self);
                 return Err(ExcType::type_error("object is not callable"));
@@ -575,7 +647,7 @@ impl<T: ResourceTracker> VM<'_, T> {
     }
 
     /// Builds `ArgValues` from positional args only.
-    fn build_args_positional_only(copied_args: Vec<Value>) -> ArgValues {
+    pub(super) fn build_args_positional_only(copied_args: Vec<Value>) -> ArgValues {
         match copied_args.len() {
             0 => ArgValues::Empty,
             1 => ArgValues::One(copied_args.into_iter().next().unwrap()),
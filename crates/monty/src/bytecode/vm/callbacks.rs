@@ -0,0 +1,316 @@
+//! Calls from the host back into sandbox functions passed to external functions.
+//!
+//! Only hosts that opt in with [`MontyRun::with_callbacks`](crate::MontyRun::with_callbacks)
+//! get callbacks; otherwise a sandbox function reaches the host as its repr. A sandbox
+//! function passed to the host becomes a [`MontyObject::Callback`] whose id is registered
+//! in [`VM::callbacks`] until the external call it was passed to returns. While that call
+//! is paused, the host can call it with
+//! [`ExtFunctionResult::Callback`](crate::ExtFunctionResult::Callback): the function's
+//! frame is pushed on top of the paused call's frame and marked as a callback frame,
+//! and when it returns or raises the VM exits with [`FrameExit::CallbackResult`],
+//! leaving the paused call as it was.
+
+use std::mem;
+
+use ahash::AHashMap;
+
+use super::{FrameExit, VM};
+use crate::{
+    MontyObject,
+    args::{ArgValues, KwargsValues},
+    bytecode::CallResult,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{ContainsHeap, DropWithHeap, HeapData},
+    object::InvalidInputError,
+    resource::{ResourceError, ResourceTracker},
+    types::{Dict, file::PendingFileEffect, host_handle::HandleEffect},
+    value::Value,
+};
+
+/// Sandbox functions passed to the host as callbacks.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Callbacks {
+    /// Whether the host opted in to callbacks.
+    enabled: bool,
+    /// Registered functions by callback id.
+    functions: AHashMap<u32, Callback>,
+    /// Callback ids by the `id()` of their function, so passing a function again reuses its id.
+    ids: AHashMap<usize, u32>,
+    /// Id for the next function registered.
+    next_id: u32,
+    /// Error from charging a registration to the resource tracker, reported once the
+    /// arguments have been converted.
+    #[serde(skip)]
+    error: Option<ResourceError>,
+}
+
+/// A registered callback.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Callback {
+    /// Reference to the function, keeping its `id()` unique while it's registered.
+    function: Value,
+    /// `id()` of the function, its key in [`Callbacks::ids`].
+    key: usize,
+    /// Number of paused calls when it was registered. It's released when the external
+    /// call paused at that depth returns.
+    depth: usize,
+}
+
+impl Callback {
+    /// Approximate memory charged to the resource tracker for a registration.
+    const SIZE: usize = mem::size_of::<(u32, Self)>() + mem::size_of::<(usize, u32)>();
+}
+
+impl DropWithHeap for Callbacks {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        for (_, callback) in self.functions {
+            callback.function.drop_with_heap(heap);
+            heap.heap_mut().tracker_mut().on_free(|| Callback::SIZE);
+        }
+    }
+}
+
+/// State of an external call paused while the host calls a callback.
+///
+/// Calls the callback makes to the host set the VM's pending effects and
+/// `instruction_ip` for themselves, so the paused call's are kept here and put
+/// back when the callback finishes.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct PausedCall {
+    /// Id of the callback being run.
+    callback_id: u32,
+    /// The paused call's [`VM::pending_file_effect`].
+    file_effect: Option<PendingFileEffect>,
+    /// The paused call's [`VM::pending_handle_effect`].
+    handle_effect: Option<HandleEffect>,
+    /// IP of the paused call's instruction, for handling an exception the host raises.
+    instruction_ip: usize,
+}
+
+impl DropWithHeap for PausedCall {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        self.file_effect.drop_with_heap(heap);
+        self.handle_effect.drop_with_heap(heap);
+    }
+}
+
+impl<T: ResourceTracker> VM<'_, T> {
+    /// Lets sandbox functions passed to the host become callbacks.
+    pub(crate) fn enable_callbacks(&mut self) {
+        self.callbacks.enabled = true;
+    }
+
+    /// Returns whether sandbox functions passed to the host become callbacks.
+    pub(crate) fn callbacks_enabled(&self) -> bool {
+        self.callbacks.enabled
+    }
+
+    /// Returns the name of `value` if it's a function defined in the sandbox.
+    pub(crate) fn sandbox_function_name(&self, value: &Value) -> Option<String> {
+        let func_id = match value {
+            Value::DefFunction(func_id) => *func_id,
+            Value::Ref(id) => match self.heap.get(*id) {
+                HeapData::Closure(closure) => closure.func_id,
+                HeapData::FunctionDefaults(fd) => fd.func_id,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let name_id = self.interns.get_function(func_id).name.name_id;
+        Some(self.interns.get_str(name_id).to_owned())
+    }
+
+    /// Registers `function` as a callback and returns its id.
+    ///
+    /// Passing the same function to the host again reuses its id. Returns `None` if the
+    /// resource tracker refuses the registration; the error is kept for
+    /// [`take_callback_error`](Self::take_callback_error).
+    pub(crate) fn register_callback(&mut self, function: &Value) -> Option<u32> {
+        let key = function.id(self);
+        if let Some(&callback_id) = self.callbacks.ids.get(&key) {
+            return Some(callback_id);
+        }
+        if let Err(err) = self.heap.tracker_mut().on_allocate(|| Callback::SIZE) {
+            self.callbacks.error.get_or_insert(err);
+            return None;
+        }
+
+        let callback_id = self.callbacks.next_id;
+        self.callbacks.next_id = callback_id.wrapping_add(1);
+        let callback = Callback {
+            function: function.clone_with_heap(self),
+            key,
+            depth: self.paused_calls.len(),
+        };
+        self.callbacks.functions.insert(callback_id, callback);
+        self.callbacks.ids.insert(key, callback_id);
+        Some(callback_id)
+    }
+
+    /// Returns the error from a registration the resource tracker refused, if any.
+    pub(crate) fn take_callback_error(&mut self) -> Option<ResourceError> {
+        self.callbacks.error.take()
+    }
+
+    /// Returns a new reference to the callback with `callback_id`, if there is one.
+    pub(crate) fn callback(&self, callback_id: u32) -> Option<Value> {
+        let callback = self.callbacks.functions.get(&callback_id)?;
+        Some(callback.function.clone_with_heap(self))
+    }
+
+    /// Releases the callbacks passed to the external call the host returned from,
+    /// including those registered while it was paused.
+    pub(crate) fn release_callbacks(&mut self) {
+        let depth = self.paused_calls.len();
+        let released = self
+            .callbacks
+            .functions
+            .extract_if(|_, callback| callback.depth >= depth);
+        for (_, callback) in released {
+            self.callbacks.ids.remove(&callback.key);
+            callback.function.drop_with_heap(&mut *self.heap);
+            self.heap.tracker_mut().on_free(|| Callback::SIZE);
+        }
+    }
+
+    /// Calls the callback with `callback_id` on behalf of the host, while an external call is paused.
+    ///
+    /// Exits with [`FrameExit::CallbackResult`] once the callback returns or raises,
+    /// unless it first makes a call the host must answer. An unknown id or arguments
+    /// that can't be converted fail the run, like an invalid return value from the host.
+    pub fn call_callback(
+        &mut self,
+        callback_id: u32,
+        args: Vec<MontyObject>,
+        kwargs: Vec<(MontyObject, MontyObject)>,
+    ) -> Result<FrameExit, RunError> {
+        let Some(callable) = self.callback(callback_id) else {
+            return Err(SimpleException::new(
+                ExcType::RuntimeError,
+                Some(format!("unknown callback id {callback_id}")),
+            )
+            .into());
+        };
+        let args = match self.callback_args(args, kwargs) {
+            Ok(args) => args,
+            Err(err) => {
+                callable.drop_with_heap(self);
+                return Err(err);
+            }
+        };
+
+        self.paused_calls.push(PausedCall {
+            callback_id,
+            file_effect: self.pending_file_effect.take(),
+            handle_effect: self.pending_handle_effect.take(),
+            instruction_ip: self.instruction_ip,
+        });
+        let result = self.call_function(&callable, args);
+        callable.drop_with_heap(self);
+        match result {
+            Ok(CallResult::FramePushed) => {
+                self.current_frame_mut().callback = true;
+                self.run()
+            }
+            Ok(CallResult::Value(value)) => self.finish_callback(Ok(value)),
+            Ok(other) => {
+                other.drop_with_heap(self);
+                Err(RunError::internal("callback did not push a frame"))
+            }
+            Err(err @ RunError::Exc(_)) => self.finish_callback(Err(err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Ends the innermost callback with `result`, restoring the external call it paused.
+    pub(super) fn finish_callback(&mut self, result: RunResult<Value>) -> RunResult<FrameExit> {
+        let Some(paused) = self.paused_calls.pop() else {
+            result.ok().drop_with_heap(self);
+            return Err(RunError::internal("no paused call for callback"));
+        };
+        self.pending_file_effect = paused.file_effect;
+        self.pending_handle_effect = paused.handle_effect;
+        self.instruction_ip = paused.instruction_ip;
+        Ok(FrameExit::CallbackResult {
+            callback_id: paused.callback_id,
+            result,
+        })
+    }
+
+    /// Turns an exception that propagated out of a callback's frame into the callback's result.
+    ///
+    /// The frame is popped by exception handling, which stops unwinding there.
+    pub(crate) fn catch_callback_error(&mut self, result: RunResult<FrameExit>) -> RunResult<FrameExit> {
+        match result {
+            Err(err) if mem::take(&mut self.callback_raised) => self.finish_callback(Err(err)),
+            result => result,
+        }
+    }
+
+    /// Converts the host's arguments for a callback.
+    fn callback_args(
+        &mut self,
+        args: Vec<MontyObject>,
+        kwargs: Vec<(MontyObject, MontyObject)>,
+    ) -> RunResult<ArgValues> {
+        let mut positional = Vec::with_capacity(args.len());
+        let mut pairs = Vec::with_capacity(kwargs.len());
+        if let Err(err) = self.convert_callback_args(args, kwargs, &mut positional, &mut pairs) {
+            positional.drop_with_heap(self);
+            pairs.drop_with_heap(self);
+            return Err(match err {
+                InvalidInputError::Resource(err) => RunError::from(err),
+                other @ InvalidInputError::InvalidType(_) => SimpleException::new(
+                    ExcType::RuntimeError,
+                    Some(format!("invalid callback argument: {other}")),
+                )
+                .into(),
+            });
+        }
+
+        if pairs.is_empty() {
+            return Ok(Self::build_args_positional_only(positional));
+        }
+        let kwargs = match Dict::from_pairs(pairs, self) {
+            Ok(dict) => KwargsValues::Dict(dict),
+            Err(err) => {
+                positional.drop_with_heap(self);
+                return Err(err);
+            }
+        };
+        Ok(if positional.is_empty() {
+            ArgValues::Kwargs(kwargs)
+        } else {
+            ArgValues::ArgsKargs {
+                args: positional,
+                kwargs,
+            }
+        })
+    }
+
+    /// Converts each argument into `positional` and `pairs`, stopping at the first that fails.
+    ///
+    /// The caller owns whatever was converted before the failure.
+    fn convert_callback_args(
+        &mut self,
+        args: Vec<MontyObject>,
+        kwargs: Vec<(MontyObject, MontyObject)>,
+        positional: &mut Vec<Value>,
+        pairs: &mut Vec<(Value, Value)>,
+    ) -> Result<(), InvalidInputError> {
+        for arg in args {
+            positional.push(arg.to_value(self)?);
+        }
+        for (key, value) in kwargs {
+            let key = key.to_value(self)?;
+            match value.to_value(self) {
+                Ok(value) => pairs.push((key, value)),
+                Err(err) => {
+                    key.drop_with_heap(self);
+                    return Err(err);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use super::VM;
 use crate::{
     defer_drop,
     exception_private::{ExcType, RunError},
+    heap::HeapData,
     resource::ResourceTracker,
     types::{LongInt, PyTrait},
     value::Value,
@@ -40,8 +41,12 @@ impl<T: ResourceTracker> VM<'_, T> {
         Ok(())
     }
 
-    /// Ordering comparison with a predicate.
-    pub(super) fn compare_ord<F>(&mut self, check: F) -> Result<(), RunError>
+    /// Ordering comparison with a predicate; `op` is the operator's symbol for errors.
+    ///
+    /// Unordered operands compare as `False`, except complex numbers and instances of
+    /// sandbox-declared dataclasses, which raise `TypeError` like CPython when the class
+    /// isn't declared with `order=True` or the other operand isn't of the same class.
+    pub(super) fn compare_ord<F>(&mut self, check: F, op: &str) -> Result<(), RunError>
     where
         F: FnOnce(Ordering) -> bool,
     {
@@ -52,7 +57,17 @@ impl<T: ResourceTracker> VM<'_, T> {
         let lhs = this.pop();
         defer_drop!(lhs, this);
 
-        let result = lhs.py_cmp(rhs, this)?.is_some_and(check);
+        let result = match lhs.py_cmp(rhs, this)? {
+            Some(ordering) => check(ordering),
+            None if raises_when_unordered(lhs, this) || raises_when_unordered(rhs, this) => {
+                return Err(ExcType::type_error(format!(
+                    "'{op}' not supported between instances of '{}' and '{}'",
+                    ordering_type_name(lhs, this),
+                    ordering_type_name(rhs, this)
+                )));
+            }
+            None => false,
+        };
         this.push(Value::Bool(result));
         Ok(())
     }
@@ -139,3 +154,27 @@ impl<T: ResourceTracker> VM<'_, T> {
         }
     }
 }
+
+/// Whether an unordered comparison involving `value` raises `TypeError` rather than
+/// comparing as `False`.
+fn raises_when_unordered(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> bool {
+    match value {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Complex(_) => true,
+            HeapData::Dataclass(dc) => dc.class_id().is_some(),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the type name CPython shows for `value` in an ordering error: the class
+/// name for dataclass instances, the builtin type name otherwise.
+fn ordering_type_name(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> String {
+    if let Value::Ref(id) = value
+        && let HeapData::Dataclass(dc) = vm.heap.get(*id)
+    {
+        return dc.name(vm.interns).to_owned();
+    }
+    value.py_type(vm).to_string()
+}
@@ -230,6 +230,13 @@ impl<T: ResourceTracker> VM<'_, T> {
             // This is where the caller invoked the function that's failing
             let call_position = this.current_frame().call_position;
 
+            // A callback's exception goes to the host rather than to the paused call
+            if this.current_frame().callback {
+                this.pop_frame();
+                this.callback_raised = true;
+                return Some(error);
+            }
+
             // Pop this frame
             if this.pop_frame() {
                 // The frame indicated evaluation should stop - e.g. inside `evaluate_function` - return the error
@@ -277,9 +284,11 @@ impl<T: ResourceTracker> VM<'_, T> {
 
     /// Creates an exception Value from exception info.
     ///
-    /// Allocates an Exception on the heap and returns a Value::Ref to it.
+    /// Allocates an Exception on the heap and returns a Value::Ref to it. The
+    /// traceback collected so far is kept on the exception for `logging.exception()`.
     fn create_exception_value(&mut self, exc: &ExceptionRaise) -> Result<Value, RunError> {
-        let exception = exc.exc.clone();
+        let mut exception = exc.exc.clone();
+        exception.set_traceback(exc.frame.clone());
         let heap_id = self.heap.allocate(HeapData::Exception(exception))?;
         Ok(Value::Ref(heap_id))
     }
@@ -0,0 +1,122 @@
+//! Results of operations on host handles.
+
+use super::VM;
+use crate::{
+    args::ArgValues,
+    bytecode::CallResult,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, HeapData},
+    resource::ResourceTracker,
+    types::{MontyIter, PyTrait, Type, host_handle::HandleEffect},
+    value::Value,
+};
+
+impl<T: ResourceTracker> VM<'_, T> {
+    /// Checks the host's `value` for a paused handle operation and continues the
+    /// operation with it, as `effect` says.
+    ///
+    /// Pushes the operation's result, except for attribute assignment, which pushes
+    /// nothing, conditional jumps, which move the frame to the jump target instead,
+    /// and unpacking, which pushes the unpacked values.
+    pub(super) fn apply_handle_effect(&mut self, effect: HandleEffect, value: Value) -> RunResult<()> {
+        match effect {
+            HandleEffect::Bool => {
+                let truth = self.handle_truth(value)?;
+                self.push(Value::Bool(truth));
+            }
+            HandleEffect::Not => {
+                let truth = self.handle_truth(value)?;
+                self.push(Value::Bool(!truth));
+            }
+            HandleEffect::Branch {
+                keep,
+                jump_when,
+                target,
+            } => {
+                let truth = match self.handle_truth(value) {
+                    Ok(truth) => truth,
+                    Err(err) => {
+                        keep.drop_with_heap(self);
+                        return Err(err);
+                    }
+                };
+                if truth == jump_when {
+                    if let Some(kept) = keep {
+                        self.push(kept);
+                    }
+                    self.current_frame_mut().ip = target;
+                } else {
+                    keep.drop_with_heap(self);
+                }
+            }
+            HandleEffect::Len => match value {
+                Value::Int(len) if len >= 0 => self.push(Value::Int(len)),
+                Value::Int(_) => {
+                    return Err(SimpleException::new_msg(ExcType::ValueError, "__len__() should return >= 0").into());
+                }
+                other => {
+                    let ty = other.py_type(self);
+                    other.drop_with_heap(self);
+                    return Err(ExcType::type_error(format!(
+                        "'{ty}' object cannot be interpreted as an integer"
+                    )));
+                }
+            },
+            HandleEffect::Iter => {
+                let iter = MontyIter::new(value, self)?;
+                let heap_id = self.heap.allocate(HeapData::Iter(iter))?;
+                self.push(Value::Ref(heap_id));
+            }
+            HandleEffect::Discard => value.drop_with_heap(self),
+            HandleEffect::ReturnNone => {
+                value.drop_with_heap(self);
+                self.push(Value::None);
+            }
+            HandleEffect::AttrDefault(default) => {
+                default.drop_with_heap(self);
+                self.push(value);
+            }
+            HandleEffect::HasAttr => {
+                value.drop_with_heap(self);
+                self.push(Value::Bool(true));
+            }
+            HandleEffect::IterInto(builtin) => {
+                let iter = MontyIter::new(value, self)?;
+                let iter = Value::Ref(self.heap.allocate(HeapData::Iter(iter))?);
+                match builtin.call(self, ArgValues::One(iter))? {
+                    CallResult::Value(result) => self.push(result),
+                    other => {
+                        other.drop_with_heap(self);
+                        return Err(RunError::internal(
+                            "builtin applied to a host handle's items didn't return",
+                        ));
+                    }
+                }
+            }
+            HandleEffect::Unpack(count) => {
+                let items = Type::List.call(self, ArgValues::One(value))?;
+                self.push(items);
+                self.unpack_sequence(count)?;
+            }
+            HandleEffect::UnpackEx(before, after) => {
+                let items = Type::List.call(self, ArgValues::One(value))?;
+                self.push(items);
+                self.unpack_ex(before, after)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the truth value the host gave for a handle, which must be a bool
+    /// like the result of `__bool__`.
+    fn handle_truth(&mut self, value: Value) -> RunResult<bool> {
+        if let Value::Bool(truth) = value {
+            return Ok(truth);
+        }
+        let ty = value.py_type(self);
+        value.drop_with_heap(self);
+        Err(ExcType::type_error(format!(
+            "__bool__ should return bool, returned {ty}"
+        )))
+    }
+}
@@ -0,0 +1,88 @@
+//! Imports of modules supplied as source files.
+
+use std::mem;
+
+use super::{CallFrame, CallResult, VM};
+use crate::{
+    exception_private::RunResult,
+    heap::{DropWithHeap, HeapData},
+    resource::ResourceTracker,
+    types::Module,
+    value::{EitherStr, Value},
+};
+
+impl<T: ResourceTracker> VM<'_, T> {
+    /// Imports the supplied source module at `index`.
+    ///
+    /// The first import pushes a frame running the module body, which returns the
+    /// module object. Later imports get the cached module straight away, even while
+    /// its body is still running — that's how circular imports see a partially
+    /// initialized module.
+    pub(super) fn import_source_module(&mut self, index: u16) -> RunResult<CallResult> {
+        if let Some(module) = self.imported_module(index) {
+            return Ok(CallResult::Value(module));
+        }
+        let Some(body) = self.interns.source_module(index).body else {
+            // A package without an `__init__.py` has no body to run
+            self.init_source_module(index)?;
+            let module = self.imported_module(index).expect("module was just initialized");
+            return Ok(CallResult::Value(module));
+        };
+        let code = &self.interns.get_function(body).code;
+        let exc_stack_base = self.exception_stack.len();
+        self.push_frame(CallFrame::new_module_body(code, self.stack.len(), exc_stack_base, body))?;
+        Ok(CallResult::FramePushed)
+    }
+
+    /// Creates the module object of the supplied source module at `index` and caches it.
+    ///
+    /// Returns `false` without doing anything if the module was already imported,
+    /// which happens when importing a submodule's parent package imported the submodule.
+    pub(super) fn init_source_module(&mut self, index: u16) -> RunResult<bool> {
+        let slot = usize::from(index);
+        if self
+            .imported_modules
+            .get(slot)
+            .is_some_and(|m| !matches!(m, Value::Undefined))
+        {
+            return Ok(false);
+        }
+        let name = &self.interns.source_module(index).name;
+        let name = match self.interns.get_string_id_by_name(name) {
+            Some(id) => EitherStr::Interned(id),
+            None => EitherStr::Heap(name.clone()),
+        };
+        let heap_id = self.heap.allocate(HeapData::Module(Module::from_source(name, index)))?;
+        if self.imported_modules.len() <= slot {
+            self.imported_modules.resize_with(slot + 1, || Value::Undefined);
+        }
+        // The replaced value is `Undefined`, dropping it is a no-op
+        let old = mem::replace(&mut self.imported_modules[slot], Value::Ref(heap_id));
+        old.drop_with_heap(self);
+        Ok(true)
+    }
+
+    /// Returns a new reference to the supplied source module at `index`, if it was imported.
+    pub(crate) fn imported_module(&self, index: u16) -> Option<Value> {
+        match self.imported_modules.get(usize::from(index))? {
+            Value::Undefined => None,
+            module => Some(module.clone_with_heap(self)),
+        }
+    }
+
+    /// Returns a new reference to attribute `name` of the supplied source module at `index`.
+    ///
+    /// Attributes are the module's globals, read live so they reflect assignments
+    /// made after the import, followed by its imported submodules.
+    pub(crate) fn source_module_attr(&self, index: u16, name: &str) -> Option<Value> {
+        let module = self.interns.source_module(index);
+        if let Some(slot) = module.globals.get(name) {
+            let value = &self.globals[slot.index()];
+            if !matches!(value, Value::Undefined) {
+                return Some(value.clone_with_heap(self));
+            }
+        }
+        let submodule = self.interns.find_source_module(&format!("{}.{name}", module.name))?;
+        self.imported_module(submodule)
+    }
+}
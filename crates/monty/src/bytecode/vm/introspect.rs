@@ -0,0 +1,73 @@
+//! Namespace snapshots for the `globals()`, `locals()`, `vars()` and `dir()` builtins.
+
+use super::VM;
+use crate::{
+    exception_private::RunResult,
+    heap::{HeapData, HeapId},
+    intern::StringId,
+    resource::ResourceTracker,
+    types::Dict,
+    value::Value,
+};
+
+impl<T: ResourceTracker> VM<'_, T> {
+    /// Returns `(name, value)` pairs for every bound global, in slot order.
+    ///
+    /// Slot names come from the module code, which the compiler seeds with every
+    /// global name. The values are new references owned by the caller.
+    pub(crate) fn global_bindings(&self) -> Vec<(StringId, Value)> {
+        let Some(code) = self.module_code else {
+            return Vec::new();
+        };
+        (0..self.globals.len())
+            .filter_map(|slot| {
+                let slot = u16::try_from(slot).ok()?;
+                let name = code.local_name(slot)?;
+                self.binding_value(&self.globals[usize::from(slot)])
+                    .map(|value| (name, value))
+            })
+            .collect()
+    }
+
+    /// Returns `(name, value)` pairs for the current frame's bound locals, in slot order.
+    ///
+    /// At module level this is the same as [`global_bindings`](Self::global_bindings).
+    /// Cell variables are dereferenced, so captured and free variables show their
+    /// current value. The values are new references owned by the caller.
+    pub(crate) fn local_bindings(&self) -> Vec<(StringId, Value)> {
+        let frame = self.current_frame();
+        if frame.function_id.is_none() {
+            return self.global_bindings();
+        }
+        (0..frame.locals_count)
+            .filter_map(|slot| {
+                // Slots the compiler never named (e.g. unused cells) read as the default id
+                let name = frame
+                    .code
+                    .local_name(slot)
+                    .filter(|&name| name != StringId::default())?;
+                let value = &self.stack[frame.stack_base + usize::from(slot)];
+                let value = match value {
+                    Value::Ref(id) if let HeapData::Cell(cell) = self.heap.get(*id) => &cell.0,
+                    other => other,
+                };
+                self.binding_value(value).map(|value| (name, value))
+            })
+            .collect()
+    }
+
+    /// Builds a new dict mapping each binding's name to its value, returning its heap id.
+    pub(crate) fn bindings_dict(&mut self, bindings: Vec<(StringId, Value)>) -> RunResult<HeapId> {
+        let pairs = bindings
+            .into_iter()
+            .map(|(name, value)| (Value::InternString(name), value))
+            .collect();
+        let dict = Dict::from_pairs(pairs, self)?;
+        Ok(self.heap.allocate(HeapData::Dict(dict))?)
+    }
+
+    /// Returns a new reference to `value`, or `None` if the slot is unbound.
+    fn binding_value(&self, value: &Value) -> Option<Value> {
+        (!matches!(value, Value::Undefined)).then(|| value.clone_with_heap(self))
+    }
+}
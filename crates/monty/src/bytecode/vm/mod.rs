@@ -7,16 +7,21 @@ mod async_exec;
 mod attr;
 mod binary;
 mod call;
+mod callbacks;
 mod collections;
 mod compare;
 mod context_manager;
 mod exceptions;
 mod format;
+mod host_handles;
+mod imports;
+mod introspect;
 mod scheduler;
 
 use std::{cmp::Ordering, mem};
 
 pub(crate) use call::CallResult;
+use callbacks::{Callbacks, PausedCall};
 use scheduler::Scheduler;
 
 use crate::{
@@ -29,18 +34,22 @@ use crate::{
         op::Opcode,
     },
     exception_private::{ExcType, RunError, RunResult, SimpleException},
+    function_signature::BoundArgs,
     heap::{ContainsHeap, DropWithHeap, Heap, HeapData, HeapGuard, HeapId, HeapReadOutput, HeapReader},
     heap_data::{Closure, FunctionDefaults},
-    intern::{FunctionId, Interns, StringId},
+    host_module::MontyModule,
+    intern::{FunctionId, Interns, StaticStrings, StringId},
     io::PrintWriter,
-    modules::{StandardLib, json::JsonStringCache},
+    modules::{StandardLib, json::JsonStringCache, logging::LoggingState, warnings::WarningsState},
     object::InvalidInputError,
     os::OsFunctionCall,
     parse::CodeRange,
     resource::ResourceTracker,
     types::{
         LongInt, MontyIter, PyTrait,
-        file::{PendingFileEffect, apply_buffer_store, apply_write_position},
+        dataclass_type::build_class,
+        file::{PendingFileEffect, apply_buffer_store, apply_open_text, apply_write_position},
+        host_handle::{HandleEffect, HandleOp, HandleRequest, HostHandle},
         timedelta,
     },
     value::{BitwiseOp, EitherStr, Value},
@@ -136,6 +145,50 @@ macro_rules! handle_load_result {
     };
 }
 
+/// Yields an operation on a host handle to the host.
+///
+/// Records the request's effect so the host's result is checked and turned into
+/// the operation's value when execution resumes.
+macro_rules! yield_handle_call {
+    ($self:expr, $cached_frame:ident, $request:expr) => {{
+        let HandleRequest { handle, op, effect } = $request;
+        let call_id = $self.allocate_call_id();
+        $self.pending_handle_effect = effect;
+        // Sync cached IP back to frame before snapshot for resume
+        $self.current_frame_mut().ip = $cached_frame.ip;
+        return Ok(FrameExit::HandleCall { handle, op, call_id });
+    }};
+}
+
+/// Forwards the truth test of a conditional jump to the host when `$value` is a host handle.
+///
+/// `$keep` says whether the jump leaves the value on the stack, as `and`/`or` do.
+/// Does nothing for any other value.
+macro_rules! branch_on_host_handle {
+    ($self:expr, $cached_frame:ident, $value:ident, $offset:expr, $jump_when:expr, $keep:expr) => {
+        if let Some(handle) = HostHandle::of(&$value, $self) {
+            let mut target = $cached_frame.ip;
+            jump_relative!(target, $offset);
+            let keep = if $keep {
+                Some($value)
+            } else {
+                $value.drop_with_heap($self);
+                None
+            };
+            let effect = HandleEffect::Branch {
+                keep,
+                jump_when: $jump_when,
+                target,
+            };
+            yield_handle_call!(
+                $self,
+                $cached_frame,
+                HandleRequest::new(handle, HandleOp::Bool).with_effect(effect)
+            );
+        }
+    };
+}
+
 /// Handles the result of a call operation that returns `CallResult`.
 ///
 /// This macro eliminates the repetitive pattern of matching on `CallResult`
@@ -145,9 +198,11 @@ macro_rules! handle_load_result {
 /// Actions taken for each variant:
 /// - `Push(value)`: Push the value onto the stack
 /// - `FramePushed`: Reload the cached frame (a new frame was pushed)
-/// - `External(ext_id, args)`: Return `FrameExit::ExternalCall` to yield to host
+/// - `External(ext_id, args)`: Bind `args` if the function has a declared signature,
+///   then return `FrameExit::ExternalCall` to yield to host
 /// - `OsCall(call)`: Return `FrameExit::OsCall` to yield to host
 /// - `MethodCall(name, args)`: Return `FrameExit::MethodCall` to yield to host
+/// - `HandleCall(request)`: Return `FrameExit::HandleCall` to yield to host
 /// - `AwaitValue(value)`: Push value, then implicitly await it via `exec_get_awaitable`
 /// - `Err(err)`: Handle the exception via `catch_sync!`
 macro_rules! handle_call_result {
@@ -155,18 +210,22 @@ macro_rules! handle_call_result {
         match $result {
             Ok(CallResult::Value(result)) => $self.push(result),
             Ok(CallResult::FramePushed) => reload_cache!($self, $cached_frame),
-            Ok(CallResult::External(name, args)) => {
-                let call_id = $self.allocate_call_id();
-                let name_load_ip = $self.ext_function_load_ip.take();
-                // Sync cached IP back to frame before snapshot for resume
-                $self.current_frame_mut().ip = $cached_frame.ip;
-                return Ok(FrameExit::ExternalCall {
-                    function_name: name,
-                    args,
-                    call_id,
-                    name_load_ip,
-                });
-            }
+            Ok(CallResult::External(name, args)) => match BoundArgs::bind(&name, args, $self) {
+                Ok((args, bound_args)) => {
+                    let call_id = $self.allocate_call_id();
+                    let name_load_ip = $self.ext_function_load_ip.take();
+                    // Sync cached IP back to frame before snapshot for resume
+                    $self.current_frame_mut().ip = $cached_frame.ip;
+                    return Ok(FrameExit::ExternalCall {
+                        function_name: name,
+                        args,
+                        bound_args,
+                        call_id,
+                        name_load_ip,
+                    });
+                }
+                Err(err) => catch_sync!($self, $cached_frame, err),
+            },
             Ok(CallResult::OsCall(function_call)) => {
                 let call_id = $self.allocate_call_id();
                 // Sync cached IP back to frame before snapshot for resume
@@ -196,6 +255,7 @@ macro_rules! handle_call_result {
                     call_id,
                 });
             }
+            Ok(CallResult::HandleCall(request)) => yield_handle_call!($self, $cached_frame, request),
             Ok(CallResult::AwaitValue(value)) => {
                 // Push the value and implicitly await it (used by asyncio.run())
                 $self.push(value);
@@ -234,7 +294,11 @@ pub enum FrameExit {
         /// Name of the external function to call (interned or heap-owned).
         function_name: EitherStr,
         /// Arguments for the external function (includes both positional and keyword args).
+        ///
+        /// Empty if the function has a declared signature.
         args: ArgValues,
+        /// The arguments bound to the function's declared signature, if it has one.
+        bound_args: Option<BoundArgs>,
         /// Unique ID for this call, used for async correlation.
         call_id: CallId,
         /// Optional bytecode IP of the load instruction that produced this `ExtFunction`.
@@ -275,6 +339,19 @@ pub enum FrameExit {
         call_id: CallId,
     },
 
+    /// Execution paused for an operation on a host handle.
+    ///
+    /// The caller should perform `op` on the host object behind `handle` and call
+    /// `resume()` with the result.
+    HandleCall {
+        /// The handle the operation was made on.
+        handle: HostHandle,
+        /// The operation, with any operands.
+        op: HandleOp,
+        /// Unique ID for this call, used for async correlation.
+        call_id: CallId,
+    },
+
     /// All tasks are blocked waiting for external futures to resolve.
     ///
     /// The caller must resolve the pending CallIds before calling `resume()`.
@@ -299,17 +376,42 @@ pub enum FrameExit {
         /// Whether this is a global slot (true) or a local/function slot (false).
         is_global: bool,
     },
+
+    /// Execution paused to import a module that is neither in the standard
+    /// library nor among the host-defined modules.
+    ///
+    /// The host can supply the module, which is pushed onto the stack, or
+    /// indicate it doesn't exist (which will raise `ModuleNotFoundError`).
+    ModuleLookup {
+        /// The interned dotted name of the module being imported.
+        name_id: StringId,
+    },
+
+    /// A callback the host called while an external call was paused returned or raised.
+    ///
+    /// The external call is still paused: the host can call another callback or
+    /// `resume()` the call.
+    CallbackResult {
+        /// Id of the callback that finished.
+        callback_id: u32,
+        /// The callback's return value, or the exception it raised.
+        result: RunResult<Value>,
+    },
 }
 
 impl DropWithHeap for FrameExit {
     fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
         match self {
             Self::Return(value) => value.drop_with_heap(heap),
-            Self::ExternalCall { args, .. } | Self::MethodCall { args, .. } => {
+            Self::ExternalCall { args, bound_args, .. } => {
                 args.drop_with_heap(heap);
+                bound_args.drop_with_heap(heap);
             }
+            Self::MethodCall { args, .. } => args.drop_with_heap(heap),
             Self::OsCall { function_call, .. } => function_call.drop_with_heap(heap),
-            Self::ResolveFutures(_) | Self::NameLookup { .. } => {}
+            Self::HandleCall { op, .. } => op.drop_with_heap(heap),
+            Self::CallbackResult { result, .. } => result.ok().drop_with_heap(heap),
+            Self::ResolveFutures(_) | Self::NameLookup { .. } | Self::ModuleLookup { .. } => {}
         }
     }
 }
@@ -360,6 +462,10 @@ pub struct CallFrame<'code> {
     /// When this frame returns (or exits with an exception) the VM should exit the run loop
     /// and return to the caller. Supports `evaluate_function`.
     should_return: bool,
+
+    /// This frame runs a callback the host called, so when it returns or raises the
+    /// VM exits with `FrameExit::CallbackResult`.
+    callback: bool,
 }
 
 impl<'code> CallFrame<'code> {
@@ -377,6 +483,31 @@ impl<'code> CallFrame<'code> {
             function_id: None,
             call_position: None,
             should_return: false,
+            callback: false,
+        }
+    }
+
+    /// Creates a new call frame running the body of a module supplied as a source file.
+    ///
+    /// Like a module frame the body has no locals, as its variables are globals,
+    /// but it's stacked on the importing frame like a function call. `function_id`
+    /// is the module's `<module>` function, so the frame serializes like any other.
+    pub fn new_module_body(
+        code: &'code Code,
+        stack_base: usize,
+        exception_stack_base: usize,
+        function_id: FunctionId,
+    ) -> Self {
+        Self {
+            code,
+            ip: 0,
+            stack_base,
+            locals_count: 0,
+            exception_stack_base,
+            function_id: Some(function_id),
+            call_position: None,
+            should_return: false,
+            callback: false,
         }
     }
 
@@ -405,6 +536,7 @@ impl<'code> CallFrame<'code> {
             function_id: Some(function_id),
             call_position,
             should_return: false,
+            callback: false,
         }
     }
 }
@@ -536,6 +668,10 @@ pub struct SerializedFrame {
 
     /// Call site position (for tracebacks).
     call_position: Option<CodeRange>,
+
+    /// Whether this frame runs a callback. See `CallFrame.callback`.
+    #[serde(default)]
+    callback: bool,
 }
 
 impl CallFrame<'_> {
@@ -552,6 +688,7 @@ impl CallFrame<'_> {
             locals_count: self.locals_count,
             exception_stack_base: self.exception_stack_base,
             call_position: self.call_position,
+            callback: self.callback,
         }
     }
 }
@@ -600,6 +737,38 @@ pub struct VMSnapshot {
     /// [`VM::pending_file_effect`].
     #[serde(default)]
     pending_file_effect: Option<PendingFileEffect>,
+
+    /// Treatment of the paused host handle operation's result. See [`VM::pending_handle_effect`].
+    #[serde(default)]
+    pending_handle_effect: Option<HandleEffect>,
+
+    /// Targets of active `contextlib.redirect_stdout` blocks. See [`VM::stdout_redirects`].
+    #[serde(default)]
+    stdout_redirects: Vec<Value>,
+
+    /// Filters and registries of the `warnings` module. See [`VM::warnings`].
+    #[serde(default)]
+    warnings: WarningsState,
+
+    /// Levels, format and logger registry of the `logging` module. See [`VM::logging`].
+    #[serde(default)]
+    logging: LoggingState,
+
+    /// Modules the host made importable. See [`VM::host_modules`].
+    #[serde(default)]
+    host_modules: Vec<MontyModule>,
+
+    /// Supplied source modules imported so far. See [`VM::imported_modules`].
+    #[serde(default)]
+    imported_modules: Vec<Value>,
+
+    /// Sandbox functions passed to the host. See [`VM::callbacks`].
+    #[serde(default)]
+    callbacks: Callbacks,
+
+    /// External calls paused by running callbacks. See [`VM::paused_calls`].
+    #[serde(default)]
+    paused_calls: Vec<PausedCall>,
 }
 
 // ============================================================================
@@ -692,6 +861,58 @@ pub struct VM<'h, T: ResourceTracker> {
     /// single `Option` is sufficient even with async tasks (which interleave
     /// between OS calls, not within one).
     pub(crate) pending_file_effect: Option<PendingFileEffect>,
+
+    /// How the result of the paused host handle operation is checked and used on resume.
+    ///
+    /// Set when yielding a `HandleCall` whose result isn't simply pushed, like the
+    /// truth value of a conditional jump; cleared on resume, or when the host raises.
+    pub(crate) pending_handle_effect: Option<HandleEffect>,
+
+    /// Targets of active `contextlib.redirect_stdout` blocks, innermost last.
+    ///
+    /// While non-empty, `print()` calls `write()` on the last target instead of
+    /// writing to `print_writer`. Each entry owns a reference to its target.
+    pub(crate) stdout_redirects: Vec<Value>,
+
+    /// Filters, "already shown" registries and `catch_warnings` state of the
+    /// `warnings` module.
+    ///
+    /// Lives on the VM rather than in the module object so `warnings.warn()` sees
+    /// the same filters however the module was imported.
+    pub(crate) warnings: WarningsState,
+
+    /// Logger levels, the `basicConfig()` format and the logger registry of the
+    /// `logging` module, shared by every `Logger` object.
+    pub(crate) logging: LoggingState,
+
+    /// Modules the host made importable, searched by `LoadHostModule`.
+    ///
+    /// Seeded from the modules declared on the runner and extended with each
+    /// module the host supplies for a `ModuleLookup`, so later imports of the
+    /// same module don't yield again.
+    pub(crate) host_modules: Vec<MontyModule>,
+
+    /// Module objects of the supplied source modules imported so far, indexed like
+    /// the interns' source-module table, with `Undefined` for modules not imported yet.
+    ///
+    /// Each entry owns a reference to its module, so a module is created and its
+    /// body run only once per run.
+    pub(crate) imported_modules: Vec<Value>,
+
+    /// Sandbox functions passed to the host as callbacks, by callback id.
+    ///
+    /// Each entry owns a reference to its function, so an id stays valid until the
+    /// external call the function was passed to returns.
+    callbacks: Callbacks,
+
+    /// External calls paused while the host runs a callback, innermost last.
+    ///
+    /// Pushed when a callback is called and popped when it returns or raises.
+    paused_calls: Vec<PausedCall>,
+
+    /// Set by exception handling when an exception propagates out of a callback's frame,
+    /// so the error returned from the run loop becomes the callback's result.
+    callback_raised: bool,
 }
 
 impl<'h, T: ResourceTracker> VM<'h, T> {
@@ -716,6 +937,15 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
             module_code: None,
             json_string_cache: JsonStringCache::default(),
             pending_file_effect: None,
+            pending_handle_effect: None,
+            stdout_redirects: Vec::new(),
+            warnings: WarningsState::default(),
+            logging: LoggingState::default(),
+            host_modules: Vec::new(),
+            imported_modules: Vec::new(),
+            callbacks: Callbacks::default(),
+            paused_calls: Vec::new(),
+            callback_raised: false,
         }
     }
 
@@ -756,6 +986,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                     function_id: sf.function_id,
                     call_position: sf.call_position,
                     should_return: false,
+                    callback: sf.callback,
                 }
             })
             .collect();
@@ -780,6 +1011,15 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
             ext_function_load_ip: None,
             json_string_cache: JsonStringCache::default(),
             pending_file_effect: snapshot.pending_file_effect,
+            pending_handle_effect: snapshot.pending_handle_effect,
+            stdout_redirects: snapshot.stdout_redirects,
+            warnings: snapshot.warnings,
+            logging: snapshot.logging,
+            host_modules: snapshot.host_modules,
+            imported_modules: snapshot.imported_modules,
+            callbacks: snapshot.callbacks,
+            paused_calls: snapshot.paused_calls,
+            callback_raised: false,
         }
     }
 
@@ -806,6 +1046,14 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
             instruction_ip: self.instruction_ip,
             scheduler: mem::take(&mut self.scheduler),
             pending_file_effect: self.pending_file_effect.take(),
+            pending_handle_effect: self.pending_handle_effect.take(),
+            stdout_redirects: mem::take(&mut self.stdout_redirects),
+            warnings: mem::take(&mut self.warnings),
+            logging: mem::take(&mut self.logging),
+            host_modules: mem::take(&mut self.host_modules),
+            imported_modules: mem::take(&mut self.imported_modules),
+            callbacks: mem::take(&mut self.callbacks),
+            paused_calls: mem::take(&mut self.paused_calls),
         }
     }
 
@@ -872,9 +1120,11 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
         let mut cached_frame: CachedFrame<'h> = self.new_cached_frame();
 
         loop {
-            // Check time limit and trigger GC if needed at each instruction.
-            // For NoLimitTracker, these are inlined no-ops that compile away.
+            // Check time limit, charge one unit of fuel and trigger GC if needed
+            // at each instruction. For NoLimitTracker, these are inlined no-ops
+            // that compile away.
             self.heap.check_time()?;
+            self.heap.consume_fuel(1)?;
 
             if self.heap.should_gc() {
                 // Sync IP before GC for safety
@@ -885,6 +1135,14 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
             // Track instruction IP for exception table lookup
             self.instruction_ip = cached_frame.ip;
 
+            // Raise a host interrupt here rather than alongside `check_time` so a
+            // catchable `KeyboardInterrupt` can reach the enclosing `try` block.
+            if let Err(err) = self.heap.check_interrupt() {
+                self.current_frame_mut().ip = cached_frame.ip;
+                catch_sync!(self, cached_frame, err.into());
+                continue;
+            }
+
             // Fetch opcode using cached values (no frame access)
             let opcode = {
                 let byte = cached_frame.code.bytecode()[cached_frame.ip];
@@ -1035,10 +1293,10 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 // Comparison Operations
                 Opcode::CompareEq => try_catch_sync!(self, cached_frame, self.compare_eq()),
                 Opcode::CompareNe => try_catch_sync!(self, cached_frame, self.compare_ne()),
-                Opcode::CompareLt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_lt)),
-                Opcode::CompareLe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_le)),
-                Opcode::CompareGt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_gt)),
-                Opcode::CompareGe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_ge)),
+                Opcode::CompareLt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_lt, "<")),
+                Opcode::CompareLe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_le, "<=")),
+                Opcode::CompareGt => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_gt, ">")),
+                Opcode::CompareGe => try_catch_sync!(self, cached_frame, self.compare_ord(Ordering::is_ge, ">=")),
                 Opcode::CompareIs => self.compare_is(false),
                 Opcode::CompareIsNot => self.compare_is(true),
                 Opcode::CompareIn => try_catch_sync!(self, cached_frame, self.compare_in(false)),
@@ -1051,6 +1309,11 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 // Unary Operations
                 Opcode::UnaryNot => {
                     let value = self.pop();
+                    if let Some(handle) = HostHandle::of(&value, self) {
+                        value.drop_with_heap(self);
+                        let request = HandleRequest::new(handle, HandleOp::Bool).with_effect(HandleEffect::Not);
+                        yield_handle_call!(self, cached_frame, request);
+                    }
                     let result = !value.py_bool(self);
                     value.drop_with_heap(self);
                     self.push(Value::Bool(result));
@@ -1083,6 +1346,14 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                                     Err(e) => catch_sync!(self, cached_frame, RunError::from(e)),
                                 }
                             }
+                            HeapData::Complex(c) => {
+                                let negated = -*c;
+                                value.drop_with_heap(self);
+                                match negated.into_value(self.heap) {
+                                    Ok(v) => self.push(v),
+                                    Err(e) => catch_sync!(self, cached_frame, RunError::from(e)),
+                                }
+                            }
                             HeapData::TimeDelta(td) => {
                                 let negated = timedelta::from_total_microseconds(-timedelta::total_microseconds(td));
                                 value.drop_with_heap(self);
@@ -1114,8 +1385,8 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                         Value::Int(_) | Value::Float(_) => self.push(value),
                         Value::Bool(b) => self.push(Value::Int(i64::from(b))),
                         Value::Ref(id) => {
-                            if matches!(self.heap.get(id), HeapData::LongInt(_)) {
-                                // LongInt - return as-is (value already has correct refcount)
+                            if matches!(self.heap.get(id), HeapData::LongInt(_) | HeapData::Complex(_)) {
+                                // LongInt or complex - return as-is (value already has correct refcount)
                                 self.push(value);
                             } else {
                                 let value_type = value.py_type(self);
@@ -1248,6 +1519,10 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 Opcode::BinarySubscr => {
                     let index = self.pop();
                     let obj = self.pop();
+                    if let Some(handle) = HostHandle::of(&obj, self) {
+                        obj.drop_with_heap(self);
+                        yield_handle_call!(self, cached_frame, HandleRequest::new(handle, HandleOp::GetItem(index)));
+                    }
                     let result = obj.py_getitem(&index, self);
                     obj.drop_with_heap(self);
                     index.drop_with_heap(self);
@@ -1270,16 +1545,28 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 Opcode::LoadAttr => {
                     let name_idx = cached_frame.fetch_u16();
                     let name_id = StringId::from_index(name_idx);
+                    // Sync IP before load (`__annotations__` may call evaluate_function
+                    // which pushes frames and runs a nested run() loop)
+                    self.current_frame_mut().ip = cached_frame.ip;
                     handle_call_result!(self, cached_frame, self.load_attr(name_id));
                 }
                 Opcode::LoadAttrImport => {
                     let name_idx = cached_frame.fetch_u16();
                     let name_id = StringId::from_index(name_idx);
+                    // Sync IP in case a submodule is imported, pushing its body's frame
+                    self.current_frame_mut().ip = cached_frame.ip;
                     handle_call_result!(self, cached_frame, self.load_attr_import(name_id));
                 }
                 Opcode::StoreAttr => {
                     let name_idx = cached_frame.fetch_u16();
                     let name_id = StringId::from_index(name_idx);
+                    if let Some(handle) = HostHandle::of(self.peek(), self) {
+                        let obj = self.pop();
+                        obj.drop_with_heap(self);
+                        let value = self.pop();
+                        let op = HandleOp::SetAttr(EitherStr::Interned(name_id), value);
+                        yield_handle_call!(self, cached_frame, HandleRequest::new(handle, op));
+                    }
                     try_catch_sync!(self, cached_frame, self.store_attr(name_id));
                 }
                 // Control Flow - use cached_frame.ip directly for jumps
@@ -1290,6 +1577,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 Opcode::JumpIfTrue => {
                     let offset = cached_frame.fetch_i16();
                     let cond = self.pop();
+                    branch_on_host_handle!(self, cached_frame, cond, offset, true, false);
                     if cond.py_bool(self) {
                         jump_relative!(cached_frame.ip, offset);
                     }
@@ -1298,6 +1586,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 Opcode::JumpIfFalse => {
                     let offset = cached_frame.fetch_i16();
                     let cond = self.pop();
+                    branch_on_host_handle!(self, cached_frame, cond, offset, false, false);
                     if !cond.py_bool(self) {
                         jump_relative!(cached_frame.ip, offset);
                     }
@@ -1306,6 +1595,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 Opcode::JumpIfTrueOrPop => {
                     let offset = cached_frame.fetch_i16();
                     let value = self.pop();
+                    branch_on_host_handle!(self, cached_frame, value, offset, true, true);
                     if value.py_bool(self) {
                         self.push(value);
                         jump_relative!(cached_frame.ip, offset);
@@ -1316,6 +1606,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 Opcode::JumpIfFalseOrPop => {
                     let offset = cached_frame.fetch_i16();
                     let value = self.pop();
+                    branch_on_host_handle!(self, cached_frame, value, offset, false, true);
                     if value.py_bool(self) {
                         value.drop_with_heap(self);
                     } else {
@@ -1326,6 +1617,10 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 // Iteration - route through exception handling
                 Opcode::GetIter => {
                     let value = self.pop();
+                    if let Some(handle) = HostHandle::of(&value, self) {
+                        value.drop_with_heap(self);
+                        yield_handle_call!(self, cached_frame, HandleRequest::new(handle, HandleOp::Iter));
+                    }
                     // Create a MontyIter from the value and store on heap
                     match MontyIter::new(value, self) {
                         Ok(iter) => match self.heap.allocate(HeapData::Iter(iter)) {
@@ -1389,11 +1684,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                     let (type_id, arg_count) = cached_frame.fetch_u8_u8();
                     let arg_count = arg_count as usize;
 
-                    match self.exec_call_builtin_type(type_id, arg_count) {
-                        Ok(result) => self.push(result),
-                        // IP sync deferred to error path (no frame push possible)
-                        Err(err) => catch_sync!(self, cached_frame, err),
-                    }
+                    handle_call_result!(self, cached_frame, self.exec_call_builtin_type(type_id, arg_count));
                 }
                 Opcode::CallFunctionKw => {
                     // Fetch operands: pos_count, kw_count, then kw_count name indices
@@ -1466,28 +1757,36 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 }
                 // Function Definition
                 Opcode::MakeFunction => {
-                    let (func_idx, defaults_count) = cached_frame.fetch_u16_u8();
+                    let (func_idx, value_count) = cached_frame.fetch_u16_u8();
                     let func_id = FunctionId::from_index(func_idx);
-                    let defaults_count = defaults_count as usize;
-
-                    if defaults_count == 0 {
-                        // No defaults - use inline Value::Function (no heap allocation)
+                    // The `__annotate__` function (if any) is pushed after the defaults and
+                    // counted in the operand
+                    let has_annotate = self.interns.get_function(func_id).has_annotate;
+                    let annotate = has_annotate.then(|| self.pop());
+                    let defaults_count = value_count as usize - usize::from(has_annotate);
+
+                    if defaults_count == 0 && annotate.is_none() {
+                        // No defaults or annotations - use inline Value::Function (no heap allocation)
                         self.push(Value::DefFunction(func_id));
                     } else {
                         // Pop default values from stack (drain maintains order: first pushed = first in vec)
                         let defaults = self.pop_n(defaults_count);
 
                         // Create FunctionDefaults on heap and push reference
-                        let heap_id = self
-                            .heap
-                            .allocate(HeapData::FunctionDefaults(FunctionDefaults { func_id, defaults }))?;
+                        let heap_id = self.heap.allocate(HeapData::FunctionDefaults(FunctionDefaults {
+                            func_id,
+                            defaults,
+                            annotate,
+                        }))?;
                         self.push(Value::Ref(heap_id));
                     }
                 }
                 Opcode::MakeClosure => {
-                    let (func_idx, defaults_count, cell_count) = cached_frame.fetch_u16_u8_u8();
+                    let (func_idx, value_count, cell_count) = cached_frame.fetch_u16_u8_u8();
                     let func_id = FunctionId::from_index(func_idx);
-                    let (defaults_count, cell_count) = (defaults_count as usize, cell_count as usize);
+                    let has_annotate = self.interns.get_function(func_id).has_annotate;
+                    let defaults_count = value_count as usize - usize::from(has_annotate);
+                    let cell_count = cell_count as usize;
 
                     // Pop cells from stack (pushed after defaults, so on top)
                     // Cells are Value::Ref pointing to HeapData::Cell
@@ -1515,6 +1814,9 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                     // Reverse to get original order (individual pops reverse the order)
                     cells.reverse();
 
+                    // The `__annotate__` function (if any) sits between the defaults and the cells
+                    let annotate = has_annotate.then(|| self.pop());
+
                     // Pop default values from stack (drain maintains order: first pushed = first in vec)
                     let defaults = self.pop_n(defaults_count);
 
@@ -1523,9 +1825,21 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                         func_id,
                         cells,
                         defaults,
+                        annotate,
                     }))?;
                     self.push(Value::Ref(heap_id));
                 }
+                Opcode::BuildClass => {
+                    let (name_idx, field_count, method_count) = cached_frame.fetch_u16_u8_u8();
+                    // Methods were pushed after the fields, so they're on top
+                    let methods = self.pop_n(2 * method_count as usize);
+                    let fields = self.pop_n(3 * field_count as usize);
+                    let name = StringId::from_index(name_idx);
+                    match build_class(name, fields, methods, self) {
+                        Ok(class) => self.push(class),
+                        Err(e) => catch_sync!(self, cached_frame, e),
+                    }
+                }
                 // Exception Handling
                 Opcode::Raise => {
                     let exc = self.pop();
@@ -1578,6 +1892,10 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 // Return - reload cache after popping frame
                 Opcode::ReturnValue => {
                     let value = self.pop();
+                    if self.current_frame().callback {
+                        self.pop_frame();
+                        return self.finish_callback(Ok(value));
+                    }
                     if self.frames.len() == 1 {
                         // Last frame - check if this is main task or spawned task
                         let is_main_task = self.is_main_task();
@@ -1642,11 +1960,29 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 // Unpacking - route through exception handling
                 Opcode::UnpackSequence => {
                     let count = cached_frame.fetch_u8() as usize;
+                    if let Some(handle) = HostHandle::of(self.peek(), self) {
+                        let value = self.pop();
+                        value.drop_with_heap(self);
+                        let request =
+                            HandleRequest::new(handle, HandleOp::Iter).with_effect(HandleEffect::Unpack(count));
+                        yield_handle_call!(self, cached_frame, request);
+                    }
                     try_catch_sync!(self, cached_frame, self.unpack_sequence(count));
                 }
                 Opcode::UnpackEx => {
                     let (before, after) = cached_frame.fetch_u8_u8();
-                    try_catch_sync!(self, cached_frame, self.unpack_ex(before as usize, after as usize));
+                    let (before, after) = (before as usize, after as usize);
+                    if let Some(handle) = HostHandle::of(self.peek(), self) {
+                        let value = self.pop();
+                        value.drop_with_heap(self);
+                        let effect = HandleEffect::UnpackEx(before, after);
+                        yield_handle_call!(
+                            self,
+                            cached_frame,
+                            HandleRequest::new(handle, HandleOp::Iter).with_effect(effect)
+                        );
+                    }
+                    try_catch_sync!(self, cached_frame, self.unpack_ex(before, after));
                 }
                 // Special
                 Opcode::Nop => {
@@ -1669,6 +2005,27 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                     let error = ExcType::module_not_found_error(name_str);
                     catch_sync!(self, cached_frame, error);
                 }
+                Opcode::LoadHostModule => {
+                    let const_idx = cached_frame.fetch_u16();
+                    // The constant is an InternString from compile_load_module
+                    let Value::InternString(name_id) = cached_frame.code.constants().get(const_idx) else {
+                        unreachable!("LoadHostModule constant must be an interned string")
+                    };
+                    if let Some(index) = self.interns.find_source_module(self.interns.get_str(*name_id)) {
+                        // Sync IP before the module body's frame is pushed
+                        self.current_frame_mut().ip = cached_frame.ip;
+                        handle_call_result!(self, cached_frame, self.import_source_module(index));
+                    } else {
+                        handle_load_result!(self, cached_frame, self.load_host_module(*name_id));
+                    }
+                }
+                Opcode::InitModule => {
+                    let index = cached_frame.fetch_u16();
+                    match self.init_source_module(index) {
+                        Ok(run_body) => self.push(Value::Bool(run_body)),
+                        Err(e) => catch_sync!(self, cached_frame, e),
+                    }
+                }
                 // Context Managers
                 Opcode::BeforeWith => {
                     // Sync IP before call (py_enter may yield to host).
@@ -1697,13 +2054,31 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
         Ok(())
     }
 
+    /// Loads a host-defined module and pushes it onto the stack.
+    ///
+    /// Returns `FrameExit::ModuleLookup` when the host hasn't declared the module,
+    /// so it can be supplied lazily.
+    fn load_host_module(&mut self, name_id: StringId) -> RunResult<Option<FrameExit>> {
+        let name = self.interns.get_str(name_id);
+        let Some(module) = MontyModule::find(&self.host_modules, name) else {
+            return Ok(Some(FrameExit::ModuleLookup { name_id }));
+        };
+        // Clone so the description isn't borrowed from `self` while building the module
+        let module = module.clone();
+        let heap_id = module.create(name, self)?;
+        self.push(Value::Ref(heap_id));
+        Ok(None)
+    }
+
     /// Resumes execution after an external call completes.
     ///
     /// Pushes the return value onto the stack and continues execution.
     ///
     /// If the paused OS call has a pending file effect, the result is routed
     /// through the corresponding file-state helper before it is pushed back to
-    /// Python.
+    /// Python. Likewise for the result of a host handle operation with a pending
+    /// handle effect. The callbacks passed to the call are released once the
+    /// value, which may be one of them, is converted.
     pub fn resume(&mut self, obj: MontyObject) -> Result<FrameExit, RunError> {
         // Surface resource-exhaustion failures from `to_value` (e.g. a host
         // string whose `heap.allocate` trips `max_memory`) as the same
@@ -1716,10 +2091,30 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                 SimpleException::new(ExcType::RuntimeError, Some(format!("invalid return type: {other}"))).into()
             }
         })?;
+        self.release_callbacks();
+        if let Some(effect) = self.pending_handle_effect.take() {
+            return match self.apply_handle_effect(effect, value) {
+                Ok(()) => self.run(),
+                Err(err) => self.resume_with_exception(err),
+            };
+        }
         if let Some(effect) = self.pending_file_effect.take() {
             let result = match effect {
                 PendingFileEffect::BufferStore { file_id } => apply_buffer_store(file_id, value, self),
-                PendingFileEffect::WritePosition { file_id, .. } => apply_write_position(file_id, value, self),
+                PendingFileEffect::WritePosition {
+                    file_id,
+                    chars_written,
+                    returns_none,
+                    ..
+                } => apply_write_position(file_id, chars_written, value, self).map(|count| {
+                    if returns_none {
+                        count.drop_with_heap(self);
+                        Value::None
+                    } else {
+                        count
+                    }
+                }),
+                PendingFileEffect::OpenText { encoding, errors } => apply_open_text(value, encoding, errors, self),
             };
             match result {
                 Ok(value) => {
@@ -1749,8 +2144,27 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
     /// If caught, continues execution at the handler. If not, propagates the error.
     ///
     /// Also clears any pending file effect so user code that catches a
-    /// host-side OS exception can retry without stale in-flight state.
+    /// host-side OS exception can retry without stale in-flight state, and
+    /// returns the default of `getattr(h, name, default)` (or `False` for
+    /// `hasattr(h, name)`) if the host raised `AttributeError`. The callbacks
+    /// passed to the call are released.
     pub fn resume_with_exception(&mut self, error: RunError) -> Result<FrameExit, RunError> {
+        self.release_callbacks();
+        if let Some(effect) = self.pending_handle_effect.take() {
+            match effect {
+                HandleEffect::AttrDefault(default) if matches!(&error, RunError::Exc(e) if e.exc.exc_type() == ExcType::AttributeError) =>
+                {
+                    self.push(default);
+                    return self.run();
+                }
+                HandleEffect::HasAttr if matches!(&error, RunError::Exc(e) if e.exc.exc_type() == ExcType::AttributeError) =>
+                {
+                    self.push(Value::Bool(false));
+                    return self.run();
+                }
+                effect => effect.drop_with_heap(self),
+            }
+        }
         if let Some(effect) = self.pending_file_effect.take() {
             match effect {
                 PendingFileEffect::BufferStore { file_id } => {
@@ -1764,6 +2178,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                     file_id,
                     previous_position,
                     previous_length,
+                    ..
                 } => {
                     if let HeapReadOutput::OpenFile(mut file) = self.heap.read(file_id) {
                         file.get_mut(self.heap)
@@ -1772,6 +2187,8 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
                     }
                     self.heap.dec_ref(file_id);
                 }
+                // `open()` failed on the host, so there is no file to configure.
+                PendingFileEffect::OpenText { .. } => {}
             }
         }
         // Use the normal exception handling mechanism
@@ -1939,6 +2356,35 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
         )
     }
 
+    /// Returns the source position `depth` frames up the call stack.
+    ///
+    /// Depth 0 is the instruction currently executing, depth 1 the call site of
+    /// the current frame in its caller, and so on. Returns `None` once `depth`
+    /// walks past the module frame, mirroring how `warnings.warn(stacklevel=...)`
+    /// falls back to the `sys` module in CPython.
+    pub(crate) fn caller_position(&self, depth: usize) -> Option<CodeRange> {
+        if depth == 0 {
+            return self.current_position();
+        }
+        let index = self.frames.len().checked_sub(depth)?;
+        self.frames[index].call_position
+    }
+
+    /// Returns the name of the function whose code holds [`caller_position`](Self::caller_position)
+    /// for the same `depth`: the function name, or `<module>` for module-level code.
+    pub(crate) fn caller_frame_name(&self, depth: usize) -> Option<StringId> {
+        let index = self.frames.len().checked_sub(depth + 1)?;
+        Some(match self.frames[index].function_id {
+            Some(func_id) => self.interns.get_function(func_id).name.name_id,
+            None => StaticStrings::Module.into(),
+        })
+    }
+
+    /// Returns the exception being handled by the innermost active `except` block, if any.
+    pub(crate) fn handled_exception(&self) -> Option<&Value> {
+        self.exception_stack.last()
+    }
+
     // ========================================================================
     // Variable Operations
     // ========================================================================
@@ -2016,6 +2462,18 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
         name.parse::<Builtins>().ok()
     }
 
+    /// Returns the name of a global slot.
+    ///
+    /// Global names are read from the module code, which the compiler seeds with
+    /// every global; function code only names its own local slots. Slots after the
+    /// module's belong to the supplied source modules.
+    fn global_name(&self, slot: u16) -> Option<StringId> {
+        self.module_code
+            .unwrap_or(self.current_frame().code)
+            .local_name(slot)
+            .or_else(|| self.interns.source_module_global_name(slot))
+    }
+
     /// Creates a NameError for an undefined global variable.
     fn name_error(&self, slot: u16, name: Option<StringId>) -> RunError {
         let name_str = match name {
@@ -2050,7 +2508,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
 
         // Check for undefined value — raise appropriate error or yield to host
         if matches!(value, Value::Undefined) {
-            let name = self.current_frame().code.local_name(slot);
+            let name = self.global_name(slot);
 
             let Some(name_id) = name else {
                 // No name available — raise NameError directly
@@ -2085,7 +2543,7 @@ impl<'h, T: ResourceTracker> VM<'h, T> {
         // TODO: the `Undefined` branch is currently unreachable from Python source,
         // needs support for the `del` statement.
         if matches!(self.globals[slot as usize], Value::Undefined) {
-            let name = self.current_frame().code.local_name(slot);
+            let name = self.global_name(slot);
             return Err(self.name_error(slot, name));
         }
         let old_value = mem::replace(&mut self.globals[slot as usize], Value::Undefined);
@@ -2171,18 +2629,18 @@ impl<T: ResourceTracker> ContainsHeap for VM<'_, T> {
 /// `take_globals`) are harmlessly drained as empty.
 impl<T: ResourceTracker> Drop for VM<'_, T> {
     fn drop(&mut self) {
-        if let Some(effect) = self.pending_file_effect.take() {
-            let file_id = match effect {
-                PendingFileEffect::BufferStore { file_id } | PendingFileEffect::WritePosition { file_id, .. } => {
-                    file_id
-                }
-            };
-            self.heap.dec_ref(file_id);
-        }
+        self.pending_file_effect.take().drop_with_heap(self.heap);
+        self.pending_handle_effect.take().drop_with_heap(self.heap);
+        self.paused_calls.drain(..).drop_with_heap(self.heap);
+        mem::take(&mut self.callbacks).drop_with_heap(self.heap);
         self.exception_stack.drain(..).drop_with_heap(self.heap);
+        self.stdout_redirects.drain(..).drop_with_heap(self.heap);
+        mem::take(&mut self.warnings).drop_with_heap(self.heap);
+        mem::take(&mut self.logging).drop_with_heap(self.heap);
         self.cleanup_current_task();
         self.scheduler.cleanup(self.heap);
         self.globals.drain(..).drop_with_heap(self.heap);
+        self.imported_modules.drain(..).drop_with_heap(self.heap);
         self.json_string_cache.drop_all(self.heap);
     }
 }
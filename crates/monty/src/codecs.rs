@@ -0,0 +1,431 @@
+//! Text codecs shared by `str.encode()`, `bytes.decode()`, `bytearray()` and `open(encoding=...)`.
+//!
+//! Supports UTF-8, Latin-1, ASCII, cp1252 and UTF-16/UTF-32 (with and without an
+//! explicit byte order) under CPython's names and common aliases. Codec failures go
+//! through the `errors` handler: `strict` raises `UnicodeEncodeError` /
+//! `UnicodeDecodeError` with CPython's `start`/`end`/`reason`, and `ignore`,
+//! `replace`, `backslashreplace`, `surrogateescape` and (when encoding)
+//! `xmlcharrefreplace` substitute for the failing range.
+//!
+//! As in CPython, the error handler name is only looked up once a character or
+//! byte actually fails, so `'abc'.encode('ascii', 'bogus')` succeeds.
+//!
+//! Monty strings can't hold lone surrogates, so `surrogateescape` raises
+//! `NotImplementedError` instead of decoding a byte to U+DC80-U+DCFF, and
+//! encoding never sees a surrogate to escape.
+
+use std::fmt::Write;
+
+use crate::{
+    exception_private::{ExcType, RunResult, SimpleException},
+    resource::{ResourceTracker, check_estimated_size},
+};
+
+/// A text encoding supported by Monty's codec layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Codec {
+    Utf8,
+    Latin1,
+    Ascii,
+    Cp1252,
+    /// `None` reads the byte order from a BOM (defaulting to little-endian) and
+    /// writes a little-endian BOM, like CPython's `utf-16`.
+    Utf16(Option<Endian>),
+    /// As [`Codec::Utf16`], for `utf-32`.
+    Utf32(Option<Endian>),
+}
+
+/// Byte order of the UTF-16 and UTF-32 codecs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Endian {
+    Little,
+    Big,
+}
+
+/// cp1252 code points for bytes `0x80..=0x9F`; the other bytes match Latin-1.
+const CP1252_HIGH: [Option<char>; 32] = [
+    Some('\u{20ac}'),
+    None,
+    Some('\u{201a}'),
+    Some('\u{0192}'),
+    Some('\u{201e}'),
+    Some('\u{2026}'),
+    Some('\u{2020}'),
+    Some('\u{2021}'),
+    Some('\u{02c6}'),
+    Some('\u{2030}'),
+    Some('\u{0160}'),
+    Some('\u{2039}'),
+    Some('\u{0152}'),
+    None,
+    Some('\u{017d}'),
+    None,
+    None,
+    Some('\u{2018}'),
+    Some('\u{2019}'),
+    Some('\u{201c}'),
+    Some('\u{201d}'),
+    Some('\u{2022}'),
+    Some('\u{2013}'),
+    Some('\u{2014}'),
+    Some('\u{02dc}'),
+    Some('\u{2122}'),
+    Some('\u{0161}'),
+    Some('\u{203a}'),
+    Some('\u{0153}'),
+    None,
+    Some('\u{017e}'),
+    Some('\u{0178}'),
+];
+
+/// Reason CPython's charmap codecs give for unmapped characters and bytes.
+const CHARMAP_UNDEFINED: &str = "character maps to <undefined>";
+
+impl Codec {
+    /// Looks up a codec by name, normalizing case and separators like CPython's
+    /// `encodings.normalize_encoding`.
+    ///
+    /// Raises `LookupError: unknown encoding: {name}` for unsupported codecs.
+    pub fn lookup(name: &str) -> RunResult<Self> {
+        let mut normalized = String::with_capacity(name.len());
+        for part in name
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '.')
+            .filter(|part| !part.is_empty())
+        {
+            if !normalized.is_empty() {
+                normalized.push('_');
+            }
+            normalized.push_str(&part.to_ascii_lowercase());
+        }
+        let codec = match normalized.as_str() {
+            "utf_8" | "utf8" | "u8" | "utf" | "cp65001" => Self::Utf8,
+            "latin_1" | "latin1" | "latin" | "l1" | "iso_8859_1" | "iso8859_1" | "8859" | "cp819" => Self::Latin1,
+            "ascii" | "us_ascii" | "646" => Self::Ascii,
+            "cp1252" | "windows_1252" | "1252" => Self::Cp1252,
+            "utf_16" | "utf16" | "u16" => Self::Utf16(None),
+            "utf_16_le" | "utf_16le" => Self::Utf16(Some(Endian::Little)),
+            "utf_16_be" | "utf_16be" => Self::Utf16(Some(Endian::Big)),
+            "utf_32" | "utf32" | "u32" => Self::Utf32(None),
+            "utf_32_le" | "utf_32le" => Self::Utf32(Some(Endian::Little)),
+            "utf_32_be" | "utf_32be" => Self::Utf32(Some(Endian::Big)),
+            _ => return Err(ExcType::lookup_error_unknown_encoding(name)),
+        };
+        Ok(codec)
+    }
+
+    /// Returns this codec without a leading BOM on encode, for text appended
+    /// after the start of a file.
+    #[must_use]
+    pub fn without_bom(self) -> Self {
+        match self {
+            Self::Utf16(None) => Self::Utf16(Some(Endian::Little)),
+            Self::Utf32(None) => Self::Utf32(Some(Endian::Little)),
+            codec => codec,
+        }
+    }
+
+    /// Encodes `s`, applying the `errors` handler to characters the codec can't represent.
+    pub fn encode(self, s: &str, errors: &str, tracker: &impl ResourceTracker) -> RunResult<Vec<u8>> {
+        // Every handler and codec produces at most 4 bytes per input byte (UTF-32 of
+        // ASCII, `&#1114111;` for a 4-byte char), plus a BOM.
+        check_estimated_size(s.len().saturating_mul(4).saturating_add(4), tracker)?;
+        match self {
+            Self::Utf8 => Ok(s.as_bytes().to_vec()),
+            Self::Utf16(endian) => {
+                let mut out = Vec::with_capacity(s.len() * 2 + 2);
+                if endian.is_none() {
+                    out.extend_from_slice(&[0xff, 0xfe]);
+                }
+                for unit in s.encode_utf16() {
+                    match endian {
+                        Some(Endian::Big) => out.extend_from_slice(&unit.to_be_bytes()),
+                        _ => out.extend_from_slice(&unit.to_le_bytes()),
+                    }
+                }
+                Ok(out)
+            }
+            Self::Utf32(endian) => {
+                let mut out = Vec::with_capacity(s.len() * 4 + 4);
+                if endian.is_none() {
+                    out.extend_from_slice(&[0xff, 0xfe, 0, 0]);
+                }
+                for c in s.chars() {
+                    match endian {
+                        Some(Endian::Big) => out.extend_from_slice(&u32::from(c).to_be_bytes()),
+                        _ => out.extend_from_slice(&u32::from(c).to_le_bytes()),
+                    }
+                }
+                Ok(out)
+            }
+            Self::Latin1 | Self::Ascii | Self::Cp1252 => self.encode_single_byte(s, errors),
+        }
+    }
+
+    /// Decodes `bytes`, applying the `errors` handler to byte ranges the codec can't decode.
+    pub fn decode(self, bytes: &[u8], errors: &str, tracker: &impl ResourceTracker) -> RunResult<String> {
+        // Each input byte decodes to at most 4 bytes of UTF-8 (`\xNN` from
+        // `backslashreplace`, or a 3-byte cp1252 character).
+        check_estimated_size(bytes.len().saturating_mul(4), tracker)?;
+        let mut out = String::with_capacity(bytes.len());
+        match self {
+            Self::Utf8 => decode_utf8(bytes, errors, &mut out)?,
+            Self::Latin1 => out.extend(bytes.iter().map(|&b| char::from(b))),
+            Self::Ascii | Self::Cp1252 => {
+                for (pos, &b) in bytes.iter().enumerate() {
+                    match self.decode_byte(b) {
+                        Some(c) => out.push(c),
+                        None => {
+                            let (name, reason) = self.error_name_and_reason();
+                            handle_decode_error(name, bytes, pos, pos + 1, reason, errors, &mut out)?;
+                        }
+                    }
+                }
+            }
+            Self::Utf16(endian) => {
+                let (endian, start) = detect_bom(bytes, endian, &[0xff, 0xfe], &[0xfe, 0xff]);
+                decode_utf16(bytes, start, endian, errors, &mut out)?;
+            }
+            Self::Utf32(endian) => {
+                let (endian, start) = detect_bom(bytes, endian, &[0xff, 0xfe, 0, 0], &[0, 0, 0xfe, 0xff]);
+                decode_utf32(bytes, start, endian, errors, &mut out)?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Encodes with one of the single-byte codecs, grouping each run of
+    /// unencodable characters into one error like CPython.
+    fn encode_single_byte(self, s: &str, errors: &str) -> RunResult<Vec<u8>> {
+        let mut out = Vec::with_capacity(s.len());
+        let mut chars = s.chars().enumerate().peekable();
+        while let Some((start, c)) = chars.next() {
+            if let Some(b) = self.encode_char(c) {
+                out.push(b);
+                continue;
+            }
+            let mut run = vec![c];
+            while let Some(&(_, next)) = chars.peek()
+                && self.encode_char(next).is_none()
+            {
+                run.push(next);
+                chars.next();
+            }
+            let end = start + run.len();
+            match errors {
+                "ignore" => {}
+                "replace" => out.extend(run.iter().map(|_| b'?')),
+                "backslashreplace" => {
+                    for c in run {
+                        out.extend_from_slice(backslash_escape(c).as_bytes());
+                    }
+                }
+                "xmlcharrefreplace" => {
+                    for c in run {
+                        out.extend_from_slice(format!("&#{};", u32::from(c)).as_bytes());
+                    }
+                }
+                // No Monty string holds a lone surrogate, so `surrogateescape` has
+                // nothing to map back and fails like `strict`.
+                "strict" | "surrogateescape" => {
+                    let (name, reason) = self.error_name_and_reason();
+                    return Err(ExcType::unicode_encode_error(name, s, start, end, reason));
+                }
+                other => return Err(ExcType::lookup_error_unknown_error_handler(other)),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Encodes a single character with a single-byte codec.
+    fn encode_char(self, c: char) -> Option<u8> {
+        let n = u32::from(c);
+        match self {
+            Self::Ascii => u8::try_from(n).ok().filter(u8::is_ascii),
+            Self::Cp1252 => match n {
+                0..=0x7f | 0xa0..=0xff => u8::try_from(n).ok(),
+                _ => CP1252_HIGH
+                    .iter()
+                    .position(|&mapped| mapped == Some(c))
+                    .and_then(|i| u8::try_from(0x80 + i).ok()),
+            },
+            _ => u8::try_from(n).ok(),
+        }
+    }
+
+    /// Decodes a single byte with a single-byte codec.
+    fn decode_byte(self, b: u8) -> Option<char> {
+        match (self, b) {
+            (Self::Ascii, 0x80..) => None,
+            (Self::Cp1252, 0x80..=0x9f) => CP1252_HIGH[usize::from(b - 0x80)],
+            _ => Some(char::from(b)),
+        }
+    }
+
+    /// The codec name and reason CPython reports in errors from a single-byte codec.
+    fn error_name_and_reason(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Ascii => ("ascii", "ordinal not in range(128)"),
+            Self::Cp1252 => ("charmap", CHARMAP_UNDEFINED),
+            _ => ("latin-1", "ordinal not in range(256)"),
+        }
+    }
+}
+
+/// Decodes UTF-8, reporting each maximal invalid subsequence as one error like CPython.
+fn decode_utf8(bytes: &[u8], errors: &str, out: &mut String) -> RunResult<()> {
+    let mut pos = 0;
+    for chunk in bytes.utf8_chunks() {
+        out.push_str(chunk.valid());
+        pos += chunk.valid().len();
+        let invalid = chunk.invalid();
+        if invalid.is_empty() {
+            continue;
+        }
+        let end = pos + invalid.len();
+        let reason = if !matches!(invalid[0], 0xc2..=0xf4) {
+            "invalid start byte"
+        } else if end == bytes.len() {
+            "unexpected end of data"
+        } else {
+            "invalid continuation byte"
+        };
+        handle_decode_error("utf-8", bytes, pos, end, reason, errors, out)?;
+        pos = end;
+    }
+    Ok(())
+}
+
+/// Picks the byte order of a UTF-16/UTF-32 decode and where the data starts.
+///
+/// An explicit byte order never skips a BOM; otherwise a leading BOM selects the
+/// byte order and is skipped, and data without one is little-endian.
+fn detect_bom(bytes: &[u8], endian: Option<Endian>, le_bom: &[u8], be_bom: &[u8]) -> (Endian, usize) {
+    match endian {
+        Some(endian) => (endian, 0),
+        None if bytes.starts_with(le_bom) => (Endian::Little, le_bom.len()),
+        None if bytes.starts_with(be_bom) => (Endian::Big, be_bom.len()),
+        None => (Endian::Little, 0),
+    }
+}
+
+fn decode_utf16(bytes: &[u8], mut pos: usize, endian: Endian, errors: &str, out: &mut String) -> RunResult<()> {
+    let name = match endian {
+        Endian::Little => "utf-16-le",
+        Endian::Big => "utf-16-be",
+    };
+    let unit_at = |pos: usize| {
+        let pair = [bytes[pos], bytes[pos + 1]];
+        match endian {
+            Endian::Little => u16::from_le_bytes(pair),
+            Endian::Big => u16::from_be_bytes(pair),
+        }
+    };
+    while pos < bytes.len() {
+        if pos + 2 > bytes.len() {
+            return handle_decode_error(name, bytes, pos, bytes.len(), "truncated data", errors, out);
+        }
+        let unit = unit_at(pos);
+        if !(0xd800..0xe000).contains(&unit) {
+            out.push(char::from_u32(u32::from(unit)).expect("non-surrogate UTF-16 unit is a char"));
+            pos += 2;
+        } else if unit >= 0xdc00 {
+            handle_decode_error(name, bytes, pos, pos + 2, "illegal encoding", errors, out)?;
+            pos += 2;
+        } else if pos + 4 > bytes.len() {
+            return handle_decode_error(name, bytes, pos, bytes.len(), "unexpected end of data", errors, out);
+        } else {
+            let low = unit_at(pos + 2);
+            if (0xdc00..0xe000).contains(&low) {
+                let n = 0x10000 + ((u32::from(unit) - 0xd800) << 10) + (u32::from(low) - 0xdc00);
+                out.push(char::from_u32(n).expect("UTF-16 surrogate pair is a char"));
+                pos += 4;
+            } else {
+                handle_decode_error(name, bytes, pos, pos + 2, "illegal UTF-16 surrogate", errors, out)?;
+                pos += 2;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn decode_utf32(bytes: &[u8], mut pos: usize, endian: Endian, errors: &str, out: &mut String) -> RunResult<()> {
+    let name = match endian {
+        Endian::Little => "utf-32-le",
+        Endian::Big => "utf-32-be",
+    };
+    while pos < bytes.len() {
+        if pos + 4 > bytes.len() {
+            return handle_decode_error(name, bytes, pos, bytes.len(), "truncated data", errors, out);
+        }
+        let quad = [bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]];
+        let n = match endian {
+            Endian::Little => u32::from_le_bytes(quad),
+            Endian::Big => u32::from_be_bytes(quad),
+        };
+        match char::from_u32(n) {
+            Some(c) => out.push(c),
+            None => {
+                let reason = if n < 0x11_0000 {
+                    "code point in surrogate code point range(0xd800, 0xe000)"
+                } else {
+                    "code point not in range(0x110000)"
+                };
+                handle_decode_error(name, bytes, pos, pos + 4, reason, errors, out)?;
+            }
+        }
+        pos += 4;
+    }
+    Ok(())
+}
+
+/// Applies the `errors` handler to the undecodable bytes `start..end`.
+fn handle_decode_error(
+    encoding: &str,
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    reason: &str,
+    errors: &str,
+    out: &mut String,
+) -> RunResult<()> {
+    let strict = || ExcType::unicode_decode_error(encoding, bytes, start, end, reason);
+    match errors {
+        "strict" => return Err(strict()),
+        "ignore" => {}
+        "replace" => out.push(char::REPLACEMENT_CHARACTER),
+        "backslashreplace" => {
+            for b in &bytes[start..end] {
+                write!(out, "\\x{b:02x}").expect("writing to a String can't fail");
+            }
+        }
+        // CPython maps each byte to a lone surrogate, which Monty strings can't hold;
+        // anything else would lose the byte. Like CPython, ASCII bytes aren't escaped
+        // and fail as with `strict`.
+        "surrogateescape" => {
+            if bytes[start..end].iter().any(u8::is_ascii) {
+                return Err(strict());
+            }
+            return Err(
+                ExcType::not_implemented("decoding undecodable bytes with 'surrogateescape' is not supported").into(),
+            );
+        }
+        "xmlcharrefreplace" => {
+            return Err(SimpleException::new_msg(
+                ExcType::TypeError,
+                "don't know how to handle UnicodeDecodeError in error callback",
+            )
+            .into());
+        }
+        other => return Err(ExcType::lookup_error_unknown_error_handler(other)),
+    }
+    Ok(())
+}
+
+/// Escapes a character the way the `backslashreplace` handler does: `\xNN`, `\uNNNN` or `\UNNNNNNNN`.
+pub(crate) fn backslash_escape(c: char) -> String {
+    match u32::from(c) {
+        n @ 0..=0xff => format!("\\x{n:02x}"),
+        n @ 0x100..=0xffff => format!("\\u{n:04x}"),
+        n => format!("\\U{n:08x}"),
+    }
+}
@@ -0,0 +1,650 @@
+//! Typed conversion between Rust values and [`MontyObject`].
+//!
+//! [`IntoMonty`] and [`FromMonty`] let the [`FunctionRegistry`](crate::FunctionRegistry)
+//! call plain Rust functions: arguments are converted from `MontyObject`s, and the
+//! return value back into one. Both traits also describe their type as a Python
+//! annotation, which the registry uses to write a stub file for type checking.
+//!
+//! Impls live here for the std types with an obvious Python counterpart. Structs
+//! and enums get them from `#[derive(IntoMonty, FromMonty)]`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    hash::{BuildHasher, Hash},
+};
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use crate::{DictPairs, MontyObject};
+
+/// Converts a Rust value into a [`MontyObject`].
+///
+/// Derive it with `#[derive(IntoMonty)]`: a struct with named fields becomes a
+/// `MontyObject::Dataclass` (or a `Dict` with `#[monty(dict)]`), a newtype struct
+/// becomes its field, and an enum becomes a tagged value, see the derive's docs.
+pub trait IntoMonty {
+    /// Converts `self` into a `MontyObject`.
+    fn into_monty(self) -> MontyObject;
+
+    /// Returns the Python annotation for this type, e.g. `list[int]`.
+    ///
+    /// Types that need a definition, like a dataclass, add it to `stubs`.
+    fn type_hint(stubs: &mut TypeStubs) -> String;
+}
+
+/// Converts a [`MontyObject`] into a Rust value.
+///
+/// Conversions are strict, like the `TryFrom<&MontyObject>` impls: a `str` isn't
+/// parsed into an `i64` and a `bool` isn't an integer. The exception is `f64`,
+/// which accepts an `int` as Python does.
+pub trait FromMonty: Sized {
+    /// Whether `None` converts to this type, so a parameter or field of this type
+    /// can be omitted. Only `Option` sets this.
+    const OPTIONAL: bool = false;
+
+    /// Converts `object` into `Self`.
+    ///
+    /// # Errors
+    /// Returns [`FromMontyError`] if `object` has the wrong type or shape.
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError>;
+
+    /// Returns the Python annotation for this type, e.g. `list[int]`.
+    ///
+    /// Types that need a definition, like a dataclass, add it to `stubs`.
+    fn type_hint(stubs: &mut TypeStubs) -> String;
+}
+
+/// Error returned when a [`MontyObject`] can't be converted by [`FromMonty`].
+///
+/// Records where in the value the conversion failed, so the registry can report
+/// e.g. `fetch() argument 'pages[1].status' must be int, not str`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromMontyError {
+    /// Path to the part of the value that failed, e.g. `[1].status`.
+    path: String,
+    /// Why it failed, phrased to follow the path: `must be int, not str`.
+    reason: String,
+}
+
+impl FromMontyError {
+    /// Creates an error with a reason that reads after the failing value's name,
+    /// e.g. `is out of range`.
+    #[must_use]
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            path: String::new(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error for an object that isn't of the `expected` type.
+    #[must_use]
+    pub fn wrong_type(expected: &str, actual: &MontyObject) -> Self {
+        Self::new(format!("must be {expected}, not {}", actual.type_name()))
+    }
+
+    /// Creates an error for a required field missing from a dataclass or dict.
+    #[must_use]
+    pub fn missing_field(type_name: &str, field: &str) -> Self {
+        Self::new(format!("is missing field '{field}' of {type_name}"))
+    }
+
+    /// Records that the error happened in field `name` of the value.
+    #[must_use]
+    pub fn in_field(mut self, name: &str) -> Self {
+        self.path.insert_str(0, &format!(".{name}"));
+        self
+    }
+
+    /// Records that the error happened in item `index` of the value.
+    #[must_use]
+    pub fn in_item(mut self, index: usize) -> Self {
+        self.path.insert_str(0, &format!("[{index}]"));
+        self
+    }
+
+    /// Records that the error happened in the dict value for the key with repr `key`.
+    #[must_use]
+    pub fn in_value(mut self, key: &str) -> Self {
+        self.path.insert_str(0, &format!("[{key}]"));
+        self
+    }
+
+    /// Records that the error happened in the dict key with repr `key`.
+    #[must_use]
+    pub fn in_key(mut self, key: &str) -> Self {
+        self.path.insert_str(0, &format!("[key {key}]"));
+        self
+    }
+
+    /// Returns the path to the part of the value that failed, empty if it's the value itself.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns why the conversion failed, e.g. `must be int, not str`.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for FromMontyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value{} {}", self.path, self.reason)
+    }
+}
+
+impl std::error::Error for FromMontyError {}
+
+/// Collects the definitions and imports a stub file needs for its annotations.
+///
+/// [`IntoMonty::type_hint`] and [`FromMonty::type_hint`] add to it while describing
+/// their type; [`FunctionRegistry::type_stubs`](crate::FunctionRegistry::type_stubs)
+/// renders it ahead of the function definitions.
+#[derive(Debug, Default)]
+pub struct TypeStubs {
+    /// Names imported from each module, e.g. `typing` → `{Any, Literal}`.
+    imports: BTreeMap<&'static str, BTreeSet<&'static str>>,
+    /// Definitions in the order they were added, keyed by the name they define.
+    definitions: Vec<(String, String)>,
+}
+
+impl TypeStubs {
+    /// Adds `from {module} import {name}` to the stubs.
+    pub fn import(&mut self, module: &'static str, name: &'static str) {
+        self.imports.entry(module).or_default().insert(name);
+    }
+
+    /// Adds the definition of `name` built by `define`, unless it's already defined.
+    ///
+    /// The name is reserved before `define` runs, so a recursive type only defines
+    /// itself once.
+    pub fn define(&mut self, name: &str, define: impl FnOnce(&mut Self) -> String) {
+        if self.definitions.iter().any(|(defined, _)| defined == name) {
+            return;
+        }
+        let index = self.definitions.len();
+        self.definitions.push((name.to_owned(), String::new()));
+        let definition = define(self);
+        self.definitions[index].1 = definition;
+    }
+
+    /// Renders the imports and definitions followed by `body`, separated by blank lines.
+    pub(crate) fn render(self, body: &str) -> String {
+        let mut sections = Vec::new();
+        if !self.imports.is_empty() {
+            let imports: Vec<String> = self
+                .imports
+                .iter()
+                .map(|(module, names)| {
+                    let names: Vec<&str> = names.iter().copied().collect();
+                    format!("from {module} import {}", names.join(", "))
+                })
+                .collect();
+            sections.push(imports.join("\n"));
+        }
+        sections.extend(self.definitions.into_iter().map(|(_, definition)| definition));
+        sections.push(body.to_owned());
+        let mut stubs = sections.join("\n\n");
+        stubs.push('\n');
+        stubs
+    }
+}
+
+impl IntoMonty for MontyObject {
+    fn into_monty(self) -> Self {
+        self
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        stubs.import("typing", "Any");
+        "Any".to_owned()
+    }
+}
+
+impl FromMonty for MontyObject {
+    fn from_monty(object: Self) -> Result<Self, FromMontyError> {
+        Ok(object)
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        stubs.import("typing", "Any");
+        "Any".to_owned()
+    }
+}
+
+impl IntoMonty for () {
+    fn into_monty(self) -> MontyObject {
+        MontyObject::None
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "None".to_owned()
+    }
+}
+
+impl FromMonty for () {
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        match object {
+            MontyObject::None => Ok(()),
+            _ => Err(FromMontyError::wrong_type("None", &object)),
+        }
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "None".to_owned()
+    }
+}
+
+impl IntoMonty for bool {
+    fn into_monty(self) -> MontyObject {
+        MontyObject::Bool(self)
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "bool".to_owned()
+    }
+}
+
+impl FromMonty for bool {
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        match object {
+            MontyObject::Bool(b) => Ok(b),
+            _ => Err(FromMontyError::wrong_type("bool", &object)),
+        }
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "bool".to_owned()
+    }
+}
+
+/// Implements the traits for integer types, which convert to `Int`, or `BigInt`
+/// if they don't fit in an `i64`.
+macro_rules! impl_int {
+    ($($int:ty),*) => {$(
+        impl IntoMonty for $int {
+            fn into_monty(self) -> MontyObject {
+                match self.to_i64() {
+                    Some(i) => MontyObject::Int(i),
+                    None => MontyObject::BigInt(BigInt::from(self)),
+                }
+            }
+
+            fn type_hint(_stubs: &mut TypeStubs) -> String {
+                "int".to_owned()
+            }
+        }
+
+        impl FromMonty for $int {
+            fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+                let converted = match &object {
+                    MontyObject::Int(i) => Self::from_i64(*i),
+                    MontyObject::BigInt(i) => match i.to_i128() {
+                        Some(i) => Self::from_i128(i),
+                        None => i.to_u128().and_then(Self::from_u128),
+                    },
+                    _ => return Err(FromMontyError::wrong_type("int", &object)),
+                };
+                converted.ok_or_else(|| FromMontyError::new(concat!("is out of range for ", stringify!($int))))
+            }
+
+            fn type_hint(_stubs: &mut TypeStubs) -> String {
+                "int".to_owned()
+            }
+        }
+    )*};
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl IntoMonty for f64 {
+    fn into_monty(self) -> MontyObject {
+        MontyObject::Float(self)
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "float".to_owned()
+    }
+}
+
+impl FromMonty for f64 {
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        Self::try_from(&object).map_err(|_| FromMontyError::wrong_type("float", &object))
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "float".to_owned()
+    }
+}
+
+impl IntoMonty for f32 {
+    fn into_monty(self) -> MontyObject {
+        MontyObject::Float(f64::from(self))
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "float".to_owned()
+    }
+}
+
+impl FromMonty for f32 {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "Python floats are f64, narrowing is expected"
+    )]
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        f64::from_monty(object).map(|f| f as Self)
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "float".to_owned()
+    }
+}
+
+impl IntoMonty for String {
+    fn into_monty(self) -> MontyObject {
+        MontyObject::String(self)
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "str".to_owned()
+    }
+}
+
+impl FromMonty for String {
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        match object {
+            MontyObject::String(s) => Ok(s),
+            _ => Err(FromMontyError::wrong_type("str", &object)),
+        }
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "str".to_owned()
+    }
+}
+
+impl IntoMonty for &str {
+    fn into_monty(self) -> MontyObject {
+        MontyObject::String(self.to_owned())
+    }
+
+    fn type_hint(_stubs: &mut TypeStubs) -> String {
+        "str".to_owned()
+    }
+}
+
+impl<T: IntoMonty> IntoMonty for Box<T> {
+    fn into_monty(self) -> MontyObject {
+        (*self).into_monty()
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        T::type_hint(stubs)
+    }
+}
+
+impl<T: FromMonty> FromMonty for Box<T> {
+    const OPTIONAL: bool = T::OPTIONAL;
+
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        T::from_monty(object).map(Self::new)
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        T::type_hint(stubs)
+    }
+}
+
+impl<T: IntoMonty> IntoMonty for Option<T> {
+    fn into_monty(self) -> MontyObject {
+        self.map_or(MontyObject::None, T::into_monty)
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("{} | None", T::type_hint(stubs))
+    }
+}
+
+impl<T: FromMonty> FromMonty for Option<T> {
+    const OPTIONAL: bool = true;
+
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        match object {
+            MontyObject::None => Ok(None),
+            object => T::from_monty(object).map(Some),
+        }
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("{} | None", T::type_hint(stubs))
+    }
+}
+
+impl<T: IntoMonty> IntoMonty for Vec<T> {
+    fn into_monty(self) -> MontyObject {
+        MontyObject::List(self.into_iter().map(T::into_monty).collect())
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("list[{}]", T::type_hint(stubs))
+    }
+}
+
+/// Accepts a list or a tuple.
+impl<T: FromMonty> FromMonty for Vec<T> {
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        match object {
+            MontyObject::List(items) | MontyObject::Tuple(items) => items
+                .into_iter()
+                .enumerate()
+                .map(|(index, item)| T::from_monty(item).map_err(|err| err.in_item(index)))
+                .collect(),
+            _ => Err(FromMontyError::wrong_type("list", &object)),
+        }
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("list[{}]", T::type_hint(stubs))
+    }
+}
+
+/// Converts the entries of a map into a `Dict`.
+fn dict_into_monty<K: IntoMonty, V: IntoMonty>(entries: impl IntoIterator<Item = (K, V)>) -> MontyObject {
+    let pairs: Vec<(MontyObject, MontyObject)> = entries
+        .into_iter()
+        .map(|(key, value)| (key.into_monty(), value.into_monty()))
+        .collect();
+    MontyObject::Dict(DictPairs::from(pairs))
+}
+
+/// Converts a `Dict` into the entries of a map.
+fn dict_from_monty<K: FromMonty, V: FromMonty, M: FromIterator<(K, V)>>(
+    object: MontyObject,
+) -> Result<M, FromMontyError> {
+    match object {
+        MontyObject::Dict(pairs) => pairs
+            .into_iter()
+            .map(|(key, value)| {
+                let key_repr = key.py_repr();
+                let key = K::from_monty(key).map_err(|err| err.in_key(&key_repr))?;
+                let value = V::from_monty(value).map_err(|err| err.in_value(&key_repr))?;
+                Ok((key, value))
+            })
+            .collect(),
+        _ => Err(FromMontyError::wrong_type("dict", &object)),
+    }
+}
+
+impl<K: IntoMonty, V: IntoMonty, S> IntoMonty for HashMap<K, V, S> {
+    fn into_monty(self) -> MontyObject {
+        dict_into_monty(self)
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("dict[{}, {}]", K::type_hint(stubs), V::type_hint(stubs))
+    }
+}
+
+impl<K: FromMonty + Eq + Hash, V: FromMonty, S: BuildHasher + Default> FromMonty for HashMap<K, V, S> {
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        dict_from_monty(object)
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("dict[{}, {}]", K::type_hint(stubs), V::type_hint(stubs))
+    }
+}
+
+impl<K: IntoMonty, V: IntoMonty> IntoMonty for BTreeMap<K, V> {
+    fn into_monty(self) -> MontyObject {
+        dict_into_monty(self)
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("dict[{}, {}]", K::type_hint(stubs), V::type_hint(stubs))
+    }
+}
+
+impl<K: FromMonty + Ord, V: FromMonty> FromMonty for BTreeMap<K, V> {
+    fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+        dict_from_monty(object)
+    }
+
+    fn type_hint(stubs: &mut TypeStubs) -> String {
+        format!("dict[{}, {}]", K::type_hint(stubs), V::type_hint(stubs))
+    }
+}
+
+/// Implements the traits for tuples, which convert to a `Tuple` of the same length.
+macro_rules! impl_tuple {
+    ($len:literal; $($ty:ident $var:ident $index:tt),+) => {
+        impl<$($ty: IntoMonty),+> IntoMonty for ($($ty,)+) {
+            fn into_monty(self) -> MontyObject {
+                MontyObject::Tuple(vec![$(self.$index.into_monty()),+])
+            }
+
+            fn type_hint(stubs: &mut TypeStubs) -> String {
+                let items = [$($ty::type_hint(stubs)),+];
+                format!("tuple[{}]", items.join(", "))
+            }
+        }
+
+        impl<$($ty: FromMonty),+> FromMonty for ($($ty,)+) {
+            fn from_monty(object: MontyObject) -> Result<Self, FromMontyError> {
+                let MontyObject::Tuple(items) = object else {
+                    return Err(FromMontyError::wrong_type("tuple", &object));
+                };
+                let Ok([$($var),+]) = <[MontyObject; $len]>::try_from(items) else {
+                    return Err(FromMontyError::new(concat!("must be a tuple of length ", $len)));
+                };
+                Ok(($($ty::from_monty($var).map_err(|err| err.in_item($index))?,)+))
+            }
+
+            fn type_hint(stubs: &mut TypeStubs) -> String {
+                let items = [$($ty::type_hint(stubs)),+];
+                format!("tuple[{}]", items.join(", "))
+            }
+        }
+    };
+}
+
+impl_tuple!(1; A a 0);
+impl_tuple!(2; A a 0, B b 1);
+impl_tuple!(3; A a 0, B b 1, C c 2);
+impl_tuple!(4; A a 0, B b 1, C c 2, D d 3);
+
+/// Helpers for the code `#[derive(FromMonty)]` generates, not a public API.
+#[doc(hidden)]
+pub mod __derive {
+    use super::{FromMonty, FromMontyError};
+    use crate::MontyObject;
+
+    /// An object as an enum variant: a tag string, a tuple starting with its tag,
+    /// or a dataclass named after it.
+    pub enum Tagged {
+        Unit(String),
+        /// The tag and the whole tuple, including the tag.
+        Tuple(String, Vec<MontyObject>),
+        /// The dataclass's name and the dataclass.
+        Dataclass(String, MontyObject),
+        Other(MontyObject),
+    }
+
+    /// Reads `object` as an enum variant.
+    #[must_use]
+    pub fn tagged(object: MontyObject) -> Tagged {
+        let tag = match &object {
+            MontyObject::Tuple(items) => match items.first() {
+                Some(MontyObject::String(tag)) => Some(tag.clone()),
+                _ => None,
+            },
+            MontyObject::Dataclass { name, .. } => Some(name.clone()),
+            _ => None,
+        };
+        match (object, tag) {
+            (MontyObject::String(tag), _) => Tagged::Unit(tag),
+            (MontyObject::Tuple(items), Some(tag)) => Tagged::Tuple(tag, items),
+            (object @ MontyObject::Dataclass { .. }, Some(name)) => Tagged::Dataclass(name, object),
+            (object, _) => Tagged::Other(object),
+        }
+    }
+
+    /// Returns the items of a tuple of length `N`.
+    pub fn tuple<const N: usize>(object: MontyObject) -> Result<[MontyObject; N], FromMontyError> {
+        match object {
+            MontyObject::Tuple(items) => tuple_items(items),
+            _ => Err(FromMontyError::wrong_type("tuple", &object)),
+        }
+    }
+
+    /// Returns the items of a tuple, checking it has `N` of them.
+    pub fn tuple_items<const N: usize>(items: Vec<MontyObject>) -> Result<[MontyObject; N], FromMontyError> {
+        <[MontyObject; N]>::try_from(items).map_err(|_| FromMontyError::new(format!("must be a tuple of length {N}")))
+    }
+
+    /// The attributes of a dataclass, or the items of a dict with `str` keys or of a
+    /// named tuple, to be taken as a struct's fields.
+    pub struct Fields {
+        type_name: &'static str,
+        attrs: Vec<(String, MontyObject)>,
+    }
+
+    impl Fields {
+        /// Reads the fields of `object`, which converts to the type `type_name`.
+        pub fn new(object: MontyObject, type_name: &'static str) -> Result<Self, FromMontyError> {
+            let attrs: Vec<(String, MontyObject)> = match object {
+                MontyObject::Dataclass { attrs, .. } | MontyObject::Dict(attrs) => attrs
+                    .into_iter()
+                    .map(|(key, value)| match key {
+                        MontyObject::String(key) => Ok((key, value)),
+                        key => Err(FromMontyError::new(format!(
+                            "must have str keys, not {}",
+                            key.type_name()
+                        ))),
+                    })
+                    .collect::<Result<_, _>>()?,
+                MontyObject::NamedTuple {
+                    field_names, values, ..
+                } => field_names.into_iter().zip(values).collect(),
+                _ => return Err(FromMontyError::wrong_type(type_name, &object)),
+            };
+            Ok(Self { type_name, attrs })
+        }
+
+        /// Converts and takes the field `name`, which may only be missing if its type is optional.
+        pub fn take<T: FromMonty>(&mut self, name: &str) -> Result<T, FromMontyError> {
+            let value = match self.attrs.iter().position(|(key, _)| key == name) {
+                Some(index) => self.attrs.swap_remove(index).1,
+                None if T::OPTIONAL => MontyObject::None,
+                None => return Err(FromMontyError::missing_field(self.type_name, name)),
+            };
+            T::from_monty(value).map_err(|err| err.in_field(name))
+        }
+    }
+}
@@ -4,23 +4,25 @@ use std::{
 };
 
 use serde::{Deserialize, Serialize};
-use smallvec::smallvec;
+use smallvec::{SmallVec, smallvec};
 use strum::{Display, EnumString, IntoStaticStr};
 
 use crate::{
     args::ArgValues,
     bytecode::{CallResult, VM},
+    codecs::backslash_escape,
     defer_drop,
     exception_public::{MontyException, SourceMap, StackFrame},
     fstring::FormatError,
-    heap::{HeapData, HeapRead},
+    heap::{DropWithHeap, HeapData, HeapRead},
     intern::{Interns, StaticStrings, StringId},
+    object::{InvalidInputError, MontyObject},
     parse::CodeRange,
     resource::ResourceTracker,
     types::{
-        PyTrait, Type, allocate_tuple,
-        long_int::INT_MAX_STR_DIGITS,
-        str::{StringRepr, allocate_string, string_repr_fmt},
+        Bytes, PyTrait, Type, allocate_tuple,
+        long_int::{INT_MAX_STR_DIGITS, check_bigint_str_digits_limit},
+        str::allocate_string,
     },
     value::{EitherStr, Value},
 };
@@ -78,8 +80,14 @@ pub enum ExcType {
 
     // --- ValueError hierarchy ---
     ValueError,
-    /// Subclass of ValueError - for encoding/decoding errors.
+    /// Subclass of ValueError - base class for codec errors.
+    UnicodeError,
+    /// Subclass of UnicodeError - raised when a codec can't encode a string.
+    UnicodeEncodeError,
+    /// Subclass of UnicodeError - raised when a codec can't decode bytes.
     UnicodeDecodeError,
+    /// Subclass of UnicodeError - raised by the translation step of a codec.
+    UnicodeTranslateError,
     /// Subclass of ValueError for invalid JSON syntax in `json.loads()`.
     #[strum(serialize = "json.JSONDecodeError")]
     JsonDecodeError,
@@ -123,6 +131,32 @@ pub enum ExcType {
     TimeoutError,
     TypeError,
 
+    // --- Warning hierarchy ---
+    /// Base class for warning categories raised by `warnings.warn()`.
+    Warning,
+    /// Subclass of Warning - the default category for `warnings.warn()`.
+    UserWarning,
+    /// Subclass of Warning - for deprecated features aimed at developers.
+    DeprecationWarning,
+    /// Subclass of Warning - for features that will be deprecated in future.
+    PendingDeprecationWarning,
+    /// Subclass of Warning - for dubious syntax.
+    SyntaxWarning,
+    /// Subclass of Warning - for dubious runtime behavior.
+    RuntimeWarning,
+    /// Subclass of Warning - for deprecated features aimed at end users.
+    FutureWarning,
+    /// Subclass of Warning - for probable mistakes in module imports.
+    ImportWarning,
+    /// Subclass of Warning - for Unicode-related problems.
+    UnicodeWarning,
+    /// Subclass of Warning - for `bytes` and `bytearray` related problems.
+    BytesWarning,
+    /// Subclass of Warning - for resource usage problems.
+    ResourceWarning,
+    /// Subclass of Warning - for implicit text encodings.
+    EncodingWarning,
+
     // --- Module-specific exception types ---
 
     // --- re module ---
@@ -147,6 +181,9 @@ impl ExcType {
     /// - `LookupError` is the base for `KeyError` and `IndexError`
     /// - `ArithmeticError` is the base for `ZeroDivisionError` and `OverflowError`
     /// - `RuntimeError` is the base for `RecursionError` and `NotImplementedError`
+    /// - `UnicodeError` is the base for `UnicodeEncodeError`, `UnicodeDecodeError` and
+    ///   `UnicodeTranslateError`
+    /// - `Warning` is the base for all warning categories
     ///
     /// Returns true if `self` would be caught by `except handler_type:`.
     #[must_use]
@@ -169,11 +206,16 @@ impl ExcType {
             Self::AttributeError => matches!(self, Self::FrozenInstanceError),
             // NameError catches UnboundLocalError
             Self::NameError => matches!(self, Self::UnboundLocalError),
-            // ValueError catches UnicodeDecodeError, json.JSONDecodeError, and
+            // ValueError catches the UnicodeError family, json.JSONDecodeError, and
             // io.UnsupportedOperation (which in CPython has dual OSError + ValueError parentage)
-            Self::ValueError => matches!(
+            Self::ValueError => {
+                self.is_subclass_of(Self::UnicodeError)
+                    || matches!(self, Self::JsonDecodeError | Self::UnsupportedOperation)
+            }
+            // UnicodeError catches UnicodeEncodeError, UnicodeDecodeError and UnicodeTranslateError
+            Self::UnicodeError => matches!(
                 self,
-                Self::UnicodeDecodeError | Self::JsonDecodeError | Self::UnsupportedOperation
+                Self::UnicodeEncodeError | Self::UnicodeDecodeError | Self::UnicodeTranslateError
             ),
             // ImportError catches ModuleNotFoundError
             Self::ImportError => matches!(self, Self::ModuleNotFoundError),
@@ -188,6 +230,21 @@ impl ExcType {
                     | Self::PermissionError
                     | Self::UnsupportedOperation
             ),
+            // Warning catches every warning category
+            Self::Warning => matches!(
+                self,
+                Self::UserWarning
+                    | Self::DeprecationWarning
+                    | Self::PendingDeprecationWarning
+                    | Self::SyntaxWarning
+                    | Self::RuntimeWarning
+                    | Self::FutureWarning
+                    | Self::ImportWarning
+                    | Self::UnicodeWarning
+                    | Self::BytesWarning
+                    | Self::ResourceWarning
+                    | Self::EncodingWarning
+            ),
             // All other types only match exactly (handled by self == handler_type above)
             _ => false,
         }
@@ -195,41 +252,36 @@ impl ExcType {
 
     /// Creates an exception instance from an exception type and arguments.
     ///
-    /// Handles exception constructors like `ValueError('message')`.
-    /// Currently supports zero or one string argument.
+    /// Handles exception constructors like `ValueError('message')` and
+    /// `OSError(errno, strerror, filename)`. Any number of positional arguments
+    /// is accepted and stored as the exception's `args`; keyword arguments are
+    /// rejected as in CPython.
     ///
-    /// The `interns` parameter provides access to interned string content.
     /// Returns a heap-allocated exception value.
     pub(crate) fn call(self, vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
-        defer_drop!(args, vm);
-        let exc = match args {
-            ArgValues::Empty => Ok(SimpleException::new_none(self)),
-            ArgValues::One(value) => match value {
-                Value::InternString(string_id) => Ok(SimpleException::new_msg(
-                    self,
-                    vm.interns.get_str(*string_id).to_owned(),
-                )),
-                Value::Ref(heap_id) => {
-                    if let HeapData::Str(s) = vm.heap.get(*heap_id) {
-                        Ok(SimpleException::new_msg(self, s.as_str().to_owned()))
-                    } else {
-                        Err(RunError::internal(
-                            "exceptions can only be called with zero or one string argument",
-                        ))
-                    }
-                }
-                _ => Err(RunError::internal(
-                    "exceptions can only be called with zero or one string argument",
-                )),
-            },
-            _ => Err(RunError::internal(
-                "exceptions can only be called with zero or one string argument",
-            )),
-        }?;
+        let name: &'static str = self.into();
+        let args = args.into_pos_only(name, vm.heap)?;
+        let args = args.map(|value| MontyObject::new(value, vm)).collect();
+        let exc = SimpleException::from_args(self, args);
         let heap_id = vm.heap.allocate(HeapData::Exception(exc))?;
         Ok(Value::Ref(heap_id))
     }
 
+    /// Maps an `errno` value to the `OSError` subclass CPython picks for it.
+    ///
+    /// Only the subclasses Monty implements are mapped; any other errno stays a
+    /// plain `OSError`.
+    fn from_errno(errno: i64) -> Option<Self> {
+        match errno {
+            1 | 13 => Some(Self::PermissionError),
+            2 => Some(Self::FileNotFoundError),
+            17 => Some(Self::FileExistsError),
+            20 => Some(Self::NotADirectoryError),
+            21 => Some(Self::IsADirectoryError),
+            _ => None,
+        }
+    }
+
     /// Creates an AttributeError for when an attribute is not found (GET operation).
     ///
     /// Sets `hide_caret: true` because CPython doesn't show carets for attribute GET errors.
@@ -336,16 +388,11 @@ impl ExcType {
 
     /// Creates a KeyError for a missing dict key.
     ///
-    /// For string keys, uses the raw string value without extra quoting.
-    /// If the key's string conversion fails (e.g. huge LongInt exceeding
-    /// `INT_MAX_STR_DIGITS`), falls back to the type name so that a
-    /// `KeyError` is always raised rather than a spurious `ValueError`.
+    /// The key itself becomes the exception's argument, so `str()` shows its
+    /// repr and `args[0]` is the key, as in CPython.
     pub(crate) fn key_error(key: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunError {
-        let key_str = match key.py_str(vm) {
-            Ok(s) => s.into_owned(),
-            Err(_) => format!("<{}>", key.py_type(vm)),
-        };
-        SimpleException::new_msg(Self::KeyError, key_str).into()
+        let key = key.clone_with_heap(vm.heap);
+        SimpleException::from_args(Self::KeyError, vec![MontyObject::new(key, vm)]).into()
     }
 
     /// Creates a KeyError for popping from an empty set.
@@ -942,6 +989,18 @@ impl ExcType {
         .into()
     }
 
+    /// Creates a TypeError for issubclass() arg 2.
+    ///
+    /// Matches CPython's format: `TypeError: issubclass() arg 2 must be a class, a tuple of classes, or a union`
+    #[must_use]
+    pub(crate) fn issubclass_arg2_error() -> RunError {
+        SimpleException::new_msg(
+            Self::TypeError,
+            "issubclass() arg 2 must be a class, a tuple of classes, or a union",
+        )
+        .into()
+    }
+
     /// Creates a TypeError for invalid exception type in except clause.
     ///
     /// Matches CPython's format: `TypeError: catching classes that do not inherit from BaseException is not allowed`
@@ -1438,14 +1497,67 @@ impl ExcType {
         SimpleException::new_msg(Self::LookupError, format!("unknown encoding: {encoding}")).into()
     }
 
-    /// Creates a UnicodeDecodeError for invalid UTF-8 bytes in decode().
+    /// Creates a UnicodeEncodeError for the characters `start..end` of `object`.
     ///
-    /// Matches CPython's format: `UnicodeDecodeError: 'utf-8' codec can't decode bytes...`
+    /// Positions are in characters. Matches CPython's format:
+    /// `'ascii' codec can't encode character '\xe9' in position 0: ordinal not in range(128)`
     #[must_use]
-    pub(crate) fn unicode_decode_error_invalid_utf8() -> RunError {
-        SimpleException::new_msg(
+    pub(crate) fn unicode_encode_error(
+        encoding: &str,
+        object: &str,
+        start: usize,
+        end: usize,
+        reason: &str,
+    ) -> RunError {
+        let what = if end - start == 1 {
+            let c = object.chars().nth(start).unwrap_or_default();
+            format!("character '{}' in position {start}", backslash_escape(c))
+        } else {
+            format!("characters in position {start}-{}", end - 1)
+        };
+        let message = format!("'{encoding}' codec can't encode {what}: {reason}");
+        SimpleException::new_unicode(
+            Self::UnicodeEncodeError,
+            message,
+            UnicodeErrorDetail {
+                encoding: encoding.to_owned(),
+                object: UnicodeErrorObject::Str(object.to_owned()),
+                start,
+                end,
+                reason: reason.to_owned(),
+            },
+        )
+        .into()
+    }
+
+    /// Creates a UnicodeDecodeError for the bytes `start..end` of `object`.
+    ///
+    /// Matches CPython's format:
+    /// `'utf-8' codec can't decode byte 0xff in position 0: invalid start byte`
+    #[must_use]
+    pub(crate) fn unicode_decode_error(
+        encoding: &str,
+        object: &[u8],
+        start: usize,
+        end: usize,
+        reason: &str,
+    ) -> RunError {
+        let what = if end - start == 1 {
+            format!("byte 0x{:02x} in position {start}", object[start])
+        } else {
+            format!("bytes in position {start}-{}", end - 1)
+        };
+        let message = format!("'{encoding}' codec can't decode {what}: {reason}");
+        SimpleException::new_unicode(
             Self::UnicodeDecodeError,
-            "'utf-8' codec can't decode bytes: invalid utf-8 sequence",
+            message,
+            UnicodeErrorDetail {
+                encoding: encoding.to_owned(),
+                object: UnicodeErrorObject::Bytes(object.to_vec()),
+                start,
+                end,
+                reason: reason.to_owned(),
+            },
         )
         .into()
     }
@@ -1550,12 +1662,56 @@ impl ExcType {
 
 /// Simple lightweight representation of an exception.
 ///
-/// This is used for performance reasons for common exception patterns.
-/// Exception messages use `String` for owned storage.
+/// Exceptions are copied between the heap and `RunError` as they are raised and
+/// caught, so the constructor arguments are stored as owned `MontyObject`s rather
+/// than heap values.
 #[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub(crate) struct SimpleException {
     exc_type: ExcType,
-    arg: Option<String>,
+    /// The exception's `args` tuple.
+    args: Vec<MontyObject>,
+    /// Notes added with `add_note()`, printed after the message in a traceback.
+    #[serde(default)]
+    notes: Vec<String>,
+    /// The `filename`/`filename2` attributes of an `OSError` built from `(errno, strerror, ...)`.
+    #[serde(default)]
+    os: Option<Box<OsErrorDetail>>,
+    /// The `encoding`/`object`/`start`/`end`/`reason` attributes of a codec error.
+    #[serde(default)]
+    unicode: Option<Box<UnicodeErrorDetail>>,
+    /// The frames the exception unwound through before an `except` handler caught
+    /// it, innermost first. Only set on caught exceptions; read by `logging.exception()`.
+    #[serde(default)]
+    traceback: Option<Box<RawStackFrame>>,
+}
+
+/// Extra attributes of an `OSError` constructed with two to five arguments.
+///
+/// Its `errno` and `strerror` are the first two `args`; the filenames are kept
+/// here because CPython drops them from `args`.
+#[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+struct OsErrorDetail {
+    filename: MontyObject,
+    filename2: MontyObject,
+}
+
+/// Attributes of a `UnicodeEncodeError` / `UnicodeDecodeError` raised by a codec.
+///
+/// `start` and `end` index characters of a `str` object or bytes of a `bytes` object.
+#[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+pub(crate) struct UnicodeErrorDetail {
+    encoding: String,
+    object: UnicodeErrorObject,
+    start: usize,
+    end: usize,
+    reason: String,
+}
+
+/// The object a codec failed on: a `str` being encoded or `bytes` being decoded.
+#[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+enum UnicodeErrorObject {
+    Str(String),
+    Bytes(Vec<u8>),
 }
 
 impl fmt::Display for SimpleException {
@@ -1565,10 +1721,11 @@ impl fmt::Display for SimpleException {
 }
 impl From<MontyException> for SimpleException {
     fn from(exc: MontyException) -> Self {
-        Self {
-            exc_type: exc.exc_type(),
-            arg: exc.into_message(),
-        }
+        let exc_type = exc.exc_type();
+        let (args, notes) = exc.into_args_and_notes();
+        let mut simple = Self::from_args(exc_type, args);
+        simple.notes = notes;
+        simple
     }
 }
 
@@ -1576,22 +1733,67 @@ impl SimpleException {
     /// Creates a new exception with the given type and optional argument message.
     #[must_use]
     pub fn new(exc_type: ExcType, arg: Option<String>) -> Self {
-        Self { exc_type, arg }
+        Self::new_args(exc_type, arg.map(MontyObject::String).into_iter().collect())
     }
 
     /// Creates a new exception with the given type and argument message.
     #[must_use]
     pub fn new_msg(exc_type: ExcType, arg: impl fmt::Display) -> Self {
+        Self::new_args(exc_type, vec![MontyObject::String(arg.to_string())])
+    }
+
+    /// Creates a codec error carrying its `UnicodeError` attributes.
+    fn new_unicode(exc_type: ExcType, message: String, detail: UnicodeErrorDetail) -> Self {
         Self {
-            exc_type,
-            arg: Some(arg.to_string()),
+            unicode: Some(Box::new(detail)),
+            ..Self::new_msg(exc_type, message)
         }
     }
 
     /// Creates a new exception with the given type and no argument message.
     #[must_use]
     pub fn new_none(exc_type: ExcType) -> Self {
-        Self { exc_type, arg: None }
+        Self::new_args(exc_type, Vec::new())
+    }
+
+    /// Creates an exception whose `args` are exactly `args`.
+    fn new_args(exc_type: ExcType, args: Vec<MontyObject>) -> Self {
+        Self {
+            exc_type,
+            args,
+            notes: Vec::new(),
+            os: None,
+            unicode: None,
+            traceback: None,
+        }
+    }
+
+    /// Creates an exception the way calling `exc_type(*args)` does in Python.
+    ///
+    /// An `OSError` (or subclass) given two to five arguments is unpacked into
+    /// `errno`, `strerror`, `filename` and `filename2`; its `args` keep only the
+    /// first two. A plain `OSError` whose `errno` has a dedicated subclass, such
+    /// as `2` for `FileNotFoundError`, becomes that subclass.
+    #[must_use]
+    pub fn from_args(exc_type: ExcType, mut args: Vec<MontyObject>) -> Self {
+        if !exc_type.is_subclass_of(ExcType::OSError) || !(2..=5).contains(&args.len()) {
+            return Self::new_args(exc_type, args);
+        }
+        // `args[3]` is `winerror`, which only means something on Windows.
+        let filename2 = if args.len() == 5 { args.pop() } else { None };
+        args.truncate(3);
+        let filename = if args.len() == 3 { args.pop() } else { None };
+        let exc_type = match args[0] {
+            MontyObject::Int(errno) if exc_type == ExcType::OSError => ExcType::from_errno(errno).unwrap_or(exc_type),
+            _ => exc_type,
+        };
+        Self {
+            os: Some(Box::new(OsErrorDetail {
+                filename: filename.unwrap_or(MontyObject::None),
+                filename2: filename2.unwrap_or(MontyObject::None),
+            })),
+            ..Self::new_args(exc_type, args)
+        }
     }
 
     #[must_use]
@@ -1599,23 +1801,110 @@ impl SimpleException {
         self.exc_type
     }
 
+    /// The exception's `args`.
+    #[must_use]
+    pub fn args(&self) -> &[MontyObject] {
+        &self.args
+    }
+
+    /// The notes added with `add_note()`, oldest first.
+    #[must_use]
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// The traceback recorded when the exception was caught, if any.
     #[must_use]
-    pub fn arg(&self) -> Option<&String> {
-        self.arg.as_ref()
+    pub fn traceback(&self) -> Option<&RawStackFrame> {
+        self.traceback.as_deref()
+    }
+
+    /// Records the traceback of an exception that is being caught.
+    pub fn set_traceback(&mut self, frame: Option<RawStackFrame>) {
+        self.traceback = frame.map(Box::new);
+    }
+
+    /// The arguments that rebuild this exception when passed to [`Self::from_args`].
+    ///
+    /// These are the `args`, plus the filenames an `OSError` keeps outside `args`.
+    #[must_use]
+    pub fn constructor_args(&self) -> Vec<MontyObject> {
+        let mut args = self.args.clone();
+        if let Some(os) = &self.os {
+            if os.filename2 != MontyObject::None {
+                args.extend([os.filename.clone(), MontyObject::None, os.filename2.clone()]);
+            } else if os.filename != MontyObject::None {
+                args.push(os.filename.clone());
+            }
+        }
+        args
+    }
+
+    /// The text shown after the exception type in a traceback.
+    ///
+    /// This is `str(exc)`, except that a `KeyError`'s key is shown as-is rather
+    /// than quoted. Returns `None` when the exception has no arguments.
+    #[must_use]
+    pub fn message(&self) -> Option<String> {
+        if let Some(os) = &self.os
+            && let [errno, strerror] = self.args.as_slice()
+        {
+            if os.filename != MontyObject::None {
+                let mut msg = format!(
+                    "[Errno {}] {}: {}",
+                    arg_str(errno),
+                    arg_str(strerror),
+                    arg_repr(&os.filename)
+                );
+                if os.filename2 != MontyObject::None {
+                    write!(msg, " -> {}", arg_repr(&os.filename2)).expect("writing to a String never fails");
+                }
+                return Some(msg);
+            }
+            if *errno != MontyObject::None && *strerror != MontyObject::None {
+                return Some(format!("[Errno {}] {}", arg_str(errno), arg_str(strerror)));
+            }
+        }
+        match self.args.as_slice() {
+            [] => None,
+            [arg] => Some(arg_str(arg)),
+            args => {
+                let items: Vec<String> = args.iter().map(arg_repr).collect();
+                Some(format!("({})", items.join(", ")))
+            }
+        }
     }
 
     /// str() for an exception
     #[must_use]
     pub fn py_str(&self) -> String {
-        match (self.exc_type, &self.arg) {
-            // KeyError expecificaly uses repr of the key for str(exc)
-            (ExcType::KeyError, Some(exc)) => StringRepr(exc).to_string(),
-            (_, Some(arg)) => arg.to_owned(),
-            (_, None) => String::new(),
+        match (self.exc_type, self.args.as_slice()) {
+            // KeyError specifically uses repr of the key for str(exc)
+            (ExcType::KeyError, [key]) => arg_repr(key),
+            _ => self.message().unwrap_or_default(),
         }
     }
 }
 
+/// `str()` of an exception argument.
+///
+/// Integers too large to convert under `INT_MAX_STR_DIGITS` render as CPython's
+/// traceback placeholder instead of being converted.
+fn arg_str(arg: &MontyObject) -> String {
+    match arg {
+        MontyObject::BigInt(bi) if check_bigint_str_digits_limit(bi).is_err() => "<exception str() failed>".to_owned(),
+        _ => arg.to_string(),
+    }
+}
+
+/// `repr()` of an exception argument, with the same guard as [`arg_str`].
+fn arg_repr(arg: &MontyObject) -> String {
+    match arg {
+        MontyObject::BigInt(bi) if check_bigint_str_digits_limit(bi).is_err() => "<exception str() failed>".to_owned(),
+        _ => arg.py_repr(),
+    }
+}
+
 impl<'h> HeapRead<'h, SimpleException> {
     pub(crate) fn py_type(&self, vm: &VM<'h, impl ResourceTracker>) -> Type {
         Type::Exception(self.get(vm.heap).exc_type)
@@ -1628,8 +1917,11 @@ impl SimpleException {
         let type_str: &'static str = self.exc_type.into();
         write!(f, "{type_str}(")?;
 
-        if let Some(arg) = &self.arg {
-            string_repr_fmt(arg, f)?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(&arg_repr(arg))?;
         }
 
         f.write_char(')')
@@ -1655,28 +1947,133 @@ impl SimpleException {
 impl<'h> HeapRead<'h, SimpleException> {
     /// Gets an attribute from this exception.
     ///
-    /// Handles the `.args` attribute by allocating a tuple containing the message.
-    /// Returns `Err(AttributeError)` for all other attributes.
+    /// Handles `args` and `__notes__` on every exception, `errno`/`strerror`/
+    /// `filename`/`filename2` on `OSError` and its subclasses, `value` on
+    /// `StopIteration`, and the codec attributes of unicode errors raised by a
+    /// codec. Returns `Ok(None)` for all other attributes.
     pub fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
         // Fast path: interned strings can be matched by ID
         let is_args = attr
             .static_string()
             .map_or_else(|| attr.as_str(vm.interns) == "args", |ss| ss == StaticStrings::Args);
 
-        if is_args {
-            // Construct tuple with 0 or 1 elements based on whether arg exists
-            let elements = if let Some(arg_str) = &self.get(vm.heap).arg {
-                smallvec![allocate_string(arg_str.as_str(), vm.heap)?]
-            } else {
-                smallvec![]
-            };
-            Ok(Some(CallResult::Value(allocate_tuple(elements, vm.heap)?)))
-        } else {
-            Ok(None)
+        let exc = self.get(vm.heap);
+        if let Some(detail) = exc.unicode.clone() {
+            if let Some(result) = unicode_error_getattr(&detail, is_args, attr, vm)? {
+                return Ok(Some(result));
+            }
+        } else if is_args {
+            let args = exc.args.clone();
+            let mut elements = SmallVec::with_capacity(args.len());
+            for arg in &args {
+                match arg_value(arg, vm) {
+                    Ok(value) => elements.push(value),
+                    Err(err) => {
+                        elements.drop_with_heap(vm);
+                        return Err(err);
+                    }
+                }
+            }
+            return Ok(Some(CallResult::Value(allocate_tuple(elements, vm.heap)?)));
+        }
+
+        let exc = self.get(vm.heap);
+        let is_os_error = exc.exc_type.is_subclass_of(ExcType::OSError);
+        let os = exc.os.as_deref();
+        let field = match attr.static_string() {
+            Some(StaticStrings::DunderNotes) if !exc.notes.is_empty() => Some(MontyObject::List(
+                exc.notes.iter().cloned().map(MontyObject::String).collect(),
+            )),
+            Some(StaticStrings::Errno) if is_os_error => os.map(|_| exc.args[0].clone()),
+            Some(StaticStrings::Strerror) if is_os_error => os.map(|_| exc.args[1].clone()),
+            Some(StaticStrings::Filename) if is_os_error => os.map(|os| os.filename.clone()),
+            Some(StaticStrings::Filename2) if is_os_error => os.map(|os| os.filename2.clone()),
+            Some(StaticStrings::Value) if exc.exc_type == ExcType::StopIteration => exc.args.first().cloned(),
+            _ => return Ok(None),
+        };
+        let value = match field {
+            Some(arg) => arg_value(&arg, vm)?,
+            None => Value::None,
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+
+    /// Calls a method on this exception; only `add_note()` is supported.
+    pub(crate) fn py_call_attr(
+        &mut self,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        if attr.static_string() != Some(StaticStrings::AddNote) {
+            args.drop_with_heap(vm);
+            let exc_type = self.get(vm.heap).exc_type;
+            return Err(ExcType::attribute_error(exc_type, attr.as_str(vm.interns)));
         }
+        let note = args.get_one_arg("BaseException.add_note", vm.heap)?;
+        defer_drop!(note, vm);
+        let Some(note) = note.as_either_str(vm.heap) else {
+            let type_name = note.py_type(vm);
+            return Err(ExcType::type_error(format!("note must be a str, not '{type_name}'")));
+        };
+        let note = note.as_str(vm.interns).to_owned();
+        self.get_mut(vm.heap).notes.push(note);
+        Ok(CallResult::Value(Value::None))
+    }
+}
+
+/// Converts a stored exception argument back into a `Value`.
+///
+/// An argument with no `MontyObject` equivalent (a function, a module, a
+/// cyclic list, ...) was stored as its repr and comes back as that string.
+fn arg_value(arg: &MontyObject, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    match arg.clone().to_value(vm) {
+        Ok(value) => Ok(value),
+        Err(InvalidInputError::Resource(err)) => Err(err.into()),
+        Err(InvalidInputError::InvalidType(_)) => Ok(allocate_string(arg.to_string(), vm.heap)?),
     }
 }
 
+/// Looks up an attribute of a codec error: `encoding`, `object`, `start`, `end` and
+/// `reason`, with `args` holding all five like CPython's codec errors.
+fn unicode_error_getattr(
+    detail: &UnicodeErrorDetail,
+    is_args: bool,
+    attr: &EitherStr,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Option<CallResult>> {
+    fn index(n: usize) -> RunResult<Value> {
+        Ok(Value::Int(i64::try_from(n).map_err(|_| ExcType::overflow_c_ssize_t())?))
+    }
+    fn object(detail: &UnicodeErrorDetail, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+        match &detail.object {
+            UnicodeErrorObject::Str(s) => Ok(allocate_string(s.as_str(), vm.heap)?),
+            UnicodeErrorObject::Bytes(b) => Ok(Value::Ref(vm.heap.allocate(HeapData::Bytes(Bytes::new(b.clone())))?)),
+        }
+    }
+
+    let value = if is_args {
+        let elements = smallvec![
+            allocate_string(detail.encoding.as_str(), vm.heap)?,
+            object(detail, vm)?,
+            index(detail.start)?,
+            index(detail.end)?,
+            allocate_string(detail.reason.as_str(), vm.heap)?,
+        ];
+        allocate_tuple(elements, vm.heap)?
+    } else {
+        match attr.as_str(vm.interns) {
+            "encoding" => allocate_string(detail.encoding.as_str(), vm.heap)?,
+            "object" => object(detail, vm)?,
+            "start" => index(detail.start)?,
+            "end" => index(detail.end)?,
+            "reason" => allocate_string(detail.reason.as_str(), vm.heap)?,
+            _ => return Ok(None),
+        }
+    };
+    Ok(Some(CallResult::Value(value)))
+}
+
 /// A raised exception with optional stack frame for traceback.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ExceptionRaise {
@@ -1803,7 +2200,13 @@ impl ExceptionRaise {
             })
             .unwrap_or_default();
 
-        MontyException::new_full(self.exc.exc_type(), self.exc.arg().cloned(), traceback)
+        MontyException::new_full(
+            self.exc.exc_type(),
+            self.exc.message(),
+            self.exc.constructor_args(),
+            self.exc.notes().to_vec(),
+            traceback,
+        )
     }
 }
 
@@ -1811,7 +2214,7 @@ impl ExceptionRaise {
 ///
 /// Stores position information and optional function name as StringId.
 /// The actual name string must be looked up externally when formatting the traceback.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct RawStackFrame {
     pub position: CodeRange,
     /// The name of the frame (function name StringId, or None for module-level code).
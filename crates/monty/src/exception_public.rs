@@ -6,8 +6,9 @@ use std::{
 };
 
 use crate::{
-    exception_private::{ExcType, RawStackFrame},
+    exception_private::{ExcType, RawStackFrame, SimpleException},
     intern::Interns,
+    object::MontyObject,
     parse::CodeRange,
     types::str::StringRepr,
 };
@@ -19,6 +20,12 @@ pub struct MontyException {
     exc_type: ExcType,
     /// Optional exception message explaining what went wrong
     message: Option<String>,
+    /// The arguments the exception was constructed with, i.e. python's `exc.args`
+    #[serde(default)]
+    args: Vec<MontyObject>,
+    /// Notes added with `add_note()`, shown after the message in the traceback
+    #[serde(default)]
+    notes: Vec<String>,
     /// Stack trace of the exception, first is the outermost frame shown first in the traceback
     traceback: Vec<StackFrame>,
 }
@@ -67,10 +74,14 @@ impl fmt::Display for MontyException {
         }
 
         if let Some(msg) = &self.message {
-            write!(f, "{}: {}", self.exc_type, msg)
+            write!(f, "{}: {}", self.exc_type, msg)?;
         } else {
-            write!(f, "{}", self.exc_type)
+            write!(f, "{}", self.exc_type)?;
         }
+        for note in &self.notes {
+            write!(f, "\n{note}")?;
+        }
+        Ok(())
     }
 }
 
@@ -84,25 +95,48 @@ impl MontyException {
     pub fn new(exc_type: ExcType, message: Option<String>) -> Self {
         Self {
             exc_type,
+            args: message.iter().cloned().map(MontyObject::String).collect(),
             message,
+            notes: vec![],
             traceback: vec![],
         }
     }
 
-    pub(crate) fn new_full(exc_type: ExcType, message: Option<String>, traceback: Vec<StackFrame>) -> Self {
+    /// Create a new MontyException from the arguments its constructor was called with.
+    ///
+    /// The message is derived the same way Python's `str(exc)` is, so
+    /// `with_args(ExcType::OSError, vec![2.into(), "x".into()])` gives a
+    /// `FileNotFoundError` with the message `[Errno 2] x`.
+    #[must_use]
+    pub fn with_args(exc_type: ExcType, args: Vec<MontyObject>) -> Self {
+        let exc = SimpleException::from_args(exc_type, args);
+        Self {
+            exc_type: exc.exc_type(),
+            message: exc.message(),
+            args: exc.constructor_args(),
+            notes: vec![],
+            traceback: vec![],
+        }
+    }
+
+    pub(crate) fn new_full(
+        exc_type: ExcType,
+        message: Option<String>,
+        args: Vec<MontyObject>,
+        notes: Vec<String>,
+        traceback: Vec<StackFrame>,
+    ) -> Self {
         Self {
             exc_type,
             message,
+            args,
+            notes,
             traceback,
         }
     }
 
     pub(crate) fn runtime_error(err: impl fmt::Display) -> Self {
-        Self {
-            exc_type: ExcType::RuntimeError,
-            message: Some(err.to_string()),
-            traceback: vec![],
-        }
+        Self::new(ExcType::RuntimeError, Some(err.to_string()))
     }
 
     /// The exception type raised.
@@ -113,7 +147,8 @@ impl MontyException {
 
     /// Optional exception message explaining what went wrong.
     ///
-    /// Equivalent of python's `exc.args[0]`
+    /// This is the text shown after the exception type in a traceback, equivalent
+    /// of python's `str(exc)` (or `exc.args[0]` for a single string argument).
     #[must_use]
     pub fn message(&self) -> Option<&str> {
         self.message.as_deref()
@@ -122,13 +157,35 @@ impl MontyException {
     /// Optional exception message explaining what went wrong.
     ///
     /// This takes ownership of the MontyException and returns an owned String.
-    ///
-    /// Equivalent of python's `exc.args[0]`
     #[must_use]
     pub fn into_message(self) -> Option<String> {
         self.message
     }
 
+    /// The arguments the exception was constructed with, equivalent of python's `exc.args`.
+    #[must_use]
+    pub fn args(&self) -> &[MontyObject] {
+        &self.args
+    }
+
+    /// Notes added to the exception with `add_note()`, equivalent of python's `exc.__notes__`.
+    #[must_use]
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Consumes the exception, returning its arguments and notes.
+    ///
+    /// An exception deserialized without `args` falls back to its message as the only argument.
+    pub(crate) fn into_args_and_notes(self) -> (Vec<MontyObject>, Vec<String>) {
+        let args = if self.args.is_empty() {
+            self.message.map(MontyObject::String).into_iter().collect()
+        } else {
+            self.args
+        };
+        (args, self.notes)
+    }
+
     /// Stack trace of the exception, first is the outermost frame shown first in the traceback
     #[must_use]
     pub fn traceback(&self) -> &[StackFrame] {
@@ -155,7 +212,12 @@ impl MontyException {
     #[must_use]
     pub fn py_repr(&self) -> String {
         let type_str: &'static str = self.exc_type.into();
-        if let Some(msg) = &self.message {
+        if !self.args.is_empty() {
+            let mut repr = String::new();
+            let exc = SimpleException::from_args(self.exc_type, self.args.clone());
+            exc.py_repr_fmt(&mut repr).expect("writing to a String cannot fail");
+            repr
+        } else if let Some(msg) = &self.message {
             format!("{}({})", type_str, StringRepr(msg))
         } else {
             format!("{type_str}()")
@@ -372,6 +434,22 @@ impl CodeLoc {
     }
 }
 
+/// Returns the byte offset of the start of each line in `source`.
+///
+/// The result always starts with 0 and has one entry per line.
+pub(crate) fn line_starts(source: &str) -> Vec<u32> {
+    let mut starts = Vec::with_capacity(source.len() / 40 + 1);
+    starts.push(0);
+    for (i, b) in source.bytes().enumerate() {
+        if b == b'\n' {
+            // source should never exceed 4 GB
+            let start = u32::try_from(i + 1).unwrap_or(u32::MAX);
+            starts.push(start);
+        }
+    }
+    starts
+}
+
 /// Lazy resolver from raw byte offsets (stored on every [`CodeRange`]) back to
 /// human-readable line/column/preview-line information.
 ///
@@ -409,18 +487,9 @@ impl<'s> SourceMap<'s> {
     /// O(log n) lookups per frame.
     #[must_use]
     pub fn new(source: &'s str) -> Self {
-        let mut line_starts = Vec::with_capacity(source.len() / 40 + 1);
-        line_starts.push(0);
-        for (i, b) in source.bytes().enumerate() {
-            if b == b'\n' {
-                // source should never exceed 4 GB
-                let start = u32::try_from(i + 1).unwrap_or(u32::MAX);
-                line_starts.push(start);
-            }
-        }
         Self {
             source,
-            line_starts,
+            line_starts: line_starts(source),
             line_cache: HashMap::new(),
         }
     }
@@ -4,7 +4,7 @@ use crate::{
     fstring::FStringPart,
     intern::{BytesId, LongIntId, StringId},
     namespace::NamespaceId,
-    parse::{CodeRange, ParsedSignature, Try},
+    parse::{CodeRange, ParsedSignature, RawClassDef, Try},
     signature::Signature,
     value::{EitherStr, Marker, Value},
 };
@@ -99,6 +99,9 @@ pub struct ImportName {
     /// The binding target — the alias if provided, otherwise the module name.
     /// After the prepare phase, this includes the resolved namespace slot.
     pub binding: Identifier,
+    /// Whether `binding` names the top-level package rather than the module itself,
+    /// as in `import a.b`, which binds `a`.
+    pub bind_top_level: bool,
 }
 
 /// Target of a function call expression.
@@ -338,6 +341,16 @@ pub enum Expr {
         /// The body is wrapped as `[Node::Return(body_expr)]` during preparation.
         func_def: Box<PreparedFunctionDef>,
     },
+    /// Raw class definition from the parser, before preparation.
+    ///
+    /// Converted to `Expr::Class` during the prepare phase.
+    ClassRaw(Box<RawClassDef>),
+    /// Class definition (prepared form).
+    ///
+    /// Evaluates the field annotations and defaults and creates the methods, then
+    /// builds the class object, which stays on the stack. The `class` statement is
+    /// lowered to an assignment of the decorated class to its name.
+    Class(Box<PreparedClassDef>),
     /// Named expression (walrus operator): `(target := value)`
     ///
     /// Evaluates `value`, assigns it to `target`, and returns the value as the
@@ -456,6 +469,11 @@ pub enum Literal {
     /// An interned long integer literal. The `LongIntId` references the value in the Interns table.
     /// Used for integer literals that exceed the i64 range.
     LongInt(LongIntId),
+    /// An imaginary literal such as `2j`, holding its imaginary part.
+    ///
+    /// Complex numbers live on the heap, so this has no `Value` counterpart and is
+    /// compiled to a `complex(0.0, imag)` constructor call instead.
+    Imaginary(f64),
     /// A marker value (e.g., typing constructs like Any, Optional, etc.).
     Marker(Marker),
 }
@@ -475,6 +493,7 @@ impl From<Literal> for Value {
             Literal::Str(string_id) => Self::InternString(string_id),
             Literal::Bytes(bytes_id) => Self::InternBytes(bytes_id),
             Literal::LongInt(long_int_id) => Self::InternLongInt(long_int_id),
+            Literal::Imaginary(_) => unreachable!("imaginary literals are compiled to a complex() call"),
             Literal::Marker(marker) => Self::Marker(marker),
         }
     }
@@ -744,6 +763,9 @@ pub struct PreparedFunctionDef {
     /// Each group contains only the parameters that have defaults, in declaration order.
     /// The counts in `signature` indicate how many defaults exist for each group.
     pub default_exprs: Vec<ExprLoc>,
+    /// Prepared `__annotate__` lambda (an `Expr::Lambda`), evaluated at function definition
+    /// time alongside the defaults. `None` when the function has no annotations.
+    pub annotate: Option<ExprLoc>,
     /// Whether this is an async function (`async def`).
     ///
     /// When true, calling this function creates a `Coroutine` object instead of
@@ -751,6 +773,31 @@ pub struct PreparedFunctionDef {
     pub is_async: bool,
 }
 
+/// A field declared in a class body: `name: annotation` or `name: annotation = default`.
+///
+/// Shared by the raw and prepared class definitions; the prepare phase resolves
+/// names in the annotation and default in place.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClassField {
+    /// The field name.
+    pub name: StringId,
+    /// The annotation expression, evaluated in the enclosing scope when the class is built.
+    pub annotation: ExprLoc,
+    /// The default value expression, evaluated in the enclosing scope when the class is built.
+    pub default: Option<ExprLoc>,
+}
+
+/// A prepared class definition with resolved names.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PreparedClassDef {
+    /// The interned class name.
+    pub name_id: StringId,
+    /// Annotated fields in declaration order.
+    pub fields: Vec<ClassField>,
+    /// Prepared methods in declaration order.
+    pub methods: Vec<PreparedFunctionDef>,
+}
+
 /// Type alias for prepared AST nodes (output of prepare phase).
 pub type PreparedNode = Node<PreparedFunctionDef>;
 
@@ -6,7 +6,7 @@ use std::{
     io::{self, ErrorKind},
 };
 
-use crate::{ExcType, MontyException, types::str::StringRepr};
+use crate::{ExcType, MontyException, MontyObject, types::str::StringRepr};
 
 /// Errors from mount configuration or filesystem operations.
 #[derive(Debug)]
@@ -56,59 +56,33 @@ impl MountError {
     #[must_use]
     pub fn into_exception(self) -> MontyException {
         match self {
-            Self::NoMountPoint(path) => MontyException::new(
-                ExcType::PermissionError,
-                Some(format!("[Errno 13] Permission denied: {}", StringRepr(&path))),
-            ),
-            Self::PathEscape { virtual_path } => MontyException::new(
-                ExcType::PermissionError,
-                Some(format!("[Errno 13] Permission denied: {}", StringRepr(&virtual_path))),
-            ),
-            Self::ReadOnly(path) => MontyException::new(
-                ExcType::PermissionError,
-                Some(format!("[Errno 30] Read-only file system: {}", StringRepr(&path))),
-            ),
-            Self::CrossMountRename { src, dst } => MontyException::new(
+            Self::NoMountPoint(path) => os_error(ExcType::PermissionError, 13, "Permission denied", path),
+            Self::PathEscape { virtual_path } => {
+                os_error(ExcType::PermissionError, 13, "Permission denied", virtual_path)
+            }
+            Self::ReadOnly(path) => os_error(ExcType::PermissionError, 30, "Read-only file system", path),
+            Self::CrossMountRename { src, dst } => MontyException::with_args(
                 ExcType::OSError,
-                Some(format!(
-                    "[Errno 18] Invalid cross-device link: {} -> {}",
-                    StringRepr(&src),
-                    StringRepr(&dst)
-                )),
+                vec![
+                    MontyObject::Int(18),
+                    MontyObject::String("Invalid cross-device link".to_owned()),
+                    MontyObject::String(src),
+                    MontyObject::None,
+                    MontyObject::String(dst),
+                ],
             ),
             // Use hardcoded POSIX errno values rather than `raw_os_error()` so
             // sandboxed code sees consistent error codes regardless of host OS.
             // Windows uses different native codes (e.g. 3 for ERROR_PATH_NOT_FOUND
             // vs POSIX 2 for ENOENT).
             Self::Io(err, path) => match err.kind() {
-                ErrorKind::NotFound => MontyException::new(
-                    ExcType::FileNotFoundError,
-                    Some(format!("[Errno 2] No such file or directory: {}", StringRepr(&path))),
-                ),
-                ErrorKind::AlreadyExists => MontyException::new(
-                    ExcType::FileExistsError,
-                    Some(format!("[Errno 17] File exists: {}", StringRepr(&path))),
-                ),
-                ErrorKind::PermissionDenied => MontyException::new(
-                    ExcType::PermissionError,
-                    Some(format!("[Errno 13] Permission denied: {}", StringRepr(&path))),
-                ),
-                ErrorKind::IsADirectory => MontyException::new(
-                    ExcType::IsADirectoryError,
-                    Some(format!("[Errno 21] Is a directory: {}", StringRepr(&path))),
-                ),
-                ErrorKind::NotADirectory => MontyException::new(
-                    ExcType::NotADirectoryError,
-                    Some(format!("[Errno 20] Not a directory: {}", StringRepr(&path))),
-                ),
-                ErrorKind::DirectoryNotEmpty => MontyException::new(
-                    ExcType::OSError,
-                    Some(format!("[Errno 39] Directory not empty: {}", StringRepr(&path))),
-                ),
-                ErrorKind::InvalidFilename => MontyException::new(
-                    ExcType::OSError,
-                    Some(format!("[Errno 36] File name too long: {}", StringRepr(&path))),
-                ),
+                ErrorKind::NotFound => os_error(ExcType::FileNotFoundError, 2, "No such file or directory", path),
+                ErrorKind::AlreadyExists => os_error(ExcType::FileExistsError, 17, "File exists", path),
+                ErrorKind::PermissionDenied => os_error(ExcType::PermissionError, 13, "Permission denied", path),
+                ErrorKind::IsADirectory => os_error(ExcType::IsADirectoryError, 21, "Is a directory", path),
+                ErrorKind::NotADirectory => os_error(ExcType::NotADirectoryError, 20, "Not a directory", path),
+                ErrorKind::DirectoryNotEmpty => os_error(ExcType::OSError, 39, "Directory not empty", path),
+                ErrorKind::InvalidFilename => os_error(ExcType::OSError, 36, "File name too long", path),
                 _ => MontyException::new(ExcType::OSError, Some(format!("{err}: {}", StringRepr(&path)))),
             },
             Self::InvalidUtf8 { position, invalid_byte } => MontyException::new(
@@ -136,6 +110,19 @@ impl MountError {
     }
 }
 
+/// Builds an `OSError` (or subclass) carrying `errno`, `strerror` and `filename`,
+/// formatted as `[Errno {errno}] {strerror}: '{path}'`.
+fn os_error(exc_type: ExcType, errno: i64, strerror: &str, path: String) -> MontyException {
+    MontyException::with_args(
+        exc_type,
+        vec![
+            MontyObject::Int(errno),
+            MontyObject::String(strerror.to_owned()),
+            MontyObject::String(path),
+        ],
+    )
+}
+
 impl fmt::Display for MountError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
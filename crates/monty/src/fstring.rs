@@ -6,15 +6,20 @@
 //! F-strings can contain literal text and interpolated expressions with optional
 //! conversion flags (`!s`, `!r`, `!a`) and format specifications.
 
-use std::{fmt, fmt::Write, iter, iter::Peekable, str::FromStr};
+use std::{fmt, fmt::Write, iter, iter::Peekable, slice, str::FromStr};
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Signed, ToPrimitive};
 
 use crate::{
     bytecode::VM,
-    exception_private::{ExcType, RunError, SimpleException},
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
     expressions::ExprLoc,
+    heap::HeapData,
     intern::StringId,
     resource::{ResourceTracker, check_repeat_size},
-    types::{PyTrait, Type},
+    types::{PyTrait, Type, str::allocate_string},
     value::Value,
 };
 
@@ -931,6 +936,331 @@ pub fn format_float_percent(f: f64, spec: &ParsedFormatSpec) -> String {
     pad_signed_numeric(sign, &abs_str, spec)
 }
 
+// ============================================================================
+// printf-style formatting
+// ============================================================================
+
+/// The right-hand operand of a printf-style `template % args` format.
+pub(crate) enum PercentArgs<'a> {
+    /// The items of a tuple (or a single non-tuple value), consumed left to right.
+    Positional(&'a [Value]),
+    /// A dict: `%(key)s` looks `key` up in it, and a bare `%s` formats the dict itself.
+    Mapping(&'a Value),
+}
+
+/// Formats `template` the way CPython's `template % args` does for a `str`.
+///
+/// Supports the `s`, `r`, `a`, `d`, `i`, `u`, `o`, `x`, `X`, `c`, `e`, `E`, `f`,
+/// `F`, `g`, `G` and `%` conversions, `%(key)` lookups, the `-`, `+`, space and
+/// `0` flags, and widths / precisions given inline or as `*`. The `#`
+/// (alternate form) flag is rejected like it is in format specs.
+pub(crate) fn format_percent(
+    template: &str,
+    args: PercentArgs<'_>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<String> {
+    let (positional, mapping) = match args {
+        PercentArgs::Positional(values) => (values, None),
+        PercentArgs::Mapping(mapping) => (slice::from_ref(mapping), Some(mapping)),
+    };
+    let mut next_arg = 0;
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+
+        // `%(key)`: the key may itself contain balanced parentheses.
+        let mut keyed = None;
+        if chars.next_if(|&(_, c)| c == '(').is_some() {
+            let Some(mapping) = mapping else {
+                return Err(ExcType::type_error("format requires a mapping"));
+            };
+            let start = chars.peek().map_or(template.len(), |&(i, _)| i);
+            let mut depth = 1;
+            let end = loop {
+                match chars.next() {
+                    Some((_, '(')) => depth += 1,
+                    Some((i, ')')) => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break i;
+                        }
+                    }
+                    Some(_) => {}
+                    None => return Err(SimpleException::new_msg(ExcType::ValueError, "incomplete format key").into()),
+                }
+            };
+            keyed = Some(percent_lookup(mapping, &template[start..end], vm)?);
+        }
+        defer_drop!(keyed, vm);
+
+        let mut spec = ParsedFormatSpec {
+            fill: ' ',
+            ..Default::default()
+        };
+        let mut left = false;
+        while let Some((_, flag)) = chars.next_if(|&(_, c)| matches!(c, '-' | '+' | ' ' | '0' | '#')) {
+            match flag {
+                '-' => left = true,
+                '+' => spec.sign = Some(Sign::Plus),
+                ' ' if spec.sign.is_none() => spec.sign = Some(Sign::Space),
+                '0' => spec.zero_pad = true,
+                '#' => {
+                    return Err(SimpleException::new_msg(
+                        ExcType::ValueError,
+                        "alternate form ('#') is not supported in % formats",
+                    )
+                    .into());
+                }
+                _ => {}
+            }
+        }
+
+        // Width, inline or `*`.
+        if chars.next_if(|&(_, c)| c == '*').is_some() {
+            let width = percent_star_arg(positional, &mut next_arg)?;
+            if width < 0 {
+                left = true;
+            }
+            spec.width = usize::try_from(width.unsigned_abs()).unwrap_or(usize::MAX);
+        } else {
+            spec.width = percent_decimal(&mut chars)?.unwrap_or(0);
+        }
+
+        // Precision, inline or `*`.
+        if chars.next_if(|&(_, c)| c == '.').is_some() {
+            spec.precision = if chars.next_if(|&(_, c)| c == '*').is_some() {
+                Some(usize::try_from(percent_star_arg(positional, &mut next_arg)?.max(0)).unwrap_or(usize::MAX))
+            } else {
+                Some(percent_decimal(&mut chars)?.unwrap_or(0))
+            };
+        }
+
+        // Length modifiers are accepted and ignored, as in CPython.
+        while chars.next_if(|&(_, c)| matches!(c, 'h' | 'l' | 'L')).is_some() {}
+
+        let Some((index, conversion)) = chars.next() else {
+            return Err(SimpleException::new_msg(ExcType::ValueError, "incomplete format").into());
+        };
+        if conversion == '%' {
+            out.push('%');
+            continue;
+        }
+
+        let value = match keyed {
+            Some(value) => value,
+            None => {
+                let Some(value) = positional.get(next_arg) else {
+                    return Err(ExcType::type_error("not enough arguments for format string"));
+                };
+                next_arg += 1;
+                value
+            }
+        };
+
+        if left {
+            spec.align = Some(Align::Left);
+            spec.zero_pad = false;
+        }
+        check_repeat_size(1, spec.width, vm.heap.tracker())?;
+        let formatted = match conversion {
+            's' | 'r' | 'a' => {
+                let text = match conversion {
+                    's' => value.py_str(vm)?.into_owned(),
+                    'r' => value.py_repr(vm)?.into_owned(),
+                    _ => ascii_escape(&value.py_repr(vm)?),
+                };
+                spec.align = Some(spec.align.unwrap_or(Align::Right));
+                format_string(&text, &spec)?
+            }
+            'd' | 'i' | 'u' | 'o' | 'x' | 'X' => {
+                let Some(n) = percent_int(value, conversion, vm)? else {
+                    let type_name = value.py_type(vm);
+                    let message = if matches!(conversion, 'o' | 'x' | 'X') {
+                        format!("%{conversion} format: an integer is required, not {type_name}")
+                    } else {
+                        format!("%{conversion} format: a real number is required, not {type_name}")
+                    };
+                    return Err(ExcType::type_error(message));
+                };
+                let radix = match conversion {
+                    'o' => 8,
+                    'x' | 'X' => 16,
+                    _ => 10,
+                };
+                let mut digits = n.magnitude().to_str_radix(radix);
+                if conversion == 'X' {
+                    digits.make_ascii_uppercase();
+                }
+                let sign = if n.is_negative() {
+                    "-"
+                } else {
+                    positive_sign_prefix(spec.sign)
+                };
+                pad_signed_numeric(sign, &digits, &spec)
+            }
+            'e' | 'E' | 'f' | 'F' | 'g' | 'G' => {
+                let Some(f) = percent_float(value, vm) else {
+                    let type_name = value.py_type(vm);
+                    return Err(ExcType::type_error(format!("must be real number, not {type_name}")));
+                };
+                if f.is_finite()
+                    && let Some(precision) = spec.precision
+                {
+                    check_repeat_size(precision, 1, vm.heap.tracker())?;
+                }
+                match conversion {
+                    'e' => format_float_e(f, &spec, false),
+                    'E' => format_float_e(f, &spec, true),
+                    'f' | 'F' => format_float_f(f, &spec),
+                    _ => {
+                        let text = format_float_g(f, &spec);
+                        if conversion == 'G' { text.to_uppercase() } else { text }
+                    }
+                }
+            }
+            'c' => {
+                spec.align = Some(spec.align.unwrap_or(Align::Right));
+                match value {
+                    Value::Int(n) => format_char(*n, &spec)?,
+                    _ if value.py_type(vm) == Type::Str => {
+                        let text = value.py_str(vm)?;
+                        let len = text.chars().count();
+                        if len != 1 {
+                            return Err(ExcType::type_error(format!(
+                                "%c requires an int or a unicode character, not a string of length {len}"
+                            )));
+                        }
+                        format_string(&text, &spec)?
+                    }
+                    _ => {
+                        let type_name = value.py_type(vm);
+                        return Err(ExcType::type_error(format!(
+                            "%c requires an int or a unicode character, not {type_name}"
+                        )));
+                    }
+                }
+            }
+            other => {
+                return Err(SimpleException::new_msg(
+                    ExcType::ValueError,
+                    format!(
+                        "unsupported format character '{other}' ({:#x}) at index {index}",
+                        u32::from(other)
+                    ),
+                )
+                .into());
+            }
+        };
+        out.push_str(&formatted);
+    }
+
+    if mapping.is_none() && next_arg < positional.len() {
+        return Err(ExcType::type_error(
+            "not all arguments converted during string formatting",
+        ));
+    }
+    Ok(out)
+}
+
+/// Looks up `%(key)` in a dict, cloning the value out.
+fn percent_lookup(mapping: &Value, key: &str, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let found = match mapping {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Dict(dict) => dict.get_by_str(key, vm.heap, vm.interns).map(|v| v.clone_with_heap(vm)),
+            _ => return Err(ExcType::type_error("format requires a mapping")),
+        },
+        _ => return Err(ExcType::type_error("format requires a mapping")),
+    };
+    match found {
+        Some(value) => Ok(value),
+        None => {
+            let key = allocate_string(key.to_owned(), vm.heap)?;
+            defer_drop!(key, vm);
+            Err(ExcType::key_error(key, vm))
+        }
+    }
+}
+
+/// Consumes the next positional argument as a `*` width or precision.
+fn percent_star_arg(positional: &[Value], next_arg: &mut usize) -> RunResult<i64> {
+    let value = positional
+        .get(*next_arg)
+        .ok_or_else(|| ExcType::type_error("not enough arguments for format string"))?;
+    *next_arg += 1;
+    match value {
+        Value::Int(n) => Ok(*n),
+        Value::Bool(b) => Ok(i64::from(*b)),
+        _ => Err(ExcType::type_error("* wants int")),
+    }
+}
+
+/// Parses an inline width or precision.
+fn percent_decimal(chars: &mut Peekable<impl Iterator<Item = (usize, char)>>) -> RunResult<Option<usize>> {
+    let mut value: Option<usize> = None;
+    while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+        let digit = c.to_digit(10).expect("char::is_ascii_digit guarantees a 0-9 digit") as usize;
+        value = Some(
+            value
+                .unwrap_or(0)
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or_else(|| SimpleException::new_msg(ExcType::ValueError, "width too big"))?,
+        );
+    }
+    Ok(value)
+}
+
+/// Converts a value for an integer conversion (`%d`, `%x`, ...).
+///
+/// `%d` / `%i` / `%u` also accept floats, truncating them; `%o` / `%x` only accept
+/// integers. Returns `None` for unsupported types.
+fn percent_int(value: &Value, conversion: char, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Option<BigInt>> {
+    Ok(match value {
+        Value::Int(n) => Some(BigInt::from(*n)),
+        Value::Bool(b) => Some(BigInt::from(i64::from(*b))),
+        Value::Float(f) if matches!(conversion, 'd' | 'i' | 'u') => {
+            if !f.is_finite() {
+                let message = if f.is_nan() {
+                    "cannot convert float NaN to integer"
+                } else {
+                    "cannot convert float infinity to integer"
+                };
+                let exc_type = if f.is_nan() {
+                    ExcType::ValueError
+                } else {
+                    ExcType::OverflowError
+                };
+                return Err(SimpleException::new_msg(exc_type, message).into());
+            }
+            BigInt::from_f64(f.trunc())
+        }
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::LongInt(li) => Some(li.inner().clone()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// Converts a value for a float conversion (`%f`, `%e`, `%g`), or `None` if it isn't a number.
+fn percent_float(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<f64> {
+    match value {
+        Value::Float(f) => Some(*f),
+        Value::Int(n) => Some(*n as f64),
+        Value::Bool(b) => Some(f64::from(u8::from(*b))),
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::LongInt(li) => li.inner().to_f64(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 // ============================================================================
 // Helper functions
 // ============================================================================
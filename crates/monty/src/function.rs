@@ -1,6 +1,18 @@
 use std::fmt::{self, Write};
 
-use crate::{bytecode::Code, expressions::Identifier, intern::Interns, namespace::NamespaceId, signature::Signature};
+use crate::{
+    ResourceTracker,
+    args::ArgValues,
+    bytecode::{Code, VM},
+    exception_private::RunResult,
+    expressions::Identifier,
+    heap::{DropWithHeap, HeapData},
+    intern::Interns,
+    namespace::NamespaceId,
+    signature::Signature,
+    types::Dict,
+    value::Value,
+};
 
 /// A defined function once compiled and ready for execution.
 ///
@@ -57,6 +69,11 @@ pub(crate) struct Function {
     /// At function definition time, this many default values are evaluated and stored
     /// in a separate defaults array. The signature indicates how these map to parameters.
     pub defaults_count: usize,
+    /// Whether an `__annotate__` function is pushed after the defaults at definition time.
+    ///
+    /// It is stored on the resulting `FunctionDefaults` / `Closure` and called lazily
+    /// to build `__annotations__`.
+    pub has_annotate: bool,
     /// Whether this is an async function (`async def`).
     ///
     /// When true, calling this function creates a `Coroutine` object instead of
@@ -80,6 +97,7 @@ impl Function {
     /// * `cell_var_count` - Number of cells to create for variables captured by nested functions
     /// * `cell_param_indices` - Maps cell indices to parameter indices for captured parameters
     /// * `defaults_count` - Number of default parameter values
+    /// * `has_annotate` - Whether an `__annotate__` function follows the defaults
     /// * `is_async` - Whether this is an async function
     /// * `code` - The compiled bytecode for the function body
     #[expect(clippy::too_many_arguments)]
@@ -91,6 +109,7 @@ impl Function {
         cell_var_count: usize,
         cell_param_indices: Vec<Option<usize>>,
         defaults_count: usize,
+        has_annotate: bool,
         is_async: bool,
         code: Code,
     ) -> Self {
@@ -102,6 +121,7 @@ impl Function {
             cell_var_count,
             cell_param_indices,
             defaults_count,
+            has_annotate,
             is_async,
             code,
         }
@@ -117,3 +137,36 @@ impl Function {
         )
     }
 }
+
+/// Builds the `__annotations__` dict for a function given its (cloned) `__annotate__` function.
+///
+/// Annotations are evaluated lazily (PEP 649): when the function has annotations its
+/// `__annotate__` function is run to completion, building a fresh dict on every access.
+/// Functions without annotations get a new empty dict.
+///
+/// Takes ownership of `annotate` and drops it once the call completes.
+pub(crate) fn function_annotations(annotate: Option<Value>, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    if let Some(annotate) = annotate {
+        let result = vm.evaluate_function("__annotations__", &annotate, ArgValues::Empty);
+        annotate.drop_with_heap(vm);
+        result
+    } else {
+        let dict_id = vm.heap.allocate(HeapData::Dict(Dict::new()))?;
+        Ok(Value::Ref(dict_id))
+    }
+}
+
+/// Returns the `__annotations__` dict of `value` if it is a user-defined function,
+/// or `None` for any other value.
+pub(crate) fn annotations_of(value: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Option<Value>> {
+    let annotate = match value {
+        Value::DefFunction(_) => None,
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Closure(closure) => closure.annotate.as_ref().map(|v| v.clone_with_heap(vm)),
+            HeapData::FunctionDefaults(fd) => fd.annotate.as_ref().map(|v| v.clone_with_heap(vm)),
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    function_annotations(annotate, vm).map(Some)
+}
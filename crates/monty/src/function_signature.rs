@@ -0,0 +1,448 @@
+//! Signatures the host declares for its external functions.
+//!
+//! A call to an external function with a declared [`FunctionSignature`] is bound in
+//! the VM before yielding, with the same algorithm as a call to a function defined
+//! in the sandbox. A bad call raises `TypeError` with a traceback pointing at the
+//! calling line, and the host gets the arguments by parameter name in
+//! [`FunctionCall::bound_args`](crate::FunctionCall::bound_args), defaults included.
+
+use ruff_python_stdlib::identifiers::is_identifier;
+
+use crate::{
+    ExcType, MontyException,
+    args::ArgValues,
+    bytecode::VM,
+    exception_private::{RunError, RunResult},
+    expressions::Identifier,
+    heap::{ContainsHeap, DropWithHeap},
+    intern::{Interns, StringId},
+    object::MontyObject,
+    parse::CodeRange,
+    resource::ResourceTracker,
+    signature::Signature,
+    types::{PyTrait, r#type::Type},
+    value::{EitherStr, Value},
+};
+
+/// The parameters of an external function, declared by the host with
+/// [`MontyRun::with_signatures`](crate::MontyRun::with_signatures).
+///
+/// # Example
+/// ```
+/// use monty::{FunctionSignature, MontyObject, MontyRun, NoLimitTracker, Parameter, PrintWriter, Type};
+///
+/// let search = FunctionSignature::new(
+///     "search",
+///     vec![
+///         Parameter::new("query").with_type(Type::Str),
+///         Parameter::new("limit").with_type(Type::Int).with_default(MontyObject::Int(10)),
+///         Parameter::keyword_only("exact").with_default(MontyObject::Bool(false)),
+///     ],
+/// );
+/// let runner = MontyRun::new("search('monty', exact=True)".to_owned(), "main.py", vec![])
+///     .unwrap()
+///     .with_signatures(vec![search])
+///     .unwrap();
+/// let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Disabled).unwrap();
+/// let call = progress.into_function_call().unwrap();
+/// assert_eq!(call.arg("query"), Some(&MontyObject::String("monty".to_owned())));
+/// assert_eq!(call.arg("limit"), Some(&MontyObject::Int(10)));
+/// assert_eq!(call.arg("exact"), Some(&MontyObject::Bool(true)));
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FunctionSignature {
+    /// Name of the external function, as the host sees it in `FunctionCall`.
+    name: String,
+    /// Parameters in definition order.
+    params: Vec<Parameter>,
+}
+
+impl FunctionSignature {
+    /// Creates the signature of the external function `name`.
+    ///
+    /// Parameters must be in the order Python requires: positional-only, then
+    /// positional-or-keyword, `*args`, keyword-only and finally `**kwargs`. This is
+    /// checked when the signature is declared.
+    #[must_use]
+    pub fn new(name: impl Into<String>, params: Vec<Parameter>) -> Self {
+        Self {
+            name: name.into(),
+            params,
+        }
+    }
+
+    /// Returns the name of the external function.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the parameters in definition order.
+    #[must_use]
+    pub fn params(&self) -> &[Parameter] {
+        &self.params
+    }
+
+    /// Checks the signature and interns its names, ready to bind calls.
+    ///
+    /// # Errors
+    /// Returns a `ValueError` if the parameters couldn't appear in a Python `def`.
+    pub(crate) fn declare(self, interns: &mut Interns) -> Result<DeclaredSignature, MontyException> {
+        let error = |msg: String| MontyException::new(ExcType::ValueError, Some(format!("{}(): {msg}", self.name)));
+
+        let mut pos_args = Vec::new();
+        let mut pos_defaults_count = 0;
+        let mut args = Vec::new();
+        let mut arg_defaults_count = 0;
+        let mut var_args = None;
+        let mut kwargs = Vec::new();
+        let mut kwarg_default_map = Vec::new();
+        let mut kwarg_defaults_count = 0;
+        let mut var_kwargs = None;
+
+        for (i, param) in self.params.iter().enumerate() {
+            if !is_identifier(&param.name) {
+                return Err(error(format!(
+                    "parameter name '{}' is not a valid identifier",
+                    param.name
+                )));
+            }
+            if self.params[..i].iter().any(|other| other.name == param.name) {
+                return Err(error(format!("duplicate parameter '{}'", param.name)));
+            }
+            if let Some(previous) = i.checked_sub(1).map(|j| &self.params[j])
+                && (previous.kind > param.kind || (previous.kind == param.kind && param.kind.is_variadic()))
+            {
+                return Err(error(format!(
+                    "{} parameter '{}' can't follow {} parameter '{}'",
+                    param.kind.description(),
+                    param.name,
+                    previous.kind.description(),
+                    previous.name
+                )));
+            }
+
+            let name_id = interns.intern(&param.name);
+            let has_default = param.default.is_some();
+            if param.kind.is_variadic() && (has_default || !param.types.is_empty()) {
+                return Err(error(format!(
+                    "{} parameter '{}' can't have a default or a type",
+                    param.kind.description(),
+                    param.name
+                )));
+            }
+            if matches!(
+                param.kind,
+                ParameterKind::PositionalOnly | ParameterKind::PositionalOrKeyword
+            ) && !has_default
+                && pos_defaults_count + arg_defaults_count > 0
+            {
+                return Err(error(format!(
+                    "parameter '{}' without a default follows parameter with a default",
+                    param.name
+                )));
+            }
+
+            match param.kind {
+                ParameterKind::PositionalOnly => {
+                    pos_args.push(name_id);
+                    pos_defaults_count += usize::from(has_default);
+                }
+                ParameterKind::PositionalOrKeyword => {
+                    args.push(name_id);
+                    arg_defaults_count += usize::from(has_default);
+                }
+                ParameterKind::VarPositional => var_args = Some(name_id),
+                ParameterKind::KeywordOnly => {
+                    kwargs.push(name_id);
+                    kwarg_default_map.push(has_default.then_some(kwarg_defaults_count));
+                    kwarg_defaults_count += usize::from(has_default);
+                }
+                ParameterKind::VarKeyword => var_kwargs = Some(name_id),
+            }
+        }
+
+        // Binding tracks which named parameters are bound in a 64-bit mask
+        let named_count = pos_args.len() + args.len() + kwargs.len();
+        if named_count > 64 {
+            return Err(error(format!(
+                "too many parameters, maximum is 64 but {named_count} were given"
+            )));
+        }
+
+        Ok(DeclaredSignature {
+            name_id: interns.intern(&self.name),
+            signature: Signature::new(
+                pos_args,
+                pos_defaults_count,
+                args,
+                arg_defaults_count,
+                var_args,
+                kwargs,
+                kwarg_default_map,
+                var_kwargs,
+            ),
+            params: self.params,
+        })
+    }
+}
+
+/// One parameter of a [`FunctionSignature`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Parameter {
+    /// Parameter name, used for keyword arguments, error messages and `bound_args`.
+    name: String,
+    /// How arguments can be passed for the parameter.
+    kind: ParameterKind,
+    /// Value used when the caller doesn't pass the argument.
+    default: Option<MontyObject>,
+    /// Types the argument must be an instance of, or empty to accept any value.
+    types: Vec<Type>,
+}
+
+impl Parameter {
+    /// Creates a positional-or-keyword parameter, like `name` in `def f(name)`.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self::of_kind(name, ParameterKind::PositionalOrKeyword)
+    }
+
+    /// Creates a positional-only parameter, like `name` in `def f(name, /)`.
+    #[must_use]
+    pub fn positional_only(name: impl Into<String>) -> Self {
+        Self::of_kind(name, ParameterKind::PositionalOnly)
+    }
+
+    /// Creates a keyword-only parameter, like `name` in `def f(*, name)`.
+    #[must_use]
+    pub fn keyword_only(name: impl Into<String>) -> Self {
+        Self::of_kind(name, ParameterKind::KeywordOnly)
+    }
+
+    /// Creates a parameter collecting extra positional arguments into a tuple, like `*name`.
+    #[must_use]
+    pub fn var_positional(name: impl Into<String>) -> Self {
+        Self::of_kind(name, ParameterKind::VarPositional)
+    }
+
+    /// Creates a parameter collecting extra keyword arguments into a dict, like `**name`.
+    #[must_use]
+    pub fn var_keyword(name: impl Into<String>) -> Self {
+        Self::of_kind(name, ParameterKind::VarKeyword)
+    }
+
+    fn of_kind(name: impl Into<String>, kind: ParameterKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            default: None,
+            types: Vec::new(),
+        }
+    }
+
+    /// Makes the parameter optional, passing `default` to the host when the argument is omitted.
+    ///
+    /// Defaults aren't checked against the parameter's types.
+    #[must_use]
+    pub fn with_default(mut self, default: MontyObject) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Requires the argument to be an instance of `ty`, checked like `isinstance`.
+    ///
+    /// Call it again to accept any of several types, e.g. `Type::Str` and
+    /// `Type::NoneType` for an optional string.
+    #[must_use]
+    pub fn with_type(mut self, ty: Type) -> Self {
+        self.types.push(ty);
+        self
+    }
+
+    /// Returns the parameter name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns how arguments can be passed for the parameter.
+    #[must_use]
+    pub fn kind(&self) -> ParameterKind {
+        self.kind
+    }
+
+    /// Returns the default value, if the parameter is optional.
+    #[must_use]
+    pub fn default(&self) -> Option<&MontyObject> {
+        self.default.as_ref()
+    }
+
+    /// Returns the types the argument must be an instance of, empty if any value is accepted.
+    #[must_use]
+    pub fn types(&self) -> &[Type] {
+        &self.types
+    }
+
+    /// Describes the accepted types CPython-style, e.g. `int`, `str or None`.
+    fn expected_types(&self) -> String {
+        let names: Vec<String> = self
+            .types
+            .iter()
+            .map(|ty| match ty {
+                Type::NoneType => "None".to_owned(),
+                ty => ty.to_string(),
+            })
+            .collect();
+        match names.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+            None => String::new(),
+        }
+    }
+}
+
+/// How arguments can be passed for a [`Parameter`], in the order parameters must appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum ParameterKind {
+    /// Only by position, before `/`.
+    PositionalOnly,
+    /// By position or keyword.
+    PositionalOrKeyword,
+    /// `*args`: extra positional arguments.
+    VarPositional,
+    /// Only by keyword, after `*` or `*args`.
+    KeywordOnly,
+    /// `**kwargs`: extra keyword arguments.
+    VarKeyword,
+}
+
+impl ParameterKind {
+    fn is_variadic(self) -> bool {
+        matches!(self, Self::VarPositional | Self::VarKeyword)
+    }
+
+    /// Describes the kind for error messages.
+    fn description(self) -> &'static str {
+        match self {
+            Self::PositionalOnly => "positional-only",
+            Self::PositionalOrKeyword => "positional-or-keyword",
+            Self::VarPositional => "variadic positional",
+            Self::KeywordOnly => "keyword-only",
+            Self::VarKeyword => "variadic keyword",
+        }
+    }
+}
+
+/// A declared signature, checked and ready to bind calls.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct DeclaredSignature {
+    /// Name of the external function.
+    name_id: StringId,
+    /// The parameters as the binding algorithm sees them.
+    signature: Signature,
+    /// The declared parameters, in namespace slot order.
+    params: Vec<Parameter>,
+}
+
+impl DeclaredSignature {
+    /// Returns the interned name of the external function.
+    pub fn name_id(&self) -> StringId {
+        self.name_id
+    }
+}
+
+/// Arguments of an external call bound to its declared signature.
+///
+/// Holds one value per parameter in namespace slot order. An omitted optional
+/// argument is `Value::Undefined`, as the host gets the declared default as is.
+pub(crate) struct BoundArgs {
+    values: Vec<Value>,
+}
+
+impl BoundArgs {
+    /// Binds `args` if the external function `name` has a declared signature.
+    ///
+    /// Returns `args` unchanged if it hasn't, and [`ArgValues::Empty`] with the bound
+    /// arguments if it has.
+    ///
+    /// # Errors
+    /// Returns the `TypeError` CPython would raise calling a `def` with the same
+    /// signature, or one like `_PyArg_BadArgument`'s if an argument has the wrong type.
+    pub fn bind(
+        name: &EitherStr,
+        args: ArgValues,
+        vm: &mut VM<'_, impl ResourceTracker>,
+    ) -> RunResult<(ArgValues, Option<Self>)> {
+        let interns = vm.interns;
+        let Some(declared) = interns.declared_signature(name.as_str(interns)) else {
+            return Ok((args, None));
+        };
+        let signature = &declared.signature;
+
+        // Omitted optional arguments keep this placeholder, see `into_py_objects`
+        let defaults: Vec<Value> = (0..signature.total_defaults_count())
+            .map(|_| Value::Undefined)
+            .collect();
+        let mut values = Vec::with_capacity(signature.total_slots());
+        let func_name = Identifier::new(declared.name_id, CodeRange::default());
+        if let Err(err) = signature.bind(args, &defaults, vm, func_name, &mut values) {
+            values.drop_with_heap(vm);
+            // Count errors point at the function's definition, which an external
+            // function doesn't have; the VM points them at the call instead.
+            return Err(match err {
+                RunError::Exc(mut exc) => {
+                    exc.frame = None;
+                    RunError::Exc(exc)
+                }
+                err => err,
+            });
+        }
+
+        let mismatch = declared.params.iter().zip(&values).find_map(|(param, value)| {
+            if param.types.is_empty() || matches!(value, Value::Undefined) {
+                return None;
+            }
+            let ty = value.py_type(vm);
+            let accepted = param.types.iter().any(|&expected| ty.is_instance_of(expected));
+            (!accepted).then_some((param, ty))
+        });
+        if let Some((param, ty)) = mismatch {
+            values.drop_with_heap(vm);
+            return Err(ExcType::type_error_bad_arg_named(
+                interns.get_str(declared.name_id),
+                &param.name,
+                &param.expected_types(),
+                ty,
+            ));
+        }
+        Ok((ArgValues::Empty, Some(Self { values })))
+    }
+
+    /// Converts the bound arguments into `(parameter name, value)` pairs for the host.
+    ///
+    /// Functions defined in the sandbox become callbacks, like in `FunctionCall::args`.
+    pub fn into_py_objects(self, name: &str, vm: &mut VM<'_, impl ResourceTracker>) -> Vec<(String, MontyObject)> {
+        let interns = vm.interns;
+        let declared = interns
+            .declared_signature(name)
+            .expect("bound arguments without a declared signature");
+        declared
+            .params
+            .iter()
+            .zip(self.values)
+            .map(|(param, value)| {
+                let value = match value {
+                    Value::Undefined => param.default.clone().expect("omitted argument without a default"),
+                    value => MontyObject::new_host_arg(value, vm),
+                };
+                (param.name.clone(), value)
+            })
+            .collect()
+    }
+}
+
+impl DropWithHeap for BoundArgs {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        self.values.drop_with_heap(heap);
+    }
+}
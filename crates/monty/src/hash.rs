@@ -147,6 +147,21 @@ pub(crate) fn hash_python_bytes(b: &[u8]) -> HashValue {
     HashValue::new(hasher.finish())
 }
 
+/// Hashes a float consistently with Python's `int` hash for integral values.
+///
+/// Floats that are mathematically integers in `i64` range hash like the
+/// equivalent `int` (so `hash(1.0) == hash(1)`); other floats hash their bit
+/// representation. Shared by `Value::Float` and `complex` components.
+#[inline]
+pub(crate) fn hash_python_float(f: f64) -> HashValue {
+    if f.fract() == 0.0 && f >= (i64::MIN as f64) && f <= (i64::MAX as f64) {
+        #[expect(clippy::cast_possible_truncation)]
+        HashValue::new((f as i64).cast_unsigned())
+    } else {
+        HashValue::new(f.to_bits())
+    }
+}
+
 /// Hashes a `BigInt` consistently with Python's `int` hash.
 ///
 /// For values that fit in `i64`, returns `i.cast_unsigned()` so that
@@ -19,9 +19,12 @@ use crate::{
     heap_data::{CellValue, Closure, FunctionDefaults},
     resource::{ResourceError, ResourceTracker},
     types::{
-        Bytes, Dataclass, Dict, DictItemsView, DictKeysView, DictValuesView, FrozenSet, List, LongInt, Module,
-        MontyIter, NamedTuple, OpenFile, Path, Range, ReMatch, RePattern, Set, Slice, Str, TimeZone, Tuple, date,
-        datetime, timedelta, timezone,
+        BaseObject, ByteArray, Bytes, Complex, ContextManager, Dataclass, Dict, DictItemsView, DictKeysView,
+        DictValuesView, FrozenSet, GenericAlias, HostHandle, HostType, List, Logger, LongInt, MemoryFile, MemoryView,
+        Module, MontyIter, NamedTuple, NewType, OpenFile, Path, Range, ReMatch, RePattern, Set, Slice, Str, TimeZone,
+        Tuple, TypedDict,
+        dataclass_type::{DataclassField, DataclassType},
+        date, datetime, timedelta, timezone,
     },
     value::Value,
 };
@@ -209,6 +212,8 @@ impl<T: ResourceTracker> DerefMut for HeapReader<'_, T> {
 pub enum HeapReadOutput<'a> {
     Str(HeapRead<'a, Str>),
     Bytes(HeapRead<'a, Bytes>),
+    ByteArray(HeapRead<'a, ByteArray>),
+    MemoryView(HeapRead<'a, MemoryView>),
     List(HeapRead<'a, List>),
     Tuple(HeapRead<'a, Tuple>),
     NamedTuple(HeapRead<'a, NamedTuple>),
@@ -228,18 +233,30 @@ pub enum HeapReadOutput<'a> {
     Dataclass(HeapRead<'a, Dataclass>),
     Iter(HeapRead<'a, MontyIter>),
     LongInt(HeapRead<'a, LongInt>),
+    Complex(HeapRead<'a, Complex>),
+    BaseObject(HeapRead<'a, BaseObject>),
+    HostType(HeapRead<'a, HostType>),
+    HostHandle(HeapRead<'a, HostHandle>),
     Module(HeapRead<'a, Module>),
     Coroutine(HeapRead<'a, Coroutine>),
     GatherFuture(HeapRead<'a, GatherFuture>),
     ExternalFuture(HeapRead<'a, ExternalFuture>),
     Path(HeapRead<'a, Path>),
     OpenFile(HeapRead<'a, OpenFile>),
+    MemoryFile(HeapRead<'a, MemoryFile>),
     RePattern(HeapRead<'a, RePattern>),
     ReMatch(HeapRead<'a, ReMatch>),
     Date(HeapRead<'a, date::Date>),
     DateTime(HeapRead<'a, datetime::DateTime>),
     TimeDelta(HeapRead<'a, timedelta::TimeDelta>),
     TimeZone(HeapRead<'a, timezone::TimeZone>),
+    GenericAlias(HeapRead<'a, GenericAlias>),
+    NewType(HeapRead<'a, NewType>),
+    TypedDict(HeapRead<'a, TypedDict>),
+    DataclassType(HeapRead<'a, DataclassType>),
+    DataclassField(HeapRead<'a, DataclassField>),
+    ContextManager(HeapRead<'a, ContextManager>),
+    Logger(HeapRead<'a, Logger>),
     /// Synthetic context manager — only present under `test-hooks`.
     #[cfg(feature = "test-hooks")]
     TestContextManager(HeapRead<'a, TestContextManager>),
@@ -594,6 +611,8 @@ impl<'a> HeapPtr<'a> {
         match unsafe { &*base } {
             HeapData::Str(s) => HeapReadOutput::Str(heap_read(base, s, readers)),
             HeapData::Bytes(bytes) => HeapReadOutput::Bytes(heap_read(base, bytes, readers)),
+            HeapData::ByteArray(bytes) => HeapReadOutput::ByteArray(heap_read(base, bytes, readers)),
+            HeapData::MemoryView(view) => HeapReadOutput::MemoryView(heap_read(base, view, readers)),
             HeapData::List(list) => HeapReadOutput::List(heap_read(base, list, readers)),
             HeapData::Tuple(tuple) => HeapReadOutput::Tuple(heap_read(base, tuple, readers)),
             HeapData::NamedTuple(named_tuple) => HeapReadOutput::NamedTuple(heap_read(base, named_tuple, readers)),
@@ -617,6 +636,10 @@ impl<'a> HeapPtr<'a> {
             HeapData::Dataclass(dataclass) => HeapReadOutput::Dataclass(heap_read(base, dataclass, readers)),
             HeapData::Iter(monty_iter) => HeapReadOutput::Iter(heap_read(base, monty_iter, readers)),
             HeapData::LongInt(l) => HeapReadOutput::LongInt(heap_read(base, l, readers)),
+            HeapData::Complex(c) => HeapReadOutput::Complex(heap_read(base, c, readers)),
+            HeapData::BaseObject(o) => HeapReadOutput::BaseObject(heap_read(base, o, readers)),
+            HeapData::HostType(t) => HeapReadOutput::HostType(heap_read(base, t, readers)),
+            HeapData::HostHandle(h) => HeapReadOutput::HostHandle(heap_read(base, h, readers)),
             HeapData::Module(module) => HeapReadOutput::Module(heap_read(base, module, readers)),
             HeapData::Coroutine(coroutine) => HeapReadOutput::Coroutine(heap_read(base, coroutine, readers)),
             HeapData::GatherFuture(gather_future) => {
@@ -627,12 +650,20 @@ impl<'a> HeapPtr<'a> {
             }
             HeapData::Path(path) => HeapReadOutput::Path(heap_read(base, path, readers)),
             HeapData::OpenFile(file) => HeapReadOutput::OpenFile(heap_read(base, file, readers)),
+            HeapData::MemoryFile(file) => HeapReadOutput::MemoryFile(heap_read(base, file, readers)),
             HeapData::RePattern(re_pattern) => HeapReadOutput::RePattern(heap_read_boxed(re_pattern, readers)),
             HeapData::ReMatch(re_match) => HeapReadOutput::ReMatch(heap_read(base, re_match, readers)),
             HeapData::Date(d) => HeapReadOutput::Date(heap_read(base, d, readers)),
             HeapData::DateTime(d) => HeapReadOutput::DateTime(heap_read(base, d, readers)),
             HeapData::TimeDelta(d) => HeapReadOutput::TimeDelta(heap_read(base, d, readers)),
             HeapData::TimeZone(d) => HeapReadOutput::TimeZone(heap_read(base, d, readers)),
+            HeapData::GenericAlias(alias) => HeapReadOutput::GenericAlias(heap_read(base, alias, readers)),
+            HeapData::NewType(nt) => HeapReadOutput::NewType(heap_read(base, nt, readers)),
+            HeapData::TypedDict(td) => HeapReadOutput::TypedDict(heap_read(base, td, readers)),
+            HeapData::DataclassType(class) => HeapReadOutput::DataclassType(heap_read(base, class, readers)),
+            HeapData::DataclassField(field) => HeapReadOutput::DataclassField(heap_read(base, field, readers)),
+            HeapData::ContextManager(cm) => HeapReadOutput::ContextManager(heap_read(base, cm, readers)),
+            HeapData::Logger(logger) => HeapReadOutput::Logger(heap_read(base, logger, readers)),
             #[cfg(feature = "test-hooks")]
             HeapData::TestContextManager(cm) => HeapReadOutput::TestContextManager(heap_read(base, cm, readers)),
         }
@@ -910,6 +941,25 @@ impl<T: ResourceTracker> Heap<T> {
         self.tracker.check_time()
     }
 
+    /// Charges `amount` units of fuel against the configured fuel limit.
+    ///
+    /// Delegates to the resource tracker's `consume_fuel()`, which is a no-op
+    /// for `NoLimitTracker`. See [`ResourceTracker::consume_fuel`] for what
+    /// each operation costs.
+    #[inline]
+    pub fn consume_fuel(&self, amount: usize) -> Result<(), ResourceError> {
+        self.tracker.consume_fuel(amount)
+    }
+
+    /// Returns a pending host interrupt or cancellation as an error.
+    ///
+    /// Delegates to the resource tracker's `check_interrupt()`, which is a
+    /// no-op for `NoLimitTracker`.
+    #[inline]
+    pub fn check_interrupt(&self) -> Result<(), ResourceError> {
+        self.tracker.check_interrupt()
+    }
+
     /// Tracks in-place memory growth of an existing heap object.
     ///
     /// Call this before performing mutations that grow containers (append, insert,
@@ -1547,16 +1597,16 @@ fn for_each_child_id<F: FnMut(HeapId)>(data: &HeapData, mut on_child: F) {
             for cell_id in &closure.cells {
                 on_child(*cell_id);
             }
-            // Add default values that are heap references
-            for default in &closure.defaults {
+            // Add default values (and the `__annotate__` function) that are heap references
+            for default in closure.defaults.iter().chain(&closure.annotate) {
                 if let Value::Ref(id) = default {
                     on_child(*id);
                 }
             }
         }
         HeapData::FunctionDefaults(fd) => {
-            // Add default values that are heap references
-            for default in &fd.defaults {
+            // Add default values (and the `__annotate__` function) that are heap references
+            for default in fd.defaults.iter().chain(&fd.annotate) {
                 if let Value::Ref(id) = default {
                     on_child(*id);
                 }
@@ -1578,6 +1628,10 @@ fn for_each_child_id<F: FnMut(HeapId)>(data: &HeapData, mut on_child: F) {
                     on_child(*id);
                 }
             }
+            // Sandbox-created instances also keep their class alive
+            if let Some(class_id) = dc.class_id() {
+                on_child(class_id);
+            }
         }
         HeapData::Iter(iter) => {
             // Iterator holds a reference to the iterable being iterated
@@ -1654,6 +1708,51 @@ fn for_each_child_id<F: FnMut(HeapId)>(data: &HeapData, mut on_child: F) {
                 on_child(tz_id);
             }
         }
+        HeapData::GenericAlias(alias) => {
+            for value in alias.values() {
+                if let Value::Ref(id) = value {
+                    on_child(*id);
+                }
+            }
+        }
+        HeapData::NewType(nt) => {
+            if let Value::Ref(id) = nt.supertype() {
+                on_child(*id);
+            }
+        }
+        HeapData::TypedDict(td) => {
+            if let Value::Ref(id) = td.annotations() {
+                on_child(*id);
+            }
+        }
+        HeapData::DataclassType(class) => {
+            for value in class.values() {
+                if let Value::Ref(id) = value {
+                    on_child(*id);
+                }
+            }
+        }
+        HeapData::DataclassField(field) => {
+            for value in field.values() {
+                if let Value::Ref(id) = value {
+                    on_child(*id);
+                }
+            }
+        }
+        HeapData::ContextManager(cm) => {
+            for value in cm.values() {
+                if let Value::Ref(id) = value {
+                    on_child(*id);
+                }
+            }
+        }
+        HeapData::Logger(logger) => {
+            for value in logger.values() {
+                if let Value::Ref(id) = value {
+                    on_child(*id);
+                }
+            }
+        }
         // Leaf types with no heap references
         _ => {}
     }
@@ -1663,6 +1762,8 @@ fn py_dec_ref_ids_for_data(data: &mut HeapData, stack: &mut Vec<HeapId>) {
     match data {
         HeapData::Str(s) => s.py_dec_ref_ids(stack),
         HeapData::Bytes(b) => b.py_dec_ref_ids(stack),
+        HeapData::ByteArray(b) => b.py_dec_ref_ids(stack),
+        HeapData::MemoryView(view) => view.py_dec_ref_ids(stack),
         HeapData::List(l) => l.py_dec_ref_ids(stack),
         HeapData::Tuple(t) => t.py_dec_ref_ids(stack),
         HeapData::NamedTuple(nt) => nt.py_dec_ref_ids(stack),
@@ -1676,13 +1777,13 @@ fn py_dec_ref_ids_for_data(data: &mut HeapData, stack: &mut Vec<HeapId>) {
             // Decrement ref count for captured cells
             stack.extend(closure.cells.iter().copied());
             // Decrement ref count for default values that are heap references
-            for default in &mut closure.defaults {
+            for default in closure.defaults.iter_mut().chain(&mut closure.annotate) {
                 default.py_dec_ref_ids(stack);
             }
         }
         HeapData::FunctionDefaults(fd) => {
             // Decrement ref count for default values that are heap references
-            for default in &mut fd.defaults {
+            for default in fd.defaults.iter_mut().chain(&mut fd.annotate) {
                 default.py_dec_ref_ids(stack);
             }
         }
@@ -1735,6 +1836,13 @@ fn py_dec_ref_ids_for_data(data: &mut HeapData, stack: &mut Vec<HeapId>) {
                 stack.push(tz_id);
             }
         }
+        HeapData::GenericAlias(alias) => alias.py_dec_ref_ids(stack),
+        HeapData::NewType(nt) => nt.py_dec_ref_ids(stack),
+        HeapData::TypedDict(td) => td.py_dec_ref_ids(stack),
+        HeapData::DataclassType(class) => class.py_dec_ref_ids(stack),
+        HeapData::DataclassField(field) => field.py_dec_ref_ids(stack),
+        HeapData::ContextManager(cm) => cm.py_dec_ref_ids(stack),
+        HeapData::Logger(logger) => logger.py_dec_ref_ids(stack),
         // other types have no nested heap references
         _ => {}
     }
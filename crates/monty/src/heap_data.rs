@@ -20,13 +20,21 @@ use crate::{
     asyncio::{Awaiter, Coroutine, ExternalFuture, ExternalFutureState, GatherFuture, GatherState},
     bytecode::{CallResult, VM},
     exception_private::{RunError, RunResult, SimpleException},
+    function::function_annotations,
     hash::{HashValue, hash_python_str},
-    heap::{DropWithHeap, HeapId, HeapItem, HeapReadOutput},
-    intern::FunctionId,
+    heap::{DropWithHeap, HeapId, HeapItem, HeapReadOutput, HeapReader},
+    intern::{FunctionId, StaticStrings},
+    object::MontyObject,
     types::{
-        Bytes, Dataclass, Dict, DictItemsView, DictKeysView, DictValuesView, FrozenSet, List, LongInt, Module,
-        MontyIter, NamedTuple, OpenFile, Path, PyTrait, Range, ReMatch, RePattern, Set, Slice, Str, Tuple, Type, date,
-        datetime, str::allocate_string, timedelta, timezone,
+        BaseObject, ByteArray, Bytes, Complex, ContextManager, Dataclass, Dict, DictItemsView, DictKeysView,
+        DictValuesView, FrozenSet, GenericAlias, HostHandle, HostType, List, Logger, LongInt, MemoryFile, MemoryView,
+        Module, MontyIter, NamedTuple, NewType, OpenFile, Path, PyTrait, Range, ReMatch, RePattern, Set, Slice, Str,
+        Tuple, Type, TypedDict,
+        dataclass_type::{DataclassField, DataclassType},
+        date, datetime,
+        int::{IntReceiver, call_int_method, long_int_getattr},
+        str::allocate_string,
+        timedelta, timezone,
     },
     value::{EitherStr, Value},
 };
@@ -40,6 +48,12 @@ use crate::{
 pub(crate) enum HeapData {
     Str(Str),
     Bytes(Bytes),
+    /// A mutable byte buffer from `bytearray()`.
+    ByteArray(ByteArray),
+    /// A read-only `memoryview()` over a `bytes` or `bytearray` object.
+    ///
+    /// Holds a single reference to its source buffer, which is never a container.
+    MemoryView(MemoryView),
     List(List),
     Tuple(Tuple),
     NamedTuple(NamedTuple),
@@ -90,6 +104,15 @@ pub(crate) enum HeapData {
     /// when values fit, and promote to LongInt on overflow. When LongInt results fit back
     /// in i64, they are demoted back to `Value::Int` for performance.
     LongInt(LongInt),
+    /// A `complex` number, stored on the heap since its two `f64` components
+    /// don't fit in `Value`.
+    Complex(Complex),
+    /// A bare `object()` instance, only useful for its identity.
+    BaseObject(BaseObject),
+    /// A dataclass or named tuple class defined by the host.
+    HostType(HostType),
+    /// An opaque handle to an object that stays on the host.
+    HostHandle(HostHandle),
     /// A Python module (e.g., `sys`, `typing`).
     ///
     /// Modules have a name and a dictionary of attributes. They are created by
@@ -121,6 +144,11 @@ pub(crate) enum HeapData {
     /// The object stores only virtual path and mode state.  Reads and writes are
     /// full-file OS calls; no native file descriptor is kept while Monty runs.
     OpenFile(OpenFile),
+    /// An in-memory file from `io.StringIO()` or `io.BytesIO()`.
+    ///
+    /// The buffer is owned inline, so this is a leaf type that never yields
+    /// to the host.
+    MemoryFile(MemoryFile),
     /// A compiled regex pattern from `re.compile()`.
     ///
     /// Contains the original pattern string, flags, and compiled regex engine.
@@ -146,6 +174,30 @@ pub(crate) enum HeapData {
     TimeDelta(timedelta::TimeDelta),
     /// A fixed-offset `datetime.timezone` value.
     TimeZone(timezone::TimeZone),
+    /// A subscripted generic such as `list[int]` or `typing.Optional[str]`.
+    ///
+    /// Holds its origin and type arguments, which can be any values (including
+    /// other aliases), so it is GC-tracked.
+    GenericAlias(GenericAlias),
+    /// A distinct type created by `typing.NewType(name, tp)`.
+    NewType(NewType),
+    /// A dict type created by the functional `typing.TypedDict(name, fields)` form.
+    TypedDict(TypedDict),
+    /// A class declared in sandboxed code, processed by `@dataclass`.
+    ///
+    /// Holds field annotations, defaults and methods, so it is GC-tracked.
+    DataclassType(DataclassType),
+    /// A `dataclasses.Field` returned by `dataclasses.field()` or `dataclasses.fields()`.
+    DataclassField(DataclassField),
+    /// A context manager returned by `contextlib.suppress()`, `ExitStack()` and friends.
+    ///
+    /// `closing`, `nullcontext`, `redirect_stdout` and `ExitStack` hold arbitrary
+    /// values, so it is GC-tracked.
+    ContextManager(ContextManager),
+    /// A `logging.Logger` or `logging.LoggerAdapter`.
+    ///
+    /// An adapter holds its logger and arbitrary `extra` values, so it is GC-tracked.
+    Logger(Logger),
     /// Synthetic context manager used by tests to exercise `with` statement
     /// code paths no production type currently reaches. See
     /// [`crate::types::test_cm`] for the full rationale and removal plan.
@@ -185,12 +237,21 @@ impl HeapData {
                 | Self::Coroutine(_)
                 | Self::GatherFuture(_)
                 | Self::ExternalFuture(_)
+                | Self::GenericAlias(_)
+                | Self::NewType(_)
+                | Self::TypedDict(_)
+                | Self::DataclassType(_)
+                | Self::DataclassField(_)
+                | Self::ContextManager(_)
+                | Self::Logger(_)
         )
         // `OpenFile` is deliberately *not* listed here: its single heap
         // reference (`buffer`) only ever points to `Str` / `Bytes`, neither of
         // which is GC-tracked, so an `OpenFile` cannot participate in a
         // reference cycle. Add it back if `OpenFile` ever gains a field that
         // can hold a container value (e.g. a user-provided callback).
+        // `MemoryView` is left out for the same reason: its `obj` is always a
+        // `Bytes` or `ByteArray`.
     }
 
     /// Returns the Python `Type` for this heap data without requiring VM access.
@@ -203,6 +264,8 @@ impl HeapData {
         match self {
             Self::Str(_) => Type::Str,
             Self::Bytes(_) => Type::Bytes,
+            Self::ByteArray(_) => Type::ByteArray,
+            Self::MemoryView(_) => Type::MemoryView,
             Self::List(_) => Type::List,
             Self::Tuple(_) | Self::NamedTuple(_) => Type::Tuple,
             Self::Dict(_) => Type::Dict,
@@ -219,16 +282,28 @@ impl HeapData {
             Self::Dataclass(_) => Type::Dataclass,
             Self::Iter(_) => Type::Iterator,
             Self::LongInt(_) => Type::Int,
+            Self::Complex(_) => Type::Complex,
+            Self::BaseObject(_) => Type::Object,
+            Self::HostType(_) => Type::Type,
+            Self::HostHandle(_) => Type::Object,
             Self::Module(_) => Type::Module,
             Self::Coroutine(_) | Self::GatherFuture(_) | Self::ExternalFuture(_) => Type::Coroutine,
             Self::Path(_) => Type::Path,
             Self::OpenFile(file) => file.file_type(),
+            Self::MemoryFile(file) => file.py_type(),
             Self::RePattern(_) => Type::RePattern,
             Self::ReMatch(_) => Type::ReMatch,
             Self::Date(_) => Type::Date,
             Self::DateTime(_) => Type::DateTime,
             Self::TimeDelta(_) => Type::TimeDelta,
             Self::TimeZone(_) => Type::TimeZone,
+            Self::GenericAlias(_) => Type::GenericAlias,
+            Self::NewType(_) => Type::NewType,
+            Self::TypedDict(_) => Type::TypedDict,
+            Self::DataclassType(_) => Type::Type,
+            Self::DataclassField(_) => Type::DataclassField,
+            Self::ContextManager(cm) => cm.py_type(),
+            Self::Logger(logger) => logger.py_type(),
             #[cfg(feature = "test-hooks")]
             Self::TestContextManager(_) => Type::TestContextManager,
         }
@@ -238,6 +313,8 @@ impl HeapData {
         match self {
             Self::Str(s) => s.py_estimate_size(),
             Self::Bytes(b) => b.py_estimate_size(),
+            Self::ByteArray(b) => b.py_estimate_size(),
+            Self::MemoryView(view) => view.py_estimate_size(),
             Self::List(l) => l.py_estimate_size(),
             Self::Tuple(t) => t.py_estimate_size(),
             Self::NamedTuple(nt) => nt.py_estimate_size(),
@@ -256,12 +333,17 @@ impl HeapData {
             Self::Dataclass(dc) => dc.py_estimate_size(),
             Self::Iter(iter) => iter.py_estimate_size(),
             Self::LongInt(li) => li.py_estimate_size(),
+            Self::Complex(c) => c.py_estimate_size(),
+            Self::BaseObject(o) => o.py_estimate_size(),
+            Self::HostType(t) => t.py_estimate_size(),
+            Self::HostHandle(h) => h.py_estimate_size(),
             Self::Module(m) => m.py_estimate_size(),
             Self::Coroutine(coro) => coro.py_estimate_size(),
             Self::GatherFuture(gather) => gather.py_estimate_size(),
             Self::ExternalFuture(fut) => fut.py_estimate_size(),
             Self::Path(p) => p.py_estimate_size(),
             Self::OpenFile(file) => file.py_estimate_size(),
+            Self::MemoryFile(file) => file.py_estimate_size(),
             Self::ReMatch(m) => m.py_estimate_size(),
             Self::RePattern(p) => p.py_estimate_size(),
             Self::ExtFunction(s) => mem::size_of::<String>() + s.len(),
@@ -269,6 +351,13 @@ impl HeapData {
             Self::DateTime(d) => d.py_estimate_size(),
             Self::TimeDelta(d) => d.py_estimate_size(),
             Self::TimeZone(d) => d.py_estimate_size(),
+            Self::GenericAlias(alias) => alias.py_estimate_size(),
+            Self::NewType(nt) => nt.py_estimate_size(),
+            Self::TypedDict(td) => td.py_estimate_size(),
+            Self::DataclassType(class) => class.py_estimate_size(),
+            Self::DataclassField(field) => field.py_estimate_size(),
+            Self::ContextManager(cm) => cm.py_estimate_size(),
+            Self::Logger(logger) => logger.py_estimate_size(),
             #[cfg(feature = "test-hooks")]
             Self::TestContextManager(cm) => cm.py_estimate_size(),
         }
@@ -305,9 +394,11 @@ pub(crate) struct Closure {
     pub cells: Vec<HeapId>,
     /// Evaluated default parameter values (if any).
     pub defaults: Vec<Value>,
+    /// The `__annotate__` function, called lazily to build `__annotations__`.
+    pub annotate: Option<Value>,
 }
 
-/// A function with evaluated default parameter values (non-closure).
+/// A function with evaluated default parameter values and/or annotations (non-closure).
 ///
 /// Contains a reference to the function definition and the evaluated default values.
 /// When the function is called, defaults are cloned for missing optional parameters.
@@ -318,6 +409,8 @@ pub(crate) struct FunctionDefaults {
     pub func_id: FunctionId,
     /// Evaluated default parameter values (if any).
     pub defaults: Vec<Value>,
+    /// The `__annotate__` function, called lazily to build `__annotations__`.
+    pub annotate: Option<Value>,
 }
 
 impl HeapItem for CellValue {
@@ -344,6 +437,9 @@ impl HeapItem for Closure {
         for default in &mut self.defaults {
             default.py_dec_ref_ids(stack);
         }
+        if let Some(annotate) = &mut self.annotate {
+            annotate.py_dec_ref_ids(stack);
+        }
     }
 }
 
@@ -357,12 +453,17 @@ impl HeapItem for FunctionDefaults {
         for default in &mut self.defaults {
             default.py_dec_ref_ids(stack);
         }
+        if let Some(annotate) = &mut self.annotate {
+            annotate.py_dec_ref_ids(stack);
+        }
     }
 }
 
 impl HeapItem for SimpleException {
     fn py_estimate_size(&self) -> usize {
-        mem::size_of::<Self>() + self.arg().map_or(0, String::len)
+        mem::size_of::<Self>()
+            + self.args().len() * mem::size_of::<MontyObject>()
+            + self.notes().iter().map(String::len).sum::<usize>()
     }
 
     fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {
@@ -463,11 +564,27 @@ impl HeapItem for ExternalFuture {
     }
 }
 
+impl<'h> HeapReadOutput<'h> {
+    /// Returns the contents of a `bytes`, `bytearray` or `memoryview`, or `None` for other types.
+    ///
+    /// Used by the cross-type comparison and concatenation arms below.
+    fn bytes_like_contents<'r>(&self, heap: &'r HeapReader<'h, impl ResourceTracker>) -> Option<Cow<'r, [u8]>> {
+        match self {
+            Self::Bytes(b) => Some(Cow::Borrowed(b.get(heap).as_slice())),
+            Self::ByteArray(b) => Some(Cow::Borrowed(b.get(heap).as_slice())),
+            Self::MemoryView(view) => Some(view.get(heap).contents(heap)),
+            _ => None,
+        }
+    }
+}
+
 impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
     fn py_bool(&self, vm: &mut VM<'h, impl ResourceTracker>) -> bool {
         match self {
             Self::Str(s) => s.py_bool(vm),
             Self::Bytes(b) => b.py_bool(vm),
+            Self::ByteArray(b) => b.py_bool(vm),
+            Self::MemoryView(view) => view.py_bool(vm),
             Self::List(l) => l.py_bool(vm),
             Self::Tuple(t) => t.py_bool(vm),
             Self::NamedTuple(nt) => nt.py_bool(vm),
@@ -485,16 +602,23 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             Self::Dataclass(dc) => dc.py_bool(vm),
             Self::Iter(_) => true,
             Self::LongInt(li) => !li.get(vm.heap).is_zero(),
+            Self::Complex(c) => c.py_bool(vm),
+            Self::BaseObject(_) | Self::HostType(_) | Self::HostHandle(_) => true,
             Self::Module(_) => true,
             Self::Coroutine(_) => true,
             Self::GatherFuture(_) => true,
             Self::ExternalFuture(_) => true,
             Self::Path(p) => p.py_bool(vm),
             Self::OpenFile(file) => file.py_bool(vm),
+            Self::MemoryFile(file) => file.py_bool(vm),
             Self::ReMatch(m) => m.py_bool(vm),
             Self::RePattern(p) => p.py_bool(vm),
             Self::TimeDelta(td) => td.py_bool(vm),
             Self::Date(_) | Self::DateTime(_) | Self::TimeZone(_) => true,
+            Self::GenericAlias(_) | Self::NewType(_) | Self::TypedDict(_) => true,
+            Self::DataclassType(_) | Self::DataclassField(_) => true,
+            Self::ContextManager(cm) => cm.py_bool(vm),
+            Self::Logger(logger) => logger.py_bool(vm),
             #[cfg(feature = "test-hooks")]
             Self::TestContextManager(cm) => cm.py_bool(vm),
         }
@@ -510,6 +634,8 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         match self {
             HeapReadOutput::Str(s) => Ok(s.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::Bytes(b) => Ok(b.py_call_attr(self_id, vm, attr, args)?),
+            HeapReadOutput::ByteArray(b) => b.py_call_attr(self_id, vm, attr, args),
+            HeapReadOutput::MemoryView(view) => view.py_call_attr(self_id, vm, attr, args),
             HeapReadOutput::List(list) => Ok(list.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::Tuple(t) => Ok(t.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::Dict(dict) => Ok(dict.py_call_attr(self_id, vm, attr, args)?),
@@ -519,14 +645,24 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             HeapReadOutput::Set(s) => Ok(s.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::FrozenSet(fs) => Ok(fs.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::Dataclass(dc) => Ok(dc.py_call_attr(self_id, vm, attr, args)?),
+            HeapReadOutput::DataclassType(class) => Ok(class.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::Path(p) => Ok(p.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::OpenFile(file) => Ok(file.py_call_attr(self_id, vm, attr, args)?),
+            HeapReadOutput::MemoryFile(file) => file.py_call_attr(self_id, vm, attr, args),
+            HeapReadOutput::LongInt(_) => {
+                call_int_method(IntReceiver::Long(self_id), Type::Int, attr, args, vm).map(CallResult::Value)
+            }
             HeapReadOutput::Module(m) => Ok(m.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::ReMatch(m) => Ok(m.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::RePattern(p) => Ok(p.py_call_attr(self_id, vm, attr, args)?),
+            HeapReadOutput::Complex(c) => c.py_call_attr(self_id, vm, attr, args),
             HeapReadOutput::TimeDelta(td) => Ok(td.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::Date(d) => Ok(d.py_call_attr(self_id, vm, attr, args)?),
             HeapReadOutput::DateTime(dt) => Ok(dt.py_call_attr(self_id, vm, attr, args)?),
+            HeapReadOutput::ContextManager(cm) => cm.py_call_attr(self_id, vm, attr, args),
+            HeapReadOutput::Logger(logger) => logger.py_call_attr(self_id, vm, attr, args),
+            HeapReadOutput::Exception(e) => e.py_call_attr(vm, attr, args),
+            HeapReadOutput::HostHandle(h) => h.py_call_attr(self_id, vm, attr, args),
             #[cfg(feature = "test-hooks")]
             HeapReadOutput::TestContextManager(cm) => cm.py_call_attr(self_id, vm, attr, args),
             // Types without methods — return AttributeError
@@ -546,6 +682,9 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         // misdiagnosed as "not a context manager".
         match self {
             HeapReadOutput::OpenFile(file) => file.py_is_context_manager(),
+            HeapReadOutput::MemoryFile(file) => file.py_is_context_manager(),
+            HeapReadOutput::MemoryView(view) => view.py_is_context_manager(),
+            HeapReadOutput::ContextManager(cm) => cm.py_is_context_manager(),
             #[cfg(feature = "test-hooks")]
             HeapReadOutput::TestContextManager(cm) => cm.py_is_context_manager(),
             _ => false,
@@ -558,6 +697,9 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         // `py_call_attr` is structured.
         match self {
             HeapReadOutput::OpenFile(file) => file.py_enter(self_id, vm),
+            HeapReadOutput::MemoryFile(file) => file.py_enter(self_id, vm),
+            HeapReadOutput::MemoryView(view) => view.py_enter(self_id, vm),
+            HeapReadOutput::ContextManager(cm) => cm.py_enter(self_id, vm),
             #[cfg(feature = "test-hooks")]
             HeapReadOutput::TestContextManager(cm) => cm.py_enter(self_id, vm),
             _ => Err(ExcType::attribute_error(self.py_type(vm), "__enter__")),
@@ -572,6 +714,9 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
     ) -> RunResult<CallResult> {
         match self {
             HeapReadOutput::OpenFile(file) => file.py_exit(self_id, vm, exc),
+            HeapReadOutput::MemoryFile(file) => file.py_exit(self_id, vm, exc),
+            HeapReadOutput::MemoryView(view) => view.py_exit(self_id, vm, exc),
+            HeapReadOutput::ContextManager(cm) => cm.py_exit(self_id, vm, exc),
             #[cfg(feature = "test-hooks")]
             HeapReadOutput::TestContextManager(cm) => cm.py_exit(self_id, vm, exc),
             _ => Err(ExcType::attribute_error(self.py_type(vm), "__exit__")),
@@ -582,6 +727,8 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         match self {
             Self::Str(s) => s.py_type(vm),
             Self::Bytes(b) => b.py_type(vm),
+            Self::ByteArray(b) => b.py_type(vm),
+            Self::MemoryView(view) => view.py_type(vm),
             Self::List(l) => l.py_type(vm),
             Self::Tuple(t) => t.py_type(vm),
             Self::NamedTuple(nt) => nt.py_type(vm),
@@ -599,16 +746,28 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             Self::Dataclass(dc) => dc.py_type(vm),
             Self::Iter(_) => Type::Iterator,
             Self::LongInt(_) => Type::Int,
+            Self::Complex(c) => c.py_type(vm),
+            Self::BaseObject(o) => o.py_type(vm),
+            Self::HostType(t) => t.py_type(vm),
+            Self::HostHandle(h) => h.py_type(vm),
             Self::Module(_) => Type::Module,
             Self::Coroutine(_) | Self::GatherFuture(_) | Self::ExternalFuture(_) => Type::Coroutine,
             Self::Path(p) => p.py_type(vm),
             Self::OpenFile(file) => file.py_type(vm),
+            Self::MemoryFile(file) => file.py_type(vm),
             Self::ReMatch(re) => re.py_type(vm),
             Self::RePattern(p) => p.py_type(vm),
             Self::Date(d) => d.py_type(vm),
             Self::DateTime(d) => d.py_type(vm),
             Self::TimeDelta(d) => d.py_type(vm),
             Self::TimeZone(d) => d.py_type(vm),
+            Self::GenericAlias(alias) => alias.py_type(vm),
+            Self::NewType(nt) => nt.py_type(vm),
+            Self::TypedDict(td) => td.py_type(vm),
+            Self::DataclassType(class) => class.py_type(vm),
+            Self::DataclassField(field) => field.py_type(vm),
+            Self::ContextManager(cm) => cm.py_type(vm),
+            Self::Logger(logger) => logger.py_type(vm),
             #[cfg(feature = "test-hooks")]
             Self::TestContextManager(cm) => cm.py_type(vm),
         }
@@ -618,6 +777,8 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         match self {
             Self::Str(s) => s.py_len(vm),
             Self::Bytes(b) => b.py_len(vm),
+            Self::ByteArray(b) => b.py_len(vm),
+            Self::MemoryView(view) => view.py_len(vm),
             Self::List(l) => l.py_len(vm),
             Self::Tuple(t) => t.py_len(vm),
             Self::NamedTuple(nt) => nt.py_len(vm),
@@ -644,7 +805,10 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             (HeapReadOutput::Bytes(a), HeapReadOutput::Bytes(b)) => {
                 Ok(a.get(vm.heap).as_slice() == b.get(vm.heap).as_slice())
             }
+            (HeapReadOutput::ByteArray(a), HeapReadOutput::ByteArray(b)) => a.py_eq(b, vm),
+            (HeapReadOutput::MemoryView(a), HeapReadOutput::MemoryView(b)) => a.py_eq(b, vm),
             (HeapReadOutput::LongInt(a), HeapReadOutput::LongInt(b)) => Ok(a.get(vm.heap) == b.get(vm.heap)),
+            (HeapReadOutput::Complex(a), HeapReadOutput::Complex(b)) => a.py_eq(b, vm),
             (HeapReadOutput::Closure(a), HeapReadOutput::Closure(b)) => {
                 let a = a.get(vm.heap);
                 let b = b.get(vm.heap);
@@ -691,12 +855,7 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             (HeapReadOutput::Set(b), HeapReadOutput::DictItemsView(a)) => a.eq_set(b, vm),
             (HeapReadOutput::DictItemsView(a), HeapReadOutput::FrozenSet(b)) => a.eq_frozenset(b, vm),
             (HeapReadOutput::FrozenSet(b), HeapReadOutput::DictItemsView(a)) => a.eq_frozenset(b, vm),
-            (HeapReadOutput::Dataclass(a), HeapReadOutput::Dataclass(b)) => {
-                if a.get(vm.heap).name(vm.interns) != b.get(vm.heap).name(vm.interns) {
-                    return Ok(false);
-                }
-                a.attrs().py_eq(&b.attrs(), vm)
-            }
+            (HeapReadOutput::Dataclass(a), HeapReadOutput::Dataclass(b)) => a.py_eq(b, vm),
             // Pure data comparisons (no VM needed)
             (HeapReadOutput::Slice(a), HeapReadOutput::Slice(b)) => {
                 let a = a.get(vm.heap);
@@ -710,6 +869,9 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             (HeapReadOutput::DateTime(a), HeapReadOutput::DateTime(b)) => a.py_eq(b, vm),
             (HeapReadOutput::TimeDelta(a), HeapReadOutput::TimeDelta(b)) => a.py_eq(b, vm),
             (HeapReadOutput::TimeZone(a), HeapReadOutput::TimeZone(b)) => a.py_eq(b, vm),
+            (HeapReadOutput::GenericAlias(a), HeapReadOutput::GenericAlias(b)) => a.py_eq(b, vm),
+            (HeapReadOutput::HostType(a), HeapReadOutput::HostType(b)) => a.py_eq(b, vm),
+            (HeapReadOutput::HostHandle(a), HeapReadOutput::HostHandle(b)) => a.py_eq(b, vm),
             // External functions compare equal iff their names match — the
             // same name-based identity used by `Value::py_eq`'s ExtFunction
             // arms and `py_hash` via `hash_python_str`. (#347)
@@ -723,6 +885,10 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             | (HeapReadOutput::Coroutine(_), HeapReadOutput::Coroutine(_))
             | (HeapReadOutput::GatherFuture(_), HeapReadOutput::GatherFuture(_))
             | (HeapReadOutput::DictValuesView(_), HeapReadOutput::DictValuesView(_)) => Ok(false),
+            // bytes, bytearray and memoryview compare by content across types
+            (a, b) if let (Some(a), Some(b)) = (a.bytes_like_contents(vm.heap), b.bytes_like_contents(vm.heap)) => {
+                Ok(a == b)
+            }
             // Different types are never equal
             _ => Ok(false),
         }
@@ -738,6 +904,7 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         match self {
             Self::Str(s) => s.py_hash(self_id, vm),
             Self::Bytes(b) => b.py_hash(self_id, vm),
+            Self::MemoryView(view) => view.py_hash(self_id, vm),
             Self::Tuple(t) => t.py_hash(self_id, vm),
             Self::NamedTuple(nt) => nt.py_hash(self_id, vm),
             Self::FrozenSet(fs) => fs.py_hash(self_id, vm),
@@ -747,8 +914,19 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             Self::Path(p) => p.py_hash(self_id, vm),
             Self::Date(d) => d.py_hash(self_id, vm),
             Self::DateTime(d) => d.py_hash(self_id, vm),
+            Self::Complex(c) => c.py_hash(self_id, vm),
+            Self::BaseObject(o) => o.py_hash(self_id, vm),
+            Self::HostType(t) => t.py_hash(self_id, vm),
+            Self::HostHandle(h) => h.py_hash(self_id, vm),
             Self::TimeDelta(d) => d.py_hash(self_id, vm),
             Self::TimeZone(d) => d.py_hash(self_id, vm),
+            Self::GenericAlias(alias) => alias.py_hash(self_id, vm),
+            Self::NewType(nt) => nt.py_hash(self_id, vm),
+            Self::TypedDict(td) => td.py_hash(self_id, vm),
+            Self::DataclassType(class) => class.py_hash(self_id, vm),
+            Self::DataclassField(field) => field.py_hash(self_id, vm),
+            Self::ContextManager(cm) => cm.py_hash(self_id, vm),
+            Self::Logger(logger) => logger.py_hash(self_id, vm),
             // Closure / FunctionDefaults: hash by function ID. Two equal
             // closures share the same `func_id`, so this is sufficient.
             Self::Closure(c) => {
@@ -771,7 +949,7 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             // both Python `int` values and must hash equally when equal.
             Self::LongInt(li) => Ok(Some(li.get(vm.heap).hash())),
             Self::ExtFunction(name) => Ok(Some(hash_python_str(name.get(vm.heap)))),
-            // Unhashable: List, Dict, Set, the dict views, Iter, Module,
+            // Unhashable: List, Dict, Set, ByteArray, the dict views, Iter, Module,
             // Exception, Coroutine, GatherFuture, RePattern, ReMatch.
             _ => Ok(None),
         }
@@ -786,6 +964,8 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         match self {
             Self::Str(s) => s.py_repr_fmt(f, vm, heap_ids),
             Self::Bytes(b) => b.py_repr_fmt(f, vm, heap_ids),
+            Self::ByteArray(b) => b.py_repr_fmt(f, vm, heap_ids),
+            Self::MemoryView(view) => view.py_repr_fmt(f, vm, heap_ids),
             Self::List(l) => l.py_repr_fmt(f, vm, heap_ids),
             Self::Tuple(t) => t.py_repr_fmt(f, vm, heap_ids),
             Self::NamedTuple(nt) => nt.py_repr_fmt(f, vm, heap_ids),
@@ -814,7 +994,7 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
                 li.check_str_digits_limit()?;
                 Ok(write!(f, "{li}")?)
             }
-            Self::Module(m) => Ok(write!(f, "<module '{}'>", vm.interns.get_str(m.get(vm.heap).name()))?),
+            Self::Module(m) => Ok(write!(f, "<module '{}'>", m.get(vm.heap).name().as_str(vm.interns))?),
             Self::Coroutine(coro) => {
                 let func = vm.interns.get_function(coro.get(vm.heap).func_id);
                 let name = vm.interns.get_str(func.name.name_id);
@@ -831,10 +1011,22 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             Self::RePattern(p) => p.py_repr_fmt(f, vm, heap_ids),
             Self::ExtFunction(name) => Ok(write!(f, "<function '{}' external>", name.get(vm.heap))?),
             Self::OpenFile(file) => file.py_repr_fmt(f, vm, heap_ids),
+            Self::MemoryFile(file) => file.py_repr_fmt(f, vm, heap_ids),
             Self::Date(d) => d.py_repr_fmt(f, vm, heap_ids),
             Self::DateTime(d) => d.py_repr_fmt(f, vm, heap_ids),
+            Self::Complex(c) => c.py_repr_fmt(f, vm, heap_ids),
+            Self::BaseObject(o) => o.py_repr_fmt(f, vm, heap_ids),
+            Self::HostType(t) => t.py_repr_fmt(f, vm, heap_ids),
+            Self::HostHandle(h) => h.py_repr_fmt(f, vm, heap_ids),
             Self::TimeDelta(d) => d.py_repr_fmt(f, vm, heap_ids),
             Self::TimeZone(d) => d.py_repr_fmt(f, vm, heap_ids),
+            Self::GenericAlias(alias) => alias.py_repr_fmt(f, vm, heap_ids),
+            Self::NewType(nt) => nt.py_repr_fmt(f, vm, heap_ids),
+            Self::TypedDict(td) => td.py_repr_fmt(f, vm, heap_ids),
+            Self::DataclassType(class) => class.py_repr_fmt(f, vm, heap_ids),
+            Self::DataclassField(field) => field.py_repr_fmt(f, vm, heap_ids),
+            Self::ContextManager(cm) => cm.py_repr_fmt(f, vm, heap_ids),
+            Self::Logger(logger) => logger.py_repr_fmt(f, vm, heap_ids),
             #[cfg(feature = "test-hooks")]
             Self::TestContextManager(cm) => cm.py_repr_fmt(f, vm, heap_ids),
         }
@@ -857,6 +1049,7 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             // Datetime types have their own str output
             Self::Date(d) => d.py_str(vm),
             Self::DateTime(d) => d.py_str(vm),
+            Self::Complex(c) => c.py_str(vm),
             Self::TimeDelta(d) => d.py_str(vm),
             Self::TimeZone(d) => d.py_str(vm),
             // All other types use repr
@@ -882,6 +1075,21 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
                 result.extend_from_slice(b_bytes);
                 Ok(Some(Value::Ref(vm.heap.allocate(HeapData::Bytes(result.into()))?)))
             }
+            (HeapReadOutput::ByteArray(a), HeapReadOutput::ByteArray(b)) => a.py_add(b, vm),
+            // Mixed bytes-like concatenation takes the type of the left operand;
+            // a memoryview on the left has no `__add__`.
+            (HeapReadOutput::Bytes(_) | HeapReadOutput::ByteArray(_), b)
+                if let Some(b_bytes) = b.bytes_like_contents(vm.heap) =>
+            {
+                let mut result = self.bytes_like_contents(vm.heap).unwrap_or_default().into_owned();
+                result.extend_from_slice(&b_bytes);
+                let data = if matches!(self, HeapReadOutput::ByteArray(_)) {
+                    HeapData::ByteArray(ByteArray::new(result))
+                } else {
+                    HeapData::Bytes(result.into())
+                };
+                Ok(Some(Value::Ref(vm.heap.allocate(data)?)))
+            }
             (HeapReadOutput::List(a), HeapReadOutput::List(b)) => a.py_add(b, vm),
             (HeapReadOutput::Tuple(a), HeapReadOutput::Tuple(b)) => a.py_add(b, vm),
             (HeapReadOutput::LongInt(a), HeapReadOutput::LongInt(b)) => {
@@ -981,6 +1189,7 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
     ) -> Result<bool, crate::ResourceError> {
         match self {
             HeapReadOutput::List(list) => list.py_iadd(other, vm, self_id),
+            HeapReadOutput::ByteArray(b) => b.py_iadd(other, vm, self_id),
             _ => Ok(false),
         }
     }
@@ -989,6 +1198,8 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         match self {
             Self::Str(s) => s.py_getitem(key, vm),
             Self::Bytes(b) => b.py_getitem(key, vm),
+            Self::ByteArray(b) => b.py_getitem(key, vm),
+            Self::MemoryView(view) => view.py_getitem(key, vm),
             Self::List(l) => l.py_getitem(key, vm),
             Self::Tuple(t) => t.py_getitem(key, vm),
             Self::NamedTuple(nt) => nt.py_getitem(key, vm),
@@ -1003,6 +1214,8 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
         match self {
             Self::List(l) => l.py_setitem(key, value, vm),
             Self::Dict(d) => d.py_setitem(key, value, vm),
+            Self::ByteArray(b) => b.py_setitem(key, value, vm),
+            Self::MemoryView(view) => view.py_setitem(key, value, vm),
             _ => {
                 key.drop_with_heap(vm);
                 value.drop_with_heap(vm);
@@ -1033,9 +1246,32 @@ impl<'h> PyTrait<'h> for HeapReadOutput<'h> {
             Self::Exception(e) => e.py_getattr(attr, vm),
             Self::Path(p) => p.py_getattr(attr, vm),
             Self::OpenFile(file) => file.py_getattr(attr, vm),
+            Self::MemoryFile(file) => file.py_getattr(attr, vm),
+            Self::MemoryView(view) => view.py_getattr(attr, vm),
+            Self::LongInt(li) => {
+                let li = li.get(vm.heap).clone();
+                long_int_getattr(&li, attr, vm)
+            }
             Self::Date(d) => d.py_getattr(attr, vm),
             Self::DateTime(dt) => dt.py_getattr(attr, vm),
+            Self::Complex(c) => c.py_getattr(attr, vm),
             Self::TimeDelta(td) => td.py_getattr(attr, vm),
+            Self::GenericAlias(alias) => alias.py_getattr(attr, vm),
+            Self::NewType(nt) => nt.py_getattr(attr, vm),
+            Self::TypedDict(td) => td.py_getattr(attr, vm),
+            Self::DataclassType(class) => class.py_getattr(attr, vm),
+            Self::HostType(class) => class.py_getattr(attr, vm),
+            Self::HostHandle(handle) => handle.py_getattr(attr, vm),
+            Self::DataclassField(field) => field.py_getattr(attr, vm),
+            Self::Logger(logger) => logger.py_getattr(attr, vm),
+            Self::Closure(closure) if attr.static_string() == Some(StaticStrings::DunderAnnotations) => {
+                let annotate = closure.get(vm.heap).annotate.as_ref().map(|v| v.clone_with_heap(vm));
+                function_annotations(annotate, vm).map(|v| Some(CallResult::Value(v)))
+            }
+            Self::FunctionDefaults(fd) if attr.static_string() == Some(StaticStrings::DunderAnnotations) => {
+                let annotate = fd.get(vm.heap).annotate.as_ref().map(|v| v.clone_with_heap(vm));
+                function_annotations(annotate, vm).map(|v| Some(CallResult::Value(v)))
+            }
             _ => Ok(None),
         }
     }
@@ -0,0 +1,173 @@
+//! Modules defined by the host and importable from sandboxed code.
+//!
+//! A [`MontyModule`] describes a virtual module: its external functions, constant
+//! attributes and submodules. Hosts declare them up front with
+//! [`MontyRun::with_modules`](crate::MontyRun::with_modules), or answer a
+//! [`ModuleLookup`](crate::ModuleLookup) when code imports a module Monty doesn't know.
+//! Each import builds a fresh module object from the description, like the
+//! built-in modules.
+
+use crate::{
+    bytecode::VM,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, HeapData, HeapId},
+    object::{InvalidInputError, MontyObject},
+    resource::ResourceTracker,
+    types::{Module, str::allocate_string},
+    value::{EitherStr, Value},
+};
+
+/// A module the host makes importable from sandboxed code.
+///
+/// Functions are [`MontyObject::Function`] attributes: calling one yields
+/// [`RunProgress::FunctionCall`](crate::RunProgress::FunctionCall) with the
+/// function's name, exactly like an external function resolved through a
+/// name lookup.
+///
+/// # Example
+/// ```
+/// use monty::{MontyModule, MontyObject, MontyRun};
+///
+/// let tools = MontyModule::new("mytools")
+///     .with_function("search")
+///     .with_attr("VERSION", MontyObject::Int(2))
+///     .with_submodule(MontyModule::new("config").with_attr("DEBUG", MontyObject::Bool(false)));
+/// let code = "import mytools.config\nfrom mytools import VERSION\n(VERSION, mytools.config.DEBUG)";
+/// let runner = MontyRun::new(code.to_owned(), "main.py", vec![]).unwrap().with_modules(vec![tools]);
+/// let result = runner.run_no_limits(vec![]).unwrap();
+/// assert_eq!(result, MontyObject::Tuple(vec![MontyObject::Int(2), MontyObject::Bool(false)]));
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MontyModule {
+    /// Module name: the full dotted name for a top-level module, the last
+    /// component for a submodule.
+    name: String,
+    /// Attributes in definition order, including functions.
+    attrs: Vec<(String, MontyObject)>,
+    /// Submodules, also set as attributes of this module.
+    submodules: Vec<MontyModule>,
+}
+
+impl MontyModule {
+    /// Creates an empty module.
+    ///
+    /// Use the full dotted name (e.g. `"mytools"` or `"mytools.config"`) for a
+    /// module passed to `with_modules` or a `ModuleLookup`, and just the last
+    /// component for one passed to [`with_submodule`](Self::with_submodule).
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            attrs: Vec::new(),
+            submodules: Vec::new(),
+        }
+    }
+
+    /// Adds an external function, called by the host under the name `name`.
+    #[must_use]
+    pub fn with_function(self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.with_attr(name.clone(), MontyObject::Function { name, docstring: None })
+    }
+
+    /// Adds an attribute, e.g. a constant or a host-defined dataclass type.
+    #[must_use]
+    pub fn with_attr(mut self, name: impl Into<String>, value: MontyObject) -> Self {
+        self.attrs.push((name.into(), value));
+        self
+    }
+
+    /// Adds a submodule, importable as `<module>.<submodule name>`.
+    #[must_use]
+    pub fn with_submodule(mut self, module: Self) -> Self {
+        self.submodules.push(module);
+        self
+    }
+
+    /// Returns the module name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Replaces the module name, used to file a `ModuleLookup` answer under
+    /// the name that was imported.
+    pub(crate) fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    /// Finds the module `name` among `modules`, descending into submodules
+    /// for dotted names.
+    pub(crate) fn find<'a>(modules: &'a [Self], name: &str) -> Option<&'a Self> {
+        modules.iter().rev().find_map(|module| {
+            if module.name == name {
+                return Some(module);
+            }
+            let mut rest = name.strip_prefix(module.name.as_str())?.strip_prefix('.')?;
+            let mut found = module;
+            loop {
+                let (part, tail) = rest.split_once('.').unwrap_or((rest, ""));
+                found = found.submodules.iter().find(|sub| sub.name == part)?;
+                if tail.is_empty() {
+                    return Some(found);
+                }
+                rest = tail;
+            }
+        })
+    }
+
+    /// Builds the module object named `full_name` on the heap.
+    pub(crate) fn create(&self, full_name: &str, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<HeapId> {
+        let name = match vm.interns.get_string_id_by_name(full_name) {
+            Some(id) => EitherStr::Interned(id),
+            None => EitherStr::Heap(full_name.to_owned()),
+        };
+        let mut module = Module::new(name);
+        for (attr, value) in &self.attrs {
+            let value = value.clone().to_value(vm).map_err(|e| match e {
+                InvalidInputError::Resource(err) => RunError::from(err),
+                other @ InvalidInputError::InvalidType(_) => SimpleException::new(
+                    ExcType::RuntimeError,
+                    Some(format!("invalid attribute '{attr}' of module '{full_name}': {other}")),
+                )
+                .into(),
+            });
+            let value = match value {
+                Ok(value) => value,
+                Err(err) => {
+                    module.drop_with_heap(vm);
+                    return Err(err);
+                }
+            };
+            if let Err(err) = set_attr(&mut module, attr, value, vm) {
+                module.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+        for sub in &self.submodules {
+            let result = sub
+                .create(&format!("{full_name}.{}", sub.name), vm)
+                .and_then(|id| set_attr(&mut module, &sub.name, Value::Ref(id), vm));
+            if let Err(err) = result {
+                module.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+        Ok(vm.heap.allocate(HeapData::Module(module))?)
+    }
+}
+
+/// Sets `module.<name> = value`, using an interned key when the name is interned.
+fn set_attr(module: &mut Module, name: &str, value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<()> {
+    let key = match vm.interns.get_string_id_by_name(name) {
+        Some(id) => Value::InternString(id),
+        None => match allocate_string(name, vm.heap) {
+            Ok(key) => key,
+            Err(err) => {
+                value.drop_with_heap(vm);
+                return Err(err.into());
+            }
+        },
+    };
+    module.set_attr_value(key, value, vm)
+}
@@ -19,8 +19,12 @@ use num_bigint::BigInt;
 use strum::{EnumCount, EnumString, FromRepr, IntoStaticStr};
 
 use crate::{
+    exception_public::line_starts,
     function::Function,
+    function_signature::DeclaredSignature,
     hash::{ASCII_HASHES, HashValue, STATIC_HASHES, WithHash, hash_python_str},
+    parse::CodeRange,
+    source_module::SourceModule,
     value::Value,
 };
 
@@ -213,6 +217,10 @@ pub enum StaticStrings {
     Encode,
     Isidentifier,
     Istitle,
+    Isprintable,
+    // Also used by bytes / bytearray
+    Translate,
+    Maketrans,
 
     // ==========================
     // Bytes methods
@@ -226,6 +234,37 @@ pub enum StaticStrings {
     Hex,
     Fromhex,
 
+    // ==========================
+    // int / float methods and attributes
+    // Also uses bytes-shared: HEX, FROMHEX (on float)
+    BitLength,
+    BitCount,
+    ToBytes,
+    FromBytes,
+    AsIntegerRatio,
+    IsInteger,
+    Conjugate,
+    Real,
+    Imag,
+    Numerator,
+    Denominator,
+    Length,
+    Bytes,
+    Byteorder,
+    Signed,
+
+    // ==========================
+    // memoryview methods and attributes
+    // Also uses shared: HEX, OBJ, FORMAT
+    Tobytes,
+    Tolist,
+    Toreadonly,
+    Release,
+    Nbytes,
+    Readonly,
+    Itemsize,
+    Ndim,
+
     // ==========================
     // sys module strings
     Sys,
@@ -316,6 +355,151 @@ pub enum StaticStrings {
     Never,
     #[strum(serialize = "NoReturn")]
     NoReturn,
+    #[strum(serialize = "NewType")]
+    NewType,
+    #[strum(serialize = "TypedDict")]
+    TypedDict,
+    Cast,
+    AssertType,
+    AssertNever,
+    Overload,
+    GetOrigin,
+    GetArgs,
+    GetTypeHints,
+    Total,
+    Globalns,
+    Localns,
+    IncludeExtras,
+    #[strum(serialize = "__origin__")]
+    DunderOrigin,
+    #[strum(serialize = "__args__")]
+    DunderArgs,
+    #[strum(serialize = "__metadata__")]
+    DunderMetadata,
+    #[strum(serialize = "__supertype__")]
+    DunderSupertype,
+    #[strum(serialize = "__total__")]
+    DunderTotal,
+
+    // ==========================
+    // dataclasses module strings
+    /// Module name for `import dataclasses`.
+    Dataclasses,
+    /// `dataclasses.dataclass()` decorator.
+    Dataclass,
+    /// `dataclasses.field()` function.
+    Field,
+    /// `dataclasses.fields()` function.
+    Fields,
+    /// `dataclasses.asdict()` function.
+    Asdict,
+    /// `dataclasses.astuple()` function.
+    Astuple,
+    /// `dataclasses.is_dataclass()` function.
+    IsDataclass,
+    /// `dataclasses.MISSING` sentinel.
+    #[strum(serialize = "MISSING")]
+    Missing,
+    /// `dataclasses.FrozenInstanceError` exception.
+    #[strum(serialize = "FrozenInstanceError")]
+    FrozenInstanceError,
+    /// `dataclass(init=...)` / `field(init=...)` keyword.
+    Init,
+    /// `dataclass(repr=...)` / `field(repr=...)` keyword.
+    Repr,
+    /// `dataclass(eq=...)` keyword.
+    Eq,
+    /// `dataclass(order=...)` keyword.
+    Order,
+    /// `dataclass(frozen=...)` keyword.
+    Frozen,
+    /// `dataclass(kw_only=...)` / `field(kw_only=...)` keyword.
+    KwOnly,
+    /// `dataclass(unsafe_hash=...)` keyword.
+    UnsafeHash,
+    /// `dataclass(match_args=...)` keyword.
+    MatchArgs,
+    /// `dataclass(slots=...)` keyword.
+    Slots,
+    /// `dataclass(weakref_slot=...)` keyword.
+    WeakrefSlot,
+    /// `field(default_factory=...)` keyword.
+    DefaultFactory,
+    /// `field(hash=...)` keyword.
+    Hash,
+    /// `field(compare=...)` keyword.
+    Compare,
+    /// `field(metadata=...)` keyword.
+    Metadata,
+    /// `asdict(dict_factory=...)` keyword.
+    DictFactory,
+    /// `astuple(tuple_factory=...)` keyword.
+    TupleFactory,
+    /// Hook called after the generated `__init__`.
+    #[strum(serialize = "__post_init__")]
+    DunderPostInit,
+    /// Class `__qualname__` attribute.
+    #[strum(serialize = "__qualname__")]
+    DunderQualname,
+
+    // ==========================
+    // contextlib module strings
+    /// Module name for `import contextlib`.
+    Contextlib,
+    /// `contextlib.suppress` class.
+    Suppress,
+    /// `contextlib.nullcontext` class.
+    Nullcontext,
+    /// `contextlib.closing` class.
+    Closing,
+    /// `contextlib.redirect_stdout` class.
+    RedirectStdout,
+    /// `contextlib.ExitStack` class.
+    #[strum(serialize = "ExitStack")]
+    ExitStack,
+    /// `ExitStack.enter_context()` method.
+    EnterContext,
+    /// `ExitStack.callback()` method.
+    Callback,
+    /// `ExitStack.push()` method.
+    Push,
+    /// `ExitStack.pop_all()` method.
+    PopAll,
+    /// `nullcontext(enter_result=...)` keyword.
+    EnterResult,
+
+    // ==========================
+    // io module strings
+    /// Module name for `import io`.
+    Io,
+    /// `io.StringIO` class.
+    #[strum(serialize = "StringIO")]
+    StringIO,
+    /// `io.BytesIO` class.
+    #[strum(serialize = "BytesIO")]
+    BytesIO,
+    /// `io.UnsupportedOperation` exception.
+    #[strum(serialize = "UnsupportedOperation")]
+    UnsupportedOperation,
+    /// `io.SEEK_SET` constant.
+    #[strum(serialize = "SEEK_SET")]
+    SeekSet,
+    /// `io.SEEK_CUR` constant.
+    #[strum(serialize = "SEEK_CUR")]
+    SeekCur,
+    /// `io.SEEK_END` constant.
+    #[strum(serialize = "SEEK_END")]
+    SeekEnd,
+    /// `StringIO(initial_value=...)` keyword.
+    InitialValue,
+    /// `BytesIO(initial_bytes=...)` keyword.
+    InitialBytes,
+    /// `getvalue()` method.
+    Getvalue,
+    /// `truncate()` method.
+    Truncate,
+    /// `writelines()` method.
+    Writelines,
 
     // ==========================
     // asyncio module strings
@@ -333,11 +517,22 @@ pub enum StaticStrings {
     // ==========================
     // Exception attributes
     Args,
+    Errno,
+    Strerror,
+    Filename,
+    #[strum(serialize = "filename2")]
+    Filename2,
+    Value,
+    AddNote,
+    #[strum(serialize = "__notes__")]
+    DunderNotes,
 
     // ==========================
     // Type attributes
     #[strum(serialize = "__name__")]
     DunderName,
+    #[strum(serialize = "__annotations__")]
+    DunderAnnotations,
     #[strum(serialize = "__enter__")]
     Enter,
     #[strum(serialize = "__exit__")]
@@ -509,6 +704,159 @@ pub enum StaticStrings {
     #[strum(serialize = "nan")]
     MathNan,
 
+    // cmath module strings (shares the function and constant names above with math)
+    Cmath,
+    Phase,
+    Polar,
+    Rect,
+    /// `cmath.infj` constant
+    #[strum(serialize = "infj")]
+    CmathInfj,
+    /// `cmath.nanj` constant
+    #[strum(serialize = "nanj")]
+    CmathNanj,
+
+    // ==========================
+    // unicodedata module strings
+    Unicodedata,
+    Lookup,
+    Decimal,
+    Digit,
+    Numeric,
+    Category,
+    Bidirectional,
+    Combining,
+    EastAsianWidth,
+    Mirrored,
+    Decomposition,
+    Normalize,
+    IsNormalized,
+    UnidataVersion,
+
+    // ==========================
+    // warnings module strings
+    // Also uses shared: CATEGORY, FILENAME, FILE, SOURCE, APPEND
+    /// Module name for `import warnings`.
+    Warnings,
+    /// `warnings.warn()` function.
+    Warn,
+    /// `warnings.filterwarnings()` function.
+    Filterwarnings,
+    /// `warnings.simplefilter()` function.
+    Simplefilter,
+    /// `warnings.resetwarnings()` function.
+    Resetwarnings,
+    /// `warnings.formatwarning()` function.
+    Formatwarning,
+    /// `warnings.catch_warnings` class.
+    CatchWarnings,
+    /// Type name of the records collected by `catch_warnings(record=True)`.
+    #[strum(serialize = "WarningMessage")]
+    WarningMessage,
+    /// `warn(message=...)` keyword and `WarningMessage.message` field.
+    Message,
+    /// `filterwarnings(lineno=...)` keyword and `WarningMessage.lineno` field.
+    Lineno,
+    /// `formatwarning(line=...)` keyword and `WarningMessage.line` field.
+    Line,
+    /// `warn(stacklevel=...)` keyword.
+    Stacklevel,
+    /// `catch_warnings(record=...)` keyword.
+    Record,
+    /// `filterwarnings(action=...)` keyword.
+    Action,
+    /// `filterwarnings(module=...)` keyword (`Module` is taken by `<module>`).
+    #[strum(serialize = "module")]
+    ModuleKwarg,
+
+    // ==========================
+    // logging module strings
+    // Also uses shared: LOG, ERROR, WARN, DISABLE, NAME, STACKLEVEL
+    /// Module name for `import logging`.
+    Logging,
+    /// `logging.getLogger()` function.
+    #[strum(serialize = "getLogger")]
+    GetLogger,
+    /// `logging.basicConfig()` function.
+    #[strum(serialize = "basicConfig")]
+    BasicConfig,
+    /// `logging.getLevelName()` function.
+    #[strum(serialize = "getLevelName")]
+    GetLevelName,
+    /// `logging.getLevelNamesMapping()` function.
+    #[strum(serialize = "getLevelNamesMapping")]
+    GetLevelNamesMapping,
+    /// `debug()` function and logger method.
+    Debug,
+    /// `info()` function and logger method.
+    Info,
+    /// `warning()` function and logger method.
+    Warning,
+    /// `critical()` function and logger method.
+    Critical,
+    /// `fatal()` function and logger method.
+    Fatal,
+    /// `exception()` function and logger method.
+    Exception,
+    /// `logging.Logger` class.
+    #[strum(serialize = "Logger")]
+    LoggerClass,
+    /// `logging.LoggerAdapter` class.
+    #[strum(serialize = "LoggerAdapter")]
+    LoggerAdapter,
+    /// `logging.root` logger and the root logger's name.
+    Root,
+    /// `logging.DEBUG` level constant.
+    #[strum(serialize = "DEBUG")]
+    LevelDebug,
+    /// `logging.INFO` level constant.
+    #[strum(serialize = "INFO")]
+    LevelInfo,
+    /// `logging.WARNING` level constant.
+    #[strum(serialize = "WARNING")]
+    LevelWarning,
+    /// `logging.WARN` level constant.
+    #[strum(serialize = "WARN")]
+    LevelWarn,
+    /// `logging.ERROR` level constant.
+    #[strum(serialize = "ERROR")]
+    LevelError,
+    /// `logging.CRITICAL` level constant.
+    #[strum(serialize = "CRITICAL")]
+    LevelCritical,
+    /// `logging.FATAL` level constant.
+    #[strum(serialize = "FATAL")]
+    LevelFatal,
+    /// `logging.NOTSET` level constant.
+    #[strum(serialize = "NOTSET")]
+    LevelNotset,
+    /// `Logger.setLevel()` method.
+    #[strum(serialize = "setLevel")]
+    SetLevel,
+    /// `Logger.getEffectiveLevel()` method.
+    #[strum(serialize = "getEffectiveLevel")]
+    GetEffectiveLevel,
+    /// `Logger.isEnabledFor()` method.
+    #[strum(serialize = "isEnabledFor")]
+    IsEnabledFor,
+    /// `Logger.getChild()` method.
+    #[strum(serialize = "getChild")]
+    GetChild,
+    /// `basicConfig(level=...)` keyword and `Logger.level` attribute.
+    Level,
+    /// `log(msg=...)` keyword.
+    Msg,
+    /// `log(exc_info=...)` keyword.
+    ExcInfo,
+    /// `log(stack_info=...)` keyword.
+    StackInfo,
+    /// `log(extra=...)` keyword and `LoggerAdapter.extra` attribute.
+    Extra,
+    /// `LoggerAdapter.logger` attribute and constructor argument.
+    Logger,
+    /// `LoggerAdapter(merge_extra=...)` keyword.
+    MergeExtra,
+
     // ==========================
     // json module strings
     /// Module name for `import json`.
@@ -942,6 +1290,24 @@ pub(crate) struct Interns {
     bytes: Vec<WithHash<Vec<u8>>>,
     long_ints: Vec<WithHash<BigInt>>,
     functions: Vec<Function>,
+    /// Source files by filename, so runtime code (e.g. `warnings.warn()` or
+    /// `logging.exception()`) can turn a `CodeRange` into a line number or a traceback.
+    #[serde(default)]
+    sources: Vec<SourceFile>,
+    /// Modules supplied as source files, indexed by the operand of `InitModule`.
+    #[serde(default)]
+    source_modules: Vec<SourceModule>,
+    /// Signatures the host declared for external functions.
+    #[serde(default)]
+    declared_signatures: Vec<DeclaredSignature>,
+}
+
+/// The text of one source file and the byte offset of each of its lines.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct SourceFile {
+    filename: String,
+    text: String,
+    line_starts: Vec<u32>,
 }
 
 impl Interns {
@@ -951,6 +1317,9 @@ impl Interns {
             bytes: interner.bytes,
             long_ints: interner.long_ints,
             functions,
+            sources: Vec::new(),
+            source_modules: Vec::new(),
+            declared_signatures: Vec::new(),
         }
     }
 
@@ -1083,6 +1452,18 @@ impl Interns {
         None
     }
 
+    /// Interns a string after compilation, returning its `StringId`.
+    ///
+    /// Used for names the host supplies, e.g. the parameters of a declared signature.
+    pub(crate) fn intern(&mut self, s: &str) -> StringId {
+        if let Some(id) = self.get_string_id_by_name(s) {
+            return id;
+        }
+        let id = StringId(u32::try_from(INTERN_STRING_ID_OFFSET + self.strings.len()).expect("StringId overflow"));
+        self.strings.push(WithHash::for_str(s.to_owned()));
+        id
+    }
+
     /// Sets the compiled functions.
     ///
     /// This is called after compilation to populate the functions that were
@@ -1097,4 +1478,114 @@ impl Interns {
     pub(crate) fn functions_clone(&self) -> Vec<Function> {
         self.functions.clone()
     }
+
+    /// Records `source` under `filename`, replacing any previous source
+    /// registered under the same filename.
+    pub(crate) fn add_source(&mut self, filename: &str, source: &str) {
+        let file = SourceFile {
+            filename: filename.to_owned(),
+            text: source.to_owned(),
+            line_starts: line_starts(source),
+        };
+        if let Some(existing) = self.sources.iter_mut().find(|f| f.filename == filename) {
+            *existing = file;
+        } else {
+            self.sources.push(file);
+        }
+    }
+
+    /// Copies the sources registered on `other`.
+    ///
+    /// Used by REPL incremental compilation so frames from earlier snippets keep
+    /// resolving to line numbers.
+    pub(crate) fn copy_sources_from(&mut self, other: &Self) {
+        self.sources.clone_from(&other.sources);
+    }
+
+    /// Returns the module supplied as a source file at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub(crate) fn source_module(&self, index: u16) -> &SourceModule {
+        &self.source_modules[usize::from(index)]
+    }
+
+    /// Returns the index of the supplied source module named `name`.
+    pub(crate) fn find_source_module(&self, name: &str) -> Option<u16> {
+        let index = self.source_modules.iter().position(|m| m.name == name)?;
+        u16::try_from(index).ok()
+    }
+
+    /// Returns the number of supplied source modules, the index the next one gets.
+    pub(crate) fn source_module_count(&self) -> usize {
+        self.source_modules.len()
+    }
+
+    /// Stores `module` at `index`, replacing the module there or appending it.
+    pub(crate) fn set_source_module(&mut self, index: u16, module: SourceModule) {
+        match self.source_modules.get_mut(usize::from(index)) {
+            Some(existing) => *existing = module,
+            None => self.source_modules.push(module),
+        }
+    }
+
+    /// Returns the name of global `slot` of one of the supplied source modules.
+    pub(crate) fn source_module_global_name(&self, slot: u16) -> Option<StringId> {
+        let name = self.source_modules.iter().find_map(|module| {
+            module
+                .globals
+                .iter()
+                .find(|(_, id)| id.index() == usize::from(slot))
+                .map(|(name, _)| name)
+        })?;
+        self.get_string_id_by_name(name)
+    }
+
+    /// Copies the source modules registered on `other`.
+    ///
+    /// Used when compiling another source file, so modules compiled earlier keep their indexes.
+    pub(crate) fn copy_source_modules_from(&mut self, other: &Self) {
+        self.source_modules.clone_from(&other.source_modules);
+    }
+
+    /// Adds a declared signature, replacing any earlier one for the same function.
+    pub(crate) fn add_declared_signature(&mut self, declared: DeclaredSignature) {
+        let name_id = declared.name_id();
+        match self.declared_signatures.iter_mut().find(|d| d.name_id() == name_id) {
+            Some(existing) => *existing = declared,
+            None => self.declared_signatures.push(declared),
+        }
+    }
+
+    /// Returns the signature declared for the external function `name`.
+    pub(crate) fn declared_signature(&self, name: &str) -> Option<&DeclaredSignature> {
+        self.declared_signatures
+            .iter()
+            .find(|d| self.get_str(d.name_id()) == name)
+    }
+
+    /// Copies the signatures declared on `other`.
+    ///
+    /// Used when compiling more code, so signatures declared earlier keep applying.
+    pub(crate) fn copy_declared_signatures_from(&mut self, other: &Self) {
+        self.declared_signatures.clone_from(&other.declared_signatures);
+    }
+
+    /// Returns the source text registered under `filename`.
+    pub(crate) fn source(&self, filename: &str) -> Option<&str> {
+        self.sources
+            .iter()
+            .find(|f| f.filename == filename)
+            .map(|f| f.text.as_str())
+    }
+
+    /// Returns the 1-based line number `range` starts on, or 0 if its file is unknown.
+    pub(crate) fn line_number(&self, range: CodeRange) -> u32 {
+        let filename = self.get_str(range.filename);
+        self.sources.iter().find(|f| f.filename == filename).map_or(0, |f| {
+            let line = f.line_starts.partition_point(|&s| s <= range.start_byte);
+            u32::try_from(line).unwrap_or(u32::MAX)
+        })
+    }
 }
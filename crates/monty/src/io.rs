@@ -1,18 +1,23 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt};
 
-use crate::exception_public::MontyException;
+use crate::{
+    exception_private::{ExcType, RunResult},
+    exception_public::MontyException,
+    object::MontyObject,
+    resource::ResourceTracker,
+};
 
 /// Identifies the output stream for a single print fragment.
 ///
-/// Today the `print()` builtin only writes to `Stdout`. The `Stderr` variant is
-/// included for forward compatibility with a future `print(..., file=sys.stderr)`
-/// implementation so the collected-output API shape does not have to change when
-/// that lands.
+/// `print()` writes to `Stdout` unless given `file=sys.stderr`. `Stderr` also
+/// carries `sys.stderr.write()` text, warnings emitted by `warnings.warn()` and
+/// records emitted by the `logging` module.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PrintStream {
-    /// Standard output — the default for every `print()` call today.
+    /// Standard output — the default for `print()` and the target of `sys.stdout.write()`.
     Stdout,
-    /// Standard error — reserved for future `print(..., file=sys.stderr)` support.
+    /// Standard error — `print(..., file=sys.stderr)`, `sys.stderr.write()`,
+    /// warnings and log records.
     Stderr,
 }
 
@@ -25,19 +30,29 @@ pub enum PrintStream {
 /// # Variants
 /// - `Disabled` — silently discards all output (useful for benchmarking or suppressing output).
 /// - `Stdout` — writes to standard output (the default behavior).
-/// - `CollectString` — accumulates output into a target `String` for programmatic access.
-///   No stream labels are preserved; every fragment is appended in the order it was emitted.
+/// - `CollectString` — accumulates stdout output into a target `String` for programmatic
+///   access, in the order it was emitted. Stderr output is dropped.
 /// - `CollectStreams` — accumulates output as `(stream, text)` pairs, merging consecutive
 ///   same-stream fragments into one tuple. Each write to the same stream extends the
 ///   trailing entry rather than producing a new one; a new tuple is only pushed when
 ///   the stream changes.
 /// - `Callback` — delegates to a user-provided [`PrintWriterCallback`] implementation.
+///
+/// Warnings emitted by `warnings.warn()` are routed through the same writer:
+/// `Stdout` prints them to standard error, `CollectStreams` records them as
+/// `Stderr` fragments, `Callback` receives the structured [`MontyWarning`], and
+/// `Disabled` / `CollectString` drop them. Records emitted by the `logging`
+/// module take the same routes, with `Callback` receiving a [`MontyLogRecord`].
+///
+/// Every fragment is counted against the tracker's output limit
+/// ([`ResourceLimits::max_output_bytes`](crate::ResourceLimits::max_output_bytes))
+/// before it is delivered, whichever variant is in use.
 pub enum PrintWriter<'a> {
     /// Silently discard all output.
     Disabled,
     /// Write to standard output.
     Stdout,
-    /// Collect all output into a single `String`, in emit order, with no stream labels.
+    /// Collect stdout output into a single `String`, in emit order; stderr output is dropped.
     CollectString(&'a mut String),
     /// Collect all output as `(stream, text)` tuples.
     ///
@@ -45,10 +60,9 @@ pub enum PrintWriter<'a> {
     /// and `stdout_push` for each separator/terminator. To avoid one tuple per
     /// fragment, this variant appends to the trailing tuple when it already matches
     /// the current stream; a new tuple is only pushed when the stream changes.
-    /// So long as every write targets the same stream (the status quo today, since
-    /// `print()` only writes to stdout), a single `print(a, b)` call produces one
-    /// `(Stdout, "a b\n")` entry — and consecutive prints with `end=''` likewise
-    /// merge into a single trailing entry.
+    /// So a single `print(a, b)` call produces one `(Stdout, "a b\n")` entry,
+    /// consecutive prints with `end=''` merge into a single trailing entry, and
+    /// `print(..., file=sys.stderr)` starts a `Stderr` entry.
     CollectStreams(&'a mut Vec<(PrintStream, String)>),
     /// Delegate to a custom callback.
     Callback(&'a mut dyn PrintWriterCallback),
@@ -117,6 +131,188 @@ impl PrintWriter<'_> {
             Self::Callback(cb) => cb.stdout_push(end),
         }
     }
+
+    /// Writes text to a specific stream.
+    ///
+    /// Used for `print(..., file=sys.stderr)`, `sys.stdout.write()` and
+    /// `sys.stderr.write()`. Unlike [`stdout_write`](Self::stdout_write), the
+    /// text may contain separators and newlines.
+    pub fn write(&mut self, stream: PrintStream, output: Cow<'_, str>) -> Result<(), MontyException> {
+        match self {
+            Self::Disabled => Ok(()),
+            Self::Stdout => {
+                match stream {
+                    PrintStream::Stdout => print!("{output}"),
+                    PrintStream::Stderr => eprint!("{output}"),
+                }
+                Ok(())
+            }
+            Self::CollectString(buf) => {
+                if stream == PrintStream::Stdout {
+                    buf.push_str(&output);
+                }
+                Ok(())
+            }
+            Self::CollectStreams(buf) => {
+                append_streams_str(buf, stream, &output);
+                Ok(())
+            }
+            Self::Callback(cb) => cb.write(stream, output),
+        }
+    }
+
+    /// Delivers a warning that passed the sandbox's warning filters.
+    ///
+    /// Called at most once per `warnings.warn()` call, and never for warnings
+    /// that were ignored, turned into errors, or recorded by
+    /// `warnings.catch_warnings(record=True)`.
+    pub fn warn(&mut self, warning: &MontyWarning) -> Result<(), MontyException> {
+        match self {
+            Self::Disabled | Self::CollectString(_) => Ok(()),
+            Self::Stdout => {
+                eprintln!("{warning}");
+                Ok(())
+            }
+            Self::CollectStreams(buf) => {
+                append_streams_str(buf, PrintStream::Stderr, &format!("{warning}\n"));
+                Ok(())
+            }
+            Self::Callback(cb) => cb.warn(warning),
+        }
+    }
+
+    /// Delivers a record emitted by the `logging` module.
+    ///
+    /// Called once for each logging call whose level passes the logger's
+    /// effective level and `logging.disable()`.
+    pub fn log(&mut self, record: &MontyLogRecord) -> Result<(), MontyException> {
+        match self {
+            Self::Disabled | Self::CollectString(_) => Ok(()),
+            Self::Stdout => {
+                eprintln!("{record}");
+                Ok(())
+            }
+            Self::CollectStreams(buf) => {
+                append_streams_str(buf, PrintStream::Stderr, &format!("{record}\n"));
+                Ok(())
+            }
+            Self::Callback(cb) => cb.log(record),
+        }
+    }
+
+    /// Charges `print()` argument text to the tracker's output limit, then writes it
+    /// with [`stdout_write`](Self::stdout_write).
+    pub(crate) fn tracked_stdout_write(
+        &mut self,
+        output: Cow<'_, str>,
+        tracker: &impl ResourceTracker,
+    ) -> RunResult<()> {
+        tracker.on_output(output.len())?;
+        Ok(self.stdout_write(output)?)
+    }
+
+    /// Charges a separator or terminator to the tracker's output limit, then writes it
+    /// with [`stdout_push`](Self::stdout_push).
+    pub(crate) fn tracked_stdout_push(&mut self, end: char, tracker: &impl ResourceTracker) -> RunResult<()> {
+        tracker.on_output(end.len_utf8())?;
+        Ok(self.stdout_push(end)?)
+    }
+
+    /// Charges stream text to the tracker's output limit, then writes it with
+    /// [`write`](Self::write).
+    pub(crate) fn tracked_write(
+        &mut self,
+        stream: PrintStream,
+        output: Cow<'_, str>,
+        tracker: &impl ResourceTracker,
+    ) -> RunResult<()> {
+        tracker.on_output(output.len())?;
+        Ok(self.write(stream, output)?)
+    }
+
+    /// Charges the formatted warning line to the tracker's output limit, then
+    /// delivers it with [`warn`](Self::warn).
+    pub(crate) fn tracked_warn(&mut self, warning: &MontyWarning, tracker: &impl ResourceTracker) -> RunResult<()> {
+        tracker.on_output(warning.to_string().len() + 1)?;
+        Ok(self.warn(warning)?)
+    }
+
+    /// Charges the formatted record line to the tracker's output limit, then
+    /// delivers it with [`log`](Self::log).
+    pub(crate) fn tracked_log(&mut self, record: &MontyLogRecord, tracker: &impl ResourceTracker) -> RunResult<()> {
+        tracker.on_output(record.to_string().len() + 1)?;
+        Ok(self.log(record)?)
+    }
+}
+
+/// A warning emitted by `warnings.warn()` and shown by the sandbox's filters.
+///
+/// The `Display` implementation matches the first line of CPython's default
+/// warning format: `{filename}:{lineno}: {category}: {message}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontyWarning {
+    /// The warning category, e.g. `ExcType::UserWarning`.
+    pub category: ExcType,
+    /// The warning text, i.e. `str()` of the warning message.
+    pub message: String,
+    /// The file the warning is attributed to, after applying `stacklevel`.
+    pub filename: String,
+    /// The 1-based line the warning is attributed to, or 0 when unknown.
+    pub lineno: u32,
+}
+
+impl fmt::Display for MontyWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}: {}",
+            self.filename, self.lineno, self.category, self.message
+        )
+    }
+}
+
+/// A record emitted by a call such as `logging.info()` or `logger.exception()`.
+///
+/// The `Display` implementation is what CPython's stream handler would write:
+/// [`formatted`](Self::formatted), followed by the traceback of
+/// [`exception`](Self::exception) when there is one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MontyLogRecord {
+    /// The logger's name, `"root"` for the root logger.
+    pub name: String,
+    /// The numeric level, e.g. 20 for `INFO`.
+    pub levelno: i64,
+    /// The level's name, e.g. `"INFO"`, or `"Level 5"` for levels without a name.
+    pub level_name: String,
+    /// `str()` of the message template passed to the logging call.
+    pub msg: String,
+    /// The positional arguments of the logging call.
+    pub args: Vec<MontyObject>,
+    /// The message after `%`-formatting `msg` with `args`.
+    pub message: String,
+    /// The file of the logging call, after applying `stacklevel`.
+    pub filename: String,
+    /// The 1-based line of the logging call, or 0 when unknown.
+    pub lineno: u32,
+    /// The function containing the logging call, `"<module>"` for module-level code.
+    pub func_name: String,
+    /// The `extra` mapping of the call, merged over a `LoggerAdapter`'s `extra`.
+    pub extra: Vec<(String, MontyObject)>,
+    /// The exception attached by `exception()` or `exc_info=True`, with its traceback.
+    pub exception: Option<MontyException>,
+    /// The record rendered with the format set by `logging.basicConfig()`, or
+    /// just the message when logging was never configured.
+    pub formatted: String,
+}
+
+impl fmt::Display for MontyLogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.formatted)?;
+        if let Some(exception) = &self.exception {
+            write!(f, "\n{exception}")?;
+        }
+        Ok(())
+    }
 }
 
 /// Appends a string fragment to the collect-streams buffer, merging into the
@@ -160,4 +356,43 @@ pub trait PrintWriterCallback {
     /// # Arguments
     /// * `end` - The character to print after the formatted output.
     fn stdout_push(&mut self, end: char) -> Result<(), MontyException>;
+
+    /// Called with text written to a specific stream by `print(..., file=sys.stderr)`,
+    /// `sys.stdout.write()` or `sys.stderr.write()`.
+    ///
+    /// The text may contain separators and newlines. The default implementation
+    /// forwards stdout text to [`stdout_write`](Self::stdout_write) and discards
+    /// stderr text.
+    ///
+    /// # Arguments
+    /// * `stream` - The stream the text was written to.
+    /// * `output` - The text, exactly as written.
+    fn write(&mut self, stream: PrintStream, output: Cow<'_, str>) -> Result<(), MontyException> {
+        match stream {
+            PrintStream::Stdout => self.stdout_write(output),
+            PrintStream::Stderr => Ok(()),
+        }
+    }
+
+    /// Called for each warning shown by the sandbox's warning filters.
+    ///
+    /// The default implementation discards the warning.
+    ///
+    /// # Arguments
+    /// * `warning` - The warning's category, message and source location.
+    fn warn(&mut self, warning: &MontyWarning) -> Result<(), MontyException> {
+        let _ = warning;
+        Ok(())
+    }
+
+    /// Called for each record emitted by the `logging` module.
+    ///
+    /// The default implementation discards the record.
+    ///
+    /// # Arguments
+    /// * `record` - The logger name, level, message, arguments and source location.
+    fn log(&mut self, record: &MontyLogRecord) -> Result<(), MontyException> {
+        let _ = record;
+        Ok(())
+    }
 }
@@ -7,14 +7,18 @@ mod args;
 mod asyncio;
 mod builtins;
 mod bytecode;
+mod codecs;
+mod convert;
 mod exception_private;
 mod exception_public;
 mod expressions;
 pub mod fs;
 mod fstring;
 mod function;
+mod function_signature;
 mod hash;
 mod heap_data;
+mod host_module;
 mod intern;
 mod io;
 mod modules;
@@ -24,22 +28,30 @@ mod object_json;
 mod os;
 mod parse;
 mod prepare;
+mod registry;
 mod repl;
 mod resource;
 mod run;
 mod run_progress;
 mod signature;
 mod sorting;
+mod source_module;
 mod string_builder;
 mod types;
+mod unicode;
 mod value;
 
+#[doc(hidden)]
+pub use crate::convert::__derive;
 #[cfg(feature = "ref-count-return")]
 pub use crate::run::RefCountOutput;
 pub use crate::{
+    convert::{FromMonty, FromMontyError, IntoMonty, TypeStubs},
     exception_private::ExcType,
     exception_public::{CodeLoc, MontyException, StackFrame},
-    io::{PrintStream, PrintWriter, PrintWriterCallback},
+    function_signature::{FunctionSignature, Parameter, ParameterKind},
+    host_module::MontyModule,
+    io::{MontyLogRecord, MontyWarning, PrintStream, PrintWriter, PrintWriterCallback},
     object::{
         DictPairs, InvalidInputError, MontyDate, MontyDateTime, MontyFileHandle, MontyObject, MontyTimeDelta,
         MontyTimeZone,
@@ -49,16 +61,20 @@ pub use crate::{
         GetenvArgs, MkdirCallArgs, MontyPath, OpenCallArgs, OsFunctionCall, PathBytesDataArgs, PathStringDataArgs,
         RenameCallArgs, dir_stat, file_stat, stat_result, symlink_stat,
     },
+    registry::{AsyncHostFunction, CallError, FunctionRegistry, HostFunction, HostFuture},
     repl::{
-        MontyRepl, ReplContinuationMode, ReplFunctionCall, ReplNameLookup, ReplOsCall, ReplProgress,
-        ReplResolveFutures, ReplStartError, detect_repl_continuation_mode,
+        MontyRepl, ReplCallbackResult, ReplContinuationMode, ReplFunctionCall, ReplHandleCall, ReplModuleLookup,
+        ReplNameLookup, ReplOsCall, ReplProgress, ReplResolveFutures, ReplStartError, detect_repl_continuation_mode,
     },
     resource::{
-        DEFAULT_MAX_RECURSION_DEPTH, LimitedTracker, NoLimitTracker, ResourceError, ResourceLimits, ResourceTracker,
+        DEFAULT_MAX_RECURSION_DEPTH, FUEL_BYTES_PER_UNIT, InterruptHandle, LimitedTracker, NoLimitTracker,
+        ResourceError, ResourceLimits, ResourceTracker,
     },
     run::MontyRun,
     run_progress::{
-        ExtFunctionResult, FunctionCall, NameLookup, NameLookupResult, OsCall, ResolveFutures, RunProgress,
+        CallbackResult, ExtFunctionResult, FunctionCall, HandleCall, HandleOperation, ModuleLookup, ModuleLookupResult,
+        NameLookup, NameLookupResult, OsCall, ResolveFutures, RunProgress,
     },
     types::{file::FileMode, str::StringRepr, r#type::Type},
 };
+pub use monty_macros::{FromMonty, IntoMonty};
@@ -0,0 +1,511 @@
+//! Implementation of Python's `cmath` module.
+//!
+//! Provides the complex counterparts of the `math` functions. Every function
+//! accepts any number (`bool`, `int`, `float` or `complex`) and returns a
+//! `complex`, except `phase`, `polar` and the `is*` predicates. The formulas
+//! follow CPython's `cmathmodule.c`, including its error reporting: a finite
+//! input that overflows raises `OverflowError("math range error")` and a finite
+//! input at a singularity raises `ValueError("math domain error")`.
+//!
+//! ## Implemented functions
+//!
+//! **Roots & logarithms**: `sqrt`, `exp`, `log`, `log10`
+//! **Trigonometric**: `sin`, `cos`, `tan`, `asin`, `acos`, `atan`
+//! **Hyperbolic**: `sinh`, `cosh`, `tanh`, `asinh`, `acosh`, `atanh`
+//! **Polar coordinates**: `phase`, `polar`, `rect`
+//! **Classification**: `isfinite`, `isinf`, `isnan`, `isclose`
+//!
+//! ## Constants
+//!
+//! `pi`, `e`, `tau`, `inf`, `infj`, `nan`, `nanj`
+
+use std::f64::consts;
+
+use smallvec::smallvec;
+
+use crate::{
+    args::{ArgValues, FromArgs},
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{HeapData, HeapId},
+    intern::StaticStrings,
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{Complex, Module, PyTrait, Type, allocate_tuple, complex::as_complex},
+    value::Value,
+};
+
+/// Above this magnitude `exp(x)` may overflow even though the final result
+/// (after multiplying by a sine or cosine) is finite; matches CPython's
+/// `CM_LOG_LARGE_DOUBLE`.
+const LOG_LARGE_DOUBLE: f64 = 708.396_418_532_264_1;
+
+/// Returns a `ValueError` with the standard CPython "math domain error" message.
+fn math_domain_error() -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, "math domain error").into()
+}
+
+/// Returns an `OverflowError` with the standard CPython "math range error" message.
+fn math_range_error() -> RunError {
+    SimpleException::new_msg(ExcType::OverflowError, "math range error").into()
+}
+
+/// Cmath module functions — each variant corresponds to a Python-visible function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum CmathFunctions {
+    // Roots & logarithms
+    Sqrt,
+    Exp,
+    Log,
+    Log10,
+    // Trigonometric
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    // Hyperbolic
+    Sinh,
+    Cosh,
+    Tanh,
+    Asinh,
+    Acosh,
+    Atanh,
+    // Polar coordinates
+    Phase,
+    Polar,
+    Rect,
+    // Classification
+    Isfinite,
+    Isinf,
+    Isnan,
+    Isclose,
+}
+
+/// Creates the `cmath` module and allocates it on the heap.
+///
+/// Registers all cmath functions and the constants `pi`, `e`, `tau`, `inf`,
+/// `infj`, `nan` and `nanj`.
+///
+/// # Returns
+/// A `HeapId` pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Cmath);
+
+    for (name, func) in CMATH_FUNCTIONS {
+        module.set_attr(*name, Value::ModuleFunction(ModuleFunctions::Cmath(*func)), vm);
+    }
+
+    // Constants
+    module.set_attr(StaticStrings::Pi, Value::Float(consts::PI), vm);
+    module.set_attr(StaticStrings::MathE, Value::Float(consts::E), vm);
+    module.set_attr(StaticStrings::Tau, Value::Float(consts::TAU), vm);
+    module.set_attr(StaticStrings::MathInf, Value::Float(f64::INFINITY), vm);
+    module.set_attr(StaticStrings::MathNan, Value::Float(f64::NAN), vm);
+    let infj = Complex::new(0.0, f64::INFINITY).into_value(vm.heap)?;
+    module.set_attr(StaticStrings::CmathInfj, infj, vm);
+    let nanj = Complex::new(0.0, f64::NAN).into_value(vm.heap)?;
+    module.set_attr(StaticStrings::CmathNanj, nanj, vm);
+
+    vm.heap.allocate(HeapData::Module(module))
+}
+
+/// Static mapping of attribute names to cmath functions for module creation.
+const CMATH_FUNCTIONS: &[(StaticStrings, CmathFunctions)] = &[
+    // Roots & logarithms
+    (StaticStrings::Sqrt, CmathFunctions::Sqrt),
+    (StaticStrings::Exp, CmathFunctions::Exp),
+    (StaticStrings::Log, CmathFunctions::Log),
+    (StaticStrings::Log10, CmathFunctions::Log10),
+    // Trigonometric
+    (StaticStrings::Sin, CmathFunctions::Sin),
+    (StaticStrings::Cos, CmathFunctions::Cos),
+    (StaticStrings::Tan, CmathFunctions::Tan),
+    (StaticStrings::Asin, CmathFunctions::Asin),
+    (StaticStrings::Acos, CmathFunctions::Acos),
+    (StaticStrings::Atan, CmathFunctions::Atan),
+    // Hyperbolic
+    (StaticStrings::Sinh, CmathFunctions::Sinh),
+    (StaticStrings::Cosh, CmathFunctions::Cosh),
+    (StaticStrings::Tanh, CmathFunctions::Tanh),
+    (StaticStrings::Asinh, CmathFunctions::Asinh),
+    (StaticStrings::Acosh, CmathFunctions::Acosh),
+    (StaticStrings::Atanh, CmathFunctions::Atanh),
+    // Polar coordinates
+    (StaticStrings::Phase, CmathFunctions::Phase),
+    (StaticStrings::Polar, CmathFunctions::Polar),
+    (StaticStrings::Rect, CmathFunctions::Rect),
+    // Classification
+    (StaticStrings::Isfinite, CmathFunctions::Isfinite),
+    (StaticStrings::Isinf, CmathFunctions::Isinf),
+    (StaticStrings::Isnan, CmathFunctions::Isnan),
+    (StaticStrings::Isclose, CmathFunctions::Isclose),
+];
+
+/// Dispatches a call to a cmath module function.
+///
+/// All cmath functions are pure computations and return `Value` directly.
+pub(super) fn call(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    function: CmathFunctions,
+    args: ArgValues,
+) -> RunResult<Value> {
+    match function {
+        // Roots & logarithms
+        CmathFunctions::Sqrt => unary(vm, args, "cmath.sqrt", |z| Ok(c_sqrt(z))),
+        CmathFunctions::Exp => unary(vm, args, "cmath.exp", |z| check_range(c_exp(z), z)),
+        CmathFunctions::Log => cmath_log(vm, args),
+        CmathFunctions::Log10 => unary(vm, args, "cmath.log10", |z| {
+            let log = c_log(z);
+            check_domain(Complex::new(log.re / consts::LN_10, log.im / consts::LN_10), z)
+        }),
+        // Trigonometric
+        CmathFunctions::Sin => unary(vm, args, "cmath.sin", |z| check_range(c_sin(z), z)),
+        CmathFunctions::Cos => unary(vm, args, "cmath.cos", |z| check_range(c_cos(z), z)),
+        CmathFunctions::Tan => unary(vm, args, "cmath.tan", |z| check_range(c_tan(z), z)),
+        CmathFunctions::Asin => unary(vm, args, "cmath.asin", |z| Ok(c_asin(z))),
+        CmathFunctions::Acos => unary(vm, args, "cmath.acos", |z| Ok(c_acos(z))),
+        CmathFunctions::Atan => unary(vm, args, "cmath.atan", |z| check_domain(c_atan(z), z)),
+        // Hyperbolic
+        CmathFunctions::Sinh => unary(vm, args, "cmath.sinh", |z| check_range(c_sinh(z), z)),
+        CmathFunctions::Cosh => unary(vm, args, "cmath.cosh", |z| check_range(c_cosh(z), z)),
+        CmathFunctions::Tanh => unary(vm, args, "cmath.tanh", |z| check_range(c_tanh(z), z)),
+        CmathFunctions::Asinh => unary(vm, args, "cmath.asinh", |z| Ok(c_asinh(z))),
+        CmathFunctions::Acosh => unary(vm, args, "cmath.acosh", |z| Ok(c_acosh(z))),
+        CmathFunctions::Atanh => unary(vm, args, "cmath.atanh", |z| check_domain(c_atanh(z), z)),
+        // Polar coordinates
+        CmathFunctions::Phase => cmath_phase(vm, args),
+        CmathFunctions::Polar => cmath_polar(vm, args),
+        CmathFunctions::Rect => cmath_rect(vm, args),
+        // Classification
+        CmathFunctions::Isfinite => predicate(vm, args, "cmath.isfinite", Complex::is_finite),
+        CmathFunctions::Isinf => predicate(vm, args, "cmath.isinf", |z| z.re.is_infinite() || z.im.is_infinite()),
+        CmathFunctions::Isnan => predicate(vm, args, "cmath.isnan", |z| z.re.is_nan() || z.im.is_nan()),
+        CmathFunctions::Isclose => cmath_isclose(vm, args),
+    }
+}
+
+// ==========================
+// Argument handling
+// ==========================
+
+/// Calls a one-argument function taking and returning a complex number.
+fn unary(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    args: ArgValues,
+    name: &str,
+    f: impl FnOnce(Complex) -> RunResult<Complex>,
+) -> RunResult<Value> {
+    let value = args.get_one_arg(name, vm.heap)?;
+    defer_drop!(value, vm);
+
+    let z = value_to_complex(value, vm)?;
+    Ok(f(z)?.into_value(vm.heap)?)
+}
+
+/// Calls a one-argument classification function returning a bool.
+fn predicate(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    args: ArgValues,
+    name: &str,
+    f: impl FnOnce(Complex) -> bool,
+) -> RunResult<Value> {
+    let value = args.get_one_arg(name, vm.heap)?;
+    defer_drop!(value, vm);
+
+    let z = value_to_complex(value, vm)?;
+    Ok(Value::Bool(f(z)))
+}
+
+/// Converts any number to a complex, raising `TypeError` for other values.
+fn value_to_complex(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Complex> {
+    as_complex(value, vm.heap)
+        .ok_or_else(|| ExcType::type_error(format!("must be real number, not {}", value.py_type(vm))))
+}
+
+/// Converts a real number to a float, raising `TypeError` for complex and non-numeric values.
+fn value_to_real(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> RunResult<f64> {
+    let value_type = value.py_type(vm);
+    match as_complex(value, vm.heap) {
+        Some(z) if value_type != Type::Complex => Ok(z.re),
+        _ => Err(ExcType::type_error(format!("must be real number, not {value_type}"))),
+    }
+}
+
+/// Raises `OverflowError` when a finite input produced an infinite result.
+fn check_range(result: Complex, input: Complex) -> RunResult<Complex> {
+    if input.is_finite() && (result.re.is_infinite() || result.im.is_infinite()) {
+        Err(math_range_error())
+    } else {
+        Ok(result)
+    }
+}
+
+/// Raises `ValueError` when a finite input hit a singularity (an infinite result).
+fn check_domain(result: Complex, input: Complex) -> RunResult<Complex> {
+    if input.is_finite() && (result.re.is_infinite() || result.im.is_infinite()) {
+        Err(math_domain_error())
+    } else {
+        Ok(result)
+    }
+}
+
+// ==========================
+// Module functions
+// ==========================
+
+/// `cmath.log(x[, base])` — returns the natural logarithm of x, or the logarithm to `base`.
+///
+/// As in CPython, only the final step is checked: `log(0, 2)` returns
+/// `(-inf+nanj)` while `log(2, 0)` and `log(2, 1)` raise `ValueError`.
+fn cmath_log(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (x_val, base_val) = args.get_one_two_args("cmath.log", vm.heap)?;
+    defer_drop!(x_val, vm);
+    defer_drop!(base_val, vm);
+
+    let x = value_to_complex(x_val, vm)?;
+    let result = match base_val {
+        Some(base_v) => {
+            let base = value_to_complex(base_v, vm)?;
+            let log_base = check_domain(c_log(base), base)?;
+            if log_base.im == 0.0 {
+                if log_base.re == 0.0 {
+                    return Err(math_domain_error());
+                }
+                let log_x = c_log(x);
+                Complex::new(log_x.re / log_base.re, log_x.im / log_base.re)
+            } else {
+                c_log(x).div(log_base)?
+            }
+        }
+        None => check_domain(c_log(x), x)?,
+    };
+    Ok(result.into_value(vm.heap)?)
+}
+
+/// `cmath.phase(x)` — returns the argument of x as a float in `[-pi, pi]`.
+fn cmath_phase(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let value = args.get_one_arg("cmath.phase", vm.heap)?;
+    defer_drop!(value, vm);
+
+    let z = value_to_complex(value, vm)?;
+    Ok(Value::Float(z.im.atan2(z.re)))
+}
+
+/// `cmath.polar(x)` — returns the tuple `(abs(x), phase(x))`.
+fn cmath_polar(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let value = args.get_one_arg("cmath.polar", vm.heap)?;
+    defer_drop!(value, vm);
+
+    let z = value_to_complex(value, vm)?;
+    let modulus = z.re.hypot(z.im);
+    if modulus.is_infinite() && z.is_finite() {
+        return Err(math_range_error());
+    }
+    let tuple = allocate_tuple(
+        smallvec![Value::Float(modulus), Value::Float(z.im.atan2(z.re))],
+        vm.heap,
+    )?;
+    Ok(tuple)
+}
+
+/// `cmath.rect(r, phi)` — returns the complex number with modulus `r` and phase `phi`.
+///
+/// Both arguments must be real numbers.
+fn cmath_rect(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (r_val, phi_val) = args.get_two_args("cmath.rect", vm.heap)?;
+    defer_drop!(r_val, vm);
+    defer_drop!(phi_val, vm);
+
+    let r = value_to_real(r_val, vm)?;
+    let phi = value_to_real(phi_val, vm)?;
+    let result = if phi == 0.0 {
+        // keep the sign of a zero imaginary part, as CPython does
+        Complex::new(r, r * phi)
+    } else {
+        Complex::new(r * phi.cos(), r * phi.sin())
+    };
+    Ok(check_range(result, Complex::new(r, phi))?.into_value(vm.heap)?)
+}
+
+/// `cmath.isclose(a, b, *, rel_tol=1e-09, abs_tol=0.0)` — returns True if a and b are close.
+///
+/// Closeness is measured by the modulus of the difference.
+fn cmath_isclose(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let IscloseArgs { a, b, rel_tol, abs_tol } = IscloseArgs::from_args(args, vm)?;
+    defer_drop!(a, vm);
+    defer_drop!(b, vm);
+    defer_drop!(rel_tol, vm);
+    defer_drop!(abs_tol, vm);
+
+    let a = value_to_complex(a, vm)?;
+    let b = value_to_complex(b, vm)?;
+    let rel_tol = value_to_real(rel_tol, vm)?;
+    let abs_tol = value_to_real(abs_tol, vm)?;
+
+    if rel_tol < 0.0 || abs_tol < 0.0 {
+        return Err(SimpleException::new_msg(ExcType::ValueError, "tolerances must be non-negative").into());
+    }
+
+    // Identical values (including infinities) are always close.
+    if a == b {
+        return Ok(Value::Bool(true));
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return Ok(Value::Bool(false));
+    }
+
+    let diff = (a - b).re.hypot((a - b).im);
+    let a_abs = a.re.hypot(a.im);
+    let b_abs = b.re.hypot(b.im);
+    let result = diff <= rel_tol * b_abs || diff <= rel_tol * a_abs || diff <= abs_tol;
+    Ok(Value::Bool(result))
+}
+
+/// Argument shape for `cmath.isclose(a, b, *, rel_tol=1e-9, abs_tol=0.0)`.
+#[derive(FromArgs)]
+#[from_args(name = "isclose")]
+struct IscloseArgs {
+    a: Value,
+    b: Value,
+    #[from_args(kw_only, default = Value::Float(1e-9))]
+    rel_tol: Value,
+    #[from_args(kw_only, default = Value::Float(0.0))]
+    abs_tol: Value,
+}
+
+// ==========================
+// Complex formulas (CPython's cmathmodule.c)
+// ==========================
+
+/// Principal square root, avoiding overflow in the intermediate modulus.
+fn c_sqrt(z: Complex) -> Complex {
+    if z.re == 0.0 && z.im == 0.0 {
+        return Complex::new(0.0, z.im);
+    }
+    if z.re.is_nan() || z.im.is_nan() {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+    let ax = z.re.abs();
+    let ay = z.im.abs();
+    let s = 2.0 * (ax / 8.0 + (ax / 8.0).hypot(ay / 8.0)).sqrt();
+    let d = ay / (2.0 * s);
+    if z.re >= 0.0 {
+        Complex::new(s, d.copysign(z.im))
+    } else {
+        Complex::new(d, s.copysign(z.im))
+    }
+}
+
+fn c_exp(z: Complex) -> Complex {
+    if z.im == 0.0 {
+        return Complex::new(z.re.exp(), z.im);
+    }
+    if z.re > LOG_LARGE_DOUBLE {
+        let l = (z.re - 1.0).exp();
+        Complex::new(z.im.cos() * l * consts::E, z.im.sin() * l * consts::E)
+    } else {
+        let l = z.re.exp();
+        Complex::new(z.im.cos() * l, z.im.sin() * l)
+    }
+}
+
+fn c_log(z: Complex) -> Complex {
+    Complex::new(z.re.hypot(z.im).ln(), z.im.atan2(z.re))
+}
+
+fn c_sinh(z: Complex) -> Complex {
+    Complex::new(z.re.sinh() * z.im.cos(), z.re.cosh() * z.im.sin())
+}
+
+fn c_cosh(z: Complex) -> Complex {
+    Complex::new(z.re.cosh() * z.im.cos(), z.re.sinh() * z.im.sin())
+}
+
+fn c_tanh(z: Complex) -> Complex {
+    if z.re.abs() > LOG_LARGE_DOUBLE {
+        return Complex::new(
+            1.0_f64.copysign(z.re),
+            4.0 * z.im.sin() * z.im.cos() * (-2.0 * z.re.abs()).exp(),
+        );
+    }
+    let tx = z.re.tanh();
+    let ty = z.im.tan();
+    let cx = 1.0 / z.re.cosh();
+    let txty = tx * ty;
+    let denom = 1.0 + txty * txty;
+    Complex::new(tx * (1.0 + ty * ty) / denom, ((ty / denom) * cx) * cx)
+}
+
+fn c_asinh(z: Complex) -> Complex {
+    let s1 = c_sqrt(Complex::new(1.0 + z.im, -z.re));
+    let s2 = c_sqrt(Complex::new(1.0 - z.im, z.re));
+    Complex::new(
+        (s1.re * s2.im - s2.re * s1.im).asinh(),
+        z.im.atan2(s1.re * s2.re - s1.im * s2.im),
+    )
+}
+
+fn c_acos(z: Complex) -> Complex {
+    let s1 = c_sqrt(Complex::new(1.0 - z.re, -z.im));
+    let s2 = c_sqrt(Complex::new(1.0 + z.re, z.im));
+    Complex::new(2.0 * s1.re.atan2(s2.re), (s2.re * s1.im - s2.im * s1.re).asinh())
+}
+
+fn c_acosh(z: Complex) -> Complex {
+    let s1 = c_sqrt(Complex::new(z.re - 1.0, z.im));
+    let s2 = c_sqrt(Complex::new(z.re + 1.0, z.im));
+    Complex::new((s1.re * s2.re + s1.im * s2.im).asinh(), 2.0 * s1.im.atan2(s2.re))
+}
+
+fn c_atanh(z: Complex) -> Complex {
+    if z.re < 0.0 {
+        return -c_atanh(-z);
+    }
+    let ay = z.im.abs();
+    #[expect(clippy::float_cmp, reason = "exact comparison detects the singularity at 1")]
+    if z.re == 1.0 && ay == 0.0 {
+        // the singularity at 1: the real part is infinite
+        return Complex::new(f64::INFINITY, z.im);
+    }
+    let one_minus = 1.0 - z.re;
+    Complex::new(
+        (4.0 * z.re / (one_minus * one_minus + ay * ay)).ln_1p() / 4.0,
+        -(-2.0 * z.im).atan2(one_minus * (1.0 + z.re) - ay * ay) / 2.0,
+    )
+}
+
+/// `sin(z) = -i * sinh(i * z)`
+fn c_sin(z: Complex) -> Complex {
+    let s = c_sinh(Complex::new(-z.im, z.re));
+    Complex::new(s.im, -s.re)
+}
+
+/// `cos(z) = cosh(i * z)`
+fn c_cos(z: Complex) -> Complex {
+    c_cosh(Complex::new(-z.im, z.re))
+}
+
+/// `tan(z) = -i * tanh(i * z)`
+fn c_tan(z: Complex) -> Complex {
+    let s = c_tanh(Complex::new(-z.im, z.re));
+    Complex::new(s.im, -s.re)
+}
+
+/// `asin(z) = -i * asinh(i * z)`
+fn c_asin(z: Complex) -> Complex {
+    let s = c_asinh(Complex::new(-z.im, z.re));
+    Complex::new(s.im, -s.re)
+}
+
+/// `atan(z) = -i * atanh(i * z)`
+fn c_atan(z: Complex) -> Complex {
+    let s = c_atanh(Complex::new(-z.im, z.re));
+    Complex::new(s.im, -s.re)
+}
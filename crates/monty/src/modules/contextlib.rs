@@ -0,0 +1,44 @@
+//! Implementation of the `contextlib` module.
+//!
+//! Provides the context-manager classes `suppress`, `nullcontext`, `closing`,
+//! `redirect_stdout` and `ExitStack`. Their `__enter__` / `__exit__` behavior lives
+//! in [`crate::types::contextlib`].
+//!
+//! `contextmanager` needs generators and the async variants need `async with`,
+//! neither of which Monty supports yet.
+
+use crate::{
+    builtins::Builtins,
+    bytecode::VM,
+    heap::{HeapData, HeapId},
+    intern::StaticStrings,
+    resource::{ResourceError, ResourceTracker},
+    types::{Module, Type},
+    value::Value,
+};
+
+/// Creates the `contextlib` module and allocates it on the heap.
+///
+/// Returns a `HeapId` pointing to the newly allocated module.
+///
+/// # Panics
+///
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Contextlib);
+
+    for (ss, ty) in CLASS_ATTRS {
+        module.set_attr(*ss, Value::Builtin(Builtins::Type(*ty)), vm);
+    }
+
+    vm.heap.allocate(HeapData::Module(module))
+}
+
+/// Classes exported by this module, keyed by attribute name.
+const CLASS_ATTRS: &[(StaticStrings, Type)] = &[
+    (StaticStrings::Suppress, Type::Suppress),
+    (StaticStrings::Nullcontext, Type::NullContext),
+    (StaticStrings::Closing, Type::Closing),
+    (StaticStrings::RedirectStdout, Type::RedirectStdout),
+    (StaticStrings::ExitStack, Type::ExitStack),
+];
@@ -0,0 +1,726 @@
+//! Implementation of the `dataclasses` module.
+//!
+//! Provides:
+//! - `dataclass`: validates a class declared in sandboxed code and records its options,
+//!   either called directly (`@dataclass`) or with options (`@dataclass(frozen=True)`)
+//! - `field`: per-field settings such as `default_factory`, `init` and `kw_only`
+//! - `fields`, `asdict`, `astuple`, `replace` and `is_dataclass`
+//! - `MISSING` and `FrozenInstanceError`
+//!
+//! `asdict`, `astuple`, `replace` and `is_dataclass` also accept instances of host
+//! dataclasses; `fields` needs the class, so it only accepts sandbox-declared ones.
+
+use crate::{
+    args::{ArgValues, FromArgs, KwargsValues, LaxBool},
+    builtins::Builtins,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult},
+    heap::{DropWithHeap, HeapData, HeapId, HeapReadOutput},
+    intern::{StaticStrings, StringId},
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{
+        Dataclass, DataclassField, DataclassOptions, Dict, List, Module, PyTrait, allocate_tuple,
+        dataclass::class_options, dataclass_type::instantiate, host_handle::checked_truth, str::allocate_string,
+        tuple::TupleVec,
+    },
+    value::{Marker, Value},
+};
+
+/// Dataclasses module functions — each variant corresponds to a Python-visible function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum DataclassesFunctions {
+    /// `dataclasses.dataclass(cls=None, /, **options)`.
+    Dataclass,
+    /// The decorator returned by `dataclass(**options)`, applying the captured options.
+    #[strum(serialize = "wrap")]
+    Decorator(DataclassOptions),
+    /// `dataclasses.field(*, default, default_factory, ...)`.
+    Field,
+    /// `dataclasses.fields(class_or_instance)`.
+    Fields,
+    /// `dataclasses.asdict(obj, *, dict_factory=dict)`.
+    Asdict,
+    /// `dataclasses.astuple(obj, *, tuple_factory=tuple)`.
+    Astuple,
+    /// `dataclasses.replace(obj, /, **changes)`.
+    Replace,
+    /// `dataclasses.is_dataclass(obj)`.
+    IsDataclass,
+}
+
+/// Creates the `dataclasses` module and allocates it on the heap.
+///
+/// Returns a HeapId pointing to the newly allocated module.
+///
+/// # Panics
+///
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Dataclasses);
+
+    for (ss, function) in FUNCTION_ATTRS {
+        module.set_attr(*ss, Value::ModuleFunction(ModuleFunctions::Dataclasses(*function)), vm);
+    }
+    module.set_attr(
+        StaticStrings::Missing,
+        Value::Marker(Marker(StaticStrings::Missing)),
+        vm,
+    );
+    module.set_attr(
+        StaticStrings::FrozenInstanceError,
+        Value::Builtin(Builtins::ExcType(ExcType::FrozenInstanceError)),
+        vm,
+    );
+
+    vm.heap.allocate(HeapData::Module(module))
+}
+
+/// Dataclasses functions exported by this module, keyed by attribute name.
+const FUNCTION_ATTRS: &[(StaticStrings, DataclassesFunctions)] = &[
+    (StaticStrings::Dataclass, DataclassesFunctions::Dataclass),
+    (StaticStrings::Field, DataclassesFunctions::Field),
+    (StaticStrings::Fields, DataclassesFunctions::Fields),
+    (StaticStrings::Asdict, DataclassesFunctions::Asdict),
+    (StaticStrings::Astuple, DataclassesFunctions::Astuple),
+    (StaticStrings::Replace, DataclassesFunctions::Replace),
+    (StaticStrings::IsDataclass, DataclassesFunctions::IsDataclass),
+];
+
+/// Dispatches a `dataclasses` module function call.
+///
+/// Default factories and `__post_init__` run synchronously through
+/// `VM::evaluate_function`, so every function returns a `Value` directly.
+pub(super) fn call(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    function: DataclassesFunctions,
+    args: ArgValues,
+) -> RunResult<Value> {
+    match function {
+        DataclassesFunctions::Dataclass => dataclass(vm, args),
+        DataclassesFunctions::Decorator(options) => {
+            let cls = args.get_one_arg("wrap", vm.heap)?;
+            process_class(cls, options, vm)
+        }
+        DataclassesFunctions::Field => field(vm, args),
+        DataclassesFunctions::Fields => fields(vm, args),
+        DataclassesFunctions::Asdict => {
+            let AsdictArgs { obj, dict_factory } = AsdictArgs::from_args(args, vm)?;
+            to_collection(obj, dict_factory, false, vm)
+        }
+        DataclassesFunctions::Astuple => {
+            let AstupleArgs { obj, tuple_factory } = AstupleArgs::from_args(args, vm)?;
+            to_collection(obj, tuple_factory, true, vm)
+        }
+        DataclassesFunctions::Replace => replace(vm, args),
+        DataclassesFunctions::IsDataclass => {
+            let obj = args.get_one_arg("is_dataclass", vm.heap)?;
+            defer_drop!(obj, vm);
+            let is_dataclass = match obj {
+                Value::Ref(id) => match vm.heap.get(*id) {
+                    HeapData::Dataclass(_) => true,
+                    HeapData::DataclassType(class) => class.options().is_some(),
+                    _ => false,
+                },
+                _ => false,
+            };
+            Ok(Value::Bool(is_dataclass))
+        }
+    }
+}
+
+/// Argument shape for `dataclasses.dataclass(cls=None, /, *, init=True, ...)`.
+///
+/// `unsafe_hash`, `match_args`, `slots` and `weakref_slot` are accepted but have no effect.
+#[derive(FromArgs)]
+#[from_args(name = "dataclass")]
+struct DataclassArgs {
+    #[from_args(pos_only, default)]
+    cls: Option<Value>,
+    #[from_args(kw_only, default = LaxBool::new(true))]
+    init: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(true))]
+    repr: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(true))]
+    eq: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    order: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    unsafe_hash: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    frozen: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(true))]
+    match_args: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    kw_only: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    slots: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    weakref_slot: LaxBool,
+}
+
+/// `dataclasses.dataclass(cls=None, /, **options)`.
+///
+/// With a class, processes it immediately; with only options, returns a decorator
+/// that applies them, so both `@dataclass` and `@dataclass(frozen=True)` work.
+fn dataclass(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let DataclassArgs {
+        cls,
+        init,
+        repr,
+        eq,
+        order,
+        unsafe_hash: _,
+        frozen,
+        match_args: _,
+        kw_only,
+        slots: _,
+        weakref_slot: _,
+    } = DataclassArgs::from_args(args, vm)?;
+    let options = DataclassOptions {
+        init: init.bool(),
+        repr: repr.bool(),
+        eq: eq.bool(),
+        order: order.bool(),
+        frozen: frozen.bool(),
+        kw_only: kw_only.bool(),
+    };
+    match cls {
+        Some(cls) => process_class(cls, options, vm),
+        None => Ok(Value::ModuleFunction(ModuleFunctions::Dataclasses(
+            DataclassesFunctions::Decorator(options),
+        ))),
+    }
+}
+
+/// Validates the fields of a sandbox-declared class and records its `@dataclass` options.
+///
+/// Returns the class itself, like CPython's decorator.
+fn process_class(cls: Value, options: DataclassOptions, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let Value::Ref(class_id) = cls else {
+        let ty = cls.py_type(vm);
+        cls.drop_with_heap(vm);
+        return Err(ExcType::type_error(format!(
+            "dataclass() should be called on a class, not '{ty}'"
+        )));
+    };
+    let HeapData::DataclassType(class) = vm.heap.get(class_id) else {
+        let ty = cls.py_type(vm);
+        cls.drop_with_heap(vm);
+        return Err(ExcType::type_error(format!(
+            "dataclass() should be called on a class, not '{ty}'"
+        )));
+    };
+
+    if options.order && !options.eq {
+        cls.drop_with_heap(vm);
+        return Err(ExcType::value_error("eq must be true if order is true"));
+    }
+
+    let interns = vm.interns;
+    // The generated methods are always used, so refuse anything that expects otherwise
+    let unsupported = if options.repr {
+        class
+            .method_names()
+            .map(|id| interns.get_str(id))
+            .find(|name| name.starts_with("__") && name.ends_with("__") && *name != "__post_init__")
+            .map(|name| format!("defining {name} in a dataclass body is not supported"))
+    } else {
+        Some("dataclass(repr=False) is not supported".to_owned())
+    };
+    if let Some(message) = unsupported {
+        cls.drop_with_heap(vm);
+        return Err(ExcType::not_implemented(message).into());
+    }
+
+    let mut error = None;
+    let mut last_default: Option<&str> = None;
+    for field in class.fields() {
+        let name = field.name.map_or("", |id| interns.get_str(id));
+        if let Some(default) = &field.default
+            && let Some(type_repr) = mutable_default_type(default, vm)
+        {
+            error = Some(ExcType::value_error(format!(
+                "mutable default {type_repr} for field {name} is not allowed: use default_factory"
+            )));
+            break;
+        }
+        // Only positional `__init__` parameters need defaults to come last.
+        if !field.init || field.is_kw_only(options) {
+            continue;
+        }
+        if field.has_default() {
+            last_default = Some(name);
+        } else if let Some(default_name) = last_default {
+            error = Some(ExcType::type_error(format!(
+                "non-default argument '{name}' follows default argument '{default_name}'"
+            )));
+            break;
+        }
+    }
+    if let Some(error) = error {
+        cls.drop_with_heap(vm);
+        return Err(error);
+    }
+
+    let HeapReadOutput::DataclassType(mut class) = vm.heap.read(class_id) else {
+        unreachable!("class type changed during processing")
+    };
+    class.get_mut(vm.heap).set_options(options);
+    Ok(cls)
+}
+
+/// Returns the repr of the type of `value` if it's an unhashable type, which CPython
+/// rejects as a field default since it would be shared between instances.
+fn mutable_default_type(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<String> {
+    let Value::Ref(id) = value else {
+        return None;
+    };
+    match vm.heap.get(*id) {
+        data @ (HeapData::List(_) | HeapData::Dict(_) | HeapData::Set(_)) => {
+            Some(format!("<class '{}'>", data.py_type()))
+        }
+        HeapData::Dataclass(dc) if !dc.is_frozen() && dc.class_id().is_none_or(|id| class_options(id, vm).eq) => {
+            Some(format!("<class '__main__.{}'>", dc.name(vm.interns)))
+        }
+        _ => None,
+    }
+}
+
+/// Argument shape for `dataclasses.field(*, default=MISSING, default_factory=MISSING, ...)`.
+///
+/// `hash` and `metadata` are accepted but not recorded.
+#[derive(FromArgs)]
+#[from_args(name = "field")]
+struct FieldArgs {
+    #[from_args(kw_only, default)]
+    default: Option<Value>,
+    #[from_args(kw_only, default)]
+    default_factory: Option<Value>,
+    #[from_args(kw_only, default = LaxBool::new(true))]
+    init: LaxBool,
+    #[from_args(kw_only, default = LaxBool::new(true))]
+    repr: LaxBool,
+    #[from_args(kw_only, default = Value::None)]
+    hash: Value,
+    #[from_args(kw_only, default = LaxBool::new(true))]
+    compare: LaxBool,
+    #[from_args(kw_only, default = Value::None)]
+    metadata: Value,
+    #[from_args(kw_only, default)]
+    kw_only: Option<Value>,
+}
+
+/// `dataclasses.field(...)` — creates the `Field` that a class attribute default uses
+/// to customize its field.
+fn field(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let FieldArgs {
+        default,
+        default_factory,
+        init,
+        repr,
+        hash,
+        compare,
+        metadata,
+        kw_only,
+    } = FieldArgs::from_args(args, vm)?;
+    hash.drop_with_heap(vm);
+    metadata.drop_with_heap(vm);
+    // An explicit `MISSING` means the same as leaving the argument out.
+    let default = default.filter(|value| !is_missing(value));
+    let default_factory = default_factory.filter(|value| !is_missing(value));
+    let kw_only = kw_only.filter(|value| !is_missing(value)).map(|value| {
+        let kw_only = checked_truth(&value, vm);
+        value.drop_with_heap(vm);
+        kw_only
+    });
+    let kw_only = match kw_only.transpose() {
+        Ok(kw_only) => kw_only,
+        Err(err) => {
+            default.drop_with_heap(vm);
+            default_factory.drop_with_heap(vm);
+            return Err(err);
+        }
+    };
+    if default.is_some() && default_factory.is_some() {
+        default.drop_with_heap(vm);
+        default_factory.drop_with_heap(vm);
+        return Err(ExcType::value_error("cannot specify both default and default_factory"));
+    }
+
+    let mut field = DataclassField::new(None, Value::None);
+    field.default = default;
+    field.default_factory = default_factory;
+    field.init = init.bool();
+    field.repr = repr.bool();
+    field.compare = compare.bool();
+    field.kw_only = kw_only;
+    Ok(Value::Ref(vm.heap.allocate(HeapData::DataclassField(field))?))
+}
+
+/// Returns whether `value` is the `dataclasses.MISSING` sentinel.
+fn is_missing(value: &Value) -> bool {
+    matches!(value, Value::Marker(Marker(StaticStrings::Missing)))
+}
+
+/// `dataclasses.fields(class_or_instance)` — a tuple of `Field` objects in declaration order.
+fn fields(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let obj = args.get_one_arg("fields", vm.heap)?;
+    defer_drop!(obj, vm);
+    let class_id = match obj {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::DataclassType(class) if class.options().is_some() => Some(*id),
+            HeapData::Dataclass(dc) => dc.class_id(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let Some(HeapData::DataclassType(class)) = class_id.map(|id| vm.heap.get(id)) else {
+        return Err(ExcType::type_error("must be called with a dataclass type or instance"));
+    };
+
+    let fields: Vec<DataclassField> = class.fields().iter().map(|field| field.clone_with_heap(vm)).collect();
+    let mut items = TupleVec::with_capacity(fields.len());
+    for field in fields {
+        match vm.heap.allocate(HeapData::DataclassField(field)) {
+            Ok(id) => items.push(Value::Ref(id)),
+            Err(err) => {
+                items.drop_with_heap(vm);
+                return Err(err.into());
+            }
+        }
+    }
+    Ok(allocate_tuple(items, vm.heap)?)
+}
+
+/// Argument shape for `dataclasses.asdict(obj, *, dict_factory=dict)`.
+#[derive(FromArgs)]
+#[from_args(name = "asdict")]
+struct AsdictArgs {
+    obj: Value,
+    #[from_args(kw_only, default)]
+    dict_factory: Option<Value>,
+}
+
+/// Argument shape for `dataclasses.astuple(obj, *, tuple_factory=tuple)`.
+#[derive(FromArgs)]
+#[from_args(name = "astuple")]
+struct AstupleArgs {
+    obj: Value,
+    #[from_args(kw_only, default)]
+    tuple_factory: Option<Value>,
+}
+
+/// `asdict()` / `astuple()` — converts a dataclass instance to a dict or tuple,
+/// recursing into nested dataclasses, lists, tuples and dicts.
+///
+/// A custom factory is called with the list of `(name, value)` pairs (for `asdict`)
+/// or the list of values (for `astuple`) at every dataclass level.
+fn to_collection(
+    obj: Value,
+    factory: Option<Value>,
+    as_tuple: bool,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    defer_drop!(obj, vm);
+    defer_drop!(factory, vm);
+    if !matches!(obj, Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::Dataclass(_))) {
+        let name = if as_tuple { "astuple" } else { "asdict" };
+        return Err(ExcType::type_error(format!(
+            "{name}() should be called on dataclass instances"
+        )));
+    }
+    copy_value(obj, factory.as_ref(), as_tuple, vm)
+}
+
+/// Recursively copies `value` for `asdict()` / `astuple()`.
+///
+/// Dataclass instances become dicts (or tuples); lists, tuples and dicts are copied with
+/// their items converted. Anything else is shared rather than deep-copied.
+fn copy_value(
+    value: &Value,
+    factory: Option<&Value>,
+    as_tuple: bool,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let Value::Ref(id) = value else {
+        return Ok(value.clone_with_heap(vm));
+    };
+    let token = vm.heap.incr_recursion_depth()?;
+    defer_drop!(token, vm);
+    match vm.heap.get(*id) {
+        HeapData::Dataclass(dc) => {
+            let entries: Vec<(String, Value)> = dc
+                .field_names()
+                .iter()
+                .filter_map(|name| {
+                    let value = dc.attrs().get_by_str(name, vm.heap, vm.interns)?;
+                    Some((name.clone(), value.clone_with_heap(vm)))
+                })
+                .collect();
+            let mut items = Vec::with_capacity(entries.len());
+            let mut entries = entries.into_iter();
+            while let Some((name, value)) = entries.next() {
+                let result = copy_value(&value, factory, as_tuple, vm);
+                value.drop_with_heap(vm);
+                let item = match result {
+                    Ok(item) if as_tuple => Ok(item),
+                    Ok(item) => match allocate_string(name, vm.heap) {
+                        Ok(key) => allocate_tuple(TupleVec::from_iter([key, item]), vm.heap).map_err(Into::into),
+                        Err(err) => {
+                            item.drop_with_heap(vm);
+                            Err(err.into())
+                        }
+                    },
+                    Err(err) => Err(err),
+                };
+                match item {
+                    Ok(item) => items.push(item),
+                    Err(err) => {
+                        entries.for_each(|(_, value)| value.drop_with_heap(vm));
+                        items.drop_with_heap(vm);
+                        return Err(err);
+                    }
+                }
+            }
+            build_collection(items, factory, as_tuple, vm)
+        }
+        HeapData::List(list) => {
+            let items: Vec<Value> = list.as_slice().iter().map(|item| item.clone_with_heap(vm)).collect();
+            let items = copy_items(items, factory, as_tuple, vm)?;
+            Ok(Value::Ref(vm.heap.allocate(HeapData::List(List::new(items)))?))
+        }
+        HeapData::Tuple(tuple) => {
+            let items: Vec<Value> = tuple.as_slice().iter().map(|item| item.clone_with_heap(vm)).collect();
+            let items = copy_items(items, factory, as_tuple, vm)?;
+            Ok(allocate_tuple(items.into_iter().collect(), vm.heap)?)
+        }
+        HeapData::Dict(dict) => {
+            let flat: Vec<Value> = dict
+                .iter()
+                .flat_map(|(key, value)| [key.clone_with_heap(vm), value.clone_with_heap(vm)])
+                .collect();
+            let mut flat = copy_items(flat, factory, as_tuple, vm)?.into_iter();
+            let mut pairs = Vec::with_capacity(flat.len() / 2);
+            while let (Some(key), Some(value)) = (flat.next(), flat.next()) {
+                pairs.push((key, value));
+            }
+            let dict = Dict::from_pairs(pairs, vm)?;
+            Ok(Value::Ref(vm.heap.allocate(HeapData::Dict(dict))?))
+        }
+        _ => Ok(value.clone_with_heap(vm)),
+    }
+}
+
+/// Converts each of `items` with [`copy_value`], consuming them.
+fn copy_items(
+    items: Vec<Value>,
+    factory: Option<&Value>,
+    as_tuple: bool,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Vec<Value>> {
+    let mut copied = Vec::with_capacity(items.len());
+    let mut items = items.into_iter();
+    while let Some(item) = items.next() {
+        let result = copy_value(&item, factory, as_tuple, vm);
+        item.drop_with_heap(vm);
+        match result {
+            Ok(value) => copied.push(value),
+            Err(err) => {
+                items.drop_with_heap(vm);
+                copied.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+    }
+    Ok(copied)
+}
+
+/// Builds the dict (from `(name, value)` tuples) or tuple for one dataclass level,
+/// calling the custom factory if one was given.
+fn build_collection(
+    items: Vec<Value>,
+    factory: Option<&Value>,
+    as_tuple: bool,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    if let Some(factory) = factory {
+        let list = Value::Ref(vm.heap.allocate(HeapData::List(List::new(items)))?);
+        let ctx = if as_tuple { "tuple_factory" } else { "dict_factory" };
+        return vm.evaluate_function(ctx, factory, ArgValues::One(list));
+    }
+    if as_tuple {
+        return Ok(allocate_tuple(items.into_iter().collect(), vm.heap)?);
+    }
+    let mut pairs = Vec::with_capacity(items.len());
+    for item in items {
+        let Value::Ref(id) = &item else {
+            unreachable!("asdict items are (name, value) tuples")
+        };
+        let HeapData::Tuple(tuple) = vm.heap.get(*id) else {
+            unreachable!("asdict items are (name, value) tuples")
+        };
+        let [key, value] = tuple.as_slice() else {
+            unreachable!("asdict items are (name, value) tuples")
+        };
+        pairs.push((key.clone_with_heap(vm), value.clone_with_heap(vm)));
+        item.drop_with_heap(vm);
+    }
+    let dict = Dict::from_pairs(pairs, vm)?;
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Dict(dict))?))
+}
+
+/// `dataclasses.replace(obj, /, **changes)` — a new instance with some fields changed.
+///
+/// Instances of sandbox-declared classes go through the generated `__init__` (so
+/// defaults, factories and `__post_init__` run as in CPython); host instances are
+/// copied with the changed attributes.
+fn replace(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (mut pos_args, kwargs) = args.into_parts();
+    let obj = match (pos_args.next(), pos_args.len()) {
+        (Some(obj), 0) => obj,
+        (obj, extra) => {
+            let given = usize::from(obj.is_some()) + extra;
+            obj.drop_with_heap(vm);
+            pos_args.drop_with_heap(vm);
+            kwargs.drop_with_heap(vm);
+            return Err(ExcType::type_error(format!(
+                "replace() takes 1 positional argument but {given} were given"
+            )));
+        }
+    };
+    defer_drop!(obj, vm);
+    let dataclass_id = match obj {
+        Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::Dataclass(_)) => *id,
+        _ => {
+            kwargs.drop_with_heap(vm);
+            return Err(ExcType::type_error("replace() should be called on dataclass instances"));
+        }
+    };
+
+    let changes: Vec<(Value, Value)> = kwargs.into_iter().collect();
+    let HeapData::Dataclass(dc) = vm.heap.get(dataclass_id) else {
+        unreachable!("checked above")
+    };
+    match dc.class_id() {
+        Some(class_id) => replace_via_init(dataclass_id, class_id, changes, vm),
+        None => replace_host(dataclass_id, changes, vm),
+    }
+}
+
+/// Builds the `__init__` keyword arguments for `replace()` on a sandbox-declared instance:
+/// the changes, plus the current value of every other `init` field.
+fn replace_via_init(
+    dataclass_id: HeapId,
+    class_id: HeapId,
+    changes: Vec<(Value, Value)>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let interns = vm.interns;
+    let (HeapData::DataclassType(class), HeapData::Dataclass(dc)) = (vm.heap.get(class_id), vm.heap.get(dataclass_id))
+    else {
+        changes.drop_with_heap(vm);
+        return Err(RunError::internal("replace: dataclass instance without a class"));
+    };
+    // `(name, init, current value)` for each field
+    let current: Vec<(StringId, bool, Option<Value>)> = class
+        .fields()
+        .iter()
+        .filter_map(|field| {
+            let name = field.name?;
+            let value = dc.attrs().get_by_str(interns.get_str(name), vm.heap, interns);
+            Some((name, field.init, value.map(|value| value.clone_with_heap(vm))))
+        })
+        .collect();
+
+    let mut changed = Vec::with_capacity(changes.len());
+    let mut error = None;
+    for (key, _) in &changes {
+        let Some(key) = key.as_either_str(vm.heap) else {
+            error = Some(ExcType::type_error("keywords must be strings"));
+            break;
+        };
+        let key = key.as_str(interns).to_owned();
+        if current
+            .iter()
+            .any(|(name, init, _)| !init && interns.get_str(*name) == key)
+        {
+            error = Some(ExcType::type_error(format!(
+                "field {key} is declared with init=False, it cannot be specified with replace()"
+            )));
+            break;
+        }
+        changed.push(key);
+    }
+    if let Some(error) = error {
+        changes.drop_with_heap(vm);
+        current.into_iter().for_each(|(_, _, value)| value.drop_with_heap(vm));
+        return Err(error);
+    }
+
+    let mut kwargs = changes;
+    for (name, init, value) in current {
+        match value {
+            Some(value) if init && !changed.iter().any(|key| key == interns.get_str(name)) => {
+                kwargs.push((Value::InternString(name), value));
+            }
+            value => value.drop_with_heap(vm),
+        }
+    }
+    let kwargs = Dict::from_pairs(kwargs, vm)?;
+    instantiate(class_id, ArgValues::Kwargs(KwargsValues::Dict(kwargs)), vm)
+}
+
+/// `replace()` on a host dataclass instance: copies the attributes and applies the changes.
+fn replace_host(
+    dataclass_id: HeapId,
+    changes: Vec<(Value, Value)>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let interns = vm.interns;
+    let HeapData::Dataclass(dc) = vm.heap.get(dataclass_id) else {
+        unreachable!("checked by replace()")
+    };
+    let name = dc.name(interns).to_owned();
+    let (type_id, frozen) = (dc.type_id(), dc.is_frozen());
+    let field_names = dc.field_names().to_vec();
+    let pairs: Vec<(Value, Value)> = dc
+        .attrs()
+        .iter()
+        .map(|(key, value)| (key.clone_with_heap(vm), value.clone_with_heap(vm)))
+        .collect();
+
+    let mut attrs = Dict::from_pairs(pairs, vm)?;
+    let mut changes = changes.into_iter();
+    while let Some((key, value)) = changes.next() {
+        let key_str = key.as_either_str(vm.heap).map(|key| key.as_str(interns).to_owned());
+        let error = match key_str {
+            Some(key_str) if field_names.contains(&key_str) => None,
+            Some(key_str) => Some(ExcType::type_error_unexpected_keyword(
+                &format!("{name}.__init__"),
+                &key_str,
+            )),
+            None => Some(ExcType::type_error("keywords must be strings")),
+        };
+        let result = match error {
+            Some(error) => {
+                key.drop_with_heap(vm);
+                value.drop_with_heap(vm);
+                Err(error)
+            }
+            None => attrs.set(key, value, vm),
+        };
+        match result {
+            Ok(old) => old.drop_with_heap(vm),
+            Err(err) => {
+                changes.drop_with_heap(vm);
+                attrs.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+    }
+    let dataclass = Dataclass::new(name, type_id, field_names, attrs, frozen);
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Dataclass(dataclass))?))
+}
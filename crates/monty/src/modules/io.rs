@@ -0,0 +1,48 @@
+//! Implementation of the `io` module.
+//!
+//! Only the in-memory file classes `StringIO` and `BytesIO` are provided, plus
+//! `UnsupportedOperation` and the `SEEK_*` constants. Their methods live in
+//! [`crate::types::memory_file`]; real files come from the `open()` builtin.
+
+use crate::{
+    builtins::Builtins,
+    bytecode::VM,
+    exception_private::ExcType,
+    heap::{HeapData, HeapId},
+    intern::StaticStrings,
+    resource::{ResourceError, ResourceTracker},
+    types::{Module, Type},
+    value::Value,
+};
+
+/// Creates the `io` module and allocates it on the heap.
+///
+/// Returns a `HeapId` pointing to the newly allocated module.
+///
+/// # Panics
+///
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Io);
+
+    module.set_attr(
+        StaticStrings::StringIO,
+        Value::Builtin(Builtins::Type(Type::StringIO)),
+        vm,
+    );
+    module.set_attr(
+        StaticStrings::BytesIO,
+        Value::Builtin(Builtins::Type(Type::BytesIO)),
+        vm,
+    );
+    module.set_attr(
+        StaticStrings::UnsupportedOperation,
+        Value::Builtin(Builtins::ExcType(ExcType::UnsupportedOperation)),
+        vm,
+    );
+    module.set_attr(StaticStrings::SeekSet, Value::Int(0), vm);
+    module.set_attr(StaticStrings::SeekCur, Value::Int(1), vm);
+    module.set_attr(StaticStrings::SeekEnd, Value::Int(2), vm);
+
+    vm.heap.allocate(HeapData::Module(module))
+}
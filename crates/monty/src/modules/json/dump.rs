@@ -16,7 +16,7 @@ use crate::{
     heap::{ContainsHeap, Heap, HeapData, HeapGuard, HeapId, HeapRead, HeapReadOutput},
     resource::ResourceTracker,
     sorting::{apply_permutation, sort_indices},
-    types::{Dict, PyTrait, long_int::check_bigint_str_digits_limit, str::allocate_string},
+    types::{Dict, PyTrait, host_handle::checked_truth, long_int::check_bigint_str_digits_limit, str::allocate_string},
     value::Value,
 };
 
@@ -106,10 +106,20 @@ impl JsonDumpsConfig {
         let indent = parse_indent_value(indent, vm)?;
         config.indent = indent;
 
-        config.flags = apply_bool_flag(config.flags, Self::SORT_KEYS, sort_keys, vm);
-        config.flags = apply_bool_flag(config.flags, Self::ENSURE_ASCII, ensure_ascii, vm);
-        config.flags = apply_bool_flag(config.flags, Self::ALLOW_NAN, allow_nan, vm);
-        config.flags = apply_bool_flag(config.flags, Self::SKIPKEYS, skipkeys, vm);
+        // Every flag is dropped before any error is raised.
+        let flags = [
+            (Self::SORT_KEYS, bool_flag(sort_keys, vm)),
+            (Self::ENSURE_ASCII, bool_flag(ensure_ascii, vm)),
+            (Self::ALLOW_NAN, bool_flag(allow_nan, vm)),
+            (Self::SKIPKEYS, bool_flag(skipkeys, vm)),
+        ];
+        for (bit, set) in flags {
+            if set? {
+                config.flags |= bit;
+            } else {
+                config.flags &= !bit;
+            }
+        }
 
         // `separators=None` is documented as equivalent to "use the indent-
         // aware defaults", so we only override the per-instance separators
@@ -171,7 +181,7 @@ pub(super) fn call_dumps(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues)
 /// surfaces in the error. `kwarg_error_name` overrides the function name used
 /// in the unexpected-keyword message without affecting other error paths.
 /// Every field is a raw `Value` so the encoder can apply its own truth-test
-/// (`py_bool`) or shape coercion (`parse_indent_value` /
+/// (`checked_truth`) or shape coercion (`parse_indent_value` /
 /// `parse_separators_value`) on the way through.
 #[derive(FromArgs)]
 #[from_args(name = "dumps", kwarg_error_name = "JSONEncoder.__init__")]
@@ -191,13 +201,12 @@ struct JsonDumpsArgs {
     skipkeys: Value,
 }
 
-/// Sets `bit` in `flags` when `value` is truthy, clearing it otherwise. The
-/// value is dropped afterwards. Used by the json.dumps kwarg pipeline so each
-/// boolean-style flag is handled with a single line.
-fn apply_bool_flag(flags: u8, bit: u8, value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> u8 {
-    let new_flags = if value.py_bool(vm) { flags | bit } else { flags & !bit };
+/// Returns the truth value of a boolean-style json.dumps kwarg. The value is
+/// dropped afterwards.
+fn bool_flag(value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<bool> {
+    let set = checked_truth(&value, vm);
     value.drop_with_heap(vm);
-    new_flags
+    set
 }
 
 /// Parses the `indent=` value for `json.dumps()`.
@@ -0,0 +1,966 @@
+//! Implementation of the `logging` module.
+//!
+//! Provides `getLogger`, `basicConfig`, the module-level logging functions,
+//! `disable`, `getLevelName`, `getLevelNamesMapping`, the level constants, and
+//! the methods of the `Logger` and `LoggerAdapter` types from
+//! [`crate::types::logger`].
+//!
+//! There are no handler or formatter objects. A record that passes the level
+//! checks becomes a structured [`MontyLogRecord`] handed to the host through
+//! [`PrintWriter::log`](crate::PrintWriter::log). Its `formatted` text uses the
+//! format installed by `basicConfig()` or, before that, just the message, like
+//! CPython's last-resort handler. The module-level functions (`logging.info()`
+//! etc.) call `basicConfig()` first, as in CPython.
+//!
+//! Levels, the format and the name-to-logger registry live on the VM in
+//! [`LoggingState`], so they survive snapshot/resume.
+
+use std::{borrow::Cow, mem};
+
+use ahash::AHashMap;
+
+use crate::{
+    args::{ArgValues, FromArgs, KwargsValues, LaxBool},
+    builtins::Builtins,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, ExceptionRaise, RunError, RunResult, SimpleException},
+    exception_public::MontyException,
+    fstring::{PercentArgs, format_percent},
+    heap::{ContainsHeap, DropWithHeap, HeapData, HeapId},
+    intern::StaticStrings,
+    io::MontyLogRecord,
+    modules::ModuleFunctions,
+    object::MontyObject,
+    resource::{ResourceError, ResourceTracker},
+    types::{
+        Dict, Module, PyTrait, Type, host_handle::checked_truth, logger::Logger, str::StringRepr, str::allocate_string,
+    },
+    value::{EitherStr, Value},
+};
+
+/// Logging module functions — each variant corresponds to a Python-visible function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum LoggingFunctions {
+    #[strum(serialize = "getLogger")]
+    GetLogger,
+    #[strum(serialize = "basicConfig")]
+    BasicConfig,
+    Debug,
+    Info,
+    Warning,
+    Warn,
+    Error,
+    Exception,
+    Critical,
+    Fatal,
+    Log,
+    Disable,
+    #[strum(serialize = "getLevelName")]
+    GetLevelName,
+    #[strum(serialize = "getLevelNamesMapping")]
+    GetLevelNamesMapping,
+}
+
+const NOTSET: i64 = 0;
+const DEBUG: i64 = 10;
+const INFO: i64 = 20;
+const WARNING: i64 = 30;
+const ERROR: i64 = 40;
+const CRITICAL: i64 = 50;
+
+/// Name of the root logger.
+const ROOT: &str = "root";
+
+/// Format `basicConfig()` installs when none is given.
+const DEFAULT_FORMAT: &str = "%(levelname)s:%(name)s:%(message)s";
+
+/// The level names, in `getLevelNamesMapping()` order.
+const LEVELS: &[(StaticStrings, i64)] = &[
+    (StaticStrings::LevelCritical, CRITICAL),
+    (StaticStrings::LevelFatal, CRITICAL),
+    (StaticStrings::LevelError, ERROR),
+    (StaticStrings::LevelWarn, WARNING),
+    (StaticStrings::LevelWarning, WARNING),
+    (StaticStrings::LevelInfo, INFO),
+    (StaticStrings::LevelDebug, DEBUG),
+    (StaticStrings::LevelNotset, NOTSET),
+];
+
+/// Record attributes that `extra` may not overwrite, as in CPython's `Logger.makeRecord()`.
+const RECORD_ATTRIBUTES: &[&str] = &[
+    "name",
+    "msg",
+    "args",
+    "levelname",
+    "levelno",
+    "pathname",
+    "filename",
+    "module",
+    "exc_info",
+    "exc_text",
+    "stack_info",
+    "lineno",
+    "funcName",
+    "created",
+    "msecs",
+    "relativeCreated",
+    "thread",
+    "threadName",
+    "processName",
+    "process",
+    "taskName",
+    "message",
+    "asctime",
+];
+
+/// Record attributes CPython formats that Monty can't provide: there is no
+/// clock, thread or process to report.
+const UNSUPPORTED_FIELDS: &[&str] = &[
+    "asctime",
+    "created",
+    "msecs",
+    "relativeCreated",
+    "thread",
+    "threadName",
+    "process",
+    "processName",
+    "taskName",
+];
+
+/// Creates the `logging` module and allocates it on the heap.
+///
+/// # Returns
+/// A `HeapId` pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Logging);
+
+    for (name, func) in LOGGING_FUNCTIONS {
+        module.set_attr(*name, Value::ModuleFunction(ModuleFunctions::Logging(*func)), vm);
+    }
+    for (name, level) in LEVELS {
+        module.set_attr(*name, Value::Int(*level), vm);
+    }
+    module.set_attr(
+        StaticStrings::LoggerClass,
+        Value::Builtin(Builtins::Type(Type::Logger)),
+        vm,
+    );
+    module.set_attr(
+        StaticStrings::LoggerAdapter,
+        Value::Builtin(Builtins::Type(Type::LoggerAdapter)),
+        vm,
+    );
+    let root = get_logger(vm, ROOT.to_owned())?;
+    module.set_attr(StaticStrings::Root, root, vm);
+
+    vm.heap.allocate(HeapData::Module(module))
+}
+
+/// Static mapping of attribute names to logging functions for module creation.
+const LOGGING_FUNCTIONS: &[(StaticStrings, LoggingFunctions)] = &[
+    (StaticStrings::GetLogger, LoggingFunctions::GetLogger),
+    (StaticStrings::BasicConfig, LoggingFunctions::BasicConfig),
+    (StaticStrings::Debug, LoggingFunctions::Debug),
+    (StaticStrings::Info, LoggingFunctions::Info),
+    (StaticStrings::Warning, LoggingFunctions::Warning),
+    (StaticStrings::Warn, LoggingFunctions::Warn),
+    (StaticStrings::Error, LoggingFunctions::Error),
+    (StaticStrings::Exception, LoggingFunctions::Exception),
+    (StaticStrings::Critical, LoggingFunctions::Critical),
+    (StaticStrings::Fatal, LoggingFunctions::Fatal),
+    (StaticStrings::Log, LoggingFunctions::Log),
+    (StaticStrings::Disable, LoggingFunctions::Disable),
+    (StaticStrings::GetLevelName, LoggingFunctions::GetLevelName),
+    (
+        StaticStrings::GetLevelNamesMapping,
+        LoggingFunctions::GetLevelNamesMapping,
+    ),
+];
+
+/// Dispatches a call to a logging module function.
+pub(super) fn call(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    function: LoggingFunctions,
+    args: ArgValues,
+) -> RunResult<Value> {
+    let (level, exception) = match function {
+        LoggingFunctions::GetLogger => return get_logger_function(vm, args),
+        LoggingFunctions::BasicConfig => return basic_config(vm, args),
+        LoggingFunctions::Disable => return disable(vm, args),
+        LoggingFunctions::GetLevelName => return get_level_name(vm, args),
+        LoggingFunctions::GetLevelNamesMapping => {
+            args.check_zero_args("getLevelNamesMapping", vm.heap)?;
+            let pairs = LEVELS
+                .iter()
+                .map(|(name, level)| (Value::InternString((*name).into()), Value::Int(*level)))
+                .collect();
+            let dict = Dict::from_pairs(pairs, vm)?;
+            return Ok(Value::Ref(vm.heap.allocate(HeapData::Dict(dict))?));
+        }
+        LoggingFunctions::Log => (None, false),
+        LoggingFunctions::Debug => (Some(DEBUG), false),
+        LoggingFunctions::Info => (Some(INFO), false),
+        LoggingFunctions::Warning | LoggingFunctions::Warn => (Some(WARNING), false),
+        LoggingFunctions::Error => (Some(ERROR), false),
+        LoggingFunctions::Exception => (Some(ERROR), true),
+        LoggingFunctions::Critical | LoggingFunctions::Fatal => (Some(CRITICAL), false),
+    };
+    let call = match level {
+        Some(level) => LogCall::parse(&function.to_string(), level, exception, args, vm)?,
+        None => LogCall::parse_log(args, vm)?,
+    };
+    // Like CPython, the module-level functions configure the root logger first.
+    vm.logging.format.get_or_insert_with(|| DEFAULT_FORMAT.to_owned());
+    log(vm, ROOT, call)?;
+    Ok(Value::None)
+}
+
+/// Interpreter-wide state of the `logging` module.
+///
+/// Owned by the VM and included in its snapshots. Each entry of `loggers` owns a
+/// reference to its logger, released by [`DropWithHeap`].
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) struct LoggingState {
+    /// Levels set with `setLevel()` or `basicConfig(level=...)`, by logger name.
+    levels: AHashMap<String, i64>,
+    /// Loggers created by `getLogger()`, so each name always maps to the same object.
+    loggers: AHashMap<String, Value>,
+    /// The level passed to `logging.disable()`; records at or below it are dropped.
+    disabled: i64,
+    /// The format installed by `basicConfig()`, or `None` until logging is configured.
+    format: Option<String>,
+}
+
+impl DropWithHeap for LoggingState {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        for (_, logger) in self.loggers {
+            logger.drop_with_heap(heap);
+        }
+    }
+}
+
+impl LoggingState {
+    /// Returns the level set on the named logger: `NOTSET` unless set, except
+    /// for the root logger, which starts at `WARNING`.
+    pub fn level(&self, name: &str) -> i64 {
+        let default = if name == ROOT { WARNING } else { NOTSET };
+        self.levels.get(name).copied().unwrap_or(default)
+    }
+
+    /// Returns the level of the nearest logger in the dotted-name hierarchy
+    /// whose level is not `NOTSET`, falling back to the root logger's.
+    pub fn effective_level(&self, name: &str) -> i64 {
+        let mut current = name;
+        while current != ROOT {
+            if let Some(&level) = self.levels.get(current)
+                && level != NOTSET
+            {
+                return level;
+            }
+            match current.rfind('.') {
+                Some(dot) => current = &current[..dot],
+                None => break,
+            }
+        }
+        self.level(ROOT)
+    }
+
+    /// Returns whether the named logger would emit a record at `level`.
+    fn is_enabled_for(&self, name: &str, level: i64) -> bool {
+        level > self.disabled && level >= self.effective_level(name)
+    }
+}
+
+/// Returns the logger with the given name, creating it on first use.
+fn get_logger(vm: &mut VM<'_, impl ResourceTracker>, name: String) -> Result<Value, ResourceError> {
+    if let Some(logger) = vm.logging.loggers.get(&name) {
+        return Ok(logger.clone_with_heap(vm.heap));
+    }
+    let logger = Value::Ref(vm.heap.allocate(HeapData::Logger(Logger::Named(name.clone())))?);
+    vm.logging.loggers.insert(name, logger.clone_with_heap(vm.heap));
+    Ok(logger)
+}
+
+/// Returns the name of the logger `logger` logs through, following `LoggerAdapter`s.
+pub(crate) fn logger_name<'a>(logger: &'a Logger, vm: &'a VM<'_, impl ResourceTracker>) -> RunResult<String> {
+    let mut logger = logger;
+    loop {
+        match logger {
+            Logger::Named(name) => return Ok(name.clone()),
+            Logger::Adapter {
+                logger: Value::Ref(id), ..
+            } => match vm.heap.get(*id) {
+                HeapData::Logger(inner) => logger = inner,
+                _ => break,
+            },
+            Logger::Adapter { .. } => break,
+        }
+    }
+    Err(RunError::internal("LoggerAdapter does not wrap a logger"))
+}
+
+/// Returns a level's name: `"INFO"` for 20, or `"Level 5"` for levels without one.
+pub(crate) fn level_name(level: i64) -> Cow<'static, str> {
+    match level {
+        CRITICAL => "CRITICAL".into(),
+        ERROR => "ERROR".into(),
+        WARNING => "WARNING".into(),
+        INFO => "INFO".into(),
+        DEBUG => "DEBUG".into(),
+        NOTSET => "NOTSET".into(),
+        _ => format!("Level {level}").into(),
+    }
+}
+
+/// Looks a level up by name, e.g. `"INFO"` to 20.
+fn level_by_name(name: &str) -> Option<i64> {
+    LEVELS
+        .iter()
+        .find(|(level_name, _)| <&str>::from(*level_name) == name)
+        .map(|(_, level)| *level)
+}
+
+/// Converts a level argument to a number, like CPython's `logging._checkLevel()`:
+/// ints are taken as-is and strings must name a level.
+fn check_level(level: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<i64> {
+    match level {
+        Value::Int(level) => Ok(*level),
+        Value::Bool(level) => Ok(i64::from(*level)),
+        _ => {
+            if let Some(name) = level.as_either_str(vm.heap) {
+                let name = name.as_str(vm.interns);
+                return level_by_name(name).ok_or_else(|| {
+                    SimpleException::new_msg(ExcType::ValueError, format!("Unknown level: {}", StringRepr(name))).into()
+                });
+            }
+            let repr = level.py_repr(vm)?;
+            Err(ExcType::type_error(format!(
+                "Level not an integer or a valid string: {repr}"
+            )))
+        }
+    }
+}
+
+/// Argument shape for `logging.getLogger(name=None)`.
+#[derive(FromArgs)]
+#[from_args(name = "getLogger")]
+struct GetLoggerArgs {
+    #[from_args(default = Value::None)]
+    name: Value,
+}
+
+/// `logging.getLogger(name=None)`: the root logger for an empty name or `"root"`.
+fn get_logger_function(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let GetLoggerArgs { name } = GetLoggerArgs::from_args(args, vm)?;
+    defer_drop!(name, vm);
+    let name = if name.py_bool(vm) {
+        let Some(name) = name.as_either_str(vm.heap) else {
+            return Err(ExcType::type_error("A logger name must be a string"));
+        };
+        name.as_str(vm.interns).to_owned()
+    } else {
+        ROOT.to_owned()
+    };
+    Ok(get_logger(vm, name)?)
+}
+
+/// Argument shape for `logging.basicConfig(**kwargs)`.
+#[derive(FromArgs)]
+#[from_args(name = "basicConfig")]
+struct BasicConfigArgs {
+    #[from_args(varkwargs)]
+    kwargs: KwargsValues,
+}
+
+/// `logging.basicConfig(**kwargs)`: sets the format and root level, once.
+///
+/// Like CPython, does nothing when logging is already configured unless
+/// `force=True`. `stream`, `datefmt`, `filemode`, `encoding` and `errors` are
+/// accepted and ignored, since records always go to the host; `filename` and
+/// `handlers` are not supported.
+fn basic_config(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let BasicConfigArgs { kwargs } = BasicConfigArgs::from_args(args, vm)?;
+    let mut options = Vec::with_capacity(kwargs.len());
+    for (key, value) in kwargs {
+        let key_str = key.py_str(vm).map(Cow::into_owned);
+        key.drop_with_heap(vm);
+        options.push((key_str, value));
+    }
+    let mut names = Vec::with_capacity(options.len());
+    let mut values = Vec::with_capacity(options.len());
+    for (key, value) in options {
+        match key {
+            Ok(key) => {
+                names.push(key);
+                values.push(value);
+            }
+            Err(err) => {
+                value.drop_with_heap(vm);
+                values.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+    }
+    defer_drop!(values, vm);
+
+    let option = |name: &str| {
+        names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| &values[i])
+            .filter(|value| !matches!(value, Value::None))
+    };
+    let force = match option("force") {
+        Some(force) => checked_truth(force, vm)?,
+        None => false,
+    };
+    if vm.logging.format.is_some() && !force {
+        return Ok(Value::None);
+    }
+
+    for unsupported in ["filename", "handlers"] {
+        if option(unsupported).is_some() {
+            return Err(SimpleException::new_msg(
+                ExcType::NotImplementedError,
+                format!("basicConfig() does not support '{unsupported}': records are always sent to the host"),
+            )
+            .into());
+        }
+    }
+    let style = match option("style") {
+        Some(style) => style.py_str(vm)?.into_owned(),
+        None => "%".to_owned(),
+    };
+    match style.as_str() {
+        "%" => {}
+        "{" | "$" => {
+            return Err(SimpleException::new_msg(
+                ExcType::NotImplementedError,
+                "basicConfig() only supports '%' style formats",
+            )
+            .into());
+        }
+        _ => {
+            return Err(SimpleException::new_msg(ExcType::ValueError, "Style must be one of: %,{,$").into());
+        }
+    }
+    let format = match option("format") {
+        Some(format) => format.py_str(vm)?.into_owned(),
+        None => DEFAULT_FORMAT.to_owned(),
+    };
+    let mut fields = format
+        .split("%(")
+        .skip(1)
+        .filter_map(|rest| rest.split_once(')').map(|(field, _)| field));
+    if fields.clone().next().is_none() {
+        return Err(
+            SimpleException::new_msg(ExcType::ValueError, format!("Invalid format '{format}' for '%' style")).into(),
+        );
+    }
+    if let Some(field) = fields.find(|field| UNSUPPORTED_FIELDS.contains(field)) {
+        return Err(SimpleException::new_msg(
+            ExcType::NotImplementedError,
+            format!("log format field '{field}' is not supported"),
+        )
+        .into());
+    }
+    let level = match option("level") {
+        Some(level) => Some(check_level(level, vm)?),
+        None => None,
+    };
+
+    vm.logging.format = Some(format);
+    if let Some(level) = level {
+        vm.logging.levels.insert(ROOT.to_owned(), level);
+    }
+
+    // Checked last, after configuring, as CPython does.
+    const ACCEPTED: &[&str] = &[
+        "force", "filename", "filemode", "handlers", "stream", "datefmt", "style", "format", "level", "encoding",
+        "errors",
+    ];
+    let unrecognised: Vec<&str> = names
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !ACCEPTED.contains(name))
+        .collect();
+    if !unrecognised.is_empty() {
+        return Err(SimpleException::new_msg(
+            ExcType::ValueError,
+            format!("Unrecognised argument(s): {}", unrecognised.join(", ")),
+        )
+        .into());
+    }
+    Ok(Value::None)
+}
+
+/// Argument shape for `logging.disable(level=CRITICAL)`.
+#[derive(FromArgs)]
+#[from_args(name = "disable")]
+struct DisableArgs {
+    #[from_args(default = Value::Int(CRITICAL))]
+    level: Value,
+}
+
+/// `logging.disable(level=CRITICAL)`: drops every record at or below `level`.
+fn disable(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let DisableArgs { level } = DisableArgs::from_args(args, vm)?;
+    defer_drop!(level, vm);
+    vm.logging.disabled = check_level(level, vm)?;
+    Ok(Value::None)
+}
+
+/// `logging.getLevelName(level)`: the name of a level number, or the number of
+/// a level name, or `"Level {level}"` when there is neither.
+fn get_level_name(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let level = args.get_one_arg("getLevelName", vm.heap)?;
+    defer_drop!(level, vm);
+    if let Value::Int(level) = level {
+        return Ok(allocate_string(level_name(*level).into_owned(), vm.heap)?);
+    }
+    if let Some(name) = level.as_either_str(vm.heap)
+        && let Some(level) = level_by_name(name.as_str(vm.interns))
+    {
+        return Ok(Value::Int(level));
+    }
+    let text = level.py_str(vm)?;
+    Ok(allocate_string(format!("Level {text}"), vm.heap)?)
+}
+
+/// Calls a method of a `Logger` or `LoggerAdapter`.
+pub(crate) fn call_method(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    self_id: HeapId,
+    attr: &EitherStr,
+    args: ArgValues,
+) -> RunResult<Value> {
+    let is_adapter = matches!(vm.heap.get(self_id), HeapData::Logger(Logger::Adapter { .. }));
+    let method = attr.static_string();
+    let level = match method {
+        Some(StaticStrings::Debug) => Some(DEBUG),
+        Some(StaticStrings::Info) => Some(INFO),
+        Some(StaticStrings::Warning | StaticStrings::Warn) => Some(WARNING),
+        Some(StaticStrings::Error | StaticStrings::Exception) => Some(ERROR),
+        Some(StaticStrings::Critical) => Some(CRITICAL),
+        Some(StaticStrings::Fatal) if !is_adapter => Some(CRITICAL),
+        _ => None,
+    };
+    if let Some(level) = level {
+        let exception = method == Some(StaticStrings::Exception);
+        let func_name = attr.as_str(vm.interns).to_owned();
+        let call = LogCall::parse(&func_name, level, exception, args, vm)?;
+        log_through(vm, self_id, call)?;
+        return Ok(Value::None);
+    }
+
+    match method {
+        Some(StaticStrings::Log) => {
+            let call = LogCall::parse_log(args, vm)?;
+            log_through(vm, self_id, call)?;
+            Ok(Value::None)
+        }
+        Some(StaticStrings::SetLevel) => {
+            let level = args.get_one_arg("setLevel", vm.heap)?;
+            defer_drop!(level, vm);
+            let level = check_level(level, vm)?;
+            let name = logger_id_name(vm, self_id)?;
+            vm.logging.levels.insert(name, level);
+            Ok(Value::None)
+        }
+        Some(StaticStrings::GetEffectiveLevel) => {
+            args.check_zero_args("getEffectiveLevel", vm.heap)?;
+            let name = logger_id_name(vm, self_id)?;
+            Ok(Value::Int(vm.logging.effective_level(&name)))
+        }
+        Some(StaticStrings::IsEnabledFor) => {
+            let level = args.get_one_arg("isEnabledFor", vm.heap)?;
+            defer_drop!(level, vm);
+            let Value::Int(level) = level else {
+                return Err(ExcType::type_error("level must be an integer"));
+            };
+            let name = logger_id_name(vm, self_id)?;
+            Ok(Value::Bool(vm.logging.is_enabled_for(&name, *level)))
+        }
+        Some(StaticStrings::GetChild) if !is_adapter => {
+            let suffix = args.get_one_arg("getChild", vm.heap)?;
+            defer_drop!(suffix, vm);
+            let Some(suffix) = suffix.as_either_str(vm.heap) else {
+                return Err(ExcType::type_error("getChild() argument must be str"));
+            };
+            let suffix = suffix.as_str(vm.interns).to_owned();
+            let name = logger_id_name(vm, self_id)?;
+            let child = if name == ROOT {
+                suffix
+            } else {
+                format!("{name}.{suffix}")
+            };
+            Ok(get_logger(vm, child)?)
+        }
+        _ => {
+            args.drop_with_heap(vm);
+            let type_name = if is_adapter { Type::LoggerAdapter } else { Type::Logger };
+            Err(ExcType::attribute_error(type_name, attr.as_str(vm.interns)))
+        }
+    }
+}
+
+/// Returns the name of the logger the heap `Logger` at `id` logs through.
+fn logger_id_name(vm: &VM<'_, impl ResourceTracker>, id: HeapId) -> RunResult<String> {
+    match vm.heap.get(id) {
+        HeapData::Logger(logger) => logger_name(logger, vm),
+        _ => Err(RunError::internal("expected a logger")),
+    }
+}
+
+/// Argument shape for the level methods, e.g.
+/// `info(msg, *args, exc_info=None, stack_info=False, stacklevel=1, extra=None)`.
+///
+/// `msg` is optional here so the missing-argument error can name the method.
+#[derive(FromArgs)]
+#[from_args(name = "log")]
+struct LevelArgs {
+    #[from_args(default)]
+    msg: Option<Value>,
+    #[from_args(varargs)]
+    args: Vec<Value>,
+    #[from_args(default)]
+    exc_info: Option<Value>,
+    #[from_args(default = LaxBool::new(false))]
+    stack_info: LaxBool,
+    #[from_args(default = 1)]
+    stacklevel: i64,
+    #[from_args(default = Value::None)]
+    extra: Value,
+}
+
+/// Argument shape for `log(level, msg, *args, exc_info=None, stack_info=False, stacklevel=1, extra=None)`.
+#[derive(FromArgs)]
+#[from_args(name = "log")]
+struct LogArgs {
+    level: Value,
+    msg: Value,
+    #[from_args(varargs)]
+    args: Vec<Value>,
+    #[from_args(default = Value::None)]
+    exc_info: Value,
+    #[from_args(default = LaxBool::new(false))]
+    stack_info: LaxBool,
+    #[from_args(default = 1)]
+    stacklevel: i64,
+    #[from_args(default = Value::None)]
+    extra: Value,
+}
+
+/// The arguments of one logging call.
+struct LogCall {
+    level: i64,
+    msg: Value,
+    args: Vec<Value>,
+    /// `exc_info`: an exception, an `(type, value, traceback)` tuple, or a flag
+    /// asking for the exception currently being handled.
+    exc_info: Value,
+    stacklevel: i64,
+    extra: Value,
+}
+
+impl DropWithHeap for LogCall {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        self.msg.drop_with_heap(heap);
+        self.args.drop_with_heap(heap);
+        self.exc_info.drop_with_heap(heap);
+        self.extra.drop_with_heap(heap);
+    }
+}
+
+impl LogCall {
+    /// Parses the arguments of a level method such as `info()`.
+    ///
+    /// `exception()` logs at `ERROR` with `exc_info` defaulting to true.
+    fn parse(
+        func_name: &str,
+        level: i64,
+        exception: bool,
+        args: ArgValues,
+        vm: &mut VM<'_, impl ResourceTracker>,
+    ) -> RunResult<Self> {
+        let LevelArgs {
+            msg,
+            args,
+            exc_info,
+            stack_info: _,
+            stacklevel,
+            extra,
+        } = LevelArgs::from_args(args, vm)?;
+        let Some(msg) = msg else {
+            args.drop_with_heap(vm);
+            exc_info.drop_with_heap(vm);
+            extra.drop_with_heap(vm);
+            return Err(ExcType::type_error_missing_positional_with_names(func_name, &["msg"]));
+        };
+        Ok(Self {
+            level,
+            msg,
+            args,
+            exc_info: exc_info.unwrap_or(Value::Bool(exception)),
+            stacklevel,
+            extra,
+        })
+    }
+
+    /// Parses the arguments of `log(level, msg, *args, ...)`.
+    fn parse_log(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Self> {
+        let LogArgs {
+            level,
+            msg,
+            args,
+            exc_info,
+            stack_info: _,
+            stacklevel,
+            extra,
+        } = LogArgs::from_args(args, vm)?;
+        let call = Self {
+            level: NOTSET,
+            msg,
+            args,
+            exc_info,
+            stacklevel,
+            extra,
+        };
+        let level = match level {
+            Value::Int(level) => level,
+            Value::Bool(level) => i64::from(level),
+            other => {
+                other.drop_with_heap(vm);
+                call.drop_with_heap(vm);
+                return Err(ExcType::type_error("level must be an integer"));
+            }
+        };
+        Ok(Self { level, ..call })
+    }
+}
+
+/// Logs `call` through the `Logger` or `LoggerAdapter` at `id`.
+///
+/// Each adapter applies its `extra`, outermost first, as `LoggerAdapter.process()`
+/// does: it replaces the call's `extra`, or with `merge_extra=True` is merged
+/// under it.
+fn log_through(vm: &mut VM<'_, impl ResourceTracker>, id: HeapId, call: LogCall) -> RunResult<()> {
+    let mut call = call;
+    let mut id = id;
+    let name = loop {
+        let next = match vm.heap.get(id) {
+            HeapData::Logger(Logger::Named(name)) => break name.clone(),
+            HeapData::Logger(Logger::Adapter {
+                logger: Value::Ref(inner),
+                extra,
+                merge_extra,
+            }) => Some((*inner, extra.clone_with_heap(vm), *merge_extra)),
+            _ => None,
+        };
+        let Some((inner, adapter_extra, merge_extra)) = next else {
+            call.drop_with_heap(vm);
+            return Err(RunError::internal("LoggerAdapter does not wrap a logger"));
+        };
+        if merge_extra && !matches!(call.extra, Value::None) {
+            let merged = merge_dicts(&adapter_extra, &call.extra, vm);
+            adapter_extra.drop_with_heap(vm);
+            match merged {
+                Ok(merged) => mem::replace(&mut call.extra, merged).drop_with_heap(vm),
+                Err(err) => {
+                    call.drop_with_heap(vm);
+                    return Err(err);
+                }
+            }
+        } else {
+            mem::replace(&mut call.extra, adapter_extra).drop_with_heap(vm);
+        }
+        id = inner;
+    };
+    log(vm, &name, call)
+}
+
+/// Returns a new dict holding the items of `base` updated with those of `overrides`.
+fn merge_dicts(base: &Value, overrides: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let mut pairs = dict_pairs(base, vm)?;
+    match dict_pairs(overrides, vm) {
+        Ok(more) => pairs.extend(more),
+        Err(err) => {
+            pairs.drop_with_heap(vm);
+            return Err(err);
+        }
+    }
+    let dict = Dict::from_pairs(pairs, vm)?;
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Dict(dict))?))
+}
+
+/// Returns new references to the items of an `extra` mapping, which must be a
+/// dict or `None`.
+fn dict_pairs(extra: &Value, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Vec<(Value, Value)>> {
+    match extra {
+        Value::None => Ok(Vec::new()),
+        Value::Ref(id) if let HeapData::Dict(dict) = vm.heap.get(*id) => Ok(dict
+            .iter()
+            .map(|(key, value)| (key.clone_with_heap(vm), value.clone_with_heap(vm)))
+            .collect()),
+        _ => Err(ExcType::type_error(format!(
+            "'extra' must be a dict, not '{}'",
+            extra.py_type(vm)
+        ))),
+    }
+}
+
+/// Emits a record for `call` from the named logger, if its level passes the
+/// logger's effective level and `logging.disable()`.
+fn log(vm: &mut VM<'_, impl ResourceTracker>, name: &str, call: LogCall) -> RunResult<()> {
+    defer_drop!(call, vm);
+    if !vm.logging.is_enabled_for(name, call.level) {
+        return Ok(());
+    }
+
+    let msg = call.msg.py_str(vm)?.into_owned();
+    let message = match call.args.as_slice() {
+        [] => msg.clone(),
+        // A single non-empty dict is used for `%(key)s` lookups, as in CPython.
+        [mapping @ Value::Ref(id)] if matches!(vm.heap.get(*id), HeapData::Dict(dict) if !dict.is_empty()) => {
+            format_percent(&msg, PercentArgs::Mapping(mapping), vm)?
+        }
+        args => format_percent(&msg, PercentArgs::Positional(args), vm)?,
+    };
+
+    // Attribute the record to the caller `stacklevel` frames up, or to the
+    // outermost frame when that walks past the top of the stack.
+    let max_depth = usize::try_from(call.stacklevel.max(1) - 1).unwrap_or(usize::MAX);
+    let mut depth = 0;
+    while depth < max_depth && vm.caller_position(depth + 1).is_some() {
+        depth += 1;
+    }
+    let (filename, lineno, func_name) = match vm.caller_position(depth) {
+        Some(position) => (
+            vm.interns.get_str(position.filename).to_owned(),
+            vm.interns.line_number(position),
+            vm.caller_frame_name(depth)
+                .map_or("<module>", |id| vm.interns.get_str(id))
+                .to_owned(),
+        ),
+        None => ("(unknown file)".to_owned(), 0, "(unknown function)".to_owned()),
+    };
+
+    let exception = exc_info_exception(&call.exc_info, vm)?;
+
+    let extra = dict_pairs(&call.extra, vm)?;
+    defer_drop!(extra, vm);
+    let mut extra_names = Vec::with_capacity(extra.len());
+    for (key, _) in extra {
+        let key = key.py_str(vm)?.into_owned();
+        if RECORD_ATTRIBUTES.contains(&key.as_str()) {
+            let key = allocate_string(
+                format!("Attempt to overwrite {} in LogRecord", StringRepr(&key)),
+                vm.heap,
+            )?;
+            defer_drop!(key, vm);
+            return Err(ExcType::key_error(key, vm));
+        }
+        extra_names.push(key);
+    }
+
+    let level_name = level_name(call.level).into_owned();
+    let formatted = match vm.logging.format.clone() {
+        None => message.clone(),
+        Some(format) => {
+            let module = filename.strip_suffix(".py").unwrap_or(&filename);
+            let mut fields = vec![
+                ("name", allocate_string(name.to_owned(), vm.heap)?),
+                ("msg", call.msg.clone_with_heap(vm)),
+                ("levelname", allocate_string(level_name.clone(), vm.heap)?),
+                ("levelno", Value::Int(call.level)),
+                ("pathname", allocate_string(filename.clone(), vm.heap)?),
+                ("filename", allocate_string(filename.clone(), vm.heap)?),
+                ("module", allocate_string(module.to_owned(), vm.heap)?),
+                ("lineno", Value::Int(i64::from(lineno))),
+                ("funcName", allocate_string(func_name.clone(), vm.heap)?),
+                ("message", allocate_string(message.clone(), vm.heap)?),
+            ]
+            .into_iter()
+            .map(|(key, value)| Ok((allocate_string(key.to_owned(), vm.heap)?, value)))
+            .collect::<Result<Vec<_>, ResourceError>>()?;
+            fields.extend(
+                extra
+                    .iter()
+                    .map(|(key, value)| (key.clone_with_heap(vm), value.clone_with_heap(vm))),
+            );
+            let record = Dict::from_pairs(fields, vm)?;
+            let record = Value::Ref(vm.heap.allocate(HeapData::Dict(record))?);
+            defer_drop!(record, vm);
+            format_percent(&format, PercentArgs::Mapping(record), vm)?
+        }
+    };
+
+    let args = call
+        .args
+        .iter()
+        .map(|arg| MontyObject::new(arg.clone_with_heap(vm), vm))
+        .collect();
+    let extra = extra_names
+        .into_iter()
+        .zip(extra.iter())
+        .map(|(key, (_, value))| (key, MontyObject::new(value.clone_with_heap(vm), vm)))
+        .collect();
+    let record = MontyLogRecord {
+        name: name.to_owned(),
+        levelno: call.level,
+        level_name,
+        msg,
+        args,
+        message,
+        filename,
+        lineno,
+        func_name,
+        extra,
+        exception,
+        formatted,
+    };
+    vm.print_writer.tracked_log(&record, vm.heap.tracker())?;
+    Ok(())
+}
+
+/// Resolves `exc_info` to the exception to attach to a record, with the
+/// traceback it was caught with.
+///
+/// An exception instance or `(type, value, traceback)` tuple names the
+/// exception directly; any other true value means the exception currently
+/// being handled, if there is one.
+fn exc_info_exception(exc_info: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Option<MontyException>> {
+    let wanted = checked_truth(exc_info, vm)?;
+    let exc = match exc_info {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Exception(_) => Some(exc_info),
+            HeapData::Tuple(tuple) => tuple.as_slice().get(1),
+            _ => None,
+        },
+        _ => None,
+    };
+    let exc = match exc {
+        Some(exc) => Some(exc),
+        None if wanted => vm.handled_exception(),
+        None => None,
+    };
+    let Some(Value::Ref(id)) = exc else {
+        return Ok(None);
+    };
+    let HeapData::Exception(exc) = vm.heap.get(*id) else {
+        return Ok(None);
+    };
+    let raise = ExceptionRaise {
+        exc: exc.clone(),
+        frame: exc.traceback().cloned(),
+        hide_caret: false,
+    };
+    Ok(Some(raise.into_python_exception(vm.interns, |filename| {
+        vm.interns.source(filename)
+    })))
+}
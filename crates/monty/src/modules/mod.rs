@@ -17,16 +17,23 @@ use crate::{
 };
 
 pub(crate) mod asyncio;
+pub(crate) mod cmath;
+pub(crate) mod contextlib;
+pub(crate) mod dataclasses;
 pub(crate) mod datetime;
 #[cfg(feature = "test-hooks")]
 pub(crate) mod gc;
+pub(crate) mod io;
 pub(crate) mod json;
+pub(crate) mod logging;
 pub(crate) mod math;
 pub(crate) mod os;
 pub(crate) mod pathlib;
 pub(crate) mod re;
 pub(crate) mod sys;
 pub(crate) mod typing;
+pub(crate) mod unicodedata;
+pub(crate) mod warnings;
 
 /// Built-in modules that can be imported.
 #[repr(u8)]
@@ -50,6 +57,20 @@ pub(crate) enum StandardLib {
     Re,
     /// The `datetime` module providing date and time types.
     Datetime,
+    /// The `dataclasses` module for declaring dataclasses in sandboxed code.
+    Dataclasses,
+    /// The `contextlib` module providing `suppress`, `ExitStack` and other context managers.
+    Contextlib,
+    /// The `io` module providing the in-memory `StringIO` and `BytesIO` files.
+    Io,
+    /// The `cmath` module providing mathematical functions for complex numbers.
+    Cmath,
+    /// The `unicodedata` module providing access to the Unicode character database.
+    Unicodedata,
+    /// The `warnings` module providing `warn()`, warning filters and `catch_warnings`.
+    Warnings,
+    /// The `logging` module delivering structured log records to the host.
+    Logging,
     /// The `gc` module exposing a single `collect()` for tests. Only present
     /// under the `test-hooks` feature so production sandboxes never see it.
     ///
@@ -74,6 +95,13 @@ impl StandardLib {
             StaticStrings::Json => Some(Self::Json),
             StaticStrings::Re => Some(Self::Re),
             StaticStrings::Datetime => Some(Self::Datetime),
+            StaticStrings::Dataclasses => Some(Self::Dataclasses),
+            StaticStrings::Contextlib => Some(Self::Contextlib),
+            StaticStrings::Io => Some(Self::Io),
+            StaticStrings::Cmath => Some(Self::Cmath),
+            StaticStrings::Unicodedata => Some(Self::Unicodedata),
+            StaticStrings::Warnings => Some(Self::Warnings),
+            StaticStrings::Logging => Some(Self::Logging),
             #[cfg(feature = "test-hooks")]
             StaticStrings::Gc => Some(Self::Gc),
             _ => None,
@@ -98,6 +126,13 @@ impl StandardLib {
             Self::Json => json::create_module(vm),
             Self::Re => re::create_module(vm),
             Self::Datetime => datetime::create_module(vm),
+            Self::Dataclasses => dataclasses::create_module(vm),
+            Self::Contextlib => contextlib::create_module(vm),
+            Self::Io => io::create_module(vm),
+            Self::Cmath => cmath::create_module(vm),
+            Self::Unicodedata => unicodedata::create_module(vm),
+            Self::Warnings => warnings::create_module(vm),
+            Self::Logging => logging::create_module(vm),
             #[cfg(feature = "test-hooks")]
             Self::Gc => gc::create_module(vm),
         }
@@ -108,10 +143,16 @@ impl StandardLib {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub(crate) enum ModuleFunctions {
     Asyncio(asyncio::AsyncioFunctions),
+    Cmath(cmath::CmathFunctions),
+    Dataclasses(dataclasses::DataclassesFunctions),
     Json(json::JsonFunctions),
     Math(math::MathFunctions),
     Os(os::OsFunctions),
     Re(re::ReFunctions),
+    Typing(typing::TypingFunctions),
+    Unicodedata(unicodedata::UnicodedataFunctions),
+    Warnings(warnings::WarningsFunctions),
+    Logging(logging::LoggingFunctions),
     /// `gc` module functions — only present under the `test-hooks` feature.
     /// See [`gc`] for why we keep this gated rather than always-on.
     #[cfg(feature = "test-hooks")]
@@ -128,10 +169,16 @@ impl fmt::Display for ModuleFunctions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Asyncio(func) => write!(f, "{func}"),
+            Self::Cmath(func) => write!(f, "{func}"),
+            Self::Dataclasses(func) => write!(f, "{func}"),
             Self::Json(func) => write!(f, "{func}"),
             Self::Math(func) => write!(f, "{func}"),
             Self::Os(func) => write!(f, "{func}"),
             Self::Re(func) => write!(f, "{func}"),
+            Self::Typing(func) => write!(f, "{func}"),
+            Self::Unicodedata(func) => write!(f, "{func}"),
+            Self::Warnings(func) => write!(f, "{func}"),
+            Self::Logging(func) => write!(f, "{func}"),
             #[cfg(feature = "test-hooks")]
             Self::Gc(func) => write!(f, "{func}"),
             #[cfg(feature = "test-hooks")]
@@ -148,10 +195,16 @@ impl ModuleFunctions {
     pub fn call(self, vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<CallResult> {
         match self {
             Self::Asyncio(functions) => asyncio::call(vm, functions, args),
+            Self::Cmath(functions) => cmath::call(vm, functions, args).map(CallResult::Value),
+            Self::Dataclasses(functions) => dataclasses::call(vm, functions, args).map(CallResult::Value),
             Self::Json(functions) => json::call(vm, functions, args).map(CallResult::Value),
             Self::Math(functions) => math::call(vm, functions, args).map(CallResult::Value),
             Self::Os(functions) => os::call(vm, functions, args),
             Self::Re(functions) => re::call(vm, functions, args),
+            Self::Typing(functions) => typing::call(vm, functions, args).map(CallResult::Value),
+            Self::Unicodedata(functions) => unicodedata::call(vm, functions, args).map(CallResult::Value),
+            Self::Warnings(functions) => warnings::call(vm, functions, args).map(CallResult::Value),
+            Self::Logging(functions) => logging::call(vm, functions, args).map(CallResult::Value),
             #[cfg(feature = "test-hooks")]
             Self::Gc(functions) => gc::call(vm, functions, args).map(CallResult::Value),
             #[cfg(feature = "test-hooks")]
@@ -36,7 +36,11 @@ use crate::{
     intern::StaticStrings,
     modules::ModuleFunctions,
     resource::{ResourceError, ResourceTracker},
-    types::{Module, PyTrait, RePattern, Type, re_pattern::value_to_str, str::allocate_string},
+    types::{
+        Module, PyTrait, RePattern, Type,
+        re_pattern::{results_list, value_to_str},
+        str::allocate_string,
+    },
     value::Value,
 };
 
@@ -207,7 +211,7 @@ pub(super) fn call(
 /// The pattern is compiled once and stored, avoiding recompilation overhead.
 fn call_compile(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let (pattern_val, flags) = extract_pattern_and_flags(args, "re.compile", vm)?;
-    let compiled = RePattern::compile(pattern_val, flags)?;
+    let compiled = RePattern::compile(pattern_val, flags, vm.heap.tracker().regex_backtrack_limit())?;
     Ok(Value::Ref(vm.heap.allocate(HeapData::RePattern(Box::new(compiled)))?))
 }
 
@@ -217,7 +221,7 @@ fn call_compile(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunRe
 /// object on success, or `None` if no position in the string matches.
 fn call_search(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let (pattern, text, flags) = extract_pattern_string_flags(args, "re.search", vm)?;
-    let compiled = RePattern::compile(pattern, flags)?;
+    let compiled = RePattern::compile(pattern, flags, vm.heap.tracker().regex_backtrack_limit())?;
     compiled.search(&text, vm.heap)
 }
 
@@ -227,7 +231,7 @@ fn call_search(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunRes
 /// object if the pattern matches at position 0, or `None` otherwise.
 fn call_match(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let (pattern, text, flags) = extract_pattern_string_flags(args, "re.match", vm)?;
-    let compiled = RePattern::compile(pattern, flags)?;
+    let compiled = RePattern::compile(pattern, flags, vm.heap.tracker().regex_backtrack_limit())?;
     compiled.match_start(&text, vm.heap)
 }
 
@@ -237,7 +241,7 @@ fn call_match(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResu
 /// object if the pattern matches the whole string, or `None` otherwise.
 fn call_fullmatch(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let (pattern, text, flags) = extract_pattern_string_flags(args, "re.fullmatch", vm)?;
-    let compiled = RePattern::compile(pattern, flags)?;
+    let compiled = RePattern::compile(pattern, flags, vm.heap.tracker().regex_backtrack_limit())?;
     compiled.fullmatch(&text, vm.heap)
 }
 
@@ -247,8 +251,10 @@ fn call_fullmatch(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> Run
 /// strings or tuples depending on the number of capture groups (matching CPython semantics).
 fn call_findall(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let (pattern, text, flags) = extract_pattern_string_flags(args, "re.findall", vm)?;
-    let compiled = RePattern::compile(pattern, flags)?;
-    compiled.findall(&text, vm.heap)
+    let compiled = RePattern::compile(pattern, flags, vm.heap.tracker().regex_backtrack_limit())?;
+    let mut results = Vec::new();
+    let scan = compiled.findall(&text, vm.heap, &mut results);
+    results_list(results, scan, vm)
 }
 
 /// `re.sub(pattern, repl, string, count=0, flags=0)` — substitute matches with a replacement.
@@ -309,7 +315,7 @@ fn call_sub(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult
     let repl = value_to_str(repl_val, vm)?.into_owned();
     let text = value_to_str(string_val, vm)?.into_owned();
 
-    let compiled = RePattern::compile(pattern, flags)?;
+    let compiled = RePattern::compile(pattern, flags, vm.heap.tracker().regex_backtrack_limit())?;
     compiled.sub(&repl, &text, count, vm.heap)
 }
 
@@ -334,7 +340,7 @@ fn call_split(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResu
     let pattern = value_to_str(pattern_val, vm)?.into_owned();
     let text = value_to_str(string_val, vm)?.into_owned();
 
-    let compiled = RePattern::compile(pattern, flags)?;
+    let compiled = RePattern::compile(pattern, flags, vm.heap.tracker().regex_backtrack_limit())?;
     compiled.split(&text, maxsplit, vm.heap)
 }
 
@@ -382,8 +388,10 @@ struct ReSplitArgs {
 /// over the returned list automatically.
 fn call_finditer(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
     let (pattern, text, flags) = extract_pattern_string_flags(args, "re.finditer", vm)?;
-    let compiled = RePattern::compile(pattern, flags)?;
-    compiled.finditer(&text, vm.heap)
+    let compiled = RePattern::compile(pattern, flags, vm.heap.tracker().regex_backtrack_limit())?;
+    let mut results = Vec::new();
+    let scan = compiled.finditer(&text, vm.heap, &mut results);
+    results_list(results, scan, vm)
 }
 
 /// `re.escape(pattern)` — escape special regex characters in a string.
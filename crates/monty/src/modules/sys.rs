@@ -4,8 +4,8 @@
 //! - `version`: Python version string (e.g., "3.14.0 (Monty)")
 //! - `version_info`: Named tuple (3, 14, 0, 'final', 0)
 //! - `platform`: Platform identifier ("monty")
-//! - `stdout`: Marker for standard output (no real functionality)
-//! - `stderr`: Marker for standard error (no real functionality)
+//! - `stdout`: Marker for standard output, with `write`, `writelines` and `flush`
+//! - `stderr`: Marker for standard error, with the same methods
 //!
 //! Under the `test-hooks` feature one callable is also exposed:
 //! - `setrecursionlimit(n)`: tighten the active recursion ceiling so fixtures
@@ -13,17 +13,17 @@
 //!   *lowering* the host-configured ceiling — see [`SysFunctions`].
 
 #[cfg(feature = "test-hooks")]
+use crate::modules::ModuleFunctions;
 use crate::{
     args::ArgValues,
+    bytecode::{CallResult, VM},
+    defer_drop, defer_drop_mut,
     exception_private::{ExcType, RunResult},
-    modules::ModuleFunctions,
-};
-use crate::{
-    bytecode::VM,
-    heap::{HeapData, HeapId},
-    intern::StaticStrings,
+    heap::{DropWithHeap, HeapData, HeapId},
+    intern::{StaticStrings, StringId},
+    io::PrintStream,
     resource::{ResourceError, ResourceTracker},
-    types::{Module, NamedTuple},
+    types::{Module, MontyIter, NamedTuple, PyTrait, Type},
     value::{Marker, Value},
 };
 
@@ -54,7 +54,7 @@ pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, Re
     // sys.platform
     module.set_attr(StaticStrings::Platform, StaticStrings::Monty.into(), vm);
 
-    // sys.stdout / sys.stderr - markers for standard output/error
+    // sys.stdout / sys.stderr - markers for standard output/error, see `call_stream_method`
     module.set_attr(StaticStrings::Stdout, Value::Marker(Marker(StaticStrings::Stdout)), vm);
     module.set_attr(StaticStrings::Stderr, Value::Marker(Marker(StaticStrings::Stderr)), vm);
 
@@ -93,6 +93,71 @@ pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, Re
     vm.heap.allocate(HeapData::Module(module))
 }
 
+/// Calls a method of `sys.stdout` or `sys.stderr`.
+///
+/// Inside `contextlib.redirect_stdout`, `sys.stdout` stands for the redirect
+/// target (CPython rebinds it), so stdout calls are forwarded to the target.
+/// Otherwise `write()` and `writelines()` send their text to the host's print
+/// writer on `stream`, and `flush()` does nothing since nothing is buffered.
+pub(crate) fn call_stream_method(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    stream: PrintStream,
+    name_id: StringId,
+    args: ArgValues,
+) -> RunResult<CallResult> {
+    if stream == PrintStream::Stdout
+        && let Some(target) = vm.stdout_redirects.last()
+    {
+        let target = target.clone_with_heap(vm);
+        return vm.call_attr(target, name_id, args);
+    }
+
+    let value = match StaticStrings::from_string_id(name_id) {
+        Some(StaticStrings::Write) => {
+            let text = args.get_one_arg("write", vm.heap)?;
+            defer_drop!(text, vm);
+            let text = stream_text(text, vm)?;
+            let len = text.chars().count();
+            vm.print_writer.tracked_write(stream, text.into(), vm.heap.tracker())?;
+            Value::Int(i64::try_from(len).map_err(|_| ExcType::overflow_c_ssize_t())?)
+        }
+        Some(StaticStrings::Writelines) => {
+            let lines = args.get_one_arg("writelines", vm.heap)?;
+            let iter = MontyIter::new(lines, vm)?;
+            defer_drop_mut!(iter, vm);
+            while let Some(line) = iter.for_next(vm)? {
+                defer_drop!(line, vm);
+                let text = stream_text(line, vm)?;
+                vm.print_writer.tracked_write(stream, text.into(), vm.heap.tracker())?;
+            }
+            Value::None
+        }
+        Some(StaticStrings::Flush) => {
+            args.check_zero_args("flush", vm.heap)?;
+            Value::None
+        }
+        _ => {
+            args.drop_with_heap(vm);
+            return Err(ExcType::attribute_error(
+                Type::TextIOWrapper,
+                vm.interns.get_str(name_id),
+            ));
+        }
+    };
+    Ok(CallResult::Value(value))
+}
+
+/// Returns the text passed to `sys.stdout.write()` and friends, which must be a `str`.
+fn stream_text(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> RunResult<String> {
+    match value.as_either_str(vm.heap) {
+        Some(text) => Ok(text.as_str(vm.interns).to_owned()),
+        None => Err(ExcType::type_error(format!(
+            "write() argument must be str, not {}",
+            value.py_type(vm)
+        ))),
+    }
+}
+
 /// Dispatches a `sys` module function call.
 ///
 /// Only present under the `test-hooks` feature — production builds register
@@ -2,20 +2,60 @@
 //!
 //! Provides a minimal implementation of Python's `typing` module with:
 //! - `TYPE_CHECKING`: Always False (used for conditional imports)
-//! - Common type hints as `Marker` values (Any, Optional, List, Dict, etc.)
+//! - Common type hints as `Marker` values (Any, Optional, List, Dict, etc.);
+//!   subscripting them builds a [`GenericAlias`](crate::types::GenericAlias)
+//! - Runtime helpers: `cast`, `assert_type`, `assert_never`, `overload`, `final`,
+//!   `get_origin`, `get_args`, `get_type_hints`, `NewType` and `TypedDict`
 //!
-//! These markers exist so code that imports typing constructs works correctly,
-//! though Monty doesn't perform static type checking.
+//! Like CPython, none of these validate anything at runtime: Monty doesn't perform
+//! static type checking, but code can introspect annotations.
 
 use crate::{
-    bytecode::VM,
-    heap::{HeapData, HeapId},
+    args::{ArgValues, FromArgs},
+    builtins::Builtins,
+    bytecode::{CallResult, VM},
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    function::annotations_of,
+    heap::{DropWithHeap, HeapData, HeapId},
     intern::StaticStrings,
+    modules::ModuleFunctions,
     resource::{ResourceError, ResourceTracker},
-    types::Module,
+    types::{
+        Dict, List, Module, NewType, PyTrait, Type, TypedDict, allocate_tuple, host_handle::checked_truth,
+        tuple::TupleVec, typing_forms::copy_dict,
+    },
     value::{Marker, Value},
 };
 
+/// Typing module functions — each variant corresponds to a Python-visible function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum TypingFunctions {
+    /// `typing.cast(typ, val)` — returns `val` unchanged.
+    Cast,
+    /// `typing.assert_type(val, typ)` — returns `val` unchanged.
+    AssertType,
+    /// `typing.assert_never(arg)` — always raises `AssertionError`.
+    AssertNever,
+    /// `typing.overload(func)` — returns `func` unchanged.
+    Overload,
+    /// `typing.final(func)` — returns `func` unchanged.
+    Final,
+    /// `typing.get_origin(tp)` — the unsubscripted form of a generic alias.
+    GetOrigin,
+    /// `typing.get_args(tp)` — the type arguments of a generic alias.
+    GetArgs,
+    /// `typing.get_type_hints(obj, globalns=None, localns=None, include_extras=False)`.
+    GetTypeHints,
+    /// `typing.NewType(name, tp)` — creates a distinct type for type checkers.
+    #[strum(serialize = "NewType")]
+    NewType,
+    /// `typing.TypedDict(name, fields, *, total=True)` — the functional `TypedDict` form.
+    #[strum(serialize = "TypedDict")]
+    TypedDict,
+}
+
 /// Creates the `typing` module and allocates it on the heap.
 ///
 /// Returns a HeapId pointing to the newly allocated module.
@@ -34,6 +74,11 @@ pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, Re
         module.set_attr(*ss, Value::Marker(Marker(*ss)), vm);
     }
 
+    // Runtime helper functions
+    for (ss, function) in FUNCTION_ATTRS {
+        module.set_attr(*ss, Value::ModuleFunction(ModuleFunctions::Typing(*function)), vm);
+    }
+
     vm.heap.allocate(HeapData::Module(module))
 }
 
@@ -68,3 +113,267 @@ const MARKER_ATTRS: &[StaticStrings] = &[
     StaticStrings::Never,
     StaticStrings::NoReturn,
 ];
+
+/// Typing functions exported by this module, keyed by attribute name.
+const FUNCTION_ATTRS: &[(StaticStrings, TypingFunctions)] = &[
+    (StaticStrings::Cast, TypingFunctions::Cast),
+    (StaticStrings::AssertType, TypingFunctions::AssertType),
+    (StaticStrings::AssertNever, TypingFunctions::AssertNever),
+    (StaticStrings::Overload, TypingFunctions::Overload),
+    (StaticStrings::Final, TypingFunctions::Final),
+    (StaticStrings::GetOrigin, TypingFunctions::GetOrigin),
+    (StaticStrings::GetArgs, TypingFunctions::GetArgs),
+    (StaticStrings::GetTypeHints, TypingFunctions::GetTypeHints),
+    (StaticStrings::NewType, TypingFunctions::NewType),
+    (StaticStrings::TypedDict, TypingFunctions::TypedDict),
+];
+
+/// Dispatches a call to a `typing` module function.
+pub(super) fn call(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    function: TypingFunctions,
+    args: ArgValues,
+) -> RunResult<Value> {
+    match function {
+        TypingFunctions::Cast => {
+            let (typ, val) = args.get_two_args("cast", vm.heap)?;
+            typ.drop_with_heap(vm);
+            Ok(val)
+        }
+        TypingFunctions::AssertType => {
+            let (val, typ) = args.get_two_args("assert_type", vm.heap)?;
+            typ.drop_with_heap(vm);
+            Ok(val)
+        }
+        TypingFunctions::AssertNever => {
+            let arg = args.get_one_arg("assert_never", vm.heap)?;
+            defer_drop!(arg, vm);
+            let arg_repr = arg.py_repr(vm)?;
+            Err(SimpleException::new_msg(
+                ExcType::AssertionError,
+                format!("Expected code to be unreachable, but got: {arg_repr}"),
+            )
+            .into())
+        }
+        TypingFunctions::Overload => args.get_one_arg("overload", vm.heap),
+        TypingFunctions::Final => args.get_one_arg("final", vm.heap),
+        TypingFunctions::GetOrigin => {
+            let tp = args.get_one_arg("get_origin", vm.heap)?;
+            defer_drop!(tp, vm);
+            Ok(get_origin(tp, vm))
+        }
+        TypingFunctions::GetArgs => {
+            let tp = args.get_one_arg("get_args", vm.heap)?;
+            defer_drop!(tp, vm);
+            get_args(tp, vm)
+        }
+        TypingFunctions::GetTypeHints => get_type_hints(vm, args),
+        TypingFunctions::NewType => new_type(vm, args),
+        TypingFunctions::TypedDict => typed_dict(vm, args),
+    }
+}
+
+/// `typing.get_origin(tp)`.
+///
+/// Returns the builtin type for `list[int]` / `typing.List[int]` (and the bare
+/// `typing.List`), the typing form for other aliases (`Union`, `Literal`,
+/// `Annotated`, ...), and `None` for anything that isn't a generic.
+fn get_origin(tp: &Value, vm: &VM<'_, impl ResourceTracker>) -> Value {
+    match tp {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::GenericAlias(alias) => match alias.form() {
+                Some(marker @ Marker(StaticStrings::Annotated)) => Value::Marker(marker),
+                _ => alias.origin().clone_with_heap(vm),
+            },
+            _ => Value::None,
+        },
+        Value::Marker(marker) => match marker.0 {
+            StaticStrings::ListType => Value::Builtin(Builtins::Type(Type::List)),
+            StaticStrings::DictType => Value::Builtin(Builtins::Type(Type::Dict)),
+            StaticStrings::TupleType => Value::Builtin(Builtins::Type(Type::Tuple)),
+            StaticStrings::SetType => Value::Builtin(Builtins::Type(Type::Set)),
+            StaticStrings::FrozenSet => Value::Builtin(Builtins::Type(Type::FrozenSet)),
+            StaticStrings::Type => Value::Builtin(Builtins::Type(Type::Type)),
+            StaticStrings::Generic => Value::Marker(*marker),
+            _ => Value::None,
+        },
+        _ => Value::None,
+    }
+}
+
+/// `typing.get_args(tp)`.
+///
+/// `Callable[[a, b], r]` is reported as `([a, b], r)` and `Annotated[T, m]` as
+/// `(T, m)`; anything that isn't a generic alias gives `()`.
+fn get_args(tp: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let Value::Ref(id) = tp else {
+        return allocate_tuple(TupleVec::new(), vm.heap).map_err(Into::into);
+    };
+    let HeapData::GenericAlias(alias) = vm.heap.get(*id) else {
+        return allocate_tuple(TupleVec::new(), vm.heap).map_err(Into::into);
+    };
+    let has_callable_params = alias.has_callable_params();
+    let args: Vec<Value> = alias.args().iter().map(|v| v.clone_with_heap(vm)).collect();
+    let items: TupleVec = if has_callable_params {
+        let mut args = args;
+        let result = args.pop().expect("callable has a result type");
+        let params = vm.heap.allocate(HeapData::List(List::new(args)));
+        let params = match params {
+            Ok(id) => Value::Ref(id),
+            Err(err) => {
+                result.drop_with_heap(vm);
+                return Err(err.into());
+            }
+        };
+        [params, result].into_iter().collect()
+    } else {
+        args.into_iter().collect()
+    };
+    Ok(allocate_tuple(items, vm.heap)?)
+}
+
+/// Argument shape for `typing.get_type_hints(obj, globalns=None, localns=None, include_extras=False)`.
+///
+/// `globalns` and `localns` are accepted but ignored: string annotations aren't resolved.
+#[derive(FromArgs)]
+#[from_args(name = "get_type_hints")]
+struct GetTypeHintsArgs {
+    obj: Value,
+    #[from_args(default = Value::None)]
+    globalns: Value,
+    #[from_args(default = Value::None)]
+    localns: Value,
+    #[from_args(default = Value::Bool(false))]
+    include_extras: Value,
+}
+
+/// `typing.get_type_hints(obj, ...)`.
+///
+/// Returns the annotations of a function or `TypedDict` with `None` replaced by
+/// `NoneType` and, unless `include_extras` is true, top-level `Annotated[T, ...]`
+/// replaced by `T`. Modules, builtin types and builtin functions have no hints.
+fn get_type_hints(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let GetTypeHintsArgs {
+        obj,
+        globalns,
+        localns,
+        include_extras,
+    } = GetTypeHintsArgs::from_args(args, vm)?;
+    defer_drop!(obj, vm);
+    globalns.drop_with_heap(vm);
+    localns.drop_with_heap(vm);
+    defer_drop!(include_extras, vm);
+    let include_extras = checked_truth(include_extras, vm)?;
+
+    let hints = if let Some(annotations) = annotations_of(obj, vm)? {
+        annotations
+    } else {
+        match obj {
+            Value::Ref(id) => match vm.heap.get(*id) {
+                HeapData::TypedDict(typed_dict) => {
+                    let annotations = typed_dict.annotations().clone_with_heap(vm);
+                    defer_drop!(annotations, vm);
+                    copy_dict(annotations, vm)?
+                }
+                HeapData::DataclassType(class) => {
+                    let annotations = Dict::from_pairs(class.annotation_pairs(vm), vm)?;
+                    Value::Ref(vm.heap.allocate(HeapData::Dict(annotations))?)
+                }
+                HeapData::Module(_) => Value::Ref(vm.heap.allocate(HeapData::Dict(Dict::new()))?),
+                _ => return Err(not_introspectable(obj, vm)),
+            },
+            Value::Builtin(_) | Value::ModuleFunction(_) => Value::Ref(vm.heap.allocate(HeapData::Dict(Dict::new()))?),
+            _ => return Err(not_introspectable(obj, vm)),
+        }
+    };
+    defer_drop!(hints, vm);
+
+    let Value::Ref(hints_id) = hints else {
+        return Err(ExcType::type_error("__annotations__ must be a dict"));
+    };
+    let HeapData::Dict(dict) = vm.heap.get(*hints_id) else {
+        return Err(ExcType::type_error("__annotations__ must be a dict"));
+    };
+    let pairs: Vec<(Value, Value)> = dict
+        .iter()
+        .map(|(k, v)| (k.clone_with_heap(vm), normalize_hint(v, include_extras, vm)))
+        .collect();
+    let result = Dict::from_pairs(pairs, vm)?;
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Dict(result))?))
+}
+
+/// Converts one annotation into the form `get_type_hints` reports.
+fn normalize_hint(hint: &Value, include_extras: bool, vm: &VM<'_, impl ResourceTracker>) -> Value {
+    match hint {
+        Value::None => Value::Builtin(Builtins::Type(Type::NoneType)),
+        Value::Ref(id) if !include_extras => match vm.heap.get(*id) {
+            HeapData::GenericAlias(alias) if alias.form() == Some(Marker(StaticStrings::Annotated)) => {
+                alias.origin().clone_with_heap(vm)
+            }
+            _ => hint.clone_with_heap(vm),
+        },
+        _ => hint.clone_with_heap(vm),
+    }
+}
+
+/// Builds the `TypeError` raised by `get_type_hints` for objects without annotations.
+fn not_introspectable(obj: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunError {
+    match obj.py_repr(vm) {
+        Ok(obj_repr) => ExcType::type_error(format!("{obj_repr} does not have annotations")),
+        Err(err) => err,
+    }
+}
+
+/// `typing.NewType(name, tp)`.
+fn new_type(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let (name, tp) = args.get_two_args("NewType", vm.heap)?;
+    defer_drop!(name, vm);
+    let Some(name) = name.as_either_str(vm.heap) else {
+        tp.drop_with_heap(vm);
+        return Err(ExcType::type_error("NewType() argument 'name' must be str"));
+    };
+    let new_type = NewType::new(name.as_str(vm.interns).to_owned(), tp);
+    Ok(Value::Ref(vm.heap.allocate(HeapData::NewType(new_type))?))
+}
+
+/// Argument shape for `typing.TypedDict(typename, fields, /, *, total=True)`.
+#[derive(FromArgs)]
+#[from_args(name = "TypedDict")]
+struct TypedDictArgs {
+    #[from_args(pos_only)]
+    typename: Value,
+    #[from_args(pos_only)]
+    fields: Value,
+    #[from_args(kw_only, default = Value::Bool(true))]
+    total: Value,
+}
+
+/// `typing.TypedDict(name, fields, *, total=True)` — the functional form.
+///
+/// The class-based `class Movie(TypedDict): ...` form isn't supported since Monty has
+/// no class inheritance.
+fn typed_dict(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let TypedDictArgs {
+        typename,
+        fields,
+        total,
+    } = TypedDictArgs::from_args(args, vm)?;
+    defer_drop!(typename, vm);
+    defer_drop!(fields, vm);
+    defer_drop!(total, vm);
+    let total = checked_truth(total, vm)?;
+    let Some(name) = typename.as_either_str(vm.heap) else {
+        return Err(ExcType::type_error("TypedDict() argument 'typename' must be str"));
+    };
+    let name = name.as_str(vm.interns).to_owned();
+    let is_dict = matches!(fields, Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::Dict(_)));
+    if !is_dict {
+        return Err(ExcType::type_error("TypedDict() argument 'fields' must be a dict"));
+    }
+    let annotations = copy_dict(fields, vm)?;
+    Ok(Value::Ref(vm.heap.allocate(HeapData::TypedDict(TypedDict::new(
+        name,
+        annotations,
+        total,
+    )))?))
+}
@@ -0,0 +1,332 @@
+//! Implementation of Python's `unicodedata` module.
+//!
+//! Backed by the generated tables in [`crate::unicode`], which are pinned to
+//! the Unicode version of the CPython release they were generated from and
+//! exposed as `unicodedata.unidata_version`.
+//!
+//! ## Implemented functions
+//!
+//! **Properties**: `category`, `bidirectional`, `combining`, `east_asian_width`,
+//! `mirrored`, `decomposition`
+//! **Numeric values**: `decimal`, `digit`, `numeric`
+//! **Names**: `name`, `lookup`
+//! **Normalization**: `normalize`, `is_normalized`
+//!
+//! ## Constants
+//!
+//! `unidata_version`
+
+use std::fmt::Write;
+
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{DropWithHeap, HeapData, HeapId},
+    intern::StaticStrings,
+    modules::ModuleFunctions,
+    resource::{ResourceError, ResourceTracker},
+    types::{
+        Module, PyTrait, Type,
+        str::{allocate_char, allocate_string},
+    },
+    unicode::{self, MAX_NAME_LEN, NormalizationForm},
+    value::Value,
+};
+
+/// Unicodedata module functions — each variant corresponds to a Python-visible function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum UnicodedataFunctions {
+    // Properties
+    Category,
+    Bidirectional,
+    Combining,
+    EastAsianWidth,
+    Mirrored,
+    Decomposition,
+    // Numeric values
+    Decimal,
+    Digit,
+    Numeric,
+    // Names
+    Name,
+    Lookup,
+    // Normalization
+    Normalize,
+    IsNormalized,
+}
+
+/// Creates the `unicodedata` module and allocates it on the heap.
+///
+/// Registers all unicodedata functions and the `unidata_version` constant.
+///
+/// # Returns
+/// A `HeapId` pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Unicodedata);
+
+    for (name, func) in UNICODEDATA_FUNCTIONS {
+        module.set_attr(*name, Value::ModuleFunction(ModuleFunctions::Unicodedata(*func)), vm);
+    }
+
+    let version = allocate_string(unicode::UNIDATA_VERSION, vm.heap)?;
+    module.set_attr(StaticStrings::UnidataVersion, version, vm);
+
+    vm.heap.allocate(HeapData::Module(module))
+}
+
+/// Static mapping of attribute names to unicodedata functions for module creation.
+const UNICODEDATA_FUNCTIONS: &[(StaticStrings, UnicodedataFunctions)] = &[
+    // Properties
+    (StaticStrings::Category, UnicodedataFunctions::Category),
+    (StaticStrings::Bidirectional, UnicodedataFunctions::Bidirectional),
+    (StaticStrings::Combining, UnicodedataFunctions::Combining),
+    (StaticStrings::EastAsianWidth, UnicodedataFunctions::EastAsianWidth),
+    (StaticStrings::Mirrored, UnicodedataFunctions::Mirrored),
+    (StaticStrings::Decomposition, UnicodedataFunctions::Decomposition),
+    // Numeric values
+    (StaticStrings::Decimal, UnicodedataFunctions::Decimal),
+    (StaticStrings::Digit, UnicodedataFunctions::Digit),
+    (StaticStrings::Numeric, UnicodedataFunctions::Numeric),
+    // Names
+    (StaticStrings::Name, UnicodedataFunctions::Name),
+    (StaticStrings::Lookup, UnicodedataFunctions::Lookup),
+    // Normalization
+    (StaticStrings::Normalize, UnicodedataFunctions::Normalize),
+    (StaticStrings::IsNormalized, UnicodedataFunctions::IsNormalized),
+];
+
+/// Dispatches a call to a unicodedata module function.
+///
+/// All unicodedata functions are pure lookups and return `Value` directly.
+pub(super) fn call(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    function: UnicodedataFunctions,
+    args: ArgValues,
+) -> RunResult<Value> {
+    match function {
+        // Properties
+        UnicodedataFunctions::Category => property(vm, args, function, |c, vm| {
+            Ok(allocate_string(unicode::category(c), vm.heap)?)
+        }),
+        UnicodedataFunctions::Bidirectional => property(vm, args, function, |c, vm| {
+            Ok(allocate_string(unicode::bidirectional(c), vm.heap)?)
+        }),
+        UnicodedataFunctions::Combining => property(vm, args, function, |c, _| {
+            Ok(Value::Int(i64::from(unicode::combining(c))))
+        }),
+        UnicodedataFunctions::EastAsianWidth => property(vm, args, function, |c, vm| {
+            Ok(allocate_string(unicode::east_asian_width(c), vm.heap)?)
+        }),
+        UnicodedataFunctions::Mirrored => property(vm, args, function, |c, _| {
+            Ok(Value::Int(i64::from(unicode::mirrored(c))))
+        }),
+        UnicodedataFunctions::Decomposition => property(vm, args, function, |c, vm| {
+            Ok(allocate_string(decomposition_string(c), vm.heap)?)
+        }),
+        // Numeric values
+        UnicodedataFunctions::Decimal => with_default(vm, args, function, "not a decimal", |c, _| {
+            Ok(unicode::decimal(c).map(|d| Value::Int(i64::from(d))))
+        }),
+        UnicodedataFunctions::Digit => with_default(vm, args, function, "not a digit", |c, _| {
+            Ok(unicode::digit(c).map(|d| Value::Int(i64::from(d))))
+        }),
+        UnicodedataFunctions::Numeric => with_default(vm, args, function, "not a numeric character", |c, _| {
+            Ok(unicode::numeric(c).map(Value::Float))
+        }),
+        // Names
+        UnicodedataFunctions::Name => with_default(vm, args, function, "no such name", |c, vm| {
+            unicode::name(c)
+                .map(|name| allocate_string(name, vm.heap))
+                .transpose()
+                .map_err(Into::into)
+        }),
+        UnicodedataFunctions::Lookup => unicodedata_lookup(vm, args),
+        // Normalization
+        UnicodedataFunctions::Normalize => unicodedata_normalize(vm, args, false),
+        UnicodedataFunctions::IsNormalized => unicodedata_normalize(vm, args, true),
+    }
+}
+
+// ==========================
+// Argument handling
+// ==========================
+
+/// Returns the string held by `value`, if it is a `str`.
+fn str_value<'a>(value: &Value, vm: &'a VM<'_, impl ResourceTracker>) -> Option<&'a str> {
+    match value {
+        Value::InternString(string_id) => Some(vm.interns.get_str(*string_id)),
+        Value::Ref(heap_id) => match vm.heap.get(*heap_id) {
+            HeapData::Str(s) => Some(s.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Extracts the single character CPython's `unicodedata` functions take.
+///
+/// `argument` is `"argument"` for the one-argument functions and `"argument 1"`
+/// for those taking a default, matching CPython's messages.
+fn char_arg(
+    value: &Value,
+    function: UnicodedataFunctions,
+    argument: &str,
+    vm: &VM<'_, impl ResourceTracker>,
+) -> RunResult<char> {
+    let mut chars = str_value(value, vm).map(str::chars);
+    match chars.as_mut().map(|chars| (chars.next(), chars.next())) {
+        Some((Some(c), None)) => Ok(c),
+        _ => Err(ExcType::type_error(format!(
+            "{function}() {argument} must be a unicode character, not {}",
+            value.py_type(vm).cpython_arg_name()
+        ))),
+    }
+}
+
+/// Calls a one-argument property lookup on a single character.
+fn property<T: ResourceTracker>(
+    vm: &mut VM<'_, T>,
+    args: ArgValues,
+    function: UnicodedataFunctions,
+    f: impl FnOnce(char, &mut VM<'_, T>) -> RunResult<Value>,
+) -> RunResult<Value> {
+    let value = args.get_one_arg(&format!("unicodedata.{function}"), vm.heap)?;
+    defer_drop!(value, vm);
+
+    let c = char_arg(value, function, "argument", vm)?;
+    f(c, vm)
+}
+
+/// Calls a lookup taking a character and an optional default, which is
+/// returned when the lookup finds nothing; without a default that raises
+/// `ValueError(missing)`.
+fn with_default<T: ResourceTracker>(
+    vm: &mut VM<'_, T>,
+    args: ArgValues,
+    function: UnicodedataFunctions,
+    missing: &str,
+    f: impl FnOnce(char, &mut VM<'_, T>) -> RunResult<Option<Value>>,
+) -> RunResult<Value> {
+    let (value, default) = args.get_one_two_args(&function.to_string(), vm.heap)?;
+    defer_drop!(value, vm);
+
+    let c = match char_arg(value, function, "argument 1", vm) {
+        Ok(c) => c,
+        Err(err) => {
+            default.drop_with_heap(vm);
+            return Err(err);
+        }
+    };
+    match f(c, vm) {
+        Ok(Some(result)) => {
+            default.drop_with_heap(vm);
+            Ok(result)
+        }
+        Ok(None) => default.ok_or_else(|| SimpleException::new_msg(ExcType::ValueError, missing).into()),
+        Err(err) => {
+            default.drop_with_heap(vm);
+            Err(err)
+        }
+    }
+}
+
+/// Formats a decomposition as `unicodedata.decomposition()` does: the tag (if
+/// any) followed by the mapping as space-separated hex code points.
+fn decomposition_string(c: char) -> String {
+    let mut out = String::new();
+    if let Some((tag, mapping)) = unicode::decomposition(c) {
+        out.push_str(tag);
+        for part in mapping {
+            if !out.is_empty() {
+                out.push(' ');
+            }
+            write!(out, "{:04X}", u32::from(part)).expect("writing to a String can't fail");
+        }
+    }
+    out
+}
+
+// ==========================
+// Module functions
+// ==========================
+
+/// `unicodedata.lookup(name)` — returns the character or named sequence called `name`.
+///
+/// Accepts `str` or `bytes`; matching ignores ASCII case and accepts name aliases.
+fn unicodedata_lookup(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let value = args.get_one_arg("unicodedata.lookup", vm.heap)?;
+    defer_drop!(value, vm);
+
+    let name = match value {
+        Value::InternString(string_id) => Some(vm.interns.get_str(*string_id).to_owned()),
+        Value::InternBytes(bytes_id) => Some(String::from_utf8_lossy(vm.interns.get_bytes(*bytes_id)).into_owned()),
+        Value::Ref(heap_id) => match vm.heap.get(*heap_id) {
+            HeapData::Str(s) => Some(s.as_str().to_owned()),
+            HeapData::Bytes(bytes) => Some(String::from_utf8_lossy(bytes.as_slice()).into_owned()),
+            _ => None,
+        },
+        _ => None,
+    };
+    let Some(name) = name else {
+        let message = match value.py_type(vm) {
+            Type::ByteArray => "lookup() argument must be read-only bytes-like object, not bytearray".to_owned(),
+            other => format!("a bytes-like object is required, not '{other}'"),
+        };
+        return Err(ExcType::type_error(message));
+    };
+    if name.len() > MAX_NAME_LEN {
+        return Err(SimpleException::new_msg(ExcType::KeyError, "name too long").into());
+    }
+    match unicode::lookup(&name) {
+        Some(found) => match found.chars().next() {
+            Some(c) if found.len() == c.len_utf8() => Ok(allocate_char(c, vm.heap)?),
+            _ => Ok(allocate_string(found, vm.heap)?),
+        },
+        None => Err(SimpleException::new_msg(ExcType::KeyError, format!("undefined character name '{name}'")).into()),
+    }
+}
+
+/// `unicodedata.normalize(form, unistr)` and `unicodedata.is_normalized(form, unistr)`.
+///
+/// `form` must be one of `"NFC"`, `"NFD"`, `"NFKC"` or `"NFKD"`.
+fn unicodedata_normalize(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues, check_only: bool) -> RunResult<Value> {
+    let function = if check_only { "is_normalized" } else { "normalize" };
+    let (form_val, s_val) = args.get_two_args(function, vm.heap)?;
+    defer_drop!(form_val, vm);
+    defer_drop!(s_val, vm);
+
+    let Some(form) = str_value(form_val, vm) else {
+        let got = form_val.py_type(vm);
+        return Err(ExcType::type_error_bad_arg_pos(
+            function,
+            1,
+            "str",
+            got.cpython_arg_name(),
+        ));
+    };
+    let Some(s) = str_value(s_val, vm) else {
+        let got = s_val.py_type(vm);
+        return Err(ExcType::type_error_bad_arg_pos(
+            function,
+            2,
+            "str",
+            got.cpython_arg_name(),
+        ));
+    };
+    let form = NormalizationForm::from_name(form)
+        .ok_or_else(|| SimpleException::new_msg(ExcType::ValueError, "invalid normalization form"))?;
+    let normalized = unicode::normalize(s, form);
+    if check_only {
+        Ok(Value::Bool(normalized == s))
+    } else if normalized == s {
+        Ok(s_val.clone_with_heap(vm))
+    } else {
+        Ok(allocate_string(normalized, vm.heap)?)
+    }
+}
@@ -0,0 +1,732 @@
+//! Implementation of the `warnings` module.
+//!
+//! Provides `warn`, `filterwarnings`, `simplefilter`, `resetwarnings`,
+//! `formatwarning` and the `catch_warnings` context manager.
+//!
+//! The filter list, the "already shown" registries and the active
+//! `catch_warnings(record=True)` log live on the VM in [`WarningsState`], so they
+//! survive snapshot/resume. Shown warnings are not printed by Monty itself: they are
+//! handed to the host through [`PrintWriter::warn`](crate::PrintWriter::warn) as
+//! structured [`MontyWarning`] records.
+//!
+//! The default filters match CPython's for a non-debug build: `DeprecationWarning`
+//! is shown for code in `__main__` and ignored elsewhere, and
+//! `PendingDeprecationWarning`, `ImportWarning` and `ResourceWarning` are ignored.
+//! Everything else is shown once per source location.
+
+use std::mem;
+
+use ahash::AHashSet;
+
+use crate::{
+    args::{ArgValues, FromArgs, LaxBool},
+    builtins::Builtins,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{ContainsHeap, DropWithHeap, HeapData, HeapId, HeapReadOutput},
+    intern::StaticStrings,
+    io::MontyWarning,
+    modules::{ModuleFunctions, re::IGNORECASE},
+    object::MontyObject,
+    resource::{ResourceError, ResourceTracker},
+    types::{List, Module, NamedTuple, PyTrait, RePattern, Type, str::allocate_string},
+    value::Value,
+};
+
+/// Warnings module functions — each variant corresponds to a Python-visible function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum WarningsFunctions {
+    Warn,
+    Filterwarnings,
+    Simplefilter,
+    Resetwarnings,
+    Formatwarning,
+}
+
+/// Creates the `warnings` module and allocates it on the heap.
+///
+/// # Returns
+/// A `HeapId` pointing to the newly allocated module.
+///
+/// # Panics
+/// Panics if the required strings have not been pre-interned during prepare phase.
+pub fn create_module(vm: &mut VM<'_, impl ResourceTracker>) -> Result<HeapId, ResourceError> {
+    let mut module = Module::new(StaticStrings::Warnings);
+
+    for (name, func) in WARNINGS_FUNCTIONS {
+        module.set_attr(*name, Value::ModuleFunction(ModuleFunctions::Warnings(*func)), vm);
+    }
+    module.set_attr(
+        StaticStrings::CatchWarnings,
+        Value::Builtin(Builtins::Type(Type::CatchWarnings)),
+        vm,
+    );
+
+    vm.heap.allocate(HeapData::Module(module))
+}
+
+/// Static mapping of attribute names to warnings functions for module creation.
+const WARNINGS_FUNCTIONS: &[(StaticStrings, WarningsFunctions)] = &[
+    (StaticStrings::Warn, WarningsFunctions::Warn),
+    (StaticStrings::Filterwarnings, WarningsFunctions::Filterwarnings),
+    (StaticStrings::Simplefilter, WarningsFunctions::Simplefilter),
+    (StaticStrings::Resetwarnings, WarningsFunctions::Resetwarnings),
+    (StaticStrings::Formatwarning, WarningsFunctions::Formatwarning),
+];
+
+/// Dispatches a call to a warnings module function.
+pub(super) fn call(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    function: WarningsFunctions,
+    args: ArgValues,
+) -> RunResult<Value> {
+    match function {
+        WarningsFunctions::Warn => warn(vm, args),
+        WarningsFunctions::Filterwarnings => filterwarnings(vm, args),
+        WarningsFunctions::Simplefilter => simplefilter(vm, args),
+        WarningsFunctions::Resetwarnings => {
+            args.check_zero_args("resetwarnings", vm.heap)?;
+            vm.warnings.filters.clear();
+            vm.warnings.filters_mutated();
+            Ok(Value::None)
+        }
+        WarningsFunctions::Formatwarning => formatwarning(vm, args),
+    }
+}
+
+/// What a matching filter does with a warning.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, serde::Serialize, serde::Deserialize,
+)]
+#[strum(serialize_all = "lowercase")]
+enum WarningAction {
+    /// Raise the warning as an exception.
+    Error,
+    /// Never show the warning.
+    Ignore,
+    /// Always show the warning.
+    Always,
+    /// Alias for `always`.
+    All,
+    /// Show the warning once per source location.
+    Default,
+    /// Show the warning once per module.
+    Module,
+    /// Show the warning only once, regardless of location.
+    Once,
+}
+
+/// One entry of the warnings filter list, as built by `filterwarnings()`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct WarningFilter {
+    action: WarningAction,
+    /// Case-insensitive regex that must match the start of the warning text.
+    message: Option<RePattern>,
+    /// The warning category must be a subclass of this.
+    category: ExcType,
+    /// Regex that must match the whole module name.
+    module: Option<RePattern>,
+    /// Line number the warning must come from, or 0 for any line.
+    lineno: u32,
+}
+
+impl WarningFilter {
+    /// Returns whether this filter applies to a warning.
+    fn matches(&self, text: &str, category: ExcType, module: &str, lineno: u32) -> RunResult<bool> {
+        if !category.is_subclass_of(self.category) || (self.lineno != 0 && self.lineno != lineno) {
+            return Ok(false);
+        }
+        if let Some(message) = &self.message
+            && !message.is_match_start(text)?
+        {
+            return Ok(false);
+        }
+        match &self.module {
+            Some(pattern) => pattern.is_fullmatch(module),
+            None => Ok(true),
+        }
+    }
+}
+
+/// Key of the per-location registry used by the `default` and `module` actions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+struct ShownKey {
+    filename: String,
+    text: String,
+    category: ExcType,
+    lineno: u32,
+}
+
+/// Filter and state saved by an active `catch_warnings` block.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct SavedWarnings {
+    filters: Vec<WarningFilter>,
+    log: Option<Value>,
+}
+
+/// Interpreter-wide state of the `warnings` module.
+///
+/// Owned by the VM and included in its snapshots. `log` and the saved logs own a
+/// reference to their list, released by [`DropWithHeap`].
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WarningsState {
+    /// Active filters, first match wins.
+    filters: Vec<WarningFilter>,
+    /// Warnings already shown by `default` / `module` / `once` filters.
+    ///
+    /// Cleared whenever the filter list changes, as CPython does by bumping its
+    /// filters version.
+    shown: AHashSet<ShownKey>,
+    /// Warnings already shown by a `once` filter, keyed by text and category.
+    ///
+    /// Unlike `shown`, CPython never invalidates this registry.
+    shown_once: AHashSet<(String, ExcType)>,
+    /// State to restore when each active `catch_warnings` block exits, innermost last.
+    saved: Vec<SavedWarnings>,
+    /// The list shown warnings are appended to inside `catch_warnings(record=True)`.
+    log: Option<Value>,
+}
+
+impl Default for WarningsState {
+    /// CPython's default filter list for a release build.
+    fn default() -> Self {
+        let main = RePattern::compile("__main__".to_owned(), 0, None).expect("static pattern compiles");
+        let mut filters = vec![WarningFilter {
+            action: WarningAction::Default,
+            message: None,
+            category: ExcType::DeprecationWarning,
+            module: Some(main),
+            lineno: 0,
+        }];
+        for category in [
+            ExcType::DeprecationWarning,
+            ExcType::PendingDeprecationWarning,
+            ExcType::ImportWarning,
+            ExcType::ResourceWarning,
+        ] {
+            filters.push(WarningFilter {
+                action: WarningAction::Ignore,
+                message: None,
+                category,
+                module: None,
+                lineno: 0,
+            });
+        }
+        Self {
+            filters,
+            shown: AHashSet::new(),
+            shown_once: AHashSet::new(),
+            saved: Vec::new(),
+            log: None,
+        }
+    }
+}
+
+impl DropWithHeap for WarningsState {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        self.log.drop_with_heap(heap);
+        for saved in self.saved {
+            saved.log.drop_with_heap(heap);
+        }
+    }
+}
+
+impl WarningsState {
+    /// Invalidates the per-location registry after the filter list changed.
+    fn filters_mutated(&mut self) {
+        self.shown.clear();
+    }
+
+    /// Inserts a filter at the front of the list, or appends it with `append`.
+    ///
+    /// An identical filter already in the list is removed first (or, when
+    /// appending, the new one is dropped), as in CPython.
+    fn add_filter(&mut self, filter: WarningFilter, append: bool) {
+        if append {
+            if !self.filters.contains(&filter) {
+                self.filters.push(filter);
+            }
+        } else {
+            self.filters.retain(|existing| *existing != filter);
+            self.filters.insert(0, filter);
+        }
+        self.filters_mutated();
+    }
+
+    /// Applies the filters to a warning and returns the action to take, or
+    /// `None` if it was already shown from this location.
+    ///
+    /// Updates the registries for the `default`, `module` and `once` actions.
+    fn decide(
+        &mut self,
+        text: &str,
+        category: ExcType,
+        filename: &str,
+        lineno: u32,
+    ) -> RunResult<Option<WarningAction>> {
+        let key = ShownKey {
+            filename: filename.to_owned(),
+            text: text.to_owned(),
+            category,
+            lineno,
+        };
+        if self.shown.contains(&key) {
+            return Ok(None);
+        }
+        let module = if filename == SYS_FILENAME { "sys" } else { "__main__" };
+        let mut action = WarningAction::Default;
+        for filter in &self.filters {
+            if filter.matches(text, category, module, lineno)? {
+                action = filter.action;
+                break;
+            }
+        }
+        let show = match action {
+            WarningAction::Ignore => false,
+            WarningAction::Error | WarningAction::Always | WarningAction::All => true,
+            WarningAction::Default => self.shown.insert(key),
+            WarningAction::Module => {
+                let module_key = ShownKey {
+                    lineno: 0,
+                    ..key.clone()
+                };
+                self.shown.insert(key);
+                self.shown.insert(module_key)
+            }
+            WarningAction::Once => {
+                self.shown.insert(key);
+                self.shown_once.insert((text.to_owned(), category))
+            }
+        };
+        Ok(show.then_some(action))
+    }
+
+    /// Saves the filters and log when a `catch_warnings` block is entered.
+    ///
+    /// `log` replaces the active log when recording; otherwise the current log
+    /// (if any) stays active inside the block.
+    fn save(&mut self, log: Option<Value>, heap: &impl ContainsHeap) {
+        let log = log.or_else(|| self.log.as_ref().map(|current| current.clone_with_heap(heap)));
+        let previous = mem::replace(&mut self.log, log);
+        self.saved.push(SavedWarnings {
+            filters: self.filters.clone(),
+            log: previous,
+        });
+        self.filters_mutated();
+    }
+
+    /// Restores the state saved by the innermost `catch_warnings` block.
+    fn restore(&mut self, heap: &mut impl ContainsHeap) {
+        if let Some(saved) = self.saved.pop() {
+            self.filters = saved.filters;
+            mem::replace(&mut self.log, saved.log).drop_with_heap(heap);
+            self.filters_mutated();
+        }
+    }
+}
+
+/// Filename CPython attributes a warning to when `stacklevel` walks past the
+/// outermost frame.
+const SYS_FILENAME: &str = "sys";
+
+/// Arguments of a `warnings.catch_warnings(...)` context manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct CatchWarnings {
+    record: bool,
+    /// Passed to `simplefilter()` on entry, together with the fields below.
+    action: Option<WarningAction>,
+    category: ExcType,
+    lineno: u32,
+    append: bool,
+}
+
+/// Argument shape for `warnings.catch_warnings(*, record=False, action=None, category=Warning, lineno=0, append=False)`.
+#[derive(FromArgs)]
+#[from_args(name = "catch_warnings")]
+struct CatchWarningsArgs {
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    record: LaxBool,
+    #[from_args(kw_only, default = Value::None)]
+    action: Value,
+    #[from_args(kw_only, default = Value::Builtin(Builtins::ExcType(ExcType::Warning)))]
+    category: Value,
+    #[from_args(kw_only, default = Value::Int(0))]
+    lineno: Value,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    append: LaxBool,
+}
+
+impl CatchWarnings {
+    /// Parses the arguments of `warnings.catch_warnings(...)`.
+    pub fn init(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Self> {
+        let CatchWarningsArgs {
+            record,
+            action,
+            category,
+            lineno,
+            append,
+        } = CatchWarningsArgs::from_args(args, vm)?;
+        defer_drop!(action, vm);
+        defer_drop!(category, vm);
+        defer_drop!(lineno, vm);
+        let action = match action {
+            Value::None => None,
+            action => Some(parse_action(action, vm)?),
+        };
+        Ok(Self {
+            record: record.bool(),
+            action,
+            category: filter_category(category)?,
+            lineno: filter_lineno(lineno)?,
+            append: append.bool(),
+        })
+    }
+
+    /// Returns whether the block records warnings, for `repr()`.
+    #[must_use]
+    pub fn record(self) -> bool {
+        self.record
+    }
+
+    /// `catch_warnings.__enter__`: saves the warnings state and applies `action`.
+    ///
+    /// Returns the record list when `record=True`, otherwise `None`.
+    pub fn enter(self, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+        let (log, result) = if self.record {
+            let list = Value::Ref(vm.heap.allocate(HeapData::List(List::new(Vec::new())))?);
+            let result = list.clone_with_heap(vm);
+            (Some(list), result)
+        } else {
+            (None, Value::None)
+        };
+        vm.warnings.save(log, vm.heap);
+        if let Some(action) = self.action {
+            let filter = WarningFilter {
+                action,
+                message: None,
+                category: self.category,
+                module: None,
+                lineno: self.lineno,
+            };
+            vm.warnings.add_filter(filter, self.append);
+        }
+        Ok(result)
+    }
+
+    /// `catch_warnings.__exit__`: restores the state saved by [`enter`](Self::enter).
+    pub fn exit(vm: &mut VM<'_, impl ResourceTracker>) {
+        vm.warnings.restore(vm.heap);
+    }
+}
+
+/// Argument shape for `warnings.warn(message, category=None, stacklevel=1, source=None)`.
+#[derive(FromArgs)]
+#[from_args(name = "warn")]
+struct WarnArgs {
+    message: Value,
+    #[from_args(default = Value::None)]
+    category: Value,
+    #[from_args(default = 1)]
+    stacklevel: i64,
+    #[from_args(default = Value::None)]
+    source: Value,
+}
+
+/// `warnings.warn(message, category=None, stacklevel=1, source=None)`.
+///
+/// Attributes the warning to the caller `stacklevel` frames up, runs it through
+/// the filters, then raises it, records it in the active `catch_warnings` log, or
+/// hands it to the host via `PrintWriter::warn`.
+fn warn(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let WarnArgs {
+        message,
+        category,
+        stacklevel,
+        source,
+    } = WarnArgs::from_args(args, vm)?;
+    defer_drop!(message, vm);
+    defer_drop!(category, vm);
+    defer_drop!(source, vm);
+
+    // A Warning instance carries its own category; anything else is wrapped
+    // in an instance of `category` (UserWarning by default).
+    let instance_category = match message {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Exception(exc) if exc.exc_type().is_subclass_of(ExcType::Warning) => Some(exc.exc_type()),
+            _ => None,
+        },
+        _ => None,
+    };
+    let category = match (instance_category, category) {
+        (Some(category), _) => category,
+        (None, Value::None) => ExcType::UserWarning,
+        (None, Value::Builtin(Builtins::ExcType(exc_type))) if exc_type.is_subclass_of(ExcType::Warning) => *exc_type,
+        (None, other) => {
+            let type_name = other.py_type(vm);
+            return Err(ExcType::type_error(format!(
+                "category must be a Warning subclass, not '{type_name}'"
+            )));
+        }
+    };
+    let text = message.py_str(vm)?.into_owned();
+
+    let depth = usize::try_from(stacklevel.max(1) - 1).unwrap_or(usize::MAX);
+    let (filename, lineno) = match vm.caller_position(depth) {
+        Some(position) => (
+            vm.interns.get_str(position.filename).to_owned(),
+            vm.interns.line_number(position),
+        ),
+        None => (SYS_FILENAME.to_owned(), 1),
+    };
+
+    let Some(action) = vm.warnings.decide(&text, category, &filename, lineno)? else {
+        return Ok(Value::None);
+    };
+
+    // The Warning instance raised by "error" filters and stored in records.
+    let instance = if instance_category.is_some() {
+        message.clone_with_heap(vm)
+    } else {
+        let arg = MontyObject::new(message.clone_with_heap(vm), vm);
+        let exc = SimpleException::from_args(category, vec![arg]);
+        Value::Ref(vm.heap.allocate(HeapData::Exception(exc))?)
+    };
+    defer_drop!(instance, vm);
+
+    if action == WarningAction::Error {
+        let Value::Ref(id) = instance else {
+            return Err(RunError::internal("warning instance is not a heap exception"));
+        };
+        return match vm.heap.get(*id) {
+            HeapData::Exception(exc) => Err(exc.clone().into()),
+            _ => Err(RunError::internal("warning instance is not a heap exception")),
+        };
+    }
+
+    if let Some(Value::Ref(log_id)) = vm.warnings.log {
+        let record = NamedTuple::new(
+            StaticStrings::WarningMessage,
+            vec![
+                StaticStrings::Message.into(),
+                StaticStrings::Category.into(),
+                StaticStrings::Filename.into(),
+                StaticStrings::Lineno.into(),
+                StaticStrings::File.into(),
+                StaticStrings::Line.into(),
+                StaticStrings::Source.into(),
+            ],
+            vec![
+                instance.clone_with_heap(vm),
+                Value::Builtin(Builtins::ExcType(category)),
+                allocate_string(filename, vm.heap)?,
+                Value::Int(i64::from(lineno)),
+                Value::None,
+                Value::None,
+                source.clone_with_heap(vm),
+            ],
+        );
+        let record = Value::Ref(vm.heap.allocate(HeapData::NamedTuple(record))?);
+        if let HeapReadOutput::List(mut list) = vm.heap.read(log_id) {
+            list.append(vm, record)?;
+        } else {
+            record.drop_with_heap(vm);
+        }
+        return Ok(Value::None);
+    }
+
+    vm.print_writer.tracked_warn(
+        &MontyWarning {
+            category,
+            message: text,
+            filename,
+            lineno,
+        },
+        vm.heap.tracker(),
+    )?;
+    Ok(Value::None)
+}
+
+/// Argument shape for `warnings.filterwarnings(action, message='', category=Warning, module='', lineno=0, append=False)`.
+#[derive(FromArgs)]
+#[from_args(name = "filterwarnings")]
+struct FilterwarningsArgs {
+    action: Value,
+    #[from_args(default = Value::InternString(StaticStrings::EmptyString.into()))]
+    message: Value,
+    #[from_args(default = Value::Builtin(Builtins::ExcType(ExcType::Warning)))]
+    category: Value,
+    #[from_args(static_string = "ModuleKwarg", default = Value::InternString(StaticStrings::EmptyString.into()))]
+    module: Value,
+    #[from_args(default = Value::Int(0))]
+    lineno: Value,
+    #[from_args(default = LaxBool::new(false))]
+    append: LaxBool,
+}
+
+/// `warnings.filterwarnings(action, message='', category=Warning, module='', lineno=0, append=False)`.
+fn filterwarnings(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let FilterwarningsArgs {
+        action,
+        message,
+        category,
+        module,
+        lineno,
+        append,
+    } = FilterwarningsArgs::from_args(args, vm)?;
+    defer_drop!(action, vm);
+    defer_drop!(message, vm);
+    defer_drop!(category, vm);
+    defer_drop!(module, vm);
+    defer_drop!(lineno, vm);
+
+    let action = parse_action(action, vm)?;
+    let Some(message) = message.as_either_str(vm.heap) else {
+        return Err(ExcType::type_error("message must be a string"));
+    };
+    let message = message.as_str(vm.interns).to_owned();
+    let category = filter_category(category)?;
+    let Some(module) = module.as_either_str(vm.heap) else {
+        return Err(ExcType::type_error("module must be a string"));
+    };
+    let module = module.as_str(vm.interns).to_owned();
+    let lineno = filter_lineno(lineno)?;
+
+    let backtrack_limit = vm.heap.tracker().regex_backtrack_limit();
+    let message = if message.is_empty() {
+        None
+    } else {
+        Some(RePattern::compile(message, IGNORECASE, backtrack_limit)?)
+    };
+    let module = if module.is_empty() {
+        None
+    } else {
+        Some(RePattern::compile(module, 0, backtrack_limit)?)
+    };
+    let filter = WarningFilter {
+        action,
+        message,
+        category,
+        module,
+        lineno,
+    };
+    vm.warnings.add_filter(filter, append.bool());
+    Ok(Value::None)
+}
+
+/// Argument shape for `warnings.simplefilter(action, category=Warning, lineno=0, append=False)`.
+#[derive(FromArgs)]
+#[from_args(name = "simplefilter")]
+struct SimplefilterArgs {
+    action: Value,
+    #[from_args(default = Value::Builtin(Builtins::ExcType(ExcType::Warning)))]
+    category: Value,
+    #[from_args(default = Value::Int(0))]
+    lineno: Value,
+    #[from_args(default = LaxBool::new(false))]
+    append: LaxBool,
+}
+
+/// `warnings.simplefilter(action, category=Warning, lineno=0, append=False)`.
+fn simplefilter(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let SimplefilterArgs {
+        action,
+        category,
+        lineno,
+        append,
+    } = SimplefilterArgs::from_args(args, vm)?;
+    defer_drop!(action, vm);
+    defer_drop!(category, vm);
+    defer_drop!(lineno, vm);
+
+    let filter = WarningFilter {
+        action: parse_action(action, vm)?,
+        message: None,
+        category: filter_category(category)?,
+        module: None,
+        lineno: filter_lineno(lineno)?,
+    };
+    vm.warnings.add_filter(filter, append.bool());
+    Ok(Value::None)
+}
+
+/// Argument shape for `warnings.formatwarning(message, category, filename, lineno, line=None)`.
+#[derive(FromArgs)]
+#[from_args(name = "formatwarning")]
+struct FormatwarningArgs {
+    message: Value,
+    category: Value,
+    filename: Value,
+    lineno: Value,
+    #[from_args(default = Value::None)]
+    line: Value,
+}
+
+/// `warnings.formatwarning(message, category, filename, lineno, line=None)`.
+///
+/// Monty has no `linecache`, so the source line is only included when passed
+/// explicitly.
+fn formatwarning(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let FormatwarningArgs {
+        message,
+        category,
+        filename,
+        lineno,
+        line,
+    } = FormatwarningArgs::from_args(args, vm)?;
+    defer_drop!(message, vm);
+    defer_drop!(category, vm);
+    defer_drop!(filename, vm);
+    defer_drop!(lineno, vm);
+    defer_drop!(line, vm);
+
+    let Value::Builtin(Builtins::ExcType(category)) = category else {
+        let type_name = category.py_type(vm);
+        return Err(ExcType::attribute_error(type_name, "__name__"));
+    };
+    let message = message.py_str(vm)?;
+    let filename = filename.py_str(vm)?;
+    let lineno = lineno.py_str(vm)?;
+    let mut formatted = format!("{filename}:{lineno}: {category}: {message}\n");
+    if !matches!(line, Value::None) {
+        let line = line.py_str(vm)?;
+        let line = line.trim();
+        if !line.is_empty() {
+            formatted.push_str("  ");
+            formatted.push_str(line);
+            formatted.push('\n');
+        }
+    }
+    Ok(allocate_string(formatted, vm.heap)?)
+}
+
+/// Validates a filter action such as `'ignore'`.
+fn parse_action(action: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<WarningAction> {
+    if let Some(name) = action.as_either_str(vm.heap)
+        && let Ok(action) = name.as_str(vm.interns).parse()
+    {
+        return Ok(action);
+    }
+    let repr = action.py_repr(vm)?;
+    Err(SimpleException::new_msg(ExcType::ValueError, format!("invalid action: {repr}")).into())
+}
+
+/// Validates the `category` of a filter.
+fn filter_category(category: &Value) -> RunResult<ExcType> {
+    match category {
+        Value::Builtin(Builtins::ExcType(exc_type)) if exc_type.is_subclass_of(ExcType::Warning) => Ok(*exc_type),
+        _ => Err(ExcType::type_error("category must be a Warning subclass")),
+    }
+}
+
+/// Validates the `lineno` of a filter.
+fn filter_lineno(lineno: &Value) -> RunResult<u32> {
+    let lineno = match lineno {
+        Value::Int(lineno) => *lineno,
+        Value::Bool(lineno) => i64::from(*lineno),
+        _ => return Err(ExcType::type_error("lineno must be an int")),
+    };
+    u32::try_from(lineno)
+        .map_err(|_| SimpleException::new_msg(ExcType::ValueError, "lineno must be an int >= 0").into())
+}
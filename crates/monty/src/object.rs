@@ -21,11 +21,15 @@ use crate::{
     heap::{HeapData, HeapId, HeapReadOutput},
     resource::{ResourceError, ResourceTracker},
     types::{
-        Dataclass, LongInt, NamedTuple, OpenFile, Path, PyTrait, TimeZone, Type, allocate_tuple,
+        ByteArray, Complex, Dataclass, HostHandle, HostType, LongInt, NamedTuple, OpenFile, Path, PyTrait, TimeZone,
+        Type, allocate_tuple,
+        bytearray::bytearray_repr_fmt,
         bytes::{Bytes, bytes_repr},
+        complex::format_repr as complex_format_repr,
         date as date_type, datetime as datetime_type,
         dict::Dict,
         file::FileMode,
+        host_type::HostTypeKind,
         list::List,
         set::{FrozenSet, Set},
         str::{StringRepr, allocate_string, string_repr_fmt},
@@ -205,7 +209,7 @@ fn monty_datetime_naive(datetime: &MontyDateTime) -> Option<NaiveDateTime> {
 ///
 /// Only immutable variants implement `Hash`, including the datetime family
 /// (`Date`, `DateTime`, `TimeDelta`, `TimeZone`). Attempting to hash mutable
-/// variants (`List`, `Dict`) will panic.
+/// variants (`List`, `Dict`, `ByteArray`) will panic.
 ///
 /// # Serialization
 ///
@@ -240,10 +244,21 @@ pub enum MontyObject {
     BigInt(BigInt),
     /// Python float (64-bit IEEE 754).
     Float(f64),
+    /// Python complex number.
+    Complex {
+        /// The real part.
+        real: f64,
+        /// The imaginary part.
+        imag: f64,
+    },
     /// Python string (UTF-8).
     String(String),
     /// Python bytes object.
     Bytes(Vec<u8>),
+    /// Python bytearray object (mutable bytes).
+    ///
+    /// A `memoryview` has no variant of its own and is returned as `Bytes` of its contents.
+    ByteArray(Vec<u8>),
     /// Python list (mutable sequence).
     List(Vec<Self>),
     /// Python tuple (immutable sequence).
@@ -275,12 +290,15 @@ pub enum MontyObject {
     TimeDelta(MontyTimeDelta),
     /// Python `datetime.timezone` fixed-offset timezone.
     TimeZone(MontyTimeZone),
-    /// Python exception with type and optional message argument.
+    /// Python exception with type and constructor arguments.
     Exception {
         /// The exception type (e.g., `ValueError`, `TypeError`).
         exc_type: ExcType,
-        /// Optional string argument passed to the exception constructor.
-        arg: Option<String>,
+        /// The arguments passed to the exception constructor.
+        ///
+        /// For an `OSError` these include `filename` and `filename2`, which Python
+        /// keeps out of `exc.args`.
+        args: Vec<Self>,
     },
     /// A Python type object (e.g., `int`, `str`, `list`).
     ///
@@ -310,6 +328,29 @@ pub enum MontyObject {
         /// Whether this dataclass instance is immutable.
         frozen: bool,
     },
+    /// A dataclass type defined by the host.
+    ///
+    /// Lets sandbox code branch on the kind of `Dataclass` a tool returned:
+    /// `isinstance(x, T)` and `issubclass` match instances with the same `name` and
+    /// `type_id`, and `type(x)` on such an instance compares equal to `T`.
+    DataclassType {
+        /// The class name (e.g., "Point", "User").
+        name: String,
+        /// Identifier of the type, from `id(dc_type)` in python.
+        type_id: u64,
+        /// Declared field names in definition order.
+        field_names: Vec<String>,
+    },
+    /// A named tuple type defined by the host.
+    ///
+    /// Matches `NamedTuple` values with the same type name and field names, and
+    /// compares equal to `type(x)` of such a value.
+    NamedTupleType {
+        /// Type name (e.g., "os.stat_result").
+        type_name: String,
+        /// Field names in order.
+        field_names: Vec<String>,
+    },
     /// An external function provided by the host.
     ///
     /// Returned by the host in response to a `NameLookup` to provide a callable
@@ -320,6 +361,30 @@ pub enum MontyObject {
         /// Optional docstring for the function.
         docstring: Option<String>,
     },
+    /// An opaque handle to an object that stays on the host, e.g. a database cursor.
+    ///
+    /// The sandbox can't see the object's state: attribute access, item access, calls,
+    /// iteration, `len()` and truth tests on the handle pause execution with a
+    /// `HandleCall` asking the host to perform the operation.
+    HostHandle {
+        /// The class name shown by `repr()` and `type()` (e.g., "Cursor").
+        type_name: String,
+        /// Host-chosen identifier of the object.
+        handle_id: u64,
+    },
+    /// A function defined in the sandbox, passed as an argument to the host.
+    ///
+    /// The host can't run the function itself, but can call it back while the call it
+    /// was passed to is paused, by resuming with
+    /// [`ExtFunctionResult::Callback`](crate::ExtFunctionResult::Callback).
+    /// The id stays valid for the rest of the run, including across `dump`/`load`,
+    /// and passing the object back in gives sandbox code the original function.
+    Callback {
+        /// The function's name, `<lambda>` for lambdas.
+        name: String,
+        /// Identifies the function within the run.
+        callback_id: u32,
+    },
     /// Fallback for values that cannot be represented as other variants.
     ///
     /// Contains the `repr()` string of the original value.
@@ -356,7 +421,17 @@ impl MontyObject {
     ///
     /// The `interns` parameter is used to look up interned string/bytes content.
     pub(crate) fn new(value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> Self {
-        let py_obj = Self::from_value(&value, vm);
+        let py_obj = Self::from_value(&value, vm, false);
+        value.drop_with_heap(vm);
+        py_obj
+    }
+
+    /// Converts an argument passed to the host, like [`new`](Self::new), except that
+    /// functions defined in the sandbox become [`Callback`](Self::Callback)s the host
+    /// can call back, if it opted in to callbacks.
+    pub(crate) fn new_host_arg(value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> Self {
+        let callbacks = vm.callbacks_enabled();
+        let py_obj = Self::from_value(&value, vm, callbacks);
         value.drop_with_heap(vm);
         py_obj
     }
@@ -383,8 +458,10 @@ impl MontyObject {
             Self::Int(i) => Ok(Value::Int(i)),
             Self::BigInt(bi) => Ok(LongInt::new(bi).into_value(vm.heap)?),
             Self::Float(f) => Ok(Value::Float(f)),
+            Self::Complex { real, imag } => Ok(Complex::new(real, imag).into_value(vm.heap)?),
             Self::String(s) => Ok(allocate_string(s, vm.heap)?),
             Self::Bytes(b) => Ok(Value::Ref(vm.heap.allocate(HeapData::Bytes(Bytes::new(b)))?)),
+            Self::ByteArray(b) => Ok(Value::Ref(vm.heap.allocate(HeapData::ByteArray(ByteArray::new(b)))?)),
             Self::List(items) => {
                 let values: Vec<Value> = items
                     .into_iter()
@@ -496,8 +573,8 @@ impl MontyObject {
                     Ok(Value::Ref(vm.heap.allocate(HeapData::TimeZone(tz))?))
                 }
             }
-            Self::Exception { exc_type, arg } => {
-                let exc = SimpleException::new(exc_type, arg);
+            Self::Exception { exc_type, args } => {
+                let exc = SimpleException::from_args(exc_type, args);
                 Ok(Value::Ref(vm.heap.allocate(HeapData::Exception(exc))?))
             }
             Self::Dataclass {
@@ -517,7 +594,26 @@ impl MontyObject {
                 let dc = Dataclass::new(name, type_id, field_names, dict, frozen);
                 Ok(Value::Ref(vm.heap.allocate(HeapData::Dataclass(dc))?))
             }
+            Self::DataclassType {
+                name,
+                type_id,
+                field_names,
+            } => {
+                let class = HostType::dataclass(name, type_id, field_names);
+                Ok(Value::Ref(vm.heap.allocate(HeapData::HostType(class))?))
+            }
+            Self::NamedTupleType { type_name, field_names } => {
+                let class = HostType::named_tuple(type_name, field_names);
+                Ok(Value::Ref(vm.heap.allocate(HeapData::HostType(class))?))
+            }
             Self::Path(s) => Ok(Value::Ref(vm.heap.allocate(HeapData::Path(Path::new(s)))?)),
+            Self::HostHandle { type_name, handle_id } => {
+                let handle = HostHandle::new(type_name, handle_id);
+                Ok(Value::Ref(vm.heap.allocate(HeapData::HostHandle(handle))?))
+            }
+            Self::Callback { callback_id, .. } => vm
+                .callback(callback_id)
+                .ok_or_else(|| InvalidInputError::invalid_type(format!("unknown callback id {callback_id}"))),
             Self::FileHandle(handle) => {
                 let file = OpenFile::with_state(handle.path, handle.mode, handle.position);
                 Ok(Value::Ref(vm.heap.allocate(HeapData::OpenFile(file))?))
@@ -542,9 +638,12 @@ impl MontyObject {
 
     /// Top-level entry into [`from_value_inner`], allocating the visited-set used
     /// for cycle detection.
-    fn from_value(object: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> Self {
+    ///
+    /// `callbacks` says whether sandbox functions are registered as callbacks, which is
+    /// only useful to the host while the run is paused, or converted to their repr.
+    fn from_value(object: &Value, vm: &mut VM<'_, impl ResourceTracker>, callbacks: bool) -> Self {
         let mut visited = AHashSet::new();
-        Self::from_value_inner(object, vm, &mut visited)
+        Self::from_value_inner(object, vm, &mut visited, callbacks)
     }
 
     /// Internal helper for converting Value to MontyObject with cycle detection.
@@ -557,13 +656,25 @@ impl MontyObject {
     /// `clone_with_heap` the next child before recursing — the `inc_ref` makes
     /// it safe for a future user-defined `__repr__` to mutate the surrounding
     /// container during the recursive call without freeing the value mid-format.
-    fn from_value_inner(object: &Value, vm: &mut VM<'_, impl ResourceTracker>, visited: &mut AHashSet<HeapId>) -> Self {
+    fn from_value_inner(
+        object: &Value,
+        vm: &mut VM<'_, impl ResourceTracker>,
+        visited: &mut AHashSet<HeapId>,
+        callbacks: bool,
+    ) -> Self {
         // Check depth limit before processing
         let Ok(token) = vm.heap.incr_recursion_depth() else {
             return Self::Repr("<deeply nested>".to_owned());
         };
         defer_drop!(token, vm);
 
+        if callbacks
+            && let Some(name) = vm.sandbox_function_name(object)
+            && let Some(callback_id) = vm.register_callback(object)
+        {
+            return Self::Callback { name, callback_id };
+        }
+
         let interns = vm.interns;
         match object {
             Value::Undefined => panic!("Undefined found while converting to MontyObject"),
@@ -593,13 +704,19 @@ impl MontyObject {
                 let result = match vm.heap.read(*id) {
                     HeapReadOutput::Str(s) => Self::String(s.get(vm.heap).as_str().to_owned()),
                     HeapReadOutput::Bytes(b) => Self::Bytes(b.get(vm.heap).as_slice().to_owned()),
+                    HeapReadOutput::ByteArray(b) => Self::ByteArray(b.get(vm.heap).as_slice().to_owned()),
+                    HeapReadOutput::Complex(c) => {
+                        let c = *c.get(vm.heap);
+                        Self::Complex { real: c.re, imag: c.im }
+                    }
+                    HeapReadOutput::MemoryView(view) => Self::Bytes(view.get(vm.heap).contents(vm.heap).into_owned()),
                     HeapReadOutput::List(list) => {
                         let len = list.get(vm.heap).len();
                         let mut items = Vec::with_capacity(len);
                         for i in 0..len {
                             let item = list.get(vm.heap).as_slice()[i].clone_with_heap(vm.heap);
                             defer_drop!(item, vm);
-                            items.push(Self::from_value_inner(item, vm, visited));
+                            items.push(Self::from_value_inner(item, vm, visited, callbacks));
                         }
                         Self::List(items)
                     }
@@ -609,7 +726,7 @@ impl MontyObject {
                         for i in 0..len {
                             let item = tuple.get(vm.heap).as_slice()[i].clone_with_heap(vm.heap);
                             defer_drop!(item, vm);
-                            items.push(Self::from_value_inner(item, vm, visited));
+                            items.push(Self::from_value_inner(item, vm, visited, callbacks));
                         }
                         Self::Tuple(items)
                     }
@@ -626,7 +743,7 @@ impl MontyObject {
                         for i in 0..len {
                             let item = nt.get(vm.heap).as_vec()[i].clone_with_heap(vm.heap);
                             defer_drop!(item, vm);
-                            values.push(Self::from_value_inner(item, vm, visited));
+                            values.push(Self::from_value_inner(item, vm, visited, callbacks));
                         }
                         Self::NamedTuple {
                             type_name,
@@ -644,14 +761,14 @@ impl MontyObject {
                                 .expect("index in range")
                                 .clone_with_heap(vm.heap);
                             defer_drop!(key, vm);
-                            let k = Self::from_value_inner(key, vm, visited);
+                            let k = Self::from_value_inner(key, vm, visited, callbacks);
                             let value = dict
                                 .get(vm.heap)
                                 .value_at(i)
                                 .expect("index in range")
                                 .clone_with_heap(vm.heap);
                             defer_drop!(value, vm);
-                            let v = Self::from_value_inner(value, vm, visited);
+                            let v = Self::from_value_inner(value, vm, visited, callbacks);
                             pairs.push((k, v));
                         }
                         Self::Dict(DictPairs(pairs))
@@ -667,7 +784,7 @@ impl MontyObject {
                                 .expect("index in range")
                                 .clone_with_heap(vm.heap);
                             defer_drop!(item, vm);
-                            items.push(Self::from_value_inner(item, vm, visited));
+                            items.push(Self::from_value_inner(item, vm, visited, callbacks));
                         }
                         Self::Set(items)
                     }
@@ -682,7 +799,7 @@ impl MontyObject {
                                 .expect("index in range")
                                 .clone_with_heap(vm.heap);
                             defer_drop!(item, vm);
-                            items.push(Self::from_value_inner(item, vm, visited));
+                            items.push(Self::from_value_inner(item, vm, visited, callbacks));
                         }
                         Self::FrozenSet(items)
                     }
@@ -691,7 +808,7 @@ impl MontyObject {
                     HeapReadOutput::Cell(cell) => {
                         let inner = cell.get(vm.heap).0.clone_with_heap(vm.heap);
                         defer_drop!(inner, vm);
-                        Self::from_value_inner(inner, vm, visited)
+                        Self::from_value_inner(inner, vm, visited, callbacks)
                     }
                     HeapReadOutput::Date(d) => {
                         let (year, month, day) = date_type::to_ymd(*d.get(vm.heap));
@@ -739,7 +856,7 @@ impl MontyObject {
                         let exc_ref = exc.get(vm.heap);
                         Self::Exception {
                             exc_type: exc_ref.exc_type(),
-                            arg: exc_ref.arg().map(ToString::to_string),
+                            args: exc_ref.constructor_args(),
                         }
                     }
                     HeapReadOutput::Dataclass(dc) => {
@@ -762,7 +879,7 @@ impl MontyObject {
                                 .expect("index in range")
                                 .clone_with_heap(vm.heap);
                             defer_drop!(key, vm);
-                            let k = Self::from_value_inner(key, vm, visited);
+                            let k = Self::from_value_inner(key, vm, visited, callbacks);
                             let value = dc
                                 .get(vm.heap)
                                 .attrs()
@@ -770,7 +887,7 @@ impl MontyObject {
                                 .expect("index in range")
                                 .clone_with_heap(vm.heap);
                             defer_drop!(value, vm);
-                            let v = Self::from_value_inner(value, vm, visited);
+                            let v = Self::from_value_inner(value, vm, visited, callbacks);
                             pairs.push((k, v));
                         }
                         Self::Dataclass {
@@ -781,12 +898,30 @@ impl MontyObject {
                             frozen,
                         }
                     }
+                    HeapReadOutput::HostType(class) => {
+                        let class = class.get(vm.heap);
+                        let name = class.name().to_owned();
+                        let field_names = class.field_names().to_vec();
+                        match class.kind() {
+                            HostTypeKind::Dataclass { type_id } => Self::DataclassType {
+                                name,
+                                type_id,
+                                field_names,
+                            },
+                            HostTypeKind::NamedTuple => Self::NamedTupleType {
+                                type_name: name,
+                                field_names,
+                            },
+                            // Hosts can't pass handle classes in, so there's no input form to round-trip
+                            HostTypeKind::Handle => Self::Repr(format!("<class '{name}'>")),
+                        }
+                    }
                     // Iterators are internal objects — represent as a fixed type
                     // string rather than recursing.
                     HeapReadOutput::Iter(_) => Self::Repr("<iterator>".to_owned()),
                     HeapReadOutput::LongInt(li) => Self::BigInt(li.get(vm.heap).inner().clone()),
                     HeapReadOutput::Module(m) => {
-                        Self::Repr(format!("<module '{}'>", vm.interns.get_str(m.get(vm.heap).name())))
+                        Self::Repr(format!("<module '{}'>", m.get(vm.heap).name().as_str(vm.interns)))
                     }
                     HeapReadOutput::Coroutine(coro) => {
                         let func_id = coro.get(vm.heap).func_id;
@@ -813,6 +948,13 @@ impl MontyObject {
                         name: name.get(vm.heap).clone(),
                         docstring: None,
                     },
+                    HeapReadOutput::HostHandle(handle) => {
+                        let handle = handle.get(vm.heap);
+                        Self::HostHandle {
+                            type_name: handle.type_name().to_owned(),
+                            handle_id: handle.handle_id(),
+                        }
+                    }
                     _ => repr_or_error(object, vm),
                 };
 
@@ -882,8 +1024,10 @@ impl MontyObject {
                 }
                 Ok(())
             }
+            Self::Complex { real, imag } => f.write_str(&complex_format_repr(Complex::new(*real, *imag))),
             Self::String(s) => string_repr_fmt(s, f),
             Self::Bytes(b) => f.write_str(&bytes_repr(b)),
+            Self::ByteArray(b) => bytearray_repr_fmt(b, f),
             Self::List(l) => {
                 f.write_char('[')?;
                 let mut iter = l.iter();
@@ -1039,15 +1183,7 @@ impl MontyObject {
                 }
                 f.write_char(')')
             }
-            Self::Exception { exc_type, arg } => {
-                let type_str: &'static str = exc_type.into();
-                write!(f, "{type_str}(")?;
-
-                if let Some(arg) = &arg {
-                    string_repr_fmt(arg, f)?;
-                }
-                f.write_char(')')
-            }
+            Self::Exception { exc_type, args } => SimpleException::from_args(*exc_type, args.clone()).py_repr_fmt(f),
             Self::Dataclass {
                 name,
                 field_names,
@@ -1076,11 +1212,15 @@ impl MontyObject {
                 }
                 f.write_char(')')
             }
+            Self::DataclassType { name, .. } => write!(f, "<class '{name}'>"),
+            Self::NamedTupleType { type_name, .. } => write!(f, "<class '{type_name}'>"),
             Self::Path(p) => write!(f, "PosixPath('{p}')"),
             Self::FileHandle(handle) => write!(f, "{handle}"),
             Self::Type(t) => write!(f, "<class '{t}'>"),
             Self::BuiltinFunction(func) => write!(f, "<built-in function {func}>"),
             Self::Function { name, .. } => write!(f, "<function '{name}' external>"),
+            Self::HostHandle { type_name, handle_id } => write!(f, "<{type_name} handle {handle_id}>"),
+            Self::Callback { name, .. } => write!(f, "<function '{name}' callback>"),
             Self::Repr(s) => write!(f, "Repr({})", StringRepr(s)),
             Self::Cycle(_, placeholder) => f.write_str(placeholder),
         }
@@ -1104,8 +1244,9 @@ impl MontyObject {
             Self::Int(i) => *i != 0,
             Self::BigInt(bi) => !bi.is_zero(),
             Self::Float(f) => *f != 0.0,
+            Self::Complex { real, imag } => *real != 0.0 || *imag != 0.0,
             Self::String(s) => !s.is_empty(),
-            Self::Bytes(b) => !b.is_empty(),
+            Self::Bytes(b) | Self::ByteArray(b) => !b.is_empty(),
             Self::List(l) => !l.is_empty(),
             Self::Tuple(t) => !t.is_empty(),
             Self::NamedTuple { values, .. } => !values.is_empty(),
@@ -1120,9 +1261,16 @@ impl MontyObject {
             Self::Path(_) => true,           // Path instances are always truthy
             Self::FileHandle { .. } => true, // File objects are always truthy
             Self::Dataclass { .. } => true,  // Dataclass instances are always truthy
-            Self::Type(_) | Self::BuiltinFunction(_) | Self::Function { .. } | Self::Repr(_) | Self::Cycle(_, _) => {
-                true
-            }
+            Self::Type(_)
+            | Self::DataclassType { .. }
+            | Self::NamedTupleType { .. }
+            | Self::BuiltinFunction(_)
+            | Self::Function { .. }
+            | Self::Callback { .. }
+            | Self::Repr(_)
+            | Self::Cycle(_, _) => true,
+            // The host decides the truth of the object behind a handle; the handle itself is truthy
+            Self::HostHandle { .. } => true,
         }
     }
 
@@ -1137,8 +1285,10 @@ impl MontyObject {
             Self::Bool(_) => "bool",
             Self::Int(_) | Self::BigInt(_) => "int",
             Self::Float(_) => "float",
+            Self::Complex { .. } => "complex",
             Self::String(_) => "str",
             Self::Bytes(_) => "bytes",
+            Self::ByteArray(_) => "bytearray",
             Self::List(_) => "list",
             Self::Tuple(_) => "tuple",
             Self::NamedTuple { .. } => "namedtuple",
@@ -1153,9 +1303,10 @@ impl MontyObject {
             Self::Path(_) => "PosixPath",
             Self::FileHandle(handle) => handle.mode.type_name(),
             Self::Dataclass { .. } => "dataclass",
-            Self::Type(_) => "type",
+            Self::Type(_) | Self::DataclassType { .. } | Self::NamedTupleType { .. } => "type",
             Self::BuiltinFunction(_) => "builtin_function_or_method",
-            Self::Function { .. } => "function",
+            Self::Function { .. } | Self::Callback { .. } => "function",
+            Self::HostHandle { .. } => "handle",
             Self::Repr(_) => "repr",
             Self::Cycle(_, _) => "cycle",
         }
@@ -1187,6 +1338,10 @@ impl Hash for MontyObject {
                 }
             }
             Self::Float(f) => f.to_bits().hash(state),
+            Self::Complex { real, imag } => {
+                real.to_bits().hash(state);
+                imag.to_bits().hash(state);
+            }
             Self::String(string) => string.hash(state),
             Self::Bytes(bytes) => bytes.hash(state),
             Self::Date(date) => date.hash(state),
@@ -1200,6 +1355,19 @@ impl Hash for MontyObject {
                 position.hash(state);
             }
             Self::Type(t) => t.to_string().hash(state),
+            Self::DataclassType { name, type_id, .. } => {
+                name.hash(state);
+                type_id.hash(state);
+            }
+            Self::NamedTupleType { type_name, field_names } => {
+                type_name.hash(state);
+                field_names.hash(state);
+            }
+            Self::HostHandle { type_name, handle_id } => {
+                type_name.hash(state);
+                handle_id.hash(state);
+            }
+            Self::Callback { callback_id, .. } => callback_id.hash(state),
             Self::Cycle(_, _) => panic!("cycle values are not hashable"),
             _ => panic!("{} python values are not hashable", self.type_name()),
         }
@@ -1218,8 +1386,19 @@ impl PartialEq for MontyObject {
             (Self::Int(a), Self::BigInt(b)) | (Self::BigInt(b), Self::Int(a)) => BigInt::from(*a) == *b,
             // Use to_bits() for float comparison to be consistent with Hash
             (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
+            (
+                Self::Complex {
+                    real: a_real,
+                    imag: a_imag,
+                },
+                Self::Complex {
+                    real: b_real,
+                    imag: b_imag,
+                },
+            ) => a_real.to_bits() == b_real.to_bits() && a_imag.to_bits() == b_imag.to_bits(),
             (Self::String(a), Self::String(b)) => a == b,
             (Self::Bytes(a), Self::Bytes(b)) => a == b,
+            (Self::ByteArray(a), Self::ByteArray(b)) => a == b,
             (Self::List(a), Self::List(b)) => a == b,
             (Self::Tuple(a), Self::Tuple(b)) => a == b,
             (Self::Date(a), Self::Date(b)) => a == b,
@@ -1248,13 +1427,13 @@ impl PartialEq for MontyObject {
             (
                 Self::Exception {
                     exc_type: a_type,
-                    arg: a_arg,
+                    args: a_args,
                 },
                 Self::Exception {
                     exc_type: b_type,
-                    arg: b_arg,
+                    args: b_args,
                 },
-            ) => a_type == b_type && a_arg == b_arg,
+            ) => a_type == b_type && a_args == b_args,
             (
                 Self::Dataclass {
                     name: a_name,
@@ -1300,9 +1479,42 @@ impl PartialEq for MontyObject {
                     docstring: b_doc,
                 },
             ) => a_name == b_name && a_doc == b_doc,
+            (
+                Self::HostHandle {
+                    type_name: a_name,
+                    handle_id: a_id,
+                },
+                Self::HostHandle {
+                    type_name: b_name,
+                    handle_id: b_id,
+                },
+            ) => a_name == b_name && a_id == b_id,
+            (Self::Callback { callback_id: a, .. }, Self::Callback { callback_id: b, .. }) => a == b,
             (Self::Repr(a), Self::Repr(b)) => a == b,
             (Self::Cycle(a, _), Self::Cycle(b, _)) => a == b,
             (Self::Type(a), Self::Type(b)) => a == b,
+            (
+                Self::DataclassType {
+                    name: a_name,
+                    type_id: a_type_id,
+                    field_names: a_field_names,
+                },
+                Self::DataclassType {
+                    name: b_name,
+                    type_id: b_type_id,
+                    field_names: b_field_names,
+                },
+            ) => a_name == b_name && a_type_id == b_type_id && a_field_names == b_field_names,
+            (
+                Self::NamedTupleType {
+                    type_name: a_name,
+                    field_names: a_field_names,
+                },
+                Self::NamedTupleType {
+                    type_name: b_name,
+                    field_names: b_field_names,
+                },
+            ) => a_name == b_name && a_field_names == b_field_names,
             _ => false,
         }
     }
@@ -13,7 +13,9 @@
 //!   `List` → `[...]`, `None` → `null`, `Dict` → JSON object).
 //! - Non-JSON-native values are wrapped in a single-key object with a
 //!   `$`-prefixed discriminator (e.g. `Tuple` → `{"$tuple":[...]}`,
-//!   `Bytes` → `{"$bytes":[...]}`, `Exception` → `{"$exception":{...}}`).
+//!   `Bytes` → `{"$bytes":[...]}`, `ByteArray` → `{"$bytearray":[...]}`,
+//!   `Complex` → `{"$complex":[real, imag]}`,
+//!   `Exception` → `{"$exception":{"type":"ValueError","args":[...]}}`).
 //! - `...` (Ellipsis) serializes as `{"$ellipsis": "..."}` so it's
 //!   unambiguously distinguishable from a plain string `"..."` while
 //!   staying consistent with the other `$`-tagged non-JSON-native shapes.
@@ -23,7 +25,9 @@
 //!   collapsing them with `None`.
 //! - Dataclasses and namedtuples are emitted as two-key objects carrying
 //!   both the instance's attribute/field data and its class name:
-//!   `{"$dataclass": {"x": 1, "y": 2}, "name": "Point"}`.
+//!   `{"$dataclass": {"x": 1, "y": 2}, "name": "Point"}`. Host handles and
+//!   callbacks use the same shape with their id: `{"$handle": 3, "name": "Cursor"}`,
+//!   `{"$callback": 0, "name": "process"}`.
 //! - Dates and timezones serialize as structured objects so fields are
 //!   accessible to consumers without parsing ISO strings.
 //! - Dicts whose keys are all Python strings serialize as a normal JSON
@@ -115,6 +119,11 @@ impl Serialize for JsonMontyObject<'_> {
                     serialize_tagged(serializer, "$float", &s)
                 }
             }
+            MontyObject::Complex { real, imag } => serialize_tagged_seq(
+                serializer,
+                "$complex",
+                &[MontyObject::Float(*real), MontyObject::Float(*imag)],
+            ),
             MontyObject::String(s) => serializer.serialize_str(s),
             MontyObject::List(items) => serialize_seq(serializer, items),
             MontyObject::Dict(pairs) => serialize_dict(serializer, pairs),
@@ -129,6 +138,7 @@ impl Serialize for JsonMontyObject<'_> {
             MontyObject::Set(items) => serialize_tagged_seq(serializer, "$set", items),
             MontyObject::FrozenSet(items) => serialize_tagged_seq(serializer, "$frozenset", items),
             MontyObject::Bytes(bytes) => serialize_tagged(serializer, "$bytes", bytes),
+            MontyObject::ByteArray(bytes) => serialize_tagged(serializer, "$bytearray", bytes),
             MontyObject::NamedTuple {
                 type_name,
                 field_names,
@@ -139,14 +149,14 @@ impl Serialize for JsonMontyObject<'_> {
                 &FieldsBody { field_names, values },
                 type_name,
             ),
-            MontyObject::Exception { exc_type, arg } => {
+            MontyObject::Exception { exc_type, args } => {
                 let type_str: &'static str = exc_type.into();
                 serialize_tagged(
                     serializer,
                     "$exception",
                     &ExceptionBody {
                         exc_type: type_str,
-                        arg: arg.as_deref(),
+                        args: (!args.is_empty()).then_some(JsonMontyArray(args)),
                     },
                 )
             }
@@ -164,8 +174,16 @@ impl Serialize for JsonMontyObject<'_> {
                 serialize_named(serializer, "$dataclass", &AttrsBody(attrs), name)
             }
             MontyObject::Type(t) => serialize_tagged(serializer, "$type", &TypeName(*t)),
+            // Host classes use the same shape as builtin types, keyed by their class name.
+            MontyObject::DataclassType { name, .. } | MontyObject::NamedTupleType { type_name: name, .. } => {
+                serialize_tagged(serializer, "$type", name)
+            }
             MontyObject::BuiltinFunction(f) => serialize_tagged(serializer, "$builtin", &DisplayAsStr(f)),
             MontyObject::Function { name, .. } => serialize_tagged(serializer, "$function", name),
+            MontyObject::HostHandle { type_name, handle_id } => {
+                serialize_named(serializer, "$handle", handle_id, type_name)
+            }
+            MontyObject::Callback { name, callback_id } => serialize_named(serializer, "$callback", callback_id, name),
             MontyObject::Repr(s) => serialize_tagged(serializer, "$repr", s),
             MontyObject::Cycle(_, placeholder) => serialize_tagged(serializer, "$cycle", placeholder),
         }
@@ -362,13 +380,13 @@ impl Serialize for AttrsBody<'_> {
     }
 }
 
-/// Body of an `$exception` tag — omits `arg` when `None` for a tighter shape.
+/// Body of an `$exception` tag — omits `args` when empty for a tighter shape.
 #[derive(Serialize)]
 struct ExceptionBody<'a> {
     #[serde(rename = "type")]
     exc_type: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
-    arg: Option<&'a str>,
+    args: Option<JsonMontyArray<'a>>,
 }
 
 /// Body of a `$file` tag — the open file's path, mode, and position.
@@ -18,7 +18,7 @@
 //! and the other inherent methods, then wire the new variant into the fs/
 //! dispatcher and any host backends.
 
-use std::{fmt, ops::Deref};
+use std::{borrow::Cow, fmt, ops::Deref};
 
 use crate::{
     ExcType, MontyException, MontyObject,
@@ -28,7 +28,7 @@ use crate::{
     heap::{ContainsHeap, DropWithHeap, Heap, HeapData},
     intern::{Interns, StaticStrings},
     resource::ResourceTracker,
-    types::{file::FileMode, str::StringRepr},
+    types::{bytes::bytes_like_contents, file::FileMode, str::StringRepr},
     value::Value,
 };
 
@@ -612,14 +612,7 @@ fn value_to_owned_string(value: &Value, heap: &Heap<impl ResourceTracker>, inter
 
 /// Owned `Vec<u8>` if `value` is a `bytes` (interned or heap), else `None`.
 fn value_to_owned_bytes(value: &Value, heap: &Heap<impl ResourceTracker>, interns: &Interns) -> Option<Vec<u8>> {
-    match value {
-        Value::InternBytes(id) => Some(interns.get_bytes(*id).to_owned()),
-        Value::Ref(id) => match heap.get(*id) {
-            HeapData::Bytes(b) => Some(b.as_slice().to_owned()),
-            _ => None,
-        },
-        _ => None,
-    }
+    bytes_like_contents(value, heap, interns).map(Cow::into_owned)
 }
 
 // =============================================================================
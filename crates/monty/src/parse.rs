@@ -16,8 +16,8 @@ use crate::{
     exception_private::ExcType,
     exception_public::{MontyException, SourceMap},
     expressions::{
-        AssignTarget, Callable, CmpOperator, Comprehension, DictItem, Expr, ExprLoc, Identifier, ImportName, Literal,
-        Node, Operator, SequenceItem, UnpackTarget,
+        AssignTarget, Callable, ClassField, CmpOperator, Comprehension, DictItem, Expr, ExprLoc, Identifier,
+        ImportName, Literal, Node, Operator, SequenceItem, UnpackTarget,
     },
     fstring::{ConversionFlag, FStringPart, FormatSpec, ParseFormatSpecError, ParsedFormatSpec, encode_format_spec},
     intern::{InternerBuilder, StringId},
@@ -84,7 +84,7 @@ impl ParsedSignature {
 /// Contains the function name, signature, and body as parsed AST nodes.
 /// During the prepare phase, this is transformed into `PreparedFunctionDef`
 /// with resolved names and scope information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RawFunctionDef {
     /// The function name identifier (not yet resolved to a namespace index).
     pub name: Identifier,
@@ -92,10 +92,33 @@ pub struct RawFunctionDef {
     pub signature: ParsedSignature,
     /// The unprepared function body (names not yet resolved).
     pub body: Vec<ParseNode>,
+    /// Synthesized `__annotate__` lambda returning the parameter and return annotations
+    /// as a dict, or `None` when the function has no annotations.
+    ///
+    /// Annotations are evaluated lazily (PEP 649, the Python 3.14 default): the lambda is
+    /// created in the enclosing scope at definition time but only called when
+    /// `__annotations__` is read, so annotations naming undefined or
+    /// `TYPE_CHECKING`-only symbols don't break the `def`.
+    pub annotate: Option<ExprLoc>,
     /// Whether this is an async function (`async def`).
     pub is_async: bool,
 }
 
+/// A raw (unprepared) class definition from the parser.
+///
+/// Classes only exist as dataclass declarations: the body is reduced to the
+/// annotated fields and the methods. During the prepare phase this becomes a
+/// `PreparedClassDef`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RawClassDef {
+    /// The interned class name.
+    pub name_id: StringId,
+    /// Annotated fields in declaration order.
+    pub fields: Vec<ClassField>,
+    /// Methods in declaration order; their names are not bound in the enclosing scope.
+    pub methods: Vec<RawFunctionDef>,
+}
+
 /// Type alias for parsed AST nodes (output of the parser).
 ///
 /// This uses `Node<RawFunctionDef>` where function definitions contain their
@@ -145,7 +168,21 @@ pub(crate) fn parse_with_interner(
     filename: &str,
     interner: InternerBuilder,
 ) -> Result<ParseResult, ParseError> {
-    let mut parser = Parser::new(code, filename, interner);
+    parse_in_package(code, filename, interner, "")
+}
+
+/// Parses the code of a module inside `package`, resolving relative imports against it.
+///
+/// `package` is the dotted name of the package the module belongs to (the package
+/// itself for an `__init__.py`), or empty for a top-level module, in which case
+/// relative imports fail like they do in the main script.
+pub(crate) fn parse_in_package(
+    code: &str,
+    filename: &str,
+    interner: InternerBuilder,
+    package: &str,
+) -> Result<ParseResult, ParseError> {
+    let mut parser = Parser::new(code, filename, interner, package);
     let parsed =
         parse_module(code).map_err(|e| ParseError::syntax(e.error.to_string(), parser.convert_range(e.range())))?;
     let module = parsed.into_syntax();
@@ -170,19 +207,55 @@ pub struct Parser<'a> {
     /// Starts at MAX_NESTING_DEPTH and decrements on each nested level.
     /// When it reaches zero, we return a "Source is too deeply nested" syntax error.
     depth_remaining: u16,
+    /// Dotted name of the package relative imports resolve against, empty outside a package.
+    package: &'a str,
 }
 
 impl<'a> Parser<'a> {
-    fn new(code: &'a str, filename: &'a str, mut interner: InternerBuilder) -> Self {
+    fn new(code: &'a str, filename: &'a str, mut interner: InternerBuilder, package: &'a str) -> Self {
         let filename_id = interner.intern(filename);
         Self {
             code,
             filename_id,
             interner,
             depth_remaining: MAX_NESTING_DEPTH,
+            package,
         }
     }
 
+    /// Resolves the module of `from <dots><module> import ...` against the current package.
+    ///
+    /// One dot refers to the package itself, each further dot to its parent.
+    fn resolve_relative_import(
+        &self,
+        module: Option<&str>,
+        level: u32,
+        position: CodeRange,
+    ) -> Result<String, ParseError> {
+        if self.package.is_empty() {
+            return Err(ParseError::import_error(
+                "attempted relative import with no known parent package",
+                position,
+            ));
+        }
+        let mut base = self.package;
+        for _ in 1..level {
+            match base.rsplit_once('.') {
+                Some((parent, _)) => base = parent,
+                None => {
+                    return Err(ParseError::import_error(
+                        "attempted relative import beyond top-level package",
+                        position,
+                    ));
+                }
+            }
+        }
+        Ok(match module {
+            Some(module) => format!("{base}.{module}"),
+            None => base.to_owned(),
+        })
+    }
+
     fn parse_statements(
         &mut self,
         statements: impl IntoIterator<Item = Stmt, IntoIter: ExactSizeIterator>,
@@ -241,6 +314,157 @@ impl<'a> Parser<'a> {
         Ok(tail)
     }
 
+    /// Parses a `def` statement into a raw function definition.
+    ///
+    /// Used both for module/function-level definitions and for methods in class bodies.
+    fn parse_function_def(&mut self, function: ast::StmtFunctionDef) -> Result<RawFunctionDef, ParseError> {
+        let params = &function.parameters;
+
+        // Parse positional-only parameters (before /)
+        let pos_args = self.parse_params_with_defaults(&params.posonlyargs)?;
+
+        // Parse positional-or-keyword parameters
+        let args = self.parse_params_with_defaults(&params.args)?;
+
+        // Parse *args
+        let var_args = params.vararg.as_ref().map(|p| self.interner.intern(&p.name.id));
+
+        // Parse keyword-only parameters (after * or *args)
+        let kwargs = self.parse_params_with_defaults(&params.kwonlyargs)?;
+
+        // Parse **kwargs
+        let var_kwargs = params.kwarg.as_ref().map(|p| self.interner.intern(&p.name.id));
+
+        let signature = ParsedSignature {
+            pos_args,
+            args,
+            var_args,
+            kwargs,
+            var_kwargs,
+        };
+
+        let annotate = self.parse_annotate(params, function.returns, function.range)?;
+
+        let name = self.identifier(&function.name.id, function.name.range);
+        // Parse function body recursively
+        let body = self.parse_statements(function.body)?;
+        let is_async = function.is_async;
+
+        Ok(RawFunctionDef {
+            name,
+            signature,
+            body,
+            annotate,
+            is_async,
+        })
+    }
+
+    /// Parses a `class` statement.
+    ///
+    /// Only dataclass-style classes are supported: no bases, at least one decorator,
+    /// and a body made of annotated fields (with optional defaults), methods, `pass`
+    /// and docstrings. The statement is lowered to `Name = dec(...(<class>))`, so the
+    /// decorators are evaluated before the class is built and applied innermost
+    /// first, as in CPython. Whether a decorator is actually `dataclass` is only
+    /// known at runtime.
+    fn parse_class_def(&mut self, class: ast::StmtClassDef) -> Result<ParseNode, ParseError> {
+        let ast::StmtClassDef {
+            decorator_list,
+            name,
+            type_params,
+            arguments,
+            body,
+            range,
+            ..
+        } = class;
+        let position = self.convert_range(range);
+        if arguments.is_some_and(|arguments| !arguments.args.is_empty() || !arguments.keywords.is_empty()) {
+            return Err(ParseError::not_implemented("class inheritance", position));
+        }
+        if type_params.is_some() {
+            return Err(ParseError::not_implemented("generic classes", position));
+        }
+        if decorator_list.is_empty() {
+            return Err(ParseError::not_implemented(
+                "classes without the @dataclass decorator",
+                position,
+            ));
+        }
+
+        let mut fields = Vec::new();
+        let mut methods = Vec::new();
+        for statement in body {
+            match statement {
+                // Docstrings and `...` placeholders
+                Stmt::Expr(ast::StmtExpr { value, .. })
+                    if matches!(*value, AstExpr::StringLiteral(_) | AstExpr::EllipsisLiteral(_)) => {}
+                Stmt::Pass(_) => {}
+                Stmt::AnnAssign(ast::StmtAnnAssign {
+                    target,
+                    annotation,
+                    value,
+                    range,
+                    ..
+                }) => {
+                    let AstExpr::Name(ast::ExprName { id, .. }) = *target else {
+                        return Err(ParseError::not_implemented(
+                            "class fields other than simple names",
+                            self.convert_range(range),
+                        ));
+                    };
+                    let name = self.interner.intern(&id);
+                    let annotation = self.parse_expression(*annotation)?;
+                    let default = match value {
+                        Some(value) => Some(self.parse_expression(*value)?),
+                        None => None,
+                    };
+                    fields.push(ClassField {
+                        name,
+                        annotation,
+                        default,
+                    });
+                }
+                Stmt::FunctionDef(function) => {
+                    // `@staticmethod`, `@property` etc. would silently change meaning if ignored
+                    if let Some(decorator) = function.decorator_list.first() {
+                        return Err(ParseError::not_implemented(
+                            "method decorators",
+                            self.convert_range(decorator.range),
+                        ));
+                    }
+                    methods.push(self.parse_function_def(function)?);
+                }
+                other => {
+                    return Err(ParseError::not_implemented(
+                        "statements other than fields and methods in class bodies",
+                        self.convert_range(other.range()),
+                    ));
+                }
+            }
+        }
+
+        let target = self.identifier(&name.id, name.range);
+        let mut object = ExprLoc::new(
+            position,
+            Expr::ClassRaw(Box::new(RawClassDef {
+                name_id: target.name_id,
+                fields,
+                methods,
+            })),
+        );
+        for decorator in decorator_list.into_iter().rev() {
+            let callable = self.parse_expression(decorator.expression)?;
+            object = ExprLoc::new(
+                position,
+                Expr::IndirectCall {
+                    callable: Box::new(callable),
+                    args: Box::new(ArgExprs::One(object)),
+                },
+            );
+        }
+        Ok(Node::Assign { target, object })
+    }
+
     /// Parses an exception handler (except clause).
     ///
     /// Handles `except:`, `except ExcType:`, and `except ExcType as name:` forms.
@@ -267,48 +491,8 @@ impl<'a> Parser<'a> {
 
     fn parse_statement_impl(&mut self, statement: Stmt) -> Result<ParseNode, ParseError> {
         match statement {
-            Stmt::FunctionDef(function) => {
-                let params = &function.parameters;
-
-                // Parse positional-only parameters (before /)
-                let pos_args = self.parse_params_with_defaults(&params.posonlyargs)?;
-
-                // Parse positional-or-keyword parameters
-                let args = self.parse_params_with_defaults(&params.args)?;
-
-                // Parse *args
-                let var_args = params.vararg.as_ref().map(|p| self.interner.intern(&p.name.id));
-
-                // Parse keyword-only parameters (after * or *args)
-                let kwargs = self.parse_params_with_defaults(&params.kwonlyargs)?;
-
-                // Parse **kwargs
-                let var_kwargs = params.kwarg.as_ref().map(|p| self.interner.intern(&p.name.id));
-
-                let signature = ParsedSignature {
-                    pos_args,
-                    args,
-                    var_args,
-                    kwargs,
-                    var_kwargs,
-                };
-
-                let name = self.identifier(&function.name.id, function.name.range);
-                // Parse function body recursively
-                let body = self.parse_statements(function.body)?;
-                let is_async = function.is_async;
-
-                Ok(Node::FunctionDef(RawFunctionDef {
-                    name,
-                    signature,
-                    body,
-                    is_async,
-                }))
-            }
-            Stmt::ClassDef(c) => Err(ParseError::not_implemented(
-                "class definitions",
-                self.convert_range(c.range),
-            )),
+            Stmt::FunctionDef(function) => Ok(Node::FunctionDef(self.parse_function_def(function)?)),
+            Stmt::ClassDef(class) => self.parse_class_def(class),
             Stmt::Return(ast::StmtReturn { value, .. }) => Ok(Node::Return(match value {
                 Some(value) => Some(self.parse_expression(*value)?),
                 None => None,
@@ -542,13 +726,20 @@ impl<'a> Parser<'a> {
                     .iter()
                     .map(|alias_node| {
                         let module_name = self.interner.intern(&alias_node.name);
-                        // The binding name is the alias if present, otherwise the module name
-                        let binding_name = alias_node
-                            .asname
-                            .as_ref()
-                            .map_or(module_name, |n| self.interner.intern(&n.id));
+                        // The binding name is the alias if present, otherwise the module name,
+                        // or its top-level package for a dotted name (`import a.b` binds `a`)
+                        let (binding_name, bind_top_level) =
+                            match (&alias_node.asname, alias_node.name.as_str().split_once('.')) {
+                                (Some(asname), _) => (self.interner.intern(&asname.id), false),
+                                (None, Some((top_level, _))) => (self.interner.intern(top_level), true),
+                                (None, None) => (module_name, false),
+                            };
                         let binding = Identifier::new(binding_name, position);
-                        ImportName { module_name, binding }
+                        ImportName {
+                            module_name,
+                            binding,
+                            bind_top_level,
+                        }
                     })
                     .collect();
                 Ok(Node::Import { names: import_names })
@@ -561,23 +752,14 @@ impl<'a> Parser<'a> {
                 ..
             }) => {
                 let position = self.convert_range(range);
-                // We only support absolute imports (level 0)
-                if level != 0 {
-                    return Err(ParseError::import_error(
-                        "attempted relative import with no known parent package",
-                        position,
-                    ));
-                }
-                // Module name is required for absolute imports
-                let module_name = match module {
-                    Some(m) => self.interner.intern(&m),
-                    None => {
-                        return Err(ParseError::import_error(
-                            "attempted relative import with no known parent package",
-                            position,
-                        ));
-                    }
+                let module = module.as_ref().map(|m| m.as_str());
+                let module_name = if level == 0 {
+                    // `from import x` isn't valid syntax, so absolute imports always name a module
+                    module.expect("absolute import names a module").to_owned()
+                } else {
+                    self.resolve_relative_import(module, level, position)?
                 };
+                let module_name = self.interner.intern(&module_name);
                 // Parse the imported names
                 let names = names
                     .iter()
@@ -1123,7 +1305,7 @@ impl<'a> Parser<'a> {
                         }
                     }
                     Number::Float(f) => Literal::Float(f),
-                    Number::Complex { .. } => return Err(ParseError::not_implemented("complex constants", position)),
+                    Number::Complex { imag, .. } => Literal::Imaginary(imag),
                 };
                 Ok(ExprLoc::new(position, Expr::Literal(const_value)))
             }
@@ -1485,6 +1667,78 @@ impl<'a> Parser<'a> {
             .collect()
     }
 
+    /// Builds the `__annotate__` lambda for a function definition.
+    ///
+    /// The lambda takes no parameters and returns a dict literal mapping each annotated
+    /// parameter name (in declaration order) and `'return'` to its annotation expression,
+    /// mirroring the dict CPython's `__annotate__` produces. Returns `None` when nothing
+    /// is annotated, so unannotated functions pay nothing at definition time.
+    fn parse_annotate(
+        &mut self,
+        params: &ast::Parameters,
+        returns: Option<Box<AstExpr>>,
+        range: TextRange,
+    ) -> Result<Option<ExprLoc>, ParseError> {
+        let annotated = params
+            .posonlyargs
+            .iter()
+            .chain(&params.args)
+            .map(|p| &p.parameter)
+            .chain(params.vararg.as_deref())
+            .chain(params.kwonlyargs.iter().map(|p| &p.parameter))
+            .chain(params.kwarg.as_deref())
+            .filter_map(|p| {
+                p.annotation
+                    .as_deref()
+                    .map(|annotation| (p.name.id.as_str(), annotation))
+            });
+
+        let mut items = Vec::new();
+        for (name, annotation) in annotated {
+            if let Some(value) = self.parse_annotation(annotation.clone())? {
+                let key = self.annotation_key(name, annotation.range());
+                items.push(DictItem::Pair(key, value));
+            }
+        }
+        if let Some(returns) = returns {
+            let key = self.annotation_key("return", returns.range());
+            if let Some(value) = self.parse_annotation(*returns)? {
+                items.push(DictItem::Pair(key, value));
+            }
+        }
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        let position = self.convert_range(range);
+        Ok(Some(ExprLoc::new(
+            position,
+            Expr::LambdaRaw {
+                name_id: self.interner.intern("__annotate__"),
+                signature: ParsedSignature::default(),
+                body: Box::new(ExprLoc::new(position, Expr::Dict(items))),
+            },
+        )))
+    }
+
+    /// Parses one annotation for an `__annotate__` dict.
+    ///
+    /// Annotations are only evaluated when `__annotations__` is read, so one Monty can't
+    /// parse (like `*args: *Ts`) is left out rather than failing the whole module.
+    fn parse_annotation(&mut self, annotation: AstExpr) -> Result<Option<ExprLoc>, ParseError> {
+        match self.parse_expression(annotation) {
+            Ok(value) => Ok(Some(value)),
+            Err(ParseError::NotImplemented { .. } | ParseError::NotSupported { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Creates the string-literal key used for one entry of an `__annotate__` dict.
+    fn annotation_key(&mut self, name: &str, range: TextRange) -> ExprLoc {
+        let string_id = self.interner.intern(name);
+        ExprLoc::new(self.convert_range(range), Expr::Literal(Literal::Str(string_id)))
+    }
+
     /// Parses comprehension generators (the `for ... in ... if ...` clauses).
     ///
     /// Each generator represents one `for` clause with zero or more `if` filters.
@@ -6,16 +6,31 @@ use crate::{
     args::{ArgExprs, CallArg, CallKwarg},
     builtins::Builtins,
     expressions::{
-        AssignTarget, Callable, CmpOperator, Comprehension, DictItem, Expr, ExprLoc, Identifier, ImportName, Literal,
-        NameScope, Node, Operator, PreparedFunctionDef, PreparedNode, SequenceItem, UnpackTarget,
+        AssignTarget, Callable, ClassField, CmpOperator, Comprehension, DictItem, Expr, ExprLoc, Identifier,
+        ImportName, Literal, NameScope, Node, Operator, PreparedClassDef, PreparedFunctionDef, PreparedNode,
+        SequenceItem, UnpackTarget,
     },
     fstring::{FStringPart, FormatSpec},
     intern::{InternerBuilder, StringId},
     namespace::NamespaceId,
-    parse::{CodeRange, ExceptHandler, ParseError, ParseNode, ParseResult, ParsedSignature, RawFunctionDef, Try},
+    parse::{
+        CodeRange, ExceptHandler, ParseError, ParseNode, ParseResult, ParsedSignature, RawClassDef, RawFunctionDef, Try,
+    },
     signature::Signature,
 };
 
+/// Creates the identifier of a function whose name is never bound in a namespace
+/// (lambdas and methods).
+fn unbound_name(name_id: StringId, position: CodeRange) -> Identifier {
+    // Slot 0 is the trivial placeholder; the name never lands in a namespace.
+    Identifier::new_with_scope(
+        name_id,
+        position,
+        NamespaceId::new(0).expect("slot 0 fits in u16"),
+        NameScope::Local,
+    )
+}
+
 /// Builds the `ParseError` raised when a scope's namespace would exceed
 /// `NamespaceId`'s `u16` capacity (the bytecode slot operand width).
 /// Hoisted so the cold error path stays out of inlined allocator calls.
@@ -86,6 +101,19 @@ pub struct PrepareResult {
     pub interner: InternerBuilder,
 }
 
+impl PrepareResult {
+    /// Interns the name of every global slot, returning `(slot, name)` pairs.
+    ///
+    /// The compiler records these on the module code so `globals()` can name
+    /// every slot, including input names and REPL globals from earlier snippets.
+    pub fn global_names(&mut self) -> Vec<(NamespaceId, StringId)> {
+        self.name_map
+            .iter()
+            .map(|(name, &slot)| (slot, self.interner.intern(name)))
+            .collect()
+    }
+}
+
 /// Prepares parsed nodes for compilation by resolving names and building the initial namespace.
 ///
 /// The namespace will be converted to runtime Objects when execution begins and the heap is available.
@@ -143,6 +171,26 @@ pub(crate) fn prepare_with_existing_names(
     })
 }
 
+/// Prepares the parsed nodes of a module supplied as a source file.
+///
+/// The module gets its own global namespace: its names are assigned slots from
+/// `first_slot` onwards, after the slots of every module compiled before it, so
+/// all modules share the VM's globals without sharing names. The last expression
+/// is not returned, as the module's frame returns the module object.
+pub(crate) fn prepare_source_module(parse_result: ParseResult, first_slot: usize) -> Result<PrepareResult, ParseError> {
+    let ParseResult { nodes, interner } = parse_result;
+    let mut p = Prepare::new_module_with_name_map(AHashMap::new(), &interner);
+    p.namespace_size = first_slot;
+    let prepared_nodes = p.prepare_nodes(nodes)?;
+
+    Ok(PrepareResult {
+        namespace_size: p.namespace_size,
+        name_map: p.name_map,
+        nodes: prepared_nodes,
+        interner,
+    })
+}
+
 /// State machine for the preparation phase that transforms parsed AST nodes into a prepared form.
 ///
 /// This struct maintains the mapping between variable names and their namespace indices,
@@ -595,9 +643,10 @@ impl<'i, 'g> Prepare<'i, 'g> {
                     name,
                     signature,
                     body,
+                    annotate,
                     is_async,
                 }) => {
-                    let func_node = self.prepare_function_def(name, &signature, body, is_async)?;
+                    let func_node = self.prepare_function_def(name, &signature, body, annotate, is_async)?;
                     new_nodes.push(func_node);
                 }
                 Node::Global { names, position } => {
@@ -721,6 +770,7 @@ impl<'i, 'g> Prepare<'i, 'g> {
                             Ok(ImportName {
                                 module_name: import_name.module_name,
                                 binding: resolved_binding,
+                                bind_top_level: import_name.bind_top_level,
                             })
                         })
                         .collect::<Result<_, _>>()?;
@@ -932,6 +982,8 @@ impl<'i, 'g> Prepare<'i, 'g> {
                 // Lambda should only be created during prepare, never during parsing
                 unreachable!("Expr::Lambda should not exist before prepare phase")
             }
+            Expr::ClassRaw(class_def) => return self.prepare_class(*class_def, position),
+            Expr::Class(_) => unreachable!("Expr::Class should not exist before prepare phase"),
             Expr::Slice { lower, upper, step } => Expr::Slice {
                 lower: lower.map(|e| self.prepare_expression(*e)).transpose()?.map(Box::new),
                 upper: upper.map(|e| self.prepare_expression(*e)).transpose()?.map(Box::new),
@@ -1327,6 +1379,7 @@ impl<'i, 'g> Prepare<'i, 'g> {
         name: Identifier,
         parsed_sig: &ParsedSignature,
         body: Vec<ParseNode>,
+        annotate: Option<ExprLoc>,
         is_async: bool,
     ) -> Result<PreparedNode, ParseError> {
         // Register the function name in the current scope; `def` binds the name.
@@ -1522,6 +1575,9 @@ impl<'i, 'g> Prepare<'i, 'g> {
             }
         }
 
+        // Like defaults, the `__annotate__` lambda is created in the enclosing scope
+        let annotate = annotate.map(|expr| self.prepare_expression(expr)).transpose()?;
+
         // Return the prepared function definition inline in the AST
         Ok(Node::FunctionDef(PreparedFunctionDef {
             name,
@@ -1532,6 +1588,7 @@ impl<'i, 'g> Prepare<'i, 'g> {
             cell_var_count,
             cell_param_indices,
             default_exprs,
+            annotate,
             is_async,
         }))
     }
@@ -1539,11 +1596,8 @@ impl<'i, 'g> Prepare<'i, 'g> {
     /// Prepares a lambda expression, converting it into a prepared function definition.
     ///
     /// Lambdas are essentially anonymous functions with an implicit return of their body
-    /// expression. This method follows the same preparation logic as `prepare_function_def`
-    /// but:
-    /// - Uses `<lambda>` as the function name (not registered in scope)
-    /// - Wraps the body expression as `Node::Return(body)`
-    /// - Returns `ExprLoc` with `Expr::Lambda` instead of `PreparedNode`
+    /// expression: the body is wrapped as `Node::Return(body)` and prepared with
+    /// `prepare_unbound_function` under the `<lambda>` name.
     fn prepare_lambda(
         &mut self,
         lambda_name_id: StringId,
@@ -1552,25 +1606,39 @@ impl<'i, 'g> Prepare<'i, 'g> {
         position: CodeRange,
     ) -> Result<ExprLoc, ParseError> {
         // Create a synthetic <lambda> name identifier (not registered in scope)
-        let lambda_name = Identifier::new_with_scope(
-            lambda_name_id,
-            position,
-            // Slot 0 is the trivial placeholder; the lambda name never lands
-            // in a namespace because lambdas don't have a binding name.
-            NamespaceId::new(0).expect("slot 0 fits in u16"),
-            NameScope::Local,
-        );
+        let lambda_name = unbound_name(lambda_name_id, position);
 
         // Wrap the body expression as a return statement for scope analysis
-        let body_as_node: ParseNode = Node::Return(Some(body.clone()));
-        let body_nodes = vec![body_as_node];
+        let body_nodes = vec![Node::Return(Some(body.clone()))];
+
+        // Lambdas are never async and have no annotations
+        let func_def = self.prepare_unbound_function(lambda_name, parsed_sig, body_nodes, None, false)?;
 
+        Ok(ExprLoc::new(
+            position,
+            Expr::Lambda {
+                func_def: Box::new(func_def),
+            },
+        ))
+    }
+
+    /// Prepares a function whose name is not bound in the enclosing scope (lambdas and methods).
+    ///
+    /// Follows the same preparation logic as `prepare_function_def`, but returns the
+    /// `PreparedFunctionDef` itself so the caller decides where the function value goes.
+    fn prepare_unbound_function(
+        &mut self,
+        name: Identifier,
+        parsed_sig: &ParsedSignature,
+        body: Vec<ParseNode>,
+        annotate: Option<ExprLoc>,
+        is_async: bool,
+    ) -> Result<PreparedFunctionDef, ParseError> {
         // Extract param names from the parsed signature for scope analysis
         let param_names: Vec<StringId> = parsed_sig.param_names().collect();
 
-        // Pass 1: Collect scope information from the lambda body
-        // (Lambdas can't have global/nonlocal declarations, but can have nested functions)
-        let scope_info = collect_function_scope_info(&body_nodes, &param_names, self.interner);
+        // Pass 1: Collect scope information from the function body
+        let scope_info = collect_function_scope_info(&body, &param_names, self.interner);
 
         // Build enclosing_locals: names that are local to this scope or captured from enclosing scope.
         // This includes free_vars so that nested lambdas can capture pass-through variables.
@@ -1581,7 +1649,7 @@ impl<'i, 'g> Prepare<'i, 'g> {
             for key in self.name_map.keys() {
                 locals.insert(key.clone());
             }
-            // Include free_vars so nested functions/lambdas can capture pass-through variables
+            // Include free_vars so nested functions can capture pass-through variables
             for key in self.free_var_map.keys() {
                 locals.insert(key.clone());
             }
@@ -1608,11 +1676,11 @@ impl<'i, 'g> Prepare<'i, 'g> {
                 .reborrow()
         };
 
-        // Pass 2: Create child preparer for lambda body with scope info
+        // Pass 2: Create child preparer for the function body with scope info
         let mut inner_prepare = Prepare::new_function(
-            body_nodes.len(),
+            body.len(),
             &param_names,
-            position,
+            name.position,
             scope_info.assigned_names,
             scope_info.global_names,
             scope_info.nonlocal_names,
@@ -1623,8 +1691,8 @@ impl<'i, 'g> Prepare<'i, 'g> {
             self.interner,
         )?;
 
-        // Prepare the lambda body
-        let prepared_body = inner_prepare.prepare_nodes(body_nodes)?;
+        // Prepare the function body
+        let prepared_body = inner_prepare.prepare_nodes(body)?;
 
         // Pull data out of `inner_prepare` and drop it so its `GlobalsRef`
         // borrow on `self.name_map` is released before we mutate `self.name_map`.
@@ -1639,7 +1707,7 @@ impl<'i, 'g> Prepare<'i, 'g> {
                 let slot = if let Some(existing) = self.name_map.get(captured_name) {
                     *existing
                 } else {
-                    let slot = self.alloc_slot(position)?;
+                    let slot = self.alloc_slot(name.position)?;
                     self.name_map.insert(captured_name.clone(), slot);
                     slot
                 };
@@ -1732,9 +1800,11 @@ impl<'i, 'g> Prepare<'i, 'g> {
             }
         }
 
-        // Create the prepared function definition (lambdas are never async)
-        let func_def = PreparedFunctionDef {
-            name: lambda_name,
+        // Like defaults, the `__annotate__` lambda is created in the enclosing scope
+        let annotate = annotate.map(|expr| self.prepare_expression(expr)).transpose()?;
+
+        Ok(PreparedFunctionDef {
+            name,
             signature,
             body: prepared_body,
             namespace_size,
@@ -1742,14 +1812,53 @@ impl<'i, 'g> Prepare<'i, 'g> {
             cell_var_count,
             cell_param_indices,
             default_exprs,
-            is_async: false,
-        };
+            annotate,
+            is_async,
+        })
+    }
+
+    /// Prepares a class definition.
+    ///
+    /// Field annotations and defaults are expressions of the enclosing scope; methods
+    /// are prepared like lambdas since their names live in the class, not in the
+    /// enclosing scope.
+    fn prepare_class(&mut self, class_def: RawClassDef, position: CodeRange) -> Result<ExprLoc, ParseError> {
+        let RawClassDef {
+            name_id,
+            fields,
+            methods,
+        } = class_def;
+
+        let mut prepared_fields = Vec::with_capacity(fields.len());
+        for field in fields {
+            let annotation = self.prepare_expression(field.annotation)?;
+            let default = field.default.map(|expr| self.prepare_expression(expr)).transpose()?;
+            prepared_fields.push(ClassField {
+                name: field.name,
+                annotation,
+                default,
+            });
+        }
+
+        let mut prepared_methods = Vec::with_capacity(methods.len());
+        for method in methods {
+            let name = unbound_name(method.name.name_id, method.name.position);
+            prepared_methods.push(self.prepare_unbound_function(
+                name,
+                &method.signature,
+                method.body,
+                method.annotate,
+                method.is_async,
+            )?);
+        }
 
         Ok(ExprLoc::new(
             position,
-            Expr::Lambda {
-                func_def: Box::new(func_def),
-            },
+            Expr::Class(Box::new(PreparedClassDef {
+                name_id,
+                fields: prepared_fields,
+                methods: prepared_methods,
+            })),
         ))
     }
 
@@ -2377,8 +2486,16 @@ fn collect_assigned_names_from_expr(expr: &ExprLoc, assigned_names: &mut AHashSe
                 collect_assigned_names_from_expr(e, assigned_names, interner);
             }
         }
-        // Lambda bodies have their own scope - walrus inside them doesn't affect us
-        Expr::LambdaRaw { .. } | Expr::Lambda { .. } => {}
+        Expr::ClassRaw(class_def) => {
+            for field in &class_def.fields {
+                collect_assigned_names_from_expr(&field.annotation, assigned_names, interner);
+                if let Some(default) = &field.default {
+                    collect_assigned_names_from_expr(default, assigned_names, interner);
+                }
+            }
+        }
+        // Lambda and method bodies have their own scope - walrus inside them doesn't affect us
+        Expr::LambdaRaw { .. } | Expr::Lambda { .. } | Expr::Class(_) => {}
         // Leaf expressions don't contain walrus operators
         Expr::Literal(_) | Expr::Builtin(_) | Expr::Name(_) => {}
     }
@@ -2464,42 +2581,7 @@ fn collect_cell_vars_from_node(
     interner: &InternerBuilder,
 ) {
     match node {
-        Node::FunctionDef(RawFunctionDef { signature, body, .. }) => {
-            // Find what names are referenced inside this nested function
-            let mut referenced = AHashSet::new();
-            for n in body {
-                collect_referenced_names_from_node(n, &mut referenced, interner);
-            }
-
-            // Extract param names from signature for scope analysis
-            let param_names: Vec<StringId> = signature.param_names().collect();
-
-            // Collect the nested function's own locals (params + assigned)
-            let nested_scope = collect_function_scope_info(body, &param_names, interner);
-
-            // Any name that is:
-            // - Referenced by the nested function
-            // - Not a local of the nested function
-            // - Not declared global in the nested function
-            // - In our locals
-            // becomes a cell_var
-            for name in &referenced {
-                if !nested_scope.assigned_names.contains(name)
-                    && !param_names.iter().any(|p| interner.get_str(*p) == name)
-                    && !nested_scope.global_names.contains(name)
-                    && our_locals.contains(name)
-                {
-                    cell_vars.insert(name.clone());
-                }
-            }
-
-            // Also check what the nested function explicitly declares as nonlocal
-            for name in &nested_scope.nonlocal_names {
-                if our_locals.contains(name) {
-                    cell_vars.insert(name.clone());
-                }
-            }
-        }
+        Node::FunctionDef(function) => collect_cell_vars_from_function(function, our_locals, cell_vars, interner),
         // Recurse into control flow structures
         Node::For {
             iter, body, or_else, ..
@@ -2747,8 +2829,78 @@ fn collect_cell_vars_from_expr(
         Expr::Await(value) => {
             collect_cell_vars_from_expr(value, our_locals, cell_vars, interner);
         }
+        Expr::ClassRaw(class_def) => {
+            for field in &class_def.fields {
+                collect_cell_vars_from_expr(&field.annotation, our_locals, cell_vars, interner);
+                if let Some(default) = &field.default {
+                    collect_cell_vars_from_expr(default, our_locals, cell_vars, interner);
+                }
+            }
+            for method in &class_def.methods {
+                collect_cell_vars_from_function(method, our_locals, cell_vars, interner);
+            }
+        }
         // Leaf expressions
-        Expr::Literal(_) | Expr::Builtin(_) | Expr::Name(_) | Expr::Lambda { .. } | Expr::Slice { .. } => {}
+        Expr::Literal(_)
+        | Expr::Builtin(_)
+        | Expr::Name(_)
+        | Expr::Lambda { .. }
+        | Expr::Class(_)
+        | Expr::Slice { .. } => {}
+    }
+}
+
+/// Finds the names of our scope captured by a nested function (or method) definition.
+fn collect_cell_vars_from_function(
+    function: &RawFunctionDef,
+    our_locals: &AHashSet<String>,
+    cell_vars: &mut AHashSet<String>,
+    interner: &InternerBuilder,
+) {
+    let RawFunctionDef {
+        signature,
+        body,
+        annotate,
+        ..
+    } = function;
+    // The `__annotate__` lambda is created in our scope, so it captures like any lambda
+    if let Some(annotate) = annotate {
+        collect_cell_vars_from_expr(annotate, our_locals, cell_vars, interner);
+    }
+
+    // Find what names are referenced inside this nested function
+    let mut referenced = AHashSet::new();
+    for n in body {
+        collect_referenced_names_from_node(n, &mut referenced, interner);
+    }
+
+    // Extract param names from signature for scope analysis
+    let param_names: Vec<StringId> = signature.param_names().collect();
+
+    // Collect the nested function's own locals (params + assigned)
+    let nested_scope = collect_function_scope_info(body, &param_names, interner);
+
+    // Any name that is:
+    // - Referenced by the nested function
+    // - Not a local of the nested function
+    // - Not declared global in the nested function
+    // - In our locals
+    // becomes a cell_var
+    for name in &referenced {
+        if !nested_scope.assigned_names.contains(name)
+            && !param_names.iter().any(|p| interner.get_str(*p) == name)
+            && !nested_scope.global_names.contains(name)
+            && our_locals.contains(name)
+        {
+            cell_vars.insert(name.clone());
+        }
+    }
+
+    // Also check what the nested function explicitly declares as nonlocal
+    for name in &nested_scope.nonlocal_names {
+        if our_locals.contains(name) {
+            cell_vars.insert(name.clone());
+        }
     }
 }
 
@@ -2904,8 +3056,12 @@ fn collect_referenced_names_from_node(node: &ParseNode, referenced: &mut AHashSe
                 collect_referenced_names_from_node(n, referenced, interner);
             }
         }
-        Node::FunctionDef(_) => {
-            // Don't recurse into nested function bodies - they have their own scope
+        Node::FunctionDef(RawFunctionDef { annotate, .. }) => {
+            // Don't recurse into nested function bodies - they have their own scope.
+            // The `__annotate__` lambda is created in our scope, so its references count.
+            if let Some(annotate) = annotate {
+                collect_referenced_names_from_expr(annotate, referenced, interner);
+            }
         }
         Node::Try(Try {
             body,
@@ -3062,6 +3218,28 @@ fn collect_referenced_names_from_expr(expr: &ExprLoc, referenced: &mut AHashSet<
             // Lambda should only exist after preparation; this function operates on raw expressions
             unreachable!("Expr::Lambda should not exist during scope analysis")
         }
+        Expr::ClassRaw(class_def) => {
+            for field in &class_def.fields {
+                collect_referenced_names_from_expr(&field.annotation, referenced, interner);
+                if let Some(default) = &field.default {
+                    collect_referenced_names_from_expr(default, referenced, interner);
+                }
+            }
+            // Like nested `def`s, method bodies have their own scope. Their annotations
+            // and defaults are evaluated in ours.
+            for method in &class_def.methods {
+                if let Some(annotate) = &method.annotate {
+                    collect_referenced_names_from_expr(annotate, referenced, interner);
+                }
+                let params = &method.signature;
+                for param in params.pos_args.iter().chain(&params.args).chain(&params.kwargs) {
+                    if let Some(default) = &param.default {
+                        collect_referenced_names_from_expr(default, referenced, interner);
+                    }
+                }
+            }
+        }
+        Expr::Class(_) => unreachable!("Expr::Class should not exist during scope analysis"),
         Expr::Named { value, .. } => {
             // Only the value is referenced; target is being assigned, not read
             collect_referenced_names_from_expr(value, referenced, interner);
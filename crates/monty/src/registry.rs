@@ -0,0 +1,532 @@
+//! Typed registry of external functions implemented in Rust.
+//!
+//! Rather than matching on [`FunctionCall::function_name`] and destructuring the
+//! arguments, embedders register closures with typed parameters in a
+//! [`FunctionRegistry`], and [`FunctionRegistry::run`] drives the run loop,
+//! answering name lookups and calls for them. Arguments are converted with
+//! [`FromMonty`] and return values with [`IntoMonty`].
+//!
+//! Each function's parameter names are declared as its signature (see
+//! [`MontyRun::with_signatures`]), so the sandbox can pass arguments by keyword and
+//! a bad call raises `TypeError` at the calling line before the host is involved.
+
+use std::{
+    future::poll_fn,
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    ExcType, MontyException, MontyObject,
+    convert::{FromMonty, FromMontyError, IntoMonty, TypeStubs},
+    function_signature::{FunctionSignature, Parameter},
+    io::PrintWriter,
+    resource::ResourceTracker,
+    run::MontyRun,
+    run_progress::{ExtFunctionResult, FunctionCall, ModuleLookupResult, NameLookupResult, RunProgress},
+};
+
+/// The pending result of an async host function.
+pub type HostFuture = Pin<Box<dyn Future<Output = Result<MontyObject, MontyException>>>>;
+
+/// Why calling a registered function failed.
+#[derive(Debug)]
+pub enum CallError {
+    /// The function got this many arguments instead of one per parameter.
+    ArgumentCount(usize),
+    /// The argument at this index couldn't be converted to its parameter's type.
+    Argument(usize, FromMontyError),
+    /// The function returned an error, which is raised in the sandbox.
+    Raised(MontyException),
+}
+
+/// A function that can be registered with [`FunctionRegistry::with_function`].
+///
+/// Implemented for closures and functions of up to 8 parameters that implement
+/// [`FromMonty`], returning `Result<R, E>` where `R` implements [`IntoMonty`] and
+/// `E` converts into the [`MontyException`] raised in the sandbox.
+pub trait HostFunction<Args>: 'static {
+    /// Number of parameters.
+    const ARITY: usize;
+
+    /// Whether each parameter's type accepts `None`, see [`FromMonty::OPTIONAL`].
+    fn optional_params() -> Vec<bool>;
+
+    /// Returns the annotations of the parameters and the return type.
+    fn type_hints(stubs: &mut TypeStubs) -> (Vec<String>, String);
+
+    /// Calls the function with one argument per parameter.
+    ///
+    /// # Errors
+    /// Returns [`CallError`] if an argument can't be converted or the function fails.
+    fn call(&self, args: Vec<MontyObject>) -> Result<MontyObject, CallError>;
+}
+
+/// An async function that can be registered with [`FunctionRegistry::with_async_function`].
+///
+/// Like [`HostFunction`], for closures returning a future of `Result<R, E>`.
+pub trait AsyncHostFunction<Args>: 'static {
+    /// Number of parameters.
+    const ARITY: usize;
+
+    /// Whether each parameter's type accepts `None`, see [`FromMonty::OPTIONAL`].
+    fn optional_params() -> Vec<bool>;
+
+    /// Returns the annotations of the parameters and the awaited return type.
+    fn type_hints(stubs: &mut TypeStubs) -> (Vec<String>, String);
+
+    /// Calls the function with one argument per parameter, returning its future.
+    ///
+    /// # Errors
+    /// Returns [`CallError`] if an argument can't be converted.
+    fn call(&self, args: Vec<MontyObject>) -> Result<HostFuture, CallError>;
+}
+
+/// Implements [`HostFunction`] and [`AsyncHostFunction`] for closures taking the given
+/// parameter types, which are converted from the arguments in order.
+macro_rules! impl_host_function {
+    ($len:literal; $($ty:ident $var:ident $index:literal),*) => {
+        impl<F, R, E, $($ty),*> HostFunction<($($ty,)*)> for F
+        where
+            F: Fn($($ty),*) -> Result<R, E> + 'static,
+            R: IntoMonty,
+            E: Into<MontyException>,
+            $($ty: FromMonty,)*
+        {
+            const ARITY: usize = $len;
+
+            fn optional_params() -> Vec<bool> {
+                vec![$($ty::OPTIONAL),*]
+            }
+
+            fn type_hints(stubs: &mut TypeStubs) -> (Vec<String>, String) {
+                (vec![$(<$ty as FromMonty>::type_hint(stubs)),*], R::type_hint(stubs))
+            }
+
+            fn call(&self, args: Vec<MontyObject>) -> Result<MontyObject, CallError> {
+                let [$($var),*] = <[MontyObject; $len]>::try_from(args)
+                    .map_err(|args| CallError::ArgumentCount(args.len()))?;
+                $(let $var = $ty::from_monty($var).map_err(|err| CallError::Argument($index, err))?;)*
+                self($($var),*)
+                    .map(R::into_monty)
+                    .map_err(|err| CallError::Raised(err.into()))
+            }
+        }
+
+        impl<F, Fut, R, E, $($ty),*> AsyncHostFunction<($($ty,)*)> for F
+        where
+            F: Fn($($ty),*) -> Fut + 'static,
+            Fut: Future<Output = Result<R, E>> + 'static,
+            R: IntoMonty,
+            E: Into<MontyException>,
+            $($ty: FromMonty,)*
+        {
+            const ARITY: usize = $len;
+
+            fn optional_params() -> Vec<bool> {
+                vec![$($ty::OPTIONAL),*]
+            }
+
+            fn type_hints(stubs: &mut TypeStubs) -> (Vec<String>, String) {
+                (vec![$(<$ty as FromMonty>::type_hint(stubs)),*], R::type_hint(stubs))
+            }
+
+            fn call(&self, args: Vec<MontyObject>) -> Result<HostFuture, CallError> {
+                let [$($var),*] = <[MontyObject; $len]>::try_from(args)
+                    .map_err(|args| CallError::ArgumentCount(args.len()))?;
+                $(let $var = $ty::from_monty($var).map_err(|err| CallError::Argument($index, err))?;)*
+                let future = self($($var),*);
+                Ok(Box::pin(async move { future.await.map(R::into_monty).map_err(Into::into) }))
+            }
+        }
+    };
+}
+
+impl_host_function!(0;);
+impl_host_function!(1; A0 a0 0);
+impl_host_function!(2; A0 a0 0, A1 a1 1);
+impl_host_function!(3; A0 a0 0, A1 a1 1, A2 a2 2);
+impl_host_function!(4; A0 a0 0, A1 a1 1, A2 a2 2, A3 a3 3);
+impl_host_function!(5; A0 a0 0, A1 a1 1, A2 a2 2, A3 a3 3, A4 a4 4);
+impl_host_function!(6; A0 a0 0, A1 a1 1, A2 a2 2, A3 a3 3, A4 a4 4, A5 a5 5);
+impl_host_function!(7; A0 a0 0, A1 a1 1, A2 a2 2, A3 a3 3, A4 a4 4, A5 a5 5, A6 a6 6);
+impl_host_function!(8; A0 a0 0, A1 a1 1, A2 a2 2, A3 a3 3, A4 a4 4, A5 a5 5, A6 a6 6, A7 a7 7);
+
+/// External functions implemented in Rust, called by name from the sandbox.
+///
+/// # Example
+/// ```
+/// use monty::{FunctionRegistry, MontyException, MontyObject, MontyRun, NoLimitTracker, PrintWriter};
+///
+/// fn scale(values: Vec<f64>, factor: Option<f64>) -> Result<Vec<f64>, MontyException> {
+///     let factor = factor.unwrap_or(2.0);
+///     Ok(values.into_iter().map(|v| v * factor).collect())
+/// }
+///
+/// let registry = FunctionRegistry::new().with_function("scale", &["values", "factor"], scale);
+///
+/// let runner = MontyRun::new("scale([1, 2.5])".to_owned(), "main.py", vec![]).unwrap();
+/// let result = registry.run(runner, vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+/// assert_eq!(result, MontyObject::List(vec![MontyObject::Float(2.0), MontyObject::Float(5.0)]));
+/// ```
+#[derive(Default)]
+pub struct FunctionRegistry {
+    /// Registered functions, in registration order for the stubs.
+    functions: Vec<RegisteredFunction>,
+}
+
+/// A function in a [`FunctionRegistry`].
+struct RegisteredFunction {
+    name: String,
+    params: Vec<String>,
+    /// Parameters from this index on accept `None`, and default to it.
+    first_optional: usize,
+    /// `HostFunction::type_hints` or `AsyncHostFunction::type_hints` of the function.
+    type_hints: fn(&mut TypeStubs) -> (Vec<String>, String),
+    handler: Handler,
+}
+
+/// A registered function with its argument types erased.
+enum Handler {
+    Sync(Box<dyn Fn(Vec<MontyObject>) -> Result<MontyObject, CallError>>),
+    Async(Box<dyn Fn(Vec<MontyObject>) -> Result<HostFuture, CallError>>),
+}
+
+/// How a registered function answered a call.
+enum Answer {
+    Ready(ExtFunctionResult),
+    Pending(HostFuture),
+}
+
+impl FunctionRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `function` as the external function `name`, with one parameter name
+    /// per argument, replacing any function already registered as `name`.
+    ///
+    /// Trailing parameters whose type is an `Option` can be omitted and default to `None`.
+    /// Giving a different number of parameter names than the function takes doesn't compile:
+    ///
+    /// ```compile_fail
+    /// use monty::{FunctionRegistry, MontyException};
+    ///
+    /// fn add(a: i64, b: i64) -> Result<i64, MontyException> {
+    ///     Ok(a + b)
+    /// }
+    ///
+    /// let registry = FunctionRegistry::new().with_function("add", &["a"], add);
+    /// ```
+    #[must_use]
+    pub fn with_function<Args: 'static, F: HostFunction<Args>, const N: usize>(
+        self,
+        name: &str,
+        params: &[&str; N],
+        function: F,
+    ) -> Self {
+        const {
+            assert!(
+                N == <F as HostFunction<Args>>::ARITY,
+                "the number of parameter names must match the function's parameters"
+            );
+        }
+        let handler = Handler::Sync(Box::new(move |args| HostFunction::call(&function, args)));
+        self.with_registered(name, params, &F::optional_params(), F::type_hints, handler)
+    }
+
+    /// Registers the async `function` as the external function `name`, like [`with_function`](Self::with_function).
+    ///
+    /// Sandbox code must `await` its calls, and the registry must be run with
+    /// [`run_async`](Self::run_async).
+    #[must_use]
+    pub fn with_async_function<Args: 'static, F: AsyncHostFunction<Args>, const N: usize>(
+        self,
+        name: &str,
+        params: &[&str; N],
+        function: F,
+    ) -> Self {
+        const {
+            assert!(
+                N == <F as AsyncHostFunction<Args>>::ARITY,
+                "the number of parameter names must match the function's parameters"
+            );
+        }
+        let handler = Handler::Async(Box::new(move |args| AsyncHostFunction::call(&function, args)));
+        self.with_registered(name, params, &F::optional_params(), F::type_hints, handler)
+    }
+
+    fn with_registered(
+        mut self,
+        name: &str,
+        params: &[&str],
+        optional: &[bool],
+        type_hints: fn(&mut TypeStubs) -> (Vec<String>, String),
+        handler: Handler,
+    ) -> Self {
+        let function = RegisteredFunction {
+            name: name.to_owned(),
+            params: params.iter().map(|&param| param.to_owned()).collect(),
+            first_optional: optional
+                .iter()
+                .rposition(|&is_optional| !is_optional)
+                .map_or(0, |index| index + 1),
+            type_hints,
+            handler,
+        };
+        self.functions.retain(|registered| registered.name != name);
+        self.functions.push(function);
+        self
+    }
+
+    /// Returns the signatures of the registered functions, as [`run`](Self::run) declares them.
+    #[must_use]
+    pub fn signatures(&self) -> Vec<FunctionSignature> {
+        self.functions
+            .iter()
+            .map(|function| {
+                let params = function
+                    .params
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| {
+                        let param = Parameter::new(name.clone());
+                        if index >= function.first_optional {
+                            param.with_default(MontyObject::None)
+                        } else {
+                            param
+                        }
+                    })
+                    .collect();
+                FunctionSignature::new(function.name.clone(), params)
+            })
+            .collect()
+    }
+
+    /// Returns a stub file declaring the registered functions and the types they use.
+    ///
+    /// Pass it to `monty_type_checking::type_check` as the stubs file, e.g. named
+    /// `type_stubs.pyi`, to type check code that calls the functions.
+    #[must_use]
+    pub fn type_stubs(&self) -> String {
+        let mut stubs = TypeStubs::default();
+        let functions: Vec<String> = self
+            .functions
+            .iter()
+            .map(|function| {
+                let (param_hints, return_hint) = (function.type_hints)(&mut stubs);
+                let params: Vec<String> = function
+                    .params
+                    .iter()
+                    .zip(param_hints)
+                    .enumerate()
+                    .map(|(index, (name, hint))| {
+                        if index >= function.first_optional {
+                            format!("{name}: {hint} = None")
+                        } else {
+                            format!("{name}: {hint}")
+                        }
+                    })
+                    .collect();
+                let def = match function.handler {
+                    Handler::Sync(_) => "def",
+                    Handler::Async(_) => "async def",
+                };
+                format!("{def} {}({}) -> {return_hint}: ...", function.name, params.join(", "))
+            })
+            .collect();
+        stubs.render(&functions.join("\n"))
+    }
+
+    /// Runs `runner` to completion, calling the registered functions.
+    ///
+    /// Names of registered functions resolve to them, other undefined names raise
+    /// `NameError`, OS calls and imports of unknown modules raise as they would with
+    /// no handler, and dataclass method calls and operations on host handles raise
+    /// `NotImplementedError`.
+    ///
+    /// # Errors
+    /// Returns the exception the code raised, an error declaring the functions'
+    /// signatures, or a `RuntimeError` if the code calls an async function.
+    pub fn run<T: ResourceTracker>(
+        &self,
+        runner: MontyRun,
+        inputs: Vec<MontyObject>,
+        resource_tracker: T,
+        print: PrintWriter<'_>,
+    ) -> Result<MontyObject, MontyException> {
+        let run = pin!(self.run_loop(runner, inputs, resource_tracker, print, false));
+        match run.poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(result) => result,
+            Poll::Pending => unreachable!("the loop only waits for async functions, which a sync run rejects"),
+        }
+    }
+
+    /// Runs `runner` to completion like [`run`](Self::run), also calling async functions.
+    ///
+    /// A call to an async function is a future in the sandbox. When all sandbox tasks
+    /// are waiting, the pending futures are polled together and the sandbox resumes
+    /// with the ones that finished.
+    ///
+    /// # Errors
+    /// Returns the exception the code raised, or an error declaring the functions' signatures.
+    pub async fn run_async<T: ResourceTracker>(
+        &self,
+        runner: MontyRun,
+        inputs: Vec<MontyObject>,
+        resource_tracker: T,
+        print: PrintWriter<'_>,
+    ) -> Result<MontyObject, MontyException> {
+        self.run_loop(runner, inputs, resource_tracker, print, true).await
+    }
+
+    async fn run_loop<T: ResourceTracker>(
+        &self,
+        runner: MontyRun,
+        inputs: Vec<MontyObject>,
+        resource_tracker: T,
+        mut print: PrintWriter<'_>,
+        allow_async: bool,
+    ) -> Result<MontyObject, MontyException> {
+        let runner = runner.with_signatures(self.signatures())?;
+        let mut pending: Vec<(u32, HostFuture)> = Vec::new();
+        let mut progress = runner.start(inputs, resource_tracker, print.reborrow())?;
+        loop {
+            progress = match progress {
+                RunProgress::Complete(value) => return Ok(value),
+                RunProgress::FunctionCall(mut call) => {
+                    let result = match self.answer(&mut call) {
+                        Answer::Ready(result) => result,
+                        Answer::Pending(future) if allow_async => {
+                            pending.push((call.call_id, future));
+                            ExtFunctionResult::Future(call.call_id)
+                        }
+                        Answer::Pending(_) => {
+                            return Err(MontyException::runtime_error(format!(
+                                "{}() is async, call it with FunctionRegistry::run_async",
+                                call.function_name
+                            )));
+                        }
+                    };
+                    call.resume(result, print.reborrow())?
+                }
+                RunProgress::ResolveFutures(state) => {
+                    if pending.is_empty() {
+                        return Err(MontyException::runtime_error(format!(
+                            "waiting for futures the registry didn't create: {:?}",
+                            state.pending_call_ids()
+                        )));
+                    }
+                    let results = poll_fn(|cx| {
+                        let mut results = Vec::new();
+                        pending.retain_mut(|(call_id, future)| match future.as_mut().poll(cx) {
+                            Poll::Ready(result) => {
+                                results.push((*call_id, ext_function_result(result)));
+                                false
+                            }
+                            Poll::Pending => true,
+                        });
+                        if results.is_empty() {
+                            Poll::Pending
+                        } else {
+                            Poll::Ready(results)
+                        }
+                    })
+                    .await;
+                    state.resume(results, print.reborrow())?
+                }
+                RunProgress::NameLookup(lookup) => {
+                    let result = if self.get(&lookup.name).is_some() {
+                        NameLookupResult::Value(MontyObject::Function {
+                            name: lookup.name.clone(),
+                            docstring: None,
+                        })
+                    } else {
+                        NameLookupResult::Undefined
+                    };
+                    lookup.resume(result, print.reborrow())?
+                }
+                RunProgress::OsCall(call) => {
+                    let exc = call.function_call.on_no_handler();
+                    call.resume(exc, print.reborrow())?
+                }
+                RunProgress::ModuleLookup(lookup) => lookup.resume(ModuleLookupResult::NotFound, print.reborrow())?,
+                RunProgress::HandleCall(call) => {
+                    let exc = not_implemented("Host handles are not supported by FunctionRegistry");
+                    call.resume(exc, print.reborrow())?
+                }
+                RunProgress::CallbackResult(result) => {
+                    // The registry never calls callbacks, so this is only a safeguard
+                    let exc = not_implemented("Callbacks are not supported by FunctionRegistry");
+                    result.resume(exc, print.reborrow())?
+                }
+            };
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&RegisteredFunction> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    /// Calls the registered function `call` is for, taking its bound arguments.
+    fn answer<T: ResourceTracker>(&self, call: &mut FunctionCall<T>) -> Answer {
+        if call.method_call {
+            let type_name = match call.args.first() {
+                Some(MontyObject::Dataclass { name, .. }) => name.as_str(),
+                Some(object) => object.type_name(),
+                None => "object",
+            };
+            let msg = format!(
+                "Dataclass methods are not supported by FunctionRegistry: can't call '{type_name}.{}()'",
+                call.function_name
+            );
+            return Answer::Ready(not_implemented(&msg).into());
+        }
+        // Calls to registered functions are bound to the signatures `run_loop` declares
+        let (Some(function), Some(bound_args)) = (self.get(&call.function_name), call.bound_args.take()) else {
+            return Answer::Ready(ExtFunctionResult::NotFound(call.function_name.clone()));
+        };
+        let args = bound_args.into_iter().map(|(_, value)| value).collect();
+        match &function.handler {
+            Handler::Sync(handler) => Answer::Ready(match handler(args) {
+                Ok(value) => ExtFunctionResult::Return(value),
+                Err(err) => ExtFunctionResult::Error(function.call_error(err)),
+            }),
+            Handler::Async(handler) => match handler(args) {
+                Ok(future) => Answer::Pending(future),
+                Err(err) => Answer::Ready(ExtFunctionResult::Error(function.call_error(err))),
+            },
+        }
+    }
+}
+
+impl RegisteredFunction {
+    /// Returns the exception to raise in the sandbox for a failed call.
+    fn call_error(&self, err: CallError) -> MontyException {
+        let name = &self.name;
+        let msg = match err {
+            CallError::ArgumentCount(given) => {
+                format!("{name}() takes {} arguments but {given} were given", self.params.len())
+            }
+            CallError::Argument(index, err) => {
+                let param = &self.params[index];
+                format!("{name}() argument '{param}{}' {}", err.path(), err.reason())
+            }
+            CallError::Raised(exc) => return exc,
+        };
+        MontyException::new(ExcType::TypeError, Some(msg))
+    }
+}
+
+fn ext_function_result(result: Result<MontyObject, MontyException>) -> ExtFunctionResult {
+    match result {
+        Ok(value) => ExtFunctionResult::Return(value),
+        Err(exc) => ExtFunctionResult::Error(exc),
+    }
+}
+
+fn not_implemented(msg: &str) -> MontyException {
+    MontyException::new(ExcType::NotImplementedError, Some(msg.to_owned()))
+}
@@ -18,8 +18,9 @@ use crate::{
     bytecode::{VM, VMSnapshot},
     defer_drop,
     exception_private::RunError,
+    function_signature::FunctionSignature,
     heap::{DropWithHeap, Heap, HeapReader},
-    heap_data::HeapData,
+    host_module::MontyModule,
     intern::{InternerBuilder, Interns},
     io::PrintWriter,
     namespace::NamespaceId,
@@ -27,7 +28,10 @@ use crate::{
     os::OsFunctionCall,
     resource::ResourceTracker,
     run::Executor,
-    run_progress::{ConvertedExit, ExtFunctionResult, NameLookupResult, convert_frame_exit},
+    run_progress::{
+        ConvertedExit, ExtFunctionResult, HandleOperation, ModuleLookupResult, NameLookupResult, convert_frame_exit,
+        resume_module_lookup,
+    },
     value::Value,
 };
 
@@ -68,6 +72,13 @@ pub struct MontyRepl<T: ResourceTracker> {
     /// executions these are the only VM values that persist — stack and frames
     /// are transient.
     globals: Vec<Value>,
+    /// Host-defined modules importable from every snippet, including modules
+    /// supplied for an earlier `ModuleLookup`.
+    #[serde(default)]
+    modules: Vec<MontyModule>,
+    /// Whether sandbox functions reach the host as callbacks, see [`MontyRepl::with_callbacks`].
+    #[serde(default)]
+    callbacks: bool,
 }
 
 impl<T: ResourceTracker> MontyRepl<T> {
@@ -87,7 +98,41 @@ impl<T: ResourceTracker> MontyRepl<T> {
             sources: AHashMap::new(),
             heap,
             globals: Vec::new(),
+            modules: Vec::new(),
+            callbacks: false,
+        }
+    }
+
+    /// Makes host-defined modules importable from every snippet.
+    ///
+    /// See [`MontyRun::with_modules`](crate::MontyRun::with_modules).
+    #[must_use]
+    pub fn with_modules(mut self, modules: Vec<MontyModule>) -> Self {
+        self.modules = modules;
+        self
+    }
+
+    /// Lets functions defined in the sandbox reach the host as callbacks in every snippet.
+    ///
+    /// See [`MontyRun::with_callbacks`](crate::MontyRun::with_callbacks).
+    #[must_use]
+    pub fn with_callbacks(mut self) -> Self {
+        self.callbacks = true;
+        self
+    }
+
+    /// Declares the signatures of external functions called from every snippet.
+    ///
+    /// See [`MontyRun::with_signatures`](crate::MontyRun::with_signatures).
+    ///
+    /// # Errors
+    /// Returns a `ValueError` if a signature's parameters couldn't appear in a Python `def`.
+    pub fn with_signatures(mut self, signatures: Vec<FunctionSignature>) -> Result<Self, MontyException> {
+        for signature in signatures {
+            let declared = signature.declare(&mut self.interns)?;
+            self.interns.add_declared_signature(declared);
         }
+        Ok(self)
     }
 
     /// Returns the resource tracker that will be used for the next snippet.
@@ -162,6 +207,10 @@ impl<T: ResourceTracker> MontyRepl<T> {
                 &executor.interns,
                 print.reborrow(),
             );
+            vm.host_modules.clone_from(&this.modules);
+            if this.callbacks {
+                vm.enable_callbacks();
+            }
 
             // Inject inputs with VM alive
             if let Err(error) = inject_inputs_into_vm(executor, input_values, &mut vm) {
@@ -231,6 +280,7 @@ impl<T: ResourceTracker> MontyRepl<T> {
                 &executor.interns,
                 print.reborrow(),
             );
+            vm.host_modules.clone_from(&self.modules);
 
             if let Err(e) = inject_inputs_into_vm(executor, input_values, &mut vm) {
                 self.globals = vm.take_globals();
@@ -280,6 +330,7 @@ impl<T: ResourceTracker> MontyRepl<T> {
             &mut (&self.interns, print),
             |reader, (interns, print)| {
                 let vm = &mut VM::new(mem::take(&mut self.globals), reader, interns, print.reborrow());
+                vm.host_modules.clone_from(&self.modules);
 
                 let callable = vm.globals[slot_idx.index()].clone_with_heap(vm);
                 defer_drop!(callable, vm);
@@ -316,7 +367,7 @@ impl<T: ResourceTracker> MontyRepl<T> {
             .iter()
             .filter_map(|(name, ns_id)| {
                 let idx = ns_id.index();
-                if idx < self.globals.len() && is_callable(&self.globals[idx], &self.heap) {
+                if idx < self.globals.len() && self.globals[idx].is_callable(&self.heap) {
                     Some(name.as_str())
                 } else {
                     None
@@ -330,7 +381,7 @@ impl<T: ResourceTracker> MontyRepl<T> {
     pub fn has_function(&self, name: &str) -> bool {
         self.global_name_map.get(name).is_some_and(|ns_id| {
             let idx = ns_id.index();
-            idx < self.globals.len() && is_callable(&self.globals[idx], &self.heap)
+            idx < self.globals.len() && self.globals[idx].is_callable(&self.heap)
         })
     }
 
@@ -402,10 +453,16 @@ pub enum ReplProgress<T: ResourceTracker> {
     FunctionCall(ReplFunctionCall<T>),
     /// Execution paused for an OS-level operation.
     OsCall(ReplOsCall<T>),
+    /// Execution paused for an operation on a host handle.
+    HandleCall(ReplHandleCall<T>),
+    /// A callback the host called while an external call was paused has finished.
+    CallbackResult(ReplCallbackResult<T>),
     /// All async tasks are blocked waiting for external futures to resolve.
     ResolveFutures(ReplResolveFutures<T>),
     /// Execution paused for an unresolved name lookup.
     NameLookup(ReplNameLookup<T>),
+    /// Execution paused to import a module the host hasn't declared.
+    ModuleLookup(ReplModuleLookup<T>),
     /// Snippet execution completed with the updated REPL and result value.
     Complete {
         /// Updated REPL session state to continue feeding snippets.
@@ -439,6 +496,24 @@ impl<T: ResourceTracker> ReplProgress<T> {
         }
     }
 
+    /// Consumes the progress and returns the `ReplHandleCall` struct.
+    #[must_use]
+    pub fn into_handle_call(self) -> Option<ReplHandleCall<T>> {
+        match self {
+            Self::HandleCall(call) => Some(call),
+            _ => None,
+        }
+    }
+
+    /// Consumes the progress and returns the `ReplCallbackResult` struct.
+    #[must_use]
+    pub fn into_callback_result(self) -> Option<ReplCallbackResult<T>> {
+        match self {
+            Self::CallbackResult(result) => Some(result),
+            _ => None,
+        }
+    }
+
     /// Consumes the progress and returns the `ReplResolveFutures` struct.
     #[must_use]
     pub fn into_resolve_futures(self) -> Option<ReplResolveFutures<T>> {
@@ -457,6 +532,15 @@ impl<T: ResourceTracker> ReplProgress<T> {
         }
     }
 
+    /// Consumes the progress and returns the `ReplModuleLookup` struct.
+    #[must_use]
+    pub fn into_module_lookup(self) -> Option<ReplModuleLookup<T>> {
+        match self {
+            Self::ModuleLookup(lookup) => Some(lookup),
+            _ => None,
+        }
+    }
+
     /// Consumes the progress and returns the completed REPL and value.
     #[must_use]
     pub fn into_complete(self) -> Option<(MontyRepl<T>, MontyObject)> {
@@ -478,8 +562,11 @@ impl<T: ResourceTracker> ReplProgress<T> {
         match self {
             Self::FunctionCall(call) => call.into_repl(),
             Self::OsCall(call) => call.into_repl(),
+            Self::HandleCall(call) => call.into_repl(),
+            Self::CallbackResult(result) => result.into_repl(),
             Self::ResolveFutures(state) => state.into_repl(),
             Self::NameLookup(lookup) => lookup.into_repl(),
+            Self::ModuleLookup(lookup) => lookup.into_repl(),
             Self::Complete { repl, .. } => repl,
         }
     }
@@ -522,6 +609,11 @@ pub struct ReplFunctionCall<T: ResourceTracker> {
     pub args: Vec<MontyObject>,
     /// The keyword arguments passed to the function (key, value pairs).
     pub kwargs: Vec<(MontyObject, MontyObject)>,
+    /// The arguments by parameter name, if the function has a declared signature.
+    ///
+    /// See [`FunctionCall::bound_args`](crate::FunctionCall::bound_args).
+    #[serde(default)]
+    pub bound_args: Option<Vec<(String, MontyObject)>>,
     /// Unique identifier for this call (used for async correlation).
     pub call_id: u32,
     /// Whether this is a dataclass method call (first arg is `self`).
@@ -539,6 +631,18 @@ impl<T: ResourceTracker> ReplFunctionCall<T> {
         self.snapshot.into_repl()
     }
 
+    /// Returns the argument bound to the parameter `name`.
+    ///
+    /// Always `None` if the function has no declared signature.
+    #[must_use]
+    pub fn arg(&self, name: &str) -> Option<&MontyObject> {
+        let bound_args = self.bound_args.as_ref()?;
+        bound_args
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+    }
+
     /// Resumes snippet execution with an external result.
     pub fn resume(
         self,
@@ -602,6 +706,90 @@ impl<T: ResourceTracker> ReplOsCall<T> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// ReplHandleCall
+// ---------------------------------------------------------------------------
+
+/// REPL execution paused for an operation on a host handle.
+///
+/// Resume with `resume(result, print)` to provide the operation's result and continue.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: DeserializeOwned"))]
+pub struct ReplHandleCall<T: ResourceTracker> {
+    /// The type name of the handle.
+    pub type_name: String,
+    /// The host-chosen id of the handle.
+    pub handle_id: u64,
+    /// What the snippet did with the handle.
+    pub operation: HandleOperation,
+    /// Unique identifier for this call (used for async correlation).
+    pub call_id: u32,
+    /// Internal REPL execution snapshot.
+    snapshot: ReplSnapshot<T>,
+}
+
+impl<T: ResourceTracker> ReplHandleCall<T> {
+    /// Extracts the REPL session, discarding the in-flight execution state.
+    ///
+    /// Restores globals from the VM snapshot so the REPL remains usable.
+    #[must_use]
+    pub fn into_repl(self) -> MontyRepl<T> {
+        self.snapshot.into_repl()
+    }
+
+    /// Resumes snippet execution with the operation's result.
+    pub fn resume(
+        self,
+        result: impl Into<ExtFunctionResult>,
+        print: PrintWriter<'_>,
+    ) -> Result<ReplProgress<T>, Box<ReplStartError<T>>> {
+        self.snapshot.run(result, print)
+    }
+
+    /// Resumes execution by pushing an `ExternalFuture` for async resolution.
+    pub fn resume_pending(self, print: PrintWriter<'_>) -> Result<ReplProgress<T>, Box<ReplStartError<T>>> {
+        self.snapshot.run(ExtFunctionResult::Future(self.call_id), print)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ReplCallbackResult
+// ---------------------------------------------------------------------------
+
+/// A callback the host called while a REPL snippet's external call was paused has finished.
+///
+/// The external call is still paused: resume it with `resume(result, print)`, or
+/// call another callback. See [`CallbackResult`](crate::CallbackResult).
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: DeserializeOwned"))]
+pub struct ReplCallbackResult<T: ResourceTracker> {
+    /// Id of the callback that finished.
+    pub callback_id: u32,
+    /// The callback's return value, or the exception it raised.
+    pub result: Result<MontyObject, MontyException>,
+    /// Internal REPL execution snapshot.
+    snapshot: ReplSnapshot<T>,
+}
+
+impl<T: ResourceTracker> ReplCallbackResult<T> {
+    /// Extracts the REPL session, discarding the in-flight execution state.
+    ///
+    /// Restores globals from the VM snapshot so the REPL remains usable.
+    #[must_use]
+    pub fn into_repl(self) -> MontyRepl<T> {
+        self.snapshot.into_repl()
+    }
+
+    /// Resumes the paused external call with its result, or calls another callback.
+    pub fn resume(
+        self,
+        result: impl Into<ExtFunctionResult>,
+        print: PrintWriter<'_>,
+    ) -> Result<ReplProgress<T>, Box<ReplStartError<T>>> {
+        self.snapshot.run(result, print)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ReplNameLookup
 // ---------------------------------------------------------------------------
@@ -715,6 +903,77 @@ impl<T: ResourceTracker> ReplNameLookup<T> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// ReplModuleLookup
+// ---------------------------------------------------------------------------
+
+/// REPL execution paused to import a module the host hasn't declared.
+///
+/// Call `resume(result, print)` with the appropriate `ModuleLookupResult`. A
+/// supplied module stays importable in later snippets.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: DeserializeOwned"))]
+pub struct ReplModuleLookup<T: ResourceTracker> {
+    /// The full dotted name of the module being imported.
+    pub name: String,
+    /// Internal REPL execution snapshot.
+    snapshot: ReplSnapshot<T>,
+}
+
+impl<T: ResourceTracker> ReplModuleLookup<T> {
+    /// Extracts the REPL session, discarding the in-flight execution state.
+    ///
+    /// Restores globals from the VM snapshot so the REPL remains usable.
+    #[must_use]
+    pub fn into_repl(self) -> MontyRepl<T> {
+        self.snapshot.into_repl()
+    }
+
+    /// Resumes execution after module resolution, either importing the
+    /// supplied module or raising `ModuleNotFoundError`.
+    pub fn resume(
+        self,
+        result: impl Into<ModuleLookupResult>,
+        print: PrintWriter<'_>,
+    ) -> Result<ReplProgress<T>, Box<ReplStartError<T>>> {
+        let Self { name, snapshot } = self;
+        let ReplSnapshot {
+            mut repl,
+            executor,
+            vm_state,
+        } = snapshot;
+        let result = result.into();
+        if let ModuleLookupResult::Module(module) = &result {
+            let mut module = module.clone();
+            module.set_name(name.clone());
+            repl.modules.push(module);
+        }
+
+        let (converted, vm_state) =
+            HeapReader::with(&mut repl.heap, &mut (&executor, print), |reader, (executor, print)| {
+                let mut vm = VM::restore(
+                    vm_state,
+                    &executor.module_code,
+                    reader,
+                    &executor.interns,
+                    print.reborrow(),
+                );
+                let vm_result = resume_module_lookup(&mut vm, &name, result);
+
+                // Convert while VM alive, then snapshot or reclaim globals
+                let converted = convert_frame_exit(vm_result, &mut vm);
+                let vm_state = if converted.needs_snapshot() {
+                    Some(vm.snapshot())
+                } else {
+                    repl.globals = vm.take_globals();
+                    None
+                };
+                (converted, vm_state)
+            });
+        build_repl_progress(converted, vm_state, executor, repl)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ReplResolveFutures
 // ---------------------------------------------------------------------------
@@ -937,6 +1196,11 @@ impl<T: ResourceTracker> ReplSnapshot<T> {
                     ExtFunctionResult::NotFound(function_name) => {
                         vm.resume_with_exception(ExtFunctionResult::not_found_exc(&function_name))
                     }
+                    ExtFunctionResult::Callback {
+                        callback_id,
+                        args,
+                        kwargs,
+                    } => vm.call_callback(callback_id, args, kwargs),
                 };
 
                 // Convert while VM alive, then snapshot or reclaim globals
@@ -1013,12 +1277,14 @@ fn build_repl_progress<T: ResourceTracker>(
             function_name,
             args,
             kwargs,
+            bound_args,
             call_id,
             method_call,
         } => Ok(ReplProgress::FunctionCall(ReplFunctionCall {
             function_name,
             args,
             kwargs,
+            bound_args,
             call_id,
             method_call,
             snapshot: new_repl_snapshot!(),
@@ -1028,6 +1294,18 @@ fn build_repl_progress<T: ResourceTracker>(
             call_id,
             snapshot: new_repl_snapshot!(),
         })),
+        ConvertedExit::HandleCall {
+            type_name,
+            handle_id,
+            operation,
+            call_id,
+        } => Ok(ReplProgress::HandleCall(ReplHandleCall {
+            type_name,
+            handle_id,
+            operation,
+            call_id,
+            snapshot: new_repl_snapshot!(),
+        })),
         ConvertedExit::ResolveFutures(pending_call_ids) => Ok(ReplProgress::ResolveFutures(ReplResolveFutures {
             repl,
             executor,
@@ -1044,6 +1322,20 @@ fn build_repl_progress<T: ResourceTracker>(
             is_global,
             snapshot: new_repl_snapshot!(),
         })),
+        ConvertedExit::ModuleLookup { name } => Ok(ReplProgress::ModuleLookup(ReplModuleLookup {
+            name,
+            snapshot: new_repl_snapshot!(),
+        })),
+        ConvertedExit::CallbackResult { callback_id, result } => {
+            let result = result.map_err(|err| {
+                err.into_python_exception(&executor.interns, |fname| repl.sources.get(fname).map(String::as_str))
+            });
+            Ok(ReplProgress::CallbackResult(ReplCallbackResult {
+                callback_id,
+                result,
+                snapshot: new_repl_snapshot!(),
+            }))
+        }
         ConvertedExit::Error(err) => {
             // Resolve traceback frames against every snippet the REPL has
             // seen, not just the currently-executing one. `executor.interns`
@@ -1109,19 +1401,3 @@ fn convert_args(args: Vec<MontyObject>, vm: &mut VM<'_, impl ResourceTracker>) -
         }
     }
 }
-
-/// Returns `true` if the value is a callable type.
-///
-/// For heap-allocated values (`Ref`), checks the actual `HeapData` variant
-/// rather than accepting all refs — only closures, functions with defaults,
-/// and heap-allocated external functions are callable.
-fn is_callable(value: &Value, heap: &Heap<impl ResourceTracker>) -> bool {
-    match value {
-        Value::DefFunction(_) | Value::Builtin(_) | Value::ExtFunction(_) | Value::ModuleFunction(_) => true,
-        Value::Ref(id) => matches!(
-            heap.get(*id),
-            HeapData::Closure(_) | HeapData::FunctionDefaults(_) | HeapData::ExtFunction(_)
-        ),
-        _ => false,
-    }
-}
@@ -2,6 +2,10 @@ use std::{
     cell::Cell,
     error::Error,
     fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicU8, Ordering},
+    },
     time::{Duration, Instant},
 };
 
@@ -18,6 +22,18 @@ use crate::{
 /// the allocation check can catch them.
 pub const LARGE_RESULT_THRESHOLD: usize = 100_000;
 
+/// Bytes of string, bytes or regex input a builtin processes per unit of fuel.
+///
+/// One unit of fuel is roughly the cost of one bytecode instruction, so builtins
+/// that scan or copy data within a single instruction charge one unit per this
+/// many bytes (see [`ResourceTracker::consume_fuel`]).
+pub const FUEL_BYTES_PER_UNIT: usize = 64;
+
+/// Returns the fuel charged for processing `bytes` bytes of data.
+pub(crate) fn bytes_fuel(bytes: usize) -> usize {
+    bytes / FUEL_BYTES_PER_UNIT
+}
+
 /// Pre-checks that an operation producing `item_len * count` bytes won't exceed resource limits.
 ///
 /// Used for sequence repeats (`'x' * 999_999_999`), padding operations
@@ -33,6 +49,9 @@ pub fn check_repeat_size(item_len: usize, count: usize, tracker: &impl ResourceT
 /// For bases with 0 or 1 significant bits (0, 1, -1), the result is always
 /// small regardless of exponent, so the check is skipped.
 ///
+/// Also charges fuel for the work: one unit per 64-bit limb of the result for
+/// each squaring step (one per bit of the exponent).
+///
 /// The estimate includes a 4× safety multiplier because `BigInt::pow` uses repeated squaring,
 /// which allocates intermediate values on the Rust heap (not tracked by the resource tracker).
 /// At peak, old/new base and old/new accumulator coexist simultaneously during each
@@ -42,7 +61,11 @@ pub fn check_pow_size(base_bits: u64, exponent: u64, tracker: &impl ResourceTrac
     if base_bits <= 1 {
         return Ok(());
     }
-    let result_bytes = estimate_bits_to_bytes(base_bits.saturating_mul(exponent));
+    let result_bits = base_bits.saturating_mul(exponent);
+    // One squaring step per bit of the exponent.
+    let steps = usize::try_from(u64::BITS - exponent.leading_zeros()).unwrap_or(usize::MAX);
+    tracker.consume_fuel(bits_to_limbs(result_bits).saturating_mul(steps))?;
+    let result_bytes = estimate_bits_to_bytes(result_bits);
     // Repeated squaring needs ~4× result size in peak memory (old/new base + old/new accumulator
     // coexist during each multiplication step), and these are Rust heap allocations not tracked
     // by the resource tracker.
@@ -52,7 +75,11 @@ pub fn check_pow_size(base_bits: u64, exponent: u64, tracker: &impl ResourceTrac
 /// Pre-checks that an integer multiplication won't exceed resource limits.
 ///
 /// The result of multiplying two numbers has at most `a_bits + b_bits` bits.
+///
+/// Also charges fuel for the work: the product of the operands' sizes in
+/// 64-bit limbs, as for schoolbook multiplication.
 pub fn check_mult_size(a_bits: u64, b_bits: u64, tracker: &impl ResourceTracker) -> Result<(), ResourceError> {
+    tracker.consume_fuel(bits_to_limbs(a_bits).saturating_mul(bits_to_limbs(b_bits)))?;
     check_estimated_size(estimate_bits_to_bytes(a_bits.saturating_add(b_bits)), tracker)
 }
 
@@ -136,20 +163,33 @@ fn estimate_bits_to_bytes(bits: u64) -> usize {
     usize::try_from(bits.saturating_add(7) / 8).unwrap_or(usize::MAX)
 }
 
+/// Converts a bit count to 64-bit limbs, saturating to `usize::MAX` on overflow.
+fn bits_to_limbs(bits: u64) -> usize {
+    usize::try_from(bits.div_ceil(64)).unwrap_or(usize::MAX)
+}
+
 /// Error returned when a resource limit is exceeded during execution.
 ///
 /// This allows the sandbox to enforce strict limits on allocation count,
-/// execution time, and memory usage.
+/// execution time, fuel, memory usage and output volume.
 #[derive(Debug, Clone)]
 pub enum ResourceError {
     /// Maximum number of allocations exceeded.
     Allocation { limit: usize, count: usize },
     /// Maximum execution time exceeded.
     Time { limit: Duration, elapsed: Duration },
+    /// Maximum fuel (instruction count) exceeded.
+    Fuel { limit: usize, consumed: usize },
     /// Maximum memory usage exceeded.
     Memory { limit: usize, used: usize },
     /// Maximum recursion depth exceeded.
     Recursion { limit: usize, depth: usize },
+    /// Maximum output volume exceeded.
+    Output { limit: usize, written: usize },
+    /// Execution was interrupted via [`InterruptHandle::interrupt`].
+    Interrupted,
+    /// Execution was cancelled via [`InterruptHandle::cancel`].
+    Cancelled,
     /// Any other error, e.g. when propagating a python exception
     Exception(MontyException),
 }
@@ -163,12 +203,20 @@ impl fmt::Display for ResourceError {
             Self::Time { limit, elapsed } => {
                 write!(f, "time limit exceeded: {elapsed:?} > {limit:?}")
             }
+            Self::Fuel { limit, consumed } => {
+                write!(f, "fuel limit exceeded: {consumed} > {limit}")
+            }
             Self::Memory { limit, used } => {
                 write!(f, "memory limit exceeded: {used} bytes > {limit} bytes")
             }
             Self::Recursion { .. } => {
                 write!(f, "maximum recursion depth exceeded")
             }
+            Self::Output { limit, written } => {
+                write!(f, "output limit exceeded: {written} bytes > {limit} bytes")
+            }
+            Self::Interrupted => write!(f, "execution interrupted"),
+            Self::Cancelled => write!(f, "execution cancelled"),
             Self::Exception(exc) => {
                 write!(f, "{exc}")
             }
@@ -185,7 +233,11 @@ impl ResourceError {
     /// - `Allocation` → `MemoryError`
     /// - `Memory` → `MemoryError`
     /// - `Time` → `TimeoutError`
+    /// - `Fuel` → `TimeoutError`
     /// - `Recursion` → `RecursionError`
+    /// - `Output` → `MemoryError`
+    /// - `Interrupted` → `KeyboardInterrupt`
+    /// - `Cancelled` → `KeyboardInterrupt`
     #[must_use]
     pub(crate) fn into_exception(self, frame: Option<RawStackFrame>) -> ExceptionRaise {
         let (exc_type, msg) = match self {
@@ -201,11 +253,27 @@ impl ResourceError {
                 ExcType::TimeoutError,
                 Some(format!("time limit exceeded: {elapsed:?} > {limit:?}")),
             ),
+            Self::Fuel { limit, consumed } => (
+                ExcType::TimeoutError,
+                Some(format!("fuel limit exceeded: {consumed} > {limit}")),
+            ),
             Self::Recursion { .. } => (
                 ExcType::RecursionError,
                 Some("maximum recursion depth exceeded".to_string()),
             ),
-            Self::Exception(exc) => (exc.exc_type(), exc.into_message()),
+            Self::Output { limit, written } => (
+                ExcType::MemoryError,
+                Some(format!("output limit exceeded: {written} bytes > {limit} bytes")),
+            ),
+            Self::Interrupted => (ExcType::KeyboardInterrupt, None),
+            Self::Cancelled => (ExcType::KeyboardInterrupt, Some("execution cancelled".to_string())),
+            Self::Exception(exc) => {
+                let exc = SimpleException::from(exc);
+                return match frame {
+                    Some(f) => exc.with_frame(f),
+                    None => exc.into(),
+                };
+            }
         };
         let exc = SimpleException::new(exc_type, msg);
         match frame {
@@ -217,10 +285,11 @@ impl ResourceError {
 
 impl From<ResourceError> for RunError {
     fn from(err: ResourceError) -> Self {
-        // RecursionError is catchable in CPython, so it must be catchable here too.
-        // Other resource errors (memory, time, allocation) remain uncatchable to prevent
-        // untrusted code from suppressing resource limit violations.
-        if matches!(err, ResourceError::Recursion { .. }) {
+        // RecursionError is catchable in CPython, so it must be catchable here too,
+        // as is a KeyboardInterrupt the host raised with `InterruptHandle::interrupt`.
+        // Other resource errors (memory, time, allocation, cancellation) remain uncatchable
+        // to prevent untrusted code from suppressing resource limit violations.
+        if matches!(err, ResourceError::Recursion { .. } | ResourceError::Interrupted) {
             Self::Exc(err.into_exception(None))
         } else {
             Self::UncatchableExc(err.into_exception(None))
@@ -262,6 +331,25 @@ pub trait ResourceTracker: fmt::Debug {
     /// an immutable heap reference, such as `py_repr_fmt`.
     fn check_time(&self) -> Result<(), ResourceError>;
 
+    /// Called to charge `amount` units of fuel, a deterministic measure of work.
+    ///
+    /// The VM charges one unit per bytecode instruction. Builtins that do
+    /// non-trivial work within a single instruction charge extra in proportion
+    /// to it: one unit per item for Rust-side iteration and per comparison when
+    /// sorting, one per [`FUEL_BYTES_PER_UNIT`] bytes for regex and `str.join`,
+    /// and per 64-bit limb for big integer multiplication and powers.
+    ///
+    /// Unlike [`check_time`](Self::check_time), the same program always consumes
+    /// the same fuel, whatever the machine load. Returns `Err(ResourceError::Fuel)`
+    /// once the total exceeds the limit.
+    ///
+    /// The default implementation imposes no limit. Wrapper trackers must
+    /// delegate to their inner tracker.
+    #[inline]
+    fn consume_fuel(&self, _amount: usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
     /// Called before pushing a new call frame to check recursion depth.
     ///
     /// Returns `Ok(())` if within recursion limit, or `Err(ResourceError::Recursion)`
@@ -329,6 +417,99 @@ pub trait ResourceTracker: fmt::Debug {
     fn lower_recursion_limit(&self, _new_limit: usize) -> Result<(), Option<usize>> {
         Err(None)
     }
+
+    /// Returns the backtracking step budget for a single regex match call.
+    ///
+    /// Applied when `re` patterns are compiled, so it bounds every search that
+    /// needs `fancy_regex`'s backtracking engine (backreferences, lookaround).
+    /// Exceeding it raises `TimeoutError` inside the sandbox.
+    ///
+    /// `None` means "use `fancy_regex`'s built-in default of 1,000,000 steps".
+    /// Wrapper trackers must delegate to their inner tracker.
+    fn regex_backtrack_limit(&self) -> Option<usize> {
+        None
+    }
+
+    /// Called before `bytes` bytes of output are handed to the print writer.
+    ///
+    /// Covers `print()`, `sys.stdout` / `sys.stderr` writes, warnings and log
+    /// records — text that leaves the heap and so isn't counted by
+    /// [`on_allocate`](Self::on_allocate). Returns `Err(ResourceError::Output)`
+    /// once the total would exceed the limit.
+    ///
+    /// The default implementation imposes no limit. Wrapper trackers must
+    /// delegate to their inner tracker.
+    fn on_output(&self, _bytes: usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
+    /// Called by the VM before each bytecode instruction, and when resuming from
+    /// [`ResolveFutures`](crate::ResolveFutures), to pick up a pending
+    /// [`InterruptHandle`] request.
+    ///
+    /// Returns `Err(ResourceError::Interrupted)` or `Err(ResourceError::Cancelled)`
+    /// when the host has asked execution to stop.
+    ///
+    /// The default implementation never interrupts. Wrapper trackers must
+    /// delegate to their inner tracker.
+    #[inline]
+    fn check_interrupt(&self) -> Result<(), ResourceError> {
+        Ok(())
+    }
+}
+
+/// Lets a host keep ownership of a tracker while running code with it, and
+/// inspect it afterwards, e.g. to read [`LimitedTracker::fuel_consumed`] after
+/// [`MontyRun::run`](crate::MontyRun::run).
+impl<T: ResourceTracker> ResourceTracker for &T {
+    fn on_allocate(&self, get_size: impl FnOnce() -> usize) -> Result<(), ResourceError> {
+        (**self).on_allocate(get_size)
+    }
+
+    fn on_free(&self, get_size: impl FnOnce() -> usize) {
+        (**self).on_free(get_size);
+    }
+
+    fn check_time(&self) -> Result<(), ResourceError> {
+        (**self).check_time()
+    }
+
+    fn consume_fuel(&self, amount: usize) -> Result<(), ResourceError> {
+        (**self).consume_fuel(amount)
+    }
+
+    fn check_recursion_depth(&self, current_depth: usize) -> Result<(), ResourceError> {
+        (**self).check_recursion_depth(current_depth)
+    }
+
+    fn check_large_result(&self, estimated_bytes: usize) -> Result<(), ResourceError> {
+        (**self).check_large_result(estimated_bytes)
+    }
+
+    fn on_grow(&self, additional_bytes: usize) -> Result<(), ResourceError> {
+        (**self).on_grow(additional_bytes)
+    }
+
+    fn gc_interval(&self) -> Option<usize> {
+        (**self).gc_interval()
+    }
+
+    #[cfg(feature = "test-hooks")]
+    fn lower_recursion_limit(&self, new_limit: usize) -> Result<(), Option<usize>> {
+        (**self).lower_recursion_limit(new_limit)
+    }
+
+    fn regex_backtrack_limit(&self) -> Option<usize> {
+        (**self).regex_backtrack_limit()
+    }
+
+    fn on_output(&self, bytes: usize) -> Result<(), ResourceError> {
+        (**self).on_output(bytes)
+    }
+
+    fn check_interrupt(&self) -> Result<(), ResourceError> {
+        (**self).check_interrupt()
+    }
 }
 
 /// A resource tracker that imposes no limits except default recursion limit.
@@ -396,12 +577,27 @@ pub struct ResourceLimits {
     pub max_allocations: Option<usize>,
     /// Maximum execution time.
     pub max_duration: Option<Duration>,
+    /// Maximum fuel: bytecode instructions plus the proportional cost of
+    /// expensive builtins (see [`ResourceTracker::consume_fuel`]).
+    ///
+    /// Unlike `max_duration`, this is deterministic across machines and runs.
+    #[serde(default)]
+    pub max_fuel: Option<usize>,
     /// Maximum heap memory in bytes (approximate).
     pub max_memory: Option<usize>,
     /// Run garbage collection every N GC-tracked allocations.
     pub gc_interval: Option<usize>,
     /// Maximum recursion depth (function call stack depth).
     pub max_recursion_depth: Option<usize>,
+    /// Maximum number of backtracking steps a single regex match may take.
+    ///
+    /// `None` uses `fancy_regex`'s default of 1,000,000 steps.
+    #[serde(default)]
+    pub regex_backtrack_limit: Option<usize>,
+    /// Maximum bytes of output (`print()`, `sys.stdout` / `sys.stderr` writes,
+    /// warnings and log records) over the whole run.
+    #[serde(default)]
+    pub max_output_bytes: Option<usize>,
 }
 
 /// Recommended maximum recursion depth if not otherwise specified.
@@ -431,6 +627,13 @@ impl ResourceLimits {
         self
     }
 
+    /// Sets the maximum fuel (see [`ResourceTracker::consume_fuel`]).
+    #[must_use]
+    pub fn max_fuel(mut self, limit: usize) -> Self {
+        self.max_fuel = Some(limit);
+        self
+    }
+
     /// Sets the maximum memory usage in bytes.
     #[must_use]
     pub fn max_memory(mut self, limit: usize) -> Self {
@@ -451,6 +654,20 @@ impl ResourceLimits {
         self.max_recursion_depth = limit;
         self
     }
+
+    /// Sets the maximum number of backtracking steps for a single regex match.
+    #[must_use]
+    pub fn regex_backtrack_limit(mut self, limit: usize) -> Self {
+        self.regex_backtrack_limit = Some(limit);
+        self
+    }
+
+    /// Sets the maximum bytes of output.
+    #[must_use]
+    pub fn max_output_bytes(mut self, limit: usize) -> Self {
+        self.max_output_bytes = Some(limit);
+        self
+    }
 }
 
 /// How often to actually check `Instant::elapsed()` in `check_time`.
@@ -461,6 +678,75 @@ impl ResourceLimits {
 /// timeouts promptly.
 const TIME_CHECK_INTERVAL: u16 = 10;
 
+/// No stop requested.
+const INTERRUPT_NONE: u8 = 0;
+/// A catchable `KeyboardInterrupt` was requested.
+const INTERRUPT_RAISE: u8 = 1;
+/// An uncatchable cancellation was requested.
+const INTERRUPT_CANCEL: u8 = 2;
+
+/// Thread-safe handle for stopping an execution from another thread.
+///
+/// Obtain one from [`LimitedTracker::interrupt_handle`] before starting
+/// execution, move a clone to another thread and call [`interrupt`](Self::interrupt)
+/// or [`cancel`](Self::cancel). The VM picks the request up before the next
+/// bytecode instruction, or as soon as the host resumes a
+/// [`ResolveFutures`](crate::ResolveFutures) snapshot.
+///
+/// Clones share the same flag, so any of them can stop the execution.
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle(Arc<AtomicU8>);
+
+impl InterruptHandle {
+    /// Creates a new handle with no stop requested.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Raises `KeyboardInterrupt` in the running code.
+    ///
+    /// Like a Ctrl-C in CPython, the exception can be caught by `except
+    /// KeyboardInterrupt` (or a bare `except`), and `finally` blocks run.
+    /// The request is consumed once raised, so a later `interrupt()` raises again.
+    pub fn interrupt(&self) {
+        // Never downgrade a pending cancellation to a catchable interrupt.
+        let _ = self
+            .0
+            .compare_exchange(INTERRUPT_NONE, INTERRUPT_RAISE, Ordering::SeqCst, Ordering::SeqCst);
+    }
+
+    /// Stops execution with [`ResourceError::Cancelled`].
+    ///
+    /// The resulting `KeyboardInterrupt` cannot be caught by the running code,
+    /// and the request stays in effect, so any later resume fails the same way.
+    pub fn cancel(&self) {
+        self.0.store(INTERRUPT_CANCEL, Ordering::SeqCst);
+    }
+
+    /// Returns true if an interrupt or cancellation is pending.
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.0.load(Ordering::SeqCst) != INTERRUPT_NONE
+    }
+
+    /// Returns the pending request as an error, consuming it if it is an interrupt.
+    fn check(&self) -> Result<(), ResourceError> {
+        match self.0.load(Ordering::Relaxed) {
+            INTERRUPT_NONE => Ok(()),
+            INTERRUPT_CANCEL => Err(ResourceError::Cancelled),
+            _ => match self
+                .0
+                .compare_exchange(INTERRUPT_RAISE, INTERRUPT_NONE, Ordering::SeqCst, Ordering::SeqCst)
+            {
+                Ok(_) => Err(ResourceError::Interrupted),
+                // `cancel()` raced with us and won.
+                Err(_) => Err(ResourceError::Cancelled),
+            },
+        }
+    }
+}
+
 /// A resource tracker that enforces configurable limits.
 ///
 /// Tracks allocation count, memory usage, and execution time, returning
@@ -491,6 +777,12 @@ pub struct LimitedTracker {
     allocation_count: Cell<usize>,
     /// Current approximate memory usage in bytes.
     current_memory: Cell<usize>,
+    /// Total bytes of output written so far.
+    #[serde(default)]
+    output_bytes: Cell<usize>,
+    /// Total fuel consumed so far.
+    #[serde(default)]
+    fuel_consumed: Cell<usize>,
     /// Counter for rate-limiting `Instant::elapsed()` calls in `check_time`.
     check_counter: Cell<u16>,
     /// Optional override applied on top of `limits.max_recursion_depth`.
@@ -509,6 +801,12 @@ pub struct LimitedTracker {
     /// existed (`#[serde(default)]` gives back the `None` fallback case).
     #[serde(default)]
     recursion_limit_override: Cell<Option<usize>>,
+    /// Flag shared with the handles returned by [`LimitedTracker::interrupt_handle`].
+    ///
+    /// Not serialized: a deserialized tracker gets a fresh flag, so hosts must
+    /// take a new handle from it.
+    #[serde(skip)]
+    interrupt: InterruptHandle,
 }
 
 impl LimitedTracker {
@@ -523,8 +821,11 @@ impl LimitedTracker {
             start_time: Instant::now(),
             allocation_count: Cell::new(0),
             current_memory: Cell::new(0),
+            output_bytes: Cell::new(0),
+            fuel_consumed: Cell::new(0),
             check_counter: Cell::new(0),
             recursion_limit_override: Cell::new(None),
+            interrupt: InterruptHandle::new(),
         }
     }
 
@@ -546,6 +847,31 @@ impl LimitedTracker {
         self.current_memory.get()
     }
 
+    /// Returns the total fuel consumed so far.
+    ///
+    /// Counted whether or not a fuel limit is set, so hosts can measure a
+    /// script's cost and pick a limit for it.
+    #[must_use]
+    pub fn fuel_consumed(&self) -> usize {
+        self.fuel_consumed.get()
+    }
+
+    /// Returns the total bytes of output written so far.
+    #[must_use]
+    pub fn output_bytes(&self) -> usize {
+        self.output_bytes.get()
+    }
+
+    /// Returns a handle that can interrupt or cancel execution from another thread.
+    ///
+    /// Take the handle before passing the tracker to `start()` or `run()`. The
+    /// handle is not part of a serialized snapshot, so after loading one take a
+    /// new handle from the deserialized tracker.
+    #[must_use]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.interrupt.clone()
+    }
+
     /// Returns the elapsed time since tracker creation.
     #[must_use]
     pub fn elapsed(&self) -> Duration {
@@ -637,6 +963,17 @@ impl ResourceTracker for LimitedTracker {
         Ok(())
     }
 
+    fn consume_fuel(&self, amount: usize) -> Result<(), ResourceError> {
+        let consumed = self.fuel_consumed.get().saturating_add(amount);
+        self.fuel_consumed.set(consumed);
+        if let Some(max) = self.limits.max_fuel
+            && consumed > max
+        {
+            return Err(ResourceError::Fuel { limit: max, consumed });
+        }
+        Ok(())
+    }
+
     fn check_recursion_depth(&self, current_depth: usize) -> Result<(), ResourceError> {
         if let Some(max) = self.active_recursion_limit() {
             // current_depth is before push, so new depth would be current_depth + 1
@@ -668,6 +1005,25 @@ impl ResourceTracker for LimitedTracker {
         self.limits.gc_interval
     }
 
+    fn regex_backtrack_limit(&self) -> Option<usize> {
+        self.limits.regex_backtrack_limit
+    }
+
+    fn on_output(&self, bytes: usize) -> Result<(), ResourceError> {
+        let written = self.output_bytes.get().saturating_add(bytes);
+        if let Some(max) = self.limits.max_output_bytes
+            && written > max
+        {
+            return Err(ResourceError::Output { limit: max, written });
+        }
+        self.output_bytes.set(written);
+        Ok(())
+    }
+
+    fn check_interrupt(&self) -> Result<(), ResourceError> {
+        self.interrupt.check()
+    }
+
     /// Lowers the live recursion ceiling to `new_limit`, refusing to raise it.
     ///
     /// The constructed limit (`limits.max_recursion_depth`) acts as the hard
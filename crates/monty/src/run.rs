@@ -1,5 +1,8 @@
 //! Public interface for running Monty code.
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use ahash::AHashMap;
 use ruff_python_stdlib::identifiers::is_identifier;
@@ -7,16 +10,23 @@ use ruff_python_stdlib::identifiers::is_identifier;
 use crate::{
     ExcType, MontyException,
     bytecode::{Code, Compiler, FrameExit, VM},
-    exception_private::RunResult,
+    exception_private::{RunError, RunResult},
+    expressions::Identifier,
+    function::Function,
+    function_signature::FunctionSignature,
     heap::{DropWithHeap, Heap, HeapReader},
-    intern::{InternerBuilder, Interns},
+    host_module::MontyModule,
+    intern::{FunctionId, InternerBuilder, Interns, StaticStrings},
     io::PrintWriter,
+    modules::StandardLib,
     namespace::NamespaceId,
     object::MontyObject,
-    parse::{CodeRange, ParseError, parse, parse_with_interner},
-    prepare::{prepare, prepare_with_existing_names},
+    parse::{CodeRange, ParseError, parse, parse_in_package, parse_with_interner},
+    prepare::{prepare, prepare_source_module, prepare_with_existing_names},
     resource::{NoLimitTracker, ResourceTracker},
     run_progress::{RunProgress, build_run_progress, check_snapshot_from_converted, convert_frame_exit},
+    signature::Signature,
+    source_module::{ModulePath, SourceModule},
     types::str::StringRepr,
     value::Value,
 };
@@ -59,6 +69,80 @@ impl MontyRun {
         Executor::new(code, script_name, input_names).map(|executor| Self { executor })
     }
 
+    /// Makes host-defined modules importable from the code.
+    ///
+    /// Imports of any other non-stdlib module yield
+    /// [`RunProgress::ModuleLookup`] from [`start`](Self::start), and raise
+    /// `ModuleNotFoundError` from [`run`](Self::run).
+    #[must_use]
+    pub fn with_modules(mut self, modules: Vec<MontyModule>) -> Self {
+        self.executor.modules = modules;
+        self
+    }
+
+    /// Lets functions defined in the sandbox reach the host as callbacks.
+    ///
+    /// A sandbox function passed to an external function then becomes a
+    /// [`MontyObject::Callback`] the host can call with
+    /// [`ExtFunctionResult::Callback`](crate::ExtFunctionResult::Callback) until it
+    /// resumes that call. Without this, sandbox functions reach the host as their repr.
+    #[must_use]
+    pub fn with_callbacks(mut self) -> Self {
+        self.executor.callbacks = true;
+        self
+    }
+
+    /// Declares the signatures of external functions.
+    ///
+    /// Calls to a function with a declared signature are bound before yielding
+    /// [`RunProgress::FunctionCall`]: a call that doesn't match raises `TypeError`
+    /// in the sandbox, and [`FunctionCall::bound_args`](crate::FunctionCall::bound_args)
+    /// gives the host the arguments by parameter name. A later signature for the
+    /// same function replaces the earlier one, and [`dump`](Self::dump) includes them.
+    ///
+    /// # Errors
+    /// Returns a `ValueError` if a signature's parameters couldn't appear in a Python `def`,
+    /// e.g. a keyword-only parameter before a positional one.
+    pub fn with_signatures(mut self, signatures: Vec<FunctionSignature>) -> Result<Self, MontyException> {
+        for signature in signatures {
+            let declared = signature.declare(&mut self.executor.interns)?;
+            self.executor.interns.add_declared_signature(declared);
+        }
+        Ok(self)
+    }
+
+    /// Adds a Python source file the code can import as a module.
+    ///
+    /// `path` is relative and `/`-separated: `helpers.py` is imported as `helpers`,
+    /// `pkg/tools.py` as `pkg.tools` and `pkg/__init__.py` as `pkg`. Each module has
+    /// its own globals, runs on first import and is cached for the rest of the run,
+    /// so circular imports see the partially initialized module like in CPython.
+    /// Relative imports resolve against the module's package, and tracebacks show
+    /// `path` as the filename of the module's frames.
+    ///
+    /// The file is compiled immediately, and [`dump`](Self::dump) includes it.
+    ///
+    /// # Errors
+    /// Returns `MontyException` if the file cannot be parsed, or a `ValueError` if
+    /// `path` doesn't name a module, names a standard library module or was
+    /// already added.
+    ///
+    /// # Example
+    /// ```
+    /// use monty::{MontyObject, MontyRun};
+    ///
+    /// let code = "from helpers import double\ndouble(21)";
+    /// let runner = MontyRun::new(code.to_owned(), "main.py", vec![])
+    ///     .unwrap()
+    ///     .with_file("helpers.py", "def double(x):\n    return x * 2")
+    ///     .unwrap();
+    /// assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(42));
+    /// ```
+    pub fn with_file(mut self, path: &str, code: &str) -> Result<Self, MontyException> {
+        self.executor.add_source_file(path, code)?;
+        Ok(self)
+    }
+
     /// Returns the code that was parsed to create this snapshot.
     #[must_use]
     pub fn code(&self) -> &str {
@@ -165,6 +249,10 @@ impl MontyRun {
         let (converted, vm_state) =
             HeapReader::with(&mut heap, &mut (&executor, print), |reader, (executor, print)| {
                 let mut vm = VM::new(globals, reader, &executor.interns, print.reborrow());
+                vm.host_modules.clone_from(&executor.modules);
+                if executor.callbacks {
+                    vm.enable_callbacks();
+                }
                 executor.populate_inputs(inputs, &mut vm)?;
 
                 // Start execution
@@ -205,6 +293,12 @@ pub(crate) struct Executor {
     /// Used by the REPL path to look up namespace slots for injected inputs.
     /// Empty for the standard (non-REPL) execution path.
     pub(crate) input_names: Vec<String>,
+    /// Host-defined modules importable from the code, see [`MontyRun::with_modules`].
+    #[serde(default)]
+    pub(crate) modules: Vec<MontyModule>,
+    /// Whether sandbox functions reach the host as callbacks, see [`MontyRun::with_callbacks`].
+    #[serde(default)]
+    pub(crate) callbacks: bool,
     /// Estimated heap capacity for pre-allocation on subsequent runs.
     /// Uses AtomicUsize for thread-safety (required by PyO3's Sync bound).
     heap_capacity: AtomicUsize,
@@ -219,6 +313,8 @@ impl Clone for Executor {
             interns: self.interns.clone(),
             code: self.code.clone(),
             input_names: self.input_names.clone(),
+            modules: self.modules.clone(),
+            callbacks: self.callbacks,
             heap_capacity: AtomicUsize::new(self.heap_capacity.load(Ordering::Relaxed)),
         }
     }
@@ -229,7 +325,8 @@ impl Executor {
     pub(crate) fn new(code: String, script_name: &str, input_names: Vec<String>) -> Result<Self, MontyException> {
         check_identifier(&input_names)?;
         let parse_result = parse(&code, script_name).map_err(|e| e.into_python_exc(script_name, &code))?;
-        let prepared = prepare(parse_result, input_names).map_err(|e| e.into_python_exc(script_name, &code))?;
+        let mut prepared = prepare(parse_result, input_names).map_err(|e| e.into_python_exc(script_name, &code))?;
+        let global_names = prepared.global_names();
 
         // Create interns with empty functions (functions will be set after compilation)
         let mut interns = Interns::new(prepared.interner, Vec::new());
@@ -237,11 +334,13 @@ impl Executor {
         // Compile the module to bytecode, which also compiles all nested functions.
         // The compiler enforces the bytecode-format namespace-size limit and reports
         // it as a `SyntaxError` rather than panicking on the `u16` cast.
-        let compile_result = Compiler::compile_module(&prepared.nodes, &interns, prepared.namespace_size)
-            .map_err(|e| e.into_python_exc(script_name, &code))?;
+        let compile_result =
+            Compiler::compile_module(&prepared.nodes, &interns, prepared.namespace_size, &global_names)
+                .map_err(|e| e.into_python_exc(script_name, &code))?;
 
         // Set the compiled functions in the interns
         interns.set_functions(compile_result.functions);
+        interns.add_source(script_name, &code);
 
         Ok(Self {
             namespace_size: prepared.namespace_size,
@@ -250,6 +349,8 @@ impl Executor {
             interns,
             code,
             input_names: Vec::new(),
+            modules: Vec::new(),
+            callbacks: false,
             heap_capacity: AtomicUsize::new(prepared.namespace_size),
         })
     }
@@ -294,8 +395,9 @@ impl Executor {
         let seeded_interner = InternerBuilder::from_interns(existing_interns, &code);
         let parse_result = parse_with_interner(&code, script_name, seeded_interner)
             .map_err(|e| e.into_python_exc(script_name, &code))?;
-        let prepared = prepare_with_existing_names(parse_result, existing_name_map)
+        let mut prepared = prepare_with_existing_names(parse_result, existing_name_map)
             .map_err(|e| e.into_python_exc(script_name, &code))?;
+        let global_names = prepared.global_names();
 
         let existing_functions = existing_interns.functions_clone();
         let mut interns = Interns::new(prepared.interner, Vec::new());
@@ -303,10 +405,14 @@ impl Executor {
             &prepared.nodes,
             &interns,
             prepared.namespace_size,
+            &global_names,
             existing_functions,
         )
         .map_err(|e| e.into_python_exc(script_name, &code))?;
         interns.set_functions(compile_result.functions);
+        interns.copy_sources_from(existing_interns);
+        interns.copy_declared_signatures_from(existing_interns);
+        interns.add_source(script_name, &code);
 
         Ok(Self {
             namespace_size: prepared.namespace_size,
@@ -315,10 +421,108 @@ impl Executor {
             interns,
             code,
             input_names,
+            modules: Vec::new(),
+            callbacks: false,
             heap_capacity: AtomicUsize::new(0),
         })
     }
 
+    /// Compiles a module supplied as a source file, see [`MontyRun::with_file`].
+    ///
+    /// Like [`new_repl_snippet`](Self::new_repl_snippet), compilation is seeded with
+    /// the existing interns and functions so the code compiled so far keeps its ids.
+    /// The module's globals get slots after all existing ones, and its body becomes
+    /// a `<module>` function that importing the module calls.
+    fn add_source_file(&mut self, path: &str, code: &str) -> Result<(), MontyException> {
+        let module_path = ModulePath::from_path(path).map_err(value_error)?;
+        let (top_level, _) = module_path.name.split_once('.').unwrap_or((&module_path.name, ""));
+        if StaticStrings::from_str(top_level)
+            .ok()
+            .and_then(|name| StandardLib::from_string_id(name.into()))
+            .is_some()
+        {
+            return Err(value_error(format!(
+                "module '{top_level}' is part of the standard library and can't be replaced"
+            )));
+        }
+        let index = match self.interns.find_source_module(&module_path.name) {
+            // Replaces the empty package created for a submodule added earlier
+            Some(index) if self.interns.source_module(index).body.is_none() => index,
+            Some(_) => return Err(value_error(format!("module '{}' was already added", module_path.name))),
+            None => u16::try_from(self.interns.source_module_count())
+                .map_err(|_| value_error(format!("too many modules, maximum is {}", u16::MAX)))?,
+        };
+
+        let seeded_interner = InternerBuilder::from_interns(&self.interns, code);
+        let parse_result = parse_in_package(code, path, seeded_interner, &module_path.package)
+            .map_err(|e| e.into_python_exc(path, code))?;
+        let mut prepared =
+            prepare_source_module(parse_result, self.namespace_size).map_err(|e| e.into_python_exc(path, code))?;
+        let global_names = prepared.global_names();
+
+        let name_id = prepared.interner.intern(&module_path.name);
+        let parent = module_path.name.rsplit_once('.').map(|(parent, _)| parent);
+        let parent_id = parent.map(|parent| prepared.interner.intern(parent));
+        let filename_id = prepared.interner.intern(path);
+
+        let mut interns = Interns::new(prepared.interner, Vec::new());
+        let compile_result = Compiler::compile_source_module(
+            &prepared.nodes,
+            &interns,
+            prepared.namespace_size,
+            &global_names,
+            self.interns.functions_clone(),
+            index,
+            name_id,
+            parent_id,
+            filename_id,
+        )
+        .map_err(|e| e.into_python_exc(path, code))?;
+        let mut functions = compile_result.functions;
+        let body_id = u16::try_from(functions.len())
+            .map_err(|_| value_error(format!("too many functions, maximum is {}", u16::MAX)))?;
+        functions.push(Function::new(
+            Identifier::new(StaticStrings::Module.into(), CodeRange::default()),
+            Signature::default(),
+            0,
+            Vec::new(),
+            0,
+            Vec::new(),
+            0,
+            false,
+            false,
+            compile_result.code,
+        ));
+        interns.set_functions(functions);
+        interns.copy_sources_from(&self.interns);
+        interns.add_source(path, code);
+        interns.copy_source_modules_from(&self.interns);
+        interns.copy_declared_signatures_from(&self.interns);
+        interns.set_source_module(
+            index,
+            SourceModule {
+                name: module_path.name.clone(),
+                body: Some(FunctionId::from_index(body_id)),
+                globals: prepared.name_map,
+            },
+        );
+
+        // Packages containing the module import as empty modules until their `__init__.py` is added
+        let mut package = parent;
+        while let Some(name) = package {
+            if interns.find_source_module(name).is_none() {
+                let index = u16::try_from(interns.source_module_count())
+                    .map_err(|_| value_error(format!("too many modules, maximum is {}", u16::MAX)))?;
+                interns.set_source_module(index, SourceModule::namespace_package(name.to_owned()));
+            }
+            package = name.rsplit_once('.').map(|(parent, _)| parent);
+        }
+
+        self.interns = interns;
+        self.namespace_size = prepared.namespace_size;
+        Ok(())
+    }
+
     /// Executes the code with a custom resource tracker.
     ///
     /// This provides full control over resource tracking and garbage collection
@@ -342,6 +546,7 @@ impl Executor {
         // Create VM first, then populate inputs with VM alive
         let result = HeapReader::with(&mut heap, &mut (self, print), |reader, (executor, print)| {
             let mut vm = VM::new(globals, reader, &executor.interns, print.reborrow());
+            vm.host_modules.clone_from(&executor.modules);
             executor.populate_inputs(inputs, &mut vm)?;
             executor.run_to_completion(&mut vm)
         });
@@ -350,15 +555,15 @@ impl Executor {
             self.heap_capacity.store(heap.size(), Ordering::Relaxed);
         }
 
-        // Non-REPL execution has exactly one source, so every frame's filename
-        // resolves to the same `self.code`.
-        result.map_err(|e| e.into_python_exception(&self.interns, |_| Some(self.code.as_str())))
+        // Frames of supplied source files resolve to their own source.
+        result.map_err(|e| e.into_python_exception(&self.interns, |filename| self.interns.source(filename)))
     }
 
     /// Runs module code on an already-configured VM to completion.
     ///
     /// Executes [`VM::run_module`], then handles `NameLookup` and `ExternalCall`
-    /// exits by raising `NameError` through the VM so tracebacks are properly
+    /// exits by raising `NameError`, and `ModuleLookup` exits by raising
+    /// `ModuleNotFoundError`, through the VM so tracebacks are properly
     /// captured. Finally converts the result via [`frame_exit_to_object`].
     ///
     /// This is the shared non-iterative execution core used by both the standard
@@ -376,9 +581,14 @@ impl Executor {
                     let err = ExcType::name_error(name);
                     frame_exit_result = vm.resume_with_exception(err.into());
                 }
+                Ok(FrameExit::ModuleLookup { name_id }) => {
+                    let err = ExcType::module_not_found_error(self.interns.get_str(name_id));
+                    frame_exit_result = vm.resume_with_exception(err);
+                }
                 Ok(FrameExit::ExternalCall {
                     function_name,
                     args,
+                    bound_args,
                     name_load_ip,
                     ..
                 }) => {
@@ -391,6 +601,7 @@ impl Executor {
                     }
                     let name = function_name.as_str(&self.interns);
                     args.drop_with_heap(vm);
+                    bound_args.drop_with_heap(vm);
                     let err = ExcType::name_error(name);
                     frame_exit_result = vm.resume_with_exception(err.into());
                 }
@@ -433,6 +644,7 @@ impl Executor {
         HeapReader::with(&mut heap, &mut &*self, |reader, executor| {
             // Create VM, populate inputs, and run
             let mut vm = VM::new(globals, reader, &executor.interns, PrintWriter::Stdout);
+            vm.host_modules.clone_from(&executor.modules);
             executor.populate_inputs(inputs, &mut vm)?;
             let frame_exit_result = vm.run_module(&executor.module_code);
 
@@ -460,9 +672,9 @@ impl Executor {
             let heap_count = vm.heap.entry_count();
 
             // Convert return value while VM is still alive (needs access to interns).
-            // Non-REPL: single source, so every frame resolves to `executor.code`.
-            let py_object = frame_exit_to_object(frame_exit_result, &mut vm)
-                .map_err(|e| e.into_python_exception(&executor.interns, |_| Some(executor.code.as_str())))?;
+            let py_object = frame_exit_to_object(frame_exit_result, &mut vm).map_err(|e| {
+                e.into_python_exception(&executor.interns, |filename| executor.interns.source(filename))
+            })?;
 
             // Drop globals with proper ref counting
             for value in globals {
@@ -524,9 +736,13 @@ pub(crate) fn frame_exit_to_object(
     match frame_exit_result? {
         FrameExit::Return(return_value) => Ok(MontyObject::new(return_value, vm)),
         FrameExit::ExternalCall {
-            function_name, args, ..
+            function_name,
+            args,
+            bound_args,
+            ..
         } => {
             args.drop_with_heap(vm);
+            bound_args.drop_with_heap(vm);
             let function_name = function_name.as_str(vm.interns);
             Err(ExcType::not_implemented(format!(
                 "External function '{function_name}' not implemented with standard execution"
@@ -549,6 +765,14 @@ pub(crate) fn frame_exit_to_object(
                     .into(),
             )
         }
+        FrameExit::HandleCall { handle, op, .. } => {
+            op.drop_with_heap(vm);
+            Err(ExcType::not_implemented(format!(
+                "Operation on host handle '{}' not implemented with standard execution",
+                handle.type_name()
+            ))
+            .into())
+        }
         FrameExit::ResolveFutures(_) => {
             Err(ExcType::not_implemented("async futures not supported by standard execution.").into())
         }
@@ -556,6 +780,12 @@ pub(crate) fn frame_exit_to_object(
             let name = vm.interns.get_str(name_id);
             Err(ExcType::name_error(name).into())
         }
+        FrameExit::ModuleLookup { name_id } => Err(ExcType::module_not_found_error(vm.interns.get_str(name_id))),
+        // Only the host calls callbacks, so standard execution never gets a result.
+        FrameExit::CallbackResult { result, .. } => {
+            result.ok().drop_with_heap(vm);
+            Err(RunError::internal("callback result with standard execution"))
+        }
     }
 }
 
@@ -578,6 +808,11 @@ pub struct RefCountOutput {
     pub allocations_since_gc: u32,
 }
 
+/// Builds the `ValueError` returned when a source file can't be added.
+fn value_error(msg: String) -> MontyException {
+    MontyException::new(ExcType::ValueError, Some(msg))
+}
+
 /// Check if input names are valid Python identifiers.
 ///
 /// `is_identifier` also checks that the names are not keywords.
@@ -1,7 +1,8 @@
 //! This module defines the public types returned by [`MontyRun::start()`](crate::MontyRun::start)
 //! and their resume methods. Each variant of [`RunProgress`] wraps a dedicated struct
-//! (`FunctionCall`, `OsCall`, `NameLookup`, `ResolveFutures`) that carries only the
-//! fields and resume methods relevant to that suspension point.
+//! (`FunctionCall`, `OsCall`, `HandleCall`, `CallbackResult`, `NameLookup`, `ModuleLookup`,
+//! `ResolveFutures`) that carries only the fields and resume methods relevant to that
+//! suspension point.
 //!
 //! The internal [`Snapshot`] type is `pub(crate)` — callers interact exclusively with
 //! the per-variant structs.
@@ -16,11 +17,14 @@ use crate::{
     bytecode::{FrameExit, VM, VMSnapshot},
     exception_private::{RunError, RunResult},
     heap::{Heap, HeapReader},
+    host_module::MontyModule,
     io::PrintWriter,
     object::MontyObject,
     os::OsFunctionCall,
     resource::ResourceTracker,
     run::Executor,
+    types::host_handle::HandleOp,
+    value::Value,
 };
 
 // ---------------------------------------------------------------------------
@@ -43,10 +47,16 @@ pub enum RunProgress<T: ResourceTracker> {
     FunctionCall(FunctionCall<T>),
     /// Execution paused for an OS-level operation (filesystem, network, etc.).
     OsCall(OsCall<T>),
+    /// Execution paused for an operation on a host handle.
+    HandleCall(HandleCall<T>),
+    /// A callback the host called while an external call was paused has finished.
+    CallbackResult(CallbackResult<T>),
     /// All async tasks are blocked waiting for external futures to resolve.
     ResolveFutures(ResolveFutures<T>),
     /// Execution paused for an unresolved name lookup.
     NameLookup(NameLookup<T>),
+    /// Execution paused to import a module the host hasn't declared.
+    ModuleLookup(ModuleLookup<T>),
     /// Execution completed with a final result.
     Complete(MontyObject),
 }
@@ -70,6 +80,24 @@ impl<T: ResourceTracker> RunProgress<T> {
         }
     }
 
+    /// Consumes the progress and returns the `HandleCall` struct if this is a host handle operation.
+    #[must_use]
+    pub fn into_handle_call(self) -> Option<HandleCall<T>> {
+        match self {
+            Self::HandleCall(call) => Some(call),
+            _ => None,
+        }
+    }
+
+    /// Consumes the progress and returns the `CallbackResult` struct if a callback finished.
+    #[must_use]
+    pub fn into_callback_result(self) -> Option<CallbackResult<T>> {
+        match self {
+            Self::CallbackResult(result) => Some(result),
+            _ => None,
+        }
+    }
+
     /// Consumes the progress and returns the final value if execution completed.
     #[must_use]
     pub fn into_complete(self) -> Option<MontyObject> {
@@ -96,6 +124,15 @@ impl<T: ResourceTracker> RunProgress<T> {
             _ => None,
         }
     }
+
+    /// Consumes the progress and returns the `ModuleLookup` struct.
+    #[must_use]
+    pub fn into_module_lookup(self) -> Option<ModuleLookup<T>> {
+        match self {
+            Self::ModuleLookup(lookup) => Some(lookup),
+            _ => None,
+        }
+    }
 }
 
 impl<T: ResourceTracker + serde::Serialize> RunProgress<T> {
@@ -142,6 +179,13 @@ pub struct FunctionCall<T: ResourceTracker> {
     pub args: Vec<MontyObject>,
     /// The keyword arguments passed to the function (key, value pairs).
     pub kwargs: Vec<(MontyObject, MontyObject)>,
+    /// The arguments by parameter name, if the function has a declared signature.
+    ///
+    /// Holds one entry per parameter in definition order, with defaults for omitted
+    /// arguments, a tuple for `*args` and a dict for `**kwargs`. `args` and `kwargs`
+    /// are empty when this is set. See [`MontyRun::with_signatures`](crate::MontyRun::with_signatures).
+    #[serde(default)]
+    pub bound_args: Option<Vec<(String, MontyObject)>>,
     /// Unique identifier for this call (used for async correlation).
     pub call_id: u32,
     /// Whether this is a dataclass method call (first arg is `self`).
@@ -156,6 +200,7 @@ impl<T: ResourceTracker> FunctionCall<T> {
         function_name: String,
         args: Vec<MontyObject>,
         kwargs: Vec<(MontyObject, MontyObject)>,
+        bound_args: Option<Vec<(String, MontyObject)>>,
         call_id: u32,
         method_call: bool,
         snapshot: Snapshot<T>,
@@ -164,12 +209,25 @@ impl<T: ResourceTracker> FunctionCall<T> {
             function_name,
             args,
             kwargs,
+            bound_args,
             call_id,
             method_call,
             snapshot,
         }
     }
 
+    /// Returns the argument bound to the parameter `name`.
+    ///
+    /// Always `None` if the function has no declared signature.
+    #[must_use]
+    pub fn arg(&self, name: &str) -> Option<&MontyObject> {
+        let bound_args = self.bound_args.as_ref()?;
+        bound_args
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+    }
+
     /// Returns a mutable reference to the resource tracker.
     ///
     /// This allows modifying resource limits between execution phases,
@@ -278,6 +336,180 @@ impl<T: ResourceTracker> OsCall<T> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// HandleCall
+// ---------------------------------------------------------------------------
+
+/// Execution paused for an operation on a host handle.
+///
+/// Sandbox code used a [`MontyObject::HostHandle`] the host passed in. The host
+/// should perform `operation` on its object with `handle_id` and call
+/// `resume(result, print)` with the result, or with an exception to raise in
+/// its place. Each [`HandleOperation`] says what result it expects.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::de::DeserializeOwned"))]
+pub struct HandleCall<T: ResourceTracker> {
+    /// The type name of the handle.
+    pub type_name: String,
+    /// The host-chosen id of the handle.
+    pub handle_id: u64,
+    /// What the sandbox code did with the handle.
+    pub operation: HandleOperation,
+    /// Unique identifier for this call (used for async correlation).
+    pub call_id: u32,
+    /// Internal execution snapshot.
+    snapshot: Snapshot<T>,
+}
+
+impl<T: ResourceTracker> HandleCall<T> {
+    /// Creates a new `HandleCall` from its parts.
+    fn new(type_name: String, handle_id: u64, operation: HandleOperation, call_id: u32, snapshot: Snapshot<T>) -> Self {
+        Self {
+            type_name,
+            handle_id,
+            operation,
+            call_id,
+            snapshot,
+        }
+    }
+
+    /// Returns a mutable reference to the resource tracker.
+    pub fn tracker_mut(&mut self) -> &mut T {
+        self.snapshot.heap.tracker_mut()
+    }
+
+    /// Resumes execution with the result of the operation or the exception it raised.
+    ///
+    /// # Arguments
+    /// * `result` — The return value or exception.
+    /// * `print` — Writer for `print()` output.
+    pub fn resume(
+        self,
+        result: impl Into<ExtFunctionResult>,
+        print: PrintWriter<'_>,
+    ) -> Result<RunProgress<T>, MontyException> {
+        self.snapshot.run(result, print)
+    }
+
+    /// Resumes execution by pushing an `ExternalFuture` instead of a concrete value,
+    /// like [`FunctionCall::resume_pending`].
+    ///
+    /// Only attribute reads, item reads and calls can return a future; the
+    /// other operations raise `TypeError` in the sandbox.
+    pub fn resume_pending(self, print: PrintWriter<'_>) -> Result<RunProgress<T>, MontyException> {
+        self.snapshot.run(ExtFunctionResult::Future(self.call_id), print)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CallbackResult
+// ---------------------------------------------------------------------------
+
+/// A callback the host called while an external call was paused has finished.
+///
+/// The host called a [`MontyObject::Callback`] by resuming with
+/// [`ExtFunctionResult::Callback`], and `result` is what the sandbox function
+/// returned or raised. The external call is still paused: `resume` it with its
+/// result, or with another `ExtFunctionResult::Callback`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::de::DeserializeOwned"))]
+pub struct CallbackResult<T: ResourceTracker> {
+    /// Id of the callback that finished.
+    pub callback_id: u32,
+    /// The callback's return value, or the exception it raised.
+    pub result: Result<MontyObject, MontyException>,
+    /// Internal execution snapshot.
+    snapshot: Snapshot<T>,
+}
+
+impl<T: ResourceTracker> CallbackResult<T> {
+    /// Creates a new `CallbackResult` from its parts.
+    fn new(callback_id: u32, result: Result<MontyObject, MontyException>, snapshot: Snapshot<T>) -> Self {
+        Self {
+            callback_id,
+            result,
+            snapshot,
+        }
+    }
+
+    /// Returns a mutable reference to the resource tracker.
+    pub fn tracker_mut(&mut self) -> &mut T {
+        self.snapshot.heap.tracker_mut()
+    }
+
+    /// Resumes the paused external call, like [`FunctionCall::resume`].
+    ///
+    /// # Arguments
+    /// * `result` — The external call's return value, exception or pending future marker,
+    ///   or another callback to call.
+    /// * `print` — Writer for `print()` output.
+    pub fn resume(
+        self,
+        result: impl Into<ExtFunctionResult>,
+        print: PrintWriter<'_>,
+    ) -> Result<RunProgress<T>, MontyException> {
+        self.snapshot.run(result, print)
+    }
+}
+
+/// An operation sandbox code made on a host handle, and the result it expects.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum HandleOperation {
+    /// Attribute read, `h.name`: resume with the attribute's value.
+    GetAttr { name: String },
+    /// Attribute assignment, `h.name = value`: the result is ignored.
+    SetAttr { name: String, value: MontyObject },
+    /// Item read, `h[key]`: resume with the item.
+    GetItem { key: MontyObject },
+    /// Call of the handle itself, `h(*args, **kwargs)`: resume with the return value.
+    Call {
+        args: Vec<MontyObject>,
+        kwargs: Vec<(MontyObject, MontyObject)>,
+    },
+    /// Method call, `h.name(*args, **kwargs)`: resume with the return value.
+    CallMethod {
+        name: String,
+        args: Vec<MontyObject>,
+        kwargs: Vec<(MontyObject, MontyObject)>,
+    },
+    /// Iteration, `iter(h)` or `for x in h`: resume with an iterable, such as a list.
+    Iter,
+    /// `len(h)`: resume with a non-negative int.
+    Len,
+    /// Truth test, `bool(h)`, `not h` or `if h:`: resume with a bool.
+    Bool,
+}
+
+impl HandleOperation {
+    /// Converts the VM's operation, releasing its operands.
+    fn new(op: HandleOp, vm: &mut VM<'_, impl ResourceTracker>) -> Self {
+        match op {
+            HandleOp::GetAttr(name) => Self::GetAttr {
+                name: name.into_string(vm.interns),
+            },
+            HandleOp::SetAttr(name, value) => Self::SetAttr {
+                name: name.into_string(vm.interns),
+                value: MontyObject::new_host_arg(value, vm),
+            },
+            HandleOp::GetItem(key) => Self::GetItem {
+                key: MontyObject::new_host_arg(key, vm),
+            },
+            HandleOp::Call(args) => {
+                let (args, kwargs) = args.into_py_objects(vm);
+                Self::Call { args, kwargs }
+            }
+            HandleOp::CallMethod(name, args) => {
+                let name = name.into_string(vm.interns);
+                let (args, kwargs) = args.into_py_objects(vm);
+                Self::CallMethod { name, args, kwargs }
+            }
+            HandleOp::Iter => Self::Iter,
+            HandleOp::Len => Self::Len,
+            HandleOp::Bool => Self::Bool,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // NameLookup
 // ---------------------------------------------------------------------------
@@ -387,6 +619,112 @@ impl<T: ResourceTracker> NameLookup<T> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// ModuleLookup
+// ---------------------------------------------------------------------------
+
+/// Execution paused to import a module that is neither in the standard library
+/// nor declared with [`MontyRun::with_modules`](crate::MontyRun::with_modules).
+///
+/// Call `resume(result, print)` with `ModuleLookupResult::Module(module)` to
+/// supply the module, or `ModuleLookupResult::NotFound` to raise
+/// `ModuleNotFoundError`. A supplied module is remembered for the rest of the
+/// execution, so importing it again doesn't pause.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::de::DeserializeOwned"))]
+pub struct ModuleLookup<T: ResourceTracker> {
+    /// The full dotted name of the module being imported.
+    pub name: String,
+    /// Internal execution snapshot.
+    snapshot: Snapshot<T>,
+}
+
+impl<T: ResourceTracker> ModuleLookup<T> {
+    /// Creates a new `ModuleLookup` from its parts.
+    fn new(name: String, snapshot: Snapshot<T>) -> Self {
+        Self { name, snapshot }
+    }
+
+    /// Resumes execution after module resolution.
+    ///
+    /// The supplied module is registered under the imported name, whatever name it
+    /// was created with, then built and pushed as the result of the import.
+    ///
+    /// # Arguments
+    /// * `result` — The module, or `NotFound`.
+    /// * `print` — Writer for print output.
+    pub fn resume(
+        self,
+        result: impl Into<ModuleLookupResult>,
+        print: PrintWriter<'_>,
+    ) -> Result<RunProgress<T>, MontyException> {
+        let Snapshot {
+            mut heap,
+            executor,
+            vm_state,
+        } = self.snapshot;
+        let result = result.into();
+        let name = self.name;
+
+        let (converted, vm_state) =
+            HeapReader::with(&mut heap, &mut (&executor, print), |reader, (executor, print)| {
+                let mut vm = VM::restore(
+                    vm_state,
+                    &executor.module_code,
+                    reader,
+                    &executor.interns,
+                    print.reborrow(),
+                );
+                let vm_result = resume_module_lookup(&mut vm, &name, result);
+
+                // Three-phase: convert while VM alive, snapshot, build progress
+                let converted = convert_frame_exit(vm_result, &mut vm);
+                let vm_state = check_snapshot_from_converted(&converted, vm);
+                (converted, vm_state)
+            });
+        build_run_progress(converted, vm_state, executor, heap)
+    }
+}
+
+/// Resolves a paused `LoadHostModule` with the host's answer and continues.
+///
+/// Shared by [`ModuleLookup::resume`] and the REPL equivalent.
+pub(crate) fn resume_module_lookup(
+    vm: &mut VM<'_, impl ResourceTracker>,
+    name: &str,
+    result: ModuleLookupResult,
+) -> RunResult<FrameExit> {
+    match result {
+        ModuleLookupResult::Module(mut module) => {
+            module.set_name(name.to_owned());
+            vm.host_modules.push(module.clone());
+            match module.create(name, vm) {
+                Ok(heap_id) => {
+                    vm.push(Value::Ref(heap_id));
+                    vm.run()
+                }
+                Err(err) => vm.resume_with_exception(err),
+            }
+        }
+        ModuleLookupResult::NotFound => vm.resume_with_exception(ExcType::module_not_found_error(name)),
+    }
+}
+
+/// Result of a module lookup from the host.
+#[derive(Debug)]
+pub enum ModuleLookupResult {
+    /// The module to import.
+    Module(MontyModule),
+    /// No such module — VM will raise `ModuleNotFoundError`.
+    NotFound,
+}
+
+impl From<MontyModule> for ModuleLookupResult {
+    fn from(module: MontyModule) -> Self {
+        Self::Module(module)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ResolveFutures
 // ---------------------------------------------------------------------------
@@ -580,6 +918,11 @@ impl<T: ResourceTracker> Snapshot<T> {
                     ExtFunctionResult::NotFound(function_name) => {
                         vm.resume_with_exception(ExtFunctionResult::not_found_exc(&function_name))
                     }
+                    ExtFunctionResult::Callback {
+                        callback_id,
+                        args,
+                        kwargs,
+                    } => vm.call_callback(callback_id, args, kwargs),
                 };
 
                 // Three-phase: convert while VM alive, snapshot, build progress
@@ -625,6 +968,19 @@ pub enum ExtFunctionResult {
     Future(u32),
     /// The function was not found, should result in a `NameError` exception.
     NotFound(String),
+    /// Calls a [`MontyObject::Callback`] from the paused call's arguments, leaving the
+    /// call paused.
+    ///
+    /// Execution continues in the callback until it finishes with
+    /// [`RunProgress::CallbackResult`], or makes a call of its own.
+    Callback {
+        /// The callback's id.
+        callback_id: u32,
+        /// Positional arguments for the callback.
+        args: Vec<MontyObject>,
+        /// Keyword arguments for the callback (key, value pairs).
+        kwargs: Vec<(MontyObject, MontyObject)>,
+    },
 }
 
 impl ExtFunctionResult {
@@ -663,6 +1019,7 @@ pub(crate) enum ConvertedExit {
         function_name: String,
         args: Vec<MontyObject>,
         kwargs: Vec<(MontyObject, MontyObject)>,
+        bound_args: Option<Vec<(String, MontyObject)>>,
         call_id: u32,
         method_call: bool,
     },
@@ -671,6 +1028,13 @@ pub(crate) enum ConvertedExit {
         function_call: OsFunctionCall,
         call_id: u32,
     },
+    /// Operation on a host handle.
+    HandleCall {
+        type_name: String,
+        handle_id: u64,
+        operation: HandleOperation,
+        call_id: u32,
+    },
     /// All async tasks are blocked waiting for external futures.
     ResolveFutures(Vec<u32>),
     /// Unresolved name lookup.
@@ -679,6 +1043,13 @@ pub(crate) enum ConvertedExit {
         namespace_slot: u16,
         is_global: bool,
     },
+    /// Import of a module the host hasn't declared.
+    ModuleLookup { name: String },
+    /// A callback called by the host finished.
+    CallbackResult {
+        callback_id: u32,
+        result: Result<MontyObject, RunError>,
+    },
     /// Runtime error.
     Error(RunError),
 }
@@ -693,22 +1064,26 @@ impl ConvertedExit {
 /// Converts a `FrameExit` into a `ConvertedExit` while the VM is still alive.
 ///
 /// All `Value` → `MontyObject` and `StringId` → `String` conversions happen here,
-/// while the VM (and its heap/interns) are still accessible.
+/// while the VM (and its heap/interns) are still accessible. If the resource tracker
+/// refused to register a callback passed to the host, the run fails instead.
 pub(crate) fn convert_frame_exit(result: RunResult<FrameExit>, vm: &mut VM<'_, impl ResourceTracker>) -> ConvertedExit {
-    match result {
+    let converted = match vm.catch_callback_error(result) {
         Ok(FrameExit::Return(value)) => ConvertedExit::Complete(MontyObject::new(value, vm)),
         Ok(FrameExit::ExternalCall {
             function_name,
             args,
+            bound_args,
             call_id,
             ..
         }) => {
             let name = function_name.into_string(vm.interns);
             let (args_py, kwargs_py) = args.into_py_objects(vm);
+            let bound_args = bound_args.map(|bound_args| bound_args.into_py_objects(&name, vm));
             ConvertedExit::FunctionCall {
                 function_name: name,
                 args: args_py,
                 kwargs: kwargs_py,
+                bound_args,
                 call_id: call_id.raw(),
                 method_call: false,
             }
@@ -728,10 +1103,17 @@ pub(crate) fn convert_frame_exit(result: RunResult<FrameExit>, vm: &mut VM<'_, i
                 function_name: name,
                 args: args_py,
                 kwargs: kwargs_py,
+                bound_args: None,
                 call_id: call_id.raw(),
                 method_call: true,
             }
         }
+        Ok(FrameExit::HandleCall { handle, op, call_id }) => ConvertedExit::HandleCall {
+            type_name: handle.type_name().to_owned(),
+            handle_id: handle.handle_id(),
+            operation: HandleOperation::new(op, vm),
+            call_id: call_id.raw(),
+        },
         Ok(FrameExit::ResolveFutures(pending_call_ids)) => {
             ConvertedExit::ResolveFutures(pending_call_ids.iter().map(|id| id.raw()).collect())
         }
@@ -747,7 +1129,18 @@ pub(crate) fn convert_frame_exit(result: RunResult<FrameExit>, vm: &mut VM<'_, i
                 is_global,
             }
         }
+        Ok(FrameExit::ModuleLookup { name_id }) => ConvertedExit::ModuleLookup {
+            name: vm.interns.get_str(name_id).to_owned(),
+        },
+        Ok(FrameExit::CallbackResult { callback_id, result }) => ConvertedExit::CallbackResult {
+            callback_id,
+            result: result.map(|value| MontyObject::new_host_arg(value, vm)),
+        },
         Err(err) => ConvertedExit::Error(err),
+    };
+    match vm.take_callback_error() {
+        Some(err) => ConvertedExit::Error(err.into()),
+        None => converted,
     }
 }
 
@@ -792,12 +1185,14 @@ pub(crate) fn build_run_progress<T: ResourceTracker>(
             function_name,
             args,
             kwargs,
+            bound_args,
             call_id,
             method_call,
         } => Ok(RunProgress::FunctionCall(FunctionCall::new(
             function_name,
             args,
             kwargs,
+            bound_args,
             call_id,
             method_call,
             new_snapshot!(),
@@ -807,6 +1202,18 @@ pub(crate) fn build_run_progress<T: ResourceTracker>(
             call_id,
             new_snapshot!(),
         ))),
+        ConvertedExit::HandleCall {
+            type_name,
+            handle_id,
+            operation,
+            call_id,
+        } => Ok(RunProgress::HandleCall(HandleCall::new(
+            type_name,
+            handle_id,
+            operation,
+            call_id,
+            new_snapshot!(),
+        ))),
         ConvertedExit::ResolveFutures(pending_call_ids) => Ok(RunProgress::ResolveFutures(ResolveFutures::new(
             executor,
             vm_state.expect("snapshot should exist for ResolveFutures"),
@@ -823,8 +1230,19 @@ pub(crate) fn build_run_progress<T: ResourceTracker>(
             is_global,
             new_snapshot!(),
         ))),
+        ConvertedExit::ModuleLookup { name } => Ok(RunProgress::ModuleLookup(ModuleLookup::new(name, new_snapshot!()))),
+        ConvertedExit::CallbackResult { callback_id, result } => {
+            let result = result.map_err(|err| {
+                err.into_python_exception(&executor.interns, |filename| executor.interns.source(filename))
+            });
+            Ok(RunProgress::CallbackResult(CallbackResult::new(
+                callback_id,
+                result,
+                new_snapshot!(),
+            )))
+        }
         ConvertedExit::Error(err) => {
-            Err(err.into_python_exception(&executor.interns, |_| Some(executor.code.as_str())))
+            Err(err.into_python_exception(&executor.interns, |filename| executor.interns.source(filename)))
         }
     }
 }
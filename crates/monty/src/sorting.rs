@@ -147,7 +147,8 @@ fn compare_values(
         // short-circuit if we've already encountered an error in a previous comparison
         return Ordering::Equal;
     }
-    if let Err(e) = vm.heap.check_time() {
+    // Each comparison costs one unit of fuel.
+    if let Err(e) = vm.heap.check_time().and_then(|()| vm.heap.consume_fuel(1)) {
         *sort_result = Err(e.into());
         return Ordering::Equal;
     }
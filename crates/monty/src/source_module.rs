@@ -0,0 +1,77 @@
+//! Python modules supplied by the host as source files.
+//!
+//! Hosts add files with [`MontyRun::with_file`](crate::MontyRun::with_file). Each
+//! file is compiled up front into a `<module>` function with its own range of
+//! global slots, and imported like a regular Python module: the first import runs
+//! the module body and caches the module object for the rest of the run, so
+//! later (and circular) imports see the same, possibly partially initialized, module.
+
+use ahash::AHashMap;
+use ruff_python_stdlib::identifiers::is_identifier;
+
+use crate::{intern::FunctionId, namespace::NamespaceId, types::str::StringRepr};
+
+/// A module compiled from a source file, or a package directory without an `__init__.py`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct SourceModule {
+    /// The dotted module name, e.g. `pkg.helpers`.
+    pub name: String,
+    /// The `<module>` function running the module body.
+    ///
+    /// `None` for a package that only exists because one of its submodules was
+    /// supplied, which imports as an empty module.
+    pub body: Option<FunctionId>,
+    /// Maps the module's global names to their slots in the VM's globals.
+    pub globals: AHashMap<String, NamespaceId>,
+}
+
+impl SourceModule {
+    /// Creates an empty package module, for a package directory without an `__init__.py`.
+    pub fn namespace_package(name: String) -> Self {
+        Self {
+            name,
+            body: None,
+            globals: AHashMap::new(),
+        }
+    }
+
+    /// Returns the dotted name of the module's parent package, if it's a submodule.
+    pub fn parent(&self) -> Option<&str> {
+        self.name.rsplit_once('.').map(|(parent, _)| parent)
+    }
+}
+
+/// The module a source file defines, derived from its path.
+pub(crate) struct ModulePath {
+    /// Dotted module name: `pkg/helpers.py` is `pkg.helpers`, `pkg/__init__.py` is `pkg`.
+    pub name: String,
+    /// The package relative imports resolve against: the module itself for an
+    /// `__init__.py`, its parent otherwise, empty for a top-level module.
+    pub package: String,
+}
+
+impl ModulePath {
+    /// Derives the module name from a relative `/`-separated path ending in `.py`.
+    ///
+    /// Returns an error message if the path can't name an importable module.
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let invalid = || format!("{} is not a valid module path", StringRepr(path));
+        let stem = path.strip_prefix("./").unwrap_or(path);
+        let stem = stem.strip_suffix(".py").ok_or_else(invalid)?;
+        let mut parts: Vec<&str> = stem.split('/').collect();
+        let is_package = parts.len() > 1 && parts.last() == Some(&"__init__");
+        if is_package {
+            parts.pop();
+        }
+        if !parts.iter().all(|part| is_identifier(part)) {
+            return Err(invalid());
+        }
+        let name = parts.join(".");
+        let package = if is_package {
+            name.clone()
+        } else {
+            parts[..parts.len() - 1].join(".")
+        };
+        Ok(Self { name, package })
+    }
+}
@@ -0,0 +1,81 @@
+//! Instances of the builtin `object` type.
+//!
+//! `object()` has no state of its own; instances are only useful for their
+//! identity, typically as sentinels (`_MISSING = object()`).
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use ahash::AHashSet;
+
+use super::{PyTrait, Type};
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    exception_private::{ExcType, RunResult, SimpleException},
+    hash::HashValue,
+    heap::{DropWithHeap, HeapData, HeapId, HeapItem, HeapRead},
+    resource::ResourceTracker,
+    value::Value,
+};
+
+/// A bare `object()` instance, compared and hashed by identity.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) struct BaseObject;
+
+/// Creates a new `object()` instance.
+///
+/// `object()` takes no arguments.
+pub(crate) fn init(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    if !matches!(args, ArgValues::Empty) {
+        args.drop_with_heap(vm);
+        return Err(SimpleException::new_msg(ExcType::TypeError, "object() takes no arguments").into());
+    }
+    Ok(Value::Ref(vm.heap.allocate(HeapData::BaseObject(BaseObject))?))
+}
+
+impl HeapItem for BaseObject {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>()
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {}
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, BaseObject> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::Object
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// Instances only compare equal to themselves, which `Value::py_eq` checks by identity.
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_hash(&self, self_id: HeapId, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        _vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        Ok(f.write_str("<object object>")?)
+    }
+}
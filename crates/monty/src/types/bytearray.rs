@@ -0,0 +1,405 @@
+//! Python `bytearray` type: a mutable sequence of bytes.
+//!
+//! Every non-mutating method is shared with `bytes` through
+//! [`call_bytes_method_impl`], which returns `bytearray` results for a
+//! `bytearray` receiver. This module adds the mutators (`append`, `extend`,
+//! `pop`, `insert`, `remove`, `clear`, `reverse`, `copy`) and item/slice
+//! assignment, which charge any growth against the resource tracker.
+//!
+//! Resizing is never blocked by live `memoryview`s over the same buffer;
+//! see [`super::MemoryView`] for how views cope with that.
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt::{self, Write},
+    mem,
+};
+
+use ahash::AHashSet;
+
+use super::{
+    PyTrait, Slice, Type,
+    bytes::{
+        byte_value, bytes_from_source, bytes_like_contents, bytes_repr_fmt, call_bytes_method_impl,
+        collect_byte_values, get_byte_at_index,
+    },
+    slice::{slice_collect_iterator, slice_length},
+};
+use crate::{
+    args::{ArgValues, FromArgs},
+    bytecode::{CallResult, VM},
+    codecs::Codec,
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, HeapData, HeapId, HeapItem, HeapRead, heap_read_ref_as_field},
+    intern::StaticStrings,
+    resource::{ResourceError, ResourceTracker},
+    value::{EitherStr, Value},
+};
+
+/// Python bytearray value stored on the heap.
+///
+/// Unlike [`super::Bytes`] it is mutable, so it carries no cached hash and is unhashable.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub(crate) struct ByteArray(Vec<u8>);
+
+impl ByteArray {
+    /// Creates a new bytearray from a byte vector.
+    #[must_use]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns a reference to the inner byte slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Creates a bytearray from the `bytearray()` constructor call.
+    ///
+    /// - `bytearray()` returns an empty bytearray
+    /// - `bytearray(int)` returns that many zero bytes
+    /// - `bytearray(bytes_like)` copies a `bytes`, `bytearray` or `memoryview`
+    /// - `bytearray(iterable)` collects ints in `range(0, 256)`
+    /// - `bytearray(string, encoding[, errors])` encodes the string
+    pub fn init(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        let ByteArrayInitArgs {
+            source,
+            encoding,
+            errors,
+        } = ByteArrayInitArgs::from_args(args, vm)?;
+        let data = match (source, encoding) {
+            (None, None) => Vec::new(),
+            (Some(source), None) => {
+                if source.is_str(vm.heap) {
+                    source.drop_with_heap(vm);
+                    return Err(ExcType::type_error("string argument without an encoding"));
+                }
+                bytes_from_source(source, Type::ByteArray, vm)?
+            }
+            (Some(source), Some(encoding)) if source.is_str(vm.heap) => {
+                defer_drop!(source, vm);
+                let codec = Codec::lookup(&encoding)?;
+                let s = source.as_either_str(vm.heap).expect("source checked to be a str");
+                codec.encode(
+                    s.as_str(vm.interns),
+                    errors.as_deref().unwrap_or("strict"),
+                    vm.heap.tracker(),
+                )?
+            }
+            (source, Some(_)) => {
+                source.drop_with_heap(vm);
+                return Err(ExcType::type_error("encoding without a string argument"));
+            }
+        };
+        let heap_id = vm.heap.allocate(HeapData::ByteArray(Self::new(data)))?;
+        Ok(Value::Ref(heap_id))
+    }
+}
+
+/// Argument shape for `bytearray(source=..., encoding=..., errors=...)`.
+#[derive(FromArgs)]
+#[from_args(name = "bytearray", c_error_named)]
+struct ByteArrayInitArgs {
+    #[from_args(default)]
+    source: Option<Value>,
+    #[from_args(default)]
+    encoding: Option<String>,
+    #[from_args(default)]
+    errors: Option<String>,
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, ByteArray> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::ByteArray
+    }
+
+    fn py_len(&self, vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        Some(self.get(vm.heap).0.len())
+    }
+
+    fn py_getitem(&self, key: &Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+        if let Value::Ref(id) = key
+            && let HeapData::Slice(slice) = vm.heap.get(*id)
+        {
+            let b = self.get(vm.heap);
+            let sliced = slice_collect_iterator(vm, slice, b.0.iter(), |b| *b)?;
+            let heap_id = vm.heap.allocate(HeapData::ByteArray(ByteArray::new(sliced)))?;
+            return Ok(Value::Ref(heap_id));
+        }
+
+        let index = key.as_index(vm, Type::ByteArray)?;
+        let byte = get_byte_at_index(&self.get(vm.heap).0, index).ok_or_else(index_error)?;
+        Ok(Value::Int(i64::from(byte)))
+    }
+
+    fn py_setitem(&mut self, key: Value, value: Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<()> {
+        defer_drop!(key, vm);
+        defer_drop!(value, vm);
+
+        if let Value::Ref(id) = key
+            && let HeapData::Slice(slice) = vm.heap.get(*id)
+        {
+            let slice = slice.clone();
+            return self.setitem_slice(&slice, value, vm);
+        }
+
+        let index = key.as_index(vm, Type::ByteArray)?;
+        let len = i64::try_from(self.get(vm.heap).0.len()).expect("bytearray length exceeds i64::MAX");
+        let normalized = if index < 0 { index + len } else { index };
+        if normalized < 0 || normalized >= len {
+            return Err(index_error());
+        }
+        let byte = byte_value(value, Type::ByteArray, vm)?;
+        let idx = usize::try_from(normalized).expect("index validated non-negative");
+        self.get_mut(vm.heap).0[idx] = byte;
+        Ok(())
+    }
+
+    fn py_eq(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(self.get(vm.heap).0 == other.get(vm.heap).0)
+    }
+
+    fn py_cmp(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<Ordering>> {
+        Ok(Some(self.get(vm.heap).0.cmp(&other.get(vm.heap).0)))
+    }
+
+    fn py_bool(&self, vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        !self.get(vm.heap).0.is_empty()
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        Ok(bytearray_repr_fmt(&self.get(vm.heap).0, f)?)
+    }
+
+    fn py_add(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> Result<Option<Value>, ResourceError> {
+        let result = [self.get(vm.heap).as_slice(), other.get(vm.heap).as_slice()].concat();
+        Ok(Some(Value::Ref(
+            vm.heap.allocate(HeapData::ByteArray(ByteArray::new(result)))?,
+        )))
+    }
+
+    /// Extends in place from any bytes-like `other`, including the bytearray itself.
+    fn py_iadd(
+        &mut self,
+        other: &Value,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _self_id: Option<HeapId>,
+    ) -> Result<bool, ResourceError> {
+        let Some(extra) = bytes_like_contents(other, vm.heap, vm.interns).map(Cow::into_owned) else {
+            return Ok(false);
+        };
+        vm.heap.track_growth(extra.len())?;
+        self.get_mut(vm.heap).0.extend(extra);
+        Ok(true)
+    }
+
+    fn py_call_attr(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        let Some(method) = attr.static_string() else {
+            args.drop_with_heap(vm);
+            return Err(ExcType::attribute_error(Type::ByteArray, attr.as_str(vm.interns)));
+        };
+
+        let result = match method {
+            StaticStrings::Append => {
+                let item = args.get_one_arg("bytearray.append", vm.heap)?;
+                defer_drop!(item, vm);
+                let byte = byte_value(item, Type::ByteArray, vm)?;
+                vm.heap.track_growth(1)?;
+                self.get_mut(vm.heap).0.push(byte);
+                Value::None
+            }
+            StaticStrings::Extend => {
+                let iterable = args.get_one_arg("bytearray.extend", vm.heap)?;
+                let extra = extend_source(iterable, vm)?;
+                vm.heap.track_growth(extra.len())?;
+                self.get_mut(vm.heap).0.extend(extra);
+                Value::None
+            }
+            StaticStrings::Insert => {
+                let (index, item) = args.get_two_args("insert", vm.heap)?;
+                defer_drop!(index, vm);
+                defer_drop!(item, vm);
+                let index = index.as_int(vm)?;
+                let byte = byte_value(item, Type::ByteArray, vm)?;
+                let len = self.get(vm.heap).0.len();
+                // Like `list.insert`, out-of-range indices clamp to either end.
+                let index = if index < 0 {
+                    let abs = usize::try_from(index.unsigned_abs()).unwrap_or(usize::MAX);
+                    len.saturating_sub(abs)
+                } else {
+                    usize::try_from(index).unwrap_or(len).min(len)
+                };
+                vm.heap.track_growth(1)?;
+                self.get_mut(vm.heap).0.insert(index, byte);
+                Value::None
+            }
+            StaticStrings::Pop => {
+                let index_arg = args.get_zero_one_arg("bytearray.pop", vm.heap)?;
+                let index = if let Some(v) = index_arg {
+                    defer_drop!(v, vm);
+                    v.as_int(vm)?
+                } else {
+                    -1
+                };
+                let len = self.get(vm.heap).0.len();
+                if len == 0 {
+                    return Err(SimpleException::new_msg(ExcType::IndexError, "pop from empty bytearray").into());
+                }
+                let len_i64 = i64::try_from(len).expect("bytearray length exceeds i64::MAX");
+                let normalized = if index < 0 { index + len_i64 } else { index };
+                if normalized < 0 || normalized >= len_i64 {
+                    return Err(ExcType::index_error_pop_out_of_range());
+                }
+                let idx = usize::try_from(normalized).expect("index validated non-negative");
+                Value::Int(i64::from(self.get_mut(vm.heap).0.remove(idx)))
+            }
+            StaticStrings::Remove => {
+                let item = args.get_one_arg("bytearray.remove", vm.heap)?;
+                defer_drop!(item, vm);
+                let byte = byte_value(item, Type::ByteArray, vm)?;
+                let data = &mut self.get_mut(vm.heap).0;
+                let Some(position) = data.iter().position(|b| *b == byte) else {
+                    return Err(SimpleException::new_msg(ExcType::ValueError, "value not found in bytearray").into());
+                };
+                data.remove(position);
+                Value::None
+            }
+            StaticStrings::Clear => {
+                args.check_zero_args("bytearray.clear", vm.heap)?;
+                mem::take(&mut self.get_mut(vm.heap).0);
+                Value::None
+            }
+            StaticStrings::Reverse => {
+                args.check_zero_args("bytearray.reverse", vm.heap)?;
+                self.get_mut(vm.heap).0.reverse();
+                Value::None
+            }
+            StaticStrings::Copy => {
+                args.check_zero_args("bytearray.copy", vm.heap)?;
+                let copy = self.get(vm.heap).0.clone();
+                Value::Ref(vm.heap.allocate(HeapData::ByteArray(ByteArray::new(copy)))?)
+            }
+            _ => {
+                let field = heap_read_ref_as_field!(self, ByteArray, 0);
+                let bytes = field.as_slice(vm.heap);
+                call_bytes_method_impl(&bytes, Type::ByteArray, method, args, vm)?
+            }
+        };
+        Ok(CallResult::Value(result))
+    }
+}
+
+impl<'h> HeapRead<'h, ByteArray> {
+    /// Implements `ba[slice] = value`.
+    ///
+    /// A simple slice is replaced by `value` whatever its length, growing or
+    /// shrinking the bytearray; an extended slice (step other than 1) requires
+    /// `value` to have exactly as many bytes as the slice selects.
+    fn setitem_slice(&mut self, slice: &Slice, value: &Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<()> {
+        let new = assignment_source(value, vm)?;
+        let len = self.get(vm.heap).0.len();
+        let (start, stop, step) = slice.indices(len)?;
+
+        if step == 1 {
+            let start = usize::try_from(start).expect("slice.indices() clamps start to [0, len] for step > 0");
+            let stop = usize::try_from(stop)
+                .expect("slice.indices() clamps stop to [0, len] for step > 0")
+                .max(start);
+            if let Some(growth) = new.len().checked_sub(stop - start) {
+                vm.heap.track_growth(growth)?;
+            }
+            self.get_mut(vm.heap).0.splice(start..stop, new);
+            return Ok(());
+        }
+
+        let count = slice_length(start, stop, step);
+        if new.len() != count {
+            return Err(SimpleException::new_msg(
+                ExcType::ValueError,
+                format!(
+                    "attempt to assign bytes of size {} to extended slice of size {count}",
+                    new.len()
+                ),
+            )
+            .into());
+        }
+        let data = &mut self.get_mut(vm.heap).0;
+        let mut position = start;
+        for byte in new {
+            let idx = usize::try_from(position).expect("extended slice positions lie within the bytearray");
+            data[idx] = byte;
+            position += step;
+        }
+        Ok(())
+    }
+}
+
+impl HeapItem for ByteArray {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>() + self.0.len()
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {
+        // No-op: bytearrays don't hold Value references
+    }
+}
+
+/// Writes the repr of a bytearray, e.g. `bytearray(b'abc')`.
+pub fn bytearray_repr_fmt(bytes: &[u8], f: &mut impl Write) -> fmt::Result {
+    f.write_str("bytearray(")?;
+    bytes_repr_fmt(bytes, f)?;
+    f.write_char(')')
+}
+
+/// Converts the right-hand side of `ba[slice] = value` into bytes.
+///
+/// Accepts a bytes-like object or an iterable of ints; ints and `str` are rejected
+/// as in CPython.
+fn assignment_source(value: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Vec<u8>> {
+    if let Some(contents) = bytes_like_contents(value, vm.heap, vm.interns).map(Cow::into_owned) {
+        return Ok(contents);
+    }
+    if matches!(value, Value::Int(_) | Value::Bool(_)) || value.is_str(vm.heap) {
+        return Err(ExcType::type_error(
+            "can assign only bytes, buffers, or iterables of ints in range(0, 256)",
+        ));
+    }
+    collect_byte_values(value.clone_with_heap(vm), Type::ByteArray, vm)
+}
+
+/// Converts the argument of `bytearray.extend()` into bytes.
+fn extend_source(iterable: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Vec<u8>> {
+    let contents = bytes_like_contents(&iterable, vm.heap, vm.interns).map(Cow::into_owned);
+    if let Some(contents) = contents {
+        iterable.drop_with_heap(vm);
+        return Ok(contents);
+    }
+    if iterable.is_str(vm.heap) {
+        iterable.drop_with_heap(vm);
+        return Err(ExcType::type_error("expected iterable of integers; got: 'str'"));
+    }
+    if matches!(iterable, Value::Int(_) | Value::Bool(_) | Value::Float(_) | Value::None) {
+        let t = iterable.py_type(vm);
+        return Err(ExcType::type_error(format!("can't extend bytearray with {t}")));
+    }
+    collect_byte_values(iterable, Type::ByteArray, vm)
+}
+
+/// Creates an IndexError for an out-of-range bytearray index.
+fn index_error() -> RunError {
+    SimpleException::new_msg(ExcType::IndexError, "bytearray index out of range").into()
+}
@@ -7,7 +7,7 @@
 /// # Implemented Methods
 ///
 /// ## Encoding/Decoding
-/// - `decode([encoding[, errors]])` - Decode to string (UTF-8 only)
+/// - `decode([encoding[, errors]])` - Decode to string
 /// - `hex([sep[, bytes_per_sep]])` - Return hex string representation
 /// - `fromhex(string)` - Create bytes from hex string (classmethod)
 ///
@@ -63,14 +63,13 @@
 ///
 /// # Unimplemented Methods
 /// - `expandtabs(tabsize=8)` - Tab expansion
-/// - `translate(table[, delete])` - Character translation
-/// - `maketrans(frm, to)` - Create translation table (staticmethod)
 use std::{
+    borrow::Cow,
     cell::Cell,
     cmp::Ordering,
     ffi::c_int,
     fmt::{self, Write},
-    mem, ops, str,
+    mem, ops,
 };
 
 use ahash::AHashSet;
@@ -80,14 +79,16 @@ use super::{MontyIter, PyTrait, Type};
 use crate::{
     args::{ArgValues, FromArgs},
     bytecode::{CallResult, VM},
+    codecs::Codec,
     defer_drop, defer_drop_mut,
     exception_private::{ExcType, RunResult, SimpleException},
     hash::{HashValue, hash_python_bytes},
     heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId, HeapItem, HeapRead, heap_read_ref_as_field},
-    intern::{StaticStrings, StringId},
-    resource::{ResourceError, ResourceTracker, check_repeat_size, check_replace_size},
+    intern::{Interns, StaticStrings, StringId},
+    resource::{ResourceError, ResourceTracker, check_estimated_size, check_repeat_size, check_replace_size},
     types::{
-        List,
+        ByteArray, List,
+        host_handle::checked_truth,
         slice::{normalize_sequence_index, slice_collect_iterator},
     },
     value::{EitherStr, Value},
@@ -159,41 +160,21 @@ impl Bytes {
     /// - `bytes()` with no args returns empty bytes
     /// - `bytes(int)` returns bytes of that length filled with zeros
     /// - `bytes(string)` encodes the string as UTF-8 (simplified, no encoding param)
-    /// - `bytes(bytes)` returns a copy of the bytes
+    /// - `bytes(bytes_like)` returns a copy of a `bytes`, `bytearray` or `memoryview`
+    /// - `bytes(iterable)` collects ints in `range(0, 256)`
     ///
     /// Note: Full Python semantics for bytes() are more complex (encoding, errors params).
     pub fn init(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
         let BytesInitArgs { source } = BytesInitArgs::from_args(args, vm)?;
-        defer_drop!(source, vm);
         let new_data = match source {
             None => Vec::new(),
-            Some(Value::Int(n)) => {
-                if *n < 0 {
-                    return Err(ExcType::value_error_negative_bytes_count());
-                }
-                let size = usize::try_from(*n).expect("bytes count validated non-negative");
-                // Pre-check the requested size against resource limits before
-                // touching the global allocator. Without this, `bytes(n)` for a
-                // very large `n` would attempt the native allocation directly
-                // and abort the host on failure rather than raising MemoryError.
-                // Mirrors the guard already used by `bytes.ljust`/`zfill`/`*`.
-                check_repeat_size(size, 1, vm.heap.tracker())?;
-                vec![0u8; size]
-            }
-            Some(Value::InternString(string_id)) => {
-                let s = vm.interns.get_str(*string_id);
-                s.as_bytes().to_vec()
-            }
-            Some(Value::InternBytes(bytes_id)) => {
-                let b = vm.interns.get_bytes(*bytes_id);
-                b.to_vec()
+            Some(Value::InternString(string_id)) => vm.interns.get_str(string_id).as_bytes().to_vec(),
+            Some(v @ Value::Ref(id)) if let HeapData::Str(s) = vm.heap.get(id) => {
+                let data = s.as_str().as_bytes().to_vec();
+                v.drop_with_heap(vm);
+                data
             }
-            Some(v @ Value::Ref(id)) => match vm.heap.get(*id) {
-                HeapData::Str(s) => s.as_str().as_bytes().to_vec(),
-                HeapData::Bytes(b) => b.as_slice().to_vec(),
-                _ => return Err(ExcType::type_error_bytes_init(v.py_type(vm))),
-            },
-            Some(v) => return Err(ExcType::type_error_bytes_init(v.py_type(vm))),
+            Some(v) => bytes_from_source(v, Type::Bytes, vm)?,
         };
         let heap_id = vm.heap.allocate(HeapData::Bytes(Self::new(new_data)))?;
         Ok(Value::Ref(heap_id))
@@ -210,6 +191,80 @@ struct BytesInitArgs {
     source: Option<Value>,
 }
 
+/// Converts a non-`str` `source` argument of `bytes()` / `bytearray()` into its content.
+///
+/// Accepts an int (that many zero bytes), a bytes-like object, or an iterable of
+/// ints in `range(0, 256)`. `ty` names the type being built in error messages.
+pub(crate) fn bytes_from_source(source: Value, ty: Type, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Vec<u8>> {
+    let contents = bytes_like_contents(&source, vm.heap, vm.interns).map(Cow::into_owned);
+    if let Some(contents) = contents {
+        source.drop_with_heap(vm);
+        return Ok(contents);
+    }
+    let count = match source {
+        Value::Int(n) => n,
+        Value::Bool(b) => i64::from(b),
+        source => return collect_byte_values(source, ty, vm),
+    };
+    if count < 0 {
+        return Err(ExcType::value_error_negative_bytes_count());
+    }
+    let size = usize::try_from(count).expect("bytes count validated non-negative");
+    // Pre-check the requested size against resource limits before
+    // touching the global allocator. Without this, `bytes(n)` for a
+    // very large `n` would attempt the native allocation directly
+    // and abort the host on failure rather than raising MemoryError.
+    // Mirrors the guard already used by `bytes.ljust`/`zfill`/`*`.
+    check_repeat_size(size, 1, vm.heap.tracker())?;
+    Ok(vec![0u8; size])
+}
+
+/// Collects an iterable of ints in `range(0, 256)`, as `bytes(iterable)` does.
+///
+/// `ty` names the type being built in the "cannot convert" error.
+pub(crate) fn collect_byte_values(
+    iterable: Value,
+    ty: Type,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Vec<u8>> {
+    let source_ty = iterable.py_type(vm);
+    let cannot_convert = || ExcType::type_error(format!("cannot convert '{source_ty}' object to {ty}"));
+    if iterable.is_str(vm.heap) {
+        iterable.drop_with_heap(vm);
+        return Err(cannot_convert());
+    }
+    let iter = MontyIter::new(iterable, vm).map_err(|_| cannot_convert())?;
+    defer_drop_mut!(iter, vm);
+    let mut bytes = Vec::new();
+    while let Some(item) = iter.for_next(vm)? {
+        defer_drop!(item, vm);
+        bytes.push(byte_value(item, ty, vm)?);
+        // Re-check the limit each time the buffer doubles so a huge iterable
+        // cannot grow an untracked buffer far past the memory budget.
+        if bytes.len().is_power_of_two() {
+            check_estimated_size(bytes.len(), vm.heap.tracker())?;
+        }
+    }
+    Ok(bytes)
+}
+
+/// Converts an int in `range(0, 256)` to a byte, as stored in `bytes` and `bytearray`.
+///
+/// `ty` selects CPython's wording of the range error: `bytearray` says "byte",
+/// `bytes()` and `int.from_bytes()` say "bytes".
+pub(crate) fn byte_value(value: &Value, ty: Type, vm: &VM<'_, impl ResourceTracker>) -> RunResult<u8> {
+    let byte = match value {
+        Value::Bool(b) => Some(i64::from(*b)),
+        // Heap `LongInt`s never fit in a byte, so skip the i64 conversion.
+        Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::LongInt(_)) => None,
+        other => Some(other.as_int(vm)?),
+    };
+    byte.and_then(|b| u8::try_from(b).ok()).ok_or_else(|| {
+        let noun = if ty == Type::ByteArray { "byte" } else { "bytes" };
+        SimpleException::new_msg(ExcType::ValueError, format!("{noun} must be in range(0, 256)")).into()
+    })
+}
+
 impl From<Vec<u8>> for Bytes {
     fn from(bytes: Vec<u8>) -> Self {
         Self::new(bytes)
@@ -313,7 +368,7 @@ impl<'h> PyTrait<'h> for HeapRead<'h, Bytes> {
 
         let field = heap_read_ref_as_field!(self, Bytes, 0);
         let bytes = field.as_slice(vm.heap);
-        call_bytes_method_impl(&bytes, method, args, vm).map(CallResult::Value)
+        call_bytes_method_impl(&bytes, Type::Bytes, method, args, vm).map(CallResult::Value)
     }
 }
 
@@ -341,16 +396,18 @@ pub fn call_bytes_method(
         args.drop_with_heap(vm);
         return Err(ExcType::attribute_error(Type::Bytes, vm.interns.get_str(method_id)));
     };
-    call_bytes_method_impl(&vm.heap.protect(bytes), method, args, vm)
+    call_bytes_method_impl(&vm.heap.protect(bytes), Type::Bytes, method, args, vm)
 }
 
 /// Calls a bytes method on a byte slice.
 ///
-/// This is the unified implementation for bytes method calls, used by both
-/// heap-allocated `Bytes` (via `py_call_attr`) and interned bytes literals
-/// (`Value::InternBytes`).
-fn call_bytes_method_impl<'h>(
+/// This is the unified implementation for bytes method calls, used by
+/// heap-allocated `Bytes` (via `py_call_attr`), interned bytes literals
+/// (`Value::InternBytes`) and `bytearray`. `ty` is the receiver's type
+/// (`bytes` or `bytearray`): methods that build new sequences return that type.
+pub(crate) fn call_bytes_method_impl<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     method: StaticStrings,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
@@ -361,23 +418,23 @@ fn call_bytes_method_impl<'h>(
         // Simple transformations (no arguments)
         StaticStrings::Lower => {
             args.check_zero_args("bytes.lower", vm.heap)?;
-            bytes_lower(bytes, vm)
+            bytes_lower(bytes, ty, vm)
         }
         StaticStrings::Upper => {
             args.check_zero_args("bytes.upper", vm.heap)?;
-            bytes_upper(bytes, vm)
+            bytes_upper(bytes, ty, vm)
         }
         StaticStrings::Capitalize => {
             args.check_zero_args("bytes.capitalize", vm.heap)?;
-            bytes_capitalize(bytes, vm)
+            bytes_capitalize(bytes, ty, vm)
         }
         StaticStrings::Title => {
             args.check_zero_args("bytes.title", vm.heap)?;
-            bytes_title(bytes, vm)
+            bytes_title(bytes, ty, vm)
         }
         StaticStrings::Swapcase => {
             args.check_zero_args("bytes.swapcase", vm.heap)?;
-            bytes_swapcase(bytes, vm)
+            bytes_swapcase(bytes, ty, vm)
         }
         // Predicate methods (no arguments, return bool)
         StaticStrings::Isalpha => {
@@ -421,35 +478,37 @@ fn call_bytes_method_impl<'h>(
         StaticStrings::Startswith => bytes_startswith(bytes, args, vm),
         StaticStrings::Endswith => bytes_endswith(bytes, args, vm),
         // Strip/trim methods
-        StaticStrings::Strip => bytes_strip(bytes, args, vm),
-        StaticStrings::Lstrip => bytes_lstrip(bytes, args, vm),
-        StaticStrings::Rstrip => bytes_rstrip(bytes, args, vm),
-        StaticStrings::Removeprefix => bytes_removeprefix(bytes, args, vm),
-        StaticStrings::Removesuffix => bytes_removesuffix(bytes, args, vm),
+        StaticStrings::Strip => bytes_strip(bytes, ty, args, vm),
+        StaticStrings::Lstrip => bytes_lstrip(bytes, ty, args, vm),
+        StaticStrings::Rstrip => bytes_rstrip(bytes, ty, args, vm),
+        StaticStrings::Removeprefix => bytes_removeprefix(bytes, ty, args, vm),
+        StaticStrings::Removesuffix => bytes_removesuffix(bytes, ty, args, vm),
         // Split methods
-        StaticStrings::Split => bytes_split(bytes, args, vm),
-        StaticStrings::Rsplit => bytes_rsplit(bytes, args, vm),
-        StaticStrings::Splitlines => bytes_splitlines(bytes, args, vm),
-        StaticStrings::Partition => bytes_partition(bytes, args, vm),
-        StaticStrings::Rpartition => bytes_rpartition(bytes, args, vm),
+        StaticStrings::Split => bytes_split(bytes, ty, args, vm),
+        StaticStrings::Rsplit => bytes_rsplit(bytes, ty, args, vm),
+        StaticStrings::Splitlines => bytes_splitlines(bytes, ty, args, vm),
+        StaticStrings::Partition => bytes_partition(bytes, ty, args, vm),
+        StaticStrings::Rpartition => bytes_rpartition(bytes, ty, args, vm),
         // Replace/padding methods
-        StaticStrings::Replace => bytes_replace(bytes, args, vm),
-        StaticStrings::Center => bytes_center(bytes, args, vm),
-        StaticStrings::Ljust => bytes_ljust(bytes, args, vm),
-        StaticStrings::Rjust => bytes_rjust(bytes, args, vm),
-        StaticStrings::Zfill => bytes_zfill(bytes, args, vm),
+        StaticStrings::Replace => bytes_replace(bytes, ty, args, vm),
+        StaticStrings::Translate => bytes_translate(bytes, ty, args, vm),
+        StaticStrings::Maketrans => bytes_maketrans(args, vm),
+        StaticStrings::Center => bytes_center(bytes, ty, args, vm),
+        StaticStrings::Ljust => bytes_ljust(bytes, ty, args, vm),
+        StaticStrings::Rjust => bytes_rjust(bytes, ty, args, vm),
+        StaticStrings::Zfill => bytes_zfill(bytes, ty, args, vm),
         // Join method
         StaticStrings::Join => {
             let iterable = args.get_one_arg("bytes.join", vm.heap)?;
-            bytes_join(bytes, iterable, vm)
+            bytes_join(bytes, ty, iterable, vm)
         }
         // Hex method
         StaticStrings::Hex => bytes_hex(bytes, args, vm),
         // fromhex is a classmethod but also accessible on instances
-        StaticStrings::Fromhex => bytes_fromhex(args, vm),
+        StaticStrings::Fromhex => bytes_fromhex(ty, args, vm),
         _ => {
             args.drop_with_heap(vm.heap);
-            Err(ExcType::attribute_error(Type::Bytes, method.into()))
+            Err(ExcType::attribute_error(ty, method.into()))
         }
     }
 }
@@ -500,29 +559,20 @@ pub fn bytes_repr(bytes: &[u8]) -> String {
 
 /// Implements Python's `bytes.decode([encoding[, errors]])` method.
 ///
-/// Converts bytes to a string. Currently only supports UTF-8 encoding.
+/// Converts bytes to a string using the codecs in [`crate::codecs`].
 fn bytes_decode<'h>(
     bytes: &HeapRead<'h, [u8]>,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
     let BytesDecodeArgs { encoding, errors } = BytesDecodeArgs::from_args(args, vm)?;
-    // `errors` is accepted for parity but ignored — UTF-8 decoding of valid
-    // bytes has nothing to handle, and `lookup_error_unknown_error_handler`
-    // would be the next layer once non-UTF-8 codecs land.
-    let _ = errors;
-    let encoding = encoding.map_or_else(|| "utf-8".to_owned(), |e| e.to_ascii_lowercase());
-
-    // Only support UTF-8 family
-    if !matches!(encoding.as_str(), "utf-8" | "utf8" | "utf_8") {
-        return Err(ExcType::lookup_error_unknown_encoding(&encoding));
-    }
-
-    // Decode as UTF-8
-    match str::from_utf8(bytes.get(vm.heap)) {
-        Ok(s) => Ok(super::str::allocate_string(s, vm.heap)?),
-        Err(_) => Err(ExcType::unicode_decode_error_invalid_utf8()),
-    }
+    let codec = Codec::lookup(encoding.as_deref().unwrap_or("utf-8"))?;
+    let s = codec.decode(
+        bytes.get(vm.heap),
+        errors.as_deref().unwrap_or("strict"),
+        vm.heap.tracker(),
+    )?;
+    Ok(super::str::allocate_string(s, vm.heap)?)
 }
 
 /// Argument shape for `bytes.decode(encoding='utf-8', errors='strict')`.
@@ -732,8 +782,8 @@ fn parse_bytes_prefix_suffix_args(
 
 /// Extracts bytes (or tuple of bytes) for startswith/endswith methods.
 ///
-/// Returns `PrefixSuffixArg::Single` for a single bytes value, or
-/// `PrefixSuffixArg::Multiple` for a tuple of bytes values.
+/// Returns `PrefixSuffixArg::Single` for a single bytes-like value, or
+/// `PrefixSuffixArg::Multiple` for a tuple of bytes-like values.
 fn extract_bytes_for_prefix_suffix(
     value: &Value,
     method: &str,
@@ -742,13 +792,14 @@ fn extract_bytes_for_prefix_suffix(
     // Extract the method name (e.g., "startswith" from "bytes.startswith")
     let method_name = method.strip_prefix("bytes.").unwrap_or(method);
 
+    if let Some(b) = bytes_like_contents(value, vm.heap, vm.interns) {
+        return Ok(PrefixSuffixArg::Single(b.into_owned()));
+    }
     match value {
-        Value::InternBytes(id) => Ok(PrefixSuffixArg::Single(vm.interns.get_bytes(*id).to_vec())),
         Value::InternString(_) => Err(ExcType::type_error(format!(
             "{method_name} first arg must be bytes or a tuple of bytes, not str"
         ))),
         Value::Ref(id) => match vm.heap.get(*id) {
-            HeapData::Bytes(b) => Ok(PrefixSuffixArg::Single(b.as_slice().to_vec())),
             HeapData::Str(_) => Err(ExcType::type_error(format!(
                 "{method_name} first arg must be bytes or a tuple of bytes, not str"
             ))),
@@ -783,31 +834,47 @@ fn extract_bytes_for_prefix_suffix(
 
 /// Extracts a single bytes value for tuple element in startswith/endswith.
 fn extract_single_bytes_for_prefix_suffix(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Vec<u8>> {
+    if let Some(b) = bytes_like_contents(value, vm.heap, vm.interns) {
+        return Ok(b.into_owned());
+    }
     match value {
-        Value::InternBytes(id) => Ok(vm.interns.get_bytes(*id).to_vec()),
         Value::InternString(_) => Err(ExcType::type_error("expected bytes, not str")),
-        Value::Ref(id) => match vm.heap.get(*id) {
-            HeapData::Bytes(b) => Ok(b.as_slice().to_vec()),
-            _ => Err(ExcType::type_error("expected bytes")),
-        },
         _ => Err(ExcType::type_error("expected bytes")),
     }
 }
 
-/// Extracts bytes from a Value (bytes only, NOT str - matches CPython behavior).
+/// Extracts bytes from a bytes-like Value (NOT str - matches CPython behavior).
 ///
 /// CPython raises `TypeError: a bytes-like object is required, not 'str'` when
 /// a str is passed to bytes methods like find, count, index, startswith, endswith.
-fn extract_bytes_only<'a>(value: &Value, vm: &'a VM<'_, impl ResourceTracker>) -> RunResult<&'a [u8]> {
+fn extract_bytes_only<'a>(value: &Value, vm: &'a VM<'_, impl ResourceTracker>) -> RunResult<Cow<'a, [u8]>> {
+    if let Some(b) = bytes_like_contents(value, vm.heap, vm.interns) {
+        return Ok(b);
+    }
+    if value.is_str(vm.heap) {
+        Err(ExcType::type_error("a bytes-like object is required, not 'str'"))
+    } else {
+        Err(ExcType::type_error("a bytes-like object is required"))
+    }
+}
+
+/// Returns the contents of a bytes-like object: `bytes`, `bytearray` or `memoryview`.
+///
+/// Returns `None` for any other value, including `str`.
+pub(crate) fn bytes_like_contents<'a>(
+    value: &Value,
+    heap: &'a Heap<impl ResourceTracker>,
+    interns: &'a Interns,
+) -> Option<Cow<'a, [u8]>> {
     match value {
-        Value::InternBytes(id) => Ok(vm.interns.get_bytes(*id)),
-        Value::InternString(_) => Err(ExcType::type_error("a bytes-like object is required, not 'str'")),
-        Value::Ref(id) => match vm.heap.get(*id) {
-            HeapData::Bytes(b) => Ok(b.as_slice()),
-            HeapData::Str(_) => Err(ExcType::type_error("a bytes-like object is required, not 'str'")),
-            _ => Err(ExcType::type_error("a bytes-like object is required")),
+        Value::InternBytes(id) => Some(Cow::Borrowed(interns.get_bytes(*id))),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Bytes(b) => Some(Cow::Borrowed(b.as_slice())),
+            HeapData::ByteArray(b) => Some(Cow::Borrowed(b.as_slice())),
+            HeapData::MemoryView(view) => Some(view.contents(heap)),
+            _ => None,
         },
-        _ => Err(ExcType::type_error("a bytes-like object is required")),
+        _ => None,
     }
 }
 
@@ -845,7 +912,7 @@ fn parse_bytes_sub_args(
     };
 
     // Ensure start <= end to prevent slice panics (Python treats start > end as empty slice)
-    Ok((sub.to_owned(), start, end.max(start)))
+    Ok((sub.into_owned(), start, end.max(start)))
 }
 
 // =============================================================================
@@ -855,24 +922,28 @@ fn parse_bytes_sub_args(
 /// Implements Python's `bytes.lower()` method.
 ///
 /// Returns a copy of the bytes with all ASCII uppercase characters converted to lowercase.
-fn bytes_lower<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+fn bytes_lower<'h>(bytes: &HeapRead<'h, [u8]>, ty: Type, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
     let result: Vec<u8> = bytes.get(vm.heap).iter().map(|&b| b.to_ascii_lowercase()).collect();
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Implements Python's `bytes.upper()` method.
 ///
 /// Returns a copy of the bytes with all ASCII lowercase characters converted to uppercase.
-fn bytes_upper<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+fn bytes_upper<'h>(bytes: &HeapRead<'h, [u8]>, ty: Type, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
     let result: Vec<u8> = bytes.get(vm.heap).iter().map(|&b| b.to_ascii_uppercase()).collect();
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Implements Python's `bytes.capitalize()` method.
 ///
 /// Returns a copy of the bytes with the first byte capitalized (if ASCII) and
 /// the rest lowercased.
-fn bytes_capitalize<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+fn bytes_capitalize<'h>(
+    bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
+    vm: &mut VM<'h, impl ResourceTracker>,
+) -> RunResult<Value> {
     let bytes = bytes.get(vm.heap);
     let mut result = Vec::with_capacity(bytes.len());
     if let Some((&first, rest)) = bytes.split_first() {
@@ -881,14 +952,14 @@ fn bytes_capitalize<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl Resourc
             result.push(b.to_ascii_lowercase());
         }
     }
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Implements Python's `bytes.title()` method.
 ///
 /// Returns a titlecased version of the bytes where words start with an uppercase
 /// ASCII character and the remaining characters are lowercase.
-fn bytes_title<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+fn bytes_title<'h>(bytes: &HeapRead<'h, [u8]>, ty: Type, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
     let bytes = bytes.get(vm.heap);
     let mut result = Vec::with_capacity(bytes.len());
     let mut prev_is_cased = false;
@@ -902,14 +973,14 @@ fn bytes_title<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl ResourceTrac
         prev_is_cased = b.is_ascii_alphabetic();
     }
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Implements Python's `bytes.swapcase()` method.
 ///
 /// Returns a copy of the bytes with ASCII uppercase characters converted to
 /// lowercase and vice versa.
-fn bytes_swapcase<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+fn bytes_swapcase<'h>(bytes: &HeapRead<'h, [u8]>, ty: Type, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
     let result: Vec<u8> = bytes
         .get(vm.heap)
         .iter()
@@ -923,7 +994,7 @@ fn bytes_swapcase<'h>(bytes: &HeapRead<'h, [u8]>, vm: &mut VM<'h, impl ResourceT
             }
         })
         .collect();
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 // =============================================================================
@@ -1098,6 +1169,7 @@ fn bytes_rindex<'h>(
 /// If chars is not specified, ASCII whitespace bytes are removed.
 fn bytes_strip<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1105,9 +1177,9 @@ fn bytes_strip<'h>(
     defer_drop!(value, vm);
     let result = match value {
         None | Some(Value::None) => bytes_strip_whitespace_both(bytes.get(vm.heap)),
-        Some(v) => bytes_strip_both(bytes.get(vm.heap), extract_bytes_only(v, vm)?),
+        Some(v) => bytes_strip_both(bytes.get(vm.heap), &extract_bytes_only(v, vm)?),
     };
-    allocate_bytes(result.to_vec(), vm.heap)
+    allocate_bytes(ty, result.to_vec(), vm.heap)
 }
 
 /// Implements Python's `bytes.lstrip([chars])` method.
@@ -1115,6 +1187,7 @@ fn bytes_strip<'h>(
 /// Returns a copy of the bytes with leading bytes removed.
 fn bytes_lstrip<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1122,9 +1195,9 @@ fn bytes_lstrip<'h>(
     defer_drop!(value, vm);
     let result = match value {
         None | Some(Value::None) => bytes_strip_whitespace_start(bytes.get(vm.heap)),
-        Some(v) => bytes_strip_start(bytes.get(vm.heap), extract_bytes_only(v, vm)?),
+        Some(v) => bytes_strip_start(bytes.get(vm.heap), &extract_bytes_only(v, vm)?),
     };
-    allocate_bytes(result.to_vec(), vm.heap)
+    allocate_bytes(ty, result.to_vec(), vm.heap)
 }
 
 /// Implements Python's `bytes.rstrip([chars])` method.
@@ -1132,6 +1205,7 @@ fn bytes_lstrip<'h>(
 /// Returns a copy of the bytes with trailing bytes removed.
 fn bytes_rstrip<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1139,9 +1213,9 @@ fn bytes_rstrip<'h>(
     defer_drop!(value, vm);
     let result = match value {
         None | Some(Value::None) => bytes_strip_whitespace_end(bytes.get(vm.heap)),
-        Some(v) => bytes_strip_end(bytes.get(vm.heap), extract_bytes_only(v, vm)?),
+        Some(v) => bytes_strip_end(bytes.get(vm.heap), &extract_bytes_only(v, vm)?),
     };
-    allocate_bytes(result.to_vec(), vm.heap)
+    allocate_bytes(ty, result.to_vec(), vm.heap)
 }
 
 /// Strips bytes in `chars` from both ends of the byte slice.
@@ -1197,6 +1271,7 @@ fn bytes_strip_whitespace_end(bytes: &[u8]) -> &[u8] {
 /// Otherwise, return a copy of the original bytes.
 fn bytes_removeprefix<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1205,12 +1280,12 @@ fn bytes_removeprefix<'h>(
     let prefix = extract_bytes_only(prefix_value, vm)?;
 
     let bytes = bytes.get(vm.heap);
-    let result = if bytes.starts_with(prefix) {
+    let result = if bytes.starts_with(&prefix) {
         bytes[prefix.len()..].to_vec()
     } else {
         bytes.to_vec()
     };
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Implements Python's `bytes.removesuffix(suffix)` method.
@@ -1219,6 +1294,7 @@ fn bytes_removeprefix<'h>(
 /// Otherwise, return a copy of the original bytes.
 fn bytes_removesuffix<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1227,12 +1303,12 @@ fn bytes_removesuffix<'h>(
     let suffix = extract_bytes_only(suffix_value, vm)?;
 
     let bytes = bytes.get(vm.heap);
-    let result = if bytes.ends_with(suffix) && !suffix.is_empty() {
+    let result = if bytes.ends_with(&suffix) && !suffix.is_empty() {
         bytes[..bytes.len() - suffix.len()].to_vec()
     } else {
         bytes.to_vec()
     };
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 // =============================================================================
@@ -1244,6 +1320,7 @@ fn bytes_removesuffix<'h>(
 /// Returns a list of the bytes split by the separator.
 fn bytes_split<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1276,7 +1353,7 @@ fn bytes_split<'h>(
     let mut list_items = Vec::with_capacity(parts.len());
     for part in parts {
         vm.heap.check_time()?;
-        list_items.push(allocate_bytes(part.to_vec(), vm.heap)?);
+        list_items.push(allocate_bytes(ty, part.to_vec(), vm.heap)?);
     }
 
     let list = List::new(list_items);
@@ -1289,6 +1366,7 @@ fn bytes_split<'h>(
 /// Returns a list of the bytes split by the separator, splitting from the right.
 fn bytes_rsplit<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1321,7 +1399,7 @@ fn bytes_rsplit<'h>(
     let mut list_items = Vec::with_capacity(parts.len());
     for part in parts {
         vm.heap.check_time()?;
-        list_items.push(allocate_bytes(part.to_vec(), vm.heap)?);
+        list_items.push(allocate_bytes(ty, part.to_vec(), vm.heap)?);
     }
 
     let list = List::new(list_items);
@@ -1345,7 +1423,7 @@ fn coerce_bytes_split_args(
     defer_drop!(maxsplit, vm);
     let sep = match sep {
         Value::None => None,
-        _ => Some(extract_bytes_only(sep, vm)?.to_owned()),
+        _ => Some(extract_bytes_only(sep, vm)?.into_owned()),
     };
     let maxsplit_int = maxsplit.as_int(vm)?;
     Ok((sep, maxsplit_int))
@@ -1515,6 +1593,7 @@ fn bytes_rsplitn_whitespace(bytes: &[u8], maxsplit: usize) -> Vec<&[u8]> {
 /// Returns a list of the lines in the bytes, breaking at line boundaries.
 fn bytes_splitlines<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1560,7 +1639,7 @@ fn bytes_splitlines<'h>(
         } else {
             &bytes[start..line_end]
         };
-        lines.push(allocate_bytes(line.to_vec(), vm.heap)?);
+        lines.push(allocate_bytes(ty, line.to_vec(), vm.heap)?);
         start = end;
     }
 
@@ -1576,9 +1655,9 @@ fn parse_bytes_splitlines_args(args: ArgValues, vm: &mut VM<'_, impl ResourceTra
     let result = match keepends {
         None => false,
         Some(v) => {
-            let r = v.py_bool(vm);
+            let r = checked_truth(&v, vm);
             v.drop_with_heap(vm.heap);
-            r
+            r?
         }
     };
     Ok(result)
@@ -1599,6 +1678,7 @@ struct BytesSplitlinesArgs {
 /// Splits the bytes at the first occurrence of sep, and returns a 3-tuple.
 fn bytes_partition<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1611,14 +1691,14 @@ fn bytes_partition<'h>(
     }
 
     let bytes = bytes.get(vm.heap);
-    let (before, sep_found, after) = match find_subsequence(bytes, sep) {
+    let (before, sep_found, after) = match find_subsequence(bytes, &sep) {
         Some(pos) => (bytes[..pos].to_vec(), sep.to_vec(), bytes[pos + sep.len()..].to_vec()),
         None => (bytes.to_vec(), Vec::new(), Vec::new()),
     };
 
-    let before_val = allocate_bytes(before, vm.heap)?;
-    let sep_val = allocate_bytes(sep_found, vm.heap)?;
-    let after_val = allocate_bytes(after, vm.heap)?;
+    let before_val = allocate_bytes(ty, before, vm.heap)?;
+    let sep_val = allocate_bytes(ty, sep_found, vm.heap)?;
+    let after_val = allocate_bytes(ty, after, vm.heap)?;
 
     Ok(super::allocate_tuple(
         smallvec![before_val, sep_val, after_val],
@@ -1631,6 +1711,7 @@ fn bytes_partition<'h>(
 /// Splits the bytes at the last occurrence of sep, and returns a 3-tuple.
 fn bytes_rpartition<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1643,14 +1724,14 @@ fn bytes_rpartition<'h>(
     }
 
     let bytes = bytes.get(vm.heap);
-    let (before, sep_found, after) = match rfind_subsequence(bytes, sep) {
+    let (before, sep_found, after) = match rfind_subsequence(bytes, &sep) {
         Some(pos) => (bytes[..pos].to_vec(), sep.to_vec(), bytes[pos + sep.len()..].to_vec()),
         None => (Vec::new(), Vec::new(), bytes.to_vec()),
     };
 
-    let before_val = allocate_bytes(before, vm.heap)?;
-    let sep_val = allocate_bytes(sep_found, vm.heap)?;
-    let after_val = allocate_bytes(after, vm.heap)?;
+    let before_val = allocate_bytes(ty, before, vm.heap)?;
+    let sep_val = allocate_bytes(ty, sep_found, vm.heap)?;
+    let after_val = allocate_bytes(ty, after, vm.heap)?;
 
     Ok(super::allocate_tuple(
         smallvec![before_val, sep_val, after_val],
@@ -1667,6 +1748,7 @@ fn bytes_rpartition<'h>(
 /// Returns a copy with all occurrences of old replaced by new.
 fn bytes_replace<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1683,7 +1765,7 @@ fn bytes_replace<'h>(
         bytes_replace_n(bytes, &old, &new, n, vm.heap)?
     };
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Parses arguments for bytes.replace method.
@@ -1697,8 +1779,8 @@ fn parse_bytes_replace_args(
     defer_drop!(new, vm);
     defer_drop!(count, vm);
 
-    let old_b = extract_bytes_only(old, vm)?.to_owned();
-    let new_b = extract_bytes_only(new, vm)?.to_owned();
+    let old_b = extract_bytes_only(old, vm)?.into_owned();
+    let new_b = extract_bytes_only(new, vm)?.into_owned();
     let count_i = count.as_int(vm)?;
     Ok((old_b, new_b, count_i))
 }
@@ -1713,6 +1795,78 @@ struct BytesReplaceArgs {
     count: Value,
 }
 
+/// Implements Python's `bytes.translate(table, /, delete=b'')` method.
+///
+/// Removes every byte in `delete`, then maps each remaining byte through the
+/// 256-byte `table` (`None` leaves bytes unchanged).
+fn bytes_translate<'h>(
+    bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
+    args: ArgValues,
+    vm: &mut VM<'h, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let BytesTranslateArgs { table, delete } = BytesTranslateArgs::from_args(args, vm)?;
+    defer_drop!(table, vm);
+    defer_drop!(delete, vm);
+
+    let table = match table {
+        Value::None => None,
+        table => {
+            let table = extract_bytes_only(table, vm)?;
+            if table.len() != 256 {
+                return Err(SimpleException::new_msg(
+                    ExcType::ValueError,
+                    "translation table must be 256 characters long",
+                )
+                .into());
+            }
+            Some(table)
+        }
+    };
+    let delete = match delete {
+        Some(delete) => extract_bytes_only(delete, vm)?,
+        None => Cow::Borrowed(&[][..]),
+    };
+    let result = bytes
+        .get(vm.heap)
+        .iter()
+        .filter(|b| !delete.contains(b))
+        .map(|&b| table.as_ref().map_or(b, |table| table[usize::from(b)]))
+        .collect();
+    allocate_bytes(ty, result, vm.heap)
+}
+
+/// Argument shape for `bytes.translate(table, /, delete=b'')`.
+#[derive(FromArgs)]
+#[from_args(name = "translate")]
+struct BytesTranslateArgs {
+    #[from_args(pos_only)]
+    table: Value,
+    #[from_args(default)]
+    delete: Option<Value>,
+}
+
+/// Implements Python's `bytes.maketrans(from, to)` static method.
+///
+/// Returns the 256-byte table for [`bytes_translate`] mapping each byte of
+/// `from` to the byte of `to` at the same index. Always returns `bytes`, also
+/// when called on `bytearray`.
+pub(crate) fn bytes_maketrans(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let (from, to) = args.get_two_args("maketrans", vm.heap)?;
+    defer_drop!(from, vm);
+    defer_drop!(to, vm);
+    let from = extract_bytes_only(from, vm)?;
+    let to = extract_bytes_only(to, vm)?;
+    if from.len() != to.len() {
+        return Err(SimpleException::new_msg(ExcType::ValueError, "maketrans arguments must have same length").into());
+    }
+    let mut table: Vec<u8> = (0..=u8::MAX).collect();
+    for (&f, &t) in from.iter().zip(to.iter()) {
+        table[usize::from(f)] = t;
+    }
+    allocate_bytes(Type::Bytes, table, vm.heap)
+}
+
 /// Replaces all occurrences of `old` with `new` in bytes.
 ///
 /// Checks the time limit periodically to enforce `max_duration` during
@@ -1799,6 +1953,7 @@ fn bytes_replace_n(
 /// Returns centered in a bytes of length width.
 fn bytes_center<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1825,7 +1980,7 @@ fn bytes_center<'h>(
         result
     };
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Implements Python's `bytes.ljust(width[, fillbyte])` method.
@@ -1833,6 +1988,7 @@ fn bytes_center<'h>(
 /// Returns left-justified in a bytes of length width.
 fn bytes_ljust<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1854,7 +2010,7 @@ fn bytes_ljust<'h>(
         result
     };
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Implements Python's `bytes.rjust(width[, fillbyte])` method.
@@ -1862,6 +2018,7 @@ fn bytes_ljust<'h>(
 /// Returns right-justified in a bytes of length width.
 fn bytes_rjust<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1883,7 +2040,7 @@ fn bytes_rjust<'h>(
         result
     };
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Parses arguments for bytes justify methods (center, ljust, rjust).
@@ -1928,6 +2085,7 @@ fn parse_bytes_justify_args(
 /// Returns a copy of the bytes left filled with ASCII '0' digits.
 fn bytes_zfill<'h>(
     bytes: &HeapRead<'h, [u8]>,
+    ty: Type,
     args: ArgValues,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1963,7 +2121,7 @@ fn bytes_zfill<'h>(
         result
     };
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 // =============================================================================
@@ -1975,6 +2133,7 @@ fn bytes_zfill<'h>(
 /// Joins elements of the iterable with the separator bytes.
 fn bytes_join<'h>(
     separator: &HeapRead<'h, [u8]>,
+    ty: Type,
     iterable: Value,
     vm: &mut VM<'h, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1993,32 +2152,18 @@ fn bytes_join<'h>(
             result.extend_from_slice(separator.get(vm.heap));
         }
 
-        // Check item is bytes and extract its content
-        match item {
-            Value::InternBytes(id) => {
-                result.extend_from_slice(vm.interns.get_bytes(*id));
-            }
-            Value::Ref(heap_id) => {
-                if let HeapData::Bytes(b) = vm.heap.get(*heap_id) {
-                    result.extend_from_slice(b.as_slice());
-                } else {
-                    let t = item.py_type(vm);
-                    return Err(ExcType::type_error(format!(
-                        "sequence item {index}: expected a bytes-like object, {t} found"
-                    )));
-                }
-            }
-            _ => {
-                let t = item.py_type(vm);
-                return Err(ExcType::type_error(format!(
-                    "sequence item {index}: expected a bytes-like object, {t} found"
-                )));
-            }
-        }
+        // Check item is bytes-like and extract its content
+        let Some(b) = bytes_like_contents(item, vm.heap, vm.interns) else {
+            let t = item.py_type(vm);
+            return Err(ExcType::type_error(format!(
+                "sequence item {index}: expected a bytes-like object, {t} found"
+            )));
+        };
+        result.extend_from_slice(&b);
         index += 1;
     }
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 // =============================================================================
@@ -2173,7 +2318,7 @@ struct BytesHexArgs {
 ///
 /// Creates bytes from a hexadecimal string. Whitespace is allowed between byte pairs,
 /// but not between the two digits of a byte.
-pub fn bytes_fromhex(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+pub fn bytes_fromhex(ty: Type, args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
     let hex_value = args.get_one_arg("bytes.fromhex", vm.heap)?;
     defer_drop!(hex_value, vm);
 
@@ -2245,7 +2390,7 @@ pub fn bytes_fromhex(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) ->
         result.push((hi_val << 4) | lo_val);
     }
 
-    allocate_bytes(result, vm.heap)
+    allocate_bytes(ty, result, vm.heap)
 }
 
 /// Converts a hex character to its numeric value.
@@ -2262,8 +2407,12 @@ fn hex_char_to_value(c: char) -> Option<u8> {
 // Helper function for bytes allocation
 // =============================================================================
 
-/// Allocates bytes on the heap.
-fn allocate_bytes(bytes: Vec<u8>, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
-    let heap_id = heap.allocate(HeapData::Bytes(Bytes::new(bytes)))?;
-    Ok(Value::Ref(heap_id))
+/// Allocates a `bytes` or, when `ty` is `Type::ByteArray`, a `bytearray` on the heap.
+fn allocate_bytes(ty: Type, bytes: Vec<u8>, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
+    let data = if ty == Type::ByteArray {
+        HeapData::ByteArray(ByteArray::new(bytes))
+    } else {
+        HeapData::Bytes(Bytes::new(bytes))
+    };
+    Ok(Value::Ref(heap.allocate(data)?))
 }
@@ -0,0 +1,530 @@
+//! Python `complex` implementation.
+//!
+//! A complex number needs two `f64` components, which don't fit in the 16-byte
+//! `Value`, so complex values always live on the heap. Arithmetic follows CPython's
+//! algorithms (`_Py_c_quot`, `_Py_c_pow`, `c_powi`), with `bool`, `int` and `float`
+//! operands promoted to complex before the operation.
+
+use std::{
+    borrow::Cow,
+    fmt::Write,
+    mem,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use ahash::AHashSet;
+
+use crate::{
+    args::{ArgValues, FromArgs},
+    bytecode::{CallResult, VM},
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    hash::{HashValue, hash_python_float},
+    heap::{Heap, HeapData, HeapId, HeapItem, HeapRead},
+    intern::StaticStrings,
+    resource::{ResourceError, ResourceTracker},
+    types::{PyTrait, Type},
+    value::{EitherStr, Value},
+};
+
+/// Multiplier CPython uses to combine the real and imaginary hashes (`_PyHASH_IMAG`).
+const HASH_IMAG: u64 = 1_000_003;
+
+/// Integral exponents up to this magnitude are computed by repeated multiplication,
+/// matching CPython's `complex_pow` fast path.
+const MAX_INT_POWER: f64 = 100.0;
+
+/// A Python `complex` value.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    #[must_use]
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// Allocates the complex number on the heap and returns a reference to it.
+    pub fn into_value(self, heap: &Heap<impl ResourceTracker>) -> Result<Value, ResourceError> {
+        Ok(Value::Ref(heap.allocate(HeapData::Complex(self))?))
+    }
+
+    /// Returns `abs(z)`, raising `OverflowError` when finite components overflow.
+    pub fn abs(self) -> RunResult<f64> {
+        let result = self.re.hypot(self.im);
+        if result.is_infinite() && self.re.is_finite() && self.im.is_finite() {
+            return Err(SimpleException::new_msg(ExcType::OverflowError, "absolute value too large").into());
+        }
+        Ok(result)
+    }
+
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// True division, raising `ZeroDivisionError` for a zero divisor.
+    pub fn div(self, other: Self) -> RunResult<Self> {
+        self.quot(other)
+            .ok_or_else(|| SimpleException::new_msg(ExcType::ZeroDivisionError, "complex division by zero").into())
+    }
+
+    /// Exponentiation following CPython's `complex_pow`.
+    ///
+    /// Raises `ZeroDivisionError` for zero to a negative or complex power and
+    /// `OverflowError` when the result is infinite.
+    pub fn pow(self, exponent: Self) -> RunResult<Self> {
+        let result = if exponent.im == 0.0 && exponent.re.fract() == 0.0 && exponent.re.abs() <= MAX_INT_POWER {
+            #[expect(clippy::cast_possible_truncation, reason = "exponent is integral and at most 100")]
+            let n = exponent.re as i32;
+            self.powi(n)
+        } else {
+            self.powc(exponent)
+        };
+        let Some(result) = result else {
+            return Err(
+                SimpleException::new_msg(ExcType::ZeroDivisionError, "zero to a negative or complex power").into(),
+            );
+        };
+        if result.re.is_infinite() || result.im.is_infinite() {
+            return Err(SimpleException::new_msg(ExcType::OverflowError, "complex exponentiation").into());
+        }
+        Ok(result)
+    }
+
+    /// Smith's algorithm for complex division, as used by CPython's `_Py_c_quot`.
+    ///
+    /// Returns `None` when the divisor is zero.
+    fn quot(self, other: Self) -> Option<Self> {
+        let abs_re = other.re.abs();
+        let abs_im = other.im.abs();
+        if abs_re >= abs_im {
+            if abs_re == 0.0 {
+                return None;
+            }
+            let ratio = other.im / other.re;
+            let denom = other.re + other.im * ratio;
+            Some(Self::new(
+                (self.re + self.im * ratio) / denom,
+                (self.im - self.re * ratio) / denom,
+            ))
+        } else if abs_im >= abs_re {
+            let ratio = other.re / other.im;
+            let denom = other.re * ratio + other.im;
+            Some(Self::new(
+                (self.re * ratio + self.im) / denom,
+                (self.im * ratio - self.re) / denom,
+            ))
+        } else {
+            // at least one component of the divisor is NaN
+            Some(Self::new(f64::NAN, f64::NAN))
+        }
+    }
+
+    /// Integer power by repeated squaring; negative powers take the reciprocal.
+    fn powi(self, n: i32) -> Option<Self> {
+        let mut result = Self::new(1.0, 0.0);
+        let mut base = self;
+        let mut remaining = n.unsigned_abs();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            remaining >>= 1;
+        }
+        if n < 0 {
+            Self::new(1.0, 0.0).quot(result)
+        } else {
+            Some(result)
+        }
+    }
+
+    /// General power via polar form, as in CPython's `_Py_c_pow`.
+    fn powc(self, exponent: Self) -> Option<Self> {
+        if exponent.re == 0.0 && exponent.im == 0.0 {
+            return Some(Self::new(1.0, 0.0));
+        }
+        if self.re == 0.0 && self.im == 0.0 {
+            if exponent.im != 0.0 || exponent.re < 0.0 {
+                return None;
+            }
+            return Some(Self::new(0.0, 0.0));
+        }
+        let modulus = self.re.hypot(self.im);
+        let mut len = modulus.powf(exponent.re);
+        let angle = self.im.atan2(self.re);
+        let mut phase = angle * exponent.re;
+        if exponent.im != 0.0 {
+            len /= (angle * exponent.im).exp();
+            phase += exponent.im * modulus.ln();
+        }
+        Some(Self::new(len * phase.cos(), len * phase.sin()))
+    }
+
+    /// Python hash, equal to the hash of the real part when the imaginary part is zero.
+    #[must_use]
+    pub fn hash(self) -> HashValue {
+        let re_hash = hash_python_float(self.re);
+        if self.im == 0.0 {
+            return re_hash;
+        }
+        let im_hash = hash_python_float(self.im);
+        HashValue::new(re_hash.raw().wrapping_add(im_hash.raw().wrapping_mul(HASH_IMAG)))
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+/// Converts a number to a complex, promoting `bool`, `int` and `float`.
+///
+/// Returns `None` for non-numeric values. Long ints too large for a float
+/// become infinite.
+pub(crate) fn as_complex(value: &Value, heap: &Heap<impl ResourceTracker>) -> Option<Complex> {
+    match value {
+        Value::Bool(b) => Some(Complex::new(f64::from(u8::from(*b)), 0.0)),
+        Value::Int(i) => Some(Complex::new(*i as f64, 0.0)),
+        Value::Float(f) => Some(Complex::new(*f, 0.0)),
+        Value::Ref(id) => match heap.get(*id) {
+            HeapData::Complex(c) => Some(*c),
+            HeapData::LongInt(li) => Some(Complex::new(li.to_f64().unwrap_or(f64::INFINITY), 0.0)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns both operands as complex numbers when at least one of them is a
+/// `complex` and the other is a number.
+///
+/// Used by the arithmetic and equality operators, so that `int` and `float`
+/// operands only take the complex path when mixed with a complex.
+pub(crate) fn complex_operands(
+    lhs: &Value,
+    rhs: &Value,
+    heap: &Heap<impl ResourceTracker>,
+) -> Option<(Complex, Complex)> {
+    let is_complex = |value: &Value| matches!(value, Value::Ref(id) if matches!(heap.get(*id), HeapData::Complex(_)));
+    if !is_complex(lhs) && !is_complex(rhs) {
+        return None;
+    }
+    Some((as_complex(lhs, heap)?, as_complex(rhs, heap)?))
+}
+
+/// Formats one component the way CPython's `repr(complex)` does: the shortest
+/// round-trip digits, without a trailing `.0`, switching to exponent notation
+/// outside `1e-4 <= |x| < 1e16`.
+fn format_component(f: f64) -> String {
+    if f.is_nan() {
+        return "nan".to_owned();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { "inf" } else { "-inf" }.to_owned();
+    }
+    let sci = format!("{f:e}");
+    let (mantissa, exponent) = sci.split_once('e').expect("`{:e}` output always contains an exponent");
+    let exponent: i32 = exponent.parse().expect("`{:e}` exponent is an integer");
+    if f != 0.0 && !(-4..16).contains(&exponent) {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{mantissa}e{sign}{:02}", exponent.unsigned_abs())
+    } else {
+        f.to_string()
+    }
+}
+
+/// Formats a complex number as its Python `repr()`, e.g. `1j`, `(1+2j)` or `(-0-1j)`.
+#[must_use]
+pub(crate) fn format_repr(c: Complex) -> String {
+    let im = format_component(c.im);
+    if c.re == 0.0 && c.re.is_sign_positive() {
+        return format!("{im}j");
+    }
+    let sign = if c.im.is_sign_negative() && !c.im.is_nan() {
+        ""
+    } else {
+        "+"
+    };
+    format!("({}{sign}{im}j)", format_component(c.re))
+}
+
+/// Creates a `complex` from constructor arguments.
+///
+/// Supports `complex()`, `complex(string)`, and `complex(real=0, imag=0)` where
+/// either argument may itself be a number of any numeric type, including complex.
+pub(crate) fn init(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let ComplexArgs { real, imag } = ComplexArgs::from_args(args, vm)?;
+    defer_drop!(real, vm);
+    defer_drop!(imag, vm);
+
+    let Some(real) = real else {
+        let im = match imag {
+            Some(imag) => complex_arg(imag, vm, false)?,
+            None => Complex::new(0.0, 0.0),
+        };
+        return Ok(Complex::new(0.0 - im.im, im.re).into_value(vm.heap)?);
+    };
+
+    if let Some(s) = real.as_either_str(vm.heap) {
+        if imag.is_some() {
+            return Err(ExcType::type_error(
+                "complex() can't take second arg if first is a string",
+            ));
+        }
+        return Ok(parse_complex(s.as_str(vm.interns))?.into_value(vm.heap)?);
+    }
+
+    let re = complex_arg(real, vm, true)?;
+    match imag {
+        None => {
+            if matches!(real, Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::Complex(_))) {
+                return Ok(real.clone_with_heap(vm.heap));
+            }
+            Ok(re.into_value(vm.heap)?)
+        }
+        Some(imag) => {
+            if imag.is_str(vm.heap) {
+                return Err(ExcType::type_error("complex() second arg can't be a string"));
+            }
+            let im = complex_arg(imag, vm, false)?;
+            Ok(Complex::new(re.re - im.im, re.im + im.re).into_value(vm.heap)?)
+        }
+    }
+}
+
+/// Argument shape for `complex(real=0, imag=0)`.
+#[derive(FromArgs)]
+#[from_args(name = "complex")]
+struct ComplexArgs {
+    #[from_args(default)]
+    real: Option<Value>,
+    #[from_args(default)]
+    imag: Option<Value>,
+}
+
+/// Converts a `complex()` constructor argument to a complex number.
+fn complex_arg(value: &Value, vm: &VM<'_, impl ResourceTracker>, first: bool) -> RunResult<Complex> {
+    as_complex(value, vm.heap).ok_or_else(|| {
+        let msg = if first {
+            format!(
+                "complex() first argument must be a string or a number, not '{}'",
+                value.py_type(vm)
+            )
+        } else {
+            format!(
+                "complex() second argument must be a number, not '{}'",
+                value.py_type(vm)
+            )
+        };
+        ExcType::type_error(msg)
+    })
+}
+
+/// Parses the string forms accepted by `complex()`: `"1"`, `"2j"`, `"1+2j"`,
+/// `"-j"`, optionally wrapped in parentheses and surrounded by whitespace.
+fn parse_complex(s: &str) -> RunResult<Complex> {
+    let mut text = s.trim();
+    if let Some(inner) = text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        text = inner.trim();
+    }
+    parse_complex_body(text).ok_or_else(|| {
+        RunError::from(SimpleException::new_msg(
+            ExcType::ValueError,
+            "complex() arg is a malformed string",
+        ))
+    })
+}
+
+fn parse_complex_body(text: &str) -> Option<Complex> {
+    let is_j = |rest: &str| rest == "j" || rest == "J";
+    let Some((x, len)) = scan_float(text) else {
+        // bare imaginary unit: "j", "+j" or "-j"
+        let (sign, rest) = split_sign(text);
+        return is_j(rest).then(|| Complex::new(0.0, sign));
+    };
+    let rest = &text[len..];
+    if rest.is_empty() {
+        return Some(Complex::new(x, 0.0));
+    }
+    if is_j(rest) {
+        return Some(Complex::new(0.0, x));
+    }
+    if !rest.starts_with(['+', '-']) {
+        return None;
+    }
+    if let Some((y, len)) = scan_float(rest) {
+        return is_j(&rest[len..]).then(|| Complex::new(x, y));
+    }
+    let (sign, unit) = split_sign(rest);
+    is_j(unit).then(|| Complex::new(x, sign))
+}
+
+/// Splits an optional leading sign, returning `1.0` or `-1.0` and the remainder.
+fn split_sign(text: &str) -> (f64, &str) {
+    if let Some(rest) = text.strip_prefix('-') {
+        (-1.0, rest)
+    } else {
+        (1.0, text.strip_prefix('+').unwrap_or(text))
+    }
+}
+
+/// Scans the longest float literal at the start of `text`, returning its value
+/// and byte length. Accepts an optional sign, decimal digits with an optional
+/// fraction and exponent, and the special names `inf`, `infinity` and `nan`.
+fn scan_float(text: &str) -> Option<(f64, usize)> {
+    let bytes = text.as_bytes();
+    let mut pos = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+
+    let lower = text[pos..].to_ascii_lowercase();
+    for name in ["infinity", "inf", "nan"] {
+        if lower.starts_with(name) {
+            let end = pos + name.len();
+            let value = if name == "nan" { f64::NAN } else { f64::INFINITY };
+            return Some((if bytes[0] == b'-' { -value } else { value }, end));
+        }
+    }
+
+    let digits_start = pos;
+    while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+        pos += 1;
+    }
+    let mut digit_count = pos - digits_start;
+    if bytes.get(pos) == Some(&b'.') {
+        pos += 1;
+        let fraction_start = pos;
+        while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+            pos += 1;
+        }
+        digit_count += pos - fraction_start;
+    }
+    if digit_count == 0 {
+        return None;
+    }
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        let mut exp_end = pos + 1;
+        if matches!(bytes.get(exp_end), Some(b'+' | b'-')) {
+            exp_end += 1;
+        }
+        let exp_digits_start = exp_end;
+        while bytes.get(exp_end).is_some_and(u8::is_ascii_digit) {
+            exp_end += 1;
+        }
+        if exp_end > exp_digits_start {
+            pos = exp_end;
+        }
+    }
+    text[..pos].parse().ok().map(|value| (value, pos))
+}
+
+impl HeapItem for Complex {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>()
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {}
+}
+
+/// `HeapRead`-based dispatch for `Complex`, enabling the `HeapReadOutput` enum to
+/// delegate `PyTrait` calls to heap-resident complex numbers.
+impl<'h> PyTrait<'h> for HeapRead<'h, Complex> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::Complex
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(self.get(vm.heap) == other.get(vm.heap))
+    }
+
+    fn py_hash(&self, _self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        Ok(Some(self.get(vm.heap).hash()))
+    }
+
+    fn py_bool(&self, vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        let c = self.get(vm.heap);
+        c.re != 0.0 || c.im != 0.0
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        f.write_str(&format_repr(*self.get(vm.heap)))?;
+        Ok(())
+    }
+
+    fn py_str(&self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Cow<'static, str>> {
+        Ok(Cow::Owned(format_repr(*self.get(vm.heap))))
+    }
+
+    fn py_call_attr(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        if attr.static_string() == Some(StaticStrings::Conjugate) {
+            // Copy the value to release the HeapRead borrow before checking args
+            let c = *self.get(vm.heap);
+            args.check_zero_args("complex.conjugate", vm.heap)?;
+            return Ok(CallResult::Value(c.conjugate().into_value(vm.heap)?));
+        }
+        args.drop_with_heap(vm);
+        Err(ExcType::attribute_error(Type::Complex, attr.as_str(vm.interns)))
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let c = self.get(vm.heap);
+        match attr.static_string() {
+            Some(StaticStrings::Real) => Ok(Some(CallResult::Value(Value::Float(c.re)))),
+            Some(StaticStrings::Imag) => Ok(Some(CallResult::Value(Value::Float(c.im)))),
+            _ => Ok(None),
+        }
+    }
+}
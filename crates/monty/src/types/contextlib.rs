@@ -0,0 +1,530 @@
+//! Context managers returned by the `contextlib` module, plus `warnings.catch_warnings`.
+//!
+//! `suppress`, `nullcontext`, `closing`, `redirect_stdout`, `ExitStack` and
+//! `catch_warnings` all share one heap variant, [`ContextManager`], since each only
+//! differs in what it holds and what `__enter__` / `__exit__` do with it.
+//!
+//! Work that runs Python code on exit — `thing.close()`, `ExitStack` callbacks,
+//! `target.write()` from a redirected `print()` — goes through the synchronous
+//! `VM::evaluate_*` helpers, so external functions can't be used there.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    iter, mem,
+};
+
+use ahash::AHashSet;
+
+use super::{PyTrait, Type};
+use crate::{
+    args::{ArgValues, FromArgs, KwargsValues},
+    builtins::Builtins,
+    bytecode::{CallResult, VM},
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    hash::HashValue,
+    heap::{ContainsHeap, DropWithHeap, HeapData, HeapId, HeapItem, HeapRead},
+    intern::StaticStrings,
+    modules::warnings::CatchWarnings,
+    resource::ResourceTracker,
+    types::{Dict, host_handle::checked_truth},
+    value::{EitherStr, Value},
+};
+
+/// A context manager created by one of the `contextlib` constructors.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) enum ContextManager {
+    /// `suppress(*exceptions)`: swallows exceptions matching any of the classes.
+    Suppress(Vec<ExcType>),
+    /// `nullcontext(enter_result=None)`: returns `enter_result` from `__enter__`.
+    NullContext(Value),
+    /// `closing(thing)`: calls `thing.close()` on exit.
+    Closing(Value),
+    /// `redirect_stdout(new_target)`: sends `print()` output to `new_target.write()`
+    /// while active.
+    RedirectStdout(Value),
+    /// `ExitStack()`: callbacks run in LIFO order on exit or `close()`.
+    ExitStack(Vec<ExitCallback>),
+    /// `warnings.catch_warnings(...)`: saves the warnings filters on entry and
+    /// restores them on exit.
+    CatchWarnings(CatchWarnings),
+}
+
+/// A callback registered on an `ExitStack`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) enum ExitCallback {
+    /// A context manager from `enter_context()` or `push()`; its `__exit__` runs.
+    Exit(Value),
+    /// A callable passed to `push()`, called as `__exit__(exc_type, exc, tb)`.
+    ExitFunction(Value),
+    /// `callback(function, *args, **kwargs)`; the return value is ignored.
+    Callback {
+        function: Value,
+        args: Vec<Value>,
+        kwargs: Vec<(Value, Value)>,
+    },
+}
+
+/// Constructs a context manager from a call to one of the `contextlib` classes.
+pub(crate) fn init(ty: Type, vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let cm = match ty {
+        Type::Suppress => {
+            let exceptions = args.into_pos_only("suppress", vm.heap)?;
+            defer_drop!(exceptions, vm);
+            let mut types = Vec::with_capacity(exceptions.as_slice().len());
+            for exception in exceptions.as_slice() {
+                match exception {
+                    Value::Builtin(Builtins::ExcType(exc_type)) => types.push(*exc_type),
+                    _ => return Err(ExcType::except_invalid_type_error()),
+                }
+            }
+            ContextManager::Suppress(types)
+        }
+        Type::NullContext => {
+            let NullContextArgs { enter_result } = NullContextArgs::from_args(args, vm)?;
+            ContextManager::NullContext(enter_result)
+        }
+        Type::Closing => ContextManager::Closing(args.get_one_arg("closing", vm.heap)?),
+        Type::RedirectStdout => ContextManager::RedirectStdout(args.get_one_arg("redirect_stdout", vm.heap)?),
+        Type::ExitStack => {
+            args.check_zero_args("ExitStack", vm.heap)?;
+            ContextManager::ExitStack(Vec::new())
+        }
+        Type::CatchWarnings => ContextManager::CatchWarnings(CatchWarnings::init(vm, args)?),
+        _ => {
+            args.drop_with_heap(vm);
+            return Err(ExcType::type_error_not_callable(ty));
+        }
+    };
+    Ok(Value::Ref(vm.heap.allocate(HeapData::ContextManager(cm))?))
+}
+
+/// Argument shape for `contextlib.nullcontext(enter_result=None)`.
+#[derive(FromArgs)]
+#[from_args(name = "nullcontext")]
+struct NullContextArgs {
+    #[from_args(default = Value::None)]
+    enter_result: Value,
+}
+
+impl ContextManager {
+    /// Returns the `contextlib` class this manager is an instance of.
+    #[must_use]
+    pub fn py_type(&self) -> Type {
+        match self {
+            Self::Suppress(_) => Type::Suppress,
+            Self::NullContext(_) => Type::NullContext,
+            Self::Closing(_) => Type::Closing,
+            Self::RedirectStdout(_) => Type::RedirectStdout,
+            Self::ExitStack(_) => Type::ExitStack,
+            Self::CatchWarnings(_) => Type::CatchWarnings,
+        }
+    }
+
+    /// Returns every value held by the manager, for GC traversal.
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        let (single, callbacks) = match self {
+            Self::Suppress(_) | Self::CatchWarnings(_) => (None, &[][..]),
+            Self::NullContext(value) | Self::Closing(value) | Self::RedirectStdout(value) => (Some(value), &[][..]),
+            Self::ExitStack(callbacks) => (None, callbacks.as_slice()),
+        };
+        single
+            .into_iter()
+            .chain(callbacks.iter().flat_map(ExitCallback::values))
+    }
+}
+
+impl ExitCallback {
+    /// Returns every value held by the callback.
+    fn values(&self) -> impl Iterator<Item = &Value> {
+        let (head, args, kwargs) = match self {
+            Self::Exit(value) | Self::ExitFunction(value) => (value, &[][..], &[][..]),
+            Self::Callback { function, args, kwargs } => (function, args.as_slice(), kwargs.as_slice()),
+        };
+        iter::once(head)
+            .chain(args)
+            .chain(kwargs.iter().flat_map(|(key, value)| [key, value]))
+    }
+
+    /// Runs the callback with the exception currently propagating, if any.
+    ///
+    /// Returns whether the callback asked for the exception to be suppressed.
+    fn run(self, exc: Option<HeapId>, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<bool> {
+        match self {
+            Self::Exit(cm) => {
+                defer_drop!(cm, vm);
+                let Value::Ref(cm_id) = *cm else {
+                    return Err(RunError::internal("ExitStack: expected context-manager ref"));
+                };
+                let result = vm.heap.read(cm_id).py_exit(cm_id, vm, exc)?;
+                let value = vm.evaluate_call_result("ExitStack.__exit__()", result)?;
+                defer_drop!(value, vm);
+                checked_truth(value, vm)
+            }
+            Self::ExitFunction(function) => {
+                defer_drop!(function, vm);
+                let (exc_type, exc_value) = match exc {
+                    Some(id) => {
+                        let exc_type = match vm.heap.get(id) {
+                            HeapData::Exception(e) => Value::Builtin(Builtins::ExcType(e.exc_type())),
+                            _ => Value::None,
+                        };
+                        vm.heap.inc_ref(id);
+                        (exc_type, Value::Ref(id))
+                    }
+                    None => (Value::None, Value::None),
+                };
+                let args = ArgValues::ArgsKargs {
+                    args: vec![exc_type, exc_value, Value::None],
+                    kwargs: KwargsValues::Empty,
+                };
+                let value = vm.evaluate_function("ExitStack.__exit__()", function, args)?;
+                defer_drop!(value, vm);
+                checked_truth(value, vm)
+            }
+            Self::Callback { function, args, kwargs } => {
+                defer_drop!(function, vm);
+                let kwargs = if kwargs.is_empty() {
+                    KwargsValues::Empty
+                } else {
+                    match Dict::from_pairs(kwargs, vm) {
+                        Ok(dict) => KwargsValues::Dict(dict),
+                        Err(err) => {
+                            args.drop_with_heap(vm);
+                            return Err(err);
+                        }
+                    }
+                };
+                let value =
+                    vm.evaluate_function("ExitStack.__exit__()", function, ArgValues::ArgsKargs { args, kwargs })?;
+                value.drop_with_heap(vm);
+                Ok(false)
+            }
+        }
+    }
+}
+
+impl DropWithHeap for ExitCallback {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        match self {
+            Self::Exit(value) | Self::ExitFunction(value) => value.drop_with_heap(heap),
+            Self::Callback { function, args, kwargs } => {
+                function.drop_with_heap(heap);
+                args.drop_with_heap(heap);
+                for (key, value) in kwargs {
+                    key.drop_with_heap(heap);
+                    value.drop_with_heap(heap);
+                }
+            }
+        }
+    }
+}
+
+impl HeapItem for ContextManager {
+    fn py_estimate_size(&self) -> usize {
+        let callbacks = match self {
+            Self::Suppress(types) => types.len() * mem::size_of::<ExcType>(),
+            Self::ExitStack(callbacks) => callbacks.len() * mem::size_of::<ExitCallback>(),
+            _ => 0,
+        };
+        mem::size_of::<Self>() + callbacks
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        match self {
+            Self::Suppress(_) | Self::CatchWarnings(_) => {}
+            Self::NullContext(value) | Self::Closing(value) | Self::RedirectStdout(value) => {
+                value.py_dec_ref_ids(stack);
+            }
+            Self::ExitStack(callbacks) => {
+                for callback in callbacks {
+                    match callback {
+                        ExitCallback::Exit(value) | ExitCallback::ExitFunction(value) => value.py_dec_ref_ids(stack),
+                        ExitCallback::Callback { function, args, kwargs } => {
+                            function.py_dec_ref_ids(stack);
+                            for arg in args {
+                                arg.py_dec_ref_ids(stack);
+                            }
+                            for (key, value) in kwargs {
+                                key.py_dec_ref_ids(stack);
+                                value.py_dec_ref_ids(stack);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, ContextManager> {
+    fn py_type(&self, vm: &VM<'h, impl ResourceTracker>) -> Type {
+        self.get(vm.heap).py_type()
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// Context managers only compare equal to themselves, which `Value::py_eq` checks by identity.
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_hash(&self, self_id: HeapId, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        match self.get(vm.heap) {
+            ContextManager::CatchWarnings(cw) if cw.record() => Ok(f.write_str("catch_warnings(record=True)")?),
+            ContextManager::CatchWarnings(_) => Ok(f.write_str("catch_warnings()")?),
+            _ => Ok(write!(f, "<{} object>", self.py_type(vm))?),
+        }
+    }
+
+    /// `ExitStack` methods; the other managers only have `__enter__` / `__exit__`.
+    fn py_call_attr(
+        &mut self,
+        self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        let is_exit_stack = matches!(self.get(vm.heap), ContextManager::ExitStack(_));
+        let value = match attr.static_string() {
+            Some(StaticStrings::EnterContext) if is_exit_stack => self.enter_context(vm, args)?,
+            Some(StaticStrings::Callback) if is_exit_stack => self.callback(vm, args)?,
+            Some(StaticStrings::Push) if is_exit_stack => self.push(vm, args)?,
+            Some(StaticStrings::PopAll) if is_exit_stack => {
+                args.check_zero_args("pop_all", vm.heap)?;
+                let callbacks = mem::take(self.callbacks_mut(vm));
+                Value::Ref(
+                    vm.heap
+                        .allocate(HeapData::ContextManager(ContextManager::ExitStack(callbacks)))?,
+                )
+            }
+            Some(StaticStrings::Close) if is_exit_stack => {
+                args.check_zero_args("close", vm.heap)?;
+                self.py_exit(self_id, vm, None)?.drop_with_heap(vm);
+                Value::None
+            }
+            _ => {
+                args.drop_with_heap(vm);
+                return Err(ExcType::attribute_error(self.py_type(vm), attr.as_str(vm.interns)));
+            }
+        };
+        Ok(CallResult::Value(value))
+    }
+
+    fn py_is_context_manager(&self) -> bool {
+        true
+    }
+
+    fn py_enter(&mut self, self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<CallResult> {
+        let value = match self.get(vm.heap) {
+            ContextManager::Suppress(_) => Value::None,
+            ContextManager::NullContext(value) | ContextManager::Closing(value) => value.clone_with_heap(vm),
+            ContextManager::RedirectStdout(target) => {
+                let target = target.clone_with_heap(vm);
+                let redirect = target.clone_with_heap(vm);
+                vm.stdout_redirects.push(redirect);
+                target
+            }
+            ContextManager::ExitStack(_) => {
+                vm.heap.inc_ref(self_id);
+                Value::Ref(self_id)
+            }
+            ContextManager::CatchWarnings(cw) => {
+                let cw = *cw;
+                cw.enter(vm)?
+            }
+        };
+        Ok(CallResult::Value(value))
+    }
+
+    fn py_exit(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        exc: Option<HeapId>,
+    ) -> RunResult<CallResult> {
+        let value = match self.get(vm.heap) {
+            ContextManager::Suppress(types) => match exc.map(|id| vm.heap.get(id)) {
+                Some(HeapData::Exception(e)) => {
+                    let exc_type = e.exc_type();
+                    Value::Bool(types.iter().any(|t| exc_type.is_subclass_of(*t)))
+                }
+                _ => Value::None,
+            },
+            ContextManager::NullContext(_) => Value::None,
+            ContextManager::Closing(thing) => {
+                let thing = thing.clone_with_heap(vm);
+                let result = vm.evaluate_method(
+                    "closing.__exit__()",
+                    thing,
+                    StaticStrings::Close.into(),
+                    ArgValues::Empty,
+                )?;
+                result.drop_with_heap(vm);
+                Value::None
+            }
+            ContextManager::RedirectStdout(_) => {
+                vm.stdout_redirects.pop().drop_with_heap(vm);
+                Value::None
+            }
+            ContextManager::ExitStack(_) => {
+                let callbacks = mem::take(self.callbacks_mut(vm));
+                Value::Bool(unwind(callbacks, exc, vm)?)
+            }
+            ContextManager::CatchWarnings(_) => {
+                CatchWarnings::exit(vm);
+                Value::None
+            }
+        };
+        Ok(CallResult::Value(value))
+    }
+}
+
+impl<'h> HeapRead<'h, ContextManager> {
+    /// Returns the callbacks of an `ExitStack`.
+    fn callbacks_mut<'a>(&'a mut self, vm: &'a mut VM<'h, impl ResourceTracker>) -> &'a mut Vec<ExitCallback> {
+        match self.get_mut(vm.heap) {
+            ContextManager::ExitStack(callbacks) => callbacks,
+            _ => unreachable!("callbacks_mut called on a context manager that isn't an ExitStack"),
+        }
+    }
+
+    /// `ExitStack.enter_context(cm)`: enters `cm` and schedules its `__exit__`.
+    fn enter_context(&mut self, vm: &mut VM<'h, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        let cm = args.get_one_arg("enter_context", vm.heap)?;
+        let cm_id = match cm {
+            Value::Ref(id) if vm.heap.read(id).py_is_context_manager() => id,
+            _ => {
+                let ty = cm.py_type(vm);
+                cm.drop_with_heap(vm);
+                let qualified = if ty.to_string().contains('.') {
+                    ty.to_string()
+                } else {
+                    format!("builtins.{ty}")
+                };
+                return Err(SimpleException::new_msg(
+                    ExcType::TypeError,
+                    format!("'{qualified}' object does not support the context manager protocol"),
+                )
+                .into());
+            }
+        };
+        let entered = vm
+            .heap
+            .read(cm_id)
+            .py_enter(cm_id, vm)
+            .and_then(|result| vm.evaluate_call_result("ExitStack.enter_context()", result));
+        match entered {
+            Ok(value) => {
+                self.callbacks_mut(vm).push(ExitCallback::Exit(cm));
+                Ok(value)
+            }
+            Err(err) => {
+                cm.drop_with_heap(vm);
+                Err(err)
+            }
+        }
+    }
+
+    /// `ExitStack.callback(callback, /, *args, **kwds)`: schedules `callback(*args, **kwds)`.
+    fn callback(&mut self, vm: &mut VM<'h, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        let (mut positional, kwargs) = args.into_parts();
+        let kwargs = kwargs.into_iter().collect::<Vec<_>>();
+        let Some(function) = positional.next() else {
+            kwargs.drop_with_heap(vm);
+            return Err(ExcType::type_error(
+                "ExitStack.callback() missing 1 required positional argument: 'callback'",
+            ));
+        };
+        let result = function.clone_with_heap(vm);
+        self.callbacks_mut(vm).push(ExitCallback::Callback {
+            function,
+            args: positional.collect(),
+            kwargs,
+        });
+        Ok(result)
+    }
+
+    /// `ExitStack.push(exit)`: schedules a context manager's `__exit__` or an exit callable.
+    fn push(&mut self, vm: &mut VM<'h, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        let exit = args.get_one_arg("push", vm.heap)?;
+        let result = exit.clone_with_heap(vm);
+        let callback = match exit {
+            Value::Ref(id) if vm.heap.read(id).py_is_context_manager() => ExitCallback::Exit(exit),
+            _ => ExitCallback::ExitFunction(exit),
+        };
+        self.callbacks_mut(vm).push(callback);
+        Ok(result)
+    }
+}
+
+/// Runs `ExitStack` callbacks in LIFO order, as `ExitStack.__exit__` does.
+///
+/// Each callback sees the exception propagating at that point: the original one, a
+/// replacement raised by a later-registered callback, or none once it has been
+/// suppressed. Returns whether the original exception was suppressed; a replacement
+/// exception still pending after the last callback is raised instead.
+fn unwind(
+    mut callbacks: Vec<ExitCallback>,
+    exc: Option<HeapId>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<bool> {
+    let received = exc.is_some();
+    let mut pending: Option<Value> = exc.map(|id| {
+        vm.heap.inc_ref(id);
+        Value::Ref(id)
+    });
+    let mut raised: Option<RunError> = None;
+    let mut suppressed = false;
+    while let Some(callback) = callbacks.pop() {
+        let exc_id = pending.as_ref().and_then(Value::ref_id);
+        match callback.run(exc_id, vm) {
+            Ok(true) => {
+                pending.take().drop_with_heap(vm);
+                raised = None;
+                suppressed = true;
+            }
+            Ok(false) => {}
+            Err(RunError::Exc(err)) => match vm.heap.allocate(HeapData::Exception(err.exc.clone())) {
+                Ok(id) => {
+                    pending.replace(Value::Ref(id)).drop_with_heap(vm);
+                    raised = Some(RunError::Exc(err));
+                }
+                Err(resource_err) => {
+                    callbacks.drop_with_heap(vm);
+                    pending.drop_with_heap(vm);
+                    return Err(resource_err.into());
+                }
+            },
+            Err(err) => {
+                callbacks.drop_with_heap(vm);
+                pending.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+    }
+    pending.drop_with_heap(vm);
+    match raised {
+        Some(err) => Err(err),
+        None => Ok(received && suppressed),
+    }
+}
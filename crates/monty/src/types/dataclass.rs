@@ -1,4 +1,5 @@
 use std::{
+    cmp::Ordering,
     fmt::Write,
     hash::{DefaultHasher, Hash, Hasher},
     mem,
@@ -7,7 +8,10 @@ use std::{
 use ahash::AHashSet;
 use serde::ser::SerializeStruct;
 
-use super::{Dict, PyTrait};
+use super::{
+    Dict, PyTrait,
+    dataclass_type::{DataclassField, DataclassOptions},
+};
 use crate::{
     args::ArgValues,
     bytecode::{CallResult, VM},
@@ -15,12 +19,12 @@ use crate::{
     exception_private::{ExcType, RunResult, SimpleException},
     hash::HashValue,
     heap::{
-        BorrowedHeapRead, BorrowedHeapReadMut, HeapId, HeapItem, HeapRead, heap_read_ref_as_field,
-        heap_read_ref_as_field_mut,
+        BorrowedHeapRead, BorrowedHeapReadMut, DropWithHeap, HeapData, HeapId, HeapItem, HeapRead,
+        heap_read_ref_as_field, heap_read_ref_as_field_mut,
     },
     intern::Interns,
     resource::ResourceTracker,
-    types::Type,
+    types::{Type, str::allocate_string},
     value::{EitherStr, Value},
 };
 
@@ -32,11 +36,17 @@ use crate::{
 /// in `attrs`, it is dispatched as a `MethodCall` to the host (provided the name
 /// is public — no leading underscore).
 ///
+/// Instances of classes declared in the sandbox carry a `class_id` instead: their
+/// methods, field flags and `eq`/`order` options come from the
+/// [`DataclassType`](super::dataclass_type::DataclassType), and nothing is
+/// dispatched to the host.
+///
 /// # Fields
 /// - `name`: The class name (e.g., "Point", "User")
 /// - `field_names`: Declared field names in definition order (used for repr)
 /// - `attrs`: All attributes including declared fields and dynamically added ones
 /// - `frozen`: Whether the dataclass instance is immutable
+/// - `class_id`: The sandbox-declared class, owning a reference (`None` for host instances)
 ///
 /// # Hashability
 /// When `frozen` is true, the dataclass is immutable and hashable. The hash
@@ -65,6 +75,8 @@ pub(crate) struct Dataclass {
     attrs: Dict,
     /// Whether this dataclass instance is immutable (affects hashability)
     frozen: bool,
+    /// The sandbox-declared class of this instance, if any
+    class_id: Option<HeapId>,
 }
 
 impl Dataclass {
@@ -84,9 +96,19 @@ impl Dataclass {
             field_names,
             attrs,
             frozen,
+            class_id: None,
         }
     }
 
+    /// Attaches the sandbox-declared class of this instance.
+    ///
+    /// The caller must have incremented the class's refcount; the instance owns that reference.
+    #[must_use]
+    pub fn with_class(mut self, class_id: HeapId) -> Self {
+        self.class_id = Some(class_id);
+        self
+    }
+
     /// Returns the class name.
     #[must_use]
     pub fn name<'a>(&'a self, interns: &'a Interns) -> &'a str {
@@ -116,6 +138,36 @@ impl Dataclass {
     pub fn is_frozen(&self) -> bool {
         self.frozen
     }
+
+    /// Returns the sandbox-declared class of this instance, if any.
+    #[must_use]
+    pub fn class_id(&self) -> Option<HeapId> {
+        self.class_id
+    }
+
+    /// Returns the indices of the declared fields selected by `include`.
+    ///
+    /// Host instances have no per-field flags, so every field is selected.
+    fn selected_fields(
+        &self,
+        vm: &VM<'_, impl ResourceTracker>,
+        include: impl Fn(&DataclassField) -> bool,
+    ) -> Vec<usize> {
+        match self.class_id.map(|id| vm.heap.get(id)) {
+            Some(HeapData::DataclassType(class)) => (0..class.fields().len())
+                .filter(|&i| include(&class.fields()[i]))
+                .collect(),
+            _ => (0..self.field_names.len()).collect(),
+        }
+    }
+
+    /// Returns a new reference to the value of the declared field at `index`.
+    fn field_value(&self, index: usize, vm: &VM<'_, impl ResourceTracker>) -> Option<Value> {
+        let field_name = self.field_names.get(index)?;
+        self.attrs
+            .get_by_str(field_name, vm.heap, vm.interns)
+            .map(|value| value.clone_with_heap(vm))
+    }
 }
 
 impl<'h> HeapRead<'h, Dataclass> {
@@ -146,6 +198,72 @@ impl<'h> HeapRead<'h, Dataclass> {
         self.attrs_mut().set(name, value, vm)
     }
 
+    /// Deletes an attribute.
+    ///
+    /// Returns `FrozenInstanceError` if the dataclass is frozen, or `AttributeError`
+    /// if the attribute doesn't exist.
+    pub fn del_attr(&mut self, name: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<()> {
+        let interns = vm.interns;
+        if self.get(vm.heap).frozen {
+            return Err(SimpleException::new_msg(
+                ExcType::FrozenInstanceError,
+                format!("cannot delete field '{}'", name.as_str(interns)),
+            )
+            .into());
+        }
+        let key = match name {
+            EitherStr::Interned(string_id) => Value::InternString(*string_id),
+            EitherStr::Heap(s) => allocate_string(s.as_str(), vm.heap)?,
+        };
+        defer_drop!(key, vm);
+        if let Some((old_key, old_value)) = self.attrs_mut().pop(key, vm)? {
+            old_key.drop_with_heap(vm);
+            old_value.drop_with_heap(vm);
+            Ok(())
+        } else {
+            Err(ExcType::attribute_error(
+                self.get(vm.heap).name(interns),
+                name.as_str(interns),
+            ))
+        }
+    }
+
+    /// Calls a method of a sandbox-declared class, or a callable stored in a field.
+    ///
+    /// Methods receive the instance as their first argument. Unlike host dataclasses,
+    /// unknown names raise `AttributeError` instead of being dispatched to the host.
+    fn call_class_attr(
+        &self,
+        self_id: HeapId,
+        class_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        let interns = vm.interns;
+        let attr_str = attr.as_str(interns);
+        let method = match vm.heap.get(class_id) {
+            HeapData::DataclassType(class) => class.method(attr_str, vm).map(|method| method.clone_with_heap(vm)),
+            _ => None,
+        };
+        if let Some(method) = method {
+            defer_drop!(method, vm);
+            vm.heap.inc_ref(self_id);
+            return vm.call_function(method, args.prepend(Value::Ref(self_id)));
+        }
+        let field_value = self
+            .get(vm.heap)
+            .attrs
+            .get_by_str(attr_str, vm.heap, interns)
+            .map(|value| value.clone_with_heap(vm));
+        if let Some(callable) = field_value {
+            defer_drop!(callable, vm);
+            return vm.call_function(callable, args);
+        }
+        args.drop_with_heap(vm);
+        Err(ExcType::attribute_error(self.get(vm.heap).name(interns), attr_str))
+    }
+
     pub fn attrs(&self) -> BorrowedHeapRead<'_, 'h, Dict> {
         heap_read_ref_as_field!(self, Dataclass, attrs)
     }
@@ -165,15 +283,83 @@ impl<'h> PyTrait<'h> for HeapRead<'h, Dataclass> {
         None
     }
 
+    /// Host dataclasses are equal if they have the same name and equal attrs.
+    ///
+    /// Instances of a sandbox-declared class are equal if they share the class, it was
+    /// declared with `eq=True`, and all fields with `compare=True` are equal.
     fn py_eq(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
-        // Dataclasses are equal if they have the same name and equal attrs
-        Ok(self.get(vm.heap).name == other.get(vm.heap).name && self.attrs().py_eq(&other.attrs(), vm)?)
+        let (class_id, other_class_id) = (self.get(vm.heap).class_id, other.get(vm.heap).class_id);
+        let Some(class_id) = class_id else {
+            return Ok(other_class_id.is_none()
+                && self.get(vm.heap).name == other.get(vm.heap).name
+                && self.attrs().py_eq(&other.attrs(), vm)?);
+        };
+        if other_class_id != Some(class_id) || !class_options(class_id, vm).eq {
+            return Ok(false);
+        }
+        let token = vm.heap.incr_recursion_depth()?;
+        defer_drop!(token, vm);
+        for i in self.get(vm.heap).selected_fields(vm, |field| field.compare) {
+            let a = self.get(vm.heap).field_value(i, vm);
+            defer_drop!(a, vm);
+            let b = other.get(vm.heap).field_value(i, vm);
+            defer_drop!(b, vm);
+            let equal = match (a, b) {
+                (Some(a), Some(b)) => a.py_eq(b, vm)?,
+                _ => false,
+            };
+            if !equal {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Orders instances of a sandbox-declared class declared with `order=True`, comparing
+    /// the fields with `compare=True` like a tuple. Anything else is unordered.
+    fn py_cmp(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<Ordering>> {
+        let class_id = self.get(vm.heap).class_id;
+        let Some(class_id) = class_id else {
+            return Ok(None);
+        };
+        if other.get(vm.heap).class_id != Some(class_id) || !class_options(class_id, vm).order {
+            return Ok(None);
+        }
+        let token = vm.heap.incr_recursion_depth()?;
+        defer_drop!(token, vm);
+        for i in self.get(vm.heap).selected_fields(vm, |field| field.compare) {
+            let a = self.get(vm.heap).field_value(i, vm);
+            defer_drop!(a, vm);
+            let b = other.get(vm.heap).field_value(i, vm);
+            defer_drop!(b, vm);
+            let (Some(a), Some(b)) = (a, b) else {
+                return Ok(None);
+            };
+            match a.py_cmp(b, vm)? {
+                Some(Ordering::Equal) => {}
+                Some(ordering) => return Ok(Some(ordering)),
+                // Like tuples, equal-but-unorderable values don't block the comparison
+                None if a.py_eq(b, vm)? => {}
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(Ordering::Equal))
     }
 
     /// Hashes a frozen dataclass by its class name and the values of declared fields.
     ///
-    /// Mutable (non-frozen) dataclasses return `None` (unhashable).
-    fn py_hash(&self, _self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+    /// Mutable (non-frozen) dataclasses return `None` (unhashable). Instances of a
+    /// sandbox-declared class follow the class options like CPython: `eq=False` hashes
+    /// by identity, and only fields with `compare=True` contribute.
+    fn py_hash(&self, self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let class_id = self.get(vm.heap).class_id;
+        if let Some(class_id) = class_id
+            && !class_options(class_id, vm).eq
+        {
+            let mut hasher = DefaultHasher::new();
+            self_id.hash(&mut hasher);
+            return Ok(Some(HashValue::new(hasher.finish())));
+        }
         // Only frozen (immutable) dataclasses are hashable
         if !self.get(vm.heap).frozen {
             return Ok(None);
@@ -184,12 +370,10 @@ impl<'h> PyTrait<'h> for HeapRead<'h, Dataclass> {
         // Hash the class name
         self.get(vm.heap).name.hash(&mut hasher);
         // Hash each declared field (name, value) pair in order
-        let field_count = self.get(vm.heap).field_names.len();
-        for i in 0..field_count {
+        for i in self.get(vm.heap).selected_fields(vm, |field| field.compare) {
             let field_name = &self.get(vm.heap).field_names[i];
             field_name.hash(&mut hasher);
-            if let Some(value) = self.get(vm.heap).attrs.get_by_str(field_name, vm.heap, vm.interns) {
-                let value = value.clone_with_heap(vm.heap);
+            if let Some(value) = self.get(vm.heap).field_value(i, vm) {
                 defer_drop!(value, vm);
                 match value.py_hash(vm)? {
                     Some(h) => h.hash(&mut hasher),
@@ -218,15 +402,15 @@ impl<'h> PyTrait<'h> for HeapRead<'h, Dataclass> {
         defer_drop!(token, vm);
 
         // Format: ClassName(field1=value1, field2=value2, ...)
-        // Only declared fields are shown, not dynamically added attributes
+        // Only declared fields are shown (minus `repr=False` ones), not dynamically added attributes
         let dc = self.get(vm.heap);
         f.write_str(dc.name(vm.interns))?;
         f.write_char('(')?;
 
-        let field_count = self.get(vm.heap).field_names.len();
+        let shown_fields = self.get(vm.heap).selected_fields(vm, |field| field.repr);
         let interns = vm.interns;
-        for i in 0..field_count {
-            if i > 0 {
+        for (n, i) in shown_fields.into_iter().enumerate() {
+            if n > 0 {
                 f.write_str(", ")?;
             }
             // Write field name
@@ -263,6 +447,9 @@ impl<'h> PyTrait<'h> for HeapRead<'h, Dataclass> {
         attr: &EitherStr,
         args: ArgValues,
     ) -> RunResult<CallResult> {
+        if let Some(class_id) = self.get(vm.heap).class_id {
+            return self.call_class_attr(self_id, class_id, vm, attr, args);
+        }
         let attr_str = attr.as_str(vm.interns);
         // Only public methods (no underscore prefix = no dunders, no private)
         if !attr_str.starts_with('_')
@@ -307,6 +494,25 @@ impl<'h> PyTrait<'h> for HeapRead<'h, Dataclass> {
     }
 }
 
+/// Returns the sandbox-declared class of `value` if it's an instance of one.
+pub(crate) fn class_of(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<HeapId> {
+    match value {
+        Value::Ref(id) => match vm.heap.get(*id) {
+            HeapData::Dataclass(dc) => dc.class_id(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the `@dataclass` options of a sandbox-declared class.
+pub(crate) fn class_options(class_id: HeapId, vm: &VM<'_, impl ResourceTracker>) -> DataclassOptions {
+    match vm.heap.get(class_id) {
+        HeapData::DataclassType(class) => class.options().unwrap_or_default(),
+        _ => DataclassOptions::default(),
+    }
+}
+
 impl HeapItem for Dataclass {
     fn py_estimate_size(&self) -> usize {
         mem::size_of::<Self>()
@@ -318,19 +524,23 @@ impl HeapItem for Dataclass {
     fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
         // Delegate to the attrs Dict which handles all nested heap references
         self.attrs.py_dec_ref_ids(stack);
+        if let Some(class_id) = self.class_id {
+            stack.push(class_id);
+        }
     }
 }
 
 // Custom serde implementation for Dataclass.
-// Serializes all five fields.
+// Serializes all six fields.
 impl serde::Serialize for Dataclass {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut state = serializer.serialize_struct("Dataclass", 5)?;
+        let mut state = serializer.serialize_struct("Dataclass", 6)?;
         state.serialize_field("name", &self.name)?;
         state.serialize_field("type_id", &self.type_id)?;
         state.serialize_field("field_names", &self.field_names)?;
         state.serialize_field("attrs", &self.attrs)?;
         state.serialize_field("frozen", &self.frozen)?;
+        state.serialize_field("class_id", &self.class_id)?;
         state.end()
     }
 }
@@ -344,6 +554,7 @@ impl<'de> serde::Deserialize<'de> for Dataclass {
             field_names: Vec<String>,
             attrs: Dict,
             frozen: bool,
+            class_id: Option<HeapId>,
         }
         let dc = DataclassData::deserialize(deserializer)?;
         Ok(Self {
@@ -352,6 +563,7 @@ impl<'de> serde::Deserialize<'de> for Dataclass {
             field_names: dc.field_names,
             attrs: dc.attrs,
             frozen: dc.frozen,
+            class_id: dc.class_id,
         })
     }
 }
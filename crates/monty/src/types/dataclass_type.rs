@@ -0,0 +1,720 @@
+//! Classes declared in sandboxed code, and the `dataclasses.Field` objects describing their fields.
+//!
+//! A `class` statement compiles to `Opcode::BuildClass`, which collects the field
+//! annotations, defaults and methods of the class body into an unprocessed
+//! [`DataclassType`]. `dataclasses.dataclass()` validates the fields and records the
+//! decorator options; only processed classes can be instantiated.
+//!
+//! Instances are ordinary [`Dataclass`] values holding a reference to their class, which
+//! supplies the methods, the `repr`/`compare` field flags and the `eq`/`order` options.
+//! They convert to the same `MontyObject::Dataclass` shape as host dataclasses.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use ahash::AHashSet;
+
+use crate::{
+    args::{ArgValues, KwargsValues},
+    bytecode::{CallResult, VM},
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    hash::HashValue,
+    heap::{DropWithHeap, HeapData, HeapId, HeapItem, HeapRead},
+    intern::{StaticStrings, StringId},
+    resource::ResourceTracker,
+    types::{Dataclass, Dict, PyTrait, Type},
+    value::{EitherStr, Marker, Value},
+};
+
+/// Options passed to the `@dataclass(...)` decorator.
+///
+/// `unsafe_hash`, `match_args`, `slots` and `weakref_slot` are accepted by
+/// `dataclass()` but have no effect, so they aren't recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub(crate) struct DataclassOptions {
+    pub init: bool,
+    pub repr: bool,
+    pub eq: bool,
+    pub order: bool,
+    pub frozen: bool,
+    pub kw_only: bool,
+}
+
+impl Default for DataclassOptions {
+    fn default() -> Self {
+        Self {
+            init: true,
+            repr: true,
+            eq: true,
+            order: false,
+            frozen: false,
+            kw_only: false,
+        }
+    }
+}
+
+/// A field of a sandbox-declared class, also used for the objects returned by
+/// `dataclasses.field()` and `dataclasses.fields()`.
+///
+/// `name` is `None` and `annotation` is `None` for a `field()` result that hasn't been
+/// assigned to a class attribute yet. `default` and `default_factory` are `None` when
+/// missing (`dataclasses.MISSING`), as is `kw_only` when it defers to the class option.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct DataclassField {
+    pub name: Option<StringId>,
+    pub annotation: Value,
+    pub default: Option<Value>,
+    pub default_factory: Option<Value>,
+    pub init: bool,
+    pub repr: bool,
+    pub compare: bool,
+    pub kw_only: Option<bool>,
+}
+
+impl DataclassField {
+    /// Creates a field without a default, as declared by a bare `x: int` annotation.
+    #[must_use]
+    pub fn new(name: Option<StringId>, annotation: Value) -> Self {
+        Self {
+            name,
+            annotation,
+            default: None,
+            default_factory: None,
+            init: true,
+            repr: true,
+            compare: true,
+            kw_only: None,
+        }
+    }
+
+    /// Returns a copy of this field, taking new references to its values.
+    #[must_use]
+    pub fn clone_with_heap(&self, vm: &VM<'_, impl ResourceTracker>) -> Self {
+        Self {
+            name: self.name,
+            annotation: self.annotation.clone_with_heap(vm),
+            default: self.default.as_ref().map(|v| v.clone_with_heap(vm)),
+            default_factory: self.default_factory.as_ref().map(|v| v.clone_with_heap(vm)),
+            init: self.init,
+            repr: self.repr,
+            compare: self.compare,
+            kw_only: self.kw_only,
+        }
+    }
+
+    /// Returns whether the field is keyword-only, given the class-wide `kw_only` option.
+    #[must_use]
+    pub fn is_kw_only(&self, options: DataclassOptions) -> bool {
+        self.kw_only.unwrap_or(options.kw_only)
+    }
+
+    /// Returns whether the field can be omitted from the `__init__` call.
+    #[must_use]
+    pub fn has_default(&self) -> bool {
+        self.default.is_some() || self.default_factory.is_some()
+    }
+
+    /// Returns the heap references held by this field, for GC traversal.
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        [
+            Some(&self.annotation),
+            self.default.as_ref(),
+            self.default_factory.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, DataclassField> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::DataclassField
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// `Field`s only compare equal to themselves, which `Value::py_eq` checks by identity.
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_hash(&self, self_id: HeapId, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    /// Writes `Field(name='x',type=<class 'int'>,default=...)`, omitting CPython's
+    /// `hash`, `metadata` and `_field_type` entries.
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        let field = self.get(vm.heap);
+        match field.name {
+            Some(name) => write!(f, "Field(name='{}',type=", vm.interns.get_str(name))?,
+            None => f.write_str("Field(name=None,type=")?,
+        }
+        let annotation = field.annotation.clone_with_heap(vm);
+        let default = field.default.as_ref().map(|v| v.clone_with_heap(vm));
+        let default_factory = field.default_factory.as_ref().map(|v| v.clone_with_heap(vm));
+        defer_drop!(annotation, vm);
+        defer_drop!(default, vm);
+        defer_drop!(default_factory, vm);
+        annotation.py_repr_fmt(f, vm, heap_ids)?;
+        for (label, value) in [("default", default), ("default_factory", default_factory)] {
+            write!(f, ",{label}=")?;
+            match value {
+                Some(value) => value.py_repr_fmt(f, vm, heap_ids)?,
+                None => Marker(StaticStrings::Missing).py_repr_fmt(f)?,
+            }
+        }
+        let field = self.get(vm.heap);
+        write!(
+            f,
+            ",init={},repr={},compare={},kw_only=",
+            py_bool_str(field.init),
+            py_bool_str(field.repr),
+            py_bool_str(field.compare)
+        )?;
+        match field.kw_only {
+            Some(kw_only) => f.write_str(py_bool_str(kw_only))?,
+            None => Marker(StaticStrings::Missing).py_repr_fmt(f)?,
+        }
+        f.write_char(')')?;
+        Ok(())
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let field = self.get(vm.heap);
+        let missing = || Value::Marker(Marker(StaticStrings::Missing));
+        let value = match attr.as_str(vm.interns) {
+            "name" => field.name.map_or(Value::None, Value::InternString),
+            "type" => field.annotation.clone_with_heap(vm),
+            "default" => field.default.as_ref().map_or_else(missing, |v| v.clone_with_heap(vm)),
+            "default_factory" => field
+                .default_factory
+                .as_ref()
+                .map_or_else(missing, |v| v.clone_with_heap(vm)),
+            "init" => Value::Bool(field.init),
+            "repr" => Value::Bool(field.repr),
+            "compare" => Value::Bool(field.compare),
+            "kw_only" => field.kw_only.map_or_else(missing, Value::Bool),
+            "hash" => Value::None,
+            "metadata" => Value::Ref(vm.heap.allocate(HeapData::Dict(Dict::new()))?),
+            _ => return Ok(None),
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+}
+
+impl HeapItem for DataclassField {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>()
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.annotation.py_dec_ref_ids(stack);
+        if let Some(default) = &mut self.default {
+            default.py_dec_ref_ids(stack);
+        }
+        if let Some(factory) = &mut self.default_factory {
+            factory.py_dec_ref_ids(stack);
+        }
+    }
+}
+
+/// A class declared in sandboxed code.
+///
+/// Holds the fields in declaration order and the functions defined in the class
+/// body. `options` is `None` until the class has been processed by `dataclass()`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct DataclassType {
+    name: StringId,
+    fields: Vec<DataclassField>,
+    methods: Vec<(StringId, Value)>,
+    options: Option<DataclassOptions>,
+}
+
+impl DataclassType {
+    /// Returns the interned class name.
+    #[must_use]
+    pub fn name(&self) -> StringId {
+        self.name
+    }
+
+    /// Returns the fields in declaration order.
+    #[must_use]
+    pub fn fields(&self) -> &[DataclassField] {
+        &self.fields
+    }
+
+    /// Returns the `@dataclass` options, or `None` if the class hasn't been decorated.
+    #[must_use]
+    pub fn options(&self) -> Option<DataclassOptions> {
+        self.options
+    }
+
+    /// Records the `@dataclass` options once the fields have been validated.
+    pub fn set_options(&mut self, options: DataclassOptions) {
+        self.options = Some(options);
+    }
+
+    /// Looks up a function defined in the class body by name.
+    #[must_use]
+    pub fn method(&self, name: &str, vm: &VM<'_, impl ResourceTracker>) -> Option<&Value> {
+        self.methods
+            .iter()
+            .find(|(method_name, _)| vm.interns.get_str(*method_name) == name)
+            .map(|(_, function)| function)
+    }
+
+    /// Returns the names of the functions defined in the class body.
+    pub fn method_names(&self) -> impl Iterator<Item = StringId> + '_ {
+        self.methods.iter().map(|(name, _)| *name)
+    }
+
+    /// Returns the index of the field with the given name.
+    #[must_use]
+    pub fn field_index(&self, name: &str, vm: &VM<'_, impl ResourceTracker>) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|field| field.name.is_some_and(|id| vm.interns.get_str(id) == name))
+    }
+
+    /// Returns the names of the class attributes: fields with defaults, then the
+    /// functions defined in the class body.
+    pub fn attr_names(&self) -> impl Iterator<Item = StringId> + '_ {
+        self.fields
+            .iter()
+            .filter(|field| field.default.is_some())
+            .filter_map(|field| field.name)
+            .chain(self.methods.iter().map(|(name, _)| *name))
+    }
+
+    /// Returns `(name, annotation)` pairs for building the `__annotations__` dict.
+    pub fn annotation_pairs(&self, vm: &VM<'_, impl ResourceTracker>) -> Vec<(Value, Value)> {
+        self.fields
+            .iter()
+            .filter_map(|field| Some((Value::InternString(field.name?), field.annotation.clone_with_heap(vm))))
+            .collect()
+    }
+
+    /// Returns the heap references held by this class, for GC traversal.
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.fields
+            .iter()
+            .flat_map(DataclassField::values)
+            .chain(self.methods.iter().map(|(_, function)| function))
+    }
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, DataclassType> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::Type
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// Classes only compare equal to themselves, which `Value::py_eq` checks by identity.
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_hash(&self, self_id: HeapId, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        let name = vm.interns.get_str(self.get(vm.heap).name);
+        Ok(write!(f, "<class '__main__.{name}'>")?)
+    }
+
+    /// Calls a function from the class body without binding it, so `P.method(p)` works.
+    fn py_call_attr(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        let interns = vm.interns;
+        let class = self.get(vm.heap);
+        let attr_name = attr.as_str(interns);
+        let class_name = interns.get_str(class.name);
+        let Some(method) = class.method(attr_name, vm).map(|m| m.clone_with_heap(vm)) else {
+            args.drop_with_heap(vm);
+            return Err(attribute_error_class(class_name, attr_name));
+        };
+        defer_drop!(method, vm);
+        vm.call_function(method, args)
+    }
+
+    /// Class attributes: `__name__`, `__qualname__`, `__annotations__`, field defaults
+    /// and the functions defined in the class body.
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let class = self.get(vm.heap);
+        let value = match attr.static_string() {
+            Some(StaticStrings::DunderName | StaticStrings::DunderQualname) => Value::InternString(class.name),
+            Some(StaticStrings::DunderAnnotations) => {
+                let annotations = Dict::from_pairs(class.annotation_pairs(vm), vm)?;
+                Value::Ref(vm.heap.allocate(HeapData::Dict(annotations))?)
+            }
+            _ => {
+                let attr_name = attr.as_str(vm.interns);
+                let default = class
+                    .field_index(attr_name, vm)
+                    .and_then(|index| class.fields[index].default.as_ref());
+                match default.or_else(|| class.method(attr_name, vm)) {
+                    Some(value) => value.clone_with_heap(vm),
+                    None => return Err(attribute_error_class(vm.interns.get_str(class.name), attr_name)),
+                }
+            }
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+}
+
+impl HeapItem for DataclassType {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>()
+            + self.fields.len() * mem::size_of::<DataclassField>()
+            + self.methods.len() * mem::size_of::<(StringId, Value)>()
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        for field in &mut self.fields {
+            field.py_dec_ref_ids(stack);
+        }
+        for (_, function) in &mut self.methods {
+            function.py_dec_ref_ids(stack);
+        }
+    }
+}
+
+/// Builds an unprocessed class from the values `Opcode::BuildClass` popped off the stack.
+///
+/// `field_values` holds `(name, annotation, default)` triples and `method_values`
+/// `(name, function)` pairs. A default of `Value::Undefined` means the field has none;
+/// a `dataclasses.Field` default supplies the field's settings.
+pub(crate) fn build_class(
+    name: StringId,
+    field_values: Vec<Value>,
+    method_values: Vec<Value>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let mut fields = Vec::with_capacity(field_values.len() / 3);
+    let mut field_values = field_values.into_iter();
+    while let (Some(field_name), Some(annotation), Some(default)) =
+        (field_values.next(), field_values.next(), field_values.next())
+    {
+        let Value::InternString(field_name) = field_name else {
+            return Err(RunError::internal("BuildClass: expected an interned field name"));
+        };
+        let spec = match &default {
+            Value::Ref(id) => match vm.heap.get(*id) {
+                HeapData::DataclassField(spec) => Some(spec.clone_with_heap(vm)),
+                _ => None,
+            },
+            _ => None,
+        };
+        let mut field = if let Some(mut field) = spec {
+            default.drop_with_heap(vm);
+            mem::replace(&mut field.annotation, annotation).drop_with_heap(vm);
+            field
+        } else {
+            let mut field = DataclassField::new(Some(field_name), annotation);
+            if !matches!(default, Value::Undefined) {
+                field.default = Some(default);
+            }
+            field
+        };
+        field.name = Some(field_name);
+        fields.push(field);
+    }
+
+    let mut methods = Vec::with_capacity(method_values.len() / 2);
+    let mut method_values = method_values.into_iter();
+    while let (Some(method_name), Some(function)) = (method_values.next(), method_values.next()) {
+        let Value::InternString(method_name) = method_name else {
+            return Err(RunError::internal("BuildClass: expected an interned method name"));
+        };
+        methods.push((method_name, function));
+    }
+
+    let class = DataclassType {
+        name,
+        fields,
+        methods,
+        options: None,
+    };
+    Ok(Value::Ref(vm.heap.allocate(HeapData::DataclassType(class))?))
+}
+
+/// Creates an instance of a sandbox-declared class, running the generated `__init__`.
+///
+/// Binds the arguments to the `init` fields (keyword-only fields must be passed by
+/// keyword), fills in defaults and `default_factory` results, then calls
+/// `__post_init__` if the class defines one.
+pub(crate) fn instantiate(
+    class_id: HeapId,
+    args: ArgValues,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let HeapData::DataclassType(class) = vm.heap.get(class_id) else {
+        args.drop_with_heap(vm);
+        return Err(RunError::internal("instantiate: expected a class"));
+    };
+    let interns = vm.interns;
+    let class_name = interns.get_str(class.name);
+    let Some(options) = class.options else {
+        args.drop_with_heap(vm);
+        return Err(ExcType::not_implemented("classes without the @dataclass decorator").into());
+    };
+    let field_count = class.fields.len();
+
+    let mut values: Vec<Option<Value>> = (0..field_count).map(|_| None).collect();
+    if let Err(err) = bind_init_args(class_id, options, args, &mut values, vm) {
+        values.drop_with_heap(vm);
+        return Err(err);
+    }
+
+    // Fill in defaults in declaration order, calling factories as we go.
+    for index in 0..field_count {
+        if values[index].is_some() {
+            continue;
+        }
+        let HeapData::DataclassType(class) = vm.heap.get(class_id) else {
+            unreachable!("class type changed during instantiation")
+        };
+        let field = &class.fields[index];
+        if let Some(default) = &field.default {
+            values[index] = Some(default.clone_with_heap(vm));
+        } else if let Some(factory) = &field.default_factory {
+            let factory = factory.clone_with_heap(vm);
+            let result = vm.evaluate_function("default_factory", &factory, ArgValues::Empty);
+            factory.drop_with_heap(vm);
+            match result {
+                Ok(value) => values[index] = Some(value),
+                Err(err) => {
+                    values.drop_with_heap(vm);
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    let HeapData::DataclassType(class) = vm.heap.get(class_id) else {
+        unreachable!("class type changed during instantiation")
+    };
+    let field_names: Vec<String> = class
+        .fields
+        .iter()
+        .filter_map(|field| field.name.map(|id| interns.get_str(id).to_owned()))
+        .collect();
+    let pairs: Vec<(Value, Value)> = class
+        .fields
+        .iter()
+        .zip(values)
+        .filter_map(|(field, value)| Some((Value::InternString(field.name?), value?)))
+        .collect();
+    let post_init = class
+        .method("__post_init__", vm)
+        .map(|function| function.clone_with_heap(vm));
+    defer_drop!(post_init, vm);
+
+    let attrs = Dict::from_pairs(pairs, vm)?;
+    vm.heap.inc_ref(class_id);
+    let instance = Dataclass::new(class_name.to_owned(), 0, field_names, attrs, options.frozen).with_class(class_id);
+    let instance = Value::Ref(vm.heap.allocate(HeapData::Dataclass(instance))?);
+
+    if let Some(post_init) = post_init {
+        let args = ArgValues::One(instance.clone_with_heap(vm));
+        match vm.evaluate_function("__post_init__", post_init, args) {
+            Ok(result) => result.drop_with_heap(vm),
+            Err(err) => {
+                instance.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+    }
+    Ok(instance)
+}
+
+/// Binds the arguments of a generated `__init__` call into `values`, one slot per field.
+///
+/// Reports errors with CPython's `P.__init__()` messages. Consumes `args`; on error the
+/// caller must drop whatever was already bound.
+fn bind_init_args(
+    class_id: HeapId,
+    options: DataclassOptions,
+    args: ArgValues,
+    values: &mut [Option<Value>],
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<()> {
+    let HeapData::DataclassType(class) = vm.heap.get(class_id) else {
+        args.drop_with_heap(vm);
+        return Err(RunError::internal("bind_init_args: expected a class"));
+    };
+    let interns = vm.interns;
+    let class_name = interns.get_str(class.name);
+    if !options.init {
+        if args.count() > 0 {
+            args.drop_with_heap(vm);
+            return Err(ExcType::type_error(format!("{class_name}() takes no arguments")));
+        }
+        return Ok(());
+    }
+    let func_name = format!("{class_name}.__init__");
+
+    // Positional parameters are the non-keyword-only `init` fields, in declaration order.
+    let positional: Vec<usize> = (0..class.fields.len())
+        .filter(|&i| class.fields[i].init && !class.fields[i].is_kw_only(options))
+        .collect();
+    let (pos_args, kwargs) = args.into_parts();
+    if pos_args.len() > positional.len() {
+        let required = positional.iter().filter(|&&i| !class.fields[i].has_default()).count();
+        let given = pos_args.len() + 1;
+        pos_args.drop_with_heap(vm);
+        kwargs.drop_with_heap(vm);
+        return Err(too_many_positional(
+            &func_name,
+            required + 1,
+            positional.len() + 1,
+            given,
+        ));
+    }
+    for (value, &index) in pos_args.zip(&positional) {
+        values[index] = Some(value);
+    }
+
+    bind_init_kwargs(class_id, &func_name, kwargs, values, vm)?;
+
+    let HeapData::DataclassType(class) = vm.heap.get(class_id) else {
+        return Err(RunError::internal("bind_init_args: expected a class"));
+    };
+    let missing = |kw_only: bool| {
+        class
+            .fields
+            .iter()
+            .zip(values.iter())
+            .filter(|(field, value)| {
+                field.init && value.is_none() && !field.has_default() && field.is_kw_only(options) == kw_only
+            })
+            .filter_map(|(field, _)| field.name.map(|id| interns.get_str(id)))
+            .collect::<Vec<_>>()
+    };
+    let missing_positional = missing(false);
+    if !missing_positional.is_empty() {
+        return Err(ExcType::type_error_missing_positional_with_names(
+            &func_name,
+            &missing_positional,
+        ));
+    }
+    let missing_kw_only = missing(true);
+    if !missing_kw_only.is_empty() {
+        return Err(ExcType::type_error_missing_kwonly_with_names(
+            &func_name,
+            &missing_kw_only,
+        ));
+    }
+    Ok(())
+}
+
+/// Binds keyword arguments to `init` fields by name.
+fn bind_init_kwargs(
+    class_id: HeapId,
+    func_name: &str,
+    kwargs: KwargsValues,
+    values: &mut [Option<Value>],
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<()> {
+    let mut kwargs = kwargs.into_iter();
+    while let Some((key, value)) = kwargs.next() {
+        let index = match key.as_either_str(vm.heap) {
+            Some(key_str) => {
+                let key_str = key_str.as_str(vm.interns);
+                let HeapData::DataclassType(class) = vm.heap.get(class_id) else {
+                    unreachable!("class type changed during instantiation")
+                };
+                match class.field_index(key_str, vm) {
+                    Some(index) if class.fields[index].init => {
+                        if values[index].is_some() {
+                            Err(ExcType::type_error_duplicate_arg(func_name, key_str))
+                        } else {
+                            Ok(index)
+                        }
+                    }
+                    _ => Err(ExcType::type_error_unexpected_keyword(func_name, key_str)),
+                }
+            }
+            None => Err(ExcType::type_error(format!("{func_name}() keywords must be strings"))),
+        };
+        key.drop_with_heap(vm);
+        match index {
+            Ok(index) => values[index] = Some(value),
+            Err(err) => {
+                value.drop_with_heap(vm);
+                kwargs.drop_with_heap(vm);
+                return Err(err);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Creates the `TypeError` for too many positional arguments, counting `self`.
+///
+/// Matches CPython: `P.__init__() takes from 2 to 3 positional arguments but 4 were given`
+/// when some parameters have defaults.
+fn too_many_positional(func_name: &str, min: usize, max: usize, given: usize) -> RunError {
+    if min == max {
+        ExcType::type_error_too_many_positional(func_name, max, given, 0)
+    } else {
+        ExcType::type_error(format!(
+            "{func_name}() takes from {min} to {max} positional arguments but {given} were given"
+        ))
+    }
+}
+
+/// Creates the `AttributeError` for a missing class attribute.
+///
+/// Matches CPython's format: `type object 'P' has no attribute 'z'`.
+pub(crate) fn attribute_error_class(class_name: &str, attr: &str) -> RunError {
+    SimpleException::new_msg(
+        ExcType::AttributeError,
+        format!("type object '{class_name}' has no attribute '{attr}'"),
+    )
+    .into()
+}
+
+/// Python spelling of a bool, for reprs.
+fn py_bool_str(b: bool) -> &'static str {
+    if b { "True" } else { "False" }
+}
@@ -46,10 +46,11 @@
 //!   rejected at parse time because Monty has no read-position tracking;
 //!   without it a write after a read would silently truncate the file via
 //!   the one-shot OS write.
-//! - The `encoding`, `errors`, and `newline` arguments to `open()` are
-//!   accepted only at their CPython defaults (with `encoding="utf-8"` as
-//!   a documented no-op). Text I/O is whole-file UTF-8 with no error
-//!   handlers or newline translation.
+//! - `newline` is accepted only at its CPython default, so there is no
+//!   newline translation. `encoding` and `errors` go through
+//!   [`crate::codecs`]: without them, text I/O is UTF-8 decoded by the host
+//!   (`ReadText`/`WriteText`); otherwise Monty reads and writes bytes and
+//!   decodes/encodes them itself.
 //! - Bytes paths are decoded as UTF-8 instead of using CPython's
 //!   `os.fsdecode` / filesystem-encoding behavior.
 //! - `tell()` in text mode returns a char index, not CPython's opaque byte
@@ -68,14 +69,15 @@ use ahash::AHashSet;
 
 use super::{
     List, PyTrait, Type,
-    bytes::Bytes,
+    bytes::{Bytes, bytes_like_contents},
     str::{allocate_string, allocate_string_no_interning},
 };
 use crate::{
     args::ArgValues,
     bytecode::{CallResult, VM},
+    codecs::Codec,
     exception_private::{ExcType, RunError, RunResult, SimpleException},
-    heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId, HeapItem, HeapRead, HeapReadOutput},
+    heap::{ContainsHeap, DropWithHeap, Heap, HeapData, HeapGuard, HeapId, HeapItem, HeapRead, HeapReadOutput},
     intern::StaticStrings,
     os::{MontyPath, OsFunctionCall, PathBytesDataArgs, PathStringDataArgs},
     resource::ResourceTracker,
@@ -116,7 +118,7 @@ pub(crate) enum ReadSpec {
 /// writes need to update [`OpenFile`] state only after the host reports a
 /// successful OS operation. Keeping them in one enum avoids adding another VM
 /// hook while preserving retry-safe exception behavior.
-#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub(crate) enum PendingFileEffect {
     /// Store a full-file read result into the file buffer, then compute the
     /// pending read/seek slice.
@@ -130,7 +132,29 @@ pub(crate) enum PendingFileEffect {
         previous_position: u64,
         /// Known file length before dispatch, restored on host exception.
         previous_length: u64,
+        /// Chars written by a text write that Monty encoded itself. The host
+        /// reports the bytes it wrote, but `write()` returns the char count.
+        chars_written: Option<u64>,
+        /// Set when the write was made by `print(..., file=f)`, which returns
+        /// `None` rather than the count.
+        #[serde(default)]
+        returns_none: bool,
     },
+    /// Attach the `encoding` / `errors` passed to `open()` to the file the
+    /// host returns.
+    OpenText {
+        encoding: Option<String>,
+        errors: Option<String>,
+    },
+}
+
+impl DropWithHeap for PendingFileEffect {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        match self {
+            Self::BufferStore { file_id } | Self::WritePosition { file_id, .. } => heap.heap_mut().dec_ref(file_id),
+            Self::OpenText { .. } => {}
+        }
+    }
 }
 
 /// A parsed Python `open()` mode.
@@ -398,6 +422,13 @@ pub(crate) struct OpenFile {
     /// Tells the resume path which slice to compute once the buffer is
     /// populated. Cleared by the post-resume hook (or by exception cleanup).
     pending_read: Option<ReadSpec>,
+    /// The `encoding` passed to `open()` in text mode, already validated by
+    /// [`Codec::lookup`]. `None` means UTF-8.
+    #[serde(default)]
+    encoding: Option<String>,
+    /// The `errors` passed to `open()` in text mode. `None` means `strict`.
+    #[serde(default)]
+    errors: Option<String>,
     /// Tracks whether we have reached EOF on this file. Separate from the
     /// buffer's existence so bare `read()` (which today never populates the
     /// buffer) can also flag EOF without forcing a load.
@@ -459,9 +490,40 @@ impl OpenFile {
             pending_read: None,
             eof: false,
             file_length: position,
+            encoding: None,
+            errors: None,
+        }
+    }
+
+    /// Sets the text `encoding` / `errors` passed to `open()`.
+    pub(crate) fn set_text_encoding(&mut self, encoding: Option<String>, errors: Option<String>) {
+        self.encoding = encoding;
+        self.errors = errors;
+    }
+
+    /// Returns the codec Monty applies to this text file's content, or `None`
+    /// when the host's UTF-8 `ReadText` / `WriteText` calls are enough.
+    fn text_codec(&self) -> Option<Codec> {
+        if self.mode.is_binary() {
+            return None;
+        }
+        let codec = self
+            .encoding
+            .as_deref()
+            .map_or(Codec::Utf8, |name| Codec::lookup(name).unwrap_or(Codec::Utf8));
+        let strict = self.errors.as_deref().is_none_or(|errors| errors == "strict");
+        if codec == Codec::Utf8 && strict {
+            None
+        } else {
+            Some(codec)
         }
     }
 
+    /// The `errors` handler name for [`Self::text_codec`].
+    fn errors(&self) -> &str {
+        self.errors.as_deref().unwrap_or("strict")
+    }
+
     /// Returns the virtual path used for OS calls.
     #[must_use]
     pub fn path(&self) -> &str {
@@ -495,7 +557,10 @@ impl OpenFile {
 
 impl HeapItem for OpenFile {
     fn py_estimate_size(&self) -> usize {
-        mem::size_of::<Self>() + self.path.len()
+        mem::size_of::<Self>()
+            + self.path.len()
+            + self.encoding.as_ref().map_or(0, String::len)
+            + self.errors.as_ref().map_or(0, String::len)
     }
 
     fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
@@ -615,7 +680,10 @@ impl<'h> PyTrait<'h> for HeapRead<'h, OpenFile> {
             StaticStrings::Name => allocate_string(file.path.clone(), vm.heap)?,
             StaticStrings::Mode => allocate_string(file.mode.as_str().to_owned(), vm.heap)?,
             StaticStrings::Closed => Value::Bool(file.closed),
-            StaticStrings::Encoding if !file.mode.is_binary() => allocate_string("utf-8", vm.heap)?,
+            StaticStrings::Encoding if !file.mode.is_binary() => {
+                allocate_string(file.encoding.clone().unwrap_or_else(|| "utf-8".to_owned()), vm.heap)?
+            }
+            StaticStrings::Errors if !file.mode.is_binary() => allocate_string(file.errors().to_owned(), vm.heap)?,
             _ => return Err(ExcType::attribute_error(self.py_type(vm), attr.as_str(vm.interns))),
         };
         Ok(Some(CallResult::Value(value)))
@@ -767,7 +835,9 @@ impl<'h> HeapRead<'h, OpenFile> {
         // separately routes the result into the file's `buffer` slot, so we
         // only need the path here, not a file-handle reference.
         let path = MontyPath::new(self.get(vm.heap).path().to_owned());
-        let call = if binary {
+        // Files with a non-default codec are read as bytes and decoded in
+        // `apply_buffer_store`.
+        let call = if binary || self.get(vm.heap).text_codec().is_some() {
             OsFunctionCall::ReadBytes(path)
         } else {
             OsFunctionCall::ReadText(path)
@@ -796,26 +866,40 @@ impl<'h> HeapRead<'h, OpenFile> {
             data.drop_with_heap(vm);
             return Err(err);
         }
-        let (path, append, binary) = {
-            let file = self.get_mut(vm.heap);
+        let (path, append, binary, codec) = {
+            let file = self.get(vm.heap);
             if !file.mode.writable() {
                 let message = if file.mode.is_binary() { "write" } else { "not writable" };
                 data.drop_with_heap(vm);
                 return Err(unsupported_operation(message));
             }
             let append = file.mode.is_append() || file.first_write_done;
-            let binary = file.mode.is_binary();
-            let path = file.path().to_owned();
-            file.first_write_done = true;
-            (path, append, binary)
+            // Only text written at the start of the file gets a UTF-16/32 BOM.
+            let codec = file
+                .text_codec()
+                .map(|codec| if file.position == 0 { codec } else { codec.without_bom() });
+            (file.path().to_owned(), append, file.mode.is_binary(), codec)
         };
 
         // Extract the data payload into an owned `String` / `Vec<u8>` so the
         // OS-call args struct can own it across the snapshot/resume boundary.
         // `validate_write_data` already gated the variant — `binary` selects
-        // bytes vs str.
+        // bytes vs str. A text file with its own codec is written as the
+        // encoded bytes.
         let path = MontyPath::new(path);
-        let call = if binary {
+        let mut chars_written = None;
+        let call = if let Some(codec) = codec {
+            let text = extract_str_payload(&data, vm).expect("validate_write_data accepted a str-shaped value");
+            data.drop_with_heap(vm);
+            let encoded = codec.encode(&text, self.get(vm.heap).errors(), vm.heap.tracker())?;
+            chars_written = Some(text.chars().count() as u64);
+            let args = PathBytesDataArgs { path, data: encoded };
+            if append {
+                OsFunctionCall::AppendBytes(args)
+            } else {
+                OsFunctionCall::WriteBytes(args)
+            }
+        } else if binary {
             let bytes = extract_bytes_payload(&data, vm).expect("validate_write_data accepted a bytes-shaped value");
             data.drop_with_heap(vm);
             let args = PathBytesDataArgs { path, data: bytes };
@@ -835,11 +919,14 @@ impl<'h> HeapRead<'h, OpenFile> {
             }
         };
 
+        self.get_mut(vm.heap).first_write_done = true;
         inc_ref_for_pending_oscall(vm, self_id);
         vm.pending_file_effect = Some(PendingFileEffect::WritePosition {
             file_id: self_id,
             previous_position: self.get(vm.heap).position,
             previous_length: self.get(vm.heap).file_length,
+            chars_written,
+            returns_none: false,
         });
         Ok(CallResult::OsCall(call))
     }
@@ -1005,6 +1092,43 @@ fn os_read_result_to_heap_id(result: Value, vm: &mut VM<'_, impl ResourceTracker
     Ok(id)
 }
 
+/// Decodes the bytes read for a text file opened with a non-default
+/// `encoding` / `errors`, returning the id of the decoded `str`.
+///
+/// Files without their own codec were read with `ReadText`, so `result_id`
+/// is returned unchanged. Consumes the caller's reference to `result_id` on
+/// every path.
+fn decode_text_buffer(file_id: HeapId, result_id: HeapId, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<HeapId> {
+    let HeapReadOutput::OpenFile(file) = vm.heap.read(file_id) else {
+        vm.heap.dec_ref(result_id);
+        return Err(RunError::internal(
+            "decode_text_buffer: file_id does not point to an OpenFile",
+        ));
+    };
+    let f = file.get(vm.heap);
+    let Some(codec) = f.text_codec() else {
+        return Ok(result_id);
+    };
+    let errors = f.errors().to_owned();
+    drop(file);
+
+    let HeapReadOutput::Bytes(bytes) = vm.heap.read(result_id) else {
+        vm.heap.dec_ref(result_id);
+        return Err(RunError::internal("decode_text_buffer: OS result must be bytes"));
+    };
+    let decoded = codec.decode(bytes.get(vm.heap).as_slice(), &errors, vm.heap.tracker());
+    drop(bytes);
+    vm.heap.dec_ref(result_id);
+    let value = allocate_string_no_interning(decoded?, vm.heap)?;
+    let Value::Ref(id) = &value else {
+        unreachable!("allocate_string_no_interning returns Value::Ref");
+    };
+    let id = *id;
+    vm.heap.inc_ref(id);
+    value.drop_with_heap(vm);
+    Ok(id)
+}
+
 /// Stores the OS-returned full-file content into an [`OpenFile`]'s buffer and
 /// computes the slice that the originating call (`read(N)` / `readline()` /
 /// `readlines()` / `seek()`) should return.
@@ -1062,6 +1186,7 @@ pub(crate) fn apply_buffer_store(
     // no longer needed — the refcount now lives on `result_id`.
     let (_, vm) = pin.as_parts_mut();
     let result_id = os_read_result_to_heap_id(result, vm)?;
+    let result_id = decode_text_buffer(file_id, result_id, vm)?;
 
     // Stage 3: install the buffer. Defensive: if it was already populated
     // (e.g. a snapshot/restore race), drop the new content and slice from
@@ -1097,18 +1222,40 @@ pub(crate) fn apply_buffer_store(
     // `pin` drops here, releasing the pending-file-effect refcount.
 }
 
+/// Attaches the `encoding` / `errors` passed to `open()` to the file the host
+/// returned, then returns that file to Python.
+///
+/// A host that answers `open()` with something other than a file handle is
+/// left alone; the value is passed through unchanged.
+pub(crate) fn apply_open_text(
+    value: Value,
+    encoding: Option<String>,
+    errors: Option<String>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    if let Value::Ref(id) = &value
+        && let HeapReadOutput::OpenFile(mut file) = vm.heap.read(*id)
+    {
+        file.get_mut(vm.heap).set_text_encoding(encoding, errors);
+    }
+    Ok(value)
+}
+
 /// Applies a successful host write result to an [`OpenFile`]'s logical
 /// position, then returns that same result to Python.
 ///
 /// The host write result is expected to be the number of user-visible units
 /// written: chars for text files, bytes for binary files. That matches the
 /// values returned by Monty's filesystem backends and CPython's `write()`.
+/// Text that Monty encoded itself was sent as bytes, so `chars_written`
+/// overrides the host's count and becomes the returned value.
 ///
 /// As with [`apply_buffer_store`], the pending-file-effect pin on `file_id`
 /// is released via the RAII [`HeapGuard`] regardless of which path the
 /// function takes.
 pub(crate) fn apply_write_position(
     file_id: HeapId,
+    chars_written: Option<u64>,
     result: Value,
     vm: &mut VM<'_, impl ResourceTracker>,
 ) -> RunResult<Value> {
@@ -1124,6 +1271,8 @@ pub(crate) fn apply_write_position(
         ));
     }
     let written = u64::try_from(written).map_err(|_| ExcType::overflow_c_ssize_t())?;
+    // Text encoded by Monty reports the host's byte count; `write()` returns chars.
+    let written = chars_written.unwrap_or(written);
 
     let HeapReadOutput::OpenFile(mut file) = vm.heap.read(file_id) else {
         return Err(RunError::internal(
@@ -1140,6 +1289,10 @@ pub(crate) fn apply_write_position(
     f.eof = new_position >= f.file_length;
     drop(file);
 
+    if chars_written.is_some() {
+        // `written` came from `chars_written`, which is at most `str` length.
+        return Ok(Value::Int(i64::try_from(written).expect("char count fits in i64")));
+    }
     Ok(result_guard.into_inner())
     // `pin` drops here, releasing the pending-file-effect refcount.
 }
@@ -1432,7 +1585,7 @@ fn resolve_seek_target(offset: i64, whence: i64, position: i64, buffer_len: i64)
 
 /// Helper: returns the byte index of the `nth` character in `s`, or `s.len()`
 /// when `nth >= s.chars().count()`.
-fn nth_char_byte_offset(s: &str, nth: usize) -> usize {
+pub(super) fn nth_char_byte_offset(s: &str, nth: usize) -> usize {
     s.char_indices().nth(nth).map_or(s.len(), |(i, _)| i)
 }
 
@@ -1560,7 +1713,10 @@ fn parse_seek_args(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> Ru
 /// CPython accepts `None` as "read all" and treats `bool` as an integer for
 /// this argument. Heap-backed integer arguments are explicitly dropped after
 /// conversion because `get_zero_one_arg` transfers ownership to the caller.
-fn parse_read_size_arg(size_arg: Option<Value>, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<ReadSpec> {
+pub(super) fn parse_read_size_arg(
+    size_arg: Option<Value>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<ReadSpec> {
     let Some(size) = size_arg else {
         return Ok(ReadSpec::All);
     };
@@ -1618,7 +1774,7 @@ fn validate_write_data(data: &Value, binary: bool, vm: &VM<'_, impl ResourceTrac
 
 /// Owned `String` from a value pre-validated as a Python `str` (returns
 /// `None` only if `validate_write_data` was bypassed — caller unwraps).
-fn extract_str_payload(data: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<String> {
+pub(super) fn extract_str_payload(data: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<String> {
     match data {
         Value::InternString(id) => Some(vm.interns.get_str(*id).to_owned()),
         Value::Ref(id) => match vm.heap.get(*id) {
@@ -1629,24 +1785,20 @@ fn extract_str_payload(data: &Value, vm: &VM<'_, impl ResourceTracker>) -> Optio
     }
 }
 
-/// Owned `Vec<u8>` from a value pre-validated as Python `bytes` — binary
-/// companion to [`extract_str_payload`].
-fn extract_bytes_payload(data: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<Vec<u8>> {
-    match data {
-        Value::InternBytes(id) => Some(vm.interns.get_bytes(*id).to_owned()),
-        Value::Ref(id) => match vm.heap.get(*id) {
-            HeapData::Bytes(b) => Some(b.as_slice().to_owned()),
-            _ => None,
-        },
-        _ => None,
-    }
+/// Owned `Vec<u8>` from a value pre-validated as bytes-like (`bytes`,
+/// `bytearray` or `memoryview`) — binary companion to [`extract_str_payload`].
+pub(super) fn extract_bytes_payload(data: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<Vec<u8>> {
+    bytes_like_contents(data, vm.heap, vm.interns).map(Cow::into_owned)
 }
 
-/// Returns whether a value is a Python `bytes` object.
-fn is_bytes(data: &Value, heap: &Heap<impl ResourceTracker>) -> bool {
+/// Returns whether a value is bytes-like: `bytes`, `bytearray` or `memoryview`.
+pub(super) fn is_bytes(data: &Value, heap: &Heap<impl ResourceTracker>) -> bool {
     match data {
         Value::InternBytes(_) => true,
-        Value::Ref(id) => matches!(heap.get(*id), HeapData::Bytes(_)),
+        Value::Ref(id) => matches!(
+            heap.get(*id),
+            HeapData::Bytes(_) | HeapData::ByteArray(_) | HeapData::MemoryView(_)
+        ),
         _ => false,
     }
 }
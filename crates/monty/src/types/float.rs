@@ -0,0 +1,268 @@
+//! Methods and attributes of Python `float` values.
+//!
+//! Floats are always stored inline as `Value::Float`, so these helpers take the
+//! raw `f64` rather than a heap reference.
+
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use smallvec::smallvec;
+
+use super::{LongInt, Type, allocate_tuple, str::allocate_string};
+use crate::{
+    args::ArgValues,
+    bytecode::VM,
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    intern::StaticStrings,
+    resource::ResourceTracker,
+    value::{EitherStr, Value},
+};
+
+/// Number of explicitly stored mantissa bits in an `f64`.
+const MANTISSA_BITS: u32 = 52;
+
+/// Calls a `float` method such as `is_integer()` or `hex()`.
+pub(crate) fn call_float_method(
+    f: f64,
+    attr: &EitherStr,
+    args: ArgValues,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let Some(method) = attr.static_string() else {
+        args.drop_with_heap(vm);
+        return Err(ExcType::attribute_error(Type::Float, attr.as_str(vm.interns)));
+    };
+    match method {
+        StaticStrings::IsInteger => {
+            args.check_zero_args("float.is_integer", vm.heap)?;
+            Ok(Value::Bool(f.is_finite() && f.fract() == 0.0))
+        }
+        StaticStrings::AsIntegerRatio => {
+            args.check_zero_args("float.as_integer_ratio", vm.heap)?;
+            let (numerator, denominator) = float_as_integer_ratio(f)?;
+            let numerator = LongInt::new(numerator).into_value(vm.heap)?;
+            let denominator = LongInt::new(denominator).into_value(vm.heap)?;
+            Ok(allocate_tuple(smallvec![numerator, denominator], vm.heap)?)
+        }
+        StaticStrings::Hex => {
+            args.check_zero_args("float.hex", vm.heap)?;
+            Ok(allocate_string(float_hex(f), vm.heap)?)
+        }
+        StaticStrings::Conjugate => {
+            args.check_zero_args("float.conjugate", vm.heap)?;
+            Ok(Value::Float(f))
+        }
+        _ => {
+            args.drop_with_heap(vm);
+            Err(ExcType::attribute_error(Type::Float, attr.as_str(vm.interns)))
+        }
+    }
+}
+
+/// Looks up a data attribute of a `float`: `real` or `imag`.
+///
+/// Returns `None` for any other name so the caller can raise `AttributeError`.
+pub(crate) fn float_getattr(f: f64, attr: &EitherStr) -> Option<Value> {
+    match attr.static_string()? {
+        StaticStrings::Real => Some(Value::Float(f)),
+        StaticStrings::Imag => Some(Value::Float(0.0)),
+        _ => None,
+    }
+}
+
+/// Implements the `float.fromhex()` class method.
+pub(crate) fn float_fromhex(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let value = args.get_one_arg("float.fromhex", vm.heap)?;
+    defer_drop!(value, vm);
+    let Some(s) = value.as_either_str(vm.heap) else {
+        return Err(ExcType::type_error("bad argument type for built-in operation"));
+    };
+    parse_hex_float(s.as_str(vm.interns)).map(Value::Float)
+}
+
+/// Returns the exact `(numerator, denominator)` of a finite float, in lowest terms.
+fn float_as_integer_ratio(f: f64) -> RunResult<(BigInt, BigInt)> {
+    if f.is_nan() {
+        return Err(SimpleException::new_msg(ExcType::ValueError, "cannot convert NaN to integer ratio").into());
+    }
+    if f.is_infinite() {
+        return Err(
+            SimpleException::new_msg(ExcType::OverflowError, "cannot convert Infinity to integer ratio").into(),
+        );
+    }
+    if f == 0.0 {
+        return Ok((BigInt::zero(), BigInt::one()));
+    }
+    let (mantissa, exponent) = decompose(f.abs());
+    // Strip trailing zero bits so the ratio is already reduced.
+    let shift = mantissa.trailing_zeros();
+    let mantissa = mantissa >> shift;
+    let exponent = exponent + i64::from(shift);
+    let mut numerator = BigInt::from(mantissa);
+    let mut denominator = BigInt::one();
+    if exponent >= 0 {
+        numerator <<= exponent;
+    } else {
+        denominator <<= -exponent;
+    }
+    if f < 0.0 {
+        numerator = -numerator;
+    }
+    Ok((numerator, denominator))
+}
+
+/// Splits a positive finite float into `(mantissa, exponent)` with `f == mantissa * 2**exponent`.
+fn decompose(f: f64) -> (u64, i64) {
+    let bits = f.to_bits();
+    let biased = i64::try_from((bits >> MANTISSA_BITS) & 0x7ff).expect("11-bit exponent fits in i64");
+    let fraction = bits & ((1 << MANTISSA_BITS) - 1);
+    if biased == 0 {
+        // Subnormal: no implicit leading bit.
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << MANTISSA_BITS), biased - 1075)
+    }
+}
+
+/// Formats a float as CPython's `float.hex()` does, e.g. `0x1.8000000000000p+0`.
+fn float_hex(f: f64) -> String {
+    if f.is_nan() {
+        return "nan".to_owned();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { "inf" } else { "-inf" }.to_owned();
+    }
+    let sign = if f.is_sign_negative() { "-" } else { "" };
+    if f == 0.0 {
+        return format!("{sign}0x0.0p+0");
+    }
+    let bits = f.to_bits();
+    let biased = (bits >> MANTISSA_BITS) & 0x7ff;
+    let fraction = bits & ((1 << MANTISSA_BITS) - 1);
+    let (lead, exponent) = if biased == 0 {
+        (0, -1022)
+    } else {
+        (1, i64::try_from(biased).expect("11-bit exponent fits in i64") - 1023)
+    };
+    format!("{sign}0x{lead}.{fraction:013x}p{exponent:+}")
+}
+
+/// Parses CPython's hexadecimal float syntax, e.g. `-0x1.8p3`, `inf` or `0X.Fp-2`.
+///
+/// The result is correctly rounded (round-half-to-even), like `float.fromhex()`.
+fn parse_hex_float(s: &str) -> RunResult<f64> {
+    let invalid = || SimpleException::new_msg(ExcType::ValueError, "invalid hexadecimal floating-point string");
+    let s = s.trim();
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let sign = if negative { -1.0 } else { 1.0 };
+    if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+        return Ok(sign * f64::INFINITY);
+    }
+    if rest.eq_ignore_ascii_case("nan") {
+        return Ok(f64::NAN);
+    }
+    let rest = rest
+        .strip_prefix("0x")
+        .or_else(|| rest.strip_prefix("0X"))
+        .unwrap_or(rest);
+
+    let (digits, exponent_part) = match rest.find(['p', 'P']) {
+        Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+        None => (rest, None),
+    };
+    let (int_digits, frac_digits) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_digits.is_empty() && frac_digits.is_empty() {
+        return Err(invalid().into());
+    }
+    if !int_digits
+        .bytes()
+        .chain(frac_digits.bytes())
+        .all(|b| b.is_ascii_hexdigit())
+    {
+        return Err(invalid().into());
+    }
+    let all_digits = [int_digits, frac_digits].concat();
+    let mantissa = BigInt::parse_bytes(all_digits.as_bytes(), 16).expect("validated hex digits");
+    let mut exponent = match exponent_part {
+        Some(part) => parse_hex_float_exponent(part).ok_or_else(invalid)?,
+        None => 0,
+    };
+    let frac_bits = i64::try_from(frac_digits.len()).unwrap_or(i64::MAX).saturating_mul(4);
+    exponent = exponent.saturating_sub(frac_bits);
+
+    if mantissa.is_zero() {
+        return Ok(sign * 0.0);
+    }
+    round_to_f64(&mantissa, exponent).map(|f| sign * f).ok_or_else(|| {
+        SimpleException::new_msg(
+            ExcType::OverflowError,
+            "hexadecimal value too large to represent as a float",
+        )
+        .into()
+    })
+}
+
+/// Parses the decimal exponent after `p`, saturating absurdly large values.
+fn parse_hex_float_exponent(part: &str) -> Option<i64> {
+    let (negative, digits) = match part.as_bytes().first() {
+        Some(b'-') => (true, &part[1..]),
+        Some(b'+') => (false, &part[1..]),
+        _ => (false, part),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // Anything beyond this is far outside the float range either way.
+    let magnitude = digits.parse::<i64>().unwrap_or(i64::MAX).min(1 << 40);
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Rounds the positive value `mantissa * 2**exponent` to the nearest `f64`.
+///
+/// Returns `None` if the result overflows.
+fn round_to_f64(mantissa: &BigInt, exponent: i64) -> Option<f64> {
+    let len = i64::try_from(mantissa.bits()).unwrap_or(i64::MAX);
+    let top = len.saturating_add(exponent).saturating_sub(1);
+    if top > 1023 {
+        return None;
+    }
+    // Bits of precision available at this magnitude: 53 for normal floats,
+    // fewer (possibly none) once the value drops into the subnormal range.
+    let precision = if top >= -1022 { 53 } else { top + 1075 };
+    let shift = len - precision;
+    let (rounded, scale) = if shift > 0 {
+        let shift = u64::try_from(shift).expect("positive shift");
+        let kept: BigInt = mantissa >> shift;
+        let remainder = mantissa - (&kept << shift);
+        let half = BigInt::one() << (shift - 1);
+        let round_up = remainder > half || (remainder == half && kept.bit(0));
+        let kept = if round_up { kept + 1u32 } else { kept };
+        (kept, exponent.saturating_add(i64::try_from(shift).unwrap_or(i64::MAX)))
+    } else {
+        (mantissa.clone(), exponent)
+    };
+    let rounded_top = i64::try_from(rounded.bits()).unwrap_or(i64::MAX) + scale - 1;
+    if rounded_top > 1023 {
+        return None;
+    }
+    let rounded: f64 = num_traits::ToPrimitive::to_f64(&rounded).expect("at most 53 significant bits");
+    Some(scale_by_power_of_two(rounded, scale))
+}
+
+/// Computes `x * 2**exponent` exactly, for results known to be representable.
+fn scale_by_power_of_two(mut x: f64, mut exponent: i64) -> f64 {
+    // Step in chunks so no intermediate power of two overflows or underflows.
+    while exponent > 1000 {
+        x *= 2f64.powi(1000);
+        exponent -= 1000;
+    }
+    while exponent < -1000 {
+        x *= 2f64.powi(-1000);
+        exponent += 1000;
+    }
+    x * 2f64.powi(i32::try_from(exponent).expect("exponent within ±1000"))
+}
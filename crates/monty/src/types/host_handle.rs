@@ -0,0 +1,298 @@
+//! Opaque handles to objects that stay on the host.
+//!
+//! A host passes `MontyObject::HostHandle` for a stateful object it doesn't want to
+//! serialize into the sandbox — a database cursor, an HTTP client, an LLM session.
+//! Sandbox code only sees the handle's type name and id: attribute access, item
+//! access, calls, iteration, `len()` and truth tests each pause execution with a
+//! `HandleCall` asking the host to perform the operation on the real object.
+//! Builtins that iterate a handle ask for `iter()` first; those that would have to
+//! iterate or test the truth of a handle midway raise `NotImplementedError`.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use ahash::AHashSet;
+
+use super::{PyTrait, Type};
+use crate::{
+    args::ArgValues,
+    builtins::Builtins,
+    bytecode::{CallResult, VM},
+    exception_private::{ExcType, RunError, RunResult},
+    hash::HashValue,
+    heap::{ContainsHeap, DropWithHeap, HeapData, HeapId, HeapItem, HeapRead},
+    resource::ResourceTracker,
+    value::{EitherStr, Value},
+};
+
+/// A handle to a host object, identified by its type name and host-chosen id.
+///
+/// Two handles with the same type name and id refer to the same host object, so
+/// they compare equal even when the host passed them in separately.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct HostHandle {
+    /// Class name shown by `repr()` and `type()`, e.g. "Cursor".
+    type_name: String,
+    handle_id: u64,
+}
+
+impl HostHandle {
+    pub fn new(type_name: String, handle_id: u64) -> Self {
+        Self { type_name, handle_id }
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    pub fn handle_id(&self) -> u64 {
+        self.handle_id
+    }
+
+    /// Returns the handle `value` refers to, if it's a host handle.
+    pub fn of(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<Self> {
+        match value {
+            Value::Ref(id) => match vm.heap.get(*id) {
+                HeapData::HostHandle(handle) => Some(handle.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The error for a builtin that would have to iterate this handle midway, where
+    /// execution can't pause to ask the host.
+    pub fn iteration_unsupported(&self) -> RunError {
+        ExcType::not_implemented(format!(
+            "a host handle ('{}') can't be iterated here; call iter() on it first",
+            self.type_name
+        ))
+        .into()
+    }
+
+    /// Returns the handle passed as the only argument of a builtin call, if any.
+    pub fn sole_arg(args: &ArgValues, vm: &VM<'_, impl ResourceTracker>) -> Option<Self> {
+        match args {
+            ArgValues::One(value) => Self::of(value, vm),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the truth value of `value` for builtins that can't pause to ask the host,
+/// raising `NotImplementedError` for a host handle instead of assuming it's true.
+pub(crate) fn checked_truth(value: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<bool> {
+    match HostHandle::of(value, vm) {
+        Some(handle) => Err(ExcType::not_implemented(format!(
+            "the truth value of a host handle ('{}') can't be tested here; call bool() on it first",
+            handle.type_name
+        ))
+        .into()),
+        None => Ok(value.py_bool(vm)),
+    }
+}
+
+/// An operation on a host handle, forwarded to the host.
+pub(crate) struct HandleRequest {
+    pub handle: HostHandle,
+    pub op: HandleOp,
+    /// How the host's result is checked and turned into the operation's value on resume.
+    pub effect: Option<HandleEffect>,
+}
+
+impl HandleRequest {
+    /// Creates a request whose result gets the default treatment for `op`.
+    pub fn new(handle: HostHandle, op: HandleOp) -> Self {
+        let effect = op.default_effect();
+        Self { handle, op, effect }
+    }
+
+    /// Replaces the effect applied to the host's result, for operations made
+    /// in a context that uses the result differently.
+    #[must_use]
+    pub fn with_effect(mut self, effect: HandleEffect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+}
+
+impl DropWithHeap for HandleRequest {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        self.op.drop_with_heap(heap);
+        self.effect.drop_with_heap(heap);
+    }
+}
+
+/// What sandbox code did with a host handle.
+pub(crate) enum HandleOp {
+    /// `h.name`
+    GetAttr(EitherStr),
+    /// `h.name = value`
+    SetAttr(EitherStr, Value),
+    /// `h[key]`
+    GetItem(Value),
+    /// `h(*args, **kwargs)`
+    Call(ArgValues),
+    /// `h.name(*args, **kwargs)`
+    CallMethod(EitherStr, ArgValues),
+    /// `iter(h)`, including `for` loops.
+    Iter,
+    /// `len(h)`
+    Len,
+    /// A truth test: `bool(h)`, `not h`, `if h:`.
+    Bool,
+}
+
+impl HandleOp {
+    /// The effect applied to the host's result of this operation when it resumes.
+    fn default_effect(&self) -> Option<HandleEffect> {
+        match self {
+            Self::GetAttr(_) | Self::GetItem(_) | Self::Call(_) | Self::CallMethod(..) => None,
+            Self::SetAttr(..) => Some(HandleEffect::Discard),
+            Self::Iter => Some(HandleEffect::Iter),
+            Self::Len => Some(HandleEffect::Len),
+            Self::Bool => Some(HandleEffect::Bool),
+        }
+    }
+}
+
+impl DropWithHeap for HandleOp {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        match self {
+            Self::SetAttr(_, value) | Self::GetItem(value) => value.drop_with_heap(heap),
+            Self::Call(args) | Self::CallMethod(_, args) => args.drop_with_heap(heap),
+            Self::GetAttr(_) | Self::Iter | Self::Len | Self::Bool => {}
+        }
+    }
+}
+
+/// How the host's result of a handle operation is turned into the value Python
+/// code sees, applied when the paused execution resumes.
+///
+/// Operations like `len()` and truth tests have to check the host's answer the
+/// way CPython checks `__len__` and `__bool__` results.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) enum HandleEffect {
+    /// `bool(h)`: the result must be a bool.
+    Bool,
+    /// `not h`: pushes the negated truth value.
+    Not,
+    /// A conditional jump on the truth value.
+    ///
+    /// The frame resumes after the jump instruction and moves to `target` when the
+    /// truth value equals `jump_when`. `keep` holds the handle for `and`/`or`, which
+    /// leave it on the stack when they jump.
+    Branch {
+        keep: Option<Value>,
+        jump_when: bool,
+        target: usize,
+    },
+    /// `len(h)`: the result must be a non-negative int.
+    Len,
+    /// `iter(h)`: the result is iterated over.
+    Iter,
+    /// `h.name = value`: the result is discarded.
+    Discard,
+    /// `setattr(h, name, value)`: returns `None` whatever the result.
+    ReturnNone,
+    /// `getattr(h, name, default)`: returns `default` if the host raises `AttributeError`.
+    AttrDefault(Value),
+    /// `hasattr(h, name)`: `True`, or `False` if the host raises `AttributeError`.
+    HasAttr,
+    /// `list(h)`, `sorted(h)`, `any(h)` etc.: the builtin is called with an iterator
+    /// over the result of `iter(h)`.
+    IterInto(Builtins),
+    /// `a, b = h`: the result of `iter(h)` is unpacked into this many values.
+    Unpack(usize),
+    /// `a, *b, c = h`: the result of `iter(h)` is unpacked around a starred target,
+    /// with this many targets before and after it.
+    UnpackEx(usize, usize),
+}
+
+impl DropWithHeap for HandleEffect {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        match self {
+            Self::Branch { keep, .. } => keep.drop_with_heap(heap),
+            Self::AttrDefault(default) => default.drop_with_heap(heap),
+            Self::Bool
+            | Self::Not
+            | Self::Len
+            | Self::Iter
+            | Self::Discard
+            | Self::ReturnNone
+            | Self::HasAttr
+            | Self::IterInto(_)
+            | Self::Unpack(_)
+            | Self::UnpackEx(..) => {}
+        }
+    }
+}
+
+impl HeapItem for HostHandle {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>() + self.type_name.len()
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {}
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, HostHandle> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::Object
+    }
+
+    /// `len()` is forwarded to the host rather than answered here.
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(self.get(vm.heap) == other.get(vm.heap))
+    }
+
+    fn py_hash(&self, _self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let handle = self.get(vm.heap);
+        let mut hasher = DefaultHasher::new();
+        handle.type_name.hash(&mut hasher);
+        handle.handle_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    /// Never consulted for a handle: truth tests are forwarded to the host (see
+    /// `HandleOp::Bool`) or raise, see [`checked_truth`].
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        let handle = self.get(vm.heap);
+        Ok(write!(f, "<{} handle {}>", handle.type_name, handle.handle_id)?)
+    }
+
+    fn py_call_attr(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        let handle = self.get(vm.heap).clone();
+        let op = HandleOp::CallMethod(attr.clone(), args);
+        Ok(CallResult::HandleCall(HandleRequest::new(handle, op)))
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let handle = self.get(vm.heap).clone();
+        let op = HandleOp::GetAttr(attr.clone());
+        Ok(Some(CallResult::HandleCall(HandleRequest::new(handle, op))))
+    }
+}
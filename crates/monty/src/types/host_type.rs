@@ -0,0 +1,191 @@
+//! Dataclass, named tuple and host handle classes defined by the host.
+//!
+//! Host classes can't be executed in the sandbox, but sandbox code still needs to
+//! tell them apart: a host passes the class in (`MontyObject::DataclassType` /
+//! `MontyObject::NamedTupleType`) so `isinstance(result, Point)` can branch on the
+//! kind of object a tool returned, and `type()` on a host instance rebuilds the
+//! same description. Since every `type()` call builds a new object, host classes
+//! compare by value — `type(p) == Point` holds but `type(p) is Point` doesn't.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use ahash::AHashSet;
+
+use super::{PyTrait, Type};
+use crate::{
+    bytecode::{CallResult, VM},
+    exception_private::RunResult,
+    hash::HashValue,
+    heap::{HeapData, HeapId, HeapItem, HeapRead},
+    intern::StaticStrings,
+    resource::ResourceTracker,
+    types::{dataclass_type::attribute_error_class, str::allocate_string},
+    value::{EitherStr, Value},
+};
+
+/// A host-defined class, identified the same way as the instances it describes.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct HostType {
+    /// Class name, e.g. "Point" or "os.stat_result".
+    name: String,
+    /// Field names in definition order.
+    field_names: Vec<String>,
+    kind: HostTypeKind,
+}
+
+/// Which kind of host instance a [`HostType`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) enum HostTypeKind {
+    /// Matches host dataclass instances with the same name and `type_id`.
+    Dataclass { type_id: u64 },
+    /// Matches named tuples with the same type name and field names.
+    NamedTuple,
+    /// Matches host handles with the same type name.
+    Handle,
+}
+
+impl HostType {
+    /// Creates the class of host dataclass instances with the given `type_id`.
+    pub fn dataclass(name: String, type_id: u64, field_names: Vec<String>) -> Self {
+        Self {
+            name,
+            field_names,
+            kind: HostTypeKind::Dataclass { type_id },
+        }
+    }
+
+    /// Creates the class of named tuples with the given type name and fields.
+    pub fn named_tuple(name: String, field_names: Vec<String>) -> Self {
+        Self {
+            name,
+            field_names,
+            kind: HostTypeKind::NamedTuple,
+        }
+    }
+
+    /// Creates the class of host handles with the given type name.
+    pub fn handle(name: String) -> Self {
+        Self {
+            name,
+            field_names: Vec::new(),
+            kind: HostTypeKind::Handle,
+        }
+    }
+
+    /// Returns the host class of `value`, if it's a host dataclass instance, a named tuple
+    /// or a host handle.
+    ///
+    /// Instances of sandbox-declared classes return `None`; their class lives on the heap.
+    pub fn of(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> Option<Self> {
+        let Value::Ref(id) = value else {
+            return None;
+        };
+        match vm.heap.get(*id) {
+            HeapData::Dataclass(dc) if dc.class_id().is_none() => Some(Self::dataclass(
+                dc.name(vm.interns).to_owned(),
+                dc.type_id(),
+                dc.field_names().to_vec(),
+            )),
+            HeapData::NamedTuple(nt) => Some(Self::named_tuple(
+                nt.name(vm.interns).to_owned(),
+                nt.field_names()
+                    .iter()
+                    .map(|name| name.as_str(vm.interns).to_owned())
+                    .collect(),
+            )),
+            HeapData::HostHandle(handle) => Some(Self::handle(handle.type_name().to_owned())),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn field_names(&self) -> &[String] {
+        &self.field_names
+    }
+
+    pub fn kind(&self) -> HostTypeKind {
+        self.kind
+    }
+
+    /// The builtin type of this class's instances.
+    pub fn instance_type(&self) -> Type {
+        match self.kind {
+            HostTypeKind::Dataclass { .. } => Type::Dataclass,
+            HostTypeKind::NamedTuple => Type::NamedTuple,
+            HostTypeKind::Handle => Type::Object,
+        }
+    }
+
+    /// Whether `self` and `other` describe the same class.
+    ///
+    /// Dataclasses are identified by `type_id` (field names are implied by the class);
+    /// named tuples carry no id, so their fields have to match as well.
+    pub fn is_same_class(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.kind == other.kind
+            && (matches!(self.kind, HostTypeKind::Dataclass { .. }) || self.field_names == other.field_names)
+    }
+}
+
+impl HeapItem for HostType {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>() + self.name.len() + self.field_names.iter().map(String::len).sum::<usize>()
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {}
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, HostType> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::Type
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(self.get(vm.heap).is_same_class(other.get(vm.heap)))
+    }
+
+    /// Hashes the name only, which is consistent with `is_same_class`.
+    fn py_hash(&self, _self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self.get(vm.heap).name.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        Ok(write!(f, "<class '{}'>", self.get(vm.heap).name)?)
+    }
+
+    /// Class attributes: `__name__` and `__qualname__`.
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let class = self.get(vm.heap);
+        match attr.static_string() {
+            Some(StaticStrings::DunderName | StaticStrings::DunderQualname) => {
+                // `__name__` drops the module prefix of names like "os.stat_result"
+                let name = class.name.rsplit('.').next().unwrap_or(&class.name).to_owned();
+                Ok(Some(CallResult::Value(allocate_string(name, vm.heap)?)))
+            }
+            _ => Err(attribute_error_class(&class.name, attr.as_str(vm.interns))),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Methods and attributes of Python `int` values.
+//!
+//! `int` has no heap type of its own: small values live inline as `Value::Int`
+//! (and `Value::Bool`, which shares every `int` method), while larger values are
+//! heap-allocated [`LongInt`]s. [`IntReceiver`] abstracts over the two so each
+//! method is written once.
+
+use num_bigint::{BigInt, Sign};
+use num_traits::{Signed, Zero};
+use smallvec::smallvec;
+
+use super::{Bytes, Type, allocate_tuple, bytes::collect_byte_values, file::extract_bytes_payload};
+use crate::{
+    args::{ArgValues, FromArgs, LaxBool},
+    bytecode::{CallResult, VM},
+    defer_drop,
+    exception_private::{ExcType, RunResult, SimpleException},
+    heap::{HeapData, HeapId},
+    intern::StaticStrings,
+    resource::{ResourceTracker, check_estimated_size},
+    types::LongInt,
+    value::{EitherStr, Value},
+};
+
+/// The receiver of an `int` method or attribute lookup.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum IntReceiver {
+    /// An inline `int` or `bool`; bools are widened to `0` / `1`.
+    Small(i64),
+    /// A heap-allocated `LongInt`.
+    Long(HeapId),
+}
+
+impl IntReceiver {
+    /// Returns the receiver's value as a `BigInt`.
+    fn to_bigint(self, vm: &VM<'_, impl ResourceTracker>) -> BigInt {
+        match self {
+            Self::Small(i) => BigInt::from(i),
+            Self::Long(id) => match vm.heap.get(id) {
+                HeapData::LongInt(li) => li.0.clone(),
+                _ => unreachable!("IntReceiver::Long must reference a LongInt"),
+            },
+        }
+    }
+
+    /// Returns the receiver as a plain `int` value (never a `bool`).
+    fn to_value(self, vm: &VM<'_, impl ResourceTracker>) -> Value {
+        match self {
+            Self::Small(i) => Value::Int(i),
+            Self::Long(id) => {
+                vm.heap.inc_ref(id);
+                Value::Ref(id)
+            }
+        }
+    }
+}
+
+/// Calls an `int` method such as `bit_length()` or `to_bytes()`.
+///
+/// `ty` is the receiver's Python type (`int` or `bool`), used in error messages.
+pub(crate) fn call_int_method(
+    receiver: IntReceiver,
+    ty: Type,
+    attr: &EitherStr,
+    args: ArgValues,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let Some(method) = attr.static_string() else {
+        args.drop_with_heap(vm);
+        return Err(ExcType::attribute_error(ty, attr.as_str(vm.interns)));
+    };
+    match method {
+        StaticStrings::BitLength => {
+            args.check_zero_args("int.bit_length", vm.heap)?;
+            let bits = match receiver {
+                IntReceiver::Small(i) => u64::from(64 - i.unsigned_abs().leading_zeros()),
+                IntReceiver::Long(_) => receiver.to_bigint(vm).bits(),
+            };
+            Ok(Value::Int(
+                i64::try_from(bits).map_err(|_| ExcType::overflow_c_ssize_t())?,
+            ))
+        }
+        StaticStrings::BitCount => {
+            args.check_zero_args("int.bit_count", vm.heap)?;
+            let ones = match receiver {
+                IntReceiver::Small(i) => u64::from(i.unsigned_abs().count_ones()),
+                IntReceiver::Long(_) => receiver.to_bigint(vm).magnitude().count_ones(),
+            };
+            Ok(Value::Int(
+                i64::try_from(ones).map_err(|_| ExcType::overflow_c_ssize_t())?,
+            ))
+        }
+        StaticStrings::ToBytes => int_to_bytes(receiver, args, vm),
+        StaticStrings::AsIntegerRatio => {
+            args.check_zero_args("int.as_integer_ratio", vm.heap)?;
+            let numerator = receiver.to_value(vm);
+            Ok(allocate_tuple(smallvec![numerator, Value::Int(1)], vm.heap)?)
+        }
+        StaticStrings::IsInteger => {
+            args.check_zero_args("int.is_integer", vm.heap)?;
+            Ok(Value::Bool(true))
+        }
+        StaticStrings::Conjugate => {
+            args.check_zero_args("int.conjugate", vm.heap)?;
+            Ok(receiver.to_value(vm))
+        }
+        _ => {
+            args.drop_with_heap(vm);
+            Err(ExcType::attribute_error(ty, attr.as_str(vm.interns)))
+        }
+    }
+}
+
+/// Looks up a data attribute of an `int`: `real`, `imag`, `numerator` or `denominator`.
+///
+/// Returns `None` for any other name so the caller can raise `AttributeError`.
+pub(crate) fn int_getattr(receiver: IntReceiver, attr: &EitherStr, vm: &VM<'_, impl ResourceTracker>) -> Option<Value> {
+    match attr.static_string()? {
+        StaticStrings::Real | StaticStrings::Numerator => Some(receiver.to_value(vm)),
+        StaticStrings::Imag => Some(Value::Int(0)),
+        StaticStrings::Denominator => Some(Value::Int(1)),
+        _ => None,
+    }
+}
+
+/// Looks up a data attribute of a heap `LongInt`; see [`int_getattr`].
+///
+/// Without the receiver's heap id, `real` and `numerator` return an equal copy
+/// rather than the same object.
+pub(crate) fn long_int_getattr(
+    li: &LongInt,
+    attr: &EitherStr,
+    vm: &VM<'_, impl ResourceTracker>,
+) -> RunResult<Option<CallResult>> {
+    let value = match attr.static_string() {
+        Some(StaticStrings::Real | StaticStrings::Numerator) => li.clone().into_value(vm.heap)?,
+        Some(StaticStrings::Imag) => Value::Int(0),
+        Some(StaticStrings::Denominator) => Value::Int(1),
+        _ => return Ok(None),
+    };
+    Ok(Some(CallResult::Value(value)))
+}
+
+/// Arguments of `int.to_bytes(length=1, byteorder='big', *, signed=False)`.
+#[derive(FromArgs)]
+#[from_args(name = "to_bytes", bad_arg_named)]
+struct ToBytesArgs {
+    #[from_args(default = Value::Int(1))]
+    length: Value,
+    #[from_args(default)]
+    byteorder: Option<String>,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    signed: LaxBool,
+}
+
+/// Implements `int.to_bytes()`.
+fn int_to_bytes(receiver: IntReceiver, args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let ToBytesArgs {
+        length,
+        byteorder,
+        signed,
+    } = ToBytesArgs::from_args(args, vm)?;
+    defer_drop!(length, vm);
+    let length = length.as_int(vm)?;
+    let little = parse_byteorder(byteorder.as_deref())?;
+    let Ok(length) = usize::try_from(length) else {
+        return Err(SimpleException::new_msg(ExcType::ValueError, "length argument must be non-negative").into());
+    };
+
+    let value = receiver.to_bigint(vm);
+    let negative = value.is_negative();
+    if negative && !signed.bool() {
+        return Err(SimpleException::new_msg(ExcType::OverflowError, "can't convert negative int to unsigned").into());
+    }
+    // Minimal little-endian encoding; zero needs no bytes at all.
+    let mut bytes = if value.is_zero() {
+        Vec::new()
+    } else if signed.bool() {
+        value.to_signed_bytes_le()
+    } else {
+        value.to_bytes_le().1
+    };
+    if bytes.len() > length {
+        return Err(SimpleException::new_msg(ExcType::OverflowError, "int too big to convert").into());
+    }
+    check_estimated_size(length, vm.heap.tracker())?;
+    bytes.resize(length, if negative { 0xff } else { 0 });
+    if !little {
+        bytes.reverse();
+    }
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Bytes(Bytes::new(bytes)))?))
+}
+
+/// Arguments of `int.from_bytes(bytes, byteorder='big', *, signed=False)`.
+#[derive(FromArgs)]
+#[from_args(name = "from_bytes", bad_arg_named)]
+struct FromBytesArgs {
+    bytes: Value,
+    #[from_args(default)]
+    byteorder: Option<String>,
+    #[from_args(kw_only, default = LaxBool::new(false))]
+    signed: LaxBool,
+}
+
+/// Implements the `int.from_bytes()` class method.
+///
+/// Accepts any bytes-like object or iterable of ints in `range(0, 256)`.
+pub(crate) fn int_from_bytes(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let FromBytesArgs {
+        bytes,
+        byteorder,
+        signed,
+    } = FromBytesArgs::from_args(args, vm)?;
+    let mut bytes = match extract_bytes_payload(&bytes, vm) {
+        Some(payload) => {
+            bytes.drop_with_heap(vm);
+            payload
+        }
+        None => collect_byte_values(bytes, Type::Bytes, vm)?,
+    };
+    let little = parse_byteorder(byteorder.as_deref())?;
+    check_estimated_size(bytes.len(), vm.heap.tracker())?;
+    if !little {
+        bytes.reverse();
+    }
+    let value = if signed.bool() {
+        BigInt::from_signed_bytes_le(&bytes)
+    } else {
+        BigInt::from_bytes_le(Sign::Plus, &bytes)
+    };
+    Ok(LongInt::new(value).into_value(vm.heap)?)
+}
+
+/// Parses a `byteorder` argument, returning `true` for little-endian.
+fn parse_byteorder(byteorder: Option<&str>) -> RunResult<bool> {
+    match byteorder {
+        None | Some("big") => Ok(false),
+        Some("little") => Ok(true),
+        Some(_) => {
+            Err(SimpleException::new_msg(ExcType::ValueError, "byteorder must be either 'little' or 'big'").into())
+        }
+    }
+}
@@ -19,11 +19,12 @@ use std::mem;
 use crate::{
     args::ArgValues,
     bytecode::VM,
+    defer_drop,
     exception_private::{ExcType, RunResult},
     heap::{ContainsHeap, DropWithHeap, Heap, HeapData, HeapGuard, HeapId, HeapItem, HeapRead, HeapReadOutput},
     intern::{BytesId, Interns},
     resource::{ResourceError, ResourceTracker, check_estimated_size},
-    types::{PyTrait, Range, dict_view::DictView, str::allocate_char},
+    types::{HostHandle, PyTrait, Range, dict_view::DictView, memory_file, str::allocate_char},
     value::{VALUE_SIZE, Value},
 };
 
@@ -48,16 +49,13 @@ impl MontyIter {
     ///
     /// - `iter(iterable)` - Returns an iterator for the iterable. If the argument is
     ///   already an iterator, returns the same object.
-    /// - `iter(callable, sentinel)` - Not yet supported.
+    /// - `iter(callable, sentinel)` - Returns an iterator that calls `callable` with no
+    ///   arguments until it returns a value equal to `sentinel`.
     pub fn init(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
         let (iterable, sentinel) = args.get_one_two_args("iter", vm.heap)?;
 
-        if let Some(s) = sentinel {
-            // Two-argument form: iter(callable, sentinel)
-            // This is the sentinel iteration protocol, not yet supported
-            iterable.drop_with_heap(vm);
-            s.drop_with_heap(vm);
-            return Err(ExcType::type_error("iter(callable, sentinel) is not yet supported"));
+        if let Some(sentinel) = sentinel {
+            return Self::new_callable(iterable, sentinel, vm);
         }
 
         // Check if already an iterator - return self
@@ -76,7 +74,8 @@ impl MontyIter {
 
     /// Creates a new MontyIter from a Value.
     ///
-    /// Returns an error if the value is not iterable.
+    /// Returns an error if the value is not iterable, or `NotImplementedError` for a
+    /// host handle, whose items only the host can produce.
     /// For strings, copies the string content for byte-offset based iteration.
     /// For ranges, the data is copied so the heap reference is dropped immediately.
     pub fn new(mut value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Self> {
@@ -95,12 +94,36 @@ impl MontyIter {
                 value,
             })
         } else {
-            let err = ExcType::type_error_not_iterable(value.py_type(vm));
+            // Iterating a host handle has to go through the host, which needs `iter(h)`
+            let err = match HostHandle::of(&value, vm) {
+                Some(handle) => handle.iteration_unsupported(),
+                None => ExcType::type_error_not_iterable(value.py_type(vm)),
+            };
             value.drop_with_heap(vm);
             Err(err)
         }
     }
 
+    /// Creates the iterator for `iter(callable, sentinel)`, returning it as a heap value.
+    ///
+    /// The callable and sentinel are kept together in a 2-tuple held as the iterator's
+    /// value, so reference counting and GC traversal need no special handling.
+    fn new_callable(callable: Value, sentinel: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+        if !callable.is_callable(vm.heap) {
+            callable.drop_with_heap(vm);
+            sentinel.drop_with_heap(vm);
+            return Err(ExcType::type_error("iter(v, w): v must be callable"));
+        }
+        let value = super::allocate_tuple(smallvec::smallvec![callable, sentinel], vm.heap)?;
+        let iter = Self {
+            index: 0,
+            iter_value: IterValue::Callable { exhausted: false },
+            value,
+        };
+        let id = vm.heap.allocate(HeapData::Iter(iter))?;
+        Ok(Value::Ref(id))
+    }
+
     /// Drops the iterator and its held value properly.
     pub fn drop_with_heap(self, heap: &mut impl ContainsHeap) {
         self.value.drop_with_heap(heap);
@@ -128,7 +151,9 @@ impl MontyIter {
         // inlined as a no-op. For LimitTracker it ensures that Rust-side loops
         // (sum, sorted, min, max, etc.) cannot bypass the VM's per-instruction
         // timeout check by running entirely within a single bytecode instruction.
+        // Each step also costs one unit of fuel, for the same reason.
         vm.heap.check_time()?;
+        vm.heap.consume_fuel(1)?;
         match &mut self.iter_value {
             IterValue::Range { next, step, len } => {
                 if self.index >= *len {
@@ -187,6 +212,19 @@ impl MontyIter {
                 self.index += 1;
                 Ok(Some(item))
             }
+            IterValue::MemoryFileLines { heap_id } => memory_file::next_line(*heap_id, vm),
+            IterValue::Iterator { heap_id } => next_from_iterator(*heap_id, vm),
+            IterValue::Callable { exhausted } => {
+                if *exhausted {
+                    return Ok(None);
+                }
+                let Value::Ref(pair_id) = &self.value else {
+                    panic!("Callable iterator should hold a (callable, sentinel) tuple");
+                };
+                let item = next_from_callable(*pair_id, vm)?;
+                *exhausted = item.is_none();
+                Ok(item)
+            }
         }
     }
 
@@ -199,14 +237,28 @@ impl MontyIter {
         let len = match &self.iter_value {
             IterValue::Range { len, .. } | IterValue::IterStr { len, .. } | IterValue::InternBytes { len, .. } => *len,
             IterValue::HeapRef { heap_id, len, .. } => {
-                // For List (len=None), check current length dynamically
-                len.unwrap_or_else(|| {
-                    let HeapData::List(list) = heap.get(*heap_id) else {
-                        panic!("HeapRef with len=None should only be List")
-                    };
-                    list.len()
+                // For List and ByteArray (len=None), check current length dynamically
+                len.unwrap_or_else(|| match heap.get(*heap_id) {
+                    HeapData::List(list) => list.len(),
+                    HeapData::ByteArray(b) => b.as_slice().len(),
+                    _ => panic!("HeapRef with len=None should only be List or ByteArray"),
                 })
             }
+            IterValue::MemoryFileLines { heap_id } => {
+                let HeapData::MemoryFile(file) = heap.get(*heap_id) else {
+                    panic!("MemoryFileLines should only reference a MemoryFile")
+                };
+                // Lines are read from the file's own position, not counted by `index`.
+                return file.remaining_lines();
+            }
+            IterValue::Iterator { heap_id } => {
+                let HeapData::Iter(iter) = heap.get(*heap_id) else {
+                    panic!("Iterator should only reference an Iter")
+                };
+                return iter.size_hint(heap);
+            }
+            // The number of calls before the sentinel appears is unknown
+            IterValue::Callable { .. } => return 0,
         };
         len.saturating_sub(self.index)
     }
@@ -400,10 +452,70 @@ impl<'h> HeapRead<'h, MontyIter> {
                 self.get_mut(vm.heap).index += 1;
                 Ok(Some(item))
             }
+            IterValue::MemoryFileLines { heap_id } => {
+                let heap_id = *heap_id;
+                memory_file::next_line(heap_id, vm)
+            }
+            IterValue::Iterator { heap_id } => {
+                let heap_id = *heap_id;
+                next_from_iterator(heap_id, vm)
+            }
+            IterValue::Callable { exhausted } => {
+                if *exhausted {
+                    return Ok(None);
+                }
+                let Value::Ref(pair_id) = this.value else {
+                    panic!("Callable iterator should hold a (callable, sentinel) tuple");
+                };
+                // The callable may run arbitrary code (including advancing this same
+                // iterator), so no borrow of `this` is held across the call.
+                let item = next_from_callable(pair_id, vm)?;
+                if item.is_none()
+                    && let IterValue::Callable { exhausted } = &mut self.get_mut(vm.heap).iter_value
+                {
+                    *exhausted = true;
+                }
+                Ok(item)
+            }
         }
     }
 }
 
+/// Advances another iterator that is itself being iterated over, e.g. `for x in iter(xs)`.
+///
+/// Both share progress, matching Python where an iterator's `__iter__` returns itself.
+fn next_from_iterator(heap_id: HeapId, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Option<Value>> {
+    let HeapReadOutput::Iter(mut iter) = vm.heap.read(heap_id) else {
+        panic!("Iterator should only reference an Iter");
+    };
+    iter.advance(vm)
+}
+
+/// Calls the callable of an `iter(callable, sentinel)` iterator, returning `None`
+/// once it produces a value equal to the sentinel.
+///
+/// `pair_id` is the `(callable, sentinel)` tuple held by the iterator.
+fn next_from_callable(pair_id: HeapId, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Option<Value>> {
+    let HeapData::Tuple(pair) = vm.heap.get(pair_id) else {
+        panic!("Callable iterator should hold a (callable, sentinel) tuple");
+    };
+    let [callable, sentinel] = pair.as_slice() else {
+        panic!("Callable iterator should hold a (callable, sentinel) tuple");
+    };
+    let (callable, sentinel) = (callable.clone_with_heap(vm), sentinel.clone_with_heap(vm));
+    defer_drop!(callable, vm);
+    defer_drop!(sentinel, vm);
+
+    let item = vm.evaluate_function("iter()", callable, ArgValues::Empty)?;
+    let mut item_guard = HeapGuard::new(item, vm);
+    let (item, vm) = item_guard.as_parts();
+    if item.py_eq(sentinel, vm)? {
+        Ok(None)
+    } else {
+        Ok(Some(item_guard.into_inner()))
+    }
+}
+
 /// Gets an item from a heap-allocated container at the given index.
 ///
 /// Returns `Ok(None)` if the index is out of bounds (for lists that shrunk during iteration).
@@ -471,6 +583,10 @@ fn get_heap_item(
             ))
         }
         HeapData::Bytes(bytes) => Ok(Some(Value::Int(i64::from(bytes.as_slice()[index])))),
+        // ByteArray can shrink during iteration, like List
+        HeapData::ByteArray(bytes) => Ok(bytes.as_slice().get(index).map(|b| Value::Int(i64::from(*b)))),
+        // The viewed buffer may have shrunk below the view's captured length
+        HeapData::MemoryView(view) => Ok(view.item(vm.heap, index).map(|b| Value::Int(i64::from(b)))),
         HeapData::Set(set) => {
             // Check for set mutation
             if let Some(expected) = expected_len
@@ -526,6 +642,8 @@ pub fn iterator_next(
 
     let result = match vm.heap.read(*iter_id) {
         HeapReadOutput::Iter(mut iter) => iter.advance(vm)?,
+        // In-memory files are their own iterators, yielding one line per call.
+        HeapReadOutput::MemoryFile(_) => memory_file::next_line(*iter_id, vm)?,
         other => {
             let data_type = other.py_type(vm);
             return Err(ExcType::type_error(format!("'{data_type}' object is not an iterator")));
@@ -576,9 +694,10 @@ enum IterValue {
     },
     /// Iterating over interned bytes, yields `Value::Int` for each byte.
     InternBytes { bytes_id: BytesId, len: usize },
-    /// Iterating over a heap-allocated container (List, Tuple, NamedTuple, Dict, Bytes, Set, FrozenSet).
+    /// Iterating over a heap-allocated container (List, Tuple, NamedTuple, Dict, Bytes, ByteArray,
+    /// MemoryView, Set, FrozenSet).
     ///
-    /// - `len`: `None` for List (checked dynamically since lists can mutate during iteration),
+    /// - `len`: `None` for List and ByteArray (checked dynamically since they can mutate during iteration),
     ///   `Some(n)` for other types (captured at construction for exhaustion checking).
     /// - `checks_mutation`: `true` for Dict/Set (raises RuntimeError if size changes),
     ///   `false` for other types.
@@ -587,6 +706,20 @@ enum IterValue {
         len: Option<usize>,
         checks_mutation: bool,
     },
+    /// Iterating over an `io.StringIO` / `io.BytesIO`, yields one line per step.
+    ///
+    /// Reading advances the file's own position, so the iterator keeps no state
+    /// beyond the file reference and shares progress with `readline()` calls.
+    MemoryFileLines { heap_id: HeapId },
+    /// Iterating over another iterator, yields whatever it yields.
+    ///
+    /// The inner iterator is advanced in place, so progress is shared with it.
+    Iterator { heap_id: HeapId },
+    /// `iter(callable, sentinel)`, yields the callable's results until one equals the sentinel.
+    ///
+    /// The callable and sentinel live in the iterator's value as a 2-tuple.
+    /// `exhausted` is set once the sentinel is seen, so the callable is never called again.
+    Callable { exhausted: bool },
 }
 
 impl IterValue {
@@ -659,6 +792,17 @@ impl IterValue {
                 len: Some(frozenset.len()),
                 checks_mutation: false,
             }),
+            // ByteArray: mutable like List, so its length is checked dynamically
+            HeapData::ByteArray(_) => Some(Self::HeapRef {
+                heap_id,
+                len: None,
+                checks_mutation: false,
+            }),
+            HeapData::MemoryView(view) => Some(Self::HeapRef {
+                heap_id,
+                len: Some(view.len()),
+                checks_mutation: false,
+            }),
             // Dict and dict views: captured len, WITH mutation check
             HeapData::Dict(dict) => Some(Self::HeapRef {
                 heap_id,
@@ -689,6 +833,10 @@ impl IterValue {
             HeapData::Str(s) => Some(Self::from_str(s.as_str())),
             // Range: copy values for iteration
             HeapData::Range(range) => Some(Self::from_range(range)),
+            // In-memory file: read lines from the file's current position
+            HeapData::MemoryFile(_) => Some(Self::MemoryFileLines { heap_id }),
+            // Iterator: advance it in place
+            HeapData::Iter(_) => Some(Self::Iterator { heap_id }),
             // other types are not iterable
             _ => None,
         }
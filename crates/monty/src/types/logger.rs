@@ -0,0 +1,181 @@
+//! `logging.Logger` and `logging.LoggerAdapter` objects.
+//!
+//! A logger only holds its name. Levels, the `basicConfig()` format and the
+//! name-to-logger registry live on the VM in
+//! [`LoggingState`](crate::modules::logging::LoggingState), so every route to a
+//! logger sees the same level. The logging methods are implemented in
+//! [`crate::modules::logging`].
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use ahash::AHashSet;
+
+use super::{PyTrait, Type};
+use crate::{
+    args::{ArgValues, FromArgs, LaxBool},
+    bytecode::{CallResult, VM},
+    exception_private::{ExcType, RunResult},
+    hash::HashValue,
+    heap::{DropWithHeap, HeapData, HeapId, HeapItem, HeapRead},
+    intern::StaticStrings,
+    modules::logging,
+    resource::ResourceTracker,
+    types::str::allocate_string,
+    value::{EitherStr, Value},
+};
+
+/// A logger returned by `logging.getLogger()`, or an adapter wrapping one.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) enum Logger {
+    /// A logger from `getLogger()`, by its dotted name (`"root"` for the root logger).
+    Named(String),
+    /// `LoggerAdapter(logger, extra=None, merge_extra=False)`: logs through
+    /// `logger` with `extra` attached to every record.
+    Adapter {
+        logger: Value,
+        extra: Value,
+        merge_extra: bool,
+    },
+}
+
+/// Argument shape for `logging.LoggerAdapter(logger, extra=None, merge_extra=False)`.
+#[derive(FromArgs)]
+#[from_args(name = "LoggerAdapter")]
+struct LoggerAdapterArgs {
+    logger: Value,
+    #[from_args(default = Value::None)]
+    extra: Value,
+    #[from_args(default = LaxBool::new(false))]
+    merge_extra: LaxBool,
+}
+
+/// Constructs a `logging.LoggerAdapter`.
+pub(crate) fn init_adapter(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let LoggerAdapterArgs {
+        logger,
+        extra,
+        merge_extra,
+    } = LoggerAdapterArgs::from_args(args, vm)?;
+    let is_logger = matches!(logger, Value::Ref(id) if matches!(vm.heap.get(id), HeapData::Logger(_)));
+    if !is_logger {
+        let type_name = logger.py_type(vm);
+        logger.drop_with_heap(vm);
+        extra.drop_with_heap(vm);
+        return Err(ExcType::type_error(format!(
+            "LoggerAdapter() argument 'logger' must be a logging.Logger, not '{type_name}'"
+        )));
+    }
+    let adapter = Logger::Adapter {
+        logger,
+        extra,
+        merge_extra: merge_extra.bool(),
+    };
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Logger(adapter))?))
+}
+
+impl Logger {
+    /// Returns `Logger` or `LoggerAdapter`.
+    #[must_use]
+    pub fn py_type(&self) -> Type {
+        match self {
+            Self::Named(_) => Type::Logger,
+            Self::Adapter { .. } => Type::LoggerAdapter,
+        }
+    }
+
+    /// Returns every value held by the logger, for GC traversal.
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        let held = match self {
+            Self::Named(_) => None,
+            Self::Adapter { logger, extra, .. } => Some([logger, extra]),
+        };
+        held.into_iter().flatten()
+    }
+}
+
+impl HeapItem for Logger {
+    fn py_estimate_size(&self) -> usize {
+        let name = match self {
+            Self::Named(name) => name.len(),
+            Self::Adapter { .. } => 0,
+        };
+        mem::size_of::<Self>() + name
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        if let Self::Adapter { logger, extra, .. } = self {
+            logger.py_dec_ref_ids(stack);
+            extra.py_dec_ref_ids(stack);
+        }
+    }
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, Logger> {
+    fn py_type(&self, vm: &VM<'h, impl ResourceTracker>) -> Type {
+        self.get(vm.heap).py_type()
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// Loggers only compare equal to themselves, which `Value::py_eq` checks by identity.
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_hash(&self, self_id: HeapId, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    /// `<Logger name (LEVEL)>`, showing the effective level like CPython.
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        let class = match self.get(vm.heap) {
+            Logger::Named(_) => "Logger",
+            Logger::Adapter { .. } => "LoggerAdapter",
+        };
+        let name = logging::logger_name(self.get(vm.heap), vm)?;
+        let level = logging::level_name(vm.logging.effective_level(&name));
+        Ok(write!(f, "<{class} {name} ({level})>")?)
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        if attr.static_string() == Some(StaticStrings::Name) {
+            let name = logging::logger_name(self.get(vm.heap), vm)?;
+            return Ok(Some(CallResult::Value(allocate_string(name, vm.heap)?)));
+        }
+        let value = match (self.get(vm.heap), attr.static_string()) {
+            (Logger::Named(name), Some(StaticStrings::Level)) => Value::Int(vm.logging.level(name)),
+            (Logger::Adapter { logger, .. }, Some(StaticStrings::Logger)) => logger.clone_with_heap(vm),
+            (Logger::Adapter { extra, .. }, Some(StaticStrings::Extra)) => extra.clone_with_heap(vm),
+            _ => return Ok(None),
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+
+    fn py_call_attr(
+        &mut self,
+        self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        logging::call_method(vm, self_id, attr, args).map(CallResult::Value)
+    }
+}
@@ -0,0 +1,684 @@
+//! In-memory file objects returned by `io.StringIO()` and `io.BytesIO()`.
+//!
+//! Unlike [`OpenFile`](super::OpenFile), these never leave the sandbox: the
+//! whole buffer lives inside the heap entry and every operation completes in
+//! pure Monty, without an OS call. Buffer growth from `write()` is reported to
+//! the resource tracker before the buffer is resized, so a runaway writer hits
+//! `max_memory` instead of exhausting the host.
+//!
+//! Argument parsing and payload validation are shared with `open()` file
+//! objects; positions follow the same convention (char index for text, byte
+//! index for binary), which for `StringIO` also matches CPython.
+
+use std::{fmt::Write, iter, mem};
+
+use ahash::AHashSet;
+
+use super::{
+    List, MontyIter, PyTrait, Type,
+    bytes::Bytes,
+    file::{ReadSpec, extract_bytes_payload, extract_str_payload, is_bytes, nth_char_byte_offset, parse_read_size_arg},
+    str::allocate_string,
+};
+use crate::{
+    args::{ArgValues, FromArgs},
+    bytecode::{CallResult, VM},
+    defer_drop, defer_drop_mut,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{DropWithHeap, Heap, HeapData, HeapId, HeapItem, HeapRead, HeapReadOutput},
+    intern::StaticStrings,
+    resource::{ResourceTracker, check_estimated_size},
+    value::{EitherStr, Value},
+};
+
+/// A `StringIO` or `BytesIO` object.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct MemoryFile {
+    buffer: MemoryBuffer,
+    /// Char index (text) or byte index (binary) of the next read or write.
+    /// May point past the end of the buffer after `seek()`; a write there pads
+    /// the gap with NULs, as CPython does.
+    position: usize,
+    /// Whether `close()` has been called. Every operation except `close()`
+    /// raises `ValueError` afterwards, including `getvalue()`.
+    closed: bool,
+}
+
+/// Contents of a [`MemoryFile`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+enum MemoryBuffer {
+    /// `StringIO` contents.
+    Text {
+        text: String,
+        /// Cached char/byte bookkeeping for `position`, so sequential reads and
+        /// appends don't rescan the text from the start.
+        ///
+        /// **Not serialized**, for the same reason as `OpenFile::buffer_meta`:
+        /// a crafted snapshot could otherwise point `byte_position` into the
+        /// middle of a code point. It is rebuilt on first use after a restore.
+        #[serde(skip)]
+        cursor: Option<TextCursor>,
+    },
+    /// `BytesIO` contents.
+    Binary(Vec<u8>),
+}
+
+/// Cached metadata about a `StringIO` buffer relative to its position.
+#[derive(Debug, Clone, Copy)]
+struct TextCursor {
+    /// Byte offset matching `position`, clamped to `text.len()`.
+    byte_position: usize,
+    /// Total number of chars in the text.
+    char_len: usize,
+}
+
+/// Data read from or written to a [`MemoryFile`].
+enum Chunk {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl Chunk {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) => text.is_empty(),
+            Self::Binary(bytes) => bytes.is_empty(),
+        }
+    }
+
+    fn into_value(self, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
+        Ok(match self {
+            Self::Text(text) => allocate_string(text, heap)?,
+            Self::Binary(bytes) => Value::Ref(heap.allocate(HeapData::Bytes(Bytes::new(bytes)))?),
+        })
+    }
+}
+
+/// Constructs a `StringIO` or `BytesIO` from a call to the class.
+pub(crate) fn init(ty: Type, vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+    let file = match ty {
+        Type::StringIO => {
+            let StringIOArgs { initial_value } = StringIOArgs::from_args(args, vm)?;
+            defer_drop!(initial_value, vm);
+            let text = match initial_value {
+                Value::None => String::new(),
+                value => extract_str_payload(value, vm).ok_or_else(|| {
+                    ExcType::type_error(format!("initial_value must be str or None, not {}", value.py_type(vm)))
+                })?,
+            };
+            MemoryFile::new(MemoryBuffer::Text { text, cursor: None })
+        }
+        Type::BytesIO => {
+            let BytesIOArgs { initial_bytes } = BytesIOArgs::from_args(args, vm)?;
+            defer_drop!(initial_bytes, vm);
+            let bytes = match initial_bytes {
+                Value::None => Vec::new(),
+                value => extract_bytes_payload(value, vm).ok_or_else(|| bytes_like_required(value, vm))?,
+            };
+            MemoryFile::new(MemoryBuffer::Binary(bytes))
+        }
+        _ => {
+            args.drop_with_heap(vm);
+            return Err(ExcType::type_error_not_callable(ty));
+        }
+    };
+    Ok(Value::Ref(vm.heap.allocate(HeapData::MemoryFile(file))?))
+}
+
+/// Argument shape for `io.StringIO(initial_value='')`.
+#[derive(FromArgs)]
+#[from_args(name = "StringIO")]
+struct StringIOArgs {
+    #[from_args(default = Value::None)]
+    initial_value: Value,
+}
+
+/// Argument shape for `io.BytesIO(initial_bytes=b'')`.
+#[derive(FromArgs)]
+#[from_args(name = "BytesIO")]
+struct BytesIOArgs {
+    #[from_args(default = Value::None)]
+    initial_bytes: Value,
+}
+
+/// Returns the next line of an in-memory file for `for line in f:`, or `None`
+/// once it is exhausted.
+pub(crate) fn next_line(file_id: HeapId, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Option<Value>> {
+    let HeapReadOutput::MemoryFile(mut file) = vm.heap.read(file_id) else {
+        return Err(RunError::internal("next_line: file_id does not point to a MemoryFile"));
+    };
+    let file = file.get_mut(vm.heap);
+    file.ensure_open()?;
+    let line = file.read(None, true);
+    if line.is_empty() {
+        Ok(None)
+    } else {
+        line.into_value(vm.heap).map(Some)
+    }
+}
+
+impl MemoryFile {
+    fn new(buffer: MemoryBuffer) -> Self {
+        Self {
+            buffer,
+            position: 0,
+            closed: false,
+        }
+    }
+
+    /// Returns `io.StringIO` or `io.BytesIO`.
+    #[must_use]
+    pub fn py_type(&self) -> Type {
+        match self.buffer {
+            MemoryBuffer::Text { .. } => Type::StringIO,
+            MemoryBuffer::Binary(_) => Type::BytesIO,
+        }
+    }
+
+    /// Counts the lines left to read, for iterator size hints.
+    #[must_use]
+    pub fn remaining_lines(&self) -> usize {
+        let count = |tail: &[u8]| tail.split_inclusive(|b| *b == b'\n').count();
+        match &self.buffer {
+            MemoryBuffer::Text { text, .. } => count(&text.as_bytes()[nth_char_byte_offset(text, self.position)..]),
+            MemoryBuffer::Binary(bytes) => count(bytes.get(self.position..).unwrap_or_default()),
+        }
+    }
+
+    /// Raises CPython's error for operations after `close()`. `StringIO` and
+    /// `BytesIO` word it slightly differently.
+    fn ensure_open(&self) -> RunResult<()> {
+        if !self.closed {
+            Ok(())
+        } else if matches!(self.buffer, MemoryBuffer::Text { .. }) {
+            Err(SimpleException::new_msg(ExcType::ValueError, "I/O operation on closed file").into())
+        } else {
+            Err(closed_file_error())
+        }
+    }
+
+    /// Returns the buffer length in chars (text) or bytes (binary).
+    fn len(&mut self) -> usize {
+        match &mut self.buffer {
+            MemoryBuffer::Text { text, cursor } => text_cursor(text, cursor, self.position).char_len,
+            MemoryBuffer::Binary(bytes) => bytes.len(),
+        }
+    }
+
+    /// Reads from the current position and advances past what was read.
+    ///
+    /// Reads up to `limit` units, or everything when `None`; with `line`, also
+    /// stops after the first newline.
+    fn read(&mut self, limit: Option<usize>, line: bool) -> Chunk {
+        let Self { buffer, position, .. } = self;
+        match buffer {
+            MemoryBuffer::Text { text, cursor } => {
+                let TextCursor {
+                    byte_position,
+                    char_len,
+                } = text_cursor(text, cursor, *position);
+                let tail = &text[byte_position..];
+                let (end, count) = read_extent(
+                    tail.char_indices().map(|(i, c)| (i, c.len_utf8(), c == '\n')),
+                    limit,
+                    line,
+                );
+                let chunk = tail[..end].to_owned();
+                *position += count;
+                *cursor = Some(TextCursor {
+                    byte_position: byte_position + end,
+                    char_len,
+                });
+                Chunk::Text(chunk)
+            }
+            MemoryBuffer::Binary(bytes) => {
+                let tail = bytes.get(*position..).unwrap_or_default();
+                let (end, count) = read_extent(tail.iter().enumerate().map(|(i, b)| (i, 1, *b == b'\n')), limit, line);
+                let chunk = tail[..end].to_vec();
+                *position += count;
+                Chunk::Binary(chunk)
+            }
+        }
+    }
+
+    /// Returns how many bytes the buffer grows by when `data` is written at
+    /// the current position, including NUL padding past the end.
+    fn write_growth(&mut self, data: &Chunk) -> usize {
+        let position = self.position;
+        match (&mut self.buffer, data) {
+            (MemoryBuffer::Text { text, cursor }, Chunk::Text(data)) => {
+                let TextCursor {
+                    byte_position,
+                    char_len,
+                } = text_cursor(text, cursor, position);
+                if position >= char_len {
+                    (position - char_len).saturating_add(data.len())
+                } else {
+                    let replaced = nth_char_byte_offset(&text[byte_position..], data.chars().count());
+                    data.len().saturating_sub(replaced)
+                }
+            }
+            (MemoryBuffer::Binary(bytes), Chunk::Binary(data)) => {
+                position.saturating_add(data.len()).saturating_sub(bytes.len())
+            }
+            _ => 0,
+        }
+    }
+
+    /// Writes `data` at the current position, overwriting what is there, and
+    /// returns the number of chars or bytes written.
+    fn write(&mut self, data: &Chunk) -> usize {
+        let Self { buffer, position, .. } = self;
+        let written = match (buffer, data) {
+            (MemoryBuffer::Text { text, cursor }, Chunk::Text(data)) => {
+                let TextCursor {
+                    byte_position,
+                    char_len,
+                } = text_cursor(text, cursor, *position);
+                let written = data.chars().count();
+                let (byte_position, char_len) = if *position >= char_len {
+                    text.extend(iter::repeat_n('\0', *position - char_len));
+                    text.push_str(data);
+                    (text.len(), *position + written)
+                } else {
+                    let end = byte_position + nth_char_byte_offset(&text[byte_position..], written);
+                    text.replace_range(byte_position..end, data);
+                    (byte_position + data.len(), char_len.max(*position + written))
+                };
+                *cursor = Some(TextCursor {
+                    byte_position,
+                    char_len,
+                });
+                written
+            }
+            (MemoryBuffer::Binary(bytes), Chunk::Binary(data)) => {
+                if *position > bytes.len() {
+                    bytes.resize(*position, 0);
+                }
+                let overlap = (bytes.len() - *position).min(data.len());
+                bytes[*position..*position + overlap].copy_from_slice(&data[..overlap]);
+                bytes.extend_from_slice(&data[overlap..]);
+                data.len()
+            }
+            _ => 0,
+        };
+        *position += written;
+        written
+    }
+
+    /// Moves to `position`, keeping the text cursor in step.
+    fn set_position(&mut self, position: usize) {
+        self.position = position;
+        if let MemoryBuffer::Text { text, cursor } = &mut self.buffer {
+            let char_len = text_cursor(text, cursor, 0).char_len;
+            *cursor = Some(TextCursor {
+                byte_position: nth_char_byte_offset(text, position),
+                char_len,
+            });
+        }
+    }
+
+    /// Implements `seek(offset, whence)` with `StringIO`'s or `BytesIO`'s rules,
+    /// returning the new position.
+    fn seek(&mut self, offset: i64, whence: i64) -> RunResult<usize> {
+        let position = match self.buffer {
+            MemoryBuffer::Text { .. } => match whence {
+                0 if offset < 0 => {
+                    return Err(SimpleException::new_msg(
+                        ExcType::ValueError,
+                        format!("Negative seek position {offset}"),
+                    )
+                    .into());
+                }
+                1 | 2 if offset != 0 => {
+                    return Err(
+                        SimpleException::new_msg(ExcType::OSError, "Can't do nonzero cur-relative seeks").into(),
+                    );
+                }
+                0 => usize::try_from(offset).map_err(|_| ExcType::overflow_c_ssize_t())?,
+                1 => self.position,
+                2 => self.len(),
+                _ => {
+                    return Err(SimpleException::new_msg(
+                        ExcType::ValueError,
+                        format!("Invalid whence ({whence}, should be 0, 1 or 2)"),
+                    )
+                    .into());
+                }
+            },
+            MemoryBuffer::Binary(_) => {
+                let base = match whence {
+                    0 if offset < 0 => {
+                        return Err(SimpleException::new_msg(
+                            ExcType::ValueError,
+                            format!("negative seek value {offset}"),
+                        )
+                        .into());
+                    }
+                    0 => 0,
+                    1 => self.position,
+                    2 => self.len(),
+                    _ => {
+                        return Err(SimpleException::new_msg(
+                            ExcType::ValueError,
+                            format!("invalid whence ({whence}, should be 0, 1 or 2)"),
+                        )
+                        .into());
+                    }
+                };
+                // Relative seeks before the start clamp to 0, as in CPython.
+                let base = i64::try_from(base).map_err(|_| ExcType::overflow_c_ssize_t())?;
+                let target = base.checked_add(offset).ok_or_else(ExcType::overflow_c_ssize_t)?;
+                usize::try_from(target.max(0)).map_err(|_| ExcType::overflow_c_ssize_t())?
+            }
+        };
+        self.set_position(position);
+        Ok(position)
+    }
+
+    /// Cuts the buffer down to `size` units; the position is left unchanged.
+    fn truncate(&mut self, size: usize) {
+        match &mut self.buffer {
+            MemoryBuffer::Text { text, cursor } => {
+                let end = nth_char_byte_offset(text, size);
+                text.truncate(end);
+                *cursor = None;
+            }
+            MemoryBuffer::Binary(bytes) => bytes.truncate(size),
+        }
+    }
+
+    /// Returns a copy of the whole buffer.
+    fn getvalue(&self) -> Chunk {
+        match &self.buffer {
+            MemoryBuffer::Text { text, .. } => Chunk::Text(text.clone()),
+            MemoryBuffer::Binary(bytes) => Chunk::Binary(bytes.clone()),
+        }
+    }
+}
+
+/// Returns the text cursor for `position`, building it if it isn't cached.
+fn text_cursor(text: &str, cursor: &mut Option<TextCursor>, position: usize) -> TextCursor {
+    *cursor.get_or_insert_with(|| TextCursor {
+        byte_position: nth_char_byte_offset(text, position),
+        char_len: text.chars().count(),
+    })
+}
+
+/// Finds where a read over `units` — `(byte_offset, byte_len, is_newline)`
+/// for each char or byte of the unread tail — stops.
+///
+/// Returns the end byte offset and the number of units read.
+fn read_extent(units: impl Iterator<Item = (usize, usize, bool)>, limit: Option<usize>, line: bool) -> (usize, usize) {
+    let mut end = 0;
+    let mut count = 0;
+    for (offset, len, newline) in units {
+        if limit == Some(count) {
+            break;
+        }
+        end = offset + len;
+        count += 1;
+        if line && newline {
+            break;
+        }
+    }
+    (end, count)
+}
+
+impl HeapItem for MemoryFile {
+    fn py_estimate_size(&self) -> usize {
+        let buffer = match &self.buffer {
+            MemoryBuffer::Text { text, .. } => text.len(),
+            MemoryBuffer::Binary(bytes) => bytes.len(),
+        };
+        mem::size_of::<Self>() + buffer
+    }
+
+    fn py_dec_ref_ids(&mut self, _stack: &mut Vec<HeapId>) {}
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, MemoryFile> {
+    fn py_type(&self, vm: &VM<'h, impl ResourceTracker>) -> Type {
+        self.get(vm.heap).py_type()
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        Ok(write!(f, "<{} object>", self.py_type(vm))?)
+    }
+
+    fn py_call_attr(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        let Some(method) = attr.static_string() else {
+            args.drop_with_heap(vm);
+            return Err(ExcType::attribute_error(self.py_type(vm), attr.as_str(vm.interns)));
+        };
+
+        let value = match method {
+            StaticStrings::Read => {
+                let spec = parse_read_size_arg(args.get_zero_one_arg("read", vm.heap)?, vm)?;
+                self.read(vm, spec, false)?
+            }
+            StaticStrings::Readline => {
+                let spec = parse_read_size_arg(args.get_zero_one_arg("readline", vm.heap)?, vm)?;
+                self.read(vm, spec, true)?
+            }
+            StaticStrings::Readlines => {
+                args.check_zero_args("readlines", vm.heap)?;
+                self.readlines(vm)?
+            }
+            StaticStrings::Write => {
+                let data = args.get_one_arg("write", vm.heap)?;
+                defer_drop!(data, vm);
+                Value::Int(i64::try_from(self.write(data, vm)?).map_err(|_| ExcType::overflow_c_ssize_t())?)
+            }
+            StaticStrings::Writelines => {
+                let lines = args.get_one_arg("writelines", vm.heap)?;
+                self.writelines(lines, vm)?;
+                Value::None
+            }
+            StaticStrings::Seek => {
+                let (offset, whence) = args.get_one_two_args("seek", vm.heap)?;
+                defer_drop!(offset, vm);
+                let offset = offset.as_int(vm)?;
+                let whence = match whence {
+                    Some(whence) => {
+                        defer_drop!(whence, vm);
+                        whence.as_int(vm)?
+                    }
+                    None => 0,
+                };
+                let file = self.get_mut(vm.heap);
+                file.ensure_open()?;
+                let position = file.seek(offset, whence)?;
+                Value::Int(i64::try_from(position).map_err(|_| ExcType::overflow_c_ssize_t())?)
+            }
+            StaticStrings::Tell => {
+                args.check_zero_args("tell", vm.heap)?;
+                let file = self.get(vm.heap);
+                file.ensure_open()?;
+                Value::Int(i64::try_from(file.position).map_err(|_| ExcType::overflow_c_ssize_t())?)
+            }
+            StaticStrings::Truncate => {
+                let size = args.get_zero_one_arg("truncate", vm.heap)?;
+                self.truncate(size, vm)?
+            }
+            StaticStrings::Getvalue => {
+                args.check_zero_args("getvalue", vm.heap)?;
+                let file = self.get(vm.heap);
+                file.ensure_open()?;
+                file.getvalue().into_value(vm.heap)?
+            }
+            StaticStrings::Close => {
+                args.check_zero_args("close", vm.heap)?;
+                self.get_mut(vm.heap).closed = true;
+                Value::None
+            }
+            StaticStrings::Flush => {
+                args.check_zero_args("flush", vm.heap)?;
+                if self.get(vm.heap).closed {
+                    return Err(closed_file_error());
+                }
+                Value::None
+            }
+            StaticStrings::Readable | StaticStrings::Writable | StaticStrings::Seekable => {
+                args.check_zero_args(attr.as_str(vm.interns), vm.heap)?;
+                self.get(vm.heap).ensure_open()?;
+                Value::Bool(true)
+            }
+            _ => {
+                args.drop_with_heap(vm);
+                return Err(ExcType::attribute_error(self.py_type(vm), attr.as_str(vm.interns)));
+            }
+        };
+        Ok(CallResult::Value(value))
+    }
+
+    fn py_is_context_manager(&self) -> bool {
+        true
+    }
+
+    fn py_enter(&mut self, self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<CallResult> {
+        self.get(vm.heap).ensure_open()?;
+        vm.heap.inc_ref(self_id);
+        Ok(CallResult::Value(Value::Ref(self_id)))
+    }
+
+    /// Closes the buffer; exceptions are never suppressed.
+    fn py_exit(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _exc: Option<HeapId>,
+    ) -> RunResult<CallResult> {
+        self.get_mut(vm.heap).closed = true;
+        Ok(CallResult::Value(Value::None))
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        match attr.static_string() {
+            Some(StaticStrings::Closed) => Ok(Some(CallResult::Value(Value::Bool(self.get(vm.heap).closed)))),
+            _ => Err(ExcType::attribute_error(self.py_type(vm), attr.as_str(vm.interns))),
+        }
+    }
+}
+
+impl<'h> HeapRead<'h, MemoryFile> {
+    /// Implements `read(size=-1)` and `readline(size=-1)`.
+    fn read(&mut self, vm: &mut VM<'h, impl ResourceTracker>, spec: ReadSpec, line: bool) -> RunResult<Value> {
+        let limit = match spec {
+            ReadSpec::Size(n) => Some(n),
+            _ => None,
+        };
+        let file = self.get_mut(vm.heap);
+        file.ensure_open()?;
+        file.read(limit, line).into_value(vm.heap)
+    }
+
+    /// Implements `readlines()`.
+    fn readlines(&mut self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+        if self.get(vm.heap).closed {
+            return Err(closed_file_error());
+        }
+        let mut lines = Vec::new();
+        loop {
+            let line = self.get_mut(vm.heap).read(None, true);
+            if line.is_empty() {
+                break;
+            }
+            lines.push(line.into_value(vm.heap)?);
+        }
+        Ok(Value::Ref(vm.heap.allocate(HeapData::List(List::new(lines)))?))
+    }
+
+    /// Implements `write(data)`, returning the number of chars or bytes written.
+    ///
+    /// The growth is checked against the resource tracker before the buffer is
+    /// touched, so a failed write leaves the file unchanged.
+    fn write(&mut self, data: &Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<usize> {
+        let chunk =
+            match self.get(vm.heap).buffer {
+                MemoryBuffer::Text { .. } => Chunk::Text(extract_str_payload(data, vm).ok_or_else(|| {
+                    ExcType::type_error(format!("string argument expected, got '{}'", data.py_type(vm)))
+                })?),
+                MemoryBuffer::Binary(_) if is_bytes(data, vm.heap) => {
+                    Chunk::Binary(extract_bytes_payload(data, vm).unwrap_or_default())
+                }
+                MemoryBuffer::Binary(_) => return Err(bytes_like_required(data, vm)),
+            };
+        let file = self.get_mut(vm.heap);
+        file.ensure_open()?;
+        let growth = file.write_growth(&chunk);
+        check_estimated_size(growth, vm.heap.tracker())?;
+        vm.heap.track_growth(growth)?;
+        Ok(self.get_mut(vm.heap).write(&chunk))
+    }
+
+    /// Implements `writelines(lines)`: writes each item, adding no separators.
+    fn writelines(&mut self, lines: Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<()> {
+        let iter = MontyIter::new(lines, vm)?;
+        defer_drop_mut!(iter, vm);
+        while let Some(line) = iter.for_next(vm)? {
+            defer_drop!(line, vm);
+            self.write(line, vm)?;
+        }
+        Ok(())
+    }
+
+    /// Implements `truncate(size=None)`, returning the new size.
+    fn truncate(&mut self, size: Option<Value>, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+        let size = match size {
+            None | Some(Value::None) => None,
+            Some(size) => {
+                defer_drop!(size, vm);
+                Some(size.as_int(vm)?)
+            }
+        };
+        let file = self.get_mut(vm.heap);
+        file.ensure_open()?;
+        let size = match size {
+            None => file.position,
+            Some(size) if size < 0 => {
+                return Err(
+                    SimpleException::new_msg(ExcType::ValueError, format!("Negative size value {size}")).into(),
+                );
+            }
+            Some(size) => usize::try_from(size).map_err(|_| ExcType::overflow_c_ssize_t())?,
+        };
+        file.truncate(size);
+        Ok(Value::Int(
+            i64::try_from(size).map_err(|_| ExcType::overflow_c_ssize_t())?,
+        ))
+    }
+}
+
+/// The `ValueError` raised by `IOBase` methods on a closed file.
+fn closed_file_error() -> RunError {
+    SimpleException::new_msg(ExcType::ValueError, "I/O operation on closed file.").into()
+}
+
+/// The `TypeError` raised when `BytesIO` is given something other than bytes.
+fn bytes_like_required(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> RunError {
+    ExcType::type_error(format!("a bytes-like object is required, not '{}'", value.py_type(vm)))
+}
@@ -0,0 +1,392 @@
+//! Python `memoryview` type: a read-only window onto a `bytes` or `bytearray`.
+//!
+//! A view holds a reference to its underlying object plus the `start`, `step`
+//! and length selected by any slicing, so slicing a view never copies. Views
+//! only support the one-dimensional unsigned-byte format (`'B'`).
+//!
+//! Views are always read-only, even over a `bytearray`, and do not pin the
+//! size of the `bytearray` they wrap: if it shrinks, positions past its new end
+//! are simply skipped when the view is read.
+use std::{borrow::Cow, fmt::Write};
+
+use ahash::AHashSet;
+
+use super::{
+    Bytes, List, PyTrait, Type,
+    bytes::{call_bytes_method_impl, get_byte_at_index},
+    slice::slice_length,
+};
+use crate::{
+    args::ArgValues,
+    bytecode::{CallResult, VM},
+    defer_drop,
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    hash::{HashValue, hash_python_bytes},
+    heap::{DropWithHeap, Heap, HeapData, HeapId, HeapItem, HeapRead},
+    intern::{StaticStrings, StringId},
+    resource::ResourceTracker,
+    value::{EitherStr, Value},
+};
+
+/// Python memoryview value stored on the heap.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct MemoryView {
+    /// The viewed object: always a `Value::Ref` to a heap `Bytes` or `ByteArray`.
+    ///
+    /// Interned bytes literals are copied to a heap `Bytes` when first viewed.
+    obj: Value,
+    /// Position in `obj` of the view's first byte.
+    start: usize,
+    /// Distance in `obj` between consecutive bytes of the view; never zero.
+    step: i64,
+    /// Number of bytes in the view.
+    len: usize,
+    /// Set by `release()`; every later operation except `released` raises `ValueError`.
+    released: bool,
+}
+
+impl MemoryView {
+    /// Creates a view from the `memoryview(obj)` constructor call.
+    ///
+    /// Viewing a memoryview creates a new view of the same object and range.
+    pub fn init(vm: &mut VM<'_, impl ResourceTracker>, args: ArgValues) -> RunResult<Value> {
+        let source = args.get_one_arg("memoryview", vm.heap)?;
+        let view = match source {
+            Value::InternBytes(bytes_id) => {
+                let bytes = vm.interns.get_bytes(bytes_id).to_vec();
+                let len = bytes.len();
+                let obj = Value::Ref(vm.heap.allocate(HeapData::Bytes(Bytes::new(bytes)))?);
+                Self::whole(obj, len)
+            }
+            Value::Ref(id) => match vm.heap.get(id) {
+                HeapData::Bytes(b) => {
+                    let len = b.len();
+                    Self::whole(source, len)
+                }
+                HeapData::ByteArray(b) => {
+                    let len = b.as_slice().len();
+                    Self::whole(source, len)
+                }
+                HeapData::MemoryView(view) => {
+                    if let Err(err) = view.check_released() {
+                        source.drop_with_heap(vm);
+                        return Err(err);
+                    }
+                    let copy = Self {
+                        obj: view.obj.clone_with_heap(vm.heap),
+                        start: view.start,
+                        step: view.step,
+                        len: view.len,
+                        released: false,
+                    };
+                    source.drop_with_heap(vm);
+                    copy
+                }
+                _ => return Err(not_bytes_like(source, vm)),
+            },
+            _ => return Err(not_bytes_like(source, vm)),
+        };
+        Ok(Value::Ref(vm.heap.allocate(HeapData::MemoryView(view))?))
+    }
+
+    /// Creates a view covering all `len` bytes of `obj`.
+    fn whole(obj: Value, len: usize) -> Self {
+        Self {
+            obj,
+            start: 0,
+            step: 1,
+            len,
+            released: false,
+        }
+    }
+
+    /// Returns the bytes selected by the view.
+    ///
+    /// Borrows from the underlying object when the view is contiguous.
+    pub fn contents<'a>(&self, heap: &'a Heap<impl ResourceTracker>) -> Cow<'a, [u8]> {
+        let data = self.backing(heap);
+        if self.step == 1 {
+            let end = self.start.saturating_add(self.len).min(data.len());
+            return Cow::Borrowed(&data[self.start.min(end)..end]);
+        }
+        Cow::Owned((0..self.len).filter_map(|i| self.byte_at(data, i)).collect())
+    }
+
+    /// Returns the number of items in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the byte at view position `index`, or `None` if it is past the end of the view
+    /// or the underlying buffer has since shrunk below it.
+    pub fn item(&self, heap: &Heap<impl ResourceTracker>, index: usize) -> Option<u8> {
+        if index >= self.len {
+            return None;
+        }
+        self.byte_at(self.backing(heap), index)
+    }
+
+    /// Returns the whole underlying byte buffer.
+    fn backing<'a>(&self, heap: &'a Heap<impl ResourceTracker>) -> &'a [u8] {
+        let id = self.obj.ref_id().expect("memoryview obj is always a heap reference");
+        match heap.get(id) {
+            HeapData::Bytes(b) => b.as_slice(),
+            HeapData::ByteArray(b) => b.as_slice(),
+            _ => unreachable!("memoryview obj must reference bytes or bytearray"),
+        }
+    }
+
+    /// Returns the byte at view position `i`, or `None` if it lies past the end of the buffer.
+    fn byte_at(&self, data: &[u8], i: usize) -> Option<u8> {
+        let offset = i64::try_from(i).ok()?.checked_mul(self.step)?;
+        let position = i64::try_from(self.start).ok()?.checked_add(offset)?;
+        get_byte_at_index(data, position).filter(|_| position >= 0)
+    }
+
+    /// Whether the viewed object is a (mutable) `bytearray`.
+    fn is_writable_source(&self, heap: &Heap<impl ResourceTracker>) -> bool {
+        let id = self.obj.ref_id().expect("memoryview obj is always a heap reference");
+        matches!(heap.get(id), HeapData::ByteArray(_))
+    }
+
+    /// Raises `ValueError` if `release()` has been called.
+    fn check_released(&self) -> RunResult<()> {
+        if self.released {
+            Err(
+                SimpleException::new_msg(ExcType::ValueError, "operation forbidden on released memoryview object")
+                    .into(),
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, MemoryView> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::MemoryView
+    }
+
+    fn py_len(&self, vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        Some(self.get(vm.heap).len())
+    }
+
+    fn py_getitem(&self, key: &Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+        let view = self.get(vm.heap);
+        view.check_released()?;
+
+        if let Value::Ref(id) = key
+            && let HeapData::Slice(slice) = vm.heap.get(*id)
+        {
+            let (start, stop, step) = slice.indices(view.len)?;
+            let count = slice_length(start, stop, step);
+            // An empty slice may start outside the view; pin it to 0 so `start` stays valid.
+            let first = if count == 0 {
+                0
+            } else {
+                i64::try_from(view.start).expect("memoryview start exceeds i64::MAX") + start * view.step
+            };
+            let sliced = MemoryView {
+                obj: view.obj.clone_with_heap(vm.heap),
+                start: usize::try_from(first).expect("non-empty slice starts inside the view"),
+                step: view.step * step,
+                len: count,
+                released: false,
+            };
+            return Ok(Value::Ref(vm.heap.allocate(HeapData::MemoryView(sliced))?));
+        }
+
+        let Ok(index) = key.as_index(vm, Type::MemoryView) else {
+            return Err(ExcType::type_error("memoryview: invalid slice key"));
+        };
+        let len = i64::try_from(view.len).expect("memoryview length exceeds i64::MAX");
+        let normalized = if index < 0 { index + len } else { index };
+        let byte = usize::try_from(normalized)
+            .ok()
+            .filter(|_| normalized < len)
+            .and_then(|i| view.byte_at(view.backing(vm.heap), i))
+            .ok_or_else(index_error)?;
+        Ok(Value::Int(i64::from(byte)))
+    }
+
+    fn py_setitem(&mut self, key: Value, value: Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<()> {
+        key.drop_with_heap(vm);
+        value.drop_with_heap(vm);
+        self.get(vm.heap).check_released()?;
+        Err(ExcType::type_error("cannot modify read-only memory"))
+    }
+
+    fn py_eq(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        let (a, b) = (self.get(vm.heap), other.get(vm.heap));
+        if a.released || b.released {
+            return Ok(false);
+        }
+        Ok(a.contents(vm.heap) == b.contents(vm.heap))
+    }
+
+    /// Hashes like the equivalent `bytes`; views over a `bytearray` are unhashable.
+    fn py_hash(&self, _self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let view = self.get(vm.heap);
+        view.check_released()?;
+        if view.is_writable_source(vm.heap) {
+            return Err(SimpleException::new_msg(ExcType::ValueError, "cannot hash writable memoryview object").into());
+        }
+        Ok(Some(hash_python_bytes(&view.contents(vm.heap))))
+    }
+
+    fn py_bool(&self, vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        self.get(vm.heap).len != 0
+    }
+
+    /// Unlike CPython, the repr carries no address: `<memory>` or `<released memory>`.
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        if self.get(vm.heap).released {
+            f.write_str("<released memory>")?;
+        } else {
+            f.write_str("<memory>")?;
+        }
+        Ok(())
+    }
+
+    fn py_call_attr(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        attr: &EitherStr,
+        args: ArgValues,
+    ) -> RunResult<CallResult> {
+        let Some(method) = attr.static_string() else {
+            args.drop_with_heap(vm);
+            return Err(ExcType::attribute_error(Type::MemoryView, attr.as_str(vm.interns)));
+        };
+
+        if method == StaticStrings::Release {
+            args.check_zero_args("memoryview.release", vm.heap)?;
+            self.get_mut(vm.heap).released = true;
+            return Ok(CallResult::Value(Value::None));
+        }
+        if let Err(err) = self.get(vm.heap).check_released() {
+            args.drop_with_heap(vm);
+            return Err(err);
+        }
+
+        let result = match method {
+            StaticStrings::Tobytes => {
+                args.check_zero_args("memoryview.tobytes", vm.heap)?;
+                let bytes = self.get(vm.heap).contents(vm.heap).into_owned();
+                Value::Ref(vm.heap.allocate(HeapData::Bytes(Bytes::new(bytes)))?)
+            }
+            StaticStrings::Tolist => {
+                args.check_zero_args("memoryview.tolist", vm.heap)?;
+                let items = self
+                    .get(vm.heap)
+                    .contents(vm.heap)
+                    .iter()
+                    .map(|b| Value::Int(i64::from(*b)))
+                    .collect();
+                Value::Ref(vm.heap.allocate(HeapData::List(List::new(items)))?)
+            }
+            StaticStrings::Toreadonly => {
+                args.check_zero_args("memoryview.toreadonly", vm.heap)?;
+                let view = self.get(vm.heap);
+                let copy = MemoryView {
+                    obj: view.obj.clone_with_heap(vm.heap),
+                    start: view.start,
+                    step: view.step,
+                    len: view.len,
+                    released: false,
+                };
+                Value::Ref(vm.heap.allocate(HeapData::MemoryView(copy))?)
+            }
+            StaticStrings::Hex => {
+                let contents = self.get(vm.heap).contents(vm.heap).into_owned();
+                call_bytes_method_impl(&vm.heap.protect(contents.as_slice()), Type::Bytes, method, args, vm)?
+            }
+            _ => {
+                args.drop_with_heap(vm);
+                return Err(ExcType::attribute_error(Type::MemoryView, attr.as_str(vm.interns)));
+            }
+        };
+        Ok(CallResult::Value(result))
+    }
+
+    fn py_is_context_manager(&self) -> bool {
+        true
+    }
+
+    fn py_enter(&mut self, self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<CallResult> {
+        self.get(vm.heap).check_released()?;
+        vm.heap.inc_ref(self_id);
+        Ok(CallResult::Value(Value::Ref(self_id)))
+    }
+
+    /// Releases the view; exceptions are never suppressed.
+    fn py_exit(
+        &mut self,
+        _self_id: HeapId,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _exc: Option<HeapId>,
+    ) -> RunResult<CallResult> {
+        self.get_mut(vm.heap).released = true;
+        Ok(CallResult::Value(Value::None))
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let view = self.get(vm.heap);
+        let value = match attr.static_string() {
+            Some(StaticStrings::Obj) => {
+                view.check_released()?;
+                view.obj.clone_with_heap(vm.heap)
+            }
+            Some(StaticStrings::Nbytes) => {
+                view.check_released()?;
+                Value::Int(i64::try_from(view.len).expect("memoryview length exceeds i64::MAX"))
+            }
+            Some(StaticStrings::Readonly) => {
+                view.check_released()?;
+                Value::Bool(true)
+            }
+            Some(StaticStrings::Itemsize) => {
+                view.check_released()?;
+                Value::Int(1)
+            }
+            Some(StaticStrings::Ndim) => {
+                view.check_released()?;
+                Value::Int(1)
+            }
+            Some(StaticStrings::Format) => {
+                view.check_released()?;
+                Value::InternString(StringId::from_ascii(b'B'))
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+}
+
+impl HeapItem for MemoryView {
+    fn py_estimate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.obj.py_dec_ref_ids(stack);
+    }
+}
+
+/// Creates the `TypeError` for `memoryview()` of a non-bytes-like object, dropping `source`.
+fn not_bytes_like(source: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunError {
+    defer_drop!(source, vm);
+    let t = source.py_type(vm);
+    ExcType::type_error(format!("memoryview: a bytes-like object is required, not '{t}'"))
+}
+
+/// Creates an IndexError for an out-of-range memoryview index.
+fn index_error() -> RunError {
+    SimpleException::new_msg(ExcType::IndexError, "index out of bounds on dimension 1").into()
+}
@@ -5,16 +5,28 @@
 ///
 /// The `AbstractValue` trait provides a common interface for all heap-allocated
 /// types, enabling efficient dispatch via `enum_dispatch`.
+pub mod base_object;
+pub mod bytearray;
 pub mod bytes;
+pub mod complex;
+pub mod contextlib;
 pub mod dataclass;
+pub mod dataclass_type;
 pub mod date;
 pub mod datetime;
 pub mod dict;
 pub mod dict_view;
 pub mod file;
+pub mod float;
+pub mod host_handle;
+pub mod host_type;
+pub mod int;
 pub mod iter;
 pub mod list;
+pub mod logger;
 pub mod long_int;
+pub mod memory_file;
+pub mod memoryview;
 pub mod module;
 pub mod namedtuple;
 pub mod path;
@@ -37,15 +49,26 @@ pub mod timedelta;
 pub mod timezone;
 pub mod tuple;
 pub mod r#type;
+pub mod typing_forms;
 
+pub(crate) use base_object::BaseObject;
+pub(crate) use bytearray::ByteArray;
 pub(crate) use bytes::Bytes;
+pub(crate) use complex::Complex;
+pub(crate) use contextlib::ContextManager;
 pub(crate) use dataclass::Dataclass;
+pub(crate) use dataclass_type::{DataclassField, DataclassOptions, DataclassType};
 pub(crate) use dict::Dict;
 pub(crate) use dict_view::{DictItemsView, DictKeysView, DictValuesView};
 pub(crate) use file::OpenFile;
+pub(crate) use host_handle::HostHandle;
+pub(crate) use host_type::HostType;
 pub(crate) use iter::MontyIter;
 pub(crate) use list::List;
+pub(crate) use logger::Logger;
 pub(crate) use long_int::LongInt;
+pub(crate) use memory_file::MemoryFile;
+pub(crate) use memoryview::MemoryView;
 pub(crate) use module::Module;
 pub(crate) use namedtuple::NamedTuple;
 pub(crate) use path::Path;
@@ -63,3 +86,4 @@ pub(crate) use timedelta::TimeDelta;
 pub(crate) use timezone::TimeZone;
 pub(crate) use tuple::{Tuple, allocate_tuple};
 pub(crate) use r#type::Type;
+pub(crate) use typing_forms::{GenericAlias, NewType, TypedDict};
@@ -7,7 +7,7 @@ use crate::{
     bytecode::{CallResult, VM},
     defer_drop,
     exception_private::{ExcType, RunResult},
-    heap::{HeapGuard, HeapId, HeapItem, HeapRead},
+    heap::{ContainsHeap, DropWithHeap, HeapGuard, HeapId, HeapItem, HeapRead},
     intern::StringId,
     resource::ResourceTracker,
     types::Dict,
@@ -18,33 +18,51 @@ use crate::{
 ///
 /// Modules in Monty are simplified compared to CPython - they just have a name
 /// and a dictionary of attributes. This is sufficient for built-in modules like
-/// `sys` and `typing` where we control the available attributes.
+/// `sys` and `typing` where we control the available attributes, and for
+/// host-defined modules built from a [`MontyModule`](crate::MontyModule).
+///
+/// Modules supplied as source files keep their attributes in their global slots
+/// instead, see [`MontyRun::with_file`](crate::MontyRun::with_file).
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct Module {
     /// The module name (e.g., "sys", "typing").
-    name: StringId,
+    ///
+    /// Heap-owned only for host-supplied modules whose name the code never mentions,
+    /// such as a submodule reached through attribute access.
+    name: EitherStr,
     /// The module's attributes (e.g., `version`, `platform` for `sys`).
     attrs: Dict,
+    /// Index of the supplied source module whose globals are this module's attributes.
+    #[serde(default)]
+    source: Option<u16>,
 }
 
 impl Module {
     /// Creates a new module with an empty attributes dictionary.
-    ///
-    /// The module name must be pre-interned during the prepare phase.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the module name string has not been pre-interned.
-    pub fn new(name: impl Into<StringId>) -> Self {
+    pub fn new(name: impl Into<EitherStr>) -> Self {
         Self {
             name: name.into(),
             attrs: Dict::new(),
+            source: None,
+        }
+    }
+
+    /// Creates the module object of the supplied source module at `index`.
+    pub fn from_source(name: impl Into<EitherStr>, index: u16) -> Self {
+        Self {
+            source: Some(index),
+            ..Self::new(name)
         }
     }
 
-    /// Returns the module's name StringId.
-    pub fn name(&self) -> StringId {
-        self.name
+    /// Returns the index of the supplied source module this module was created for.
+    pub fn source(&self) -> Option<u16> {
+        self.source
+    }
+
+    /// Returns the module's name.
+    pub fn name(&self) -> &EitherStr {
+        &self.name
     }
 
     /// Returns a reference to the module's attribute dictionary.
@@ -65,6 +83,14 @@ impl Module {
         self.attrs.set(key, value, vm).unwrap();
     }
 
+    /// Sets an attribute whose name may not be interned, as a `str` key value.
+    pub fn set_attr_value(&mut self, key: Value, value: Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<()> {
+        if let Some(old) = self.attrs.set(key, value, vm)? {
+            old.drop_with_heap(vm);
+        }
+        Ok(())
+    }
+
     /// Returns whether this module has any heap references in its attributes.
     pub fn has_refs(&self) -> bool {
         self.attrs.has_refs()
@@ -83,6 +109,11 @@ impl<'h> HeapRead<'h, Module> {
     /// For `Property` values, invokes the property getter rather than returning
     /// the Property itself - this implements Python's descriptor protocol.
     pub fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> Option<CallResult> {
+        if let Some(index) = self.get(vm.heap).source {
+            return vm
+                .source_module_attr(index, attr.as_str(vm.interns))
+                .map(CallResult::Value);
+        }
         let value = self
             .get(vm.heap)
             .attrs
@@ -117,21 +148,28 @@ impl<'h> HeapRead<'h, Module> {
             EitherStr::Interned(id) => vm.interns.get_str(*id),
             EitherStr::Heap(s) => {
                 return Err(ExcType::attribute_error_module(
-                    vm.interns.get_str(self.get(vm.heap).name),
+                    self.get(vm.heap).name.as_str(vm.interns),
                     s,
                 ));
             }
         };
 
-        match self.get(vm.heap).attrs().get_by_str(attr_str, vm.heap, vm.interns) {
+        let value = match self.get(vm.heap).source {
+            Some(index) => vm.source_module_attr(index, attr_str),
+            None => self
+                .get(vm.heap)
+                .attrs()
+                .get_by_str(attr_str, vm.heap, vm.interns)
+                .map(|value| value.clone_with_heap(vm)),
+        };
+        match value {
             Some(value) => {
-                let value = value.clone_with_heap(vm);
                 let (args, vm) = args_guard.into_parts();
                 defer_drop!(value, vm);
                 vm.call_function(value, args)
             }
             None => Err(ExcType::attribute_error_module(
-                vm.interns.get_str(self.get(vm.heap).name),
+                self.get(vm.heap).name.as_str(vm.interns),
                 attr.as_str(vm.interns),
             )),
         }
@@ -147,3 +185,9 @@ impl HeapItem for Module {
         self.attrs.py_dec_ref_ids(stack);
     }
 }
+
+impl DropWithHeap for Module {
+    fn drop_with_heap<H: ContainsHeap>(self, heap: &mut H) {
+        self.attrs.drop_with_heap(heap);
+    }
+}
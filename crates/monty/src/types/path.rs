@@ -585,8 +585,9 @@ impl<'h> PyTrait<'h> for HeapRead<'h, Path> {
                 // `Path.open(mode='r', ...)` is `open(self, mode, ...)` with
                 // `self` prepended as the implicit `file` argument. Reuses
                 // `builtin_open`'s mode/kwarg validation (including rejection
-                // of `+`/`x` modes and non-default `buffering`/`encoding`/
-                // `errors`/`newline`) so the two entry points stay in sync.
+                // of `+`/`x` modes and non-default `buffering`/`newline`) and
+                // its `encoding`/`errors` handling so the two entry points
+                // stay in sync.
                 //
                 // The `inc_ref` is required because the prepended `Value::Ref`
                 // is dropped by `builtin_open` via `defer_drop!` once the path
@@ -3,16 +3,24 @@
 //! `RePattern` wraps a compiled `fancy_regex::Regex` with the original Python pattern
 //! string and flags. The `fancy_regex` crate supports backreferences, lookahead/lookbehind,
 //! and other advanced features, but uses backtracking which means patterns are susceptible
-//! to ReDoS. Monty's resource limits (time and allocation budgets) are the primary defense
-//! against catastrophic backtracking in untrusted patterns.
+//! to ReDoS. Two budgets defend against catastrophic backtracking in untrusted patterns:
 //!
-//! Custom serde serializes only the pattern string and flags, recompiling the regex
-//! on deserialization. This supports Monty's snapshot/restore feature.
+//! - a per-call backtrack step limit (`ResourceTracker::regex_backtrack_limit`) baked into
+//!   the compiled regex; exceeding it raises `TimeoutError`
+//! - `ResourceTracker::check_time` before every match attempt, including between the
+//!   individual matches of `findall`/`sub`/`split`/`finditer`, so a call scanning a long
+//!   string stops promptly once `max_duration` is exhausted
+//!
+//! Each call also charges fuel for scanning the string once, plus one unit per match
+//! found by the iterating methods, so `max_fuel` reflects the work done.
+//!
+//! Custom serde serializes only the pattern string, flags and backtrack limit, recompiling
+//! the regex on deserialization. This supports Monty's snapshot/restore feature.
 
 use std::{borrow::Cow, fmt::Write, iter, mem, str};
 
 use ahash::AHashSet;
-use fancy_regex::Regex;
+use fancy_regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use smallvec::SmallVec;
 
@@ -20,11 +28,11 @@ use crate::{
     args::{ArgValues, FromArgs},
     bytecode::{CallResult, VM},
     defer_drop,
-    exception_private::{ExcType, RunResult},
-    heap::{Heap, HeapData, HeapId, HeapItem, HeapRead},
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
+    heap::{ContainsHeap, DropWithHeap, Heap, HeapData, HeapId, HeapItem, HeapRead},
     intern::StaticStrings,
     modules::re::{ASCII, DOTALL, IGNORECASE, MULTILINE},
-    resource::{ResourceTracker, check_estimated_size},
+    resource::{ResourceTracker, bytes_fuel, check_estimated_size},
     types::{
         List, PyTrait, ReMatch, Type, allocate_tuple,
         str::{allocate_string, string_repr_fmt},
@@ -36,17 +44,23 @@ use crate::{
 ///
 /// Wraps a `fancy_regex::Regex` with the original Python pattern string and flags.
 /// The `fancy_regex` crate supports backtracking features like backreferences and
-/// lookaround, but this means patterns are susceptible to ReDoS — Monty's resource
-/// limits are the defense against catastrophic backtracking.
+/// lookaround, but this means patterns are susceptible to ReDoS — the backtrack limit
+/// and Monty's time budget are the defense against catastrophic backtracking.
 ///
-/// Custom serde serializes only the pattern string and flags, recompiling the
-/// regex on deserialization. This supports Monty's snapshot/restore feature.
+/// Custom serde serializes only the pattern string, flags and backtrack limit,
+/// recompiling the regex on deserialization. This supports Monty's snapshot/restore feature.
 #[derive(Debug, Clone)]
 pub(crate) struct RePattern {
     /// The original Python regex pattern string.
     pattern: String,
     /// Python regex flags bitmask (IGNORECASE=2, MULTILINE=8, DOTALL=16, ASCII=256).
     flags: u16,
+    /// Backtracking step budget each compiled regex was built with.
+    ///
+    /// Captured from the tracker at compile time so a pattern keeps the budget it
+    /// was created under, including across snapshot/restore. `None` means
+    /// `fancy_regex`'s default.
+    backtrack_limit: Option<usize>,
     /// The compiled Rust regex, unanchored.
     compiled: Regex,
     /// The compiled regex anchored with `\A(?:...)` for `match()`.
@@ -78,16 +92,20 @@ impl RePattern {
     /// the pattern. Also pre-compiles anchored variants for `match` (`\A(?:pattern)`)
     /// and `fullmatch` (`\A(?:pattern)\z`) to correctly handle alternations.
     ///
+    /// `backtrack_limit` is the per-call backtracking budget, normally taken from
+    /// `ResourceTracker::regex_backtrack_limit`.
+    ///
     /// # Errors
     ///
     /// Returns `re.PatternError` if the pattern is invalid.
-    pub fn compile(pattern: String, flags: u16) -> RunResult<Self> {
-        let compiled = compile_regex(&pattern, flags)?;
-        let compiled_match = compile_regex(&format!("\\A(?:{pattern})"), flags)?;
-        let compiled_fullmatch = compile_regex(&format!("\\A(?:{pattern})\\z"), flags)?;
+    pub fn compile(pattern: String, flags: u16, backtrack_limit: Option<usize>) -> RunResult<Self> {
+        let compiled = compile_regex(&pattern, flags, backtrack_limit)?;
+        let compiled_match = compile_regex(&format!("\\A(?:{pattern})"), flags, backtrack_limit)?;
+        let compiled_fullmatch = compile_regex(&format!("\\A(?:{pattern})\\z"), flags, backtrack_limit)?;
         Ok(Self {
             pattern,
             flags,
+            backtrack_limit,
             compiled,
             compiled_match,
             compiled_fullmatch,
@@ -98,13 +116,15 @@ impl RePattern {
     ///
     /// Returns a `ReMatch` heap object on success, or `Value::None` if no match.
     pub fn search(&self, text: &str, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
+        heap.tracker().check_time()?;
+        heap.consume_fuel(bytes_fuel(text.len()))?;
         match self.compiled.captures(text) {
             Ok(Some(caps)) => {
                 let m = ReMatch::from_captures(&caps, text, &self.pattern, &self.compiled);
                 Ok(Value::Ref(heap.allocate(HeapData::ReMatch(m))?))
             }
             Ok(None) => Ok(Value::None),
-            Err(err) => Err(ExcType::re_pattern_error(err)),
+            Err(err) => Err(regex_exec_error(err, self.backtrack_limit)),
         }
     }
 
@@ -116,13 +136,15 @@ impl RePattern {
     ///
     /// Returns a `ReMatch` heap object on success, or `Value::None` if no match.
     pub fn match_start(&self, text: &str, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
+        heap.tracker().check_time()?;
+        heap.consume_fuel(bytes_fuel(text.len()))?;
         match self.compiled_match.captures(text) {
             Ok(Some(caps)) => {
                 let match_obj = ReMatch::from_captures(&caps, text, &self.pattern, &self.compiled);
                 Ok(Value::Ref(heap.allocate(HeapData::ReMatch(match_obj))?))
             }
             Ok(None) => Ok(Value::None),
-            Err(err) => Err(ExcType::re_pattern_error(err)),
+            Err(err) => Err(regex_exec_error(err, self.backtrack_limit)),
         }
     }
 
@@ -134,58 +156,87 @@ impl RePattern {
     ///
     /// Returns a `ReMatch` heap object on success, or `Value::None` if no match.
     pub fn fullmatch(&self, text: &str, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
+        heap.tracker().check_time()?;
+        heap.consume_fuel(bytes_fuel(text.len()))?;
         match self.compiled_fullmatch.captures(text) {
             Ok(Some(caps)) => {
                 let match_obj = ReMatch::from_captures(&caps, text, &self.pattern, &self.compiled);
                 Ok(Value::Ref(heap.allocate(HeapData::ReMatch(match_obj))?))
             }
             Ok(None) => Ok(Value::None),
-            Err(err) => Err(ExcType::re_pattern_error(err)),
+            Err(err) => Err(regex_exec_error(err, self.backtrack_limit)),
         }
     }
 
+    /// Returns whether the pattern matches at the start of `text`, without
+    /// allocating a match object.
+    ///
+    /// Used by internal callers such as the `warnings` filters.
+    pub fn is_match_start(&self, text: &str) -> RunResult<bool> {
+        self.compiled_match
+            .is_match(text)
+            .map_err(|err| regex_exec_error(err, self.backtrack_limit))
+    }
+
+    /// Returns whether the pattern matches the whole of `text`, without
+    /// allocating a match object.
+    pub fn is_fullmatch(&self, text: &str) -> RunResult<bool> {
+        self.compiled_fullmatch
+            .is_match(text)
+            .map_err(|err| regex_exec_error(err, self.backtrack_limit))
+    }
+
     /// `pattern.findall(string)` — return all non-overlapping matches.
     ///
     /// Follows CPython's semantics:
     /// - No capture groups: returns a list of matched strings
     /// - One capture group: returns a list of the group's matched strings
     /// - Multiple capture groups: returns a list of tuples of matched strings
-    pub fn findall(&self, text: &str, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
+    ///
+    /// Each result is allocated as soon as it's found and appended to `results`, so
+    /// `max_memory` and `max_allocations` apply while scanning; wrap `results` with
+    /// [`results_list`], which also releases them if the scan fails part-way.
+    pub fn findall(&self, text: &str, heap: &Heap<impl ResourceTracker>, results: &mut Vec<Value>) -> RunResult<()> {
         let cap_count = self.compiled.captures_len();
-        let mut results = Vec::new();
+        heap.consume_fuel(bytes_fuel(text.len()))?;
 
         match cap_count {
-            // No capture groups — return list of full match strings
+            // No capture groups — collect full match strings
             0 | 1 => {
                 for m in self.compiled.find_iter(text) {
-                    let val = m.map_err(ExcType::re_pattern_error)?.as_str();
-                    results.push(allocate_string(val, heap)?);
+                    heap.tracker().check_time()?;
+                    heap.consume_fuel(1)?;
+                    let m = m.map_err(|err| regex_exec_error(err, self.backtrack_limit))?;
+                    results.push(allocate_string(m.as_str(), heap)?);
                 }
             }
-            // One capture group — return list of the group's strings
+            // One capture group — collect the group's string
             2 => {
                 for caps in self.compiled.captures_iter(text) {
-                    let caps = caps.map_err(ExcType::re_pattern_error)?;
-                    let val = caps.get(1).map_or("", |m| m.as_str());
-                    results.push(allocate_string(val, heap)?);
+                    heap.tracker().check_time()?;
+                    heap.consume_fuel(1)?;
+                    let caps = caps.map_err(|err| regex_exec_error(err, self.backtrack_limit))?;
+                    results.push(allocate_string(caps.get(1).map_or("", |m| m.as_str()), heap)?);
                 }
             }
-            // Multiple capture groups — return list of tuples
+            // Multiple capture groups — collect a tuple of the groups' strings
             _ => {
                 for caps in self.compiled.captures_iter(text) {
-                    let caps = caps.map_err(ExcType::re_pattern_error)?;
-                    let mut elements: SmallVec<[Value; 3]> = SmallVec::with_capacity(cap_count - 1);
+                    heap.tracker().check_time()?;
+                    heap.consume_fuel(1)?;
+                    let caps = caps.map_err(|err| regex_exec_error(err, self.backtrack_limit))?;
+                    // Push the group strings onto `results` first so they're released
+                    // with the rest if a later allocation fails, then gather them.
+                    let start = results.len();
                     for cap in caps.iter().skip(1) {
-                        let val = cap.map_or("", |m| m.as_str());
-                        elements.push(allocate_string(val, heap)?);
+                        results.push(allocate_string(cap.map_or("", |m| m.as_str()), heap)?);
                     }
+                    let elements: SmallVec<[Value; 3]> = results.drain(start..).collect();
                     results.push(allocate_tuple(elements, heap)?);
                 }
             }
         }
-
-        let list = List::new(results);
-        Ok(Value::Ref(heap.allocate(HeapData::List(list))?))
+        Ok(())
     }
 
     /// `pattern.sub(repl, string, count=0)` — substitute matches with a replacement.
@@ -203,12 +254,15 @@ impl RePattern {
         // Translate Python-style backreferences (\1, \2) to regex crate style ($1, $2)
         let rust_repl = translate_replacement(repl);
         let effective_count = if count == 0 { usize::MAX } else { count };
+        heap.consume_fuel(bytes_fuel(text.len()))?;
 
         let mut result = String::new();
         let mut last_end = 0;
 
         for caps in self.compiled.captures_iter(text).take(effective_count) {
-            let caps = caps.map_err(ExcType::re_pattern_error)?;
+            heap.tracker().check_time()?;
+            heap.consume_fuel(1)?;
+            let caps = caps.map_err(|err| regex_exec_error(err, self.backtrack_limit))?;
             let m = caps.get(0).expect("capture group 0 always exists");
             result.push_str(&text[last_end..m.start()]);
             caps.expand(rust_repl.as_ref(), &mut result);
@@ -226,17 +280,14 @@ impl RePattern {
     /// Returns a list of strings. If `maxsplit` is non-zero, at most `maxsplit`
     /// splits occur and the remainder of the string is returned as the final element.
     pub fn split(&self, text: &str, maxsplit: usize, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
-        let pieces: Vec<&str> = if maxsplit == 0 {
-            self.compiled
-                .split(text)
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(ExcType::re_pattern_error)?
-        } else {
-            self.compiled
-                .splitn(text, maxsplit + 1)
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(ExcType::re_pattern_error)?
-        };
+        let limit = if maxsplit == 0 { usize::MAX } else { maxsplit + 1 };
+        heap.consume_fuel(bytes_fuel(text.len()))?;
+        let mut pieces: Vec<&str> = Vec::new();
+        for piece in self.compiled.splitn(text, limit) {
+            heap.tracker().check_time()?;
+            heap.consume_fuel(1)?;
+            pieces.push(piece.map_err(|err| regex_exec_error(err, self.backtrack_limit))?);
+        }
 
         let mut results = Vec::with_capacity(pieces.len());
         for piece in pieces {
@@ -252,17 +303,31 @@ impl RePattern {
     /// Eagerly collects all match objects into a list. This differs from CPython's
     /// lazy iterator but produces the same results when iterated. The VM's `GetIter`
     /// opcode handles iteration over the returned list.
-    pub fn finditer(&self, text: &str, heap: &Heap<impl ResourceTracker>) -> RunResult<Value> {
-        let mut results = Vec::new();
+    ///
+    /// Like [`findall`](Self::findall), each match object is allocated as soon as it's
+    /// found and appended to `results`; wrap them with [`results_list`].
+    pub fn finditer(&self, text: &str, heap: &Heap<impl ResourceTracker>, results: &mut Vec<Value>) -> RunResult<()> {
+        heap.consume_fuel(bytes_fuel(text.len()))?;
         for caps in self.compiled.captures_iter(text) {
-            let caps = caps.map_err(ExcType::re_pattern_error)?;
+            heap.tracker().check_time()?;
+            heap.consume_fuel(1)?;
+            let caps = caps.map_err(|err| regex_exec_error(err, self.backtrack_limit))?;
             let m = ReMatch::from_captures(&caps, text, &self.pattern, &self.compiled);
             results.push(Value::Ref(heap.allocate(HeapData::ReMatch(m))?));
         }
+        Ok(())
+    }
+}
 
-        let list = List::new(results);
-        Ok(Value::Ref(heap.allocate(HeapData::List(list))?))
+/// Wraps the values `findall` or `finditer` allocated into a list, or releases them
+/// and returns the error if the scan failed part-way.
+pub(crate) fn results_list(results: Vec<Value>, scan: RunResult<()>, heap: &mut impl ContainsHeap) -> RunResult<Value> {
+    if let Err(err) = scan {
+        results.drop_with_heap(heap);
+        return Err(err);
     }
+    let list = List::new(results);
+    Ok(Value::Ref(heap.heap().allocate(HeapData::List(list))?))
 }
 
 impl<'h> PyTrait<'h> for HeapRead<'h, RePattern> {
@@ -352,7 +417,9 @@ impl<'h> PyTrait<'h> for HeapRead<'h, RePattern> {
                 let arg = args.get_one_arg("Pattern.findall", vm.heap)?;
                 defer_drop!(arg, vm);
                 let text = value_to_str(arg, vm)?.into_owned();
-                self.get(vm.heap).findall(&text, vm.heap)
+                let mut results = Vec::new();
+                let scan = self.get(vm.heap).findall(&text, vm.heap, &mut results);
+                results_list(results, scan, vm)
             }
             Some(StaticStrings::Sub) => call_pattern_sub(self, args, vm),
             Some(StaticStrings::Split) => call_pattern_split(self, args, vm),
@@ -360,7 +427,9 @@ impl<'h> PyTrait<'h> for HeapRead<'h, RePattern> {
                 let arg = args.get_one_arg("Pattern.finditer", vm.heap)?;
                 defer_drop!(arg, vm);
                 let text = value_to_str(arg, vm)?.into_owned();
-                self.get(vm.heap).finditer(&text, vm.heap)
+                let mut results = Vec::new();
+                let scan = self.get(vm.heap).finditer(&text, vm.heap, &mut results);
+                results_list(results, scan, vm)
             }
             _ => return Err(ExcType::attribute_error(Type::RePattern, attr.as_str(vm.interns))),
         }?;
@@ -508,10 +577,12 @@ fn extract_maxsplit(val: Option<Value>, vm: &mut VM<'_, impl ResourceTracker>) -
 /// - `re.MULTILINE` (8) → `(?m)` prefix
 /// - `re.DOTALL` (16) → `(?s)` prefix
 ///
+/// When `backtrack_limit` is set it replaces `fancy_regex`'s default step budget.
+///
 /// # Errors
 ///
 /// Returns `re.PatternError(...)` if the pattern is invalid.
-pub(crate) fn compile_regex(pattern: &str, flags: u16) -> RunResult<Regex> {
+pub(crate) fn compile_regex(pattern: &str, flags: u16, backtrack_limit: Option<usize>) -> RunResult<Regex> {
     let mut prefix = String::new();
     if flags & IGNORECASE != 0 {
         prefix.push('i');
@@ -533,9 +604,35 @@ pub(crate) fn compile_regex(pattern: &str, flags: u16) -> RunResult<Regex> {
         format!("(?{prefix}){pattern}")
     };
 
-    Regex::new(&full_pattern).map_err(ExcType::re_pattern_error)
+    let mut builder = RegexBuilder::new(&full_pattern);
+    if let Some(limit) = backtrack_limit {
+        builder.backtrack_limit(limit);
+    }
+    builder.build().map_err(ExcType::re_pattern_error)
 }
 
+/// Converts an error raised while *executing* a regex into a Python exception.
+///
+/// Exhausting the backtrack budget becomes `TimeoutError` — the pattern is valid,
+/// it just costs more than the sandbox allows for one call. Anything else (e.g. the
+/// backtracking stack overflowing) is reported as `re.PatternError`, as before.
+fn regex_exec_error(err: fancy_regex::Error, backtrack_limit: Option<usize>) -> RunError {
+    match err {
+        fancy_regex::Error::RuntimeError(fancy_regex::RuntimeError::BacktrackLimitExceeded) => {
+            let limit = backtrack_limit.unwrap_or(DEFAULT_BACKTRACK_LIMIT);
+            SimpleException::new_msg(
+                ExcType::TimeoutError,
+                format!("regex backtrack limit exceeded ({limit} steps)"),
+            )
+            .into()
+        }
+        err => ExcType::re_pattern_error(err),
+    }
+}
+
+/// `fancy_regex`'s built-in backtrack limit, used in messages when no explicit limit is set.
+const DEFAULT_BACKTRACK_LIMIT: usize = 1_000_000;
+
 /// Translates Python-style replacement backreferences to `fancy_regex` syntax.
 ///
 /// Python uses `\1`, `\2`, `\g<1>`, `\g<name>` for backreferences in replacement strings.
@@ -658,14 +755,14 @@ pub(crate) fn value_to_str<'a>(val: &'a Value, vm: &'a VM<'_, impl ResourceTrack
 
 impl Serialize for RePattern {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        // Serialize only pattern string and flags; regex is recompiled on deserialize.
-        (&self.pattern, self.flags).serialize(serializer)
+        // Serialize only pattern string, flags and limit; regex is recompiled on deserialize.
+        (&self.pattern, self.flags, self.backtrack_limit).serialize(serializer)
     }
 }
 
 impl<'de> Deserialize<'de> for RePattern {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let (pattern, flags): (String, u16) = Deserialize::deserialize(deserializer)?;
-        Self::compile(pattern, flags).map_err(|e| de::Error::custom(format!("{e:?}")))
+        let (pattern, flags, backtrack_limit): (String, u16, Option<usize>) = Deserialize::deserialize(deserializer)?;
+        Self::compile(pattern, flags, backtrack_limit).map_err(|e| de::Error::custom(format!("{e:?}")))
     }
 }
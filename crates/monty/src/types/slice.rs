@@ -320,3 +320,15 @@ pub(crate) fn normalize_sequence_index(index: i64, len: usize) -> usize {
         usize::try_from(index).unwrap_or(len).min(len)
     }
 }
+
+/// Returns the number of items selected by `(start, stop, step)` as returned by [`Slice::indices`].
+pub(crate) fn slice_length(start: i64, stop: i64, step: i64) -> usize {
+    let count = if step > 0 && stop > start {
+        (stop - start - 1) / step + 1
+    } else if step < 0 && start > stop {
+        (start - stop - 1) / -step + 1
+    } else {
+        0
+    };
+    usize::try_from(count).expect("slice length is non-negative")
+}
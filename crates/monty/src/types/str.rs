@@ -7,21 +7,23 @@ use std::{borrow::Cow, cell::Cell, cmp::Ordering, fmt, fmt::Write, mem, ops};
 use ahash::AHashSet;
 use smallvec::smallvec;
 
-use super::{Bytes, MontyIter, PyTrait};
+use super::{Bytes, Dict, MontyIter, PyTrait};
 use crate::{
     args::{ArgValues, FromArgs},
     bytecode::{CallResult, VM},
+    codecs::Codec,
     defer_drop, defer_drop_mut,
-    exception_private::{ExcType, RunResult},
+    exception_private::{ExcType, RunError, RunResult, SimpleException},
     hash::{HashValue, hash_python_str},
     heap::{DropWithHeap, Heap, HeapData, HeapGuard, HeapId, HeapItem, HeapRead, heap_read_ref_as_field},
     intern::{StaticStrings, StringId},
-    resource::{ResourceError, ResourceTracker, check_replace_size},
+    resource::{ResourceError, ResourceTracker, bytes_fuel, check_estimated_size, check_replace_size},
     string_builder::StringBuilder,
     types::{
         Type,
         slice::{normalize_sequence_index, slice_collect_iterator},
     },
+    unicode::{self, Case},
     value::{EitherStr, Value},
 };
 
@@ -315,11 +317,6 @@ pub fn call_str_method(
 ///   which is complex and involves parsing format specifications like `{:>10.2f}`.
 /// - `format_map(mapping)` - Similar to `format()` but takes a mapping; depends on
 ///   `format()` implementation.
-/// - `maketrans()` / `translate()` - Character translation tables; moderate complexity,
-///   requires building and applying Unicode translation maps.
-/// - `expandtabs(tabsize=8)` - Tab expansion; simple but rarely used in practice.
-/// - `isprintable()` - Checks if all characters are printable; requires accurate Unicode
-///   category data for the "printable" property.
 fn call_str_method_impl<'h>(
     s: &HeapRead<'h, str>,
     method: StaticStrings,
@@ -418,6 +415,12 @@ fn call_str_method_impl<'h>(
         StaticStrings::Expandtabs => str_expandtabs(s, args, vm),
         // Additional methods
         StaticStrings::Encode => str_encode(s, args, vm),
+        StaticStrings::Translate => {
+            let table = args.get_one_arg("str.translate", vm.heap)?;
+            defer_drop!(table, vm);
+            str_translate(s, table, vm)
+        }
+        StaticStrings::Maketrans => str_maketrans(args, vm),
         StaticStrings::Isidentifier => {
             args.check_zero_args("str.isidentifier", vm.heap)?;
             Ok(Value::Bool(str_isidentifier(s.get(vm.heap))))
@@ -426,6 +429,10 @@ fn call_str_method_impl<'h>(
             args.check_zero_args("str.istitle", vm.heap)?;
             Ok(Value::Bool(str_istitle(s.get(vm.heap))))
         }
+        StaticStrings::Isprintable => {
+            args.check_zero_args("str.isprintable", vm.heap)?;
+            Ok(Value::Bool(str_isprintable(s.get(vm.heap))))
+        }
         // Existing method
         StaticStrings::Join => {
             let iterable = args.get_one_arg("str.join", vm.heap)?;
@@ -493,6 +500,8 @@ fn str_join<'h>(
         index += 1;
     }
 
+    // Copying the joined text costs fuel on top of the per-item iteration.
+    vm.heap.consume_fuel(bytes_fuel(result.len()))?;
     // Allocate result (uses interned empty string if result is empty)
     Ok(allocate_string(result, vm.heap)?)
 }
@@ -551,48 +560,59 @@ impl fmt::Display for StringRepr<'_> {
 // =============================================================================
 
 /// Implements Python's `str.lower()` method.
+///
+/// Uses the full Unicode lowercase mappings (`'İ'` becomes two characters) and
+/// lowers a capital sigma at the end of a word to `'ς'`.
 fn str_lower(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
-    Ok(allocate_string(s.to_lowercase(), vm.heap)?)
+    if s.is_ascii() {
+        return Ok(allocate_string(s.to_ascii_lowercase(), vm.heap)?);
+    }
+    let chars: Vec<char> = s.chars().collect();
+    let mut result = String::with_capacity(s.len());
+    for i in 0..chars.len() {
+        unicode::push_lower_at(&chars, i, &mut result);
+    }
+    Ok(allocate_string(result, vm.heap)?)
 }
 
-/// Implements Python's `str.upper()` method.
+/// Implements Python's `str.upper()` method, using the full Unicode uppercase
+/// mappings (`'ß'` becomes `'SS'`).
 fn str_upper(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
-    Ok(allocate_string(s.to_uppercase(), vm.heap)?)
+    Ok(allocate_string(map_case(s, Case::Upper), vm.heap)?)
 }
 
 /// Implements Python's `str.capitalize()` method.
 ///
-/// Returns a copy of the string with its first character capitalized and the rest lowercased.
+/// Returns a copy of the string with its first character titlecased and the rest lowercased.
 fn str_capitalize(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
-    let mut chars = s.chars();
-    let result = match chars.next() {
-        None => String::new(),
-        Some(first) => {
-            let mut result = first.to_uppercase().to_string();
-            for c in chars {
-                result.extend(c.to_lowercase());
-            }
-            result
+    let chars: Vec<char> = s.chars().collect();
+    let mut result = String::with_capacity(s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if i == 0 {
+            unicode::push_case(c, Case::Title, &mut result);
+        } else {
+            unicode::push_lower_at(&chars, i, &mut result);
         }
-    };
+    }
     Ok(allocate_string(result, vm.heap)?)
 }
 
 /// Implements Python's `str.title()` method.
 ///
-/// Returns a titlecased version of the string where words start with an uppercase
-/// character and the remaining characters are lowercase.
+/// Titlecases the first cased character after each uncased one and lowercases
+/// the rest, so `"they're"` becomes `"They'Re"` as in CPython.
 fn str_title(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let chars: Vec<char> = s.chars().collect();
     let mut result = String::with_capacity(s.len());
     let mut prev_is_cased = false;
 
-    for c in s.chars() {
+    for (i, &c) in chars.iter().enumerate() {
         if prev_is_cased {
-            result.extend(c.to_lowercase());
+            unicode::push_lower_at(&chars, i, &mut result);
         } else {
-            result.extend(c.to_uppercase());
+            unicode::push_case(c, Case::Title, &mut result);
         }
-        prev_is_cased = c.is_alphabetic();
+        prev_is_cased = unicode::is_cased(c);
     }
 
     Ok(allocate_string(result, vm.heap)?)
@@ -602,13 +622,14 @@ fn str_title(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
 ///
 /// Returns a copy of the string with uppercase characters converted to lowercase and vice versa.
 fn str_swapcase(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let chars: Vec<char> = s.chars().collect();
     let mut result = String::with_capacity(s.len());
 
-    for c in s.chars() {
-        if c.is_uppercase() {
-            result.extend(c.to_lowercase());
-        } else if c.is_lowercase() {
-            result.extend(c.to_uppercase());
+    for (i, &c) in chars.iter().enumerate() {
+        if unicode::is_upper(c) {
+            unicode::push_lower_at(&chars, i, &mut result);
+        } else if unicode::is_lower(c) {
+            unicode::push_case(c, Case::Upper, &mut result);
         } else {
             result.push(c);
         }
@@ -620,10 +641,25 @@ fn str_swapcase(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value>
 /// Implements Python's `str.casefold()` method.
 ///
 /// Returns a casefolded copy of the string. Casefolding is similar to lowercasing
-/// but more aggressive because it is intended for caseless string matching.
+/// but more aggressive because it is intended for caseless string matching
+/// (`'ß'` folds to `'ss'`).
 fn str_casefold(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value> {
-    // Rust's to_lowercase() is equivalent to Unicode casefolding for most purposes
-    Ok(allocate_string(s.to_lowercase(), vm.heap)?)
+    if s.is_ascii() {
+        return Ok(allocate_string(s.to_ascii_lowercase(), vm.heap)?);
+    }
+    Ok(allocate_string(map_case(s, Case::Fold), vm.heap)?)
+}
+
+/// Applies a context-free full case mapping to every character of `s`.
+fn map_case(s: &str, case: Case) -> String {
+    if s.is_ascii() && matches!(case, Case::Upper) {
+        return s.to_ascii_uppercase();
+    }
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        unicode::push_case(c, case, &mut result);
+    }
+    result
 }
 
 // =============================================================================
@@ -634,7 +670,7 @@ fn str_casefold(s: &str, vm: &VM<'_, impl ResourceTracker>) -> RunResult<Value>
 ///
 /// Returns True if all characters in the string are alphabetic and there is at least one character.
 fn str_isalpha(s: &str) -> bool {
-    !s.is_empty() && s.chars().all(char::is_alphabetic)
+    !s.is_empty() && s.chars().all(unicode::is_alpha)
 }
 
 /// Implements Python's `str.isdigit()` method.
@@ -643,30 +679,33 @@ fn str_isalpha(s: &str) -> bool {
 /// In Python, digits include decimal digits (Nd) plus characters with Numeric_Type=Digit
 /// (superscripts, subscripts, circled digits, etc.).
 fn str_isdigit(s: &str) -> bool {
-    !s.is_empty() && s.chars().all(is_unicode_digit)
+    !s.is_empty() && s.chars().all(unicode::is_digit)
 }
 
 /// Implements Python's `str.isalnum()` method.
 ///
-/// Returns True if all characters in the string are alphanumeric and there is at least one character.
+/// Returns True if every character is alphabetic, decimal, a digit or numeric
+/// and there is at least one character.
 fn str_isalnum(s: &str) -> bool {
-    !s.is_empty() && s.chars().all(char::is_alphanumeric)
+    !s.is_empty()
+        && s.chars()
+            .all(|c| unicode::is_alpha(c) || unicode::is_decimal(c) || unicode::is_digit(c) || unicode::is_numeric(c))
 }
 
 /// Implements Python's `str.isnumeric()` method.
 ///
 /// Returns True if all characters in the string are numeric and there is at least one character.
-/// In Python, numeric includes decimal digits (Nd), letter numerals (Nl), and other numerals (No).
-/// Rust's `char::is_numeric()` checks for all of these categories.
+/// Numeric characters are those with a Unicode numeric value: digits plus
+/// fractions, Roman numerals, CJK numerals and so on.
 fn str_isnumeric(s: &str) -> bool {
-    !s.is_empty() && s.chars().all(char::is_numeric)
+    !s.is_empty() && s.chars().all(unicode::is_numeric)
 }
 
 /// Implements Python's `str.isspace()` method.
 ///
 /// Returns True if all characters in the string are whitespace and there is at least one character.
 fn str_isspace(s: &str) -> bool {
-    !s.is_empty() && s.chars().all(char::is_whitespace)
+    !s.is_empty() && s.chars().all(unicode::is_space)
 }
 
 /// Implements Python's `str.islower()` method.
@@ -675,10 +714,10 @@ fn str_isspace(s: &str) -> bool {
 fn str_islower(s: &str) -> bool {
     let mut has_cased = false;
     for c in s.chars() {
-        if c.is_uppercase() {
+        if unicode::is_upper(c) || unicode::is_title(c) {
             return false;
         }
-        if c.is_lowercase() {
+        if unicode::is_lower(c) {
             has_cased = true;
         }
     }
@@ -691,10 +730,10 @@ fn str_islower(s: &str) -> bool {
 fn str_isupper(s: &str) -> bool {
     let mut has_cased = false;
     for c in s.chars() {
-        if c.is_lowercase() {
+        if unicode::is_lower(c) || unicode::is_title(c) {
             return false;
         }
-        if c.is_uppercase() {
+        if unicode::is_upper(c) {
             has_cased = true;
         }
     }
@@ -707,175 +746,16 @@ fn str_isupper(s: &str) -> bool {
 /// Decimal characters are those in Unicode category Nd (Decimal_Number) - digits that can be used
 /// to form numbers in base 10.
 fn str_isdecimal(s: &str) -> bool {
-    !s.is_empty() && s.chars().all(is_unicode_decimal)
-}
-
-/// Checks if a character is a Unicode decimal digit (Nd category).
-///
-/// This covers decimal digit ranges from various scripts including ASCII, Arabic-Indic,
-/// Devanagari, Bengali, Thai, Fullwidth, and many others.
-fn is_unicode_decimal(c: char) -> bool {
-    let cp = c as u32;
-    matches!(
-        cp,
-        // Basic Latin (ASCII digits)
-        0x0030..=0x0039
-        // Arabic-Indic digits
-        | 0x0660..=0x0669
-        // Extended Arabic-Indic digits
-        | 0x06F0..=0x06F9
-        // NKo digits
-        | 0x07C0..=0x07C9
-        // Devanagari digits
-        | 0x0966..=0x096F
-        // Bengali digits
-        | 0x09E6..=0x09EF
-        // Gurmukhi digits
-        | 0x0A66..=0x0A6F
-        // Gujarati digits
-        | 0x0AE6..=0x0AEF
-        // Oriya digits
-        | 0x0B66..=0x0B6F
-        // Tamil digits
-        | 0x0BE6..=0x0BEF
-        // Telugu digits
-        | 0x0C66..=0x0C6F
-        // Kannada digits
-        | 0x0CE6..=0x0CEF
-        // Malayalam digits
-        | 0x0D66..=0x0D6F
-        // Sinhala Lith digits
-        | 0x0DE6..=0x0DEF
-        // Thai digits
-        | 0x0E50..=0x0E59
-        // Lao digits
-        | 0x0ED0..=0x0ED9
-        // Tibetan digits
-        | 0x0F20..=0x0F29
-        // Myanmar digits
-        | 0x1040..=0x1049
-        // Myanmar Shan digits
-        | 0x1090..=0x1099
-        // Khmer digits
-        | 0x17E0..=0x17E9
-        // Mongolian digits
-        | 0x1810..=0x1819
-        // Limbu digits
-        | 0x1946..=0x194F
-        // New Tai Lue digits
-        | 0x19D0..=0x19D9
-        // Tai Tham Hora digits
-        | 0x1A80..=0x1A89
-        // Tai Tham Tham digits
-        | 0x1A90..=0x1A99
-        // Balinese digits
-        | 0x1B50..=0x1B59
-        // Sundanese digits
-        | 0x1BB0..=0x1BB9
-        // Lepcha digits
-        | 0x1C40..=0x1C49
-        // Ol Chiki digits
-        | 0x1C50..=0x1C59
-        // Vai digits
-        | 0xA620..=0xA629
-        // Saurashtra digits
-        | 0xA8D0..=0xA8D9
-        // Kayah Li digits
-        | 0xA900..=0xA909
-        // Javanese digits
-        | 0xA9D0..=0xA9D9
-        // Myanmar Tai Laing digits
-        | 0xA9F0..=0xA9F9
-        // Cham digits
-        | 0xAA50..=0xAA59
-        // Meetei Mayek digits
-        | 0xABF0..=0xABF9
-        // Fullwidth digits
-        | 0xFF10..=0xFF19
-        // Osmanya digits
-        | 0x104A0..=0x104A9
-        // Hanifi Rohingya digits
-        | 0x10D30..=0x10D39
-        // Brahmi digits
-        | 0x11066..=0x1106F
-        // Sora Sompeng digits
-        | 0x110F0..=0x110F9
-        // Chakma digits
-        | 0x11136..=0x1113F
-        // Sharada digits
-        | 0x111D0..=0x111D9
-        // Khudawadi digits
-        | 0x112F0..=0x112F9
-        // Newa digits
-        | 0x11450..=0x11459
-        // Tirhuta digits
-        | 0x114D0..=0x114D9
-        // Modi digits
-        | 0x11650..=0x11659
-        // Takri digits
-        | 0x116C0..=0x116C9
-        // Ahom digits
-        | 0x11730..=0x11739
-        // Warang Citi digits
-        | 0x118E0..=0x118E9
-        // Dives Akuru digits
-        | 0x11950..=0x11959
-        // Bhaiksuki digits
-        | 0x11C50..=0x11C59
-        // Masaram Gondi digits
-        | 0x11D50..=0x11D59
-        // Gunjala Gondi digits
-        | 0x11DA0..=0x11DA9
-        // Adlam digits
-        | 0x1E950..=0x1E959
-        // Segmented digits
-        | 0x1FBF0..=0x1FBF9
-    )
-}
-
-/// Checks if a character is a Unicode digit (isdigit).
-///
-/// This includes decimal digits (Nd) plus characters with Numeric_Type=Digit
-/// such as superscripts, subscripts, and circled digits.
-fn is_unicode_digit(c: char) -> bool {
-    // First check if it's a decimal digit
-    if is_unicode_decimal(c) {
-        return true;
-    }
-
-    let cp = c as u32;
-    matches!(
-        cp,
-        // Superscripts (², ³)
-        0x00B2..=0x00B3
-        // Superscript 1
-        | 0x00B9
-        // Superscript digits 0, 4-9
-        | 0x2070
-        | 0x2074..=0x2079
-        // Subscript digits 0-9
-        | 0x2080..=0x2089
-        // Circled digits 1-9
-        | 0x2460..=0x2468
-        // Circled digit 0
-        | 0x24EA
-        // Circled digits 10-20
-        | 0x2469..=0x2473
-        // Parenthesized digits 1-9
-        | 0x2474..=0x247C
-        // Period digits 1-9
-        | 0x2488..=0x2490
-        // Double circled digits 1-10
-        | 0x24F5..=0x24FE
-        // Dingbat circled sans-serif digits 1-10
-        | 0x2780..=0x2789
-        // Dingbat negative circled digits 1-10
-        | 0x278A..=0x2793
-        // Dingbat circled sans-serif digits 1-10
-        | 0x24FF
-        // Fullwidth digit zero (already in decimal, but include for completeness)
-        // | 0xFF10..=0xFF19  // Already covered by is_unicode_decimal
-    )
+    !s.is_empty() && s.chars().all(unicode::is_decimal)
+}
+
+/// Implements Python's `str.isprintable()` method.
+///
+/// Returns True if no character is a control, format, separator (other than
+/// the ASCII space), surrogate, private-use or unassigned character. The empty
+/// string is printable.
+fn str_isprintable(s: &str) -> bool {
+    s.chars().all(unicode::is_printable)
 }
 
 // =============================================================================
@@ -1907,26 +1787,12 @@ struct ExpandtabsArgs {
 
 /// Implements Python's `str.encode(encoding='utf-8', errors='strict')` method.
 ///
-/// Returns an encoded version of the string as a bytes object. Only supports
-/// UTF-8 encoding (the native encoding for Rust strings).
+/// Returns an encoded version of the string as a bytes object, using the
+/// codecs in [`crate::codecs`].
 fn str_encode<'h>(s: &HeapRead<'h, str>, args: ArgValues, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
     let EncodeArgs { encoding, errors } = EncodeArgs::from_args(args, vm)?;
-    let encoding = encoding.unwrap_or_else(|| "utf-8".to_owned());
-    let errors = errors.unwrap_or_else(|| "strict".to_owned());
-
-    // Only UTF-8 is supported - Rust strings are always valid UTF-8
-    let encoding_lower = encoding.to_ascii_lowercase();
-    if encoding_lower != "utf-8" && encoding_lower != "utf8" {
-        return Err(ExcType::lookup_error_unknown_encoding(&encoding));
-    }
-
-    // For UTF-8 encoding of a valid UTF-8 string, errors mode doesn't matter
-    // since there's nothing to handle - the string is already valid UTF-8
-    if errors != "strict" && errors != "ignore" && errors != "replace" && errors != "backslashreplace" {
-        return Err(ExcType::lookup_error_unknown_error_handler(&errors));
-    }
-
-    let bytes = s.get(vm.heap).as_bytes().to_vec();
+    let codec = Codec::lookup(encoding.as_deref().unwrap_or("utf-8"))?;
+    let bytes = codec.encode(s.get(vm.heap), errors.as_deref().unwrap_or("strict"), vm.heap.tracker())?;
     let heap_id = vm.heap.allocate(HeapData::Bytes(Bytes::new(bytes)))?;
     Ok(Value::Ref(heap_id))
 }
@@ -1949,42 +1815,174 @@ struct EncodeArgs {
     errors: Option<String>,
 }
 
-/// Implements Python's `str.isidentifier()` predicate.
+/// Implements Python's `str.translate(table)` method.
 ///
-/// Returns True if the string is a valid Python identifier according to
-/// the language definition (starts with letter or underscore, followed by
-/// letters, digits, or underscores). Empty strings return False.
-fn str_isidentifier(s: &str) -> bool {
-    if s.is_empty() {
-        return false;
+/// Looks up each character's code point in `table`: an int maps to that
+/// character, a str replaces it, `None` deletes it, and a `LookupError` (e.g. a
+/// missing dict key) keeps it unchanged.
+fn str_translate<'h>(s: &HeapRead<'h, str>, table: &Value, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Value> {
+    // `table.__getitem__` needs the VM, so the source can't stay borrowed from the heap.
+    let source = s.get(vm.heap).to_owned();
+    let mut result = String::with_capacity(source.len());
+    for c in source.chars() {
+        vm.heap.check_time()?;
+        let mapped = match table.py_getitem(&Value::Int(i64::from(u32::from(c))), vm) {
+            Ok(mapped) => mapped,
+            Err(RunError::Exc(e)) if e.exc.exc_type().is_subclass_of(ExcType::LookupError) => {
+                result.push(c);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        defer_drop!(mapped, vm);
+        match mapped {
+            Value::None => {}
+            Value::Int(n) => {
+                let c = u32::try_from(*n).ok().and_then(char::from_u32).ok_or_else(|| {
+                    SimpleException::new_msg(ExcType::ValueError, "character mapping must be in range(0x110000)")
+                })?;
+                result.push(c);
+            }
+            _ => match mapped.as_either_str(vm.heap) {
+                Some(replacement) => result.push_str(replacement.as_str(vm.interns)),
+                None => {
+                    return Err(ExcType::type_error(
+                        "character mapping must return integer, None or str",
+                    ));
+                }
+            },
+        }
+        // Replacements can grow the result without bound, so re-check the limit
+        // each time the buffer doubles.
+        if result.len().is_power_of_two() {
+            check_estimated_size(result.len(), vm.heap.tracker())?;
+        }
     }
+    Ok(allocate_string(result, vm.heap)?)
+}
 
-    let mut chars = s.chars();
-
-    // First character must be a letter (Unicode) or underscore
-    let first = chars.next().unwrap();
-    if !is_xid_start(first) && first != '_' {
-        return false;
+/// Implements Python's `str.maketrans(x[, y[, z]])` static method.
+///
+/// With one argument, `x` is a dict whose single-character keys are converted
+/// to code points. With two, each character of `x` maps to the character of
+/// `y` at the same index, and each character of the optional third argument
+/// maps to `None`. Callable on both `str` and str instances.
+pub(crate) fn str_maketrans(args: ArgValues, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let MaketransArgs { x, y, z } = MaketransArgs::from_args(args, vm)?;
+    defer_drop!(x, vm);
+    defer_drop!(y, vm);
+    defer_drop!(z, vm);
+
+    let mut dict_guard = HeapGuard::new(Dict::new(), vm);
+    let (dict, vm) = dict_guard.as_parts_mut();
+    if let Some(y) = y {
+        let (Some(from), Some(to)) = (x.as_either_str(vm.heap), y.as_either_str(vm.heap)) else {
+            return Err(if x.is_str(vm.heap) {
+                ExcType::type_error(format!("maketrans() argument 2 must be str, not {}", y.py_type(vm)))
+            } else {
+                ExcType::type_error("first maketrans argument must be a string if there is a second argument")
+            });
+        };
+        let (from, to) = (from.as_str(vm.interns), to.as_str(vm.interns));
+        if from.chars().count() != to.chars().count() {
+            return Err(SimpleException::new_msg(
+                ExcType::ValueError,
+                "the first two maketrans arguments must have equal length",
+            )
+            .into());
+        }
+        let pairs: Vec<(char, Option<char>)> = from.chars().zip(to.chars().map(Some)).collect();
+        let deleted: Vec<char> = match z {
+            Some(z) => match z.as_either_str(vm.heap) {
+                Some(z) => z.as_str(vm.interns).chars().collect(),
+                None => {
+                    return Err(ExcType::type_error(format!(
+                        "maketrans() argument 3 must be str, not {}",
+                        z.py_type(vm)
+                    )));
+                }
+            },
+            None => Vec::new(),
+        };
+        for (key, value) in pairs.into_iter().chain(deleted.into_iter().map(|c| (c, None))) {
+            let value = value.map_or(Value::None, |c| Value::Int(i64::from(u32::from(c))));
+            let old = dict.set(Value::Int(i64::from(u32::from(key))), value, vm)?;
+            old.drop_with_heap(vm);
+        }
+    } else {
+        let Value::Ref(source_id) = x else {
+            return Err(ExcType::type_error(
+                "if you give only one argument to maketrans it must be a dict",
+            ));
+        };
+        let HeapData::Dict(source) = vm.heap.get(*source_id) else {
+            return Err(ExcType::type_error(
+                "if you give only one argument to maketrans it must be a dict",
+            ));
+        };
+        for i in 0..source.len() {
+            let entry = |vm: &VM<'_, _>, value: bool| {
+                let HeapData::Dict(source) = vm.heap.get(*source_id) else {
+                    unreachable!("maketrans source checked to be a dict");
+                };
+                let entry = if value { source.value_at(i) } else { source.key_at(i) };
+                entry.expect("index within dict length").clone_with_heap(vm)
+            };
+            let key = entry(vm, false);
+            let key = match key.as_either_str(vm.heap) {
+                Some(s) => {
+                    key.drop_with_heap(vm);
+                    let mut chars = s.as_str(vm.interns).chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Value::Int(i64::from(u32::from(c))),
+                        _ => {
+                            return Err(SimpleException::new_msg(
+                                ExcType::ValueError,
+                                "string keys in translate table must be of length 1",
+                            )
+                            .into());
+                        }
+                    }
+                }
+                None if matches!(key.py_type(vm), Type::Int | Type::Bool) => key,
+                None => {
+                    key.drop_with_heap(vm);
+                    return Err(ExcType::type_error(
+                        "keys in translate table must be strings or integers",
+                    ));
+                }
+            };
+            let value = entry(vm, true);
+            let old = dict.set(key, value, vm)?;
+            old.drop_with_heap(vm);
+        }
     }
 
-    // Remaining characters must be letters, digits (Unicode), or underscores
-    chars.all(is_xid_continue)
+    let dict = dict_guard.into_inner();
+    let heap_id = vm.heap.allocate(HeapData::Dict(dict))?;
+    Ok(Value::Ref(heap_id))
 }
 
-/// Checks if a character is valid at the start of an identifier (XID_Start).
-///
-/// This is a simplified implementation that covers ASCII and common Unicode letters.
-/// Python uses the full Unicode XID_Start property.
-fn is_xid_start(c: char) -> bool {
-    c.is_alphabetic()
+/// Argument shape for `str.maketrans(x, y=..., z=..., /)`.
+#[derive(FromArgs)]
+#[from_args(name = "maketrans")]
+struct MaketransArgs {
+    #[from_args(pos_only)]
+    x: Value,
+    #[from_args(pos_only, default)]
+    y: Option<Value>,
+    #[from_args(pos_only, default)]
+    z: Option<Value>,
 }
 
-/// Checks if a character is valid in the continuation of an identifier (XID_Continue).
+/// Implements Python's `str.isidentifier()` predicate.
 ///
-/// This is a simplified implementation that covers ASCII and common Unicode.
-/// Python uses the full Unicode XID_Continue property.
-fn is_xid_continue(c: char) -> bool {
-    c.is_alphanumeric() || c == '_'
+/// Returns True if the string is a valid Python identifier according to
+/// the language definition: an XID_Start character or underscore followed by
+/// XID_Continue characters. Empty strings return False.
+fn str_isidentifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(unicode::is_identifier_start) && chars.all(unicode::is_identifier_continue)
 }
 
 /// Implements Python's `str.istitle()` predicate.
@@ -2001,14 +1999,14 @@ fn str_istitle(s: &str) -> bool {
     let mut has_cased = false;
 
     for c in s.chars() {
-        if c.is_uppercase() {
-            // Uppercase must follow uncased
+        if unicode::is_upper(c) || unicode::is_title(c) {
+            // Uppercase and titlecase must follow uncased
             if prev_cased {
                 return false;
             }
             prev_cased = true;
             has_cased = true;
-        } else if c.is_lowercase() {
+        } else if unicode::is_lower(c) {
             // Lowercase must follow cased
             if !prev_cased {
                 return false;
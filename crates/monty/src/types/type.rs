@@ -11,9 +11,18 @@ use crate::{
     intern::{StaticStrings, StringId},
     resource::ResourceTracker,
     types::{
-        AttrCallResult, Bytes, Dict, FrozenSet, List, LongInt, MontyIter, Path, PyTrait, Range, Set, Slice, Str,
-        TimeZone, Tuple, bytes::bytes_fromhex, date, datetime, dict::dict_fromkeys, long_int::INT_MAX_STR_DIGITS,
-        str::StringRepr, timedelta,
+        AttrCallResult, ByteArray, Bytes, Dict, FrozenSet, List, LongInt, MemoryView, MontyIter, Path, PyTrait, Range,
+        Set, Slice, Str, TimeZone, Tuple, base_object,
+        bytes::{bytes_fromhex, bytes_maketrans},
+        complex, contextlib, date, datetime,
+        dict::dict_fromkeys,
+        float,
+        host_handle::checked_truth,
+        int, logger,
+        long_int::INT_MAX_STR_DIGITS,
+        memory_file,
+        str::{StringRepr, str_maketrans},
+        timedelta,
     },
     value::Value,
 };
@@ -28,10 +37,13 @@ use crate::{
 pub enum Type {
     Ellipsis,
     Type,
+    /// The root `object` type; `object()` instances are identity-only sentinels
+    Object,
     NoneType,
     Bool,
     Int,
     Float,
+    Complex,
     Range,
     Slice,
     Date,
@@ -40,6 +52,8 @@ pub enum Type {
     TimeZone,
     Str,
     Bytes,
+    ByteArray,
+    MemoryView,
     List,
     Tuple,
     NamedTuple,
@@ -68,6 +82,16 @@ pub enum Type {
     BufferedRandom,
     /// typing module special forms (Any, Optional, Union, etc.) - displays as "typing._SpecialForm"
     SpecialForm,
+    /// A subscripted generic such as `list[int]` or `typing.Optional[str]` - displays as "types.GenericAlias"
+    GenericAlias,
+    /// A distinct type created by `typing.NewType()` - displays as "typing.NewType"
+    NewType,
+    /// A dict type created by the functional `typing.TypedDict()` form - displays as "typing._TypedDictMeta"
+    TypedDict,
+    /// A field descriptor returned by `dataclasses.field()` / `dataclasses.fields()` - displays as "dataclasses.Field"
+    DataclassField,
+    /// The type of the `dataclasses.MISSING` sentinel - displays as "dataclasses._MISSING_TYPE"
+    MissingType,
     /// A filesystem path from `pathlib.Path` - displays as "PosixPath"
     Path,
     /// A property descriptor - displays as "property"
@@ -76,6 +100,26 @@ pub enum Type {
     RePattern,
     /// A regex match result from `re.match()` / `re.search()` etc. - displays as "re.Match"
     ReMatch,
+    /// `contextlib.suppress(...)` - displays as "contextlib.suppress"
+    Suppress,
+    /// `contextlib.nullcontext(...)` - displays as "contextlib.nullcontext"
+    NullContext,
+    /// `contextlib.closing(...)` - displays as "contextlib.closing"
+    Closing,
+    /// `contextlib.redirect_stdout(...)` - displays as "contextlib.redirect_stdout"
+    RedirectStdout,
+    /// `contextlib.ExitStack()` - displays as "contextlib.ExitStack"
+    ExitStack,
+    /// `warnings.catch_warnings(...)` - displays as "warnings.catch_warnings"
+    CatchWarnings,
+    /// Logger from `logging.getLogger()` - displays as "logging.Logger"
+    Logger,
+    /// `logging.LoggerAdapter(...)` - displays as "logging.LoggerAdapter"
+    LoggerAdapter,
+    /// In-memory text file from `io.StringIO()` - displays as "_io.StringIO"
+    StringIO,
+    /// In-memory binary file from `io.BytesIO()` - displays as "_io.BytesIO"
+    BytesIO,
     /// Synthetic context manager exposed via the `_test_cm` builtin. Only
     /// reachable under the `test-hooks` cargo feature; intentionally a
     /// distinct `Type` variant rather than one of the existing ones so a
@@ -89,10 +133,12 @@ impl fmt::Display for Type {
         match self {
             Self::Ellipsis => f.write_str("ellipsis"),
             Self::Type => f.write_str("type"),
+            Self::Object => f.write_str("object"),
             Self::NoneType => f.write_str("NoneType"),
             Self::Bool => f.write_str("bool"),
             Self::Int => f.write_str("int"),
             Self::Float => f.write_str("float"),
+            Self::Complex => f.write_str("complex"),
             Self::Range => f.write_str("range"),
             Self::Slice => f.write_str("slice"),
             Self::Date => f.write_str("date"),
@@ -101,6 +147,8 @@ impl fmt::Display for Type {
             Self::TimeZone => f.write_str("timezone"),
             Self::Str => f.write_str("str"),
             Self::Bytes => f.write_str("bytes"),
+            Self::ByteArray => f.write_str("bytearray"),
+            Self::MemoryView => f.write_str("memoryview"),
             Self::List => f.write_str("list"),
             Self::Tuple => f.write_str("tuple"),
             Self::NamedTuple => f.write_str("namedtuple"),
@@ -123,10 +171,25 @@ impl fmt::Display for Type {
             Self::BufferedWriter => f.write_str("_io.BufferedWriter"),
             Self::BufferedRandom => f.write_str("_io.BufferedRandom"),
             Self::SpecialForm => f.write_str("typing._SpecialForm"),
+            Self::GenericAlias => f.write_str("types.GenericAlias"),
+            Self::NewType => f.write_str("typing.NewType"),
+            Self::TypedDict => f.write_str("typing._TypedDictMeta"),
+            Self::DataclassField => f.write_str("dataclasses.Field"),
+            Self::MissingType => f.write_str("dataclasses._MISSING_TYPE"),
             Self::Path => f.write_str("PosixPath"),
             Self::Property => f.write_str("property"),
             Self::RePattern => f.write_str("re.Pattern"),
             Self::ReMatch => f.write_str("re.Match"),
+            Self::Suppress => f.write_str("contextlib.suppress"),
+            Self::NullContext => f.write_str("contextlib.nullcontext"),
+            Self::Closing => f.write_str("contextlib.closing"),
+            Self::RedirectStdout => f.write_str("contextlib.redirect_stdout"),
+            Self::ExitStack => f.write_str("contextlib.ExitStack"),
+            Self::CatchWarnings => f.write_str("warnings.catch_warnings"),
+            Self::Logger => f.write_str("logging.Logger"),
+            Self::LoggerAdapter => f.write_str("logging.LoggerAdapter"),
+            Self::StringIO => f.write_str("_io.StringIO"),
+            Self::BytesIO => f.write_str("_io.BytesIO"),
             #[cfg(feature = "test-hooks")]
             Self::TestContextManager => f.write_str("_test_cm"),
         }
@@ -182,8 +245,11 @@ impl Type {
             Self::Bool => Some("bool"),
             Self::Int => Some("int"),
             Self::Float => Some("float"),
+            Self::Complex => Some("complex"),
             Self::Str => Some("str"),
             Self::Bytes => Some("bytes"),
+            Self::ByteArray => Some("bytearray"),
+            Self::MemoryView => Some("memoryview"),
             Self::List => Some("list"),
             Self::Tuple => Some("tuple"),
             Self::Dict => Some("dict"),
@@ -194,6 +260,7 @@ impl Type {
             Self::Iterator => Some("iter"),
             Self::Type => Some("type"),
             Self::Property => Some("property"),
+            Self::Object => Some("object"),
             _ => None,
         }
     }
@@ -212,8 +279,11 @@ impl Type {
             "bool" => Some(Self::Bool),
             "int" => Some(Self::Int),
             "float" => Some(Self::Float),
+            "complex" => Some(Self::Complex),
             "str" => Some(Self::Str),
             "bytes" => Some(Self::Bytes),
+            "bytearray" => Some(Self::ByteArray),
+            "memoryview" => Some(Self::MemoryView),
             "list" => Some(Self::List),
             "tuple" => Some(Self::Tuple),
             "dict" => Some(Self::Dict),
@@ -224,6 +294,7 @@ impl Type {
             "iter" => Some(Self::Iterator),
             "type" => Some(Self::Type),
             "property" => Some(Self::Property),
+            "object" => Some(Self::Object),
             _ => None,
         }
     }
@@ -233,9 +304,10 @@ impl Type {
     /// This handles Python's subtype relationships:
     /// - `bool` is a subtype of `int` (so `isinstance(True, int)` returns True)
     /// - `datetime` is a subtype of `date` (so `isinstance(datetime_obj, date)` returns True)
+    /// - every type is a subtype of `object`
     #[must_use]
     pub fn is_instance_of(self, other: Self) -> bool {
-        if self == other {
+        if self == other || other == Self::Object {
             true
         } else if self == Self::Bool && other == Self::Int {
             // bool is a subtype of int in Python
@@ -269,6 +341,10 @@ impl Type {
             Self::Slice => Some(11),
             Self::Iterator => Some(12),
             Self::Path => Some(13),
+            Self::ByteArray => Some(14),
+            Self::MemoryView => Some(15),
+            Self::Complex => Some(16),
+            Self::Object => Some(17),
             _ => None,
         }
     }
@@ -293,6 +369,10 @@ impl Type {
             11 => Some(Self::Slice),
             12 => Some(Self::Iterator),
             13 => Some(Self::Path),
+            14 => Some(Self::ByteArray),
+            15 => Some(Self::MemoryView),
+            16 => Some(Self::Complex),
+            17 => Some(Self::Object),
             _ => None,
         }
     }
@@ -308,7 +388,17 @@ impl Type {
     ) -> RunResult<AttrCallResult> {
         match (self, method_id) {
             (Self::Dict, m) if m == StaticStrings::Fromkeys => dict_fromkeys(args, vm).map(AttrCallResult::Value),
-            (Self::Bytes, m) if m == StaticStrings::Fromhex => bytes_fromhex(args, vm).map(AttrCallResult::Value),
+            (Self::Bytes | Self::ByteArray, m) if m == StaticStrings::Fromhex => {
+                bytes_fromhex(self, args, vm).map(AttrCallResult::Value)
+            }
+            (Self::Bytes | Self::ByteArray, m) if m == StaticStrings::Maketrans => {
+                bytes_maketrans(args, vm).map(AttrCallResult::Value)
+            }
+            (Self::Str, m) if m == StaticStrings::Maketrans => str_maketrans(args, vm).map(AttrCallResult::Value),
+            (Self::Int, m) if m == StaticStrings::FromBytes => int::int_from_bytes(args, vm).map(AttrCallResult::Value),
+            (Self::Float, m) if m == StaticStrings::Fromhex => {
+                float::float_fromhex(args, vm).map(AttrCallResult::Value)
+            }
             (Self::Date, m) if m == StaticStrings::Today => date::class_today(vm.heap, args),
             (Self::Date, m) if m == StaticStrings::Fromisoformat => {
                 date::class_fromisoformat(vm.heap, args, vm.interns).map(AttrCallResult::Value)
@@ -342,6 +432,10 @@ impl Type {
             Self::FrozenSet => FrozenSet::init(vm, args),
             Self::Str => Str::init(vm, args),
             Self::Bytes => Bytes::init(vm, args),
+            Self::ByteArray => ByteArray::init(vm, args),
+            Self::MemoryView => MemoryView::init(vm, args),
+            Self::Complex => complex::init(vm, args),
+            Self::Object => base_object::init(vm, args),
             Self::Range => Range::init(vm, args),
             Self::Slice => Slice::init(vm, args),
             Self::Date => date::init(vm, args),
@@ -350,6 +444,14 @@ impl Type {
             Self::TimeZone => TimeZone::init(vm, args),
             Self::Iterator => MontyIter::init(vm, args),
             Self::Path => Path::init(vm, args),
+            Self::Suppress
+            | Self::NullContext
+            | Self::Closing
+            | Self::RedirectStdout
+            | Self::ExitStack
+            | Self::CatchWarnings => contextlib::init(self, vm, args),
+            Self::LoggerAdapter => logger::init_adapter(vm, args),
+            Self::StringIO | Self::BytesIO => memory_file::init(self, vm, args),
 
             // Primitive types - inline implementation
             Self::Int => {
@@ -396,7 +498,7 @@ impl Type {
                     return Ok(Value::Bool(false));
                 };
                 defer_drop!(v, vm);
-                Ok(Value::Bool(v.py_bool(vm)))
+                Ok(Value::Bool(checked_truth(v, vm)?))
             }
 
             // Non-callable types - raise TypeError
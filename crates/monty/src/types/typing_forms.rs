@@ -0,0 +1,694 @@
+//! Runtime objects produced by the `typing` module.
+//!
+//! - [`GenericAlias`]: the result of subscripting a typing form or builtin type, e.g.
+//!   `list[int]`, `typing.Dict[str, int]`, `typing.Optional[str]` or `int | None`.
+//!   Exposes `__origin__` / `__args__` so code can introspect annotations at runtime.
+//! - [`NewType`]: the callable returned by `typing.NewType(name, tp)`. Calling it
+//!   returns its argument unchanged, exactly like CPython.
+//! - [`TypedDict`]: the class returned by the functional `typing.TypedDict(name, fields)`
+//!   form. Calling it builds a plain `dict`.
+//!
+//! None of these validate anything: like CPython, typing constructs are only
+//! metadata at runtime.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use ahash::AHashSet;
+
+use crate::{
+    builtins::Builtins,
+    bytecode::{CallResult, VM},
+    defer_drop,
+    exception_private::{ExcType, RunResult},
+    hash::HashValue,
+    heap::{DropWithHeap, HeapData, HeapId, HeapItem, HeapRead},
+    intern::StaticStrings,
+    resource::ResourceTracker,
+    types::{Dict, PyTrait, Type, allocate_tuple, str::allocate_string, tuple::TupleVec},
+    value::{EitherStr, Marker, Value},
+};
+
+/// A subscripted generic such as `list[int]`, `typing.List[int]` or `int | str`.
+///
+/// `form` records which typing special form was subscripted (`None` for builtin types
+/// like `list[int]`); it drives the repr and how `get_origin` / `get_args` behave.
+/// `origin` is the value reported as `__origin__`: the builtin type for the
+/// `List`/`Dict`/... aliases, otherwise the typing form itself.
+///
+/// Some forms store their arguments in a normalized shape:
+/// - `Union` / `Optional`: nested unions are flattened, `None` becomes `NoneType` and
+///   duplicates are removed.
+/// - `Callable[[a, b], r]`: the parameter list is flattened, so `args` is `[a, b, r]`.
+/// - `Annotated[T, m1, m2]`: `args` holds `[T, m1, m2]`; `__args__` only reports `T`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct GenericAlias {
+    form: Option<Marker>,
+    origin: Value,
+    args: Vec<Value>,
+}
+
+impl GenericAlias {
+    /// Returns the typing form this alias was created from, if any.
+    #[must_use]
+    pub fn form(&self) -> Option<Marker> {
+        self.form
+    }
+
+    /// Returns the `__origin__` of this alias.
+    #[must_use]
+    pub fn origin(&self) -> &Value {
+        match self.form {
+            Some(Marker(StaticStrings::Annotated)) => &self.args[0],
+            _ => &self.origin,
+        }
+    }
+
+    /// Returns the normalized type arguments (see the type docs for their shape).
+    #[must_use]
+    pub fn args(&self) -> &[Value] {
+        &self.args
+    }
+
+    /// Iterates over every value held by this alias (the stored origin and all args).
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        std::iter::once(&self.origin).chain(&self.args)
+    }
+
+    /// Returns `true` if this alias is a union (`Union[...]`, `Optional[...]` or `X | Y`).
+    #[must_use]
+    pub fn is_union(&self) -> bool {
+        self.form == Some(Marker(StaticStrings::UnionType))
+    }
+
+    /// Returns `true` if this is a `Callable[...]` alias written with an explicit
+    /// parameter list (as opposed to `Callable[..., R]`).
+    #[must_use]
+    pub fn has_callable_params(&self) -> bool {
+        self.form == Some(Marker(StaticStrings::Callable))
+            && !(self.args.len() == 2 && matches!(self.args[0], Value::Ellipsis))
+    }
+}
+
+/// Implements `form[key]` for typing special forms such as `Optional`, `List` or `Literal`.
+pub(crate) fn subscript_marker(marker: Marker, key: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let origin = match marker.0 {
+        StaticStrings::ListType => Value::Builtin(Builtins::Type(Type::List)),
+        StaticStrings::DictType => Value::Builtin(Builtins::Type(Type::Dict)),
+        StaticStrings::TupleType => Value::Builtin(Builtins::Type(Type::Tuple)),
+        StaticStrings::SetType => Value::Builtin(Builtins::Type(Type::Set)),
+        StaticStrings::FrozenSet => Value::Builtin(Builtins::Type(Type::FrozenSet)),
+        StaticStrings::Type => Value::Builtin(Builtins::Type(Type::Type)),
+        StaticStrings::Optional => {
+            if is_tuple(key, vm) {
+                // CPython truncates the repr with `{arg!r:.100}`
+                let key_repr = key.py_repr(vm)?;
+                let key_repr: String = key_repr.chars().take(100).collect();
+                return Err(ExcType::type_error(format!(
+                    "typing.Optional requires a single type. Got {key_repr}."
+                )));
+            }
+            let args = vec![key.clone_with_heap(vm), Value::None];
+            return make_union(args, vm);
+        }
+        StaticStrings::UnionType => {
+            let args = subscript_args(key, vm);
+            return make_union(args, vm);
+        }
+        StaticStrings::Callable => {
+            let args = subscript_args(key, vm);
+            return make_callable(marker, args, vm);
+        }
+        StaticStrings::Annotated => {
+            let args = subscript_args(key, vm);
+            if args.len() < 2 {
+                args.drop_with_heap(vm);
+                return Err(ExcType::type_error(
+                    "Annotated[...] should be used with at least two arguments (a type and an annotation).",
+                ));
+            }
+            return allocate_alias(Some(marker), Value::Marker(marker), args, vm);
+        }
+        StaticStrings::Sequence
+        | StaticStrings::Mapping
+        | StaticStrings::Iterable
+        | StaticStrings::IteratorType
+        | StaticStrings::Generator
+        | StaticStrings::ClassVar
+        | StaticStrings::FinalType
+        | StaticStrings::Literal
+        | StaticStrings::Generic
+        | StaticStrings::Protocol => Value::Marker(marker),
+        _ => {
+            let mut repr = String::new();
+            marker.py_repr_fmt(&mut repr)?;
+            return Err(ExcType::type_error(format!("{repr} is not subscriptable")));
+        }
+    };
+    let args = subscript_args(key, vm);
+    allocate_alias(Some(marker), origin, args, vm)
+}
+
+/// Implements `type[key]` for the builtin container types, e.g. `list[int]` or `dict[str, int]`.
+///
+/// Returns `None` for types that are not generic at runtime.
+pub(crate) fn subscript_type(ty: Type, key: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Option<Value>> {
+    match ty {
+        Type::List | Type::Dict | Type::Tuple | Type::Set | Type::FrozenSet | Type::Type => {
+            let args = subscript_args(key, vm);
+            allocate_alias(None, Value::Builtin(Builtins::Type(ty)), args, vm).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Implements `lhs | rhs` between type-like values, producing a union alias.
+///
+/// Returns `None` when either operand is not a type (so the caller can fall back to
+/// the numeric `|`), or when both are `None`.
+pub(crate) fn union_operands(
+    lhs: &Value,
+    rhs: &Value,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Option<Value>> {
+    if matches!((lhs, rhs), (Value::None, Value::None)) || !is_type_like(lhs, vm) || !is_type_like(rhs, vm) {
+        return Ok(None);
+    }
+    let args = vec![lhs.clone_with_heap(vm), rhs.clone_with_heap(vm)];
+    make_union(args, vm).map(Some)
+}
+
+/// Returns `true` for values that can appear on either side of a `X | Y` type union.
+fn is_type_like(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> bool {
+    match value {
+        Value::None | Value::Builtin(Builtins::Type(_)) => true,
+        Value::Marker(marker) => !matches!(marker.py_type(), Type::TextIOWrapper | Type::MissingType),
+        Value::Ref(id) => matches!(
+            vm.heap.get(*id),
+            HeapData::GenericAlias(_) | HeapData::NewType(_) | HeapData::TypedDict(_)
+        ),
+        _ => false,
+    }
+}
+
+fn is_tuple(value: &Value, vm: &VM<'_, impl ResourceTracker>) -> bool {
+    matches!(value, Value::Ref(id) if matches!(vm.heap.get(*id), HeapData::Tuple(_)))
+}
+
+/// Splits a subscript key into its arguments: `X[a, b]` passes the tuple `(a, b)`.
+fn subscript_args(key: &Value, vm: &VM<'_, impl ResourceTracker>) -> Vec<Value> {
+    if let Value::Ref(id) = key
+        && let HeapData::Tuple(tuple) = vm.heap.get(*id)
+    {
+        tuple.as_slice().iter().map(|v| v.clone_with_heap(vm)).collect()
+    } else {
+        vec![key.clone_with_heap(vm)]
+    }
+}
+
+/// Builds a normalized union from `args`, returning the single member if only one remains.
+fn make_union(args: Vec<Value>, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let mut members: Vec<Value> = Vec::with_capacity(args.len());
+    let mut pending = args.into_iter();
+    while let Some(arg) = pending.next() {
+        let arg = match arg {
+            Value::None => Value::Builtin(Builtins::Type(Type::NoneType)),
+            other => other,
+        };
+        // Flatten nested unions
+        let nested = match &arg {
+            Value::Ref(id) => match vm.heap.get(*id) {
+                HeapData::GenericAlias(alias) if alias.is_union() => {
+                    Some(alias.args.iter().map(|v| v.clone_with_heap(vm)).collect::<Vec<_>>())
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(nested) = nested {
+            arg.drop_with_heap(vm);
+            let rest: Vec<Value> = pending.collect();
+            pending = nested.into_iter().chain(rest).collect::<Vec<_>>().into_iter();
+            continue;
+        }
+        let mut duplicate = false;
+        for member in &members {
+            match member.py_eq(&arg, vm) {
+                Ok(true) => {
+                    duplicate = true;
+                    break;
+                }
+                Ok(false) => {}
+                Err(err) => {
+                    arg.drop_with_heap(vm);
+                    pending.drop_with_heap(vm);
+                    members.drop_with_heap(vm);
+                    return Err(err);
+                }
+            }
+        }
+        if duplicate {
+            arg.drop_with_heap(vm);
+        } else {
+            members.push(arg);
+        }
+    }
+    if members.len() == 1 {
+        return Ok(members.pop().expect("union has one member"));
+    }
+    let form = Marker(StaticStrings::UnionType);
+    allocate_alias(Some(form), Value::Marker(form), members, vm)
+}
+
+/// Builds `Callable[[params...], result]`, flattening the parameter list into the args.
+fn make_callable(marker: Marker, args: Vec<Value>, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    if args.len() != 2 {
+        args.drop_with_heap(vm);
+        return Err(ExcType::type_error(
+            "Callable must be used as Callable[[arg, ...], result].",
+        ));
+    }
+    let mut args = args.into_iter();
+    let params = args.next().expect("checked length");
+    let result = args.next().expect("checked length");
+    let mut flattened = match &params {
+        Value::Ellipsis => vec![Value::Ellipsis],
+        Value::Ref(id) if let HeapData::List(list) = vm.heap.get(*id) => {
+            list.as_slice().iter().map(|v| v.clone_with_heap(vm)).collect()
+        }
+        _ => {
+            params.drop_with_heap(vm);
+            result.drop_with_heap(vm);
+            return Err(ExcType::type_error(
+                "Callable[args, result]: args must be a list or Ellipsis",
+            ));
+        }
+    };
+    params.drop_with_heap(vm);
+    flattened.push(result);
+    allocate_alias(Some(marker), Value::Marker(marker), flattened, vm)
+}
+
+fn allocate_alias(
+    form: Option<Marker>,
+    origin: Value,
+    args: Vec<Value>,
+    vm: &mut VM<'_, impl ResourceTracker>,
+) -> RunResult<Value> {
+    let alias = GenericAlias { form, origin, args };
+    Ok(Value::Ref(vm.heap.allocate(HeapData::GenericAlias(alias))?))
+}
+
+/// Writes a type argument the way `typing` reprs do: builtin types by name,
+/// `None` for `NoneType`, `...` for `Ellipsis` and `repr()` for anything else.
+fn write_type_arg(
+    f: &mut impl Write,
+    value: &Value,
+    vm: &mut VM<'_, impl ResourceTracker>,
+    heap_ids: &mut AHashSet<HeapId>,
+) -> RunResult<()> {
+    match value {
+        Value::Builtin(Builtins::Type(ty)) => write!(f, "{}", ty.cpython_arg_name())?,
+        Value::Ellipsis => f.write_str("...")?,
+        _ => value.py_repr_fmt(f, vm, heap_ids)?,
+    }
+    Ok(())
+}
+
+/// Writes `args` separated by `sep` using [`write_type_arg`].
+fn write_type_args(
+    f: &mut impl Write,
+    args: &[Value],
+    sep: &str,
+    vm: &mut VM<'_, impl ResourceTracker>,
+    heap_ids: &mut AHashSet<HeapId>,
+) -> RunResult<()> {
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            f.write_str(sep)?;
+        }
+        write_type_arg(f, arg, vm, heap_ids)?;
+    }
+    Ok(())
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, GenericAlias> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::GenericAlias
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// Aliases are equal when they have the same form, origin and arguments.
+    /// Union members are compared without regard to order, like CPython.
+    fn py_eq(&self, other: &Self, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        let (a, b) = (self.get(vm.heap), other.get(vm.heap));
+        if a.form != b.form || a.args.len() != b.args.len() {
+            return Ok(false);
+        }
+        let unordered = a.is_union();
+        let a_origin = a.origin.clone_with_heap(vm);
+        let b_origin = b.origin.clone_with_heap(vm);
+        let a_args: Vec<Value> = a.args.iter().map(|v| v.clone_with_heap(vm)).collect();
+        let b_args: Vec<Value> = b.args.iter().map(|v| v.clone_with_heap(vm)).collect();
+        defer_drop!(a_origin, vm);
+        defer_drop!(b_origin, vm);
+        defer_drop!(a_args, vm);
+        defer_drop!(b_args, vm);
+
+        let token = vm.heap.incr_recursion_depth()?;
+        defer_drop!(token, vm);
+        if !a_origin.py_eq(b_origin, vm)? {
+            return Ok(false);
+        }
+        for (i, a_arg) in a_args.iter().enumerate() {
+            let found = if unordered {
+                let mut found = false;
+                for b_arg in b_args.iter() {
+                    if a_arg.py_eq(b_arg, vm)? {
+                        found = true;
+                        break;
+                    }
+                }
+                found
+            } else {
+                a_arg.py_eq(&b_args[i], vm)?
+            };
+            if !found {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn py_hash(&self, _self_id: HeapId, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let alias = self.get(vm.heap);
+        let form = alias.form;
+        let unordered = alias.is_union();
+        let origin = alias.origin.clone_with_heap(vm);
+        let args: Vec<Value> = alias.args.iter().map(|v| v.clone_with_heap(vm)).collect();
+        defer_drop!(origin, vm);
+        defer_drop!(args, vm);
+
+        let token = vm.heap.incr_recursion_depth()?;
+        defer_drop!(token, vm);
+        let mut hasher = DefaultHasher::new();
+        form.hash(&mut hasher);
+        let Some(origin_hash) = origin.py_hash(vm)? else {
+            return Ok(None);
+        };
+        origin_hash.hash(&mut hasher);
+        // Union members are combined with XOR so the hash doesn't depend on their order
+        let mut unordered_hash = 0u64;
+        for arg in args.iter() {
+            let Some(arg_hash) = arg.py_hash(vm)? else {
+                return Ok(None);
+            };
+            if unordered {
+                let mut arg_hasher = DefaultHasher::new();
+                arg_hash.hash(&mut arg_hasher);
+                unordered_hash ^= arg_hasher.finish();
+            } else {
+                arg_hash.hash(&mut hasher);
+            }
+        }
+        unordered_hash.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        let Ok(token) = vm.heap.incr_recursion_depth() else {
+            return Ok(f.write_str("...")?);
+        };
+        defer_drop!(token, vm);
+
+        let alias = self.get(vm.heap);
+        let form = alias.form;
+        let has_callable_params = alias.has_callable_params();
+        let origin = alias.origin.clone_with_heap(vm);
+        let args: Vec<Value> = alias.args.iter().map(|v| v.clone_with_heap(vm)).collect();
+        defer_drop!(origin, vm);
+        defer_drop!(args, vm);
+
+        match form {
+            Some(Marker(StaticStrings::UnionType)) => write_type_args(f, args, " | ", vm, heap_ids),
+            Some(marker) => {
+                marker.py_repr_fmt(f)?;
+                f.write_char('[')?;
+                if has_callable_params {
+                    let (result, params) = args.split_last().expect("callable has a result type");
+                    f.write_char('[')?;
+                    write_type_args(f, params, ", ", vm, heap_ids)?;
+                    f.write_str("], ")?;
+                    write_type_arg(f, result, vm, heap_ids)?;
+                } else {
+                    write_type_args(f, args, ", ", vm, heap_ids)?;
+                }
+                Ok(f.write_char(']')?)
+            }
+            None => {
+                write_type_arg(f, origin, vm, heap_ids)?;
+                f.write_char('[')?;
+                write_type_args(f, args, ", ", vm, heap_ids)?;
+                Ok(f.write_char(']')?)
+            }
+        }
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let alias = self.get(vm.heap);
+        let value = match attr.static_string() {
+            Some(StaticStrings::DunderOrigin) => alias.origin().clone_with_heap(vm),
+            Some(StaticStrings::DunderArgs) => {
+                let args = match alias.form {
+                    Some(Marker(StaticStrings::Annotated)) => &alias.args[..1],
+                    _ => &alias.args[..],
+                };
+                let items: TupleVec = args.iter().map(|v| v.clone_with_heap(vm)).collect();
+                allocate_tuple(items, vm.heap)?
+            }
+            Some(StaticStrings::DunderMetadata) if alias.form == Some(Marker(StaticStrings::Annotated)) => {
+                let items: TupleVec = alias.args[1..].iter().map(|v| v.clone_with_heap(vm)).collect();
+                allocate_tuple(items, vm.heap)?
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+}
+
+impl HeapItem for GenericAlias {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>() + self.args.len() * mem::size_of::<Value>()
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.origin.py_dec_ref_ids(stack);
+        for arg in &mut self.args {
+            arg.py_dec_ref_ids(stack);
+        }
+    }
+}
+
+/// A distinct type created by `typing.NewType(name, tp)`.
+///
+/// Calling it returns the argument unchanged; it exists only so type checkers can
+/// tell `UserId` apart from `int`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct NewType {
+    name: String,
+    supertype: Value,
+}
+
+impl NewType {
+    /// Creates a new `NewType` with the given name and supertype.
+    #[must_use]
+    pub fn new(name: String, supertype: Value) -> Self {
+        Self { name, supertype }
+    }
+
+    /// Returns the name given to this `NewType`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type this `NewType` was derived from.
+    #[must_use]
+    pub fn supertype(&self) -> &Value {
+        &self.supertype
+    }
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, NewType> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::NewType
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// `NewType`s only compare equal to themselves, which `Value::py_eq` checks by identity.
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_hash(&self, self_id: HeapId, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        Ok(write!(f, "__main__.{}", self.get(vm.heap).name)?)
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let new_type = self.get(vm.heap);
+        let value = match attr.static_string() {
+            Some(StaticStrings::DunderName) => allocate_string(new_type.name.clone(), vm.heap)?,
+            Some(StaticStrings::DunderSupertype) => new_type.supertype.clone_with_heap(vm),
+            _ => return Ok(None),
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+}
+
+impl HeapItem for NewType {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>() + self.name.len()
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.supertype.py_dec_ref_ids(stack);
+    }
+}
+
+/// A dict type created by the functional `typing.TypedDict(name, fields, total=True)` form.
+///
+/// Calling it builds a plain `dict` (keys are not checked against the declared fields).
+/// `annotations` holds a private copy of the `fields` dict.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct TypedDict {
+    name: String,
+    annotations: Value,
+    total: bool,
+}
+
+impl TypedDict {
+    /// Creates a new `TypedDict`; `annotations` must be an owned reference to a dict.
+    #[must_use]
+    pub fn new(name: String, annotations: Value, total: bool) -> Self {
+        Self {
+            name,
+            annotations,
+            total,
+        }
+    }
+
+    /// Returns the dict of declared field annotations.
+    ///
+    /// Callers exposing it to Python code must copy it (see [`copy_dict`]) so the
+    /// `TypedDict`'s own fields can't be mutated.
+    #[must_use]
+    pub fn annotations(&self) -> &Value {
+        &self.annotations
+    }
+}
+
+/// Returns a shallow copy of `dict`, which must be a dict value.
+pub(crate) fn copy_dict(dict: &Value, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<Value> {
+    let pairs: Vec<(Value, Value)> = match dict {
+        Value::Ref(id) if let HeapData::Dict(dict) = vm.heap.get(*id) => dict
+            .iter()
+            .map(|(k, v)| (k.clone_with_heap(vm), v.clone_with_heap(vm)))
+            .collect(),
+        _ => return Err(ExcType::type_error("expected a dict")),
+    };
+    let copy = Dict::from_pairs(pairs, vm)?;
+    Ok(Value::Ref(vm.heap.allocate(HeapData::Dict(copy))?))
+}
+
+impl<'h> PyTrait<'h> for HeapRead<'h, TypedDict> {
+    fn py_type(&self, _vm: &VM<'h, impl ResourceTracker>) -> Type {
+        Type::TypedDict
+    }
+
+    fn py_len(&self, _vm: &VM<'h, impl ResourceTracker>) -> Option<usize> {
+        None
+    }
+
+    /// `TypedDict`s only compare equal to themselves, which `Value::py_eq` checks by identity.
+    fn py_eq(&self, _other: &Self, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<bool> {
+        Ok(false)
+    }
+
+    fn py_hash(&self, self_id: HeapId, _vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<HashValue>> {
+        let mut hasher = DefaultHasher::new();
+        self_id.hash(&mut hasher);
+        Ok(Some(HashValue::new(hasher.finish())))
+    }
+
+    fn py_bool(&self, _vm: &mut VM<'h, impl ResourceTracker>) -> bool {
+        true
+    }
+
+    fn py_repr_fmt(
+        &self,
+        f: &mut impl Write,
+        vm: &mut VM<'h, impl ResourceTracker>,
+        _heap_ids: &mut AHashSet<HeapId>,
+    ) -> RunResult<()> {
+        Ok(write!(f, "<class '__main__.{}'>", self.get(vm.heap).name)?)
+    }
+
+    fn py_getattr(&self, attr: &EitherStr, vm: &mut VM<'h, impl ResourceTracker>) -> RunResult<Option<CallResult>> {
+        let typed_dict = self.get(vm.heap);
+        let value = match attr.static_string() {
+            Some(StaticStrings::DunderName) => allocate_string(typed_dict.name.clone(), vm.heap)?,
+            Some(StaticStrings::DunderAnnotations) => {
+                let annotations = typed_dict.annotations.clone_with_heap(vm);
+                defer_drop!(annotations, vm);
+                copy_dict(annotations, vm)?
+            }
+            Some(StaticStrings::DunderTotal) => Value::Bool(typed_dict.total),
+            _ => return Ok(None),
+        };
+        Ok(Some(CallResult::Value(value)))
+    }
+}
+
+impl HeapItem for TypedDict {
+    fn py_estimate_size(&self) -> usize {
+        mem::size_of::<Self>() + self.name.len()
+    }
+
+    fn py_dec_ref_ids(&mut self, stack: &mut Vec<HeapId>) {
+        self.annotations.py_dec_ref_ids(stack);
+    }
+}
@@ -0,0 +1,634 @@
+//! Unicode character database backing `unicodedata` and the case and
+//! classification methods of `str`.
+//!
+//! The tables are generated by `scripts/generate_unicode_tables.py` from
+//! CPython's own `unicodedata` and `str` predicates, so every property here
+//! matches the CPython release they were generated with, including the
+//! special-casing (`'ß'.upper() == 'SS'`) and final-sigma rules of
+//! `str.lower()`.
+//!
+//! Each code point maps through a two-level index (`index.bin`) to one of a few
+//! hundred distinct [`Record`]s holding its category, bidirectional class,
+//! combining class, East Asian width, digit value and `FLAG_*` bits. Rarer
+//! properties (case mappings, numeric values, compositions) are sorted tables
+//! searched by code point, and decompositions and names are packed into
+//! `decompositions.bin` and `names.bin` with a chunk index every few dozen
+//! entries.
+
+use std::{iter, str};
+
+#[rustfmt::skip]
+#[expect(clippy::unreadable_literal, reason = "generated tables")]
+mod tables;
+
+/// The Unicode version of the tables, exposed as `unicodedata.unidata_version`.
+pub(crate) const UNIDATA_VERSION: &str = tables::UNIDATA_VERSION;
+
+static INDEX: &[u8] = include_bytes!("index.bin");
+static DECOMPOSITIONS: &[u8] = include_bytes!("decompositions.bin");
+static NAMES: &[u8] = include_bytes!("names.bin");
+
+const FLAG_ALPHA: u16 = 1 << 0;
+const FLAG_DECIMAL: u16 = 1 << 1;
+const FLAG_DIGIT: u16 = 1 << 2;
+const FLAG_NUMERIC: u16 = 1 << 3;
+const FLAG_LOWER: u16 = 1 << 4;
+const FLAG_UPPER: u16 = 1 << 5;
+const FLAG_TITLE: u16 = 1 << 6;
+const FLAG_CASED: u16 = 1 << 7;
+const FLAG_CASE_IGNORABLE: u16 = 1 << 8;
+const FLAG_SPACE: u16 = 1 << 9;
+const FLAG_PRINTABLE: u16 = 1 << 10;
+const FLAG_IDENTIFIER_START: u16 = 1 << 11;
+const FLAG_IDENTIFIER_CONTINUE: u16 = 1 << 12;
+const FLAG_MIRRORED: u16 = 1 << 13;
+
+/// The properties shared by runs of code points, stored once per distinct combination.
+#[derive(Debug)]
+struct Record {
+    /// Index into [`tables::CATEGORIES`].
+    category: u8,
+    /// Index into [`tables::BIDI_CLASSES`].
+    bidirectional: u8,
+    /// Canonical combining class.
+    combining: u8,
+    /// Index into [`tables::EAST_ASIAN_WIDTHS`].
+    east_asian_width: u8,
+    /// Digit value, or `0xFF` for none.
+    digit: u8,
+    /// `FLAG_*` bits.
+    flags: u16,
+}
+
+impl Record {
+    const fn new(category: u8, bidirectional: u8, combining: u8, east_asian_width: u8, digit: u8, flags: u16) -> Self {
+        Self {
+            category,
+            bidirectional,
+            combining,
+            east_asian_width,
+            digit,
+            flags,
+        }
+    }
+}
+
+/// Reads the `index`th little-endian `u16` of `index.bin`.
+fn index_entry(index: usize) -> usize {
+    usize::from(u16::from_le_bytes([INDEX[index * 2], INDEX[index * 2 + 1]]))
+}
+
+fn record(c: char) -> &'static Record {
+    let cp = c as usize;
+    let block = index_entry(cp >> tables::SHIFT);
+    let offset = (block << tables::SHIFT) + (cp & ((1 << tables::SHIFT) - 1));
+    &tables::RECORDS[index_entry(tables::INDEX1_LEN + offset)]
+}
+
+fn has_flag(c: char, flag: u16) -> bool {
+    record(c).flags & flag != 0
+}
+
+/// The two-letter general category, e.g. `"Lu"`.
+pub(crate) fn category(c: char) -> &'static str {
+    tables::CATEGORIES[usize::from(record(c).category)]
+}
+
+/// The bidirectional class, or `""` for unassigned code points.
+pub(crate) fn bidirectional(c: char) -> &'static str {
+    tables::BIDI_CLASSES[usize::from(record(c).bidirectional)]
+}
+
+/// The canonical combining class.
+pub(crate) fn combining(c: char) -> u8 {
+    record(c).combining
+}
+
+/// The East Asian width, e.g. `"W"`.
+pub(crate) fn east_asian_width(c: char) -> &'static str {
+    tables::EAST_ASIAN_WIDTHS[usize::from(record(c).east_asian_width)]
+}
+
+pub(crate) fn mirrored(c: char) -> bool {
+    has_flag(c, FLAG_MIRRORED)
+}
+
+/// The decimal digit value, for characters where `str.isdecimal()` is true.
+pub(crate) fn decimal(c: char) -> Option<u8> {
+    if is_decimal(c) { digit(c) } else { None }
+}
+
+/// The digit value, for characters where `str.isdigit()` is true.
+pub(crate) fn digit(c: char) -> Option<u8> {
+    let digit = record(c).digit;
+    (digit != 0xFF).then_some(digit)
+}
+
+/// The numeric value, for characters where `str.isnumeric()` is true.
+pub(crate) fn numeric(c: char) -> Option<f64> {
+    if let Some(digit) = digit(c) {
+        return Some(f64::from(digit));
+    }
+    tables::NUMERIC
+        .binary_search_by_key(&u32::from(c), |&(cp, _)| cp)
+        .ok()
+        .map(|i| tables::NUMERIC[i].1)
+}
+
+pub(crate) fn is_alpha(c: char) -> bool {
+    has_flag(c, FLAG_ALPHA)
+}
+
+pub(crate) fn is_decimal(c: char) -> bool {
+    has_flag(c, FLAG_DECIMAL)
+}
+
+pub(crate) fn is_digit(c: char) -> bool {
+    has_flag(c, FLAG_DIGIT)
+}
+
+pub(crate) fn is_numeric(c: char) -> bool {
+    has_flag(c, FLAG_NUMERIC)
+}
+
+pub(crate) fn is_lower(c: char) -> bool {
+    has_flag(c, FLAG_LOWER)
+}
+
+pub(crate) fn is_upper(c: char) -> bool {
+    has_flag(c, FLAG_UPPER)
+}
+
+/// Whether `c` is a titlecase letter (category `Lt`).
+pub(crate) fn is_title(c: char) -> bool {
+    has_flag(c, FLAG_TITLE)
+}
+
+/// Whether `c` is lowercase, uppercase or titlecase.
+pub(crate) fn is_cased(c: char) -> bool {
+    has_flag(c, FLAG_CASED)
+}
+
+/// Whether `c` is whitespace as `str.isspace()` and `str.split()` define it.
+pub(crate) fn is_space(c: char) -> bool {
+    has_flag(c, FLAG_SPACE)
+}
+
+pub(crate) fn is_printable(c: char) -> bool {
+    has_flag(c, FLAG_PRINTABLE)
+}
+
+/// Whether `c` can start an identifier (XID_Start or `_`).
+pub(crate) fn is_identifier_start(c: char) -> bool {
+    has_flag(c, FLAG_IDENTIFIER_START)
+}
+
+/// Whether `c` can continue an identifier (XID_Continue).
+pub(crate) fn is_identifier_continue(c: char) -> bool {
+    has_flag(c, FLAG_IDENTIFIER_CONTINUE)
+}
+
+// === Case mapping ===
+
+/// Which of the full case mappings to apply.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Case {
+    Lower,
+    Upper,
+    Title,
+    Fold,
+}
+
+/// Appends the full `case` mapping of `c` to `out`, which may be several characters.
+///
+/// Doesn't apply the final-sigma rule; see [`push_lower_at`].
+pub(crate) fn push_case(c: char, case: Case, out: &mut String) {
+    match tables::CASING.binary_search_by_key(&u32::from(c), |&(cp, ..)| cp) {
+        Ok(i) => {
+            let (_, lower, upper, title, fold) = tables::CASING[i];
+            out.push_str(match case {
+                Case::Lower => lower,
+                Case::Upper => upper,
+                Case::Title => title,
+                Case::Fold => fold,
+            });
+        }
+        Err(_) => out.push(c),
+    }
+}
+
+/// Appends the lowercase mapping of `chars[i]` to `out`, applying the
+/// final-sigma rule: a capital sigma preceded by a cased letter and not
+/// followed by one (skipping case-ignorable characters) becomes `'ς'`.
+pub(crate) fn push_lower_at(chars: &[char], i: usize, out: &mut String) {
+    if chars[i] != 'Σ' {
+        push_case(chars[i], Case::Lower, out);
+        return;
+    }
+    let is_ignorable = |c: &&char| has_flag(**c, FLAG_CASE_IGNORABLE);
+    let cased_before = chars[..i]
+        .iter()
+        .rev()
+        .find(|c| !is_ignorable(c))
+        .is_some_and(|&c| is_cased(c));
+    let cased_after = chars[i + 1..]
+        .iter()
+        .find(|c| !is_ignorable(c))
+        .is_some_and(|&c| is_cased(c));
+    out.push(if cased_before && !cased_after { 'ς' } else { 'σ' });
+}
+
+// === Decomposition and normalization ===
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P", "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE", "WI", "YU", "EU",
+    "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M", "B", "BS", "S",
+    "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// The syllable index of a precomposed Hangul syllable.
+fn hangul_index(c: char) -> Option<u32> {
+    u32::from(c).checked_sub(HANGUL_S_BASE).filter(|&s| s < HANGUL_S_COUNT)
+}
+
+/// Builds a `char` from a code point the tables guarantee is valid.
+fn to_char(cp: u32) -> char {
+    char::from_u32(cp).expect("Unicode tables hold valid code points")
+}
+
+/// Reads a 3-byte little-endian code point.
+fn read_u24(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0])
+}
+
+/// Looks up the single-level decomposition of `c` in `decompositions.bin`,
+/// returning its tag index and mapping bytes (3-byte code points).
+fn stored_decomposition(c: char) -> Option<(u8, &'static [u8])> {
+    let cp = u32::from(c);
+    let chunk = tables::DECOMPOSITION_CHUNKS
+        .partition_point(|&(first, _)| first <= cp)
+        .checked_sub(1)?;
+    let mut pos = tables::DECOMPOSITION_CHUNKS[chunk].1 as usize;
+    while pos < DECOMPOSITIONS.len() {
+        let entry_cp = read_u24(&DECOMPOSITIONS[pos..]);
+        let tag = DECOMPOSITIONS[pos + 3];
+        let len = usize::from(DECOMPOSITIONS[pos + 4]) * 3;
+        let mapping = &DECOMPOSITIONS[pos + 5..pos + 5 + len];
+        if entry_cp == cp {
+            return Some((tag, mapping));
+        } else if entry_cp > cp {
+            return None;
+        }
+        pos += 5 + len;
+    }
+    None
+}
+
+/// The decomposition of `c` as `unicodedata.decomposition()` reports it: the
+/// tag (`""` for canonical) and the mapping. Hangul syllables map to their jamo.
+pub(crate) fn decomposition(c: char) -> Option<(&'static str, Vec<char>)> {
+    if let Some(s) = hangul_index(c) {
+        let mut jamo = vec![
+            to_char(HANGUL_L_BASE + s / HANGUL_N_COUNT),
+            to_char(HANGUL_V_BASE + (s % HANGUL_N_COUNT) / HANGUL_T_COUNT),
+        ];
+        if s % HANGUL_T_COUNT != 0 {
+            jamo.push(to_char(HANGUL_T_BASE + s % HANGUL_T_COUNT));
+        }
+        return Some(("", jamo));
+    }
+    let (tag, mapping) = stored_decomposition(c)?;
+    let chars = mapping.chunks_exact(3).map(|cp| to_char(read_u24(cp))).collect();
+    Some((tables::DECOMPOSITION_TAGS[usize::from(tag)], chars))
+}
+
+/// A Unicode normalization form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Parses the form names `unicodedata.normalize()` accepts.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "NFC" => Some(Self::Nfc),
+            "NFD" => Some(Self::Nfd),
+            "NFKC" => Some(Self::Nfkc),
+            "NFKD" => Some(Self::Nfkd),
+            _ => None,
+        }
+    }
+
+    fn is_compatibility(self) -> bool {
+        matches!(self, Self::Nfkc | Self::Nfkd)
+    }
+
+    fn is_composed(self) -> bool {
+        matches!(self, Self::Nfc | Self::Nfkc)
+    }
+}
+
+/// Returns `s` in normalization form `form`.
+pub(crate) fn normalize(s: &str, form: NormalizationForm) -> String {
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose_into(c, form.is_compatibility(), &mut chars);
+    }
+    reorder_combining(&mut chars);
+    if form.is_composed() {
+        compose(&mut chars);
+    }
+    chars.into_iter().collect()
+}
+
+/// Appends the full (recursive) decomposition of `c` to `out`.
+fn decompose_into(c: char, compatibility: bool, out: &mut Vec<char>) {
+    if hangul_index(c).is_some() {
+        if let Some((_, jamo)) = decomposition(c) {
+            out.extend(jamo);
+        }
+        return;
+    }
+    match stored_decomposition(c) {
+        Some((tag, mapping)) if tag == 0 || compatibility => {
+            for cp in mapping.chunks_exact(3) {
+                decompose_into(to_char(read_u24(cp)), compatibility, out);
+            }
+        }
+        _ => out.push(c),
+    }
+}
+
+/// Puts each run of non-starters into canonical order, a stable sort by combining class.
+fn reorder_combining(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if combining(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let end = chars[start..]
+            .iter()
+            .position(|&c| combining(c) == 0)
+            .map_or(chars.len(), |n| start + n);
+        chars[start..end].sort_by_key(|&c| combining(c));
+        start = end;
+    }
+}
+
+/// Applies canonical composition to a decomposed, canonically ordered sequence.
+fn compose(chars: &mut Vec<char>) {
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    // Combining class of the last character kept since the starter; `None` when adjacent.
+    let mut last_class: Option<u8> = None;
+    for &c in chars.iter() {
+        let class = combining(c);
+        if let Some(si) = starter
+            && last_class.is_none_or(|last| last < class)
+            && let Some(composite) = compose_pair(out[si], c)
+        {
+            out[si] = composite;
+            continue;
+        }
+        if class == 0 {
+            starter = Some(out.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        out.push(c);
+    }
+    *chars = out;
+}
+
+/// The primary composite of `first` followed by `second`, if there is one.
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first_cp, second_cp) = (u32::from(first), u32::from(second));
+    if let Some(l) = first_cp.checked_sub(HANGUL_L_BASE).filter(|&l| l < HANGUL_L_COUNT)
+        && let Some(v) = second_cp.checked_sub(HANGUL_V_BASE).filter(|&v| v < HANGUL_V_COUNT)
+    {
+        return Some(to_char(HANGUL_S_BASE + (l * HANGUL_V_COUNT + v) * HANGUL_T_COUNT));
+    }
+    if let Some(s) = hangul_index(first)
+        && s % HANGUL_T_COUNT == 0
+        && let Some(t) = second_cp
+            .checked_sub(HANGUL_T_BASE)
+            .filter(|&t| t > 0 && t < HANGUL_T_COUNT)
+    {
+        return Some(to_char(first_cp + t));
+    }
+    tables::COMPOSITIONS
+        .binary_search_by_key(&(first_cp, second_cp), |&(a, b, _)| (a, b))
+        .ok()
+        .map(|i| to_char(tables::COMPOSITIONS[i].2))
+}
+
+// === Names ===
+
+/// `unicodedata.lookup()` rejects longer names without searching.
+pub(crate) const MAX_NAME_LEN: usize = 256;
+
+/// Reads the `index`th little-endian `u32` of `names.bin`.
+fn names_u32(index: usize) -> usize {
+    let pos = index * 4;
+    u32::from_le_bytes([NAMES[pos], NAMES[pos + 1], NAMES[pos + 2], NAMES[pos + 3]]) as usize
+}
+
+/// The phrasebook word for token `token` (which must be at least 2).
+fn name_word(token: usize) -> &'static str {
+    let word = token - 2;
+    let word_count = names_u32(0);
+    let words_start = 4 * (word_count + 2);
+    let (start, end) = (names_u32(word + 1), names_u32(word + 2));
+    str::from_utf8(&NAMES[words_start + start..words_start + end]).expect("phrasebook words are ASCII")
+}
+
+/// A decoded `names.bin` entry.
+struct NameEntry {
+    cp: u32,
+    tokens: &'static [u8],
+    /// Offset of the next entry.
+    next: usize,
+}
+
+/// Decodes the entry at `pos` (relative to the entries start), whose code point
+/// is `previous` plus the stored distance.
+fn read_name_entry(pos: usize, previous: u32) -> Option<NameEntry> {
+    let bytes = NAMES.get(tables::NAME_ENTRIES_START + pos..)?;
+    if bytes.is_empty() {
+        return None;
+    }
+    let mut delta = 0;
+    let mut shift = 0;
+    let mut i = 0;
+    loop {
+        let byte = bytes[i];
+        delta |= u32::from(byte & 0x7F) << shift;
+        shift += 7;
+        i += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    let len = usize::from(bytes[i]);
+    Some(NameEntry {
+        cp: previous + delta,
+        tokens: &bytes[i + 1..i + 1 + len],
+        next: pos + i + 1 + len,
+    })
+}
+
+/// Calls `f` with each piece of the name encoded by `tokens`, stopping early
+/// if it returns `false`.
+fn for_each_name_piece(tokens: &[u8], mut f: impl FnMut(&str) -> bool) {
+    let mut i = 0;
+    let mut previous_was_word = false;
+    while i < tokens.len() {
+        let mut token = usize::from(tokens[i]);
+        i += 1;
+        if token >= 0x80 {
+            token = ((token & 0x7F) << 8) | usize::from(tokens[i]);
+            i += 1;
+        }
+        let keep_going = match token {
+            0 => f(" "),
+            1 => f("-"),
+            _ => (!previous_was_word || f(" ")) && f(name_word(token)),
+        };
+        if !keep_going {
+            return;
+        }
+        previous_was_word = token >= 2;
+    }
+}
+
+/// The entries of the `names.bin` chunk starting at `offset` with code point `first`.
+fn name_chunk_entries((first, offset): (u32, u32)) -> impl Iterator<Item = NameEntry> {
+    let first_entry = read_name_entry(offset as usize, first);
+    iter::successors(first_entry, |entry| read_name_entry(entry.next, entry.cp)).take(tables::CHUNK)
+}
+
+/// The stored name of `cp` in the phrasebook.
+fn stored_name(cp: u32) -> Option<String> {
+    let chunk = tables::NAME_CHUNKS
+        .partition_point(|&(first, _)| first <= cp)
+        .checked_sub(1)?;
+    let entry = name_chunk_entries(tables::NAME_CHUNKS[chunk])
+        .find(|entry| entry.cp >= cp)
+        .filter(|entry| entry.cp == cp)?;
+    let mut name = String::new();
+    for_each_name_piece(entry.tokens, |piece| {
+        name.push_str(piece);
+        true
+    });
+    Some(name)
+}
+
+/// The character's name, as `unicodedata.name()` reports it.
+pub(crate) fn name(c: char) -> Option<String> {
+    let cp = u32::from(c);
+    if let Some(s) = hangul_index(c) {
+        let l = JAMO_L[(s / HANGUL_N_COUNT) as usize];
+        let v = JAMO_V[((s % HANGUL_N_COUNT) / HANGUL_T_COUNT) as usize];
+        let t = JAMO_T[(s % HANGUL_T_COUNT) as usize];
+        return Some(format!("HANGUL SYLLABLE {l}{v}{t}"));
+    }
+    if let Some(&(_, _, prefix)) = tables::NAME_RANGES
+        .iter()
+        .find(|&&(start, end, _)| (start..=end).contains(&cp))
+    {
+        return Some(format!("{prefix}-{cp:04X}"));
+    }
+    stored_name(cp)
+}
+
+/// Finds the character or named sequence called `name`, ignoring ASCII case,
+/// as `unicodedata.lookup()` does. Name aliases resolve to their character.
+pub(crate) fn lookup(name: &str) -> Option<String> {
+    let name = name.to_ascii_uppercase();
+    if let Some(syllable) = name.strip_prefix("HANGUL SYLLABLE ") {
+        return lookup_hangul(syllable).map(String::from);
+    }
+    if let Some(c) = lookup_ranged(&name) {
+        return Some(c.to_string());
+    }
+    if let Ok(i) = tables::NAME_ALIASES.binary_search_by_key(&name.as_str(), |&(alias, _)| alias) {
+        return Some(to_char(tables::NAME_ALIASES[i].1).to_string());
+    }
+    if let Ok(i) = tables::NAMED_SEQUENCES.binary_search_by_key(&name.as_str(), |&(seq_name, _)| seq_name) {
+        return Some(tables::NAMED_SEQUENCES[i].1.to_owned());
+    }
+    lookup_stored(&name).map(String::from)
+}
+
+/// Matches the jamo of a Hangul syllable name, taking the longest match for
+/// each jamo in turn like CPython.
+fn lookup_hangul(syllable: &str) -> Option<char> {
+    fn longest(rest: &str, jamo: &[&str]) -> Option<usize> {
+        (0..jamo.len())
+            .filter(|&i| rest.starts_with(jamo[i]))
+            .max_by_key(|&i| jamo[i].len())
+    }
+    let l = longest(syllable, &JAMO_L)?;
+    let rest = &syllable[JAMO_L[l].len()..];
+    let v = longest(rest, &JAMO_V)?;
+    let rest = &rest[JAMO_V[v].len()..];
+    let t = longest(rest, &JAMO_T)?;
+    if rest.len() != JAMO_T[t].len() {
+        return None;
+    }
+    let index = (l * JAMO_V.len() + v) * JAMO_T.len() + t;
+    Some(to_char(HANGUL_S_BASE + u32::try_from(index).ok()?))
+}
+
+/// Matches a `<prefix>-<hex code point>` name against [`tables::NAME_RANGES`]
+/// and [`tables::LOOKUP_RANGES`].
+fn lookup_ranged(name: &str) -> Option<char> {
+    let (prefix, hex) = name.rsplit_once('-')?;
+    let cp = u32::from_str_radix(hex, 16).ok()?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) || format!("{cp:04X}") != hex {
+        return None;
+    }
+    tables::NAME_RANGES
+        .iter()
+        .chain(tables::LOOKUP_RANGES)
+        .any(|&(start, end, range_prefix)| range_prefix == prefix && (start..=end).contains(&cp))
+        .then(|| to_char(cp))
+}
+
+/// Scans the phrasebook for an entry spelling `name`.
+fn lookup_stored(name: &str) -> Option<char> {
+    let entry = tables::NAME_CHUNKS
+        .iter()
+        .flat_map(|&chunk| name_chunk_entries(chunk))
+        .find(|entry| {
+            let mut rest = Some(name);
+            for_each_name_piece(entry.tokens, |piece| {
+                rest = rest.and_then(|rest| rest.strip_prefix(piece));
+                rest.is_some()
+            });
+            rest == Some("")
+        })?;
+    Some(to_char(entry.cp))
+}
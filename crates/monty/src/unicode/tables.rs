@@ -0,0 +1,7612 @@
+//! Unicode 15.1.0 tables generated by `scripts/generate_unicode_tables.py`. Do not edit.
+
+use super::Record;
+
+/// The Unicode version of these tables, exposed as `unicodedata.unidata_version`.
+pub(super) const UNIDATA_VERSION: &str = "15.1.0";
+
+/// Bits of the code point used to index within a block of `index.bin`.
+pub(super) const SHIFT: u32 = 7;
+
+/// General category names, indexed by [`Record::category`].
+pub(super) const CATEGORIES: [&str; 30] = ["Cn", "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps", "Pe", "Pi", "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co"];
+
+/// Bidirectional class names, indexed by [`Record::bidirectional`].
+pub(super) const BIDI_CLASSES: [&str; 24] = ["", "L", "LRE", "LRO", "R", "AL", "RLE", "RLO", "PDF", "EN", "ES", "ET", "AN", "CS", "NSM", "BN", "B", "S", "WS", "ON", "LRI", "RLI", "FSI", "PDI"];
+
+/// East Asian width names, indexed by [`Record::east_asian_width`].
+pub(super) const EAST_ASIAN_WIDTHS: [&str; 6] = ["N", "Na", "A", "W", "H", "F"];
+
+/// Decomposition tags, indexed by the tag of a `decompositions.bin` entry; tag 0 is canonical.
+pub(super) const DECOMPOSITION_TAGS: [&str; 17] = ["", "<font>", "<noBreak>", "<initial>", "<medial>", "<final>", "<isolated>", "<circle>", "<super>", "<sub>", "<vertical>", "<wide>", "<narrow>", "<small>", "<square>", "<fraction>", "<compat>"];
+
+/// The distinct character records, indexed by the second level of `index.bin`.
+pub(super) static RECORDS: [Record; 394] = [
+    Record::new(26, 15, 0, 0, 255, 0),
+    Record::new(26, 17, 0, 0, 255, 512),
+    Record::new(26, 16, 0, 0, 255, 512),
+    Record::new(26, 18, 0, 0, 255, 512),
+    Record::new(23, 18, 0, 1, 255, 1536),
+    Record::new(18, 19, 0, 1, 255, 1024),
+    Record::new(18, 11, 0, 1, 255, 1024),
+    Record::new(20, 11, 0, 1, 255, 1024),
+    Record::new(18, 19, 0, 1, 255, 1280),
+    Record::new(14, 19, 0, 1, 255, 9216),
+    Record::new(15, 19, 0, 1, 255, 9216),
+    Record::new(19, 10, 0, 1, 255, 1024),
+    Record::new(18, 13, 0, 1, 255, 1024),
+    Record::new(13, 10, 0, 1, 255, 1024),
+    Record::new(18, 13, 0, 1, 255, 1280),
+    Record::new(9, 9, 0, 1, 0, 5134),
+    Record::new(9, 9, 0, 1, 1, 5134),
+    Record::new(9, 9, 0, 1, 2, 5134),
+    Record::new(9, 9, 0, 1, 3, 5134),
+    Record::new(9, 9, 0, 1, 4, 5134),
+    Record::new(9, 9, 0, 1, 5, 5134),
+    Record::new(9, 9, 0, 1, 6, 5134),
+    Record::new(9, 9, 0, 1, 7, 5134),
+    Record::new(9, 9, 0, 1, 8, 5134),
+    Record::new(9, 9, 0, 1, 9, 5134),
+    Record::new(19, 19, 0, 1, 255, 9216),
+    Record::new(19, 19, 0, 1, 255, 1024),
+    Record::new(1, 1, 0, 1, 255, 7329),
+    Record::new(21, 19, 0, 1, 255, 1280),
+    Record::new(12, 19, 0, 1, 255, 7168),
+    Record::new(2, 1, 0, 1, 255, 7313),
+    Record::new(23, 13, 0, 0, 255, 512),
+    Record::new(18, 19, 0, 2, 255, 1024),
+    Record::new(20, 11, 0, 2, 255, 1024),
+    Record::new(22, 19, 0, 1, 255, 1024),
+    Record::new(21, 19, 0, 2, 255, 1280),
+    Record::new(22, 19, 0, 0, 255, 1024),
+    Record::new(5, 1, 0, 2, 255, 7313),
+    Record::new(16, 19, 0, 0, 255, 9216),
+    Record::new(27, 15, 0, 2, 255, 256),
+    Record::new(22, 19, 0, 2, 255, 1024),
+    Record::new(22, 11, 0, 2, 255, 1024),
+    Record::new(19, 11, 0, 2, 255, 1024),
+    Record::new(11, 9, 0, 2, 2, 1036),
+    Record::new(11, 9, 0, 2, 3, 1036),
+    Record::new(2, 1, 0, 0, 255, 7313),
+    Record::new(18, 19, 0, 2, 255, 5376),
+    Record::new(11, 9, 0, 2, 1, 1036),
+    Record::new(17, 19, 0, 0, 255, 9216),
+    Record::new(11, 19, 0, 2, 255, 1032),
+    Record::new(1, 1, 0, 0, 255, 7329),
+    Record::new(1, 1, 0, 2, 255, 7329),
+    Record::new(19, 19, 0, 2, 255, 1024),
+    Record::new(2, 1, 0, 2, 255, 7313),
+    Record::new(5, 1, 0, 0, 255, 7169),
+    Record::new(3, 1, 0, 0, 255, 7361),
+    Record::new(4, 1, 0, 0, 255, 7569),
+    Record::new(4, 19, 0, 0, 255, 7425),
+    Record::new(4, 1, 0, 0, 255, 7425),
+    Record::new(21, 19, 0, 0, 255, 1280),
+    Record::new(4, 19, 0, 2, 255, 7425),
+    Record::new(4, 1, 0, 2, 255, 7425),
+    Record::new(6, 14, 230, 2, 255, 5376),
+    Record::new(6, 14, 232, 2, 255, 5376),
+    Record::new(6, 14, 220, 2, 255, 5376),
+    Record::new(6, 14, 216, 2, 255, 5376),
+    Record::new(6, 14, 202, 2, 255, 5376),
+    Record::new(6, 14, 1, 2, 255, 5376),
+    Record::new(6, 14, 240, 2, 255, 5520),
+    Record::new(6, 14, 0, 2, 255, 5376),
+    Record::new(6, 14, 233, 2, 255, 5376),
+    Record::new(6, 14, 234, 2, 255, 5376),
+    Record::new(0, 0, 0, 0, 255, 0),
+    Record::new(4, 1, 0, 0, 255, 1425),
+    Record::new(18, 19, 0, 0, 255, 1024),
+    Record::new(18, 19, 0, 0, 255, 5376),
+    Record::new(19, 19, 0, 0, 255, 1024),
+    Record::new(22, 1, 0, 0, 255, 1024),
+    Record::new(6, 14, 230, 0, 255, 5376),
+    Record::new(8, 14, 0, 0, 255, 1280),
+    Record::new(18, 1, 0, 0, 255, 1024),
+    Record::new(18, 1, 0, 0, 255, 1280),
+    Record::new(13, 19, 0, 0, 255, 1024),
+    Record::new(20, 11, 0, 0, 255, 1024),
+    Record::new(6, 14, 220, 0, 255, 5376),
+    Record::new(6, 14, 222, 0, 255, 5376),
+    Record::new(6, 14, 228, 0, 255, 5376),
+    Record::new(6, 14, 10, 0, 255, 5376),
+    Record::new(6, 14, 11, 0, 255, 5376),
+    Record::new(6, 14, 12, 0, 255, 5376),
+    Record::new(6, 14, 13, 0, 255, 5376),
+    Record::new(6, 14, 14, 0, 255, 5376),
+    Record::new(6, 14, 15, 0, 255, 5376),
+    Record::new(6, 14, 16, 0, 255, 5376),
+    Record::new(6, 14, 17, 0, 255, 5376),
+    Record::new(6, 14, 18, 0, 255, 5376),
+    Record::new(6, 14, 19, 0, 255, 5376),
+    Record::new(6, 14, 20, 0, 255, 5376),
+    Record::new(6, 14, 21, 0, 255, 5376),
+    Record::new(6, 14, 22, 0, 255, 5376),
+    Record::new(13, 4, 0, 0, 255, 1024),
+    Record::new(6, 14, 23, 0, 255, 5376),
+    Record::new(18, 4, 0, 0, 255, 1024),
+    Record::new(6, 14, 24, 0, 255, 5376),
+    Record::new(6, 14, 25, 0, 255, 5376),
+    Record::new(5, 4, 0, 0, 255, 7169),
+    Record::new(18, 4, 0, 0, 255, 1280),
+    Record::new(27, 12, 0, 0, 255, 256),
+    Record::new(19, 5, 0, 0, 255, 1024),
+    Record::new(18, 11, 0, 0, 255, 1024),
+    Record::new(20, 5, 0, 0, 255, 1024),
+    Record::new(18, 13, 0, 0, 255, 1024),
+    Record::new(18, 5, 0, 0, 255, 1024),
+    Record::new(6, 14, 30, 0, 255, 5376),
+    Record::new(6, 14, 31, 0, 255, 5376),
+    Record::new(6, 14, 32, 0, 255, 5376),
+    Record::new(27, 5, 0, 0, 255, 256),
+    Record::new(5, 5, 0, 0, 255, 7169),
+    Record::new(4, 5, 0, 0, 255, 7425),
+    Record::new(6, 14, 27, 0, 255, 5376),
+    Record::new(6, 14, 28, 0, 255, 5376),
+    Record::new(6, 14, 29, 0, 255, 5376),
+    Record::new(6, 14, 33, 0, 255, 5376),
+    Record::new(6, 14, 34, 0, 255, 5376),
+    Record::new(9, 12, 0, 0, 0, 5134),
+    Record::new(9, 12, 0, 0, 1, 5134),
+    Record::new(9, 12, 0, 0, 2, 5134),
+    Record::new(9, 12, 0, 0, 3, 5134),
+    Record::new(9, 12, 0, 0, 4, 5134),
+    Record::new(9, 12, 0, 0, 5, 5134),
+    Record::new(9, 12, 0, 0, 6, 5134),
+    Record::new(9, 12, 0, 0, 7, 5134),
+    Record::new(9, 12, 0, 0, 8, 5134),
+    Record::new(9, 12, 0, 0, 9, 5134),
+    Record::new(18, 12, 0, 0, 255, 1024),
+    Record::new(6, 14, 35, 0, 255, 5376),
+    Record::new(9, 9, 0, 0, 0, 5134),
+    Record::new(9, 9, 0, 0, 1, 5134),
+    Record::new(9, 9, 0, 0, 2, 5134),
+    Record::new(9, 9, 0, 0, 3, 5134),
+    Record::new(9, 9, 0, 0, 4, 5134),
+    Record::new(9, 9, 0, 0, 5, 5134),
+    Record::new(9, 9, 0, 0, 6, 5134),
+    Record::new(9, 9, 0, 0, 7, 5134),
+    Record::new(9, 9, 0, 0, 8, 5134),
+    Record::new(9, 9, 0, 0, 9, 5134),
+    Record::new(22, 5, 0, 0, 255, 1024),
+    Record::new(6, 14, 36, 0, 255, 5376),
+    Record::new(6, 14, 0, 0, 255, 5376),
+    Record::new(9, 4, 0, 0, 0, 5134),
+    Record::new(9, 4, 0, 0, 1, 5134),
+    Record::new(9, 4, 0, 0, 2, 5134),
+    Record::new(9, 4, 0, 0, 3, 5134),
+    Record::new(9, 4, 0, 0, 4, 5134),
+    Record::new(9, 4, 0, 0, 5, 5134),
+    Record::new(9, 4, 0, 0, 6, 5134),
+    Record::new(9, 4, 0, 0, 7, 5134),
+    Record::new(9, 4, 0, 0, 8, 5134),
+    Record::new(9, 4, 0, 0, 9, 5134),
+    Record::new(4, 4, 0, 0, 255, 7425),
+    Record::new(20, 4, 0, 0, 255, 1024),
+    Record::new(21, 5, 0, 0, 255, 1280),
+    Record::new(7, 1, 0, 0, 255, 5120),
+    Record::new(6, 14, 7, 0, 255, 5376),
+    Record::new(6, 14, 9, 0, 255, 5376),
+    Record::new(9, 1, 0, 0, 0, 5134),
+    Record::new(9, 1, 0, 0, 1, 5134),
+    Record::new(9, 1, 0, 0, 2, 5134),
+    Record::new(9, 1, 0, 0, 3, 5134),
+    Record::new(9, 1, 0, 0, 4, 5134),
+    Record::new(9, 1, 0, 0, 5, 5134),
+    Record::new(9, 1, 0, 0, 6, 5134),
+    Record::new(9, 1, 0, 0, 7, 5134),
+    Record::new(9, 1, 0, 0, 8, 5134),
+    Record::new(9, 1, 0, 0, 9, 5134),
+    Record::new(11, 1, 0, 0, 255, 1032),
+    Record::new(6, 14, 84, 0, 255, 5376),
+    Record::new(6, 14, 91, 0, 255, 5376),
+    Record::new(11, 19, 0, 0, 255, 1032),
+    Record::new(6, 1, 0, 0, 255, 5376),
+    Record::new(5, 1, 0, 0, 255, 5121),
+    Record::new(6, 14, 103, 0, 255, 5376),
+    Record::new(6, 14, 107, 0, 255, 5376),
+    Record::new(6, 14, 118, 0, 255, 5376),
+    Record::new(6, 14, 122, 0, 255, 5376),
+    Record::new(6, 14, 216, 0, 255, 5376),
+    Record::new(14, 19, 0, 0, 255, 9216),
+    Record::new(15, 19, 0, 0, 255, 9216),
+    Record::new(6, 14, 129, 0, 255, 5376),
+    Record::new(6, 14, 130, 0, 255, 5376),
+    Record::new(6, 14, 132, 0, 255, 5376),
+    Record::new(5, 1, 0, 3, 255, 7169),
+    Record::new(11, 1, 0, 0, 1, 5132),
+    Record::new(11, 1, 0, 0, 2, 5132),
+    Record::new(11, 1, 0, 0, 3, 5132),
+    Record::new(11, 1, 0, 0, 4, 5132),
+    Record::new(11, 1, 0, 0, 5, 5132),
+    Record::new(11, 1, 0, 0, 6, 5132),
+    Record::new(11, 1, 0, 0, 7, 5132),
+    Record::new(11, 1, 0, 0, 8, 5132),
+    Record::new(11, 1, 0, 0, 9, 5132),
+    Record::new(23, 18, 0, 0, 255, 512),
+    Record::new(10, 1, 0, 0, 255, 7176),
+    Record::new(7, 1, 9, 0, 255, 5120),
+    Record::new(27, 15, 0, 0, 255, 256),
+    Record::new(6, 14, 0, 0, 255, 7424),
+    Record::new(6, 14, 1, 0, 255, 5376),
+    Record::new(6, 14, 234, 0, 255, 5376),
+    Record::new(6, 14, 214, 0, 255, 5376),
+    Record::new(6, 14, 202, 0, 255, 5376),
+    Record::new(6, 14, 232, 0, 255, 5376),
+    Record::new(6, 14, 218, 0, 255, 5376),
+    Record::new(6, 14, 233, 0, 255, 5376),
+    Record::new(27, 15, 0, 0, 255, 4352),
+    Record::new(27, 1, 0, 0, 255, 256),
+    Record::new(27, 4, 0, 0, 255, 256),
+    Record::new(13, 19, 0, 2, 255, 1024),
+    Record::new(16, 19, 0, 2, 255, 1280),
+    Record::new(17, 19, 0, 2, 255, 1280),
+    Record::new(14, 19, 0, 0, 255, 1024),
+    Record::new(16, 19, 0, 0, 255, 1024),
+    Record::new(16, 19, 0, 2, 255, 1024),
+    Record::new(17, 19, 0, 2, 255, 1024),
+    Record::new(18, 19, 0, 2, 255, 1280),
+    Record::new(24, 18, 0, 0, 255, 512),
+    Record::new(25, 16, 0, 0, 255, 512),
+    Record::new(27, 2, 0, 0, 255, 256),
+    Record::new(27, 6, 0, 0, 255, 256),
+    Record::new(27, 8, 0, 0, 255, 256),
+    Record::new(27, 3, 0, 0, 255, 256),
+    Record::new(27, 7, 0, 0, 255, 256),
+    Record::new(18, 11, 0, 2, 255, 1024),
+    Record::new(12, 19, 0, 0, 255, 5120),
+    Record::new(19, 13, 0, 0, 255, 1024),
+    Record::new(27, 20, 0, 0, 255, 256),
+    Record::new(27, 21, 0, 0, 255, 256),
+    Record::new(27, 22, 0, 0, 255, 256),
+    Record::new(27, 23, 0, 0, 255, 256),
+    Record::new(11, 9, 0, 0, 0, 1036),
+    Record::new(11, 9, 0, 2, 4, 1036),
+    Record::new(11, 9, 0, 0, 5, 1036),
+    Record::new(11, 9, 0, 0, 6, 1036),
+    Record::new(11, 9, 0, 0, 7, 1036),
+    Record::new(11, 9, 0, 0, 8, 1036),
+    Record::new(11, 9, 0, 0, 9, 1036),
+    Record::new(19, 10, 0, 0, 255, 1024),
+    Record::new(4, 1, 0, 2, 255, 7569),
+    Record::new(20, 11, 0, 4, 255, 1024),
+    Record::new(19, 19, 0, 0, 255, 7168),
+    Record::new(22, 11, 0, 0, 255, 7168),
+    Record::new(19, 19, 0, 0, 255, 9216),
+    Record::new(10, 1, 0, 2, 255, 7336),
+    Record::new(10, 1, 0, 0, 255, 7336),
+    Record::new(10, 1, 0, 2, 255, 7320),
+    Record::new(10, 1, 0, 0, 255, 7320),
+    Record::new(19, 19, 0, 2, 255, 9216),
+    Record::new(19, 11, 0, 0, 255, 1024),
+    Record::new(22, 19, 0, 3, 255, 1024),
+    Record::new(14, 19, 0, 3, 255, 9216),
+    Record::new(15, 19, 0, 3, 255, 9216),
+    Record::new(11, 19, 0, 2, 1, 1036),
+    Record::new(11, 19, 0, 2, 2, 1036),
+    Record::new(11, 19, 0, 2, 3, 1036),
+    Record::new(11, 19, 0, 2, 4, 1036),
+    Record::new(11, 19, 0, 2, 5, 1036),
+    Record::new(11, 19, 0, 2, 6, 1036),
+    Record::new(11, 19, 0, 2, 7, 1036),
+    Record::new(11, 19, 0, 2, 8, 1036),
+    Record::new(11, 19, 0, 2, 9, 1036),
+    Record::new(11, 9, 0, 2, 5, 1036),
+    Record::new(11, 9, 0, 2, 6, 1036),
+    Record::new(11, 9, 0, 2, 7, 1036),
+    Record::new(11, 9, 0, 2, 8, 1036),
+    Record::new(11, 9, 0, 2, 9, 1036),
+    Record::new(11, 9, 0, 2, 255, 1032),
+    Record::new(22, 1, 0, 2, 255, 1024),
+    Record::new(22, 1, 0, 2, 255, 1184),
+    Record::new(22, 1, 0, 2, 255, 1168),
+    Record::new(11, 19, 0, 0, 0, 1036),
+    Record::new(11, 19, 0, 2, 0, 1036),
+    Record::new(19, 19, 0, 3, 255, 1024),
+    Record::new(11, 19, 0, 0, 1, 1036),
+    Record::new(11, 19, 0, 0, 2, 1036),
+    Record::new(11, 19, 0, 0, 3, 1036),
+    Record::new(11, 19, 0, 0, 4, 1036),
+    Record::new(11, 19, 0, 0, 5, 1036),
+    Record::new(11, 19, 0, 0, 6, 1036),
+    Record::new(11, 19, 0, 0, 7, 1036),
+    Record::new(11, 19, 0, 0, 8, 1036),
+    Record::new(11, 19, 0, 0, 9, 1036),
+    Record::new(22, 19, 0, 0, 255, 9216),
+    Record::new(4, 19, 0, 0, 255, 1281),
+    Record::new(23, 18, 0, 5, 255, 512),
+    Record::new(18, 19, 0, 3, 255, 1024),
+    Record::new(4, 1, 0, 3, 255, 7425),
+    Record::new(10, 1, 0, 3, 255, 7176),
+    Record::new(13, 19, 0, 3, 255, 1024),
+    Record::new(14, 19, 0, 3, 255, 1024),
+    Record::new(15, 19, 0, 3, 255, 1024),
+    Record::new(6, 14, 218, 3, 255, 5376),
+    Record::new(6, 14, 228, 3, 255, 5376),
+    Record::new(6, 14, 232, 3, 255, 5376),
+    Record::new(6, 14, 222, 3, 255, 5376),
+    Record::new(7, 1, 224, 3, 255, 5120),
+    Record::new(6, 14, 8, 3, 255, 5376),
+    Record::new(21, 19, 0, 3, 255, 1280),
+    Record::new(18, 19, 0, 3, 255, 5120),
+    Record::new(22, 1, 0, 3, 255, 1024),
+    Record::new(11, 1, 0, 3, 255, 1032),
+    Record::new(11, 1, 0, 2, 255, 1032),
+    Record::new(11, 19, 0, 3, 255, 1032),
+    Record::new(5, 1, 0, 3, 255, 7177),
+    Record::new(21, 1, 0, 0, 255, 1280),
+    Record::new(22, 11, 0, 0, 255, 1024),
+    Record::new(28, 1, 0, 0, 255, 0),
+    Record::new(29, 1, 0, 2, 255, 0),
+    Record::new(0, 0, 0, 3, 255, 0),
+    Record::new(6, 14, 26, 0, 255, 5376),
+    Record::new(5, 5, 0, 0, 255, 1025),
+    Record::new(15, 19, 0, 0, 255, 1024),
+    Record::new(18, 19, 0, 3, 255, 1280),
+    Record::new(12, 19, 0, 3, 255, 5120),
+    Record::new(18, 13, 0, 3, 255, 1024),
+    Record::new(18, 13, 0, 3, 255, 1280),
+    Record::new(18, 11, 0, 3, 255, 1024),
+    Record::new(19, 10, 0, 3, 255, 1024),
+    Record::new(13, 10, 0, 3, 255, 1024),
+    Record::new(19, 19, 0, 3, 255, 9216),
+    Record::new(20, 11, 0, 3, 255, 1024),
+    Record::new(18, 19, 0, 5, 255, 1024),
+    Record::new(18, 11, 0, 5, 255, 1024),
+    Record::new(20, 11, 0, 5, 255, 1024),
+    Record::new(18, 19, 0, 5, 255, 1280),
+    Record::new(14, 19, 0, 5, 255, 9216),
+    Record::new(15, 19, 0, 5, 255, 9216),
+    Record::new(19, 10, 0, 5, 255, 1024),
+    Record::new(18, 13, 0, 5, 255, 1024),
+    Record::new(13, 10, 0, 5, 255, 1024),
+    Record::new(18, 13, 0, 5, 255, 1280),
+    Record::new(9, 9, 0, 5, 0, 5134),
+    Record::new(9, 9, 0, 5, 1, 5134),
+    Record::new(9, 9, 0, 5, 2, 5134),
+    Record::new(9, 9, 0, 5, 3, 5134),
+    Record::new(9, 9, 0, 5, 4, 5134),
+    Record::new(9, 9, 0, 5, 5, 5134),
+    Record::new(9, 9, 0, 5, 6, 5134),
+    Record::new(9, 9, 0, 5, 7, 5134),
+    Record::new(9, 9, 0, 5, 8, 5134),
+    Record::new(9, 9, 0, 5, 9, 5134),
+    Record::new(19, 19, 0, 5, 255, 9216),
+    Record::new(19, 19, 0, 5, 255, 1024),
+    Record::new(1, 1, 0, 5, 255, 7329),
+    Record::new(21, 19, 0, 5, 255, 1280),
+    Record::new(12, 19, 0, 5, 255, 5120),
+    Record::new(2, 1, 0, 5, 255, 7313),
+    Record::new(18, 19, 0, 4, 255, 1024),
+    Record::new(14, 19, 0, 4, 255, 9216),
+    Record::new(15, 19, 0, 4, 255, 9216),
+    Record::new(18, 19, 0, 4, 255, 5120),
+    Record::new(5, 1, 0, 4, 255, 7169),
+    Record::new(4, 1, 0, 4, 255, 7425),
+    Record::new(4, 1, 0, 4, 255, 5377),
+    Record::new(22, 19, 0, 5, 255, 1024),
+    Record::new(22, 19, 0, 4, 255, 1024),
+    Record::new(19, 19, 0, 4, 255, 1024),
+    Record::new(27, 19, 0, 0, 255, 256),
+    Record::new(10, 19, 0, 0, 255, 7176),
+    Record::new(11, 9, 0, 0, 255, 1032),
+    Record::new(11, 4, 0, 0, 255, 1032),
+    Record::new(22, 4, 0, 0, 255, 1024),
+    Record::new(11, 4, 0, 0, 1, 1036),
+    Record::new(11, 4, 0, 0, 2, 1036),
+    Record::new(11, 4, 0, 0, 3, 1036),
+    Record::new(11, 4, 0, 0, 4, 1036),
+    Record::new(1, 4, 0, 0, 255, 7329),
+    Record::new(2, 4, 0, 0, 255, 7313),
+    Record::new(11, 12, 0, 0, 1, 1036),
+    Record::new(11, 12, 0, 0, 2, 1036),
+    Record::new(11, 12, 0, 0, 3, 1036),
+    Record::new(11, 12, 0, 0, 4, 1036),
+    Record::new(11, 12, 0, 0, 5, 1036),
+    Record::new(11, 12, 0, 0, 6, 1036),
+    Record::new(11, 12, 0, 0, 7, 1036),
+    Record::new(11, 12, 0, 0, 8, 1036),
+    Record::new(11, 12, 0, 0, 9, 1036),
+    Record::new(11, 12, 0, 0, 255, 1032),
+    Record::new(11, 5, 0, 0, 255, 1032),
+    Record::new(6, 1, 9, 0, 255, 5376),
+    Record::new(6, 14, 0, 3, 255, 5376),
+    Record::new(7, 1, 6, 3, 255, 5120),
+    Record::new(7, 1, 216, 0, 255, 5120),
+    Record::new(7, 1, 226, 0, 255, 5120),
+    Record::new(19, 1, 0, 0, 255, 1024),
+    Record::new(11, 9, 0, 2, 0, 1036),
+];
+
+/// Number of `u16` entries of the first level of `index.bin`, which holds the
+/// block of each `1 << SHIFT` code points; the second level after it holds the
+/// [`RECORDS`] index of each code point, in shared blocks.
+pub(super) const INDEX1_LEN: usize = 8704;
+
+/// Full case mappings `(code point, lower, upper, title, casefold)` for every
+/// character with at least one mapping other than itself, ordered by code point.
+pub(super) static CASING: &[(u32, &str, &str, &str, &str)] = &[
+    (0x41, "a", "A", "A", "a"),
+    (0x42, "b", "B", "B", "b"),
+    (0x43, "c", "C", "C", "c"),
+    (0x44, "d", "D", "D", "d"),
+    (0x45, "e", "E", "E", "e"),
+    (0x46, "f", "F", "F", "f"),
+    (0x47, "g", "G", "G", "g"),
+    (0x48, "h", "H", "H", "h"),
+    (0x49, "i", "I", "I", "i"),
+    (0x4a, "j", "J", "J", "j"),
+    (0x4b, "k", "K", "K", "k"),
+    (0x4c, "l", "L", "L", "l"),
+    (0x4d, "m", "M", "M", "m"),
+    (0x4e, "n", "N", "N", "n"),
+    (0x4f, "o", "O", "O", "o"),
+    (0x50, "p", "P", "P", "p"),
+    (0x51, "q", "Q", "Q", "q"),
+    (0x52, "r", "R", "R", "r"),
+    (0x53, "s", "S", "S", "s"),
+    (0x54, "t", "T", "T", "t"),
+    (0x55, "u", "U", "U", "u"),
+    (0x56, "v", "V", "V", "v"),
+    (0x57, "w", "W", "W", "w"),
+    (0x58, "x", "X", "X", "x"),
+    (0x59, "y", "Y", "Y", "y"),
+    (0x5a, "z", "Z", "Z", "z"),
+    (0x61, "a", "A", "A", "a"),
+    (0x62, "b", "B", "B", "b"),
+    (0x63, "c", "C", "C", "c"),
+    (0x64, "d", "D", "D", "d"),
+    (0x65, "e", "E", "E", "e"),
+    (0x66, "f", "F", "F", "f"),
+    (0x67, "g", "G", "G", "g"),
+    (0x68, "h", "H", "H", "h"),
+    (0x69, "i", "I", "I", "i"),
+    (0x6a, "j", "J", "J", "j"),
+    (0x6b, "k", "K", "K", "k"),
+    (0x6c, "l", "L", "L", "l"),
+    (0x6d, "m", "M", "M", "m"),
+    (0x6e, "n", "N", "N", "n"),
+    (0x6f, "o", "O", "O", "o"),
+    (0x70, "p", "P", "P", "p"),
+    (0x71, "q", "Q", "Q", "q"),
+    (0x72, "r", "R", "R", "r"),
+    (0x73, "s", "S", "S", "s"),
+    (0x74, "t", "T", "T", "t"),
+    (0x75, "u", "U", "U", "u"),
+    (0x76, "v", "V", "V", "v"),
+    (0x77, "w", "W", "W", "w"),
+    (0x78, "x", "X", "X", "x"),
+    (0x79, "y", "Y", "Y", "y"),
+    (0x7a, "z", "Z", "Z", "z"),
+    (0xb5, "\u{b5}", "\u{39c}", "\u{39c}", "\u{3bc}"),
+    (0xc0, "\u{e0}", "\u{c0}", "\u{c0}", "\u{e0}"),
+    (0xc1, "\u{e1}", "\u{c1}", "\u{c1}", "\u{e1}"),
+    (0xc2, "\u{e2}", "\u{c2}", "\u{c2}", "\u{e2}"),
+    (0xc3, "\u{e3}", "\u{c3}", "\u{c3}", "\u{e3}"),
+    (0xc4, "\u{e4}", "\u{c4}", "\u{c4}", "\u{e4}"),
+    (0xc5, "\u{e5}", "\u{c5}", "\u{c5}", "\u{e5}"),
+    (0xc6, "\u{e6}", "\u{c6}", "\u{c6}", "\u{e6}"),
+    (0xc7, "\u{e7}", "\u{c7}", "\u{c7}", "\u{e7}"),
+    (0xc8, "\u{e8}", "\u{c8}", "\u{c8}", "\u{e8}"),
+    (0xc9, "\u{e9}", "\u{c9}", "\u{c9}", "\u{e9}"),
+    (0xca, "\u{ea}", "\u{ca}", "\u{ca}", "\u{ea}"),
+    (0xcb, "\u{eb}", "\u{cb}", "\u{cb}", "\u{eb}"),
+    (0xcc, "\u{ec}", "\u{cc}", "\u{cc}", "\u{ec}"),
+    (0xcd, "\u{ed}", "\u{cd}", "\u{cd}", "\u{ed}"),
+    (0xce, "\u{ee}", "\u{ce}", "\u{ce}", "\u{ee}"),
+    (0xcf, "\u{ef}", "\u{cf}", "\u{cf}", "\u{ef}"),
+    (0xd0, "\u{f0}", "\u{d0}", "\u{d0}", "\u{f0}"),
+    (0xd1, "\u{f1}", "\u{d1}", "\u{d1}", "\u{f1}"),
+    (0xd2, "\u{f2}", "\u{d2}", "\u{d2}", "\u{f2}"),
+    (0xd3, "\u{f3}", "\u{d3}", "\u{d3}", "\u{f3}"),
+    (0xd4, "\u{f4}", "\u{d4}", "\u{d4}", "\u{f4}"),
+    (0xd5, "\u{f5}", "\u{d5}", "\u{d5}", "\u{f5}"),
+    (0xd6, "\u{f6}", "\u{d6}", "\u{d6}", "\u{f6}"),
+    (0xd8, "\u{f8}", "\u{d8}", "\u{d8}", "\u{f8}"),
+    (0xd9, "\u{f9}", "\u{d9}", "\u{d9}", "\u{f9}"),
+    (0xda, "\u{fa}", "\u{da}", "\u{da}", "\u{fa}"),
+    (0xdb, "\u{fb}", "\u{db}", "\u{db}", "\u{fb}"),
+    (0xdc, "\u{fc}", "\u{dc}", "\u{dc}", "\u{fc}"),
+    (0xdd, "\u{fd}", "\u{dd}", "\u{dd}", "\u{fd}"),
+    (0xde, "\u{fe}", "\u{de}", "\u{de}", "\u{fe}"),
+    (0xdf, "\u{df}", "SS", "Ss", "ss"),
+    (0xe0, "\u{e0}", "\u{c0}", "\u{c0}", "\u{e0}"),
+    (0xe1, "\u{e1}", "\u{c1}", "\u{c1}", "\u{e1}"),
+    (0xe2, "\u{e2}", "\u{c2}", "\u{c2}", "\u{e2}"),
+    (0xe3, "\u{e3}", "\u{c3}", "\u{c3}", "\u{e3}"),
+    (0xe4, "\u{e4}", "\u{c4}", "\u{c4}", "\u{e4}"),
+    (0xe5, "\u{e5}", "\u{c5}", "\u{c5}", "\u{e5}"),
+    (0xe6, "\u{e6}", "\u{c6}", "\u{c6}", "\u{e6}"),
+    (0xe7, "\u{e7}", "\u{c7}", "\u{c7}", "\u{e7}"),
+    (0xe8, "\u{e8}", "\u{c8}", "\u{c8}", "\u{e8}"),
+    (0xe9, "\u{e9}", "\u{c9}", "\u{c9}", "\u{e9}"),
+    (0xea, "\u{ea}", "\u{ca}", "\u{ca}", "\u{ea}"),
+    (0xeb, "\u{eb}", "\u{cb}", "\u{cb}", "\u{eb}"),
+    (0xec, "\u{ec}", "\u{cc}", "\u{cc}", "\u{ec}"),
+    (0xed, "\u{ed}", "\u{cd}", "\u{cd}", "\u{ed}"),
+    (0xee, "\u{ee}", "\u{ce}", "\u{ce}", "\u{ee}"),
+    (0xef, "\u{ef}", "\u{cf}", "\u{cf}", "\u{ef}"),
+    (0xf0, "\u{f0}", "\u{d0}", "\u{d0}", "\u{f0}"),
+    (0xf1, "\u{f1}", "\u{d1}", "\u{d1}", "\u{f1}"),
+    (0xf2, "\u{f2}", "\u{d2}", "\u{d2}", "\u{f2}"),
+    (0xf3, "\u{f3}", "\u{d3}", "\u{d3}", "\u{f3}"),
+    (0xf4, "\u{f4}", "\u{d4}", "\u{d4}", "\u{f4}"),
+    (0xf5, "\u{f5}", "\u{d5}", "\u{d5}", "\u{f5}"),
+    (0xf6, "\u{f6}", "\u{d6}", "\u{d6}", "\u{f6}"),
+    (0xf8, "\u{f8}", "\u{d8}", "\u{d8}", "\u{f8}"),
+    (0xf9, "\u{f9}", "\u{d9}", "\u{d9}", "\u{f9}"),
+    (0xfa, "\u{fa}", "\u{da}", "\u{da}", "\u{fa}"),
+    (0xfb, "\u{fb}", "\u{db}", "\u{db}", "\u{fb}"),
+    (0xfc, "\u{fc}", "\u{dc}", "\u{dc}", "\u{fc}"),
+    (0xfd, "\u{fd}", "\u{dd}", "\u{dd}", "\u{fd}"),
+    (0xfe, "\u{fe}", "\u{de}", "\u{de}", "\u{fe}"),
+    (0xff, "\u{ff}", "\u{178}", "\u{178}", "\u{ff}"),
+    (0x100, "\u{101}", "\u{100}", "\u{100}", "\u{101}"),
+    (0x101, "\u{101}", "\u{100}", "\u{100}", "\u{101}"),
+    (0x102, "\u{103}", "\u{102}", "\u{102}", "\u{103}"),
+    (0x103, "\u{103}", "\u{102}", "\u{102}", "\u{103}"),
+    (0x104, "\u{105}", "\u{104}", "\u{104}", "\u{105}"),
+    (0x105, "\u{105}", "\u{104}", "\u{104}", "\u{105}"),
+    (0x106, "\u{107}", "\u{106}", "\u{106}", "\u{107}"),
+    (0x107, "\u{107}", "\u{106}", "\u{106}", "\u{107}"),
+    (0x108, "\u{109}", "\u{108}", "\u{108}", "\u{109}"),
+    (0x109, "\u{109}", "\u{108}", "\u{108}", "\u{109}"),
+    (0x10a, "\u{10b}", "\u{10a}", "\u{10a}", "\u{10b}"),
+    (0x10b, "\u{10b}", "\u{10a}", "\u{10a}", "\u{10b}"),
+    (0x10c, "\u{10d}", "\u{10c}", "\u{10c}", "\u{10d}"),
+    (0x10d, "\u{10d}", "\u{10c}", "\u{10c}", "\u{10d}"),
+    (0x10e, "\u{10f}", "\u{10e}", "\u{10e}", "\u{10f}"),
+    (0x10f, "\u{10f}", "\u{10e}", "\u{10e}", "\u{10f}"),
+    (0x110, "\u{111}", "\u{110}", "\u{110}", "\u{111}"),
+    (0x111, "\u{111}", "\u{110}", "\u{110}", "\u{111}"),
+    (0x112, "\u{113}", "\u{112}", "\u{112}", "\u{113}"),
+    (0x113, "\u{113}", "\u{112}", "\u{112}", "\u{113}"),
+    (0x114, "\u{115}", "\u{114}", "\u{114}", "\u{115}"),
+    (0x115, "\u{115}", "\u{114}", "\u{114}", "\u{115}"),
+    (0x116, "\u{117}", "\u{116}", "\u{116}", "\u{117}"),
+    (0x117, "\u{117}", "\u{116}", "\u{116}", "\u{117}"),
+    (0x118, "\u{119}", "\u{118}", "\u{118}", "\u{119}"),
+    (0x119, "\u{119}", "\u{118}", "\u{118}", "\u{119}"),
+    (0x11a, "\u{11b}", "\u{11a}", "\u{11a}", "\u{11b}"),
+    (0x11b, "\u{11b}", "\u{11a}", "\u{11a}", "\u{11b}"),
+    (0x11c, "\u{11d}", "\u{11c}", "\u{11c}", "\u{11d}"),
+    (0x11d, "\u{11d}", "\u{11c}", "\u{11c}", "\u{11d}"),
+    (0x11e, "\u{11f}", "\u{11e}", "\u{11e}", "\u{11f}"),
+    (0x11f, "\u{11f}", "\u{11e}", "\u{11e}", "\u{11f}"),
+    (0x120, "\u{121}", "\u{120}", "\u{120}", "\u{121}"),
+    (0x121, "\u{121}", "\u{120}", "\u{120}", "\u{121}"),
+    (0x122, "\u{123}", "\u{122}", "\u{122}", "\u{123}"),
+    (0x123, "\u{123}", "\u{122}", "\u{122}", "\u{123}"),
+    (0x124, "\u{125}", "\u{124}", "\u{124}", "\u{125}"),
+    (0x125, "\u{125}", "\u{124}", "\u{124}", "\u{125}"),
+    (0x126, "\u{127}", "\u{126}", "\u{126}", "\u{127}"),
+    (0x127, "\u{127}", "\u{126}", "\u{126}", "\u{127}"),
+    (0x128, "\u{129}", "\u{128}", "\u{128}", "\u{129}"),
+    (0x129, "\u{129}", "\u{128}", "\u{128}", "\u{129}"),
+    (0x12a, "\u{12b}", "\u{12a}", "\u{12a}", "\u{12b}"),
+    (0x12b, "\u{12b}", "\u{12a}", "\u{12a}", "\u{12b}"),
+    (0x12c, "\u{12d}", "\u{12c}", "\u{12c}", "\u{12d}"),
+    (0x12d, "\u{12d}", "\u{12c}", "\u{12c}", "\u{12d}"),
+    (0x12e, "\u{12f}", "\u{12e}", "\u{12e}", "\u{12f}"),
+    (0x12f, "\u{12f}", "\u{12e}", "\u{12e}", "\u{12f}"),
+    (0x130, "i\u{307}", "\u{130}", "\u{130}", "i\u{307}"),
+    (0x131, "\u{131}", "I", "I", "\u{131}"),
+    (0x132, "\u{133}", "\u{132}", "\u{132}", "\u{133}"),
+    (0x133, "\u{133}", "\u{132}", "\u{132}", "\u{133}"),
+    (0x134, "\u{135}", "\u{134}", "\u{134}", "\u{135}"),
+    (0x135, "\u{135}", "\u{134}", "\u{134}", "\u{135}"),
+    (0x136, "\u{137}", "\u{136}", "\u{136}", "\u{137}"),
+    (0x137, "\u{137}", "\u{136}", "\u{136}", "\u{137}"),
+    (0x139, "\u{13a}", "\u{139}", "\u{139}", "\u{13a}"),
+    (0x13a, "\u{13a}", "\u{139}", "\u{139}", "\u{13a}"),
+    (0x13b, "\u{13c}", "\u{13b}", "\u{13b}", "\u{13c}"),
+    (0x13c, "\u{13c}", "\u{13b}", "\u{13b}", "\u{13c}"),
+    (0x13d, "\u{13e}", "\u{13d}", "\u{13d}", "\u{13e}"),
+    (0x13e, "\u{13e}", "\u{13d}", "\u{13d}", "\u{13e}"),
+    (0x13f, "\u{140}", "\u{13f}", "\u{13f}", "\u{140}"),
+    (0x140, "\u{140}", "\u{13f}", "\u{13f}", "\u{140}"),
+    (0x141, "\u{142}", "\u{141}", "\u{141}", "\u{142}"),
+    (0x142, "\u{142}", "\u{141}", "\u{141}", "\u{142}"),
+    (0x143, "\u{144}", "\u{143}", "\u{143}", "\u{144}"),
+    (0x144, "\u{144}", "\u{143}", "\u{143}", "\u{144}"),
+    (0x145, "\u{146}", "\u{145}", "\u{145}", "\u{146}"),
+    (0x146, "\u{146}", "\u{145}", "\u{145}", "\u{146}"),
+    (0x147, "\u{148}", "\u{147}", "\u{147}", "\u{148}"),
+    (0x148, "\u{148}", "\u{147}", "\u{147}", "\u{148}"),
+    (0x149, "\u{149}", "\u{2bc}N", "\u{2bc}N", "\u{2bc}n"),
+    (0x14a, "\u{14b}", "\u{14a}", "\u{14a}", "\u{14b}"),
+    (0x14b, "\u{14b}", "\u{14a}", "\u{14a}", "\u{14b}"),
+    (0x14c, "\u{14d}", "\u{14c}", "\u{14c}", "\u{14d}"),
+    (0x14d, "\u{14d}", "\u{14c}", "\u{14c}", "\u{14d}"),
+    (0x14e, "\u{14f}", "\u{14e}", "\u{14e}", "\u{14f}"),
+    (0x14f, "\u{14f}", "\u{14e}", "\u{14e}", "\u{14f}"),
+    (0x150, "\u{151}", "\u{150}", "\u{150}", "\u{151}"),
+    (0x151, "\u{151}", "\u{150}", "\u{150}", "\u{151}"),
+    (0x152, "\u{153}", "\u{152}", "\u{152}", "\u{153}"),
+    (0x153, "\u{153}", "\u{152}", "\u{152}", "\u{153}"),
+    (0x154, "\u{155}", "\u{154}", "\u{154}", "\u{155}"),
+    (0x155, "\u{155}", "\u{154}", "\u{154}", "\u{155}"),
+    (0x156, "\u{157}", "\u{156}", "\u{156}", "\u{157}"),
+    (0x157, "\u{157}", "\u{156}", "\u{156}", "\u{157}"),
+    (0x158, "\u{159}", "\u{158}", "\u{158}", "\u{159}"),
+    (0x159, "\u{159}", "\u{158}", "\u{158}", "\u{159}"),
+    (0x15a, "\u{15b}", "\u{15a}", "\u{15a}", "\u{15b}"),
+    (0x15b, "\u{15b}", "\u{15a}", "\u{15a}", "\u{15b}"),
+    (0x15c, "\u{15d}", "\u{15c}", "\u{15c}", "\u{15d}"),
+    (0x15d, "\u{15d}", "\u{15c}", "\u{15c}", "\u{15d}"),
+    (0x15e, "\u{15f}", "\u{15e}", "\u{15e}", "\u{15f}"),
+    (0x15f, "\u{15f}", "\u{15e}", "\u{15e}", "\u{15f}"),
+    (0x160, "\u{161}", "\u{160}", "\u{160}", "\u{161}"),
+    (0x161, "\u{161}", "\u{160}", "\u{160}", "\u{161}"),
+    (0x162, "\u{163}", "\u{162}", "\u{162}", "\u{163}"),
+    (0x163, "\u{163}", "\u{162}", "\u{162}", "\u{163}"),
+    (0x164, "\u{165}", "\u{164}", "\u{164}", "\u{165}"),
+    (0x165, "\u{165}", "\u{164}", "\u{164}", "\u{165}"),
+    (0x166, "\u{167}", "\u{166}", "\u{166}", "\u{167}"),
+    (0x167, "\u{167}", "\u{166}", "\u{166}", "\u{167}"),
+    (0x168, "\u{169}", "\u{168}", "\u{168}", "\u{169}"),
+    (0x169, "\u{169}", "\u{168}", "\u{168}", "\u{169}"),
+    (0x16a, "\u{16b}", "\u{16a}", "\u{16a}", "\u{16b}"),
+    (0x16b, "\u{16b}", "\u{16a}", "\u{16a}", "\u{16b}"),
+    (0x16c, "\u{16d}", "\u{16c}", "\u{16c}", "\u{16d}"),
+    (0x16d, "\u{16d}", "\u{16c}", "\u{16c}", "\u{16d}"),
+    (0x16e, "\u{16f}", "\u{16e}", "\u{16e}", "\u{16f}"),
+    (0x16f, "\u{16f}", "\u{16e}", "\u{16e}", "\u{16f}"),
+    (0x170, "\u{171}", "\u{170}", "\u{170}", "\u{171}"),
+    (0x171, "\u{171}", "\u{170}", "\u{170}", "\u{171}"),
+    (0x172, "\u{173}", "\u{172}", "\u{172}", "\u{173}"),
+    (0x173, "\u{173}", "\u{172}", "\u{172}", "\u{173}"),
+    (0x174, "\u{175}", "\u{174}", "\u{174}", "\u{175}"),
+    (0x175, "\u{175}", "\u{174}", "\u{174}", "\u{175}"),
+    (0x176, "\u{177}", "\u{176}", "\u{176}", "\u{177}"),
+    (0x177, "\u{177}", "\u{176}", "\u{176}", "\u{177}"),
+    (0x178, "\u{ff}", "\u{178}", "\u{178}", "\u{ff}"),
+    (0x179, "\u{17a}", "\u{179}", "\u{179}", "\u{17a}"),
+    (0x17a, "\u{17a}", "\u{179}", "\u{179}", "\u{17a}"),
+    (0x17b, "\u{17c}", "\u{17b}", "\u{17b}", "\u{17c}"),
+    (0x17c, "\u{17c}", "\u{17b}", "\u{17b}", "\u{17c}"),
+    (0x17d, "\u{17e}", "\u{17d}", "\u{17d}", "\u{17e}"),
+    (0x17e, "\u{17e}", "\u{17d}", "\u{17d}", "\u{17e}"),
+    (0x17f, "\u{17f}", "S", "S", "s"),
+    (0x180, "\u{180}", "\u{243}", "\u{243}", "\u{180}"),
+    (0x181, "\u{253}", "\u{181}", "\u{181}", "\u{253}"),
+    (0x182, "\u{183}", "\u{182}", "\u{182}", "\u{183}"),
+    (0x183, "\u{183}", "\u{182}", "\u{182}", "\u{183}"),
+    (0x184, "\u{185}", "\u{184}", "\u{184}", "\u{185}"),
+    (0x185, "\u{185}", "\u{184}", "\u{184}", "\u{185}"),
+    (0x186, "\u{254}", "\u{186}", "\u{186}", "\u{254}"),
+    (0x187, "\u{188}", "\u{187}", "\u{187}", "\u{188}"),
+    (0x188, "\u{188}", "\u{187}", "\u{187}", "\u{188}"),
+    (0x189, "\u{256}", "\u{189}", "\u{189}", "\u{256}"),
+    (0x18a, "\u{257}", "\u{18a}", "\u{18a}", "\u{257}"),
+    (0x18b, "\u{18c}", "\u{18b}", "\u{18b}", "\u{18c}"),
+    (0x18c, "\u{18c}", "\u{18b}", "\u{18b}", "\u{18c}"),
+    (0x18e, "\u{1dd}", "\u{18e}", "\u{18e}", "\u{1dd}"),
+    (0x18f, "\u{259}", "\u{18f}", "\u{18f}", "\u{259}"),
+    (0x190, "\u{25b}", "\u{190}", "\u{190}", "\u{25b}"),
+    (0x191, "\u{192}", "\u{191}", "\u{191}", "\u{192}"),
+    (0x192, "\u{192}", "\u{191}", "\u{191}", "\u{192}"),
+    (0x193, "\u{260}", "\u{193}", "\u{193}", "\u{260}"),
+    (0x194, "\u{263}", "\u{194}", "\u{194}", "\u{263}"),
+    (0x195, "\u{195}", "\u{1f6}", "\u{1f6}", "\u{195}"),
+    (0x196, "\u{269}", "\u{196}", "\u{196}", "\u{269}"),
+    (0x197, "\u{268}", "\u{197}", "\u{197}", "\u{268}"),
+    (0x198, "\u{199}", "\u{198}", "\u{198}", "\u{199}"),
+    (0x199, "\u{199}", "\u{198}", "\u{198}", "\u{199}"),
+    (0x19a, "\u{19a}", "\u{23d}", "\u{23d}", "\u{19a}"),
+    (0x19c, "\u{26f}", "\u{19c}", "\u{19c}", "\u{26f}"),
+    (0x19d, "\u{272}", "\u{19d}", "\u{19d}", "\u{272}"),
+    (0x19e, "\u{19e}", "\u{220}", "\u{220}", "\u{19e}"),
+    (0x19f, "\u{275}", "\u{19f}", "\u{19f}", "\u{275}"),
+    (0x1a0, "\u{1a1}", "\u{1a0}", "\u{1a0}", "\u{1a1}"),
+    (0x1a1, "\u{1a1}", "\u{1a0}", "\u{1a0}", "\u{1a1}"),
+    (0x1a2, "\u{1a3}", "\u{1a2}", "\u{1a2}", "\u{1a3}"),
+    (0x1a3, "\u{1a3}", "\u{1a2}", "\u{1a2}", "\u{1a3}"),
+    (0x1a4, "\u{1a5}", "\u{1a4}", "\u{1a4}", "\u{1a5}"),
+    (0x1a5, "\u{1a5}", "\u{1a4}", "\u{1a4}", "\u{1a5}"),
+    (0x1a6, "\u{280}", "\u{1a6}", "\u{1a6}", "\u{280}"),
+    (0x1a7, "\u{1a8}", "\u{1a7}", "\u{1a7}", "\u{1a8}"),
+    (0x1a8, "\u{1a8}", "\u{1a7}", "\u{1a7}", "\u{1a8}"),
+    (0x1a9, "\u{283}", "\u{1a9}", "\u{1a9}", "\u{283}"),
+    (0x1ac, "\u{1ad}", "\u{1ac}", "\u{1ac}", "\u{1ad}"),
+    (0x1ad, "\u{1ad}", "\u{1ac}", "\u{1ac}", "\u{1ad}"),
+    (0x1ae, "\u{288}", "\u{1ae}", "\u{1ae}", "\u{288}"),
+    (0x1af, "\u{1b0}", "\u{1af}", "\u{1af}", "\u{1b0}"),
+    (0x1b0, "\u{1b0}", "\u{1af}", "\u{1af}", "\u{1b0}"),
+    (0x1b1, "\u{28a}", "\u{1b1}", "\u{1b1}", "\u{28a}"),
+    (0x1b2, "\u{28b}", "\u{1b2}", "\u{1b2}", "\u{28b}"),
+    (0x1b3, "\u{1b4}", "\u{1b3}", "\u{1b3}", "\u{1b4}"),
+    (0x1b4, "\u{1b4}", "\u{1b3}", "\u{1b3}", "\u{1b4}"),
+    (0x1b5, "\u{1b6}", "\u{1b5}", "\u{1b5}", "\u{1b6}"),
+    (0x1b6, "\u{1b6}", "\u{1b5}", "\u{1b5}", "\u{1b6}"),
+    (0x1b7, "\u{292}", "\u{1b7}", "\u{1b7}", "\u{292}"),
+    (0x1b8, "\u{1b9}", "\u{1b8}", "\u{1b8}", "\u{1b9}"),
+    (0x1b9, "\u{1b9}", "\u{1b8}", "\u{1b8}", "\u{1b9}"),
+    (0x1bc, "\u{1bd}", "\u{1bc}", "\u{1bc}", "\u{1bd}"),
+    (0x1bd, "\u{1bd}", "\u{1bc}", "\u{1bc}", "\u{1bd}"),
+    (0x1bf, "\u{1bf}", "\u{1f7}", "\u{1f7}", "\u{1bf}"),
+    (0x1c4, "\u{1c6}", "\u{1c4}", "\u{1c5}", "\u{1c6}"),
+    (0x1c5, "\u{1c6}", "\u{1c4}", "\u{1c5}", "\u{1c6}"),
+    (0x1c6, "\u{1c6}", "\u{1c4}", "\u{1c5}", "\u{1c6}"),
+    (0x1c7, "\u{1c9}", "\u{1c7}", "\u{1c8}", "\u{1c9}"),
+    (0x1c8, "\u{1c9}", "\u{1c7}", "\u{1c8}", "\u{1c9}"),
+    (0x1c9, "\u{1c9}", "\u{1c7}", "\u{1c8}", "\u{1c9}"),
+    (0x1ca, "\u{1cc}", "\u{1ca}", "\u{1cb}", "\u{1cc}"),
+    (0x1cb, "\u{1cc}", "\u{1ca}", "\u{1cb}", "\u{1cc}"),
+    (0x1cc, "\u{1cc}", "\u{1ca}", "\u{1cb}", "\u{1cc}"),
+    (0x1cd, "\u{1ce}", "\u{1cd}", "\u{1cd}", "\u{1ce}"),
+    (0x1ce, "\u{1ce}", "\u{1cd}", "\u{1cd}", "\u{1ce}"),
+    (0x1cf, "\u{1d0}", "\u{1cf}", "\u{1cf}", "\u{1d0}"),
+    (0x1d0, "\u{1d0}", "\u{1cf}", "\u{1cf}", "\u{1d0}"),
+    (0x1d1, "\u{1d2}", "\u{1d1}", "\u{1d1}", "\u{1d2}"),
+    (0x1d2, "\u{1d2}", "\u{1d1}", "\u{1d1}", "\u{1d2}"),
+    (0x1d3, "\u{1d4}", "\u{1d3}", "\u{1d3}", "\u{1d4}"),
+    (0x1d4, "\u{1d4}", "\u{1d3}", "\u{1d3}", "\u{1d4}"),
+    (0x1d5, "\u{1d6}", "\u{1d5}", "\u{1d5}", "\u{1d6}"),
+    (0x1d6, "\u{1d6}", "\u{1d5}", "\u{1d5}", "\u{1d6}"),
+    (0x1d7, "\u{1d8}", "\u{1d7}", "\u{1d7}", "\u{1d8}"),
+    (0x1d8, "\u{1d8}", "\u{1d7}", "\u{1d7}", "\u{1d8}"),
+    (0x1d9, "\u{1da}", "\u{1d9}", "\u{1d9}", "\u{1da}"),
+    (0x1da, "\u{1da}", "\u{1d9}", "\u{1d9}", "\u{1da}"),
+    (0x1db, "\u{1dc}", "\u{1db}", "\u{1db}", "\u{1dc}"),
+    (0x1dc, "\u{1dc}", "\u{1db}", "\u{1db}", "\u{1dc}"),
+    (0x1dd, "\u{1dd}", "\u{18e}", "\u{18e}", "\u{1dd}"),
+    (0x1de, "\u{1df}", "\u{1de}", "\u{1de}", "\u{1df}"),
+    (0x1df, "\u{1df}", "\u{1de}", "\u{1de}", "\u{1df}"),
+    (0x1e0, "\u{1e1}", "\u{1e0}", "\u{1e0}", "\u{1e1}"),
+    (0x1e1, "\u{1e1}", "\u{1e0}", "\u{1e0}", "\u{1e1}"),
+    (0x1e2, "\u{1e3}", "\u{1e2}", "\u{1e2}", "\u{1e3}"),
+    (0x1e3, "\u{1e3}", "\u{1e2}", "\u{1e2}", "\u{1e3}"),
+    (0x1e4, "\u{1e5}", "\u{1e4}", "\u{1e4}", "\u{1e5}"),
+    (0x1e5, "\u{1e5}", "\u{1e4}", "\u{1e4}", "\u{1e5}"),
+    (0x1e6, "\u{1e7}", "\u{1e6}", "\u{1e6}", "\u{1e7}"),
+    (0x1e7, "\u{1e7}", "\u{1e6}", "\u{1e6}", "\u{1e7}"),
+    (0x1e8, "\u{1e9}", "\u{1e8}", "\u{1e8}", "\u{1e9}"),
+    (0x1e9, "\u{1e9}", "\u{1e8}", "\u{1e8}", "\u{1e9}"),
+    (0x1ea, "\u{1eb}", "\u{1ea}", "\u{1ea}", "\u{1eb}"),
+    (0x1eb, "\u{1eb}", "\u{1ea}", "\u{1ea}", "\u{1eb}"),
+    (0x1ec, "\u{1ed}", "\u{1ec}", "\u{1ec}", "\u{1ed}"),
+    (0x1ed, "\u{1ed}", "\u{1ec}", "\u{1ec}", "\u{1ed}"),
+    (0x1ee, "\u{1ef}", "\u{1ee}", "\u{1ee}", "\u{1ef}"),
+    (0x1ef, "\u{1ef}", "\u{1ee}", "\u{1ee}", "\u{1ef}"),
+    (0x1f0, "\u{1f0}", "J\u{30c}", "J\u{30c}", "j\u{30c}"),
+    (0x1f1, "\u{1f3}", "\u{1f1}", "\u{1f2}", "\u{1f3}"),
+    (0x1f2, "\u{1f3}", "\u{1f1}", "\u{1f2}", "\u{1f3}"),
+    (0x1f3, "\u{1f3}", "\u{1f1}", "\u{1f2}", "\u{1f3}"),
+    (0x1f4, "\u{1f5}", "\u{1f4}", "\u{1f4}", "\u{1f5}"),
+    (0x1f5, "\u{1f5}", "\u{1f4}", "\u{1f4}", "\u{1f5}"),
+    (0x1f6, "\u{195}", "\u{1f6}", "\u{1f6}", "\u{195}"),
+    (0x1f7, "\u{1bf}", "\u{1f7}", "\u{1f7}", "\u{1bf}"),
+    (0x1f8, "\u{1f9}", "\u{1f8}", "\u{1f8}", "\u{1f9}"),
+    (0x1f9, "\u{1f9}", "\u{1f8}", "\u{1f8}", "\u{1f9}"),
+    (0x1fa, "\u{1fb}", "\u{1fa}", "\u{1fa}", "\u{1fb}"),
+    (0x1fb, "\u{1fb}", "\u{1fa}", "\u{1fa}", "\u{1fb}"),
+    (0x1fc, "\u{1fd}", "\u{1fc}", "\u{1fc}", "\u{1fd}"),
+    (0x1fd, "\u{1fd}", "\u{1fc}", "\u{1fc}", "\u{1fd}"),
+    (0x1fe, "\u{1ff}", "\u{1fe}", "\u{1fe}", "\u{1ff}"),
+    (0x1ff, "\u{1ff}", "\u{1fe}", "\u{1fe}", "\u{1ff}"),
+    (0x200, "\u{201}", "\u{200}", "\u{200}", "\u{201}"),
+    (0x201, "\u{201}", "\u{200}", "\u{200}", "\u{201}"),
+    (0x202, "\u{203}", "\u{202}", "\u{202}", "\u{203}"),
+    (0x203, "\u{203}", "\u{202}", "\u{202}", "\u{203}"),
+    (0x204, "\u{205}", "\u{204}", "\u{204}", "\u{205}"),
+    (0x205, "\u{205}", "\u{204}", "\u{204}", "\u{205}"),
+    (0x206, "\u{207}", "\u{206}", "\u{206}", "\u{207}"),
+    (0x207, "\u{207}", "\u{206}", "\u{206}", "\u{207}"),
+    (0x208, "\u{209}", "\u{208}", "\u{208}", "\u{209}"),
+    (0x209, "\u{209}", "\u{208}", "\u{208}", "\u{209}"),
+    (0x20a, "\u{20b}", "\u{20a}", "\u{20a}", "\u{20b}"),
+    (0x20b, "\u{20b}", "\u{20a}", "\u{20a}", "\u{20b}"),
+    (0x20c, "\u{20d}", "\u{20c}", "\u{20c}", "\u{20d}"),
+    (0x20d, "\u{20d}", "\u{20c}", "\u{20c}", "\u{20d}"),
+    (0x20e, "\u{20f}", "\u{20e}", "\u{20e}", "\u{20f}"),
+    (0x20f, "\u{20f}", "\u{20e}", "\u{20e}", "\u{20f}"),
+    (0x210, "\u{211}", "\u{210}", "\u{210}", "\u{211}"),
+    (0x211, "\u{211}", "\u{210}", "\u{210}", "\u{211}"),
+    (0x212, "\u{213}", "\u{212}", "\u{212}", "\u{213}"),
+    (0x213, "\u{213}", "\u{212}", "\u{212}", "\u{213}"),
+    (0x214, "\u{215}", "\u{214}", "\u{214}", "\u{215}"),
+    (0x215, "\u{215}", "\u{214}", "\u{214}", "\u{215}"),
+    (0x216, "\u{217}", "\u{216}", "\u{216}", "\u{217}"),
+    (0x217, "\u{217}", "\u{216}", "\u{216}", "\u{217}"),
+    (0x218, "\u{219}", "\u{218}", "\u{218}", "\u{219}"),
+    (0x219, "\u{219}", "\u{218}", "\u{218}", "\u{219}"),
+    (0x21a, "\u{21b}", "\u{21a}", "\u{21a}", "\u{21b}"),
+    (0x21b, "\u{21b}", "\u{21a}", "\u{21a}", "\u{21b}"),
+    (0x21c, "\u{21d}", "\u{21c}", "\u{21c}", "\u{21d}"),
+    (0x21d, "\u{21d}", "\u{21c}", "\u{21c}", "\u{21d}"),
+    (0x21e, "\u{21f}", "\u{21e}", "\u{21e}", "\u{21f}"),
+    (0x21f, "\u{21f}", "\u{21e}", "\u{21e}", "\u{21f}"),
+    (0x220, "\u{19e}", "\u{220}", "\u{220}", "\u{19e}"),
+    (0x222, "\u{223}", "\u{222}", "\u{222}", "\u{223}"),
+    (0x223, "\u{223}", "\u{222}", "\u{222}", "\u{223}"),
+    (0x224, "\u{225}", "\u{224}", "\u{224}", "\u{225}"),
+    (0x225, "\u{225}", "\u{224}", "\u{224}", "\u{225}"),
+    (0x226, "\u{227}", "\u{226}", "\u{226}", "\u{227}"),
+    (0x227, "\u{227}", "\u{226}", "\u{226}", "\u{227}"),
+    (0x228, "\u{229}", "\u{228}", "\u{228}", "\u{229}"),
+    (0x229, "\u{229}", "\u{228}", "\u{228}", "\u{229}"),
+    (0x22a, "\u{22b}", "\u{22a}", "\u{22a}", "\u{22b}"),
+    (0x22b, "\u{22b}", "\u{22a}", "\u{22a}", "\u{22b}"),
+    (0x22c, "\u{22d}", "\u{22c}", "\u{22c}", "\u{22d}"),
+    (0x22d, "\u{22d}", "\u{22c}", "\u{22c}", "\u{22d}"),
+    (0x22e, "\u{22f}", "\u{22e}", "\u{22e}", "\u{22f}"),
+    (0x22f, "\u{22f}", "\u{22e}", "\u{22e}", "\u{22f}"),
+    (0x230, "\u{231}", "\u{230}", "\u{230}", "\u{231}"),
+    (0x231, "\u{231}", "\u{230}", "\u{230}", "\u{231}"),
+    (0x232, "\u{233}", "\u{232}", "\u{232}", "\u{233}"),
+    (0x233, "\u{233}", "\u{232}", "\u{232}", "\u{233}"),
+    (0x23a, "\u{2c65}", "\u{23a}", "\u{23a}", "\u{2c65}"),
+    (0x23b, "\u{23c}", "\u{23b}", "\u{23b}", "\u{23c}"),
+    (0x23c, "\u{23c}", "\u{23b}", "\u{23b}", "\u{23c}"),
+    (0x23d, "\u{19a}", "\u{23d}", "\u{23d}", "\u{19a}"),
+    (0x23e, "\u{2c66}", "\u{23e}", "\u{23e}", "\u{2c66}"),
+    (0x23f, "\u{23f}", "\u{2c7e}", "\u{2c7e}", "\u{23f}"),
+    (0x240, "\u{240}", "\u{2c7f}", "\u{2c7f}", "\u{240}"),
+    (0x241, "\u{242}", "\u{241}", "\u{241}", "\u{242}"),
+    (0x242, "\u{242}", "\u{241}", "\u{241}", "\u{242}"),
+    (0x243, "\u{180}", "\u{243}", "\u{243}", "\u{180}"),
+    (0x244, "\u{289}", "\u{244}", "\u{244}", "\u{289}"),
+    (0x245, "\u{28c}", "\u{245}", "\u{245}", "\u{28c}"),
+    (0x246, "\u{247}", "\u{246}", "\u{246}", "\u{247}"),
+    (0x247, "\u{247}", "\u{246}", "\u{246}", "\u{247}"),
+    (0x248, "\u{249}", "\u{248}", "\u{248}", "\u{249}"),
+    (0x249, "\u{249}", "\u{248}", "\u{248}", "\u{249}"),
+    (0x24a, "\u{24b}", "\u{24a}", "\u{24a}", "\u{24b}"),
+    (0x24b, "\u{24b}", "\u{24a}", "\u{24a}", "\u{24b}"),
+    (0x24c, "\u{24d}", "\u{24c}", "\u{24c}", "\u{24d}"),
+    (0x24d, "\u{24d}", "\u{24c}", "\u{24c}", "\u{24d}"),
+    (0x24e, "\u{24f}", "\u{24e}", "\u{24e}", "\u{24f}"),
+    (0x24f, "\u{24f}", "\u{24e}", "\u{24e}", "\u{24f}"),
+    (0x250, "\u{250}", "\u{2c6f}", "\u{2c6f}", "\u{250}"),
+    (0x251, "\u{251}", "\u{2c6d}", "\u{2c6d}", "\u{251}"),
+    (0x252, "\u{252}", "\u{2c70}", "\u{2c70}", "\u{252}"),
+    (0x253, "\u{253}", "\u{181}", "\u{181}", "\u{253}"),
+    (0x254, "\u{254}", "\u{186}", "\u{186}", "\u{254}"),
+    (0x256, "\u{256}", "\u{189}", "\u{189}", "\u{256}"),
+    (0x257, "\u{257}", "\u{18a}", "\u{18a}", "\u{257}"),
+    (0x259, "\u{259}", "\u{18f}", "\u{18f}", "\u{259}"),
+    (0x25b, "\u{25b}", "\u{190}", "\u{190}", "\u{25b}"),
+    (0x25c, "\u{25c}", "\u{a7ab}", "\u{a7ab}", "\u{25c}"),
+    (0x260, "\u{260}", "\u{193}", "\u{193}", "\u{260}"),
+    (0x261, "\u{261}", "\u{a7ac}", "\u{a7ac}", "\u{261}"),
+    (0x263, "\u{263}", "\u{194}", "\u{194}", "\u{263}"),
+    (0x265, "\u{265}", "\u{a78d}", "\u{a78d}", "\u{265}"),
+    (0x266, "\u{266}", "\u{a7aa}", "\u{a7aa}", "\u{266}"),
+    (0x268, "\u{268}", "\u{197}", "\u{197}", "\u{268}"),
+    (0x269, "\u{269}", "\u{196}", "\u{196}", "\u{269}"),
+    (0x26a, "\u{26a}", "\u{a7ae}", "\u{a7ae}", "\u{26a}"),
+    (0x26b, "\u{26b}", "\u{2c62}", "\u{2c62}", "\u{26b}"),
+    (0x26c, "\u{26c}", "\u{a7ad}", "\u{a7ad}", "\u{26c}"),
+    (0x26f, "\u{26f}", "\u{19c}", "\u{19c}", "\u{26f}"),
+    (0x271, "\u{271}", "\u{2c6e}", "\u{2c6e}", "\u{271}"),
+    (0x272, "\u{272}", "\u{19d}", "\u{19d}", "\u{272}"),
+    (0x275, "\u{275}", "\u{19f}", "\u{19f}", "\u{275}"),
+    (0x27d, "\u{27d}", "\u{2c64}", "\u{2c64}", "\u{27d}"),
+    (0x280, "\u{280}", "\u{1a6}", "\u{1a6}", "\u{280}"),
+    (0x282, "\u{282}", "\u{a7c5}", "\u{a7c5}", "\u{282}"),
+    (0x283, "\u{283}", "\u{1a9}", "\u{1a9}", "\u{283}"),
+    (0x287, "\u{287}", "\u{a7b1}", "\u{a7b1}", "\u{287}"),
+    (0x288, "\u{288}", "\u{1ae}", "\u{1ae}", "\u{288}"),
+    (0x289, "\u{289}", "\u{244}", "\u{244}", "\u{289}"),
+    (0x28a, "\u{28a}", "\u{1b1}", "\u{1b1}", "\u{28a}"),
+    (0x28b, "\u{28b}", "\u{1b2}", "\u{1b2}", "\u{28b}"),
+    (0x28c, "\u{28c}", "\u{245}", "\u{245}", "\u{28c}"),
+    (0x292, "\u{292}", "\u{1b7}", "\u{1b7}", "\u{292}"),
+    (0x29d, "\u{29d}", "\u{a7b2}", "\u{a7b2}", "\u{29d}"),
+    (0x29e, "\u{29e}", "\u{a7b0}", "\u{a7b0}", "\u{29e}"),
+    (0x345, "\u{345}", "\u{399}", "\u{399}", "\u{3b9}"),
+    (0x370, "\u{371}", "\u{370}", "\u{370}", "\u{371}"),
+    (0x371, "\u{371}", "\u{370}", "\u{370}", "\u{371}"),
+    (0x372, "\u{373}", "\u{372}", "\u{372}", "\u{373}"),
+    (0x373, "\u{373}", "\u{372}", "\u{372}", "\u{373}"),
+    (0x376, "\u{377}", "\u{376}", "\u{376}", "\u{377}"),
+    (0x377, "\u{377}", "\u{376}", "\u{376}", "\u{377}"),
+    (0x37b, "\u{37b}", "\u{3fd}", "\u{3fd}", "\u{37b}"),
+    (0x37c, "\u{37c}", "\u{3fe}", "\u{3fe}", "\u{37c}"),
+    (0x37d, "\u{37d}", "\u{3ff}", "\u{3ff}", "\u{37d}"),
+    (0x37f, "\u{3f3}", "\u{37f}", "\u{37f}", "\u{3f3}"),
+    (0x386, "\u{3ac}", "\u{386}", "\u{386}", "\u{3ac}"),
+    (0x388, "\u{3ad}", "\u{388}", "\u{388}", "\u{3ad}"),
+    (0x389, "\u{3ae}", "\u{389}", "\u{389}", "\u{3ae}"),
+    (0x38a, "\u{3af}", "\u{38a}", "\u{38a}", "\u{3af}"),
+    (0x38c, "\u{3cc}", "\u{38c}", "\u{38c}", "\u{3cc}"),
+    (0x38e, "\u{3cd}", "\u{38e}", "\u{38e}", "\u{3cd}"),
+    (0x38f, "\u{3ce}", "\u{38f}", "\u{38f}", "\u{3ce}"),
+    (0x390, "\u{390}", "\u{399}\u{308}\u{301}", "\u{399}\u{308}\u{301}", "\u{3b9}\u{308}\u{301}"),
+    (0x391, "\u{3b1}", "\u{391}", "\u{391}", "\u{3b1}"),
+    (0x392, "\u{3b2}", "\u{392}", "\u{392}", "\u{3b2}"),
+    (0x393, "\u{3b3}", "\u{393}", "\u{393}", "\u{3b3}"),
+    (0x394, "\u{3b4}", "\u{394}", "\u{394}", "\u{3b4}"),
+    (0x395, "\u{3b5}", "\u{395}", "\u{395}", "\u{3b5}"),
+    (0x396, "\u{3b6}", "\u{396}", "\u{396}", "\u{3b6}"),
+    (0x397, "\u{3b7}", "\u{397}", "\u{397}", "\u{3b7}"),
+    (0x398, "\u{3b8}", "\u{398}", "\u{398}", "\u{3b8}"),
+    (0x399, "\u{3b9}", "\u{399}", "\u{399}", "\u{3b9}"),
+    (0x39a, "\u{3ba}", "\u{39a}", "\u{39a}", "\u{3ba}"),
+    (0x39b, "\u{3bb}", "\u{39b}", "\u{39b}", "\u{3bb}"),
+    (0x39c, "\u{3bc}", "\u{39c}", "\u{39c}", "\u{3bc}"),
+    (0x39d, "\u{3bd}", "\u{39d}", "\u{39d}", "\u{3bd}"),
+    (0x39e, "\u{3be}", "\u{39e}", "\u{39e}", "\u{3be}"),
+    (0x39f, "\u{3bf}", "\u{39f}", "\u{39f}", "\u{3bf}"),
+    (0x3a0, "\u{3c0}", "\u{3a0}", "\u{3a0}", "\u{3c0}"),
+    (0x3a1, "\u{3c1}", "\u{3a1}", "\u{3a1}", "\u{3c1}"),
+    (0x3a3, "\u{3c3}", "\u{3a3}", "\u{3a3}", "\u{3c3}"),
+    (0x3a4, "\u{3c4}", "\u{3a4}", "\u{3a4}", "\u{3c4}"),
+    (0x3a5, "\u{3c5}", "\u{3a5}", "\u{3a5}", "\u{3c5}"),
+    (0x3a6, "\u{3c6}", "\u{3a6}", "\u{3a6}", "\u{3c6}"),
+    (0x3a7, "\u{3c7}", "\u{3a7}", "\u{3a7}", "\u{3c7}"),
+    (0x3a8, "\u{3c8}", "\u{3a8}", "\u{3a8}", "\u{3c8}"),
+    (0x3a9, "\u{3c9}", "\u{3a9}", "\u{3a9}", "\u{3c9}"),
+    (0x3aa, "\u{3ca}", "\u{3aa}", "\u{3aa}", "\u{3ca}"),
+    (0x3ab, "\u{3cb}", "\u{3ab}", "\u{3ab}", "\u{3cb}"),
+    (0x3ac, "\u{3ac}", "\u{386}", "\u{386}", "\u{3ac}"),
+    (0x3ad, "\u{3ad}", "\u{388}", "\u{388}", "\u{3ad}"),
+    (0x3ae, "\u{3ae}", "\u{389}", "\u{389}", "\u{3ae}"),
+    (0x3af, "\u{3af}", "\u{38a}", "\u{38a}", "\u{3af}"),
+    (0x3b0, "\u{3b0}", "\u{3a5}\u{308}\u{301}", "\u{3a5}\u{308}\u{301}", "\u{3c5}\u{308}\u{301}"),
+    (0x3b1, "\u{3b1}", "\u{391}", "\u{391}", "\u{3b1}"),
+    (0x3b2, "\u{3b2}", "\u{392}", "\u{392}", "\u{3b2}"),
+    (0x3b3, "\u{3b3}", "\u{393}", "\u{393}", "\u{3b3}"),
+    (0x3b4, "\u{3b4}", "\u{394}", "\u{394}", "\u{3b4}"),
+    (0x3b5, "\u{3b5}", "\u{395}", "\u{395}", "\u{3b5}"),
+    (0x3b6, "\u{3b6}", "\u{396}", "\u{396}", "\u{3b6}"),
+    (0x3b7, "\u{3b7}", "\u{397}", "\u{397}", "\u{3b7}"),
+    (0x3b8, "\u{3b8}", "\u{398}", "\u{398}", "\u{3b8}"),
+    (0x3b9, "\u{3b9}", "\u{399}", "\u{399}", "\u{3b9}"),
+    (0x3ba, "\u{3ba}", "\u{39a}", "\u{39a}", "\u{3ba}"),
+    (0x3bb, "\u{3bb}", "\u{39b}", "\u{39b}", "\u{3bb}"),
+    (0x3bc, "\u{3bc}", "\u{39c}", "\u{39c}", "\u{3bc}"),
+    (0x3bd, "\u{3bd}", "\u{39d}", "\u{39d}", "\u{3bd}"),
+    (0x3be, "\u{3be}", "\u{39e}", "\u{39e}", "\u{3be}"),
+    (0x3bf, "\u{3bf}", "\u{39f}", "\u{39f}", "\u{3bf}"),
+    (0x3c0, "\u{3c0}", "\u{3a0}", "\u{3a0}", "\u{3c0}"),
+    (0x3c1, "\u{3c1}", "\u{3a1}", "\u{3a1}", "\u{3c1}"),
+    (0x3c2, "\u{3c2}", "\u{3a3}", "\u{3a3}", "\u{3c3}"),
+    (0x3c3, "\u{3c3}", "\u{3a3}", "\u{3a3}", "\u{3c3}"),
+    (0x3c4, "\u{3c4}", "\u{3a4}", "\u{3a4}", "\u{3c4}"),
+    (0x3c5, "\u{3c5}", "\u{3a5}", "\u{3a5}", "\u{3c5}"),
+    (0x3c6, "\u{3c6}", "\u{3a6}", "\u{3a6}", "\u{3c6}"),
+    (0x3c7, "\u{3c7}", "\u{3a7}", "\u{3a7}", "\u{3c7}"),
+    (0x3c8, "\u{3c8}", "\u{3a8}", "\u{3a8}", "\u{3c8}"),
+    (0x3c9, "\u{3c9}", "\u{3a9}", "\u{3a9}", "\u{3c9}"),
+    (0x3ca, "\u{3ca}", "\u{3aa}", "\u{3aa}", "\u{3ca}"),
+    (0x3cb, "\u{3cb}", "\u{3ab}", "\u{3ab}", "\u{3cb}"),
+    (0x3cc, "\u{3cc}", "\u{38c}", "\u{38c}", "\u{3cc}"),
+    (0x3cd, "\u{3cd}", "\u{38e}", "\u{38e}", "\u{3cd}"),
+    (0x3ce, "\u{3ce}", "\u{38f}", "\u{38f}", "\u{3ce}"),
+    (0x3cf, "\u{3d7}", "\u{3cf}", "\u{3cf}", "\u{3d7}"),
+    (0x3d0, "\u{3d0}", "\u{392}", "\u{392}", "\u{3b2}"),
+    (0x3d1, "\u{3d1}", "\u{398}", "\u{398}", "\u{3b8}"),
+    (0x3d5, "\u{3d5}", "\u{3a6}", "\u{3a6}", "\u{3c6}"),
+    (0x3d6, "\u{3d6}", "\u{3a0}", "\u{3a0}", "\u{3c0}"),
+    (0x3d7, "\u{3d7}", "\u{3cf}", "\u{3cf}", "\u{3d7}"),
+    (0x3d8, "\u{3d9}", "\u{3d8}", "\u{3d8}", "\u{3d9}"),
+    (0x3d9, "\u{3d9}", "\u{3d8}", "\u{3d8}", "\u{3d9}"),
+    (0x3da, "\u{3db}", "\u{3da}", "\u{3da}", "\u{3db}"),
+    (0x3db, "\u{3db}", "\u{3da}", "\u{3da}", "\u{3db}"),
+    (0x3dc, "\u{3dd}", "\u{3dc}", "\u{3dc}", "\u{3dd}"),
+    (0x3dd, "\u{3dd}", "\u{3dc}", "\u{3dc}", "\u{3dd}"),
+    (0x3de, "\u{3df}", "\u{3de}", "\u{3de}", "\u{3df}"),
+    (0x3df, "\u{3df}", "\u{3de}", "\u{3de}", "\u{3df}"),
+    (0x3e0, "\u{3e1}", "\u{3e0}", "\u{3e0}", "\u{3e1}"),
+    (0x3e1, "\u{3e1}", "\u{3e0}", "\u{3e0}", "\u{3e1}"),
+    (0x3e2, "\u{3e3}", "\u{3e2}", "\u{3e2}", "\u{3e3}"),
+    (0x3e3, "\u{3e3}", "\u{3e2}", "\u{3e2}", "\u{3e3}"),
+    (0x3e4, "\u{3e5}", "\u{3e4}", "\u{3e4}", "\u{3e5}"),
+    (0x3e5, "\u{3e5}", "\u{3e4}", "\u{3e4}", "\u{3e5}"),
+    (0x3e6, "\u{3e7}", "\u{3e6}", "\u{3e6}", "\u{3e7}"),
+    (0x3e7, "\u{3e7}", "\u{3e6}", "\u{3e6}", "\u{3e7}"),
+    (0x3e8, "\u{3e9}", "\u{3e8}", "\u{3e8}", "\u{3e9}"),
+    (0x3e9, "\u{3e9}", "\u{3e8}", "\u{3e8}", "\u{3e9}"),
+    (0x3ea, "\u{3eb}", "\u{3ea}", "\u{3ea}", "\u{3eb}"),
+    (0x3eb, "\u{3eb}", "\u{3ea}", "\u{3ea}", "\u{3eb}"),
+    (0x3ec, "\u{3ed}", "\u{3ec}", "\u{3ec}", "\u{3ed}"),
+    (0x3ed, "\u{3ed}", "\u{3ec}", "\u{3ec}", "\u{3ed}"),
+    (0x3ee, "\u{3ef}", "\u{3ee}", "\u{3ee}", "\u{3ef}"),
+    (0x3ef, "\u{3ef}", "\u{3ee}", "\u{3ee}", "\u{3ef}"),
+    (0x3f0, "\u{3f0}", "\u{39a}", "\u{39a}", "\u{3ba}"),
+    (0x3f1, "\u{3f1}", "\u{3a1}", "\u{3a1}", "\u{3c1}"),
+    (0x3f2, "\u{3f2}", "\u{3f9}", "\u{3f9}", "\u{3f2}"),
+    (0x3f3, "\u{3f3}", "\u{37f}", "\u{37f}", "\u{3f3}"),
+    (0x3f4, "\u{3b8}", "\u{3f4}", "\u{3f4}", "\u{3b8}"),
+    (0x3f5, "\u{3f5}", "\u{395}", "\u{395}", "\u{3b5}"),
+    (0x3f7, "\u{3f8}", "\u{3f7}", "\u{3f7}", "\u{3f8}"),
+    (0x3f8, "\u{3f8}", "\u{3f7}", "\u{3f7}", "\u{3f8}"),
+    (0x3f9, "\u{3f2}", "\u{3f9}", "\u{3f9}", "\u{3f2}"),
+    (0x3fa, "\u{3fb}", "\u{3fa}", "\u{3fa}", "\u{3fb}"),
+    (0x3fb, "\u{3fb}", "\u{3fa}", "\u{3fa}", "\u{3fb}"),
+    (0x3fd, "\u{37b}", "\u{3fd}", "\u{3fd}", "\u{37b}"),
+    (0x3fe, "\u{37c}", "\u{3fe}", "\u{3fe}", "\u{37c}"),
+    (0x3ff, "\u{37d}", "\u{3ff}", "\u{3ff}", "\u{37d}"),
+    (0x400, "\u{450}", "\u{400}", "\u{400}", "\u{450}"),
+    (0x401, "\u{451}", "\u{401}", "\u{401}", "\u{451}"),
+    (0x402, "\u{452}", "\u{402}", "\u{402}", "\u{452}"),
+    (0x403, "\u{453}", "\u{403}", "\u{403}", "\u{453}"),
+    (0x404, "\u{454}", "\u{404}", "\u{404}", "\u{454}"),
+    (0x405, "\u{455}", "\u{405}", "\u{405}", "\u{455}"),
+    (0x406, "\u{456}", "\u{406}", "\u{406}", "\u{456}"),
+    (0x407, "\u{457}", "\u{407}", "\u{407}", "\u{457}"),
+    (0x408, "\u{458}", "\u{408}", "\u{408}", "\u{458}"),
+    (0x409, "\u{459}", "\u{409}", "\u{409}", "\u{459}"),
+    (0x40a, "\u{45a}", "\u{40a}", "\u{40a}", "\u{45a}"),
+    (0x40b, "\u{45b}", "\u{40b}", "\u{40b}", "\u{45b}"),
+    (0x40c, "\u{45c}", "\u{40c}", "\u{40c}", "\u{45c}"),
+    (0x40d, "\u{45d}", "\u{40d}", "\u{40d}", "\u{45d}"),
+    (0x40e, "\u{45e}", "\u{40e}", "\u{40e}", "\u{45e}"),
+    (0x40f, "\u{45f}", "\u{40f}", "\u{40f}", "\u{45f}"),
+    (0x410, "\u{430}", "\u{410}", "\u{410}", "\u{430}"),
+    (0x411, "\u{431}", "\u{411}", "\u{411}", "\u{431}"),
+    (0x412, "\u{432}", "\u{412}", "\u{412}", "\u{432}"),
+    (0x413, "\u{433}", "\u{413}", "\u{413}", "\u{433}"),
+    (0x414, "\u{434}", "\u{414}", "\u{414}", "\u{434}"),
+    (0x415, "\u{435}", "\u{415}", "\u{415}", "\u{435}"),
+    (0x416, "\u{436}", "\u{416}", "\u{416}", "\u{436}"),
+    (0x417, "\u{437}", "\u{417}", "\u{417}", "\u{437}"),
+    (0x418, "\u{438}", "\u{418}", "\u{418}", "\u{438}"),
+    (0x419, "\u{439}", "\u{419}", "\u{419}", "\u{439}"),
+    (0x41a, "\u{43a}", "\u{41a}", "\u{41a}", "\u{43a}"),
+    (0x41b, "\u{43b}", "\u{41b}", "\u{41b}", "\u{43b}"),
+    (0x41c, "\u{43c}", "\u{41c}", "\u{41c}", "\u{43c}"),
+    (0x41d, "\u{43d}", "\u{41d}", "\u{41d}", "\u{43d}"),
+    (0x41e, "\u{43e}", "\u{41e}", "\u{41e}", "\u{43e}"),
+    (0x41f, "\u{43f}", "\u{41f}", "\u{41f}", "\u{43f}"),
+    (0x420, "\u{440}", "\u{420}", "\u{420}", "\u{440}"),
+    (0x421, "\u{441}", "\u{421}", "\u{421}", "\u{441}"),
+    (0x422, "\u{442}", "\u{422}", "\u{422}", "\u{442}"),
+    (0x423, "\u{443}", "\u{423}", "\u{423}", "\u{443}"),
+    (0x424, "\u{444}", "\u{424}", "\u{424}", "\u{444}"),
+    (0x425, "\u{445}", "\u{425}", "\u{425}", "\u{445}"),
+    (0x426, "\u{446}", "\u{426}", "\u{426}", "\u{446}"),
+    (0x427, "\u{447}", "\u{427}", "\u{427}", "\u{447}"),
+    (0x428, "\u{448}", "\u{428}", "\u{428}", "\u{448}"),
+    (0x429, "\u{449}", "\u{429}", "\u{429}", "\u{449}"),
+    (0x42a, "\u{44a}", "\u{42a}", "\u{42a}", "\u{44a}"),
+    (0x42b, "\u{44b}", "\u{42b}", "\u{42b}", "\u{44b}"),
+    (0x42c, "\u{44c}", "\u{42c}", "\u{42c}", "\u{44c}"),
+    (0x42d, "\u{44d}", "\u{42d}", "\u{42d}", "\u{44d}"),
+    (0x42e, "\u{44e}", "\u{42e}", "\u{42e}", "\u{44e}"),
+    (0x42f, "\u{44f}", "\u{42f}", "\u{42f}", "\u{44f}"),
+    (0x430, "\u{430}", "\u{410}", "\u{410}", "\u{430}"),
+    (0x431, "\u{431}", "\u{411}", "\u{411}", "\u{431}"),
+    (0x432, "\u{432}", "\u{412}", "\u{412}", "\u{432}"),
+    (0x433, "\u{433}", "\u{413}", "\u{413}", "\u{433}"),
+    (0x434, "\u{434}", "\u{414}", "\u{414}", "\u{434}"),
+    (0x435, "\u{435}", "\u{415}", "\u{415}", "\u{435}"),
+    (0x436, "\u{436}", "\u{416}", "\u{416}", "\u{436}"),
+    (0x437, "\u{437}", "\u{417}", "\u{417}", "\u{437}"),
+    (0x438, "\u{438}", "\u{418}", "\u{418}", "\u{438}"),
+    (0x439, "\u{439}", "\u{419}", "\u{419}", "\u{439}"),
+    (0x43a, "\u{43a}", "\u{41a}", "\u{41a}", "\u{43a}"),
+    (0x43b, "\u{43b}", "\u{41b}", "\u{41b}", "\u{43b}"),
+    (0x43c, "\u{43c}", "\u{41c}", "\u{41c}", "\u{43c}"),
+    (0x43d, "\u{43d}", "\u{41d}", "\u{41d}", "\u{43d}"),
+    (0x43e, "\u{43e}", "\u{41e}", "\u{41e}", "\u{43e}"),
+    (0x43f, "\u{43f}", "\u{41f}", "\u{41f}", "\u{43f}"),
+    (0x440, "\u{440}", "\u{420}", "\u{420}", "\u{440}"),
+    (0x441, "\u{441}", "\u{421}", "\u{421}", "\u{441}"),
+    (0x442, "\u{442}", "\u{422}", "\u{422}", "\u{442}"),
+    (0x443, "\u{443}", "\u{423}", "\u{423}", "\u{443}"),
+    (0x444, "\u{444}", "\u{424}", "\u{424}", "\u{444}"),
+    (0x445, "\u{445}", "\u{425}", "\u{425}", "\u{445}"),
+    (0x446, "\u{446}", "\u{426}", "\u{426}", "\u{446}"),
+    (0x447, "\u{447}", "\u{427}", "\u{427}", "\u{447}"),
+    (0x448, "\u{448}", "\u{428}", "\u{428}", "\u{448}"),
+    (0x449, "\u{449}", "\u{429}", "\u{429}", "\u{449}"),
+    (0x44a, "\u{44a}", "\u{42a}", "\u{42a}", "\u{44a}"),
+    (0x44b, "\u{44b}", "\u{42b}", "\u{42b}", "\u{44b}"),
+    (0x44c, "\u{44c}", "\u{42c}", "\u{42c}", "\u{44c}"),
+    (0x44d, "\u{44d}", "\u{42d}", "\u{42d}", "\u{44d}"),
+    (0x44e, "\u{44e}", "\u{42e}", "\u{42e}", "\u{44e}"),
+    (0x44f, "\u{44f}", "\u{42f}", "\u{42f}", "\u{44f}"),
+    (0x450, "\u{450}", "\u{400}", "\u{400}", "\u{450}"),
+    (0x451, "\u{451}", "\u{401}", "\u{401}", "\u{451}"),
+    (0x452, "\u{452}", "\u{402}", "\u{402}", "\u{452}"),
+    (0x453, "\u{453}", "\u{403}", "\u{403}", "\u{453}"),
+    (0x454, "\u{454}", "\u{404}", "\u{404}", "\u{454}"),
+    (0x455, "\u{455}", "\u{405}", "\u{405}", "\u{455}"),
+    (0x456, "\u{456}", "\u{406}", "\u{406}", "\u{456}"),
+    (0x457, "\u{457}", "\u{407}", "\u{407}", "\u{457}"),
+    (0x458, "\u{458}", "\u{408}", "\u{408}", "\u{458}"),
+    (0x459, "\u{459}", "\u{409}", "\u{409}", "\u{459}"),
+    (0x45a, "\u{45a}", "\u{40a}", "\u{40a}", "\u{45a}"),
+    (0x45b, "\u{45b}", "\u{40b}", "\u{40b}", "\u{45b}"),
+    (0x45c, "\u{45c}", "\u{40c}", "\u{40c}", "\u{45c}"),
+    (0x45d, "\u{45d}", "\u{40d}", "\u{40d}", "\u{45d}"),
+    (0x45e, "\u{45e}", "\u{40e}", "\u{40e}", "\u{45e}"),
+    (0x45f, "\u{45f}", "\u{40f}", "\u{40f}", "\u{45f}"),
+    (0x460, "\u{461}", "\u{460}", "\u{460}", "\u{461}"),
+    (0x461, "\u{461}", "\u{460}", "\u{460}", "\u{461}"),
+    (0x462, "\u{463}", "\u{462}", "\u{462}", "\u{463}"),
+    (0x463, "\u{463}", "\u{462}", "\u{462}", "\u{463}"),
+    (0x464, "\u{465}", "\u{464}", "\u{464}", "\u{465}"),
+    (0x465, "\u{465}", "\u{464}", "\u{464}", "\u{465}"),
+    (0x466, "\u{467}", "\u{466}", "\u{466}", "\u{467}"),
+    (0x467, "\u{467}", "\u{466}", "\u{466}", "\u{467}"),
+    (0x468, "\u{469}", "\u{468}", "\u{468}", "\u{469}"),
+    (0x469, "\u{469}", "\u{468}", "\u{468}", "\u{469}"),
+    (0x46a, "\u{46b}", "\u{46a}", "\u{46a}", "\u{46b}"),
+    (0x46b, "\u{46b}", "\u{46a}", "\u{46a}", "\u{46b}"),
+    (0x46c, "\u{46d}", "\u{46c}", "\u{46c}", "\u{46d}"),
+    (0x46d, "\u{46d}", "\u{46c}", "\u{46c}", "\u{46d}"),
+    (0x46e, "\u{46f}", "\u{46e}", "\u{46e}", "\u{46f}"),
+    (0x46f, "\u{46f}", "\u{46e}", "\u{46e}", "\u{46f}"),
+    (0x470, "\u{471}", "\u{470}", "\u{470}", "\u{471}"),
+    (0x471, "\u{471}", "\u{470}", "\u{470}", "\u{471}"),
+    (0x472, "\u{473}", "\u{472}", "\u{472}", "\u{473}"),
+    (0x473, "\u{473}", "\u{472}", "\u{472}", "\u{473}"),
+    (0x474, "\u{475}", "\u{474}", "\u{474}", "\u{475}"),
+    (0x475, "\u{475}", "\u{474}", "\u{474}", "\u{475}"),
+    (0x476, "\u{477}", "\u{476}", "\u{476}", "\u{477}"),
+    (0x477, "\u{477}", "\u{476}", "\u{476}", "\u{477}"),
+    (0x478, "\u{479}", "\u{478}", "\u{478}", "\u{479}"),
+    (0x479, "\u{479}", "\u{478}", "\u{478}", "\u{479}"),
+    (0x47a, "\u{47b}", "\u{47a}", "\u{47a}", "\u{47b}"),
+    (0x47b, "\u{47b}", "\u{47a}", "\u{47a}", "\u{47b}"),
+    (0x47c, "\u{47d}", "\u{47c}", "\u{47c}", "\u{47d}"),
+    (0x47d, "\u{47d}", "\u{47c}", "\u{47c}", "\u{47d}"),
+    (0x47e, "\u{47f}", "\u{47e}", "\u{47e}", "\u{47f}"),
+    (0x47f, "\u{47f}", "\u{47e}", "\u{47e}", "\u{47f}"),
+    (0x480, "\u{481}", "\u{480}", "\u{480}", "\u{481}"),
+    (0x481, "\u{481}", "\u{480}", "\u{480}", "\u{481}"),
+    (0x48a, "\u{48b}", "\u{48a}", "\u{48a}", "\u{48b}"),
+    (0x48b, "\u{48b}", "\u{48a}", "\u{48a}", "\u{48b}"),
+    (0x48c, "\u{48d}", "\u{48c}", "\u{48c}", "\u{48d}"),
+    (0x48d, "\u{48d}", "\u{48c}", "\u{48c}", "\u{48d}"),
+    (0x48e, "\u{48f}", "\u{48e}", "\u{48e}", "\u{48f}"),
+    (0x48f, "\u{48f}", "\u{48e}", "\u{48e}", "\u{48f}"),
+    (0x490, "\u{491}", "\u{490}", "\u{490}", "\u{491}"),
+    (0x491, "\u{491}", "\u{490}", "\u{490}", "\u{491}"),
+    (0x492, "\u{493}", "\u{492}", "\u{492}", "\u{493}"),
+    (0x493, "\u{493}", "\u{492}", "\u{492}", "\u{493}"),
+    (0x494, "\u{495}", "\u{494}", "\u{494}", "\u{495}"),
+    (0x495, "\u{495}", "\u{494}", "\u{494}", "\u{495}"),
+    (0x496, "\u{497}", "\u{496}", "\u{496}", "\u{497}"),
+    (0x497, "\u{497}", "\u{496}", "\u{496}", "\u{497}"),
+    (0x498, "\u{499}", "\u{498}", "\u{498}", "\u{499}"),
+    (0x499, "\u{499}", "\u{498}", "\u{498}", "\u{499}"),
+    (0x49a, "\u{49b}", "\u{49a}", "\u{49a}", "\u{49b}"),
+    (0x49b, "\u{49b}", "\u{49a}", "\u{49a}", "\u{49b}"),
+    (0x49c, "\u{49d}", "\u{49c}", "\u{49c}", "\u{49d}"),
+    (0x49d, "\u{49d}", "\u{49c}", "\u{49c}", "\u{49d}"),
+    (0x49e, "\u{49f}", "\u{49e}", "\u{49e}", "\u{49f}"),
+    (0x49f, "\u{49f}", "\u{49e}", "\u{49e}", "\u{49f}"),
+    (0x4a0, "\u{4a1}", "\u{4a0}", "\u{4a0}", "\u{4a1}"),
+    (0x4a1, "\u{4a1}", "\u{4a0}", "\u{4a0}", "\u{4a1}"),
+    (0x4a2, "\u{4a3}", "\u{4a2}", "\u{4a2}", "\u{4a3}"),
+    (0x4a3, "\u{4a3}", "\u{4a2}", "\u{4a2}", "\u{4a3}"),
+    (0x4a4, "\u{4a5}", "\u{4a4}", "\u{4a4}", "\u{4a5}"),
+    (0x4a5, "\u{4a5}", "\u{4a4}", "\u{4a4}", "\u{4a5}"),
+    (0x4a6, "\u{4a7}", "\u{4a6}", "\u{4a6}", "\u{4a7}"),
+    (0x4a7, "\u{4a7}", "\u{4a6}", "\u{4a6}", "\u{4a7}"),
+    (0x4a8, "\u{4a9}", "\u{4a8}", "\u{4a8}", "\u{4a9}"),
+    (0x4a9, "\u{4a9}", "\u{4a8}", "\u{4a8}", "\u{4a9}"),
+    (0x4aa, "\u{4ab}", "\u{4aa}", "\u{4aa}", "\u{4ab}"),
+    (0x4ab, "\u{4ab}", "\u{4aa}", "\u{4aa}", "\u{4ab}"),
+    (0x4ac, "\u{4ad}", "\u{4ac}", "\u{4ac}", "\u{4ad}"),
+    (0x4ad, "\u{4ad}", "\u{4ac}", "\u{4ac}", "\u{4ad}"),
+    (0x4ae, "\u{4af}", "\u{4ae}", "\u{4ae}", "\u{4af}"),
+    (0x4af, "\u{4af}", "\u{4ae}", "\u{4ae}", "\u{4af}"),
+    (0x4b0, "\u{4b1}", "\u{4b0}", "\u{4b0}", "\u{4b1}"),
+    (0x4b1, "\u{4b1}", "\u{4b0}", "\u{4b0}", "\u{4b1}"),
+    (0x4b2, "\u{4b3}", "\u{4b2}", "\u{4b2}", "\u{4b3}"),
+    (0x4b3, "\u{4b3}", "\u{4b2}", "\u{4b2}", "\u{4b3}"),
+    (0x4b4, "\u{4b5}", "\u{4b4}", "\u{4b4}", "\u{4b5}"),
+    (0x4b5, "\u{4b5}", "\u{4b4}", "\u{4b4}", "\u{4b5}"),
+    (0x4b6, "\u{4b7}", "\u{4b6}", "\u{4b6}", "\u{4b7}"),
+    (0x4b7, "\u{4b7}", "\u{4b6}", "\u{4b6}", "\u{4b7}"),
+    (0x4b8, "\u{4b9}", "\u{4b8}", "\u{4b8}", "\u{4b9}"),
+    (0x4b9, "\u{4b9}", "\u{4b8}", "\u{4b8}", "\u{4b9}"),
+    (0x4ba, "\u{4bb}", "\u{4ba}", "\u{4ba}", "\u{4bb}"),
+    (0x4bb, "\u{4bb}", "\u{4ba}", "\u{4ba}", "\u{4bb}"),
+    (0x4bc, "\u{4bd}", "\u{4bc}", "\u{4bc}", "\u{4bd}"),
+    (0x4bd, "\u{4bd}", "\u{4bc}", "\u{4bc}", "\u{4bd}"),
+    (0x4be, "\u{4bf}", "\u{4be}", "\u{4be}", "\u{4bf}"),
+    (0x4bf, "\u{4bf}", "\u{4be}", "\u{4be}", "\u{4bf}"),
+    (0x4c0, "\u{4cf}", "\u{4c0}", "\u{4c0}", "\u{4cf}"),
+    (0x4c1, "\u{4c2}", "\u{4c1}", "\u{4c1}", "\u{4c2}"),
+    (0x4c2, "\u{4c2}", "\u{4c1}", "\u{4c1}", "\u{4c2}"),
+    (0x4c3, "\u{4c4}", "\u{4c3}", "\u{4c3}", "\u{4c4}"),
+    (0x4c4, "\u{4c4}", "\u{4c3}", "\u{4c3}", "\u{4c4}"),
+    (0x4c5, "\u{4c6}", "\u{4c5}", "\u{4c5}", "\u{4c6}"),
+    (0x4c6, "\u{4c6}", "\u{4c5}", "\u{4c5}", "\u{4c6}"),
+    (0x4c7, "\u{4c8}", "\u{4c7}", "\u{4c7}", "\u{4c8}"),
+    (0x4c8, "\u{4c8}", "\u{4c7}", "\u{4c7}", "\u{4c8}"),
+    (0x4c9, "\u{4ca}", "\u{4c9}", "\u{4c9}", "\u{4ca}"),
+    (0x4ca, "\u{4ca}", "\u{4c9}", "\u{4c9}", "\u{4ca}"),
+    (0x4cb, "\u{4cc}", "\u{4cb}", "\u{4cb}", "\u{4cc}"),
+    (0x4cc, "\u{4cc}", "\u{4cb}", "\u{4cb}", "\u{4cc}"),
+    (0x4cd, "\u{4ce}", "\u{4cd}", "\u{4cd}", "\u{4ce}"),
+    (0x4ce, "\u{4ce}", "\u{4cd}", "\u{4cd}", "\u{4ce}"),
+    (0x4cf, "\u{4cf}", "\u{4c0}", "\u{4c0}", "\u{4cf}"),
+    (0x4d0, "\u{4d1}", "\u{4d0}", "\u{4d0}", "\u{4d1}"),
+    (0x4d1, "\u{4d1}", "\u{4d0}", "\u{4d0}", "\u{4d1}"),
+    (0x4d2, "\u{4d3}", "\u{4d2}", "\u{4d2}", "\u{4d3}"),
+    (0x4d3, "\u{4d3}", "\u{4d2}", "\u{4d2}", "\u{4d3}"),
+    (0x4d4, "\u{4d5}", "\u{4d4}", "\u{4d4}", "\u{4d5}"),
+    (0x4d5, "\u{4d5}", "\u{4d4}", "\u{4d4}", "\u{4d5}"),
+    (0x4d6, "\u{4d7}", "\u{4d6}", "\u{4d6}", "\u{4d7}"),
+    (0x4d7, "\u{4d7}", "\u{4d6}", "\u{4d6}", "\u{4d7}"),
+    (0x4d8, "\u{4d9}", "\u{4d8}", "\u{4d8}", "\u{4d9}"),
+    (0x4d9, "\u{4d9}", "\u{4d8}", "\u{4d8}", "\u{4d9}"),
+    (0x4da, "\u{4db}", "\u{4da}", "\u{4da}", "\u{4db}"),
+    (0x4db, "\u{4db}", "\u{4da}", "\u{4da}", "\u{4db}"),
+    (0x4dc, "\u{4dd}", "\u{4dc}", "\u{4dc}", "\u{4dd}"),
+    (0x4dd, "\u{4dd}", "\u{4dc}", "\u{4dc}", "\u{4dd}"),
+    (0x4de, "\u{4df}", "\u{4de}", "\u{4de}", "\u{4df}"),
+    (0x4df, "\u{4df}", "\u{4de}", "\u{4de}", "\u{4df}"),
+    (0x4e0, "\u{4e1}", "\u{4e0}", "\u{4e0}", "\u{4e1}"),
+    (0x4e1, "\u{4e1}", "\u{4e0}", "\u{4e0}", "\u{4e1}"),
+    (0x4e2, "\u{4e3}", "\u{4e2}", "\u{4e2}", "\u{4e3}"),
+    (0x4e3, "\u{4e3}", "\u{4e2}", "\u{4e2}", "\u{4e3}"),
+    (0x4e4, "\u{4e5}", "\u{4e4}", "\u{4e4}", "\u{4e5}"),
+    (0x4e5, "\u{4e5}", "\u{4e4}", "\u{4e4}", "\u{4e5}"),
+    (0x4e6, "\u{4e7}", "\u{4e6}", "\u{4e6}", "\u{4e7}"),
+    (0x4e7, "\u{4e7}", "\u{4e6}", "\u{4e6}", "\u{4e7}"),
+    (0x4e8, "\u{4e9}", "\u{4e8}", "\u{4e8}", "\u{4e9}"),
+    (0x4e9, "\u{4e9}", "\u{4e8}", "\u{4e8}", "\u{4e9}"),
+    (0x4ea, "\u{4eb}", "\u{4ea}", "\u{4ea}", "\u{4eb}"),
+    (0x4eb, "\u{4eb}", "\u{4ea}", "\u{4ea}", "\u{4eb}"),
+    (0x4ec, "\u{4ed}", "\u{4ec}", "\u{4ec}", "\u{4ed}"),
+    (0x4ed, "\u{4ed}", "\u{4ec}", "\u{4ec}", "\u{4ed}"),
+    (0x4ee, "\u{4ef}", "\u{4ee}", "\u{4ee}", "\u{4ef}"),
+    (0x4ef, "\u{4ef}", "\u{4ee}", "\u{4ee}", "\u{4ef}"),
+    (0x4f0, "\u{4f1}", "\u{4f0}", "\u{4f0}", "\u{4f1}"),
+    (0x4f1, "\u{4f1}", "\u{4f0}", "\u{4f0}", "\u{4f1}"),
+    (0x4f2, "\u{4f3}", "\u{4f2}", "\u{4f2}", "\u{4f3}"),
+    (0x4f3, "\u{4f3}", "\u{4f2}", "\u{4f2}", "\u{4f3}"),
+    (0x4f4, "\u{4f5}", "\u{4f4}", "\u{4f4}", "\u{4f5}"),
+    (0x4f5, "\u{4f5}", "\u{4f4}", "\u{4f4}", "\u{4f5}"),
+    (0x4f6, "\u{4f7}", "\u{4f6}", "\u{4f6}", "\u{4f7}"),
+    (0x4f7, "\u{4f7}", "\u{4f6}", "\u{4f6}", "\u{4f7}"),
+    (0x4f8, "\u{4f9}", "\u{4f8}", "\u{4f8}", "\u{4f9}"),
+    (0x4f9, "\u{4f9}", "\u{4f8}", "\u{4f8}", "\u{4f9}"),
+    (0x4fa, "\u{4fb}", "\u{4fa}", "\u{4fa}", "\u{4fb}"),
+    (0x4fb, "\u{4fb}", "\u{4fa}", "\u{4fa}", "\u{4fb}"),
+    (0x4fc, "\u{4fd}", "\u{4fc}", "\u{4fc}", "\u{4fd}"),
+    (0x4fd, "\u{4fd}", "\u{4fc}", "\u{4fc}", "\u{4fd}"),
+    (0x4fe, "\u{4ff}", "\u{4fe}", "\u{4fe}", "\u{4ff}"),
+    (0x4ff, "\u{4ff}", "\u{4fe}", "\u{4fe}", "\u{4ff}"),
+    (0x500, "\u{501}", "\u{500}", "\u{500}", "\u{501}"),
+    (0x501, "\u{501}", "\u{500}", "\u{500}", "\u{501}"),
+    (0x502, "\u{503}", "\u{502}", "\u{502}", "\u{503}"),
+    (0x503, "\u{503}", "\u{502}", "\u{502}", "\u{503}"),
+    (0x504, "\u{505}", "\u{504}", "\u{504}", "\u{505}"),
+    (0x505, "\u{505}", "\u{504}", "\u{504}", "\u{505}"),
+    (0x506, "\u{507}", "\u{506}", "\u{506}", "\u{507}"),
+    (0x507, "\u{507}", "\u{506}", "\u{506}", "\u{507}"),
+    (0x508, "\u{509}", "\u{508}", "\u{508}", "\u{509}"),
+    (0x509, "\u{509}", "\u{508}", "\u{508}", "\u{509}"),
+    (0x50a, "\u{50b}", "\u{50a}", "\u{50a}", "\u{50b}"),
+    (0x50b, "\u{50b}", "\u{50a}", "\u{50a}", "\u{50b}"),
+    (0x50c, "\u{50d}", "\u{50c}", "\u{50c}", "\u{50d}"),
+    (0x50d, "\u{50d}", "\u{50c}", "\u{50c}", "\u{50d}"),
+    (0x50e, "\u{50f}", "\u{50e}", "\u{50e}", "\u{50f}"),
+    (0x50f, "\u{50f}", "\u{50e}", "\u{50e}", "\u{50f}"),
+    (0x510, "\u{511}", "\u{510}", "\u{510}", "\u{511}"),
+    (0x511, "\u{511}", "\u{510}", "\u{510}", "\u{511}"),
+    (0x512, "\u{513}", "\u{512}", "\u{512}", "\u{513}"),
+    (0x513, "\u{513}", "\u{512}", "\u{512}", "\u{513}"),
+    (0x514, "\u{515}", "\u{514}", "\u{514}", "\u{515}"),
+    (0x515, "\u{515}", "\u{514}", "\u{514}", "\u{515}"),
+    (0x516, "\u{517}", "\u{516}", "\u{516}", "\u{517}"),
+    (0x517, "\u{517}", "\u{516}", "\u{516}", "\u{517}"),
+    (0x518, "\u{519}", "\u{518}", "\u{518}", "\u{519}"),
+    (0x519, "\u{519}", "\u{518}", "\u{518}", "\u{519}"),
+    (0x51a, "\u{51b}", "\u{51a}", "\u{51a}", "\u{51b}"),
+    (0x51b, "\u{51b}", "\u{51a}", "\u{51a}", "\u{51b}"),
+    (0x51c, "\u{51d}", "\u{51c}", "\u{51c}", "\u{51d}"),
+    (0x51d, "\u{51d}", "\u{51c}", "\u{51c}", "\u{51d}"),
+    (0x51e, "\u{51f}", "\u{51e}", "\u{51e}", "\u{51f}"),
+    (0x51f, "\u{51f}", "\u{51e}", "\u{51e}", "\u{51f}"),
+    (0x520, "\u{521}", "\u{520}", "\u{520}", "\u{521}"),
+    (0x521, "\u{521}", "\u{520}", "\u{520}", "\u{521}"),
+    (0x522, "\u{523}", "\u{522}", "\u{522}", "\u{523}"),
+    (0x523, "\u{523}", "\u{522}", "\u{522}", "\u{523}"),
+    (0x524, "\u{525}", "\u{524}", "\u{524}", "\u{525}"),
+    (0x525, "\u{525}", "\u{524}", "\u{524}", "\u{525}"),
+    (0x526, "\u{527}", "\u{526}", "\u{526}", "\u{527}"),
+    (0x527, "\u{527}", "\u{526}", "\u{526}", "\u{527}"),
+    (0x528, "\u{529}", "\u{528}", "\u{528}", "\u{529}"),
+    (0x529, "\u{529}", "\u{528}", "\u{528}", "\u{529}"),
+    (0x52a, "\u{52b}", "\u{52a}", "\u{52a}", "\u{52b}"),
+    (0x52b, "\u{52b}", "\u{52a}", "\u{52a}", "\u{52b}"),
+    (0x52c, "\u{52d}", "\u{52c}", "\u{52c}", "\u{52d}"),
+    (0x52d, "\u{52d}", "\u{52c}", "\u{52c}", "\u{52d}"),
+    (0x52e, "\u{52f}", "\u{52e}", "\u{52e}", "\u{52f}"),
+    (0x52f, "\u{52f}", "\u{52e}", "\u{52e}", "\u{52f}"),
+    (0x531, "\u{561}", "\u{531}", "\u{531}", "\u{561}"),
+    (0x532, "\u{562}", "\u{532}", "\u{532}", "\u{562}"),
+    (0x533, "\u{563}", "\u{533}", "\u{533}", "\u{563}"),
+    (0x534, "\u{564}", "\u{534}", "\u{534}", "\u{564}"),
+    (0x535, "\u{565}", "\u{535}", "\u{535}", "\u{565}"),
+    (0x536, "\u{566}", "\u{536}", "\u{536}", "\u{566}"),
+    (0x537, "\u{567}", "\u{537}", "\u{537}", "\u{567}"),
+    (0x538, "\u{568}", "\u{538}", "\u{538}", "\u{568}"),
+    (0x539, "\u{569}", "\u{539}", "\u{539}", "\u{569}"),
+    (0x53a, "\u{56a}", "\u{53a}", "\u{53a}", "\u{56a}"),
+    (0x53b, "\u{56b}", "\u{53b}", "\u{53b}", "\u{56b}"),
+    (0x53c, "\u{56c}", "\u{53c}", "\u{53c}", "\u{56c}"),
+    (0x53d, "\u{56d}", "\u{53d}", "\u{53d}", "\u{56d}"),
+    (0x53e, "\u{56e}", "\u{53e}", "\u{53e}", "\u{56e}"),
+    (0x53f, "\u{56f}", "\u{53f}", "\u{53f}", "\u{56f}"),
+    (0x540, "\u{570}", "\u{540}", "\u{540}", "\u{570}"),
+    (0x541, "\u{571}", "\u{541}", "\u{541}", "\u{571}"),
+    (0x542, "\u{572}", "\u{542}", "\u{542}", "\u{572}"),
+    (0x543, "\u{573}", "\u{543}", "\u{543}", "\u{573}"),
+    (0x544, "\u{574}", "\u{544}", "\u{544}", "\u{574}"),
+    (0x545, "\u{575}", "\u{545}", "\u{545}", "\u{575}"),
+    (0x546, "\u{576}", "\u{546}", "\u{546}", "\u{576}"),
+    (0x547, "\u{577}", "\u{547}", "\u{547}", "\u{577}"),
+    (0x548, "\u{578}", "\u{548}", "\u{548}", "\u{578}"),
+    (0x549, "\u{579}", "\u{549}", "\u{549}", "\u{579}"),
+    (0x54a, "\u{57a}", "\u{54a}", "\u{54a}", "\u{57a}"),
+    (0x54b, "\u{57b}", "\u{54b}", "\u{54b}", "\u{57b}"),
+    (0x54c, "\u{57c}", "\u{54c}", "\u{54c}", "\u{57c}"),
+    (0x54d, "\u{57d}", "\u{54d}", "\u{54d}", "\u{57d}"),
+    (0x54e, "\u{57e}", "\u{54e}", "\u{54e}", "\u{57e}"),
+    (0x54f, "\u{57f}", "\u{54f}", "\u{54f}", "\u{57f}"),
+    (0x550, "\u{580}", "\u{550}", "\u{550}", "\u{580}"),
+    (0x551, "\u{581}", "\u{551}", "\u{551}", "\u{581}"),
+    (0x552, "\u{582}", "\u{552}", "\u{552}", "\u{582}"),
+    (0x553, "\u{583}", "\u{553}", "\u{553}", "\u{583}"),
+    (0x554, "\u{584}", "\u{554}", "\u{554}", "\u{584}"),
+    (0x555, "\u{585}", "\u{555}", "\u{555}", "\u{585}"),
+    (0x556, "\u{586}", "\u{556}", "\u{556}", "\u{586}"),
+    (0x561, "\u{561}", "\u{531}", "\u{531}", "\u{561}"),
+    (0x562, "\u{562}", "\u{532}", "\u{532}", "\u{562}"),
+    (0x563, "\u{563}", "\u{533}", "\u{533}", "\u{563}"),
+    (0x564, "\u{564}", "\u{534}", "\u{534}", "\u{564}"),
+    (0x565, "\u{565}", "\u{535}", "\u{535}", "\u{565}"),
+    (0x566, "\u{566}", "\u{536}", "\u{536}", "\u{566}"),
+    (0x567, "\u{567}", "\u{537}", "\u{537}", "\u{567}"),
+    (0x568, "\u{568}", "\u{538}", "\u{538}", "\u{568}"),
+    (0x569, "\u{569}", "\u{539}", "\u{539}", "\u{569}"),
+    (0x56a, "\u{56a}", "\u{53a}", "\u{53a}", "\u{56a}"),
+    (0x56b, "\u{56b}", "\u{53b}", "\u{53b}", "\u{56b}"),
+    (0x56c, "\u{56c}", "\u{53c}", "\u{53c}", "\u{56c}"),
+    (0x56d, "\u{56d}", "\u{53d}", "\u{53d}", "\u{56d}"),
+    (0x56e, "\u{56e}", "\u{53e}", "\u{53e}", "\u{56e}"),
+    (0x56f, "\u{56f}", "\u{53f}", "\u{53f}", "\u{56f}"),
+    (0x570, "\u{570}", "\u{540}", "\u{540}", "\u{570}"),
+    (0x571, "\u{571}", "\u{541}", "\u{541}", "\u{571}"),
+    (0x572, "\u{572}", "\u{542}", "\u{542}", "\u{572}"),
+    (0x573, "\u{573}", "\u{543}", "\u{543}", "\u{573}"),
+    (0x574, "\u{574}", "\u{544}", "\u{544}", "\u{574}"),
+    (0x575, "\u{575}", "\u{545}", "\u{545}", "\u{575}"),
+    (0x576, "\u{576}", "\u{546}", "\u{546}", "\u{576}"),
+    (0x577, "\u{577}", "\u{547}", "\u{547}", "\u{577}"),
+    (0x578, "\u{578}", "\u{548}", "\u{548}", "\u{578}"),
+    (0x579, "\u{579}", "\u{549}", "\u{549}", "\u{579}"),
+    (0x57a, "\u{57a}", "\u{54a}", "\u{54a}", "\u{57a}"),
+    (0x57b, "\u{57b}", "\u{54b}", "\u{54b}", "\u{57b}"),
+    (0x57c, "\u{57c}", "\u{54c}", "\u{54c}", "\u{57c}"),
+    (0x57d, "\u{57d}", "\u{54d}", "\u{54d}", "\u{57d}"),
+    (0x57e, "\u{57e}", "\u{54e}", "\u{54e}", "\u{57e}"),
+    (0x57f, "\u{57f}", "\u{54f}", "\u{54f}", "\u{57f}"),
+    (0x580, "\u{580}", "\u{550}", "\u{550}", "\u{580}"),
+    (0x581, "\u{581}", "\u{551}", "\u{551}", "\u{581}"),
+    (0x582, "\u{582}", "\u{552}", "\u{552}", "\u{582}"),
+    (0x583, "\u{583}", "\u{553}", "\u{553}", "\u{583}"),
+    (0x584, "\u{584}", "\u{554}", "\u{554}", "\u{584}"),
+    (0x585, "\u{585}", "\u{555}", "\u{555}", "\u{585}"),
+    (0x586, "\u{586}", "\u{556}", "\u{556}", "\u{586}"),
+    (0x587, "\u{587}", "\u{535}\u{552}", "\u{535}\u{582}", "\u{565}\u{582}"),
+    (0x10a0, "\u{2d00}", "\u{10a0}", "\u{10a0}", "\u{2d00}"),
+    (0x10a1, "\u{2d01}", "\u{10a1}", "\u{10a1}", "\u{2d01}"),
+    (0x10a2, "\u{2d02}", "\u{10a2}", "\u{10a2}", "\u{2d02}"),
+    (0x10a3, "\u{2d03}", "\u{10a3}", "\u{10a3}", "\u{2d03}"),
+    (0x10a4, "\u{2d04}", "\u{10a4}", "\u{10a4}", "\u{2d04}"),
+    (0x10a5, "\u{2d05}", "\u{10a5}", "\u{10a5}", "\u{2d05}"),
+    (0x10a6, "\u{2d06}", "\u{10a6}", "\u{10a6}", "\u{2d06}"),
+    (0x10a7, "\u{2d07}", "\u{10a7}", "\u{10a7}", "\u{2d07}"),
+    (0x10a8, "\u{2d08}", "\u{10a8}", "\u{10a8}", "\u{2d08}"),
+    (0x10a9, "\u{2d09}", "\u{10a9}", "\u{10a9}", "\u{2d09}"),
+    (0x10aa, "\u{2d0a}", "\u{10aa}", "\u{10aa}", "\u{2d0a}"),
+    (0x10ab, "\u{2d0b}", "\u{10ab}", "\u{10ab}", "\u{2d0b}"),
+    (0x10ac, "\u{2d0c}", "\u{10ac}", "\u{10ac}", "\u{2d0c}"),
+    (0x10ad, "\u{2d0d}", "\u{10ad}", "\u{10ad}", "\u{2d0d}"),
+    (0x10ae, "\u{2d0e}", "\u{10ae}", "\u{10ae}", "\u{2d0e}"),
+    (0x10af, "\u{2d0f}", "\u{10af}", "\u{10af}", "\u{2d0f}"),
+    (0x10b0, "\u{2d10}", "\u{10b0}", "\u{10b0}", "\u{2d10}"),
+    (0x10b1, "\u{2d11}", "\u{10b1}", "\u{10b1}", "\u{2d11}"),
+    (0x10b2, "\u{2d12}", "\u{10b2}", "\u{10b2}", "\u{2d12}"),
+    (0x10b3, "\u{2d13}", "\u{10b3}", "\u{10b3}", "\u{2d13}"),
+    (0x10b4, "\u{2d14}", "\u{10b4}", "\u{10b4}", "\u{2d14}"),
+    (0x10b5, "\u{2d15}", "\u{10b5}", "\u{10b5}", "\u{2d15}"),
+    (0x10b6, "\u{2d16}", "\u{10b6}", "\u{10b6}", "\u{2d16}"),
+    (0x10b7, "\u{2d17}", "\u{10b7}", "\u{10b7}", "\u{2d17}"),
+    (0x10b8, "\u{2d18}", "\u{10b8}", "\u{10b8}", "\u{2d18}"),
+    (0x10b9, "\u{2d19}", "\u{10b9}", "\u{10b9}", "\u{2d19}"),
+    (0x10ba, "\u{2d1a}", "\u{10ba}", "\u{10ba}", "\u{2d1a}"),
+    (0x10bb, "\u{2d1b}", "\u{10bb}", "\u{10bb}", "\u{2d1b}"),
+    (0x10bc, "\u{2d1c}", "\u{10bc}", "\u{10bc}", "\u{2d1c}"),
+    (0x10bd, "\u{2d1d}", "\u{10bd}", "\u{10bd}", "\u{2d1d}"),
+    (0x10be, "\u{2d1e}", "\u{10be}", "\u{10be}", "\u{2d1e}"),
+    (0x10bf, "\u{2d1f}", "\u{10bf}", "\u{10bf}", "\u{2d1f}"),
+    (0x10c0, "\u{2d20}", "\u{10c0}", "\u{10c0}", "\u{2d20}"),
+    (0x10c1, "\u{2d21}", "\u{10c1}", "\u{10c1}", "\u{2d21}"),
+    (0x10c2, "\u{2d22}", "\u{10c2}", "\u{10c2}", "\u{2d22}"),
+    (0x10c3, "\u{2d23}", "\u{10c3}", "\u{10c3}", "\u{2d23}"),
+    (0x10c4, "\u{2d24}", "\u{10c4}", "\u{10c4}", "\u{2d24}"),
+    (0x10c5, "\u{2d25}", "\u{10c5}", "\u{10c5}", "\u{2d25}"),
+    (0x10c7, "\u{2d27}", "\u{10c7}", "\u{10c7}", "\u{2d27}"),
+    (0x10cd, "\u{2d2d}", "\u{10cd}", "\u{10cd}", "\u{2d2d}"),
+    (0x10d0, "\u{10d0}", "\u{1c90}", "\u{10d0}", "\u{10d0}"),
+    (0x10d1, "\u{10d1}", "\u{1c91}", "\u{10d1}", "\u{10d1}"),
+    (0x10d2, "\u{10d2}", "\u{1c92}", "\u{10d2}", "\u{10d2}"),
+    (0x10d3, "\u{10d3}", "\u{1c93}", "\u{10d3}", "\u{10d3}"),
+    (0x10d4, "\u{10d4}", "\u{1c94}", "\u{10d4}", "\u{10d4}"),
+    (0x10d5, "\u{10d5}", "\u{1c95}", "\u{10d5}", "\u{10d5}"),
+    (0x10d6, "\u{10d6}", "\u{1c96}", "\u{10d6}", "\u{10d6}"),
+    (0x10d7, "\u{10d7}", "\u{1c97}", "\u{10d7}", "\u{10d7}"),
+    (0x10d8, "\u{10d8}", "\u{1c98}", "\u{10d8}", "\u{10d8}"),
+    (0x10d9, "\u{10d9}", "\u{1c99}", "\u{10d9}", "\u{10d9}"),
+    (0x10da, "\u{10da}", "\u{1c9a}", "\u{10da}", "\u{10da}"),
+    (0x10db, "\u{10db}", "\u{1c9b}", "\u{10db}", "\u{10db}"),
+    (0x10dc, "\u{10dc}", "\u{1c9c}", "\u{10dc}", "\u{10dc}"),
+    (0x10dd, "\u{10dd}", "\u{1c9d}", "\u{10dd}", "\u{10dd}"),
+    (0x10de, "\u{10de}", "\u{1c9e}", "\u{10de}", "\u{10de}"),
+    (0x10df, "\u{10df}", "\u{1c9f}", "\u{10df}", "\u{10df}"),
+    (0x10e0, "\u{10e0}", "\u{1ca0}", "\u{10e0}", "\u{10e0}"),
+    (0x10e1, "\u{10e1}", "\u{1ca1}", "\u{10e1}", "\u{10e1}"),
+    (0x10e2, "\u{10e2}", "\u{1ca2}", "\u{10e2}", "\u{10e2}"),
+    (0x10e3, "\u{10e3}", "\u{1ca3}", "\u{10e3}", "\u{10e3}"),
+    (0x10e4, "\u{10e4}", "\u{1ca4}", "\u{10e4}", "\u{10e4}"),
+    (0x10e5, "\u{10e5}", "\u{1ca5}", "\u{10e5}", "\u{10e5}"),
+    (0x10e6, "\u{10e6}", "\u{1ca6}", "\u{10e6}", "\u{10e6}"),
+    (0x10e7, "\u{10e7}", "\u{1ca7}", "\u{10e7}", "\u{10e7}"),
+    (0x10e8, "\u{10e8}", "\u{1ca8}", "\u{10e8}", "\u{10e8}"),
+    (0x10e9, "\u{10e9}", "\u{1ca9}", "\u{10e9}", "\u{10e9}"),
+    (0x10ea, "\u{10ea}", "\u{1caa}", "\u{10ea}", "\u{10ea}"),
+    (0x10eb, "\u{10eb}", "\u{1cab}", "\u{10eb}", "\u{10eb}"),
+    (0x10ec, "\u{10ec}", "\u{1cac}", "\u{10ec}", "\u{10ec}"),
+    (0x10ed, "\u{10ed}", "\u{1cad}", "\u{10ed}", "\u{10ed}"),
+    (0x10ee, "\u{10ee}", "\u{1cae}", "\u{10ee}", "\u{10ee}"),
+    (0x10ef, "\u{10ef}", "\u{1caf}", "\u{10ef}", "\u{10ef}"),
+    (0x10f0, "\u{10f0}", "\u{1cb0}", "\u{10f0}", "\u{10f0}"),
+    (0x10f1, "\u{10f1}", "\u{1cb1}", "\u{10f1}", "\u{10f1}"),
+    (0x10f2, "\u{10f2}", "\u{1cb2}", "\u{10f2}", "\u{10f2}"),
+    (0x10f3, "\u{10f3}", "\u{1cb3}", "\u{10f3}", "\u{10f3}"),
+    (0x10f4, "\u{10f4}", "\u{1cb4}", "\u{10f4}", "\u{10f4}"),
+    (0x10f5, "\u{10f5}", "\u{1cb5}", "\u{10f5}", "\u{10f5}"),
+    (0x10f6, "\u{10f6}", "\u{1cb6}", "\u{10f6}", "\u{10f6}"),
+    (0x10f7, "\u{10f7}", "\u{1cb7}", "\u{10f7}", "\u{10f7}"),
+    (0x10f8, "\u{10f8}", "\u{1cb8}", "\u{10f8}", "\u{10f8}"),
+    (0x10f9, "\u{10f9}", "\u{1cb9}", "\u{10f9}", "\u{10f9}"),
+    (0x10fa, "\u{10fa}", "\u{1cba}", "\u{10fa}", "\u{10fa}"),
+    (0x10fd, "\u{10fd}", "\u{1cbd}", "\u{10fd}", "\u{10fd}"),
+    (0x10fe, "\u{10fe}", "\u{1cbe}", "\u{10fe}", "\u{10fe}"),
+    (0x10ff, "\u{10ff}", "\u{1cbf}", "\u{10ff}", "\u{10ff}"),
+    (0x13a0, "\u{ab70}", "\u{13a0}", "\u{13a0}", "\u{13a0}"),
+    (0x13a1, "\u{ab71}", "\u{13a1}", "\u{13a1}", "\u{13a1}"),
+    (0x13a2, "\u{ab72}", "\u{13a2}", "\u{13a2}", "\u{13a2}"),
+    (0x13a3, "\u{ab73}", "\u{13a3}", "\u{13a3}", "\u{13a3}"),
+    (0x13a4, "\u{ab74}", "\u{13a4}", "\u{13a4}", "\u{13a4}"),
+    (0x13a5, "\u{ab75}", "\u{13a5}", "\u{13a5}", "\u{13a5}"),
+    (0x13a6, "\u{ab76}", "\u{13a6}", "\u{13a6}", "\u{13a6}"),
+    (0x13a7, "\u{ab77}", "\u{13a7}", "\u{13a7}", "\u{13a7}"),
+    (0x13a8, "\u{ab78}", "\u{13a8}", "\u{13a8}", "\u{13a8}"),
+    (0x13a9, "\u{ab79}", "\u{13a9}", "\u{13a9}", "\u{13a9}"),
+    (0x13aa, "\u{ab7a}", "\u{13aa}", "\u{13aa}", "\u{13aa}"),
+    (0x13ab, "\u{ab7b}", "\u{13ab}", "\u{13ab}", "\u{13ab}"),
+    (0x13ac, "\u{ab7c}", "\u{13ac}", "\u{13ac}", "\u{13ac}"),
+    (0x13ad, "\u{ab7d}", "\u{13ad}", "\u{13ad}", "\u{13ad}"),
+    (0x13ae, "\u{ab7e}", "\u{13ae}", "\u{13ae}", "\u{13ae}"),
+    (0x13af, "\u{ab7f}", "\u{13af}", "\u{13af}", "\u{13af}"),
+    (0x13b0, "\u{ab80}", "\u{13b0}", "\u{13b0}", "\u{13b0}"),
+    (0x13b1, "\u{ab81}", "\u{13b1}", "\u{13b1}", "\u{13b1}"),
+    (0x13b2, "\u{ab82}", "\u{13b2}", "\u{13b2}", "\u{13b2}"),
+    (0x13b3, "\u{ab83}", "\u{13b3}", "\u{13b3}", "\u{13b3}"),
+    (0x13b4, "\u{ab84}", "\u{13b4}", "\u{13b4}", "\u{13b4}"),
+    (0x13b5, "\u{ab85}", "\u{13b5}", "\u{13b5}", "\u{13b5}"),
+    (0x13b6, "\u{ab86}", "\u{13b6}", "\u{13b6}", "\u{13b6}"),
+    (0x13b7, "\u{ab87}", "\u{13b7}", "\u{13b7}", "\u{13b7}"),
+    (0x13b8, "\u{ab88}", "\u{13b8}", "\u{13b8}", "\u{13b8}"),
+    (0x13b9, "\u{ab89}", "\u{13b9}", "\u{13b9}", "\u{13b9}"),
+    (0x13ba, "\u{ab8a}", "\u{13ba}", "\u{13ba}", "\u{13ba}"),
+    (0x13bb, "\u{ab8b}", "\u{13bb}", "\u{13bb}", "\u{13bb}"),
+    (0x13bc, "\u{ab8c}", "\u{13bc}", "\u{13bc}", "\u{13bc}"),
+    (0x13bd, "\u{ab8d}", "\u{13bd}", "\u{13bd}", "\u{13bd}"),
+    (0x13be, "\u{ab8e}", "\u{13be}", "\u{13be}", "\u{13be}"),
+    (0x13bf, "\u{ab8f}", "\u{13bf}", "\u{13bf}", "\u{13bf}"),
+    (0x13c0, "\u{ab90}", "\u{13c0}", "\u{13c0}", "\u{13c0}"),
+    (0x13c1, "\u{ab91}", "\u{13c1}", "\u{13c1}", "\u{13c1}"),
+    (0x13c2, "\u{ab92}", "\u{13c2}", "\u{13c2}", "\u{13c2}"),
+    (0x13c3, "\u{ab93}", "\u{13c3}", "\u{13c3}", "\u{13c3}"),
+    (0x13c4, "\u{ab94}", "\u{13c4}", "\u{13c4}", "\u{13c4}"),
+    (0x13c5, "\u{ab95}", "\u{13c5}", "\u{13c5}", "\u{13c5}"),
+    (0x13c6, "\u{ab96}", "\u{13c6}", "\u{13c6}", "\u{13c6}"),
+    (0x13c7, "\u{ab97}", "\u{13c7}", "\u{13c7}", "\u{13c7}"),
+    (0x13c8, "\u{ab98}", "\u{13c8}", "\u{13c8}", "\u{13c8}"),
+    (0x13c9, "\u{ab99}", "\u{13c9}", "\u{13c9}", "\u{13c9}"),
+    (0x13ca, "\u{ab9a}", "\u{13ca}", "\u{13ca}", "\u{13ca}"),
+    (0x13cb, "\u{ab9b}", "\u{13cb}", "\u{13cb}", "\u{13cb}"),
+    (0x13cc, "\u{ab9c}", "\u{13cc}", "\u{13cc}", "\u{13cc}"),
+    (0x13cd, "\u{ab9d}", "\u{13cd}", "\u{13cd}", "\u{13cd}"),
+    (0x13ce, "\u{ab9e}", "\u{13ce}", "\u{13ce}", "\u{13ce}"),
+    (0x13cf, "\u{ab9f}", "\u{13cf}", "\u{13cf}", "\u{13cf}"),
+    (0x13d0, "\u{aba0}", "\u{13d0}", "\u{13d0}", "\u{13d0}"),
+    (0x13d1, "\u{aba1}", "\u{13d1}", "\u{13d1}", "\u{13d1}"),
+    (0x13d2, "\u{aba2}", "\u{13d2}", "\u{13d2}", "\u{13d2}"),
+    (0x13d3, "\u{aba3}", "\u{13d3}", "\u{13d3}", "\u{13d3}"),
+    (0x13d4, "\u{aba4}", "\u{13d4}", "\u{13d4}", "\u{13d4}"),
+    (0x13d5, "\u{aba5}", "\u{13d5}", "\u{13d5}", "\u{13d5}"),
+    (0x13d6, "\u{aba6}", "\u{13d6}", "\u{13d6}", "\u{13d6}"),
+    (0x13d7, "\u{aba7}", "\u{13d7}", "\u{13d7}", "\u{13d7}"),
+    (0x13d8, "\u{aba8}", "\u{13d8}", "\u{13d8}", "\u{13d8}"),
+    (0x13d9, "\u{aba9}", "\u{13d9}", "\u{13d9}", "\u{13d9}"),
+    (0x13da, "\u{abaa}", "\u{13da}", "\u{13da}", "\u{13da}"),
+    (0x13db, "\u{abab}", "\u{13db}", "\u{13db}", "\u{13db}"),
+    (0x13dc, "\u{abac}", "\u{13dc}", "\u{13dc}", "\u{13dc}"),
+    (0x13dd, "\u{abad}", "\u{13dd}", "\u{13dd}", "\u{13dd}"),
+    (0x13de, "\u{abae}", "\u{13de}", "\u{13de}", "\u{13de}"),
+    (0x13df, "\u{abaf}", "\u{13df}", "\u{13df}", "\u{13df}"),
+    (0x13e0, "\u{abb0}", "\u{13e0}", "\u{13e0}", "\u{13e0}"),
+    (0x13e1, "\u{abb1}", "\u{13e1}", "\u{13e1}", "\u{13e1}"),
+    (0x13e2, "\u{abb2}", "\u{13e2}", "\u{13e2}", "\u{13e2}"),
+    (0x13e3, "\u{abb3}", "\u{13e3}", "\u{13e3}", "\u{13e3}"),
+    (0x13e4, "\u{abb4}", "\u{13e4}", "\u{13e4}", "\u{13e4}"),
+    (0x13e5, "\u{abb5}", "\u{13e5}", "\u{13e5}", "\u{13e5}"),
+    (0x13e6, "\u{abb6}", "\u{13e6}", "\u{13e6}", "\u{13e6}"),
+    (0x13e7, "\u{abb7}", "\u{13e7}", "\u{13e7}", "\u{13e7}"),
+    (0x13e8, "\u{abb8}", "\u{13e8}", "\u{13e8}", "\u{13e8}"),
+    (0x13e9, "\u{abb9}", "\u{13e9}", "\u{13e9}", "\u{13e9}"),
+    (0x13ea, "\u{abba}", "\u{13ea}", "\u{13ea}", "\u{13ea}"),
+    (0x13eb, "\u{abbb}", "\u{13eb}", "\u{13eb}", "\u{13eb}"),
+    (0x13ec, "\u{abbc}", "\u{13ec}", "\u{13ec}", "\u{13ec}"),
+    (0x13ed, "\u{abbd}", "\u{13ed}", "\u{13ed}", "\u{13ed}"),
+    (0x13ee, "\u{abbe}", "\u{13ee}", "\u{13ee}", "\u{13ee}"),
+    (0x13ef, "\u{abbf}", "\u{13ef}", "\u{13ef}", "\u{13ef}"),
+    (0x13f0, "\u{13f8}", "\u{13f0}", "\u{13f0}", "\u{13f0}"),
+    (0x13f1, "\u{13f9}", "\u{13f1}", "\u{13f1}", "\u{13f1}"),
+    (0x13f2, "\u{13fa}", "\u{13f2}", "\u{13f2}", "\u{13f2}"),
+    (0x13f3, "\u{13fb}", "\u{13f3}", "\u{13f3}", "\u{13f3}"),
+    (0x13f4, "\u{13fc}", "\u{13f4}", "\u{13f4}", "\u{13f4}"),
+    (0x13f5, "\u{13fd}", "\u{13f5}", "\u{13f5}", "\u{13f5}"),
+    (0x13f8, "\u{13f8}", "\u{13f0}", "\u{13f0}", "\u{13f0}"),
+    (0x13f9, "\u{13f9}", "\u{13f1}", "\u{13f1}", "\u{13f1}"),
+    (0x13fa, "\u{13fa}", "\u{13f2}", "\u{13f2}", "\u{13f2}"),
+    (0x13fb, "\u{13fb}", "\u{13f3}", "\u{13f3}", "\u{13f3}"),
+    (0x13fc, "\u{13fc}", "\u{13f4}", "\u{13f4}", "\u{13f4}"),
+    (0x13fd, "\u{13fd}", "\u{13f5}", "\u{13f5}", "\u{13f5}"),
+    (0x1c80, "\u{1c80}", "\u{412}", "\u{412}", "\u{432}"),
+    (0x1c81, "\u{1c81}", "\u{414}", "\u{414}", "\u{434}"),
+    (0x1c82, "\u{1c82}", "\u{41e}", "\u{41e}", "\u{43e}"),
+    (0x1c83, "\u{1c83}", "\u{421}", "\u{421}", "\u{441}"),
+    (0x1c84, "\u{1c84}", "\u{422}", "\u{422}", "\u{442}"),
+    (0x1c85, "\u{1c85}", "\u{422}", "\u{422}", "\u{442}"),
+    (0x1c86, "\u{1c86}", "\u{42a}", "\u{42a}", "\u{44a}"),
+    (0x1c87, "\u{1c87}", "\u{462}", "\u{462}", "\u{463}"),
+    (0x1c88, "\u{1c88}", "\u{a64a}", "\u{a64a}", "\u{a64b}"),
+    (0x1c90, "\u{10d0}", "\u{1c90}", "\u{1c90}", "\u{10d0}"),
+    (0x1c91, "\u{10d1}", "\u{1c91}", "\u{1c91}", "\u{10d1}"),
+    (0x1c92, "\u{10d2}", "\u{1c92}", "\u{1c92}", "\u{10d2}"),
+    (0x1c93, "\u{10d3}", "\u{1c93}", "\u{1c93}", "\u{10d3}"),
+    (0x1c94, "\u{10d4}", "\u{1c94}", "\u{1c94}", "\u{10d4}"),
+    (0x1c95, "\u{10d5}", "\u{1c95}", "\u{1c95}", "\u{10d5}"),
+    (0x1c96, "\u{10d6}", "\u{1c96}", "\u{1c96}", "\u{10d6}"),
+    (0x1c97, "\u{10d7}", "\u{1c97}", "\u{1c97}", "\u{10d7}"),
+    (0x1c98, "\u{10d8}", "\u{1c98}", "\u{1c98}", "\u{10d8}"),
+    (0x1c99, "\u{10d9}", "\u{1c99}", "\u{1c99}", "\u{10d9}"),
+    (0x1c9a, "\u{10da}", "\u{1c9a}", "\u{1c9a}", "\u{10da}"),
+    (0x1c9b, "\u{10db}", "\u{1c9b}", "\u{1c9b}", "\u{10db}"),
+    (0x1c9c, "\u{10dc}", "\u{1c9c}", "\u{1c9c}", "\u{10dc}"),
+    (0x1c9d, "\u{10dd}", "\u{1c9d}", "\u{1c9d}", "\u{10dd}"),
+    (0x1c9e, "\u{10de}", "\u{1c9e}", "\u{1c9e}", "\u{10de}"),
+    (0x1c9f, "\u{10df}", "\u{1c9f}", "\u{1c9f}", "\u{10df}"),
+    (0x1ca0, "\u{10e0}", "\u{1ca0}", "\u{1ca0}", "\u{10e0}"),
+    (0x1ca1, "\u{10e1}", "\u{1ca1}", "\u{1ca1}", "\u{10e1}"),
+    (0x1ca2, "\u{10e2}", "\u{1ca2}", "\u{1ca2}", "\u{10e2}"),
+    (0x1ca3, "\u{10e3}", "\u{1ca3}", "\u{1ca3}", "\u{10e3}"),
+    (0x1ca4, "\u{10e4}", "\u{1ca4}", "\u{1ca4}", "\u{10e4}"),
+    (0x1ca5, "\u{10e5}", "\u{1ca5}", "\u{1ca5}", "\u{10e5}"),
+    (0x1ca6, "\u{10e6}", "\u{1ca6}", "\u{1ca6}", "\u{10e6}"),
+    (0x1ca7, "\u{10e7}", "\u{1ca7}", "\u{1ca7}", "\u{10e7}"),
+    (0x1ca8, "\u{10e8}", "\u{1ca8}", "\u{1ca8}", "\u{10e8}"),
+    (0x1ca9, "\u{10e9}", "\u{1ca9}", "\u{1ca9}", "\u{10e9}"),
+    (0x1caa, "\u{10ea}", "\u{1caa}", "\u{1caa}", "\u{10ea}"),
+    (0x1cab, "\u{10eb}", "\u{1cab}", "\u{1cab}", "\u{10eb}"),
+    (0x1cac, "\u{10ec}", "\u{1cac}", "\u{1cac}", "\u{10ec}"),
+    (0x1cad, "\u{10ed}", "\u{1cad}", "\u{1cad}", "\u{10ed}"),
+    (0x1cae, "\u{10ee}", "\u{1cae}", "\u{1cae}", "\u{10ee}"),
+    (0x1caf, "\u{10ef}", "\u{1caf}", "\u{1caf}", "\u{10ef}"),
+    (0x1cb0, "\u{10f0}", "\u{1cb0}", "\u{1cb0}", "\u{10f0}"),
+    (0x1cb1, "\u{10f1}", "\u{1cb1}", "\u{1cb1}", "\u{10f1}"),
+    (0x1cb2, "\u{10f2}", "\u{1cb2}", "\u{1cb2}", "\u{10f2}"),
+    (0x1cb3, "\u{10f3}", "\u{1cb3}", "\u{1cb3}", "\u{10f3}"),
+    (0x1cb4, "\u{10f4}", "\u{1cb4}", "\u{1cb4}", "\u{10f4}"),
+    (0x1cb5, "\u{10f5}", "\u{1cb5}", "\u{1cb5}", "\u{10f5}"),
+    (0x1cb6, "\u{10f6}", "\u{1cb6}", "\u{1cb6}", "\u{10f6}"),
+    (0x1cb7, "\u{10f7}", "\u{1cb7}", "\u{1cb7}", "\u{10f7}"),
+    (0x1cb8, "\u{10f8}", "\u{1cb8}", "\u{1cb8}", "\u{10f8}"),
+    (0x1cb9, "\u{10f9}", "\u{1cb9}", "\u{1cb9}", "\u{10f9}"),
+    (0x1cba, "\u{10fa}", "\u{1cba}", "\u{1cba}", "\u{10fa}"),
+    (0x1cbd, "\u{10fd}", "\u{1cbd}", "\u{1cbd}", "\u{10fd}"),
+    (0x1cbe, "\u{10fe}", "\u{1cbe}", "\u{1cbe}", "\u{10fe}"),
+    (0x1cbf, "\u{10ff}", "\u{1cbf}", "\u{1cbf}", "\u{10ff}"),
+    (0x1d79, "\u{1d79}", "\u{a77d}", "\u{a77d}", "\u{1d79}"),
+    (0x1d7d, "\u{1d7d}", "\u{2c63}", "\u{2c63}", "\u{1d7d}"),
+    (0x1d8e, "\u{1d8e}", "\u{a7c6}", "\u{a7c6}", "\u{1d8e}"),
+    (0x1e00, "\u{1e01}", "\u{1e00}", "\u{1e00}", "\u{1e01}"),
+    (0x1e01, "\u{1e01}", "\u{1e00}", "\u{1e00}", "\u{1e01}"),
+    (0x1e02, "\u{1e03}", "\u{1e02}", "\u{1e02}", "\u{1e03}"),
+    (0x1e03, "\u{1e03}", "\u{1e02}", "\u{1e02}", "\u{1e03}"),
+    (0x1e04, "\u{1e05}", "\u{1e04}", "\u{1e04}", "\u{1e05}"),
+    (0x1e05, "\u{1e05}", "\u{1e04}", "\u{1e04}", "\u{1e05}"),
+    (0x1e06, "\u{1e07}", "\u{1e06}", "\u{1e06}", "\u{1e07}"),
+    (0x1e07, "\u{1e07}", "\u{1e06}", "\u{1e06}", "\u{1e07}"),
+    (0x1e08, "\u{1e09}", "\u{1e08}", "\u{1e08}", "\u{1e09}"),
+    (0x1e09, "\u{1e09}", "\u{1e08}", "\u{1e08}", "\u{1e09}"),
+    (0x1e0a, "\u{1e0b}", "\u{1e0a}", "\u{1e0a}", "\u{1e0b}"),
+    (0x1e0b, "\u{1e0b}", "\u{1e0a}", "\u{1e0a}", "\u{1e0b}"),
+    (0x1e0c, "\u{1e0d}", "\u{1e0c}", "\u{1e0c}", "\u{1e0d}"),
+    (0x1e0d, "\u{1e0d}", "\u{1e0c}", "\u{1e0c}", "\u{1e0d}"),
+    (0x1e0e, "\u{1e0f}", "\u{1e0e}", "\u{1e0e}", "\u{1e0f}"),
+    (0x1e0f, "\u{1e0f}", "\u{1e0e}", "\u{1e0e}", "\u{1e0f}"),
+    (0x1e10, "\u{1e11}", "\u{1e10}", "\u{1e10}", "\u{1e11}"),
+    (0x1e11, "\u{1e11}", "\u{1e10}", "\u{1e10}", "\u{1e11}"),
+    (0x1e12, "\u{1e13}", "\u{1e12}", "\u{1e12}", "\u{1e13}"),
+    (0x1e13, "\u{1e13}", "\u{1e12}", "\u{1e12}", "\u{1e13}"),
+    (0x1e14, "\u{1e15}", "\u{1e14}", "\u{1e14}", "\u{1e15}"),
+    (0x1e15, "\u{1e15}", "\u{1e14}", "\u{1e14}", "\u{1e15}"),
+    (0x1e16, "\u{1e17}", "\u{1e16}", "\u{1e16}", "\u{1e17}"),
+    (0x1e17, "\u{1e17}", "\u{1e16}", "\u{1e16}", "\u{1e17}"),
+    (0x1e18, "\u{1e19}", "\u{1e18}", "\u{1e18}", "\u{1e19}"),
+    (0x1e19, "\u{1e19}", "\u{1e18}", "\u{1e18}", "\u{1e19}"),
+    (0x1e1a, "\u{1e1b}", "\u{1e1a}", "\u{1e1a}", "\u{1e1b}"),
+    (0x1e1b, "\u{1e1b}", "\u{1e1a}", "\u{1e1a}", "\u{1e1b}"),
+    (0x1e1c, "\u{1e1d}", "\u{1e1c}", "\u{1e1c}", "\u{1e1d}"),
+    (0x1e1d, "\u{1e1d}", "\u{1e1c}", "\u{1e1c}", "\u{1e1d}"),
+    (0x1e1e, "\u{1e1f}", "\u{1e1e}", "\u{1e1e}", "\u{1e1f}"),
+    (0x1e1f, "\u{1e1f}", "\u{1e1e}", "\u{1e1e}", "\u{1e1f}"),
+    (0x1e20, "\u{1e21}", "\u{1e20}", "\u{1e20}", "\u{1e21}"),
+    (0x1e21, "\u{1e21}", "\u{1e20}", "\u{1e20}", "\u{1e21}"),
+    (0x1e22, "\u{1e23}", "\u{1e22}", "\u{1e22}", "\u{1e23}"),
+    (0x1e23, "\u{1e23}", "\u{1e22}", "\u{1e22}", "\u{1e23}"),
+    (0x1e24, "\u{1e25}", "\u{1e24}", "\u{1e24}", "\u{1e25}"),
+    (0x1e25, "\u{1e25}", "\u{1e24}", "\u{1e24}", "\u{1e25}"),
+    (0x1e26, "\u{1e27}", "\u{1e26}", "\u{1e26}", "\u{1e27}"),
+    (0x1e27, "\u{1e27}", "\u{1e26}", "\u{1e26}", "\u{1e27}"),
+    (0x1e28, "\u{1e29}", "\u{1e28}", "\u{1e28}", "\u{1e29}"),
+    (0x1e29, "\u{1e29}", "\u{1e28}", "\u{1e28}", "\u{1e29}"),
+    (0x1e2a, "\u{1e2b}", "\u{1e2a}", "\u{1e2a}", "\u{1e2b}"),
+    (0x1e2b, "\u{1e2b}", "\u{1e2a}", "\u{1e2a}", "\u{1e2b}"),
+    (0x1e2c, "\u{1e2d}", "\u{1e2c}", "\u{1e2c}", "\u{1e2d}"),
+    (0x1e2d, "\u{1e2d}", "\u{1e2c}", "\u{1e2c}", "\u{1e2d}"),
+    (0x1e2e, "\u{1e2f}", "\u{1e2e}", "\u{1e2e}", "\u{1e2f}"),
+    (0x1e2f, "\u{1e2f}", "\u{1e2e}", "\u{1e2e}", "\u{1e2f}"),
+    (0x1e30, "\u{1e31}", "\u{1e30}", "\u{1e30}", "\u{1e31}"),
+    (0x1e31, "\u{1e31}", "\u{1e30}", "\u{1e30}", "\u{1e31}"),
+    (0x1e32, "\u{1e33}", "\u{1e32}", "\u{1e32}", "\u{1e33}"),
+    (0x1e33, "\u{1e33}", "\u{1e32}", "\u{1e32}", "\u{1e33}"),
+    (0x1e34, "\u{1e35}", "\u{1e34}", "\u{1e34}", "\u{1e35}"),
+    (0x1e35, "\u{1e35}", "\u{1e34}", "\u{1e34}", "\u{1e35}"),
+    (0x1e36, "\u{1e37}", "\u{1e36}", "\u{1e36}", "\u{1e37}"),
+    (0x1e37, "\u{1e37}", "\u{1e36}", "\u{1e36}", "\u{1e37}"),
+    (0x1e38, "\u{1e39}", "\u{1e38}", "\u{1e38}", "\u{1e39}"),
+    (0x1e39, "\u{1e39}", "\u{1e38}", "\u{1e38}", "\u{1e39}"),
+    (0x1e3a, "\u{1e3b}", "\u{1e3a}", "\u{1e3a}", "\u{1e3b}"),
+    (0x1e3b, "\u{1e3b}", "\u{1e3a}", "\u{1e3a}", "\u{1e3b}"),
+    (0x1e3c, "\u{1e3d}", "\u{1e3c}", "\u{1e3c}", "\u{1e3d}"),
+    (0x1e3d, "\u{1e3d}", "\u{1e3c}", "\u{1e3c}", "\u{1e3d}"),
+    (0x1e3e, "\u{1e3f}", "\u{1e3e}", "\u{1e3e}", "\u{1e3f}"),
+    (0x1e3f, "\u{1e3f}", "\u{1e3e}", "\u{1e3e}", "\u{1e3f}"),
+    (0x1e40, "\u{1e41}", "\u{1e40}", "\u{1e40}", "\u{1e41}"),
+    (0x1e41, "\u{1e41}", "\u{1e40}", "\u{1e40}", "\u{1e41}"),
+    (0x1e42, "\u{1e43}", "\u{1e42}", "\u{1e42}", "\u{1e43}"),
+    (0x1e43, "\u{1e43}", "\u{1e42}", "\u{1e42}", "\u{1e43}"),
+    (0x1e44, "\u{1e45}", "\u{1e44}", "\u{1e44}", "\u{1e45}"),
+    (0x1e45, "\u{1e45}", "\u{1e44}", "\u{1e44}", "\u{1e45}"),
+    (0x1e46, "\u{1e47}", "\u{1e46}", "\u{1e46}", "\u{1e47}"),
+    (0x1e47, "\u{1e47}", "\u{1e46}", "\u{1e46}", "\u{1e47}"),
+    (0x1e48, "\u{1e49}", "\u{1e48}", "\u{1e48}", "\u{1e49}"),
+    (0x1e49, "\u{1e49}", "\u{1e48}", "\u{1e48}", "\u{1e49}"),
+    (0x1e4a, "\u{1e4b}", "\u{1e4a}", "\u{1e4a}", "\u{1e4b}"),
+    (0x1e4b, "\u{1e4b}", "\u{1e4a}", "\u{1e4a}", "\u{1e4b}"),
+    (0x1e4c, "\u{1e4d}", "\u{1e4c}", "\u{1e4c}", "\u{1e4d}"),
+    (0x1e4d, "\u{1e4d}", "\u{1e4c}", "\u{1e4c}", "\u{1e4d}"),
+    (0x1e4e, "\u{1e4f}", "\u{1e4e}", "\u{1e4e}", "\u{1e4f}"),
+    (0x1e4f, "\u{1e4f}", "\u{1e4e}", "\u{1e4e}", "\u{1e4f}"),
+    (0x1e50, "\u{1e51}", "\u{1e50}", "\u{1e50}", "\u{1e51}"),
+    (0x1e51, "\u{1e51}", "\u{1e50}", "\u{1e50}", "\u{1e51}"),
+    (0x1e52, "\u{1e53}", "\u{1e52}", "\u{1e52}", "\u{1e53}"),
+    (0x1e53, "\u{1e53}", "\u{1e52}", "\u{1e52}", "\u{1e53}"),
+    (0x1e54, "\u{1e55}", "\u{1e54}", "\u{1e54}", "\u{1e55}"),
+    (0x1e55, "\u{1e55}", "\u{1e54}", "\u{1e54}", "\u{1e55}"),
+    (0x1e56, "\u{1e57}", "\u{1e56}", "\u{1e56}", "\u{1e57}"),
+    (0x1e57, "\u{1e57}", "\u{1e56}", "\u{1e56}", "\u{1e57}"),
+    (0x1e58, "\u{1e59}", "\u{1e58}", "\u{1e58}", "\u{1e59}"),
+    (0x1e59, "\u{1e59}", "\u{1e58}", "\u{1e58}", "\u{1e59}"),
+    (0x1e5a, "\u{1e5b}", "\u{1e5a}", "\u{1e5a}", "\u{1e5b}"),
+    (0x1e5b, "\u{1e5b}", "\u{1e5a}", "\u{1e5a}", "\u{1e5b}"),
+    (0x1e5c, "\u{1e5d}", "\u{1e5c}", "\u{1e5c}", "\u{1e5d}"),
+    (0x1e5d, "\u{1e5d}", "\u{1e5c}", "\u{1e5c}", "\u{1e5d}"),
+    (0x1e5e, "\u{1e5f}", "\u{1e5e}", "\u{1e5e}", "\u{1e5f}"),
+    (0x1e5f, "\u{1e5f}", "\u{1e5e}", "\u{1e5e}", "\u{1e5f}"),
+    (0x1e60, "\u{1e61}", "\u{1e60}", "\u{1e60}", "\u{1e61}"),
+    (0x1e61, "\u{1e61}", "\u{1e60}", "\u{1e60}", "\u{1e61}"),
+    (0x1e62, "\u{1e63}", "\u{1e62}", "\u{1e62}", "\u{1e63}"),
+    (0x1e63, "\u{1e63}", "\u{1e62}", "\u{1e62}", "\u{1e63}"),
+    (0x1e64, "\u{1e65}", "\u{1e64}", "\u{1e64}", "\u{1e65}"),
+    (0x1e65, "\u{1e65}", "\u{1e64}", "\u{1e64}", "\u{1e65}"),
+    (0x1e66, "\u{1e67}", "\u{1e66}", "\u{1e66}", "\u{1e67}"),
+    (0x1e67, "\u{1e67}", "\u{1e66}", "\u{1e66}", "\u{1e67}"),
+    (0x1e68, "\u{1e69}", "\u{1e68}", "\u{1e68}", "\u{1e69}"),
+    (0x1e69, "\u{1e69}", "\u{1e68}", "\u{1e68}", "\u{1e69}"),
+    (0x1e6a, "\u{1e6b}", "\u{1e6a}", "\u{1e6a}", "\u{1e6b}"),
+    (0x1e6b, "\u{1e6b}", "\u{1e6a}", "\u{1e6a}", "\u{1e6b}"),
+    (0x1e6c, "\u{1e6d}", "\u{1e6c}", "\u{1e6c}", "\u{1e6d}"),
+    (0x1e6d, "\u{1e6d}", "\u{1e6c}", "\u{1e6c}", "\u{1e6d}"),
+    (0x1e6e, "\u{1e6f}", "\u{1e6e}", "\u{1e6e}", "\u{1e6f}"),
+    (0x1e6f, "\u{1e6f}", "\u{1e6e}", "\u{1e6e}", "\u{1e6f}"),
+    (0x1e70, "\u{1e71}", "\u{1e70}", "\u{1e70}", "\u{1e71}"),
+    (0x1e71, "\u{1e71}", "\u{1e70}", "\u{1e70}", "\u{1e71}"),
+    (0x1e72, "\u{1e73}", "\u{1e72}", "\u{1e72}", "\u{1e73}"),
+    (0x1e73, "\u{1e73}", "\u{1e72}", "\u{1e72}", "\u{1e73}"),
+    (0x1e74, "\u{1e75}", "\u{1e74}", "\u{1e74}", "\u{1e75}"),
+    (0x1e75, "\u{1e75}", "\u{1e74}", "\u{1e74}", "\u{1e75}"),
+    (0x1e76, "\u{1e77}", "\u{1e76}", "\u{1e76}", "\u{1e77}"),
+    (0x1e77, "\u{1e77}", "\u{1e76}", "\u{1e76}", "\u{1e77}"),
+    (0x1e78, "\u{1e79}", "\u{1e78}", "\u{1e78}", "\u{1e79}"),
+    (0x1e79, "\u{1e79}", "\u{1e78}", "\u{1e78}", "\u{1e79}"),
+    (0x1e7a, "\u{1e7b}", "\u{1e7a}", "\u{1e7a}", "\u{1e7b}"),
+    (0x1e7b, "\u{1e7b}", "\u{1e7a}", "\u{1e7a}", "\u{1e7b}"),
+    (0x1e7c, "\u{1e7d}", "\u{1e7c}", "\u{1e7c}", "\u{1e7d}"),
+    (0x1e7d, "\u{1e7d}", "\u{1e7c}", "\u{1e7c}", "\u{1e7d}"),
+    (0x1e7e, "\u{1e7f}", "\u{1e7e}", "\u{1e7e}", "\u{1e7f}"),
+    (0x1e7f, "\u{1e7f}", "\u{1e7e}", "\u{1e7e}", "\u{1e7f}"),
+    (0x1e80, "\u{1e81}", "\u{1e80}", "\u{1e80}", "\u{1e81}"),
+    (0x1e81, "\u{1e81}", "\u{1e80}", "\u{1e80}", "\u{1e81}"),
+    (0x1e82, "\u{1e83}", "\u{1e82}", "\u{1e82}", "\u{1e83}"),
+    (0x1e83, "\u{1e83}", "\u{1e82}", "\u{1e82}", "\u{1e83}"),
+    (0x1e84, "\u{1e85}", "\u{1e84}", "\u{1e84}", "\u{1e85}"),
+    (0x1e85, "\u{1e85}", "\u{1e84}", "\u{1e84}", "\u{1e85}"),
+    (0x1e86, "\u{1e87}", "\u{1e86}", "\u{1e86}", "\u{1e87}"),
+    (0x1e87, "\u{1e87}", "\u{1e86}", "\u{1e86}", "\u{1e87}"),
+    (0x1e88, "\u{1e89}", "\u{1e88}", "\u{1e88}", "\u{1e89}"),
+    (0x1e89, "\u{1e89}", "\u{1e88}", "\u{1e88}", "\u{1e89}"),
+    (0x1e8a, "\u{1e8b}", "\u{1e8a}", "\u{1e8a}", "\u{1e8b}"),
+    (0x1e8b, "\u{1e8b}", "\u{1e8a}", "\u{1e8a}", "\u{1e8b}"),
+    (0x1e8c, "\u{1e8d}", "\u{1e8c}", "\u{1e8c}", "\u{1e8d}"),
+    (0x1e8d, "\u{1e8d}", "\u{1e8c}", "\u{1e8c}", "\u{1e8d}"),
+    (0x1e8e, "\u{1e8f}", "\u{1e8e}", "\u{1e8e}", "\u{1e8f}"),
+    (0x1e8f, "\u{1e8f}", "\u{1e8e}", "\u{1e8e}", "\u{1e8f}"),
+    (0x1e90, "\u{1e91}", "\u{1e90}", "\u{1e90}", "\u{1e91}"),
+    (0x1e91, "\u{1e91}", "\u{1e90}", "\u{1e90}", "\u{1e91}"),
+    (0x1e92, "\u{1e93}", "\u{1e92}", "\u{1e92}", "\u{1e93}"),
+    (0x1e93, "\u{1e93}", "\u{1e92}", "\u{1e92}", "\u{1e93}"),
+    (0x1e94, "\u{1e95}", "\u{1e94}", "\u{1e94}", "\u{1e95}"),
+    (0x1e95, "\u{1e95}", "\u{1e94}", "\u{1e94}", "\u{1e95}"),
+    (0x1e96, "\u{1e96}", "H\u{331}", "H\u{331}", "h\u{331}"),
+    (0x1e97, "\u{1e97}", "T\u{308}", "T\u{308}", "t\u{308}"),
+    (0x1e98, "\u{1e98}", "W\u{30a}", "W\u{30a}", "w\u{30a}"),
+    (0x1e99, "\u{1e99}", "Y\u{30a}", "Y\u{30a}", "y\u{30a}"),
+    (0x1e9a, "\u{1e9a}", "A\u{2be}", "A\u{2be}", "a\u{2be}"),
+    (0x1e9b, "\u{1e9b}", "\u{1e60}", "\u{1e60}", "\u{1e61}"),
+    (0x1e9e, "\u{df}", "\u{1e9e}", "\u{1e9e}", "ss"),
+    (0x1ea0, "\u{1ea1}", "\u{1ea0}", "\u{1ea0}", "\u{1ea1}"),
+    (0x1ea1, "\u{1ea1}", "\u{1ea0}", "\u{1ea0}", "\u{1ea1}"),
+    (0x1ea2, "\u{1ea3}", "\u{1ea2}", "\u{1ea2}", "\u{1ea3}"),
+    (0x1ea3, "\u{1ea3}", "\u{1ea2}", "\u{1ea2}", "\u{1ea3}"),
+    (0x1ea4, "\u{1ea5}", "\u{1ea4}", "\u{1ea4}", "\u{1ea5}"),
+    (0x1ea5, "\u{1ea5}", "\u{1ea4}", "\u{1ea4}", "\u{1ea5}"),
+    (0x1ea6, "\u{1ea7}", "\u{1ea6}", "\u{1ea6}", "\u{1ea7}"),
+    (0x1ea7, "\u{1ea7}", "\u{1ea6}", "\u{1ea6}", "\u{1ea7}"),
+    (0x1ea8, "\u{1ea9}", "\u{1ea8}", "\u{1ea8}", "\u{1ea9}"),
+    (0x1ea9, "\u{1ea9}", "\u{1ea8}", "\u{1ea8}", "\u{1ea9}"),
+    (0x1eaa, "\u{1eab}", "\u{1eaa}", "\u{1eaa}", "\u{1eab}"),
+    (0x1eab, "\u{1eab}", "\u{1eaa}", "\u{1eaa}", "\u{1eab}"),
+    (0x1eac, "\u{1ead}", "\u{1eac}", "\u{1eac}", "\u{1ead}"),
+    (0x1ead, "\u{1ead}", "\u{1eac}", "\u{1eac}", "\u{1ead}"),
+    (0x1eae, "\u{1eaf}", "\u{1eae}", "\u{1eae}", "\u{1eaf}"),
+    (0x1eaf, "\u{1eaf}", "\u{1eae}", "\u{1eae}", "\u{1eaf}"),
+    (0x1eb0, "\u{1eb1}", "\u{1eb0}", "\u{1eb0}", "\u{1eb1}"),
+    (0x1eb1, "\u{1eb1}", "\u{1eb0}", "\u{1eb0}", "\u{1eb1}"),
+    (0x1eb2, "\u{1eb3}", "\u{1eb2}", "\u{1eb2}", "\u{1eb3}"),
+    (0x1eb3, "\u{1eb3}", "\u{1eb2}", "\u{1eb2}", "\u{1eb3}"),
+    (0x1eb4, "\u{1eb5}", "\u{1eb4}", "\u{1eb4}", "\u{1eb5}"),
+    (0x1eb5, "\u{1eb5}", "\u{1eb4}", "\u{1eb4}", "\u{1eb5}"),
+    (0x1eb6, "\u{1eb7}", "\u{1eb6}", "\u{1eb6}", "\u{1eb7}"),
+    (0x1eb7, "\u{1eb7}", "\u{1eb6}", "\u{1eb6}", "\u{1eb7}"),
+    (0x1eb8, "\u{1eb9}", "\u{1eb8}", "\u{1eb8}", "\u{1eb9}"),
+    (0x1eb9, "\u{1eb9}", "\u{1eb8}", "\u{1eb8}", "\u{1eb9}"),
+    (0x1eba, "\u{1ebb}", "\u{1eba}", "\u{1eba}", "\u{1ebb}"),
+    (0x1ebb, "\u{1ebb}", "\u{1eba}", "\u{1eba}", "\u{1ebb}"),
+    (0x1ebc, "\u{1ebd}", "\u{1ebc}", "\u{1ebc}", "\u{1ebd}"),
+    (0x1ebd, "\u{1ebd}", "\u{1ebc}", "\u{1ebc}", "\u{1ebd}"),
+    (0x1ebe, "\u{1ebf}", "\u{1ebe}", "\u{1ebe}", "\u{1ebf}"),
+    (0x1ebf, "\u{1ebf}", "\u{1ebe}", "\u{1ebe}", "\u{1ebf}"),
+    (0x1ec0, "\u{1ec1}", "\u{1ec0}", "\u{1ec0}", "\u{1ec1}"),
+    (0x1ec1, "\u{1ec1}", "\u{1ec0}", "\u{1ec0}", "\u{1ec1}"),
+    (0x1ec2, "\u{1ec3}", "\u{1ec2}", "\u{1ec2}", "\u{1ec3}"),
+    (0x1ec3, "\u{1ec3}", "\u{1ec2}", "\u{1ec2}", "\u{1ec3}"),
+    (0x1ec4, "\u{1ec5}", "\u{1ec4}", "\u{1ec4}", "\u{1ec5}"),
+    (0x1ec5, "\u{1ec5}", "\u{1ec4}", "\u{1ec4}", "\u{1ec5}"),
+    (0x1ec6, "\u{1ec7}", "\u{1ec6}", "\u{1ec6}", "\u{1ec7}"),
+    (0x1ec7, "\u{1ec7}", "\u{1ec6}", "\u{1ec6}", "\u{1ec7}"),
+    (0x1ec8, "\u{1ec9}", "\u{1ec8}", "\u{1ec8}", "\u{1ec9}"),
+    (0x1ec9, "\u{1ec9}", "\u{1ec8}", "\u{1ec8}", "\u{1ec9}"),
+    (0x1eca, "\u{1ecb}", "\u{1eca}", "\u{1eca}", "\u{1ecb}"),
+    (0x1ecb, "\u{1ecb}", "\u{1eca}", "\u{1eca}", "\u{1ecb}"),
+    (0x1ecc, "\u{1ecd}", "\u{1ecc}", "\u{1ecc}", "\u{1ecd}"),
+    (0x1ecd, "\u{1ecd}", "\u{1ecc}", "\u{1ecc}", "\u{1ecd}"),
+    (0x1ece, "\u{1ecf}", "\u{1ece}", "\u{1ece}", "\u{1ecf}"),
+    (0x1ecf, "\u{1ecf}", "\u{1ece}", "\u{1ece}", "\u{1ecf}"),
+    (0x1ed0, "\u{1ed1}", "\u{1ed0}", "\u{1ed0}", "\u{1ed1}"),
+    (0x1ed1, "\u{1ed1}", "\u{1ed0}", "\u{1ed0}", "\u{1ed1}"),
+    (0x1ed2, "\u{1ed3}", "\u{1ed2}", "\u{1ed2}", "\u{1ed3}"),
+    (0x1ed3, "\u{1ed3}", "\u{1ed2}", "\u{1ed2}", "\u{1ed3}"),
+    (0x1ed4, "\u{1ed5}", "\u{1ed4}", "\u{1ed4}", "\u{1ed5}"),
+    (0x1ed5, "\u{1ed5}", "\u{1ed4}", "\u{1ed4}", "\u{1ed5}"),
+    (0x1ed6, "\u{1ed7}", "\u{1ed6}", "\u{1ed6}", "\u{1ed7}"),
+    (0x1ed7, "\u{1ed7}", "\u{1ed6}", "\u{1ed6}", "\u{1ed7}"),
+    (0x1ed8, "\u{1ed9}", "\u{1ed8}", "\u{1ed8}", "\u{1ed9}"),
+    (0x1ed9, "\u{1ed9}", "\u{1ed8}", "\u{1ed8}", "\u{1ed9}"),
+    (0x1eda, "\u{1edb}", "\u{1eda}", "\u{1eda}", "\u{1edb}"),
+    (0x1edb, "\u{1edb}", "\u{1eda}", "\u{1eda}", "\u{1edb}"),
+    (0x1edc, "\u{1edd}", "\u{1edc}", "\u{1edc}", "\u{1edd}"),
+    (0x1edd, "\u{1edd}", "\u{1edc}", "\u{1edc}", "\u{1edd}"),
+    (0x1ede, "\u{1edf}", "\u{1ede}", "\u{1ede}", "\u{1edf}"),
+    (0x1edf, "\u{1edf}", "\u{1ede}", "\u{1ede}", "\u{1edf}"),
+    (0x1ee0, "\u{1ee1}", "\u{1ee0}", "\u{1ee0}", "\u{1ee1}"),
+    (0x1ee1, "\u{1ee1}", "\u{1ee0}", "\u{1ee0}", "\u{1ee1}"),
+    (0x1ee2, "\u{1ee3}", "\u{1ee2}", "\u{1ee2}", "\u{1ee3}"),
+    (0x1ee3, "\u{1ee3}", "\u{1ee2}", "\u{1ee2}", "\u{1ee3}"),
+    (0x1ee4, "\u{1ee5}", "\u{1ee4}", "\u{1ee4}", "\u{1ee5}"),
+    (0x1ee5, "\u{1ee5}", "\u{1ee4}", "\u{1ee4}", "\u{1ee5}"),
+    (0x1ee6, "\u{1ee7}", "\u{1ee6}", "\u{1ee6}", "\u{1ee7}"),
+    (0x1ee7, "\u{1ee7}", "\u{1ee6}", "\u{1ee6}", "\u{1ee7}"),
+    (0x1ee8, "\u{1ee9}", "\u{1ee8}", "\u{1ee8}", "\u{1ee9}"),
+    (0x1ee9, "\u{1ee9}", "\u{1ee8}", "\u{1ee8}", "\u{1ee9}"),
+    (0x1eea, "\u{1eeb}", "\u{1eea}", "\u{1eea}", "\u{1eeb}"),
+    (0x1eeb, "\u{1eeb}", "\u{1eea}", "\u{1eea}", "\u{1eeb}"),
+    (0x1eec, "\u{1eed}", "\u{1eec}", "\u{1eec}", "\u{1eed}"),
+    (0x1eed, "\u{1eed}", "\u{1eec}", "\u{1eec}", "\u{1eed}"),
+    (0x1eee, "\u{1eef}", "\u{1eee}", "\u{1eee}", "\u{1eef}"),
+    (0x1eef, "\u{1eef}", "\u{1eee}", "\u{1eee}", "\u{1eef}"),
+    (0x1ef0, "\u{1ef1}", "\u{1ef0}", "\u{1ef0}", "\u{1ef1}"),
+    (0x1ef1, "\u{1ef1}", "\u{1ef0}", "\u{1ef0}", "\u{1ef1}"),
+    (0x1ef2, "\u{1ef3}", "\u{1ef2}", "\u{1ef2}", "\u{1ef3}"),
+    (0x1ef3, "\u{1ef3}", "\u{1ef2}", "\u{1ef2}", "\u{1ef3}"),
+    (0x1ef4, "\u{1ef5}", "\u{1ef4}", "\u{1ef4}", "\u{1ef5}"),
+    (0x1ef5, "\u{1ef5}", "\u{1ef4}", "\u{1ef4}", "\u{1ef5}"),
+    (0x1ef6, "\u{1ef7}", "\u{1ef6}", "\u{1ef6}", "\u{1ef7}"),
+    (0x1ef7, "\u{1ef7}", "\u{1ef6}", "\u{1ef6}", "\u{1ef7}"),
+    (0x1ef8, "\u{1ef9}", "\u{1ef8}", "\u{1ef8}", "\u{1ef9}"),
+    (0x1ef9, "\u{1ef9}", "\u{1ef8}", "\u{1ef8}", "\u{1ef9}"),
+    (0x1efa, "\u{1efb}", "\u{1efa}", "\u{1efa}", "\u{1efb}"),
+    (0x1efb, "\u{1efb}", "\u{1efa}", "\u{1efa}", "\u{1efb}"),
+    (0x1efc, "\u{1efd}", "\u{1efc}", "\u{1efc}", "\u{1efd}"),
+    (0x1efd, "\u{1efd}", "\u{1efc}", "\u{1efc}", "\u{1efd}"),
+    (0x1efe, "\u{1eff}", "\u{1efe}", "\u{1efe}", "\u{1eff}"),
+    (0x1eff, "\u{1eff}", "\u{1efe}", "\u{1efe}", "\u{1eff}"),
+    (0x1f00, "\u{1f00}", "\u{1f08}", "\u{1f08}", "\u{1f00}"),
+    (0x1f01, "\u{1f01}", "\u{1f09}", "\u{1f09}", "\u{1f01}"),
+    (0x1f02, "\u{1f02}", "\u{1f0a}", "\u{1f0a}", "\u{1f02}"),
+    (0x1f03, "\u{1f03}", "\u{1f0b}", "\u{1f0b}", "\u{1f03}"),
+    (0x1f04, "\u{1f04}", "\u{1f0c}", "\u{1f0c}", "\u{1f04}"),
+    (0x1f05, "\u{1f05}", "\u{1f0d}", "\u{1f0d}", "\u{1f05}"),
+    (0x1f06, "\u{1f06}", "\u{1f0e}", "\u{1f0e}", "\u{1f06}"),
+    (0x1f07, "\u{1f07}", "\u{1f0f}", "\u{1f0f}", "\u{1f07}"),
+    (0x1f08, "\u{1f00}", "\u{1f08}", "\u{1f08}", "\u{1f00}"),
+    (0x1f09, "\u{1f01}", "\u{1f09}", "\u{1f09}", "\u{1f01}"),
+    (0x1f0a, "\u{1f02}", "\u{1f0a}", "\u{1f0a}", "\u{1f02}"),
+    (0x1f0b, "\u{1f03}", "\u{1f0b}", "\u{1f0b}", "\u{1f03}"),
+    (0x1f0c, "\u{1f04}", "\u{1f0c}", "\u{1f0c}", "\u{1f04}"),
+    (0x1f0d, "\u{1f05}", "\u{1f0d}", "\u{1f0d}", "\u{1f05}"),
+    (0x1f0e, "\u{1f06}", "\u{1f0e}", "\u{1f0e}", "\u{1f06}"),
+    (0x1f0f, "\u{1f07}", "\u{1f0f}", "\u{1f0f}", "\u{1f07}"),
+    (0x1f10, "\u{1f10}", "\u{1f18}", "\u{1f18}", "\u{1f10}"),
+    (0x1f11, "\u{1f11}", "\u{1f19}", "\u{1f19}", "\u{1f11}"),
+    (0x1f12, "\u{1f12}", "\u{1f1a}", "\u{1f1a}", "\u{1f12}"),
+    (0x1f13, "\u{1f13}", "\u{1f1b}", "\u{1f1b}", "\u{1f13}"),
+    (0x1f14, "\u{1f14}", "\u{1f1c}", "\u{1f1c}", "\u{1f14}"),
+    (0x1f15, "\u{1f15}", "\u{1f1d}", "\u{1f1d}", "\u{1f15}"),
+    (0x1f18, "\u{1f10}", "\u{1f18}", "\u{1f18}", "\u{1f10}"),
+    (0x1f19, "\u{1f11}", "\u{1f19}", "\u{1f19}", "\u{1f11}"),
+    (0x1f1a, "\u{1f12}", "\u{1f1a}", "\u{1f1a}", "\u{1f12}"),
+    (0x1f1b, "\u{1f13}", "\u{1f1b}", "\u{1f1b}", "\u{1f13}"),
+    (0x1f1c, "\u{1f14}", "\u{1f1c}", "\u{1f1c}", "\u{1f14}"),
+    (0x1f1d, "\u{1f15}", "\u{1f1d}", "\u{1f1d}", "\u{1f15}"),
+    (0x1f20, "\u{1f20}", "\u{1f28}", "\u{1f28}", "\u{1f20}"),
+    (0x1f21, "\u{1f21}", "\u{1f29}", "\u{1f29}", "\u{1f21}"),
+    (0x1f22, "\u{1f22}", "\u{1f2a}", "\u{1f2a}", "\u{1f22}"),
+    (0x1f23, "\u{1f23}", "\u{1f2b}", "\u{1f2b}", "\u{1f23}"),
+    (0x1f24, "\u{1f24}", "\u{1f2c}", "\u{1f2c}", "\u{1f24}"),
+    (0x1f25, "\u{1f25}", "\u{1f2d}", "\u{1f2d}", "\u{1f25}"),
+    (0x1f26, "\u{1f26}", "\u{1f2e}", "\u{1f2e}", "\u{1f26}"),
+    (0x1f27, "\u{1f27}", "\u{1f2f}", "\u{1f2f}", "\u{1f27}"),
+    (0x1f28, "\u{1f20}", "\u{1f28}", "\u{1f28}", "\u{1f20}"),
+    (0x1f29, "\u{1f21}", "\u{1f29}", "\u{1f29}", "\u{1f21}"),
+    (0x1f2a, "\u{1f22}", "\u{1f2a}", "\u{1f2a}", "\u{1f22}"),
+    (0x1f2b, "\u{1f23}", "\u{1f2b}", "\u{1f2b}", "\u{1f23}"),
+    (0x1f2c, "\u{1f24}", "\u{1f2c}", "\u{1f2c}", "\u{1f24}"),
+    (0x1f2d, "\u{1f25}", "\u{1f2d}", "\u{1f2d}", "\u{1f25}"),
+    (0x1f2e, "\u{1f26}", "\u{1f2e}", "\u{1f2e}", "\u{1f26}"),
+    (0x1f2f, "\u{1f27}", "\u{1f2f}", "\u{1f2f}", "\u{1f27}"),
+    (0x1f30, "\u{1f30}", "\u{1f38}", "\u{1f38}", "\u{1f30}"),
+    (0x1f31, "\u{1f31}", "\u{1f39}", "\u{1f39}", "\u{1f31}"),
+    (0x1f32, "\u{1f32}", "\u{1f3a}", "\u{1f3a}", "\u{1f32}"),
+    (0x1f33, "\u{1f33}", "\u{1f3b}", "\u{1f3b}", "\u{1f33}"),
+    (0x1f34, "\u{1f34}", "\u{1f3c}", "\u{1f3c}", "\u{1f34}"),
+    (0x1f35, "\u{1f35}", "\u{1f3d}", "\u{1f3d}", "\u{1f35}"),
+    (0x1f36, "\u{1f36}", "\u{1f3e}", "\u{1f3e}", "\u{1f36}"),
+    (0x1f37, "\u{1f37}", "\u{1f3f}", "\u{1f3f}", "\u{1f37}"),
+    (0x1f38, "\u{1f30}", "\u{1f38}", "\u{1f38}", "\u{1f30}"),
+    (0x1f39, "\u{1f31}", "\u{1f39}", "\u{1f39}", "\u{1f31}"),
+    (0x1f3a, "\u{1f32}", "\u{1f3a}", "\u{1f3a}", "\u{1f32}"),
+    (0x1f3b, "\u{1f33}", "\u{1f3b}", "\u{1f3b}", "\u{1f33}"),
+    (0x1f3c, "\u{1f34}", "\u{1f3c}", "\u{1f3c}", "\u{1f34}"),
+    (0x1f3d, "\u{1f35}", "\u{1f3d}", "\u{1f3d}", "\u{1f35}"),
+    (0x1f3e, "\u{1f36}", "\u{1f3e}", "\u{1f3e}", "\u{1f36}"),
+    (0x1f3f, "\u{1f37}", "\u{1f3f}", "\u{1f3f}", "\u{1f37}"),
+    (0x1f40, "\u{1f40}", "\u{1f48}", "\u{1f48}", "\u{1f40}"),
+    (0x1f41, "\u{1f41}", "\u{1f49}", "\u{1f49}", "\u{1f41}"),
+    (0x1f42, "\u{1f42}", "\u{1f4a}", "\u{1f4a}", "\u{1f42}"),
+    (0x1f43, "\u{1f43}", "\u{1f4b}", "\u{1f4b}", "\u{1f43}"),
+    (0x1f44, "\u{1f44}", "\u{1f4c}", "\u{1f4c}", "\u{1f44}"),
+    (0x1f45, "\u{1f45}", "\u{1f4d}", "\u{1f4d}", "\u{1f45}"),
+    (0x1f48, "\u{1f40}", "\u{1f48}", "\u{1f48}", "\u{1f40}"),
+    (0x1f49, "\u{1f41}", "\u{1f49}", "\u{1f49}", "\u{1f41}"),
+    (0x1f4a, "\u{1f42}", "\u{1f4a}", "\u{1f4a}", "\u{1f42}"),
+    (0x1f4b, "\u{1f43}", "\u{1f4b}", "\u{1f4b}", "\u{1f43}"),
+    (0x1f4c, "\u{1f44}", "\u{1f4c}", "\u{1f4c}", "\u{1f44}"),
+    (0x1f4d, "\u{1f45}", "\u{1f4d}", "\u{1f4d}", "\u{1f45}"),
+    (0x1f50, "\u{1f50}", "\u{3a5}\u{313}", "\u{3a5}\u{313}", "\u{3c5}\u{313}"),
+    (0x1f51, "\u{1f51}", "\u{1f59}", "\u{1f59}", "\u{1f51}"),
+    (0x1f52, "\u{1f52}", "\u{3a5}\u{313}\u{300}", "\u{3a5}\u{313}\u{300}", "\u{3c5}\u{313}\u{300}"),
+    (0x1f53, "\u{1f53}", "\u{1f5b}", "\u{1f5b}", "\u{1f53}"),
+    (0x1f54, "\u{1f54}", "\u{3a5}\u{313}\u{301}", "\u{3a5}\u{313}\u{301}", "\u{3c5}\u{313}\u{301}"),
+    (0x1f55, "\u{1f55}", "\u{1f5d}", "\u{1f5d}", "\u{1f55}"),
+    (0x1f56, "\u{1f56}", "\u{3a5}\u{313}\u{342}", "\u{3a5}\u{313}\u{342}", "\u{3c5}\u{313}\u{342}"),
+    (0x1f57, "\u{1f57}", "\u{1f5f}", "\u{1f5f}", "\u{1f57}"),
+    (0x1f59, "\u{1f51}", "\u{1f59}", "\u{1f59}", "\u{1f51}"),
+    (0x1f5b, "\u{1f53}", "\u{1f5b}", "\u{1f5b}", "\u{1f53}"),
+    (0x1f5d, "\u{1f55}", "\u{1f5d}", "\u{1f5d}", "\u{1f55}"),
+    (0x1f5f, "\u{1f57}", "\u{1f5f}", "\u{1f5f}", "\u{1f57}"),
+    (0x1f60, "\u{1f60}", "\u{1f68}", "\u{1f68}", "\u{1f60}"),
+    (0x1f61, "\u{1f61}", "\u{1f69}", "\u{1f69}", "\u{1f61}"),
+    (0x1f62, "\u{1f62}", "\u{1f6a}", "\u{1f6a}", "\u{1f62}"),
+    (0x1f63, "\u{1f63}", "\u{1f6b}", "\u{1f6b}", "\u{1f63}"),
+    (0x1f64, "\u{1f64}", "\u{1f6c}", "\u{1f6c}", "\u{1f64}"),
+    (0x1f65, "\u{1f65}", "\u{1f6d}", "\u{1f6d}", "\u{1f65}"),
+    (0x1f66, "\u{1f66}", "\u{1f6e}", "\u{1f6e}", "\u{1f66}"),
+    (0x1f67, "\u{1f67}", "\u{1f6f}", "\u{1f6f}", "\u{1f67}"),
+    (0x1f68, "\u{1f60}", "\u{1f68}", "\u{1f68}", "\u{1f60}"),
+    (0x1f69, "\u{1f61}", "\u{1f69}", "\u{1f69}", "\u{1f61}"),
+    (0x1f6a, "\u{1f62}", "\u{1f6a}", "\u{1f6a}", "\u{1f62}"),
+    (0x1f6b, "\u{1f63}", "\u{1f6b}", "\u{1f6b}", "\u{1f63}"),
+    (0x1f6c, "\u{1f64}", "\u{1f6c}", "\u{1f6c}", "\u{1f64}"),
+    (0x1f6d, "\u{1f65}", "\u{1f6d}", "\u{1f6d}", "\u{1f65}"),
+    (0x1f6e, "\u{1f66}", "\u{1f6e}", "\u{1f6e}", "\u{1f66}"),
+    (0x1f6f, "\u{1f67}", "\u{1f6f}", "\u{1f6f}", "\u{1f67}"),
+    (0x1f70, "\u{1f70}", "\u{1fba}", "\u{1fba}", "\u{1f70}"),
+    (0x1f71, "\u{1f71}", "\u{1fbb}", "\u{1fbb}", "\u{1f71}"),
+    (0x1f72, "\u{1f72}", "\u{1fc8}", "\u{1fc8}", "\u{1f72}"),
+    (0x1f73, "\u{1f73}", "\u{1fc9}", "\u{1fc9}", "\u{1f73}"),
+    (0x1f74, "\u{1f74}", "\u{1fca}", "\u{1fca}", "\u{1f74}"),
+    (0x1f75, "\u{1f75}", "\u{1fcb}", "\u{1fcb}", "\u{1f75}"),
+    (0x1f76, "\u{1f76}", "\u{1fda}", "\u{1fda}", "\u{1f76}"),
+    (0x1f77, "\u{1f77}", "\u{1fdb}", "\u{1fdb}", "\u{1f77}"),
+    (0x1f78, "\u{1f78}", "\u{1ff8}", "\u{1ff8}", "\u{1f78}"),
+    (0x1f79, "\u{1f79}", "\u{1ff9}", "\u{1ff9}", "\u{1f79}"),
+    (0x1f7a, "\u{1f7a}", "\u{1fea}", "\u{1fea}", "\u{1f7a}"),
+    (0x1f7b, "\u{1f7b}", "\u{1feb}", "\u{1feb}", "\u{1f7b}"),
+    (0x1f7c, "\u{1f7c}", "\u{1ffa}", "\u{1ffa}", "\u{1f7c}"),
+    (0x1f7d, "\u{1f7d}", "\u{1ffb}", "\u{1ffb}", "\u{1f7d}"),
+    (0x1f80, "\u{1f80}", "\u{1f08}\u{399}", "\u{1f88}", "\u{1f00}\u{3b9}"),
+    (0x1f81, "\u{1f81}", "\u{1f09}\u{399}", "\u{1f89}", "\u{1f01}\u{3b9}"),
+    (0x1f82, "\u{1f82}", "\u{1f0a}\u{399}", "\u{1f8a}", "\u{1f02}\u{3b9}"),
+    (0x1f83, "\u{1f83}", "\u{1f0b}\u{399}", "\u{1f8b}", "\u{1f03}\u{3b9}"),
+    (0x1f84, "\u{1f84}", "\u{1f0c}\u{399}", "\u{1f8c}", "\u{1f04}\u{3b9}"),
+    (0x1f85, "\u{1f85}", "\u{1f0d}\u{399}", "\u{1f8d}", "\u{1f05}\u{3b9}"),
+    (0x1f86, "\u{1f86}", "\u{1f0e}\u{399}", "\u{1f8e}", "\u{1f06}\u{3b9}"),
+    (0x1f87, "\u{1f87}", "\u{1f0f}\u{399}", "\u{1f8f}", "\u{1f07}\u{3b9}"),
+    (0x1f88, "\u{1f80}", "\u{1f08}\u{399}", "\u{1f88}", "\u{1f00}\u{3b9}"),
+    (0x1f89, "\u{1f81}", "\u{1f09}\u{399}", "\u{1f89}", "\u{1f01}\u{3b9}"),
+    (0x1f8a, "\u{1f82}", "\u{1f0a}\u{399}", "\u{1f8a}", "\u{1f02}\u{3b9}"),
+    (0x1f8b, "\u{1f83}", "\u{1f0b}\u{399}", "\u{1f8b}", "\u{1f03}\u{3b9}"),
+    (0x1f8c, "\u{1f84}", "\u{1f0c}\u{399}", "\u{1f8c}", "\u{1f04}\u{3b9}"),
+    (0x1f8d, "\u{1f85}", "\u{1f0d}\u{399}", "\u{1f8d}", "\u{1f05}\u{3b9}"),
+    (0x1f8e, "\u{1f86}", "\u{1f0e}\u{399}", "\u{1f8e}", "\u{1f06}\u{3b9}"),
+    (0x1f8f, "\u{1f87}", "\u{1f0f}\u{399}", "\u{1f8f}", "\u{1f07}\u{3b9}"),
+    (0x1f90, "\u{1f90}", "\u{1f28}\u{399}", "\u{1f98}", "\u{1f20}\u{3b9}"),
+    (0x1f91, "\u{1f91}", "\u{1f29}\u{399}", "\u{1f99}", "\u{1f21}\u{3b9}"),
+    (0x1f92, "\u{1f92}", "\u{1f2a}\u{399}", "\u{1f9a}", "\u{1f22}\u{3b9}"),
+    (0x1f93, "\u{1f93}", "\u{1f2b}\u{399}", "\u{1f9b}", "\u{1f23}\u{3b9}"),
+    (0x1f94, "\u{1f94}", "\u{1f2c}\u{399}", "\u{1f9c}", "\u{1f24}\u{3b9}"),
+    (0x1f95, "\u{1f95}", "\u{1f2d}\u{399}", "\u{1f9d}", "\u{1f25}\u{3b9}"),
+    (0x1f96, "\u{1f96}", "\u{1f2e}\u{399}", "\u{1f9e}", "\u{1f26}\u{3b9}"),
+    (0x1f97, "\u{1f97}", "\u{1f2f}\u{399}", "\u{1f9f}", "\u{1f27}\u{3b9}"),
+    (0x1f98, "\u{1f90}", "\u{1f28}\u{399}", "\u{1f98}", "\u{1f20}\u{3b9}"),
+    (0x1f99, "\u{1f91}", "\u{1f29}\u{399}", "\u{1f99}", "\u{1f21}\u{3b9}"),
+    (0x1f9a, "\u{1f92}", "\u{1f2a}\u{399}", "\u{1f9a}", "\u{1f22}\u{3b9}"),
+    (0x1f9b, "\u{1f93}", "\u{1f2b}\u{399}", "\u{1f9b}", "\u{1f23}\u{3b9}"),
+    (0x1f9c, "\u{1f94}", "\u{1f2c}\u{399}", "\u{1f9c}", "\u{1f24}\u{3b9}"),
+    (0x1f9d, "\u{1f95}", "\u{1f2d}\u{399}", "\u{1f9d}", "\u{1f25}\u{3b9}"),
+    (0x1f9e, "\u{1f96}", "\u{1f2e}\u{399}", "\u{1f9e}", "\u{1f26}\u{3b9}"),
+    (0x1f9f, "\u{1f97}", "\u{1f2f}\u{399}", "\u{1f9f}", "\u{1f27}\u{3b9}"),
+    (0x1fa0, "\u{1fa0}", "\u{1f68}\u{399}", "\u{1fa8}", "\u{1f60}\u{3b9}"),
+    (0x1fa1, "\u{1fa1}", "\u{1f69}\u{399}", "\u{1fa9}", "\u{1f61}\u{3b9}"),
+    (0x1fa2, "\u{1fa2}", "\u{1f6a}\u{399}", "\u{1faa}", "\u{1f62}\u{3b9}"),
+    (0x1fa3, "\u{1fa3}", "\u{1f6b}\u{399}", "\u{1fab}", "\u{1f63}\u{3b9}"),
+    (0x1fa4, "\u{1fa4}", "\u{1f6c}\u{399}", "\u{1fac}", "\u{1f64}\u{3b9}"),
+    (0x1fa5, "\u{1fa5}", "\u{1f6d}\u{399}", "\u{1fad}", "\u{1f65}\u{3b9}"),
+    (0x1fa6, "\u{1fa6}", "\u{1f6e}\u{399}", "\u{1fae}", "\u{1f66}\u{3b9}"),
+    (0x1fa7, "\u{1fa7}", "\u{1f6f}\u{399}", "\u{1faf}", "\u{1f67}\u{3b9}"),
+    (0x1fa8, "\u{1fa0}", "\u{1f68}\u{399}", "\u{1fa8}", "\u{1f60}\u{3b9}"),
+    (0x1fa9, "\u{1fa1}", "\u{1f69}\u{399}", "\u{1fa9}", "\u{1f61}\u{3b9}"),
+    (0x1faa, "\u{1fa2}", "\u{1f6a}\u{399}", "\u{1faa}", "\u{1f62}\u{3b9}"),
+    (0x1fab, "\u{1fa3}", "\u{1f6b}\u{399}", "\u{1fab}", "\u{1f63}\u{3b9}"),
+    (0x1fac, "\u{1fa4}", "\u{1f6c}\u{399}", "\u{1fac}", "\u{1f64}\u{3b9}"),
+    (0x1fad, "\u{1fa5}", "\u{1f6d}\u{399}", "\u{1fad}", "\u{1f65}\u{3b9}"),
+    (0x1fae, "\u{1fa6}", "\u{1f6e}\u{399}", "\u{1fae}", "\u{1f66}\u{3b9}"),
+    (0x1faf, "\u{1fa7}", "\u{1f6f}\u{399}", "\u{1faf}", "\u{1f67}\u{3b9}"),
+    (0x1fb0, "\u{1fb0}", "\u{1fb8}", "\u{1fb8}", "\u{1fb0}"),
+    (0x1fb1, "\u{1fb1}", "\u{1fb9}", "\u{1fb9}", "\u{1fb1}"),
+    (0x1fb2, "\u{1fb2}", "\u{1fba}\u{399}", "\u{1fba}\u{345}", "\u{1f70}\u{3b9}"),
+    (0x1fb3, "\u{1fb3}", "\u{391}\u{399}", "\u{1fbc}", "\u{3b1}\u{3b9}"),
+    (0x1fb4, "\u{1fb4}", "\u{386}\u{399}", "\u{386}\u{345}", "\u{3ac}\u{3b9}"),
+    (0x1fb6, "\u{1fb6}", "\u{391}\u{342}", "\u{391}\u{342}", "\u{3b1}\u{342}"),
+    (0x1fb7, "\u{1fb7}", "\u{391}\u{342}\u{399}", "\u{391}\u{342}\u{345}", "\u{3b1}\u{342}\u{3b9}"),
+    (0x1fb8, "\u{1fb0}", "\u{1fb8}", "\u{1fb8}", "\u{1fb0}"),
+    (0x1fb9, "\u{1fb1}", "\u{1fb9}", "\u{1fb9}", "\u{1fb1}"),
+    (0x1fba, "\u{1f70}", "\u{1fba}", "\u{1fba}", "\u{1f70}"),
+    (0x1fbb, "\u{1f71}", "\u{1fbb}", "\u{1fbb}", "\u{1f71}"),
+    (0x1fbc, "\u{1fb3}", "\u{391}\u{399}", "\u{1fbc}", "\u{3b1}\u{3b9}"),
+    (0x1fbe, "\u{1fbe}", "\u{399}", "\u{399}", "\u{3b9}"),
+    (0x1fc2, "\u{1fc2}", "\u{1fca}\u{399}", "\u{1fca}\u{345}", "\u{1f74}\u{3b9}"),
+    (0x1fc3, "\u{1fc3}", "\u{397}\u{399}", "\u{1fcc}", "\u{3b7}\u{3b9}"),
+    (0x1fc4, "\u{1fc4}", "\u{389}\u{399}", "\u{389}\u{345}", "\u{3ae}\u{3b9}"),
+    (0x1fc6, "\u{1fc6}", "\u{397}\u{342}", "\u{397}\u{342}", "\u{3b7}\u{342}"),
+    (0x1fc7, "\u{1fc7}", "\u{397}\u{342}\u{399}", "\u{397}\u{342}\u{345}", "\u{3b7}\u{342}\u{3b9}"),
+    (0x1fc8, "\u{1f72}", "\u{1fc8}", "\u{1fc8}", "\u{1f72}"),
+    (0x1fc9, "\u{1f73}", "\u{1fc9}", "\u{1fc9}", "\u{1f73}"),
+    (0x1fca, "\u{1f74}", "\u{1fca}", "\u{1fca}", "\u{1f74}"),
+    (0x1fcb, "\u{1f75}", "\u{1fcb}", "\u{1fcb}", "\u{1f75}"),
+    (0x1fcc, "\u{1fc3}", "\u{397}\u{399}", "\u{1fcc}", "\u{3b7}\u{3b9}"),
+    (0x1fd0, "\u{1fd0}", "\u{1fd8}", "\u{1fd8}", "\u{1fd0}"),
+    (0x1fd1, "\u{1fd1}", "\u{1fd9}", "\u{1fd9}", "\u{1fd1}"),
+    (0x1fd2, "\u{1fd2}", "\u{399}\u{308}\u{300}", "\u{399}\u{308}\u{300}", "\u{3b9}\u{308}\u{300}"),
+    (0x1fd3, "\u{1fd3}", "\u{399}\u{308}\u{301}", "\u{399}\u{308}\u{301}", "\u{3b9}\u{308}\u{301}"),
+    (0x1fd6, "\u{1fd6}", "\u{399}\u{342}", "\u{399}\u{342}", "\u{3b9}\u{342}"),
+    (0x1fd7, "\u{1fd7}", "\u{399}\u{308}\u{342}", "\u{399}\u{308}\u{342}", "\u{3b9}\u{308}\u{342}"),
+    (0x1fd8, "\u{1fd0}", "\u{1fd8}", "\u{1fd8}", "\u{1fd0}"),
+    (0x1fd9, "\u{1fd1}", "\u{1fd9}", "\u{1fd9}", "\u{1fd1}"),
+    (0x1fda, "\u{1f76}", "\u{1fda}", "\u{1fda}", "\u{1f76}"),
+    (0x1fdb, "\u{1f77}", "\u{1fdb}", "\u{1fdb}", "\u{1f77}"),
+    (0x1fe0, "\u{1fe0}", "\u{1fe8}", "\u{1fe8}", "\u{1fe0}"),
+    (0x1fe1, "\u{1fe1}", "\u{1fe9}", "\u{1fe9}", "\u{1fe1}"),
+    (0x1fe2, "\u{1fe2}", "\u{3a5}\u{308}\u{300}", "\u{3a5}\u{308}\u{300}", "\u{3c5}\u{308}\u{300}"),
+    (0x1fe3, "\u{1fe3}", "\u{3a5}\u{308}\u{301}", "\u{3a5}\u{308}\u{301}", "\u{3c5}\u{308}\u{301}"),
+    (0x1fe4, "\u{1fe4}", "\u{3a1}\u{313}", "\u{3a1}\u{313}", "\u{3c1}\u{313}"),
+    (0x1fe5, "\u{1fe5}", "\u{1fec}", "\u{1fec}", "\u{1fe5}"),
+    (0x1fe6, "\u{1fe6}", "\u{3a5}\u{342}", "\u{3a5}\u{342}", "\u{3c5}\u{342}"),
+    (0x1fe7, "\u{1fe7}", "\u{3a5}\u{308}\u{342}", "\u{3a5}\u{308}\u{342}", "\u{3c5}\u{308}\u{342}"),
+    (0x1fe8, "\u{1fe0}", "\u{1fe8}", "\u{1fe8}", "\u{1fe0}"),
+    (0x1fe9, "\u{1fe1}", "\u{1fe9}", "\u{1fe9}", "\u{1fe1}"),
+    (0x1fea, "\u{1f7a}", "\u{1fea}", "\u{1fea}", "\u{1f7a}"),
+    (0x1feb, "\u{1f7b}", "\u{1feb}", "\u{1feb}", "\u{1f7b}"),
+    (0x1fec, "\u{1fe5}", "\u{1fec}", "\u{1fec}", "\u{1fe5}"),
+    (0x1ff2, "\u{1ff2}", "\u{1ffa}\u{399}", "\u{1ffa}\u{345}", "\u{1f7c}\u{3b9}"),
+    (0x1ff3, "\u{1ff3}", "\u{3a9}\u{399}", "\u{1ffc}", "\u{3c9}\u{3b9}"),
+    (0x1ff4, "\u{1ff4}", "\u{38f}\u{399}", "\u{38f}\u{345}", "\u{3ce}\u{3b9}"),
+    (0x1ff6, "\u{1ff6}", "\u{3a9}\u{342}", "\u{3a9}\u{342}", "\u{3c9}\u{342}"),
+    (0x1ff7, "\u{1ff7}", "\u{3a9}\u{342}\u{399}", "\u{3a9}\u{342}\u{345}", "\u{3c9}\u{342}\u{3b9}"),
+    (0x1ff8, "\u{1f78}", "\u{1ff8}", "\u{1ff8}", "\u{1f78}"),
+    (0x1ff9, "\u{1f79}", "\u{1ff9}", "\u{1ff9}", "\u{1f79}"),
+    (0x1ffa, "\u{1f7c}", "\u{1ffa}", "\u{1ffa}", "\u{1f7c}"),
+    (0x1ffb, "\u{1f7d}", "\u{1ffb}", "\u{1ffb}", "\u{1f7d}"),
+    (0x1ffc, "\u{1ff3}", "\u{3a9}\u{399}", "\u{1ffc}", "\u{3c9}\u{3b9}"),
+    (0x2126, "\u{3c9}", "\u{2126}", "\u{2126}", "\u{3c9}"),
+    (0x212a, "k", "\u{212a}", "\u{212a}", "k"),
+    (0x212b, "\u{e5}", "\u{212b}", "\u{212b}", "\u{e5}"),
+    (0x2132, "\u{214e}", "\u{2132}", "\u{2132}", "\u{214e}"),
+    (0x214e, "\u{214e}", "\u{2132}", "\u{2132}", "\u{214e}"),
+    (0x2160, "\u{2170}", "\u{2160}", "\u{2160}", "\u{2170}"),
+    (0x2161, "\u{2171}", "\u{2161}", "\u{2161}", "\u{2171}"),
+    (0x2162, "\u{2172}", "\u{2162}", "\u{2162}", "\u{2172}"),
+    (0x2163, "\u{2173}", "\u{2163}", "\u{2163}", "\u{2173}"),
+    (0x2164, "\u{2174}", "\u{2164}", "\u{2164}", "\u{2174}"),
+    (0x2165, "\u{2175}", "\u{2165}", "\u{2165}", "\u{2175}"),
+    (0x2166, "\u{2176}", "\u{2166}", "\u{2166}", "\u{2176}"),
+    (0x2167, "\u{2177}", "\u{2167}", "\u{2167}", "\u{2177}"),
+    (0x2168, "\u{2178}", "\u{2168}", "\u{2168}", "\u{2178}"),
+    (0x2169, "\u{2179}", "\u{2169}", "\u{2169}", "\u{2179}"),
+    (0x216a, "\u{217a}", "\u{216a}", "\u{216a}", "\u{217a}"),
+    (0x216b, "\u{217b}", "\u{216b}", "\u{216b}", "\u{217b}"),
+    (0x216c, "\u{217c}", "\u{216c}", "\u{216c}", "\u{217c}"),
+    (0x216d, "\u{217d}", "\u{216d}", "\u{216d}", "\u{217d}"),
+    (0x216e, "\u{217e}", "\u{216e}", "\u{216e}", "\u{217e}"),
+    (0x216f, "\u{217f}", "\u{216f}", "\u{216f}", "\u{217f}"),
+    (0x2170, "\u{2170}", "\u{2160}", "\u{2160}", "\u{2170}"),
+    (0x2171, "\u{2171}", "\u{2161}", "\u{2161}", "\u{2171}"),
+    (0x2172, "\u{2172}", "\u{2162}", "\u{2162}", "\u{2172}"),
+    (0x2173, "\u{2173}", "\u{2163}", "\u{2163}", "\u{2173}"),
+    (0x2174, "\u{2174}", "\u{2164}", "\u{2164}", "\u{2174}"),
+    (0x2175, "\u{2175}", "\u{2165}", "\u{2165}", "\u{2175}"),
+    (0x2176, "\u{2176}", "\u{2166}", "\u{2166}", "\u{2176}"),
+    (0x2177, "\u{2177}", "\u{2167}", "\u{2167}", "\u{2177}"),
+    (0x2178, "\u{2178}", "\u{2168}", "\u{2168}", "\u{2178}"),
+    (0x2179, "\u{2179}", "\u{2169}", "\u{2169}", "\u{2179}"),
+    (0x217a, "\u{217a}", "\u{216a}", "\u{216a}", "\u{217a}"),
+    (0x217b, "\u{217b}", "\u{216b}", "\u{216b}", "\u{217b}"),
+    (0x217c, "\u{217c}", "\u{216c}", "\u{216c}", "\u{217c}"),
+    (0x217d, "\u{217d}", "\u{216d}", "\u{216d}", "\u{217d}"),
+    (0x217e, "\u{217e}", "\u{216e}", "\u{216e}", "\u{217e}"),
+    (0x217f, "\u{217f}", "\u{216f}", "\u{216f}", "\u{217f}"),
+    (0x2183, "\u{2184}", "\u{2183}", "\u{2183}", "\u{2184}"),
+    (0x2184, "\u{2184}", "\u{2183}", "\u{2183}", "\u{2184}"),
+    (0x24b6, "\u{24d0}", "\u{24b6}", "\u{24b6}", "\u{24d0}"),
+    (0x24b7, "\u{24d1}", "\u{24b7}", "\u{24b7}", "\u{24d1}"),
+    (0x24b8, "\u{24d2}", "\u{24b8}", "\u{24b8}", "\u{24d2}"),
+    (0x24b9, "\u{24d3}", "\u{24b9}", "\u{24b9}", "\u{24d3}"),
+    (0x24ba, "\u{24d4}", "\u{24ba}", "\u{24ba}", "\u{24d4}"),
+    (0x24bb, "\u{24d5}", "\u{24bb}", "\u{24bb}", "\u{24d5}"),
+    (0x24bc, "\u{24d6}", "\u{24bc}", "\u{24bc}", "\u{24d6}"),
+    (0x24bd, "\u{24d7}", "\u{24bd}", "\u{24bd}", "\u{24d7}"),
+    (0x24be, "\u{24d8}", "\u{24be}", "\u{24be}", "\u{24d8}"),
+    (0x24bf, "\u{24d9}", "\u{24bf}", "\u{24bf}", "\u{24d9}"),
+    (0x24c0, "\u{24da}", "\u{24c0}", "\u{24c0}", "\u{24da}"),
+    (0x24c1, "\u{24db}", "\u{24c1}", "\u{24c1}", "\u{24db}"),
+    (0x24c2, "\u{24dc}", "\u{24c2}", "\u{24c2}", "\u{24dc}"),
+    (0x24c3, "\u{24dd}", "\u{24c3}", "\u{24c3}", "\u{24dd}"),
+    (0x24c4, "\u{24de}", "\u{24c4}", "\u{24c4}", "\u{24de}"),
+    (0x24c5, "\u{24df}", "\u{24c5}", "\u{24c5}", "\u{24df}"),
+    (0x24c6, "\u{24e0}", "\u{24c6}", "\u{24c6}", "\u{24e0}"),
+    (0x24c7, "\u{24e1}", "\u{24c7}", "\u{24c7}", "\u{24e1}"),
+    (0x24c8, "\u{24e2}", "\u{24c8}", "\u{24c8}", "\u{24e2}"),
+    (0x24c9, "\u{24e3}", "\u{24c9}", "\u{24c9}", "\u{24e3}"),
+    (0x24ca, "\u{24e4}", "\u{24ca}", "\u{24ca}", "\u{24e4}"),
+    (0x24cb, "\u{24e5}", "\u{24cb}", "\u{24cb}", "\u{24e5}"),
+    (0x24cc, "\u{24e6}", "\u{24cc}", "\u{24cc}", "\u{24e6}"),
+    (0x24cd, "\u{24e7}", "\u{24cd}", "\u{24cd}", "\u{24e7}"),
+    (0x24ce, "\u{24e8}", "\u{24ce}", "\u{24ce}", "\u{24e8}"),
+    (0x24cf, "\u{24e9}", "\u{24cf}", "\u{24cf}", "\u{24e9}"),
+    (0x24d0, "\u{24d0}", "\u{24b6}", "\u{24b6}", "\u{24d0}"),
+    (0x24d1, "\u{24d1}", "\u{24b7}", "\u{24b7}", "\u{24d1}"),
+    (0x24d2, "\u{24d2}", "\u{24b8}", "\u{24b8}", "\u{24d2}"),
+    (0x24d3, "\u{24d3}", "\u{24b9}", "\u{24b9}", "\u{24d3}"),
+    (0x24d4, "\u{24d4}", "\u{24ba}", "\u{24ba}", "\u{24d4}"),
+    (0x24d5, "\u{24d5}", "\u{24bb}", "\u{24bb}", "\u{24d5}"),
+    (0x24d6, "\u{24d6}", "\u{24bc}", "\u{24bc}", "\u{24d6}"),
+    (0x24d7, "\u{24d7}", "\u{24bd}", "\u{24bd}", "\u{24d7}"),
+    (0x24d8, "\u{24d8}", "\u{24be}", "\u{24be}", "\u{24d8}"),
+    (0x24d9, "\u{24d9}", "\u{24bf}", "\u{24bf}", "\u{24d9}"),
+    (0x24da, "\u{24da}", "\u{24c0}", "\u{24c0}", "\u{24da}"),
+    (0x24db, "\u{24db}", "\u{24c1}", "\u{24c1}", "\u{24db}"),
+    (0x24dc, "\u{24dc}", "\u{24c2}", "\u{24c2}", "\u{24dc}"),
+    (0x24dd, "\u{24dd}", "\u{24c3}", "\u{24c3}", "\u{24dd}"),
+    (0x24de, "\u{24de}", "\u{24c4}", "\u{24c4}", "\u{24de}"),
+    (0x24df, "\u{24df}", "\u{24c5}", "\u{24c5}", "\u{24df}"),
+    (0x24e0, "\u{24e0}", "\u{24c6}", "\u{24c6}", "\u{24e0}"),
+    (0x24e1, "\u{24e1}", "\u{24c7}", "\u{24c7}", "\u{24e1}"),
+    (0x24e2, "\u{24e2}", "\u{24c8}", "\u{24c8}", "\u{24e2}"),
+    (0x24e3, "\u{24e3}", "\u{24c9}", "\u{24c9}", "\u{24e3}"),
+    (0x24e4, "\u{24e4}", "\u{24ca}", "\u{24ca}", "\u{24e4}"),
+    (0x24e5, "\u{24e5}", "\u{24cb}", "\u{24cb}", "\u{24e5}"),
+    (0x24e6, "\u{24e6}", "\u{24cc}", "\u{24cc}", "\u{24e6}"),
+    (0x24e7, "\u{24e7}", "\u{24cd}", "\u{24cd}", "\u{24e7}"),
+    (0x24e8, "\u{24e8}", "\u{24ce}", "\u{24ce}", "\u{24e8}"),
+    (0x24e9, "\u{24e9}", "\u{24cf}", "\u{24cf}", "\u{24e9}"),
+    (0x2c00, "\u{2c30}", "\u{2c00}", "\u{2c00}", "\u{2c30}"),
+    (0x2c01, "\u{2c31}", "\u{2c01}", "\u{2c01}", "\u{2c31}"),
+    (0x2c02, "\u{2c32}", "\u{2c02}", "\u{2c02}", "\u{2c32}"),
+    (0x2c03, "\u{2c33}", "\u{2c03}", "\u{2c03}", "\u{2c33}"),
+    (0x2c04, "\u{2c34}", "\u{2c04}", "\u{2c04}", "\u{2c34}"),
+    (0x2c05, "\u{2c35}", "\u{2c05}", "\u{2c05}", "\u{2c35}"),
+    (0x2c06, "\u{2c36}", "\u{2c06}", "\u{2c06}", "\u{2c36}"),
+    (0x2c07, "\u{2c37}", "\u{2c07}", "\u{2c07}", "\u{2c37}"),
+    (0x2c08, "\u{2c38}", "\u{2c08}", "\u{2c08}", "\u{2c38}"),
+    (0x2c09, "\u{2c39}", "\u{2c09}", "\u{2c09}", "\u{2c39}"),
+    (0x2c0a, "\u{2c3a}", "\u{2c0a}", "\u{2c0a}", "\u{2c3a}"),
+    (0x2c0b, "\u{2c3b}", "\u{2c0b}", "\u{2c0b}", "\u{2c3b}"),
+    (0x2c0c, "\u{2c3c}", "\u{2c0c}", "\u{2c0c}", "\u{2c3c}"),
+    (0x2c0d, "\u{2c3d}", "\u{2c0d}", "\u{2c0d}", "\u{2c3d}"),
+    (0x2c0e, "\u{2c3e}", "\u{2c0e}", "\u{2c0e}", "\u{2c3e}"),
+    (0x2c0f, "\u{2c3f}", "\u{2c0f}", "\u{2c0f}", "\u{2c3f}"),
+    (0x2c10, "\u{2c40}", "\u{2c10}", "\u{2c10}", "\u{2c40}"),
+    (0x2c11, "\u{2c41}", "\u{2c11}", "\u{2c11}", "\u{2c41}"),
+    (0x2c12, "\u{2c42}", "\u{2c12}", "\u{2c12}", "\u{2c42}"),
+    (0x2c13, "\u{2c43}", "\u{2c13}", "\u{2c13}", "\u{2c43}"),
+    (0x2c14, "\u{2c44}", "\u{2c14}", "\u{2c14}", "\u{2c44}"),
+    (0x2c15, "\u{2c45}", "\u{2c15}", "\u{2c15}", "\u{2c45}"),
+    (0x2c16, "\u{2c46}", "\u{2c16}", "\u{2c16}", "\u{2c46}"),
+    (0x2c17, "\u{2c47}", "\u{2c17}", "\u{2c17}", "\u{2c47}"),
+    (0x2c18, "\u{2c48}", "\u{2c18}", "\u{2c18}", "\u{2c48}"),
+    (0x2c19, "\u{2c49}", "\u{2c19}", "\u{2c19}", "\u{2c49}"),
+    (0x2c1a, "\u{2c4a}", "\u{2c1a}", "\u{2c1a}", "\u{2c4a}"),
+    (0x2c1b, "\u{2c4b}", "\u{2c1b}", "\u{2c1b}", "\u{2c4b}"),
+    (0x2c1c, "\u{2c4c}", "\u{2c1c}", "\u{2c1c}", "\u{2c4c}"),
+    (0x2c1d, "\u{2c4d}", "\u{2c1d}", "\u{2c1d}", "\u{2c4d}"),
+    (0x2c1e, "\u{2c4e}", "\u{2c1e}", "\u{2c1e}", "\u{2c4e}"),
+    (0x2c1f, "\u{2c4f}", "\u{2c1f}", "\u{2c1f}", "\u{2c4f}"),
+    (0x2c20, "\u{2c50}", "\u{2c20}", "\u{2c20}", "\u{2c50}"),
+    (0x2c21, "\u{2c51}", "\u{2c21}", "\u{2c21}", "\u{2c51}"),
+    (0x2c22, "\u{2c52}", "\u{2c22}", "\u{2c22}", "\u{2c52}"),
+    (0x2c23, "\u{2c53}", "\u{2c23}", "\u{2c23}", "\u{2c53}"),
+    (0x2c24, "\u{2c54}", "\u{2c24}", "\u{2c24}", "\u{2c54}"),
+    (0x2c25, "\u{2c55}", "\u{2c25}", "\u{2c25}", "\u{2c55}"),
+    (0x2c26, "\u{2c56}", "\u{2c26}", "\u{2c26}", "\u{2c56}"),
+    (0x2c27, "\u{2c57}", "\u{2c27}", "\u{2c27}", "\u{2c57}"),
+    (0x2c28, "\u{2c58}", "\u{2c28}", "\u{2c28}", "\u{2c58}"),
+    (0x2c29, "\u{2c59}", "\u{2c29}", "\u{2c29}", "\u{2c59}"),
+    (0x2c2a, "\u{2c5a}", "\u{2c2a}", "\u{2c2a}", "\u{2c5a}"),
+    (0x2c2b, "\u{2c5b}", "\u{2c2b}", "\u{2c2b}", "\u{2c5b}"),
+    (0x2c2c, "\u{2c5c}", "\u{2c2c}", "\u{2c2c}", "\u{2c5c}"),
+    (0x2c2d, "\u{2c5d}", "\u{2c2d}", "\u{2c2d}", "\u{2c5d}"),
+    (0x2c2e, "\u{2c5e}", "\u{2c2e}", "\u{2c2e}", "\u{2c5e}"),
+    (0x2c2f, "\u{2c5f}", "\u{2c2f}", "\u{2c2f}", "\u{2c5f}"),
+    (0x2c30, "\u{2c30}", "\u{2c00}", "\u{2c00}", "\u{2c30}"),
+    (0x2c31, "\u{2c31}", "\u{2c01}", "\u{2c01}", "\u{2c31}"),
+    (0x2c32, "\u{2c32}", "\u{2c02}", "\u{2c02}", "\u{2c32}"),
+    (0x2c33, "\u{2c33}", "\u{2c03}", "\u{2c03}", "\u{2c33}"),
+    (0x2c34, "\u{2c34}", "\u{2c04}", "\u{2c04}", "\u{2c34}"),
+    (0x2c35, "\u{2c35}", "\u{2c05}", "\u{2c05}", "\u{2c35}"),
+    (0x2c36, "\u{2c36}", "\u{2c06}", "\u{2c06}", "\u{2c36}"),
+    (0x2c37, "\u{2c37}", "\u{2c07}", "\u{2c07}", "\u{2c37}"),
+    (0x2c38, "\u{2c38}", "\u{2c08}", "\u{2c08}", "\u{2c38}"),
+    (0x2c39, "\u{2c39}", "\u{2c09}", "\u{2c09}", "\u{2c39}"),
+    (0x2c3a, "\u{2c3a}", "\u{2c0a}", "\u{2c0a}", "\u{2c3a}"),
+    (0x2c3b, "\u{2c3b}", "\u{2c0b}", "\u{2c0b}", "\u{2c3b}"),
+    (0x2c3c, "\u{2c3c}", "\u{2c0c}", "\u{2c0c}", "\u{2c3c}"),
+    (0x2c3d, "\u{2c3d}", "\u{2c0d}", "\u{2c0d}", "\u{2c3d}"),
+    (0x2c3e, "\u{2c3e}", "\u{2c0e}", "\u{2c0e}", "\u{2c3e}"),
+    (0x2c3f, "\u{2c3f}", "\u{2c0f}", "\u{2c0f}", "\u{2c3f}"),
+    (0x2c40, "\u{2c40}", "\u{2c10}", "\u{2c10}", "\u{2c40}"),
+    (0x2c41, "\u{2c41}", "\u{2c11}", "\u{2c11}", "\u{2c41}"),
+    (0x2c42, "\u{2c42}", "\u{2c12}", "\u{2c12}", "\u{2c42}"),
+    (0x2c43, "\u{2c43}", "\u{2c13}", "\u{2c13}", "\u{2c43}"),
+    (0x2c44, "\u{2c44}", "\u{2c14}", "\u{2c14}", "\u{2c44}"),
+    (0x2c45, "\u{2c45}", "\u{2c15}", "\u{2c15}", "\u{2c45}"),
+    (0x2c46, "\u{2c46}", "\u{2c16}", "\u{2c16}", "\u{2c46}"),
+    (0x2c47, "\u{2c47}", "\u{2c17}", "\u{2c17}", "\u{2c47}"),
+    (0x2c48, "\u{2c48}", "\u{2c18}", "\u{2c18}", "\u{2c48}"),
+    (0x2c49, "\u{2c49}", "\u{2c19}", "\u{2c19}", "\u{2c49}"),
+    (0x2c4a, "\u{2c4a}", "\u{2c1a}", "\u{2c1a}", "\u{2c4a}"),
+    (0x2c4b, "\u{2c4b}", "\u{2c1b}", "\u{2c1b}", "\u{2c4b}"),
+    (0x2c4c, "\u{2c4c}", "\u{2c1c}", "\u{2c1c}", "\u{2c4c}"),
+    (0x2c4d, "\u{2c4d}", "\u{2c1d}", "\u{2c1d}", "\u{2c4d}"),
+    (0x2c4e, "\u{2c4e}", "\u{2c1e}", "\u{2c1e}", "\u{2c4e}"),
+    (0x2c4f, "\u{2c4f}", "\u{2c1f}", "\u{2c1f}", "\u{2c4f}"),
+    (0x2c50, "\u{2c50}", "\u{2c20}", "\u{2c20}", "\u{2c50}"),
+    (0x2c51, "\u{2c51}", "\u{2c21}", "\u{2c21}", "\u{2c51}"),
+    (0x2c52, "\u{2c52}", "\u{2c22}", "\u{2c22}", "\u{2c52}"),
+    (0x2c53, "\u{2c53}", "\u{2c23}", "\u{2c23}", "\u{2c53}"),
+    (0x2c54, "\u{2c54}", "\u{2c24}", "\u{2c24}", "\u{2c54}"),
+    (0x2c55, "\u{2c55}", "\u{2c25}", "\u{2c25}", "\u{2c55}"),
+    (0x2c56, "\u{2c56}", "\u{2c26}", "\u{2c26}", "\u{2c56}"),
+    (0x2c57, "\u{2c57}", "\u{2c27}", "\u{2c27}", "\u{2c57}"),
+    (0x2c58, "\u{2c58}", "\u{2c28}", "\u{2c28}", "\u{2c58}"),
+    (0x2c59, "\u{2c59}", "\u{2c29}", "\u{2c29}", "\u{2c59}"),
+    (0x2c5a, "\u{2c5a}", "\u{2c2a}", "\u{2c2a}", "\u{2c5a}"),
+    (0x2c5b, "\u{2c5b}", "\u{2c2b}", "\u{2c2b}", "\u{2c5b}"),
+    (0x2c5c, "\u{2c5c}", "\u{2c2c}", "\u{2c2c}", "\u{2c5c}"),
+    (0x2c5d, "\u{2c5d}", "\u{2c2d}", "\u{2c2d}", "\u{2c5d}"),
+    (0x2c5e, "\u{2c5e}", "\u{2c2e}", "\u{2c2e}", "\u{2c5e}"),
+    (0x2c5f, "\u{2c5f}", "\u{2c2f}", "\u{2c2f}", "\u{2c5f}"),
+    (0x2c60, "\u{2c61}", "\u{2c60}", "\u{2c60}", "\u{2c61}"),
+    (0x2c61, "\u{2c61}", "\u{2c60}", "\u{2c60}", "\u{2c61}"),
+    (0x2c62, "\u{26b}", "\u{2c62}", "\u{2c62}", "\u{26b}"),
+    (0x2c63, "\u{1d7d}", "\u{2c63}", "\u{2c63}", "\u{1d7d}"),
+    (0x2c64, "\u{27d}", "\u{2c64}", "\u{2c64}", "\u{27d}"),
+    (0x2c65, "\u{2c65}", "\u{23a}", "\u{23a}", "\u{2c65}"),
+    (0x2c66, "\u{2c66}", "\u{23e}", "\u{23e}", "\u{2c66}"),
+    (0x2c67, "\u{2c68}", "\u{2c67}", "\u{2c67}", "\u{2c68}"),
+    (0x2c68, "\u{2c68}", "\u{2c67}", "\u{2c67}", "\u{2c68}"),
+    (0x2c69, "\u{2c6a}", "\u{2c69}", "\u{2c69}", "\u{2c6a}"),
+    (0x2c6a, "\u{2c6a}", "\u{2c69}", "\u{2c69}", "\u{2c6a}"),
+    (0x2c6b, "\u{2c6c}", "\u{2c6b}", "\u{2c6b}", "\u{2c6c}"),
+    (0x2c6c, "\u{2c6c}", "\u{2c6b}", "\u{2c6b}", "\u{2c6c}"),
+    (0x2c6d, "\u{251}", "\u{2c6d}", "\u{2c6d}", "\u{251}"),
+    (0x2c6e, "\u{271}", "\u{2c6e}", "\u{2c6e}", "\u{271}"),
+    (0x2c6f, "\u{250}", "\u{2c6f}", "\u{2c6f}", "\u{250}"),
+    (0x2c70, "\u{252}", "\u{2c70}", "\u{2c70}", "\u{252}"),
+    (0x2c72, "\u{2c73}", "\u{2c72}", "\u{2c72}", "\u{2c73}"),
+    (0x2c73, "\u{2c73}", "\u{2c72}", "\u{2c72}", "\u{2c73}"),
+    (0x2c75, "\u{2c76}", "\u{2c75}", "\u{2c75}", "\u{2c76}"),
+    (0x2c76, "\u{2c76}", "\u{2c75}", "\u{2c75}", "\u{2c76}"),
+    (0x2c7e, "\u{23f}", "\u{2c7e}", "\u{2c7e}", "\u{23f}"),
+    (0x2c7f, "\u{240}", "\u{2c7f}", "\u{2c7f}", "\u{240}"),
+    (0x2c80, "\u{2c81}", "\u{2c80}", "\u{2c80}", "\u{2c81}"),
+    (0x2c81, "\u{2c81}", "\u{2c80}", "\u{2c80}", "\u{2c81}"),
+    (0x2c82, "\u{2c83}", "\u{2c82}", "\u{2c82}", "\u{2c83}"),
+    (0x2c83, "\u{2c83}", "\u{2c82}", "\u{2c82}", "\u{2c83}"),
+    (0x2c84, "\u{2c85}", "\u{2c84}", "\u{2c84}", "\u{2c85}"),
+    (0x2c85, "\u{2c85}", "\u{2c84}", "\u{2c84}", "\u{2c85}"),
+    (0x2c86, "\u{2c87}", "\u{2c86}", "\u{2c86}", "\u{2c87}"),
+    (0x2c87, "\u{2c87}", "\u{2c86}", "\u{2c86}", "\u{2c87}"),
+    (0x2c88, "\u{2c89}", "\u{2c88}", "\u{2c88}", "\u{2c89}"),
+    (0x2c89, "\u{2c89}", "\u{2c88}", "\u{2c88}", "\u{2c89}"),
+    (0x2c8a, "\u{2c8b}", "\u{2c8a}", "\u{2c8a}", "\u{2c8b}"),
+    (0x2c8b, "\u{2c8b}", "\u{2c8a}", "\u{2c8a}", "\u{2c8b}"),
+    (0x2c8c, "\u{2c8d}", "\u{2c8c}", "\u{2c8c}", "\u{2c8d}"),
+    (0x2c8d, "\u{2c8d}", "\u{2c8c}", "\u{2c8c}", "\u{2c8d}"),
+    (0x2c8e, "\u{2c8f}", "\u{2c8e}", "\u{2c8e}", "\u{2c8f}"),
+    (0x2c8f, "\u{2c8f}", "\u{2c8e}", "\u{2c8e}", "\u{2c8f}"),
+    (0x2c90, "\u{2c91}", "\u{2c90}", "\u{2c90}", "\u{2c91}"),
+    (0x2c91, "\u{2c91}", "\u{2c90}", "\u{2c90}", "\u{2c91}"),
+    (0x2c92, "\u{2c93}", "\u{2c92}", "\u{2c92}", "\u{2c93}"),
+    (0x2c93, "\u{2c93}", "\u{2c92}", "\u{2c92}", "\u{2c93}"),
+    (0x2c94, "\u{2c95}", "\u{2c94}", "\u{2c94}", "\u{2c95}"),
+    (0x2c95, "\u{2c95}", "\u{2c94}", "\u{2c94}", "\u{2c95}"),
+    (0x2c96, "\u{2c97}", "\u{2c96}", "\u{2c96}", "\u{2c97}"),
+    (0x2c97, "\u{2c97}", "\u{2c96}", "\u{2c96}", "\u{2c97}"),
+    (0x2c98, "\u{2c99}", "\u{2c98}", "\u{2c98}", "\u{2c99}"),
+    (0x2c99, "\u{2c99}", "\u{2c98}", "\u{2c98}", "\u{2c99}"),
+    (0x2c9a, "\u{2c9b}", "\u{2c9a}", "\u{2c9a}", "\u{2c9b}"),
+    (0x2c9b, "\u{2c9b}", "\u{2c9a}", "\u{2c9a}", "\u{2c9b}"),
+    (0x2c9c, "\u{2c9d}", "\u{2c9c}", "\u{2c9c}", "\u{2c9d}"),
+    (0x2c9d, "\u{2c9d}", "\u{2c9c}", "\u{2c9c}", "\u{2c9d}"),
+    (0x2c9e, "\u{2c9f}", "\u{2c9e}", "\u{2c9e}", "\u{2c9f}"),
+    (0x2c9f, "\u{2c9f}", "\u{2c9e}", "\u{2c9e}", "\u{2c9f}"),
+    (0x2ca0, "\u{2ca1}", "\u{2ca0}", "\u{2ca0}", "\u{2ca1}"),
+    (0x2ca1, "\u{2ca1}", "\u{2ca0}", "\u{2ca0}", "\u{2ca1}"),
+    (0x2ca2, "\u{2ca3}", "\u{2ca2}", "\u{2ca2}", "\u{2ca3}"),
+    (0x2ca3, "\u{2ca3}", "\u{2ca2}", "\u{2ca2}", "\u{2ca3}"),
+    (0x2ca4, "\u{2ca5}", "\u{2ca4}", "\u{2ca4}", "\u{2ca5}"),
+    (0x2ca5, "\u{2ca5}", "\u{2ca4}", "\u{2ca4}", "\u{2ca5}"),
+    (0x2ca6, "\u{2ca7}", "\u{2ca6}", "\u{2ca6}", "\u{2ca7}"),
+    (0x2ca7, "\u{2ca7}", "\u{2ca6}", "\u{2ca6}", "\u{2ca7}"),
+    (0x2ca8, "\u{2ca9}", "\u{2ca8}", "\u{2ca8}", "\u{2ca9}"),
+    (0x2ca9, "\u{2ca9}", "\u{2ca8}", "\u{2ca8}", "\u{2ca9}"),
+    (0x2caa, "\u{2cab}", "\u{2caa}", "\u{2caa}", "\u{2cab}"),
+    (0x2cab, "\u{2cab}", "\u{2caa}", "\u{2caa}", "\u{2cab}"),
+    (0x2cac, "\u{2cad}", "\u{2cac}", "\u{2cac}", "\u{2cad}"),
+    (0x2cad, "\u{2cad}", "\u{2cac}", "\u{2cac}", "\u{2cad}"),
+    (0x2cae, "\u{2caf}", "\u{2cae}", "\u{2cae}", "\u{2caf}"),
+    (0x2caf, "\u{2caf}", "\u{2cae}", "\u{2cae}", "\u{2caf}"),
+    (0x2cb0, "\u{2cb1}", "\u{2cb0}", "\u{2cb0}", "\u{2cb1}"),
+    (0x2cb1, "\u{2cb1}", "\u{2cb0}", "\u{2cb0}", "\u{2cb1}"),
+    (0x2cb2, "\u{2cb3}", "\u{2cb2}", "\u{2cb2}", "\u{2cb3}"),
+    (0x2cb3, "\u{2cb3}", "\u{2cb2}", "\u{2cb2}", "\u{2cb3}"),
+    (0x2cb4, "\u{2cb5}", "\u{2cb4}", "\u{2cb4}", "\u{2cb5}"),
+    (0x2cb5, "\u{2cb5}", "\u{2cb4}", "\u{2cb4}", "\u{2cb5}"),
+    (0x2cb6, "\u{2cb7}", "\u{2cb6}", "\u{2cb6}", "\u{2cb7}"),
+    (0x2cb7, "\u{2cb7}", "\u{2cb6}", "\u{2cb6}", "\u{2cb7}"),
+    (0x2cb8, "\u{2cb9}", "\u{2cb8}", "\u{2cb8}", "\u{2cb9}"),
+    (0x2cb9, "\u{2cb9}", "\u{2cb8}", "\u{2cb8}", "\u{2cb9}"),
+    (0x2cba, "\u{2cbb}", "\u{2cba}", "\u{2cba}", "\u{2cbb}"),
+    (0x2cbb, "\u{2cbb}", "\u{2cba}", "\u{2cba}", "\u{2cbb}"),
+    (0x2cbc, "\u{2cbd}", "\u{2cbc}", "\u{2cbc}", "\u{2cbd}"),
+    (0x2cbd, "\u{2cbd}", "\u{2cbc}", "\u{2cbc}", "\u{2cbd}"),
+    (0x2cbe, "\u{2cbf}", "\u{2cbe}", "\u{2cbe}", "\u{2cbf}"),
+    (0x2cbf, "\u{2cbf}", "\u{2cbe}", "\u{2cbe}", "\u{2cbf}"),
+    (0x2cc0, "\u{2cc1}", "\u{2cc0}", "\u{2cc0}", "\u{2cc1}"),
+    (0x2cc1, "\u{2cc1}", "\u{2cc0}", "\u{2cc0}", "\u{2cc1}"),
+    (0x2cc2, "\u{2cc3}", "\u{2cc2}", "\u{2cc2}", "\u{2cc3}"),
+    (0x2cc3, "\u{2cc3}", "\u{2cc2}", "\u{2cc2}", "\u{2cc3}"),
+    (0x2cc4, "\u{2cc5}", "\u{2cc4}", "\u{2cc4}", "\u{2cc5}"),
+    (0x2cc5, "\u{2cc5}", "\u{2cc4}", "\u{2cc4}", "\u{2cc5}"),
+    (0x2cc6, "\u{2cc7}", "\u{2cc6}", "\u{2cc6}", "\u{2cc7}"),
+    (0x2cc7, "\u{2cc7}", "\u{2cc6}", "\u{2cc6}", "\u{2cc7}"),
+    (0x2cc8, "\u{2cc9}", "\u{2cc8}", "\u{2cc8}", "\u{2cc9}"),
+    (0x2cc9, "\u{2cc9}", "\u{2cc8}", "\u{2cc8}", "\u{2cc9}"),
+    (0x2cca, "\u{2ccb}", "\u{2cca}", "\u{2cca}", "\u{2ccb}"),
+    (0x2ccb, "\u{2ccb}", "\u{2cca}", "\u{2cca}", "\u{2ccb}"),
+    (0x2ccc, "\u{2ccd}", "\u{2ccc}", "\u{2ccc}", "\u{2ccd}"),
+    (0x2ccd, "\u{2ccd}", "\u{2ccc}", "\u{2ccc}", "\u{2ccd}"),
+    (0x2cce, "\u{2ccf}", "\u{2cce}", "\u{2cce}", "\u{2ccf}"),
+    (0x2ccf, "\u{2ccf}", "\u{2cce}", "\u{2cce}", "\u{2ccf}"),
+    (0x2cd0, "\u{2cd1}", "\u{2cd0}", "\u{2cd0}", "\u{2cd1}"),
+    (0x2cd1, "\u{2cd1}", "\u{2cd0}", "\u{2cd0}", "\u{2cd1}"),
+    (0x2cd2, "\u{2cd3}", "\u{2cd2}", "\u{2cd2}", "\u{2cd3}"),
+    (0x2cd3, "\u{2cd3}", "\u{2cd2}", "\u{2cd2}", "\u{2cd3}"),
+    (0x2cd4, "\u{2cd5}", "\u{2cd4}", "\u{2cd4}", "\u{2cd5}"),
+    (0x2cd5, "\u{2cd5}", "\u{2cd4}", "\u{2cd4}", "\u{2cd5}"),
+    (0x2cd6, "\u{2cd7}", "\u{2cd6}", "\u{2cd6}", "\u{2cd7}"),
+    (0x2cd7, "\u{2cd7}", "\u{2cd6}", "\u{2cd6}", "\u{2cd7}"),
+    (0x2cd8, "\u{2cd9}", "\u{2cd8}", "\u{2cd8}", "\u{2cd9}"),
+    (0x2cd9, "\u{2cd9}", "\u{2cd8}", "\u{2cd8}", "\u{2cd9}"),
+    (0x2cda, "\u{2cdb}", "\u{2cda}", "\u{2cda}", "\u{2cdb}"),
+    (0x2cdb, "\u{2cdb}", "\u{2cda}", "\u{2cda}", "\u{2cdb}"),
+    (0x2cdc, "\u{2cdd}", "\u{2cdc}", "\u{2cdc}", "\u{2cdd}"),
+    (0x2cdd, "\u{2cdd}", "\u{2cdc}", "\u{2cdc}", "\u{2cdd}"),
+    (0x2cde, "\u{2cdf}", "\u{2cde}", "\u{2cde}", "\u{2cdf}"),
+    (0x2cdf, "\u{2cdf}", "\u{2cde}", "\u{2cde}", "\u{2cdf}"),
+    (0x2ce0, "\u{2ce1}", "\u{2ce0}", "\u{2ce0}", "\u{2ce1}"),
+    (0x2ce1, "\u{2ce1}", "\u{2ce0}", "\u{2ce0}", "\u{2ce1}"),
+    (0x2ce2, "\u{2ce3}", "\u{2ce2}", "\u{2ce2}", "\u{2ce3}"),
+    (0x2ce3, "\u{2ce3}", "\u{2ce2}", "\u{2ce2}", "\u{2ce3}"),
+    (0x2ceb, "\u{2cec}", "\u{2ceb}", "\u{2ceb}", "\u{2cec}"),
+    (0x2cec, "\u{2cec}", "\u{2ceb}", "\u{2ceb}", "\u{2cec}"),
+    (0x2ced, "\u{2cee}", "\u{2ced}", "\u{2ced}", "\u{2cee}"),
+    (0x2cee, "\u{2cee}", "\u{2ced}", "\u{2ced}", "\u{2cee}"),
+    (0x2cf2, "\u{2cf3}", "\u{2cf2}", "\u{2cf2}", "\u{2cf3}"),
+    (0x2cf3, "\u{2cf3}", "\u{2cf2}", "\u{2cf2}", "\u{2cf3}"),
+    (0x2d00, "\u{2d00}", "\u{10a0}", "\u{10a0}", "\u{2d00}"),
+    (0x2d01, "\u{2d01}", "\u{10a1}", "\u{10a1}", "\u{2d01}"),
+    (0x2d02, "\u{2d02}", "\u{10a2}", "\u{10a2}", "\u{2d02}"),
+    (0x2d03, "\u{2d03}", "\u{10a3}", "\u{10a3}", "\u{2d03}"),
+    (0x2d04, "\u{2d04}", "\u{10a4}", "\u{10a4}", "\u{2d04}"),
+    (0x2d05, "\u{2d05}", "\u{10a5}", "\u{10a5}", "\u{2d05}"),
+    (0x2d06, "\u{2d06}", "\u{10a6}", "\u{10a6}", "\u{2d06}"),
+    (0x2d07, "\u{2d07}", "\u{10a7}", "\u{10a7}", "\u{2d07}"),
+    (0x2d08, "\u{2d08}", "\u{10a8}", "\u{10a8}", "\u{2d08}"),
+    (0x2d09, "\u{2d09}", "\u{10a9}", "\u{10a9}", "\u{2d09}"),
+    (0x2d0a, "\u{2d0a}", "\u{10aa}", "\u{10aa}", "\u{2d0a}"),
+    (0x2d0b, "\u{2d0b}", "\u{10ab}", "\u{10ab}", "\u{2d0b}"),
+    (0x2d0c, "\u{2d0c}", "\u{10ac}", "\u{10ac}", "\u{2d0c}"),
+    (0x2d0d, "\u{2d0d}", "\u{10ad}", "\u{10ad}", "\u{2d0d}"),
+    (0x2d0e, "\u{2d0e}", "\u{10ae}", "\u{10ae}", "\u{2d0e}"),
+    (0x2d0f, "\u{2d0f}", "\u{10af}", "\u{10af}", "\u{2d0f}"),
+    (0x2d10, "\u{2d10}", "\u{10b0}", "\u{10b0}", "\u{2d10}"),
+    (0x2d11, "\u{2d11}", "\u{10b1}", "\u{10b1}", "\u{2d11}"),
+    (0x2d12, "\u{2d12}", "\u{10b2}", "\u{10b2}", "\u{2d12}"),
+    (0x2d13, "\u{2d13}", "\u{10b3}", "\u{10b3}", "\u{2d13}"),
+    (0x2d14, "\u{2d14}", "\u{10b4}", "\u{10b4}", "\u{2d14}"),
+    (0x2d15, "\u{2d15}", "\u{10b5}", "\u{10b5}", "\u{2d15}"),
+    (0x2d16, "\u{2d16}", "\u{10b6}", "\u{10b6}", "\u{2d16}"),
+    (0x2d17, "\u{2d17}", "\u{10b7}", "\u{10b7}", "\u{2d17}"),
+    (0x2d18, "\u{2d18}", "\u{10b8}", "\u{10b8}", "\u{2d18}"),
+    (0x2d19, "\u{2d19}", "\u{10b9}", "\u{10b9}", "\u{2d19}"),
+    (0x2d1a, "\u{2d1a}", "\u{10ba}", "\u{10ba}", "\u{2d1a}"),
+    (0x2d1b, "\u{2d1b}", "\u{10bb}", "\u{10bb}", "\u{2d1b}"),
+    (0x2d1c, "\u{2d1c}", "\u{10bc}", "\u{10bc}", "\u{2d1c}"),
+    (0x2d1d, "\u{2d1d}", "\u{10bd}", "\u{10bd}", "\u{2d1d}"),
+    (0x2d1e, "\u{2d1e}", "\u{10be}", "\u{10be}", "\u{2d1e}"),
+    (0x2d1f, "\u{2d1f}", "\u{10bf}", "\u{10bf}", "\u{2d1f}"),
+    (0x2d20, "\u{2d20}", "\u{10c0}", "\u{10c0}", "\u{2d20}"),
+    (0x2d21, "\u{2d21}", "\u{10c1}", "\u{10c1}", "\u{2d21}"),
+    (0x2d22, "\u{2d22}", "\u{10c2}", "\u{10c2}", "\u{2d22}"),
+    (0x2d23, "\u{2d23}", "\u{10c3}", "\u{10c3}", "\u{2d23}"),
+    (0x2d24, "\u{2d24}", "\u{10c4}", "\u{10c4}", "\u{2d24}"),
+    (0x2d25, "\u{2d25}", "\u{10c5}", "\u{10c5}", "\u{2d25}"),
+    (0x2d27, "\u{2d27}", "\u{10c7}", "\u{10c7}", "\u{2d27}"),
+    (0x2d2d, "\u{2d2d}", "\u{10cd}", "\u{10cd}", "\u{2d2d}"),
+    (0xa640, "\u{a641}", "\u{a640}", "\u{a640}", "\u{a641}"),
+    (0xa641, "\u{a641}", "\u{a640}", "\u{a640}", "\u{a641}"),
+    (0xa642, "\u{a643}", "\u{a642}", "\u{a642}", "\u{a643}"),
+    (0xa643, "\u{a643}", "\u{a642}", "\u{a642}", "\u{a643}"),
+    (0xa644, "\u{a645}", "\u{a644}", "\u{a644}", "\u{a645}"),
+    (0xa645, "\u{a645}", "\u{a644}", "\u{a644}", "\u{a645}"),
+    (0xa646, "\u{a647}", "\u{a646}", "\u{a646}", "\u{a647}"),
+    (0xa647, "\u{a647}", "\u{a646}", "\u{a646}", "\u{a647}"),
+    (0xa648, "\u{a649}", "\u{a648}", "\u{a648}", "\u{a649}"),
+    (0xa649, "\u{a649}", "\u{a648}", "\u{a648}", "\u{a649}"),
+    (0xa64a, "\u{a64b}", "\u{a64a}", "\u{a64a}", "\u{a64b}"),
+    (0xa64b, "\u{a64b}", "\u{a64a}", "\u{a64a}", "\u{a64b}"),
+    (0xa64c, "\u{a64d}", "\u{a64c}", "\u{a64c}", "\u{a64d}"),
+    (0xa64d, "\u{a64d}", "\u{a64c}", "\u{a64c}", "\u{a64d}"),
+    (0xa64e, "\u{a64f}", "\u{a64e}", "\u{a64e}", "\u{a64f}"),
+    (0xa64f, "\u{a64f}", "\u{a64e}", "\u{a64e}", "\u{a64f}"),
+    (0xa650, "\u{a651}", "\u{a650}", "\u{a650}", "\u{a651}"),
+    (0xa651, "\u{a651}", "\u{a650}", "\u{a650}", "\u{a651}"),
+    (0xa652, "\u{a653}", "\u{a652}", "\u{a652}", "\u{a653}"),
+    (0xa653, "\u{a653}", "\u{a652}", "\u{a652}", "\u{a653}"),
+    (0xa654, "\u{a655}", "\u{a654}", "\u{a654}", "\u{a655}"),
+    (0xa655, "\u{a655}", "\u{a654}", "\u{a654}", "\u{a655}"),
+    (0xa656, "\u{a657}", "\u{a656}", "\u{a656}", "\u{a657}"),
+    (0xa657, "\u{a657}", "\u{a656}", "\u{a656}", "\u{a657}"),
+    (0xa658, "\u{a659}", "\u{a658}", "\u{a658}", "\u{a659}"),
+    (0xa659, "\u{a659}", "\u{a658}", "\u{a658}", "\u{a659}"),
+    (0xa65a, "\u{a65b}", "\u{a65a}", "\u{a65a}", "\u{a65b}"),
+    (0xa65b, "\u{a65b}", "\u{a65a}", "\u{a65a}", "\u{a65b}"),
+    (0xa65c, "\u{a65d}", "\u{a65c}", "\u{a65c}", "\u{a65d}"),
+    (0xa65d, "\u{a65d}", "\u{a65c}", "\u{a65c}", "\u{a65d}"),
+    (0xa65e, "\u{a65f}", "\u{a65e}", "\u{a65e}", "\u{a65f}"),
+    (0xa65f, "\u{a65f}", "\u{a65e}", "\u{a65e}", "\u{a65f}"),
+    (0xa660, "\u{a661}", "\u{a660}", "\u{a660}", "\u{a661}"),
+    (0xa661, "\u{a661}", "\u{a660}", "\u{a660}", "\u{a661}"),
+    (0xa662, "\u{a663}", "\u{a662}", "\u{a662}", "\u{a663}"),
+    (0xa663, "\u{a663}", "\u{a662}", "\u{a662}", "\u{a663}"),
+    (0xa664, "\u{a665}", "\u{a664}", "\u{a664}", "\u{a665}"),
+    (0xa665, "\u{a665}", "\u{a664}", "\u{a664}", "\u{a665}"),
+    (0xa666, "\u{a667}", "\u{a666}", "\u{a666}", "\u{a667}"),
+    (0xa667, "\u{a667}", "\u{a666}", "\u{a666}", "\u{a667}"),
+    (0xa668, "\u{a669}", "\u{a668}", "\u{a668}", "\u{a669}"),
+    (0xa669, "\u{a669}", "\u{a668}", "\u{a668}", "\u{a669}"),
+    (0xa66a, "\u{a66b}", "\u{a66a}", "\u{a66a}", "\u{a66b}"),
+    (0xa66b, "\u{a66b}", "\u{a66a}", "\u{a66a}", "\u{a66b}"),
+    (0xa66c, "\u{a66d}", "\u{a66c}", "\u{a66c}", "\u{a66d}"),
+    (0xa66d, "\u{a66d}", "\u{a66c}", "\u{a66c}", "\u{a66d}"),
+    (0xa680, "\u{a681}", "\u{a680}", "\u{a680}", "\u{a681}"),
+    (0xa681, "\u{a681}", "\u{a680}", "\u{a680}", "\u{a681}"),
+    (0xa682, "\u{a683}", "\u{a682}", "\u{a682}", "\u{a683}"),
+    (0xa683, "\u{a683}", "\u{a682}", "\u{a682}", "\u{a683}"),
+    (0xa684, "\u{a685}", "\u{a684}", "\u{a684}", "\u{a685}"),
+    (0xa685, "\u{a685}", "\u{a684}", "\u{a684}", "\u{a685}"),
+    (0xa686, "\u{a687}", "\u{a686}", "\u{a686}", "\u{a687}"),
+    (0xa687, "\u{a687}", "\u{a686}", "\u{a686}", "\u{a687}"),
+    (0xa688, "\u{a689}", "\u{a688}", "\u{a688}", "\u{a689}"),
+    (0xa689, "\u{a689}", "\u{a688}", "\u{a688}", "\u{a689}"),
+    (0xa68a, "\u{a68b}", "\u{a68a}", "\u{a68a}", "\u{a68b}"),
+    (0xa68b, "\u{a68b}", "\u{a68a}", "\u{a68a}", "\u{a68b}"),
+    (0xa68c, "\u{a68d}", "\u{a68c}", "\u{a68c}", "\u{a68d}"),
+    (0xa68d, "\u{a68d}", "\u{a68c}", "\u{a68c}", "\u{a68d}"),
+    (0xa68e, "\u{a68f}", "\u{a68e}", "\u{a68e}", "\u{a68f}"),
+    (0xa68f, "\u{a68f}", "\u{a68e}", "\u{a68e}", "\u{a68f}"),
+    (0xa690, "\u{a691}", "\u{a690}", "\u{a690}", "\u{a691}"),
+    (0xa691, "\u{a691}", "\u{a690}", "\u{a690}", "\u{a691}"),
+    (0xa692, "\u{a693}", "\u{a692}", "\u{a692}", "\u{a693}"),
+    (0xa693, "\u{a693}", "\u{a692}", "\u{a692}", "\u{a693}"),
+    (0xa694, "\u{a695}", "\u{a694}", "\u{a694}", "\u{a695}"),
+    (0xa695, "\u{a695}", "\u{a694}", "\u{a694}", "\u{a695}"),
+    (0xa696, "\u{a697}", "\u{a696}", "\u{a696}", "\u{a697}"),
+    (0xa697, "\u{a697}", "\u{a696}", "\u{a696}", "\u{a697}"),
+    (0xa698, "\u{a699}", "\u{a698}", "\u{a698}", "\u{a699}"),
+    (0xa699, "\u{a699}", "\u{a698}", "\u{a698}", "\u{a699}"),
+    (0xa69a, "\u{a69b}", "\u{a69a}", "\u{a69a}", "\u{a69b}"),
+    (0xa69b, "\u{a69b}", "\u{a69a}", "\u{a69a}", "\u{a69b}"),
+    (0xa722, "\u{a723}", "\u{a722}", "\u{a722}", "\u{a723}"),
+    (0xa723, "\u{a723}", "\u{a722}", "\u{a722}", "\u{a723}"),
+    (0xa724, "\u{a725}", "\u{a724}", "\u{a724}", "\u{a725}"),
+    (0xa725, "\u{a725}", "\u{a724}", "\u{a724}", "\u{a725}"),
+    (0xa726, "\u{a727}", "\u{a726}", "\u{a726}", "\u{a727}"),
+    (0xa727, "\u{a727}", "\u{a726}", "\u{a726}", "\u{a727}"),
+    (0xa728, "\u{a729}", "\u{a728}", "\u{a728}", "\u{a729}"),
+    (0xa729, "\u{a729}", "\u{a728}", "\u{a728}", "\u{a729}"),
+    (0xa72a, "\u{a72b}", "\u{a72a}", "\u{a72a}", "\u{a72b}"),
+    (0xa72b, "\u{a72b}", "\u{a72a}", "\u{a72a}", "\u{a72b}"),
+    (0xa72c, "\u{a72d}", "\u{a72c}", "\u{a72c}", "\u{a72d}"),
+    (0xa72d, "\u{a72d}", "\u{a72c}", "\u{a72c}", "\u{a72d}"),
+    (0xa72e, "\u{a72f}", "\u{a72e}", "\u{a72e}", "\u{a72f}"),
+    (0xa72f, "\u{a72f}", "\u{a72e}", "\u{a72e}", "\u{a72f}"),
+    (0xa732, "\u{a733}", "\u{a732}", "\u{a732}", "\u{a733}"),
+    (0xa733, "\u{a733}", "\u{a732}", "\u{a732}", "\u{a733}"),
+    (0xa734, "\u{a735}", "\u{a734}", "\u{a734}", "\u{a735}"),
+    (0xa735, "\u{a735}", "\u{a734}", "\u{a734}", "\u{a735}"),
+    (0xa736, "\u{a737}", "\u{a736}", "\u{a736}", "\u{a737}"),
+    (0xa737, "\u{a737}", "\u{a736}", "\u{a736}", "\u{a737}"),
+    (0xa738, "\u{a739}", "\u{a738}", "\u{a738}", "\u{a739}"),
+    (0xa739, "\u{a739}", "\u{a738}", "\u{a738}", "\u{a739}"),
+    (0xa73a, "\u{a73b}", "\u{a73a}", "\u{a73a}", "\u{a73b}"),
+    (0xa73b, "\u{a73b}", "\u{a73a}", "\u{a73a}", "\u{a73b}"),
+    (0xa73c, "\u{a73d}", "\u{a73c}", "\u{a73c}", "\u{a73d}"),
+    (0xa73d, "\u{a73d}", "\u{a73c}", "\u{a73c}", "\u{a73d}"),
+    (0xa73e, "\u{a73f}", "\u{a73e}", "\u{a73e}", "\u{a73f}"),
+    (0xa73f, "\u{a73f}", "\u{a73e}", "\u{a73e}", "\u{a73f}"),
+    (0xa740, "\u{a741}", "\u{a740}", "\u{a740}", "\u{a741}"),
+    (0xa741, "\u{a741}", "\u{a740}", "\u{a740}", "\u{a741}"),
+    (0xa742, "\u{a743}", "\u{a742}", "\u{a742}", "\u{a743}"),
+    (0xa743, "\u{a743}", "\u{a742}", "\u{a742}", "\u{a743}"),
+    (0xa744, "\u{a745}", "\u{a744}", "\u{a744}", "\u{a745}"),
+    (0xa745, "\u{a745}", "\u{a744}", "\u{a744}", "\u{a745}"),
+    (0xa746, "\u{a747}", "\u{a746}", "\u{a746}", "\u{a747}"),
+    (0xa747, "\u{a747}", "\u{a746}", "\u{a746}", "\u{a747}"),
+    (0xa748, "\u{a749}", "\u{a748}", "\u{a748}", "\u{a749}"),
+    (0xa749, "\u{a749}", "\u{a748}", "\u{a748}", "\u{a749}"),
+    (0xa74a, "\u{a74b}", "\u{a74a}", "\u{a74a}", "\u{a74b}"),
+    (0xa74b, "\u{a74b}", "\u{a74a}", "\u{a74a}", "\u{a74b}"),
+    (0xa74c, "\u{a74d}", "\u{a74c}", "\u{a74c}", "\u{a74d}"),
+    (0xa74d, "\u{a74d}", "\u{a74c}", "\u{a74c}", "\u{a74d}"),
+    (0xa74e, "\u{a74f}", "\u{a74e}", "\u{a74e}", "\u{a74f}"),
+    (0xa74f, "\u{a74f}", "\u{a74e}", "\u{a74e}", "\u{a74f}"),
+    (0xa750, "\u{a751}", "\u{a750}", "\u{a750}", "\u{a751}"),
+    (0xa751, "\u{a751}", "\u{a750}", "\u{a750}", "\u{a751}"),
+    (0xa752, "\u{a753}", "\u{a752}", "\u{a752}", "\u{a753}"),
+    (0xa753, "\u{a753}", "\u{a752}", "\u{a752}", "\u{a753}"),
+    (0xa754, "\u{a755}", "\u{a754}", "\u{a754}", "\u{a755}"),
+    (0xa755, "\u{a755}", "\u{a754}", "\u{a754}", "\u{a755}"),
+    (0xa756, "\u{a757}", "\u{a756}", "\u{a756}", "\u{a757}"),
+    (0xa757, "\u{a757}", "\u{a756}", "\u{a756}", "\u{a757}"),
+    (0xa758, "\u{a759}", "\u{a758}", "\u{a758}", "\u{a759}"),
+    (0xa759, "\u{a759}", "\u{a758}", "\u{a758}", "\u{a759}"),
+    (0xa75a, "\u{a75b}", "\u{a75a}", "\u{a75a}", "\u{a75b}"),
+    (0xa75b, "\u{a75b}", "\u{a75a}", "\u{a75a}", "\u{a75b}"),
+    (0xa75c, "\u{a75d}", "\u{a75c}", "\u{a75c}", "\u{a75d}"),
+    (0xa75d, "\u{a75d}", "\u{a75c}", "\u{a75c}", "\u{a75d}"),
+    (0xa75e, "\u{a75f}", "\u{a75e}", "\u{a75e}", "\u{a75f}"),
+    (0xa75f, "\u{a75f}", "\u{a75e}", "\u{a75e}", "\u{a75f}"),
+    (0xa760, "\u{a761}", "\u{a760}", "\u{a760}", "\u{a761}"),
+    (0xa761, "\u{a761}", "\u{a760}", "\u{a760}", "\u{a761}"),
+    (0xa762, "\u{a763}", "\u{a762}", "\u{a762}", "\u{a763}"),
+    (0xa763, "\u{a763}", "\u{a762}", "\u{a762}", "\u{a763}"),
+    (0xa764, "\u{a765}", "\u{a764}", "\u{a764}", "\u{a765}"),
+    (0xa765, "\u{a765}", "\u{a764}", "\u{a764}", "\u{a765}"),
+    (0xa766, "\u{a767}", "\u{a766}", "\u{a766}", "\u{a767}"),
+    (0xa767, "\u{a767}", "\u{a766}", "\u{a766}", "\u{a767}"),
+    (0xa768, "\u{a769}", "\u{a768}", "\u{a768}", "\u{a769}"),
+    (0xa769, "\u{a769}", "\u{a768}", "\u{a768}", "\u{a769}"),
+    (0xa76a, "\u{a76b}", "\u{a76a}", "\u{a76a}", "\u{a76b}"),
+    (0xa76b, "\u{a76b}", "\u{a76a}", "\u{a76a}", "\u{a76b}"),
+    (0xa76c, "\u{a76d}", "\u{a76c}", "\u{a76c}", "\u{a76d}"),
+    (0xa76d, "\u{a76d}", "\u{a76c}", "\u{a76c}", "\u{a76d}"),
+    (0xa76e, "\u{a76f}", "\u{a76e}", "\u{a76e}", "\u{a76f}"),
+    (0xa76f, "\u{a76f}", "\u{a76e}", "\u{a76e}", "\u{a76f}"),
+    (0xa779, "\u{a77a}", "\u{a779}", "\u{a779}", "\u{a77a}"),
+    (0xa77a, "\u{a77a}", "\u{a779}", "\u{a779}", "\u{a77a}"),
+    (0xa77b, "\u{a77c}", "\u{a77b}", "\u{a77b}", "\u{a77c}"),
+    (0xa77c, "\u{a77c}", "\u{a77b}", "\u{a77b}", "\u{a77c}"),
+    (0xa77d, "\u{1d79}", "\u{a77d}", "\u{a77d}", "\u{1d79}"),
+    (0xa77e, "\u{a77f}", "\u{a77e}", "\u{a77e}", "\u{a77f}"),
+    (0xa77f, "\u{a77f}", "\u{a77e}", "\u{a77e}", "\u{a77f}"),
+    (0xa780, "\u{a781}", "\u{a780}", "\u{a780}", "\u{a781}"),
+    (0xa781, "\u{a781}", "\u{a780}", "\u{a780}", "\u{a781}"),
+    (0xa782, "\u{a783}", "\u{a782}", "\u{a782}", "\u{a783}"),
+    (0xa783, "\u{a783}", "\u{a782}", "\u{a782}", "\u{a783}"),
+    (0xa784, "\u{a785}", "\u{a784}", "\u{a784}", "\u{a785}"),
+    (0xa785, "\u{a785}", "\u{a784}", "\u{a784}", "\u{a785}"),
+    (0xa786, "\u{a787}", "\u{a786}", "\u{a786}", "\u{a787}"),
+    (0xa787, "\u{a787}", "\u{a786}", "\u{a786}", "\u{a787}"),
+    (0xa78b, "\u{a78c}", "\u{a78b}", "\u{a78b}", "\u{a78c}"),
+    (0xa78c, "\u{a78c}", "\u{a78b}", "\u{a78b}", "\u{a78c}"),
+    (0xa78d, "\u{265}", "\u{a78d}", "\u{a78d}", "\u{265}"),
+    (0xa790, "\u{a791}", "\u{a790}", "\u{a790}", "\u{a791}"),
+    (0xa791, "\u{a791}", "\u{a790}", "\u{a790}", "\u{a791}"),
+    (0xa792, "\u{a793}", "\u{a792}", "\u{a792}", "\u{a793}"),
+    (0xa793, "\u{a793}", "\u{a792}", "\u{a792}", "\u{a793}"),
+    (0xa794, "\u{a794}", "\u{a7c4}", "\u{a7c4}", "\u{a794}"),
+    (0xa796, "\u{a797}", "\u{a796}", "\u{a796}", "\u{a797}"),
+    (0xa797, "\u{a797}", "\u{a796}", "\u{a796}", "\u{a797}"),
+    (0xa798, "\u{a799}", "\u{a798}", "\u{a798}", "\u{a799}"),
+    (0xa799, "\u{a799}", "\u{a798}", "\u{a798}", "\u{a799}"),
+    (0xa79a, "\u{a79b}", "\u{a79a}", "\u{a79a}", "\u{a79b}"),
+    (0xa79b, "\u{a79b}", "\u{a79a}", "\u{a79a}", "\u{a79b}"),
+    (0xa79c, "\u{a79d}", "\u{a79c}", "\u{a79c}", "\u{a79d}"),
+    (0xa79d, "\u{a79d}", "\u{a79c}", "\u{a79c}", "\u{a79d}"),
+    (0xa79e, "\u{a79f}", "\u{a79e}", "\u{a79e}", "\u{a79f}"),
+    (0xa79f, "\u{a79f}", "\u{a79e}", "\u{a79e}", "\u{a79f}"),
+    (0xa7a0, "\u{a7a1}", "\u{a7a0}", "\u{a7a0}", "\u{a7a1}"),
+    (0xa7a1, "\u{a7a1}", "\u{a7a0}", "\u{a7a0}", "\u{a7a1}"),
+    (0xa7a2, "\u{a7a3}", "\u{a7a2}", "\u{a7a2}", "\u{a7a3}"),
+    (0xa7a3, "\u{a7a3}", "\u{a7a2}", "\u{a7a2}", "\u{a7a3}"),
+    (0xa7a4, "\u{a7a5}", "\u{a7a4}", "\u{a7a4}", "\u{a7a5}"),
+    (0xa7a5, "\u{a7a5}", "\u{a7a4}", "\u{a7a4}", "\u{a7a5}"),
+    (0xa7a6, "\u{a7a7}", "\u{a7a6}", "\u{a7a6}", "\u{a7a7}"),
+    (0xa7a7, "\u{a7a7}", "\u{a7a6}", "\u{a7a6}", "\u{a7a7}"),
+    (0xa7a8, "\u{a7a9}", "\u{a7a8}", "\u{a7a8}", "\u{a7a9}"),
+    (0xa7a9, "\u{a7a9}", "\u{a7a8}", "\u{a7a8}", "\u{a7a9}"),
+    (0xa7aa, "\u{266}", "\u{a7aa}", "\u{a7aa}", "\u{266}"),
+    (0xa7ab, "\u{25c}", "\u{a7ab}", "\u{a7ab}", "\u{25c}"),
+    (0xa7ac, "\u{261}", "\u{a7ac}", "\u{a7ac}", "\u{261}"),
+    (0xa7ad, "\u{26c}", "\u{a7ad}", "\u{a7ad}", "\u{26c}"),
+    (0xa7ae, "\u{26a}", "\u{a7ae}", "\u{a7ae}", "\u{26a}"),
+    (0xa7b0, "\u{29e}", "\u{a7b0}", "\u{a7b0}", "\u{29e}"),
+    (0xa7b1, "\u{287}", "\u{a7b1}", "\u{a7b1}", "\u{287}"),
+    (0xa7b2, "\u{29d}", "\u{a7b2}", "\u{a7b2}", "\u{29d}"),
+    (0xa7b3, "\u{ab53}", "\u{a7b3}", "\u{a7b3}", "\u{ab53}"),
+    (0xa7b4, "\u{a7b5}", "\u{a7b4}", "\u{a7b4}", "\u{a7b5}"),
+    (0xa7b5, "\u{a7b5}", "\u{a7b4}", "\u{a7b4}", "\u{a7b5}"),
+    (0xa7b6, "\u{a7b7}", "\u{a7b6}", "\u{a7b6}", "\u{a7b7}"),
+    (0xa7b7, "\u{a7b7}", "\u{a7b6}", "\u{a7b6}", "\u{a7b7}"),
+    (0xa7b8, "\u{a7b9}", "\u{a7b8}", "\u{a7b8}", "\u{a7b9}"),
+    (0xa7b9, "\u{a7b9}", "\u{a7b8}", "\u{a7b8}", "\u{a7b9}"),
+    (0xa7ba, "\u{a7bb}", "\u{a7ba}", "\u{a7ba}", "\u{a7bb}"),
+    (0xa7bb, "\u{a7bb}", "\u{a7ba}", "\u{a7ba}", "\u{a7bb}"),
+    (0xa7bc, "\u{a7bd}", "\u{a7bc}", "\u{a7bc}", "\u{a7bd}"),
+    (0xa7bd, "\u{a7bd}", "\u{a7bc}", "\u{a7bc}", "\u{a7bd}"),
+    (0xa7be, "\u{a7bf}", "\u{a7be}", "\u{a7be}", "\u{a7bf}"),
+    (0xa7bf, "\u{a7bf}", "\u{a7be}", "\u{a7be}", "\u{a7bf}"),
+    (0xa7c0, "\u{a7c1}", "\u{a7c0}", "\u{a7c0}", "\u{a7c1}"),
+    (0xa7c1, "\u{a7c1}", "\u{a7c0}", "\u{a7c0}", "\u{a7c1}"),
+    (0xa7c2, "\u{a7c3}", "\u{a7c2}", "\u{a7c2}", "\u{a7c3}"),
+    (0xa7c3, "\u{a7c3}", "\u{a7c2}", "\u{a7c2}", "\u{a7c3}"),
+    (0xa7c4, "\u{a794}", "\u{a7c4}", "\u{a7c4}", "\u{a794}"),
+    (0xa7c5, "\u{282}", "\u{a7c5}", "\u{a7c5}", "\u{282}"),
+    (0xa7c6, "\u{1d8e}", "\u{a7c6}", "\u{a7c6}", "\u{1d8e}"),
+    (0xa7c7, "\u{a7c8}", "\u{a7c7}", "\u{a7c7}", "\u{a7c8}"),
+    (0xa7c8, "\u{a7c8}", "\u{a7c7}", "\u{a7c7}", "\u{a7c8}"),
+    (0xa7c9, "\u{a7ca}", "\u{a7c9}", "\u{a7c9}", "\u{a7ca}"),
+    (0xa7ca, "\u{a7ca}", "\u{a7c9}", "\u{a7c9}", "\u{a7ca}"),
+    (0xa7d0, "\u{a7d1}", "\u{a7d0}", "\u{a7d0}", "\u{a7d1}"),
+    (0xa7d1, "\u{a7d1}", "\u{a7d0}", "\u{a7d0}", "\u{a7d1}"),
+    (0xa7d6, "\u{a7d7}", "\u{a7d6}", "\u{a7d6}", "\u{a7d7}"),
+    (0xa7d7, "\u{a7d7}", "\u{a7d6}", "\u{a7d6}", "\u{a7d7}"),
+    (0xa7d8, "\u{a7d9}", "\u{a7d8}", "\u{a7d8}", "\u{a7d9}"),
+    (0xa7d9, "\u{a7d9}", "\u{a7d8}", "\u{a7d8}", "\u{a7d9}"),
+    (0xa7f5, "\u{a7f6}", "\u{a7f5}", "\u{a7f5}", "\u{a7f6}"),
+    (0xa7f6, "\u{a7f6}", "\u{a7f5}", "\u{a7f5}", "\u{a7f6}"),
+    (0xab53, "\u{ab53}", "\u{a7b3}", "\u{a7b3}", "\u{ab53}"),
+    (0xab70, "\u{ab70}", "\u{13a0}", "\u{13a0}", "\u{13a0}"),
+    (0xab71, "\u{ab71}", "\u{13a1}", "\u{13a1}", "\u{13a1}"),
+    (0xab72, "\u{ab72}", "\u{13a2}", "\u{13a2}", "\u{13a2}"),
+    (0xab73, "\u{ab73}", "\u{13a3}", "\u{13a3}", "\u{13a3}"),
+    (0xab74, "\u{ab74}", "\u{13a4}", "\u{13a4}", "\u{13a4}"),
+    (0xab75, "\u{ab75}", "\u{13a5}", "\u{13a5}", "\u{13a5}"),
+    (0xab76, "\u{ab76}", "\u{13a6}", "\u{13a6}", "\u{13a6}"),
+    (0xab77, "\u{ab77}", "\u{13a7}", "\u{13a7}", "\u{13a7}"),
+    (0xab78, "\u{ab78}", "\u{13a8}", "\u{13a8}", "\u{13a8}"),
+    (0xab79, "\u{ab79}", "\u{13a9}", "\u{13a9}", "\u{13a9}"),
+    (0xab7a, "\u{ab7a}", "\u{13aa}", "\u{13aa}", "\u{13aa}"),
+    (0xab7b, "\u{ab7b}", "\u{13ab}", "\u{13ab}", "\u{13ab}"),
+    (0xab7c, "\u{ab7c}", "\u{13ac}", "\u{13ac}", "\u{13ac}"),
+    (0xab7d, "\u{ab7d}", "\u{13ad}", "\u{13ad}", "\u{13ad}"),
+    (0xab7e, "\u{ab7e}", "\u{13ae}", "\u{13ae}", "\u{13ae}"),
+    (0xab7f, "\u{ab7f}", "\u{13af}", "\u{13af}", "\u{13af}"),
+    (0xab80, "\u{ab80}", "\u{13b0}", "\u{13b0}", "\u{13b0}"),
+    (0xab81, "\u{ab81}", "\u{13b1}", "\u{13b1}", "\u{13b1}"),
+    (0xab82, "\u{ab82}", "\u{13b2}", "\u{13b2}", "\u{13b2}"),
+    (0xab83, "\u{ab83}", "\u{13b3}", "\u{13b3}", "\u{13b3}"),
+    (0xab84, "\u{ab84}", "\u{13b4}", "\u{13b4}", "\u{13b4}"),
+    (0xab85, "\u{ab85}", "\u{13b5}", "\u{13b5}", "\u{13b5}"),
+    (0xab86, "\u{ab86}", "\u{13b6}", "\u{13b6}", "\u{13b6}"),
+    (0xab87, "\u{ab87}", "\u{13b7}", "\u{13b7}", "\u{13b7}"),
+    (0xab88, "\u{ab88}", "\u{13b8}", "\u{13b8}", "\u{13b8}"),
+    (0xab89, "\u{ab89}", "\u{13b9}", "\u{13b9}", "\u{13b9}"),
+    (0xab8a, "\u{ab8a}", "\u{13ba}", "\u{13ba}", "\u{13ba}"),
+    (0xab8b, "\u{ab8b}", "\u{13bb}", "\u{13bb}", "\u{13bb}"),
+    (0xab8c, "\u{ab8c}", "\u{13bc}", "\u{13bc}", "\u{13bc}"),
+    (0xab8d, "\u{ab8d}", "\u{13bd}", "\u{13bd}", "\u{13bd}"),
+    (0xab8e, "\u{ab8e}", "\u{13be}", "\u{13be}", "\u{13be}"),
+    (0xab8f, "\u{ab8f}", "\u{13bf}", "\u{13bf}", "\u{13bf}"),
+    (0xab90, "\u{ab90}", "\u{13c0}", "\u{13c0}", "\u{13c0}"),
+    (0xab91, "\u{ab91}", "\u{13c1}", "\u{13c1}", "\u{13c1}"),
+    (0xab92, "\u{ab92}", "\u{13c2}", "\u{13c2}", "\u{13c2}"),
+    (0xab93, "\u{ab93}", "\u{13c3}", "\u{13c3}", "\u{13c3}"),
+    (0xab94, "\u{ab94}", "\u{13c4}", "\u{13c4}", "\u{13c4}"),
+    (0xab95, "\u{ab95}", "\u{13c5}", "\u{13c5}", "\u{13c5}"),
+    (0xab96, "\u{ab96}", "\u{13c6}", "\u{13c6}", "\u{13c6}"),
+    (0xab97, "\u{ab97}", "\u{13c7}", "\u{13c7}", "\u{13c7}"),
+    (0xab98, "\u{ab98}", "\u{13c8}", "\u{13c8}", "\u{13c8}"),
+    (0xab99, "\u{ab99}", "\u{13c9}", "\u{13c9}", "\u{13c9}"),
+    (0xab9a, "\u{ab9a}", "\u{13ca}", "\u{13ca}", "\u{13ca}"),
+    (0xab9b, "\u{ab9b}", "\u{13cb}", "\u{13cb}", "\u{13cb}"),
+    (0xab9c, "\u{ab9c}", "\u{13cc}", "\u{13cc}", "\u{13cc}"),
+    (0xab9d, "\u{ab9d}", "\u{13cd}", "\u{13cd}", "\u{13cd}"),
+    (0xab9e, "\u{ab9e}", "\u{13ce}", "\u{13ce}", "\u{13ce}"),
+    (0xab9f, "\u{ab9f}", "\u{13cf}", "\u{13cf}", "\u{13cf}"),
+    (0xaba0, "\u{aba0}", "\u{13d0}", "\u{13d0}", "\u{13d0}"),
+    (0xaba1, "\u{aba1}", "\u{13d1}", "\u{13d1}", "\u{13d1}"),
+    (0xaba2, "\u{aba2}", "\u{13d2}", "\u{13d2}", "\u{13d2}"),
+    (0xaba3, "\u{aba3}", "\u{13d3}", "\u{13d3}", "\u{13d3}"),
+    (0xaba4, "\u{aba4}", "\u{13d4}", "\u{13d4}", "\u{13d4}"),
+    (0xaba5, "\u{aba5}", "\u{13d5}", "\u{13d5}", "\u{13d5}"),
+    (0xaba6, "\u{aba6}", "\u{13d6}", "\u{13d6}", "\u{13d6}"),
+    (0xaba7, "\u{aba7}", "\u{13d7}", "\u{13d7}", "\u{13d7}"),
+    (0xaba8, "\u{aba8}", "\u{13d8}", "\u{13d8}", "\u{13d8}"),
+    (0xaba9, "\u{aba9}", "\u{13d9}", "\u{13d9}", "\u{13d9}"),
+    (0xabaa, "\u{abaa}", "\u{13da}", "\u{13da}", "\u{13da}"),
+    (0xabab, "\u{abab}", "\u{13db}", "\u{13db}", "\u{13db}"),
+    (0xabac, "\u{abac}", "\u{13dc}", "\u{13dc}", "\u{13dc}"),
+    (0xabad, "\u{abad}", "\u{13dd}", "\u{13dd}", "\u{13dd}"),
+    (0xabae, "\u{abae}", "\u{13de}", "\u{13de}", "\u{13de}"),
+    (0xabaf, "\u{abaf}", "\u{13df}", "\u{13df}", "\u{13df}"),
+    (0xabb0, "\u{abb0}", "\u{13e0}", "\u{13e0}", "\u{13e0}"),
+    (0xabb1, "\u{abb1}", "\u{13e1}", "\u{13e1}", "\u{13e1}"),
+    (0xabb2, "\u{abb2}", "\u{13e2}", "\u{13e2}", "\u{13e2}"),
+    (0xabb3, "\u{abb3}", "\u{13e3}", "\u{13e3}", "\u{13e3}"),
+    (0xabb4, "\u{abb4}", "\u{13e4}", "\u{13e4}", "\u{13e4}"),
+    (0xabb5, "\u{abb5}", "\u{13e5}", "\u{13e5}", "\u{13e5}"),
+    (0xabb6, "\u{abb6}", "\u{13e6}", "\u{13e6}", "\u{13e6}"),
+    (0xabb7, "\u{abb7}", "\u{13e7}", "\u{13e7}", "\u{13e7}"),
+    (0xabb8, "\u{abb8}", "\u{13e8}", "\u{13e8}", "\u{13e8}"),
+    (0xabb9, "\u{abb9}", "\u{13e9}", "\u{13e9}", "\u{13e9}"),
+    (0xabba, "\u{abba}", "\u{13ea}", "\u{13ea}", "\u{13ea}"),
+    (0xabbb, "\u{abbb}", "\u{13eb}", "\u{13eb}", "\u{13eb}"),
+    (0xabbc, "\u{abbc}", "\u{13ec}", "\u{13ec}", "\u{13ec}"),
+    (0xabbd, "\u{abbd}", "\u{13ed}", "\u{13ed}", "\u{13ed}"),
+    (0xabbe, "\u{abbe}", "\u{13ee}", "\u{13ee}", "\u{13ee}"),
+    (0xabbf, "\u{abbf}", "\u{13ef}", "\u{13ef}", "\u{13ef}"),
+    (0xfb00, "\u{fb00}", "FF", "Ff", "ff"),
+    (0xfb01, "\u{fb01}", "FI", "Fi", "fi"),
+    (0xfb02, "\u{fb02}", "FL", "Fl", "fl"),
+    (0xfb03, "\u{fb03}", "FFI", "Ffi", "ffi"),
+    (0xfb04, "\u{fb04}", "FFL", "Ffl", "ffl"),
+    (0xfb05, "\u{fb05}", "ST", "St", "st"),
+    (0xfb06, "\u{fb06}", "ST", "St", "st"),
+    (0xfb13, "\u{fb13}", "\u{544}\u{546}", "\u{544}\u{576}", "\u{574}\u{576}"),
+    (0xfb14, "\u{fb14}", "\u{544}\u{535}", "\u{544}\u{565}", "\u{574}\u{565}"),
+    (0xfb15, "\u{fb15}", "\u{544}\u{53b}", "\u{544}\u{56b}", "\u{574}\u{56b}"),
+    (0xfb16, "\u{fb16}", "\u{54e}\u{546}", "\u{54e}\u{576}", "\u{57e}\u{576}"),
+    (0xfb17, "\u{fb17}", "\u{544}\u{53d}", "\u{544}\u{56d}", "\u{574}\u{56d}"),
+    (0xff21, "\u{ff41}", "\u{ff21}", "\u{ff21}", "\u{ff41}"),
+    (0xff22, "\u{ff42}", "\u{ff22}", "\u{ff22}", "\u{ff42}"),
+    (0xff23, "\u{ff43}", "\u{ff23}", "\u{ff23}", "\u{ff43}"),
+    (0xff24, "\u{ff44}", "\u{ff24}", "\u{ff24}", "\u{ff44}"),
+    (0xff25, "\u{ff45}", "\u{ff25}", "\u{ff25}", "\u{ff45}"),
+    (0xff26, "\u{ff46}", "\u{ff26}", "\u{ff26}", "\u{ff46}"),
+    (0xff27, "\u{ff47}", "\u{ff27}", "\u{ff27}", "\u{ff47}"),
+    (0xff28, "\u{ff48}", "\u{ff28}", "\u{ff28}", "\u{ff48}"),
+    (0xff29, "\u{ff49}", "\u{ff29}", "\u{ff29}", "\u{ff49}"),
+    (0xff2a, "\u{ff4a}", "\u{ff2a}", "\u{ff2a}", "\u{ff4a}"),
+    (0xff2b, "\u{ff4b}", "\u{ff2b}", "\u{ff2b}", "\u{ff4b}"),
+    (0xff2c, "\u{ff4c}", "\u{ff2c}", "\u{ff2c}", "\u{ff4c}"),
+    (0xff2d, "\u{ff4d}", "\u{ff2d}", "\u{ff2d}", "\u{ff4d}"),
+    (0xff2e, "\u{ff4e}", "\u{ff2e}", "\u{ff2e}", "\u{ff4e}"),
+    (0xff2f, "\u{ff4f}", "\u{ff2f}", "\u{ff2f}", "\u{ff4f}"),
+    (0xff30, "\u{ff50}", "\u{ff30}", "\u{ff30}", "\u{ff50}"),
+    (0xff31, "\u{ff51}", "\u{ff31}", "\u{ff31}", "\u{ff51}"),
+    (0xff32, "\u{ff52}", "\u{ff32}", "\u{ff32}", "\u{ff52}"),
+    (0xff33, "\u{ff53}", "\u{ff33}", "\u{ff33}", "\u{ff53}"),
+    (0xff34, "\u{ff54}", "\u{ff34}", "\u{ff34}", "\u{ff54}"),
+    (0xff35, "\u{ff55}", "\u{ff35}", "\u{ff35}", "\u{ff55}"),
+    (0xff36, "\u{ff56}", "\u{ff36}", "\u{ff36}", "\u{ff56}"),
+    (0xff37, "\u{ff57}", "\u{ff37}", "\u{ff37}", "\u{ff57}"),
+    (0xff38, "\u{ff58}", "\u{ff38}", "\u{ff38}", "\u{ff58}"),
+    (0xff39, "\u{ff59}", "\u{ff39}", "\u{ff39}", "\u{ff59}"),
+    (0xff3a, "\u{ff5a}", "\u{ff3a}", "\u{ff3a}", "\u{ff5a}"),
+    (0xff41, "\u{ff41}", "\u{ff21}", "\u{ff21}", "\u{ff41}"),
+    (0xff42, "\u{ff42}", "\u{ff22}", "\u{ff22}", "\u{ff42}"),
+    (0xff43, "\u{ff43}", "\u{ff23}", "\u{ff23}", "\u{ff43}"),
+    (0xff44, "\u{ff44}", "\u{ff24}", "\u{ff24}", "\u{ff44}"),
+    (0xff45, "\u{ff45}", "\u{ff25}", "\u{ff25}", "\u{ff45}"),
+    (0xff46, "\u{ff46}", "\u{ff26}", "\u{ff26}", "\u{ff46}"),
+    (0xff47, "\u{ff47}", "\u{ff27}", "\u{ff27}", "\u{ff47}"),
+    (0xff48, "\u{ff48}", "\u{ff28}", "\u{ff28}", "\u{ff48}"),
+    (0xff49, "\u{ff49}", "\u{ff29}", "\u{ff29}", "\u{ff49}"),
+    (0xff4a, "\u{ff4a}", "\u{ff2a}", "\u{ff2a}", "\u{ff4a}"),
+    (0xff4b, "\u{ff4b}", "\u{ff2b}", "\u{ff2b}", "\u{ff4b}"),
+    (0xff4c, "\u{ff4c}", "\u{ff2c}", "\u{ff2c}", "\u{ff4c}"),
+    (0xff4d, "\u{ff4d}", "\u{ff2d}", "\u{ff2d}", "\u{ff4d}"),
+    (0xff4e, "\u{ff4e}", "\u{ff2e}", "\u{ff2e}", "\u{ff4e}"),
+    (0xff4f, "\u{ff4f}", "\u{ff2f}", "\u{ff2f}", "\u{ff4f}"),
+    (0xff50, "\u{ff50}", "\u{ff30}", "\u{ff30}", "\u{ff50}"),
+    (0xff51, "\u{ff51}", "\u{ff31}", "\u{ff31}", "\u{ff51}"),
+    (0xff52, "\u{ff52}", "\u{ff32}", "\u{ff32}", "\u{ff52}"),
+    (0xff53, "\u{ff53}", "\u{ff33}", "\u{ff33}", "\u{ff53}"),
+    (0xff54, "\u{ff54}", "\u{ff34}", "\u{ff34}", "\u{ff54}"),
+    (0xff55, "\u{ff55}", "\u{ff35}", "\u{ff35}", "\u{ff55}"),
+    (0xff56, "\u{ff56}", "\u{ff36}", "\u{ff36}", "\u{ff56}"),
+    (0xff57, "\u{ff57}", "\u{ff37}", "\u{ff37}", "\u{ff57}"),
+    (0xff58, "\u{ff58}", "\u{ff38}", "\u{ff38}", "\u{ff58}"),
+    (0xff59, "\u{ff59}", "\u{ff39}", "\u{ff39}", "\u{ff59}"),
+    (0xff5a, "\u{ff5a}", "\u{ff3a}", "\u{ff3a}", "\u{ff5a}"),
+    (0x10400, "\u{10428}", "\u{10400}", "\u{10400}", "\u{10428}"),
+    (0x10401, "\u{10429}", "\u{10401}", "\u{10401}", "\u{10429}"),
+    (0x10402, "\u{1042a}", "\u{10402}", "\u{10402}", "\u{1042a}"),
+    (0x10403, "\u{1042b}", "\u{10403}", "\u{10403}", "\u{1042b}"),
+    (0x10404, "\u{1042c}", "\u{10404}", "\u{10404}", "\u{1042c}"),
+    (0x10405, "\u{1042d}", "\u{10405}", "\u{10405}", "\u{1042d}"),
+    (0x10406, "\u{1042e}", "\u{10406}", "\u{10406}", "\u{1042e}"),
+    (0x10407, "\u{1042f}", "\u{10407}", "\u{10407}", "\u{1042f}"),
+    (0x10408, "\u{10430}", "\u{10408}", "\u{10408}", "\u{10430}"),
+    (0x10409, "\u{10431}", "\u{10409}", "\u{10409}", "\u{10431}"),
+    (0x1040a, "\u{10432}", "\u{1040a}", "\u{1040a}", "\u{10432}"),
+    (0x1040b, "\u{10433}", "\u{1040b}", "\u{1040b}", "\u{10433}"),
+    (0x1040c, "\u{10434}", "\u{1040c}", "\u{1040c}", "\u{10434}"),
+    (0x1040d, "\u{10435}", "\u{1040d}", "\u{1040d}", "\u{10435}"),
+    (0x1040e, "\u{10436}", "\u{1040e}", "\u{1040e}", "\u{10436}"),
+    (0x1040f, "\u{10437}", "\u{1040f}", "\u{1040f}", "\u{10437}"),
+    (0x10410, "\u{10438}", "\u{10410}", "\u{10410}", "\u{10438}"),
+    (0x10411, "\u{10439}", "\u{10411}", "\u{10411}", "\u{10439}"),
+    (0x10412, "\u{1043a}", "\u{10412}", "\u{10412}", "\u{1043a}"),
+    (0x10413, "\u{1043b}", "\u{10413}", "\u{10413}", "\u{1043b}"),
+    (0x10414, "\u{1043c}", "\u{10414}", "\u{10414}", "\u{1043c}"),
+    (0x10415, "\u{1043d}", "\u{10415}", "\u{10415}", "\u{1043d}"),
+    (0x10416, "\u{1043e}", "\u{10416}", "\u{10416}", "\u{1043e}"),
+    (0x10417, "\u{1043f}", "\u{10417}", "\u{10417}", "\u{1043f}"),
+    (0x10418, "\u{10440}", "\u{10418}", "\u{10418}", "\u{10440}"),
+    (0x10419, "\u{10441}", "\u{10419}", "\u{10419}", "\u{10441}"),
+    (0x1041a, "\u{10442}", "\u{1041a}", "\u{1041a}", "\u{10442}"),
+    (0x1041b, "\u{10443}", "\u{1041b}", "\u{1041b}", "\u{10443}"),
+    (0x1041c, "\u{10444}", "\u{1041c}", "\u{1041c}", "\u{10444}"),
+    (0x1041d, "\u{10445}", "\u{1041d}", "\u{1041d}", "\u{10445}"),
+    (0x1041e, "\u{10446}", "\u{1041e}", "\u{1041e}", "\u{10446}"),
+    (0x1041f, "\u{10447}", "\u{1041f}", "\u{1041f}", "\u{10447}"),
+    (0x10420, "\u{10448}", "\u{10420}", "\u{10420}", "\u{10448}"),
+    (0x10421, "\u{10449}", "\u{10421}", "\u{10421}", "\u{10449}"),
+    (0x10422, "\u{1044a}", "\u{10422}", "\u{10422}", "\u{1044a}"),
+    (0x10423, "\u{1044b}", "\u{10423}", "\u{10423}", "\u{1044b}"),
+    (0x10424, "\u{1044c}", "\u{10424}", "\u{10424}", "\u{1044c}"),
+    (0x10425, "\u{1044d}", "\u{10425}", "\u{10425}", "\u{1044d}"),
+    (0x10426, "\u{1044e}", "\u{10426}", "\u{10426}", "\u{1044e}"),
+    (0x10427, "\u{1044f}", "\u{10427}", "\u{10427}", "\u{1044f}"),
+    (0x10428, "\u{10428}", "\u{10400}", "\u{10400}", "\u{10428}"),
+    (0x10429, "\u{10429}", "\u{10401}", "\u{10401}", "\u{10429}"),
+    (0x1042a, "\u{1042a}", "\u{10402}", "\u{10402}", "\u{1042a}"),
+    (0x1042b, "\u{1042b}", "\u{10403}", "\u{10403}", "\u{1042b}"),
+    (0x1042c, "\u{1042c}", "\u{10404}", "\u{10404}", "\u{1042c}"),
+    (0x1042d, "\u{1042d}", "\u{10405}", "\u{10405}", "\u{1042d}"),
+    (0x1042e, "\u{1042e}", "\u{10406}", "\u{10406}", "\u{1042e}"),
+    (0x1042f, "\u{1042f}", "\u{10407}", "\u{10407}", "\u{1042f}"),
+    (0x10430, "\u{10430}", "\u{10408}", "\u{10408}", "\u{10430}"),
+    (0x10431, "\u{10431}", "\u{10409}", "\u{10409}", "\u{10431}"),
+    (0x10432, "\u{10432}", "\u{1040a}", "\u{1040a}", "\u{10432}"),
+    (0x10433, "\u{10433}", "\u{1040b}", "\u{1040b}", "\u{10433}"),
+    (0x10434, "\u{10434}", "\u{1040c}", "\u{1040c}", "\u{10434}"),
+    (0x10435, "\u{10435}", "\u{1040d}", "\u{1040d}", "\u{10435}"),
+    (0x10436, "\u{10436}", "\u{1040e}", "\u{1040e}", "\u{10436}"),
+    (0x10437, "\u{10437}", "\u{1040f}", "\u{1040f}", "\u{10437}"),
+    (0x10438, "\u{10438}", "\u{10410}", "\u{10410}", "\u{10438}"),
+    (0x10439, "\u{10439}", "\u{10411}", "\u{10411}", "\u{10439}"),
+    (0x1043a, "\u{1043a}", "\u{10412}", "\u{10412}", "\u{1043a}"),
+    (0x1043b, "\u{1043b}", "\u{10413}", "\u{10413}", "\u{1043b}"),
+    (0x1043c, "\u{1043c}", "\u{10414}", "\u{10414}", "\u{1043c}"),
+    (0x1043d, "\u{1043d}", "\u{10415}", "\u{10415}", "\u{1043d}"),
+    (0x1043e, "\u{1043e}", "\u{10416}", "\u{10416}", "\u{1043e}"),
+    (0x1043f, "\u{1043f}", "\u{10417}", "\u{10417}", "\u{1043f}"),
+    (0x10440, "\u{10440}", "\u{10418}", "\u{10418}", "\u{10440}"),
+    (0x10441, "\u{10441}", "\u{10419}", "\u{10419}", "\u{10441}"),
+    (0x10442, "\u{10442}", "\u{1041a}", "\u{1041a}", "\u{10442}"),
+    (0x10443, "\u{10443}", "\u{1041b}", "\u{1041b}", "\u{10443}"),
+    (0x10444, "\u{10444}", "\u{1041c}", "\u{1041c}", "\u{10444}"),
+    (0x10445, "\u{10445}", "\u{1041d}", "\u{1041d}", "\u{10445}"),
+    (0x10446, "\u{10446}", "\u{1041e}", "\u{1041e}", "\u{10446}"),
+    (0x10447, "\u{10447}", "\u{1041f}", "\u{1041f}", "\u{10447}"),
+    (0x10448, "\u{10448}", "\u{10420}", "\u{10420}", "\u{10448}"),
+    (0x10449, "\u{10449}", "\u{10421}", "\u{10421}", "\u{10449}"),
+    (0x1044a, "\u{1044a}", "\u{10422}", "\u{10422}", "\u{1044a}"),
+    (0x1044b, "\u{1044b}", "\u{10423}", "\u{10423}", "\u{1044b}"),
+    (0x1044c, "\u{1044c}", "\u{10424}", "\u{10424}", "\u{1044c}"),
+    (0x1044d, "\u{1044d}", "\u{10425}", "\u{10425}", "\u{1044d}"),
+    (0x1044e, "\u{1044e}", "\u{10426}", "\u{10426}", "\u{1044e}"),
+    (0x1044f, "\u{1044f}", "\u{10427}", "\u{10427}", "\u{1044f}"),
+    (0x104b0, "\u{104d8}", "\u{104b0}", "\u{104b0}", "\u{104d8}"),
+    (0x104b1, "\u{104d9}", "\u{104b1}", "\u{104b1}", "\u{104d9}"),
+    (0x104b2, "\u{104da}", "\u{104b2}", "\u{104b2}", "\u{104da}"),
+    (0x104b3, "\u{104db}", "\u{104b3}", "\u{104b3}", "\u{104db}"),
+    (0x104b4, "\u{104dc}", "\u{104b4}", "\u{104b4}", "\u{104dc}"),
+    (0x104b5, "\u{104dd}", "\u{104b5}", "\u{104b5}", "\u{104dd}"),
+    (0x104b6, "\u{104de}", "\u{104b6}", "\u{104b6}", "\u{104de}"),
+    (0x104b7, "\u{104df}", "\u{104b7}", "\u{104b7}", "\u{104df}"),
+    (0x104b8, "\u{104e0}", "\u{104b8}", "\u{104b8}", "\u{104e0}"),
+    (0x104b9, "\u{104e1}", "\u{104b9}", "\u{104b9}", "\u{104e1}"),
+    (0x104ba, "\u{104e2}", "\u{104ba}", "\u{104ba}", "\u{104e2}"),
+    (0x104bb, "\u{104e3}", "\u{104bb}", "\u{104bb}", "\u{104e3}"),
+    (0x104bc, "\u{104e4}", "\u{104bc}", "\u{104bc}", "\u{104e4}"),
+    (0x104bd, "\u{104e5}", "\u{104bd}", "\u{104bd}", "\u{104e5}"),
+    (0x104be, "\u{104e6}", "\u{104be}", "\u{104be}", "\u{104e6}"),
+    (0x104bf, "\u{104e7}", "\u{104bf}", "\u{104bf}", "\u{104e7}"),
+    (0x104c0, "\u{104e8}", "\u{104c0}", "\u{104c0}", "\u{104e8}"),
+    (0x104c1, "\u{104e9}", "\u{104c1}", "\u{104c1}", "\u{104e9}"),
+    (0x104c2, "\u{104ea}", "\u{104c2}", "\u{104c2}", "\u{104ea}"),
+    (0x104c3, "\u{104eb}", "\u{104c3}", "\u{104c3}", "\u{104eb}"),
+    (0x104c4, "\u{104ec}", "\u{104c4}", "\u{104c4}", "\u{104ec}"),
+    (0x104c5, "\u{104ed}", "\u{104c5}", "\u{104c5}", "\u{104ed}"),
+    (0x104c6, "\u{104ee}", "\u{104c6}", "\u{104c6}", "\u{104ee}"),
+    (0x104c7, "\u{104ef}", "\u{104c7}", "\u{104c7}", "\u{104ef}"),
+    (0x104c8, "\u{104f0}", "\u{104c8}", "\u{104c8}", "\u{104f0}"),
+    (0x104c9, "\u{104f1}", "\u{104c9}", "\u{104c9}", "\u{104f1}"),
+    (0x104ca, "\u{104f2}", "\u{104ca}", "\u{104ca}", "\u{104f2}"),
+    (0x104cb, "\u{104f3}", "\u{104cb}", "\u{104cb}", "\u{104f3}"),
+    (0x104cc, "\u{104f4}", "\u{104cc}", "\u{104cc}", "\u{104f4}"),
+    (0x104cd, "\u{104f5}", "\u{104cd}", "\u{104cd}", "\u{104f5}"),
+    (0x104ce, "\u{104f6}", "\u{104ce}", "\u{104ce}", "\u{104f6}"),
+    (0x104cf, "\u{104f7}", "\u{104cf}", "\u{104cf}", "\u{104f7}"),
+    (0x104d0, "\u{104f8}", "\u{104d0}", "\u{104d0}", "\u{104f8}"),
+    (0x104d1, "\u{104f9}", "\u{104d1}", "\u{104d1}", "\u{104f9}"),
+    (0x104d2, "\u{104fa}", "\u{104d2}", "\u{104d2}", "\u{104fa}"),
+    (0x104d3, "\u{104fb}", "\u{104d3}", "\u{104d3}", "\u{104fb}"),
+    (0x104d8, "\u{104d8}", "\u{104b0}", "\u{104b0}", "\u{104d8}"),
+    (0x104d9, "\u{104d9}", "\u{104b1}", "\u{104b1}", "\u{104d9}"),
+    (0x104da, "\u{104da}", "\u{104b2}", "\u{104b2}", "\u{104da}"),
+    (0x104db, "\u{104db}", "\u{104b3}", "\u{104b3}", "\u{104db}"),
+    (0x104dc, "\u{104dc}", "\u{104b4}", "\u{104b4}", "\u{104dc}"),
+    (0x104dd, "\u{104dd}", "\u{104b5}", "\u{104b5}", "\u{104dd}"),
+    (0x104de, "\u{104de}", "\u{104b6}", "\u{104b6}", "\u{104de}"),
+    (0x104df, "\u{104df}", "\u{104b7}", "\u{104b7}", "\u{104df}"),
+    (0x104e0, "\u{104e0}", "\u{104b8}", "\u{104b8}", "\u{104e0}"),
+    (0x104e1, "\u{104e1}", "\u{104b9}", "\u{104b9}", "\u{104e1}"),
+    (0x104e2, "\u{104e2}", "\u{104ba}", "\u{104ba}", "\u{104e2}"),
+    (0x104e3, "\u{104e3}", "\u{104bb}", "\u{104bb}", "\u{104e3}"),
+    (0x104e4, "\u{104e4}", "\u{104bc}", "\u{104bc}", "\u{104e4}"),
+    (0x104e5, "\u{104e5}", "\u{104bd}", "\u{104bd}", "\u{104e5}"),
+    (0x104e6, "\u{104e6}", "\u{104be}", "\u{104be}", "\u{104e6}"),
+    (0x104e7, "\u{104e7}", "\u{104bf}", "\u{104bf}", "\u{104e7}"),
+    (0x104e8, "\u{104e8}", "\u{104c0}", "\u{104c0}", "\u{104e8}"),
+    (0x104e9, "\u{104e9}", "\u{104c1}", "\u{104c1}", "\u{104e9}"),
+    (0x104ea, "\u{104ea}", "\u{104c2}", "\u{104c2}", "\u{104ea}"),
+    (0x104eb, "\u{104eb}", "\u{104c3}", "\u{104c3}", "\u{104eb}"),
+    (0x104ec, "\u{104ec}", "\u{104c4}", "\u{104c4}", "\u{104ec}"),
+    (0x104ed, "\u{104ed}", "\u{104c5}", "\u{104c5}", "\u{104ed}"),
+    (0x104ee, "\u{104ee}", "\u{104c6}", "\u{104c6}", "\u{104ee}"),
+    (0x104ef, "\u{104ef}", "\u{104c7}", "\u{104c7}", "\u{104ef}"),
+    (0x104f0, "\u{104f0}", "\u{104c8}", "\u{104c8}", "\u{104f0}"),
+    (0x104f1, "\u{104f1}", "\u{104c9}", "\u{104c9}", "\u{104f1}"),
+    (0x104f2, "\u{104f2}", "\u{104ca}", "\u{104ca}", "\u{104f2}"),
+    (0x104f3, "\u{104f3}", "\u{104cb}", "\u{104cb}", "\u{104f3}"),
+    (0x104f4, "\u{104f4}", "\u{104cc}", "\u{104cc}", "\u{104f4}"),
+    (0x104f5, "\u{104f5}", "\u{104cd}", "\u{104cd}", "\u{104f5}"),
+    (0x104f6, "\u{104f6}", "\u{104ce}", "\u{104ce}", "\u{104f6}"),
+    (0x104f7, "\u{104f7}", "\u{104cf}", "\u{104cf}", "\u{104f7}"),
+    (0x104f8, "\u{104f8}", "\u{104d0}", "\u{104d0}", "\u{104f8}"),
+    (0x104f9, "\u{104f9}", "\u{104d1}", "\u{104d1}", "\u{104f9}"),
+    (0x104fa, "\u{104fa}", "\u{104d2}", "\u{104d2}", "\u{104fa}"),
+    (0x104fb, "\u{104fb}", "\u{104d3}", "\u{104d3}", "\u{104fb}"),
+    (0x10570, "\u{10597}", "\u{10570}", "\u{10570}", "\u{10597}"),
+    (0x10571, "\u{10598}", "\u{10571}", "\u{10571}", "\u{10598}"),
+    (0x10572, "\u{10599}", "\u{10572}", "\u{10572}", "\u{10599}"),
+    (0x10573, "\u{1059a}", "\u{10573}", "\u{10573}", "\u{1059a}"),
+    (0x10574, "\u{1059b}", "\u{10574}", "\u{10574}", "\u{1059b}"),
+    (0x10575, "\u{1059c}", "\u{10575}", "\u{10575}", "\u{1059c}"),
+    (0x10576, "\u{1059d}", "\u{10576}", "\u{10576}", "\u{1059d}"),
+    (0x10577, "\u{1059e}", "\u{10577}", "\u{10577}", "\u{1059e}"),
+    (0x10578, "\u{1059f}", "\u{10578}", "\u{10578}", "\u{1059f}"),
+    (0x10579, "\u{105a0}", "\u{10579}", "\u{10579}", "\u{105a0}"),
+    (0x1057a, "\u{105a1}", "\u{1057a}", "\u{1057a}", "\u{105a1}"),
+    (0x1057c, "\u{105a3}", "\u{1057c}", "\u{1057c}", "\u{105a3}"),
+    (0x1057d, "\u{105a4}", "\u{1057d}", "\u{1057d}", "\u{105a4}"),
+    (0x1057e, "\u{105a5}", "\u{1057e}", "\u{1057e}", "\u{105a5}"),
+    (0x1057f, "\u{105a6}", "\u{1057f}", "\u{1057f}", "\u{105a6}"),
+    (0x10580, "\u{105a7}", "\u{10580}", "\u{10580}", "\u{105a7}"),
+    (0x10581, "\u{105a8}", "\u{10581}", "\u{10581}", "\u{105a8}"),
+    (0x10582, "\u{105a9}", "\u{10582}", "\u{10582}", "\u{105a9}"),
+    (0x10583, "\u{105aa}", "\u{10583}", "\u{10583}", "\u{105aa}"),
+    (0x10584, "\u{105ab}", "\u{10584}", "\u{10584}", "\u{105ab}"),
+    (0x10585, "\u{105ac}", "\u{10585}", "\u{10585}", "\u{105ac}"),
+    (0x10586, "\u{105ad}", "\u{10586}", "\u{10586}", "\u{105ad}"),
+    (0x10587, "\u{105ae}", "\u{10587}", "\u{10587}", "\u{105ae}"),
+    (0x10588, "\u{105af}", "\u{10588}", "\u{10588}", "\u{105af}"),
+    (0x10589, "\u{105b0}", "\u{10589}", "\u{10589}", "\u{105b0}"),
+    (0x1058a, "\u{105b1}", "\u{1058a}", "\u{1058a}", "\u{105b1}"),
+    (0x1058c, "\u{105b3}", "\u{1058c}", "\u{1058c}", "\u{105b3}"),
+    (0x1058d, "\u{105b4}", "\u{1058d}", "\u{1058d}", "\u{105b4}"),
+    (0x1058e, "\u{105b5}", "\u{1058e}", "\u{1058e}", "\u{105b5}"),
+    (0x1058f, "\u{105b6}", "\u{1058f}", "\u{1058f}", "\u{105b6}"),
+    (0x10590, "\u{105b7}", "\u{10590}", "\u{10590}", "\u{105b7}"),
+    (0x10591, "\u{105b8}", "\u{10591}", "\u{10591}", "\u{105b8}"),
+    (0x10592, "\u{105b9}", "\u{10592}", "\u{10592}", "\u{105b9}"),
+    (0x10594, "\u{105bb}", "\u{10594}", "\u{10594}", "\u{105bb}"),
+    (0x10595, "\u{105bc}", "\u{10595}", "\u{10595}", "\u{105bc}"),
+    (0x10597, "\u{10597}", "\u{10570}", "\u{10570}", "\u{10597}"),
+    (0x10598, "\u{10598}", "\u{10571}", "\u{10571}", "\u{10598}"),
+    (0x10599, "\u{10599}", "\u{10572}", "\u{10572}", "\u{10599}"),
+    (0x1059a, "\u{1059a}", "\u{10573}", "\u{10573}", "\u{1059a}"),
+    (0x1059b, "\u{1059b}", "\u{10574}", "\u{10574}", "\u{1059b}"),
+    (0x1059c, "\u{1059c}", "\u{10575}", "\u{10575}", "\u{1059c}"),
+    (0x1059d, "\u{1059d}", "\u{10576}", "\u{10576}", "\u{1059d}"),
+    (0x1059e, "\u{1059e}", "\u{10577}", "\u{10577}", "\u{1059e}"),
+    (0x1059f, "\u{1059f}", "\u{10578}", "\u{10578}", "\u{1059f}"),
+    (0x105a0, "\u{105a0}", "\u{10579}", "\u{10579}", "\u{105a0}"),
+    (0x105a1, "\u{105a1}", "\u{1057a}", "\u{1057a}", "\u{105a1}"),
+    (0x105a3, "\u{105a3}", "\u{1057c}", "\u{1057c}", "\u{105a3}"),
+    (0x105a4, "\u{105a4}", "\u{1057d}", "\u{1057d}", "\u{105a4}"),
+    (0x105a5, "\u{105a5}", "\u{1057e}", "\u{1057e}", "\u{105a5}"),
+    (0x105a6, "\u{105a6}", "\u{1057f}", "\u{1057f}", "\u{105a6}"),
+    (0x105a7, "\u{105a7}", "\u{10580}", "\u{10580}", "\u{105a7}"),
+    (0x105a8, "\u{105a8}", "\u{10581}", "\u{10581}", "\u{105a8}"),
+    (0x105a9, "\u{105a9}", "\u{10582}", "\u{10582}", "\u{105a9}"),
+    (0x105aa, "\u{105aa}", "\u{10583}", "\u{10583}", "\u{105aa}"),
+    (0x105ab, "\u{105ab}", "\u{10584}", "\u{10584}", "\u{105ab}"),
+    (0x105ac, "\u{105ac}", "\u{10585}", "\u{10585}", "\u{105ac}"),
+    (0x105ad, "\u{105ad}", "\u{10586}", "\u{10586}", "\u{105ad}"),
+    (0x105ae, "\u{105ae}", "\u{10587}", "\u{10587}", "\u{105ae}"),
+    (0x105af, "\u{105af}", "\u{10588}", "\u{10588}", "\u{105af}"),
+    (0x105b0, "\u{105b0}", "\u{10589}", "\u{10589}", "\u{105b0}"),
+    (0x105b1, "\u{105b1}", "\u{1058a}", "\u{1058a}", "\u{105b1}"),
+    (0x105b3, "\u{105b3}", "\u{1058c}", "\u{1058c}", "\u{105b3}"),
+    (0x105b4, "\u{105b4}", "\u{1058d}", "\u{1058d}", "\u{105b4}"),
+    (0x105b5, "\u{105b5}", "\u{1058e}", "\u{1058e}", "\u{105b5}"),
+    (0x105b6, "\u{105b6}", "\u{1058f}", "\u{1058f}", "\u{105b6}"),
+    (0x105b7, "\u{105b7}", "\u{10590}", "\u{10590}", "\u{105b7}"),
+    (0x105b8, "\u{105b8}", "\u{10591}", "\u{10591}", "\u{105b8}"),
+    (0x105b9, "\u{105b9}", "\u{10592}", "\u{10592}", "\u{105b9}"),
+    (0x105bb, "\u{105bb}", "\u{10594}", "\u{10594}", "\u{105bb}"),
+    (0x105bc, "\u{105bc}", "\u{10595}", "\u{10595}", "\u{105bc}"),
+    (0x10c80, "\u{10cc0}", "\u{10c80}", "\u{10c80}", "\u{10cc0}"),
+    (0x10c81, "\u{10cc1}", "\u{10c81}", "\u{10c81}", "\u{10cc1}"),
+    (0x10c82, "\u{10cc2}", "\u{10c82}", "\u{10c82}", "\u{10cc2}"),
+    (0x10c83, "\u{10cc3}", "\u{10c83}", "\u{10c83}", "\u{10cc3}"),
+    (0x10c84, "\u{10cc4}", "\u{10c84}", "\u{10c84}", "\u{10cc4}"),
+    (0x10c85, "\u{10cc5}", "\u{10c85}", "\u{10c85}", "\u{10cc5}"),
+    (0x10c86, "\u{10cc6}", "\u{10c86}", "\u{10c86}", "\u{10cc6}"),
+    (0x10c87, "\u{10cc7}", "\u{10c87}", "\u{10c87}", "\u{10cc7}"),
+    (0x10c88, "\u{10cc8}", "\u{10c88}", "\u{10c88}", "\u{10cc8}"),
+    (0x10c89, "\u{10cc9}", "\u{10c89}", "\u{10c89}", "\u{10cc9}"),
+    (0x10c8a, "\u{10cca}", "\u{10c8a}", "\u{10c8a}", "\u{10cca}"),
+    (0x10c8b, "\u{10ccb}", "\u{10c8b}", "\u{10c8b}", "\u{10ccb}"),
+    (0x10c8c, "\u{10ccc}", "\u{10c8c}", "\u{10c8c}", "\u{10ccc}"),
+    (0x10c8d, "\u{10ccd}", "\u{10c8d}", "\u{10c8d}", "\u{10ccd}"),
+    (0x10c8e, "\u{10cce}", "\u{10c8e}", "\u{10c8e}", "\u{10cce}"),
+    (0x10c8f, "\u{10ccf}", "\u{10c8f}", "\u{10c8f}", "\u{10ccf}"),
+    (0x10c90, "\u{10cd0}", "\u{10c90}", "\u{10c90}", "\u{10cd0}"),
+    (0x10c91, "\u{10cd1}", "\u{10c91}", "\u{10c91}", "\u{10cd1}"),
+    (0x10c92, "\u{10cd2}", "\u{10c92}", "\u{10c92}", "\u{10cd2}"),
+    (0x10c93, "\u{10cd3}", "\u{10c93}", "\u{10c93}", "\u{10cd3}"),
+    (0x10c94, "\u{10cd4}", "\u{10c94}", "\u{10c94}", "\u{10cd4}"),
+    (0x10c95, "\u{10cd5}", "\u{10c95}", "\u{10c95}", "\u{10cd5}"),
+    (0x10c96, "\u{10cd6}", "\u{10c96}", "\u{10c96}", "\u{10cd6}"),
+    (0x10c97, "\u{10cd7}", "\u{10c97}", "\u{10c97}", "\u{10cd7}"),
+    (0x10c98, "\u{10cd8}", "\u{10c98}", "\u{10c98}", "\u{10cd8}"),
+    (0x10c99, "\u{10cd9}", "\u{10c99}", "\u{10c99}", "\u{10cd9}"),
+    (0x10c9a, "\u{10cda}", "\u{10c9a}", "\u{10c9a}", "\u{10cda}"),
+    (0x10c9b, "\u{10cdb}", "\u{10c9b}", "\u{10c9b}", "\u{10cdb}"),
+    (0x10c9c, "\u{10cdc}", "\u{10c9c}", "\u{10c9c}", "\u{10cdc}"),
+    (0x10c9d, "\u{10cdd}", "\u{10c9d}", "\u{10c9d}", "\u{10cdd}"),
+    (0x10c9e, "\u{10cde}", "\u{10c9e}", "\u{10c9e}", "\u{10cde}"),
+    (0x10c9f, "\u{10cdf}", "\u{10c9f}", "\u{10c9f}", "\u{10cdf}"),
+    (0x10ca0, "\u{10ce0}", "\u{10ca0}", "\u{10ca0}", "\u{10ce0}"),
+    (0x10ca1, "\u{10ce1}", "\u{10ca1}", "\u{10ca1}", "\u{10ce1}"),
+    (0x10ca2, "\u{10ce2}", "\u{10ca2}", "\u{10ca2}", "\u{10ce2}"),
+    (0x10ca3, "\u{10ce3}", "\u{10ca3}", "\u{10ca3}", "\u{10ce3}"),
+    (0x10ca4, "\u{10ce4}", "\u{10ca4}", "\u{10ca4}", "\u{10ce4}"),
+    (0x10ca5, "\u{10ce5}", "\u{10ca5}", "\u{10ca5}", "\u{10ce5}"),
+    (0x10ca6, "\u{10ce6}", "\u{10ca6}", "\u{10ca6}", "\u{10ce6}"),
+    (0x10ca7, "\u{10ce7}", "\u{10ca7}", "\u{10ca7}", "\u{10ce7}"),
+    (0x10ca8, "\u{10ce8}", "\u{10ca8}", "\u{10ca8}", "\u{10ce8}"),
+    (0x10ca9, "\u{10ce9}", "\u{10ca9}", "\u{10ca9}", "\u{10ce9}"),
+    (0x10caa, "\u{10cea}", "\u{10caa}", "\u{10caa}", "\u{10cea}"),
+    (0x10cab, "\u{10ceb}", "\u{10cab}", "\u{10cab}", "\u{10ceb}"),
+    (0x10cac, "\u{10cec}", "\u{10cac}", "\u{10cac}", "\u{10cec}"),
+    (0x10cad, "\u{10ced}", "\u{10cad}", "\u{10cad}", "\u{10ced}"),
+    (0x10cae, "\u{10cee}", "\u{10cae}", "\u{10cae}", "\u{10cee}"),
+    (0x10caf, "\u{10cef}", "\u{10caf}", "\u{10caf}", "\u{10cef}"),
+    (0x10cb0, "\u{10cf0}", "\u{10cb0}", "\u{10cb0}", "\u{10cf0}"),
+    (0x10cb1, "\u{10cf1}", "\u{10cb1}", "\u{10cb1}", "\u{10cf1}"),
+    (0x10cb2, "\u{10cf2}", "\u{10cb2}", "\u{10cb2}", "\u{10cf2}"),
+    (0x10cc0, "\u{10cc0}", "\u{10c80}", "\u{10c80}", "\u{10cc0}"),
+    (0x10cc1, "\u{10cc1}", "\u{10c81}", "\u{10c81}", "\u{10cc1}"),
+    (0x10cc2, "\u{10cc2}", "\u{10c82}", "\u{10c82}", "\u{10cc2}"),
+    (0x10cc3, "\u{10cc3}", "\u{10c83}", "\u{10c83}", "\u{10cc3}"),
+    (0x10cc4, "\u{10cc4}", "\u{10c84}", "\u{10c84}", "\u{10cc4}"),
+    (0x10cc5, "\u{10cc5}", "\u{10c85}", "\u{10c85}", "\u{10cc5}"),
+    (0x10cc6, "\u{10cc6}", "\u{10c86}", "\u{10c86}", "\u{10cc6}"),
+    (0x10cc7, "\u{10cc7}", "\u{10c87}", "\u{10c87}", "\u{10cc7}"),
+    (0x10cc8, "\u{10cc8}", "\u{10c88}", "\u{10c88}", "\u{10cc8}"),
+    (0x10cc9, "\u{10cc9}", "\u{10c89}", "\u{10c89}", "\u{10cc9}"),
+    (0x10cca, "\u{10cca}", "\u{10c8a}", "\u{10c8a}", "\u{10cca}"),
+    (0x10ccb, "\u{10ccb}", "\u{10c8b}", "\u{10c8b}", "\u{10ccb}"),
+    (0x10ccc, "\u{10ccc}", "\u{10c8c}", "\u{10c8c}", "\u{10ccc}"),
+    (0x10ccd, "\u{10ccd}", "\u{10c8d}", "\u{10c8d}", "\u{10ccd}"),
+    (0x10cce, "\u{10cce}", "\u{10c8e}", "\u{10c8e}", "\u{10cce}"),
+    (0x10ccf, "\u{10ccf}", "\u{10c8f}", "\u{10c8f}", "\u{10ccf}"),
+    (0x10cd0, "\u{10cd0}", "\u{10c90}", "\u{10c90}", "\u{10cd0}"),
+    (0x10cd1, "\u{10cd1}", "\u{10c91}", "\u{10c91}", "\u{10cd1}"),
+    (0x10cd2, "\u{10cd2}", "\u{10c92}", "\u{10c92}", "\u{10cd2}"),
+    (0x10cd3, "\u{10cd3}", "\u{10c93}", "\u{10c93}", "\u{10cd3}"),
+    (0x10cd4, "\u{10cd4}", "\u{10c94}", "\u{10c94}", "\u{10cd4}"),
+    (0x10cd5, "\u{10cd5}", "\u{10c95}", "\u{10c95}", "\u{10cd5}"),
+    (0x10cd6, "\u{10cd6}", "\u{10c96}", "\u{10c96}", "\u{10cd6}"),
+    (0x10cd7, "\u{10cd7}", "\u{10c97}", "\u{10c97}", "\u{10cd7}"),
+    (0x10cd8, "\u{10cd8}", "\u{10c98}", "\u{10c98}", "\u{10cd8}"),
+    (0x10cd9, "\u{10cd9}", "\u{10c99}", "\u{10c99}", "\u{10cd9}"),
+    (0x10cda, "\u{10cda}", "\u{10c9a}", "\u{10c9a}", "\u{10cda}"),
+    (0x10cdb, "\u{10cdb}", "\u{10c9b}", "\u{10c9b}", "\u{10cdb}"),
+    (0x10cdc, "\u{10cdc}", "\u{10c9c}", "\u{10c9c}", "\u{10cdc}"),
+    (0x10cdd, "\u{10cdd}", "\u{10c9d}", "\u{10c9d}", "\u{10cdd}"),
+    (0x10cde, "\u{10cde}", "\u{10c9e}", "\u{10c9e}", "\u{10cde}"),
+    (0x10cdf, "\u{10cdf}", "\u{10c9f}", "\u{10c9f}", "\u{10cdf}"),
+    (0x10ce0, "\u{10ce0}", "\u{10ca0}", "\u{10ca0}", "\u{10ce0}"),
+    (0x10ce1, "\u{10ce1}", "\u{10ca1}", "\u{10ca1}", "\u{10ce1}"),
+    (0x10ce2, "\u{10ce2}", "\u{10ca2}", "\u{10ca2}", "\u{10ce2}"),
+    (0x10ce3, "\u{10ce3}", "\u{10ca3}", "\u{10ca3}", "\u{10ce3}"),
+    (0x10ce4, "\u{10ce4}", "\u{10ca4}", "\u{10ca4}", "\u{10ce4}"),
+    (0x10ce5, "\u{10ce5}", "\u{10ca5}", "\u{10ca5}", "\u{10ce5}"),
+    (0x10ce6, "\u{10ce6}", "\u{10ca6}", "\u{10ca6}", "\u{10ce6}"),
+    (0x10ce7, "\u{10ce7}", "\u{10ca7}", "\u{10ca7}", "\u{10ce7}"),
+    (0x10ce8, "\u{10ce8}", "\u{10ca8}", "\u{10ca8}", "\u{10ce8}"),
+    (0x10ce9, "\u{10ce9}", "\u{10ca9}", "\u{10ca9}", "\u{10ce9}"),
+    (0x10cea, "\u{10cea}", "\u{10caa}", "\u{10caa}", "\u{10cea}"),
+    (0x10ceb, "\u{10ceb}", "\u{10cab}", "\u{10cab}", "\u{10ceb}"),
+    (0x10cec, "\u{10cec}", "\u{10cac}", "\u{10cac}", "\u{10cec}"),
+    (0x10ced, "\u{10ced}", "\u{10cad}", "\u{10cad}", "\u{10ced}"),
+    (0x10cee, "\u{10cee}", "\u{10cae}", "\u{10cae}", "\u{10cee}"),
+    (0x10cef, "\u{10cef}", "\u{10caf}", "\u{10caf}", "\u{10cef}"),
+    (0x10cf0, "\u{10cf0}", "\u{10cb0}", "\u{10cb0}", "\u{10cf0}"),
+    (0x10cf1, "\u{10cf1}", "\u{10cb1}", "\u{10cb1}", "\u{10cf1}"),
+    (0x10cf2, "\u{10cf2}", "\u{10cb2}", "\u{10cb2}", "\u{10cf2}"),
+    (0x118a0, "\u{118c0}", "\u{118a0}", "\u{118a0}", "\u{118c0}"),
+    (0x118a1, "\u{118c1}", "\u{118a1}", "\u{118a1}", "\u{118c1}"),
+    (0x118a2, "\u{118c2}", "\u{118a2}", "\u{118a2}", "\u{118c2}"),
+    (0x118a3, "\u{118c3}", "\u{118a3}", "\u{118a3}", "\u{118c3}"),
+    (0x118a4, "\u{118c4}", "\u{118a4}", "\u{118a4}", "\u{118c4}"),
+    (0x118a5, "\u{118c5}", "\u{118a5}", "\u{118a5}", "\u{118c5}"),
+    (0x118a6, "\u{118c6}", "\u{118a6}", "\u{118a6}", "\u{118c6}"),
+    (0x118a7, "\u{118c7}", "\u{118a7}", "\u{118a7}", "\u{118c7}"),
+    (0x118a8, "\u{118c8}", "\u{118a8}", "\u{118a8}", "\u{118c8}"),
+    (0x118a9, "\u{118c9}", "\u{118a9}", "\u{118a9}", "\u{118c9}"),
+    (0x118aa, "\u{118ca}", "\u{118aa}", "\u{118aa}", "\u{118ca}"),
+    (0x118ab, "\u{118cb}", "\u{118ab}", "\u{118ab}", "\u{118cb}"),
+    (0x118ac, "\u{118cc}", "\u{118ac}", "\u{118ac}", "\u{118cc}"),
+    (0x118ad, "\u{118cd}", "\u{118ad}", "\u{118ad}", "\u{118cd}"),
+    (0x118ae, "\u{118ce}", "\u{118ae}", "\u{118ae}", "\u{118ce}"),
+    (0x118af, "\u{118cf}", "\u{118af}", "\u{118af}", "\u{118cf}"),
+    (0x118b0, "\u{118d0}", "\u{118b0}", "\u{118b0}", "\u{118d0}"),
+    (0x118b1, "\u{118d1}", "\u{118b1}", "\u{118b1}", "\u{118d1}"),
+    (0x118b2, "\u{118d2}", "\u{118b2}", "\u{118b2}", "\u{118d2}"),
+    (0x118b3, "\u{118d3}", "\u{118b3}", "\u{118b3}", "\u{118d3}"),
+    (0x118b4, "\u{118d4}", "\u{118b4}", "\u{118b4}", "\u{118d4}"),
+    (0x118b5, "\u{118d5}", "\u{118b5}", "\u{118b5}", "\u{118d5}"),
+    (0x118b6, "\u{118d6}", "\u{118b6}", "\u{118b6}", "\u{118d6}"),
+    (0x118b7, "\u{118d7}", "\u{118b7}", "\u{118b7}", "\u{118d7}"),
+    (0x118b8, "\u{118d8}", "\u{118b8}", "\u{118b8}", "\u{118d8}"),
+    (0x118b9, "\u{118d9}", "\u{118b9}", "\u{118b9}", "\u{118d9}"),
+    (0x118ba, "\u{118da}", "\u{118ba}", "\u{118ba}", "\u{118da}"),
+    (0x118bb, "\u{118db}", "\u{118bb}", "\u{118bb}", "\u{118db}"),
+    (0x118bc, "\u{118dc}", "\u{118bc}", "\u{118bc}", "\u{118dc}"),
+    (0x118bd, "\u{118dd}", "\u{118bd}", "\u{118bd}", "\u{118dd}"),
+    (0x118be, "\u{118de}", "\u{118be}", "\u{118be}", "\u{118de}"),
+    (0x118bf, "\u{118df}", "\u{118bf}", "\u{118bf}", "\u{118df}"),
+    (0x118c0, "\u{118c0}", "\u{118a0}", "\u{118a0}", "\u{118c0}"),
+    (0x118c1, "\u{118c1}", "\u{118a1}", "\u{118a1}", "\u{118c1}"),
+    (0x118c2, "\u{118c2}", "\u{118a2}", "\u{118a2}", "\u{118c2}"),
+    (0x118c3, "\u{118c3}", "\u{118a3}", "\u{118a3}", "\u{118c3}"),
+    (0x118c4, "\u{118c4}", "\u{118a4}", "\u{118a4}", "\u{118c4}"),
+    (0x118c5, "\u{118c5}", "\u{118a5}", "\u{118a5}", "\u{118c5}"),
+    (0x118c6, "\u{118c6}", "\u{118a6}", "\u{118a6}", "\u{118c6}"),
+    (0x118c7, "\u{118c7}", "\u{118a7}", "\u{118a7}", "\u{118c7}"),
+    (0x118c8, "\u{118c8}", "\u{118a8}", "\u{118a8}", "\u{118c8}"),
+    (0x118c9, "\u{118c9}", "\u{118a9}", "\u{118a9}", "\u{118c9}"),
+    (0x118ca, "\u{118ca}", "\u{118aa}", "\u{118aa}", "\u{118ca}"),
+    (0x118cb, "\u{118cb}", "\u{118ab}", "\u{118ab}", "\u{118cb}"),
+    (0x118cc, "\u{118cc}", "\u{118ac}", "\u{118ac}", "\u{118cc}"),
+    (0x118cd, "\u{118cd}", "\u{118ad}", "\u{118ad}", "\u{118cd}"),
+    (0x118ce, "\u{118ce}", "\u{118ae}", "\u{118ae}", "\u{118ce}"),
+    (0x118cf, "\u{118cf}", "\u{118af}", "\u{118af}", "\u{118cf}"),
+    (0x118d0, "\u{118d0}", "\u{118b0}", "\u{118b0}", "\u{118d0}"),
+    (0x118d1, "\u{118d1}", "\u{118b1}", "\u{118b1}", "\u{118d1}"),
+    (0x118d2, "\u{118d2}", "\u{118b2}", "\u{118b2}", "\u{118d2}"),
+    (0x118d3, "\u{118d3}", "\u{118b3}", "\u{118b3}", "\u{118d3}"),
+    (0x118d4, "\u{118d4}", "\u{118b4}", "\u{118b4}", "\u{118d4}"),
+    (0x118d5, "\u{118d5}", "\u{118b5}", "\u{118b5}", "\u{118d5}"),
+    (0x118d6, "\u{118d6}", "\u{118b6}", "\u{118b6}", "\u{118d6}"),
+    (0x118d7, "\u{118d7}", "\u{118b7}", "\u{118b7}", "\u{118d7}"),
+    (0x118d8, "\u{118d8}", "\u{118b8}", "\u{118b8}", "\u{118d8}"),
+    (0x118d9, "\u{118d9}", "\u{118b9}", "\u{118b9}", "\u{118d9}"),
+    (0x118da, "\u{118da}", "\u{118ba}", "\u{118ba}", "\u{118da}"),
+    (0x118db, "\u{118db}", "\u{118bb}", "\u{118bb}", "\u{118db}"),
+    (0x118dc, "\u{118dc}", "\u{118bc}", "\u{118bc}", "\u{118dc}"),
+    (0x118dd, "\u{118dd}", "\u{118bd}", "\u{118bd}", "\u{118dd}"),
+    (0x118de, "\u{118de}", "\u{118be}", "\u{118be}", "\u{118de}"),
+    (0x118df, "\u{118df}", "\u{118bf}", "\u{118bf}", "\u{118df}"),
+    (0x16e40, "\u{16e60}", "\u{16e40}", "\u{16e40}", "\u{16e60}"),
+    (0x16e41, "\u{16e61}", "\u{16e41}", "\u{16e41}", "\u{16e61}"),
+    (0x16e42, "\u{16e62}", "\u{16e42}", "\u{16e42}", "\u{16e62}"),
+    (0x16e43, "\u{16e63}", "\u{16e43}", "\u{16e43}", "\u{16e63}"),
+    (0x16e44, "\u{16e64}", "\u{16e44}", "\u{16e44}", "\u{16e64}"),
+    (0x16e45, "\u{16e65}", "\u{16e45}", "\u{16e45}", "\u{16e65}"),
+    (0x16e46, "\u{16e66}", "\u{16e46}", "\u{16e46}", "\u{16e66}"),
+    (0x16e47, "\u{16e67}", "\u{16e47}", "\u{16e47}", "\u{16e67}"),
+    (0x16e48, "\u{16e68}", "\u{16e48}", "\u{16e48}", "\u{16e68}"),
+    (0x16e49, "\u{16e69}", "\u{16e49}", "\u{16e49}", "\u{16e69}"),
+    (0x16e4a, "\u{16e6a}", "\u{16e4a}", "\u{16e4a}", "\u{16e6a}"),
+    (0x16e4b, "\u{16e6b}", "\u{16e4b}", "\u{16e4b}", "\u{16e6b}"),
+    (0x16e4c, "\u{16e6c}", "\u{16e4c}", "\u{16e4c}", "\u{16e6c}"),
+    (0x16e4d, "\u{16e6d}", "\u{16e4d}", "\u{16e4d}", "\u{16e6d}"),
+    (0x16e4e, "\u{16e6e}", "\u{16e4e}", "\u{16e4e}", "\u{16e6e}"),
+    (0x16e4f, "\u{16e6f}", "\u{16e4f}", "\u{16e4f}", "\u{16e6f}"),
+    (0x16e50, "\u{16e70}", "\u{16e50}", "\u{16e50}", "\u{16e70}"),
+    (0x16e51, "\u{16e71}", "\u{16e51}", "\u{16e51}", "\u{16e71}"),
+    (0x16e52, "\u{16e72}", "\u{16e52}", "\u{16e52}", "\u{16e72}"),
+    (0x16e53, "\u{16e73}", "\u{16e53}", "\u{16e53}", "\u{16e73}"),
+    (0x16e54, "\u{16e74}", "\u{16e54}", "\u{16e54}", "\u{16e74}"),
+    (0x16e55, "\u{16e75}", "\u{16e55}", "\u{16e55}", "\u{16e75}"),
+    (0x16e56, "\u{16e76}", "\u{16e56}", "\u{16e56}", "\u{16e76}"),
+    (0x16e57, "\u{16e77}", "\u{16e57}", "\u{16e57}", "\u{16e77}"),
+    (0x16e58, "\u{16e78}", "\u{16e58}", "\u{16e58}", "\u{16e78}"),
+    (0x16e59, "\u{16e79}", "\u{16e59}", "\u{16e59}", "\u{16e79}"),
+    (0x16e5a, "\u{16e7a}", "\u{16e5a}", "\u{16e5a}", "\u{16e7a}"),
+    (0x16e5b, "\u{16e7b}", "\u{16e5b}", "\u{16e5b}", "\u{16e7b}"),
+    (0x16e5c, "\u{16e7c}", "\u{16e5c}", "\u{16e5c}", "\u{16e7c}"),
+    (0x16e5d, "\u{16e7d}", "\u{16e5d}", "\u{16e5d}", "\u{16e7d}"),
+    (0x16e5e, "\u{16e7e}", "\u{16e5e}", "\u{16e5e}", "\u{16e7e}"),
+    (0x16e5f, "\u{16e7f}", "\u{16e5f}", "\u{16e5f}", "\u{16e7f}"),
+    (0x16e60, "\u{16e60}", "\u{16e40}", "\u{16e40}", "\u{16e60}"),
+    (0x16e61, "\u{16e61}", "\u{16e41}", "\u{16e41}", "\u{16e61}"),
+    (0x16e62, "\u{16e62}", "\u{16e42}", "\u{16e42}", "\u{16e62}"),
+    (0x16e63, "\u{16e63}", "\u{16e43}", "\u{16e43}", "\u{16e63}"),
+    (0x16e64, "\u{16e64}", "\u{16e44}", "\u{16e44}", "\u{16e64}"),
+    (0x16e65, "\u{16e65}", "\u{16e45}", "\u{16e45}", "\u{16e65}"),
+    (0x16e66, "\u{16e66}", "\u{16e46}", "\u{16e46}", "\u{16e66}"),
+    (0x16e67, "\u{16e67}", "\u{16e47}", "\u{16e47}", "\u{16e67}"),
+    (0x16e68, "\u{16e68}", "\u{16e48}", "\u{16e48}", "\u{16e68}"),
+    (0x16e69, "\u{16e69}", "\u{16e49}", "\u{16e49}", "\u{16e69}"),
+    (0x16e6a, "\u{16e6a}", "\u{16e4a}", "\u{16e4a}", "\u{16e6a}"),
+    (0x16e6b, "\u{16e6b}", "\u{16e4b}", "\u{16e4b}", "\u{16e6b}"),
+    (0x16e6c, "\u{16e6c}", "\u{16e4c}", "\u{16e4c}", "\u{16e6c}"),
+    (0x16e6d, "\u{16e6d}", "\u{16e4d}", "\u{16e4d}", "\u{16e6d}"),
+    (0x16e6e, "\u{16e6e}", "\u{16e4e}", "\u{16e4e}", "\u{16e6e}"),
+    (0x16e6f, "\u{16e6f}", "\u{16e4f}", "\u{16e4f}", "\u{16e6f}"),
+    (0x16e70, "\u{16e70}", "\u{16e50}", "\u{16e50}", "\u{16e70}"),
+    (0x16e71, "\u{16e71}", "\u{16e51}", "\u{16e51}", "\u{16e71}"),
+    (0x16e72, "\u{16e72}", "\u{16e52}", "\u{16e52}", "\u{16e72}"),
+    (0x16e73, "\u{16e73}", "\u{16e53}", "\u{16e53}", "\u{16e73}"),
+    (0x16e74, "\u{16e74}", "\u{16e54}", "\u{16e54}", "\u{16e74}"),
+    (0x16e75, "\u{16e75}", "\u{16e55}", "\u{16e55}", "\u{16e75}"),
+    (0x16e76, "\u{16e76}", "\u{16e56}", "\u{16e56}", "\u{16e76}"),
+    (0x16e77, "\u{16e77}", "\u{16e57}", "\u{16e57}", "\u{16e77}"),
+    (0x16e78, "\u{16e78}", "\u{16e58}", "\u{16e58}", "\u{16e78}"),
+    (0x16e79, "\u{16e79}", "\u{16e59}", "\u{16e59}", "\u{16e79}"),
+    (0x16e7a, "\u{16e7a}", "\u{16e5a}", "\u{16e5a}", "\u{16e7a}"),
+    (0x16e7b, "\u{16e7b}", "\u{16e5b}", "\u{16e5b}", "\u{16e7b}"),
+    (0x16e7c, "\u{16e7c}", "\u{16e5c}", "\u{16e5c}", "\u{16e7c}"),
+    (0x16e7d, "\u{16e7d}", "\u{16e5d}", "\u{16e5d}", "\u{16e7d}"),
+    (0x16e7e, "\u{16e7e}", "\u{16e5e}", "\u{16e5e}", "\u{16e7e}"),
+    (0x16e7f, "\u{16e7f}", "\u{16e5f}", "\u{16e5f}", "\u{16e7f}"),
+    (0x1e900, "\u{1e922}", "\u{1e900}", "\u{1e900}", "\u{1e922}"),
+    (0x1e901, "\u{1e923}", "\u{1e901}", "\u{1e901}", "\u{1e923}"),
+    (0x1e902, "\u{1e924}", "\u{1e902}", "\u{1e902}", "\u{1e924}"),
+    (0x1e903, "\u{1e925}", "\u{1e903}", "\u{1e903}", "\u{1e925}"),
+    (0x1e904, "\u{1e926}", "\u{1e904}", "\u{1e904}", "\u{1e926}"),
+    (0x1e905, "\u{1e927}", "\u{1e905}", "\u{1e905}", "\u{1e927}"),
+    (0x1e906, "\u{1e928}", "\u{1e906}", "\u{1e906}", "\u{1e928}"),
+    (0x1e907, "\u{1e929}", "\u{1e907}", "\u{1e907}", "\u{1e929}"),
+    (0x1e908, "\u{1e92a}", "\u{1e908}", "\u{1e908}", "\u{1e92a}"),
+    (0x1e909, "\u{1e92b}", "\u{1e909}", "\u{1e909}", "\u{1e92b}"),
+    (0x1e90a, "\u{1e92c}", "\u{1e90a}", "\u{1e90a}", "\u{1e92c}"),
+    (0x1e90b, "\u{1e92d}", "\u{1e90b}", "\u{1e90b}", "\u{1e92d}"),
+    (0x1e90c, "\u{1e92e}", "\u{1e90c}", "\u{1e90c}", "\u{1e92e}"),
+    (0x1e90d, "\u{1e92f}", "\u{1e90d}", "\u{1e90d}", "\u{1e92f}"),
+    (0x1e90e, "\u{1e930}", "\u{1e90e}", "\u{1e90e}", "\u{1e930}"),
+    (0x1e90f, "\u{1e931}", "\u{1e90f}", "\u{1e90f}", "\u{1e931}"),
+    (0x1e910, "\u{1e932}", "\u{1e910}", "\u{1e910}", "\u{1e932}"),
+    (0x1e911, "\u{1e933}", "\u{1e911}", "\u{1e911}", "\u{1e933}"),
+    (0x1e912, "\u{1e934}", "\u{1e912}", "\u{1e912}", "\u{1e934}"),
+    (0x1e913, "\u{1e935}", "\u{1e913}", "\u{1e913}", "\u{1e935}"),
+    (0x1e914, "\u{1e936}", "\u{1e914}", "\u{1e914}", "\u{1e936}"),
+    (0x1e915, "\u{1e937}", "\u{1e915}", "\u{1e915}", "\u{1e937}"),
+    (0x1e916, "\u{1e938}", "\u{1e916}", "\u{1e916}", "\u{1e938}"),
+    (0x1e917, "\u{1e939}", "\u{1e917}", "\u{1e917}", "\u{1e939}"),
+    (0x1e918, "\u{1e93a}", "\u{1e918}", "\u{1e918}", "\u{1e93a}"),
+    (0x1e919, "\u{1e93b}", "\u{1e919}", "\u{1e919}", "\u{1e93b}"),
+    (0x1e91a, "\u{1e93c}", "\u{1e91a}", "\u{1e91a}", "\u{1e93c}"),
+    (0x1e91b, "\u{1e93d}", "\u{1e91b}", "\u{1e91b}", "\u{1e93d}"),
+    (0x1e91c, "\u{1e93e}", "\u{1e91c}", "\u{1e91c}", "\u{1e93e}"),
+    (0x1e91d, "\u{1e93f}", "\u{1e91d}", "\u{1e91d}", "\u{1e93f}"),
+    (0x1e91e, "\u{1e940}", "\u{1e91e}", "\u{1e91e}", "\u{1e940}"),
+    (0x1e91f, "\u{1e941}", "\u{1e91f}", "\u{1e91f}", "\u{1e941}"),
+    (0x1e920, "\u{1e942}", "\u{1e920}", "\u{1e920}", "\u{1e942}"),
+    (0x1e921, "\u{1e943}", "\u{1e921}", "\u{1e921}", "\u{1e943}"),
+    (0x1e922, "\u{1e922}", "\u{1e900}", "\u{1e900}", "\u{1e922}"),
+    (0x1e923, "\u{1e923}", "\u{1e901}", "\u{1e901}", "\u{1e923}"),
+    (0x1e924, "\u{1e924}", "\u{1e902}", "\u{1e902}", "\u{1e924}"),
+    (0x1e925, "\u{1e925}", "\u{1e903}", "\u{1e903}", "\u{1e925}"),
+    (0x1e926, "\u{1e926}", "\u{1e904}", "\u{1e904}", "\u{1e926}"),
+    (0x1e927, "\u{1e927}", "\u{1e905}", "\u{1e905}", "\u{1e927}"),
+    (0x1e928, "\u{1e928}", "\u{1e906}", "\u{1e906}", "\u{1e928}"),
+    (0x1e929, "\u{1e929}", "\u{1e907}", "\u{1e907}", "\u{1e929}"),
+    (0x1e92a, "\u{1e92a}", "\u{1e908}", "\u{1e908}", "\u{1e92a}"),
+    (0x1e92b, "\u{1e92b}", "\u{1e909}", "\u{1e909}", "\u{1e92b}"),
+    (0x1e92c, "\u{1e92c}", "\u{1e90a}", "\u{1e90a}", "\u{1e92c}"),
+    (0x1e92d, "\u{1e92d}", "\u{1e90b}", "\u{1e90b}", "\u{1e92d}"),
+    (0x1e92e, "\u{1e92e}", "\u{1e90c}", "\u{1e90c}", "\u{1e92e}"),
+    (0x1e92f, "\u{1e92f}", "\u{1e90d}", "\u{1e90d}", "\u{1e92f}"),
+    (0x1e930, "\u{1e930}", "\u{1e90e}", "\u{1e90e}", "\u{1e930}"),
+    (0x1e931, "\u{1e931}", "\u{1e90f}", "\u{1e90f}", "\u{1e931}"),
+    (0x1e932, "\u{1e932}", "\u{1e910}", "\u{1e910}", "\u{1e932}"),
+    (0x1e933, "\u{1e933}", "\u{1e911}", "\u{1e911}", "\u{1e933}"),
+    (0x1e934, "\u{1e934}", "\u{1e912}", "\u{1e912}", "\u{1e934}"),
+    (0x1e935, "\u{1e935}", "\u{1e913}", "\u{1e913}", "\u{1e935}"),
+    (0x1e936, "\u{1e936}", "\u{1e914}", "\u{1e914}", "\u{1e936}"),
+    (0x1e937, "\u{1e937}", "\u{1e915}", "\u{1e915}", "\u{1e937}"),
+    (0x1e938, "\u{1e938}", "\u{1e916}", "\u{1e916}", "\u{1e938}"),
+    (0x1e939, "\u{1e939}", "\u{1e917}", "\u{1e917}", "\u{1e939}"),
+    (0x1e93a, "\u{1e93a}", "\u{1e918}", "\u{1e918}", "\u{1e93a}"),
+    (0x1e93b, "\u{1e93b}", "\u{1e919}", "\u{1e919}", "\u{1e93b}"),
+    (0x1e93c, "\u{1e93c}", "\u{1e91a}", "\u{1e91a}", "\u{1e93c}"),
+    (0x1e93d, "\u{1e93d}", "\u{1e91b}", "\u{1e91b}", "\u{1e93d}"),
+    (0x1e93e, "\u{1e93e}", "\u{1e91c}", "\u{1e91c}", "\u{1e93e}"),
+    (0x1e93f, "\u{1e93f}", "\u{1e91d}", "\u{1e91d}", "\u{1e93f}"),
+    (0x1e940, "\u{1e940}", "\u{1e91e}", "\u{1e91e}", "\u{1e940}"),
+    (0x1e941, "\u{1e941}", "\u{1e91f}", "\u{1e91f}", "\u{1e941}"),
+    (0x1e942, "\u{1e942}", "\u{1e920}", "\u{1e920}", "\u{1e942}"),
+    (0x1e943, "\u{1e943}", "\u{1e921}", "\u{1e921}", "\u{1e943}"),
+];
+
+/// Numeric values of characters with no digit value, ordered by code point.
+pub(super) static NUMERIC: &[(u32, f64)] = &[
+    (0xbc, 0.25),
+    (0xbd, 0.5),
+    (0xbe, 0.75),
+    (0x9f4, 0.0625),
+    (0x9f5, 0.125),
+    (0x9f6, 0.1875),
+    (0x9f7, 0.25),
+    (0x9f8, 0.75),
+    (0x9f9, 16.0),
+    (0xb72, 0.25),
+    (0xb73, 0.5),
+    (0xb74, 0.75),
+    (0xb75, 0.0625),
+    (0xb76, 0.125),
+    (0xb77, 0.1875),
+    (0xbf0, 10.0),
+    (0xbf1, 100.0),
+    (0xbf2, 1000.0),
+    (0xc78, 0.0),
+    (0xc79, 1.0),
+    (0xc7a, 2.0),
+    (0xc7b, 3.0),
+    (0xc7c, 1.0),
+    (0xc7d, 2.0),
+    (0xc7e, 3.0),
+    (0xd58, 0.00625),
+    (0xd59, 0.025),
+    (0xd5a, 0.0375),
+    (0xd5b, 0.05),
+    (0xd5c, 0.1),
+    (0xd5d, 0.15),
+    (0xd5e, 0.2),
+    (0xd70, 10.0),
+    (0xd71, 100.0),
+    (0xd72, 1000.0),
+    (0xd73, 0.25),
+    (0xd74, 0.5),
+    (0xd75, 0.75),
+    (0xd76, 0.0625),
+    (0xd77, 0.125),
+    (0xd78, 0.1875),
+    (0xf2a, 0.5),
+    (0xf2b, 1.5),
+    (0xf2c, 2.5),
+    (0xf2d, 3.5),
+    (0xf2e, 4.5),
+    (0xf2f, 5.5),
+    (0xf30, 6.5),
+    (0xf31, 7.5),
+    (0xf32, 8.5),
+    (0xf33, -0.5),
+    (0x1372, 10.0),
+    (0x1373, 20.0),
+    (0x1374, 30.0),
+    (0x1375, 40.0),
+    (0x1376, 50.0),
+    (0x1377, 60.0),
+    (0x1378, 70.0),
+    (0x1379, 80.0),
+    (0x137a, 90.0),
+    (0x137b, 100.0),
+    (0x137c, 10000.0),
+    (0x16ee, 17.0),
+    (0x16ef, 18.0),
+    (0x16f0, 19.0),
+    (0x17f0, 0.0),
+    (0x17f1, 1.0),
+    (0x17f2, 2.0),
+    (0x17f3, 3.0),
+    (0x17f4, 4.0),
+    (0x17f5, 5.0),
+    (0x17f6, 6.0),
+    (0x17f7, 7.0),
+    (0x17f8, 8.0),
+    (0x17f9, 9.0),
+    (0x2150, 0.14285714285714285),
+    (0x2151, 0.1111111111111111),
+    (0x2152, 0.1),
+    (0x2153, 0.3333333333333333),
+    (0x2154, 0.6666666666666666),
+    (0x2155, 0.2),
+    (0x2156, 0.4),
+    (0x2157, 0.6),
+    (0x2158, 0.8),
+    (0x2159, 0.16666666666666666),
+    (0x215a, 0.8333333333333334),
+    (0x215b, 0.125),
+    (0x215c, 0.375),
+    (0x215d, 0.625),
+    (0x215e, 0.875),
+    (0x215f, 1.0),
+    (0x2160, 1.0),
+    (0x2161, 2.0),
+    (0x2162, 3.0),
+    (0x2163, 4.0),
+    (0x2164, 5.0),
+    (0x2165, 6.0),
+    (0x2166, 7.0),
+    (0x2167, 8.0),
+    (0x2168, 9.0),
+    (0x2169, 10.0),
+    (0x216a, 11.0),
+    (0x216b, 12.0),
+    (0x216c, 50.0),
+    (0x216d, 100.0),
+    (0x216e, 500.0),
+    (0x216f, 1000.0),
+    (0x2170, 1.0),
+    (0x2171, 2.0),
+    (0x2172, 3.0),
+    (0x2173, 4.0),
+    (0x2174, 5.0),
+    (0x2175, 6.0),
+    (0x2176, 7.0),
+    (0x2177, 8.0),
+    (0x2178, 9.0),
+    (0x2179, 10.0),
+    (0x217a, 11.0),
+    (0x217b, 12.0),
+    (0x217c, 50.0),
+    (0x217d, 100.0),
+    (0x217e, 500.0),
+    (0x217f, 1000.0),
+    (0x2180, 1000.0),
+    (0x2181, 5000.0),
+    (0x2182, 10000.0),
+    (0x2185, 6.0),
+    (0x2186, 50.0),
+    (0x2187, 50000.0),
+    (0x2188, 100000.0),
+    (0x2189, 0.0),
+    (0x2469, 10.0),
+    (0x246a, 11.0),
+    (0x246b, 12.0),
+    (0x246c, 13.0),
+    (0x246d, 14.0),
+    (0x246e, 15.0),
+    (0x246f, 16.0),
+    (0x2470, 17.0),
+    (0x2471, 18.0),
+    (0x2472, 19.0),
+    (0x2473, 20.0),
+    (0x247d, 10.0),
+    (0x247e, 11.0),
+    (0x247f, 12.0),
+    (0x2480, 13.0),
+    (0x2481, 14.0),
+    (0x2482, 15.0),
+    (0x2483, 16.0),
+    (0x2484, 17.0),
+    (0x2485, 18.0),
+    (0x2486, 19.0),
+    (0x2487, 20.0),
+    (0x2491, 10.0),
+    (0x2492, 11.0),
+    (0x2493, 12.0),
+    (0x2494, 13.0),
+    (0x2495, 14.0),
+    (0x2496, 15.0),
+    (0x2497, 16.0),
+    (0x2498, 17.0),
+    (0x2499, 18.0),
+    (0x249a, 19.0),
+    (0x249b, 20.0),
+    (0x24eb, 11.0),
+    (0x24ec, 12.0),
+    (0x24ed, 13.0),
+    (0x24ee, 14.0),
+    (0x24ef, 15.0),
+    (0x24f0, 16.0),
+    (0x24f1, 17.0),
+    (0x24f2, 18.0),
+    (0x24f3, 19.0),
+    (0x24f4, 20.0),
+    (0x24fe, 10.0),
+    (0x277f, 10.0),
+    (0x2789, 10.0),
+    (0x2793, 10.0),
+    (0x2cfd, 0.5),
+    (0x3007, 0.0),
+    (0x3021, 1.0),
+    (0x3022, 2.0),
+    (0x3023, 3.0),
+    (0x3024, 4.0),
+    (0x3025, 5.0),
+    (0x3026, 6.0),
+    (0x3027, 7.0),
+    (0x3028, 8.0),
+    (0x3029, 9.0),
+    (0x3038, 10.0),
+    (0x3039, 20.0),
+    (0x303a, 30.0),
+    (0x3192, 1.0),
+    (0x3193, 2.0),
+    (0x3194, 3.0),
+    (0x3195, 4.0),
+    (0x3220, 1.0),
+    (0x3221, 2.0),
+    (0x3222, 3.0),
+    (0x3223, 4.0),
+    (0x3224, 5.0),
+    (0x3225, 6.0),
+    (0x3226, 7.0),
+    (0x3227, 8.0),
+    (0x3228, 9.0),
+    (0x3229, 10.0),
+    (0x3248, 10.0),
+    (0x3249, 20.0),
+    (0x324a, 30.0),
+    (0x324b, 40.0),
+    (0x324c, 50.0),
+    (0x324d, 60.0),
+    (0x324e, 70.0),
+    (0x324f, 80.0),
+    (0x3251, 21.0),
+    (0x3252, 22.0),
+    (0x3253, 23.0),
+    (0x3254, 24.0),
+    (0x3255, 25.0),
+    (0x3256, 26.0),
+    (0x3257, 27.0),
+    (0x3258, 28.0),
+    (0x3259, 29.0),
+    (0x325a, 30.0),
+    (0x325b, 31.0),
+    (0x325c, 32.0),
+    (0x325d, 33.0),
+    (0x325e, 34.0),
+    (0x325f, 35.0),
+    (0x3280, 1.0),
+    (0x3281, 2.0),
+    (0x3282, 3.0),
+    (0x3283, 4.0),
+    (0x3284, 5.0),
+    (0x3285, 6.0),
+    (0x3286, 7.0),
+    (0x3287, 8.0),
+    (0x3288, 9.0),
+    (0x3289, 10.0),
+    (0x32b1, 36.0),
+    (0x32b2, 37.0),
+    (0x32b3, 38.0),
+    (0x32b4, 39.0),
+    (0x32b5, 40.0),
+    (0x32b6, 41.0),
+    (0x32b7, 42.0),
+    (0x32b8, 43.0),
+    (0x32b9, 44.0),
+    (0x32ba, 45.0),
+    (0x32bb, 46.0),
+    (0x32bc, 47.0),
+    (0x32bd, 48.0),
+    (0x32be, 49.0),
+    (0x32bf, 50.0),
+    (0x3405, 5.0),
+    (0x3483, 2.0),
+    (0x382a, 5.0),
+    (0x3b4d, 7.0),
+    (0x4e00, 1.0),
+    (0x4e03, 7.0),
+    (0x4e07, 10000.0),
+    (0x4e09, 3.0),
+    (0x4e24, 2.0),
+    (0x4e5d, 9.0),
+    (0x4e8c, 2.0),
+    (0x4e94, 5.0),
+    (0x4e96, 4.0),
+    (0x4eac, 1e+16),
+    (0x4ebf, 100000000.0),
+    (0x4ec0, 10.0),
+    (0x4edf, 1000.0),
+    (0x4ee8, 3.0),
+    (0x4f0d, 5.0),
+    (0x4f70, 100.0),
+    (0x4fe9, 2.0),
+    (0x5006, 2.0),
+    (0x5104, 100000000.0),
+    (0x5146, 1000000.0),
+    (0x5169, 2.0),
+    (0x516b, 8.0),
+    (0x516d, 6.0),
+    (0x5341, 10.0),
+    (0x5343, 1000.0),
+    (0x5344, 20.0),
+    (0x5345, 30.0),
+    (0x534c, 40.0),
+    (0x53c1, 3.0),
+    (0x53c2, 3.0),
+    (0x53c3, 3.0),
+    (0x53c4, 3.0),
+    (0x56db, 4.0),
+    (0x58f1, 1.0),
+    (0x58f9, 1.0),
+    (0x5e7a, 1.0),
+    (0x5efe, 9.0),
+    (0x5eff, 20.0),
+    (0x5f0c, 1.0),
+    (0x5f0d, 2.0),
+    (0x5f0e, 3.0),
+    (0x5f10, 2.0),
+    (0x62d0, 7.0),
+    (0x62fe, 10.0),
+    (0x634c, 8.0),
+    (0x67d2, 7.0),
+    (0x6d1e, 0.0),
+    (0x6f06, 7.0),
+    (0x7396, 9.0),
+    (0x767e, 100.0),
+    (0x7695, 200.0),
+    (0x79ed, 1000000000.0),
+    (0x8086, 4.0),
+    (0x842c, 10000.0),
+    (0x8cae, 2.0),
+    (0x8cb3, 2.0),
+    (0x8d30, 2.0),
+    (0x920e, 9.0),
+    (0x94a9, 9.0),
+    (0x9621, 1000.0),
+    (0x9646, 6.0),
+    (0x964c, 100.0),
+    (0x9678, 6.0),
+    (0x96f6, 0.0),
+    (0xa6e6, 1.0),
+    (0xa6e7, 2.0),
+    (0xa6e8, 3.0),
+    (0xa6e9, 4.0),
+    (0xa6ea, 5.0),
+    (0xa6eb, 6.0),
+    (0xa6ec, 7.0),
+    (0xa6ed, 8.0),
+    (0xa6ee, 9.0),
+    (0xa6ef, 0.0),
+    (0xa830, 0.25),
+    (0xa831, 0.5),
+    (0xa832, 0.75),
+    (0xa833, 0.0625),
+    (0xa834, 0.125),
+    (0xa835, 0.1875),
+    (0xf96b, 3.0),
+    (0xf973, 10.0),
+    (0xf978, 2.0),
+    (0xf9b2, 0.0),
+    (0xf9d1, 6.0),
+    (0xf9d3, 6.0),
+    (0xf9fd, 10.0),
+    (0x10107, 1.0),
+    (0x10108, 2.0),
+    (0x10109, 3.0),
+    (0x1010a, 4.0),
+    (0x1010b, 5.0),
+    (0x1010c, 6.0),
+    (0x1010d, 7.0),
+    (0x1010e, 8.0),
+    (0x1010f, 9.0),
+    (0x10110, 10.0),
+    (0x10111, 20.0),
+    (0x10112, 30.0),
+    (0x10113, 40.0),
+    (0x10114, 50.0),
+    (0x10115, 60.0),
+    (0x10116, 70.0),
+    (0x10117, 80.0),
+    (0x10118, 90.0),
+    (0x10119, 100.0),
+    (0x1011a, 200.0),
+    (0x1011b, 300.0),
+    (0x1011c, 400.0),
+    (0x1011d, 500.0),
+    (0x1011e, 600.0),
+    (0x1011f, 700.0),
+    (0x10120, 800.0),
+    (0x10121, 900.0),
+    (0x10122, 1000.0),
+    (0x10123, 2000.0),
+    (0x10124, 3000.0),
+    (0x10125, 4000.0),
+    (0x10126, 5000.0),
+    (0x10127, 6000.0),
+    (0x10128, 7000.0),
+    (0x10129, 8000.0),
+    (0x1012a, 9000.0),
+    (0x1012b, 10000.0),
+    (0x1012c, 20000.0),
+    (0x1012d, 30000.0),
+    (0x1012e, 40000.0),
+    (0x1012f, 50000.0),
+    (0x10130, 60000.0),
+    (0x10131, 70000.0),
+    (0x10132, 80000.0),
+    (0x10133, 90000.0),
+    (0x10140, 0.25),
+    (0x10141, 0.5),
+    (0x10142, 1.0),
+    (0x10143, 5.0),
+    (0x10144, 50.0),
+    (0x10145, 500.0),
+    (0x10146, 5000.0),
+    (0x10147, 50000.0),
+    (0x10148, 5.0),
+    (0x10149, 10.0),
+    (0x1014a, 50.0),
+    (0x1014b, 100.0),
+    (0x1014c, 500.0),
+    (0x1014d, 1000.0),
+    (0x1014e, 5000.0),
+    (0x1014f, 5.0),
+    (0x10150, 10.0),
+    (0x10151, 50.0),
+    (0x10152, 100.0),
+    (0x10153, 500.0),
+    (0x10154, 1000.0),
+    (0x10155, 10000.0),
+    (0x10156, 50000.0),
+    (0x10157, 10.0),
+    (0x10158, 1.0),
+    (0x10159, 1.0),
+    (0x1015a, 1.0),
+    (0x1015b, 2.0),
+    (0x1015c, 2.0),
+    (0x1015d, 2.0),
+    (0x1015e, 2.0),
+    (0x1015f, 5.0),
+    (0x10160, 10.0),
+    (0x10161, 10.0),
+    (0x10162, 10.0),
+    (0x10163, 10.0),
+    (0x10164, 10.0),
+    (0x10165, 30.0),
+    (0x10166, 50.0),
+    (0x10167, 50.0),
+    (0x10168, 50.0),
+    (0x10169, 50.0),
+    (0x1016a, 100.0),
+    (0x1016b, 300.0),
+    (0x1016c, 500.0),
+    (0x1016d, 500.0),
+    (0x1016e, 500.0),
+    (0x1016f, 500.0),
+    (0x10170, 500.0),
+    (0x10171, 1000.0),
+    (0x10172, 5000.0),
+    (0x10173, 5.0),
+    (0x10174, 50.0),
+    (0x10175, 0.5),
+    (0x10176, 0.5),
+    (0x10177, 0.6666666666666666),
+    (0x10178, 0.75),
+    (0x1018a, 0.0),
+    (0x1018b, 0.25),
+    (0x102e1, 1.0),
+    (0x102e2, 2.0),
+    (0x102e3, 3.0),
+    (0x102e4, 4.0),
+    (0x102e5, 5.0),
+    (0x102e6, 6.0),
+    (0x102e7, 7.0),
+    (0x102e8, 8.0),
+    (0x102e9, 9.0),
+    (0x102ea, 10.0),
+    (0x102eb, 20.0),
+    (0x102ec, 30.0),
+    (0x102ed, 40.0),
+    (0x102ee, 50.0),
+    (0x102ef, 60.0),
+    (0x102f0, 70.0),
+    (0x102f1, 80.0),
+    (0x102f2, 90.0),
+    (0x102f3, 100.0),
+    (0x102f4, 200.0),
+    (0x102f5, 300.0),
+    (0x102f6, 400.0),
+    (0x102f7, 500.0),
+    (0x102f8, 600.0),
+    (0x102f9, 700.0),
+    (0x102fa, 800.0),
+    (0x102fb, 900.0),
+    (0x10320, 1.0),
+    (0x10321, 5.0),
+    (0x10322, 10.0),
+    (0x10323, 50.0),
+    (0x10341, 90.0),
+    (0x1034a, 900.0),
+    (0x103d1, 1.0),
+    (0x103d2, 2.0),
+    (0x103d3, 10.0),
+    (0x103d4, 20.0),
+    (0x103d5, 100.0),
+    (0x10858, 1.0),
+    (0x10859, 2.0),
+    (0x1085a, 3.0),
+    (0x1085b, 10.0),
+    (0x1085c, 20.0),
+    (0x1085d, 100.0),
+    (0x1085e, 1000.0),
+    (0x1085f, 10000.0),
+    (0x10879, 1.0),
+    (0x1087a, 2.0),
+    (0x1087b, 3.0),
+    (0x1087c, 4.0),
+    (0x1087d, 5.0),
+    (0x1087e, 10.0),
+    (0x1087f, 20.0),
+    (0x108a7, 1.0),
+    (0x108a8, 2.0),
+    (0x108a9, 3.0),
+    (0x108aa, 4.0),
+    (0x108ab, 4.0),
+    (0x108ac, 5.0),
+    (0x108ad, 10.0),
+    (0x108ae, 20.0),
+    (0x108af, 100.0),
+    (0x108fb, 1.0),
+    (0x108fc, 5.0),
+    (0x108fd, 10.0),
+    (0x108fe, 20.0),
+    (0x108ff, 100.0),
+    (0x10916, 1.0),
+    (0x10917, 10.0),
+    (0x10918, 20.0),
+    (0x10919, 100.0),
+    (0x1091a, 2.0),
+    (0x1091b, 3.0),
+    (0x109bc, 0.9166666666666666),
+    (0x109bd, 0.5),
+    (0x109c0, 1.0),
+    (0x109c1, 2.0),
+    (0x109c2, 3.0),
+    (0x109c3, 4.0),
+    (0x109c4, 5.0),
+    (0x109c5, 6.0),
+    (0x109c6, 7.0),
+    (0x109c7, 8.0),
+    (0x109c8, 9.0),
+    (0x109c9, 10.0),
+    (0x109ca, 20.0),
+    (0x109cb, 30.0),
+    (0x109cc, 40.0),
+    (0x109cd, 50.0),
+    (0x109ce, 60.0),
+    (0x109cf, 70.0),
+    (0x109d2, 100.0),
+    (0x109d3, 200.0),
+    (0x109d4, 300.0),
+    (0x109d5, 400.0),
+    (0x109d6, 500.0),
+    (0x109d7, 600.0),
+    (0x109d8, 700.0),
+    (0x109d9, 800.0),
+    (0x109da, 900.0),
+    (0x109db, 1000.0),
+    (0x109dc, 2000.0),
+    (0x109dd, 3000.0),
+    (0x109de, 4000.0),
+    (0x109df, 5000.0),
+    (0x109e0, 6000.0),
+    (0x109e1, 7000.0),
+    (0x109e2, 8000.0),
+    (0x109e3, 9000.0),
+    (0x109e4, 10000.0),
+    (0x109e5, 20000.0),
+    (0x109e6, 30000.0),
+    (0x109e7, 40000.0),
+    (0x109e8, 50000.0),
+    (0x109e9, 60000.0),
+    (0x109ea, 70000.0),
+    (0x109eb, 80000.0),
+    (0x109ec, 90000.0),
+    (0x109ed, 100000.0),
+    (0x109ee, 200000.0),
+    (0x109ef, 300000.0),
+    (0x109f0, 400000.0),
+    (0x109f1, 500000.0),
+    (0x109f2, 600000.0),
+    (0x109f3, 700000.0),
+    (0x109f4, 800000.0),
+    (0x109f5, 900000.0),
+    (0x109f6, 0.08333333333333333),
+    (0x109f7, 0.16666666666666666),
+    (0x109f8, 0.25),
+    (0x109f9, 0.3333333333333333),
+    (0x109fa, 0.4166666666666667),
+    (0x109fb, 0.5),
+    (0x109fc, 0.5833333333333334),
+    (0x109fd, 0.6666666666666666),
+    (0x109fe, 0.75),
+    (0x109ff, 0.8333333333333334),
+    (0x10a44, 10.0),
+    (0x10a45, 20.0),
+    (0x10a46, 100.0),
+    (0x10a47, 1000.0),
+    (0x10a48, 0.5),
+    (0x10a7d, 1.0),
+    (0x10a7e, 50.0),
+    (0x10a9d, 1.0),
+    (0x10a9e, 10.0),
+    (0x10a9f, 20.0),
+    (0x10aeb, 1.0),
+    (0x10aec, 5.0),
+    (0x10aed, 10.0),
+    (0x10aee, 20.0),
+    (0x10aef, 100.0),
+    (0x10b58, 1.0),
+    (0x10b59, 2.0),
+    (0x10b5a, 3.0),
+    (0x10b5b, 4.0),
+    (0x10b5c, 10.0),
+    (0x10b5d, 20.0),
+    (0x10b5e, 100.0),
+    (0x10b5f, 1000.0),
+    (0x10b78, 1.0),
+    (0x10b79, 2.0),
+    (0x10b7a, 3.0),
+    (0x10b7b, 4.0),
+    (0x10b7c, 10.0),
+    (0x10b7d, 20.0),
+    (0x10b7e, 100.0),
+    (0x10b7f, 1000.0),
+    (0x10ba9, 1.0),
+    (0x10baa, 2.0),
+    (0x10bab, 3.0),
+    (0x10bac, 4.0),
+    (0x10bad, 10.0),
+    (0x10bae, 20.0),
+    (0x10baf, 100.0),
+    (0x10cfa, 1.0),
+    (0x10cfb, 5.0),
+    (0x10cfc, 10.0),
+    (0x10cfd, 50.0),
+    (0x10cfe, 100.0),
+    (0x10cff, 1000.0),
+    (0x10e69, 10.0),
+    (0x10e6a, 20.0),
+    (0x10e6b, 30.0),
+    (0x10e6c, 40.0),
+    (0x10e6d, 50.0),
+    (0x10e6e, 60.0),
+    (0x10e6f, 70.0),
+    (0x10e70, 80.0),
+    (0x10e71, 90.0),
+    (0x10e72, 100.0),
+    (0x10e73, 200.0),
+    (0x10e74, 300.0),
+    (0x10e75, 400.0),
+    (0x10e76, 500.0),
+    (0x10e77, 600.0),
+    (0x10e78, 700.0),
+    (0x10e79, 800.0),
+    (0x10e7a, 900.0),
+    (0x10e7b, 0.5),
+    (0x10e7c, 0.25),
+    (0x10e7d, 0.3333333333333333),
+    (0x10e7e, 0.6666666666666666),
+    (0x10f1d, 1.0),
+    (0x10f1e, 2.0),
+    (0x10f1f, 3.0),
+    (0x10f20, 4.0),
+    (0x10f21, 5.0),
+    (0x10f22, 10.0),
+    (0x10f23, 20.0),
+    (0x10f24, 30.0),
+    (0x10f25, 100.0),
+    (0x10f26, 0.5),
+    (0x10f51, 1.0),
+    (0x10f52, 10.0),
+    (0x10f53, 20.0),
+    (0x10f54, 100.0),
+    (0x10fc5, 1.0),
+    (0x10fc6, 2.0),
+    (0x10fc7, 3.0),
+    (0x10fc8, 4.0),
+    (0x10fc9, 10.0),
+    (0x10fca, 20.0),
+    (0x10fcb, 100.0),
+    (0x1105b, 10.0),
+    (0x1105c, 20.0),
+    (0x1105d, 30.0),
+    (0x1105e, 40.0),
+    (0x1105f, 50.0),
+    (0x11060, 60.0),
+    (0x11061, 70.0),
+    (0x11062, 80.0),
+    (0x11063, 90.0),
+    (0x11064, 100.0),
+    (0x11065, 1000.0),
+    (0x111e1, 1.0),
+    (0x111e2, 2.0),
+    (0x111e3, 3.0),
+    (0x111e4, 4.0),
+    (0x111e5, 5.0),
+    (0x111e6, 6.0),
+    (0x111e7, 7.0),
+    (0x111e8, 8.0),
+    (0x111e9, 9.0),
+    (0x111ea, 10.0),
+    (0x111eb, 20.0),
+    (0x111ec, 30.0),
+    (0x111ed, 40.0),
+    (0x111ee, 50.0),
+    (0x111ef, 60.0),
+    (0x111f0, 70.0),
+    (0x111f1, 80.0),
+    (0x111f2, 90.0),
+    (0x111f3, 100.0),
+    (0x111f4, 1000.0),
+    (0x1173a, 10.0),
+    (0x1173b, 20.0),
+    (0x118ea, 10.0),
+    (0x118eb, 20.0),
+    (0x118ec, 30.0),
+    (0x118ed, 40.0),
+    (0x118ee, 50.0),
+    (0x118ef, 60.0),
+    (0x118f0, 70.0),
+    (0x118f1, 80.0),
+    (0x118f2, 90.0),
+    (0x11c5a, 1.0),
+    (0x11c5b, 2.0),
+    (0x11c5c, 3.0),
+    (0x11c5d, 4.0),
+    (0x11c5e, 5.0),
+    (0x11c5f, 6.0),
+    (0x11c60, 7.0),
+    (0x11c61, 8.0),
+    (0x11c62, 9.0),
+    (0x11c63, 10.0),
+    (0x11c64, 20.0),
+    (0x11c65, 30.0),
+    (0x11c66, 40.0),
+    (0x11c67, 50.0),
+    (0x11c68, 60.0),
+    (0x11c69, 70.0),
+    (0x11c6a, 80.0),
+    (0x11c6b, 90.0),
+    (0x11c6c, 100.0),
+    (0x11fc0, 0.003125),
+    (0x11fc1, 0.00625),
+    (0x11fc2, 0.0125),
+    (0x11fc3, 0.015625),
+    (0x11fc4, 0.025),
+    (0x11fc5, 0.03125),
+    (0x11fc6, 0.0375),
+    (0x11fc7, 0.046875),
+    (0x11fc8, 0.05),
+    (0x11fc9, 0.0625),
+    (0x11fca, 0.0625),
+    (0x11fcb, 0.1),
+    (0x11fcc, 0.125),
+    (0x11fcd, 0.15),
+    (0x11fce, 0.1875),
+    (0x11fcf, 0.2),
+    (0x11fd0, 0.25),
+    (0x11fd1, 0.5),
+    (0x11fd2, 0.5),
+    (0x11fd3, 0.75),
+    (0x11fd4, 0.003125),
+    (0x12400, 2.0),
+    (0x12401, 3.0),
+    (0x12402, 4.0),
+    (0x12403, 5.0),
+    (0x12404, 6.0),
+    (0x12405, 7.0),
+    (0x12406, 8.0),
+    (0x12407, 9.0),
+    (0x12408, 3.0),
+    (0x12409, 4.0),
+    (0x1240a, 5.0),
+    (0x1240b, 6.0),
+    (0x1240c, 7.0),
+    (0x1240d, 8.0),
+    (0x1240e, 9.0),
+    (0x1240f, 4.0),
+    (0x12410, 5.0),
+    (0x12411, 6.0),
+    (0x12412, 7.0),
+    (0x12413, 8.0),
+    (0x12414, 9.0),
+    (0x12415, 1.0),
+    (0x12416, 2.0),
+    (0x12417, 3.0),
+    (0x12418, 4.0),
+    (0x12419, 5.0),
+    (0x1241a, 6.0),
+    (0x1241b, 7.0),
+    (0x1241c, 8.0),
+    (0x1241d, 9.0),
+    (0x1241e, 1.0),
+    (0x1241f, 2.0),
+    (0x12420, 3.0),
+    (0x12421, 4.0),
+    (0x12422, 5.0),
+    (0x12423, 2.0),
+    (0x12424, 3.0),
+    (0x12425, 3.0),
+    (0x12426, 4.0),
+    (0x12427, 5.0),
+    (0x12428, 6.0),
+    (0x12429, 7.0),
+    (0x1242a, 8.0),
+    (0x1242b, 9.0),
+    (0x1242c, 1.0),
+    (0x1242d, 2.0),
+    (0x1242e, 3.0),
+    (0x1242f, 3.0),
+    (0x12430, 4.0),
+    (0x12431, 5.0),
+    (0x12432, 216000.0),
+    (0x12433, 432000.0),
+    (0x12434, 1.0),
+    (0x12435, 2.0),
+    (0x12436, 3.0),
+    (0x12437, 3.0),
+    (0x12438, 4.0),
+    (0x12439, 5.0),
+    (0x1243a, 3.0),
+    (0x1243b, 3.0),
+    (0x1243c, 4.0),
+    (0x1243d, 4.0),
+    (0x1243e, 4.0),
+    (0x1243f, 4.0),
+    (0x12440, 6.0),
+    (0x12441, 7.0),
+    (0x12442, 7.0),
+    (0x12443, 7.0),
+    (0x12444, 8.0),
+    (0x12445, 8.0),
+    (0x12446, 9.0),
+    (0x12447, 9.0),
+    (0x12448, 9.0),
+    (0x12449, 9.0),
+    (0x1244a, 2.0),
+    (0x1244b, 3.0),
+    (0x1244c, 4.0),
+    (0x1244d, 5.0),
+    (0x1244e, 6.0),
+    (0x1244f, 1.0),
+    (0x12450, 2.0),
+    (0x12451, 3.0),
+    (0x12452, 4.0),
+    (0x12453, 4.0),
+    (0x12454, 5.0),
+    (0x12455, 5.0),
+    (0x12456, 2.0),
+    (0x12457, 3.0),
+    (0x12458, 1.0),
+    (0x12459, 2.0),
+    (0x1245a, 0.3333333333333333),
+    (0x1245b, 0.6666666666666666),
+    (0x1245c, 0.8333333333333334),
+    (0x1245d, 0.3333333333333333),
+    (0x1245e, 0.6666666666666666),
+    (0x1245f, 0.125),
+    (0x12460, 0.25),
+    (0x12461, 0.16666666666666666),
+    (0x12462, 0.25),
+    (0x12463, 0.25),
+    (0x12464, 0.5),
+    (0x12465, 0.3333333333333333),
+    (0x12466, 0.6666666666666666),
+    (0x12467, 40.0),
+    (0x12468, 50.0),
+    (0x12469, 4.0),
+    (0x1246a, 5.0),
+    (0x1246b, 6.0),
+    (0x1246c, 7.0),
+    (0x1246d, 8.0),
+    (0x1246e, 9.0),
+    (0x16b5b, 10.0),
+    (0x16b5c, 100.0),
+    (0x16b5d, 10000.0),
+    (0x16b5e, 1000000.0),
+    (0x16b5f, 100000000.0),
+    (0x16b60, 10000000000.0),
+    (0x16b61, 1000000000000.0),
+    (0x16e80, 0.0),
+    (0x16e81, 1.0),
+    (0x16e82, 2.0),
+    (0x16e83, 3.0),
+    (0x16e84, 4.0),
+    (0x16e85, 5.0),
+    (0x16e86, 6.0),
+    (0x16e87, 7.0),
+    (0x16e88, 8.0),
+    (0x16e89, 9.0),
+    (0x16e8a, 10.0),
+    (0x16e8b, 11.0),
+    (0x16e8c, 12.0),
+    (0x16e8d, 13.0),
+    (0x16e8e, 14.0),
+    (0x16e8f, 15.0),
+    (0x16e90, 16.0),
+    (0x16e91, 17.0),
+    (0x16e92, 18.0),
+    (0x16e93, 19.0),
+    (0x16e94, 1.0),
+    (0x16e95, 2.0),
+    (0x16e96, 3.0),
+    (0x1d2c0, 0.0),
+    (0x1d2c1, 1.0),
+    (0x1d2c2, 2.0),
+    (0x1d2c3, 3.0),
+    (0x1d2c4, 4.0),
+    (0x1d2c5, 5.0),
+    (0x1d2c6, 6.0),
+    (0x1d2c7, 7.0),
+    (0x1d2c8, 8.0),
+    (0x1d2c9, 9.0),
+    (0x1d2ca, 10.0),
+    (0x1d2cb, 11.0),
+    (0x1d2cc, 12.0),
+    (0x1d2cd, 13.0),
+    (0x1d2ce, 14.0),
+    (0x1d2cf, 15.0),
+    (0x1d2d0, 16.0),
+    (0x1d2d1, 17.0),
+    (0x1d2d2, 18.0),
+    (0x1d2d3, 19.0),
+    (0x1d2e0, 0.0),
+    (0x1d2e1, 1.0),
+    (0x1d2e2, 2.0),
+    (0x1d2e3, 3.0),
+    (0x1d2e4, 4.0),
+    (0x1d2e5, 5.0),
+    (0x1d2e6, 6.0),
+    (0x1d2e7, 7.0),
+    (0x1d2e8, 8.0),
+    (0x1d2e9, 9.0),
+    (0x1d2ea, 10.0),
+    (0x1d2eb, 11.0),
+    (0x1d2ec, 12.0),
+    (0x1d2ed, 13.0),
+    (0x1d2ee, 14.0),
+    (0x1d2ef, 15.0),
+    (0x1d2f0, 16.0),
+    (0x1d2f1, 17.0),
+    (0x1d2f2, 18.0),
+    (0x1d2f3, 19.0),
+    (0x1d360, 1.0),
+    (0x1d361, 2.0),
+    (0x1d362, 3.0),
+    (0x1d363, 4.0),
+    (0x1d364, 5.0),
+    (0x1d365, 6.0),
+    (0x1d366, 7.0),
+    (0x1d367, 8.0),
+    (0x1d368, 9.0),
+    (0x1d369, 10.0),
+    (0x1d36a, 20.0),
+    (0x1d36b, 30.0),
+    (0x1d36c, 40.0),
+    (0x1d36d, 50.0),
+    (0x1d36e, 60.0),
+    (0x1d36f, 70.0),
+    (0x1d370, 80.0),
+    (0x1d371, 90.0),
+    (0x1d372, 1.0),
+    (0x1d373, 2.0),
+    (0x1d374, 3.0),
+    (0x1d375, 4.0),
+    (0x1d376, 5.0),
+    (0x1d377, 1.0),
+    (0x1d378, 5.0),
+    (0x1e8c7, 1.0),
+    (0x1e8c8, 2.0),
+    (0x1e8c9, 3.0),
+    (0x1e8ca, 4.0),
+    (0x1e8cb, 5.0),
+    (0x1e8cc, 6.0),
+    (0x1e8cd, 7.0),
+    (0x1e8ce, 8.0),
+    (0x1e8cf, 9.0),
+    (0x1ec71, 1.0),
+    (0x1ec72, 2.0),
+    (0x1ec73, 3.0),
+    (0x1ec74, 4.0),
+    (0x1ec75, 5.0),
+    (0x1ec76, 6.0),
+    (0x1ec77, 7.0),
+    (0x1ec78, 8.0),
+    (0x1ec79, 9.0),
+    (0x1ec7a, 10.0),
+    (0x1ec7b, 20.0),
+    (0x1ec7c, 30.0),
+    (0x1ec7d, 40.0),
+    (0x1ec7e, 50.0),
+    (0x1ec7f, 60.0),
+    (0x1ec80, 70.0),
+    (0x1ec81, 80.0),
+    (0x1ec82, 90.0),
+    (0x1ec83, 100.0),
+    (0x1ec84, 200.0),
+    (0x1ec85, 300.0),
+    (0x1ec86, 400.0),
+    (0x1ec87, 500.0),
+    (0x1ec88, 600.0),
+    (0x1ec89, 700.0),
+    (0x1ec8a, 800.0),
+    (0x1ec8b, 900.0),
+    (0x1ec8c, 1000.0),
+    (0x1ec8d, 2000.0),
+    (0x1ec8e, 3000.0),
+    (0x1ec8f, 4000.0),
+    (0x1ec90, 5000.0),
+    (0x1ec91, 6000.0),
+    (0x1ec92, 7000.0),
+    (0x1ec93, 8000.0),
+    (0x1ec94, 9000.0),
+    (0x1ec95, 10000.0),
+    (0x1ec96, 20000.0),
+    (0x1ec97, 30000.0),
+    (0x1ec98, 40000.0),
+    (0x1ec99, 50000.0),
+    (0x1ec9a, 60000.0),
+    (0x1ec9b, 70000.0),
+    (0x1ec9c, 80000.0),
+    (0x1ec9d, 90000.0),
+    (0x1ec9e, 100000.0),
+    (0x1ec9f, 200000.0),
+    (0x1eca0, 100000.0),
+    (0x1eca1, 10000000.0),
+    (0x1eca2, 20000000.0),
+    (0x1eca3, 1.0),
+    (0x1eca4, 2.0),
+    (0x1eca5, 3.0),
+    (0x1eca6, 4.0),
+    (0x1eca7, 5.0),
+    (0x1eca8, 6.0),
+    (0x1eca9, 7.0),
+    (0x1ecaa, 8.0),
+    (0x1ecab, 9.0),
+    (0x1ecad, 0.25),
+    (0x1ecae, 0.5),
+    (0x1ecaf, 0.75),
+    (0x1ecb1, 1.0),
+    (0x1ecb2, 2.0),
+    (0x1ecb3, 10000.0),
+    (0x1ecb4, 100000.0),
+    (0x1ed01, 1.0),
+    (0x1ed02, 2.0),
+    (0x1ed03, 3.0),
+    (0x1ed04, 4.0),
+    (0x1ed05, 5.0),
+    (0x1ed06, 6.0),
+    (0x1ed07, 7.0),
+    (0x1ed08, 8.0),
+    (0x1ed09, 9.0),
+    (0x1ed0a, 10.0),
+    (0x1ed0b, 20.0),
+    (0x1ed0c, 30.0),
+    (0x1ed0d, 40.0),
+    (0x1ed0e, 50.0),
+    (0x1ed0f, 60.0),
+    (0x1ed10, 70.0),
+    (0x1ed11, 80.0),
+    (0x1ed12, 90.0),
+    (0x1ed13, 100.0),
+    (0x1ed14, 200.0),
+    (0x1ed15, 300.0),
+    (0x1ed16, 400.0),
+    (0x1ed17, 500.0),
+    (0x1ed18, 600.0),
+    (0x1ed19, 700.0),
+    (0x1ed1a, 800.0),
+    (0x1ed1b, 900.0),
+    (0x1ed1c, 1000.0),
+    (0x1ed1d, 2000.0),
+    (0x1ed1e, 3000.0),
+    (0x1ed1f, 4000.0),
+    (0x1ed20, 5000.0),
+    (0x1ed21, 6000.0),
+    (0x1ed22, 7000.0),
+    (0x1ed23, 8000.0),
+    (0x1ed24, 9000.0),
+    (0x1ed25, 10000.0),
+    (0x1ed26, 20000.0),
+    (0x1ed27, 30000.0),
+    (0x1ed28, 40000.0),
+    (0x1ed29, 50000.0),
+    (0x1ed2a, 60000.0),
+    (0x1ed2b, 70000.0),
+    (0x1ed2c, 80000.0),
+    (0x1ed2d, 90000.0),
+    (0x1ed2f, 2.0),
+    (0x1ed30, 3.0),
+    (0x1ed31, 4.0),
+    (0x1ed32, 5.0),
+    (0x1ed33, 6.0),
+    (0x1ed34, 7.0),
+    (0x1ed35, 8.0),
+    (0x1ed36, 9.0),
+    (0x1ed37, 10.0),
+    (0x1ed38, 400.0),
+    (0x1ed39, 600.0),
+    (0x1ed3a, 2000.0),
+    (0x1ed3b, 10000.0),
+    (0x1ed3c, 0.5),
+    (0x1ed3d, 0.16666666666666666),
+    (0x1f10b, 0.0),
+    (0x1f10c, 0.0),
+    (0x20001, 7.0),
+    (0x20064, 4.0),
+    (0x200e2, 4.0),
+    (0x20121, 5.0),
+    (0x2092a, 1.0),
+    (0x20983, 30.0),
+    (0x2098c, 40.0),
+    (0x2099c, 40.0),
+    (0x20aea, 6.0),
+    (0x20afd, 3.0),
+    (0x20b19, 3.0),
+    (0x22390, 2.0),
+    (0x22998, 3.0),
+    (0x23b1b, 3.0),
+    (0x2626d, 4.0),
+    (0x2f890, 9.0),
+];
+
+/// `(first code point, offset)` of every 32th entry in `decompositions.bin`.
+pub(super) static DECOMPOSITION_CHUNKS: &[(u32, u32)] = &[
+    (0xa0, 0),
+    (0xd4, 340),
+    (0xfc, 692),
+    (0x11f, 1044),
+    (0x145, 1396),
+    (0x16b, 1748),
+    (0x1cb, 2097),
+    (0x1ee, 2449),
+    (0x210, 2801),
+    (0x2b4, 3141),
+    (0x38f, 3445),
+    (0x40d, 3764),
+    (0x4e7, 4116),
+    (0x959, 4468),
+    (0xccb, 4820),
+    (0x1026, 5169),
+    (0x1d41, 5461),
+    (0x1d62, 5717),
+    (0x1db1, 5973),
+    (0x1e11, 6280),
+    (0x1e31, 6632),
+    (0x1e51, 6984),
+    (0x1e71, 7336),
+    (0x1e91, 7688),
+    (0x1eb5, 8040),
+    (0x1ed5, 8392),
+    (0x1ef5, 8744),
+    (0x1f1d, 9096),
+    (0x1f3f, 9448),
+    (0x1f67, 9800),
+    (0x1f89, 10131),
+    (0x1fa9, 10483),
+    (0x1fcb, 10826),
+    (0x1fee, 11163),
+    (0x2034, 11461),
+    (0x2088, 11756),
+    (0x210d, 12045),
+    (0x2138, 12316),
+    (0x2163, 12683),
+    (0x21ae, 13026),
+    (0x2288, 13384),
+    (0x2470, 13703),
+    (0x2490, 14148),
+    (0x24b0, 14593),
+    (0x24d0, 14885),
+    (0x2c7c, 15168),
+    (0x2f1b, 15424),
+    (0x2f3b, 15680),
+    (0x2f5b, 15936),
+    (0x2f7b, 16192),
+    (0x2f9b, 16448),
+    (0x2fbb, 16704),
+    (0x304c, 16960),
+    (0x30b0, 17312),
+    (0x3133, 17658),
+    (0x3153, 17914),
+    (0x3173, 18170),
+    (0x3196, 18426),
+    (0x3216, 18838),
+    (0x3237, 19328),
+    (0x325f, 19710),
+    (0x3280, 20035),
+    (0x32a0, 20291),
+    (0x32c0, 20592),
+    (0x32e0, 20911),
+    (0x3300, 21170),
+    (0x3320, 21684),
+    (0x3340, 22156),
+    (0x3360, 22604),
+    (0x3380, 23019),
+    (0x33a0, 23395),
+    (0x33c0, 23804),
+    (0x33e0, 24195),
+    (0xa69c, 24616),
+    (0xf913, 24872),
+    (0xf933, 25128),
+    (0xf953, 25384),
+    (0xf973, 25640),
+    (0xf993, 25896),
+    (0xf9b3, 26152),
+    (0xf9d3, 26408),
+    (0xf9f3, 26664),
+    (0xfa18, 26920),
+    (0xfa3f, 27176),
+    (0xfa5f, 27432),
+    (0xfa81, 27688),
+    (0xfaa1, 27944),
+    (0xfac1, 28200),
+    (0xfb13, 28483),
+    (0xfb3a, 28805),
+    (0xfb5e, 29115),
+    (0xfb7e, 29371),
+    (0xfb9e, 29627),
+    (0xfbdf, 29883),
+    (0xfbff, 30193),
+    (0xfc1f, 30542),
+    (0xfc3f, 30894),
+    (0xfc5f, 31249),
+    (0xfc7f, 31616),
+    (0xfc9f, 31968),
+    (0xfcbf, 32320),
+    (0xfcdf, 32672),
+    (0xfcff, 33033),
+    (0xfd1f, 33385),
+    (0xfd51, 33740),
+    (0xfd71, 34188),
+    (0xfd93, 34636),
+    (0xfdb3, 35084),
+    (0xfdfb, 35598),
+    (0xfe44, 35884),
+    (0xfe68, 36140),
+    (0xfe8e, 36438),
+    (0xfeae, 36694),
+    (0xfece, 36950),
+    (0xfeee, 37206),
+    (0xff12, 37486),
+    (0xff32, 37742),
+    (0xff52, 37998),
+    (0xff72, 38254),
+    (0xff92, 38510),
+    (0xffb2, 38766),
+    (0xffdb, 39022),
+    (0x10792, 39278),
+    (0x107b3, 39534),
+    (0x1d1bf, 39862),
+    (0x1d41e, 40124),
+    (0x1d43e, 40380),
+    (0x1d45f, 40636),
+    (0x1d47f, 40892),
+    (0x1d4a2, 41148),
+    (0x1d4ca, 41404),
+    (0x1d4ea, 41660),
+    (0x1d50d, 41916),
+    (0x1d52f, 42172),
+    (0x1d556, 42428),
+    (0x1d576, 42684),
+    (0x1d596, 42940),
+    (0x1d5b6, 43196),
+    (0x1d5d6, 43452),
+    (0x1d5f6, 43708),
+    (0x1d616, 43964),
+    (0x1d636, 44220),
+    (0x1d656, 44476),
+    (0x1d676, 44732),
+    (0x1d696, 44988),
+    (0x1d6b8, 45244),
+    (0x1d6d8, 45500),
+    (0x1d6f8, 45756),
+    (0x1d718, 46012),
+    (0x1d738, 46268),
+    (0x1d758, 46524),
+    (0x1d778, 46780),
+    (0x1d798, 47036),
+    (0x1d7b8, 47292),
+    (0x1d7da, 47548),
+    (0x1d7fa, 47804),
+    (0x1e04a, 48060),
+    (0x1e06a, 48316),
+    (0x1ee1d, 48572),
+    (0x1ee54, 48828),
+    (0x1ee83, 49084),
+    (0x1eeab, 49340),
+    (0x1f114, 49653),
+    (0x1f135, 50053),
+    (0x1f201, 50345),
+    (0x1f22e, 50604),
+    (0x1fbf7, 50914),
+    (0x2f81d, 51170),
+    (0x2f83d, 51426),
+    (0x2f85d, 51682),
+    (0x2f87d, 51938),
+    (0x2f89d, 52194),
+    (0x2f8bd, 52450),
+    (0x2f8dd, 52706),
+    (0x2f8fd, 52962),
+    (0x2f91d, 53218),
+    (0x2f93d, 53474),
+    (0x2f95d, 53730),
+    (0x2f97d, 53986),
+    (0x2f99d, 54242),
+    (0x2f9bd, 54498),
+    (0x2f9dd, 54754),
+    (0x2f9fd, 55010),
+    (0x2fa1d, 55266),
+];
+
+/// Primary composites `(first, second, composite)`, ordered by `(first, second)`.
+pub(super) static COMPOSITIONS: &[(u32, u32, u32)] = &[
+    (0x3c, 0x338, 0x226e),
+    (0x3d, 0x338, 0x2260),
+    (0x3e, 0x338, 0x226f),
+    (0x41, 0x300, 0xc0),
+    (0x41, 0x301, 0xc1),
+    (0x41, 0x302, 0xc2),
+    (0x41, 0x303, 0xc3),
+    (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102),
+    (0x41, 0x307, 0x226),
+    (0x41, 0x308, 0xc4),
+    (0x41, 0x309, 0x1ea2),
+    (0x41, 0x30a, 0xc5),
+    (0x41, 0x30c, 0x1cd),
+    (0x41, 0x30f, 0x200),
+    (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1ea0),
+    (0x41, 0x325, 0x1e00),
+    (0x41, 0x328, 0x104),
+    (0x42, 0x307, 0x1e02),
+    (0x42, 0x323, 0x1e04),
+    (0x42, 0x331, 0x1e06),
+    (0x43, 0x301, 0x106),
+    (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10a),
+    (0x43, 0x30c, 0x10c),
+    (0x43, 0x327, 0xc7),
+    (0x44, 0x307, 0x1e0a),
+    (0x44, 0x30c, 0x10e),
+    (0x44, 0x323, 0x1e0c),
+    (0x44, 0x327, 0x1e10),
+    (0x44, 0x32d, 0x1e12),
+    (0x44, 0x331, 0x1e0e),
+    (0x45, 0x300, 0xc8),
+    (0x45, 0x301, 0xc9),
+    (0x45, 0x302, 0xca),
+    (0x45, 0x303, 0x1ebc),
+    (0x45, 0x304, 0x112),
+    (0x45, 0x306, 0x114),
+    (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xcb),
+    (0x45, 0x309, 0x1eba),
+    (0x45, 0x30c, 0x11a),
+    (0x45, 0x30f, 0x204),
+    (0x45, 0x311, 0x206),
+    (0x45, 0x323, 0x1eb8),
+    (0x45, 0x327, 0x228),
+    (0x45, 0x328, 0x118),
+    (0x45, 0x32d, 0x1e18),
+    (0x45, 0x330, 0x1e1a),
+    (0x46, 0x307, 0x1e1e),
+    (0x47, 0x301, 0x1f4),
+    (0x47, 0x302, 0x11c),
+    (0x47, 0x304, 0x1e20),
+    (0x47, 0x306, 0x11e),
+    (0x47, 0x307, 0x120),
+    (0x47, 0x30c, 0x1e6),
+    (0x47, 0x327, 0x122),
+    (0x48, 0x302, 0x124),
+    (0x48, 0x307, 0x1e22),
+    (0x48, 0x308, 0x1e26),
+    (0x48, 0x30c, 0x21e),
+    (0x48, 0x323, 0x1e24),
+    (0x48, 0x327, 0x1e28),
+    (0x48, 0x32e, 0x1e2a),
+    (0x49, 0x300, 0xcc),
+    (0x49, 0x301, 0xcd),
+    (0x49, 0x302, 0xce),
+    (0x49, 0x303, 0x128),
+    (0x49, 0x304, 0x12a),
+    (0x49, 0x306, 0x12c),
+    (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xcf),
+    (0x49, 0x309, 0x1ec8),
+    (0x49, 0x30c, 0x1cf),
+    (0x49, 0x30f, 0x208),
+    (0x49, 0x311, 0x20a),
+    (0x49, 0x323, 0x1eca),
+    (0x49, 0x328, 0x12e),
+    (0x49, 0x330, 0x1e2c),
+    (0x4a, 0x302, 0x134),
+    (0x4b, 0x301, 0x1e30),
+    (0x4b, 0x30c, 0x1e8),
+    (0x4b, 0x323, 0x1e32),
+    (0x4b, 0x327, 0x136),
+    (0x4b, 0x331, 0x1e34),
+    (0x4c, 0x301, 0x139),
+    (0x4c, 0x30c, 0x13d),
+    (0x4c, 0x323, 0x1e36),
+    (0x4c, 0x327, 0x13b),
+    (0x4c, 0x32d, 0x1e3c),
+    (0x4c, 0x331, 0x1e3a),
+    (0x4d, 0x301, 0x1e3e),
+    (0x4d, 0x307, 0x1e40),
+    (0x4d, 0x323, 0x1e42),
+    (0x4e, 0x300, 0x1f8),
+    (0x4e, 0x301, 0x143),
+    (0x4e, 0x303, 0xd1),
+    (0x4e, 0x307, 0x1e44),
+    (0x4e, 0x30c, 0x147),
+    (0x4e, 0x323, 0x1e46),
+    (0x4e, 0x327, 0x145),
+    (0x4e, 0x32d, 0x1e4a),
+    (0x4e, 0x331, 0x1e48),
+    (0x4f, 0x300, 0xd2),
+    (0x4f, 0x301, 0xd3),
+    (0x4f, 0x302, 0xd4),
+    (0x4f, 0x303, 0xd5),
+    (0x4f, 0x304, 0x14c),
+    (0x4f, 0x306, 0x14e),
+    (0x4f, 0x307, 0x22e),
+    (0x4f, 0x308, 0xd6),
+    (0x4f, 0x309, 0x1ece),
+    (0x4f, 0x30b, 0x150),
+    (0x4f, 0x30c, 0x1d1),
+    (0x4f, 0x30f, 0x20c),
+    (0x4f, 0x311, 0x20e),
+    (0x4f, 0x31b, 0x1a0),
+    (0x4f, 0x323, 0x1ecc),
+    (0x4f, 0x328, 0x1ea),
+    (0x50, 0x301, 0x1e54),
+    (0x50, 0x307, 0x1e56),
+    (0x52, 0x301, 0x154),
+    (0x52, 0x307, 0x1e58),
+    (0x52, 0x30c, 0x158),
+    (0x52, 0x30f, 0x210),
+    (0x52, 0x311, 0x212),
+    (0x52, 0x323, 0x1e5a),
+    (0x52, 0x327, 0x156),
+    (0x52, 0x331, 0x1e5e),
+    (0x53, 0x301, 0x15a),
+    (0x53, 0x302, 0x15c),
+    (0x53, 0x307, 0x1e60),
+    (0x53, 0x30c, 0x160),
+    (0x53, 0x323, 0x1e62),
+    (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15e),
+    (0x54, 0x307, 0x1e6a),
+    (0x54, 0x30c, 0x164),
+    (0x54, 0x323, 0x1e6c),
+    (0x54, 0x326, 0x21a),
+    (0x54, 0x327, 0x162),
+    (0x54, 0x32d, 0x1e70),
+    (0x54, 0x331, 0x1e6e),
+    (0x55, 0x300, 0xd9),
+    (0x55, 0x301, 0xda),
+    (0x55, 0x302, 0xdb),
+    (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16a),
+    (0x55, 0x306, 0x16c),
+    (0x55, 0x308, 0xdc),
+    (0x55, 0x309, 0x1ee6),
+    (0x55, 0x30a, 0x16e),
+    (0x55, 0x30b, 0x170),
+    (0x55, 0x30c, 0x1d3),
+    (0x55, 0x30f, 0x214),
+    (0x55, 0x311, 0x216),
+    (0x55, 0x31b, 0x1af),
+    (0x55, 0x323, 0x1ee4),
+    (0x55, 0x324, 0x1e72),
+    (0x55, 0x328, 0x172),
+    (0x55, 0x32d, 0x1e76),
+    (0x55, 0x330, 0x1e74),
+    (0x56, 0x303, 0x1e7c),
+    (0x56, 0x323, 0x1e7e),
+    (0x57, 0x300, 0x1e80),
+    (0x57, 0x301, 0x1e82),
+    (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1e86),
+    (0x57, 0x308, 0x1e84),
+    (0x57, 0x323, 0x1e88),
+    (0x58, 0x307, 0x1e8a),
+    (0x58, 0x308, 0x1e8c),
+    (0x59, 0x300, 0x1ef2),
+    (0x59, 0x301, 0xdd),
+    (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1ef8),
+    (0x59, 0x304, 0x232),
+    (0x59, 0x307, 0x1e8e),
+    (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1ef6),
+    (0x59, 0x323, 0x1ef4),
+    (0x5a, 0x301, 0x179),
+    (0x5a, 0x302, 0x1e90),
+    (0x5a, 0x307, 0x17b),
+    (0x5a, 0x30c, 0x17d),
+    (0x5a, 0x323, 0x1e92),
+    (0x5a, 0x331, 0x1e94),
+    (0x61, 0x300, 0xe0),
+    (0x61, 0x301, 0xe1),
+    (0x61, 0x302, 0xe2),
+    (0x61, 0x303, 0xe3),
+    (0x61, 0x304, 0x101),
+    (0x61, 0x306, 0x103),
+    (0x61, 0x307, 0x227),
+    (0x61, 0x308, 0xe4),
+    (0x61, 0x309, 0x1ea3),
+    (0x61, 0x30a, 0xe5),
+    (0x61, 0x30c, 0x1ce),
+    (0x61, 0x30f, 0x201),
+    (0x61, 0x311, 0x203),
+    (0x61, 0x323, 0x1ea1),
+    (0x61, 0x325, 0x1e01),
+    (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1e03),
+    (0x62, 0x323, 0x1e05),
+    (0x62, 0x331, 0x1e07),
+    (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109),
+    (0x63, 0x307, 0x10b),
+    (0x63, 0x30c, 0x10d),
+    (0x63, 0x327, 0xe7),
+    (0x64, 0x307, 0x1e0b),
+    (0x64, 0x30c, 0x10f),
+    (0x64, 0x323, 0x1e0d),
+    (0x64, 0x327, 0x1e11),
+    (0x64, 0x32d, 0x1e13),
+    (0x64, 0x331, 0x1e0f),
+    (0x65, 0x300, 0xe8),
+    (0x65, 0x301, 0xe9),
+    (0x65, 0x302, 0xea),
+    (0x65, 0x303, 0x1ebd),
+    (0x65, 0x304, 0x113),
+    (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117),
+    (0x65, 0x308, 0xeb),
+    (0x65, 0x309, 0x1ebb),
+    (0x65, 0x30c, 0x11b),
+    (0x65, 0x30f, 0x205),
+    (0x65, 0x311, 0x207),
+    (0x65, 0x323, 0x1eb9),
+    (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119),
+    (0x65, 0x32d, 0x1e19),
+    (0x65, 0x330, 0x1e1b),
+    (0x66, 0x307, 0x1e1f),
+    (0x67, 0x301, 0x1f5),
+    (0x67, 0x302, 0x11d),
+    (0x67, 0x304, 0x1e21),
+    (0x67, 0x306, 0x11f),
+    (0x67, 0x307, 0x121),
+    (0x67, 0x30c, 0x1e7),
+    (0x67, 0x327, 0x123),
+    (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1e23),
+    (0x68, 0x308, 0x1e27),
+    (0x68, 0x30c, 0x21f),
+    (0x68, 0x323, 0x1e25),
+    (0x68, 0x327, 0x1e29),
+    (0x68, 0x32e, 0x1e2b),
+    (0x68, 0x331, 0x1e96),
+    (0x69, 0x300, 0xec),
+    (0x69, 0x301, 0xed),
+    (0x69, 0x302, 0xee),
+    (0x69, 0x303, 0x129),
+    (0x69, 0x304, 0x12b),
+    (0x69, 0x306, 0x12d),
+    (0x69, 0x308, 0xef),
+    (0x69, 0x309, 0x1ec9),
+    (0x69, 0x30c, 0x1d0),
+    (0x69, 0x30f, 0x209),
+    (0x69, 0x311, 0x20b),
+    (0x69, 0x323, 0x1ecb),
+    (0x69, 0x328, 0x12f),
+    (0x69, 0x330, 0x1e2d),
+    (0x6a, 0x302, 0x135),
+    (0x6a, 0x30c, 0x1f0),
+    (0x6b, 0x301, 0x1e31),
+    (0x6b, 0x30c, 0x1e9),
+    (0x6b, 0x323, 0x1e33),
+    (0x6b, 0x327, 0x137),
+    (0x6b, 0x331, 0x1e35),
+    (0x6c, 0x301, 0x13a),
+    (0x6c, 0x30c, 0x13e),
+    (0x6c, 0x323, 0x1e37),
+    (0x6c, 0x327, 0x13c),
+    (0x6c, 0x32d, 0x1e3d),
+    (0x6c, 0x331, 0x1e3b),
+    (0x6d, 0x301, 0x1e3f),
+    (0x6d, 0x307, 0x1e41),
+    (0x6d, 0x323, 0x1e43),
+    (0x6e, 0x300, 0x1f9),
+    (0x6e, 0x301, 0x144),
+    (0x6e, 0x303, 0xf1),
+    (0x6e, 0x307, 0x1e45),
+    (0x6e, 0x30c, 0x148),
+    (0x6e, 0x323, 0x1e47),
+    (0x6e, 0x327, 0x146),
+    (0x6e, 0x32d, 0x1e4b),
+    (0x6e, 0x331, 0x1e49),
+    (0x6f, 0x300, 0xf2),
+    (0x6f, 0x301, 0xf3),
+    (0x6f, 0x302, 0xf4),
+    (0x6f, 0x303, 0xf5),
+    (0x6f, 0x304, 0x14d),
+    (0x6f, 0x306, 0x14f),
+    (0x6f, 0x307, 0x22f),
+    (0x6f, 0x308, 0xf6),
+    (0x6f, 0x309, 0x1ecf),
+    (0x6f, 0x30b, 0x151),
+    (0x6f, 0x30c, 0x1d2),
+    (0x6f, 0x30f, 0x20d),
+    (0x6f, 0x311, 0x20f),
+    (0x6f, 0x31b, 0x1a1),
+    (0x6f, 0x323, 0x1ecd),
+    (0x6f, 0x328, 0x1eb),
+    (0x70, 0x301, 0x1e55),
+    (0x70, 0x307, 0x1e57),
+    (0x72, 0x301, 0x155),
+    (0x72, 0x307, 0x1e59),
+    (0x72, 0x30c, 0x159),
+    (0x72, 0x30f, 0x211),
+    (0x72, 0x311, 0x213),
+    (0x72, 0x323, 0x1e5b),
+    (0x72, 0x327, 0x157),
+    (0x72, 0x331, 0x1e5f),
+    (0x73, 0x301, 0x15b),
+    (0x73, 0x302, 0x15d),
+    (0x73, 0x307, 0x1e61),
+    (0x73, 0x30c, 0x161),
+    (0x73, 0x323, 0x1e63),
+    (0x73, 0x326, 0x219),
+    (0x73, 0x327, 0x15f),
+    (0x74, 0x307, 0x1e6b),
+    (0x74, 0x308, 0x1e97),
+    (0x74, 0x30c, 0x165),
+    (0x74, 0x323, 0x1e6d),
+    (0x74, 0x326, 0x21b),
+    (0x74, 0x327, 0x163),
+    (0x74, 0x32d, 0x1e71),
+    (0x74, 0x331, 0x1e6f),
+    (0x75, 0x300, 0xf9),
+    (0x75, 0x301, 0xfa),
+    (0x75, 0x302, 0xfb),
+    (0x75, 0x303, 0x169),
+    (0x75, 0x304, 0x16b),
+    (0x75, 0x306, 0x16d),
+    (0x75, 0x308, 0xfc),
+    (0x75, 0x309, 0x1ee7),
+    (0x75, 0x30a, 0x16f),
+    (0x75, 0x30b, 0x171),
+    (0x75, 0x30c, 0x1d4),
+    (0x75, 0x30f, 0x215),
+    (0x75, 0x311, 0x217),
+    (0x75, 0x31b, 0x1b0),
+    (0x75, 0x323, 0x1ee5),
+    (0x75, 0x324, 0x1e73),
+    (0x75, 0x328, 0x173),
+    (0x75, 0x32d, 0x1e77),
+    (0x75, 0x330, 0x1e75),
+    (0x76, 0x303, 0x1e7d),
+    (0x76, 0x323, 0x1e7f),
+    (0x77, 0x300, 0x1e81),
+    (0x77, 0x301, 0x1e83),
+    (0x77, 0x302, 0x175),
+    (0x77, 0x307, 0x1e87),
+    (0x77, 0x308, 0x1e85),
+    (0x77, 0x30a, 0x1e98),
+    (0x77, 0x323, 0x1e89),
+    (0x78, 0x307, 0x1e8b),
+    (0x78, 0x308, 0x1e8d),
+    (0x79, 0x300, 0x1ef3),
+    (0x79, 0x301, 0xfd),
+    (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1ef9),
+    (0x79, 0x304, 0x233),
+    (0x79, 0x307, 0x1e8f),
+    (0x79, 0x308, 0xff),
+    (0x79, 0x309, 0x1ef7),
+    (0x79, 0x30a, 0x1e99),
+    (0x79, 0x323, 0x1ef5),
+    (0x7a, 0x301, 0x17a),
+    (0x7a, 0x302, 0x1e91),
+    (0x7a, 0x307, 0x17c),
+    (0x7a, 0x30c, 0x17e),
+    (0x7a, 0x323, 0x1e93),
+    (0x7a, 0x331, 0x1e95),
+    (0xa8, 0x300, 0x1fed),
+    (0xa8, 0x301, 0x385),
+    (0xa8, 0x342, 0x1fc1),
+    (0xc2, 0x300, 0x1ea6),
+    (0xc2, 0x301, 0x1ea4),
+    (0xc2, 0x303, 0x1eaa),
+    (0xc2, 0x309, 0x1ea8),
+    (0xc4, 0x304, 0x1de),
+    (0xc5, 0x301, 0x1fa),
+    (0xc6, 0x301, 0x1fc),
+    (0xc6, 0x304, 0x1e2),
+    (0xc7, 0x301, 0x1e08),
+    (0xca, 0x300, 0x1ec0),
+    (0xca, 0x301, 0x1ebe),
+    (0xca, 0x303, 0x1ec4),
+    (0xca, 0x309, 0x1ec2),
+    (0xcf, 0x301, 0x1e2e),
+    (0xd4, 0x300, 0x1ed2),
+    (0xd4, 0x301, 0x1ed0),
+    (0xd4, 0x303, 0x1ed6),
+    (0xd4, 0x309, 0x1ed4),
+    (0xd5, 0x301, 0x1e4c),
+    (0xd5, 0x304, 0x22c),
+    (0xd5, 0x308, 0x1e4e),
+    (0xd6, 0x304, 0x22a),
+    (0xd8, 0x301, 0x1fe),
+    (0xdc, 0x300, 0x1db),
+    (0xdc, 0x301, 0x1d7),
+    (0xdc, 0x304, 0x1d5),
+    (0xdc, 0x30c, 0x1d9),
+    (0xe2, 0x300, 0x1ea7),
+    (0xe2, 0x301, 0x1ea5),
+    (0xe2, 0x303, 0x1eab),
+    (0xe2, 0x309, 0x1ea9),
+    (0xe4, 0x304, 0x1df),
+    (0xe5, 0x301, 0x1fb),
+    (0xe6, 0x301, 0x1fd),
+    (0xe6, 0x304, 0x1e3),
+    (0xe7, 0x301, 0x1e09),
+    (0xea, 0x300, 0x1ec1),
+    (0xea, 0x301, 0x1ebf),
+    (0xea, 0x303, 0x1ec5),
+    (0xea, 0x309, 0x1ec3),
+    (0xef, 0x301, 0x1e2f),
+    (0xf4, 0x300, 0x1ed3),
+    (0xf4, 0x301, 0x1ed1),
+    (0xf4, 0x303, 0x1ed7),
+    (0xf4, 0x309, 0x1ed5),
+    (0xf5, 0x301, 0x1e4d),
+    (0xf5, 0x304, 0x22d),
+    (0xf5, 0x308, 0x1e4f),
+    (0xf6, 0x304, 0x22b),
+    (0xf8, 0x301, 0x1ff),
+    (0xfc, 0x300, 0x1dc),
+    (0xfc, 0x301, 0x1d8),
+    (0xfc, 0x304, 0x1d6),
+    (0xfc, 0x30c, 0x1da),
+    (0x102, 0x300, 0x1eb0),
+    (0x102, 0x301, 0x1eae),
+    (0x102, 0x303, 0x1eb4),
+    (0x102, 0x309, 0x1eb2),
+    (0x103, 0x300, 0x1eb1),
+    (0x103, 0x301, 0x1eaf),
+    (0x103, 0x303, 0x1eb5),
+    (0x103, 0x309, 0x1eb3),
+    (0x112, 0x300, 0x1e14),
+    (0x112, 0x301, 0x1e16),
+    (0x113, 0x300, 0x1e15),
+    (0x113, 0x301, 0x1e17),
+    (0x14c, 0x300, 0x1e50),
+    (0x14c, 0x301, 0x1e52),
+    (0x14d, 0x300, 0x1e51),
+    (0x14d, 0x301, 0x1e53),
+    (0x15a, 0x307, 0x1e64),
+    (0x15b, 0x307, 0x1e65),
+    (0x160, 0x307, 0x1e66),
+    (0x161, 0x307, 0x1e67),
+    (0x168, 0x301, 0x1e78),
+    (0x169, 0x301, 0x1e79),
+    (0x16a, 0x308, 0x1e7a),
+    (0x16b, 0x308, 0x1e7b),
+    (0x17f, 0x307, 0x1e9b),
+    (0x1a0, 0x300, 0x1edc),
+    (0x1a0, 0x301, 0x1eda),
+    (0x1a0, 0x303, 0x1ee0),
+    (0x1a0, 0x309, 0x1ede),
+    (0x1a0, 0x323, 0x1ee2),
+    (0x1a1, 0x300, 0x1edd),
+    (0x1a1, 0x301, 0x1edb),
+    (0x1a1, 0x303, 0x1ee1),
+    (0x1a1, 0x309, 0x1edf),
+    (0x1a1, 0x323, 0x1ee3),
+    (0x1af, 0x300, 0x1eea),
+    (0x1af, 0x301, 0x1ee8),
+    (0x1af, 0x303, 0x1eee),
+    (0x1af, 0x309, 0x1eec),
+    (0x1af, 0x323, 0x1ef0),
+    (0x1b0, 0x300, 0x1eeb),
+    (0x1b0, 0x301, 0x1ee9),
+    (0x1b0, 0x303, 0x1eef),
+    (0x1b0, 0x309, 0x1eed),
+    (0x1b0, 0x323, 0x1ef1),
+    (0x1b7, 0x30c, 0x1ee),
+    (0x1ea, 0x304, 0x1ec),
+    (0x1eb, 0x304, 0x1ed),
+    (0x226, 0x304, 0x1e0),
+    (0x227, 0x304, 0x1e1),
+    (0x228, 0x306, 0x1e1c),
+    (0x229, 0x306, 0x1e1d),
+    (0x22e, 0x304, 0x230),
+    (0x22f, 0x304, 0x231),
+    (0x292, 0x30c, 0x1ef),
+    (0x391, 0x300, 0x1fba),
+    (0x391, 0x301, 0x386),
+    (0x391, 0x304, 0x1fb9),
+    (0x391, 0x306, 0x1fb8),
+    (0x391, 0x313, 0x1f08),
+    (0x391, 0x314, 0x1f09),
+    (0x391, 0x345, 0x1fbc),
+    (0x395, 0x300, 0x1fc8),
+    (0x395, 0x301, 0x388),
+    (0x395, 0x313, 0x1f18),
+    (0x395, 0x314, 0x1f19),
+    (0x397, 0x300, 0x1fca),
+    (0x397, 0x301, 0x389),
+    (0x397, 0x313, 0x1f28),
+    (0x397, 0x314, 0x1f29),
+    (0x397, 0x345, 0x1fcc),
+    (0x399, 0x300, 0x1fda),
+    (0x399, 0x301, 0x38a),
+    (0x399, 0x304, 0x1fd9),
+    (0x399, 0x306, 0x1fd8),
+    (0x399, 0x308, 0x3aa),
+    (0x399, 0x313, 0x1f38),
+    (0x399, 0x314, 0x1f39),
+    (0x39f, 0x300, 0x1ff8),
+    (0x39f, 0x301, 0x38c),
+    (0x39f, 0x313, 0x1f48),
+    (0x39f, 0x314, 0x1f49),
+    (0x3a1, 0x314, 0x1fec),
+    (0x3a5, 0x300, 0x1fea),
+    (0x3a5, 0x301, 0x38e),
+    (0x3a5, 0x304, 0x1fe9),
+    (0x3a5, 0x306, 0x1fe8),
+    (0x3a5, 0x308, 0x3ab),
+    (0x3a5, 0x314, 0x1f59),
+    (0x3a9, 0x300, 0x1ffa),
+    (0x3a9, 0x301, 0x38f),
+    (0x3a9, 0x313, 0x1f68),
+    (0x3a9, 0x314, 0x1f69),
+    (0x3a9, 0x345, 0x1ffc),
+    (0x3ac, 0x345, 0x1fb4),
+    (0x3ae, 0x345, 0x1fc4),
+    (0x3b1, 0x300, 0x1f70),
+    (0x3b1, 0x301, 0x3ac),
+    (0x3b1, 0x304, 0x1fb1),
+    (0x3b1, 0x306, 0x1fb0),
+    (0x3b1, 0x313, 0x1f00),
+    (0x3b1, 0x314, 0x1f01),
+    (0x3b1, 0x342, 0x1fb6),
+    (0x3b1, 0x345, 0x1fb3),
+    (0x3b5, 0x300, 0x1f72),
+    (0x3b5, 0x301, 0x3ad),
+    (0x3b5, 0x313, 0x1f10),
+    (0x3b5, 0x314, 0x1f11),
+    (0x3b7, 0x300, 0x1f74),
+    (0x3b7, 0x301, 0x3ae),
+    (0x3b7, 0x313, 0x1f20),
+    (0x3b7, 0x314, 0x1f21),
+    (0x3b7, 0x342, 0x1fc6),
+    (0x3b7, 0x345, 0x1fc3),
+    (0x3b9, 0x300, 0x1f76),
+    (0x3b9, 0x301, 0x3af),
+    (0x3b9, 0x304, 0x1fd1),
+    (0x3b9, 0x306, 0x1fd0),
+    (0x3b9, 0x308, 0x3ca),
+    (0x3b9, 0x313, 0x1f30),
+    (0x3b9, 0x314, 0x1f31),
+    (0x3b9, 0x342, 0x1fd6),
+    (0x3bf, 0x300, 0x1f78),
+    (0x3bf, 0x301, 0x3cc),
+    (0x3bf, 0x313, 0x1f40),
+    (0x3bf, 0x314, 0x1f41),
+    (0x3c1, 0x313, 0x1fe4),
+    (0x3c1, 0x314, 0x1fe5),
+    (0x3c5, 0x300, 0x1f7a),
+    (0x3c5, 0x301, 0x3cd),
+    (0x3c5, 0x304, 0x1fe1),
+    (0x3c5, 0x306, 0x1fe0),
+    (0x3c5, 0x308, 0x3cb),
+    (0x3c5, 0x313, 0x1f50),
+    (0x3c5, 0x314, 0x1f51),
+    (0x3c5, 0x342, 0x1fe6),
+    (0x3c9, 0x300, 0x1f7c),
+    (0x3c9, 0x301, 0x3ce),
+    (0x3c9, 0x313, 0x1f60),
+    (0x3c9, 0x314, 0x1f61),
+    (0x3c9, 0x342, 0x1ff6),
+    (0x3c9, 0x345, 0x1ff3),
+    (0x3ca, 0x300, 0x1fd2),
+    (0x3ca, 0x301, 0x390),
+    (0x3ca, 0x342, 0x1fd7),
+    (0x3cb, 0x300, 0x1fe2),
+    (0x3cb, 0x301, 0x3b0),
+    (0x3cb, 0x342, 0x1fe7),
+    (0x3ce, 0x345, 0x1ff4),
+    (0x3d2, 0x301, 0x3d3),
+    (0x3d2, 0x308, 0x3d4),
+    (0x406, 0x308, 0x407),
+    (0x410, 0x306, 0x4d0),
+    (0x410, 0x308, 0x4d2),
+    (0x413, 0x301, 0x403),
+    (0x415, 0x300, 0x400),
+    (0x415, 0x306, 0x4d6),
+    (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4c1),
+    (0x416, 0x308, 0x4dc),
+    (0x417, 0x308, 0x4de),
+    (0x418, 0x300, 0x40d),
+    (0x418, 0x304, 0x4e2),
+    (0x418, 0x306, 0x419),
+    (0x418, 0x308, 0x4e4),
+    (0x41a, 0x301, 0x40c),
+    (0x41e, 0x308, 0x4e6),
+    (0x423, 0x304, 0x4ee),
+    (0x423, 0x306, 0x40e),
+    (0x423, 0x308, 0x4f0),
+    (0x423, 0x30b, 0x4f2),
+    (0x427, 0x308, 0x4f4),
+    (0x42b, 0x308, 0x4f8),
+    (0x42d, 0x308, 0x4ec),
+    (0x430, 0x306, 0x4d1),
+    (0x430, 0x308, 0x4d3),
+    (0x433, 0x301, 0x453),
+    (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4d7),
+    (0x435, 0x308, 0x451),
+    (0x436, 0x306, 0x4c2),
+    (0x436, 0x308, 0x4dd),
+    (0x437, 0x308, 0x4df),
+    (0x438, 0x300, 0x45d),
+    (0x438, 0x304, 0x4e3),
+    (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4e5),
+    (0x43a, 0x301, 0x45c),
+    (0x43e, 0x308, 0x4e7),
+    (0x443, 0x304, 0x4ef),
+    (0x443, 0x306, 0x45e),
+    (0x443, 0x308, 0x4f1),
+    (0x443, 0x30b, 0x4f3),
+    (0x447, 0x308, 0x4f5),
+    (0x44b, 0x308, 0x4f9),
+    (0x44d, 0x308, 0x4ed),
+    (0x456, 0x308, 0x457),
+    (0x474, 0x30f, 0x476),
+    (0x475, 0x30f, 0x477),
+    (0x4d8, 0x308, 0x4da),
+    (0x4d9, 0x308, 0x4db),
+    (0x4e8, 0x308, 0x4ea),
+    (0x4e9, 0x308, 0x4eb),
+    (0x627, 0x653, 0x622),
+    (0x627, 0x654, 0x623),
+    (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624),
+    (0x64a, 0x654, 0x626),
+    (0x6c1, 0x654, 0x6c2),
+    (0x6d2, 0x654, 0x6d3),
+    (0x6d5, 0x654, 0x6c0),
+    (0x928, 0x93c, 0x929),
+    (0x930, 0x93c, 0x931),
+    (0x933, 0x93c, 0x934),
+    (0x9c7, 0x9be, 0x9cb),
+    (0x9c7, 0x9d7, 0x9cc),
+    (0xb47, 0xb3e, 0xb4b),
+    (0xb47, 0xb56, 0xb48),
+    (0xb47, 0xb57, 0xb4c),
+    (0xb92, 0xbd7, 0xb94),
+    (0xbc6, 0xbbe, 0xbca),
+    (0xbc6, 0xbd7, 0xbcc),
+    (0xbc7, 0xbbe, 0xbcb),
+    (0xc46, 0xc56, 0xc48),
+    (0xcbf, 0xcd5, 0xcc0),
+    (0xcc6, 0xcc2, 0xcca),
+    (0xcc6, 0xcd5, 0xcc7),
+    (0xcc6, 0xcd6, 0xcc8),
+    (0xcca, 0xcd5, 0xccb),
+    (0xd46, 0xd3e, 0xd4a),
+    (0xd46, 0xd57, 0xd4c),
+    (0xd47, 0xd3e, 0xd4b),
+    (0xdd9, 0xdca, 0xdda),
+    (0xdd9, 0xdcf, 0xddc),
+    (0xdd9, 0xddf, 0xdde),
+    (0xddc, 0xdca, 0xddd),
+    (0x1025, 0x102e, 0x1026),
+    (0x1b05, 0x1b35, 0x1b06),
+    (0x1b07, 0x1b35, 0x1b08),
+    (0x1b09, 0x1b35, 0x1b0a),
+    (0x1b0b, 0x1b35, 0x1b0c),
+    (0x1b0d, 0x1b35, 0x1b0e),
+    (0x1b11, 0x1b35, 0x1b12),
+    (0x1b3a, 0x1b35, 0x1b3b),
+    (0x1b3c, 0x1b35, 0x1b3d),
+    (0x1b3e, 0x1b35, 0x1b40),
+    (0x1b3f, 0x1b35, 0x1b41),
+    (0x1b42, 0x1b35, 0x1b43),
+    (0x1e36, 0x304, 0x1e38),
+    (0x1e37, 0x304, 0x1e39),
+    (0x1e5a, 0x304, 0x1e5c),
+    (0x1e5b, 0x304, 0x1e5d),
+    (0x1e62, 0x307, 0x1e68),
+    (0x1e63, 0x307, 0x1e69),
+    (0x1ea0, 0x302, 0x1eac),
+    (0x1ea0, 0x306, 0x1eb6),
+    (0x1ea1, 0x302, 0x1ead),
+    (0x1ea1, 0x306, 0x1eb7),
+    (0x1eb8, 0x302, 0x1ec6),
+    (0x1eb9, 0x302, 0x1ec7),
+    (0x1ecc, 0x302, 0x1ed8),
+    (0x1ecd, 0x302, 0x1ed9),
+    (0x1f00, 0x300, 0x1f02),
+    (0x1f00, 0x301, 0x1f04),
+    (0x1f00, 0x342, 0x1f06),
+    (0x1f00, 0x345, 0x1f80),
+    (0x1f01, 0x300, 0x1f03),
+    (0x1f01, 0x301, 0x1f05),
+    (0x1f01, 0x342, 0x1f07),
+    (0x1f01, 0x345, 0x1f81),
+    (0x1f02, 0x345, 0x1f82),
+    (0x1f03, 0x345, 0x1f83),
+    (0x1f04, 0x345, 0x1f84),
+    (0x1f05, 0x345, 0x1f85),
+    (0x1f06, 0x345, 0x1f86),
+    (0x1f07, 0x345, 0x1f87),
+    (0x1f08, 0x300, 0x1f0a),
+    (0x1f08, 0x301, 0x1f0c),
+    (0x1f08, 0x342, 0x1f0e),
+    (0x1f08, 0x345, 0x1f88),
+    (0x1f09, 0x300, 0x1f0b),
+    (0x1f09, 0x301, 0x1f0d),
+    (0x1f09, 0x342, 0x1f0f),
+    (0x1f09, 0x345, 0x1f89),
+    (0x1f0a, 0x345, 0x1f8a),
+    (0x1f0b, 0x345, 0x1f8b),
+    (0x1f0c, 0x345, 0x1f8c),
+    (0x1f0d, 0x345, 0x1f8d),
+    (0x1f0e, 0x345, 0x1f8e),
+    (0x1f0f, 0x345, 0x1f8f),
+    (0x1f10, 0x300, 0x1f12),
+    (0x1f10, 0x301, 0x1f14),
+    (0x1f11, 0x300, 0x1f13),
+    (0x1f11, 0x301, 0x1f15),
+    (0x1f18, 0x300, 0x1f1a),
+    (0x1f18, 0x301, 0x1f1c),
+    (0x1f19, 0x300, 0x1f1b),
+    (0x1f19, 0x301, 0x1f1d),
+    (0x1f20, 0x300, 0x1f22),
+    (0x1f20, 0x301, 0x1f24),
+    (0x1f20, 0x342, 0x1f26),
+    (0x1f20, 0x345, 0x1f90),
+    (0x1f21, 0x300, 0x1f23),
+    (0x1f21, 0x301, 0x1f25),
+    (0x1f21, 0x342, 0x1f27),
+    (0x1f21, 0x345, 0x1f91),
+    (0x1f22, 0x345, 0x1f92),
+    (0x1f23, 0x345, 0x1f93),
+    (0x1f24, 0x345, 0x1f94),
+    (0x1f25, 0x345, 0x1f95),
+    (0x1f26, 0x345, 0x1f96),
+    (0x1f27, 0x345, 0x1f97),
+    (0x1f28, 0x300, 0x1f2a),
+    (0x1f28, 0x301, 0x1f2c),
+    (0x1f28, 0x342, 0x1f2e),
+    (0x1f28, 0x345, 0x1f98),
+    (0x1f29, 0x300, 0x1f2b),
+    (0x1f29, 0x301, 0x1f2d),
+    (0x1f29, 0x342, 0x1f2f),
+    (0x1f29, 0x345, 0x1f99),
+    (0x1f2a, 0x345, 0x1f9a),
+    (0x1f2b, 0x345, 0x1f9b),
+    (0x1f2c, 0x345, 0x1f9c),
+    (0x1f2d, 0x345, 0x1f9d),
+    (0x1f2e, 0x345, 0x1f9e),
+    (0x1f2f, 0x345, 0x1f9f),
+    (0x1f30, 0x300, 0x1f32),
+    (0x1f30, 0x301, 0x1f34),
+    (0x1f30, 0x342, 0x1f36),
+    (0x1f31, 0x300, 0x1f33),
+    (0x1f31, 0x301, 0x1f35),
+    (0x1f31, 0x342, 0x1f37),
+    (0x1f38, 0x300, 0x1f3a),
+    (0x1f38, 0x301, 0x1f3c),
+    (0x1f38, 0x342, 0x1f3e),
+    (0x1f39, 0x300, 0x1f3b),
+    (0x1f39, 0x301, 0x1f3d),
+    (0x1f39, 0x342, 0x1f3f),
+    (0x1f40, 0x300, 0x1f42),
+    (0x1f40, 0x301, 0x1f44),
+    (0x1f41, 0x300, 0x1f43),
+    (0x1f41, 0x301, 0x1f45),
+    (0x1f48, 0x300, 0x1f4a),
+    (0x1f48, 0x301, 0x1f4c),
+    (0x1f49, 0x300, 0x1f4b),
+    (0x1f49, 0x301, 0x1f4d),
+    (0x1f50, 0x300, 0x1f52),
+    (0x1f50, 0x301, 0x1f54),
+    (0x1f50, 0x342, 0x1f56),
+    (0x1f51, 0x300, 0x1f53),
+    (0x1f51, 0x301, 0x1f55),
+    (0x1f51, 0x342, 0x1f57),
+    (0x1f59, 0x300, 0x1f5b),
+    (0x1f59, 0x301, 0x1f5d),
+    (0x1f59, 0x342, 0x1f5f),
+    (0x1f60, 0x300, 0x1f62),
+    (0x1f60, 0x301, 0x1f64),
+    (0x1f60, 0x342, 0x1f66),
+    (0x1f60, 0x345, 0x1fa0),
+    (0x1f61, 0x300, 0x1f63),
+    (0x1f61, 0x301, 0x1f65),
+    (0x1f61, 0x342, 0x1f67),
+    (0x1f61, 0x345, 0x1fa1),
+    (0x1f62, 0x345, 0x1fa2),
+    (0x1f63, 0x345, 0x1fa3),
+    (0x1f64, 0x345, 0x1fa4),
+    (0x1f65, 0x345, 0x1fa5),
+    (0x1f66, 0x345, 0x1fa6),
+    (0x1f67, 0x345, 0x1fa7),
+    (0x1f68, 0x300, 0x1f6a),
+    (0x1f68, 0x301, 0x1f6c),
+    (0x1f68, 0x342, 0x1f6e),
+    (0x1f68, 0x345, 0x1fa8),
+    (0x1f69, 0x300, 0x1f6b),
+    (0x1f69, 0x301, 0x1f6d),
+    (0x1f69, 0x342, 0x1f6f),
+    (0x1f69, 0x345, 0x1fa9),
+    (0x1f6a, 0x345, 0x1faa),
+    (0x1f6b, 0x345, 0x1fab),
+    (0x1f6c, 0x345, 0x1fac),
+    (0x1f6d, 0x345, 0x1fad),
+    (0x1f6e, 0x345, 0x1fae),
+    (0x1f6f, 0x345, 0x1faf),
+    (0x1f70, 0x345, 0x1fb2),
+    (0x1f74, 0x345, 0x1fc2),
+    (0x1f7c, 0x345, 0x1ff2),
+    (0x1fb6, 0x345, 0x1fb7),
+    (0x1fbf, 0x300, 0x1fcd),
+    (0x1fbf, 0x301, 0x1fce),
+    (0x1fbf, 0x342, 0x1fcf),
+    (0x1fc6, 0x345, 0x1fc7),
+    (0x1ff6, 0x345, 0x1ff7),
+    (0x1ffe, 0x300, 0x1fdd),
+    (0x1ffe, 0x301, 0x1fde),
+    (0x1ffe, 0x342, 0x1fdf),
+    (0x2190, 0x338, 0x219a),
+    (0x2192, 0x338, 0x219b),
+    (0x2194, 0x338, 0x21ae),
+    (0x21d0, 0x338, 0x21cd),
+    (0x21d2, 0x338, 0x21cf),
+    (0x21d4, 0x338, 0x21ce),
+    (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209),
+    (0x220b, 0x338, 0x220c),
+    (0x2223, 0x338, 0x2224),
+    (0x2225, 0x338, 0x2226),
+    (0x223c, 0x338, 0x2241),
+    (0x2243, 0x338, 0x2244),
+    (0x2245, 0x338, 0x2247),
+    (0x2248, 0x338, 0x2249),
+    (0x224d, 0x338, 0x226d),
+    (0x2261, 0x338, 0x2262),
+    (0x2264, 0x338, 0x2270),
+    (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274),
+    (0x2273, 0x338, 0x2275),
+    (0x2276, 0x338, 0x2278),
+    (0x2277, 0x338, 0x2279),
+    (0x227a, 0x338, 0x2280),
+    (0x227b, 0x338, 0x2281),
+    (0x227c, 0x338, 0x22e0),
+    (0x227d, 0x338, 0x22e1),
+    (0x2282, 0x338, 0x2284),
+    (0x2283, 0x338, 0x2285),
+    (0x2286, 0x338, 0x2288),
+    (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22e2),
+    (0x2292, 0x338, 0x22e3),
+    (0x22a2, 0x338, 0x22ac),
+    (0x22a8, 0x338, 0x22ad),
+    (0x22a9, 0x338, 0x22ae),
+    (0x22ab, 0x338, 0x22af),
+    (0x22b2, 0x338, 0x22ea),
+    (0x22b3, 0x338, 0x22eb),
+    (0x22b4, 0x338, 0x22ec),
+    (0x22b5, 0x338, 0x22ed),
+    (0x3046, 0x3099, 0x3094),
+    (0x304b, 0x3099, 0x304c),
+    (0x304d, 0x3099, 0x304e),
+    (0x304f, 0x3099, 0x3050),
+    (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056),
+    (0x3057, 0x3099, 0x3058),
+    (0x3059, 0x3099, 0x305a),
+    (0x305b, 0x3099, 0x305c),
+    (0x305d, 0x3099, 0x305e),
+    (0x305f, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062),
+    (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069),
+    (0x306f, 0x3099, 0x3070),
+    (0x306f, 0x309a, 0x3071),
+    (0x3072, 0x3099, 0x3073),
+    (0x3072, 0x309a, 0x3074),
+    (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309a, 0x3077),
+    (0x3078, 0x3099, 0x3079),
+    (0x3078, 0x309a, 0x307a),
+    (0x307b, 0x3099, 0x307c),
+    (0x307b, 0x309a, 0x307d),
+    (0x309d, 0x3099, 0x309e),
+    (0x30a6, 0x3099, 0x30f4),
+    (0x30ab, 0x3099, 0x30ac),
+    (0x30ad, 0x3099, 0x30ae),
+    (0x30af, 0x3099, 0x30b0),
+    (0x30b1, 0x3099, 0x30b2),
+    (0x30b3, 0x3099, 0x30b4),
+    (0x30b5, 0x3099, 0x30b6),
+    (0x30b7, 0x3099, 0x30b8),
+    (0x30b9, 0x3099, 0x30ba),
+    (0x30bb, 0x3099, 0x30bc),
+    (0x30bd, 0x3099, 0x30be),
+    (0x30bf, 0x3099, 0x30c0),
+    (0x30c1, 0x3099, 0x30c2),
+    (0x30c4, 0x3099, 0x30c5),
+    (0x30c6, 0x3099, 0x30c7),
+    (0x30c8, 0x3099, 0x30c9),
+    (0x30cf, 0x3099, 0x30d0),
+    (0x30cf, 0x309a, 0x30d1),
+    (0x30d2, 0x3099, 0x30d3),
+    (0x30d2, 0x309a, 0x30d4),
+    (0x30d5, 0x3099, 0x30d6),
+    (0x30d5, 0x309a, 0x30d7),
+    (0x30d8, 0x3099, 0x30d9),
+    (0x30d8, 0x309a, 0x30da),
+    (0x30db, 0x3099, 0x30dc),
+    (0x30db, 0x309a, 0x30dd),
+    (0x30ef, 0x3099, 0x30f7),
+    (0x30f0, 0x3099, 0x30f8),
+    (0x30f1, 0x3099, 0x30f9),
+    (0x30f2, 0x3099, 0x30fa),
+    (0x30fd, 0x3099, 0x30fe),
+    (0x11099, 0x110ba, 0x1109a),
+    (0x1109b, 0x110ba, 0x1109c),
+    (0x110a5, 0x110ba, 0x110ab),
+    (0x11131, 0x11127, 0x1112e),
+    (0x11132, 0x11127, 0x1112f),
+    (0x11347, 0x1133e, 0x1134b),
+    (0x11347, 0x11357, 0x1134c),
+    (0x114b9, 0x114b0, 0x114bc),
+    (0x114b9, 0x114ba, 0x114bb),
+    (0x114b9, 0x114bd, 0x114be),
+    (0x115b8, 0x115af, 0x115ba),
+    (0x115b9, 0x115af, 0x115bb),
+    (0x11935, 0x11930, 0x11938),
+];
+
+/// Number of entries between two entries of a chunk index.
+pub(super) const CHUNK: usize = 32;
+
+/// Offset of the entries in `names.bin`; see the generator for the layout.
+pub(super) const NAME_ENTRIES_START: usize = 105087;
+
+/// `(first code point, entry offset)` of every 32th entry in `names.bin`.
+pub(super) static NAME_CHUNKS: &[(u32, u32)] = &[
+    (0x20, 0),
+    (0x40, 154),
+    (0x60, 361),
+    (0xa1, 570),
+    (0xc1, 770),
+    (0xe1, 1049),
+    (0x101, 1330),
+    (0x121, 1621),
+    (0x141, 1911),
+    (0x161, 2201),
+    (0x181, 2506),
+    (0x1a1, 2781),
+    (0x1c1, 3051),
+    (0x1e1, 3372),
+    (0x201, 3691),
+    (0x221, 4022),
+    (0x241, 4332),
+    (0x261, 4618),
+    (0x281, 4907),
+    (0x2a1, 5198),
+    (0x2c1, 5479),
+    (0x2e1, 5710),
+    (0x301, 5984),
+    (0x321, 6193),
+    (0x341, 6410),
+    (0x361, 6631),
+    (0x387, 6880),
+    (0x3aa, 7128),
+    (0x3ca, 7377),
+    (0x3ea, 7619),
+    (0x40a, 7870),
+    (0x42a, 8093),
+    (0x44a, 8314),
+    (0x46a, 8564),
+    (0x48a, 8822),
+    (0x4aa, 9122),
+    (0x4ca, 9422),
+    (0x4ea, 9711),
+    (0x50a, 10015),
+    (0x52a, 10291),
+    (0x54b, 10545),
+    (0x56d, 10796),
+    (0x58f, 11058),
+    (0x5b0, 11333),
+    (0x5d8, 11603),
+    (0x607, 11841),
+    (0x627, 12097),
+    (0x647, 12313),
+    (0x667, 12531),
+    (0x687, 12794),
+    (0x6a7, 13086),
+    (0x6c7, 13360),
+    (0x6e7, 13641),
+    (0x707, 13952),
+    (0x728, 14202),
+    (0x748, 14445),
+    (0x76a, 14796),
+    (0x78a, 15182),
+    (0x7aa, 15401),
+    (0x7d8, 15607),
+    (0x7f8, 15869),
+    (0x81a, 16093),
+    (0x83c, 16357),
+    (0x860, 16585),
+    (0x885, 16956),
+    (0x8ac, 17266),
+    (0x8cc, 17584),
+    (0x8ec, 17874),
+    (0x90c, 18100),
+    (0x92c, 18293),
+    (0x94c, 18504),
+    (0x96c, 18714),
+    (0x98f, 18925),
+    (0x9b6, 19145),
+    (0x9eb, 19388),
+    (0xa14, 19640),
+    (0xa38, 19862),
+    (0xa73, 20085),
+    (0xaa0, 20307),
+    (0xac5, 20538),
+    (0xb02, 20781),
+    (0xb27, 21001),
+    (0xb57, 21236),
+    (0xb89, 21474),
+    (0xbbf, 21694),
+    (0xbf9, 21923),
+    (0xc20, 22146),
+    (0xc43, 22372),
+    (0xc7a, 22630),
+    (0xc9c, 22892),
+    (0xcc0, 23116),
+    (0xcf3, 23359),
+    (0xd21, 23586),
+    (0xd41, 23816),
+    (0xd69, 24089),
+    (0xd8b, 24334),
+    (0xdae, 24600),
+    (0xddb, 24905),
+    (0xe0f, 25223),
+    (0xe2f, 25536),
+    (0xe53, 25828),
+    (0xe9b, 26080),
+    (0xebd, 26344),
+    (0xf04, 26612),
+    (0xf24, 26939),
+    (0xf44, 27185),
+    (0xf65, 27377),
+    (0xf89, 27616),
+    (0xfaa, 27886),
+    (0xfcb, 28182),
+    (0x1011, 28466),
+    (0x1031, 28660),
+    (0x1051, 28874),
+    (0x1071, 29213),
+    (0x1091, 29505),
+    (0x10b1, 29743),
+    (0x10d9, 29958),
+    (0x10f9, 30150),
+    (0x1119, 30388),
+    (0x1139, 30708),
+    (0x1159, 30983),
+    (0x1179, 31220),
+    (0x1199, 31513),
+    (0x11b9, 31793),
+    (0x11d9, 32104),
+    (0x11f9, 32406),
+    (0x1219, 32620),
+    (0x1239, 32812),
+    (0x1260, 33004),
+    (0x1280, 33195),
+    (0x12a3, 33390),
+    (0x12ca, 33589),
+    (0x12eb, 33789),
+    (0x130b, 33981),
+    (0x132e, 34173),
+    (0x134e, 34365),
+    (0x1370, 34558),
+    (0x1393, 34766),
+    (0x13b9, 34971),
+    (0x13d9, 35162),
+    (0x13fb, 35355),
+    (0x141d, 35591),
+    (0x143d, 35829),
+    (0x145d, 36083),
+    (0x147d, 36319),
+    (0x149d, 36578),
+    (0x14bd, 36830),
+    (0x14dd, 37054),
+    (0x14fd, 37305),
+    (0x151d, 37554),
+    (0x153d, 37805),
+    (0x155d, 38043),
+    (0x157d, 38273),
+    (0x159d, 38503),
+    (0x15bd, 38773),
+    (0x15dd, 39003),
+    (0x15fd, 39226),
+    (0x161d, 39451),
+    (0x163d, 39675),
+    (0x165d, 39900),
+    (0x167d, 40141),
+    (0x16a0, 40377),
+    (0x16c0, 40679),
+    (0x16e0, 41018),
+    (0x1707, 41276),
+    (0x1730, 41496),
+    (0x1765, 41712),
+    (0x1793, 41933),
+    (0x17b3, 42195),
+    (0x17d3, 42440),
+    (0x1801, 42719),
+    (0x1827, 42916),
+    (0x1847, 43113),
+    (0x1867, 43361),
+    (0x188e, 43646),
+    (0x18b3, 43986),
+    (0x18d3, 44186),
+    (0x18f3, 44429),
+    (0x191d, 44657),
+    (0x1949, 44898),
+    (0x1969, 45139),
+    (0x1996, 45488),
+    (0x19ba, 45859),
+    (0x19e3, 46186),
+    (0x1a03, 46519),
+    (0x1a25, 46756),
+    (0x1a45, 47050),
+    (0x1a66, 47361),
+    (0x1a88, 47671),
+    (0x1ab6, 47947),
+    (0x1b07, 48201),
+    (0x1b27, 48469),
+    (0x1b47, 48745),
+    (0x1b6a, 48986),
+    (0x1b8b, 49287),
+    (0x1bab, 49522),
+    (0x1bcb, 49758),
+    (0x1beb, 50005),
+    (0x1c13, 50248),
+    (0x1c33, 50488),
+    (0x1c59, 50752),
+    (0x1c79, 51041),
+    (0x1ca0, 51339),
+    (0x1cc2, 51633),
+    (0x1cea, 51954),
+    (0x1d0f, 52228),
+    (0x1d2f, 52492),
+    (0x1d4f, 52717),
+    (0x1d6f, 52974),
+    (0x1d8f, 53302),
+    (0x1daf, 53616),
+    (0x1dcf, 53879),
+    (0x1def, 54144),
+    (0x1e0f, 54434),
+    (0x1e2f, 54768),
+    (0x1e4f, 55104),
+    (0x1e6f, 55443),
+    (0x1e8f, 55774),
+    (0x1eaf, 56118),
+    (0x1ecf, 56463),
+    (0x1eef, 56832),
+    (0x1f0f, 57195),
+    (0x1f33, 57581),
+    (0x1f57, 57973),
+    (0x1f7b, 58341),
+    (0x1f9d, 58811),
+    (0x1fbe, 59237),
+    (0x1fe2, 59546),
+    (0x2006, 59851),
+    (0x2026, 60093),
+    (0x2046, 60316),
+    (0x2067, 60518),
+    (0x2089, 60728),
+    (0x20ad, 60951),
+    (0x20dc, 61151),
+    (0x210b, 61381),
+    (0x212b, 61600),
+    (0x214b, 61839),
+    (0x216b, 62095),
+    (0x218b, 62388),
+    (0x21af, 62608),
+    (0x21cf, 62915),
+    (0x21ef, 63152),
+    (0x220f, 63401),
+    (0x222f, 63588),
+    (0x224f, 63801),
+    (0x226f, 64065),
+    (0x228f, 64384),
+    (0x22af, 64575),
+    (0x22cf, 64814),
+    (0x22ef, 65155),
+    (0x230f, 65416),
+    (0x232f, 65624),
+    (0x234f, 65938),
+    (0x236f, 66297),
+    (0x238f, 66558),
+    (0x23af, 66846),
+    (0x23cf, 67179),
+    (0x23ef, 67426),
+    (0x240f, 67672),
+    (0x2448, 67915),
+    (0x247d, 68110),
+    (0x249d, 68370),
+    (0x24bd, 68636),
+    (0x24dd, 68879),
+    (0x24fd, 69116),
+    (0x251d, 69440),
+    (0x253d, 69805),
+    (0x255d, 70165),
+    (0x257d, 70498),
+    (0x259d, 70746),
+    (0x25bd, 70997),
+    (0x25dd, 71233),
+    (0x25fd, 71493),
+    (0x261d, 71679),
+    (0x263d, 71881),
+    (0x265d, 72046),
+    (0x267d, 72317),
+    (0x269d, 72539),
+    (0x26bd, 72736),
+    (0x26dd, 72966),
+    (0x26fd, 73183),
+    (0x271d, 73384),
+    (0x273d, 73649),
+    (0x275d, 73926),
+    (0x277d, 74273),
+    (0x279d, 74599),
+    (0x27bd, 74916),
+    (0x27dd, 75160),
+    (0x27fd, 75439),
+    (0x281d, 75700),
+    (0x283d, 75956),
+    (0x285d, 76212),
+    (0x287d, 76468),
+    (0x289d, 76724),
+    (0x28bd, 76980),
+    (0x28dd, 77236),
+    (0x28fd, 77492),
+    (0x291d, 77790),
+    (0x293d, 78161),
+    (0x295d, 78520),
+    (0x297d, 78955),
+    (0x299d, 79272),
+    (0x29bd, 79646),
+    (0x29dd, 79876),
+    (0x29fd, 80138),
+    (0x2a1d, 80434),
+    (0x2a3d, 80703),
+    (0x2a5d, 80978),
+    (0x2a7d, 81285),
+    (0x2a9d, 81819),
+    (0x2abd, 82181),
+    (0x2add, 82467),
+    (0x2afd, 82776),
+    (0x2b1d, 83026),
+    (0x2b3d, 83286),
+    (0x2b5d, 83590),
+    (0x2b7f, 83964),
+    (0x2ba0, 84320),
+    (0x2bc0, 84654),
+    (0x2be0, 84885),
+    (0x2c00, 85111),
+    (0x2c20, 85367),
+    (0x2c40, 85644),
+    (0x2c60, 85921),
+    (0x2c80, 86225),
+    (0x2ca0, 86447),
+    (0x2cc0, 86717),
+    (0x2ce0, 87015),
+    (0x2d05, 87282),
+    (0x2d25, 87506),
+    (0x2d4d, 87749),
+    (0x2d82, 87984),
+    (0x2dac, 88178),
+    (0x2dd1, 88370),
+    (0x2df3, 88579),
+    (0x2e13, 88826),
+    (0x2e33, 89062),
+    (0x2e53, 89296),
+    (0x2e95, 89517),
+    (0x2eb6, 89733),
+    (0x2ed6, 89968),
+    (0x2f02, 90245),
+    (0x2f22, 90439),
+    (0x2f42, 90638),
+    (0x2f62, 90835),
+    (0x2f82, 91028),
+    (0x2fa2, 91222),
+    (0x2fc2, 91421),
+    (0x2ffc, 91693),
+    (0x301c, 91951),
+    (0x303c, 92220),
+    (0x305d, 92438),
+    (0x307d, 92661),
+    (0x309f, 92916),
+    (0x30bf, 93107),
+    (0x30df, 93298),
+    (0x30ff, 93501),
+    (0x3124, 93716),
+    (0x3145, 93953),
+    (0x3165, 94138),
+    (0x3185, 94414),
+    (0x31a6, 94671),
+    (0x31c6, 94894),
+    (0x31f1, 95089),
+    (0x3211, 95315),
+    (0x3232, 95571),
+    (0x3252, 95835),
+    (0x3272, 96048),
+    (0x3292, 96271),
+    (0x32b2, 96494),
+    (0x32d2, 96749),
+    (0x32f2, 96936),
+    (0x3312, 97113),
+    (0x3332, 97273),
+    (0x3352, 97433),
+    (0x3372, 97771),
+    (0x3392, 97955),
+    (0x33b2, 98143),
+    (0x33d2, 98320),
+    (0x33f2, 98609),
+    (0x4dd2, 98950),
+    (0x4df2, 99212),
+    (0xa012, 99448),
+    (0xa032, 99638),
+    (0xa052, 99829),
+    (0xa072, 100021),
+    (0xa092, 100213),
+    (0xa0b2, 100405),
+    (0xa0d2, 100597),
+    (0xa0f2, 100789),
+    (0xa112, 100980),
+    (0xa132, 101171),
+    (0xa152, 101363),
+    (0xa172, 101555),
+    (0xa192, 101747),
+    (0xa1b2, 101939),
+    (0xa1d2, 102131),
+    (0xa1f2, 102323),
+    (0xa212, 102514),
+    (0xa232, 102706),
+    (0xa252, 102898),
+    (0xa272, 103090),
+    (0xa292, 103282),
+    (0xa2b2, 103474),
+    (0xa2d2, 103666),
+    (0xa2f2, 103858),
+    (0xa312, 104050),
+    (0xa332, 104242),
+    (0xa352, 104434),
+    (0xa372, 104626),
+    (0xa392, 104818),
+    (0xa3b2, 105010),
+    (0xa3d2, 105202),
+    (0xa3f2, 105394),
+    (0xa412, 105586),
+    (0xa432, 105778),
+    (0xa452, 105970),
+    (0xa472, 106162),
+    (0xa495, 106353),
+    (0xa4b5, 106545),
+    (0xa4de, 106749),
+    (0xa4fe, 106986),
+    (0xa51e, 107184),
+    (0xa53e, 107375),
+    (0xa55e, 107564),
+    (0xa57e, 107755),
+    (0xa59e, 107946),
+    (0xa5be, 108137),
+    (0xa5de, 108328),
+    (0xa5fe, 108519),
+    (0xa61e, 108716),
+    (0xa652, 108953),
+    (0xa672, 109238),
+    (0xa692, 109464),
+    (0xa6b2, 109669),
+    (0xa6d2, 109861),
+    (0xa6f2, 110055),
+    (0xa71a, 110413),
+    (0xa73a, 110673),
+    (0xa75a, 111007),
+    (0xa77a, 111266),
+    (0xa79a, 111552),
+    (0xa7ba, 111847),
+    (0xa7f9, 112138),
+    (0xa819, 112424),
+    (0xa842, 112737),
+    (0xa862, 113019),
+    (0xa88a, 113307),
+    (0xa8aa, 113530),
+    (0xa8d2, 113771),
+    (0xa8f8, 113987),
+    (0xa918, 114245),
+    (0xa938, 114512),
+    (0xa963, 114754),
+    (0xa986, 115053),
+    (0xa9a6, 115298),
+    (0xa9c6, 115556),
+    (0xa9eb, 115809),
+    (0xaa0c, 116062),
+    (0xaa2c, 116287),
+    (0xaa57, 116537),
+    (0xaa79, 116796),
+    (0xaa99, 117110),
+    (0xaab9, 117408),
+    (0xaaf1, 117695),
+    (0xab29, 117908),
+    (0xab4a, 118220),
+    (0xab6a, 118562),
+    (0xab8e, 118780),
+    (0xabae, 119003),
+    (0xabce, 119254),
+    (0xabf0, 119564),
+    (0xd7c6, 119856),
+    (0xd7ea, 120190),
+    (0xfb1f, 120493),
+    (0xfb43, 120818),
+    (0xfb64, 121106),
+    (0xfb84, 121370),
+    (0xfba4, 121635),
+    (0xfbd4, 121923),
+    (0xfbf4, 122261),
+    (0xfc14, 122661),
+    (0xfc34, 123002),
+    (0xfc54, 123349),
+    (0xfc74, 123721),
+    (0xfc94, 124073),
+    (0xfcb4, 124429),
+    (0xfcd4, 124768),
+    (0xfcf4, 125129),
+    (0xfd14, 125494),
+    (0xfd34, 125850),
+    (0xfd54, 126207),
+    (0xfd74, 126628),
+    (0xfd96, 127042),
+    (0xfdb6, 127467),
+    (0xfdfd, 127822),
+    (0xfe23, 128098),
+    (0xfe43, 128426),
+    (0xfe64, 128658),
+    (0xfe8a, 128935),
+    (0xfeaa, 129211),
+    (0xfeca, 129473),
+    (0xfeea, 129733),
+    (0xff0d, 130026),
+    (0xff2d, 130269),
+    (0xff4d, 130540),
+    (0xff6d, 130809),
+    (0xff8d, 131066),
+    (0xffad, 131346),
+    (0xffd4, 131613),
+    (0x10006, 131851),
+    (0x10028, 132137),
+    (0x1004a, 132424),
+    (0x1008e, 132703),
+    (0x100ae, 132997),
+    (0x100ce, 133274),
+    (0x100ee, 133572),
+    (0x10117, 133835),
+    (0x1013a, 134112),
+    (0x1015a, 134456),
+    (0x1017a, 134750),
+    (0x1019b, 134963),
+    (0x101ed, 135251),
+    (0x1028f, 135513),
+    (0x102b2, 135729),
+    (0x102e1, 135953),
+    (0x10305, 136210),
+    (0x1032e, 136432),
+    (0x10353, 136665),
+    (0x10373, 136916),
+    (0x10398, 137153),
+    (0x103b9, 137395),
+    (0x10407, 137665),
+    (0x10427, 137928),
+    (0x10447, 138200),
+    (0x10467, 138436),
+    (0x10487, 138659),
+    (0x104a9, 138873),
+    (0x104cf, 139121),
+    (0x104f3, 139371),
+    (0x10517, 139599),
+    (0x1053f, 139852),
+    (0x1055f, 140140),
+    (0x1058c, 140397),
+    (0x105af, 140647),
+    (0x10614, 140882),
+    (0x10634, 141106),
+    (0x10654, 141333),
+    (0x10674, 141557),
+    (0x10694, 141781),
+    (0x106b4, 142062),
+    (0x106d4, 142286),
+    (0x106f4, 142510),
+    (0x10714, 142734),
+    (0x10734, 142958),
+    (0x10767, 143233),
+    (0x107a0, 143534),
+    (0x10808, 143826),
+    (0x10829, 144048),
+    (0x1084f, 144301),
+    (0x10870, 144559),
+    (0x10890, 144789),
+    (0x108e8, 145017),
+    (0x1090e, 145240),
+    (0x10931, 145454),
+    (0x10996, 145730),
+    (0x109b6, 146021),
+    (0x109dc, 146323),
+    (0x109fc, 146685),
+    (0x10a24, 146932),
+    (0x10a51, 147154),
+    (0x10a78, 147460),
+    (0x10a98, 147788),
+    (0x10ad8, 148034),
+    (0x10b05, 148271),
+    (0x10b25, 148491),
+    (0x10b48, 148764),
+    (0x10b6a, 149056),
+    (0x10b8f, 149354),
+    (0x10c12, 149656),
+    (0x10c32, 149976),
+    (0x10c89, 150285),
+    (0x10ca9, 150577),
+    (0x10cd6, 150869),
+    (0x10cfd, 151164),
+    (0x10d1d, 151452),
+    (0x10e6b, 151706),
+    (0x10e8c, 151950),
+    (0x10ead, 152179),
+    (0x10f1a, 152468),
+    (0x10f42, 152715),
+    (0x10f78, 152976),
+    (0x10fbe, 153228),
+    (0x10ff2, 153449),
+    (0x1101b, 153676),
+    (0x1103b, 153912),
+    (0x1105f, 154149),
+    (0x11088, 154391),
+    (0x110a8, 154611),
+    (0x110d4, 154849),
+    (0x11101, 155130),
+    (0x11121, 155351),
+    (0x11142, 155571),
+    (0x1116a, 155790),
+    (0x11193, 156014),
+    (0x111b3, 156237),
+    (0x111d3, 156478),
+    (0x111f4, 156737),
+    (0x11220, 156957),
+    (0x11240, 157186),
+    (0x112a2, 157407),
+    (0x112c8, 157625),
+    (0x112e8, 157852),
+    (0x11318, 158072),
+    (0x1133c, 158294),
+    (0x11372, 158547),
+    (0x1141d, 158774),
+    (0x1143d, 159007),
+    (0x1145e, 159229),
+    (0x1149c, 159454),
+    (0x114bc, 159693),
+    (0x1158a, 159917),
+    (0x115aa, 160137),
+    (0x115cc, 160401),
+    (0x1160e, 160720),
+    (0x1162e, 160942),
+    (0x11659, 161175),
+    (0x11692, 161425),
+    (0x116b2, 161651),
+    (0x1170e, 161874),
+    (0x11734, 162118),
+    (0x1180d, 162336),
+    (0x1182d, 162560),
+    (0x118b1, 162841),
+    (0x118d1, 163158),
+    (0x118f1, 163456),
+    (0x11923, 163736),
+    (0x11946, 164032),
+    (0x119b7, 164273),
+    (0x119d7, 164501),
+    (0x11a14, 164756),
+    (0x11a34, 165013),
+    (0x11a5c, 165307),
+    (0x11a7c, 165530),
+    (0x11a9c, 165801),
+    (0x11ac9, 166100),
+    (0x11ae9, 166463),
+    (0x11c06, 166818),
+    (0x11c27, 167042),
+    (0x11c52, 167285),
+    (0x11c75, 167501),
+    (0x11c97, 167733),
+    (0x11d01, 168005),
+    (0x11d23, 168287),
+    (0x11d50, 168582),
+    (0x11d78, 168861),
+    (0x11da1, 169153),
+    (0x11ef7, 169387),
+    (0x11f1f, 169612),
+    (0x11f42, 169845),
+    (0x11fc7, 170117),
+    (0x11fe7, 170389),
+    (0x12014, 170658),
+    (0x12034, 170934),
+    (0x12054, 171217),
+    (0x12074, 171541),
+    (0x12094, 171791),
+    (0x120b4, 172077),
+    (0x120d4, 172408),
+    (0x120f4, 172733),
+    (0x12114, 172984),
+    (0x12134, 173246),
+    (0x12154, 173510),
+    (0x12174, 173773),
+    (0x12194, 174032),
+    (0x121b4, 174297),
+    (0x121d4, 174605),
+    (0x121f4, 174925),
+    (0x12214, 175218),
+    (0x12234, 175450),
+    (0x12254, 175715),
+    (0x12274, 176053),
+    (0x12294, 176315),
+    (0x122b4, 176584),
+    (0x122d4, 176858),
+    (0x122f4, 177107),
+    (0x12314, 177385),
+    (0x12334, 177669),
+    (0x12354, 177939),
+    (0x12374, 178174),
+    (0x12394, 178443),
+    (0x1241a, 178729),
+    (0x1243a, 179044),
+    (0x1245a, 179410),
+    (0x12486, 179758),
+    (0x124a6, 180070),
+    (0x124c6, 180405),
+    (0x124e6, 180731),
+    (0x12506, 181062),
+    (0x12526, 181424),
+    (0x12f92, 181741),
+    (0x12fb2, 182061),
+    (0x12fd2, 182381),
+    (0x12ff2, 182701),
+    (0x1301f, 182897),
+    (0x1303f, 183089),
+    (0x1305f, 183281),
+    (0x1307f, 183473),
+    (0x1309f, 183665),
+    (0x130bf, 183857),
+    (0x130df, 184049),
+    (0x130ff, 184241),
+    (0x1311f, 184433),
+    (0x1313f, 184625),
+    (0x1315f, 184817),
+    (0x1317f, 185009),
+    (0x1319f, 185201),
+    (0x131bf, 185393),
+    (0x131df, 185585),
+    (0x131ff, 185777),
+    (0x1321f, 185969),
+    (0x1323f, 186161),
+    (0x1325f, 186353),
+    (0x1327f, 186545),
+    (0x1329f, 186737),
+    (0x132bf, 186929),
+    (0x132df, 187121),
+    (0x132ff, 187313),
+    (0x1331f, 187505),
+    (0x1333f, 187697),
+    (0x1335f, 187889),
+    (0x1337f, 188081),
+    (0x1339f, 188273),
+    (0x133bf, 188465),
+    (0x133df, 188657),
+    (0x133ff, 188849),
+    (0x1341f, 189041),
+    (0x1343f, 189284),
+    (0x14409, 189600),
+    (0x14429, 189792),
+    (0x14449, 189984),
+    (0x14469, 190176),
+    (0x14489, 190368),
+    (0x144a9, 190560),
+    (0x144c9, 190752),
+    (0x144e9, 190944),
+    (0x14509, 191136),
+    (0x14529, 191328),
+    (0x14549, 191520),
+    (0x14569, 191712),
+    (0x14589, 191904),
+    (0x145a9, 192096),
+    (0x145c9, 192296),
+    (0x145e9, 192498),
+    (0x14609, 192690),
+    (0x14629, 192882),
+    (0x16802, 193085),
+    (0x16822, 193413),
+    (0x16842, 193714),
+    (0x16862, 194010),
+    (0x16882, 194324),
+    (0x168a2, 194631),
+    (0x168c2, 194927),
+    (0x168e2, 195221),
+    (0x16902, 195515),
+    (0x16922, 195805),
+    (0x16942, 196093),
+    (0x16962, 196384),
+    (0x16982, 196674),
+    (0x169a2, 196968),
+    (0x169c2, 197260),
+    (0x169e2, 197554),
+    (0x16a02, 197850),
+    (0x16a22, 198167),
+    (0x16a49, 198459),
+    (0x16a6e, 198675),
+    (0x16a8e, 198898),
+    (0x16aae, 199127),
+    (0x16ad5, 199355),
+    (0x16b01, 199647),
+    (0x16b21, 199908),
+    (0x16b41, 200215),
+    (0x16b6d, 200491),
+    (0x16e42, 200815),
+    (0x16e62, 201060),
+    (0x16e82, 201303),
+    (0x16f07, 201531),
+    (0x16f27, 201761),
+    (0x16f47, 201992),
+    (0x16f6b, 202241),
+    (0x16f92, 202496),
+    (0x1880b, 202768),
+    (0x1882b, 202992),
+    (0x1884b, 203216),
+    (0x1886b, 203440),
+    (0x1888b, 203664),
+    (0x188ab, 203888),
+    (0x188cb, 204112),
+    (0x188eb, 204336),
+    (0x1890b, 204560),
+    (0x1892b, 204784),
+    (0x1894b, 205008),
+    (0x1896b, 205232),
+    (0x1898b, 205456),
+    (0x189ab, 205680),
+    (0x189cb, 205904),
+    (0x189eb, 206128),
+    (0x18a0b, 206352),
+    (0x18a2b, 206576),
+    (0x18a4b, 206800),
+    (0x18a6b, 207024),
+    (0x18a8b, 207248),
+    (0x18aab, 207472),
+    (0x18acb, 207696),
+    (0x18aeb, 207920),
+    (0x1affd, 208188),
+    (0x1b01e, 208452),
+    (0x1b03e, 208735),
+    (0x1b05e, 209023),
+    (0x1b07e, 209303),
+    (0x1b09e, 209591),
+    (0x1b0be, 209879),
+    (0x1b0de, 210167),
+    (0x1b0fe, 210455),
+    (0x1b11e, 210749),
+    (0x1bc12, 210992),
+    (0x1bc32, 211263),
+    (0x1bc52, 211510),
+    (0x1bc77, 211782),
+    (0x1bca3, 212102),
+    (0x1cf1f, 212471),
+    (0x1cf41, 212725),
+    (0x1cf6a, 213012),
+    (0x1cf8a, 213299),
+    (0x1cfaa, 213620),
+    (0x1d006, 213930),
+    (0x1d026, 214182),
+    (0x1d046, 214436),
+    (0x1d066, 214700),
+    (0x1d086, 214960),
+    (0x1d0a6, 215272),
+    (0x1d0c6, 215627),
+    (0x1d0e6, 215972),
+    (0x1d110, 216254),
+    (0x1d132, 216533),
+    (0x1d152, 216814),
+    (0x1d172, 217113),
+    (0x1d192, 217351),
+    (0x1d1b2, 217621),
+    (0x1d1d2, 217931),
+    (0x1d207, 218218),
+    (0x1d227, 218570),
+    (0x1d2c1, 218902),
+    (0x1d2ed, 219148),
+    (0x1d319, 219397),
+    (0x1d339, 219632),
+    (0x1d362, 219870),
+    (0x1d409, 220148),
+    (0x1d429, 220359),
+    (0x1d449, 220569),
+    (0x1d46a, 220782),
+    (0x1d48a, 221024),
+    (0x1d4b2, 221268),
+    (0x1d4d5, 221515),
+    (0x1d4f5, 221791),
+    (0x1d519, 222049),
+    (0x1d53b, 222296),
+    (0x1d561, 222601),
+    (0x1d581, 222886),
+    (0x1d5a1, 223162),
+    (0x1d5c1, 223435),
+    (0x1d5e1, 223723),
+    (0x1d601, 224029),
+    (0x1d621, 224337),
+    (0x1d641, 224646),
+    (0x1d661, 224986),
+    (0x1d681, 225274),
+    (0x1d6a1, 225516),
+    (0x1d6c3, 225746),
+    (0x1d6e3, 225972),
+    (0x1d703, 226196),
+    (0x1d723, 226429),
+    (0x1d743, 226685),
+    (0x1d763, 226969),
+    (0x1d783, 227290),
+    (0x1d7a3, 227631),
+    (0x1d7c3, 227983),
+    (0x1d7e5, 228260),
+    (0x1d805, 228529),
+    (0x1d825, 228908),
+    (0x1d845, 229378),
+    (0x1d865, 229804),
+    (0x1d885, 230132),
+    (0x1d8a5, 230499),
+    (0x1d8c5, 230874),
+    (0x1d8e5, 231285),
+    (0x1d905, 231683),
+    (0x1d925, 231934),
+    (0x1d945, 232301),
+    (0x1d965, 232692),
+    (0x1d985, 233065),
+    (0x1d9a5, 233468),
+    (0x1d9c5, 233934),
+    (0x1d9e5, 234352),
+    (0x1da05, 234731),
+    (0x1da25, 235020),
+    (0x1da45, 235290),
+    (0x1da65, 235591),
+    (0x1da85, 235884),
+    (0x1df05, 236160),
+    (0x1e000, 236552),
+    (0x1e024, 236808),
+    (0x1e04a, 237068),
+    (0x1e06a, 237358),
+    (0x1e11b, 237682),
+    (0x1e140, 238008),
+    (0x1e2a4, 238263),
+    (0x1e2d5, 238488),
+    (0x1e2f5, 238704),
+    (0x1e4ea, 238975),
+    (0x1e7f3, 239223),
+    (0x1e814, 239498),
+    (0x1e834, 239781),
+    (0x1e854, 240071),
+    (0x1e874, 240359),
+    (0x1e894, 240652),
+    (0x1e8b4, 240946),
+    (0x1e8d6, 241209),
+    (0x1e91f, 241461),
+    (0x1e93f, 241713),
+    (0x1ec78, 241950),
+    (0x1ec98, 242270),
+    (0x1ed04, 242592),
+    (0x1ed24, 242902),
+    (0x1ee07, 243223),
+    (0x1ee2c, 243427),
+    (0x1ee68, 243672),
+    (0x1ee8e, 243928),
+    (0x1eeb5, 244218),
+    (0x1f017, 244548),
+    (0x1f03b, 244894),
+    (0x1f05b, 245342),
+    (0x1f07b, 245761),
+    (0x1f0a7, 246156),
+    (0x1f0ca, 246499),
+    (0x1f0eb, 246840),
+    (0x1f115, 247124),
+    (0x1f135, 247385),
+    (0x1f155, 247622),
+    (0x1f175, 247913),
+    (0x1f195, 248188),
+    (0x1f1ed, 248411),
+    (0x1f21a, 248719),
+    (0x1f23a, 249071),
+    (0x1f30d, 249377),
+    (0x1f32d, 249625),
+    (0x1f34d, 249784),
+    (0x1f36d, 249961),
+    (0x1f38d, 250155),
+    (0x1f3ad, 250359),
+    (0x1f3cd, 250537),
+    (0x1f3ed, 250741),
+    (0x1f40d, 250936),
+    (0x1f42d, 251085),
+    (0x1f44d, 251260),
+    (0x1f46d, 251438),
+    (0x1f48d, 251623),
+    (0x1f4ad, 251808),
+    (0x1f4cd, 252014),
+    (0x1f4ed, 252214),
+    (0x1f50d, 252455),
+    (0x1f52d, 252680),
+    (0x1f54d, 252916),
+    (0x1f56d, 253194),
+    (0x1f58d, 253411),
+    (0x1f5ad, 253687),
+    (0x1f5cd, 253893),
+    (0x1f5ed, 254094),
+    (0x1f60d, 254360),
+    (0x1f62d, 254591),
+    (0x1f64d, 254876),
+    (0x1f66d, 255221),
+    (0x1f68d, 255468),
+    (0x1f6ad, 255650),
+    (0x1f6cd, 255837),
+    (0x1f6f4, 256023),
+    (0x1f717, 256288),
+    (0x1f737, 256638),
+    (0x1f757, 256925),
+    (0x1f77b, 257215),
+    (0x1f79b, 257443),
+    (0x1f7bb, 257702),
+    (0x1f7e1, 258004),
+    (0x1f818, 258294),
+    (0x1f838, 258698),
+    (0x1f866, 258976),
+    (0x1f886, 259434),
+    (0x1f8b0, 259765),
+    (0x1f91e, 260007),
+    (0x1f93e, 260248),
+    (0x1f95e, 260430),
+    (0x1f97e, 260623),
+    (0x1f99e, 260763),
+    (0x1f9be, 260923),
+    (0x1f9de, 261095),
+    (0x1f9fe, 261262),
+    (0x1fa1e, 261661),
+    (0x1fa3e, 262060),
+    (0x1fa6a, 262426),
+    (0x1fa96, 262601),
+    (0x1fab6, 262751),
+    (0x1fae3, 262915),
+    (0x1fb11, 263152),
+    (0x1fb31, 263408),
+    (0x1fb51, 263799),
+    (0x1fb71, 264256),
+    (0x1fb91, 264549),
+    (0x1fbb2, 265015),
+    (0x1fbf7, 265292),
+    (0xe003c, 265505),
+    (0xe005c, 265778),
+    (0xe007c, 266050),
+    (0xe011c, 266275),
+    (0xe013c, 266499),
+    (0xe015c, 266723),
+    (0xe017c, 266947),
+    (0xe019c, 267171),
+    (0xe01bc, 267395),
+    (0xe01dc, 267619),
+];
+
+/// Ranges named `<prefix>-<hex code point>`, ordered by code point.
+pub(super) static NAME_RANGES: &[(u32, u32, &str)] = &[
+    (0x3400, 0x4dbf, "CJK UNIFIED IDEOGRAPH"),
+    (0x4e00, 0x9fff, "CJK UNIFIED IDEOGRAPH"),
+    (0xf900, 0xfa6d, "CJK COMPATIBILITY IDEOGRAPH"),
+    (0xfa70, 0xfad9, "CJK COMPATIBILITY IDEOGRAPH"),
+    (0x18b00, 0x18cd5, "KHITAN SMALL SCRIPT CHARACTER"),
+    (0x1b170, 0x1b2fb, "NUSHU CHARACTER"),
+    (0x20000, 0x2a6df, "CJK UNIFIED IDEOGRAPH"),
+    (0x2a700, 0x2b739, "CJK UNIFIED IDEOGRAPH"),
+    (0x2b740, 0x2b81d, "CJK UNIFIED IDEOGRAPH"),
+    (0x2b820, 0x2cea1, "CJK UNIFIED IDEOGRAPH"),
+    (0x2ceb0, 0x2ebe0, "CJK UNIFIED IDEOGRAPH"),
+    (0x2ebf0, 0x2ee5d, "CJK UNIFIED IDEOGRAPH"),
+    (0x2f800, 0x2fa1d, "CJK COMPATIBILITY IDEOGRAPH"),
+    (0x30000, 0x3134a, "CJK UNIFIED IDEOGRAPH"),
+    (0x31350, 0x323af, "CJK UNIFIED IDEOGRAPH"),
+];
+
+/// Ranges `unicodedata.lookup()` accepts as `<prefix>-<hex code point>` although
+/// `unicodedata.name()` doesn't name them, ordered by code point.
+pub(super) static LOOKUP_RANGES: &[(u32, u32, &str)] = &[
+    (0x17000, 0x187f7, "TANGUT IDEOGRAPH"),
+    (0x18d00, 0x18d08, "TANGUT IDEOGRAPH"),
+];
+
+/// Name aliases accepted by `unicodedata.lookup()`, ordered by name.
+pub(super) static NAME_ALIASES: &[(&str, u32)] = &[
+    ("ACK", 0x6),
+    ("ACKNOWLEDGE", 0x6),
+    ("ALERT", 0x7),
+    ("ALM", 0x61c),
+    ("APC", 0x9f),
+    ("APPLICATION PROGRAM COMMAND", 0x9f),
+    ("BACKSPACE", 0x8),
+    ("BEL", 0x7),
+    ("BOM", 0xfeff),
+    ("BPH", 0x82),
+    ("BREAK PERMITTED HERE", 0x82),
+    ("BS", 0x8),
+    ("BYTE ORDER MARK", 0xfeff),
+    ("BYZANTINE MUSICAL SYMBOL FTHORA SKLIRON CHROMA VASIS", 0x1d0c5),
+    ("CAN", 0x18),
+    ("CANCEL", 0x18),
+    ("CANCEL CHARACTER", 0x94),
+    ("CARRIAGE RETURN", 0xd),
+    ("CCH", 0x94),
+    ("CGJ", 0x34f),
+    ("CHARACTER TABULATION", 0x9),
+    ("CHARACTER TABULATION SET", 0x88),
+    ("CHARACTER TABULATION WITH JUSTIFICATION", 0x89),
+    ("CONTROL SEQUENCE INTRODUCER", 0x9b),
+    ("CR", 0xd),
+    ("CSI", 0x9b),
+    ("CUNEIFORM SIGN NU11 OVER NU11 BUR OVER BUR", 0x122d5),
+    ("CUNEIFORM SIGN NU11 TENU", 0x122d4),
+    ("DATA LINK ESCAPE", 0x10),
+    ("DC1", 0x11),
+    ("DC2", 0x12),
+    ("DC3", 0x13),
+    ("DC4", 0x14),
+    ("DCS", 0x90),
+    ("DEL", 0x7f),
+    ("DELETE", 0x7f),
+    ("DEVICE CONTROL FOUR", 0x14),
+    ("DEVICE CONTROL ONE", 0x11),
+    ("DEVICE CONTROL STRING", 0x90),
+    ("DEVICE CONTROL THREE", 0x13),
+    ("DEVICE CONTROL TWO", 0x12),
+    ("DLE", 0x10),
+    ("END OF GUARDED AREA", 0x97),
+    ("END OF LINE", 0xa),
+    ("END OF MEDIUM", 0x19),
+    ("END OF PROTECTED AREA", 0x97),
+    ("END OF SELECTED AREA", 0x87),
+    ("END OF TEXT", 0x3),
+    ("END OF TRANSMISSION", 0x4),
+    ("END OF TRANSMISSION BLOCK", 0x17),
+    ("ENQ", 0x5),
+    ("ENQUIRY", 0x5),
+    ("EOL", 0xa),
+    ("EOM", 0x19),
+    ("EOT", 0x4),
+    ("EPA", 0x97),
+    ("ESA", 0x87),
+    ("ESC", 0x1b),
+    ("ESCAPE", 0x1b),
+    ("ETB", 0x17),
+    ("ETX", 0x3),
+    ("FF", 0xc),
+    ("FILE SEPARATOR", 0x1c),
+    ("FORM FEED", 0xc),
+    ("FS", 0x1c),
+    ("FSI", 0x2068),
+    ("FVS1", 0x180b),
+    ("FVS2", 0x180c),
+    ("FVS3", 0x180d),
+    ("FVS4", 0x180f),
+    ("GROUP SEPARATOR", 0x1d),
+    ("GS", 0x1d),
+    ("HANGUL JONGSEONG SSANGYESIEUNG", 0x11ee),
+    ("HANGUL JONGSEONG YESIEUNG-KHIEUKH", 0x11ef),
+    ("HANGUL JONGSEONG YESIEUNG-KIYEOK", 0x11ec),
+    ("HANGUL JONGSEONG YESIEUNG-SSANGKIYEOK", 0x11ed),
+    ("HENTAIGANA LETTER E-1", 0x1b001),
+    ("HIGH OCTET PRESET", 0x81),
+    ("HOP", 0x81),
+    ("HORIZONTAL TABULATION", 0x9),
+    ("HORIZONTAL TABULATION SET", 0x88),
+    ("HORIZONTAL TABULATION WITH JUSTIFICATION", 0x89),
+    ("HT", 0x9),
+    ("HTJ", 0x89),
+    ("HTS", 0x88),
+    ("IND", 0x84),
+    ("INDEX", 0x84),
+    ("INFORMATION SEPARATOR FOUR", 0x1c),
+    ("INFORMATION SEPARATOR ONE", 0x1f),
+    ("INFORMATION SEPARATOR THREE", 0x1d),
+    ("INFORMATION SEPARATOR TWO", 0x1e),
+    ("KANNADA LETTER LLLA", 0xcde),
+    ("LAO LETTER FO FAY", 0xe9f),
+    ("LAO LETTER FO FON", 0xe9d),
+    ("LAO LETTER LO", 0xea5),
+    ("LAO LETTER RO", 0xea3),
+    ("LATIN CAPITAL LETTER GHA", 0x1a2),
+    ("LATIN SMALL LETTER GHA", 0x1a3),
+    ("LEFTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", 0x2b7a),
+    ("LF", 0xa),
+    ("LINE FEED", 0xa),
+    ("LINE TABULATION", 0xb),
+    ("LINE TABULATION SET", 0x8a),
+    ("LOCKING-SHIFT ONE", 0xe),
+    ("LOCKING-SHIFT ZERO", 0xf),
+    ("LRE", 0x202a),
+    ("LRI", 0x2066),
+    ("LRM", 0x200e),
+    ("LRO", 0x202d),
+    ("MEDEFAIDRIN CAPITAL LETTER H", 0x16e56),
+    ("MEDEFAIDRIN CAPITAL LETTER NG", 0x16e57),
+    ("MEDEFAIDRIN SMALL LETTER H", 0x16e76),
+    ("MEDEFAIDRIN SMALL LETTER NG", 0x16e77),
+    ("MESSAGE WAITING", 0x95),
+    ("MICR DASH SYMBOL", 0x2449),
+    ("MICR ON US SYMBOL", 0x2448),
+    ("MMSP", 0x205f),
+    ("MVS", 0x180e),
+    ("MW", 0x95),
+    ("MYANMAR LETTER KHAMTI LLA", 0xaa6e),
+    ("NAK", 0x15),
+    ("NBH", 0x83),
+    ("NBSP", 0xa0),
+    ("NEGATIVE ACKNOWLEDGE", 0x15),
+    ("NEL", 0x85),
+    ("NEW LINE", 0xa),
+    ("NEXT LINE", 0x85),
+    ("NL", 0xa),
+    ("NNBSP", 0x202f),
+    ("NO BREAK HERE", 0x83),
+    ("NUL", 0x0),
+    ("NULL", 0x0),
+    ("OPERATING SYSTEM COMMAND", 0x9d),
+    ("OSC", 0x9d),
+    ("PAD", 0x80),
+    ("PADDING CHARACTER", 0x80),
+    ("PARTIAL LINE BACKWARD", 0x8c),
+    ("PARTIAL LINE DOWN", 0x8b),
+    ("PARTIAL LINE FORWARD", 0x8b),
+    ("PARTIAL LINE UP", 0x8c),
+    ("PDF", 0x202c),
+    ("PDI", 0x2069),
+    ("PLD", 0x8b),
+    ("PLU", 0x8c),
+    ("PM", 0x9e),
+    ("PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET", 0xfe18),
+    ("PRIVACY MESSAGE", 0x9e),
+    ("PRIVATE USE ONE", 0x91),
+    ("PRIVATE USE TWO", 0x92),
+    ("PRIVATE USE-1", 0x91),
+    ("PRIVATE USE-2", 0x92),
+    ("PU1", 0x91),
+    ("PU2", 0x92),
+    ("RECORD SEPARATOR", 0x1e),
+    ("REVERSE INDEX", 0x8d),
+    ("REVERSE LINE FEED", 0x8d),
+    ("RI", 0x8d),
+    ("RIGHTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", 0x2b7c),
+    ("RLE", 0x202b),
+    ("RLI", 0x2067),
+    ("RLM", 0x200f),
+    ("RLO", 0x202e),
+    ("RS", 0x1e),
+    ("SCI", 0x9a),
+    ("SET TRANSMIT STATE", 0x93),
+    ("SGC", 0x99),
+    ("SHIFT IN", 0xf),
+    ("SHIFT OUT", 0xe),
+    ("SHY", 0xad),
+    ("SI", 0xf),
+    ("SINGLE CHARACTER INTRODUCER", 0x9a),
+    ("SINGLE GRAPHIC CHARACTER INTRODUCER", 0x99),
+    ("SINGLE SHIFT THREE", 0x8f),
+    ("SINGLE SHIFT TWO", 0x8e),
+    ("SINGLE-SHIFT-2", 0x8e),
+    ("SINGLE-SHIFT-3", 0x8f),
+    ("SO", 0xe),
+    ("SOH", 0x1),
+    ("SOS", 0x98),
+    ("SP", 0x20),
+    ("SPA", 0x96),
+    ("SS2", 0x8e),
+    ("SS3", 0x8f),
+    ("SSA", 0x86),
+    ("ST", 0x9c),
+    ("START OF GUARDED AREA", 0x96),
+    ("START OF HEADING", 0x1),
+    ("START OF PROTECTED AREA", 0x96),
+    ("START OF SELECTED AREA", 0x86),
+    ("START OF STRING", 0x98),
+    ("START OF TEXT", 0x2),
+    ("STRING TERMINATOR", 0x9c),
+    ("STS", 0x93),
+    ("STX", 0x2),
+    ("SUB", 0x1a),
+    ("SUBSTITUTE", 0x1a),
+    ("SYN", 0x16),
+    ("SYNCHRONOUS IDLE", 0x16),
+    ("SYRIAC SUBLINEAR COLON SKEWED LEFT", 0x709),
+    ("TAB", 0x9),
+    ("TIBETAN MARK BKA- SHOG GI MGO RGYAN", 0xfd0),
+    ("UNIT SEPARATOR", 0x1f),
+    ("US", 0x1f),
+    ("VERTICAL TABULATION", 0xb),
+    ("VERTICAL TABULATION SET", 0x8a),
+    ("VS1", 0xfe00),
+    ("VS10", 0xfe09),
+    ("VS100", 0xe0153),
+    ("VS101", 0xe0154),
+    ("VS102", 0xe0155),
+    ("VS103", 0xe0156),
+    ("VS104", 0xe0157),
+    ("VS105", 0xe0158),
+    ("VS106", 0xe0159),
+    ("VS107", 0xe015a),
+    ("VS108", 0xe015b),
+    ("VS109", 0xe015c),
+    ("VS11", 0xfe0a),
+    ("VS110", 0xe015d),
+    ("VS111", 0xe015e),
+    ("VS112", 0xe015f),
+    ("VS113", 0xe0160),
+    ("VS114", 0xe0161),
+    ("VS115", 0xe0162),
+    ("VS116", 0xe0163),
+    ("VS117", 0xe0164),
+    ("VS118", 0xe0165),
+    ("VS119", 0xe0166),
+    ("VS12", 0xfe0b),
+    ("VS120", 0xe0167),
+    ("VS121", 0xe0168),
+    ("VS122", 0xe0169),
+    ("VS123", 0xe016a),
+    ("VS124", 0xe016b),
+    ("VS125", 0xe016c),
+    ("VS126", 0xe016d),
+    ("VS127", 0xe016e),
+    ("VS128", 0xe016f),
+    ("VS129", 0xe0170),
+    ("VS13", 0xfe0c),
+    ("VS130", 0xe0171),
+    ("VS131", 0xe0172),
+    ("VS132", 0xe0173),
+    ("VS133", 0xe0174),
+    ("VS134", 0xe0175),
+    ("VS135", 0xe0176),
+    ("VS136", 0xe0177),
+    ("VS137", 0xe0178),
+    ("VS138", 0xe0179),
+    ("VS139", 0xe017a),
+    ("VS14", 0xfe0d),
+    ("VS140", 0xe017b),
+    ("VS141", 0xe017c),
+    ("VS142", 0xe017d),
+    ("VS143", 0xe017e),
+    ("VS144", 0xe017f),
+    ("VS145", 0xe0180),
+    ("VS146", 0xe0181),
+    ("VS147", 0xe0182),
+    ("VS148", 0xe0183),
+    ("VS149", 0xe0184),
+    ("VS15", 0xfe0e),
+    ("VS150", 0xe0185),
+    ("VS151", 0xe0186),
+    ("VS152", 0xe0187),
+    ("VS153", 0xe0188),
+    ("VS154", 0xe0189),
+    ("VS155", 0xe018a),
+    ("VS156", 0xe018b),
+    ("VS157", 0xe018c),
+    ("VS158", 0xe018d),
+    ("VS159", 0xe018e),
+    ("VS16", 0xfe0f),
+    ("VS160", 0xe018f),
+    ("VS161", 0xe0190),
+    ("VS162", 0xe0191),
+    ("VS163", 0xe0192),
+    ("VS164", 0xe0193),
+    ("VS165", 0xe0194),
+    ("VS166", 0xe0195),
+    ("VS167", 0xe0196),
+    ("VS168", 0xe0197),
+    ("VS169", 0xe0198),
+    ("VS17", 0xe0100),
+    ("VS170", 0xe0199),
+    ("VS171", 0xe019a),
+    ("VS172", 0xe019b),
+    ("VS173", 0xe019c),
+    ("VS174", 0xe019d),
+    ("VS175", 0xe019e),
+    ("VS176", 0xe019f),
+    ("VS177", 0xe01a0),
+    ("VS178", 0xe01a1),
+    ("VS179", 0xe01a2),
+    ("VS18", 0xe0101),
+    ("VS180", 0xe01a3),
+    ("VS181", 0xe01a4),
+    ("VS182", 0xe01a5),
+    ("VS183", 0xe01a6),
+    ("VS184", 0xe01a7),
+    ("VS185", 0xe01a8),
+    ("VS186", 0xe01a9),
+    ("VS187", 0xe01aa),
+    ("VS188", 0xe01ab),
+    ("VS189", 0xe01ac),
+    ("VS19", 0xe0102),
+    ("VS190", 0xe01ad),
+    ("VS191", 0xe01ae),
+    ("VS192", 0xe01af),
+    ("VS193", 0xe01b0),
+    ("VS194", 0xe01b1),
+    ("VS195", 0xe01b2),
+    ("VS196", 0xe01b3),
+    ("VS197", 0xe01b4),
+    ("VS198", 0xe01b5),
+    ("VS199", 0xe01b6),
+    ("VS2", 0xfe01),
+    ("VS20", 0xe0103),
+    ("VS200", 0xe01b7),
+    ("VS201", 0xe01b8),
+    ("VS202", 0xe01b9),
+    ("VS203", 0xe01ba),
+    ("VS204", 0xe01bb),
+    ("VS205", 0xe01bc),
+    ("VS206", 0xe01bd),
+    ("VS207", 0xe01be),
+    ("VS208", 0xe01bf),
+    ("VS209", 0xe01c0),
+    ("VS21", 0xe0104),
+    ("VS210", 0xe01c1),
+    ("VS211", 0xe01c2),
+    ("VS212", 0xe01c3),
+    ("VS213", 0xe01c4),
+    ("VS214", 0xe01c5),
+    ("VS215", 0xe01c6),
+    ("VS216", 0xe01c7),
+    ("VS217", 0xe01c8),
+    ("VS218", 0xe01c9),
+    ("VS219", 0xe01ca),
+    ("VS22", 0xe0105),
+    ("VS220", 0xe01cb),
+    ("VS221", 0xe01cc),
+    ("VS222", 0xe01cd),
+    ("VS223", 0xe01ce),
+    ("VS224", 0xe01cf),
+    ("VS225", 0xe01d0),
+    ("VS226", 0xe01d1),
+    ("VS227", 0xe01d2),
+    ("VS228", 0xe01d3),
+    ("VS229", 0xe01d4),
+    ("VS23", 0xe0106),
+    ("VS230", 0xe01d5),
+    ("VS231", 0xe01d6),
+    ("VS232", 0xe01d7),
+    ("VS233", 0xe01d8),
+    ("VS234", 0xe01d9),
+    ("VS235", 0xe01da),
+    ("VS236", 0xe01db),
+    ("VS237", 0xe01dc),
+    ("VS238", 0xe01dd),
+    ("VS239", 0xe01de),
+    ("VS24", 0xe0107),
+    ("VS240", 0xe01df),
+    ("VS241", 0xe01e0),
+    ("VS242", 0xe01e1),
+    ("VS243", 0xe01e2),
+    ("VS244", 0xe01e3),
+    ("VS245", 0xe01e4),
+    ("VS246", 0xe01e5),
+    ("VS247", 0xe01e6),
+    ("VS248", 0xe01e7),
+    ("VS249", 0xe01e8),
+    ("VS25", 0xe0108),
+    ("VS250", 0xe01e9),
+    ("VS251", 0xe01ea),
+    ("VS252", 0xe01eb),
+    ("VS253", 0xe01ec),
+    ("VS254", 0xe01ed),
+    ("VS255", 0xe01ee),
+    ("VS256", 0xe01ef),
+    ("VS26", 0xe0109),
+    ("VS27", 0xe010a),
+    ("VS28", 0xe010b),
+    ("VS29", 0xe010c),
+    ("VS3", 0xfe02),
+    ("VS30", 0xe010d),
+    ("VS31", 0xe010e),
+    ("VS32", 0xe010f),
+    ("VS33", 0xe0110),
+    ("VS34", 0xe0111),
+    ("VS35", 0xe0112),
+    ("VS36", 0xe0113),
+    ("VS37", 0xe0114),
+    ("VS38", 0xe0115),
+    ("VS39", 0xe0116),
+    ("VS4", 0xfe03),
+    ("VS40", 0xe0117),
+    ("VS41", 0xe0118),
+    ("VS42", 0xe0119),
+    ("VS43", 0xe011a),
+    ("VS44", 0xe011b),
+    ("VS45", 0xe011c),
+    ("VS46", 0xe011d),
+    ("VS47", 0xe011e),
+    ("VS48", 0xe011f),
+    ("VS49", 0xe0120),
+    ("VS5", 0xfe04),
+    ("VS50", 0xe0121),
+    ("VS51", 0xe0122),
+    ("VS52", 0xe0123),
+    ("VS53", 0xe0124),
+    ("VS54", 0xe0125),
+    ("VS55", 0xe0126),
+    ("VS56", 0xe0127),
+    ("VS57", 0xe0128),
+    ("VS58", 0xe0129),
+    ("VS59", 0xe012a),
+    ("VS6", 0xfe05),
+    ("VS60", 0xe012b),
+    ("VS61", 0xe012c),
+    ("VS62", 0xe012d),
+    ("VS63", 0xe012e),
+    ("VS64", 0xe012f),
+    ("VS65", 0xe0130),
+    ("VS66", 0xe0131),
+    ("VS67", 0xe0132),
+    ("VS68", 0xe0133),
+    ("VS69", 0xe0134),
+    ("VS7", 0xfe06),
+    ("VS70", 0xe0135),
+    ("VS71", 0xe0136),
+    ("VS72", 0xe0137),
+    ("VS73", 0xe0138),
+    ("VS74", 0xe0139),
+    ("VS75", 0xe013a),
+    ("VS76", 0xe013b),
+    ("VS77", 0xe013c),
+    ("VS78", 0xe013d),
+    ("VS79", 0xe013e),
+    ("VS8", 0xfe07),
+    ("VS80", 0xe013f),
+    ("VS81", 0xe0140),
+    ("VS82", 0xe0141),
+    ("VS83", 0xe0142),
+    ("VS84", 0xe0143),
+    ("VS85", 0xe0144),
+    ("VS86", 0xe0145),
+    ("VS87", 0xe0146),
+    ("VS88", 0xe0147),
+    ("VS89", 0xe0148),
+    ("VS9", 0xfe08),
+    ("VS90", 0xe0149),
+    ("VS91", 0xe014a),
+    ("VS92", 0xe014b),
+    ("VS93", 0xe014c),
+    ("VS94", 0xe014d),
+    ("VS95", 0xe014e),
+    ("VS96", 0xe014f),
+    ("VS97", 0xe0150),
+    ("VS98", 0xe0151),
+    ("VS99", 0xe0152),
+    ("VT", 0xb),
+    ("VTS", 0x8a),
+    ("WEIERSTRASS ELLIPTIC FUNCTION", 0x2118),
+    ("WJ", 0x2060),
+    ("YI SYLLABLE ITERATION MARK", 0xa015),
+    ("ZWJ", 0x200d),
+    ("ZWNBSP", 0xfeff),
+    ("ZWNJ", 0x200c),
+    ("ZWSP", 0x200b),
+];
+
+/// Named sequences accepted by `unicodedata.lookup()`, ordered by name.
+pub(super) static NAMED_SEQUENCES: &[(&str, &str)] = &[
+    ("ARABIC SEQUENCE NOON WITH KEHEH", "\u{646}\u{6a9}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH AE", "\u{626}\u{6d5}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH ALEF", "\u{626}\u{627}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH ALEF MAKSURA", "\u{626}\u{649}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH E", "\u{626}\u{6d0}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH OE", "\u{626}\u{6c6}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH U", "\u{626}\u{6c7}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH WAW", "\u{626}\u{648}"),
+    ("ARABIC SEQUENCE YEH WITH HAMZA ABOVE WITH YU", "\u{626}\u{6c8}"),
+    ("BENGALI LETTER KHINYA", "\u{995}\u{9cd}\u{9b7}"),
+    ("BENGALI SEQUENCE FOR LETTER RHA", "\u{9a2}\u{9bc}"),
+    ("BENGALI SEQUENCE FOR LETTER RRA", "\u{9a1}\u{9bc}"),
+    ("BENGALI SEQUENCE FOR LETTER YYA", "\u{9af}\u{9bc}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER DDDHA", "\u{921}\u{93c}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER FA", "\u{92b}\u{93c}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER GHHA", "\u{917}\u{93c}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER KHHA", "\u{916}\u{93c}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER QA", "\u{915}\u{93c}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER RHA", "\u{922}\u{93c}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER YYA", "\u{92f}\u{93c}"),
+    ("DEVANAGARI SEQUENCE FOR LETTER ZA", "\u{91c}\u{93c}"),
+    ("GEORGIAN LETTER U-BRJGU", "\u{10e3}\u{302}"),
+    ("GURMUKHI SEQUENCE FOR LETTER FA", "\u{a2b}\u{a3c}"),
+    ("GURMUKHI SEQUENCE FOR LETTER GHHA", "\u{a17}\u{a3c}"),
+    ("GURMUKHI SEQUENCE FOR LETTER KHHA", "\u{a16}\u{a3c}"),
+    ("GURMUKHI SEQUENCE FOR LETTER LLA", "\u{a32}\u{a3c}"),
+    ("GURMUKHI SEQUENCE FOR LETTER SHA", "\u{a38}\u{a3c}"),
+    ("GURMUKHI SEQUENCE FOR LETTER ZA", "\u{a1c}\u{a3c}"),
+    ("HIRAGANA LETTER BIDAKUON NGA", "\u{304b}\u{309a}"),
+    ("HIRAGANA LETTER BIDAKUON NGE", "\u{3051}\u{309a}"),
+    ("HIRAGANA LETTER BIDAKUON NGI", "\u{304d}\u{309a}"),
+    ("HIRAGANA LETTER BIDAKUON NGO", "\u{3053}\u{309a}"),
+    ("HIRAGANA LETTER BIDAKUON NGU", "\u{304f}\u{309a}"),
+    ("KATAKANA LETTER AINU CE", "\u{30bb}\u{309a}"),
+    ("KATAKANA LETTER AINU P", "\u{31f7}\u{309a}"),
+    ("KATAKANA LETTER AINU TO", "\u{30c8}\u{309a}"),
+    ("KATAKANA LETTER AINU TU", "\u{30c4}\u{309a}"),
+    ("KATAKANA LETTER BIDAKUON NGA", "\u{30ab}\u{309a}"),
+    ("KATAKANA LETTER BIDAKUON NGE", "\u{30b1}\u{309a}"),
+    ("KATAKANA LETTER BIDAKUON NGI", "\u{30ad}\u{309a}"),
+    ("KATAKANA LETTER BIDAKUON NGO", "\u{30b3}\u{309a}"),
+    ("KATAKANA LETTER BIDAKUON NGU", "\u{30af}\u{309a}"),
+    ("KEYCAP ASTERISK", "*\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT EIGHT", "8\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT FIVE", "5\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT FOUR", "4\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT NINE", "9\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT ONE", "1\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT SEVEN", "7\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT SIX", "6\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT THREE", "3\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT TWO", "2\u{fe0f}\u{20e3}"),
+    ("KEYCAP DIGIT ZERO", "0\u{fe0f}\u{20e3}"),
+    ("KEYCAP NUMBER SIGN", "#\u{fe0f}\u{20e3}"),
+    ("KHMER CONSONANT SIGN COENG BA", "\u{17d2}\u{1794}"),
+    ("KHMER CONSONANT SIGN COENG CA", "\u{17d2}\u{1785}"),
+    ("KHMER CONSONANT SIGN COENG CHA", "\u{17d2}\u{1786}"),
+    ("KHMER CONSONANT SIGN COENG CHO", "\u{17d2}\u{1788}"),
+    ("KHMER CONSONANT SIGN COENG CO", "\u{17d2}\u{1787}"),
+    ("KHMER CONSONANT SIGN COENG DA", "\u{17d2}\u{178a}"),
+    ("KHMER CONSONANT SIGN COENG DO", "\u{17d2}\u{178c}"),
+    ("KHMER CONSONANT SIGN COENG HA", "\u{17d2}\u{17a0}"),
+    ("KHMER CONSONANT SIGN COENG KA", "\u{17d2}\u{1780}"),
+    ("KHMER CONSONANT SIGN COENG KHA", "\u{17d2}\u{1781}"),
+    ("KHMER CONSONANT SIGN COENG KHO", "\u{17d2}\u{1783}"),
+    ("KHMER CONSONANT SIGN COENG KO", "\u{17d2}\u{1782}"),
+    ("KHMER CONSONANT SIGN COENG LA", "\u{17d2}\u{17a1}"),
+    ("KHMER CONSONANT SIGN COENG LO", "\u{17d2}\u{179b}"),
+    ("KHMER CONSONANT SIGN COENG MO", "\u{17d2}\u{1798}"),
+    ("KHMER CONSONANT SIGN COENG NA", "\u{17d2}\u{178e}"),
+    ("KHMER CONSONANT SIGN COENG NGO", "\u{17d2}\u{1784}"),
+    ("KHMER CONSONANT SIGN COENG NO", "\u{17d2}\u{1793}"),
+    ("KHMER CONSONANT SIGN COENG NYO", "\u{17d2}\u{1789}"),
+    ("KHMER CONSONANT SIGN COENG PHA", "\u{17d2}\u{1795}"),
+    ("KHMER CONSONANT SIGN COENG PHO", "\u{17d2}\u{1797}"),
+    ("KHMER CONSONANT SIGN COENG PO", "\u{17d2}\u{1796}"),
+    ("KHMER CONSONANT SIGN COENG RO", "\u{17d2}\u{179a}"),
+    ("KHMER CONSONANT SIGN COENG SA", "\u{17d2}\u{179f}"),
+    ("KHMER CONSONANT SIGN COENG SHA", "\u{17d2}\u{179d}"),
+    ("KHMER CONSONANT SIGN COENG SSA", "\u{17d2}\u{179e}"),
+    ("KHMER CONSONANT SIGN COENG TA", "\u{17d2}\u{178f}"),
+    ("KHMER CONSONANT SIGN COENG THA", "\u{17d2}\u{1790}"),
+    ("KHMER CONSONANT SIGN COENG THO", "\u{17d2}\u{1792}"),
+    ("KHMER CONSONANT SIGN COENG TO", "\u{17d2}\u{1791}"),
+    ("KHMER CONSONANT SIGN COENG TTHA", "\u{17d2}\u{178b}"),
+    ("KHMER CONSONANT SIGN COENG TTHO", "\u{17d2}\u{178d}"),
+    ("KHMER CONSONANT SIGN COENG VO", "\u{17d2}\u{179c}"),
+    ("KHMER CONSONANT SIGN COENG YO", "\u{17d2}\u{1799}"),
+    ("KHMER INDEPENDENT VOWEL SIGN COENG QE", "\u{17d2}\u{17af}"),
+    ("KHMER INDEPENDENT VOWEL SIGN COENG QU", "\u{17d2}\u{17a7}"),
+    ("KHMER INDEPENDENT VOWEL SIGN COENG RY", "\u{17d2}\u{17ab}"),
+    ("KHMER INDEPENDENT VOWEL SIGN COENG RYY", "\u{17d2}\u{17ac}"),
+    ("KHMER VOWEL SIGN AAM", "\u{17b6}\u{17c6}"),
+    ("KHMER VOWEL SIGN COENG QA", "\u{17d2}\u{17a2}"),
+    ("KHMER VOWEL SIGN OM", "\u{17bb}\u{17c6}"),
+    ("LATIN CAPITAL LETTER A WITH MACRON AND GRAVE", "\u{100}\u{300}"),
+    ("LATIN CAPITAL LETTER A WITH OGONEK AND ACUTE", "\u{104}\u{301}"),
+    ("LATIN CAPITAL LETTER A WITH OGONEK AND TILDE", "\u{104}\u{303}"),
+    ("LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND CARON", "\u{ca}\u{30c}"),
+    ("LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND MACRON", "\u{ca}\u{304}"),
+    ("LATIN CAPITAL LETTER E WITH DOT ABOVE AND ACUTE", "\u{116}\u{301}"),
+    ("LATIN CAPITAL LETTER E WITH DOT ABOVE AND TILDE", "\u{116}\u{303}"),
+    ("LATIN CAPITAL LETTER E WITH OGONEK AND ACUTE", "\u{118}\u{301}"),
+    ("LATIN CAPITAL LETTER E WITH OGONEK AND TILDE", "\u{118}\u{303}"),
+    ("LATIN CAPITAL LETTER E WITH VERTICAL LINE BELOW", "E\u{329}"),
+    ("LATIN CAPITAL LETTER E WITH VERTICAL LINE BELOW AND ACUTE", "\u{c9}\u{329}"),
+    ("LATIN CAPITAL LETTER E WITH VERTICAL LINE BELOW AND GRAVE", "\u{c8}\u{329}"),
+    ("LATIN CAPITAL LETTER I WITH MACRON AND GRAVE", "\u{12a}\u{300}"),
+    ("LATIN CAPITAL LETTER I WITH OGONEK AND ACUTE", "\u{12e}\u{301}"),
+    ("LATIN CAPITAL LETTER I WITH OGONEK AND TILDE", "\u{12e}\u{303}"),
+    ("LATIN CAPITAL LETTER J WITH TILDE", "J\u{303}"),
+    ("LATIN CAPITAL LETTER L WITH TILDE", "L\u{303}"),
+    ("LATIN CAPITAL LETTER M WITH TILDE", "M\u{303}"),
+    ("LATIN CAPITAL LETTER O WITH VERTICAL LINE BELOW", "O\u{329}"),
+    ("LATIN CAPITAL LETTER O WITH VERTICAL LINE BELOW AND ACUTE", "\u{d3}\u{329}"),
+    ("LATIN CAPITAL LETTER O WITH VERTICAL LINE BELOW AND GRAVE", "\u{d2}\u{329}"),
+    ("LATIN CAPITAL LETTER R WITH TILDE", "R\u{303}"),
+    ("LATIN CAPITAL LETTER S WITH VERTICAL LINE BELOW", "S\u{329}"),
+    ("LATIN CAPITAL LETTER U WITH MACRON AND ACUTE", "\u{16a}\u{301}"),
+    ("LATIN CAPITAL LETTER U WITH MACRON AND GRAVE", "\u{16a}\u{300}"),
+    ("LATIN CAPITAL LETTER U WITH MACRON AND TILDE", "\u{16a}\u{303}"),
+    ("LATIN CAPITAL LETTER U WITH OGONEK AND ACUTE", "\u{172}\u{301}"),
+    ("LATIN CAPITAL LETTER U WITH OGONEK AND TILDE", "\u{172}\u{303}"),
+    ("LATIN SMALL LETTER A WITH MACRON AND GRAVE", "\u{101}\u{300}"),
+    ("LATIN SMALL LETTER A WITH OGONEK AND ACUTE", "\u{105}\u{301}"),
+    ("LATIN SMALL LETTER A WITH OGONEK AND TILDE", "\u{105}\u{303}"),
+    ("LATIN SMALL LETTER AE WITH GRAVE", "\u{e6}\u{300}"),
+    ("LATIN SMALL LETTER E WITH CIRCUMFLEX AND CARON", "\u{ea}\u{30c}"),
+    ("LATIN SMALL LETTER E WITH CIRCUMFLEX AND MACRON", "\u{ea}\u{304}"),
+    ("LATIN SMALL LETTER E WITH DOT ABOVE AND ACUTE", "\u{117}\u{301}"),
+    ("LATIN SMALL LETTER E WITH DOT ABOVE AND TILDE", "\u{117}\u{303}"),
+    ("LATIN SMALL LETTER E WITH OGONEK AND ACUTE", "\u{119}\u{301}"),
+    ("LATIN SMALL LETTER E WITH OGONEK AND TILDE", "\u{119}\u{303}"),
+    ("LATIN SMALL LETTER E WITH VERTICAL LINE BELOW", "e\u{329}"),
+    ("LATIN SMALL LETTER E WITH VERTICAL LINE BELOW AND ACUTE", "\u{e9}\u{329}"),
+    ("LATIN SMALL LETTER E WITH VERTICAL LINE BELOW AND GRAVE", "\u{e8}\u{329}"),
+    ("LATIN SMALL LETTER HOOKED SCHWA WITH ACUTE", "\u{25a}\u{301}"),
+    ("LATIN SMALL LETTER HOOKED SCHWA WITH GRAVE", "\u{25a}\u{300}"),
+    ("LATIN SMALL LETTER I WITH DOT ABOVE AND ACUTE", "i\u{307}\u{301}"),
+    ("LATIN SMALL LETTER I WITH DOT ABOVE AND GRAVE", "i\u{307}\u{300}"),
+    ("LATIN SMALL LETTER I WITH DOT ABOVE AND TILDE", "i\u{307}\u{303}"),
+    ("LATIN SMALL LETTER I WITH MACRON AND GRAVE", "\u{12b}\u{300}"),
+    ("LATIN SMALL LETTER I WITH OGONEK AND DOT ABOVE AND ACUTE", "\u{12f}\u{307}\u{301}"),
+    ("LATIN SMALL LETTER I WITH OGONEK AND DOT ABOVE AND TILDE", "\u{12f}\u{307}\u{303}"),
+    ("LATIN SMALL LETTER J WITH DOT ABOVE AND TILDE", "j\u{307}\u{303}"),
+    ("LATIN SMALL LETTER L WITH TILDE", "l\u{303}"),
+    ("LATIN SMALL LETTER M WITH TILDE", "m\u{303}"),
+    ("LATIN SMALL LETTER NG WITH TILDE ABOVE", "n\u{360}g"),
+    ("LATIN SMALL LETTER O WITH VERTICAL LINE BELOW", "o\u{329}"),
+    ("LATIN SMALL LETTER O WITH VERTICAL LINE BELOW AND ACUTE", "\u{f3}\u{329}"),
+    ("LATIN SMALL LETTER O WITH VERTICAL LINE BELOW AND GRAVE", "\u{f2}\u{329}"),
+    ("LATIN SMALL LETTER OPEN O WITH ACUTE", "\u{254}\u{301}"),
+    ("LATIN SMALL LETTER OPEN O WITH GRAVE", "\u{254}\u{300}"),
+    ("LATIN SMALL LETTER R WITH TILDE", "r\u{303}"),
+    ("LATIN SMALL LETTER S WITH VERTICAL LINE BELOW", "s\u{329}"),
+    ("LATIN SMALL LETTER SCHWA WITH ACUTE", "\u{259}\u{301}"),
+    ("LATIN SMALL LETTER SCHWA WITH GRAVE", "\u{259}\u{300}"),
+    ("LATIN SMALL LETTER TURNED V WITH ACUTE", "\u{28c}\u{301}"),
+    ("LATIN SMALL LETTER TURNED V WITH GRAVE", "\u{28c}\u{300}"),
+    ("LATIN SMALL LETTER U WITH MACRON AND ACUTE", "\u{16b}\u{301}"),
+    ("LATIN SMALL LETTER U WITH MACRON AND GRAVE", "\u{16b}\u{300}"),
+    ("LATIN SMALL LETTER U WITH MACRON AND TILDE", "\u{16b}\u{303}"),
+    ("LATIN SMALL LETTER U WITH OGONEK AND ACUTE", "\u{173}\u{301}"),
+    ("LATIN SMALL LETTER U WITH OGONEK AND TILDE", "\u{173}\u{303}"),
+    ("MODIFIER LETTER EXTRA-HIGH EXTRA-LOW CONTOUR TONE BAR", "\u{2e5}\u{2e9}"),
+    ("MODIFIER LETTER EXTRA-LOW EXTRA-HIGH CONTOUR TONE BAR", "\u{2e9}\u{2e5}"),
+    ("ORIYA SEQUENCE FOR LETTER RHA", "\u{b22}\u{b3c}"),
+    ("ORIYA SEQUENCE FOR LETTER RRA", "\u{b21}\u{b3c}"),
+    ("SINHALA CONSONANT SIGN RAKAARAANSAYA", "\u{dca}\u{200d}\u{dbb}"),
+    ("SINHALA CONSONANT SIGN REPAYA", "\u{dbb}\u{dca}\u{200d}"),
+    ("SINHALA CONSONANT SIGN YANSAYA", "\u{dca}\u{200d}\u{dba}"),
+    ("TAMIL CONSONANT C", "\u{b9a}\u{bcd}"),
+    ("TAMIL CONSONANT H", "\u{bb9}\u{bcd}"),
+    ("TAMIL CONSONANT J", "\u{b9c}\u{bcd}"),
+    ("TAMIL CONSONANT K", "\u{b95}\u{bcd}"),
+    ("TAMIL CONSONANT KSS", "\u{b95}\u{bcd}\u{bb7}\u{bcd}"),
+    ("TAMIL CONSONANT L", "\u{bb2}\u{bcd}"),
+    ("TAMIL CONSONANT LL", "\u{bb3}\u{bcd}"),
+    ("TAMIL CONSONANT LLL", "\u{bb4}\u{bcd}"),
+    ("TAMIL CONSONANT M", "\u{bae}\u{bcd}"),
+    ("TAMIL CONSONANT N", "\u{ba8}\u{bcd}"),
+    ("TAMIL CONSONANT NG", "\u{b99}\u{bcd}"),
+    ("TAMIL CONSONANT NN", "\u{ba3}\u{bcd}"),
+    ("TAMIL CONSONANT NNN", "\u{ba9}\u{bcd}"),
+    ("TAMIL CONSONANT NY", "\u{b9e}\u{bcd}"),
+    ("TAMIL CONSONANT P", "\u{baa}\u{bcd}"),
+    ("TAMIL CONSONANT R", "\u{bb0}\u{bcd}"),
+    ("TAMIL CONSONANT RR", "\u{bb1}\u{bcd}"),
+    ("TAMIL CONSONANT S", "\u{bb8}\u{bcd}"),
+    ("TAMIL CONSONANT SH", "\u{bb6}\u{bcd}"),
+    ("TAMIL CONSONANT SS", "\u{bb7}\u{bcd}"),
+    ("TAMIL CONSONANT T", "\u{ba4}\u{bcd}"),
+    ("TAMIL CONSONANT TT", "\u{b9f}\u{bcd}"),
+    ("TAMIL CONSONANT V", "\u{bb5}\u{bcd}"),
+    ("TAMIL CONSONANT Y", "\u{baf}\u{bcd}"),
+    ("TAMIL SYLLABLE CAA", "\u{b9a}\u{bbe}"),
+    ("TAMIL SYLLABLE CAI", "\u{b9a}\u{bc8}"),
+    ("TAMIL SYLLABLE CAU", "\u{b9a}\u{bcc}"),
+    ("TAMIL SYLLABLE CE", "\u{b9a}\u{bc6}"),
+    ("TAMIL SYLLABLE CEE", "\u{b9a}\u{bc7}"),
+    ("TAMIL SYLLABLE CI", "\u{b9a}\u{bbf}"),
+    ("TAMIL SYLLABLE CII", "\u{b9a}\u{bc0}"),
+    ("TAMIL SYLLABLE CO", "\u{b9a}\u{bca}"),
+    ("TAMIL SYLLABLE COO", "\u{b9a}\u{bcb}"),
+    ("TAMIL SYLLABLE CU", "\u{b9a}\u{bc1}"),
+    ("TAMIL SYLLABLE CUU", "\u{b9a}\u{bc2}"),
+    ("TAMIL SYLLABLE HAA", "\u{bb9}\u{bbe}"),
+    ("TAMIL SYLLABLE HAI", "\u{bb9}\u{bc8}"),
+    ("TAMIL SYLLABLE HAU", "\u{bb9}\u{bcc}"),
+    ("TAMIL SYLLABLE HE", "\u{bb9}\u{bc6}"),
+    ("TAMIL SYLLABLE HEE", "\u{bb9}\u{bc7}"),
+    ("TAMIL SYLLABLE HI", "\u{bb9}\u{bbf}"),
+    ("TAMIL SYLLABLE HII", "\u{bb9}\u{bc0}"),
+    ("TAMIL SYLLABLE HO", "\u{bb9}\u{bca}"),
+    ("TAMIL SYLLABLE HOO", "\u{bb9}\u{bcb}"),
+    ("TAMIL SYLLABLE HU", "\u{bb9}\u{bc1}"),
+    ("TAMIL SYLLABLE HUU", "\u{bb9}\u{bc2}"),
+    ("TAMIL SYLLABLE JAA", "\u{b9c}\u{bbe}"),
+    ("TAMIL SYLLABLE JAI", "\u{b9c}\u{bc8}"),
+    ("TAMIL SYLLABLE JAU", "\u{b9c}\u{bcc}"),
+    ("TAMIL SYLLABLE JE", "\u{b9c}\u{bc6}"),
+    ("TAMIL SYLLABLE JEE", "\u{b9c}\u{bc7}"),
+    ("TAMIL SYLLABLE JI", "\u{b9c}\u{bbf}"),
+    ("TAMIL SYLLABLE JII", "\u{b9c}\u{bc0}"),
+    ("TAMIL SYLLABLE JO", "\u{b9c}\u{bca}"),
+    ("TAMIL SYLLABLE JOO", "\u{b9c}\u{bcb}"),
+    ("TAMIL SYLLABLE JU", "\u{b9c}\u{bc1}"),
+    ("TAMIL SYLLABLE JUU", "\u{b9c}\u{bc2}"),
+    ("TAMIL SYLLABLE KAA", "\u{b95}\u{bbe}"),
+    ("TAMIL SYLLABLE KAI", "\u{b95}\u{bc8}"),
+    ("TAMIL SYLLABLE KAU", "\u{b95}\u{bcc}"),
+    ("TAMIL SYLLABLE KE", "\u{b95}\u{bc6}"),
+    ("TAMIL SYLLABLE KEE", "\u{b95}\u{bc7}"),
+    ("TAMIL SYLLABLE KI", "\u{b95}\u{bbf}"),
+    ("TAMIL SYLLABLE KII", "\u{b95}\u{bc0}"),
+    ("TAMIL SYLLABLE KO", "\u{b95}\u{bca}"),
+    ("TAMIL SYLLABLE KOO", "\u{b95}\u{bcb}"),
+    ("TAMIL SYLLABLE KSSA", "\u{b95}\u{bcd}\u{bb7}"),
+    ("TAMIL SYLLABLE KSSAA", "\u{b95}\u{bcd}\u{bb7}\u{bbe}"),
+    ("TAMIL SYLLABLE KSSAI", "\u{b95}\u{bcd}\u{bb7}\u{bc8}"),
+    ("TAMIL SYLLABLE KSSAU", "\u{b95}\u{bcd}\u{bb7}\u{bcc}"),
+    ("TAMIL SYLLABLE KSSE", "\u{b95}\u{bcd}\u{bb7}\u{bc6}"),
+    ("TAMIL SYLLABLE KSSEE", "\u{b95}\u{bcd}\u{bb7}\u{bc7}"),
+    ("TAMIL SYLLABLE KSSI", "\u{b95}\u{bcd}\u{bb7}\u{bbf}"),
+    ("TAMIL SYLLABLE KSSII", "\u{b95}\u{bcd}\u{bb7}\u{bc0}"),
+    ("TAMIL SYLLABLE KSSO", "\u{b95}\u{bcd}\u{bb7}\u{bca}"),
+    ("TAMIL SYLLABLE KSSOO", "\u{b95}\u{bcd}\u{bb7}\u{bcb}"),
+    ("TAMIL SYLLABLE KSSU", "\u{b95}\u{bcd}\u{bb7}\u{bc1}"),
+    ("TAMIL SYLLABLE KSSUU", "\u{b95}\u{bcd}\u{bb7}\u{bc2}"),
+    ("TAMIL SYLLABLE KU", "\u{b95}\u{bc1}"),
+    ("TAMIL SYLLABLE KUU", "\u{b95}\u{bc2}"),
+    ("TAMIL SYLLABLE LAA", "\u{bb2}\u{bbe}"),
+    ("TAMIL SYLLABLE LAI", "\u{bb2}\u{bc8}"),
+    ("TAMIL SYLLABLE LAU", "\u{bb2}\u{bcc}"),
+    ("TAMIL SYLLABLE LE", "\u{bb2}\u{bc6}"),
+    ("TAMIL SYLLABLE LEE", "\u{bb2}\u{bc7}"),
+    ("TAMIL SYLLABLE LI", "\u{bb2}\u{bbf}"),
+    ("TAMIL SYLLABLE LII", "\u{bb2}\u{bc0}"),
+    ("TAMIL SYLLABLE LLAA", "\u{bb3}\u{bbe}"),
+    ("TAMIL SYLLABLE LLAI", "\u{bb3}\u{bc8}"),
+    ("TAMIL SYLLABLE LLAU", "\u{bb3}\u{bcc}"),
+    ("TAMIL SYLLABLE LLE", "\u{bb3}\u{bc6}"),
+    ("TAMIL SYLLABLE LLEE", "\u{bb3}\u{bc7}"),
+    ("TAMIL SYLLABLE LLI", "\u{bb3}\u{bbf}"),
+    ("TAMIL SYLLABLE LLII", "\u{bb3}\u{bc0}"),
+    ("TAMIL SYLLABLE LLLAA", "\u{bb4}\u{bbe}"),
+    ("TAMIL SYLLABLE LLLAI", "\u{bb4}\u{bc8}"),
+    ("TAMIL SYLLABLE LLLAU", "\u{bb4}\u{bcc}"),
+    ("TAMIL SYLLABLE LLLE", "\u{bb4}\u{bc6}"),
+    ("TAMIL SYLLABLE LLLEE", "\u{bb4}\u{bc7}"),
+    ("TAMIL SYLLABLE LLLI", "\u{bb4}\u{bbf}"),
+    ("TAMIL SYLLABLE LLLII", "\u{bb4}\u{bc0}"),
+    ("TAMIL SYLLABLE LLLO", "\u{bb4}\u{bca}"),
+    ("TAMIL SYLLABLE LLLOO", "\u{bb4}\u{bcb}"),
+    ("TAMIL SYLLABLE LLLU", "\u{bb4}\u{bc1}"),
+    ("TAMIL SYLLABLE LLLUU", "\u{bb4}\u{bc2}"),
+    ("TAMIL SYLLABLE LLO", "\u{bb3}\u{bca}"),
+    ("TAMIL SYLLABLE LLOO", "\u{bb3}\u{bcb}"),
+    ("TAMIL SYLLABLE LLU", "\u{bb3}\u{bc1}"),
+    ("TAMIL SYLLABLE LLUU", "\u{bb3}\u{bc2}"),
+    ("TAMIL SYLLABLE LO", "\u{bb2}\u{bca}"),
+    ("TAMIL SYLLABLE LOO", "\u{bb2}\u{bcb}"),
+    ("TAMIL SYLLABLE LU", "\u{bb2}\u{bc1}"),
+    ("TAMIL SYLLABLE LUU", "\u{bb2}\u{bc2}"),
+    ("TAMIL SYLLABLE MAA", "\u{bae}\u{bbe}"),
+    ("TAMIL SYLLABLE MAI", "\u{bae}\u{bc8}"),
+    ("TAMIL SYLLABLE MAU", "\u{bae}\u{bcc}"),
+    ("TAMIL SYLLABLE ME", "\u{bae}\u{bc6}"),
+    ("TAMIL SYLLABLE MEE", "\u{bae}\u{bc7}"),
+    ("TAMIL SYLLABLE MI", "\u{bae}\u{bbf}"),
+    ("TAMIL SYLLABLE MII", "\u{bae}\u{bc0}"),
+    ("TAMIL SYLLABLE MO", "\u{bae}\u{bca}"),
+    ("TAMIL SYLLABLE MOO", "\u{bae}\u{bcb}"),
+    ("TAMIL SYLLABLE MU", "\u{bae}\u{bc1}"),
+    ("TAMIL SYLLABLE MUU", "\u{bae}\u{bc2}"),
+    ("TAMIL SYLLABLE NAA", "\u{ba8}\u{bbe}"),
+    ("TAMIL SYLLABLE NAI", "\u{ba8}\u{bc8}"),
+    ("TAMIL SYLLABLE NAU", "\u{ba8}\u{bcc}"),
+    ("TAMIL SYLLABLE NE", "\u{ba8}\u{bc6}"),
+    ("TAMIL SYLLABLE NEE", "\u{ba8}\u{bc7}"),
+    ("TAMIL SYLLABLE NGAA", "\u{b99}\u{bbe}"),
+    ("TAMIL SYLLABLE NGAI", "\u{b99}\u{bc8}"),
+    ("TAMIL SYLLABLE NGAU", "\u{b99}\u{bcc}"),
+    ("TAMIL SYLLABLE NGE", "\u{b99}\u{bc6}"),
+    ("TAMIL SYLLABLE NGEE", "\u{b99}\u{bc7}"),
+    ("TAMIL SYLLABLE NGI", "\u{b99}\u{bbf}"),
+    ("TAMIL SYLLABLE NGII", "\u{b99}\u{bc0}"),
+    ("TAMIL SYLLABLE NGO", "\u{b99}\u{bca}"),
+    ("TAMIL SYLLABLE NGOO", "\u{b99}\u{bcb}"),
+    ("TAMIL SYLLABLE NGU", "\u{b99}\u{bc1}"),
+    ("TAMIL SYLLABLE NGUU", "\u{b99}\u{bc2}"),
+    ("TAMIL SYLLABLE NI", "\u{ba8}\u{bbf}"),
+    ("TAMIL SYLLABLE NII", "\u{ba8}\u{bc0}"),
+    ("TAMIL SYLLABLE NNAA", "\u{ba3}\u{bbe}"),
+    ("TAMIL SYLLABLE NNAI", "\u{ba3}\u{bc8}"),
+    ("TAMIL SYLLABLE NNAU", "\u{ba3}\u{bcc}"),
+    ("TAMIL SYLLABLE NNE", "\u{ba3}\u{bc6}"),
+    ("TAMIL SYLLABLE NNEE", "\u{ba3}\u{bc7}"),
+    ("TAMIL SYLLABLE NNI", "\u{ba3}\u{bbf}"),
+    ("TAMIL SYLLABLE NNII", "\u{ba3}\u{bc0}"),
+    ("TAMIL SYLLABLE NNNAA", "\u{ba9}\u{bbe}"),
+    ("TAMIL SYLLABLE NNNAI", "\u{ba9}\u{bc8}"),
+    ("TAMIL SYLLABLE NNNAU", "\u{ba9}\u{bcc}"),
+    ("TAMIL SYLLABLE NNNE", "\u{ba9}\u{bc6}"),
+    ("TAMIL SYLLABLE NNNEE", "\u{ba9}\u{bc7}"),
+    ("TAMIL SYLLABLE NNNI", "\u{ba9}\u{bbf}"),
+    ("TAMIL SYLLABLE NNNII", "\u{ba9}\u{bc0}"),
+    ("TAMIL SYLLABLE NNNO", "\u{ba9}\u{bca}"),
+    ("TAMIL SYLLABLE NNNOO", "\u{ba9}\u{bcb}"),
+    ("TAMIL SYLLABLE NNNU", "\u{ba9}\u{bc1}"),
+    ("TAMIL SYLLABLE NNNUU", "\u{ba9}\u{bc2}"),
+    ("TAMIL SYLLABLE NNO", "\u{ba3}\u{bca}"),
+    ("TAMIL SYLLABLE NNOO", "\u{ba3}\u{bcb}"),
+    ("TAMIL SYLLABLE NNU", "\u{ba3}\u{bc1}"),
+    ("TAMIL SYLLABLE NNUU", "\u{ba3}\u{bc2}"),
+    ("TAMIL SYLLABLE NO", "\u{ba8}\u{bca}"),
+    ("TAMIL SYLLABLE NOO", "\u{ba8}\u{bcb}"),
+    ("TAMIL SYLLABLE NU", "\u{ba8}\u{bc1}"),
+    ("TAMIL SYLLABLE NUU", "\u{ba8}\u{bc2}"),
+    ("TAMIL SYLLABLE NYAA", "\u{b9e}\u{bbe}"),
+    ("TAMIL SYLLABLE NYAI", "\u{b9e}\u{bc8}"),
+    ("TAMIL SYLLABLE NYAU", "\u{b9e}\u{bcc}"),
+    ("TAMIL SYLLABLE NYE", "\u{b9e}\u{bc6}"),
+    ("TAMIL SYLLABLE NYEE", "\u{b9e}\u{bc7}"),
+    ("TAMIL SYLLABLE NYI", "\u{b9e}\u{bbf}"),
+    ("TAMIL SYLLABLE NYII", "\u{b9e}\u{bc0}"),
+    ("TAMIL SYLLABLE NYO", "\u{b9e}\u{bca}"),
+    ("TAMIL SYLLABLE NYOO", "\u{b9e}\u{bcb}"),
+    ("TAMIL SYLLABLE NYU", "\u{b9e}\u{bc1}"),
+    ("TAMIL SYLLABLE NYUU", "\u{b9e}\u{bc2}"),
+    ("TAMIL SYLLABLE PAA", "\u{baa}\u{bbe}"),
+    ("TAMIL SYLLABLE PAI", "\u{baa}\u{bc8}"),
+    ("TAMIL SYLLABLE PAU", "\u{baa}\u{bcc}"),
+    ("TAMIL SYLLABLE PE", "\u{baa}\u{bc6}"),
+    ("TAMIL SYLLABLE PEE", "\u{baa}\u{bc7}"),
+    ("TAMIL SYLLABLE PI", "\u{baa}\u{bbf}"),
+    ("TAMIL SYLLABLE PII", "\u{baa}\u{bc0}"),
+    ("TAMIL SYLLABLE PO", "\u{baa}\u{bca}"),
+    ("TAMIL SYLLABLE POO", "\u{baa}\u{bcb}"),
+    ("TAMIL SYLLABLE PU", "\u{baa}\u{bc1}"),
+    ("TAMIL SYLLABLE PUU", "\u{baa}\u{bc2}"),
+    ("TAMIL SYLLABLE RAA", "\u{bb0}\u{bbe}"),
+    ("TAMIL SYLLABLE RAI", "\u{bb0}\u{bc8}"),
+    ("TAMIL SYLLABLE RAU", "\u{bb0}\u{bcc}"),
+    ("TAMIL SYLLABLE RE", "\u{bb0}\u{bc6}"),
+    ("TAMIL SYLLABLE REE", "\u{bb0}\u{bc7}"),
+    ("TAMIL SYLLABLE RI", "\u{bb0}\u{bbf}"),
+    ("TAMIL SYLLABLE RII", "\u{bb0}\u{bc0}"),
+    ("TAMIL SYLLABLE RO", "\u{bb0}\u{bca}"),
+    ("TAMIL SYLLABLE ROO", "\u{bb0}\u{bcb}"),
+    ("TAMIL SYLLABLE RRAA", "\u{bb1}\u{bbe}"),
+    ("TAMIL SYLLABLE RRAI", "\u{bb1}\u{bc8}"),
+    ("TAMIL SYLLABLE RRAU", "\u{bb1}\u{bcc}"),
+    ("TAMIL SYLLABLE RRE", "\u{bb1}\u{bc6}"),
+    ("TAMIL SYLLABLE RREE", "\u{bb1}\u{bc7}"),
+    ("TAMIL SYLLABLE RRI", "\u{bb1}\u{bbf}"),
+    ("TAMIL SYLLABLE RRII", "\u{bb1}\u{bc0}"),
+    ("TAMIL SYLLABLE RRO", "\u{bb1}\u{bca}"),
+    ("TAMIL SYLLABLE RROO", "\u{bb1}\u{bcb}"),
+    ("TAMIL SYLLABLE RRU", "\u{bb1}\u{bc1}"),
+    ("TAMIL SYLLABLE RRUU", "\u{bb1}\u{bc2}"),
+    ("TAMIL SYLLABLE RU", "\u{bb0}\u{bc1}"),
+    ("TAMIL SYLLABLE RUU", "\u{bb0}\u{bc2}"),
+    ("TAMIL SYLLABLE SAA", "\u{bb8}\u{bbe}"),
+    ("TAMIL SYLLABLE SAI", "\u{bb8}\u{bc8}"),
+    ("TAMIL SYLLABLE SAU", "\u{bb8}\u{bcc}"),
+    ("TAMIL SYLLABLE SE", "\u{bb8}\u{bc6}"),
+    ("TAMIL SYLLABLE SEE", "\u{bb8}\u{bc7}"),
+    ("TAMIL SYLLABLE SHAA", "\u{bb6}\u{bbe}"),
+    ("TAMIL SYLLABLE SHAI", "\u{bb6}\u{bc8}"),
+    ("TAMIL SYLLABLE SHAU", "\u{bb6}\u{bcc}"),
+    ("TAMIL SYLLABLE SHE", "\u{bb6}\u{bc6}"),
+    ("TAMIL SYLLABLE SHEE", "\u{bb6}\u{bc7}"),
+    ("TAMIL SYLLABLE SHI", "\u{bb6}\u{bbf}"),
+    ("TAMIL SYLLABLE SHII", "\u{bb6}\u{bc0}"),
+    ("TAMIL SYLLABLE SHO", "\u{bb6}\u{bca}"),
+    ("TAMIL SYLLABLE SHOO", "\u{bb6}\u{bcb}"),
+    ("TAMIL SYLLABLE SHRII", "\u{bb6}\u{bcd}\u{bb0}\u{bc0}"),
+    ("TAMIL SYLLABLE SHU", "\u{bb6}\u{bc1}"),
+    ("TAMIL SYLLABLE SHUU", "\u{bb6}\u{bc2}"),
+    ("TAMIL SYLLABLE SI", "\u{bb8}\u{bbf}"),
+    ("TAMIL SYLLABLE SII", "\u{bb8}\u{bc0}"),
+    ("TAMIL SYLLABLE SO", "\u{bb8}\u{bca}"),
+    ("TAMIL SYLLABLE SOO", "\u{bb8}\u{bcb}"),
+    ("TAMIL SYLLABLE SSAA", "\u{bb7}\u{bbe}"),
+    ("TAMIL SYLLABLE SSAI", "\u{bb7}\u{bc8}"),
+    ("TAMIL SYLLABLE SSAU", "\u{bb7}\u{bcc}"),
+    ("TAMIL SYLLABLE SSE", "\u{bb7}\u{bc6}"),
+    ("TAMIL SYLLABLE SSEE", "\u{bb7}\u{bc7}"),
+    ("TAMIL SYLLABLE SSI", "\u{bb7}\u{bbf}"),
+    ("TAMIL SYLLABLE SSII", "\u{bb7}\u{bc0}"),
+    ("TAMIL SYLLABLE SSO", "\u{bb7}\u{bca}"),
+    ("TAMIL SYLLABLE SSOO", "\u{bb7}\u{bcb}"),
+    ("TAMIL SYLLABLE SSU", "\u{bb7}\u{bc1}"),
+    ("TAMIL SYLLABLE SSUU", "\u{bb7}\u{bc2}"),
+    ("TAMIL SYLLABLE SU", "\u{bb8}\u{bc1}"),
+    ("TAMIL SYLLABLE SUU", "\u{bb8}\u{bc2}"),
+    ("TAMIL SYLLABLE TAA", "\u{ba4}\u{bbe}"),
+    ("TAMIL SYLLABLE TAI", "\u{ba4}\u{bc8}"),
+    ("TAMIL SYLLABLE TAU", "\u{ba4}\u{bcc}"),
+    ("TAMIL SYLLABLE TE", "\u{ba4}\u{bc6}"),
+    ("TAMIL SYLLABLE TEE", "\u{ba4}\u{bc7}"),
+    ("TAMIL SYLLABLE TI", "\u{ba4}\u{bbf}"),
+    ("TAMIL SYLLABLE TII", "\u{ba4}\u{bc0}"),
+    ("TAMIL SYLLABLE TO", "\u{ba4}\u{bca}"),
+    ("TAMIL SYLLABLE TOO", "\u{ba4}\u{bcb}"),
+    ("TAMIL SYLLABLE TTAA", "\u{b9f}\u{bbe}"),
+    ("TAMIL SYLLABLE TTAI", "\u{b9f}\u{bc8}"),
+    ("TAMIL SYLLABLE TTAU", "\u{b9f}\u{bcc}"),
+    ("TAMIL SYLLABLE TTE", "\u{b9f}\u{bc6}"),
+    ("TAMIL SYLLABLE TTEE", "\u{b9f}\u{bc7}"),
+    ("TAMIL SYLLABLE TTI", "\u{b9f}\u{bbf}"),
+    ("TAMIL SYLLABLE TTII", "\u{b9f}\u{bc0}"),
+    ("TAMIL SYLLABLE TTO", "\u{b9f}\u{bca}"),
+    ("TAMIL SYLLABLE TTOO", "\u{b9f}\u{bcb}"),
+    ("TAMIL SYLLABLE TTU", "\u{b9f}\u{bc1}"),
+    ("TAMIL SYLLABLE TTUU", "\u{b9f}\u{bc2}"),
+    ("TAMIL SYLLABLE TU", "\u{ba4}\u{bc1}"),
+    ("TAMIL SYLLABLE TUU", "\u{ba4}\u{bc2}"),
+    ("TAMIL SYLLABLE VAA", "\u{bb5}\u{bbe}"),
+    ("TAMIL SYLLABLE VAI", "\u{bb5}\u{bc8}"),
+    ("TAMIL SYLLABLE VAU", "\u{bb5}\u{bcc}"),
+    ("TAMIL SYLLABLE VE", "\u{bb5}\u{bc6}"),
+    ("TAMIL SYLLABLE VEE", "\u{bb5}\u{bc7}"),
+    ("TAMIL SYLLABLE VI", "\u{bb5}\u{bbf}"),
+    ("TAMIL SYLLABLE VII", "\u{bb5}\u{bc0}"),
+    ("TAMIL SYLLABLE VO", "\u{bb5}\u{bca}"),
+    ("TAMIL SYLLABLE VOO", "\u{bb5}\u{bcb}"),
+    ("TAMIL SYLLABLE VU", "\u{bb5}\u{bc1}"),
+    ("TAMIL SYLLABLE VUU", "\u{bb5}\u{bc2}"),
+    ("TAMIL SYLLABLE YAA", "\u{baf}\u{bbe}"),
+    ("TAMIL SYLLABLE YAI", "\u{baf}\u{bc8}"),
+    ("TAMIL SYLLABLE YAU", "\u{baf}\u{bcc}"),
+    ("TAMIL SYLLABLE YE", "\u{baf}\u{bc6}"),
+    ("TAMIL SYLLABLE YEE", "\u{baf}\u{bc7}"),
+    ("TAMIL SYLLABLE YI", "\u{baf}\u{bbf}"),
+    ("TAMIL SYLLABLE YII", "\u{baf}\u{bc0}"),
+    ("TAMIL SYLLABLE YO", "\u{baf}\u{bca}"),
+    ("TAMIL SYLLABLE YOO", "\u{baf}\u{bcb}"),
+    ("TAMIL SYLLABLE YU", "\u{baf}\u{bc1}"),
+    ("TAMIL SYLLABLE YUU", "\u{baf}\u{bc2}"),
+];
@@ -18,7 +18,8 @@ use crate::{
     builtins::Builtins,
     bytecode::{CallResult, VM},
     exception_private::{ExcType, RunError, RunResult, SimpleException},
-    hash::{HashValue, hash_python_str},
+    function::function_annotations,
+    hash::{HashValue, hash_python_float, hash_python_str},
     heap::{ContainsHeap, DropWithHeap, Heap, HeapData, HeapGuard, HeapId, HeapReadOutput},
     intern::{BytesId, FunctionId, Interns, LongIntId, StaticStrings, StringId},
     modules::ModuleFunctions,
@@ -27,13 +28,17 @@ use crate::{
         check_repeat_size,
     },
     types::{
-        Bytes, List, LongInt, Property, PyTrait, Type, allocate_tuple,
-        bytes::{bytes_repr_fmt, get_byte_at_index},
+        ByteArray, Bytes, List, LongInt, Property, PyTrait, Type, allocate_tuple,
+        bytes::{bytes_like_contents, bytes_repr_fmt, get_byte_at_index},
+        complex::complex_operands,
+        float::float_getattr,
+        int::{IntReceiver, int_getattr},
         long_int::check_bits_str_digits_limit,
         path,
         slice::slice_collect_iterator,
         str::{allocate_char, allocate_string, get_char_at_index, string_repr_fmt},
         timedelta,
+        typing_forms::{subscript_marker, subscript_type},
     },
 };
 
@@ -177,6 +182,10 @@ impl PyTrait<'_> for Value {
             (Self::Ref(id), Self::Int(b)) if let HeapData::LongInt(li) = vm.heap.get(*id) => {
                 Ok(*li.inner() == BigInt::from(*b))
             }
+            // complex == number, with the number promoted to complex
+            (Self::Ref(_), _) | (_, Self::Ref(_)) if let Some((a, b)) = complex_operands(self, other, vm.heap) => {
+                Ok(a == b)
+            }
 
             // For interned interns, compare by StringId first (fast path for same interned string)
             (Self::InternString(s1), Self::InternString(s2)) => Ok(s1 == s2),
@@ -193,12 +202,16 @@ impl PyTrait<'_> for Value {
                 // Fast path: same BytesId means same content
                 Ok(b1 == b2 || interns.get_bytes(*b1) == interns.get_bytes(*b2))
             }
-            // same for bytes
-            (Self::InternBytes(bytes_id), Self::Ref(id2)) if let HeapData::Bytes(b2) = vm.heap.get(*id2) => {
-                Ok(interns.get_bytes(*bytes_id) == b2.as_slice())
+            // same for bytes, which also compare equal to bytearray and memoryview contents
+            (Self::InternBytes(bytes_id), Self::Ref(_))
+                if let Some(b2) = bytes_like_contents(other, vm.heap, interns) =>
+            {
+                Ok(interns.get_bytes(*bytes_id) == &*b2)
             }
-            (Self::Ref(id1), Self::InternBytes(bytes_id)) if let HeapData::Bytes(b1) = vm.heap.get(*id1) => {
-                Ok(b1.as_slice() == interns.get_bytes(*bytes_id))
+            (Self::Ref(_), Self::InternBytes(bytes_id))
+                if let Some(b1) = bytes_like_contents(self, vm.heap, interns) =>
+            {
+                Ok(&*b1 == interns.get_bytes(*bytes_id))
             }
 
             (Self::Ref(id1), Self::Ref(id2)) => {
@@ -258,7 +271,7 @@ impl PyTrait<'_> for Value {
             (Self::Ref(id), Self::Int(b)) if let HeapData::LongInt(li) = vm.heap.get(*id) => {
                 Ok(li.inner().partial_cmp(&BigInt::from(*b)))
             }
-            // Ref vs Ref comparison: handles LongInt, Str, and Tuple
+            // Ref vs Ref comparison: handles LongInt, Str, Tuple, dates and ordered dataclasses
             (Self::Ref(id1), Self::Ref(id2)) => match (vm.heap.read(*id1), vm.heap.read(*id2)) {
                 (HeapReadOutput::LongInt(a), HeapReadOutput::LongInt(b)) => {
                     Ok(a.get(vm.heap).inner().partial_cmp(b.get(vm.heap).inner()))
@@ -269,9 +282,19 @@ impl PyTrait<'_> for Value {
                 (HeapReadOutput::Tuple(a), HeapReadOutput::Tuple(b)) => a.py_cmp(&b, vm),
                 (HeapReadOutput::Date(a), HeapReadOutput::Date(b)) => Ok(a.get(vm.heap).partial_cmp(b.get(vm.heap))),
                 (HeapReadOutput::DateTime(a), HeapReadOutput::DateTime(b)) => a.py_cmp(&b, vm),
+                (HeapReadOutput::Dataclass(a), HeapReadOutput::Dataclass(b)) => a.py_cmp(&b, vm),
                 (HeapReadOutput::TimeDelta(a), HeapReadOutput::TimeDelta(b)) => {
                     Ok(a.get(vm.heap).partial_cmp(b.get(vm.heap)))
                 }
+                // bytes and bytearray order against each other; memoryview is unordered
+                (
+                    HeapReadOutput::Bytes(_) | HeapReadOutput::ByteArray(_),
+                    HeapReadOutput::Bytes(_) | HeapReadOutput::ByteArray(_),
+                ) => {
+                    let a = bytes_like_contents(self, vm.heap, interns);
+                    let b = bytes_like_contents(other, vm.heap, interns);
+                    Ok(a.partial_cmp(&b))
+                }
                 _ => Ok(None),
             },
             // Interned string comparisons
@@ -288,6 +311,18 @@ impl PyTrait<'_> for Value {
             (Self::InternBytes(b1), Self::InternBytes(b2)) => {
                 Ok(interns.get_bytes(*b1).partial_cmp(interns.get_bytes(*b2)))
             }
+            (Self::InternBytes(b1), Self::Ref(id2))
+                if let HeapData::Bytes(_) | HeapData::ByteArray(_) = vm.heap.get(*id2) =>
+            {
+                let b2 = bytes_like_contents(other, vm.heap, interns);
+                Ok(Some(interns.get_bytes(*b1)).partial_cmp(&b2.as_deref()))
+            }
+            (Self::Ref(id1), Self::InternBytes(b2))
+                if let HeapData::Bytes(_) | HeapData::ByteArray(_) = vm.heap.get(*id1) =>
+            {
+                let b1 = bytes_like_contents(self, vm.heap, interns);
+                Ok(b1.as_deref().partial_cmp(&Some(interns.get_bytes(*b2))))
+            }
             _ => Ok(None),
         }
     }
@@ -392,6 +427,10 @@ impl PyTrait<'_> for Value {
                     li.into_value(vm.heap).map(Some)
                 }
             }
+            // complex arithmetic, with bool, int and float operands promoted to complex
+            (Self::Ref(_), _) | (_, Self::Ref(_)) if let Some((a, b)) = complex_operands(self, other, vm.heap) => {
+                (a + b).into_value(vm.heap).map(Some)
+            }
             // Int + LongInt
             (Self::Int(i), Self::Ref(id)) | (Self::Ref(id), Self::Int(i))
                 if let HeapData::LongInt(li) = vm.heap.get(*id) =>
@@ -430,19 +469,31 @@ impl PyTrait<'_> for Value {
                 b.extend_from_slice(bytes2);
                 Ok(Some(Self::Ref(vm.heap.allocate(HeapData::Bytes(b.into()))?)))
             }
-            (Self::InternBytes(bytes_id), Self::Ref(id2)) if let HeapData::Bytes(b2) = vm.heap.get(*id2) => {
+            // bytes on the left always produces bytes, whatever bytes-like type is on the right
+            (Self::InternBytes(bytes_id), Self::Ref(_))
+                if let Some(b2) = bytes_like_contents(other, vm.heap, interns) =>
+            {
                 let bytes1 = interns.get_bytes(*bytes_id);
                 let mut b = Vec::with_capacity(bytes1.len() + b2.len());
                 b.extend_from_slice(bytes1);
-                b.extend_from_slice(b2);
+                b.extend_from_slice(&b2);
                 Ok(Some(Self::Ref(vm.heap.allocate(HeapData::Bytes(b.into()))?)))
             }
-            (Self::Ref(id1), Self::InternBytes(bytes_id)) if let HeapData::Bytes(b1) = vm.heap.get(*id1) => {
-                let bytes2 = interns.get_bytes(*bytes_id);
-                let mut b = Vec::with_capacity(b1.len() + bytes2.len());
-                b.extend_from_slice(b1);
-                b.extend_from_slice(bytes2);
-                Ok(Some(Self::Ref(vm.heap.allocate(HeapData::Bytes(b.into()))?)))
+            // bytearray on the left keeps its type
+            (Self::Ref(id1), Self::InternBytes(bytes_id))
+                if let HeapData::Bytes(_) | HeapData::ByteArray(_) = vm.heap.get(*id1) =>
+            {
+                let is_bytearray = matches!(vm.heap.get(*id1), HeapData::ByteArray(_));
+                let mut b = bytes_like_contents(self, vm.heap, interns)
+                    .unwrap_or_default()
+                    .into_owned();
+                b.extend_from_slice(interns.get_bytes(*bytes_id));
+                let data = if is_bytearray {
+                    HeapData::ByteArray(ByteArray::new(b))
+                } else {
+                    HeapData::Bytes(b.into())
+                };
+                Ok(Some(Self::Ref(vm.heap.allocate(data)?)))
             }
             _ => Ok(None),
         }
@@ -460,6 +511,10 @@ impl PyTrait<'_> for Value {
                     li.into_value(vm.heap).map(Some)
                 }
             }
+            // complex arithmetic, with bool, int and float operands promoted to complex
+            (Self::Ref(_), _) | (_, Self::Ref(_)) if let Some((a, b)) = complex_operands(self, other, vm.heap) => {
+                (a - b).into_value(vm.heap).map(Some)
+            }
             // Int - LongInt
             (Self::Int(a), Self::Ref(id)) if let HeapData::LongInt(li) = vm.heap.get(*id) => {
                 let result = LongInt::from(*a) - LongInt::new(li.inner().clone());
@@ -612,12 +667,12 @@ impl PyTrait<'_> for Value {
                 *self = Self::Ref(vm.heap.allocate(HeapData::Bytes(b.into()))?);
                 Ok(true)
             }
-            (Self::InternBytes(bytes_id), Self::Ref(id2)) => {
-                let result = if let HeapData::Bytes(b2) = vm.heap.get(*id2) {
+            (Self::InternBytes(bytes_id), Self::Ref(_)) => {
+                let result = if let Some(b2) = bytes_like_contents(other, vm.heap, interns) {
                     let bytes1 = interns.get_bytes(*bytes_id);
                     let mut b = Vec::with_capacity(bytes1.len() + b2.len());
                     b.extend_from_slice(bytes1);
-                    b.extend_from_slice(b2);
+                    b.extend_from_slice(&b2);
                     *self = Self::Ref(vm.heap.allocate(HeapData::Bytes(b.into()))?);
                     true
                 } else {
@@ -625,7 +680,8 @@ impl PyTrait<'_> for Value {
                 };
                 Ok(result)
             }
-            (Self::Ref(id), Self::Ref(_)) => vm.heap.read(*id).py_iadd(other, vm, Some(*id)),
+            // `bytearray += b"..."` extends in place even when the right side is interned
+            (Self::Ref(id), _) => vm.heap.read(*id).py_iadd(other, vm, Some(*id)),
             _ => Ok(false),
         }
     }
@@ -643,6 +699,10 @@ impl PyTrait<'_> for Value {
                     Ok(Some(li.into_value(vm.heap)?))
                 }
             }
+            // complex arithmetic, with bool, int and float operands promoted to complex
+            (Self::Ref(_), _) | (_, Self::Ref(_)) if let Some((a, b)) = complex_operands(self, other, vm.heap) => {
+                Ok(Some((a * b).into_value(vm.heap)?))
+            }
             // Int * heap-allocated value (commutative for the supported types).
             // Covers LongInt and TimeDelta numeric multiplication, plus repetition
             // of heap-allocated Str/Bytes/List/Tuple sequences by an integer count.
@@ -674,6 +734,12 @@ impl PyTrait<'_> for Value {
                         vm.heap.allocate(HeapData::Bytes(b.as_slice().repeat(count).into()))?,
                     )))
                 }
+                HeapData::ByteArray(b) => {
+                    let count = i64_to_repeat_count(*n)?;
+                    check_repeat_size(b.as_slice().len(), count, vm.heap.tracker())?;
+                    let repeated = ByteArray::new(b.as_slice().repeat(count));
+                    Ok(Some(Self::Ref(vm.heap.allocate(HeapData::ByteArray(repeated))?)))
+                }
                 HeapData::List(list) => {
                     let count = i64_to_repeat_count(*n)?;
                     check_repeat_size(
@@ -733,6 +799,11 @@ impl PyTrait<'_> for Value {
                             vm.heap.allocate(HeapData::Bytes(b.as_slice().repeat(count).into()))?,
                         )))
                     }
+                    HeapData::ByteArray(b) => {
+                        check_repeat_size(b.as_slice().len(), count, vm.heap.tracker())?;
+                        let repeated = ByteArray::new(b.as_slice().repeat(count));
+                        Ok(Some(Self::Ref(vm.heap.allocate(HeapData::ByteArray(repeated))?)))
+                    }
                     HeapData::List(list) => {
                         check_repeat_size(
                             list.len().saturating_mul(mem::size_of::<Self>()),
@@ -847,6 +918,10 @@ impl PyTrait<'_> for Value {
                     Ok(Some(Self::Float(*a as f64 / *b as f64)))
                 }
             }
+            // complex arithmetic, with bool, int and float operands promoted to complex
+            (Self::Ref(_), _) | (_, Self::Ref(_)) if let Some((a, b)) = complex_operands(self, other, vm.heap) => {
+                Ok(Some(a.div(b)?.into_value(vm.heap)?))
+            }
             // Int / LongInt
             (Self::Int(a), Self::Ref(id)) => {
                 if let HeapData::LongInt(li) = vm.heap.get(*id) {
@@ -1161,6 +1236,10 @@ impl PyTrait<'_> for Value {
                     }
                 }
             }
+            // complex arithmetic, with bool, int and float operands promoted to complex
+            (Self::Ref(_), _) | (_, Self::Ref(_)) if let Some((a, b)) = complex_operands(self, other, vm.heap) => {
+                Ok(Some(a.pow(b)?.into_value(vm.heap)?))
+            }
             // LongInt ** Int
             (Self::Ref(id), Self::Int(exp)) => {
                 if let HeapData::LongInt(li) = vm.heap.get(*id) {
@@ -1378,6 +1457,15 @@ impl PyTrait<'_> for Value {
                 let byte = get_byte_at_index(bytes, index).ok_or_else(ExcType::bytes_index_error)?;
                 Ok(Self::Int(i64::from(byte)))
             }
+            // Typing forms like `Optional[int]` or `List[str]`
+            Self::Marker(marker) if !matches!(marker.py_type(), Type::TextIOWrapper | Type::MissingType) => {
+                subscript_marker(*marker, key, vm)
+            }
+            // Builtin generics like `list[int]` or `dict[str, int]`
+            Self::Builtin(Builtins::Type(t)) => match subscript_type(*t, key, vm)? {
+                Some(alias) => Ok(alias),
+                None => Err(ExcType::type_error_not_sub(self.py_type(vm))),
+            },
             _ => Err(ExcType::type_error_not_sub(self.py_type(vm))),
         }
     }
@@ -1426,7 +1514,7 @@ impl Value {
             Self::InternBytes(_) => Type::Bytes,
             Self::Builtin(_) => Type::BuiltinFunction,
             Self::ModuleFunction(_) | Self::DefFunction(_) | Self::ExtFunction(_) => Type::Function,
-            Self::Marker(_) => Type::SpecialForm,
+            Self::Marker(m) => m.py_type(),
             Self::Property(_) => Type::Property,
             Self::Ref(_) => Type::NoneType, // callers should resolve Ref via HeapData::py_type()
             #[cfg(feature = "memory-model-checks")]
@@ -1507,7 +1595,7 @@ impl Value {
     pub fn module_name(&self, vm: &mut VM<'_, impl ResourceTracker>) -> String {
         match self {
             Self::Ref(id) => match vm.heap.get(*id) {
-                HeapData::Module(module) => vm.interns.get_str(module.name()).to_string(),
+                HeapData::Module(module) => module.name().as_str(vm.interns).to_string(),
                 _ => "<unknown>".to_string(),
             },
             _ => "<unknown>".to_string(),
@@ -1539,18 +1627,8 @@ impl Value {
             // Bool and int hash directly as their value, and are equivalent
             Self::Bool(b) => return Ok(Some(HashValue::new((*b).into()))),
             Self::Int(i) => return Ok(Some(HashValue::new(i.cast_unsigned()))),
-            Self::Float(f) => {
-                return {
-                    if f.fract() == 0.0 && *f >= (i64::MIN as f64) && *f <= (i64::MAX as f64) {
-                        // Hash floats that are mathematically integers the same as Ints (e.g., 1.0 hashes the same as 1)
-                        #[expect(clippy::cast_possible_truncation)]
-                        Ok(Some(HashValue::new((*f as i64).cast_unsigned())))
-                    } else {
-                        // Hash the bit representation of the float
-                        Ok(Some(HashValue::new(f.to_bits())))
-                    }
-                };
-            }
+            // Floats that are mathematically integers hash the same as Ints (e.g., 1.0 hashes the same as 1)
+            Self::Float(f) => return Ok(Some(hash_python_float(*f))),
             // For heap-allocated values, dispatch to the per-type `py_hash`
             // impl. Types that benefit from caching (Str/Bytes/Tuple/
             // NamedTuple/FrozenSet/Path) carry an inline `cached_hash`;
@@ -1578,7 +1656,7 @@ impl Value {
         Ok(Some(HashValue::new(hasher.finish())))
     }
 
-    /// TODO this doesn't have many tests!!! also doesn't cover bytes
+    /// TODO this doesn't have many tests!!!
     /// Checks if `item` is contained in `self` (the container).
     ///
     /// Implements Python's `in` operator for various container types:
@@ -1586,6 +1664,8 @@ impl Value {
     /// - Dict: key lookup
     /// - Set/FrozenSet: element lookup
     /// - Str: substring search
+    /// - Bytes/ByteArray: byte value or subsequence search
+    /// - MemoryView: byte value search
     pub fn py_contains(&self, item: &Self, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<bool> {
         match self {
             Self::Ref(heap_id) => {
@@ -1678,6 +1758,20 @@ impl Value {
                         let s_str = s.get(vm.heap).as_str();
                         str_contains(s_str, item, vm.heap, vm.interns)
                     }
+                    HeapReadOutput::Bytes(b) => bytes_contains(b.get(vm.heap).as_slice(), item, vm.heap, vm.interns),
+                    HeapReadOutput::ByteArray(b) => {
+                        bytes_contains(b.get(vm.heap).as_slice(), item, vm.heap, vm.interns)
+                    }
+                    HeapReadOutput::MemoryView(view) => {
+                        // A memoryview compares its items (ints) for equality, so
+                        // anything other than an in-range int is simply not found.
+                        let contents = view.get(vm.heap).contents(vm.heap);
+                        Ok(match item {
+                            Self::Int(i) => u8::try_from(*i).is_ok_and(|b| contents.contains(&b)),
+                            Self::Bool(b) => contents.contains(&u8::from(*b)),
+                            _ => false,
+                        })
+                    }
                     HeapReadOutput::Range(range) => {
                         // Range containment is O(1) - check bounds and step alignment
                         let range = range.get(vm.heap);
@@ -1712,6 +1806,10 @@ impl Value {
                 let container_str = vm.interns.get_str(*string_id);
                 str_contains(container_str, item, vm.heap, vm.interns)
             }
+            Self::InternBytes(bytes_id) => {
+                let container = vm.interns.get_bytes(*bytes_id);
+                bytes_contains(container, item, vm.heap, vm.interns)
+            }
             _ => {
                 let type_name = self.py_type(vm);
                 Err(ExcType::type_error(format!(
@@ -1734,6 +1832,25 @@ impl Value {
                     return Ok(call_result);
                 }
             }
+            Self::Int(i) => {
+                if let Some(value) = int_getattr(IntReceiver::Small(*i), attr, vm) {
+                    return Ok(CallResult::Value(value));
+                }
+            }
+            Self::Bool(b) => {
+                if let Some(value) = int_getattr(IntReceiver::Small(i64::from(*b)), attr, vm) {
+                    return Ok(CallResult::Value(value));
+                }
+            }
+            Self::Float(f) => {
+                if let Some(value) = float_getattr(*f, attr) {
+                    return Ok(CallResult::Value(value));
+                }
+            }
+            Self::DefFunction(_) if attr.static_string() == Some(StaticStrings::DunderAnnotations) => {
+                // Functions without defaults or annotations are stored inline, so have no `__annotate__`
+                return function_annotations(None, vm).map(CallResult::Value);
+            }
             Self::Builtin(Builtins::Type(t)) => {
                 // Handle type object attributes like __name__
                 let is_dunder_name = attr.static_string().map_or_else(
@@ -1788,6 +1905,20 @@ impl Value {
         }
     }
 
+    /// Deletes the named attribute, as `delattr()` does.
+    ///
+    /// Only dataclass instances have deletable attributes; every other type raises
+    /// `AttributeError` with CPython's message for objects without a `__dict__`.
+    pub fn py_del_attr(&self, name: &EitherStr, vm: &mut VM<'_, impl ResourceTracker>) -> RunResult<()> {
+        if let Self::Ref(heap_id) = self
+            && let HeapReadOutput::Dataclass(mut dc) = vm.heap.read(*heap_id)
+        {
+            return dc.del_attr(name, vm);
+        }
+        let type_name = self.py_type(vm);
+        Err(ExcType::attribute_error_no_setattr(type_name, name.as_str(vm.interns)))
+    }
+
     /// Extracts an integer value from the Value.
     ///
     /// Accepts `Int` and `LongInt` (if it fits in i64). Returns a `TypeError` for other types
@@ -2051,6 +2182,27 @@ impl Value {
         }
     }
 
+    /// Returns `true` if the value can be called.
+    ///
+    /// For heap-allocated values (`Ref`), checks the actual `HeapData` variant
+    /// rather than accepting all refs — only closures, functions with defaults,
+    /// heap-allocated external functions, `typing.NewType` / `TypedDict` and classes are callable.
+    pub fn is_callable(&self, heap: &Heap<impl ResourceTracker>) -> bool {
+        match self {
+            Self::DefFunction(_) | Self::Builtin(_) | Self::ExtFunction(_) | Self::ModuleFunction(_) => true,
+            Self::Ref(id) => matches!(
+                heap.get(*id),
+                HeapData::Closure(_)
+                    | HeapData::FunctionDefaults(_)
+                    | HeapData::ExtFunction(_)
+                    | HeapData::NewType(_)
+                    | HeapData::TypedDict(_)
+                    | HeapData::DataclassType(_)
+            ),
+            _ => false,
+        }
+    }
+
     /// Extracts an `i32` from a `Value`, accepting `Bool` and `Int`.
     ///
     /// Used by `date`, `datetime`, and other constructors that expect
@@ -2200,11 +2352,13 @@ impl Marker {
     ///
     /// System markers (stdout, stderr) are `TextIOWrapper`.
     /// `typing.Union` has type `type` (matching CPython).
+    /// `dataclasses.MISSING` is a `_MISSING_TYPE`.
     /// Other typing markers (Any, Optional, etc.) are `_SpecialForm`.
     pub(crate) fn py_type(self) -> Type {
         match self.0 {
             StaticStrings::Stdout | StaticStrings::Stderr => Type::TextIOWrapper,
             StaticStrings::UnionType => Type::Type,
+            StaticStrings::Missing => Type::MissingType,
             _ => Type::SpecialForm,
         }
     }
@@ -2220,6 +2374,7 @@ impl Marker {
             StaticStrings::Stdout => f.write_str("<stdout>")?,
             StaticStrings::Stderr => f.write_str("<stderr>")?,
             StaticStrings::UnionType => f.write_str("<class 'typing.Union'>")?,
+            StaticStrings::Missing => f.write_str("<dataclasses._MISSING_TYPE object>")?,
             _ => write!(f, "typing.{s}")?,
         }
         Ok(())
@@ -2507,6 +2662,37 @@ fn str_contains(
     }
 }
 
+/// Helper for containment checks in `bytes` and `bytearray`.
+///
+/// An int item must be a valid byte value and is searched for directly; any
+/// bytes-like item is searched for as a contiguous subsequence.
+fn bytes_contains(
+    container: &[u8],
+    item: &Value,
+    heap: &Heap<impl ResourceTracker>,
+    interns: &Interns,
+) -> RunResult<bool> {
+    let byte = match item {
+        Value::Int(i) => Some(*i),
+        Value::Bool(b) => Some(i64::from(*b)),
+        // Heap `LongInt`s never fit in a byte
+        Value::Ref(id) if matches!(heap.get(*id), HeapData::LongInt(_)) => None,
+        _ => {
+            let Some(needle) = bytes_like_contents(item, heap, interns) else {
+                return Err(ExcType::type_error(format!(
+                    "a bytes-like object is required, not '{}'",
+                    item.py_type_heap(heap)
+                )));
+            };
+            return Ok(needle.is_empty() || container.windows(needle.len()).any(|window| *window == *needle));
+        }
+    };
+    match byte.and_then(|b| u8::try_from(b).ok()) {
+        Some(b) => Ok(container.contains(&b)),
+        None => Err(SimpleException::new_msg(ExcType::ValueError, "byte must be in range(0, 256)").into()),
+    }
+}
+
 /// Computes the number of significant bits in an i64.
 ///
 /// Returns 0 for 0, otherwise returns ceil(log2(|value|)) + 1 (accounting for sign).
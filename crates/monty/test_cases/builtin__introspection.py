@@ -0,0 +1,250 @@
+import math
+from dataclasses import dataclass
+
+
+@dataclass
+class Point:
+    x: int
+    y: int = 0
+
+    def norm2(self):
+        return self.x * self.x + self.y * self.y
+
+
+@dataclass(frozen=True)
+class Frozen:
+    a: int
+
+
+def public(names):
+    return [n for n in names if not n.startswith('_')]
+
+
+# === callable() ===
+def plain():
+    pass
+
+
+def with_default(a=1):
+    return a
+
+
+def make_closure():
+    captured = 1
+
+    def inner():
+        return captured
+
+    return inner
+
+
+assert callable(plain), 'def function is callable'
+assert callable(with_default), 'function with defaults is callable'
+assert callable(make_closure()), 'closure is callable'
+assert callable(lambda: 1), 'lambda is callable'
+assert callable(len), 'builtin function is callable'
+assert callable(int), 'type is callable'
+assert callable(ValueError), 'exception type is callable'
+assert callable(math.sqrt), 'module function is callable'
+assert callable(Point), 'class is callable'
+assert not callable(1), 'int is not callable'
+assert not callable('abc'), 'str is not callable'
+assert not callable([plain]), 'list is not callable'
+assert not callable(Point(1)), 'instance is not callable'
+
+# === issubclass() ===
+assert issubclass(bool, int), 'bool is a subclass of int'
+assert issubclass(int, int), 'a type is a subclass of itself'
+assert not issubclass(int, str), 'int is not a subclass of str'
+assert issubclass(int, (str, int)), 'tuple of classes'
+assert issubclass(int, object), 'every type is a subclass of object'
+assert issubclass(KeyError, LookupError), 'exception hierarchy'
+assert issubclass(ValueError, Exception), 'ValueError is an Exception'
+assert not issubclass(Exception, ValueError), 'base is not a subclass of derived'
+assert issubclass(Point, Point), 'class is a subclass of itself'
+assert issubclass(Point, object), 'class is a subclass of object'
+assert not issubclass(Point, Frozen), 'unrelated classes'
+
+try:
+    issubclass(1, int)
+    assert False, 'issubclass with non-class arg 1 should raise'
+except TypeError as e:
+    assert str(e) == 'issubclass() arg 1 must be a class', f'wrong message: {e}'
+
+try:
+    issubclass(int, 1)
+    assert False, 'issubclass with non-class arg 2 should raise'
+except TypeError as e:
+    assert str(e) == 'issubclass() arg 2 must be a class, a tuple of classes, or a union', f'wrong message: {e}'
+
+# === ascii() ===
+assert ascii('abc') == "'abc'", 'ascii of plain str'
+assert ascii('héllo') == "'h\\xe9llo'", 'ascii escapes latin-1 chars'
+assert ascii('€') == "'\\u20ac'", 'ascii escapes BMP chars'
+assert ascii('😀') == "'\\U0001f600'", 'ascii escapes astral chars'
+assert ascii(['ü', 1]) == "['\\xfc', 1]", 'ascii escapes nested reprs'
+assert ascii(42) == '42', 'ascii of int'
+
+# === delattr() ===
+p = Point(1, 2)
+delattr(p, 'x')
+assert not hasattr(p, 'x'), 'delattr removes the attribute'
+assert p.y == 2, 'delattr leaves other attributes alone'
+try:
+    delattr(p, 'x')
+    assert False, 'deleting a missing attribute should raise'
+except AttributeError as e:
+    assert str(e) == "'Point' object has no attribute 'x'", f'wrong message: {e}'
+
+try:
+    delattr(Frozen(1), 'a')
+    assert False, 'deleting from a frozen dataclass should raise'
+except Exception as e:
+    assert type(e).__name__ == 'FrozenInstanceError', f'wrong type: {type(e)}'
+    assert str(e) == "cannot delete field 'a'", f'wrong message: {e}'
+
+try:
+    delattr(5, 'x')
+    assert False, 'delattr on int should raise'
+except AttributeError as e:
+    assert str(e) == "'int' object has no attribute 'x' and no __dict__ for setting new attributes", (
+        f'wrong message: {e}'
+    )
+
+try:
+    delattr(p, 1)
+    assert False, 'delattr with non-str name should raise'
+except TypeError as e:
+    assert str(e) == "attribute name must be string, not 'int'", f'wrong message: {e}'
+
+# === dir() ===
+assert public(dir(1)) == [
+    'as_integer_ratio',
+    'bit_count',
+    'bit_length',
+    'conjugate',
+    'denominator',
+    'from_bytes',
+    'imag',
+    'is_integer',
+    'numerator',
+    'real',
+    'to_bytes',
+], 'dir of int'
+assert public(dir(())) == ['count', 'index'], 'dir of tuple'
+assert public(dir([])) == public(dir(list)), 'dir of an instance matches dir of its type'
+assert 'append' in dir([]), 'dir of list includes append'
+assert 'keys' in dir({}), 'dir of dict includes keys'
+assert 'startswith' in dir(''), 'dir of str includes startswith'
+assert {'start', 'step', 'stop'} <= set(dir(slice(1))), 'dir of slice'
+assert 'args' in dir(ValueError('x')), 'dir of exception includes args'
+assert public(dir(Point(1))) == ['norm2', 'x', 'y'], 'dir of dataclass instance'
+assert public(dir(Point)) == ['norm2', 'y'], 'dir of class lists methods and defaults'
+assert 'sqrt' in dir(math), 'dir of module lists its attributes'
+assert dir(math) == sorted(dir(math)), 'dir results are sorted'
+
+
+def dir_in_function(a, b):
+    c = a + b
+    return dir()
+
+
+assert dir_in_function(1, 2) == ['a', 'b', 'c'], 'dir() without args lists local names'
+module_level_name = 1
+assert 'module_level_name' in dir(), 'dir() at module level lists globals'
+
+# === globals() and locals() ===
+g = globals()
+assert g['module_level_name'] == 1, 'globals() maps names to values'
+assert 'Point' in g, 'globals() includes classes'
+assert 'plain' in g, 'globals() includes functions'
+assert 'never_assigned_yet' not in g, 'unbound names are not in globals()'
+never_assigned_yet = 1
+
+
+def read_globals():
+    return globals()['module_level_name']
+
+
+assert read_globals() == 1, 'globals() inside a function returns module globals'
+
+
+def local_vars(a, b=2):
+    c = a + b
+    return locals()
+
+
+assert local_vars(1) == {'a': 1, 'b': 2, 'c': 3}, 'locals() in a function'
+
+
+def local_unbound():
+    before = locals()
+    x = 1
+    return before, locals()
+
+
+assert local_unbound() == ({}, {'before': {}, 'x': 1}), 'locals() only includes bound names'
+
+
+def outer_locals():
+    captured = 10
+
+    def inner():
+        seen = captured
+        return locals()
+
+    return inner(), locals()
+
+
+inner_locals, outer_locals_dict = outer_locals()
+assert inner_locals == {'seen': 10, 'captured': 10}, 'locals() includes free variables'
+assert outer_locals_dict['captured'] == 10, 'locals() includes cell variables'
+
+snapshot = locals()
+assert snapshot['module_level_name'] == 1, 'locals() at module level is globals()'
+
+try:
+    globals(1)
+    assert False, 'globals() with an argument should raise'
+except TypeError as e:
+    assert str(e) == 'globals() takes no arguments (1 given)', f'wrong message: {e}'
+
+# === vars() ===
+assert vars(Point(1, 2)) == {'x': 1, 'y': 2}, 'vars of dataclass instance'
+assert vars(math)['pi'] == math.pi, 'vars of module'
+assert local_vars(5) == {'a': 5, 'b': 2, 'c': 7}, 'locals helper still works'
+
+
+def vars_in_function(a):
+    return vars()
+
+
+assert vars_in_function(3) == {'a': 3}, 'vars() without args behaves like locals()'
+
+try:
+    vars(1)
+    assert False, 'vars of int should raise'
+except TypeError as e:
+    assert str(e) == 'vars() argument must have __dict__ attribute', f'wrong message: {e}'
+
+# === object ===
+sentinel = object()
+other = object()
+assert sentinel is sentinel, 'object is identical to itself'
+assert sentinel == sentinel, 'object equals itself'
+assert sentinel != other, 'distinct objects are not equal'
+assert type(sentinel) is object, 'type of object()'
+assert isinstance(sentinel, object), 'object() is an instance of object'
+assert isinstance(1, object), 'everything is an instance of object'
+assert isinstance('s', object), 'str is an instance of object'
+assert hash(sentinel) == hash(sentinel), 'object hash is stable'
+assert {sentinel: 1}[sentinel] == 1, 'object is usable as a dict key'
+assert bool(sentinel), 'object is truthy'
+assert repr(sentinel).startswith('<object object'), 'object repr'
+assert public(dir(sentinel)) == [], 'object has no public attributes'
+
+try:
+    object(1)
+    assert False, 'object() with arguments should raise'
+except TypeError as e:
+    assert str(e) == 'object() takes no arguments', f'wrong message: {e}'
@@ -0,0 +1,65 @@
+# === iter(callable, sentinel) ===
+def make_counter():
+    state = [0]
+
+    def counter():
+        state[0] += 1
+        return state[0]
+
+    return counter
+
+
+assert list(iter(make_counter(), 4)) == [1, 2, 3], 'stops before the sentinel'
+assert list(iter(make_counter(), 1)) == [], 'sentinel on the first call'
+
+items = []
+for value in iter(make_counter(), 3):
+    items.append(value)
+assert items == [1, 2], 'for loop over a callable iterator'
+
+lines = ['a', 'b', '']
+reader = iter(lambda: lines.pop(0), '')
+assert next(reader) == 'a', 'next() calls the callable'
+assert next(reader) == 'b', 'next() calls the callable again'
+assert next(reader, 'done') == 'done', 'sentinel exhausts the iterator'
+assert lines == [], 'callable consumed every line'
+lines.append('late')
+assert next(reader, 'done') == 'done', 'callable is not called once exhausted'
+assert lines == ['late'], 'exhausted iterator does not call the callable'
+
+# sentinel is compared with ==
+calls = iter(make_counter(), 2.0)
+assert list(calls) == [1], 'sentinel uses equality, not identity'
+
+# builtin callables work too
+assert list(iter(list, [])) == [], 'builtin callable returning the sentinel'
+
+# === iterating an existing iterator ===
+it = iter([1, 2, 3, 4])
+assert next(it) == 1, 'first item'
+assert list(it) == [2, 3, 4], 'list() continues from the current position'
+assert list(it) == [], 'iterator is exhausted'
+
+it = iter('abc')
+for ch in it:
+    assert ch == 'a', 'first char'
+    break
+assert [ch for ch in it] == ['b', 'c'], 'iteration shares progress with the iterator'
+
+# === errors ===
+try:
+    iter(1, 2)
+    assert False, 'non-callable first argument should raise'
+except TypeError as e:
+    assert str(e) == 'iter(v, w): v must be callable', f'wrong message: {e}'
+
+
+def failing():
+    raise ValueError('boom')
+
+
+try:
+    list(iter(failing, None))
+    assert False, 'exceptions from the callable should propagate'
+except ValueError as e:
+    assert str(e) == 'boom', f'wrong message: {e}'
@@ -0,0 +1,188 @@
+# === construction ===
+assert bytearray() == bytearray(b''), 'empty bytearray'
+assert bytearray(3) == bytearray(b'\x00\x00\x00'), 'bytearray from int'
+assert bytearray(b'abc') == b'abc', 'bytearray from bytes'
+assert bytearray([104, 105]) == b'hi', 'bytearray from list of ints'
+assert bytearray('hé', 'utf-8') == b'h\xc3\xa9', 'bytearray from str and encoding'
+assert bytearray(bytearray(b'xy')) == b'xy', 'bytearray from bytearray'
+assert bytearray(memoryview(b'mv')) == b'mv', 'bytearray from memoryview'
+assert type(bytearray()) is bytearray, 'type is bytearray'
+assert isinstance(bytearray(), bytearray), 'isinstance bytearray'
+assert not isinstance(bytearray(), bytes), 'bytearray is not bytes'
+
+try:
+    bytearray('x')
+    assert False, 'str without encoding should raise'
+except TypeError as e:
+    assert str(e) == 'string argument without an encoding', str(e)
+
+try:
+    bytearray([256])
+    assert False, 'out of range item should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', str(e)
+
+# === repr and str ===
+assert repr(bytearray(b'ab\x00')) == "bytearray(b'ab\\x00')", 'repr'
+assert str(bytearray()) == "bytearray(b'')", 'str of empty'
+
+# === comparison and truthiness ===
+assert bytearray(b'abc') == bytearray(b'abc'), 'bytearray == bytearray'
+assert b'abc' == bytearray(b'abc'), 'bytes == bytearray'
+assert bytearray(b'abc') != b'abd', 'bytearray != bytes'
+assert bytearray(b'abc') < b'abd', 'bytearray < bytes'
+assert b'b' > bytearray(b'a'), 'bytes > bytearray'
+assert bytearray(b'a') <= bytearray(b'a'), 'bytearray <= bytearray'
+assert not bytearray(), 'empty bytearray is falsy'
+assert bytearray(b'\x00'), 'non-empty bytearray is truthy'
+
+try:
+    hash(bytearray())
+    assert False, 'bytearray should be unhashable'
+except TypeError as e:
+    assert str(e) == "unhashable type: 'bytearray'", str(e)
+
+# === indexing and slicing ===
+ba = bytearray(b'hello')
+assert len(ba) == 5, 'len'
+assert ba[0] == 104, 'index'
+assert ba[-1] == 111, 'negative index'
+assert ba[1:3] == bytearray(b'el'), 'slice'
+assert type(ba[1:3]) is bytearray, 'slice is a bytearray'
+assert ba[::-1] == b'olleh', 'reversed slice'
+assert list(ba) == [104, 101, 108, 108, 111], 'iteration yields ints'
+
+try:
+    ba[5]
+    assert False, 'index past end should raise'
+except IndexError as e:
+    assert str(e) == 'bytearray index out of range', str(e)
+
+# === item and slice assignment ===
+ba = bytearray(b'hello')
+ba[0] = 106
+assert ba == b'jello', 'item assignment'
+ba[-1] = ord('y')
+assert ba == b'jelly', 'negative item assignment'
+ba[1:3] = b'EE'
+assert ba == b'jEEly', 'same-size slice assignment'
+ba[1:3] = b''
+assert ba == b'jly', 'shrinking slice assignment'
+ba[1:1] = bytearray(b'123')
+assert ba == b'j123ly', 'growing slice assignment'
+ba[:] = [65, 66]
+assert ba == b'AB', 'slice assignment from list of ints'
+ba = bytearray(b'abcdef')
+ba[::2] = b'XYZ'
+assert ba == b'XbYdZf', 'extended slice assignment'
+
+try:
+    ba[0] = 256
+    assert False, 'byte out of range should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', str(e)
+
+try:
+    ba[::2] = b'xy'
+    assert False, 'extended slice size mismatch should raise'
+except ValueError as e:
+    assert str(e) == 'attempt to assign bytes of size 2 to extended slice of size 3', str(e)
+
+try:
+    ba[0:1] = 'x'
+    assert False, 'str slice assignment should raise'
+except TypeError as e:
+    assert str(e) == 'can assign only bytes, buffers, or iterables of ints in range(0, 256)', str(e)
+
+# === mutating methods ===
+ba = bytearray(b'ab')
+ba.append(99)
+assert ba == b'abc', 'append'
+ba.extend(b'de')
+assert ba == b'abcde', 'extend with bytes'
+ba.extend([102])
+assert ba == b'abcdef', 'extend with list'
+ba.insert(0, 90)
+assert ba == b'Zabcdef', 'insert at start'
+ba.insert(100, 33)
+assert ba == b'Zabcdef!', 'insert past end appends'
+assert ba.pop() == 33, 'pop last'
+assert ba.pop(0) == 90, 'pop first'
+assert ba == b'abcdef', 'after pops'
+ba.remove(99)
+assert ba == b'abdef', 'remove'
+ba.reverse()
+assert ba == b'fedba', 'reverse'
+copy = ba.copy()
+copy.append(0)
+assert ba == b'fedba', 'copy is independent'
+ba.clear()
+assert ba == b'', 'clear'
+
+try:
+    ba.pop()
+    assert False, 'pop from empty should raise'
+except IndexError as e:
+    assert str(e) == 'pop from empty bytearray', str(e)
+
+try:
+    bytearray(b'a').remove(98)
+    assert False, 'remove missing should raise'
+except ValueError as e:
+    assert str(e) == 'value not found in bytearray', str(e)
+
+try:
+    bytearray().extend('ab')
+    assert False, 'extend with str should raise'
+except TypeError as e:
+    assert str(e) == "expected iterable of integers; got: 'str'", str(e)
+
+# === concatenation and repetition ===
+ba = bytearray(b'ab')
+alias = ba
+ba += b'cd'
+assert alias == b'abcd', 'in-place add mutates the same object'
+assert ba is alias, 'in-place add keeps identity'
+assert type(bytearray(b'a') + b'b') is bytearray, 'bytearray + bytes is a bytearray'
+assert type(b'a' + bytearray(b'b')) is bytes, 'bytes + bytearray is bytes'
+assert bytearray(b'a') + bytearray(b'b') == b'ab', 'bytearray + bytearray'
+assert bytearray(b'ab') * 2 == b'abab', 'repetition'
+assert type(2 * bytearray(b'a')) is bytearray, 'repetition is a bytearray'
+
+# === containment ===
+ba = bytearray(b'hello')
+assert 101 in ba, 'int in bytearray'
+assert 5 not in ba, 'missing int not in bytearray'
+assert b'ell' in ba, 'bytes in bytearray'
+assert bytearray(b'lo') in ba, 'bytearray in bytearray'
+assert bytearray(b'he') in b'hello', 'bytearray in bytes'
+assert b'' in ba, 'empty bytes always found'
+
+try:
+    300 in ba
+    assert False, 'out of range int should raise'
+except ValueError as e:
+    assert str(e) == 'byte must be in range(0, 256)', str(e)
+
+try:
+    'h' in ba
+    assert False, 'str should raise'
+except TypeError as e:
+    assert str(e) == "a bytes-like object is required, not 'str'", str(e)
+
+# === shared bytes methods ===
+ba = bytearray(b'Hello World')
+assert ba.find(b'o') == 4, 'find'
+assert ba.count(b'l') == 3, 'count'
+assert ba.startswith(b'Hell'), 'startswith'
+assert ba.decode() == 'Hello World', 'decode'
+assert ba.upper() == b'HELLO WORLD', 'upper'
+assert type(ba.upper()) is bytearray, 'upper returns a bytearray'
+assert ba.split() == [bytearray(b'Hello'), bytearray(b'World')], 'split'
+assert type(ba.split()[0]) is bytearray, 'split parts are bytearrays'
+assert bytearray(b'-').join([b'a', b'b']) == b'a-b', 'join'
+assert bytearray(b'\x01\xff').hex() == '01ff', 'hex'
+assert bytearray.fromhex('01 ff') == bytearray(b'\x01\xff'), 'fromhex'
+assert type(bytearray.fromhex('00')) is bytearray, 'fromhex returns a bytearray'
+assert bytes(bytearray(b'xy')) == b'xy', 'bytes from bytearray'
+assert type(bytes(bytearray(b'xy'))) is bytes, 'bytes() of bytearray is bytes'
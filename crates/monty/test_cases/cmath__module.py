@@ -0,0 +1,124 @@
+import cmath
+import math
+
+
+def close(a, b):
+    return cmath.isclose(a, b, rel_tol=1e-12, abs_tol=1e-15)
+
+
+# === constants ===
+assert cmath.pi == math.pi, 'pi'
+assert cmath.e == math.e, 'e'
+assert cmath.tau == math.tau, 'tau'
+assert cmath.inf == float('inf'), 'inf'
+assert math.isnan(cmath.nan), 'nan'
+assert cmath.infj == complex(0, float('inf')), 'infj'
+assert repr(cmath.infj) == 'infj', 'repr infj'
+assert repr(cmath.nanj) == 'nanj', 'repr nanj'
+
+# === roots, exponentials and logarithms ===
+assert cmath.sqrt(-1) == 1j, 'sqrt(-1)'
+assert cmath.sqrt(-4 + 0j) == 2j, 'sqrt(-4)'
+assert cmath.sqrt(complex(-4, -0.0)) == -2j, 'sqrt respects the sign of zero'
+assert cmath.sqrt(2) == 1.4142135623730951 + 0j, 'sqrt(2)'
+assert type(cmath.sqrt(4)) is complex, 'sqrt returns complex'
+assert cmath.exp(0) == 1 + 0j, 'exp(0)'
+assert close(cmath.exp(1j * cmath.pi), -1), 'Euler identity'
+assert cmath.log(-1) == cmath.pi * 1j, 'log(-1)'
+assert close(cmath.log(8, 2), 3), 'log with base'
+assert cmath.log(1j, 1j) == 1 + 0j, 'log with complex base'
+assert close(cmath.log10(100j), 2 + 0.6821881769209206j), 'log10'
+
+# === trigonometric ===
+assert close(cmath.sin(1 + 2j), 3.165778513216168 + 1.9596010414216063j), 'sin'
+assert close(cmath.cos(1 + 2j), 2.0327230070196656 - 3.0518977991518j), 'cos'
+assert close(cmath.tan(1 + 2j), 0.0338128260798967 + 1.0147936161466335j), 'tan'
+assert close(cmath.asin(1 + 2j), 0.4270785863924761 + 1.5285709194809982j), 'asin'
+assert close(cmath.acos(1 + 2j), 1.1437177404024204 - 1.5285709194809982j), 'acos'
+assert close(cmath.atan(1 + 2j), 1.3389725222944935 + 0.40235947810852507j), 'atan'
+assert close(cmath.asin(2), 1.5707963267948966 + 1.3169578969248166j), 'asin outside the real domain'
+assert close(cmath.acos(2), -1.3169578969248166j), 'acos outside the real domain'
+
+# === hyperbolic ===
+assert close(cmath.sinh(1 + 2j), -0.4890562590412937 + 1.4031192506220405j), 'sinh'
+assert close(cmath.cosh(1 + 2j), -0.64214812471552 + 1.0686074213827783j), 'cosh'
+assert close(cmath.tanh(1 + 2j), 1.16673625724092 - 0.24345820118572534j), 'tanh'
+assert close(cmath.asinh(1 + 2j), 1.4693517443681852 + 1.063440023577752j), 'asinh'
+assert close(cmath.acosh(1 + 2j), 1.5285709194809982 + 1.1437177404024204j), 'acosh'
+assert close(cmath.atanh(1 + 2j), 0.17328679513998632 + 1.1780972450961724j), 'atanh'
+assert close(cmath.acosh(0.5), 1.0471975511965979j), 'acosh below one'
+assert close(cmath.atanh(2), 0.5493061443340549 + 1.5707963267948966j), 'atanh above one'
+assert cmath.tanh(1000) == 1 + 0j, 'tanh of a large real'
+
+# === polar coordinates ===
+assert cmath.phase(-1) == math.pi, 'phase(-1)'
+assert cmath.phase(complex(-1, -0.0)) == -math.pi, 'phase below the branch cut'
+assert cmath.polar(1j) == (1.0, math.pi / 2), 'polar'
+assert close(cmath.rect(2, math.pi / 2), 2j), 'rect'
+assert cmath.rect(1, 0) == 1 + 0j, 'rect with zero phase'
+
+# === classification ===
+assert cmath.isfinite(1 + 2j), 'isfinite'
+assert not cmath.isfinite(cmath.infj), 'isfinite(infj)'
+assert cmath.isinf(complex(1, float('inf'))), 'isinf'
+assert not cmath.isinf(1j), 'isinf(1j)'
+assert cmath.isnan(cmath.nanj), 'isnan'
+assert not cmath.isnan(1), 'isnan(1)'
+assert cmath.isclose(1 + 1j, 1 + 1.0000000001j), 'isclose default tolerance'
+assert not cmath.isclose(1j, 1.1j), 'isclose different values'
+assert cmath.isclose(1j, 1.1j, abs_tol=0.2), 'isclose abs_tol'
+
+# === errors ===
+try:
+    cmath.log(0)
+    assert False, 'log(0) should raise'
+except ValueError as e:
+    assert str(e) == 'math domain error', str(e)
+
+try:
+    cmath.log(2, 1)
+    assert False, 'log with base 1 should raise'
+except ValueError as e:
+    assert str(e) == 'math domain error', str(e)
+
+try:
+    cmath.atanh(1)
+    assert False, 'atanh(1) should raise'
+except ValueError as e:
+    assert str(e) == 'math domain error', str(e)
+
+try:
+    cmath.atan(1j)
+    assert False, 'atan(1j) should raise'
+except ValueError as e:
+    assert str(e) == 'math domain error', str(e)
+
+try:
+    cmath.exp(1000)
+    assert False, 'exp overflow should raise'
+except OverflowError as e:
+    assert str(e) == 'math range error', str(e)
+
+try:
+    cmath.sinh(1000)
+    assert False, 'sinh overflow should raise'
+except OverflowError as e:
+    assert str(e) == 'math range error', str(e)
+
+try:
+    cmath.sqrt('a')
+    assert False, 'str should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not str', str(e)
+
+try:
+    cmath.rect(1j, 0)
+    assert False, 'complex modulus should raise'
+except TypeError as e:
+    assert str(e) == 'must be real number, not complex', str(e)
+
+try:
+    cmath.isclose(1j, 1j, rel_tol=-1)
+    assert False, 'negative tolerance should raise'
+except ValueError as e:
+    assert str(e) == 'tolerances must be non-negative', str(e)
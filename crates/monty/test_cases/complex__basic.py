@@ -0,0 +1,173 @@
+# === literals and construction ===
+assert 1j * 1j == -1, 'j squared is -1'
+assert type(1j) is complex, 'type of imaginary literal'
+assert isinstance(2 + 3j, complex), 'isinstance complex'
+assert complex() == 0j, 'complex() is zero'
+assert complex(1, 2) == 1 + 2j, 'complex(real, imag)'
+assert complex(real=1, imag=2) == 1 + 2j, 'complex keyword args'
+assert complex(imag=2) == 2j, 'complex imag only'
+assert complex(True) == 1 + 0j, 'complex from bool'
+assert complex(1.5) == 1.5 + 0j, 'complex from float'
+assert complex(1j, 1j) == -1 + 1j, 'complex from two complexes'
+assert complex(1 + 2j) == 1 + 2j, 'complex from complex'
+
+# === parsing strings ===
+assert complex('1+2j') == 1 + 2j, 'parse a+bj'
+assert complex('  (1+2j) ') == 1 + 2j, 'parse parenthesised with spaces'
+assert complex(' 1e3-2.5J ') == 1000 - 2.5j, 'parse exponent and upper J'
+assert complex('j') == 1j, 'parse bare j'
+assert complex('-j') == -1j, 'parse bare -j'
+assert complex('3') == 3 + 0j, 'parse real only'
+assert complex('-4.5j') == -4.5j, 'parse imaginary only'
+
+try:
+    complex('x')
+    assert False, 'malformed string should raise'
+except ValueError as e:
+    assert str(e) == 'complex() arg is a malformed string', str(e)
+
+try:
+    complex('(1+2j')
+    assert False, 'unbalanced parenthesis should raise'
+except ValueError as e:
+    assert str(e) == 'complex() arg is a malformed string', str(e)
+
+try:
+    complex('1', 2)
+    assert False, 'string with second arg should raise'
+except TypeError as e:
+    assert str(e) == "complex() can't take second arg if first is a string", str(e)
+
+try:
+    complex(1, '2')
+    assert False, 'string second arg should raise'
+except TypeError as e:
+    assert str(e) == "complex() second arg can't be a string", str(e)
+
+try:
+    complex([])
+    assert False, 'list should raise'
+except TypeError as e:
+    assert str(e) == "complex() first argument must be a string or a number, not 'list'", str(e)
+
+try:
+    complex(1, [])
+    assert False, 'list second arg should raise'
+except TypeError as e:
+    assert str(e) == "complex() second argument must be a number, not 'list'", str(e)
+
+# === repr and str ===
+assert repr(1j) == '1j', 'repr pure imaginary'
+assert repr(1 + 2j) == '(1+2j)', 'repr with real part'
+assert repr(1.5 - 2.5j) == '(1.5-2.5j)', 'repr negative imaginary'
+assert repr(-1j) == '(-0-1j)', 'repr of negated 1j'
+assert repr(complex(0.0, -1)) == '-1j', 'repr negative imaginary with zero real'
+assert repr(complex(-0.0, 0.0)) == '(-0+0j)', 'repr negative zero real'
+assert repr(1e20j) == '1e+20j', 'repr large imaginary'
+assert repr(1.5e-7j) == '1.5e-07j', 'repr small imaginary'
+assert repr(complex('nan+infj')) == '(nan+infj)', 'repr non-finite'
+assert str(1 + 2j) == '(1+2j)', 'str matches repr'
+assert f'{1 + 2j}' == '(1+2j)', 'f-string'
+assert repr([1j, 2 + 0j]) == '[1j, (2+0j)]', 'repr inside list'
+
+# === attributes and methods ===
+z = 3 + 4j
+assert z.real == 3.0, 'real'
+assert z.imag == 4.0, 'imag'
+assert type(z.real) is float, 'real is a float'
+assert z.conjugate() == 3 - 4j, 'conjugate'
+assert 1j.conjugate() == -1j, 'conjugate of literal'
+assert abs(z) == 5.0, 'abs'
+
+# === arithmetic ===
+assert (1 + 2j) + (3 + 4j) == 4 + 6j, 'add'
+assert (1 + 2j) - (3 + 4j) == -2 - 2j, 'sub'
+assert (1 + 2j) * (3 + 4j) == -5 + 10j, 'mul'
+assert (1 + 2j) / (1 + 2j) == 1, 'div'
+assert (4 + 2j) / 2 == 2 + 1j, 'div by int'
+assert 1 / 1j == -1j, 'int div by complex'
+assert 2 + 1j == 1j + 2, 'int + complex commutes'
+assert 1.5 * 2j == 3j, 'float * complex'
+assert True + 1j == 1 + 1j, 'bool + complex'
+assert -(1 + 2j) == -1 - 2j, 'negation'
+assert +(1 + 2j) == 1 + 2j, 'unary plus'
+assert sum([1j, 2, 0.5]) == 2.5 + 1j, 'sum mixes numbers'
+
+z = 1j
+z += 1
+z *= 2
+assert z == 2 + 2j, 'inplace operators'
+
+# === powers ===
+assert (1 + 2j) ** 2 == -3 + 4j, 'integer power'
+assert (1 + 1j) ** -2 == -0.5j, 'negative integer power'
+assert 1j**0 == 1, 'zero power'
+assert 2 ** (1j) == 0.7692389013639721 + 0.6389612763136348j, 'int to complex power'
+assert pow(1j, 2) == -1, 'pow builtin'
+
+try:
+    0j**-1
+    assert False, 'zero to negative power should raise'
+except ZeroDivisionError as e:
+    assert 'to a negative or complex power' in str(e), str(e)
+
+try:
+    pow(1j, 2, 3)
+    assert False, 'three-argument pow should raise'
+except ValueError as e:
+    assert str(e) == 'complex modulo', str(e)
+
+try:
+    (1 + 2j) / 0
+    assert False, 'division by zero should raise'
+except ZeroDivisionError as e:
+    assert str(e) == 'complex division by zero', str(e)
+
+# === unsupported operations ===
+try:
+    1j // 1
+    assert False, 'floor division should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for //: 'complex' and 'int'", str(e)
+
+try:
+    1j % 2
+    assert False, 'modulo should raise'
+except TypeError as e:
+    assert str(e) == "unsupported operand type(s) for %: 'complex' and 'int'", str(e)
+
+try:
+    1j < 2
+    assert False, 'ordering should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'complex' and 'int'", str(e)
+
+try:
+    round(1j)
+    assert False, 'round should raise'
+except TypeError as e:
+    assert str(e) == "type complex doesn't define __round__ method", str(e)
+
+try:
+    int(1j)
+    assert False, 'int() should raise'
+except TypeError as e:
+    assert str(e) == "int() argument must be a string, a bytes-like object or a real number, not 'complex'", str(e)
+
+try:
+    float(1j)
+    assert False, 'float() should raise'
+except TypeError as e:
+    assert str(e) == "float() argument must be a string or a real number, not 'complex'", str(e)
+
+# === equality, truthiness and hashing ===
+assert 2 + 0j == 2, 'complex equals int'
+assert 2.5 + 0j == 2.5, 'complex equals float'
+assert 1j != 1, 'imaginary is not real'
+assert not 0j, 'zero is falsy'
+assert 1j, 'nonzero is truthy'
+assert hash(1j) == 1000003, 'hash of 1j'
+assert hash(2 + 0j) == hash(2), 'hash matches int when imag is zero'
+assert hash(1.5 + 0j) == hash(1.5), 'hash matches float when imag is zero'
+assert {1j: 'a'}[1j] == 'a', 'dict key'
+assert len({1 + 0j, 1, 1.0}) == 1, 'set dedupes equal numbers'
@@ -0,0 +1,194 @@
+import contextlib
+from contextlib import ExitStack, closing, nullcontext, redirect_stdout, suppress
+from dataclasses import dataclass, field
+
+# === suppress ===
+with suppress(ZeroDivisionError):
+    1 / 0
+    assert False, 'body stops at the exception'
+
+with suppress(KeyError, IndexError):
+    [][0]
+
+with suppress(LookupError):
+    {}['missing']
+
+with suppress():
+    pass
+
+try:
+    with suppress(KeyError):
+        1 / 0
+    assert False, 'unmatched exception should propagate'
+except ZeroDivisionError:
+    pass
+
+with suppress(ValueError) as value:
+    pass
+assert value is None, 'suppress enters as None'
+
+# === nullcontext ===
+with nullcontext() as value:
+    assert value is None, 'nullcontext defaults to None'
+
+with nullcontext(42) as value:
+    assert value == 42, 'nullcontext returns enter_result'
+
+with nullcontext(enter_result='x') as value:
+    assert value == 'x', 'enter_result keyword'
+
+try:
+    with nullcontext():
+        raise ValueError('boom')
+    assert False, 'nullcontext does not suppress'
+except ValueError as e:
+    assert str(e) == 'boom', str(e)
+
+
+# === closing ===
+@dataclass
+class Resource:
+    name: str
+    closed: bool = False
+
+    def close(self):
+        self.closed = True
+
+
+res = Resource('r')
+with closing(res) as value:
+    assert value is res, 'closing returns the wrapped object'
+    assert not res.closed, 'not closed inside the block'
+assert res.closed, 'closed after the block'
+
+res = Resource('r2')
+try:
+    with closing(res):
+        raise KeyError('k')
+except KeyError:
+    pass
+assert res.closed, 'closed when the body raises'
+
+
+# === redirect_stdout ===
+@dataclass
+class Capture:
+    chunks: list = field(default_factory=list)
+
+    def write(self, text):
+        self.chunks.append(text)
+        return len(text)
+
+
+capture = Capture()
+with redirect_stdout(capture) as target:
+    assert target is capture, 'redirect_stdout returns the target'
+    print('hello', 'world')
+    print('no newline', end='')
+assert ''.join(capture.chunks) == 'hello world\nno newline', capture.chunks
+
+outer = Capture()
+inner = Capture()
+with redirect_stdout(outer):
+    print('a')
+    with redirect_stdout(inner):
+        print('b')
+    print('c')
+assert ''.join(outer.chunks) == 'a\nc\n', 'nested redirects restore the outer target'
+assert ''.join(inner.chunks) == 'b\n', 'inner redirect captures its own output'
+
+# === ExitStack ===
+log = []
+
+
+def record(*args, **kwargs):
+    log.append((args, kwargs))
+
+
+with ExitStack() as stack:
+    assert isinstance(stack, ExitStack), 'ExitStack enters as itself'
+    first = stack.enter_context(nullcontext(1))
+    second = stack.enter_context(closing(Resource('s')))
+    assert first == 1, 'enter_context returns the enter result'
+    assert second.name == 's', 'enter_context returns closing target'
+    returned = stack.callback(record, 1, 2, key='v')
+    assert returned is record, 'callback returns the function'
+    stack.callback(record, 'last')
+    assert log == [], 'callbacks not run yet'
+assert log == [(('last',), {}), ((1, 2), {'key': 'v'})], 'callbacks run in LIFO order'
+assert second.closed, 'entered context managers are exited'
+
+# pop_all transfers callbacks
+log = []
+with ExitStack() as stack:
+    stack.callback(record, 'moved')
+    moved = stack.pop_all()
+assert log == [], 'pop_all leaves the original stack empty'
+moved.close()
+assert log == [(('moved',), {})], 'close runs the transferred callbacks'
+moved.close()
+assert log == [(('moved',), {})], 'close is idempotent'
+
+
+# push with an exit function that suppresses
+def swallow(exc_type, exc, tb):
+    log.append(exc_type)
+    return True
+
+
+log = []
+with ExitStack() as stack:
+    stack.push(swallow)
+    raise ValueError('hidden')
+assert log == [ValueError], 'exit function sees the exception type'
+
+# push with a context manager only registers its exit
+log = []
+with ExitStack() as stack:
+    stack.push(suppress(KeyError))
+    raise KeyError('x')
+
+# exit function sees None when there is no exception
+log = []
+with ExitStack() as stack:
+    stack.push(swallow)
+assert log == [None], 'exit function sees None without an exception'
+
+# suppression stops the exception for earlier callbacks
+log = []
+with ExitStack() as stack:
+    stack.callback(record, 'outer')
+    stack.push(swallow)
+    raise IndexError
+assert log == [IndexError, (('outer',), {})], log
+
+
+# a raising callback replaces the exception seen by earlier ones
+def explode():
+    raise RuntimeError('from callback')
+
+
+log = []
+with ExitStack() as stack:
+    stack.push(swallow)
+    stack.callback(explode)
+assert log == [RuntimeError], 'earlier exit function sees the callback error'
+
+try:
+    with ExitStack() as stack:
+        stack.callback(explode)
+    assert False, 'callback error should propagate'
+except RuntimeError as e:
+    assert str(e) == 'from callback', str(e)
+
+try:
+    ExitStack().enter_context(1)
+    assert False, 'non context manager should raise'
+except TypeError as e:
+    assert str(e) == "'builtins.int' object does not support the context manager protocol", str(e)
+
+# === module attributes and types ===
+assert contextlib.suppress is suppress, 'module attribute'
+assert isinstance(suppress(), suppress), 'isinstance with suppress'
+assert type(nullcontext()) is nullcontext, 'type of nullcontext'
+assert isinstance(ExitStack(), contextlib.ExitStack), 'isinstance with ExitStack'
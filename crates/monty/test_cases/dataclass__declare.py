@@ -0,0 +1,282 @@
+from dataclasses import (
+    MISSING,
+    FrozenInstanceError,
+    asdict,
+    astuple,
+    dataclass,
+    field,
+    fields,
+    is_dataclass,
+    replace,
+)
+
+
+# === Basic declaration ===
+@dataclass
+class Point:
+    x: int
+    y: int = 0
+
+    def total(self):
+        return self.x + self.y
+
+    def scaled(self, factor):
+        return Point(self.x * factor, self.y * factor)
+
+
+p = Point(1, 2)
+assert repr(p) == 'Point(x=1, y=2)', 'generated repr'
+assert str(p) == 'Point(x=1, y=2)', 'str uses the generated repr'
+assert p.x == 1 and p.y == 2, 'fields set from positional args'
+assert Point(3) == Point(3, 0), 'default fills in missing arg'
+assert Point(y=5, x=4) == Point(4, 5), 'keyword args'
+assert Point(1, 2) != Point(2, 1), 'eq compares fields'
+assert p.total() == 3, 'method call'
+assert p.scaled(10) == Point(10, 20), 'method returning a new instance'
+assert Point.total(p) == 3, 'method called through the class'
+assert Point.y == 0, 'class attribute holds the default'
+assert Point.__name__ == 'Point', 'class __name__'
+assert Point.__annotations__ == {'x': int, 'y': int}, 'class __annotations__'
+
+# === type() and isinstance() ===
+assert type(p) is Point, 'type of instance is its class'
+assert isinstance(p, Point), 'isinstance with the class'
+assert isinstance(p, (int, Point)), 'isinstance with a tuple containing the class'
+assert not isinstance(1, Point), 'isinstance with another type'
+
+
+@dataclass
+class Other:
+    x: int
+    y: int = 0
+
+
+assert Other(1, 2) != Point(1, 2), 'instances of different classes are not equal'
+assert not isinstance(Other(1), Point), 'isinstance distinguishes classes'
+
+# === Mutation ===
+p.x = 10
+assert p == Point(10, 2), 'fields can be reassigned'
+
+# === __init__ errors ===
+try:
+    Point()
+    assert False, 'missing argument should raise'
+except TypeError as e:
+    assert str(e) == "Point.__init__() missing 1 required positional argument: 'x'", str(e)
+
+try:
+    Point(1, 2, 3)
+    assert False, 'too many arguments should raise'
+except TypeError as e:
+    assert str(e) == 'Point.__init__() takes from 2 to 3 positional arguments but 4 were given', str(e)
+
+try:
+    Point(1, z=3)
+    assert False, 'unknown keyword should raise'
+except TypeError as e:
+    assert str(e) == "Point.__init__() got an unexpected keyword argument 'z'", str(e)
+
+try:
+    Point(1, x=1)
+    assert False, 'duplicate argument should raise'
+except TypeError as e:
+    assert str(e) == "Point.__init__() got multiple values for argument 'x'", str(e)
+
+
+# === field() and default_factory ===
+@dataclass
+class Inventory:
+    name: str
+    items: list = field(default_factory=list)
+    count: int = field(default=0, repr=False)
+    secret: str = field(default='', compare=False)
+
+
+a = Inventory('a')
+b = Inventory('b')
+a.items.append(1)
+assert a.items == [1] and b.items == [], 'each instance gets its own list'
+assert repr(a) == "Inventory(name='a', items=[1], secret='')", 'repr=False fields are hidden'
+assert Inventory('x', secret='s') == Inventory('x', secret='t'), 'compare=False fields are ignored by eq'
+
+try:
+
+    @dataclass
+    class Bad:
+        items: list = []
+
+    assert False, 'mutable default should raise'
+except ValueError as e:
+    assert str(e) == 'mutable default <class \'list\'> for field items is not allowed: use default_factory', str(e)
+
+try:
+    field(default=1, default_factory=list)
+    assert False, 'default and default_factory should raise'
+except ValueError as e:
+    assert str(e) == 'cannot specify both default and default_factory', str(e)
+
+try:
+
+    @dataclass
+    class BadOrder:
+        x: int = 0
+        y: int
+
+    assert False, 'non-default after default should raise'
+except TypeError as e:
+    assert str(e).startswith("non-default argument 'y' follows default argument"), str(e)
+
+
+# === frozen ===
+@dataclass(frozen=True)
+class Frozen:
+    a: int
+    b: str = 'b'
+
+
+f = Frozen(1)
+try:
+    f.a = 2
+    assert False, 'frozen instances reject assignment'
+except FrozenInstanceError as e:
+    assert str(e) == "cannot assign to field 'a'", str(e)
+assert hash(f) == hash(Frozen(1)), 'equal frozen instances hash equally'
+assert len({f, Frozen(1), Frozen(2)}) == 2, 'frozen instances work in sets'
+
+
+# === order ===
+@dataclass(order=True)
+class Version:
+    major: int
+    minor: int = 0
+
+
+assert Version(1, 2) < Version(1, 3), 'lt compares fields like a tuple'
+assert Version(2) > Version(1, 9), 'gt'
+assert Version(1) <= Version(1, 0), 'le'
+assert sorted([Version(2), Version(1, 5), Version(1)]) == [Version(1), Version(1, 5), Version(2)], 'sorted'
+
+try:
+
+    @dataclass(order=True, eq=False)
+    class Unordered:
+        x: int
+
+    assert False, 'order without eq should raise'
+except ValueError as e:
+    assert str(e) == 'eq must be true if order is true', str(e)
+
+
+@dataclass
+class Plain:
+    x: int
+
+
+try:
+    Plain(1) < Plain(2)
+    assert False, 'ordering without order=True should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'Plain' and 'Plain'", str(e)
+
+try:
+    Version(1) >= 1
+    assert False, 'ordering against another type should raise'
+except TypeError as e:
+    assert str(e) == "'>=' not supported between instances of 'Version' and 'int'", str(e)
+
+try:
+    Version(1) < Plain(1)
+    assert False, 'ordering against another class should raise'
+except TypeError as e:
+    assert str(e) == "'<' not supported between instances of 'Version' and 'Plain'", str(e)
+
+
+# === kw_only ===
+@dataclass(kw_only=True)
+class Options:
+    verbose: bool
+    level: int = 1
+
+
+assert Options(verbose=True) == Options(level=1, verbose=True), 'keyword-only fields'
+try:
+    Options(True)
+    assert False, 'positional arg for keyword-only field should raise'
+except TypeError as e:
+    assert str(e) == 'Options.__init__() takes 1 positional argument but 2 were given', str(e)
+
+
+@dataclass
+class Mixed:
+    a: int = 0
+    b: int = field(kw_only=True)
+
+
+assert Mixed(1, b=2) == Mixed(a=1, b=2), 'field-level kw_only'
+
+
+# === __post_init__ and init=False ===
+@dataclass
+class Rect:
+    width: int
+    height: int
+    area: int = field(init=False, default=0)
+
+    def __post_init__(self):
+        self.area = self.width * self.height
+
+
+r = Rect(2, 3)
+assert r.area == 6, '__post_init__ runs after init'
+assert repr(r) == 'Rect(width=2, height=3, area=6)', 'init=False fields are in repr'
+
+# === fields() ===
+names = [f.name for f in fields(Point)]
+assert names == ['x', 'y'], 'fields of the class'
+assert [f.name for f in fields(p)] == ['x', 'y'], 'fields of an instance'
+x_field = fields(Point)[0]
+assert x_field.type is int, 'field type'
+assert x_field.default is MISSING, 'no default is MISSING'
+assert fields(Point)[1].default == 0, 'field default'
+assert fields(Inventory)[1].default_factory is list, 'field default_factory'
+assert fields(Inventory)[2].repr is False, 'field repr flag'
+
+# === asdict / astuple ===
+
+
+@dataclass
+class Line:
+    start: Point
+    end: Point
+    tags: list = field(default_factory=list)
+
+
+line = Line(Point(0, 0), Point(1, 1), [Point(2, 2)])
+assert asdict(line) == {
+    'start': {'x': 0, 'y': 0},
+    'end': {'x': 1, 'y': 1},
+    'tags': [{'x': 2, 'y': 2}],
+}, 'asdict recurses'
+assert astuple(line) == ((0, 0), (1, 1), [(2, 2)]), 'astuple recurses'
+try:
+    asdict(Point)
+    assert False, 'asdict on a class should raise'
+except TypeError as e:
+    assert str(e) == 'asdict() should be called on dataclass instances', str(e)
+
+# === replace ===
+q = replace(Point(1, 2), y=5)
+assert q == Point(1, 5), 'replace changes one field'
+r2 = replace(r, width=10)
+assert r2.area == 30, 'replace runs __post_init__'
+try:
+    replace(r, area=1)
+    assert False, 'replacing an init=False field should raise'
+except TypeError as e:
+    assert str(e) == 'field area is declared with init=False, it cannot be specified with replace()', str(e)
+
+# === is_dataclass ===
+assert is_dataclass(Point), 'class is a dataclass'
+assert is_dataclass(p), 'instance is a dataclass'
+assert not is_dataclass(1), 'int is not a dataclass'
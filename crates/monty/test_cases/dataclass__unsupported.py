@@ -0,0 +1,54 @@
+# xfail=cpython
+# class body dunders and repr=False would be silently ignored, so they're rejected
+from dataclasses import dataclass
+
+try:
+
+    @dataclass
+    class CustomRepr:
+        x: int
+
+        def __repr__(self):
+            return 'custom'
+
+    assert False, 'dunder methods should raise'
+except NotImplementedError as e:
+    assert str(e) == 'defining __repr__ in a dataclass body is not supported', str(e)
+
+try:
+
+    @dataclass
+    class CustomOrder:
+        x: int
+
+        def __lt__(self, other):
+            return True
+
+    assert False, 'ordering dunders should raise'
+except NotImplementedError as e:
+    assert str(e) == 'defining __lt__ in a dataclass body is not supported', str(e)
+
+try:
+
+    @dataclass(repr=False)
+    class NoRepr:
+        x: int
+
+    assert False, 'repr=False should raise'
+except NotImplementedError as e:
+    assert str(e) == 'dataclass(repr=False) is not supported', str(e)
+
+
+# __post_init__ and ordinary methods are still allowed
+@dataclass
+class Point:
+    x: int
+
+    def __post_init__(self):
+        self.x = self.x * 2
+
+    def double(self):
+        return self.x * 2
+
+
+assert Point(1).double() == 4, 'regular methods still work'
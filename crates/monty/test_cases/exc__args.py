@@ -14,3 +14,42 @@ try:
     raise TypeError('type error')
 except TypeError as e:
     assert e.args[0] == 'type error', 'works for other exception types'
+
+# === multiple and non-string arguments ===
+e = ValueError('bad', 42)
+assert e.args == ('bad', 42), 'args keeps every argument'
+assert str(e) == "('bad', 42)", 'str of multi-arg exception is the args tuple'
+assert repr(e) == "ValueError('bad', 42)", 'repr lists every argument'
+
+e = Exception(1)
+assert e.args == (1,), 'non-string argument is kept as-is'
+assert str(e) == '1', 'str of a single argument'
+assert repr(e) == 'Exception(1)', 'repr of a non-string argument'
+
+e = RuntimeError(None, [1, 2], (3,))
+assert e.args == (None, [1, 2], (3,)), 'containers survive in args'
+
+e = KeyError(1)
+assert e.args == (1,), 'KeyError keeps its key'
+assert str(e) == '1', 'KeyError str is the key repr'
+assert str(KeyError('k')) == "'k'", 'KeyError str quotes a string key'
+
+try:
+    {}[(1, 'a')]
+except KeyError as e:
+    assert e.args == ((1, 'a'),), 'missing-key KeyError carries the key'
+
+try:
+    raise ValueError('a', 'b')
+except ValueError as e:
+    assert e.args[1] == 'b', 'multi-arg exception survives raise'
+
+# === StopIteration.value ===
+assert StopIteration(5).value == 5, 'value is the first argument'
+assert StopIteration().value is None, 'value defaults to None'
+
+try:
+    ValueError(msg='x')
+    assert False, 'expected TypeError'
+except TypeError as e:
+    assert str(e) == 'ValueError() takes no keyword arguments', f'unexpected message: {e}'
@@ -0,0 +1,26 @@
+# === add_note / __notes__ ===
+e = ValueError('bad')
+try:
+    e.__notes__
+    assert False, 'expected AttributeError'
+except AttributeError:
+    pass
+
+assert e.add_note('first') is None, 'add_note returns None'
+e.add_note('second')
+assert e.__notes__ == ['first', 'second'], 'notes are kept in order'
+
+try:
+    e.add_note(1)
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == "note must be a str, not 'int'", f'unexpected message: {exc}'
+
+try:
+    try:
+        raise KeyError('k')
+    except KeyError as inner:
+        inner.add_note('while looking up k')
+        raise
+except KeyError as outer:
+    assert outer.__notes__ == ['while looking up k'], 'notes survive a re-raise'
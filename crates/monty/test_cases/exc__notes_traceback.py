@@ -0,0 +1,13 @@
+e = ValueError('bad value')
+e.add_note('first note')
+e.add_note('second note')
+raise e
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "exc__notes_traceback.py", line 4, in <module>
+    raise e
+ValueError: bad value
+first note
+second note
+"""
@@ -0,0 +1,40 @@
+# === OSError attributes ===
+e = OSError('plain message')
+assert e.errno is None, 'single-argument OSError has no errno'
+assert e.strerror is None, 'single-argument OSError has no strerror'
+assert e.filename is None, 'single-argument OSError has no filename'
+assert str(e) == 'plain message', 'single argument is the message'
+
+e = OSError(5, 'Input/output error')
+assert type(e) is OSError, 'errno without a subclass stays OSError'
+assert e.errno == 5, 'errno'
+assert e.strerror == 'Input/output error', 'strerror'
+assert e.filename is None, 'no filename'
+assert str(e) == '[Errno 5] Input/output error', 'errno message'
+
+e = OSError(2, 'No such file or directory', 'missing.txt')
+assert type(e) is FileNotFoundError, 'errno 2 maps to FileNotFoundError'
+assert isinstance(e, OSError), 'subclass is still an OSError'
+assert e.args == (2, 'No such file or directory'), 'filename is not part of args'
+assert e.filename == 'missing.txt', 'filename'
+assert str(e) == "[Errno 2] No such file or directory: 'missing.txt'", 'message includes filename'
+assert repr(e) == "FileNotFoundError(2, 'No such file or directory')", 'repr shows args only'
+
+e = OSError(18, 'Invalid cross-device link', 'a', None, 'b')
+assert e.filename == 'a' and e.filename2 == 'b', 'filename2 is the fifth argument'
+assert str(e) == "[Errno 18] Invalid cross-device link: 'a' -> 'b'", 'message includes both filenames'
+
+assert type(OSError(13, 'Permission denied')) is PermissionError, 'errno 13 maps to PermissionError'
+assert type(OSError(17, 'File exists')) is FileExistsError, 'errno 17 maps to FileExistsError'
+assert type(OSError(21, 'Is a directory')) is IsADirectoryError, 'errno 21 maps to IsADirectoryError'
+assert type(FileNotFoundError(13, 'x')) is FileNotFoundError, 'only plain OSError is remapped'
+
+e = OSError(1, 2, 3, 4, 5, 6)
+assert e.args == (1, 2, 3, 4, 5, 6), 'more than five arguments are kept as args'
+assert e.errno is None, 'more than five arguments set no errno'
+
+try:
+    raise OSError(2, 'No such file or directory', 'x.txt')
+except FileNotFoundError as e:
+    assert e.errno == 2, 'attributes survive raise'
+    assert e.filename == 'x.txt', 'filename survives raise'
@@ -0,0 +1,89 @@
+# === is_integer ===
+assert (3.0).is_integer(), 'integral float'
+assert not (3.5).is_integer(), 'fractional float'
+assert not float('inf').is_integer(), 'inf is not an integer'
+assert not float('nan').is_integer(), 'nan is not an integer'
+assert (-0.0).is_integer(), 'negative zero'
+
+# === as_integer_ratio ===
+assert (0.5).as_integer_ratio() == (1, 2), 'half'
+assert (-0.75).as_integer_ratio() == (-3, 4), 'negative ratio'
+assert (3.0).as_integer_ratio() == (3, 1), 'integral ratio'
+assert (0.0).as_integer_ratio() == (0, 1), 'zero ratio'
+assert (0.1).as_integer_ratio() == (3602879701896397, 36028797018963968), 'inexact decimal'
+assert (1e300).as_integer_ratio()[1] == 1, 'large float has denominator 1'
+assert (5e-324).as_integer_ratio() == (1, 2**1074), 'smallest subnormal'
+n, d = (2.5).as_integer_ratio()
+assert n / d == 2.5, 'ratio round trips'
+try:
+    float('inf').as_integer_ratio()
+    assert False, 'inf should raise'
+except OverflowError as e:
+    assert str(e) == 'cannot convert Infinity to integer ratio', str(e)
+try:
+    float('nan').as_integer_ratio()
+    assert False, 'nan should raise'
+except ValueError as e:
+    assert str(e) == 'cannot convert NaN to integer ratio', str(e)
+
+# === hex ===
+assert (1.0).hex() == '0x1.0000000000000p+0', 'one'
+assert (1.5).hex() == '0x1.8000000000000p+0', 'one and a half'
+assert (-0.1).hex() == '-0x1.999999999999ap-4', 'negative tenth'
+assert (0.0).hex() == '0x0.0p+0', 'zero'
+assert (-0.0).hex() == '-0x0.0p+0', 'negative zero'
+assert (5e-324).hex() == '0x0.0000000000001p-1022', 'subnormal'
+assert (1e308).hex() == '0x1.1ccf385ebc8a0p+1023', 'large'
+assert float('inf').hex() == 'inf', 'inf'
+assert float('-inf').hex() == '-inf', 'negative inf'
+assert float('nan').hex() == 'nan', 'nan'
+
+# === fromhex ===
+assert float.fromhex('0x1.8p1') == 3.0, 'basic fromhex'
+assert float.fromhex('  -0X1.8P+1  ') == -3.0, 'sign, case and whitespace'
+assert float.fromhex('1e5') == 485.0, 'prefix is optional'
+assert float.fromhex('0x.8') == 0.5, 'no integer digits'
+assert float.fromhex('0x10') == 16.0, 'no fraction'
+assert float.fromhex('inf') == float('inf'), 'inf'
+assert float.fromhex('-Infinity') == float('-inf'), 'negative infinity'
+assert float.fromhex('nan') != float.fromhex('nan'), 'nan'
+assert str(float.fromhex('-0x0p0')) == '-0.0', 'negative zero'
+assert float.fromhex('0x1p-1074') == 5e-324, 'smallest subnormal'
+assert float.fromhex('0x1p-1075') == 0.0, 'half the smallest subnormal rounds to even'
+assert float.fromhex('0x1.0000000000001p-1075') == 5e-324, 'just above half rounds up'
+assert float.fromhex('0x1.00000000000008p0') == 1.0, 'ties round to even'
+assert float.fromhex('0x1.00000000000018p0') == 1.0000000000000004, 'ties round to even upwards'
+assert float.fromhex('0x1.fffffffffffffp+1023') == 1.7976931348623157e308, 'largest float'
+for value in [0.1, -2.5, 1e-310, 123456.789, 1e308]:
+    assert float.fromhex(value.hex()) == value, f'round trip {value}'
+try:
+    float.fromhex('0x1p1024')
+    assert False, 'overflow should raise'
+except OverflowError as e:
+    assert str(e) == 'hexadecimal value too large to represent as a float', str(e)
+try:
+    float.fromhex('0x1.fffffffffffff8p+1023')
+    assert False, 'rounding up past the largest float should raise'
+except OverflowError:
+    pass
+for bad in ['', '0x', '1.2.3', '0x1p', 'xyz', '0x1_0']:
+    try:
+        float.fromhex(bad)
+        assert False, f'{bad!r} should raise'
+    except ValueError as e:
+        assert str(e) == 'invalid hexadecimal floating-point string', str(e)
+try:
+    float.fromhex(1)
+    assert False, 'non-str should raise'
+except TypeError as e:
+    assert str(e) == 'bad argument type for built-in operation', str(e)
+
+# === conjugate and attributes ===
+assert (2.5).conjugate() == 2.5, 'conjugate'
+assert (2.5).real == 2.5, 'real'
+assert (2.5).imag == 0.0 and type((2.5).imag) is float, 'imag is a float'
+try:
+    (1.5).numerator
+    assert False, 'float has no numerator'
+except AttributeError as e:
+    assert str(e) == "'float' object has no attribute 'numerator'", str(e)
@@ -65,14 +65,13 @@ assert hex(big) is not None, 'hex() should not be limited'
 assert oct(big) is not None, 'oct() should not be limited'
 
 # === KeyError with huge int key ===
-# CPython raises KeyError (stores the key object). Monty falls back to the
-# type name when the key is too large to stringify, but still raises KeyError.
+# The key object is stored as-is, so a key too large to stringify still raises KeyError.
 d = {}
 try:
     d[10**5000]
     assert False, 'should raise KeyError'
-except KeyError:
-    pass
+except KeyError as e:
+    assert e.args[0] == 10**5000, 'KeyError stores the key itself'
 
 # === f-string with !s conversion ===
 y = 10**4300
@@ -0,0 +1,120 @@
+# === bit_length / bit_count ===
+assert (0).bit_length() == 0, 'zero bit_length'
+assert (1).bit_length() == 1, 'one bit_length'
+assert (255).bit_length() == 8, '255 bit_length'
+assert (-256).bit_length() == 9, 'negative bit_length uses the magnitude'
+assert (-9223372036854775807 - 1).bit_length() == 64, 'i64 min bit_length'
+assert (2**100).bit_length() == 101, 'long int bit_length'
+assert (-(2**100)).bit_length() == 101, 'negative long int bit_length'
+assert (0).bit_count() == 0, 'zero bit_count'
+assert (0b1011).bit_count() == 3, 'bit_count'
+assert (-7).bit_count() == 3, 'negative bit_count uses the magnitude'
+assert (2**100 - 1).bit_count() == 100, 'long int bit_count'
+assert True.bit_length() == 1, 'bool bit_length'
+
+# === to_bytes ===
+assert (1024).to_bytes(2, 'big') == b'\x04\x00', 'big endian'
+assert (1024).to_bytes(2, 'little') == b'\x00\x04', 'little endian'
+assert (1024).to_bytes(4) == b'\x00\x00\x04\x00', 'byteorder defaults to big'
+assert (5).to_bytes() == b'\x05', 'length defaults to 1'
+assert (0).to_bytes(0, 'big') == b'', 'zero fits in no bytes'
+assert (-1).to_bytes(2, 'big', signed=True) == b'\xff\xff', 'negative signed'
+assert (-128).to_bytes(1, 'little', signed=True) == b'\x80', 'signed minimum'
+assert (127).to_bytes(1, 'big', signed=True) == b'\x7f', 'signed maximum'
+assert (2**64).to_bytes(9, 'big') == b'\x01' + b'\x00' * 8, 'long int to_bytes'
+assert (-(2**64)).to_bytes(9, 'little', signed=True) == b'\x00' * 8 + b'\xff', 'negative long int'
+assert (65).to_bytes(length=3, byteorder='little') == b'A\x00\x00', 'keyword arguments'
+
+try:
+    (256).to_bytes(1, 'big')
+    assert False, 'overflow should raise'
+except OverflowError as e:
+    assert str(e) == 'int too big to convert', str(e)
+try:
+    (128).to_bytes(1, 'big', signed=True)
+    assert False, 'signed overflow should raise'
+except OverflowError as e:
+    assert str(e) == 'int too big to convert', str(e)
+try:
+    (-1).to_bytes(1, 'big')
+    assert False, 'negative unsigned should raise'
+except OverflowError as e:
+    assert str(e) == "can't convert negative int to unsigned", str(e)
+try:
+    (1).to_bytes(1, 'middle')
+    assert False, 'bad byteorder should raise'
+except ValueError as e:
+    assert str(e) == "byteorder must be either 'little' or 'big'", str(e)
+try:
+    (1).to_bytes(-1)
+    assert False, 'negative length should raise'
+except ValueError as e:
+    assert str(e) == 'length argument must be non-negative', str(e)
+try:
+    (1).to_bytes(1, 5)
+    assert False, 'non-str byteorder should raise'
+except TypeError as e:
+    assert str(e) == "to_bytes() argument 'byteorder' must be str, not int", str(e)
+
+# === from_bytes ===
+assert int.from_bytes(b'\x04\x00', 'big') == 1024, 'from_bytes big'
+assert int.from_bytes(b'\x04\x00', 'little') == 4, 'from_bytes little'
+assert int.from_bytes(b'\x04\x00') == 1024, 'from_bytes defaults to big'
+assert int.from_bytes(b'') == 0, 'empty bytes'
+assert int.from_bytes(b'\xff\xff', 'big', signed=True) == -1, 'signed from_bytes'
+assert int.from_bytes(b'\xff\xff', 'big') == 65535, 'unsigned from_bytes'
+assert int.from_bytes(b'\x80', 'little', signed=True) == -128, 'signed minimum'
+assert int.from_bytes([1, 0], 'big') == 256, 'list of ints'
+assert int.from_bytes((0, 1), byteorder='little') == 256, 'tuple of ints'
+big = 2**200 + 12345
+assert int.from_bytes(big.to_bytes(26, 'big'), 'big') == big, 'long int round trip'
+neg = -(2**90) - 7
+assert int.from_bytes(neg.to_bytes(12, 'little', signed=True), 'little', signed=True) == neg, 'negative round trip'
+
+try:
+    int.from_bytes([256])
+    assert False, 'out of range item should raise'
+except ValueError as e:
+    assert str(e) == 'bytes must be in range(0, 256)', str(e)
+try:
+    int.from_bytes('abc')
+    assert False, 'str should raise'
+except TypeError as e:
+    assert str(e) == "cannot convert 'str' object to bytes", str(e)
+try:
+    int.from_bytes(5)
+    assert False, 'int should raise'
+except TypeError as e:
+    assert str(e) == "cannot convert 'int' object to bytes", str(e)
+
+# === ratio, is_integer, conjugate ===
+assert (7).as_integer_ratio() == (7, 1), 'int as_integer_ratio'
+assert (-(2**70)).as_integer_ratio() == (-(2**70), 1), 'long int as_integer_ratio'
+assert (3).is_integer(), 'int is_integer'
+assert (5).conjugate() == 5, 'int conjugate'
+assert True.conjugate() == 1 and type(True.conjugate()) is int, 'bool conjugate is an int'
+
+# === attributes ===
+assert (5).real == 5 and (5).imag == 0, 'real and imag'
+assert (5).numerator == 5 and (5).denominator == 1, 'numerator and denominator'
+assert (2**80).real == 2**80, 'long int real'
+assert (2**80).numerator == 2**80 and (2**80).denominator == 1, 'long int numerator'
+assert (2**80).imag == 0, 'long int imag'
+assert True.real == 1 and type(True.real) is int, 'bool real is an int'
+assert False.denominator == 1, 'bool denominator'
+
+try:
+    (5).bit_length(1)
+    assert False, 'extra argument should raise'
+except TypeError:
+    pass
+try:
+    (5).nope()
+    assert False, 'unknown method should raise'
+except AttributeError as e:
+    assert str(e) == "'int' object has no attribute 'nope'", str(e)
+try:
+    True.nope
+    assert False, 'unknown bool attribute should raise'
+except AttributeError as e:
+    assert str(e) == "'bool' object has no attribute 'nope'", str(e)
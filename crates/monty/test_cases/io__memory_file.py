@@ -0,0 +1,164 @@
+import io
+from contextlib import redirect_stdout
+from io import BytesIO, StringIO
+
+# === StringIO basics ===
+buf = StringIO()
+assert buf.write('hello') == 5, 'write returns the character count'
+assert buf.write(' wörld') == 6, 'count is in characters, not bytes'
+assert buf.tell() == 11, 'position after writes'
+assert buf.getvalue() == 'hello wörld', 'getvalue returns everything'
+assert buf.read() == '', 'read at end is empty'
+assert buf.seek(0) == 0, 'seek returns the new position'
+assert buf.read(5) == 'hello', 'read with size'
+assert buf.read() == ' wörld', 'read the rest'
+
+buf = StringIO('line 1\nline 2\nline 3')
+assert buf.tell() == 0, 'initial value does not move the position'
+assert buf.readline() == 'line 1\n', 'readline includes the newline'
+assert buf.readline(3) == 'lin', 'readline with size'
+assert buf.readline() == 'e 2\n', 'readline continues'
+assert buf.readline() == 'line 3', 'last line has no newline'
+assert buf.readline() == '', 'readline at end'
+buf.seek(0)
+assert buf.readlines() == ['line 1\n', 'line 2\n', 'line 3'], 'readlines'
+
+buf = StringIO('a\nb\nc\n')
+assert list(buf) == ['a\n', 'b\n', 'c\n'], 'iteration yields lines'
+buf.seek(0)
+assert next(buf) == 'a\n', 'next reads one line'
+assert buf.readline() == 'b\n', 'next and readline share the position'
+lines = []
+for line in buf:
+    lines.append(line)
+assert lines == ['c\n'], 'for loop continues from the position'
+try:
+    next(buf)
+    assert False, 'exhausted file should raise StopIteration'
+except StopIteration:
+    pass
+
+# === StringIO writes ===
+buf = StringIO('abcdef')
+buf.write('XY')
+assert buf.getvalue() == 'XYcdef', 'write overwrites from the start'
+buf.seek(0, 2)
+buf.writelines(['1', '2', '3'])
+assert buf.getvalue() == 'XYcdef123', 'writelines appends'
+buf.seek(12)
+buf.write('!')
+assert buf.getvalue() == 'XYcdef123\x00\x00\x00!', 'writing past the end pads with NUL'
+assert buf.truncate(3) == 3, 'truncate returns the new size'
+assert buf.getvalue() == 'XYc', 'truncate shrinks'
+assert buf.tell() == 13, 'truncate does not move the position'
+buf.seek(1)
+assert buf.truncate() == 1, 'truncate defaults to the position'
+assert buf.getvalue() == 'X', 'truncated at position'
+
+buf = StringIO()
+assert buf.seek(0, 1) == 0, 'zero cur-relative seek is allowed'
+assert buf.seek(0, 2) == 0, 'zero end-relative seek is allowed'
+assert buf.readable() and buf.writable() and buf.seekable(), 'capabilities'
+assert StringIO(None).getvalue() == '', 'None initial value'
+
+# === BytesIO ===
+bio = BytesIO(b'abc\ndef\n')
+assert bio.read(2) == b'ab', 'bytes read'
+assert bio.readline() == b'c\n', 'bytes readline'
+assert bio.read() == b'def\n', 'bytes read rest'
+assert bio.write(b'xyz') == 3, 'bytes write count'
+assert bio.getvalue() == b'abc\ndef\nxyz', 'bytes getvalue'
+assert bio.seek(-2, 2) == 9, 'negative end-relative seek'
+assert bio.read() == b'yz', 'read after relative seek'
+assert bio.seek(-1, 1) == 10, 'negative cur-relative seek'
+assert bio.seek(-100, 1) == 0, 'cur-relative seek clamps at zero'
+bio.seek(0)
+assert list(bio) == [b'abc\n', b'def\n', b'xyz'], 'bytes iteration'
+
+bio = BytesIO()
+bio.seek(3)
+bio.write(b'!')
+assert bio.getvalue() == b'\x00\x00\x00!', 'bytes padding'
+assert BytesIO().getvalue() == b'', 'empty BytesIO'
+
+# === context manager and closing ===
+with StringIO('x') as buf:
+    assert not buf.closed, 'open inside the block'
+    assert buf.read() == 'x', 'usable inside the block'
+assert buf.closed, 'closed after the block'
+try:
+    buf.read()
+    assert False, 'reading a closed StringIO should fail'
+except ValueError as e:
+    assert str(e) == 'I/O operation on closed file', str(e)
+try:
+    buf.getvalue()
+    assert False, 'getvalue on a closed StringIO should fail'
+except ValueError:
+    pass
+buf.close()
+
+with BytesIO(b'x') as bio:
+    pass
+try:
+    bio.write(b'y')
+    assert False, 'writing a closed BytesIO should fail'
+except ValueError as e:
+    assert str(e) == 'I/O operation on closed file.', str(e)
+
+# === errors ===
+try:
+    StringIO(b'bytes')
+    assert False, 'StringIO rejects bytes'
+except TypeError as e:
+    assert str(e) == 'initial_value must be str or None, not bytes', str(e)
+try:
+    BytesIO('text')
+    assert False, 'BytesIO rejects str'
+except TypeError as e:
+    assert str(e) == "a bytes-like object is required, not 'str'", str(e)
+try:
+    StringIO().write(b'x')
+    assert False, 'StringIO.write rejects bytes'
+except TypeError:
+    pass
+try:
+    StringIO().seek(-1)
+    assert False, 'negative absolute seek'
+except ValueError as e:
+    assert str(e) == 'Negative seek position -1', str(e)
+try:
+    StringIO('abc').seek(1, 1)
+    assert False, 'nonzero cur-relative seek on StringIO'
+except OSError as e:
+    assert str(e) == "Can't do nonzero cur-relative seeks", str(e)
+try:
+    StringIO().seek(0, 3)
+    assert False, 'invalid whence'
+except ValueError as e:
+    assert str(e) == 'Invalid whence (3, should be 0, 1 or 2)', str(e)
+try:
+    BytesIO().seek(-1)
+    assert False, 'negative BytesIO seek'
+except ValueError as e:
+    assert str(e) == 'negative seek value -1', str(e)
+try:
+    StringIO('abc').truncate(-1)
+    assert False, 'negative truncate'
+except ValueError as e:
+    assert str(e) == 'Negative size value -1', str(e)
+
+# === module attributes and types ===
+assert io.StringIO is StringIO, 'module attribute'
+assert isinstance(StringIO(), io.StringIO), 'isinstance StringIO'
+assert type(BytesIO()) is BytesIO, 'type of BytesIO'
+assert not isinstance(BytesIO(), StringIO), 'BytesIO is not a StringIO'
+assert issubclass(io.UnsupportedOperation, OSError), 'UnsupportedOperation is an OSError'
+assert (io.SEEK_SET, io.SEEK_CUR, io.SEEK_END) == (0, 1, 2), 'seek constants'
+
+# === redirect_stdout into a StringIO ===
+out = StringIO()
+with redirect_stdout(out):
+    print('captured', 1, 2)
+    print('more', end='')
+assert out.getvalue() == 'captured 1 2\nmore', out.getvalue()
@@ -0,0 +1,115 @@
+import logging
+
+# === level constants ===
+assert logging.NOTSET == 0, 'NOTSET'
+assert logging.DEBUG == 10, 'DEBUG'
+assert logging.INFO == 20, 'INFO'
+assert logging.WARNING == 30, 'WARNING'
+assert logging.WARN == 30, 'WARN alias'
+assert logging.ERROR == 40, 'ERROR'
+assert logging.CRITICAL == 50, 'CRITICAL'
+assert logging.FATAL == 50, 'FATAL alias'
+
+# === getLevelName ===
+assert logging.getLevelName(logging.INFO) == 'INFO', 'name of a level'
+assert logging.getLevelName(5) == 'Level 5', 'unnamed level'
+assert logging.getLevelName('ERROR') == 40, 'level of a name'
+assert logging.getLevelName('BOGUS') == 'Level BOGUS', 'unknown name'
+mapping = logging.getLevelNamesMapping()
+assert list(mapping) == ['CRITICAL', 'FATAL', 'ERROR', 'WARN', 'WARNING', 'INFO', 'DEBUG', 'NOTSET'], 'mapping order'
+assert mapping['WARN'] == 30, 'mapping values'
+
+# === basicConfig argument checks ===
+try:
+    logging.basicConfig(style='?')
+    assert False, 'bad style should raise'
+except ValueError as e:
+    assert str(e) == 'Style must be one of: %,{,$', f'style error: {e}'
+try:
+    logging.basicConfig(foo=1)
+    assert False, 'unknown basicConfig argument should raise'
+except ValueError as e:
+    assert str(e) == 'Unrecognised argument(s): foo', f'basicConfig error: {e}'
+# The failed call above still configured logging, so later calls are ignored.
+logging.basicConfig(foo=1)
+
+# === getLogger ===
+root = logging.getLogger()
+assert root is logging.root, 'getLogger() is the root logger'
+assert logging.getLogger('') is root, 'empty name is root'
+assert logging.getLogger('root') is root, "'root' is root"
+assert root.name == 'root', 'root name'
+log = logging.getLogger('app.db')
+assert log is logging.getLogger('app.db'), 'same name, same logger'
+assert log.name == 'app.db', 'logger name'
+assert type(log) is logging.Logger, 'type of a logger'
+assert isinstance(log, logging.Logger), 'isinstance Logger'
+assert repr(log) == '<Logger app.db (WARNING)>', f'repr: {log!r}'
+assert logging.getLogger('app').getChild('db') is log, 'getChild'
+assert root.getChild('app') is logging.getLogger('app'), 'root.getChild has no prefix'
+try:
+    logging.getLogger(5)
+    assert False, 'non-str name should raise'
+except TypeError as e:
+    assert str(e) == 'A logger name must be a string', f'name error: {e}'
+
+# === levels and hierarchy ===
+assert root.level == logging.WARNING, 'root starts at WARNING'
+assert log.level == logging.NOTSET, 'loggers start at NOTSET'
+assert log.getEffectiveLevel() == logging.WARNING, 'inherits from root'
+logging.getLogger('app').setLevel(logging.DEBUG)
+assert log.getEffectiveLevel() == logging.DEBUG, 'inherits from parent'
+assert repr(log) == '<Logger app.db (DEBUG)>', 'repr shows effective level'
+log.setLevel('ERROR')
+assert log.level == logging.ERROR, 'setLevel with a name'
+assert log.getEffectiveLevel() == logging.ERROR, 'own level wins'
+assert not log.isEnabledFor(logging.WARNING), 'below level'
+assert log.isEnabledFor(logging.ERROR), 'at level'
+log.setLevel(logging.NOTSET)
+assert log.isEnabledFor(logging.DEBUG), 'back to the parent level'
+try:
+    log.setLevel('BOGUS')
+    assert False, 'unknown level name should raise'
+except ValueError as e:
+    assert str(e) == "Unknown level: 'BOGUS'", f'setLevel error: {e}'
+try:
+    log.setLevel(1.5)
+    assert False, 'float level should raise'
+except TypeError as e:
+    assert str(e) == 'Level not an integer or a valid string: 1.5', f'setLevel type error: {e}'
+
+# === disable ===
+logging.disable(logging.INFO)
+assert not log.isEnabledFor(logging.INFO), 'disabled level'
+assert log.isEnabledFor(logging.WARNING), 'above the disabled level'
+logging.disable(logging.NOTSET)
+assert log.isEnabledFor(logging.INFO), 're-enabled'
+
+# === records below the level are dropped silently ===
+quiet = logging.getLogger('quiet')
+quiet.setLevel(logging.CRITICAL)
+quiet.debug('not shown %s', 'at all')
+quiet.info('not shown')
+quiet.warning('not shown')
+quiet.error('not shown', exc_info=True)
+
+# === extra may not overwrite record attributes ===
+try:
+    logging.getLogger('app').critical('boom', extra={'message': 'x'})
+    assert False, 'overwriting message should raise'
+except KeyError as e:
+    assert e.args[0] == "Attempt to overwrite 'message' in LogRecord", f'extra error: {e}'
+
+# === LoggerAdapter ===
+adapter = logging.LoggerAdapter(log, {'user': 'sam'})
+assert isinstance(adapter, logging.LoggerAdapter), 'isinstance LoggerAdapter'
+assert adapter.logger is log, 'adapter.logger'
+assert adapter.extra == {'user': 'sam'}, 'adapter.extra'
+assert adapter.name == 'app.db', 'adapter name is the logger name'
+assert logging.LoggerAdapter(log).extra is None, 'extra defaults to None'
+adapter.setLevel(logging.INFO)
+assert log.level == logging.INFO, 'adapter.setLevel sets the logger level'
+assert adapter.getEffectiveLevel() == logging.INFO, 'adapter effective level'
+assert repr(adapter) == '<LoggerAdapter app.db (INFO)>', f'adapter repr: {adapter!r}'
+assert not adapter.isEnabledFor(logging.DEBUG), 'adapter isEnabledFor'
+adapter.debug('not shown')
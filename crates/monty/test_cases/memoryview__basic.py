@@ -0,0 +1,115 @@
+# === construction and attributes ===
+mv = memoryview(b'hello')
+assert type(mv) is memoryview, 'type is memoryview'
+assert len(mv) == 5, 'len'
+assert mv.nbytes == 5, 'nbytes'
+assert mv.readonly, 'view over bytes is read-only'
+assert mv.itemsize == 1, 'itemsize'
+assert mv.ndim == 1, 'ndim'
+assert mv.format == 'B', 'format'
+assert mv.obj == b'hello', 'obj is the viewed object'
+
+ba = bytearray(b'abc')
+assert memoryview(ba).obj is ba, 'obj keeps identity'
+assert memoryview(memoryview(ba)).obj is ba, 'view of a view shares the object'
+
+try:
+    memoryview('text')
+    assert False, 'str should raise'
+except TypeError as e:
+    assert str(e) == "memoryview: a bytes-like object is required, not 'str'", str(e)
+
+# === indexing and slicing ===
+mv = memoryview(b'hello')
+assert mv[0] == 104, 'index'
+assert mv[-1] == 111, 'negative index'
+assert mv[1:3].tobytes() == b'el', 'slice'
+assert type(mv[1:3]) is memoryview, 'slice is a memoryview'
+assert mv[::2].tobytes() == b'hlo', 'stepped slice'
+assert mv[::-1].tobytes() == b'olleh', 'reversed slice'
+assert mv[1:4][::2].tobytes() == b'el', 'slice of a stepped slice'
+assert mv[4:1].tobytes() == b'', 'empty slice'
+assert list(mv) == [104, 101, 108, 108, 111], 'iteration yields ints'
+assert list(mv[::-2]) == [111, 108, 104], 'iteration over a stepped slice'
+
+try:
+    mv[5]
+    assert False, 'index past end should raise'
+except IndexError as e:
+    assert str(e) == 'index out of bounds on dimension 1', str(e)
+
+try:
+    mv['a']
+    assert False, 'str key should raise'
+except TypeError as e:
+    assert str(e) == 'memoryview: invalid slice key', str(e)
+
+try:
+    mv[0] = 1
+    assert False, 'assignment should raise'
+except TypeError as e:
+    assert str(e) == 'cannot modify read-only memory', str(e)
+
+# === conversion methods ===
+mv = memoryview(b'\x01\x02\xff')
+assert mv.tobytes() == b'\x01\x02\xff', 'tobytes'
+assert type(mv.tobytes()) is bytes, 'tobytes returns bytes'
+assert mv.tolist() == [1, 2, 255], 'tolist'
+assert mv.hex() == '0102ff', 'hex'
+assert mv.hex(':') == '01:02:ff', 'hex with separator'
+assert mv.toreadonly().tobytes() == b'\x01\x02\xff', 'toreadonly'
+assert bytes(mv) == b'\x01\x02\xff', 'bytes from memoryview'
+
+# === views see changes to a bytearray ===
+ba = bytearray(b'abc')
+mv = memoryview(ba)
+ba[0] = 120
+assert mv[0] == 120, 'view reflects item assignment'
+assert mv.tobytes() == b'xbc', 'tobytes reflects item assignment'
+
+# === comparison, hashing and truthiness ===
+assert memoryview(b'ab') == memoryview(b'ab'), 'memoryview == memoryview'
+assert memoryview(b'ab') == b'ab', 'memoryview == bytes'
+assert b'ab' == memoryview(b'ab'), 'bytes == memoryview'
+assert memoryview(b'ab') == bytearray(b'ab'), 'memoryview == bytearray'
+assert memoryview(b'ab') != b'ac', 'memoryview != bytes'
+assert memoryview(b'abc')[::2] == b'ac', 'stepped view compares by contents'
+assert hash(memoryview(b'ab')) == hash(b'ab'), 'hash matches bytes'
+assert not memoryview(b''), 'empty view is falsy'
+assert memoryview(b'x'), 'non-empty view is truthy'
+
+try:
+    hash(memoryview(bytearray(b'ab')))
+    assert False, 'writable view should be unhashable'
+except ValueError as e:
+    assert str(e) == 'cannot hash writable memoryview object', str(e)
+
+# === concatenation and containment ===
+assert b'a' + memoryview(b'b') == b'ab', 'bytes + memoryview'
+assert type(bytearray(b'a') + memoryview(b'b')) is bytearray, 'bytearray + memoryview is a bytearray'
+ba = bytearray(b'a')
+ba += memoryview(b'bc')
+assert ba == b'abc', 'bytearray += memoryview'
+assert 104 in memoryview(b'hi'), 'int in memoryview'
+assert 300 not in memoryview(b'hi'), 'out of range int not in memoryview'
+assert b'h' not in memoryview(b'hi'), 'bytes are not items of a memoryview'
+assert b'i' in b'hi', 'bytes in bytes'
+assert 105 in b'hi', 'int in bytes'
+
+# === release and context manager ===
+mv = memoryview(b'abc')
+mv.release()
+assert repr(mv) == '<released memory>' or repr(mv).startswith('<released memory at'), 'released repr'
+try:
+    mv[0]
+    assert False, 'released view should raise'
+except ValueError as e:
+    assert str(e) == 'operation forbidden on released memoryview object', str(e)
+
+with memoryview(b'abc') as view:
+    assert view.tobytes() == b'abc', 'usable inside with'
+try:
+    view.tobytes()
+    assert False, 'view should be released after with'
+except ValueError as e:
+    assert str(e) == 'operation forbidden on released memoryview object', str(e)
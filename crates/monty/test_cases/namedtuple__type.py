@@ -0,0 +1,25 @@
+import sys
+
+vi = sys.version_info
+VersionInfo = type(vi)
+
+# === type() of a named tuple ===
+assert repr(VersionInfo) == "<class 'sys.version_info'>", f'named tuple type repr, {VersionInfo!r}'
+assert VersionInfo.__name__ == 'version_info', 'named tuple type __name__ drops the module'
+assert type(vi) == VersionInfo, 'type() of the same named tuple compares equal'
+assert type(vi) != tuple, 'named tuple type is not tuple'
+assert {VersionInfo: 1}[type(vi)] == 1, 'named tuple type is hashable'
+
+# === isinstance / issubclass ===
+assert isinstance(vi, VersionInfo), 'isinstance with the named tuple type'
+assert isinstance(vi, (int, VersionInfo)), 'isinstance with a tuple containing the type'
+assert not isinstance((3, 12, 0, 'final', 0), VersionInfo), 'plain tuple is not an instance'
+assert not isinstance(1, VersionInfo), 'int is not an instance'
+assert issubclass(VersionInfo, VersionInfo), 'issubclass with the same type'
+assert not issubclass(int, VersionInfo), 'issubclass with another type'
+
+try:
+    VersionInfo.missing
+    assert False, 'missing class attribute should raise'
+except AttributeError as e:
+    assert str(e) == "type object 'sys.version_info' has no attribute 'missing'", str(e)
@@ -41,6 +41,13 @@ writer.flush()
 writer.close()
 assert (root / 'open_write.txt').read_text() == 'alpha\nβ', 'text writes are committed'
 
+# === print(file=...) to an open file ===
+print_writer = open(root / 'open_print.txt', 'w')
+assert print('a', 1, sep='-', file=print_writer) is None, 'print to a file returns None'
+print('b', end='', file=print_writer)
+print_writer.close()
+assert (root / 'open_print.txt').read_text() == 'a-1\nb', 'print writes to the file'
+
 # === Text append ===
 append_writer = open(root / 'open_write.txt', 'a')
 assert append_writer.write('!') == 1, 'append text returns character count'
@@ -97,9 +104,8 @@ assert bytes_path_file.read() == 'hello world\n', 'open accepts bytes paths via
 bytes_path_file.close()
 
 # === All eight positional args accepted at CPython defaults ===
-# Monty only honors `file` and `mode`; the other six must be at their CPython
-# defaults (encoding='utf-8' is also accepted as a documented no-op since
-# Monty already uses UTF-8).
+# Monty honors `file`, `mode`, `encoding` and `errors`; the other four must be
+# at their CPython defaults.
 positional = open(root / 'hello.txt', 'r', -1, 'utf-8', None, None, True, None)
 assert positional.read() == 'hello world\n', 'open accepts default positional args + utf-8 encoding'
 positional.close()
@@ -113,8 +119,6 @@ kw_closefd.close()
 if is_monty:
     for kwarg_name, kwarg_value in (
         ('buffering', 0),
-        ('encoding', 'latin-1'),
-        ('errors', 'strict'),
         ('newline', ''),
         ('closefd', False),
     ):
@@ -126,6 +130,67 @@ if is_monty:
                 f'unexpected message for {kwarg_name}={kwarg_value!r}: {exc}'
             )
 
+# === Text encodings ===
+latin_writer = open(root / 'latin.txt', 'w', encoding='latin-1')
+assert latin_writer.encoding == 'latin-1', 'encoding attribute reflects open() argument'
+assert latin_writer.errors == 'strict', 'errors defaults to strict'
+assert latin_writer.write('café') == 4, 'encoded write returns char count'
+assert latin_writer.write('!') == 1, 'second encoded write returns char count'
+latin_writer.close()
+assert (root / 'latin.txt').read_bytes() == b'caf\xe9!', 'latin-1 write encodes one byte per char'
+latin_reader = open(root / 'latin.txt', encoding='latin-1')
+assert latin_reader.read(3) == 'caf', 'encoded read(N) counts chars'
+assert latin_reader.read() == 'é!', 'encoded read decodes the rest'
+latin_reader.close()
+
+try:
+    open(root / 'latin.txt').read()
+    assert False, 'expected latin-1 bytes to fail as utf-8'
+except UnicodeDecodeError as exc:
+    assert isinstance(exc, ValueError), 'UnicodeDecodeError is a ValueError'
+
+replaced = open(root / 'latin.txt', encoding='utf-8', errors='replace')
+assert replaced.errors == 'replace', 'errors attribute reflects open() argument'
+assert replaced.read() == 'caf\ufffd!', "errors='replace' substitutes U+FFFD"
+replaced.close()
+
+ascii_writer = open(root / 'ascii.txt', 'w', encoding='ascii', errors='backslashreplace')
+ascii_writer.write('naïve')
+ascii_writer.close()
+assert (root / 'ascii.txt').read_bytes() == b'na\\xefve', "errors='backslashreplace' escapes on write"
+
+try:
+    strict_writer = open(root / 'ascii.txt', 'w', encoding='ascii')
+    strict_writer.write('π')
+    assert False, 'expected ascii write of π to fail'
+except UnicodeEncodeError as exc:
+    assert exc.encoding == 'ascii', 'encode error records the codec'
+    assert (exc.start, exc.end) == (0, 1), 'encode error records the span'
+    strict_writer.close()
+
+utf16_writer = open(root / 'utf16.txt', 'w', encoding='utf-16')
+utf16_writer.write('hi')
+utf16_writer.write('!')
+utf16_writer.close()
+assert (root / 'utf16.txt').read_bytes() == b'\xff\xfeh\x00i\x00!\x00', 'utf-16 writes a single BOM'
+utf16_reader = open(root / 'utf16.txt', encoding='utf-16')
+assert utf16_reader.read() == 'hi!', 'utf-16 read strips the BOM'
+utf16_reader.close()
+
+try:
+    open(root / 'hello.txt', encoding='no-such-codec')
+    assert False, 'expected unknown encoding to fail'
+except LookupError as exc:
+    assert str(exc) == 'unknown encoding: no-such-codec', f'unexpected message: {exc}'
+
+for kwarg_name in ('encoding', 'errors'):
+    try:
+        open(root / 'data.bin', 'rb', **{kwarg_name: 'latin-1' if kwarg_name == 'encoding' else 'strict'})
+        assert False, f'expected binary {kwarg_name} to fail'
+    except ValueError as exc:
+        expected = 'an encoding' if kwarg_name == 'encoding' else 'an errors'
+        assert str(exc) == f"binary mode doesn't take {expected} argument", f'unexpected message: {exc}'
+
 # === Open-time truncation / creation (CPython truncates/creates on open) ===
 # w truncates an existing file immediately, before (and even without) any write
 (root / 'open_trunc.txt').write_text('previous contents')
@@ -592,7 +657,7 @@ kw_mode = (root / 'path_open_text.txt').open(mode='r')
 assert kw_mode.read() == 'hello via Path.open\n', 'Path.open(mode=...) reads the file'
 kw_mode.close()
 
-# `encoding='utf-8'` accepted as the documented no-op (Monty always uses UTF-8).
+# `encoding='utf-8'` is forwarded through Path.open() like open().
 enc = (root / 'path_open_text.txt').open('r', encoding='utf-8')
 assert enc.read() == 'hello via Path.open\n', "Path.open('r', encoding='utf-8') accepted"
 enc.close()
@@ -0,0 +1,47 @@
+import io
+import sys
+from contextlib import redirect_stdout
+
+# === print to a StringIO ===
+buf = io.StringIO()
+assert print('hello', 'world', file=buf) is None, 'print returns None'
+print(1, 2, sep=',', end='!', file=buf)
+assert buf.getvalue() == 'hello world\n1,2!', f'StringIO contents: {buf.getvalue()!r}'
+
+# === print to the sys streams ===
+print('to stdout', file=sys.stdout)
+print('to stderr', file=sys.stderr)
+print(file=None)
+
+# === sys stream methods ===
+assert sys.stdout.write('abc\n') == 4, 'write returns the character count'
+assert sys.stderr.write('') == 0, 'empty write'
+assert sys.stderr.write('é\n') == 2, 'count is in characters'
+assert sys.stdout.writelines(['x', 'y\n']) is None, 'writelines returns None'
+assert sys.stderr.writelines(iter(['z\n'])) is None, 'writelines takes any iterable'
+assert sys.stdout.flush() is None, 'flush returns None'
+assert sys.stderr.flush() is None, 'flush on stderr'
+
+try:
+    sys.stdout.write(5)
+    assert False, 'non-str write should raise'
+except TypeError as e:
+    assert str(e) == 'write() argument must be str, not int', f'write error: {e}'
+try:
+    sys.stderr.writelines(['ok', b'bytes'])
+    assert False, 'non-str writelines item should raise'
+except TypeError as e:
+    assert str(e) == 'write() argument must be str, not bytes', f'writelines error: {e}'
+try:
+    print('x', file=5)
+    assert False, 'file without write() should raise'
+except AttributeError as e:
+    assert str(e) == "'int' object has no attribute 'write'", f'file error: {e}'
+
+# === sys.stdout follows redirect_stdout ===
+captured = io.StringIO()
+with redirect_stdout(captured):
+    sys.stdout.write('direct ')
+    print('printed', file=sys.stdout)
+    sys.stderr.write('')
+assert captured.getvalue() == 'direct printed\n', f'redirected: {captured.getvalue()!r}'
@@ -0,0 +1,229 @@
+# === Encoding with the supported codecs ===
+assert 'café'.encode('latin-1') == b'caf\xe9', 'latin-1 encode'
+assert 'café'.encode('ISO-8859-1') == b'caf\xe9', 'latin-1 alias, case insensitive'
+assert 'abc'.encode('ascii') == b'abc', 'ascii encode'
+assert '€…'.encode('cp1252') == b'\x80\x85', 'cp1252 encode maps the 0x80 block'
+assert 'hi'.encode('utf-16') == b'\xff\xfeh\x00i\x00', 'utf-16 writes a little-endian BOM'
+assert 'hi'.encode('utf-16-le') == b'h\x00i\x00', 'utf-16-le has no BOM'
+assert 'hi'.encode('utf-16-be') == b'\x00h\x00i', 'utf-16-be has no BOM'
+assert '😀'.encode('utf-16-le') == b'=\xd8\x00\xde', 'utf-16 encodes surrogate pairs'
+assert 'a'.encode('utf-32') == b'\xff\xfe\x00\x00a\x00\x00\x00', 'utf-32 writes a BOM'
+assert 'a'.encode('utf_32_be') == b'\x00\x00\x00a', 'utf-32-be'
+
+# === Decoding with the supported codecs ===
+assert b'caf\xe9'.decode('latin-1') == 'café', 'latin-1 decode'
+assert b'abc'.decode('ascii') == 'abc', 'ascii decode'
+assert b'\x80\x93\x94'.decode('cp1252') == '€“”', 'cp1252 decode'
+assert b'\xff\xfeh\x00i\x00'.decode('utf-16') == 'hi', 'utf-16 BOM is consumed'
+assert b'\xfe\xff\x00h\x00i'.decode('utf-16') == 'hi', 'utf-16 big-endian BOM'
+assert b'h\x00i\x00'.decode('utf-16') == 'hi', 'utf-16 defaults to little-endian'
+assert b'=\xd8\x00\xde'.decode('utf-16-le') == '😀', 'utf-16 decodes surrogate pairs'
+assert b'\x00\x00\x00a'.decode('utf-32-be') == 'a', 'utf-32-be decode'
+assert bytearray(b'caf\xe9').decode('latin-1') == 'café', 'bytearray decode uses codecs'
+assert bytearray('café', 'latin-1') == bytearray(b'caf\xe9'), 'bytearray() encodes with codecs'
+
+for name in ('utf-8', 'latin-1', 'cp1252', 'utf-16', 'utf-16-be', 'utf-32', 'utf-32-le'):
+    assert 'Grüße'.encode(name).decode(name) == 'Grüße', f'{name} round trips'
+
+# === Encode error handlers ===
+assert 'naïve'.encode('ascii', 'ignore') == b'nave', 'ignore drops the character'
+assert 'naïve'.encode('ascii', 'replace') == b'na?ve', 'replace substitutes ?'
+assert 'naïve'.encode('ascii', 'backslashreplace') == b'na\\xefve', 'backslashreplace \\x escape'
+assert 'π'.encode('latin-1', 'backslashreplace') == b'\\u03c0', 'backslashreplace \\u escape'
+assert '😀'.encode('ascii', 'backslashreplace') == b'\\U0001f600', 'backslashreplace \\U escape'
+assert 'aπb'.encode('ascii', 'xmlcharrefreplace') == b'a&#960;b', 'xmlcharrefreplace'
+assert 'abc'.encode('ascii', 'bogus') == b'abc', 'unknown handler unused when nothing fails'
+
+# === Decode error handlers ===
+assert b'a\xffb'.decode('utf-8', 'ignore') == 'ab', 'ignore drops the byte'
+assert b'a\xffb'.decode('utf-8', 'replace') == 'a�b', 'replace substitutes U+FFFD'
+assert b'a\xffb'.decode('utf-8', 'backslashreplace') == 'a\\xffb', 'backslashreplace decode'
+assert b'a\x80b'.decode('ascii', 'replace') == 'a�b', 'ascii decode replace'
+assert b'\x81'.decode('cp1252', 'replace') == '�', 'cp1252 undefined byte'
+assert b'a'.decode('utf-16-le', 'replace') == '�', 'truncated utf-16 replace'
+
+# === UnicodeEncodeError ===
+try:
+    'aπb'.encode('ascii')
+    assert False, 'expected UnicodeEncodeError'
+except UnicodeEncodeError as exc:
+    assert str(exc) == "'ascii' codec can't encode character '\\u03c0' in position 1: ordinal not in range(128)", (
+        f'unexpected message: {exc}'
+    )
+    assert exc.encoding == 'ascii', 'encoding attribute'
+    assert exc.object == 'aπb', 'object attribute'
+    assert exc.start == 1, 'start attribute'
+    assert exc.end == 2, 'end attribute'
+    assert exc.reason == 'ordinal not in range(128)', 'reason attribute'
+    assert exc.args == ('ascii', 'aπb', 1, 2, 'ordinal not in range(128)'), 'args holds the codec details'
+
+try:
+    'aπσb'.encode('latin-1')
+    assert False, 'expected UnicodeEncodeError'
+except UnicodeEncodeError as exc:
+    assert str(exc) == "'latin-1' codec can't encode characters in position 1-2: ordinal not in range(256)", (
+        f'unexpected message: {exc}'
+    )
+    assert (exc.start, exc.end) == (1, 3), 'runs of unencodable characters are grouped'
+
+try:
+    'π'.encode('cp1252')
+    assert False, 'expected UnicodeEncodeError'
+except UnicodeEncodeError as exc:
+    assert exc.encoding == 'charmap', 'cp1252 reports the charmap codec'
+    assert exc.reason == 'character maps to <undefined>', f'unexpected reason: {exc.reason}'
+
+# === UnicodeDecodeError ===
+try:
+    b'ab\xff'.decode('utf-8')
+    assert False, 'expected UnicodeDecodeError'
+except UnicodeDecodeError as exc:
+    assert str(exc) == "'utf-8' codec can't decode byte 0xff in position 2: invalid start byte", (
+        f'unexpected message: {exc}'
+    )
+    assert exc.object == b'ab\xff', 'object is the bytes'
+    assert (exc.start, exc.end) == (2, 3), 'decode span'
+    assert exc.reason == 'invalid start byte', 'decode reason'
+
+try:
+    b'\xe2\x82'.decode()
+    assert False, 'expected UnicodeDecodeError'
+except UnicodeDecodeError as exc:
+    assert exc.reason == 'unexpected end of data', f'unexpected reason: {exc.reason}'
+    assert (exc.start, exc.end) == (0, 2), 'truncated sequence span'
+
+try:
+    b'\xe2(a'.decode()
+    assert False, 'expected UnicodeDecodeError'
+except UnicodeDecodeError as exc:
+    assert exc.reason == 'invalid continuation byte', f'unexpected reason: {exc.reason}'
+
+try:
+    b'\x80'.decode('ascii')
+    assert False, 'expected UnicodeDecodeError'
+except UnicodeDecodeError as exc:
+    assert str(exc) == "'ascii' codec can't decode byte 0x80 in position 0: ordinal not in range(128)", (
+        f'unexpected message: {exc}'
+    )
+
+try:
+    b'\x81'.decode('cp1252')
+    assert False, 'expected UnicodeDecodeError'
+except UnicodeDecodeError as exc:
+    assert str(exc) == "'charmap' codec can't decode byte 0x81 in position 0: character maps to <undefined>", (
+        f'unexpected message: {exc}'
+    )
+
+try:
+    b'abc'.decode('utf-16-le')
+    assert False, 'expected UnicodeDecodeError'
+except UnicodeDecodeError as exc:
+    assert str(exc) == "'utf-16-le' codec can't decode byte 0x63 in position 2: truncated data", (
+        f'unexpected message: {exc}'
+    )
+
+# === Exception hierarchy ===
+assert issubclass(UnicodeEncodeError, UnicodeError), 'UnicodeEncodeError is a UnicodeError'
+assert issubclass(UnicodeDecodeError, UnicodeError), 'UnicodeDecodeError is a UnicodeError'
+assert issubclass(UnicodeTranslateError, UnicodeError), 'UnicodeTranslateError is a UnicodeError'
+assert issubclass(UnicodeError, ValueError), 'UnicodeError is a ValueError'
+
+try:
+    'π'.encode('ascii')
+except UnicodeError:
+    caught = 'unicode'
+assert caught == 'unicode', 'except UnicodeError catches encode errors'
+
+try:
+    b'\xff'.decode()
+except ValueError:
+    caught = 'value'
+assert caught == 'value', 'except ValueError catches decode errors'
+
+try:
+    raise UnicodeError('custom')
+except ValueError as exc:
+    assert str(exc) == 'custom', 'UnicodeError can be raised directly'
+
+# === Unknown codecs and handlers ===
+try:
+    'abc'.encode('rot13-ish')
+    assert False, 'expected LookupError'
+except LookupError as exc:
+    assert str(exc) == 'unknown encoding: rot13-ish', f'unexpected message: {exc}'
+
+try:
+    b'abc'.decode('nope')
+    assert False, 'expected LookupError'
+except LookupError as exc:
+    assert str(exc) == 'unknown encoding: nope', f'unexpected message: {exc}'
+
+try:
+    'π'.encode('ascii', 'bogus')
+    assert False, 'expected LookupError'
+except LookupError as exc:
+    assert str(exc) == "unknown error handler name 'bogus'", f'unexpected message: {exc}'
+
+# === str.translate / str.maketrans ===
+table = str.maketrans('abc', 'xyz')
+assert table == {97: 120, 98: 121, 99: 122}, 'maketrans from two strings'
+assert 'aabbcc'.translate(table) == 'xxyyzz', 'translate with maketrans table'
+assert 'abcd'.translate(str.maketrans('ab', 'AB', 'd')) == 'ABc', 'third argument deletes'
+assert str.maketrans({'a': 'A', 98: None}) == {97: 'A', 98: None}, 'maketrans from dict converts str keys'
+assert 'abc'.translate({97: 'AA', 98: None}) == 'AAc', 'translate to str or None'
+assert 'abc'.translate({97: 0x3c0}) == 'πbc', 'translate to code point'
+assert 'abc'.translate({}) == 'abc', 'translate with empty table'
+assert 'abc'.translate([None, None]) == 'abc', 'IndexError from the table keeps the char'
+assert ''.maketrans('a', 'b') == {97: 98}, 'maketrans is reachable from an instance'
+
+try:
+    'a'.translate({97: 1.5})
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == 'character mapping must return integer, None or str', f'unexpected message: {exc}'
+
+try:
+    'a'.translate({97: 0x110000})
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'character mapping must be in range(0x110000)', f'unexpected message: {exc}'
+
+try:
+    str.maketrans('ab', 'x')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'the first two maketrans arguments must have equal length', f'unexpected message: {exc}'
+
+try:
+    str.maketrans('ab')
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == 'if you give only one argument to maketrans it must be a dict', f'unexpected message: {exc}'
+
+try:
+    str.maketrans({'ab': 1})
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'string keys in translate table must be of length 1', f'unexpected message: {exc}'
+
+# === bytes / bytearray translate and maketrans ===
+btable = bytes.maketrans(b'abc', b'xyz')
+assert len(btable) == 256, 'bytes.maketrans returns a 256-byte table'
+assert b'aabbcc'.translate(btable) == b'xxyyzz', 'bytes.translate with table'
+assert b'abcd'.translate(None, b'bd') == b'ac', 'bytes.translate with None table deletes'
+assert b'abcd'.translate(btable, b'a') == b'yzd', 'delete applies before mapping'
+assert b'abc'.translate(None, delete=b'c') == b'ab', 'delete as keyword'
+assert bytearray(b'abc').translate(btable) == bytearray(b'xyz'), 'bytearray.translate returns bytearray'
+assert type(bytearray.maketrans(b'a', b'b')) is bytes, 'bytearray.maketrans returns bytes'
+
+try:
+    b'abc'.translate(b'short')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'translation table must be 256 characters long', f'unexpected message: {exc}'
+
+try:
+    bytes.maketrans(b'ab', b'x')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'maketrans arguments must have same length', f'unexpected message: {exc}'
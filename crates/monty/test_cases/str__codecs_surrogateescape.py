@@ -0,0 +1,22 @@
+# xfail=cpython
+# Monty strings can't hold the lone surrogates `surrogateescape` decodes bytes to,
+# so it round-trips valid data and refuses to decode anything else.
+data = 'café ☃'.encode('utf-8')
+text = data.decode('utf-8', 'surrogateescape')
+assert text == 'café ☃', 'valid bytes decode normally'
+assert text.encode('utf-8', 'surrogateescape') == data, 'valid bytes round-trip'
+assert b'caf\xe9'.decode('latin-1', 'surrogateescape').encode('latin-1', 'surrogateescape') == b'caf\xe9', (
+    'latin-1 round-trips every byte'
+)
+
+try:
+    b'caf\xff'.decode('utf-8', 'surrogateescape')
+    assert False, 'undecodable bytes should raise'
+except NotImplementedError as e:
+    assert str(e) == "decoding undecodable bytes with 'surrogateescape' is not supported", str(e)
+
+try:
+    'café'.encode('ascii', 'surrogateescape')
+    assert False, 'unencodable characters should raise'
+except UnicodeEncodeError as e:
+    assert e.start == 3 and e.end == 4, (e.start, e.end)
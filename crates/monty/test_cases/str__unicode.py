@@ -0,0 +1,70 @@
+# === full case mappings ===
+assert 'ß'.upper() == 'SS', 'sharp s uppercases to two letters'
+assert 'ß'.lower() == 'ß', 'sharp s is already lowercase'
+assert 'ß'.casefold() == 'ss', 'sharp s casefolds to ss'
+assert 'ﬁ'.upper() == 'FI', 'ligature uppercases to two letters'
+assert '\u0130'.lower() == 'i\u0307', 'dotted capital I lowercases to i + combining dot'
+assert 'ŉ'.upper() == 'ʼN', 'apostrophe n expands'
+assert 'Straße'.upper() == 'STRASSE', 'full mapping inside a word'
+assert 'MASSE'.casefold() == 'Maße'.casefold(), 'casefold matches caseless equivalents'
+assert 'ΣΊΣΥΦΟΣ'.casefold() == 'σίσυφοσ', 'casefold has no final sigma'
+assert 'ǆ'.upper() == 'Ǆ', 'digraph uppercase'
+assert 'ǆ'.title() == 'ǅ', 'digraph titlecase differs from uppercase'
+assert 'ß'.title() == 'Ss', 'title uses the titlecase mapping'
+assert 'ﬂour'.capitalize() == 'Flour', 'capitalize uses the titlecase mapping'
+assert 'ǆemal'.capitalize() == 'ǅemal', 'capitalize titlecases a digraph'
+assert 'ß'.swapcase() == 'SS', 'swapcase uses full mappings'
+
+# === final sigma ===
+assert 'ΟΔΟΣ'.lower() == 'οδος', 'final sigma at end of word'
+assert 'ΟΔΟΣ ΟΔΟΣ'.lower() == 'οδος οδος', 'final sigma before a space'
+assert 'Σ'.lower() == 'σ', 'lone sigma is not final'
+assert 'ΣΑ'.lower() == 'σα', 'initial sigma is not final'
+assert 'ΑΣ.'.lower() == 'ας.', 'final sigma before punctuation'
+assert "ΑΣ'Α".lower() == "ασ'α", 'case-ignorable characters are skipped'
+assert 'ΟΔΟΣ'.title() == 'Οδος', 'title applies the final-sigma rule'
+assert 'οδοΣ'.swapcase() == 'ΟΔΟς', 'swapcase applies the final-sigma rule'
+
+# === title with Unicode cased letters ===
+assert "they're bill's".title() == "They'Re Bill'S", 'apostrophes start new words'
+assert 'ǅungla'.title() == 'ǅungla', 'titlecase letter counts as cased'
+assert 'ⓐⓑ'.title() == 'Ⓐⓑ', 'circled letters are cased'
+
+# === predicates ===
+assert '٣'.isdecimal() and '٣'.isdigit() and '٣'.isnumeric(), 'arabic-indic digit'
+assert not '²'.isdecimal() and '²'.isdigit() and '²'.isnumeric(), 'superscript is a digit'
+assert not '½'.isdigit() and '½'.isnumeric(), 'fraction is numeric only'
+assert not '万'.isdigit() and '万'.isnumeric(), 'CJK numeral is numeric'
+assert 'Ⅻ'.isnumeric() and not 'Ⅻ'.isdecimal(), 'roman numeral is numeric'
+assert '½'.isalnum(), 'numeric characters are alphanumeric'
+assert 'ß'.isalpha() and 'ǅ'.isalpha(), 'letters are alphabetic'
+assert not '\u0301'.isalpha(), 'combining marks are not alphabetic'
+assert '\x1c\x1d\x1e\x1f'.isspace(), 'ASCII separators are whitespace'
+assert '　\xa0 '.isspace(), 'Unicode spaces'
+assert not '\u200b'.isspace(), 'zero width space is not whitespace'
+assert 'ǅ'.istitle(), 'titlecase letter is titled'
+assert not 'ǅ'.isupper() and not 'ǅ'.islower(), 'titlecase letter is neither upper nor lower'
+assert 'ª'.islower(), 'feminine ordinal is lowercase'
+assert 'ⓐ'.islower() and 'Ⓐ'.isupper(), 'circled letters are cased'
+assert 'ǅungla'.istitle(), 'titlecase digraph starts a title word'
+
+# === isprintable ===
+assert 'abc def'.isprintable(), 'ASCII text is printable'
+assert ''.isprintable(), 'empty string is printable'
+assert 'é中😀'.isprintable(), 'letters and symbols are printable'
+assert not 'a\nb'.isprintable(), 'newline is not printable'
+assert not '\xa0'.isprintable(), 'no-break space is not printable'
+assert not '\u200b'.isprintable(), 'format characters are not printable'
+assert not '\ue000'.isprintable(), 'private use is not printable'
+assert not '\U0010ffff'.isprintable(), 'unassigned is not printable'
+
+# === isidentifier ===
+assert 'café'.isidentifier(), 'accented letters'
+assert '_x1'.isidentifier(), 'underscore start'
+assert '℘'.isidentifier(), 'Other_ID_Start character'
+assert 'x·y'.isidentifier(), 'middle dot continues an identifier'
+assert not '·x'.isidentifier(), 'middle dot cannot start an identifier'
+assert not '1x'.isidentifier(), 'digit cannot start an identifier'
+assert not '²'.isidentifier(), 'superscript two is not an identifier'
+assert 'x\u0301'.isidentifier(), 'combining marks continue an identifier'
+assert not 'a-b'.isidentifier(), 'hyphen is not allowed'
@@ -0,0 +1,110 @@
+import typing
+from typing import Annotated, Optional
+
+
+# === function annotations ===
+def greet(name: str, times: int = 1, *args: float, flag: bool, **kwargs: object) -> str:
+    return name * times
+
+
+assert greet.__annotations__ == {
+    'name': str,
+    'times': int,
+    'args': float,
+    'flag': bool,
+    'kwargs': object,
+    'return': str,
+}, f'unexpected annotations {greet.__annotations__}'
+
+
+def plain(a, b):
+    return a + b
+
+
+assert plain.__annotations__ == {}, 'unannotated function has empty annotations'
+
+
+def partial(a: int, b) -> None:
+    pass
+
+
+assert partial.__annotations__ == {'a': int, 'return': None}, 'only annotated params appear'
+
+# === annotations are evaluated in the defining scope ===
+Alias = list[int]
+
+
+def uses_alias(x: Alias, y: Optional[str] = None) -> dict[str, Alias]:
+    return {}
+
+
+assert uses_alias.__annotations__['x'] == list[int], 'alias resolved'
+assert uses_alias.__annotations__['y'] == Optional[str], 'Optional resolved'
+assert typing.get_origin(uses_alias.__annotations__['return']) is dict, 'return alias origin'
+
+
+# === closures and nested functions ===
+def outer():
+    local_type = int
+
+    def inner(v: local_type) -> local_type:
+        return v
+
+    return inner
+
+
+assert outer().__annotations__ == {'v': int, 'return': int}, 'closure annotations'
+
+# === an annotation Monty can't evaluate doesn't stop the function being defined ===
+def count_args(*args: *tuple[int, ...]) -> int:
+    return len(args)
+
+
+assert count_args(1, 2, 3) == 3, 'function with a starred annotation'
+
+# === lambdas have no annotations ===
+assert (lambda x: x).__annotations__ == {}, 'lambda annotations'
+
+
+# === get_type_hints ===
+def hinted(a: int, b: None, c: Annotated[str, 'meta']) -> None:
+    pass
+
+
+assert typing.get_type_hints(hinted) == {
+    'a': int,
+    'b': type(None),
+    'c': str,
+    'return': type(None),
+}, 'get_type_hints normalizes None and strips Annotated'
+assert typing.get_type_hints(hinted, include_extras=True)['c'] == Annotated[str, 'meta'], 'include_extras'
+assert typing.get_type_hints(plain) == {}, 'no hints'
+assert typing.get_type_hints(len) == {}, 'builtin function has no hints'
+
+
+# === annotations are not validated ===
+def loose(x: int) -> int:
+    return x
+
+
+assert loose('not an int') == 'not an int', 'annotations are not enforced'
+
+
+# === annotated functions defined in branches and loops ===
+if len(hinted.__annotations__) > 0:
+
+    def branch(x: int) -> int:
+        return x
+
+
+assert branch.__annotations__ == {'x': int, 'return': int}, 'annotated def inside if'
+
+made = []
+for i in range(2):
+
+    def looped(y: str = 'd'):
+        return y
+
+    made.append(looped)
+assert made[1].__annotations__ == {'y': str}, 'annotated def with default inside loop'
+assert made[0]() == 'd', 'default still bound after annotate'
@@ -0,0 +1,108 @@
+import typing
+from typing import Annotated, Any, Callable, Dict, List, Literal, NewType, Optional, TypedDict, Union
+
+# === cast / assert_type ===
+assert typing.cast(int, 'not an int') == 'not an int', 'cast returns its value unchanged'
+assert typing.cast('list[str]', [1]) == [1], 'cast accepts string types'
+assert typing.assert_type(5, int) == 5, 'assert_type returns its value unchanged'
+
+
+# === overload / final ===
+def identity(x):
+    return x
+
+
+assert typing.final(identity) is identity, 'final returns the function'
+assert typing.overload(identity) is not None, 'overload returns a function'
+
+# === assert_never ===
+try:
+    typing.assert_never(42)
+    assert False, 'assert_never should raise'
+except AssertionError as e:
+    assert str(e) == 'Expected code to be unreachable, but got: 42', f'unexpected message {e}'
+
+# === subscripted builtins ===
+alias = list[int]
+assert alias.__origin__ is list, 'list[int].__origin__'
+assert alias.__args__ == (int,), 'list[int].__args__'
+assert repr(alias) == 'list[int]', f'repr {alias!r}'
+assert repr(dict[str, list[int]]) == 'dict[str, list[int]]', 'nested repr'
+assert list[int] == list[int], 'aliases compare by value'
+assert list[int] != list[str], 'different args'
+assert hash(list[int]) == hash(list[int]), 'equal aliases hash equally'
+
+# === typing aliases ===
+assert List[int].__origin__ is list, 'List[int].__origin__'
+assert Dict[str, int].__args__ == (str, int), 'Dict args'
+assert repr(List[int]) == 'typing.List[int]', f'repr {List[int]!r}'
+assert repr(Callable[[int, str], bool]) == 'typing.Callable[[int, str], bool]', 'Callable repr'
+assert repr(Callable[..., int]) == 'typing.Callable[..., int]', 'Callable ellipsis repr'
+assert repr(Literal['a', 1]) == "typing.Literal['a', 1]", 'Literal repr'
+
+# === unions ===
+u = int | str
+assert repr(u) == 'int | str', f'union repr {u!r}'
+assert u.__args__ == (int, str), 'union args'
+assert Union[int, str] == (str | int), 'unions compare without order'
+assert Union[int, Union[str, float]].__args__ == (int, str, float), 'nested unions flatten'
+assert Union[int, int] is int, 'single-member union collapses'
+assert Optional[int].__args__ == (int, type(None)), 'Optional adds NoneType'
+assert repr(int | None) == 'int | None', 'None in union repr'
+assert (list[int] | None).__args__ == (list[int], type(None)), 'alias in union'
+
+# === get_origin ===
+assert typing.get_origin(list[int]) is list, 'origin of list[int]'
+assert typing.get_origin(List[int]) is list, 'origin of List[int]'
+assert typing.get_origin(List) is list, 'origin of bare List'
+assert typing.get_origin(Literal[1]) is Literal, 'origin of Literal'
+assert typing.get_origin(Annotated[int, 'meta']) is Annotated, 'origin of Annotated'
+assert typing.get_origin(int) is None, 'origin of int'
+assert typing.get_origin(Any) is None, 'origin of Any'
+assert typing.get_origin(42) is None, 'origin of non-type'
+
+# === get_args ===
+assert typing.get_args(Dict[str, int]) == (str, int), 'args of Dict'
+assert typing.get_args(Callable[[int, str], bool]) == ([int, str], bool), 'args of Callable'
+assert typing.get_args(Callable[..., bool]) == (..., bool), 'args of Callable[..., T]'
+assert typing.get_args(Annotated[int, 'a', 'b']) == (int, 'a', 'b'), 'args of Annotated'
+assert typing.get_args(Optional[str]) == (str, type(None)), 'args of Optional'
+assert typing.get_args(int) == (), 'args of int'
+
+# === Annotated ===
+ann = Annotated[int, 'meta']
+assert ann.__origin__ is int, 'Annotated.__origin__'
+assert ann.__metadata__ == ('meta',), 'Annotated.__metadata__'
+
+# === NewType ===
+UserId = NewType('UserId', int)
+assert UserId(5) == 5, 'NewType returns its argument'
+assert type(UserId(5)) is int, 'NewType does not wrap'
+assert UserId.__name__ == 'UserId', 'NewType name'
+assert UserId.__supertype__ is int, 'NewType supertype'
+assert (UserId | None).__args__ == (UserId, type(None)), 'NewType in union'
+
+# === TypedDict ===
+Movie = TypedDict('Movie', {'title': str, 'year': int})
+m = Movie(title='Blade Runner', year=1982)
+assert m == {'title': 'Blade Runner', 'year': 1982}, 'TypedDict builds a dict'
+assert type(m) is dict, 'TypedDict instances are dicts'
+assert Movie.__name__ == 'Movie', 'TypedDict name'
+assert Movie.__annotations__ == {'title': str, 'year': int}, 'TypedDict annotations'
+assert Movie.__total__ is True, 'TypedDict total default'
+Partial = TypedDict('Partial', {'x': int}, total=False)
+assert Partial.__total__ is False, 'TypedDict total=False'
+assert typing.get_type_hints(Movie) == {'title': str, 'year': int}, 'TypedDict hints'
+
+# === errors ===
+try:
+    Optional[int, str]
+    assert False, 'Optional with two args should raise'
+except TypeError as e:
+    assert str(e) == "typing.Optional requires a single type. Got (<class 'int'>, <class 'str'>).", f'unexpected message {e}'
+
+try:
+    typing.get_type_hints(42)
+    assert False, 'get_type_hints(42) should raise'
+except TypeError as e:
+    assert str(e) == '42 does not have annotations', f'unexpected message {e}'
@@ -0,0 +1,176 @@
+import unicodedata
+
+# === unidata_version ===
+assert isinstance(unicodedata.unidata_version, str), 'unidata_version is a str'
+assert unicodedata.unidata_version == '16.0.0', 'unidata_version matches CPython 3.14'
+
+# === category / bidirectional / combining / mirrored ===
+assert unicodedata.category('A') == 'Lu', 'category Lu'
+assert unicodedata.category('a') == 'Ll', 'category Ll'
+assert unicodedata.category('\u01c5') == 'Lt', 'category Lt'
+assert unicodedata.category('1') == 'Nd', 'category Nd'
+assert unicodedata.category('½') == 'No', 'category No'
+assert unicodedata.category(' ') == 'Zs', 'category Zs'
+assert unicodedata.category('\n') == 'Cc', 'category Cc'
+assert unicodedata.category('\u0301') == 'Mn', 'category Mn'
+assert unicodedata.category('€') == 'Sc', 'category Sc'
+assert unicodedata.category('\U0010ffff') == 'Cn', 'noncharacters are unassigned'
+assert unicodedata.category('\ue000') == 'Co', 'private use'
+assert unicodedata.bidirectional('A') == 'L', 'bidirectional L'
+assert unicodedata.bidirectional('א') == 'R', 'bidirectional R'
+assert unicodedata.bidirectional('1') == 'EN', 'bidirectional EN'
+assert unicodedata.bidirectional('\U0010ffff') == '', 'unassigned has no bidi class'
+assert unicodedata.combining('a') == 0, 'starter'
+assert unicodedata.combining('\u0301') == 230, 'acute accent combines above'
+assert unicodedata.combining('\u0327') == 202, 'cedilla combines below'
+assert unicodedata.mirrored('(') == 1, 'parenthesis is mirrored'
+assert unicodedata.mirrored('a') == 0, 'letter is not mirrored'
+
+# === east_asian_width ===
+assert unicodedata.east_asian_width('a') == 'Na', 'narrow'
+assert unicodedata.east_asian_width('中') == 'W', 'wide'
+assert unicodedata.east_asian_width('Ａ') == 'F', 'fullwidth'
+assert unicodedata.east_asian_width('ｱ') == 'H', 'halfwidth'
+assert unicodedata.east_asian_width('°') == 'A', 'ambiguous'
+assert unicodedata.east_asian_width('é') == 'A', 'ambiguous latin'
+assert unicodedata.east_asian_width('Ā') == 'N', 'neutral'
+
+# === decomposition ===
+assert unicodedata.decomposition('\xe9') == '0065 0301', 'canonical decomposition'
+assert unicodedata.decomposition('\ufb01') == '<compat> 0066 0069', 'compatibility decomposition'
+assert unicodedata.decomposition('\xbd') == '<fraction> 0031 2044 0032', 'fraction decomposition'
+assert unicodedata.decomposition('\u212b') == '00C5', 'angstrom sign is a singleton'
+assert unicodedata.decomposition('a') == '', 'no decomposition'
+assert unicodedata.decomposition('각') == '1100 1161 11A8', 'hangul decomposes to jamo'
+
+# === decimal / digit / numeric ===
+assert unicodedata.decimal('7') == 7, 'decimal'
+assert unicodedata.decimal('٣') == 3, 'arabic-indic decimal'
+assert unicodedata.digit('²') == 2, 'superscript digit'
+assert unicodedata.numeric('½') == 0.5, 'fraction numeric'
+assert unicodedata.numeric('Ⅻ') == 12.0, 'roman numeral numeric'
+assert unicodedata.numeric('万') == 10000.0, 'CJK numeric'
+assert unicodedata.numeric('5') == 5.0, 'digits are numeric'
+assert unicodedata.decimal('²', None) is None, 'superscripts are not decimal'
+assert unicodedata.digit('½', -1) == -1, 'fractions are not digits'
+assert unicodedata.numeric('a', 'x') == 'x', 'default returned'
+
+try:
+    unicodedata.decimal('a')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'not a decimal', f'unexpected message: {exc}'
+
+try:
+    unicodedata.digit('a')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'not a digit', f'unexpected message: {exc}'
+
+try:
+    unicodedata.numeric('a')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'not a numeric character', f'unexpected message: {exc}'
+
+# === name ===
+assert unicodedata.name('a') == 'LATIN SMALL LETTER A', 'simple name'
+assert unicodedata.name('é') == 'LATIN SMALL LETTER E WITH ACUTE', 'multi-word name'
+assert unicodedata.name('-') == 'HYPHEN-MINUS', 'hyphenated name'
+assert unicodedata.name('😀') == 'GRINNING FACE', 'astral name'
+assert unicodedata.name('中') == 'CJK UNIFIED IDEOGRAPH-4E2D', 'computed CJK name'
+assert unicodedata.name('\uf900') == 'CJK COMPATIBILITY IDEOGRAPH-F900', 'compatibility ideograph name'
+assert unicodedata.name('각') == 'HANGUL SYLLABLE GAG', 'computed hangul name'
+assert unicodedata.name('\x00', 'none') == 'none', 'controls have no name'
+
+try:
+    unicodedata.name('\x00')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'no such name', f'unexpected message: {exc}'
+
+# === lookup ===
+assert unicodedata.lookup('LATIN SMALL LETTER A') == 'a', 'lookup by name'
+assert unicodedata.lookup('latin small letter e with acute') == 'é', 'lookup ignores case'
+assert unicodedata.lookup(b'GRINNING FACE') == '😀', 'lookup accepts bytes'
+assert unicodedata.lookup('CJK UNIFIED IDEOGRAPH-4E2D') == '中', 'lookup computed CJK name'
+assert unicodedata.lookup('HANGUL SYLLABLE GAG') == '각', 'lookup hangul syllable'
+assert unicodedata.lookup('LINE FEED') == '\n', 'lookup name alias'
+assert unicodedata.lookup('BYTE ORDER MARK') == '\ufeff', 'lookup abbreviation alias'
+assert unicodedata.lookup('LATIN CAPITAL LETTER A WITH MACRON AND GRAVE') == '\u0100\u0300', 'lookup named sequence'
+for ch in 'aZ9€ß中각😀':
+    assert unicodedata.lookup(unicodedata.name(ch)) == ch, f'name round trips for {ch!r}'
+
+try:
+    unicodedata.lookup('NOPE')
+    assert False, 'expected KeyError'
+except KeyError as exc:
+    assert str(exc) == '"undefined character name \'NOPE\'"', f'unexpected message: {exc}'
+
+try:
+    unicodedata.lookup('x' * 300)
+    assert False, 'expected KeyError'
+except KeyError as exc:
+    assert str(exc) == "'name too long'", f'unexpected message: {exc}'
+
+try:
+    unicodedata.lookup(5)
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == "a bytes-like object is required, not 'int'", f'unexpected message: {exc}'
+
+# === normalize / is_normalized ===
+assert unicodedata.normalize('NFC', 'e\u0301') == '\xe9', 'NFC composes'
+assert unicodedata.normalize('NFD', '\xe9') == 'e\u0301', 'NFD decomposes'
+assert unicodedata.normalize('NFKC', '\ufb01') == 'fi', 'NFKC expands ligatures'
+assert unicodedata.normalize('NFKC', '①') == '1', 'NFKC circled digit'
+assert unicodedata.normalize('NFKC', 'Ｈｅｌｌｏ') == 'Hello', 'NFKC fullwidth'
+assert unicodedata.normalize('NFKD', '\xbd') == '1\u20442', 'NFKD fraction'
+assert unicodedata.normalize('NFC', '\u212b') == '\xc5', 'NFC replaces singletons'
+assert unicodedata.normalize('NFD', 'd\u0307\u0323') == 'd\u0323\u0307', 'NFD orders combining marks'
+assert unicodedata.normalize('NFC', 'd\u0307\u0323') == '\u1e0d\u0307', 'NFC reorders then composes'
+assert unicodedata.normalize('NFD', '\uac01') == '\u1100\u1161\u11a8', 'NFD hangul'
+assert unicodedata.normalize('NFC', '\u1100\u1161\u11a8') == '\uac01', 'NFC hangul'
+assert unicodedata.normalize('NFC', '') == '', 'empty string'
+assert unicodedata.is_normalized('NFC', '\xe9'), 'composed is NFC'
+assert not unicodedata.is_normalized('NFD', '\xe9'), 'composed is not NFD'
+assert unicodedata.is_normalized('NFKD', 'abc'), 'ASCII is normalized'
+
+try:
+    unicodedata.normalize('NFX', 'a')
+    assert False, 'expected ValueError'
+except ValueError as exc:
+    assert str(exc) == 'invalid normalization form', f'unexpected message: {exc}'
+
+try:
+    unicodedata.normalize('NFC', 5)
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == 'normalize() argument 2 must be str, not int', f'unexpected message: {exc}'
+
+# === argument errors ===
+try:
+    unicodedata.category('ab')
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == 'category() argument must be a unicode character, not str', f'unexpected message: {exc}'
+
+try:
+    unicodedata.combining(5)
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == 'combining() argument must be a unicode character, not int', f'unexpected message: {exc}'
+
+try:
+    unicodedata.name('')
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == 'name() argument 1 must be a unicode character, not str', f'unexpected message: {exc}'
+
+try:
+    unicodedata.east_asian_width(None)
+    assert False, 'expected TypeError'
+except TypeError as exc:
+    assert str(exc) == 'east_asian_width() argument must be a unicode character, not None', (
+        f'unexpected message: {exc}'
+    )
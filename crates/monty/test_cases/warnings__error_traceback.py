@@ -0,0 +1,12 @@
+import warnings
+
+warnings.simplefilter('error')
+warnings.warn('this is now an error', DeprecationWarning)
+"""
+TRACEBACK:
+Traceback (most recent call last):
+  File "warnings__error_traceback.py", line 4, in <module>
+    warnings.warn('this is now an error', DeprecationWarning)
+    ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+DeprecationWarning: this is now an error
+"""
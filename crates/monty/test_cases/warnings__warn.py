@@ -0,0 +1,162 @@
+import warnings
+
+# === hierarchy ===
+assert issubclass(UserWarning, Warning), 'UserWarning is a Warning'
+assert issubclass(DeprecationWarning, Warning), 'DeprecationWarning is a Warning'
+assert issubclass(Warning, Exception), 'Warning is an Exception'
+assert not issubclass(ValueError, Warning), 'ValueError is not a Warning'
+for cls in (
+    PendingDeprecationWarning,
+    SyntaxWarning,
+    RuntimeWarning,
+    FutureWarning,
+    ImportWarning,
+    UnicodeWarning,
+    BytesWarning,
+    ResourceWarning,
+    EncodingWarning,
+):
+    assert issubclass(cls, Warning), f'{cls.__name__} is a Warning'
+
+# === catch_warnings record ===
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('always')
+    warnings.warn('first')
+    warnings.warn('second', DeprecationWarning)
+    warnings.warn(RuntimeWarning('instance'))
+    warnings.warn(5)
+assert len(log) == 4, 'all four warnings recorded'
+w = log[0]
+assert str(w.message) == 'first', 'message text'
+assert isinstance(w.message, UserWarning), 'message is a warning instance'
+assert w.category is UserWarning, 'default category is UserWarning'
+assert w.filename.endswith('warnings__warn.py'), 'filename is the script'
+assert w.lineno == 24, 'lineno of the warn() call'
+assert w.line is None, 'no source line'
+assert log[1].category is DeprecationWarning, 'explicit category'
+assert log[2].category is RuntimeWarning, 'category from instance'
+assert str(log[2].message) == 'instance', 'instance message'
+assert str(log[3].message) == '5', 'non-str message converted with str()'
+
+# === default action shows once per location ===
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('default')
+    for _ in range(3):
+        warnings.warn('looped')
+    warnings.warn('looped')
+assert len(log) == 2, 'default action dedupes per location'
+
+# === once ===
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('once')
+    warnings.warn('same')
+    warnings.warn('same')
+    warnings.warn('other')
+assert [str(w.message) for w in log] == ['same', 'other'], 'once dedupes by text'
+
+# === ignore and error ===
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('ignore')
+    warnings.warn('hidden')
+assert log == [], 'ignored warning not recorded'
+
+with warnings.catch_warnings():
+    warnings.simplefilter('error')
+    try:
+        warnings.warn('boom', RuntimeWarning)
+        raise AssertionError('error action should raise')
+    except RuntimeWarning as e:
+        assert str(e) == 'boom', 'raised warning message'
+
+with warnings.catch_warnings(action='error', category=FutureWarning):
+    try:
+        warnings.warn('future', FutureWarning)
+        raise AssertionError('catch_warnings action should apply')
+    except FutureWarning:
+        pass
+
+# === filters restored on exit ===
+with warnings.catch_warnings():
+    warnings.simplefilter('error')
+with warnings.catch_warnings(record=True) as log:
+    warnings.warn('after restore')
+assert len(log) == 1, 'error filter did not leak out of catch_warnings'
+
+# === filterwarnings ===
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('always')
+    warnings.filterwarnings('ignore', message='SKIP')
+    warnings.warn('skip me')
+    warnings.warn('keep me')
+assert [str(w.message) for w in log] == ['keep me'], 'message regex is a case-insensitive prefix match'
+
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('always')
+    warnings.filterwarnings('ignore', category=DeprecationWarning)
+    warnings.warn('dep', DeprecationWarning)
+    warnings.warn('pending', PendingDeprecationWarning)
+    warnings.warn('user')
+assert [w.category for w in log] == [PendingDeprecationWarning, UserWarning], 'category filter'
+
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('always')
+    warnings.filterwarnings('ignore', module='__main__')
+    warnings.warn('from main')
+assert log == [], 'module filter matches __main__'
+
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('always')
+    warnings.filterwarnings('ignore', lineno=110)
+    warnings.warn('ignored by line')
+    warnings.warn('kept')
+assert [str(w.message) for w in log] == ['kept'], 'lineno filter'
+
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('always')
+    warnings.filterwarnings('ignore', category=UserWarning)
+    warnings.filterwarnings('always', category=UserWarning, append=True)
+    warnings.warn('first filter wins')
+assert log == [], 'appended filter comes after existing ones'
+
+# === resetwarnings ===
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('ignore')
+    warnings.resetwarnings()
+    warnings.warn('visible again')
+assert len(log) == 1, 'resetwarnings removes all filters'
+
+
+# === stacklevel ===
+def deprecated():
+    warnings.warn('old api', DeprecationWarning, stacklevel=2)
+
+
+with warnings.catch_warnings(record=True) as log:
+    warnings.simplefilter('always')
+    deprecated()
+    warnings.warn('too deep', stacklevel=50)
+assert log[0].lineno == 136, 'stacklevel=2 reports the caller'
+assert len(log) == 2, 'stacklevel past the stack still warns'
+
+# === formatwarning ===
+assert warnings.formatwarning('m', UserWarning, 'f.py', 3) == 'f.py:3: UserWarning: m\n', 'formatwarning'
+assert warnings.formatwarning('m', UserWarning, 'f.py', 3, '  x = 1  ') == 'f.py:3: UserWarning: m\n  x = 1\n', (
+    'formatwarning with line'
+)
+
+# === repr ===
+assert repr(warnings.catch_warnings()) == 'catch_warnings()', 'catch_warnings repr'
+assert repr(warnings.catch_warnings(record=True)) == 'catch_warnings(record=True)', 'catch_warnings record repr'
+
+# === invalid arguments ===
+try:
+    warnings.warn('x', int)
+    raise AssertionError('bad category should raise')
+except TypeError as e:
+    assert str(e) == "category must be a Warning subclass, not 'type'", 'bad category message'
+
+try:
+    warnings.simplefilter('bogus')
+    raise AssertionError('bad action should raise')
+except Exception as e:
+    assert str(e) == "invalid action: 'bogus'", 'bad action message'
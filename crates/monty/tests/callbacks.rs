@@ -0,0 +1,471 @@
+//! Tests for callbacks — `MontyObject::Callback`, `ExtFunctionResult::Callback`
+//! and the `CallbackResult` progress variant.
+//!
+//! Once the host opts in with `MontyRun::with_callbacks`, a function defined in the
+//! sandbox reaches an external function as a callback. While the external call is
+//! paused, the host can call it back: the function runs in the sandbox and its result
+//! comes back as a `CallbackResult`, after which the host resumes the external call
+//! as usual.
+
+use monty::{
+    CallbackResult, ExcType, ExtFunctionResult, FunctionCall, HandleOperation, LimitedTracker, MontyException,
+    MontyObject, MontyRun, NameLookupResult, NoLimitTracker, PrintWriter, ResourceLimits, ResourceTracker, RunProgress,
+};
+
+/// Resolves the name lookups of external functions, which the tests call by any name.
+fn step<T: ResourceTracker>(progress: Result<RunProgress<T>, MontyException>) -> RunProgress<T> {
+    let mut progress = progress.unwrap();
+    while let RunProgress::NameLookup(lookup) = progress {
+        let name = lookup.name.clone();
+        let function = MontyObject::Function { name, docstring: None };
+        progress = lookup
+            .resume(NameLookupResult::Value(function), PrintWriter::Stdout)
+            .unwrap();
+    }
+    progress
+}
+
+fn start(code: &str) -> RunProgress<NoLimitTracker> {
+    let run = MontyRun::new(code.to_owned(), "test.py", vec![])
+        .unwrap()
+        .with_callbacks();
+    step(run.start(vec![], NoLimitTracker, PrintWriter::Stdout))
+}
+
+/// Returns the external call `progress` paused at, checking the function's name.
+fn function_call<T: ResourceTracker>(progress: RunProgress<T>, name: &str) -> FunctionCall<T> {
+    let call = progress.into_function_call().expect("expected a function call");
+    assert_eq!(call.function_name, name);
+    call
+}
+
+fn callback_result(progress: RunProgress<NoLimitTracker>) -> CallbackResult<NoLimitTracker> {
+    progress.into_callback_result().expect("expected a callback result")
+}
+
+/// Returns the id of `obj`, which must be a callback for a function called `name`.
+fn callback_id(obj: &MontyObject, name: &str) -> u32 {
+    match obj {
+        MontyObject::Callback {
+            name: callback_name,
+            callback_id,
+        } => {
+            assert_eq!(callback_name, name);
+            *callback_id
+        }
+        other => panic!("expected a callback, got {other:?}"),
+    }
+}
+
+fn call(callback_id: u32, args: Vec<MontyObject>) -> ExtFunctionResult {
+    ExtFunctionResult::Callback {
+        callback_id,
+        args,
+        kwargs: vec![],
+    }
+}
+
+#[test]
+fn retry_until_callback_succeeds() {
+    let code = "
+attempts = []
+def flaky(n):
+    attempts.append(n)
+    if len(attempts) < 3:
+        raise ValueError('not yet')
+    return n * 10
+(retry(flaky, 5), attempts)
+";
+    let call_site = function_call(start(code), "retry");
+    let id = callback_id(&call_site.args[0], "flaky");
+
+    let mut result = callback_result(step(
+        call_site.resume(call(id, vec![MontyObject::Int(5)]), PrintWriter::Stdout),
+    ));
+    let mut failures = 0;
+    while let Err(exc) = &result.result {
+        assert_eq!(exc.exc_type(), ExcType::ValueError);
+        assert_eq!(exc.message(), Some("not yet"));
+        failures += 1;
+        result = callback_result(step(
+            result.resume(call(id, vec![MontyObject::Int(5)]), PrintWriter::Stdout),
+        ));
+    }
+    assert_eq!(failures, 2);
+    assert_eq!(result.callback_id, id);
+    let value = result.result.clone().unwrap();
+    assert_eq!(value, MontyObject::Int(50));
+
+    let complete = step(result.resume(value, PrintWriter::Stdout)).into_complete().unwrap();
+    assert_eq!(
+        complete,
+        MontyObject::Tuple(vec![
+            MontyObject::Int(50),
+            MontyObject::List(vec![MontyObject::Int(5), MontyObject::Int(5), MontyObject::Int(5)]),
+        ])
+    );
+}
+
+#[test]
+fn map_lambda_over_items() {
+    let call_site = function_call(start("pool_map(lambda x: x * x, [1, 2, 3])"), "pool_map");
+    let id = callback_id(&call_site.args[0], "<lambda>");
+    let MontyObject::List(items) = call_site.args[1].clone() else {
+        panic!("expected a list of items");
+    };
+
+    let mut results = Vec::new();
+    let mut progress = call_site.resume(call(id, vec![items[0].clone()]), PrintWriter::Stdout);
+    for next in items.iter().skip(1).map(Some).chain([None]) {
+        let result = callback_result(step(progress));
+        results.push(result.result.clone().unwrap());
+        progress = match next {
+            Some(item) => result.resume(call(id, vec![item.clone()]), PrintWriter::Stdout),
+            None => result.resume(MontyObject::List(results.clone()), PrintWriter::Stdout),
+        };
+    }
+
+    assert_eq!(
+        step(progress).into_complete().unwrap(),
+        MontyObject::List(vec![MontyObject::Int(1), MontyObject::Int(4), MontyObject::Int(9)])
+    );
+}
+
+#[test]
+fn closure_with_keyword_arguments() {
+    let code = "
+def make(offset):
+    def add(x, *, scale=1):
+        return x * scale + offset
+    return add
+apply(make(100))
+";
+    let call_site = function_call(start(code), "apply");
+    let id = callback_id(&call_site.args[0], "add");
+    let callback = ExtFunctionResult::Callback {
+        callback_id: id,
+        args: vec![MontyObject::Int(2)],
+        kwargs: vec![(MontyObject::String("scale".to_owned()), MontyObject::Int(3))],
+    };
+    let result = callback_result(step(call_site.resume(callback, PrintWriter::Stdout)));
+    assert_eq!(result.result, Ok(MontyObject::Int(106)));
+}
+
+#[test]
+fn bad_arguments_raise_in_callback() {
+    let call_site = function_call(start("def f(x):\n    return x\napply(f)"), "apply");
+    let id = callback_id(&call_site.args[0], "f");
+    let result = callback_result(step(call_site.resume(call(id, vec![]), PrintWriter::Stdout)));
+    let exc = result.result.clone().unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TypeError);
+
+    // The external call is still paused and can be resumed as usual
+    let complete = step(result.resume(MontyObject::Int(1), PrintWriter::Stdout));
+    assert_eq!(complete.into_complete().unwrap(), MontyObject::Int(1));
+}
+
+#[test]
+fn host_raises_callback_exception_in_caller() {
+    let code = "
+def bad():
+    raise KeyError('boom')
+try:
+    call(bad)
+except KeyError as e:
+    r = 'caught ' + str(e)
+r
+";
+    let call_site = function_call(start(code), "call");
+    let id = callback_id(&call_site.args[0], "bad");
+    let result = callback_result(step(call_site.resume(call(id, vec![]), PrintWriter::Stdout)));
+    let exc = result.result.clone().unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::KeyError);
+
+    let complete = step(result.resume(exc, PrintWriter::Stdout)).into_complete().unwrap();
+    assert_eq!(complete, MontyObject::String("caught 'boom'".to_owned()));
+}
+
+#[test]
+fn callback_exception_caught_inside_callback() {
+    let code = "
+def safe(x):
+    try:
+        return 1 / x
+    except ZeroDivisionError:
+        return 'inf'
+apply(safe)
+";
+    let call_site = function_call(start(code), "apply");
+    let id = callback_id(&call_site.args[0], "safe");
+    let result = callback_result(step(
+        call_site.resume(call(id, vec![MontyObject::Int(0)]), PrintWriter::Stdout),
+    ));
+    assert_eq!(result.result, Ok(MontyObject::String("inf".to_owned())));
+}
+
+#[test]
+fn callback_makes_external_call() {
+    let code = "
+def process(x):
+    return lookup(x) + 1
+run_job(process)
+";
+    let call_site = function_call(start(code), "run_job");
+    let id = callback_id(&call_site.args[0], "process");
+
+    let nested = function_call(
+        step(call_site.resume(call(id, vec![MontyObject::Int(1)]), PrintWriter::Stdout)),
+        "lookup",
+    );
+    assert_eq!(nested.args, vec![MontyObject::Int(1)]);
+    let result = callback_result(step(nested.resume(MontyObject::Int(41), PrintWriter::Stdout)));
+    assert_eq!(result.result, Ok(MontyObject::Int(42)));
+
+    let complete = step(result.resume(MontyObject::String("done".to_owned()), PrintWriter::Stdout));
+    assert_eq!(
+        complete.into_complete().unwrap(),
+        MontyObject::String("done".to_owned())
+    );
+}
+
+#[test]
+fn nested_callbacks() {
+    let code = "
+def inner(x):
+    return x * 2
+def outer(x):
+    return twice(inner, x) + 1
+apply(outer)
+";
+    let call_site = function_call(start(code), "apply");
+    let outer_id = callback_id(&call_site.args[0], "outer");
+
+    let nested = function_call(
+        step(call_site.resume(call(outer_id, vec![MontyObject::Int(5)]), PrintWriter::Stdout)),
+        "twice",
+    );
+    let inner_id = callback_id(&nested.args[0], "inner");
+    assert_ne!(inner_id, outer_id);
+
+    let x = nested.args[1].clone();
+    let inner_result = callback_result(step(nested.resume(call(inner_id, vec![x]), PrintWriter::Stdout)));
+    assert_eq!(inner_result.callback_id, inner_id);
+    assert_eq!(inner_result.result, Ok(MontyObject::Int(10)));
+
+    let outer_result = callback_result(step(inner_result.resume(MontyObject::Int(10), PrintWriter::Stdout)));
+    assert_eq!(outer_result.callback_id, outer_id);
+    assert_eq!(outer_result.result, Ok(MontyObject::Int(11)));
+
+    let complete = step(outer_result.resume(MontyObject::None, PrintWriter::Stdout));
+    assert_eq!(complete.into_complete().unwrap(), MontyObject::None);
+}
+
+/// A run paused anywhere in a callback's life survives `dump`/`load`, and callback ids
+/// stay valid after loading.
+#[test]
+fn callbacks_survive_dump_and_load() {
+    fn reload(progress: RunProgress<NoLimitTracker>) -> RunProgress<NoLimitTracker> {
+        let bytes = progress.dump().unwrap();
+        RunProgress::load(&bytes).unwrap()
+    }
+
+    let code = "
+def process(x):
+    return lookup(x) * 2
+try:
+    result = run_job(process)
+except ValueError:
+    result = 'failed'
+result
+";
+    let call_site = function_call(reload(start(code)), "run_job");
+    let id = callback_id(&call_site.args[0], "process");
+
+    let nested = function_call(
+        reload(step(
+            call_site.resume(call(id, vec![MontyObject::Int(3)]), PrintWriter::Stdout),
+        )),
+        "lookup",
+    );
+    let result = callback_result(reload(step(nested.resume(MontyObject::Int(4), PrintWriter::Stdout))));
+    assert_eq!(result.result, Ok(MontyObject::Int(8)));
+
+    // Call the callback again after loading, and fail the paused call with its exception
+    let nested = function_call(
+        reload(step(
+            result.resume(call(id, vec![MontyObject::Int(5)]), PrintWriter::Stdout),
+        )),
+        "lookup",
+    );
+    let missing = MontyException::new(ExcType::ValueError, Some("missing".to_owned()));
+    let result = callback_result(reload(step(nested.resume(missing, PrintWriter::Stdout))));
+    let exc = result.result.clone().unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::ValueError);
+
+    let complete = step(result.resume(exc, PrintWriter::Stdout));
+    assert_eq!(
+        complete.into_complete().unwrap(),
+        MontyObject::String("failed".to_owned())
+    );
+}
+
+#[test]
+fn same_function_keeps_its_id() {
+    let code = "
+def f():
+    pass
+def g():
+    pass
+register(f, [f, {'g': g}])
+";
+    let call_site = function_call(start(code), "register");
+    let f_id = callback_id(&call_site.args[0], "f");
+    let MontyObject::List(items) = &call_site.args[1] else {
+        panic!("expected a list");
+    };
+    assert_eq!(callback_id(&items[0], "f"), f_id);
+    let MontyObject::Dict(pairs) = &items[1] else {
+        panic!("expected a dict");
+    };
+    let (_, g) = pairs.into_iter().next().unwrap();
+    assert_ne!(callback_id(g, "g"), f_id);
+}
+
+#[test]
+fn callback_passed_back_is_original_function() {
+    let code = "
+def f(x):
+    return x + 1
+g = identity(f)
+(g is f, g(1))
+";
+    let call_site = function_call(start(code), "identity");
+    let callback = call_site.args[0].clone();
+    let complete = step(call_site.resume(callback, PrintWriter::Stdout));
+    assert_eq!(
+        complete.into_complete().unwrap(),
+        MontyObject::Tuple(vec![MontyObject::Bool(true), MontyObject::Int(2)])
+    );
+}
+
+#[test]
+fn callback_repr_and_type() {
+    let callback = MontyObject::Callback {
+        name: "process".to_owned(),
+        callback_id: 2,
+    };
+    assert_eq!(callback.py_repr(), "<function 'process' callback>");
+    assert_eq!(callback.type_name(), "function");
+}
+
+#[test]
+fn returned_functions_stay_plain_values() {
+    // Only arguments to the host become callbacks; a function as the final result doesn't
+    let complete = start(
+        "def f():
+    pass
+f",
+    )
+    .into_complete()
+    .unwrap();
+    assert!(matches!(complete, MontyObject::Repr(_)), "got {complete:?}");
+}
+
+#[test]
+fn unknown_callback_id_fails_run() {
+    let call_site = function_call(
+        start(
+            "def f():
+    pass
+apply(f)",
+        ),
+        "apply",
+    );
+    let err = call_site.resume(call(99, vec![]), PrintWriter::Stdout).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::RuntimeError);
+    assert_eq!(err.message(), Some("unknown callback id 99"));
+}
+
+#[test]
+fn callback_passed_to_host_handle() {
+    let code = "h.map(lambda row: row['id'])";
+    let handle = MontyObject::HostHandle {
+        type_name: "Cursor".to_owned(),
+        handle_id: 1,
+    };
+    let run = MontyRun::new(code.to_owned(), "test.py", vec!["h".to_owned()])
+        .unwrap()
+        .with_callbacks();
+    let handle_call = step(run.start(vec![handle], NoLimitTracker, PrintWriter::Stdout))
+        .into_handle_call()
+        .unwrap();
+    let HandleOperation::CallMethod { name, args, .. } = &handle_call.operation else {
+        panic!("expected a method call, got {:?}", handle_call.operation);
+    };
+    assert_eq!(name, "map");
+    let id = callback_id(&args[0], "<lambda>");
+
+    let row = MontyObject::dict(vec![(MontyObject::String("id".to_owned()), MontyObject::Int(7))]);
+    let result = callback_result(step(handle_call.resume(call(id, vec![row]), PrintWriter::Stdout)));
+    assert_eq!(result.result, Ok(MontyObject::Int(7)));
+
+    let complete = step(result.resume(MontyObject::List(vec![MontyObject::Int(7)]), PrintWriter::Stdout));
+    assert_eq!(
+        complete.into_complete().unwrap(),
+        MontyObject::List(vec![MontyObject::Int(7)])
+    );
+}
+
+#[test]
+fn functions_are_reprs_without_opt_in() {
+    let run = MontyRun::new("def f():\n    pass\napply(f)".to_owned(), "test.py", vec![]).unwrap();
+    let call_site = function_call(step(run.start(vec![], NoLimitTracker, PrintWriter::Stdout)), "apply");
+    assert!(
+        matches!(call_site.args[0], MontyObject::Repr(_)),
+        "got {:?}",
+        call_site.args[0]
+    );
+}
+
+#[test]
+fn callbacks_released_when_call_returns() {
+    let code = "
+def f():
+    pass
+apply(f)
+wait()
+";
+    let call_site = function_call(start(code), "apply");
+    let id = callback_id(&call_site.args[0], "f");
+    let wait = function_call(step(call_site.resume(MontyObject::None, PrintWriter::Stdout)), "wait");
+    let err = wait.resume(call(id, vec![]), PrintWriter::Stdout).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::RuntimeError);
+    assert_eq!(err.message(), Some(format!("unknown callback id {id}").as_str()));
+}
+
+/// Registrations are charged to the resource tracker until they're released.
+#[test]
+fn callbacks_charged_to_tracker() {
+    fn memory_at_calls(callbacks: bool) -> (usize, usize) {
+        let code = "
+def f():
+    pass
+apply(f)
+wait()
+";
+        let mut run = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+        if callbacks {
+            run = run.with_callbacks();
+        }
+        let tracker = LimitedTracker::new(ResourceLimits::new());
+        let mut apply = function_call(step(run.start(vec![], tracker, PrintWriter::Stdout)), "apply");
+        let at_apply = apply.tracker_mut().current_memory();
+        let mut wait = function_call(step(apply.resume(MontyObject::None, PrintWriter::Stdout)), "wait");
+        (at_apply, wait.tracker_mut().current_memory())
+    }
+
+    let (with_at_apply, with_at_wait) = memory_at_calls(true);
+    let (without_at_apply, without_at_wait) = memory_at_calls(false);
+    assert!(with_at_apply > without_at_apply);
+    assert_eq!(with_at_wait, without_at_wait);
+}
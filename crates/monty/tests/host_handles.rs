@@ -0,0 +1,404 @@
+//! Tests for host handles — `MontyObject::HostHandle` and the `HandleCall`
+//! progress variant.
+//!
+//! A handle stands for an object that stays on the host. Sandbox code can pass
+//! it around freely, but every operation that needs the real object pauses
+//! execution with a `HandleCall` describing the operation.
+
+use monty::{
+    ExcType, ExtFunctionResult, HandleOperation, MontyException, MontyObject, MontyRun, NoLimitTracker, PrintWriter,
+    RunProgress,
+};
+
+fn cursor(handle_id: u64) -> MontyObject {
+    MontyObject::HostHandle {
+        type_name: "Cursor".to_owned(),
+        handle_id,
+    }
+}
+
+fn start(code: &str) -> Result<RunProgress<NoLimitTracker>, MontyException> {
+    MontyRun::new(code.to_owned(), "test.py", vec!["h".to_owned()])
+        .unwrap()
+        .start(vec![cursor(7)], NoLimitTracker, PrintWriter::Stdout)
+}
+
+/// Runs `code` with `h` bound to a handle, answering each operation on it with `respond`.
+///
+/// Returns the result of the run and the operations the host was asked to perform.
+fn run_with_host(
+    code: &str,
+    mut respond: impl FnMut(&HandleOperation) -> ExtFunctionResult,
+) -> (Result<MontyObject, MontyException>, Vec<HandleOperation>) {
+    let mut progress = start(code);
+    let mut operations = Vec::new();
+    loop {
+        match progress {
+            Ok(RunProgress::Complete(value)) => return (Ok(value), operations),
+            Ok(RunProgress::HandleCall(call)) => {
+                assert_eq!(call.type_name, "Cursor");
+                assert_eq!(call.handle_id, 7);
+                let result = respond(&call.operation);
+                operations.push(call.operation.clone());
+                progress = call.resume(result, PrintWriter::Stdout);
+            }
+            Ok(other) => panic!("unexpected progress: {other:?}"),
+            Err(err) => return (Err(err), operations),
+        }
+    }
+}
+
+fn str_obj(s: &str) -> MontyObject {
+    MontyObject::String(s.to_owned())
+}
+
+fn exc(exc_type: ExcType, message: &str) -> ExtFunctionResult {
+    MontyException::new(exc_type, Some(message.to_owned())).into()
+}
+
+// ---------------------------------------------------------------------------
+// Attributes, items and calls
+// ---------------------------------------------------------------------------
+
+#[test]
+fn get_attribute() {
+    let (result, ops) = run_with_host("h.rowcount + 1", |_| MontyObject::Int(3).into());
+    assert_eq!(result.unwrap(), MontyObject::Int(4));
+    assert_eq!(
+        ops,
+        vec![HandleOperation::GetAttr {
+            name: "rowcount".to_owned()
+        }]
+    );
+}
+
+#[test]
+fn set_attribute() {
+    let (result, ops) = run_with_host("h.limit = 10\n'done'", |_| MontyObject::None.into());
+    assert_eq!(result.unwrap(), str_obj("done"));
+    assert_eq!(
+        ops,
+        vec![HandleOperation::SetAttr {
+            name: "limit".to_owned(),
+            value: MontyObject::Int(10),
+        }]
+    );
+}
+
+/// `setattr()` returns `None` whatever the host answers.
+#[test]
+fn setattr_builtin() {
+    let (result, ops) = run_with_host("setattr(h, 'limit', 5)", |_| MontyObject::Int(1).into());
+    assert_eq!(result.unwrap(), MontyObject::None);
+    assert_eq!(
+        ops,
+        vec![HandleOperation::SetAttr {
+            name: "limit".to_owned(),
+            value: MontyObject::Int(5),
+        }]
+    );
+}
+
+#[test]
+fn get_item() {
+    let (result, ops) = run_with_host("h['name']", |_| str_obj("monty").into());
+    assert_eq!(result.unwrap(), str_obj("monty"));
+    assert_eq!(ops, vec![HandleOperation::GetItem { key: str_obj("name") }]);
+}
+
+#[test]
+fn call_handle() {
+    let (result, ops) = run_with_host("h(1, size=2)", |_| MontyObject::Bool(true).into());
+    assert_eq!(result.unwrap(), MontyObject::Bool(true));
+    assert_eq!(
+        ops,
+        vec![HandleOperation::Call {
+            args: vec![MontyObject::Int(1)],
+            kwargs: vec![(str_obj("size"), MontyObject::Int(2))],
+        }]
+    );
+}
+
+#[test]
+fn call_method() {
+    let code = "rows = h.fetchmany(2)\nrows[1]";
+    let (result, ops) = run_with_host(code, |_| {
+        MontyObject::List(vec![MontyObject::Int(10), MontyObject::Int(20)]).into()
+    });
+    assert_eq!(result.unwrap(), MontyObject::Int(20));
+    assert_eq!(
+        ops,
+        vec![HandleOperation::CallMethod {
+            name: "fetchmany".to_owned(),
+            args: vec![MontyObject::Int(2)],
+            kwargs: vec![],
+        }]
+    );
+}
+
+/// Exceptions the host raises surface at the operation and can be caught.
+#[test]
+fn host_exception_caught() {
+    let code = "try:\n    h.closed\nexcept AttributeError as e:\n    x = str(e)\nx";
+    let (result, _) = run_with_host(code, |_| exc(ExcType::AttributeError, "no attribute 'closed'"));
+    assert_eq!(result.unwrap(), str_obj("no attribute 'closed'"));
+}
+
+/// `getattr()` with a default returns it when the host raises `AttributeError`.
+#[test]
+fn getattr_default() {
+    let (result, ops) = run_with_host("getattr(h, 'missing', 'fallback')", |_| {
+        exc(ExcType::AttributeError, "missing")
+    });
+    assert_eq!(result.unwrap(), str_obj("fallback"));
+    assert_eq!(
+        ops,
+        vec![HandleOperation::GetAttr {
+            name: "missing".to_owned()
+        }]
+    );
+
+    let (result, _) = run_with_host("getattr(h, 'present', 'fallback')", |_| MontyObject::Int(1).into());
+    assert_eq!(result.unwrap(), MontyObject::Int(1));
+
+    // Other exceptions propagate
+    let (result, _) = run_with_host("getattr(h, 'broken', 'fallback')", |_| exc(ExcType::KeyError, "k"));
+    assert_eq!(result.unwrap_err().exc_type(), ExcType::KeyError);
+}
+
+// ---------------------------------------------------------------------------
+// Iteration, len() and truth tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn iterate() {
+    let code = "total = 0\nfor row in h:\n    total += row\ntotal";
+    let (result, ops) = run_with_host(code, |_| {
+        MontyObject::List(vec![MontyObject::Int(1), MontyObject::Int(2), MontyObject::Int(3)]).into()
+    });
+    assert_eq!(result.unwrap(), MontyObject::Int(6));
+    assert_eq!(ops, vec![HandleOperation::Iter]);
+
+    let (result, _) = run_with_host("[x * 2 for x in h]", |_| {
+        MontyObject::Tuple(vec![MontyObject::Int(1), MontyObject::Int(2)]).into()
+    });
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::List(vec![MontyObject::Int(2), MontyObject::Int(4)])
+    );
+
+    let (result, ops) = run_with_host("list(iter(h))", |_| str_obj("ab").into());
+    assert_eq!(result.unwrap(), MontyObject::List(vec![str_obj("a"), str_obj("b")]));
+    assert_eq!(ops, vec![HandleOperation::Iter]);
+}
+
+#[test]
+fn iterate_non_iterable_result() {
+    let (result, _) = run_with_host("for x in h:\n    pass", |_| MontyObject::Int(1).into());
+    let err = result.unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::TypeError);
+    assert_eq!(err.message(), Some("'int' object is not iterable"));
+}
+
+#[test]
+fn len() {
+    let (result, ops) = run_with_host("len(h)", |_| MontyObject::Int(3).into());
+    assert_eq!(result.unwrap(), MontyObject::Int(3));
+    assert_eq!(ops, vec![HandleOperation::Len]);
+
+    let (result, _) = run_with_host("len(h)", |_| MontyObject::Int(-1).into());
+    let err = result.unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ValueError);
+    assert_eq!(err.message(), Some("__len__() should return >= 0"));
+
+    let (result, _) = run_with_host("len(h)", |_| str_obj("3").into());
+    let err = result.unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::TypeError);
+    assert_eq!(err.message(), Some("'str' object cannot be interpreted as an integer"));
+}
+
+#[test]
+fn truth_tests() {
+    for (code, truth, expected) in [
+        ("bool(h)", false, MontyObject::Bool(false)),
+        ("not h", false, MontyObject::Bool(true)),
+        ("not h", true, MontyObject::Bool(false)),
+        ("'yes' if h else 'no'", true, str_obj("yes")),
+        ("'yes' if h else 'no'", false, str_obj("no")),
+        ("x = 0\nwhile h:\n    x += 1\n    break\nx", true, MontyObject::Int(1)),
+        ("h and 'rows'", true, str_obj("rows")),
+        ("h and 'rows'", false, cursor(7)),
+        ("h or 'empty'", true, cursor(7)),
+        ("h or 'empty'", false, str_obj("empty")),
+    ] {
+        let (result, ops) = run_with_host(code, |_| MontyObject::Bool(truth).into());
+        assert_eq!(result.unwrap(), expected, "{code} with {truth}");
+        assert_eq!(ops, vec![HandleOperation::Bool], "{code}");
+    }
+}
+
+/// Like `__bool__`, the host must answer truth tests with a bool.
+#[test]
+fn truth_test_non_bool_result() {
+    for code in ["bool(h)", "not h", "if h:\n    pass", "h or 1"] {
+        let (result, _) = run_with_host(code, |_| MontyObject::Int(1).into());
+        let err = result.unwrap_err();
+        assert_eq!(err.exc_type(), ExcType::TypeError, "{code}");
+        assert_eq!(
+            err.message(),
+            Some("__bool__ should return bool, returned int"),
+            "{code}"
+        );
+    }
+}
+
+/// Builtins that consume an iterable, and unpacking, ask for `iter(h)` and run on
+/// the host's answer.
+#[test]
+fn builtins_iterate_through_host() {
+    let ints = |values: &[i64]| values.iter().map(|&v| MontyObject::Int(v)).collect::<Vec<_>>();
+    for (code, expected) in [
+        ("list(h)", MontyObject::List(ints(&[3, 1, 2]))),
+        ("tuple(h)", MontyObject::Tuple(ints(&[3, 1, 2]))),
+        ("sorted(h)", MontyObject::List(ints(&[1, 2, 3]))),
+        ("sum(h)", MontyObject::Int(6)),
+        ("max(h)", MontyObject::Int(3)),
+        ("any(h)", MontyObject::Bool(true)),
+        ("all(h)", MontyObject::Bool(true)),
+        ("a, b, c = h\nb", MontyObject::Int(1)),
+        ("a, *rest = h\nrest", MontyObject::List(ints(&[1, 2]))),
+    ] {
+        let (result, ops) = run_with_host(code, |_| MontyObject::List(ints(&[3, 1, 2])).into());
+        assert_eq!(result.unwrap(), expected, "{code}");
+        assert_eq!(ops, vec![HandleOperation::Iter], "{code}");
+    }
+
+    let (result, _) = run_with_host("a, b = h", |_| MontyObject::List(ints(&[3, 1, 2])).into());
+    assert_eq!(result.unwrap_err().exc_type(), ExcType::ValueError);
+}
+
+/// `hasattr()` asks the host for the attribute and is false if it raises `AttributeError`.
+#[test]
+fn hasattr_builtin() {
+    let (result, ops) = run_with_host("hasattr(h, 'rowcount')", |_| MontyObject::Int(1).into());
+    assert_eq!(result.unwrap(), MontyObject::Bool(true));
+    assert_eq!(
+        ops,
+        vec![HandleOperation::GetAttr {
+            name: "rowcount".to_owned()
+        }]
+    );
+
+    let (result, _) = run_with_host("hasattr(h, 'missing')", |_| exc(ExcType::AttributeError, "missing"));
+    assert_eq!(result.unwrap(), MontyObject::Bool(false));
+
+    let (result, _) = run_with_host("hasattr(h, 'broken')", |_| exc(ExcType::KeyError, "k"));
+    assert_eq!(result.unwrap_err().exc_type(), ExcType::KeyError);
+}
+
+/// Builtins that would have to ask the host midway raise `NotImplementedError`
+/// rather than guessing.
+#[test]
+fn builtins_that_cannot_ask_the_host() {
+    let truth = "the truth value of a host handle ('Cursor') can't be tested here; call bool() on it first";
+    let iteration = "a host handle ('Cursor') can't be iterated here; call iter() on it first";
+    for (code, message) in [
+        ("any([h])", truth),
+        ("all([1, h])", truth),
+        ("list(filter(None, [h]))", truth),
+        ("sorted(h, reverse=True)", iteration),
+        ("list(zip(h, [1]))", iteration),
+    ] {
+        let (result, ops) = run_with_host(code, |_| panic!("{code} asked the host"));
+        let err = result.unwrap_err();
+        assert_eq!(err.exc_type(), ExcType::NotImplementedError, "{code}");
+        assert_eq!(err.message(), Some(message), "{code}");
+        assert!(ops.is_empty(), "{code}");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Operations answered in the sandbox
+// ---------------------------------------------------------------------------
+
+/// Returning a handle gives the host back the same handle.
+#[test]
+fn handle_round_trips() {
+    let (result, ops) = run_with_host("x = [h]\nx[0]", |_| unreachable!());
+    assert_eq!(result.unwrap(), cursor(7));
+    assert!(ops.is_empty());
+}
+
+#[test]
+fn repr_and_type() {
+    let code = "(repr(h), repr(type(h)), isinstance(h, type(h)), isinstance(h, object), type(h) == type(h))";
+    let (result, ops) = run_with_host(code, |_| unreachable!());
+    assert_eq!(
+        result.unwrap(),
+        MontyObject::Tuple(vec![
+            str_obj("<Cursor handle 7>"),
+            str_obj("<class 'Cursor'>"),
+            MontyObject::Bool(true),
+            MontyObject::Bool(true),
+            MontyObject::Bool(true),
+        ])
+    );
+    assert!(ops.is_empty());
+}
+
+/// Handles with the same type name and id refer to the same host object.
+#[test]
+fn equality_and_hash() {
+    let runner = MontyRun::new(
+        "(a == b, a == c, len({a, b, c}))".to_owned(),
+        "test.py",
+        vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+    )
+    .unwrap();
+    let result = runner.run_no_limits(vec![cursor(1), cursor(1), cursor(2)]).unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![
+            MontyObject::Bool(true),
+            MontyObject::Bool(false),
+            MontyObject::Int(2)
+        ])
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Hosts
+// ---------------------------------------------------------------------------
+
+/// A paused handle operation, including how its result is used, survives `dump`/`load`.
+#[test]
+fn handle_call_serializes() {
+    let progress = start("'yes' if h else 'no'").unwrap();
+    let bytes = progress.dump().unwrap();
+    let progress = RunProgress::<NoLimitTracker>::load(&bytes).unwrap();
+
+    let call = progress.into_handle_call().expect("expected HandleCall");
+    assert_eq!(call.operation, HandleOperation::Bool);
+    let result = call.resume(MontyObject::Bool(false), PrintWriter::Stdout).unwrap();
+    assert_eq!(result.into_complete().unwrap(), str_obj("no"));
+}
+
+/// Without a host to ask, operations on handles raise `NotImplementedError`.
+#[test]
+fn run_to_completion() {
+    let runner = MontyRun::new("h.rowcount".to_owned(), "test.py", vec!["h".to_owned()]).unwrap();
+    let err = runner.run_no_limits(vec![cursor(7)]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::NotImplementedError);
+}
+
+/// Only operations whose result is used as-is can return a future.
+#[test]
+fn pending_result_rejected() {
+    let call = start("len(h)")
+        .unwrap()
+        .into_handle_call()
+        .expect("expected HandleCall");
+    let err = call.resume_pending(PrintWriter::Stdout).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::TypeError);
+    assert_eq!(err.message(), Some("this host handle operation can't return a future"));
+}
@@ -0,0 +1,230 @@
+//! Tests for host-defined modules — `MontyRun::with_modules` and the
+//! `ModuleLookup` progress variant.
+//!
+//! Imports of a module that isn't part of the standard library are resolved
+//! against the modules the host declared up front; any other module yields
+//! `RunProgress::ModuleLookup` so the host can supply it lazily or let the
+//! import raise `ModuleNotFoundError`.
+
+use monty::{
+    ExcType, ModuleLookupResult, MontyModule, MontyObject, MontyRepl, MontyRun, NoLimitTracker, PrintWriter,
+    ReplProgress,
+};
+
+/// The `mytools` module used by most tests: a function, a constant and a submodule.
+fn mytools() -> MontyModule {
+    MontyModule::new("mytools")
+        .with_function("search")
+        .with_attr("VERSION", MontyObject::Int(2))
+        .with_submodule(MontyModule::new("config").with_attr("DEBUG", MontyObject::Bool(true)))
+}
+
+fn run(code: &str, modules: Vec<MontyModule>) -> Result<MontyObject, monty::MontyException> {
+    MontyRun::new(code.to_owned(), "test.py", vec![])
+        .unwrap()
+        .with_modules(modules)
+        .run_no_limits(vec![])
+}
+
+// ---------------------------------------------------------------------------
+// Modules declared up front
+// ---------------------------------------------------------------------------
+
+#[test]
+fn import_declared_module() {
+    let result = run("import mytools\nmytools.VERSION", vec![mytools()]).unwrap();
+    assert_eq!(result, MontyObject::Int(2));
+}
+
+#[test]
+fn import_as_and_from_import() {
+    let code = "import mytools as t\nfrom mytools import VERSION as v, config\n(t.VERSION, v, config.DEBUG)";
+    let result = run(code, vec![mytools()]).unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![MontyObject::Int(2), MontyObject::Int(2), MontyObject::Bool(true)])
+    );
+}
+
+/// `import a.b` binds the top-level package `a`, like CPython.
+#[test]
+fn import_dotted_name_binds_top_level() {
+    let result = run("import mytools.config\nmytools.config.DEBUG", vec![mytools()]).unwrap();
+    assert_eq!(result, MontyObject::Bool(true));
+
+    let result = run("from mytools.config import DEBUG\nDEBUG", vec![mytools()]).unwrap();
+    assert_eq!(result, MontyObject::Bool(true));
+
+    let result = run("import mytools.config as cfg\ncfg.DEBUG", vec![mytools()]).unwrap();
+    assert_eq!(result, MontyObject::Bool(true));
+}
+
+#[test]
+fn module_repr_and_name() {
+    let result = run("import mytools.config\nrepr(mytools.config)", vec![mytools()]).unwrap();
+    assert_eq!(result, MontyObject::String("<module 'mytools.config'>".to_owned()));
+}
+
+#[test]
+fn import_missing_attribute() {
+    let err = run("from mytools import missing", vec![mytools()]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ImportError);
+}
+
+#[test]
+fn import_missing_submodule() {
+    let err = run("import mytools.other", vec![mytools()]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ModuleNotFoundError);
+    assert_eq!(err.message(), Some("No module named 'mytools.other'"));
+}
+
+/// Without a host to ask, an unknown module raises `ModuleNotFoundError`,
+/// which the code can catch.
+#[test]
+fn unknown_module_run_to_completion() {
+    let err = run("import nothere", vec![]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ModuleNotFoundError);
+    assert_eq!(err.message(), Some("No module named 'nothere'"));
+
+    let code = "try:\n    import nothere\nexcept ImportError:\n    x = 'caught'\nx";
+    assert_eq!(run(code, vec![]).unwrap(), MontyObject::String("caught".to_owned()));
+}
+
+/// Calling a module function yields a `FunctionCall` with the function's name.
+#[test]
+fn call_module_function() {
+    let runner = MontyRun::new(
+        "from mytools import search\nsearch('monty', limit=3)".to_owned(),
+        "test.py",
+        vec![],
+    )
+    .unwrap()
+    .with_modules(vec![mytools()]);
+    let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+
+    let call = progress.into_function_call().expect("expected FunctionCall");
+    assert_eq!(call.function_name, "search");
+    assert_eq!(call.args, vec![MontyObject::String("monty".to_owned())]);
+    assert_eq!(
+        call.kwargs,
+        vec![(MontyObject::String("limit".to_owned()), MontyObject::Int(3))]
+    );
+
+    let result = call.resume(MontyObject::Int(7), PrintWriter::Stdout).unwrap();
+    assert_eq!(result.into_complete().unwrap(), MontyObject::Int(7));
+}
+
+/// Declared modules survive a `dump`/`load` round trip of the runner.
+#[test]
+fn declared_modules_serialize() {
+    let runner = MontyRun::new("import mytools\nmytools.VERSION".to_owned(), "test.py", vec![])
+        .unwrap()
+        .with_modules(vec![mytools()]);
+    let runner = MontyRun::load(&runner.dump().unwrap()).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(2));
+}
+
+// ---------------------------------------------------------------------------
+// ModuleLookup
+// ---------------------------------------------------------------------------
+
+#[test]
+fn module_lookup_resume_with_module() {
+    let code = "import lazy\nfrom lazy import VALUE\nlazy.VALUE + VALUE";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+
+    let lookup = progress.into_module_lookup().expect("expected ModuleLookup");
+    assert_eq!(lookup.name, "lazy");
+
+    // The name given to the module doesn't matter, it's filed under the imported name.
+    // The second import reuses the supplied module without another lookup.
+    let module = MontyModule::new("anything").with_attr("VALUE", MontyObject::Int(21));
+    let progress = lookup.resume(module, PrintWriter::Stdout).unwrap();
+    assert_eq!(progress.into_complete().unwrap(), MontyObject::Int(42));
+}
+
+#[test]
+fn module_lookup_not_found() {
+    let runner = MontyRun::new("import lazy".to_owned(), "test.py", vec![]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+
+    let lookup = progress.into_module_lookup().expect("expected ModuleLookup");
+    let err = lookup
+        .resume(ModuleLookupResult::NotFound, PrintWriter::Stdout)
+        .unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ModuleNotFoundError);
+    assert_eq!(err.message(), Some("No module named 'lazy'"));
+}
+
+#[test]
+fn module_lookup_dotted_name() {
+    let runner = MontyRun::new("from pkg.sub import X\nX".to_owned(), "test.py", vec![]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+
+    let lookup = progress.into_module_lookup().expect("expected ModuleLookup");
+    assert_eq!(lookup.name, "pkg.sub");
+    let module = MontyModule::new("pkg.sub").with_attr("X", MontyObject::None);
+    let progress = lookup.resume(module, PrintWriter::Stdout).unwrap();
+    assert_eq!(progress.into_complete().unwrap(), MontyObject::None);
+}
+
+/// Declared modules don't yield a lookup; other imports still do.
+#[test]
+fn module_lookup_only_for_undeclared_modules() {
+    let code = "import mytools\nimport json\nimport lazy\nmytools.VERSION";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![])
+        .unwrap()
+        .with_modules(vec![mytools()]);
+    let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+
+    let lookup = progress.into_module_lookup().expect("expected ModuleLookup");
+    assert_eq!(lookup.name, "lazy");
+    let progress = lookup.resume(MontyModule::new("lazy"), PrintWriter::Stdout).unwrap();
+    assert_eq!(progress.into_complete().unwrap(), MontyObject::Int(2));
+}
+
+/// Imports under `if TYPE_CHECKING:` never run, so never yield a lookup.
+#[test]
+fn type_checking_import_does_not_look_up() {
+    let code = "from typing import TYPE_CHECKING\nif TYPE_CHECKING:\n    import lazy\n1";
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+    assert_eq!(progress.into_complete().unwrap(), MontyObject::Int(1));
+}
+
+// ---------------------------------------------------------------------------
+// REPL
+// ---------------------------------------------------------------------------
+
+#[test]
+fn repl_declared_modules() {
+    let mut repl = MontyRepl::new("repl.py", NoLimitTracker).with_modules(vec![mytools()]);
+    repl.feed_run("import mytools", vec![], PrintWriter::Stdout).unwrap();
+    let result = repl.feed_run("mytools.VERSION", vec![], PrintWriter::Stdout).unwrap();
+    assert_eq!(result, MontyObject::Int(2));
+}
+
+/// A module supplied for a REPL lookup stays importable in later snippets.
+#[test]
+fn repl_module_lookup_persists() {
+    let repl = MontyRepl::new("repl.py", NoLimitTracker);
+    let progress = repl.feed_start("import lazy", vec![], PrintWriter::Stdout).unwrap();
+
+    let ReplProgress::ModuleLookup(lookup) = progress else {
+        panic!("expected ModuleLookup");
+    };
+    assert_eq!(lookup.name, "lazy");
+    let module = MontyModule::new("lazy").with_attr("X", MontyObject::Int(5));
+    let (repl, _) = lookup
+        .resume(module, PrintWriter::Stdout)
+        .unwrap()
+        .into_complete()
+        .expect("expected Complete");
+
+    let progress = repl
+        .feed_start("from lazy import X\nX", vec![], PrintWriter::Stdout)
+        .unwrap();
+    let (_, value) = progress.into_complete().expect("expected Complete");
+    assert_eq!(value, MontyObject::Int(5));
+}
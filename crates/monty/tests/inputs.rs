@@ -84,6 +84,29 @@ fn input_bytes() {
     assert_eq!(result, MontyObject::Bytes(vec![1, 2, 3]));
 }
 
+#[test]
+fn input_bytearray() {
+    let ex = MontyRun::new("x.append(4)\nx".to_owned(), "test.py", vec!["x".to_owned()]).unwrap();
+    let result = ex.run_no_limits(vec![MontyObject::ByteArray(vec![1, 2, 3])]).unwrap();
+    assert_eq!(result, MontyObject::ByteArray(vec![1, 2, 3, 4]));
+}
+
+#[test]
+fn input_complex() {
+    let ex = MontyRun::new("x * 2j".to_owned(), "test.py", vec!["x".to_owned()]).unwrap();
+    let result = ex
+        .run_no_limits(vec![MontyObject::Complex { real: 1.0, imag: 2.0 }])
+        .unwrap();
+    assert_eq!(result, MontyObject::Complex { real: -4.0, imag: 2.0 });
+}
+
+#[test]
+fn output_memoryview_as_bytes() {
+    let ex = MontyRun::new("memoryview(b'abcd')[1:3]".to_owned(), "test.py", vec![]).unwrap();
+    let result = ex.run_no_limits(vec![]).unwrap();
+    assert_eq!(result, MontyObject::Bytes(b"bc".to_vec()));
+}
+
 #[test]
 fn input_list() {
     let ex = MontyRun::new("x".to_owned(), "test.py", vec!["x".to_owned()]).unwrap();
@@ -228,14 +251,14 @@ fn input_exception() {
     let result = ex
         .run_no_limits(vec![MontyObject::Exception {
             exc_type: ExcType::ValueError,
-            arg: Some("test message".to_string()),
+            args: vec![MontyObject::String("test message".to_string())],
         }])
         .unwrap();
     assert_eq!(
         result,
         MontyObject::Exception {
             exc_type: ExcType::ValueError,
-            arg: Some("test message".to_string()),
+            args: vec![MontyObject::String("test message".to_string())],
         }
     );
 }
@@ -246,14 +269,14 @@ fn input_exception_no_arg() {
     let result = ex
         .run_no_limits(vec![MontyObject::Exception {
             exc_type: ExcType::TypeError,
-            arg: None,
+            args: vec![],
         }])
         .unwrap();
     assert_eq!(
         result,
         MontyObject::Exception {
             exc_type: ExcType::TypeError,
-            arg: None,
+            args: vec![],
         }
     );
 }
@@ -264,14 +287,14 @@ fn input_exception_in_list() {
     let result = ex
         .run_no_limits(vec![MontyObject::List(vec![MontyObject::Exception {
             exc_type: ExcType::KeyError,
-            arg: Some("key".to_string()),
+            args: vec![MontyObject::String("key".to_string())],
         }])])
         .unwrap();
     assert_eq!(
         result,
         MontyObject::Exception {
             exc_type: ExcType::KeyError,
-            arg: Some("key".to_string()),
+            args: vec![MontyObject::String("key".to_string())],
         }
     );
 }
@@ -282,13 +305,112 @@ fn input_exception_raise() {
     let ex = MontyRun::new("raise x".to_owned(), "test.py", vec!["x".to_owned()]).unwrap();
     let result = ex.run_no_limits(vec![MontyObject::Exception {
         exc_type: ExcType::ValueError,
-        arg: Some("input error".to_string()),
+        args: vec![MontyObject::String("input error".to_string())],
     }]);
     let exc = result.unwrap_err();
     assert_eq!(exc.exc_type(), ExcType::ValueError);
     assert_eq!(exc.message(), Some("input error"));
 }
 
+// === Host Class Tests ===
+
+fn point(type_id: u64, x: i64) -> MontyObject {
+    MontyObject::Dataclass {
+        name: "Point".to_string(),
+        type_id,
+        field_names: vec!["x".to_string()],
+        attrs: vec![(MontyObject::String("x".to_string()), MontyObject::Int(x))].into(),
+        frozen: true,
+    }
+}
+
+fn point_type(type_id: u64) -> MontyObject {
+    MontyObject::DataclassType {
+        name: "Point".to_string(),
+        type_id,
+        field_names: vec!["x".to_string()],
+    }
+}
+
+#[test]
+fn input_dataclass_type_isinstance() {
+    let code = "(isinstance(p, Point), isinstance(p, Other), isinstance(p, (int, Point)), isinstance(1, Point))";
+    let ex = MontyRun::new(
+        code.to_owned(),
+        "test.py",
+        vec!["p".to_owned(), "Point".to_owned(), "Other".to_owned()],
+    )
+    .unwrap();
+    let result = ex
+        .run_no_limits(vec![point(1, 5), point_type(1), point_type(2)])
+        .unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![
+            MontyObject::Bool(true),
+            MontyObject::Bool(false),
+            MontyObject::Bool(true),
+            MontyObject::Bool(false),
+        ])
+    );
+}
+
+#[test]
+fn input_dataclass_type_of_instance() {
+    let code = "(type(p) == Point, type(p) == type(p), issubclass(type(p), Point), type(p).__name__)";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec!["p".to_owned(), "Point".to_owned()]).unwrap();
+    let result = ex.run_no_limits(vec![point(1, 5), point_type(1)]).unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![
+            MontyObject::Bool(true),
+            MontyObject::Bool(true),
+            MontyObject::Bool(true),
+            MontyObject::String("Point".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn output_dataclass_type_of_instance() {
+    let ex = MontyRun::new("type(p)".to_owned(), "test.py", vec!["p".to_owned()]).unwrap();
+    let result = ex.run_no_limits(vec![point(7, 5)]).unwrap();
+    assert_eq!(result, point_type(7));
+}
+
+#[test]
+fn input_namedtuple_type_isinstance() {
+    let pair = MontyObject::NamedTuple {
+        type_name: "Pair".to_string(),
+        field_names: vec!["a".to_string(), "b".to_string()],
+        values: vec![MontyObject::Int(1), MontyObject::Int(2)],
+    };
+    let pair_type = MontyObject::NamedTupleType {
+        type_name: "Pair".to_string(),
+        field_names: vec!["a".to_string(), "b".to_string()],
+    };
+    let other_type = MontyObject::NamedTupleType {
+        type_name: "Pair".to_string(),
+        field_names: vec!["first".to_string(), "second".to_string()],
+    };
+    let ex = MontyRun::new(
+        "(isinstance(p, Pair), isinstance(p, Other), type(p) == Pair, type(p))".to_owned(),
+        "test.py",
+        vec!["p".to_owned(), "Pair".to_owned(), "Other".to_owned()],
+    )
+    .unwrap();
+    let result = ex.run_no_limits(vec![pair, pair_type.clone(), other_type]).unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![
+            MontyObject::Bool(true),
+            MontyObject::Bool(false),
+            MontyObject::Bool(true),
+            pair_type,
+        ])
+    );
+}
+
 // === Invalid Input Tests ===
 
 #[test]
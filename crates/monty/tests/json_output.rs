@@ -95,14 +95,14 @@ fn json_output_set_and_frozenset_tagged() {
 fn json_output_exception_tagged() {
     let obj = MontyObject::Exception {
         exc_type: ExcType::ValueError,
-        arg: Some("oops".into()),
+        args: vec![MontyObject::String("oops".to_string())],
     };
-    assert_snapshot!(to_json(&obj), @r#"{"$exception":{"type":"ValueError","arg":"oops"}}"#);
+    assert_snapshot!(to_json(&obj), @r#"{"$exception":{"type":"ValueError","args":["oops"]}}"#);
 
-    // `arg` omitted when None.
+    // `args` omitted when empty.
     let obj = MontyObject::Exception {
         exc_type: ExcType::TypeError,
-        arg: None,
+        args: vec![],
     };
     assert_snapshot!(to_json(&obj), @r#"{"$exception":{"type":"TypeError"}}"#);
 }
@@ -152,7 +152,7 @@ fn json_output_dict_none_and_bool_keys_tagged() {
 fn json_output_namedtuple_fields_and_name() {
     // namedtuple body is a JSON object of field->value, and the class name
     // is emitted as a sibling "name" key. Constructed directly because
-    // Monty's parser does not currently support class definitions.
+    // Monty's parser does not currently support `NamedTuple` class definitions.
     let obj = MontyObject::NamedTuple {
         type_name: "mymodule.Point".into(),
         field_names: vec!["x".into(), "y".into()],
@@ -178,6 +178,24 @@ fn json_output_dataclass_attrs_and_name() {
     assert_snapshot!(to_json(&obj), @r#"{"$dataclass":{"x":1,"y":2},"name":"Point"}"#);
 }
 
+#[test]
+fn json_output_host_handle_id_and_name() {
+    let obj = MontyObject::HostHandle {
+        type_name: "Cursor".into(),
+        handle_id: 3,
+    };
+    assert_snapshot!(to_json(&obj), @r#"{"$handle":3,"name":"Cursor"}"#);
+}
+
+#[test]
+fn json_output_callback_id_and_name() {
+    let obj = MontyObject::Callback {
+        name: "process".into(),
+        callback_id: 0,
+    };
+    assert_snapshot!(to_json(&obj), @r#"{"$callback":0,"name":"process"}"#);
+}
+
 #[test]
 fn json_output_dataclass_nested() {
     // A dataclass attribute whose value is itself a dataclass should be
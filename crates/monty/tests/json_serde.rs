@@ -72,9 +72,9 @@ fn json_output_ellipsis() {
 fn json_output_exception() {
     let obj = MontyObject::Exception {
         exc_type: ExcType::ValueError,
-        arg: Some("test".to_string()),
+        args: vec![MontyObject::String("test".to_string())],
     };
-    assert_snapshot!(to_json(&obj), @r#"{"Exception":{"exc_type":"ValueError","arg":"test"}}"#);
+    assert_snapshot!(to_json(&obj), @r#"{"Exception":{"exc_type":"ValueError","args":[{"String":"test"}]}}"#);
 }
 
 #[test]
@@ -0,0 +1,333 @@
+//! Tests for multi-file programs — Python source files added with
+//! `MontyRun::with_file` and imported from the main script or from each other.
+//!
+//! Each file is a module with its own globals, run on first import and cached
+//! for the rest of the run, so circular and relative imports behave like CPython.
+
+use monty::{ExcType, MontyException, MontyObject, MontyRun, NoLimitTracker, PrintWriter, RunProgress};
+
+fn runner(code: &str, files: &[(&str, &str)]) -> MontyRun {
+    let mut runner = MontyRun::new(code.to_owned(), "main.py", vec![]).unwrap();
+    for (path, source) in files {
+        runner = runner.with_file(path, source).unwrap();
+    }
+    runner
+}
+
+fn run(code: &str, files: &[(&str, &str)]) -> Result<MontyObject, MontyException> {
+    runner(code, files).run_no_limits(vec![])
+}
+
+fn ints(values: &[i64]) -> MontyObject {
+    MontyObject::Tuple(values.iter().map(|&v| MontyObject::Int(v)).collect())
+}
+
+// ---------------------------------------------------------------------------
+// Importing files
+// ---------------------------------------------------------------------------
+
+#[test]
+fn from_import_function() {
+    let helpers = "def double(x):\n    return x * 2";
+    let result = run("from helpers import double\ndouble(21)", &[("helpers.py", helpers)]).unwrap();
+    assert_eq!(result, MontyObject::Int(42));
+}
+
+/// Module functions read their own module's globals, not the importer's.
+#[test]
+fn modules_have_their_own_globals() {
+    let helpers = "factor = 3\ndef scale(x):\n    return x * factor";
+    let code = "import helpers\nfactor = 100\n(helpers.scale(2), helpers.factor, factor)";
+    let result = run(code, &[("helpers.py", helpers)]).unwrap();
+    assert_eq!(result, ints(&[6, 3, 100]));
+}
+
+/// Module attributes reflect assignments made after the import.
+#[test]
+fn module_attributes_are_live() {
+    let counter = "count = 0\ndef bump():\n    global count\n    count += 1";
+    let code = "import counter\ncounter.bump()\ncounter.bump()\ncounter.count";
+    let result = run(code, &[("counter.py", counter)]).unwrap();
+    assert_eq!(result, MontyObject::Int(2));
+}
+
+/// A module body runs once, and every import gets the same module object.
+#[test]
+fn modules_are_cached() {
+    let helpers = "print('loading helpers')\nvalue = 1";
+    let other = "import helpers\nvalue = helpers.value + 1";
+    let code = "import helpers\nimport other\nimport helpers as h\n(h is helpers, other.value)";
+    let runner = runner(code, &[("helpers.py", helpers), ("other.py", other)]);
+    let mut output = String::new();
+    let result = runner
+        .run(vec![], NoLimitTracker, PrintWriter::CollectString(&mut output))
+        .unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![MontyObject::Bool(true), MontyObject::Int(2)])
+    );
+    assert_eq!(output, "loading helpers\n");
+}
+
+#[test]
+fn module_repr() {
+    let result = run("import helpers\nrepr(helpers)", &[("helpers.py", "x = 1")]).unwrap();
+    assert_eq!(result, MontyObject::String("<module 'helpers'>".to_owned()));
+}
+
+#[test]
+fn missing_attribute() {
+    let err = run("from helpers import missing", &[("helpers.py", "x = 1")]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ImportError);
+
+    let err = run("import helpers\nhelpers.missing", &[("helpers.py", "x = 1")]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::AttributeError);
+}
+
+/// A `return` at the top level of a file ends its body early.
+#[test]
+fn module_level_return() {
+    let helpers = "x = 1\nif x:\n    return\nx = 2";
+    let result = run("import helpers\nhelpers.x", &[("helpers.py", helpers)]).unwrap();
+    assert_eq!(result, MontyObject::Int(1));
+}
+
+// ---------------------------------------------------------------------------
+// Circular imports
+// ---------------------------------------------------------------------------
+
+/// Circular imports see the partially initialized module, like CPython.
+#[test]
+fn circular_import() {
+    let a = "import b\nA = 'a'\ndef get_b():\n    return b.B";
+    let b = "import a\nB = 'b'\ndef get_a():\n    return a.A";
+    let code = "import a\nimport b\n(a.get_b(), b.get_a())";
+    let result = run(code, &[("a.py", a), ("b.py", b)]).unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![
+            MontyObject::String("b".to_owned()),
+            MontyObject::String("a".to_owned())
+        ])
+    );
+}
+
+/// Importing a name the partially initialized module hasn't defined yet fails.
+#[test]
+fn circular_from_import_of_undefined_name() {
+    let a = "from b import B\nA = 1";
+    let b = "from a import A\nB = 2";
+    let err = run("import a", &[("a.py", a), ("b.py", b)]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ImportError);
+}
+
+// ---------------------------------------------------------------------------
+// Packages and relative imports
+// ---------------------------------------------------------------------------
+
+fn package_files() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("pkg/__init__.py", "from .tools import tool\nNAME = 'pkg'"),
+        (
+            "pkg/tools.py",
+            "from . import consts\ndef tool():\n    return consts.VALUE",
+        ),
+        ("pkg/consts.py", "VALUE = 7"),
+        (
+            "pkg/sub/deep.py",
+            "from ..consts import VALUE\nfrom .. import NAME\nDEEP = (NAME, VALUE)",
+        ),
+    ]
+}
+
+#[test]
+fn package_init_and_relative_imports() {
+    let result = run("import pkg\npkg.tool()", &package_files()).unwrap();
+    assert_eq!(result, MontyObject::Int(7));
+}
+
+/// `import a.b` binds the top-level package, with the submodule as its attribute.
+#[test]
+fn import_submodule() {
+    let result = run("import pkg.consts\npkg.consts.VALUE", &package_files()).unwrap();
+    assert_eq!(result, MontyObject::Int(7));
+
+    let result = run("import pkg.consts as c\nc.VALUE", &package_files()).unwrap();
+    assert_eq!(result, MontyObject::Int(7));
+
+    let result = run("from pkg.consts import VALUE\nVALUE", &package_files()).unwrap();
+    assert_eq!(result, MontyObject::Int(7));
+}
+
+/// `from pkg import submodule` imports the submodule if the package doesn't define the name.
+#[test]
+fn from_package_import_submodule() {
+    let result = run("from pkg import consts\nconsts.VALUE", &package_files()).unwrap();
+    assert_eq!(result, MontyObject::Int(7));
+}
+
+/// A directory without an `__init__.py` imports as an empty package.
+#[test]
+fn package_without_init() {
+    let code = "from pkg.sub.deep import DEEP\nimport pkg.sub\n(DEEP, repr(pkg.sub))";
+    let result = run(code, &package_files()).unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![
+            MontyObject::Tuple(vec![MontyObject::String("pkg".to_owned()), MontyObject::Int(7)]),
+            MontyObject::String("<module 'pkg.sub'>".to_owned())
+        ])
+    );
+}
+
+/// The `__init__.py` of a package may be added after its submodules.
+#[test]
+fn init_added_after_submodule() {
+    let files = [("pkg/consts.py", "VALUE = 7"), ("pkg/__init__.py", "NAME = 'pkg'")];
+    let result = run("import pkg.consts\n(pkg.NAME, pkg.consts.VALUE)", &files).unwrap();
+    assert_eq!(
+        result,
+        MontyObject::Tuple(vec![MontyObject::String("pkg".to_owned()), MontyObject::Int(7)])
+    );
+}
+
+#[test]
+fn relative_import_beyond_top_level() {
+    let err = MontyRun::new(String::new(), "main.py", vec![])
+        .unwrap()
+        .with_file("pkg/tools.py", "from ... import x")
+        .unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ImportError);
+    assert_eq!(
+        err.message(),
+        Some("attempted relative import beyond top-level package")
+    );
+}
+
+/// Relative imports outside a package fail, in the main script and in top-level files.
+#[test]
+fn relative_import_outside_package() {
+    let err = MontyRun::new("from . import helpers".to_owned(), "main.py", vec![]).unwrap_err();
+    assert_eq!(
+        err.message(),
+        Some("attempted relative import with no known parent package")
+    );
+
+    let err = MontyRun::new(String::new(), "main.py", vec![])
+        .unwrap()
+        .with_file("helpers.py", "from .other import x")
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        Some("attempted relative import with no known parent package")
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Tracebacks name the file of each frame.
+#[test]
+fn traceback_shows_file_names() {
+    let helpers = "def fail():\n    return 1 / 0";
+    let err = run("import helpers\nhelpers.fail()", &[("helpers.py", helpers)]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ZeroDivisionError);
+    let frames: Vec<_> = err
+        .traceback()
+        .iter()
+        .map(|frame| (frame.filename.as_str(), frame.start.line))
+        .collect();
+    assert_eq!(frames, vec![("main.py", 2), ("helpers.py", 2)]);
+}
+
+/// An error raised by a module body propagates to the import.
+#[test]
+fn error_in_module_body() {
+    let err = run("import helpers", &[("helpers.py", "x = 1\nundefined_name")]).unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::NameError);
+    let frames: Vec<_> = err
+        .traceback()
+        .iter()
+        .map(|frame| (frame.filename.as_str(), frame.start.line))
+        .collect();
+    assert_eq!(frames, vec![("main.py", 1), ("helpers.py", 2)]);
+}
+
+#[test]
+fn syntax_error_names_the_file() {
+    let err = MontyRun::new(String::new(), "main.py", vec![])
+        .unwrap()
+        .with_file("helpers.py", "def broken(:")
+        .unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::SyntaxError);
+    assert_eq!(err.traceback()[0].filename, "helpers.py");
+}
+
+#[test]
+fn invalid_paths() {
+    for path in [
+        "helpers.txt",
+        "/abs/helpers.py",
+        "my-helpers.py",
+        "pkg//x.py",
+        "../x.py",
+    ] {
+        let err = MontyRun::new(String::new(), "main.py", vec![])
+            .unwrap()
+            .with_file(path, "")
+            .unwrap_err();
+        assert_eq!(err.exc_type(), ExcType::ValueError, "{path}");
+    }
+}
+
+#[test]
+fn stdlib_module_cannot_be_replaced() {
+    let err = MontyRun::new(String::new(), "main.py", vec![])
+        .unwrap()
+        .with_file("json/tools.py", "")
+        .unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ValueError);
+    assert_eq!(
+        err.message(),
+        Some("module 'json' is part of the standard library and can't be replaced")
+    );
+}
+
+#[test]
+fn duplicate_module() {
+    let err = runner("", &[("helpers.py", "")])
+        .with_file("./helpers.py", "")
+        .unwrap_err();
+    assert_eq!(err.exc_type(), ExcType::ValueError);
+    assert_eq!(err.message(), Some("module 'helpers' was already added"));
+}
+
+// ---------------------------------------------------------------------------
+// Serialization and external calls
+// ---------------------------------------------------------------------------
+
+/// Supplied files survive a `dump`/`load` round trip of the runner.
+#[test]
+fn files_serialize() {
+    let runner = runner("import pkg\npkg.tool()", &package_files());
+    let runner = MontyRun::load(&runner.dump().unwrap()).unwrap();
+    assert_eq!(runner.run_no_limits(vec![]).unwrap(), MontyObject::Int(7));
+}
+
+/// External calls made inside a module pause and resume, including across a dump/load.
+#[test]
+fn external_call_in_module_body() {
+    let helpers = "config = fetch_config()\ndef setting():\n    return config * 2";
+    let runner = runner(
+        "import helpers\nimport helpers as h\nh.setting()",
+        &[("helpers.py", helpers)],
+    );
+    let progress = runner.start(vec![], NoLimitTracker, PrintWriter::Stdout).unwrap();
+    let progress = RunProgress::<NoLimitTracker>::load(&progress.dump().unwrap()).unwrap();
+
+    let call = progress.into_function_call().expect("expected FunctionCall");
+    assert_eq!(call.function_name, "fetch_config");
+    let result = call.resume(MontyObject::Int(21), PrintWriter::Stdout).unwrap();
+    assert_eq!(result.into_complete().unwrap(), MontyObject::Int(42));
+}
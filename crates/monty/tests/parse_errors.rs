@@ -10,10 +10,9 @@ fn get_parse_err(code: impl Into<String>) -> MontyException {
 }
 
 #[test]
-fn complex_numbers_return_not_implemented_error() {
-    let err = get_parse_err("1 + 2j");
-    assert_eq!(err.exc_type(), ExcType::NotImplementedError);
-    assert_snapshot!(err.message().unwrap(), @"The monty syntax parser does not yet support complex constants");
+fn complex_literals_parse() {
+    let result = MontyRun::new("1 + 2j".to_owned(), "test.py", vec![]);
+    assert!(result.is_ok(), "imaginary literals should parse");
 }
 
 #[test]
@@ -24,10 +23,17 @@ fn yield_expressions_return_not_implemented_error() {
 }
 
 #[test]
-fn classes_return_not_implemented_error() {
+fn classes_without_dataclass_return_not_implemented_error() {
     let err = get_parse_err("class Foo: pass");
     assert_eq!(err.exc_type(), ExcType::NotImplementedError);
-    assert_snapshot!(err.message().unwrap(), @"The monty syntax parser does not yet support class definitions");
+    assert_snapshot!(err.message().unwrap(), @"The monty syntax parser does not yet support classes without the @dataclass decorator");
+}
+
+#[test]
+fn class_inheritance_returns_not_implemented_error() {
+    let err = get_parse_err("from dataclasses import dataclass\n@dataclass\nclass Foo(Bar):\n    x: int");
+    assert_eq!(err.exc_type(), ExcType::NotImplementedError);
+    assert_snapshot!(err.message().unwrap(), @"The monty syntax parser does not yet support class inheritance");
 }
 
 #[test]
@@ -55,13 +61,13 @@ fn async_with_statement_returns_not_implemented_error() {
 #[test]
 fn error_display_format() {
     // Verify the Display format matches Python's exception output with traceback
-    let err = get_parse_err("1 + 2j");
+    let err = get_parse_err("1 + (yield 1)");
     assert_snapshot!(err, @r#"
     Traceback (most recent call last):
       File "test.py", line 1, in <module>
-        1 + 2j
-            ~~
-    NotImplementedError: The monty syntax parser does not yet support complex constants
+        1 + (yield 1)
+             ~~~~~~~
+    NotImplementedError: The monty syntax parser does not yet support yield expressions
     "#);
 }
 
@@ -767,6 +773,30 @@ fn function_with_too_many_defaults_returns_syntax_error() {
     assert_eq!(err.message(), Some("more than 255 default parameter values (256)"));
 }
 
+/// Builds `def f(a0: int = 0, ...): pass` with `count` annotated defaulted parameters.
+fn annotated_defaults_function(count: usize) -> String {
+    let mut code = "def f(".to_owned();
+    for i in 0..count {
+        if i > 0 {
+            code.push_str(", ");
+        }
+        write!(code, "a{i}: int = 0").unwrap();
+    }
+    code.push_str("): pass\n");
+    code
+}
+
+#[test]
+fn annotated_function_defaults_limit_leaves_room_for_annotate() {
+    // The `__annotate__` function shares the defaults operand, so 254 defaults is the limit.
+    MontyRun::new(annotated_defaults_function(254), "test.py", vec![]).expect("254 annotated defaults compile");
+
+    let result = MontyRun::new(annotated_defaults_function(255), "test.py", vec![]);
+    let err = result.expect_err("expected compile error");
+    assert_eq!(err.exc_type(), ExcType::SyntaxError);
+    assert_eq!(err.message(), Some("more than 254 default parameter values (255)"));
+}
+
 #[test]
 fn function_with_too_many_closure_variables_returns_syntax_error() {
     // Each `xN` reference in `inner` captures the enclosing local as a free
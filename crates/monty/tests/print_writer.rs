@@ -8,8 +8,13 @@
 //! To update after an intentional change, run `cargo insta review` (or set
 //! `INSTA_UPDATE=always`).
 
+use std::borrow::Cow;
+
 use insta::assert_snapshot;
-use monty::{MontyRun, NoLimitTracker, PrintWriter};
+use monty::{
+    MontyException, MontyLogRecord, MontyObject, MontyRun, NoLimitTracker, PrintStream, PrintWriter,
+    PrintWriterCallback,
+};
 
 /// Run `code` under Monty with a string-collecting `PrintWriter` and return
 /// whatever was printed. Panics on parse/runtime errors — callers only care
@@ -208,3 +213,138 @@ fn print_multiline_sep() {
     3
     ");
 }
+
+#[test]
+fn warning_goes_to_stderr_stream() {
+    let code = "import warnings\nprint('before')\nwarnings.warn('careful')\nprint('after')";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let mut streams = Vec::new();
+    ex.run(vec![], NoLimitTracker, PrintWriter::CollectStreams(&mut streams))
+        .unwrap();
+    assert_eq!(
+        streams,
+        vec![
+            (PrintStream::Stdout, "before\n".to_owned()),
+            (PrintStream::Stderr, "test.py:3: UserWarning: careful\n".to_owned()),
+            (PrintStream::Stdout, "after\n".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn warning_dropped_by_collect_string() {
+    assert_snapshot!(run_and_capture("import warnings\nwarnings.warn('careful')\nprint('done')"), @"done
+");
+}
+
+#[test]
+fn print_file_routes_to_streams() {
+    let code = "\
+import sys
+print('out', file=sys.stdout)
+print('err', 1, sep='-', file=sys.stderr)
+sys.stderr.write('raw')
+sys.stdout.writelines(['a', 'b'])
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let mut streams = Vec::new();
+    ex.run(vec![], NoLimitTracker, PrintWriter::CollectStreams(&mut streams))
+        .unwrap();
+    assert_eq!(
+        streams,
+        vec![
+            (PrintStream::Stdout, "out\n".to_owned()),
+            (PrintStream::Stderr, "err-1\nraw".to_owned()),
+            (PrintStream::Stdout, "ab".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn stderr_dropped_by_collect_string() {
+    assert_snapshot!(
+        run_and_capture("import sys\nprint('hidden', file=sys.stderr)\nsys.stderr.write('hidden')\nsys.stdout.write('shown\\n')"),
+        @"shown
+    "
+    );
+}
+
+#[test]
+fn log_goes_to_stderr_stream() {
+    let code =
+        "import logging\nprint('before')\nlogging.warning('low on %s', 'disk')\nlogging.info('hidden')\nprint('after')";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let mut streams = Vec::new();
+    ex.run(vec![], NoLimitTracker, PrintWriter::CollectStreams(&mut streams))
+        .unwrap();
+    assert_eq!(
+        streams,
+        vec![
+            (PrintStream::Stdout, "before\n".to_owned()),
+            (PrintStream::Stderr, "WARNING:root:low on disk\n".to_owned()),
+            (PrintStream::Stdout, "after\n".to_owned()),
+        ]
+    );
+}
+
+/// Collects the log records delivered to the host.
+#[derive(Default)]
+struct LogCollector {
+    records: Vec<MontyLogRecord>,
+}
+
+impl PrintWriterCallback for LogCollector {
+    fn stdout_write(&mut self, _output: Cow<'_, str>) -> Result<(), MontyException> {
+        Ok(())
+    }
+
+    fn stdout_push(&mut self, _end: char) -> Result<(), MontyException> {
+        Ok(())
+    }
+
+    fn log(&mut self, record: &MontyLogRecord) -> Result<(), MontyException> {
+        self.records.push(record.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn log_record_fields() {
+    let code = "\
+import logging
+
+def handle():
+    log = logging.getLogger('app.db')
+    log.setLevel(logging.DEBUG)
+    try:
+        1 / 0
+    except ZeroDivisionError:
+        log.exception('query %d failed', 7, extra={'user': 'sam'})
+
+handle()
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let mut collector = LogCollector::default();
+    ex.run(vec![], NoLimitTracker, PrintWriter::Callback(&mut collector))
+        .unwrap();
+    let [record] = collector.records.as_slice() else {
+        panic!("expected one record, got {:?}", collector.records);
+    };
+    assert_eq!(record.name, "app.db");
+    assert_eq!(record.levelno, 40);
+    assert_eq!(record.level_name, "ERROR");
+    assert_eq!(record.msg, "query %d failed");
+    assert_eq!(record.args, vec![MontyObject::Int(7)]);
+    assert_eq!(record.message, "query 7 failed");
+    assert_eq!(record.filename, "test.py");
+    assert_eq!(record.lineno, 9);
+    assert_eq!(record.func_name, "handle");
+    assert_eq!(
+        record.extra,
+        vec![("user".to_owned(), MontyObject::String("sam".to_owned()))]
+    );
+    // No `basicConfig()`, so the formatted text is just the message.
+    assert_eq!(record.formatted, "query 7 failed");
+    let exception = record.exception.as_ref().expect("exception attached");
+    assert_eq!(exception.summary(), "ZeroDivisionError: division by zero");
+}
@@ -176,7 +176,7 @@ fn type_name() {
     assert_eq!(
         MontyObject::Exception {
             exc_type: ExcType::ValueError,
-            arg: None,
+            args: vec![],
         }
         .type_name(),
         "Exception"
@@ -293,7 +293,7 @@ fn is_truthy_exception() {
     assert!(
         MontyObject::Exception {
             exc_type: ExcType::ValueError,
-            arg: Some("oops".to_string()),
+            args: vec![MontyObject::String("oops".to_string())],
         }
         .is_truthy()
     );
@@ -517,7 +517,7 @@ fn repr_timezone_with_name() {
 fn repr_exception_no_arg() {
     let exc = MontyObject::Exception {
         exc_type: ExcType::ValueError,
-        arg: None,
+        args: vec![],
     };
     assert_eq!(exc.py_repr(), "ValueError()");
 }
@@ -526,7 +526,7 @@ fn repr_exception_no_arg() {
 fn repr_exception_with_arg() {
     let exc = MontyObject::Exception {
         exc_type: ExcType::TypeError,
-        arg: Some("bad type".to_string()),
+        args: vec![MontyObject::String("bad type".to_string())],
     };
     assert_eq!(exc.py_repr(), "TypeError('bad type')");
 }
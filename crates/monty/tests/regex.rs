@@ -3,14 +3,17 @@
 /// These tests verify Monty-specific regex behavior that cannot be tested via
 /// the datatest runner (which runs tests against both CPython and Monty).
 /// In particular, `fancy_regex` enforces a backtrack limit that CPython lacks,
-/// so pathological patterns raise `PatternError` in Monty instead of hanging.
+/// so pathological patterns raise `TimeoutError` in Monty instead of hanging.
 ///
 /// CPython's regex engine uses backtracking with no step limit. Pathological
 /// patterns (e.g. `((a+)\2)+b` against 50+ 'a's) cause exponential-time hangs
 /// that grow unboundedly — a denial-of-service vector. Monty uses `fancy_regex`
-/// which enforces a default 1M-step backtrack limit, raising `re.PatternError`
-/// when exceeded. This is strictly better behavior for a sandbox.
-use monty::MontyRun;
+/// which enforces a backtrack limit (1M steps by default, configurable via
+/// `ResourceLimits::regex_backtrack_limit`), raising `TimeoutError` when exceeded.
+/// This is strictly better behavior for a sandbox.
+use std::time::Duration;
+
+use monty::{ExcType, LimitedTracker, MontyRun, PrintWriter, ResourceLimits};
 
 /// Helper to run Python code and return the string result.
 fn run(code: &str) -> String {
@@ -28,7 +31,7 @@ fn run(code: &str) -> String {
 /// completely unusable at ~50+ characters and a denial-of-service vector).
 ///
 /// Monty uses `fancy_regex` which enforces a default 1M-step backtrack limit.
-/// Patterns that exceed this limit raise `re.PatternError` instead of hanging,
+/// Patterns that exceed this limit raise `TimeoutError` instead of hanging,
 /// making the sandbox safe against ReDoS attacks via backreference-based patterns.
 ///
 /// Note: `fancy_regex` delegates simple patterns (no backreferences or lookaround)
@@ -45,14 +48,11 @@ import re
 try:
     re.search(r'((a+)\2)+b', 'a' * 40 + 'c')
     result = 'no error'
-except re.PatternError as e:
+except TimeoutError as e:
     result = str(e)
 result
 ");
-    assert_eq!(
-        result,
-        "Error executing regex: Max limit for backtracking count exceeded"
-    );
+    assert_eq!(result, "regex backtrack limit exceeded (1000000 steps)");
 }
 
 /// Verify that the backtrack limit also applies to compiled patterns.
@@ -64,14 +64,11 @@ p = re.compile(r'((a+)\2)+b')
 try:
     p.search('a' * 40 + 'c')
     result = 'no error'
-except re.PatternError as e:
+except TimeoutError as e:
     result = str(e)
 result
 ");
-    assert_eq!(
-        result,
-        "Error executing regex: Max limit for backtracking count exceeded"
-    );
+    assert_eq!(result, "regex backtrack limit exceeded (1000000 steps)");
 }
 
 /// Verify that non-fancy patterns (no backreferences/lookaround) are delegated
@@ -88,3 +85,54 @@ assert m is None, 'no match expected'
 ");
     assert_eq!(result, "ok");
 }
+
+/// Verify that `ResourceLimits::regex_backtrack_limit` overrides the default budget.
+///
+/// A pattern that fits comfortably within the default 1M steps fails once the
+/// per-call budget is lowered, and the message reports the configured limit.
+#[test]
+fn backtrack_limit_configurable() {
+    let code = r"
+import re
+try:
+    re.search(r'((a+)\2)+b', 'a' * 12 + 'c')
+    result = 'no error'
+except TimeoutError as e:
+    result = str(e)
+result
+";
+    assert_eq!(run(code), "no error");
+
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let limits = ResourceLimits::new().regex_backtrack_limit(10);
+    let result = ex
+        .run(vec![], LimitedTracker::new(limits), PrintWriter::Stdout)
+        .unwrap();
+    let s: String = result.as_ref().try_into().unwrap();
+    assert_eq!(s, "regex backtrack limit exceeded (10 steps)");
+}
+
+/// Verify that a long-running regex call respects `max_duration`.
+///
+/// `findall` checks the time budget between matches, so a single call producing
+/// a million matches stops with an uncatchable `TimeoutError` part-way through.
+#[test]
+fn findall_respects_time_limit() {
+    let code = r"
+import re
+try:
+    re.findall(r'(?=a)a', 'a' * 1_000_000)
+except TimeoutError:
+    'caught'
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let limits = ResourceLimits::new().max_duration(Duration::from_millis(10));
+    let exc = ex
+        .run(vec![], LimitedTracker::new(limits), PrintWriter::Stdout)
+        .unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TimeoutError);
+    assert!(
+        exc.message().is_some_and(|m| m.contains("time limit exceeded")),
+        "expected time limit error, got: {exc}"
+    );
+}
@@ -0,0 +1,345 @@
+//! Tests for `FunctionRegistry` and the `IntoMonty` / `FromMonty` conversions,
+//! including the derives.
+//!
+//! Registered functions are called by name from the sandbox with arguments
+//! bound to their declared parameters and converted to the Rust types, and
+//! their return values are converted back.
+
+use std::{
+    collections::BTreeMap,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use monty::{
+    ExcType, FromMonty, FunctionRegistry, IntoMonty, MontyException, MontyObject, MontyRun, NoLimitTracker, PrintWriter,
+};
+
+#[derive(Debug, PartialEq, IntoMonty, FromMonty)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, PartialEq, IntoMonty, FromMonty)]
+#[monty(dict)]
+struct Vector {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, IntoMonty, FromMonty)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+}
+
+fn add(a: i64, b: i64) -> Result<i64, MontyException> {
+    Ok(a + b)
+}
+
+fn total(values: Vec<i64>) -> Result<i64, MontyException> {
+    Ok(values.into_iter().sum())
+}
+
+fn scale(values: Vec<f64>, factor: Option<f64>) -> Result<Vec<f64>, MontyException> {
+    let factor = factor.unwrap_or(2.0);
+    Ok(values.into_iter().map(|v| v * factor).collect())
+}
+
+fn tally(counts: BTreeMap<String, i64>) -> Result<i64, MontyException> {
+    Ok(counts.into_values().sum())
+}
+
+fn check(value: i64) -> Result<i64, MontyException> {
+    if value < 0 {
+        Err(MontyException::new(ExcType::ValueError, Some("negative".to_owned())))
+    } else {
+        Ok(value)
+    }
+}
+
+fn shift(Point { x, y }: Point, dx: f64) -> Result<Point, MontyException> {
+    Ok(Point { x: x + dx, y })
+}
+
+fn norm(Vector { x, y }: Vector) -> Result<f64, MontyException> {
+    Ok(x.hypot(y))
+}
+
+impl Shape {
+    fn area(self) -> f64 {
+        match self {
+            Self::Empty => 0.0,
+            Self::Circle(radius) => 3.0 * radius * radius,
+            Self::Rect { width, height } => width * height,
+        }
+    }
+}
+
+fn area(shape: Shape) -> Result<f64, MontyException> {
+    Ok(shape.area())
+}
+
+async fn fetch(id: i64) -> Result<String, MontyException> {
+    Ok(format!("page {id}"))
+}
+
+fn registry() -> FunctionRegistry {
+    FunctionRegistry::new()
+        .with_function("add", &["a", "b"], add)
+        .with_function("total", &["values"], total)
+        .with_function("scale", &["values", "factor"], scale)
+        .with_function("tally", &["counts"], tally)
+        .with_function("check", &["value"], check)
+        .with_function("shift", &["point", "dx"], shift)
+        .with_function("norm", &["vector"], norm)
+        .with_function("area", &["shape"], area)
+        .with_async_function("fetch", &["id"], fetch)
+}
+
+fn run_with(code: &str, input_names: Vec<String>, inputs: Vec<MontyObject>) -> Result<MontyObject, MontyException> {
+    let runner = MontyRun::new(code.to_owned(), "test.py", input_names).unwrap();
+    registry().run(runner, inputs, NoLimitTracker, PrintWriter::Stdout)
+}
+
+fn run(code: &str) -> Result<MontyObject, MontyException> {
+    run_with(code, vec![], vec![])
+}
+
+/// Polls `future` until it's ready; the test futures never wait on anything.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn run_async(code: &str) -> Result<MontyObject, MontyException> {
+    let runner = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let registry = registry();
+    block_on(registry.run_async(runner, vec![], NoLimitTracker, PrintWriter::Stdout))
+}
+
+fn type_error(code: &str) -> String {
+    let exc = run(code).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::TypeError, "{exc:?}");
+    exc.message().unwrap().to_owned()
+}
+
+#[test]
+fn calls_with_positional_and_keyword_arguments() {
+    assert_eq!(run("add(1, b=2)").unwrap(), MontyObject::Int(3));
+    assert_eq!(run("add(b=1, a=2)").unwrap(), MontyObject::Int(3));
+}
+
+#[test]
+fn omitted_option_parameter_is_none() {
+    assert_eq!(
+        run("scale([1, 2.5])").unwrap(),
+        MontyObject::List(vec![MontyObject::Float(2.0), MontyObject::Float(5.0)])
+    );
+    assert_eq!(
+        run("scale([1], factor=3)").unwrap(),
+        MontyObject::List(vec![MontyObject::Float(3.0)])
+    );
+}
+
+#[test]
+fn bad_calls_raise_before_reaching_the_function() {
+    assert_eq!(
+        type_error("add(1)"),
+        "add() missing 1 required positional argument: 'b'"
+    );
+}
+
+#[test]
+fn conversion_errors_name_the_argument() {
+    assert_eq!(type_error("add(1, 'x')"), "add() argument 'b' must be int, not str");
+    assert_eq!(
+        type_error("total([1, 'a'])"),
+        "total() argument 'values[1]' must be int, not str"
+    );
+    assert_eq!(
+        type_error("norm({'x': 1, 'y': 'a'})"),
+        "norm() argument 'vector.y' must be float, not str"
+    );
+    assert_eq!(
+        type_error("tally({'a': 1, 'b': 'x'})"),
+        "tally() argument 'counts['b']' must be int, not str"
+    );
+    assert_eq!(
+        type_error("tally({1: 2})"),
+        "tally() argument 'counts[key 1]' must be str, not int"
+    );
+    assert_eq!(
+        type_error("norm({'x': 1})"),
+        "norm() argument 'vector' is missing field 'y' of Vector"
+    );
+}
+
+#[test]
+fn function_errors_are_raised_in_the_sandbox() {
+    let code = "
+try:
+    check(-1)
+except ValueError as e:
+    result = str(e)
+result
+";
+    assert_eq!(run(code).unwrap(), MontyObject::String("negative".to_owned()));
+}
+
+#[test]
+fn unregistered_names_are_undefined() {
+    let exc = run("missing(1)").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::NameError);
+}
+
+#[test]
+fn closures_can_capture_state() {
+    let prefix = "Hello, ".to_owned();
+    let registry = FunctionRegistry::new().with_function("greet", &["name"], move |name: String| {
+        Ok::<_, MontyException>(format!("{prefix}{name}"))
+    });
+    let runner = MontyRun::new("greet('monty')".to_owned(), "test.py", vec![]).unwrap();
+    let result = registry
+        .run(runner, vec![], NoLimitTracker, PrintWriter::Stdout)
+        .unwrap();
+    assert_eq!(result, MontyObject::String("Hello, monty".to_owned()));
+}
+
+#[test]
+fn derived_struct_is_a_dataclass() {
+    let point = Point { x: 1.0, y: 2.0 }.into_monty();
+    let code = "
+q = shift(p, 1.5)
+(q.x, q.y)
+";
+    assert_eq!(
+        run_with(code, vec!["p".to_owned()], vec![point]).unwrap(),
+        MontyObject::Tuple(vec![MontyObject::Float(2.5), MontyObject::Float(2.0)])
+    );
+}
+
+#[test]
+fn dataclass_methods_are_not_supported() {
+    let point = Point { x: 1.0, y: 2.0 }.into_monty();
+    let exc = run_with("p.length()", vec!["p".to_owned()], vec![point]).unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::NotImplementedError);
+    assert_eq!(
+        exc.message(),
+        Some("Dataclass methods are not supported by FunctionRegistry: can't call 'Point.length()'")
+    );
+}
+
+#[test]
+fn derived_dict_struct() {
+    assert_eq!(run("norm({'x': 3, 'y': 4})").unwrap(), MontyObject::Float(5.0));
+    let vector = Vector { x: 1.0, y: 2.0 }.into_monty();
+    let MontyObject::Dict(pairs) = vector else {
+        panic!("expected a dict");
+    };
+    assert_eq!(pairs.len(), 2);
+}
+
+#[test]
+fn derived_enum_variants() {
+    assert_eq!(run("area('Empty')").unwrap(), MontyObject::Float(0.0));
+    assert_eq!(run("area(('Circle', 2))").unwrap(), MontyObject::Float(12.0));
+    assert_eq!(
+        type_error("area('Square')"),
+        "area() argument 'shape' must be a variant of Shape, not 'Square'"
+    );
+    assert_eq!(type_error("area(1)"), "area() argument 'shape' must be Shape, not int");
+}
+
+#[test]
+fn derived_enum_round_trip() {
+    for shape in [
+        Shape::Empty,
+        Shape::Circle(1.5),
+        Shape::Rect {
+            width: 2.0,
+            height: 3.0,
+        },
+    ] {
+        let object = shape.clone().into_monty();
+        assert_eq!(Shape::from_monty(object).unwrap(), shape);
+    }
+    assert_eq!(
+        Shape::Circle(1.5).into_monty(),
+        MontyObject::Tuple(vec![MontyObject::String("Circle".to_owned()), MontyObject::Float(1.5)])
+    );
+}
+
+#[test]
+fn async_functions_run_with_run_async() {
+    let code = "
+import asyncio
+
+async def main():
+    return await asyncio.gather(fetch(1), fetch(2))
+
+await main()
+";
+    assert_eq!(
+        run_async(code).unwrap(),
+        MontyObject::List(vec![
+            MontyObject::String("page 1".to_owned()),
+            MontyObject::String("page 2".to_owned()),
+        ])
+    );
+}
+
+#[test]
+fn async_functions_are_rejected_by_run() {
+    let exc = run("await fetch(1)").unwrap_err();
+    assert_eq!(exc.exc_type(), ExcType::RuntimeError);
+    assert_eq!(
+        exc.message(),
+        Some("fetch() is async, call it with FunctionRegistry::run_async")
+    );
+}
+
+#[test]
+fn type_stubs() {
+    let registry = FunctionRegistry::new()
+        .with_function("area", &["shape"], area)
+        .with_function("norm", &["vector"], norm)
+        .with_function("shift", &["point", "dx"], shift)
+        .with_async_function("fetch", &["id"], fetch)
+        .with_function("scale", &["values", "factor"], scale);
+    assert_eq!(
+        registry.type_stubs(),
+        r#"from dataclasses import dataclass
+from typing import Literal, TypeAlias, TypedDict
+
+Shape: TypeAlias = Literal["Empty"] | tuple[Literal["Circle"], float] | Rect
+
+@dataclass
+class Rect:
+    width: float
+    height: float
+
+class Vector(TypedDict):
+    x: float
+    y: float
+
+@dataclass
+class Point:
+    x: float
+    y: float
+
+def area(shape: Shape) -> float: ...
+def norm(vector: Vector) -> float: ...
+def shift(point: Point, dx: float) -> Point: ...
+async def fetch(id: int) -> str: ...
+def scale(values: list[float], factor: float | None = None) -> list[float]: ...
+"#
+    );
+}
@@ -2,10 +2,14 @@
 ///
 /// These tests verify that the `ResourceTracker` system correctly enforces
 /// allocation limits, time limits, and triggers garbage collection.
-use std::time::{Duration, Instant};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
 
 use monty::{
-    ExcType, LimitedTracker, MontyObject, MontyRun, NameLookupResult, PrintWriter, ResourceLimits, RunProgress,
+    ExcType, InterruptHandle, LimitedTracker, MontyObject, MontyRun, NameLookupResult, PrintWriter, ResourceLimits,
+    RunProgress,
 };
 
 /// Resolves consecutive `NameLookup` yields by providing a `Function` object for each name.
@@ -225,6 +229,162 @@ fn time_limit_not_exceeded() {
     assert!(result.is_ok(), "should not exceed time limit");
 }
 
+/// Test that the fuel limit stops an infinite loop.
+#[test]
+fn fuel_limit_exceeded() {
+    let code = "x = 0\nwhile True:\n    x += 1";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_fuel(10_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), PrintWriter::Stdout);
+
+    let exc = result.expect_err("should exceed fuel limit");
+    assert_eq!(exc.exc_type(), ExcType::TimeoutError);
+    assert_eq!(exc.message(), Some("fuel limit exceeded: 10001 > 10000"));
+}
+
+/// Test that fuel consumption is deterministic and readable after a run.
+#[test]
+fn fuel_consumed_is_deterministic() {
+    let code = "total = 0\nfor i in range(100):\n    total += i\ntotal";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+
+    let first = LimitedTracker::new(ResourceLimits::new());
+    let result = ex.run(vec![], &first, PrintWriter::Stdout).unwrap();
+    assert_eq!(result, MontyObject::Int(4950));
+    let second = LimitedTracker::new(ResourceLimits::new());
+    ex.run(vec![], &second, PrintWriter::Stdout).unwrap();
+
+    assert!(first.fuel_consumed() > 100, "each loop iteration costs fuel");
+    assert_eq!(first.fuel_consumed(), second.fuel_consumed());
+
+    // A limit of exactly the fuel consumed lets the same code complete.
+    let limits = ResourceLimits::new().max_fuel(first.fuel_consumed());
+    assert!(ex.run(vec![], LimitedTracker::new(limits), PrintWriter::Stdout).is_ok());
+}
+
+/// Test that builtins doing work inside a single instruction charge fuel for it.
+#[test]
+fn fuel_charged_by_builtins() {
+    let fuel = |code: &str| {
+        let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+        let tracker = LimitedTracker::new(ResourceLimits::new());
+        ex.run(vec![], &tracker, PrintWriter::Stdout).unwrap();
+        tracker.fuel_consumed()
+    };
+
+    // One unit per comparison: sorting 10,000 already-sorted items takes 9,999.
+    let copied = fuel("x = list(range(10_000))\ny = list(x)");
+    assert!(fuel("x = list(range(10_000))\ny = sorted(x)") >= copied + 9_999);
+    // One unit per 64 bytes of joined text.
+    let short = fuel("y = ','.join(['a' * 64_000] * 1)");
+    assert_eq!(fuel("y = ','.join(['a' * 64_000] * 10)") - short, 9 * 1_000 + 9);
+    // One unit per 64 bytes scanned, plus one per match.
+    let short = fuel("import re\ny = re.findall('b', 'ab' * 1)");
+    assert_eq!(
+        fuel("import re\ny = re.findall('b', 'ab' * 64_000)") - short,
+        2_000 + 63_999
+    );
+    // One unit per limb of the result for each bit of the exponent.
+    let short = fuel("y = 3 ** 10");
+    assert!(fuel("y = 3 ** 100_000") > short + 50_000);
+}
+
+/// Calls `action` on `handle` from another thread after a short delay.
+fn interrupt_later(handle: &InterruptHandle, action: fn(&InterruptHandle)) -> thread::JoinHandle<()> {
+    let handle = handle.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        action(&handle);
+    })
+}
+
+/// Test that an interrupt from another thread stops an infinite loop.
+#[test]
+fn interrupt_stops_infinite_loop() {
+    let ex = MontyRun::new("while True:\n    pass".to_owned(), "test.py", vec![]).unwrap();
+    let tracker = LimitedTracker::new(ResourceLimits::new());
+    let handle = tracker.interrupt_handle();
+    let interrupter = interrupt_later(&handle, InterruptHandle::interrupt);
+
+    let exc = ex
+        .run(vec![], tracker, PrintWriter::Stdout)
+        .expect_err("should be interrupted");
+    interrupter.join().unwrap();
+    assert_eq!(exc.exc_type(), ExcType::KeyboardInterrupt);
+    assert_eq!(exc.message(), None);
+    assert!(!handle.is_interrupted(), "the interrupt is consumed when raised");
+}
+
+/// Test that an interrupt raises a `KeyboardInterrupt` the code can catch.
+#[test]
+fn interrupt_is_catchable() {
+    let code = "\
+caught = False
+try:
+    while True:
+        pass
+except KeyboardInterrupt:
+    caught = True
+caught
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let tracker = LimitedTracker::new(ResourceLimits::new());
+    let interrupter = interrupt_later(&tracker.interrupt_handle(), InterruptHandle::interrupt);
+
+    let result = ex.run(vec![], tracker, PrintWriter::Stdout).unwrap();
+    interrupter.join().unwrap();
+    assert_eq!(result, MontyObject::Bool(true));
+}
+
+/// Test that a cancellation can't be caught, even by a bare `except`.
+#[test]
+fn cancel_is_uncatchable() {
+    let code = "\
+while True:
+    try:
+        while True:
+            pass
+    except:
+        pass
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+    let tracker = LimitedTracker::new(ResourceLimits::new());
+    let handle = tracker.interrupt_handle();
+    let interrupter = interrupt_later(&handle, InterruptHandle::cancel);
+
+    let exc = ex
+        .run(vec![], tracker, PrintWriter::Stdout)
+        .expect_err("should be cancelled");
+    interrupter.join().unwrap();
+    assert_eq!(exc.exc_type(), ExcType::KeyboardInterrupt);
+    assert_eq!(exc.message(), Some("execution cancelled"));
+    assert!(handle.is_interrupted(), "a cancellation stays in effect");
+}
+
+/// Test that an interrupt requested while suspended on external futures
+/// stops execution when the host resumes.
+#[test]
+fn interrupt_while_resolving_futures() {
+    let ex = MontyRun::new("await foo()".to_owned(), "test.py", vec![]).unwrap();
+    let tracker = LimitedTracker::new(ResourceLimits::new());
+    let handle = tracker.interrupt_handle();
+
+    let progress = ex.start(vec![], tracker, PrintWriter::Stdout).unwrap();
+    let RunProgress::FunctionCall(call) = resolve_name_lookups(progress).unwrap() else {
+        panic!("expected a call to foo");
+    };
+    let RunProgress::ResolveFutures(state) = call.resume_pending(PrintWriter::Stdout).unwrap() else {
+        panic!("expected to suspend on the pending future");
+    };
+
+    handle.interrupt();
+    let exc = state
+        .resume(vec![], PrintWriter::Stdout)
+        .expect_err("should be interrupted");
+    assert_eq!(exc.exc_type(), ExcType::KeyboardInterrupt);
+}
+
 /// Test that memory limits return an error.
 #[test]
 fn memory_limit_exceeded() {
@@ -252,6 +412,71 @@ result
     );
 }
 
+/// Test that output limits stop a runaway print loop, and can't be caught.
+#[test]
+fn output_limit_exceeded() {
+    let code = r"
+while True:
+    try:
+        print('x' * 99)
+    except MemoryError:
+        pass
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_output_bytes(1000);
+    let mut output = String::new();
+    let result = ex.run(
+        vec![],
+        LimitedTracker::new(limits),
+        PrintWriter::CollectString(&mut output),
+    );
+
+    let exc = result.expect_err("should exceed output limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+    assert_eq!(exc.message(), Some("output limit exceeded: 1099 bytes > 1000 bytes"));
+    // Ten full lines got out; the eleventh was rejected before any of it was written.
+    assert_eq!(output.len(), 1000);
+}
+
+/// Test that stderr writes, warnings and log records count towards the output limit.
+#[test]
+fn output_limit_counts_stderr() {
+    let code = r"
+import logging, sys, warnings
+sys.stderr.write('x' * 10)
+warnings.warn('w')
+logging.warning('y' * 100)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_output_bytes(100);
+    let result = ex.run(vec![], LimitedTracker::new(limits), PrintWriter::Disabled);
+
+    let exc = result.expect_err("should exceed output limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+    assert!(
+        exc.message().is_some_and(|m| m.contains("output limit exceeded")),
+        "expected output limit error, got: {exc}"
+    );
+}
+
+#[test]
+fn output_limit_not_exceeded() {
+    let code = "for i in range(10):\n    print(i)";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+
+    // Ten lines of two bytes each.
+    let limits = ResourceLimits::new().max_output_bytes(20);
+    let mut output = String::new();
+    let result = ex.run(
+        vec![],
+        LimitedTracker::new(limits),
+        PrintWriter::CollectString(&mut output),
+    );
+    assert!(result.is_ok(), "should not exceed output limit");
+}
+
 /// Regression: materializing a cheap-to-represent but enormous lazy iterable
 /// via `list()`/`tuple()`/`sorted()`/`reversed()` (and generator collection)
 /// must be rejected *during* collection, near the configured memory limit —
@@ -1830,6 +2055,46 @@ p.sub('b' * 1000, s)
     assert_eq!(exc.exc_type(), ExcType::MemoryError);
 }
 
+/// Test that `re.findall` counts its results against `max_allocations` as it scans,
+/// rather than collecting every match before allocating.
+#[test]
+fn re_findall_allocation_limit() {
+    let code = r"
+import re
+s = 'a' * 100_000
+re.findall('a', s)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_allocations(1_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), PrintWriter::Stdout);
+
+    let exc = result.expect_err("re.findall should hit the allocation limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+    assert!(
+        exc.message().is_some_and(|m| m.contains("allocation limit exceeded")),
+        "expected allocation limit error, got: {exc}"
+    );
+}
+
+/// Test that `pattern.finditer` counts its match objects against `max_memory` as it scans.
+#[test]
+fn re_pattern_finditer_memory_limit() {
+    let code = r"
+import re
+p = re.compile('(a)(a)')
+s = 'a' * 200_000
+p.finditer(s)
+";
+    let ex = MontyRun::new(code.to_owned(), "test.py", vec![]).unwrap();
+
+    let limits = ResourceLimits::new().max_memory(1_000_000);
+    let result = ex.run(vec![], LimitedTracker::new(limits), PrintWriter::Stdout);
+
+    let exc = result.expect_err("pattern.finditer should hit the memory limit");
+    assert_eq!(exc.exc_type(), ExcType::MemoryError);
+}
+
 /// Test that `re.sub` raises `re.PatternError` when the regex engine hits its backtracking limit.
 ///
 /// The pattern `(a+)+\1b` forces `fancy_regex` into its backtracking VM (due to the
@@ -0,0 +1,337 @@
+//! Tests for declared signatures of external functions — `FunctionSignature`,
+//! `MontyRun::with_signatures` and `FunctionCall::bound_args`.
+//!
+//! A call to an external function with a declared signature is bound in the
+//! sandbox before the host sees it: bad calls raise `TypeError` at the calling
+//! line, and good ones reach the host as arguments by parameter name.
+
+use monty::{
+    ExcType, FunctionCall, FunctionSignature, MontyException, MontyModule, MontyObject, MontyRepl, MontyRun,
+    NameLookupResult, NoLimitTracker, Parameter, PrintWriter, ReplProgress, RunProgress, Type,
+};
+
+/// `search(query: str, /, limit: int = 10, *tags, exact=False, **options)`
+fn search() -> FunctionSignature {
+    FunctionSignature::new(
+        "search",
+        vec![
+            Parameter::positional_only("query").with_type(Type::Str),
+            Parameter::new("limit")
+                .with_type(Type::Int)
+                .with_default(MontyObject::Int(10)),
+            Parameter::var_positional("tags"),
+            Parameter::keyword_only("exact").with_default(MontyObject::Bool(false)),
+            Parameter::var_keyword("options"),
+        ],
+    )
+}
+
+/// Resolves the name lookups of external functions, which the tests call by any name.
+fn step(
+    progress: Result<RunProgress<NoLimitTracker>, MontyException>,
+) -> Result<RunProgress<NoLimitTracker>, MontyException> {
+    let mut progress = progress?;
+    while let RunProgress::NameLookup(lookup) = progress {
+        let name = lookup.name.clone();
+        let function = MontyObject::Function { name, docstring: None };
+        progress = lookup.resume(NameLookupResult::Value(function), PrintWriter::Stdout)?;
+    }
+    Ok(progress)
+}
+
+fn start(code: &str, signatures: Vec<FunctionSignature>) -> Result<RunProgress<NoLimitTracker>, MontyException> {
+    let run = MontyRun::new(code.to_owned(), "test.py", vec![])
+        .unwrap()
+        .with_signatures(signatures)
+        .unwrap();
+    step(run.start(vec![], NoLimitTracker, PrintWriter::Stdout))
+}
+
+fn function_call(code: &str, signatures: Vec<FunctionSignature>) -> FunctionCall<NoLimitTracker> {
+    start(code, signatures)
+        .unwrap()
+        .into_function_call()
+        .expect("expected a function call")
+}
+
+/// Starts `code`, which must raise before calling the host, and returns the exception.
+fn call_error(code: &str, signatures: Vec<FunctionSignature>) -> MontyException {
+    match start(code, signatures) {
+        Err(exc) => exc,
+        Ok(progress) => panic!("expected an exception, got {progress:?}"),
+    }
+}
+
+fn str_obj(s: &str) -> MontyObject {
+    MontyObject::String(s.to_owned())
+}
+
+fn bound(pairs: Vec<(&str, MontyObject)>) -> Option<Vec<(String, MontyObject)>> {
+    Some(
+        pairs
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value))
+            .collect(),
+    )
+}
+
+#[test]
+fn binds_arguments_by_name_with_defaults() {
+    let call = function_call("search('monty')", vec![search()]);
+    assert_eq!(call.function_name, "search");
+    assert!(call.args.is_empty());
+    assert!(call.kwargs.is_empty());
+    assert_eq!(
+        call.bound_args,
+        bound(vec![
+            ("query", str_obj("monty")),
+            ("limit", MontyObject::Int(10)),
+            ("tags", MontyObject::Tuple(vec![])),
+            ("exact", MontyObject::Bool(false)),
+            ("options", MontyObject::dict(vec![])),
+        ])
+    );
+}
+
+#[test]
+fn binds_extra_arguments_into_var_parameters() {
+    let call = function_call("search('monty', 3, 'a', 'b', exact=True, lang='en')", vec![search()]);
+    assert_eq!(call.arg("limit"), Some(&MontyObject::Int(3)));
+    assert_eq!(
+        call.arg("tags"),
+        Some(&MontyObject::Tuple(vec![str_obj("a"), str_obj("b")]))
+    );
+    assert_eq!(call.arg("exact"), Some(&MontyObject::Bool(true)));
+    assert_eq!(
+        call.arg("options"),
+        Some(&MontyObject::dict(vec![(str_obj("lang"), str_obj("en"))]))
+    );
+    assert_eq!(call.arg("missing"), None);
+}
+
+#[test]
+fn keyword_and_unpacked_arguments_are_bound() {
+    let code = "
+opts = {'limit': 5, 'exact': True}
+search(*['monty'], **opts)
+";
+    let call = function_call(code, vec![search()]);
+    assert_eq!(call.arg("query"), Some(&str_obj("monty")));
+    assert_eq!(call.arg("limit"), Some(&MontyObject::Int(5)));
+    assert_eq!(call.arg("exact"), Some(&MontyObject::Bool(true)));
+}
+
+#[test]
+fn result_returns_to_the_caller() {
+    let call = function_call("n = search('monty', limit=2)\nn + 1", vec![search()]);
+    assert_eq!(call.arg("limit"), Some(&MontyObject::Int(2)));
+    let progress = call.resume(MontyObject::Int(41), PrintWriter::Stdout).unwrap();
+    assert_eq!(progress.into_complete(), Some(MontyObject::Int(42)));
+}
+
+#[test]
+fn undeclared_functions_get_raw_arguments() {
+    let call = function_call("fetch(1, key='a')", vec![search()]);
+    assert_eq!(call.args, vec![MontyObject::Int(1)]);
+    assert_eq!(call.kwargs, vec![(str_obj("key"), str_obj("a"))]);
+    assert_eq!(call.bound_args, None);
+    assert_eq!(call.arg("key"), None);
+}
+
+#[test]
+fn missing_argument_raises_type_error_at_call() {
+    let code = "
+def outer():
+    return search()
+
+outer()
+";
+    let exc = call_error(code, vec![search()]);
+    assert_eq!(exc.exc_type(), ExcType::TypeError);
+    assert_eq!(
+        exc.message(),
+        Some("search() missing 1 required positional argument: 'query'")
+    );
+    let frame = exc.traceback().last().unwrap();
+    assert_eq!(frame.frame_name.as_deref(), Some("outer"));
+    assert_eq!(frame.start.line, 3);
+}
+
+#[test]
+fn binding_errors_match_cpython() {
+    let cases = [
+        (
+            "search(query='monty')",
+            "search() got some positional-only arguments passed as keyword arguments: 'query'",
+        ),
+        (
+            "search('monty', 1, limit=2)",
+            "search() got multiple values for argument 'limit'",
+        ),
+        (
+            "lookup(1, 2, 3)",
+            "lookup() takes 2 positional arguments but 3 were given",
+        ),
+        (
+            "lookup(1, 2, flag=True)",
+            "lookup() got an unexpected keyword argument 'flag'",
+        ),
+        ("lookup(1)", "lookup() missing 1 required positional argument: 'b'"),
+        (
+            "kw_only()",
+            "kw_only() missing 1 required keyword-only argument: 'token'",
+        ),
+    ];
+    let lookup = FunctionSignature::new("lookup", vec![Parameter::new("a"), Parameter::new("b")]);
+    let kw_only = FunctionSignature::new("kw_only", vec![Parameter::keyword_only("token")]);
+    for (code, message) in cases {
+        let exc = call_error(code, vec![search(), lookup.clone(), kw_only.clone()]);
+        assert_eq!(exc.exc_type(), ExcType::TypeError, "{code}");
+        assert_eq!(exc.message(), Some(message), "{code}");
+    }
+}
+
+#[test]
+fn wrong_argument_type_raises_type_error() {
+    let exc = call_error("search('monty', limit='ten')", vec![search()]);
+    assert_eq!(exc.exc_type(), ExcType::TypeError);
+    assert_eq!(exc.message(), Some("search() argument 'limit' must be int, not str"));
+
+    // bool is an int, as with isinstance
+    let call = function_call("search('monty', True)", vec![search()]);
+    assert_eq!(call.arg("limit"), Some(&MontyObject::Bool(true)));
+}
+
+#[test]
+fn union_types_accept_any_listed_type() {
+    let signature = FunctionSignature::new(
+        "greet",
+        vec![Parameter::new("name").with_type(Type::Str).with_type(Type::NoneType)],
+    );
+    let call = function_call("greet(None)", vec![signature.clone()]);
+    assert_eq!(call.arg("name"), Some(&MontyObject::None));
+
+    let exc = call_error("greet(1)", vec![signature]);
+    assert_eq!(
+        exc.message(),
+        Some("greet() argument 'name' must be str or None, not int")
+    );
+}
+
+#[test]
+fn binding_error_can_be_caught() {
+    let code = "
+try:
+    search()
+except TypeError as e:
+    result = str(e)
+result
+";
+    let progress = start(code, vec![search()]).unwrap();
+    assert_eq!(
+        progress.into_complete(),
+        Some(str_obj("search() missing 1 required positional argument: 'query'"))
+    );
+}
+
+#[test]
+fn host_module_functions_use_declared_signatures() {
+    let code = "import tools\ntools.search('monty', exact=True)";
+    let run = MontyRun::new(code.to_owned(), "test.py", vec![])
+        .unwrap()
+        .with_modules(vec![MontyModule::new("tools").with_function("search")])
+        .with_signatures(vec![search()])
+        .unwrap();
+    let call = run
+        .start(vec![], NoLimitTracker, PrintWriter::Stdout)
+        .unwrap()
+        .into_function_call()
+        .unwrap();
+    assert_eq!(call.arg("exact"), Some(&MontyObject::Bool(true)));
+    assert_eq!(call.arg("limit"), Some(&MontyObject::Int(10)));
+}
+
+#[test]
+fn signatures_survive_dump_and_load() {
+    let run = MontyRun::new("search(limit=1)".to_owned(), "test.py", vec![])
+        .unwrap()
+        .with_signatures(vec![search()])
+        .unwrap();
+    let run = MontyRun::load(&run.dump().unwrap()).unwrap();
+    let exc = step(run.start(vec![], NoLimitTracker, PrintWriter::Stdout)).unwrap_err();
+    assert_eq!(
+        exc.message(),
+        Some("search() missing 1 required positional argument: 'query'")
+    );
+}
+
+#[test]
+fn later_signature_replaces_earlier_one() {
+    let replacement = FunctionSignature::new("search", vec![Parameter::new("text")]);
+    let call = function_call("search('monty')", vec![search(), replacement]);
+    assert_eq!(call.bound_args, bound(vec![("text", str_obj("monty"))]));
+}
+
+#[test]
+fn repl_snippets_use_declared_signatures() {
+    let repl = MontyRepl::new("repl.py", NoLimitTracker)
+        .with_signatures(vec![search()])
+        .unwrap();
+    let repl = repl.feed_start("x = 1", vec![], PrintWriter::Stdout).unwrap();
+    let (repl, _) = repl.into_complete().unwrap();
+    let mut progress = repl
+        .feed_start("search('monty', x)", vec![], PrintWriter::Stdout)
+        .unwrap();
+    while let ReplProgress::NameLookup(lookup) = progress {
+        let function = MontyObject::Function {
+            name: lookup.name.clone(),
+            docstring: None,
+        };
+        progress = lookup
+            .resume(NameLookupResult::Value(function), PrintWriter::Stdout)
+            .unwrap();
+    }
+    let call = progress.into_function_call().unwrap();
+    assert_eq!(call.arg("limit"), Some(&MontyObject::Int(1)));
+}
+
+#[test]
+fn invalid_signatures_are_rejected() {
+    let cases = [
+        (
+            vec![Parameter::keyword_only("a"), Parameter::new("b")],
+            "f(): positional-or-keyword parameter 'b' can't follow keyword-only parameter 'a'",
+        ),
+        (
+            vec![
+                Parameter::new("a").with_default(MontyObject::Int(1)),
+                Parameter::new("b"),
+            ],
+            "f(): parameter 'b' without a default follows parameter with a default",
+        ),
+        (
+            vec![Parameter::new("a"), Parameter::keyword_only("a")],
+            "f(): duplicate parameter 'a'",
+        ),
+        (
+            vec![Parameter::var_positional("a"), Parameter::var_positional("b")],
+            "f(): variadic positional parameter 'b' can't follow variadic positional parameter 'a'",
+        ),
+        (
+            vec![Parameter::var_keyword("a").with_default(MontyObject::None)],
+            "f(): variadic keyword parameter 'a' can't have a default or a type",
+        ),
+        (
+            vec![Parameter::new("not valid")],
+            "f(): parameter name 'not valid' is not a valid identifier",
+        ),
+    ];
+    for (params, message) in cases {
+        let err = MontyRun::new("1".to_owned(), "test.py", vec![])
+            .unwrap()
+            .with_signatures(vec![FunctionSignature::new("f", params)])
+            .unwrap_err();
+        assert_eq!(err.exc_type(), ExcType::ValueError);
+        assert_eq!(err.message(), Some(message));
+    }
+}
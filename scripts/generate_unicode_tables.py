@@ -0,0 +1,490 @@
+#!/usr/bin/env python3
+"""Generate Monty's Unicode tables from CPython's `unicodedata` module.
+
+Writes to `crates/monty/src/unicode/`:
+- `tables.rs` — character records, case mappings, numeric values,
+  compositions, name aliases, named sequences, and the indexes into the
+  binary files below;
+- `index.bin` — the two-level code point to record index;
+- `decompositions.bin` — the character decompositions;
+- `names.bin` — the character-name phrasebook.
+
+Every property is read back from the running interpreter (`unicodedata` and the
+`str` predicates), so the tables match that CPython byte for byte. Run it with
+the CPython version the test suite pins (see `.python-version`); any other
+Unicode version is rejected.
+
+`unicodedata` can't enumerate name aliases or named sequences, so they are read
+from the UCD's `NameAliases.txt` and `NamedSequences.txt`: either from
+`--ucd-dir`, or downloaded from unicode.org for `unicodedata.unidata_version`.
+Each entry is kept only if `unicodedata.lookup()` agrees with it.
+
+Usage:
+    python scripts/generate_unicode_tables.py [--ucd-dir DIR]
+"""
+
+import argparse
+import re
+import sys
+import unicodedata
+import urllib.request
+from collections import Counter
+from pathlib import Path
+
+EXPECTED_UNIDATA_VERSION = '16.0.0'
+"""The Unicode version of CPython 3.14, which the test suite runs against."""
+
+OUT_DIR = Path(__file__).parent.parent / 'crates' / 'monty' / 'src' / 'unicode'
+
+CATEGORIES = [
+    'Cn', 'Lu', 'Ll', 'Lt', 'Lm', 'Lo', 'Mn', 'Mc', 'Me', 'Nd', 'Nl', 'No', 'Pc', 'Pd', 'Ps', 'Pe', 'Pi', 'Pf', 'Po',
+    'Sm', 'Sc', 'Sk', 'So', 'Zs', 'Zl', 'Zp', 'Cc', 'Cf', 'Cs', 'Co',
+]  # fmt: skip
+BIDI_CLASSES = [
+    '', 'L', 'LRE', 'LRO', 'R', 'AL', 'RLE', 'RLO', 'PDF', 'EN', 'ES', 'ET', 'AN', 'CS', 'NSM', 'BN', 'B', 'S', 'WS',
+    'ON', 'LRI', 'RLI', 'FSI', 'PDI',
+]  # fmt: skip
+EAST_ASIAN_WIDTHS = ['N', 'Na', 'A', 'W', 'H', 'F']
+DECOMPOSITION_TAGS = [
+    '', '<font>', '<noBreak>', '<initial>', '<medial>', '<final>', '<isolated>', '<circle>', '<super>', '<sub>',
+    '<vertical>', '<wide>', '<narrow>', '<small>', '<square>', '<fraction>', '<compat>',
+]  # fmt: skip
+
+# Flag bits, mirrored by the `FLAG_*` constants in `unicode/mod.rs`.
+FLAG_ALPHA = 1 << 0
+FLAG_DECIMAL = 1 << 1
+FLAG_DIGIT = 1 << 2
+FLAG_NUMERIC = 1 << 3
+FLAG_LOWER = 1 << 4
+FLAG_UPPER = 1 << 5
+FLAG_TITLE = 1 << 6
+FLAG_CASED = 1 << 7
+FLAG_CASE_IGNORABLE = 1 << 8
+FLAG_SPACE = 1 << 9
+FLAG_PRINTABLE = 1 << 10
+FLAG_IDENTIFIER_START = 1 << 11
+FLAG_IDENTIFIER_CONTINUE = 1 << 12
+FLAG_MIRRORED = 1 << 13
+
+# Bits of the code point index used by the second table level.
+SHIFT = 7
+# Number of entries between two `NAME_CHUNKS` or `DECOMPOSITION_CHUNKS` entries.
+CHUNK = 32
+# Hangul syllables, whose names CPython computes from their jamo.
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+# Name tokens for the separators between words.
+SEPARATOR_TOKENS = {' ': 0, '-': 1}
+
+
+def flags_for(c: str) -> int:
+    """Returns the `FLAG_*` bits for `c`, read from CPython's `str` predicates."""
+    flags = 0
+    if c.isalpha():
+        flags |= FLAG_ALPHA
+    if c.isdecimal():
+        flags |= FLAG_DECIMAL
+    if c.isdigit():
+        flags |= FLAG_DIGIT
+    if c.isnumeric():
+        flags |= FLAG_NUMERIC
+    if c.islower():
+        flags |= FLAG_LOWER
+    if c.isupper():
+        flags |= FLAG_UPPER
+    if unicodedata.category(c) == 'Lt':
+        flags |= FLAG_TITLE
+    if flags & (FLAG_LOWER | FLAG_UPPER | FLAG_TITLE):
+        flags |= FLAG_CASED
+    # `str.lower()` only exposes Case_Ignorable through the final-sigma rule:
+    # a case-ignorable char is skipped on both sides of the sigma, any other
+    # char ends the scan, so only an ignorable one makes both of these final.
+    before = ('A' + c + 'Σ').lower()[-1] == 'ς'
+    after = ('AΣ' + c).lower()[1] == 'ς'
+    if before and after:
+        flags |= FLAG_CASE_IGNORABLE
+    if c.isspace():
+        flags |= FLAG_SPACE
+    if c.isprintable():
+        flags |= FLAG_PRINTABLE
+    if c.isidentifier():
+        flags |= FLAG_IDENTIFIER_START
+    if ('a' + c).isidentifier():
+        flags |= FLAG_IDENTIFIER_CONTINUE
+    if unicodedata.mirrored(c):
+        flags |= FLAG_MIRRORED
+    return flags
+
+
+def record_for(cp: int) -> tuple[int, int, int, int, int, int]:
+    """Returns the `Record` fields for code point `cp`."""
+    c = chr(cp)
+    digit = unicodedata.digit(c, None)
+    return (
+        CATEGORIES.index(unicodedata.category(c)),
+        BIDI_CLASSES.index(unicodedata.bidirectional(c)),
+        unicodedata.combining(c),
+        EAST_ASIAN_WIDTHS.index(unicodedata.east_asian_width(c)),
+        0xFF if digit is None else digit,
+        flags_for(c),
+    )
+
+
+def split_table(values: list[int]) -> tuple[list[int], list[int]]:
+    """Splits `values` into a two-level table of `1 << SHIFT` sized blocks with duplicates shared."""
+    size = 1 << SHIFT
+    blocks: dict[tuple[int, ...], int] = {}
+    index1 = []
+    index2: list[int] = []
+    for start in range(0, len(values), size):
+        block = tuple(values[start : start + size])
+        if block not in blocks:
+            blocks[block] = len(index2) >> SHIFT
+            index2.extend(block)
+        index1.append(blocks[block])
+    return index1, index2
+
+
+def rust_str(s: str) -> str:
+    """Formats `s` as a Rust string literal, escaping everything outside printable ASCII."""
+    out = []
+    for c in s:
+        if c in '"\\':
+            out.append('\\' + c)
+        elif ' ' <= c <= '~':
+            out.append(c)
+        else:
+            out.append(f'\\u{{{ord(c):x}}}')
+    return '"' + ''.join(out) + '"'
+
+
+def ranged_name(cp: int, name: str) -> str | None:
+    """Returns the prefix of a `<PREFIX>-<hex code point>` name, which CPython computes."""
+    m = re.fullmatch(r'(.+)-([0-9A-F]{4,6})', name)
+    if m and int(m.group(2), 16) == cp:
+        return m.group(1)
+    return None
+
+
+def build_names() -> tuple[bytes, list[tuple[int, int]], int, list[tuple[int, int, str]]]:
+    """Builds the phrasebook of stored names and the ranges of computed ones.
+
+    `names.bin` layout (all integers little-endian):
+    - `u32` word count `W`, then `W + 1` `u32` offsets into the word bytes;
+    - the word bytes;
+    - the entries, ordered by code point: the LEB128 distance from the previous
+      entry's code point (0 for the first entry of a chunk, whose code point is
+      in `NAME_CHUNKS`), a 1-byte token length, then the tokens. A token below
+      `0x80` is one byte; otherwise it is two bytes, `0x80 | high` then `low`.
+      Token 0 is a space, 1 is a hyphen, and `n >= 2` is word `n - 2`; the
+      space between two consecutive words is implied rather than stored.
+    """
+    names: dict[int, str] = {}
+    ranges: list[tuple[int, int, str]] = []
+    for cp in range(0x110000):
+        if cp in HANGUL_SYLLABLES:
+            continue
+        name = unicodedata.name(chr(cp), None)
+        if name is None:
+            continue
+        prefix = ranged_name(cp, name)
+        if prefix is None:
+            names[cp] = name
+        elif ranges and ranges[-1][2] == prefix and ranges[-1][1] == cp - 1:
+            ranges[-1] = (ranges[-1][0], cp, prefix)
+        else:
+            ranges.append((cp, cp, prefix))
+
+    token_re = re.compile(r'[^ -]+|[ -]')
+    counts = Counter(tok for name in names.values() for tok in token_re.findall(name) if tok not in SEPARATOR_TOKENS)
+    words = [word for word, _ in counts.most_common()]
+    word_ids = {word: i + 2 for i, word in enumerate(words)}
+    assert len(word_ids) + 2 < 0x8000
+
+    word_bytes = bytearray()
+    offsets = []
+    for word in words:
+        offsets.append(len(word_bytes))
+        word_bytes += word.encode('ascii')
+    offsets.append(len(word_bytes))
+
+    out = bytearray()
+    out += len(words).to_bytes(4, 'little')
+    for offset in offsets:
+        out += offset.to_bytes(4, 'little')
+    out += word_bytes
+    entries_start = len(out)
+
+    chunks = []
+    previous = 0
+    for i, (cp, name) in enumerate(sorted(names.items())):
+        if i % CHUNK == 0:
+            chunks.append((cp, len(out) - entries_start))
+            previous = cp
+        parts = token_re.findall(name)
+        tokens = bytearray()
+        for j, tok in enumerate(parts):
+            if tok == ' ' and 0 < j < len(parts) - 1 and parts[j - 1] in word_ids and parts[j + 1] in word_ids:
+                continue
+            token = SEPARATOR_TOKENS[tok] if tok in SEPARATOR_TOKENS else word_ids[tok]
+            if token < 0x80:
+                tokens.append(token)
+            else:
+                tokens += bytes([0x80 | (token >> 8), token & 0xFF])
+        assert len(tokens) < 0x100
+        out += leb128(cp - previous)
+        out.append(len(tokens))
+        out += tokens
+        previous = cp
+    return bytes(out), chunks, entries_start, ranges
+
+
+def build_lookup_ranges(prefixes: set[str]) -> list[tuple[int, int, str]]:
+    """Finds the ranges `unicodedata.lookup()` resolves by `<PREFIX>-<hex>` although `name()` has no name for them."""
+    ranges: list[tuple[int, int, str]] = []
+    for cp in range(0x110000):
+        c = chr(cp)
+        if unicodedata.category(c) != 'Lo' or unicodedata.name(c, None) is not None:
+            continue
+        for prefix in sorted(prefixes):
+            try:
+                found = unicodedata.lookup(f'{prefix}-{cp:04X}') == c
+            except KeyError:
+                found = False
+            if not found:
+                continue
+            if ranges and ranges[-1][2] == prefix and ranges[-1][1] == cp - 1:
+                ranges[-1] = (ranges[-1][0], cp, prefix)
+            else:
+                ranges.append((cp, cp, prefix))
+    return ranges
+
+
+def build_decompositions(decompositions: list[tuple[int, int, list[int]]]) -> tuple[bytes, list[tuple[int, int]]]:
+    """Packs the decompositions into `decompositions.bin`.
+
+    Each entry, ordered by code point, is a 3-byte little-endian code point, the
+    tag index, the mapping length, then the mapping as 3-byte code points.
+    `DECOMPOSITION_CHUNKS` holds the code point and offset of every chunk's first entry.
+    """
+    out = bytearray()
+    chunks = []
+    for i, (cp, tag, parts) in enumerate(decompositions):
+        if i % CHUNK == 0:
+            chunks.append((cp, len(out)))
+        out += cp.to_bytes(3, 'little')
+        out.append(tag)
+        out.append(len(parts))
+        for part in parts:
+            out += part.to_bytes(3, 'little')
+    return bytes(out), chunks
+
+
+def leb128(value: int) -> bytes:
+    """Encodes `value` as unsigned LEB128."""
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def read_ucd_file(ucd_dir: Path | None, file_name: str) -> list[list[str]]:
+    """Reads the `;`-separated rows of a UCD file, from `ucd_dir` or unicode.org."""
+    if ucd_dir is not None:
+        text = (ucd_dir / file_name).read_text()
+    else:
+        url = f'https://www.unicode.org/Public/{unicodedata.unidata_version}/ucd/{file_name}'
+        with urllib.request.urlopen(url) as response:
+            text = response.read().decode()
+    rows = []
+    for line in text.splitlines():
+        line = line.split('#', 1)[0].strip()
+        if line:
+            rows.append([field.strip() for field in line.split(';')])
+    return rows
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument('--ucd-dir', type=Path, help='directory holding NameAliases.txt and NamedSequences.txt')
+    args = parser.parse_args()
+
+    version = unicodedata.unidata_version
+    if version != EXPECTED_UNIDATA_VERSION:
+        sys.exit(
+            f'error: this interpreter has Unicode {version}, but the test suite expects '
+            f'{EXPECTED_UNIDATA_VERSION}; run the script with CPython 3.14'
+        )
+
+    records: dict[tuple[int, ...], int] = {}
+    record_ids = []
+    for cp in range(0x110000):
+        record = record_for(cp)
+        record_ids.append(records.setdefault(record, len(records)))
+    index1, index2 = split_table(record_ids)
+
+    casing = []
+    numeric = []
+    decompositions = []
+    compositions = []
+    for cp in range(0x110000):
+        c = chr(cp)
+        mappings = (c.lower(), c.upper(), c.title(), c.casefold())
+        if any(m != c for m in mappings):
+            casing.append((cp, *mappings))
+        value = unicodedata.numeric(c, None)
+        if value is not None and unicodedata.digit(c, None) is None:
+            numeric.append((cp, value))
+        decomposition = unicodedata.decomposition(c)
+        # Hangul syllables decompose algorithmically.
+        if decomposition and cp not in HANGUL_SYLLABLES:
+            fields = decomposition.split()
+            tag = fields.pop(0) if fields[0].startswith('<') else ''
+            parts = [int(f, 16) for f in fields]
+            decompositions.append((cp, DECOMPOSITION_TAGS.index(tag), parts))
+            if not tag and len(parts) == 2 and unicodedata.normalize('NFC', c) == c:
+                compositions.append((parts[0], parts[1], cp))
+    compositions.sort()
+
+    decompositions_bin, decomposition_chunks = build_decompositions(decompositions)
+    names_bin, name_chunks, entries_start, name_ranges = build_names()
+    lookup_ranges = build_lookup_ranges({prefix for _, _, prefix in name_ranges} | {'TANGUT IDEOGRAPH'})
+
+    aliases = []
+    for cp, alias, _kind in read_ucd_file(args.ucd_dir, 'NameAliases.txt'):
+        try:
+            if unicodedata.lookup(alias) == chr(int(cp, 16)):
+                aliases.append((alias, int(cp, 16)))
+        except KeyError:
+            pass
+    aliases.sort()
+    sequences = []
+    for name, cps in read_ucd_file(args.ucd_dir, 'NamedSequences.txt'):
+        seq = ''.join(chr(int(cp, 16)) for cp in cps.split())
+        try:
+            if unicodedata.lookup(name) == seq:
+                sequences.append((name, seq))
+        except KeyError:
+            pass
+    sequences.sort()
+
+    out = []
+    w = out.append
+    w(f'//! Unicode {version} tables generated by `scripts/generate_unicode_tables.py`. Do not edit.')
+    w('')
+    w('use super::Record;')
+    w('')
+    w('/// The Unicode version of these tables, exposed as `unicodedata.unidata_version`.')
+    w(f'pub(super) const UNIDATA_VERSION: &str = "{version}";')
+    w('')
+    w('/// Bits of the code point used to index within a block of `index.bin`.')
+    w(f'pub(super) const SHIFT: u32 = {SHIFT};')
+    w('')
+    w('/// General category names, indexed by [`Record::category`].')
+    w(f'pub(super) const CATEGORIES: [&str; {len(CATEGORIES)}] = [{", ".join(rust_str(c) for c in CATEGORIES)}];')
+    w('')
+    w('/// Bidirectional class names, indexed by [`Record::bidirectional`].')
+    w(
+        f'pub(super) const BIDI_CLASSES: [&str; {len(BIDI_CLASSES)}] = '
+        f'[{", ".join(rust_str(c) for c in BIDI_CLASSES)}];'
+    )
+    w('')
+    w('/// East Asian width names, indexed by [`Record::east_asian_width`].')
+    w(
+        f'pub(super) const EAST_ASIAN_WIDTHS: [&str; {len(EAST_ASIAN_WIDTHS)}] = '
+        f'[{", ".join(rust_str(c) for c in EAST_ASIAN_WIDTHS)}];'
+    )
+    w('')
+    w('/// Decomposition tags, indexed by the tag of a `decompositions.bin` entry; tag 0 is canonical.')
+    w(
+        f'pub(super) const DECOMPOSITION_TAGS: [&str; {len(DECOMPOSITION_TAGS)}] = '
+        f'[{", ".join(rust_str(c) for c in DECOMPOSITION_TAGS)}];'
+    )
+    w('')
+    w('/// The distinct character records, indexed by the second level of `index.bin`.')
+    w(f'pub(super) static RECORDS: [Record; {len(records)}] = [')
+    for record in records:
+        w('    Record::new({}, {}, {}, {}, {}, {}),'.format(*record))
+    w('];')
+    w('')
+    w('/// Number of `u16` entries of the first level of `index.bin`, which holds the')
+    w('/// block of each `1 << SHIFT` code points; the second level after it holds the')
+    w('/// [`RECORDS`] index of each code point, in shared blocks.')
+    w(f'pub(super) const INDEX1_LEN: usize = {len(index1)};')
+    w('')
+    w('/// Full case mappings `(code point, lower, upper, title, casefold)` for every')
+    w('/// character with at least one mapping other than itself, ordered by code point.')
+    w('pub(super) static CASING: &[(u32, &str, &str, &str, &str)] = &[')
+    for cp, *mappings in casing:
+        w(f'    ({cp:#x}, {", ".join(rust_str(m) for m in mappings)}),')
+    w('];')
+    w('')
+    w('/// Numeric values of characters with no digit value, ordered by code point.')
+    w('pub(super) static NUMERIC: &[(u32, f64)] = &[')
+    for cp, value in numeric:
+        w(f'    ({cp:#x}, {float(value)!r}),')
+    w('];')
+    w('')
+    w(f'/// `(first code point, offset)` of every {CHUNK}th entry in `decompositions.bin`.')
+    w('pub(super) static DECOMPOSITION_CHUNKS: &[(u32, u32)] = &[')
+    for cp, offset in decomposition_chunks:
+        w(f'    ({cp:#x}, {offset}),')
+    w('];')
+    w('')
+    w('/// Primary composites `(first, second, composite)`, ordered by `(first, second)`.')
+    w('pub(super) static COMPOSITIONS: &[(u32, u32, u32)] = &[')
+    for first, second, cp in compositions:
+        w(f'    ({first:#x}, {second:#x}, {cp:#x}),')
+    w('];')
+    w('')
+    w('/// Number of entries between two entries of a chunk index.')
+    w(f'pub(super) const CHUNK: usize = {CHUNK};')
+    w('')
+    w('/// Offset of the entries in `names.bin`; see the generator for the layout.')
+    w(f'pub(super) const NAME_ENTRIES_START: usize = {entries_start};')
+    w('')
+    w(f'/// `(first code point, entry offset)` of every {CHUNK}th entry in `names.bin`.')
+    w('pub(super) static NAME_CHUNKS: &[(u32, u32)] = &[')
+    for cp, offset in name_chunks:
+        w(f'    ({cp:#x}, {offset}),')
+    w('];')
+    w('')
+    w('/// Ranges named `<prefix>-<hex code point>`, ordered by code point.')
+    w('pub(super) static NAME_RANGES: &[(u32, u32, &str)] = &[')
+    for start, end, prefix in name_ranges:
+        w(f'    ({start:#x}, {end:#x}, {rust_str(prefix)}),')
+    w('];')
+    w('')
+    w('/// Ranges `unicodedata.lookup()` accepts as `<prefix>-<hex code point>` although')
+    w('/// `unicodedata.name()` doesn\'t name them, ordered by code point.')
+    w('pub(super) static LOOKUP_RANGES: &[(u32, u32, &str)] = &[')
+    for start, end, prefix in lookup_ranges:
+        w(f'    ({start:#x}, {end:#x}, {rust_str(prefix)}),')
+    w('];')
+    w('')
+    w('/// Name aliases accepted by `unicodedata.lookup()`, ordered by name.')
+    w('pub(super) static NAME_ALIASES: &[(&str, u32)] = &[')
+    for alias, cp in aliases:
+        w(f'    ({rust_str(alias)}, {cp:#x}),')
+    w('];')
+    w('')
+    w('/// Named sequences accepted by `unicodedata.lookup()`, ordered by name.')
+    w('pub(super) static NAMED_SEQUENCES: &[(&str, &str)] = &[')
+    for name, seq in sequences:
+        w(f'    ({rust_str(name)}, {rust_str(seq)}),')
+    w('];')
+
+    (OUT_DIR / 'tables.rs').write_text('\n'.join(out) + '\n')
+    index_bin = b''.join(v.to_bytes(2, 'little') for v in index1 + index2)
+    (OUT_DIR / 'index.bin').write_bytes(index_bin)
+    (OUT_DIR / 'decompositions.bin').write_bytes(decompositions_bin)
+    (OUT_DIR / 'names.bin').write_bytes(names_bin)
+    print(f'wrote Unicode {version} tables to {OUT_DIR}')
+
+
+if __name__ == '__main__':
+    main()